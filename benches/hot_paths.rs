@@ -0,0 +1,214 @@
+//! Benchmarks for the hot paths a frame goes through: layout of a large
+//! tree, hit testing down a deep one, keyed-list reconciliation, and
+//! frame-graph build/compile. Everything runs headless; no GPU is needed.
+//!
+//! Run with `cargo bench --bench hot_paths`.
+
+use criterion::{BatchSize, Criterion, criterion_group, criterion_main};
+use rfgui::style::{Layout, Length, Padding};
+use rfgui::ui::{RsxNode, rsx};
+use rfgui::view::Element;
+use rfgui::view::Viewport;
+use rfgui::view::base_component::hit_test_roots;
+use rfgui::view::frame_graph::slot::OutSlot;
+use rfgui::view::frame_graph::texture_resource::TextureResource;
+use rfgui::view::frame_graph::{
+    ExternalSinkKind, FrameGraph, GraphicsColorAttachmentOps, GraphicsPassBuilder, TextureDesc,
+};
+use rfgui::view::render_pass::{GraphicsCtx, GraphicsPass};
+use std::hint::black_box;
+
+const VIEWPORT_SIZE: (u32, u32) = (1280, 800);
+
+/// `rows * columns` fixed-size cells in wrapping flow rows.
+fn grid_tree(rows: usize, columns: usize) -> RsxNode {
+    rsx! {
+        <Element style={{
+            width: Length::percent(100.0),
+            layout: Layout::flow().column().no_wrap(),
+        }}>
+            {(0..rows).map(|row| rsx! {
+                <Element key={row} style={{
+                    width: Length::percent(100.0),
+                    layout: Layout::flow().row().wrap(),
+                    gap: Length::px(2.0),
+                }}>
+                    {(0..columns).map(|column| rsx! {
+                        <Element key={column} style={{
+                            width: Length::px(8.0),
+                            height: Length::px(8.0),
+                            background: "#4a90e2",
+                        }} />
+                    }).collect::<Vec<RsxNode>>()}
+                </Element>
+            }).collect::<Vec<RsxNode>>()}
+        </Element>
+    }
+}
+
+/// A chain of `depth` nested elements, each inset by one pixel.
+fn deep_tree(depth: usize) -> RsxNode {
+    let mut node = rsx! {
+        <Element style={{ width: Length::px(4.0), height: Length::px(4.0) }} />
+    };
+    for _ in 0..depth {
+        node = rsx! {
+            <Element style={{ padding: Padding::uniform(Length::px(1.0)) }}>
+                {node}
+            </Element>
+        };
+    }
+    node
+}
+
+/// A keyed list whose keys are rotated by `offset`, so consecutive
+/// offsets reorder every item.
+fn keyed_list(len: usize, offset: usize) -> RsxNode {
+    rsx! {
+        <Element style={{ layout: Layout::flow().column().no_wrap() }}>
+            {(0..len).map(|index| {
+                let key = (index + offset) % len;
+                rsx! {
+                    <Element key={key} style={{
+                        width: Length::px(120.0),
+                        height: Length::px(16.0),
+                    }} />
+                }
+            }).collect::<Vec<RsxNode>>()}
+        </Element>
+    }
+}
+
+fn viewport_with(root: &RsxNode) -> Viewport {
+    let mut viewport = Viewport::new();
+    viewport.set_size(VIEWPORT_SIZE.0, VIEWPORT_SIZE.1);
+    viewport
+        .render_rsx(root)
+        .expect("benchmark tree should commit");
+    viewport
+}
+
+fn bench_layout(c: &mut Criterion) {
+    let tree = grid_tree(100, 100);
+    let mut group = c.benchmark_group("layout");
+    group.sample_size(20);
+    group.bench_function("10k_elements_cold", |b| {
+        b.iter_batched(
+            || viewport_with(&tree),
+            |mut viewport| {
+                viewport.layout_headless();
+                viewport
+            },
+            BatchSize::LargeInput,
+        );
+    });
+    group.bench_function("10k_elements_resize", |b| {
+        let mut viewport = viewport_with(&tree);
+        viewport.layout_headless();
+        let mut wide = false;
+        b.iter(|| {
+            wide = !wide;
+            let width = if wide { 1600 } else { VIEWPORT_SIZE.0 };
+            viewport.set_size(width, VIEWPORT_SIZE.1);
+            viewport.layout_headless();
+        });
+    });
+    group.finish();
+}
+
+fn bench_hit_test(c: &mut Criterion) {
+    let depth = 200;
+    let mut viewport = viewport_with(&deep_tree(depth));
+    viewport.layout_headless();
+    // Every level insets by one pixel, so this lands in the innermost leaf.
+    let point = (depth as f32 + 2.0, depth as f32 + 2.0);
+    c.bench_function("hit_test/deep_tree_200", |b| {
+        b.iter(|| {
+            let arena = viewport.node_arena();
+            black_box(hit_test_roots(
+                arena,
+                arena.roots(),
+                black_box(point.0),
+                black_box(point.1),
+            ))
+        });
+    });
+}
+
+fn bench_reconcile(c: &mut Criterion) {
+    let len = 2_000;
+    let orders = [keyed_list(len, 0), keyed_list(len, len / 2)];
+    let mut viewport = viewport_with(&orders[0]);
+    let mut next = 1;
+    let mut group = c.benchmark_group("reconcile");
+    group.sample_size(20);
+    group.bench_function("keyed_list_2k_rotate", |b| {
+        b.iter(|| {
+            viewport
+                .render_rsx(&orders[next])
+                .expect("keyed list should reconcile");
+            next = 1 - next;
+        });
+    });
+    group.finish();
+}
+
+struct FillPass {
+    target: OutSlot<TextureResource, ()>,
+    clear: bool,
+}
+
+impl GraphicsPass for FillPass {
+    fn setup(&mut self, builder: &mut GraphicsPassBuilder<'_, '_>) {
+        let ops = if self.clear {
+            GraphicsColorAttachmentOps::clear([0.0, 0.0, 0.0, 0.0])
+        } else {
+            GraphicsColorAttachmentOps::load()
+        };
+        builder.write_color(&self.target, ops);
+    }
+
+    fn execute(&mut self, _ctx: &mut GraphicsCtx<'_, '_, '_, '_>) {}
+}
+
+fn build_frame_graph(layers: usize, passes_per_layer: usize) -> FrameGraph {
+    let mut graph = FrameGraph::new();
+    for _ in 0..layers {
+        let target = graph.declare_texture::<()>(TextureDesc::new(
+            256,
+            256,
+            wgpu::TextureFormat::Rgba8Unorm,
+            wgpu::TextureDimension::D2,
+        ));
+        for index in 0..passes_per_layer {
+            graph.add_graphics_pass(FillPass {
+                target: target.clone(),
+                clear: index == 0,
+            });
+        }
+        // Export each layer so compile keeps its passes instead of culling them.
+        graph
+            .add_texture_sink(&target, ExternalSinkKind::ExportTexture)
+            .expect("layer texture should accept an export sink");
+    }
+    graph
+}
+
+fn bench_frame_graph(c: &mut Criterion) {
+    c.bench_function("frame_graph/build_and_compile_2k_passes", |b| {
+        b.iter(|| {
+            let mut graph = build_frame_graph(20, 100);
+            graph.compile().expect("benchmark graph should compile");
+            graph
+        });
+    });
+}
+
+criterion_group!(
+    benches,
+    bench_layout,
+    bench_hit_test,
+    bench_reconcile,
+    bench_frame_graph
+);
+criterion_main!(benches);
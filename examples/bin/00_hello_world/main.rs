@@ -3,7 +3,7 @@ extern crate rfgui_components;
 
 mod scene;
 
-use rfgui::app::{App, AppConfig, AppContext, WheelConfig};
+use rfgui::app::{App, AppConfig, AppContext, WheelConfig, WindowOptions};
 use rfgui::style::Color;
 use rfgui::ui::{RsxNode, rsx};
 
@@ -23,6 +23,8 @@ fn make_config() -> AppConfig {
         initial_size: (1280, 800),
         scale_factor: None,
         transparent: false,
+        decorations: true,
+        window: WindowOptions::default(),
         clear_color: Some(Color::rgb(40, 44, 52)),
         wheel: WheelConfig::default(),
     }
@@ -11,7 +11,7 @@ mod scene_windows;
 mod utils;
 mod window_manager;
 
-use rfgui::app::{App, AppConfig, AppContext, WheelConfig};
+use rfgui::app::{App, AppConfig, AppContext, WheelConfig, WindowOptions};
 use rfgui::style::Color;
 use rfgui::ui::{RsxNode, rsx};
 use rfgui::view::viewport::ViewportPaintRendererMode;
@@ -95,6 +95,8 @@ fn make_config() -> AppConfig {
         initial_size: (1280, 800),
         scale_factor: None,
         transparent: false,
+        decorations: true,
+        window: WindowOptions::default(),
         clear_color: Some(Color::rgb(40, 44, 52)),
         wheel: WheelConfig::default(),
     }
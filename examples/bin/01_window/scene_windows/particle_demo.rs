@@ -730,10 +730,12 @@ impl Renderable for ParticleCanvas {
                 opacity: 1.0,
                 scissor_rect: None,
             },
+            // The inherited pass context carries the ancestor stencil clip,
+            // so the canvas follows rounded corners of its containers.
             TextureCompositeInput::from_render_target(
                 source_in,
                 Default::default(),
-                Default::default(),
+                ctx.graphics_pass_context(),
             ),
             TextureCompositeOutput {
                 render_target: output_target,
@@ -59,6 +59,12 @@ impl WindowManager {
                 on_blur: None,
                 window_slots: None,
                 scrollable: None,
+                state: None,
+                maximizable: None,
+                minimizable: None,
+                snappable: None,
+                style: None,
+                class: None,
             },
             children,
         });
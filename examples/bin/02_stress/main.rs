@@ -0,0 +1,180 @@
+//! Stress scenes for the layout, reconciliation and hit-test hot paths.
+//!
+//! Pick a scene with `RFGUI_STRESS=grid|list|deep` (default `grid`). Each
+//! scene updates on a 16ms interval and the viewport prints fps once a
+//! second, so a regression shows up as a drop in the reported rate.
+
+extern crate rfgui;
+
+use std::time::Duration;
+
+use rfgui::app::{App, AppConfig, AppContext, WheelConfig, WindowOptions};
+use rfgui::style::{Color, Layout, Length, Padding};
+use rfgui::ui::{RsxNode, component, rsx, use_interval, use_state};
+use rfgui::view::Element;
+use rfgui::view::viewport::ViewportDebugOptions;
+
+const TICK: Duration = Duration::from_millis(16);
+#[cfg(not(target_arch = "wasm32"))]
+const SCENE_ENV: &str = "RFGUI_STRESS";
+
+#[derive(Clone, Copy, PartialEq)]
+enum Scene {
+    /// 10k cells whose size changes every tick, forcing a full relayout.
+    Grid,
+    /// 2k keyed rows rotated every tick, exercising keyed reconciliation.
+    List,
+    /// A 200-deep nesting chain; move the pointer over it to hit test.
+    Deep,
+}
+
+impl Scene {
+    fn from_label(label: Option<&str>) -> Self {
+        match label {
+            Some("list") => Self::List,
+            Some("deep") => Self::Deep,
+            _ => Self::Grid,
+        }
+    }
+}
+
+#[component]
+fn GridScene() -> RsxNode {
+    let tick = use_state(|| 0_u32);
+    {
+        let tick = tick.clone();
+        use_interval(true, TICK, move || tick.update(|value| *value += 1));
+    }
+    let cell = if tick.get() % 2 == 0 { 8.0 } else { 9.0 };
+    rsx! {
+        <Element style={{
+            width: Length::percent(100.0),
+            layout: Layout::flow().column().no_wrap(),
+        }}>
+            {(0..100).map(|row| rsx! {
+                <Element key={row} style={{
+                    width: Length::percent(100.0),
+                    layout: Layout::flow().row().wrap(),
+                    gap: Length::px(2.0),
+                }}>
+                    {(0..100).map(|column| rsx! {
+                        <Element key={column} style={{
+                            width: Length::px(cell),
+                            height: Length::px(cell),
+                            background: "#4a90e2",
+                        }} />
+                    }).collect::<Vec<RsxNode>>()}
+                </Element>
+            }).collect::<Vec<RsxNode>>()}
+        </Element>
+    }
+}
+
+#[component]
+fn ListScene() -> RsxNode {
+    const LEN: usize = 2_000;
+    let tick = use_state(|| 0_usize);
+    {
+        let tick = tick.clone();
+        use_interval(true, TICK, move || tick.update(|value| *value += 1));
+    }
+    let offset = tick.get() * 37 % LEN;
+    rsx! {
+        <Element style={{
+            width: Length::percent(100.0),
+            height: Length::percent(100.0),
+            layout: Layout::flow().column().no_wrap(),
+        }}>
+            {(0..LEN).map(|index| {
+                let key = (index + offset) % LEN;
+                rsx! {
+                    <Element key={key} style={{
+                        width: Length::px(120.0 + (key % 7) as f32 * 10.0),
+                        height: Length::px(4.0),
+                        background: "#e2904a",
+                    }} />
+                }
+            }).collect::<Vec<RsxNode>>()}
+        </Element>
+    }
+}
+
+#[component]
+fn DeepScene() -> RsxNode {
+    let tick = use_state(|| 0_u32);
+    {
+        let tick = tick.clone();
+        use_interval(true, TICK, move || tick.update(|value| *value += 1));
+    }
+    let leaf = if tick.get() % 2 == 0 { 40.0 } else { 44.0 };
+    let mut node = rsx! {
+        <Element style={{
+            width: Length::px(leaf),
+            height: Length::px(leaf),
+            background: "#90e24a",
+        }} />
+    };
+    for _ in 0..200 {
+        node = rsx! {
+            <Element style={{ padding: Padding::uniform(Length::px(1.0)) }}>
+                {node}
+            </Element>
+        };
+    }
+    node
+}
+
+struct StressApp {
+    scene: Scene,
+}
+
+impl App for StressApp {
+    fn build(&mut self, _ctx: &mut AppContext<'_>) -> RsxNode {
+        match self.scene {
+            Scene::Grid => rsx! { <GridScene /> },
+            Scene::List => rsx! { <ListScene /> },
+            Scene::Deep => rsx! { <DeepScene /> },
+        }
+    }
+
+    fn on_ready(&mut self, ctx: &mut AppContext<'_>) {
+        ctx.viewport.set_debug_options(ViewportDebugOptions {
+            trace_fps: true,
+            ..ViewportDebugOptions::default()
+        });
+    }
+}
+
+fn make_config() -> AppConfig {
+    AppConfig {
+        title: String::from("RFGUI Stress"),
+        initial_size: (1280, 800),
+        scale_factor: None,
+        transparent: false,
+        decorations: true,
+        window: WindowOptions::default(),
+        clear_color: Some(Color::rgb(40, 44, 52)),
+        wheel: WheelConfig::default(),
+    }
+}
+
+#[cfg(not(target_arch = "wasm32"))]
+fn main() {
+    let label = std::env::var(SCENE_ENV).ok();
+    examples::winit_runner::run(
+        StressApp {
+            scene: Scene::from_label(label.as_deref()),
+        },
+        make_config(),
+    );
+}
+
+#[cfg(target_arch = "wasm32")]
+fn main() {
+    examples::web_runner::run(
+        StressApp {
+            scene: Scene::from_label(None),
+        },
+        make_config(),
+    );
+}
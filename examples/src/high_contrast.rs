@@ -0,0 +1,105 @@
+//! OS high-contrast setting for the example hosts.
+//!
+//! rfgui only stores the value (`Viewport::set_system_high_contrast`); each
+//! host reads it from its platform at startup and again whenever the window
+//! regains focus, since the setting is toggled from system preferences.
+
+/// Whether the OS asks apps for high contrast: Windows high-contrast
+/// themes, macOS "Increase contrast", or the browser's `forced-colors` /
+/// `prefers-contrast: more` media features on the web. `None` where the
+/// platform has no such setting or it could not be read.
+pub fn system_prefers_high_contrast() -> Option<bool> {
+    imp::system_prefers_high_contrast()
+}
+
+#[cfg(target_os = "windows")]
+mod imp {
+    use std::ffi::c_void;
+
+    const SPI_GETHIGHCONTRAST: u32 = 0x0042;
+    const HCF_HIGHCONTRASTON: u32 = 0x0001;
+
+    #[repr(C)]
+    struct HighContrastW {
+        cb_size: u32,
+        dw_flags: u32,
+        lpsz_default_scheme: *mut u16,
+    }
+
+    #[link(name = "user32")]
+    unsafe extern "system" {
+        fn SystemParametersInfoW(
+            ui_action: u32,
+            ui_param: u32,
+            pv_param: *mut c_void,
+            f_win_ini: u32,
+        ) -> i32;
+    }
+
+    pub(super) fn system_prefers_high_contrast() -> Option<bool> {
+        let mut info = HighContrastW {
+            cb_size: std::mem::size_of::<HighContrastW>() as u32,
+            dw_flags: 0,
+            lpsz_default_scheme: std::ptr::null_mut(),
+        };
+        // SAFETY: `info` is a correctly sized HIGHCONTRASTW that outlives
+        // the call; `cbSize` and `uiParam` both carry its size.
+        let ok = unsafe {
+            SystemParametersInfoW(
+                SPI_GETHIGHCONTRAST,
+                info.cb_size,
+                (&mut info as *mut HighContrastW).cast(),
+                0,
+            )
+        };
+        (ok != 0).then_some(info.dw_flags & HCF_HIGHCONTRASTON != 0)
+    }
+}
+
+#[cfg(target_os = "macos")]
+mod imp {
+    use objc2::msg_send;
+    use objc2::rc::Retained;
+    use objc2::runtime::{AnyClass, AnyObject};
+
+    pub(super) fn system_prefers_high_contrast() -> Option<bool> {
+        let class = AnyClass::get(c"NSWorkspace")?;
+        // SAFETY: `sharedWorkspace` and
+        // `accessibilityDisplayShouldIncreaseContrast` are argument-free
+        // NSWorkspace messages available since macOS 10.10.
+        unsafe {
+            let workspace: Option<Retained<AnyObject>> = msg_send![class, sharedWorkspace];
+            let increase: bool =
+                msg_send![&*workspace?, accessibilityDisplayShouldIncreaseContrast];
+            Some(increase)
+        }
+    }
+}
+
+#[cfg(target_arch = "wasm32")]
+mod imp {
+    pub(super) fn system_prefers_high_contrast() -> Option<bool> {
+        let window = web_sys::window()?;
+        let matches = |query: &str| {
+            window
+                .match_media(query)
+                .ok()
+                .flatten()
+                .map(|list| list.matches())
+        };
+        match (
+            matches("(forced-colors: active)"),
+            matches("(prefers-contrast: more)"),
+        ) {
+            (None, None) => None,
+            (forced, more) => Some(forced.unwrap_or(false) || more.unwrap_or(false)),
+        }
+    }
+}
+
+#[cfg(not(any(target_os = "windows", target_os = "macos", target_arch = "wasm32")))]
+mod imp {
+    pub(super) fn system_prefers_high_contrast() -> Option<bool> {
+        None
+    }
+}
@@ -7,7 +7,23 @@
 #[cfg(not(target_arch = "wasm32"))]
 pub mod winit_runner;
 
+#[cfg(any(target_os = "macos", target_os = "windows"))]
+mod native_menu;
+#[cfg(not(target_arch = "wasm32"))]
+mod native_notification;
+#[cfg(any(
+    target_os = "macos",
+    target_os = "windows",
+    all(target_os = "linux", feature = "linux-audio")
+))]
+mod native_sound;
+
+#[cfg(target_arch = "wasm32")]
+mod web_notification;
 #[cfg(target_arch = "wasm32")]
 pub mod web_runner;
+#[cfg(target_arch = "wasm32")]
+mod web_sound;
 
+pub mod high_contrast;
 pub mod winit_key_map;
@@ -0,0 +1,201 @@
+//! Native window menu and tray icon for the winit runner.
+//!
+//! winit 0.30 has neither, so the runner mirrors the viewport's
+//! `MenuCommand`s through `tray-icon` and the `muda` menus it re-exports.
+//! macOS and Windows only: on Linux both crates drive GTK, which winit does
+//! not run.
+//!
+//! Accelerators are not handed to the native menus. The viewport keeps
+//! matching them itself (`set_native_menu_shortcuts` stays off), so
+//! shortcuts behave the same with or without a native menu.
+
+#![cfg(any(target_os = "macos", target_os = "windows"))]
+
+use rfgui::platform::{IconImage, Menu, MenuCommand, MenuItem, MenuItemId, TrayEvent, TrayIcon};
+use rfgui::view::viewport::Viewport;
+use tray_icon::menu as muda;
+use tray_icon::menu::IsMenuItem;
+use tray_icon::{MouseButton, MouseButtonState, TrayIconBuilder, TrayIconEvent};
+use winit::window::Window;
+
+/// Native counterparts of the viewport's window menu and tray icon. Both
+/// are removed when replaced or dropped.
+#[derive(Default)]
+pub(crate) struct NativeMenus {
+    window_menu: Option<muda::Menu>,
+    tray: Option<tray_icon::TrayIcon>,
+}
+
+impl NativeMenus {
+    pub(crate) fn apply(&mut self, window: &Window, command: &MenuCommand) {
+        match command {
+            MenuCommand::SetWindowMenu(menu) => self.set_window_menu(window, menu.as_ref()),
+            MenuCommand::SetTrayIcon(tray) => self.set_tray_icon(tray.as_ref()),
+        }
+    }
+
+    fn set_window_menu(&mut self, window: &Window, menu: Option<&Menu>) {
+        if let Some(previous) = self.window_menu.take() {
+            detach(&previous, window);
+        }
+        let Some(menu) = menu else {
+            return;
+        };
+        let native = muda::Menu::new();
+        for item in &menu.items {
+            append(&native, item);
+        }
+        match attach(&native, window) {
+            Ok(()) => self.window_menu = Some(native),
+            Err(error) => eprintln!("[warn] failed to install the window menu: {error}"),
+        }
+    }
+
+    fn set_tray_icon(&mut self, tray: Option<&TrayIcon>) {
+        // Dropping the previous icon takes it out of the tray.
+        self.tray = None;
+        let Some(tray) = tray else {
+            return;
+        };
+        let mut builder = TrayIconBuilder::new().with_tooltip(&tray.tooltip);
+        if let Some(icon) = tray.icon.as_ref().and_then(native_icon) {
+            builder = builder.with_icon(icon);
+        }
+        if let Some(menu) = &tray.menu {
+            let native = muda::Menu::new();
+            for item in &menu.items {
+                append(&native, item);
+            }
+            builder = builder.with_menu(Box::new(native));
+        }
+        match builder.build() {
+            Ok(icon) => self.tray = Some(icon),
+            Err(error) => eprintln!("[warn] failed to create the tray icon: {error}"),
+        }
+    }
+
+    /// Route native menu and tray activations to the viewport. Returns true
+    /// when any of them reached a callback.
+    pub(crate) fn dispatch_events(viewport: &mut Viewport) -> bool {
+        let mut handled = false;
+        while let Ok(event) = muda::MenuEvent::receiver().try_recv() {
+            handled |= viewport.dispatch_menu_event(&MenuItemId::new(event.id.0));
+        }
+        while let Ok(event) = TrayIconEvent::receiver().try_recv() {
+            if let Some(event) = tray_event(&event) {
+                handled |= viewport.dispatch_tray_event(event);
+            }
+        }
+        handled
+    }
+}
+
+/// Appends `item` to `parent`; works for both `Menu` and `Submenu`.
+fn append(parent: &impl NativeMenuParent, item: &MenuItem) {
+    let result = match item {
+        MenuItem::Action(action) => match action.checked {
+            Some(checked) => parent.append_item(&muda::CheckMenuItem::with_id(
+                action.id.as_str(),
+                &action.label,
+                action.enabled,
+                checked,
+                None,
+            )),
+            None => parent.append_item(&muda::MenuItem::with_id(
+                action.id.as_str(),
+                &action.label,
+                action.enabled,
+                None,
+            )),
+        },
+        MenuItem::Submenu(menu) => {
+            let submenu = muda::Submenu::new(&menu.label, true);
+            for item in &menu.items {
+                append(&submenu, item);
+            }
+            parent.append_item(&submenu)
+        }
+        MenuItem::Separator => parent.append_item(&muda::PredefinedMenuItem::separator()),
+    };
+    if let Err(error) = result {
+        eprintln!("[warn] failed to add a native menu entry: {error}");
+    }
+}
+
+trait NativeMenuParent {
+    fn append_item(&self, item: &dyn IsMenuItem) -> muda::Result<()>;
+}
+
+impl NativeMenuParent for muda::Menu {
+    fn append_item(&self, item: &dyn IsMenuItem) -> muda::Result<()> {
+        self.append(item)
+    }
+}
+
+impl NativeMenuParent for muda::Submenu {
+    fn append_item(&self, item: &dyn IsMenuItem) -> muda::Result<()> {
+        self.append(item)
+    }
+}
+
+fn native_icon(icon: &IconImage) -> Option<tray_icon::Icon> {
+    tray_icon::Icon::from_rgba(icon.rgba.clone(), icon.width, icon.height).ok()
+}
+
+/// Completed clicks only; presses, hover and moves have no `TrayEvent`.
+fn tray_event(event: &TrayIconEvent) -> Option<TrayEvent> {
+    match event {
+        TrayIconEvent::Click {
+            button: MouseButton::Left,
+            button_state: MouseButtonState::Up,
+            ..
+        } => Some(TrayEvent::Click),
+        TrayIconEvent::Click {
+            button: MouseButton::Right,
+            button_state: MouseButtonState::Up,
+            ..
+        } => Some(TrayEvent::RightClick),
+        TrayIconEvent::DoubleClick {
+            button: MouseButton::Left,
+            ..
+        } => Some(TrayEvent::DoubleClick),
+        _ => None,
+    }
+}
+
+#[cfg(target_os = "macos")]
+fn attach(menu: &muda::Menu, _window: &Window) -> muda::Result<()> {
+    menu.init_for_nsapp();
+    Ok(())
+}
+
+#[cfg(target_os = "macos")]
+fn detach(menu: &muda::Menu, _window: &Window) {
+    menu.remove_for_nsapp();
+}
+
+#[cfg(target_os = "windows")]
+fn attach(menu: &muda::Menu, window: &Window) -> muda::Result<()> {
+    let Some(hwnd) = hwnd(window) else {
+        return Ok(());
+    };
+    // SAFETY: `hwnd` is the runner's live window.
+    unsafe { menu.init_for_hwnd(hwnd) }
+}
+
+#[cfg(target_os = "windows")]
+fn detach(menu: &muda::Menu, window: &Window) {
+    if let Some(hwnd) = hwnd(window) {
+        // SAFETY: `hwnd` is the runner's live window.
+        let _ = unsafe { menu.remove_for_hwnd(hwnd) };
+    }
+}
+
+#[cfg(target_os = "windows")]
+fn hwnd(window: &Window) -> Option<isize> {
+    use winit::raw_window_handle::{HasWindowHandle, RawWindowHandle};
+    match window.window_handle().ok()?.as_raw() {
+        RawWindowHandle::Win32(handle) => Some(handle.hwnd.get()),
+        _ => None,
+    }
+}
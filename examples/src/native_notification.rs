@@ -0,0 +1,162 @@
+//! System notifications and app badge for the winit runner.
+//!
+//! winit 0.30 has neither, so the runner mirrors the viewport's
+//! `NotificationCommand`s through `notify-rust`: D-Bus on Linux,
+//! `mac-notification-sys` on macOS and WinRT toasts on Windows. Each posted
+//! notification waits for the user's response on its own thread, which
+//! hands it back through a channel and wakes the event loop; the runner
+//! routes it to the viewport from `about_to_wait`.
+//!
+//! The badge is the dock tile label on macOS. winit has no taskbar badge,
+//! so Windows and Linux warn once and skip it. Withdrawing closes the
+//! notification on Linux; elsewhere the toast stays up, and its response
+//! no longer reaches a callback because the viewport forgot the id.
+
+#![cfg(not(target_arch = "wasm32"))]
+
+use std::sync::mpsc::{Receiver, Sender, channel};
+
+use rfgui::platform::{
+    AppBadge, Notification, NotificationCommand, NotificationEvent, NotificationId,
+};
+use rfgui::view::viewport::Viewport;
+use winit::event_loop::EventLoopProxy;
+
+/// Posts notifications natively and collects the responses.
+pub(crate) struct NativeNotifications {
+    wake: EventLoopProxy<()>,
+    sender: Sender<(NotificationId, NotificationEvent)>,
+    receiver: Receiver<(NotificationId, NotificationEvent)>,
+    /// Notifications still on screen, so `Withdraw` can close them.
+    #[cfg(all(unix, not(target_os = "macos")))]
+    shown:
+        std::collections::HashMap<NotificationId, std::sync::Arc<notify_rust::NotificationHandle>>,
+}
+
+impl NativeNotifications {
+    pub(crate) fn new(wake: EventLoopProxy<()>) -> Self {
+        let (sender, receiver) = channel();
+        Self {
+            wake,
+            sender,
+            receiver,
+            #[cfg(all(unix, not(target_os = "macos")))]
+            shown: Default::default(),
+        }
+    }
+
+    pub(crate) fn apply(&mut self, command: &NotificationCommand) {
+        match command {
+            NotificationCommand::Post(notification) => self.post(notification),
+            NotificationCommand::Withdraw(id) => self.withdraw(*id),
+            NotificationCommand::SetBadge(badge) => set_badge(*badge),
+        }
+    }
+
+    /// Route the responses gathered since the last call to the viewport.
+    /// Returns true when any of them reached a callback.
+    pub(crate) fn dispatch_events(&mut self, viewport: &mut Viewport) -> bool {
+        let mut handled = false;
+        while let Ok((id, event)) = self.receiver.try_recv() {
+            #[cfg(all(unix, not(target_os = "macos")))]
+            self.shown.remove(&id);
+            handled |= viewport.dispatch_notification_event(id, event);
+        }
+        handled
+    }
+
+    fn post(&mut self, notification: &Notification) {
+        let mut native = notify_rust::Notification::new();
+        native.summary(&notification.title).body(&notification.body);
+        // The action index doubles as its identifier, so responses map
+        // straight back to `NotificationEvent::Action`.
+        for (index, action) in notification.actions.iter().enumerate() {
+            native.action(&index.to_string(), &action.label);
+        }
+        #[cfg(all(unix, not(target_os = "macos")))]
+        if let Some(icon) = &notification.icon
+            && let Ok(image) = notify_rust::Image::from_rgba(
+                icon.width as i32,
+                icon.height as i32,
+                icon.rgba.clone(),
+            )
+        {
+            native.image_data(image);
+        }
+        let handle = match native.show() {
+            Ok(handle) => handle,
+            Err(error) => {
+                eprintln!("[warn] failed to show a notification: {error}");
+                return;
+            }
+        };
+        let id = notification.id;
+        let sender = self.sender.clone();
+        let wake = self.wake.clone();
+        let respond = move |response: &notify_rust::NotificationResponse| {
+            if let Some(event) = notification_event(response) {
+                let _ = sender.send((id, event));
+                let _ = wake.send_event(());
+            }
+        };
+
+        #[cfg(all(unix, not(target_os = "macos")))]
+        {
+            let handle = std::sync::Arc::new(handle);
+            self.shown.insert(id, handle.clone());
+            std::thread::spawn(move || {
+                pollster::block_on(handle.wait_for_action_async(respond));
+            });
+        }
+        // macOS sends the notification from `wait_for_response` itself.
+        #[cfg(not(all(unix, not(target_os = "macos"))))]
+        std::thread::spawn(move || {
+            if let Err(error) = handle.wait_for_response(respond) {
+                eprintln!("[warn] notification response failed: {error}");
+            }
+        });
+    }
+
+    #[cfg(all(unix, not(target_os = "macos")))]
+    fn withdraw(&mut self, id: NotificationId) {
+        if let Some(handle) = self.shown.remove(&id) {
+            pollster::block_on(handle.close_async());
+        }
+    }
+
+    #[cfg(not(all(unix, not(target_os = "macos"))))]
+    fn withdraw(&mut self, _id: NotificationId) {}
+}
+
+fn notification_event(response: &notify_rust::NotificationResponse) -> Option<NotificationEvent> {
+    use notify_rust::NotificationResponse;
+    match response {
+        NotificationResponse::Default => Some(NotificationEvent::Click),
+        NotificationResponse::Action(key) => key.parse().ok().map(NotificationEvent::Action),
+        NotificationResponse::Closed(_) => Some(NotificationEvent::Dismiss),
+        NotificationResponse::Reply(_) => None,
+    }
+}
+
+#[cfg(target_os = "macos")]
+fn set_badge(badge: Option<AppBadge>) {
+    use objc2::MainThreadMarker;
+    use objc2_app_kit::NSApplication;
+    use objc2_foundation::NSString;
+    let Some(mtm) = MainThreadMarker::new() else {
+        return;
+    };
+    let label = badge.map(|badge| match badge {
+        AppBadge::Dot => NSString::from_str("●"),
+        AppBadge::Count(count) => NSString::from_str(&count.to_string()),
+    });
+    NSApplication::sharedApplication(mtm)
+        .dockTile()
+        .setBadgeLabel(label.as_deref());
+}
+
+#[cfg(not(target_os = "macos"))]
+fn set_badge(_badge: Option<AppBadge>) {
+    static WARNED: std::sync::Once = std::sync::Once::new();
+    WARNED.call_once(|| eprintln!("[warn] app badges are not supported on this platform"));
+}
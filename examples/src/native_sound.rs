@@ -0,0 +1,89 @@
+//! UI sound playback for the winit runner through `rodio`.
+//!
+//! Registered sounds stay encoded; each play decodes a fresh copy into the
+//! output mixer, which is cheap for short UI clips and lets plays overlap.
+//! The output stream opens on the first play, so apps that never play a
+//! sound never touch the audio device.
+//!
+//! Linux needs ALSA headers to build `rodio`'s backend, so it is only
+//! compiled there with the `linux-audio` feature; without it the runner
+//! warns once and stays silent.
+
+#![cfg(any(
+    target_os = "macos",
+    target_os = "windows",
+    all(target_os = "linux", feature = "linux-audio")
+))]
+
+use std::collections::HashMap;
+use std::io::Cursor;
+use std::sync::Arc;
+
+use rfgui::platform::{SoundCommand, SoundSource};
+use rodio::{Decoder, OutputStream, OutputStreamBuilder, Source};
+use smol_str::SmolStr;
+
+#[derive(Default)]
+pub(crate) struct NativeSounds {
+    /// `None` until the first play; `Some(None)` once opening failed.
+    output: Option<Option<OutputStream>>,
+    sounds: HashMap<SmolStr, Arc<[u8]>>,
+}
+
+impl NativeSounds {
+    pub(crate) fn apply(&mut self, command: &SoundCommand) {
+        match command {
+            SoundCommand::Register { name, source } => {
+                let Some(bytes) = load(source) else {
+                    return;
+                };
+                // Decode once up front so a bad file is reported when it is
+                // registered rather than on every play.
+                if let Err(error) = Decoder::new(Cursor::new(bytes.clone())) {
+                    eprintln!("[warn] failed to decode sound `{name}`: {error}");
+                    return;
+                }
+                self.sounds.insert(name.clone(), bytes);
+            }
+            SoundCommand::Play { name, volume } => {
+                let Some(bytes) = self.sounds.get(name).cloned() else {
+                    return;
+                };
+                let Some(output) = self.output() else {
+                    return;
+                };
+                if let Ok(decoder) = Decoder::new(Cursor::new(bytes)) {
+                    output.mixer().add(decoder.amplify(*volume));
+                }
+            }
+        }
+    }
+
+    fn output(&mut self) -> Option<&OutputStream> {
+        self.output
+            .get_or_insert_with(|| match OutputStreamBuilder::open_default_stream() {
+                Ok(mut stream) => {
+                    stream.log_on_drop(false);
+                    Some(stream)
+                }
+                Err(error) => {
+                    eprintln!("[warn] failed to open the audio output: {error}");
+                    None
+                }
+            })
+            .as_ref()
+    }
+}
+
+fn load(source: &SoundSource) -> Option<Arc<[u8]>> {
+    match source {
+        SoundSource::Bytes(bytes) => Some(bytes.clone()),
+        SoundSource::Path(path) => match std::fs::read(path) {
+            Ok(bytes) => Some(bytes.into()),
+            Err(error) => {
+                eprintln!("[warn] failed to read sound {path}: {error}");
+                None
+            }
+        },
+    }
+}
@@ -0,0 +1,219 @@
+//! Browser notifications and app badge for the web runner.
+//!
+//! Notifications go through the Web Notifications API. The first post
+//! while permission is undecided asks for it; posts made before the answer
+//! wait and are shown once it is granted, or dropped when it is denied.
+//! Like the DOM listeners in `web_runner`, the click and close handlers
+//! dispatch straight into the shared viewport and request a redraw, which
+//! drains whatever the callbacks asked for.
+//!
+//! Page-level notifications have no action buttons and take the icon as a
+//! URL, so actions and icons are left out. The badge uses the Badging API
+//! (`navigator.setAppBadge`), which only installed web apps get; elsewhere
+//! the runner warns once and skips it.
+
+#![cfg(target_arch = "wasm32")]
+
+use std::cell::RefCell;
+use std::collections::HashMap;
+use std::rc::Rc;
+use std::sync::{Arc, Once};
+
+use rfgui::platform::{
+    AppBadge, Notification, NotificationCommand, NotificationEvent, NotificationId,
+};
+use wasm_bindgen::JsCast;
+use wasm_bindgen::closure::Closure;
+use wasm_bindgen_futures::{JsFuture, spawn_local};
+use web_sys::{NotificationOptions, NotificationPermission};
+use winit::window::Window;
+
+use crate::web_runner::SharedViewport;
+
+type Shown = Rc<RefCell<HashMap<NotificationId, ShownNotification>>>;
+
+/// A notification on screen together with the handlers it holds.
+struct ShownNotification {
+    notification: web_sys::Notification,
+    _listeners: [Closure<dyn FnMut(web_sys::Event)>; 2],
+}
+
+impl ShownNotification {
+    fn close(&self) {
+        self.notification.set_onclick(None);
+        self.notification.set_onclose(None);
+        self.notification.close();
+    }
+}
+
+/// Shows notifications through the browser and routes the responses.
+pub(crate) struct WebNotifications {
+    viewport: SharedViewport,
+    shown: Shown,
+    /// Posts waiting for the permission prompt to be answered.
+    pending: Rc<RefCell<Vec<Notification>>>,
+}
+
+impl WebNotifications {
+    pub(crate) fn new(viewport: SharedViewport) -> Self {
+        Self {
+            viewport,
+            shown: Default::default(),
+            pending: Default::default(),
+        }
+    }
+
+    pub(crate) fn apply(&mut self, command: &NotificationCommand, window: &Arc<Window>) {
+        match command {
+            NotificationCommand::Post(notification) => self.post(notification, window),
+            NotificationCommand::Withdraw(id) => {
+                self.pending
+                    .borrow_mut()
+                    .retain(|pending| pending.id != *id);
+                if let Some(shown) = self.shown.borrow_mut().remove(id) {
+                    shown.close();
+                }
+            }
+            NotificationCommand::SetBadge(badge) => set_badge(*badge),
+        }
+    }
+
+    fn post(&mut self, notification: &Notification, window: &Arc<Window>) {
+        match web_sys::Notification::permission() {
+            NotificationPermission::Granted => {
+                show(notification, &self.viewport, &self.shown, window)
+            }
+            NotificationPermission::Default => {
+                let asking = !self.pending.borrow().is_empty();
+                self.pending.borrow_mut().push(notification.clone());
+                if !asking {
+                    self.request_permission(window.clone());
+                }
+            }
+            _ => {
+                static WARNED: Once = Once::new();
+                WARNED.call_once(|| {
+                    web_sys::console::warn_1(
+                        &"[warn] notifications are blocked for this page".into(),
+                    )
+                });
+            }
+        }
+    }
+
+    fn request_permission(&self, window: Arc<Window>) {
+        let Ok(request) = web_sys::Notification::request_permission() else {
+            self.pending.borrow_mut().clear();
+            return;
+        };
+        let viewport = self.viewport.clone();
+        let shown = self.shown.clone();
+        let pending = self.pending.clone();
+        spawn_local(async move {
+            let _ = JsFuture::from(request).await;
+            let pending = std::mem::take(&mut *pending.borrow_mut());
+            if web_sys::Notification::permission() == NotificationPermission::Granted {
+                for notification in &pending {
+                    show(notification, &viewport, &shown, &window);
+                }
+            }
+        });
+    }
+}
+
+fn show(
+    notification: &Notification,
+    viewport: &SharedViewport,
+    shown: &Shown,
+    window: &Arc<Window>,
+) {
+    let options = NotificationOptions::new();
+    options.set_body(&notification.body);
+    let native = match web_sys::Notification::new_with_options(&notification.title, &options) {
+        Ok(native) => native,
+        Err(error) => {
+            web_sys::console::warn_2(&"[warn] failed to show a notification:".into(), &error);
+            return;
+        }
+    };
+    let id = notification.id;
+    let on_click = respond(id, NotificationEvent::Click, viewport, shown, window);
+    let on_close = respond(id, NotificationEvent::Dismiss, viewport, shown, window);
+    native.set_onclick(Some(on_click.as_ref().unchecked_ref()));
+    native.set_onclose(Some(on_close.as_ref().unchecked_ref()));
+    shown.borrow_mut().insert(
+        id,
+        ShownNotification {
+            notification: native,
+            _listeners: [on_click, on_close],
+        },
+    );
+}
+
+/// Handler reporting `event` for `id`. Whichever of click and close fires
+/// first resolves the notification; the other then finds it gone.
+fn respond(
+    id: NotificationId,
+    event: NotificationEvent,
+    viewport: &SharedViewport,
+    shown: &Shown,
+    window: &Arc<Window>,
+) -> Closure<dyn FnMut(web_sys::Event)> {
+    let viewport = viewport.clone();
+    let shown = Rc::downgrade(shown);
+    let window = window.clone();
+    Closure::wrap(Box::new(move |_event: web_sys::Event| {
+        let Some(shown) = shown.upgrade() else {
+            return;
+        };
+        let Some(entry) = shown.borrow_mut().remove(&id) else {
+            return;
+        };
+        entry.close();
+        if event == NotificationEvent::Click
+            && let Some(page) = web_sys::window()
+        {
+            let _ = page.focus();
+        }
+        if let Some(viewport) = viewport.borrow_mut().as_mut()
+            && viewport.dispatch_notification_event(id, event)
+        {
+            window.request_redraw();
+        }
+        // The entry owns this handler; drop it once the handler returns.
+        spawn_local(async move { drop(entry) });
+    }) as Box<dyn FnMut(web_sys::Event)>)
+}
+
+fn set_badge(badge: Option<AppBadge>) {
+    let Some(window) = web_sys::window() else {
+        return;
+    };
+    let navigator = window.navigator();
+    let method = if badge.is_some() {
+        "setAppBadge"
+    } else {
+        "clearAppBadge"
+    };
+    let Some(function) = js_sys::Reflect::get(&navigator, &method.into())
+        .ok()
+        .and_then(|value| value.dyn_into::<js_sys::Function>().ok())
+    else {
+        static WARNED: Once = Once::new();
+        WARNED.call_once(|| {
+            web_sys::console::warn_1(&"[warn] app badges are not supported in this browser".into())
+        });
+        return;
+    };
+    let result = match badge {
+        Some(AppBadge::Count(count)) => function.call1(&navigator, &count.into()),
+        // No argument shows the badge as a plain flag.
+        Some(AppBadge::Dot) | None => function.call0(&navigator),
+    };
+    // The promise rejects outside installed apps; nothing to recover there.
+    if let Ok(promise) = result.and_then(|value| value.dyn_into::<js_sys::Promise>()) {
+        spawn_local(async move {
+            let _ = JsFuture::from(promise).await;
+        });
+    }
+}
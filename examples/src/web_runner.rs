@@ -20,6 +20,8 @@
 
 #![cfg(target_arch = "wasm32")]
 
+use crate::web_notification::WebNotifications;
+use crate::web_sound::WebSounds;
 use crate::winit_key_map::{physical_key_to_rf, winit_modifiers_to_rf};
 use rfgui::app::{App, AppConfig, AppEvent, WheelConfig};
 use rfgui::platform::web_backend::{CanvasCursorSink, InMemoryClipboard};
@@ -29,12 +31,13 @@ use rfgui::platform::{
     PlatformWheelEvent, PointerType, RedrawRequester,
 };
 use rfgui::ui::run_due_timers;
-use rfgui::view::viewport::{RenderFrameResult, SurfaceFormatPreference, Viewport};
+use rfgui::view::viewport::{RedrawMode, RenderFrameResult, SurfaceFormatPreference, Viewport};
 use rfgui::view::{load_browser_fonts, load_web_font_from_url, set_default_font_families};
 use smol_str::SmolStr;
 use std::cell::{Cell, RefCell};
 use std::rc::Rc;
 use std::sync::Arc;
+use std::sync::Once;
 use wasm_bindgen::JsCast;
 use wasm_bindgen_futures::spawn_local;
 use web_sys::{ClipboardEvent, CompositionEvent, HtmlCanvasElement, window};
@@ -64,7 +67,7 @@ pub fn run<A: App + 'static>(app: A, mut config: AppConfig) {
 /// Lightweight shared cell used by the spawn_local closure to hand a
 /// freshly-built `Viewport` back into the runner after surface init
 /// completes.
-type SharedViewport = Rc<RefCell<Option<Viewport>>>;
+pub(crate) type SharedViewport = Rc<RefCell<Option<Viewport>>>;
 
 struct Runner {
     /// Holds the App until the Viewport is created, then `None`.
@@ -81,6 +84,8 @@ struct Runner {
     boot_overlay_hidden: bool,
     resize_listener: Option<wasm_bindgen::closure::Closure<dyn FnMut()>>,
     dom_input_listeners: Vec<wasm_bindgen::closure::Closure<dyn FnMut(web_sys::Event)>>,
+    notifications: WebNotifications,
+    sounds: WebSounds,
     /// Set when async viewport init kicks off. Reset once the viewport
     /// actually appears in the shared cell. Prevents re-entry from a
     /// second `resumed` call.
@@ -89,11 +94,12 @@ struct Runner {
 
 impl Runner {
     fn new(app: Box<dyn App>, config: AppConfig) -> Self {
+        let viewport: SharedViewport = Rc::new(RefCell::new(None));
         Self {
             pending_app: Rc::new(RefCell::new(Some(app))),
             config,
             window: None,
-            viewport: Rc::new(RefCell::new(None)),
+            viewport: viewport.clone(),
             clipboard: InMemoryClipboard::default(),
             cursor_sink: None,
             redraw: WebRedrawRequester::default(),
@@ -103,6 +109,8 @@ impl Runner {
             boot_overlay_hidden: false,
             resize_listener: None,
             dom_input_listeners: Vec::new(),
+            notifications: WebNotifications::new(viewport),
+            sounds: WebSounds::default(),
             init_in_flight: false,
         }
     }
@@ -118,6 +126,7 @@ impl Runner {
         let (physical_size, scale) =
             sync_canvas_size(lookup_app_canvas().as_ref(), window.scale_factor() as f32);
         let clear_color = self.config.clear_color;
+        let transparent = self.config.transparent;
         let viewport_slot = self.viewport.clone();
         let pending_app = self.pending_app.clone();
         spawn_local(async move {
@@ -134,10 +143,14 @@ impl Runner {
             viewport.set_msaa_sample_count(1);
             viewport.set_scale_factor(scale);
             viewport.set_size(physical_size.0, physical_size.1);
+            if let Some(high_contrast) = crate::high_contrast::system_prefers_high_contrast() {
+                viewport.set_system_high_contrast(high_contrast);
+            }
             viewport.set_surface_format_preference(SurfaceFormatPreference::PreferSrgb);
             if let Some(color) = clear_color {
                 viewport.set_clear_color(Box::new(color));
             }
+            viewport.set_transparent(transparent);
             viewport.attach(window.clone()).await;
             *viewport_slot.borrow_mut() = Some(viewport);
             window.request_redraw();
@@ -398,7 +411,10 @@ impl Runner {
                     sink.set_cursor(cursor);
                 }
             }
-            if requests.request_redraw {
+            // The browser's animation-frame callback already paces
+            // redraws to the display, so only continuous mode needs
+            // handling here; the FPS cap is a desktop-runner concern.
+            if requests.request_redraw || viewport.redraw_mode() == RedrawMode::Continuous {
                 want_redraw = true;
             }
             if let Some(text) = requests.clipboard_write {
@@ -416,6 +432,20 @@ impl Runner {
                 }
             }
             let _ = requests.pending_drags;
+            // Browsers expose no native menu bar or tray; menu accelerators
+            // still fire through the viewport's own key matching.
+            if !requests.menu_commands.is_empty() {
+                static WARNED: Once = Once::new();
+                warn_unsupported(&WARNED, "browsers have no native menu bar or tray icon");
+            }
+            if let Some(window) = self.window.as_ref() {
+                for cmd in &requests.notification_commands {
+                    self.notifications.apply(cmd, window);
+                }
+            }
+            for cmd in &requests.sound_commands {
+                self.sounds.apply(cmd);
+            }
         }
         if paste_needed {
             if let Some(text) = self.clipboard.get() {
@@ -1160,3 +1190,9 @@ fn apply_ime_command(window: &Window, cmd: &rfgui::platform::ImeCommand) {
         }
     }
 }
+
+/// Requests the browser has no backend for are dropped; say so once per
+/// kind on the console instead of ignoring them silently.
+fn warn_unsupported(warned: &'static Once, message: &str) {
+    warned.call_once(|| web_sys::console::warn_1(&format!("[warn] {message}").into()));
+}
@@ -0,0 +1,89 @@
+//! UI sound playback for the web runner through `HTMLAudioElement`.
+//!
+//! Byte sources become blob URLs when registered and path sources are used
+//! as URLs as they are, so the browser does the decoding. Each play starts
+//! a fresh audio element on that URL, which lets plays overlap. Browsers
+//! block playback until the page has had a user gesture; plays before that
+//! are dropped with a single warning.
+
+#![cfg(target_arch = "wasm32")]
+
+use std::collections::HashMap;
+use std::sync::Once;
+
+use rfgui::platform::{SoundCommand, SoundSource};
+use smol_str::SmolStr;
+use wasm_bindgen_futures::{JsFuture, spawn_local};
+use web_sys::{Blob, HtmlAudioElement, Url};
+
+struct RegisteredSound {
+    url: String,
+    /// Whether `url` is a blob URL this module created and must revoke.
+    owned: bool,
+}
+
+impl Drop for RegisteredSound {
+    fn drop(&mut self) {
+        if self.owned {
+            let _ = Url::revoke_object_url(&self.url);
+        }
+    }
+}
+
+#[derive(Default)]
+pub(crate) struct WebSounds {
+    sounds: HashMap<SmolStr, RegisteredSound>,
+}
+
+impl WebSounds {
+    pub(crate) fn apply(&mut self, command: &SoundCommand) {
+        match command {
+            SoundCommand::Register { name, source } => {
+                let sound = match source {
+                    SoundSource::Bytes(bytes) => {
+                        let Some(url) = blob_url(bytes) else {
+                            web_sys::console::warn_1(
+                                &format!("[warn] failed to load sound `{name}`").into(),
+                            );
+                            return;
+                        };
+                        RegisteredSound { url, owned: true }
+                    }
+                    SoundSource::Path(url) => RegisteredSound {
+                        url: url.clone(),
+                        owned: false,
+                    },
+                };
+                self.sounds.insert(name.clone(), sound);
+            }
+            SoundCommand::Play { name, volume } => {
+                let Some(sound) = self.sounds.get(name) else {
+                    return;
+                };
+                let Ok(audio) = HtmlAudioElement::new_with_src(&sound.url) else {
+                    return;
+                };
+                audio.set_volume(f64::from(volume.clamp(0.0, 1.0)));
+                let Ok(playing) = audio.play() else {
+                    return;
+                };
+                spawn_local(async move {
+                    if JsFuture::from(playing).await.is_err() {
+                        static WARNED: Once = Once::new();
+                        WARNED.call_once(|| {
+                            web_sys::console::warn_1(
+                                &"[warn] the browser blocked a UI sound".into(),
+                            )
+                        });
+                    }
+                });
+            }
+        }
+    }
+}
+
+fn blob_url(bytes: &[u8]) -> Option<String> {
+    let parts = js_sys::Array::of1(&js_sys::Uint8Array::from(bytes));
+    let blob = Blob::new_with_u8_array_sequence(&parts).ok()?;
+    Url::create_object_url_with_blob(&blob).ok()
+}
@@ -22,18 +22,20 @@ use rfgui::platform::{
     PlatformServices, PlatformTextInput, PlatformWheelEvent, PointerType,
 };
 use rfgui::ui::{next_timer_deadline, run_due_timers};
-use rfgui::view::viewport::{RenderFrameResult, Viewport};
+use rfgui::view::viewport::{FrameSchedule, RenderFrameResult, Viewport};
 use smol_str::SmolStr;
 use std::sync::Arc;
 use std::sync::Mutex;
+#[cfg(not(any(target_os = "macos", target_os = "windows")))]
+use std::sync::Once;
 use std::time::Instant;
 use winit::application::ApplicationHandler;
 use winit::dpi::{LogicalSize, PhysicalPosition, PhysicalSize};
 use winit::event::{
     DeviceEvent, DeviceId, ElementState, Ime, KeyEvent, MouseButton as WinitMouseButton,
-    MouseScrollDelta, WindowEvent,
+    MouseScrollDelta, TouchPhase, WindowEvent,
 };
-use winit::event_loop::{ActiveEventLoop, ControlFlow, EventLoop};
+use winit::event_loop::{ActiveEventLoop, ControlFlow, EventLoop, EventLoopProxy};
 use winit::window::{Window, WindowId};
 
 /// Run an `App` until the user closes the window.
@@ -43,7 +45,8 @@ use winit::window::{Window, WindowId};
 pub fn run<A: App + 'static>(app: A, config: AppConfig) {
     let event_loop = EventLoop::new().expect("failed to create winit event loop");
     event_loop.set_control_flow(ControlFlow::Wait);
-    let mut handler = Runner::new(Box::new(app), config);
+    let wake = event_loop.create_proxy();
+    let mut handler = Runner::new(Box::new(app), config, wake);
     event_loop
         .run_app(&mut handler)
         .expect("winit event loop exited with error");
@@ -84,10 +87,22 @@ struct Runner {
     /// the flag clears, the OS swallows the redraw, and on un-occlude
     /// nothing repaints.
     occluded: bool,
+    /// Set when a handler queued `WindowCommand::Close`. Applied from
+    /// `about_to_wait`, which holds the `ActiveEventLoop` needed to exit.
+    close_requested: bool,
+    #[cfg(any(target_os = "macos", target_os = "windows"))]
+    native_menus: crate::native_menu::NativeMenus,
+    notifications: crate::native_notification::NativeNotifications,
+    #[cfg(any(
+        target_os = "macos",
+        target_os = "windows",
+        all(target_os = "linux", feature = "linux-audio")
+    ))]
+    sounds: crate::native_sound::NativeSounds,
 }
 
 impl Runner {
-    fn new(app: Box<dyn App>, config: AppConfig) -> Self {
+    fn new(app: Box<dyn App>, config: AppConfig, wake: EventLoopProxy<()>) -> Self {
         let clipboard: Box<dyn Clipboard + Send> = match ArboardClipboard::new() {
             Some(c) => Box::new(c),
             None => Box::new(NullClipboard::default()),
@@ -113,6 +128,16 @@ impl Runner {
             ime_composing: false,
             last_ime_rect: None,
             occluded: false,
+            close_requested: false,
+            #[cfg(any(target_os = "macos", target_os = "windows"))]
+            native_menus: Default::default(),
+            notifications: crate::native_notification::NativeNotifications::new(wake),
+            #[cfg(any(
+                target_os = "macos",
+                target_os = "windows",
+                all(target_os = "linux", feature = "linux-audio")
+            ))]
+            sounds: Default::default(),
         }
     }
 
@@ -131,6 +156,15 @@ impl Runner {
         if let Some(color) = self.config.clear_color {
             viewport.set_clear_color(Box::new(color));
         }
+        viewport.set_transparent(self.config.transparent);
+        let (monitors, current) = monitor_snapshot(window);
+        viewport.set_monitors(monitors, current);
+        if let Some(high_contrast) = crate::high_contrast::system_prefers_high_contrast() {
+            viewport.set_system_high_contrast(high_contrast);
+        }
+        if let Some(theme) = window.theme() {
+            viewport.set_window_theme(map_window_theme(theme));
+        }
         pollster::block_on(viewport.attach(window.clone()));
         self.viewport = Some(viewport);
         // Kick the first frame. Winit does not emit RedrawRequested on
@@ -415,6 +449,43 @@ impl Runner {
         }
     }
 
+    /// Re-read the monitor list and tell the app when it or the window's
+    /// current monitor changed.
+    fn refresh_monitors(&mut self) {
+        let (Some(window), Some(viewport)) = (&self.window, self.viewport.as_mut()) else {
+            return;
+        };
+        let (monitors, current) = monitor_snapshot(window);
+        if viewport.monitors() == monitors.as_slice()
+            && viewport.current_monitor()
+                == current
+                    .and_then(|index| monitors.get(index))
+                    .or_else(|| monitors.iter().find(|monitor| monitor.is_primary))
+        {
+            return;
+        }
+        viewport.set_monitors(monitors, current);
+        viewport.dispatch_app_event(
+            &AppEvent::MonitorsChanged,
+            PlatformServices {
+                clipboard: self.clipboard.as_mut(),
+                cursor: &mut self.cursor,
+                redraw: &self.redraw,
+            },
+        );
+    }
+
+    fn shutdown(&mut self, event_loop: &ActiveEventLoop) {
+        if let Some(viewport) = self.viewport.as_mut() {
+            viewport.app_on_shutdown(PlatformServices {
+                clipboard: self.clipboard.as_mut(),
+                cursor: &mut self.cursor,
+                redraw: &self.redraw,
+            });
+        }
+        event_loop.exit();
+    }
+
     fn drain_and_apply(&mut self) {
         let Some(viewport) = self.viewport.as_mut() else {
             return;
@@ -426,22 +497,24 @@ impl Runner {
                 window.set_cursor(winit_cursor_from(cursor));
             }
             if want_redraw {
-                if self.occluded {
-                    // Keep the flag set so the un-occlude path picks it
-                    // up. request_redraw on a hidden window is a no-op
-                    // on some platforms.
-                    *self.redraw_flag.lock().unwrap() = true;
-                } else {
-                    *self.redraw_flag.lock().unwrap() = false;
-                    window.request_redraw();
-                }
+                // Defer to `about_to_wait`, which paces the frame against
+                // the viewport's FPS cap. The flag also survives occlusion
+                // so the un-occlude path picks it up.
+                *self.redraw_flag.lock().unwrap() = true;
             }
             for cmd in &requests.window_commands {
+                if matches!(cmd, rfgui::platform::WindowCommand::Close) {
+                    self.close_requested = true;
+                }
                 apply_window_command(window, cmd);
             }
             for cmd in &requests.ime_commands {
                 apply_ime_command(window, cmd);
             }
+            #[cfg(any(target_os = "macos", target_os = "windows"))]
+            for cmd in &requests.menu_commands {
+                self.native_menus.apply(window, cmd);
+            }
         }
         if let Some(text) = requests.clipboard_write {
             self.clipboard.set(&text);
@@ -458,17 +531,56 @@ impl Runner {
         // `pending_drags` still unhandled — Sprint 8b wires drag state
         // machine & OS drag bridge.
         let _ = requests.pending_drags;
+        // Native menus need GTK on Linux, which winit does not run.
+        // Accelerators keep working: the viewport matches them itself while
+        // `set_native_menu_shortcuts` is off.
+        #[cfg(not(any(target_os = "macos", target_os = "windows")))]
+        if !requests.menu_commands.is_empty() {
+            static WARNED: Once = Once::new();
+            warn_unsupported(
+                &WARNED,
+                "native menus and tray icons are not supported on this platform",
+            );
+        }
+        for cmd in &requests.notification_commands {
+            self.notifications.apply(cmd);
+        }
+        #[cfg(any(
+            target_os = "macos",
+            target_os = "windows",
+            all(target_os = "linux", feature = "linux-audio")
+        ))]
+        for cmd in &requests.sound_commands {
+            self.sounds.apply(cmd);
+        }
+        // Without `rodio` (Linux builds lacking `linux-audio`, other
+        // unixes) there is no audio output.
+        #[cfg(not(any(
+            target_os = "macos",
+            target_os = "windows",
+            all(target_os = "linux", feature = "linux-audio")
+        )))]
+        if !requests.sound_commands.is_empty() {
+            static WARNED: Once = Once::new();
+            warn_unsupported(&WARNED, "this build has no audio output for UI sounds");
+        }
     }
 }
 
+/// Requests the runner has no backend for are dropped; say so once per
+/// kind instead of ignoring them silently.
+#[cfg(not(any(target_os = "macos", target_os = "windows")))]
+fn warn_unsupported(warned: &'static Once, message: &str) {
+    warned.call_once(|| eprintln!("[warn] {message}"));
+}
+
 fn apply_window_command(window: &Window, cmd: &rfgui::platform::WindowCommand) {
     use rfgui::platform::WindowCommand;
     match cmd {
         WindowCommand::Close => {
             // winit 0.30 has no explicit close method on `Window`; the
-            // runner needs an `ActiveEventLoop` to call `exit()`. Caller
-            // should send a close via an app channel instead. We swallow
-            // for now so handlers can at least express intent.
+            // runner latches `close_requested` and exits from
+            // `about_to_wait`, where an `ActiveEventLoop` is available.
         }
         WindowCommand::Minimize => {
             window.set_minimized(true);
@@ -480,6 +592,14 @@ fn apply_window_command(window: &Window, cmd: &rfgui::platform::WindowCommand) {
             window.set_minimized(false);
             window.set_maximized(false);
         }
+        WindowCommand::ToggleMaximize => {
+            window.set_maximized(!window.is_maximized());
+        }
+        WindowCommand::DragWindow => {
+            // Fails when the primary button is no longer held (the press
+            // was already released); nothing to move in that case.
+            let _ = window.drag_window();
+        }
         WindowCommand::SetFullscreen(enable) => {
             use winit::window::Fullscreen;
             window.set_fullscreen(if *enable {
@@ -491,9 +611,81 @@ fn apply_window_command(window: &Window, cmd: &rfgui::platform::WindowCommand) {
         WindowCommand::SetTitle(title) => {
             window.set_title(title);
         }
+        WindowCommand::SetIcon(icon) => {
+            window.set_window_icon(icon.as_ref().and_then(winit_icon_from));
+        }
+        WindowCommand::SetResizable(resizable) => {
+            window.set_resizable(*resizable);
+        }
+        WindowCommand::SetMinSize(size) => {
+            window.set_min_inner_size(size.map(logical_size_from));
+        }
+        WindowCommand::SetMaxSize(size) => {
+            window.set_max_inner_size(size.map(logical_size_from));
+        }
+        WindowCommand::SetAlwaysOnTop(always_on_top) => {
+            window.set_window_level(window_level_from(*always_on_top));
+        }
+        WindowCommand::SetTransparent(transparent) => {
+            window.set_transparent(*transparent);
+            apply_macos_shadow(window, !*transparent);
+        }
+        WindowCommand::SetPosition(x, y) => {
+            window.set_outer_position(PhysicalPosition::new(*x, *y));
+        }
     }
 }
 
+fn map_window_theme(theme: winit::window::Theme) -> rfgui::app::WindowTheme {
+    match theme {
+        winit::window::Theme::Light => rfgui::app::WindowTheme::Light,
+        winit::window::Theme::Dark => rfgui::app::WindowTheme::Dark,
+    }
+}
+
+/// Host monitors plus the index of the one holding `window`.
+fn monitor_snapshot(window: &Window) -> (Vec<rfgui::platform::MonitorInfo>, Option<usize>) {
+    let primary = window.primary_monitor();
+    let current = window.current_monitor();
+    let mut current_index = None;
+    let monitors = window
+        .available_monitors()
+        .enumerate()
+        .map(|(index, handle)| {
+            if current.as_ref() == Some(&handle) {
+                current_index = Some(index);
+            }
+            let position = handle.position();
+            let size = handle.size();
+            rfgui::platform::MonitorInfo {
+                name: handle.name(),
+                position: (position.x, position.y),
+                size: (size.width, size.height),
+                scale_factor: handle.scale_factor() as f32,
+                refresh_rate_millihertz: handle.refresh_rate_millihertz(),
+                is_primary: primary.as_ref() == Some(&handle),
+            }
+        })
+        .collect();
+    (monitors, current_index)
+}
+
+fn winit_icon_from(icon: &rfgui::platform::IconImage) -> Option<winit::window::Icon> {
+    winit::window::Icon::from_rgba(icon.rgba.clone(), icon.width, icon.height).ok()
+}
+
+fn window_level_from(always_on_top: bool) -> winit::window::WindowLevel {
+    if always_on_top {
+        winit::window::WindowLevel::AlwaysOnTop
+    } else {
+        winit::window::WindowLevel::Normal
+    }
+}
+
+fn logical_size_from((width, height): (u32, u32)) -> LogicalSize<f64> {
+    LogicalSize::new(width as f64, height as f64)
+}
+
 fn apply_ime_command(window: &Window, cmd: &rfgui::platform::ImeCommand) {
     use rfgui::platform::ImeCommand;
     match cmd {
@@ -541,13 +733,27 @@ impl ApplicationHandler for Runner {
         if self.window.is_some() {
             return;
         }
-        let attrs = Window::default_attributes()
+        let options = &self.config.window;
+        let mut attrs = Window::default_attributes()
             .with_title(&self.config.title)
             .with_transparent(self.config.transparent)
+            .with_decorations(self.config.decorations)
             .with_inner_size(LogicalSize::new(
                 self.config.initial_size.0 as f64,
                 self.config.initial_size.1 as f64,
-            ));
+            ))
+            .with_resizable(options.resizable)
+            .with_window_icon(options.icon.as_ref().and_then(winit_icon_from))
+            .with_window_level(window_level_from(options.always_on_top));
+        if let Some(size) = options.min_size {
+            attrs = attrs.with_min_inner_size(logical_size_from(size));
+        }
+        if let Some(size) = options.max_size {
+            attrs = attrs.with_max_inner_size(logical_size_from(size));
+        }
+        if options.fullscreen {
+            attrs = attrs.with_fullscreen(Some(winit::window::Fullscreen::Borderless(None)));
+        }
         let window = Arc::new(
             event_loop
                 .create_window(attrs)
@@ -576,13 +782,8 @@ impl ApplicationHandler for Runner {
                             redraw: &self.redraw,
                         },
                     );
-                    viewport.app_on_shutdown(PlatformServices {
-                        clipboard: self.clipboard.as_mut(),
-                        cursor: &mut self.cursor,
-                        redraw: &self.redraw,
-                    });
                 }
-                event_loop.exit();
+                self.shutdown(event_loop);
             }
             WindowEvent::Resized(size) => {
                 if let Some(viewport) = self.viewport.as_mut() {
@@ -622,6 +823,7 @@ impl ApplicationHandler for Runner {
                         },
                     );
                 }
+                self.refresh_monitors();
             }
             WindowEvent::CursorMoved { position, .. } => {
                 self.last_mouse = Some(position);
@@ -714,9 +916,23 @@ impl ApplicationHandler for Runner {
                     }
                 }
             }
-            WindowEvent::MouseWheel { delta, .. } => {
-                let Some((dx, dy)) = self.normalize_wheel(delta) else {
-                    return;
+            WindowEvent::MouseWheel { delta, phase, .. } => {
+                // Only trackpad pixel streams carry meaningful gesture
+                // phases; mouse line ticks are reported as plain `Changed`.
+                let phase = match (delta, phase) {
+                    (MouseScrollDelta::LineDelta(..), _) => rfgui::platform::WheelPhase::Changed,
+                    (_, TouchPhase::Started) => rfgui::platform::WheelPhase::Began,
+                    (_, TouchPhase::Moved) => rfgui::platform::WheelPhase::Changed,
+                    (_, TouchPhase::Ended | TouchPhase::Cancelled) => {
+                        rfgui::platform::WheelPhase::Ended
+                    }
+                };
+                // Gesture boundaries still need delivering when their delta
+                // falls inside the deadzone.
+                let (dx, dy) = match self.normalize_wheel(delta) {
+                    Some(delta) => delta,
+                    None if phase != rfgui::platform::WheelPhase::Changed => (0.0, 0.0),
+                    None => return,
                 };
                 let position = self.last_mouse_logical.unwrap_or((0.0, 0.0));
                 let wheel = PlatformWheelEvent {
@@ -725,7 +941,7 @@ impl ApplicationHandler for Runner {
                     position,
                     modifiers: rfgui::platform::Modifiers::empty(),
                     delta_mode: rfgui::platform::WheelDeltaMode::Pixel,
-                    phase: rfgui::platform::WheelPhase::Changed,
+                    phase,
                     timestamp: rfgui::time::Instant::now(),
                 };
                 let ev = AppEvent::Wheel(wheel);
@@ -744,6 +960,14 @@ impl ApplicationHandler for Runner {
             WindowEvent::Focused(focused) => {
                 let ev = AppEvent::HostFocus(focused);
                 if let Some(viewport) = self.viewport.as_mut() {
+                    // High contrast is toggled from system settings, so
+                    // coming back to the window is when it may have changed.
+                    if focused
+                        && let Some(high_contrast) =
+                            crate::high_contrast::system_prefers_high_contrast()
+                    {
+                        viewport.set_system_high_contrast(high_contrast);
+                    }
                     viewport.dispatch_app_event(
                         &ev,
                         PlatformServices {
@@ -788,6 +1012,7 @@ impl ApplicationHandler for Runner {
                         },
                     );
                 }
+                self.refresh_monitors();
             }
             WindowEvent::Occluded(occluded) => {
                 let was_occluded = self.occluded;
@@ -812,12 +1037,10 @@ impl ApplicationHandler for Runner {
                 }
             }
             WindowEvent::ThemeChanged(theme) => {
-                let mapped = match theme {
-                    winit::window::Theme::Light => rfgui::app::WindowTheme::Light,
-                    winit::window::Theme::Dark => rfgui::app::WindowTheme::Dark,
-                };
+                let mapped = map_window_theme(theme);
                 let ev = AppEvent::ThemeChanged(mapped);
                 if let Some(viewport) = self.viewport.as_mut() {
+                    viewport.set_window_theme(mapped);
                     viewport.dispatch_app_event(
                         &ev,
                         PlatformServices {
@@ -944,6 +1167,21 @@ impl ApplicationHandler for Runner {
     }
 
     fn about_to_wait(&mut self, event_loop: &ActiveEventLoop) {
+        if self.close_requested {
+            self.shutdown(event_loop);
+            return;
+        }
+        #[cfg(any(target_os = "macos", target_os = "windows"))]
+        if let Some(viewport) = self.viewport.as_mut()
+            && crate::native_menu::NativeMenus::dispatch_events(viewport)
+        {
+            self.drain_and_apply();
+        }
+        if let Some(viewport) = self.viewport.as_mut()
+            && self.notifications.dispatch_events(viewport)
+        {
+            self.drain_and_apply();
+        }
         // Drive component timers (use_timeout, use_interval). Viewport
         // transition/animation plugins tick inside render_rsx and report
         // their state via `viewport.is_animating()` below, so they don't
@@ -953,29 +1191,35 @@ impl ApplicationHandler for Runner {
         // Skip while occluded: winit drops request_redraw on hidden
         // windows on some platforms. Consuming the flag here would lose
         // the pending frame; defer until Occluded(false) re-kicks.
-        if !self.occluded && *self.redraw_flag.lock().unwrap() {
-            *self.redraw_flag.lock().unwrap() = false;
-            if let Some(window) = &self.window {
-                window.request_redraw();
-            }
-        }
+        let pending = *self.redraw_flag.lock().unwrap();
+        let schedule = match self.viewport.as_ref() {
+            Some(viewport) if !self.occluded => viewport.frame_schedule(now, pending),
+            _ => FrameSchedule::Idle,
+        };
         // Schedule the next wake-up:
-        // - viewport reports active transitions → Poll so the loop
-        //   iterates and the freshly queued RedrawRequested fires
+        // - frame due → render now; Poll so the loop iterates and the
+        //   freshly queued RedrawRequested fires
+        // - frame held back by the FPS cap → WaitUntil(frame deadline),
+        //   keeping the flag so the wake-up renders it
         // - timer pending → WaitUntil(deadline)
         // - otherwise idle until the next user event
-        let animating = self
-            .viewport
-            .as_ref()
-            .map(|v| v.is_animating())
-            .unwrap_or(false);
-        if animating {
-            event_loop.set_control_flow(ControlFlow::Poll);
-        } else {
-            match next_timer_deadline() {
+        let timer_deadline = next_timer_deadline();
+        match schedule {
+            FrameSchedule::Now => {
+                *self.redraw_flag.lock().unwrap() = false;
+                if let Some(window) = &self.window {
+                    window.request_redraw();
+                }
+                event_loop.set_control_flow(ControlFlow::Poll);
+            }
+            FrameSchedule::At(frame_deadline) => {
+                let deadline = timer_deadline.map_or(frame_deadline, |t| t.min(frame_deadline));
+                event_loop.set_control_flow(ControlFlow::WaitUntil(deadline));
+            }
+            FrameSchedule::Idle => match timer_deadline {
                 Some(deadline) => event_loop.set_control_flow(ControlFlow::WaitUntil(deadline)),
                 None => event_loop.set_control_flow(ControlFlow::Wait),
-            }
+            },
         }
     }
 }
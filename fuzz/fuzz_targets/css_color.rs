@@ -0,0 +1,12 @@
+//! CSS color strings: names, hex, `rgb()`, `hsl()`, `oklch()` and
+//! `color(display-p3 ...)`.
+//!
+//! `cargo +nightly fuzz run css_color`
+
+#![no_main]
+
+use libfuzzer_sys::fuzz_target;
+
+fuzz_target!(|data: &str| {
+    let _ = rfgui::style::parse_color(data);
+});
@@ -0,0 +1,19 @@
+//! `rsx!` body parsing, including raw-text whitespace normalization.
+//!
+//! `cargo +nightly fuzz run rsx_tokens`
+
+#![no_main]
+
+use libfuzzer_sys::fuzz_target;
+use std::str::FromStr;
+
+#[allow(dead_code)]
+#[path = "../../rsx-macro/src/parse.rs"]
+mod parse;
+
+fuzz_target!(|data: &str| {
+    // Input that does not even lex is rejected before the parser sees it.
+    if let Ok(tokens) = proc_macro2::TokenStream::from_str(data) {
+        let _ = parse::parse_rsx_tokens(tokens);
+    }
+});
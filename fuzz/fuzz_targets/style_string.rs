@@ -0,0 +1,11 @@
+//! Inline CSS declaration strings.
+//!
+//! `cargo +nightly fuzz run style_string`
+
+#![no_main]
+
+use libfuzzer_sys::fuzz_target;
+
+fuzz_target!(|data: &str| {
+    let _ = rfgui::style::parse_style_string(data);
+});
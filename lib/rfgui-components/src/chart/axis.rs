@@ -0,0 +1,194 @@
+use super::svg_paint;
+use crate::Theme;
+use rfgui::style::{Align, Anchor, JustifyContent, Layout, Length, Position};
+use rfgui::ui::{RsxNode, rsx};
+use rfgui::view::{Element, Text};
+use std::fmt::Write as _;
+
+/// Width reserved left of the plot for value labels.
+const VALUE_LABEL_WIDTH: f32 = 44.0;
+/// Height reserved under the plot for category labels.
+const CATEGORY_LABEL_HEIGHT: f32 = 20.0;
+/// Gap around the plot on the sides without labels.
+const PLOT_INSET: f32 = 8.0;
+/// Height given to an absolutely placed value label, centered on its tick.
+const VALUE_LABEL_HEIGHT: f32 = 16.0;
+const TICK_TARGET: usize = 5;
+
+/// Value axis range rounded out to "nice" steps of 1, 2 or 5 × 10ⁿ.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct AxisTicks {
+    pub min: f64,
+    pub max: f64,
+    pub step: f64,
+}
+
+impl AxisTicks {
+    /// Ticks covering `min..=max`, aiming for about `count` of them. An
+    /// empty range is widened so it still gets a step.
+    pub fn nice(min: f64, max: f64, count: usize) -> Self {
+        let (mut min, mut max) = if min <= max { (min, max) } else { (max, min) };
+        if !min.is_finite() || !max.is_finite() {
+            (min, max) = (0.0, 1.0);
+        }
+        if min == max {
+            let pad = if min == 0.0 { 1.0 } else { min.abs() * 0.5 };
+            (min, max) = (min - pad, max + pad);
+        }
+        let range = nice_number(max - min, false);
+        let step = nice_number(range / count.max(2).saturating_sub(1) as f64, true);
+        Self {
+            min: (min / step).floor() * step,
+            max: (max / step).ceil() * step,
+            step,
+        }
+    }
+
+    pub fn values(&self) -> Vec<f64> {
+        let count = ((self.max - self.min) / self.step).round() as usize;
+        (0..=count)
+            .map(|index| self.min + self.step * index as f64)
+            .collect()
+    }
+
+    /// `value` with as many decimals as the step needs.
+    pub fn format(&self, value: f64) -> String {
+        let decimals = (-self.step.log10().floor()).max(0.0) as usize;
+        format!("{value:.decimals$}")
+    }
+}
+
+/// Round `value` to 1, 2, 5 or 10 × 10ⁿ: the nearest one when `round`,
+/// otherwise the next one up.
+fn nice_number(value: f64, round: bool) -> f64 {
+    let exponent = value.log10().floor();
+    let magnitude = 10f64.powf(exponent);
+    let fraction = value / magnitude;
+    let nice = if round {
+        match fraction {
+            f if f < 1.5 => 1.0,
+            f if f < 3.0 => 2.0,
+            f if f < 7.0 => 5.0,
+            _ => 10.0,
+        }
+    } else {
+        match fraction {
+            f if f <= 1.0 => 1.0,
+            f if f <= 2.0 => 2.0,
+            f if f <= 5.0 => 5.0,
+            _ => 10.0,
+        }
+    };
+    nice * magnitude
+}
+
+/// Plot geometry shared by the line and bar charts: labels along the left
+/// and bottom, one band per category across the plot.
+pub(super) struct CartesianFrame {
+    pub left: f32,
+    pub top: f32,
+    pub right: f32,
+    pub bottom: f32,
+    pub ticks: AxisTicks,
+    pub categories: usize,
+}
+
+impl CartesianFrame {
+    pub fn new(width: f32, height: f32, categories: usize, min: f64, max: f64) -> Self {
+        Self {
+            left: VALUE_LABEL_WIDTH,
+            top: PLOT_INSET,
+            right: (width - PLOT_INSET).max(VALUE_LABEL_WIDTH + 1.0),
+            bottom: (height - CATEGORY_LABEL_HEIGHT).max(PLOT_INSET + 1.0),
+            ticks: AxisTicks::nice(min, max, TICK_TARGET),
+            categories: categories.max(1),
+        }
+    }
+
+    pub fn y(&self, value: f64) -> f32 {
+        let share = (value - self.ticks.min) / (self.ticks.max - self.ticks.min);
+        self.bottom - (self.bottom - self.top) * share as f32
+    }
+
+    /// Where bars grow from: zero when the range spans it.
+    pub fn baseline(&self) -> f32 {
+        self.y(0.0_f64.clamp(self.ticks.min, self.ticks.max))
+    }
+
+    pub fn band(&self) -> f32 {
+        (self.right - self.left) / self.categories as f32
+    }
+
+    pub fn band_left(&self, index: usize) -> f32 {
+        self.left + self.band() * index as f32
+    }
+
+    /// Horizontal grid lines at each tick and the category axis.
+    pub fn write_grid(&self, out: &mut String, theme: &Theme) {
+        let [r, g, b, a] = theme.color.divider.to_rgba_u8();
+        let grid = rfgui::style::Color::rgba(r, g, b, a);
+        for value in self.ticks.values() {
+            let y = self.y(value).round() + 0.5;
+            let _ = write!(
+                out,
+                r#"<line x1="{}" y1="{y}" x2="{}" y2="{y}" stroke-width="1""#,
+                self.left, self.right
+            );
+            svg_paint(out, "stroke", grid);
+            out.push_str("/>");
+        }
+        let [r, g, b, a] = theme.color.border.to_rgba_u8();
+        let y = self.baseline().round() + 0.5;
+        let _ = write!(
+            out,
+            r#"<line x1="{}" y1="{y}" x2="{}" y2="{y}" stroke-width="1""#,
+            self.left, self.right
+        );
+        svg_paint(out, "stroke", rfgui::style::Color::rgba(r, g, b, a));
+        out.push_str("/>");
+    }
+
+    /// Value labels beside the ticks and category labels under the bands.
+    pub fn labels(&self, labels: &[String]) -> Vec<RsxNode> {
+        let values = self.ticks.values().into_iter().map(|value| {
+            let top = self.y(value) - VALUE_LABEL_HEIGHT / 2.0;
+            rsx! {
+                <Element style={{
+                    position: Position::absolute()
+                        .left(Length::px(0.0))
+                        .top(Length::px(top))
+                        .anchor(Anchor::Parent),
+                    width: Length::px(self.left - PLOT_INSET),
+                    height: Length::px(VALUE_LABEL_HEIGHT),
+                    layout: Layout::flow()
+                        .row()
+                        .no_wrap()
+                        .align(Align::Center)
+                        .justify_content(JustifyContent::End),
+                }}>
+                    <Text>{self.ticks.format(value)}</Text>
+                </Element>
+            }
+        });
+        let categories = labels.iter().enumerate().map(|(index, label)| {
+            rsx! {
+                <Element style={{
+                    position: Position::absolute()
+                        .left(Length::px(self.band_left(index)))
+                        .top(Length::px(self.bottom))
+                        .anchor(Anchor::Parent),
+                    width: Length::px(self.band()),
+                    height: Length::px(CATEGORY_LABEL_HEIGHT),
+                    layout: Layout::flow()
+                        .row()
+                        .no_wrap()
+                        .align(Align::Center)
+                        .justify_content(JustifyContent::Center),
+                }}>
+                    <Text>{label.clone()}</Text>
+                </Element>
+            }
+        });
+        values.chain(categories).collect()
+    }
+}
@@ -0,0 +1,201 @@
+use super::axis::CartesianFrame;
+use super::{
+    ChartSeries, LEGEND_HEIGHT, chart_legend, chart_tooltip, format_value, series_color,
+    svg_document, svg_paint, use_enter_progress,
+};
+use crate::use_theme;
+use rfgui::style::{Anchor, Layout, Length, Position};
+use rfgui::ui::{
+    RsxComponent, RsxNode, component, on_pointer_enter, on_pointer_leave, props, rsx, use_state,
+};
+use rfgui::view::{Element, ElementStylePropSchema, ImageFit, RootStyleProps, Svg, SvgSource};
+use std::fmt::Write as _;
+
+/// Share of each category band the bar group fills.
+const GROUP_SHARE: f32 = 0.7;
+/// Bars shorter than this still get a hover region this tall.
+const MIN_HIT_HEIGHT: f32 = 4.0;
+
+/// Grouped vertical bars: one group per label, one bar per series.
+pub struct BarChart;
+
+#[derive(Clone)]
+#[props]
+pub struct BarChartProps {
+    pub labels: Vec<String>,
+    pub series: Vec<ChartSeries>,
+    pub width: Option<f64>,
+    pub height: Option<f64>,
+    /// Grow the bars in when the chart mounts. Defaults to `true`.
+    pub animate: Option<bool>,
+    /// Stacked over the style of the chart container.
+    pub style: Option<ElementStylePropSchema>,
+    /// [`StyleClasses`](rfgui::view::StyleClasses) names, stacked beneath `style`.
+    pub class: Option<String>,
+}
+
+impl RsxComponent<BarChartProps> for BarChart {
+    fn render(props: BarChartProps, _children: Vec<RsxNode>) -> RsxNode {
+        rsx! {
+            <BarChartView
+                labels={props.labels}
+                series={props.series}
+                width={props.width.unwrap_or(360.0) as f32}
+                height={props.height.unwrap_or(240.0) as f32}
+                animate={props.animate.unwrap_or(true)}
+                root_style={RootStyleProps::new(props.style, props.class)}
+            />
+        }
+    }
+}
+
+#[rfgui::ui::component]
+impl rfgui::ui::RsxTag for BarChart {
+    type Props = __BarChartPropsInit;
+    type StrictProps = BarChartProps;
+    const ACCEPTS_CHILDREN: bool = false;
+
+    fn into_strict(props: Self::Props) -> Self::StrictProps {
+        props.into()
+    }
+
+    fn create_node(
+        props: Self::StrictProps,
+        children: Vec<RsxNode>,
+        _key: Option<rfgui::ui::RsxKey>,
+    ) -> RsxNode {
+        <Self as RsxComponent<BarChartProps>>::render(props, children)
+    }
+}
+
+#[component]
+fn BarChartView(
+    labels: Vec<String>,
+    series: Vec<ChartSeries>,
+    width: f32,
+    height: f32,
+    animate: bool,
+    root_style: RootStyleProps,
+) -> RsxNode {
+    let theme = use_theme().0;
+    let progress = use_enter_progress(animate, theme.motion.duration.slow);
+    let hovered = use_state(|| None::<(usize, usize)>);
+
+    let values = series
+        .iter()
+        .flat_map(|series| series.values.iter().copied());
+    let (min, max) = values.fold((0.0_f64, 0.0_f64), |(min, max), value| {
+        (min.min(value), max.max(value))
+    });
+    let plot_height = (height - LEGEND_HEIGHT).max(0.0);
+    let frame = CartesianFrame::new(width, plot_height, labels.len(), min, max);
+    let group_width = frame.band() * GROUP_SHARE;
+    let bar_width = group_width / series.len().max(1) as f32;
+    let baseline = frame.baseline();
+    let colors: Vec<_> = series
+        .iter()
+        .enumerate()
+        .map(|(index, series)| series_color(&theme, index, series.color))
+        .collect();
+
+    let mut body = String::new();
+    frame.write_grid(&mut body, &theme);
+    let mut regions = Vec::new();
+    for (series_index, entry) in series.iter().enumerate() {
+        for (label_index, value) in entry.values.iter().copied().enumerate() {
+            if label_index >= labels.len() {
+                break;
+            }
+            let x = frame.band_left(label_index)
+                + (frame.band() - group_width) / 2.0
+                + bar_width * series_index as f32;
+            let end = frame.y(value);
+            let grown = baseline + (end - baseline) * progress;
+            let _ = write!(
+                body,
+                r#"<rect x="{x}" y="{}" width="{}" height="{}""#,
+                grown.min(baseline),
+                (bar_width - 1.0).max(1.0),
+                (grown - baseline).abs()
+            );
+            svg_paint(&mut body, "fill", colors[series_index]);
+            body.push_str("/>");
+
+            let key = (series_index, label_index);
+            let enter = {
+                let hovered = hovered.binding();
+                on_pointer_enter(move |_| hovered.set(Some(key)))
+            };
+            let leave = {
+                let hovered = hovered.binding();
+                on_pointer_leave(move |_| {
+                    if hovered.get() == Some(key) {
+                        hovered.set(None);
+                    }
+                })
+            };
+            let tooltip = (hovered.get() == Some(key)).then(|| {
+                chart_tooltip(vec![
+                    labels[label_index].clone(),
+                    format!("{}: {}", entry.name, format_value(value)),
+                ])
+            });
+            let hit_height = (end - baseline).abs().max(MIN_HIT_HEIGHT);
+            let hit_top = (end + baseline - hit_height) / 2.0;
+            regions.push(rsx! {
+                <Element
+                    key={format!("{series_index}:{label_index}")}
+                    style={{
+                        position: Position::absolute()
+                            .left(Length::px(x))
+                            .top(Length::px(hit_top))
+                            .anchor(Anchor::Parent),
+                        width: Length::px(bar_width),
+                        height: Length::px(hit_height),
+                    }}
+                    on_pointer_enter={enter}
+                    on_pointer_leave={leave}
+                >
+                    {tooltip}
+                </Element>
+            });
+        }
+    }
+
+    let legend = chart_legend(
+        &theme,
+        series
+            .iter()
+            .zip(colors)
+            .map(|(series, color)| (series.name.clone(), color))
+            .collect(),
+    );
+    let root = rsx! {
+        <Element style={{
+            width: Length::px(width),
+            height: Length::px(height),
+            layout: Layout::flow().column().no_wrap(),
+            color: theme.color.text.secondary.clone(),
+            font_size: theme.typography.size.xs,
+        }}>
+            <Element style={{ width: Length::px(width), height: Length::px(plot_height) }}>
+                <Svg
+                    source={SvgSource::Content(svg_document(width, plot_height, &body))}
+                    fit={ImageFit::Fill}
+                    style={{
+                        position: Position::absolute()
+                            .left(Length::px(0.0))
+                            .top(Length::px(0.0))
+                            .anchor(Anchor::Parent),
+                        width: Length::px(width),
+                        height: Length::px(plot_height),
+                    }}
+                />
+                {frame.labels(&labels)}
+                {regions}
+            </Element>
+            {legend}
+        </Element>
+    };
+    root_style.apply(root)
+}
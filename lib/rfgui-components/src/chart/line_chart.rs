@@ -0,0 +1,234 @@
+use super::axis::CartesianFrame;
+use super::{
+    ChartSeries, LEGEND_HEIGHT, chart_legend, chart_tooltip, format_value, series_color,
+    svg_document, svg_paint, use_enter_progress,
+};
+use crate::use_theme;
+use rfgui::style::{Anchor, Color, Layout, Length, Position};
+use rfgui::ui::{
+    RsxComponent, RsxNode, component, on_pointer_enter, on_pointer_leave, props, rsx, use_state,
+};
+use rfgui::view::{Element, ElementStylePropSchema, ImageFit, RootStyleProps, Svg, SvgSource};
+use std::fmt::Write as _;
+
+const LINE_WIDTH: f32 = 2.0;
+const POINT_RADIUS: f32 = 3.0;
+
+/// One polyline per series, with a point at each label.
+pub struct LineChart;
+
+#[derive(Clone)]
+#[props]
+pub struct LineChartProps {
+    pub labels: Vec<String>,
+    pub series: Vec<ChartSeries>,
+    pub width: Option<f64>,
+    pub height: Option<f64>,
+    /// Rise the lines from the baseline when the chart mounts. Defaults to
+    /// `true`.
+    pub animate: Option<bool>,
+    /// Stacked over the style of the chart container.
+    pub style: Option<ElementStylePropSchema>,
+    /// [`StyleClasses`](rfgui::view::StyleClasses) names, stacked beneath `style`.
+    pub class: Option<String>,
+}
+
+impl RsxComponent<LineChartProps> for LineChart {
+    fn render(props: LineChartProps, _children: Vec<RsxNode>) -> RsxNode {
+        rsx! {
+            <LineChartView
+                labels={props.labels}
+                series={props.series}
+                width={props.width.unwrap_or(360.0) as f32}
+                height={props.height.unwrap_or(240.0) as f32}
+                animate={props.animate.unwrap_or(true)}
+                root_style={RootStyleProps::new(props.style, props.class)}
+            />
+        }
+    }
+}
+
+#[rfgui::ui::component]
+impl rfgui::ui::RsxTag for LineChart {
+    type Props = __LineChartPropsInit;
+    type StrictProps = LineChartProps;
+    const ACCEPTS_CHILDREN: bool = false;
+
+    fn into_strict(props: Self::Props) -> Self::StrictProps {
+        props.into()
+    }
+
+    fn create_node(
+        props: Self::StrictProps,
+        children: Vec<RsxNode>,
+        _key: Option<rfgui::ui::RsxKey>,
+    ) -> RsxNode {
+        <Self as RsxComponent<LineChartProps>>::render(props, children)
+    }
+}
+
+#[component]
+fn LineChartView(
+    labels: Vec<String>,
+    series: Vec<ChartSeries>,
+    width: f32,
+    height: f32,
+    animate: bool,
+    root_style: RootStyleProps,
+) -> RsxNode {
+    let theme = use_theme().0;
+    let progress = use_enter_progress(animate, theme.motion.duration.slow);
+    let hovered = use_state(|| None::<usize>);
+
+    let mut values = series
+        .iter()
+        .flat_map(|series| series.values.iter().copied());
+    let first = values.next().unwrap_or(0.0);
+    let (min, max) = values.fold((first, first), |(min, max), value| {
+        (min.min(value), max.max(value))
+    });
+    let plot_height = (height - LEGEND_HEIGHT).max(0.0);
+    let frame = CartesianFrame::new(width, plot_height, labels.len(), min, max);
+    let baseline = frame.baseline();
+    let center = |index: usize| frame.band_left(index) + frame.band() / 2.0;
+    let colors: Vec<_> = series
+        .iter()
+        .enumerate()
+        .map(|(index, series)| series_color(&theme, index, series.color))
+        .collect();
+    let [r, g, b, a] = theme.color.layer.surface.to_rgba_u8();
+    let point_ring = Color::rgba(r, g, b, a);
+
+    let mut body = String::new();
+    frame.write_grid(&mut body, &theme);
+    for (entry, color) in series.iter().zip(&colors) {
+        let points: Vec<(f32, f32)> = entry
+            .values
+            .iter()
+            .take(labels.len())
+            .enumerate()
+            .map(|(index, value)| {
+                let y = frame.y(*value);
+                (center(index), baseline + (y - baseline) * progress)
+            })
+            .collect();
+        if points.len() > 1 {
+            body.push_str(r#"<path d=""#);
+            for (index, (x, y)) in points.iter().enumerate() {
+                let _ = write!(body, "{}{x} {y} ", if index == 0 { 'M' } else { 'L' });
+            }
+            let _ = write!(
+                body,
+                r#"" fill="none" stroke-width="{LINE_WIDTH}" stroke-linejoin="round""#
+            );
+            svg_paint(&mut body, "stroke", *color);
+            body.push_str("/>");
+        }
+        for (x, y) in points {
+            let _ = write!(
+                body,
+                r#"<circle cx="{x}" cy="{y}" r="{POINT_RADIUS}" stroke-width="1.5""#
+            );
+            svg_paint(&mut body, "fill", *color);
+            svg_paint(&mut body, "stroke", point_ring);
+            body.push_str("/>");
+        }
+    }
+
+    // One hover column per label; the tooltip lists every series there.
+    let regions: Vec<RsxNode> = labels
+        .iter()
+        .enumerate()
+        .map(|(index, label)| {
+            let enter = {
+                let hovered = hovered.binding();
+                on_pointer_enter(move |_| hovered.set(Some(index)))
+            };
+            let leave = {
+                let hovered = hovered.binding();
+                on_pointer_leave(move |_| {
+                    if hovered.get() == Some(index) {
+                        hovered.set(None);
+                    }
+                })
+            };
+            let active = hovered.get() == Some(index);
+            let guide = active.then(|| {
+                rsx! {
+                    <Element style={{
+                        position: Position::absolute()
+                            .left(Length::px(frame.band() / 2.0))
+                            .top(Length::px(0.0))
+                            .anchor(Anchor::Parent),
+                        width: Length::px(1.0),
+                        height: Length::percent(100.0),
+                        background: theme.color.border.clone(),
+                    }} />
+                }
+            });
+            let tooltip = active.then(|| {
+                let mut lines = vec![label.clone()];
+                lines.extend(series.iter().filter_map(|entry| {
+                    let value = entry.values.get(index)?;
+                    Some(format!("{}: {}", entry.name, format_value(*value)))
+                }));
+                chart_tooltip(lines)
+            });
+            rsx! {
+                <Element
+                    key={index}
+                    style={{
+                        position: Position::absolute()
+                            .left(Length::px(frame.band_left(index)))
+                            .top(Length::px(frame.top))
+                            .anchor(Anchor::Parent),
+                        width: Length::px(frame.band()),
+                        height: Length::px(frame.bottom - frame.top),
+                    }}
+                    on_pointer_enter={enter}
+                    on_pointer_leave={leave}
+                >
+                    {guide}
+                    {tooltip}
+                </Element>
+            }
+        })
+        .collect();
+
+    let legend = chart_legend(
+        &theme,
+        series
+            .iter()
+            .zip(colors)
+            .map(|(series, color)| (series.name.clone(), color))
+            .collect(),
+    );
+    let root = rsx! {
+        <Element style={{
+            width: Length::px(width),
+            height: Length::px(height),
+            layout: Layout::flow().column().no_wrap(),
+            color: theme.color.text.secondary.clone(),
+            font_size: theme.typography.size.xs,
+        }}>
+            <Element style={{ width: Length::px(width), height: Length::px(plot_height) }}>
+                <Svg
+                    source={SvgSource::Content(svg_document(width, plot_height, &body))}
+                    fit={ImageFit::Fill}
+                    style={{
+                        position: Position::absolute()
+                            .left(Length::px(0.0))
+                            .top(Length::px(0.0))
+                            .anchor(Anchor::Parent),
+                        width: Length::px(width),
+                        height: Length::px(plot_height),
+                    }}
+                />
+                {frame.labels(&labels)}
+                {regions}
+            </Element>
+            {legend}
+        </Element>
+    };
+    root_style.apply(root)
+}
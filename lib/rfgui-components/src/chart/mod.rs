@@ -0,0 +1,168 @@
+//! Line, bar and pie charts.
+//!
+//! Marks, grid lines and axes are drawn as one SVG document sized to the
+//! chart in pixels, so the vector pass rasterizes them at the display scale
+//! and they stay crisp. Axis labels, the legend and tooltips are regular
+//! elements; hover runs through element hit testing, either over invisible
+//! regions laid on the marks or, for pie slices, from the pointer position.
+
+mod axis;
+mod bar_chart;
+mod line_chart;
+mod pie_chart;
+
+pub use axis::*;
+pub use bar_chart::*;
+pub use line_chart::*;
+pub use pie_chart::*;
+
+use crate::{Theme, Tooltip, TooltipPlacement};
+use rfgui::style::{Align, Color, Layout, Length};
+use rfgui::ui::{RsxNode, rsx, use_interval, use_state};
+use rfgui::view::{Element, Text};
+use std::fmt::Write as _;
+use std::time::Duration;
+
+const ENTER_FRAME: Duration = Duration::from_millis(16);
+const LEGEND_HEIGHT: f32 = 24.0;
+const LEGEND_SWATCH: f32 = 10.0;
+
+/// Named values, one per chart label.
+#[derive(Clone, Debug, PartialEq)]
+pub struct ChartSeries {
+    pub name: String,
+    pub values: Vec<f64>,
+    /// Defaults to the theme's chart palette by series index.
+    pub color: Option<Color>,
+}
+
+impl ChartSeries {
+    pub fn new(name: impl Into<String>, values: impl Into<Vec<f64>>) -> Self {
+        Self {
+            name: name.into(),
+            values: values.into(),
+            color: None,
+        }
+    }
+
+    pub fn color(mut self, color: Color) -> Self {
+        self.color = Some(color);
+        self
+    }
+}
+
+/// `index`-th color of the chart palette, unless `explicit` overrides it.
+fn series_color(theme: &Theme, index: usize, explicit: Option<Color>) -> Color {
+    explicit.unwrap_or_else(|| {
+        let atom = &theme.color.atom;
+        let palette = [
+            &atom.blue,
+            &atom.green,
+            &atom.orange,
+            &atom.purple,
+            &atom.red,
+            &atom.cyan,
+            &atom.yellow,
+        ];
+        let [r, g, b, a] = palette[index % palette.len()].to_rgba_u8();
+        Color::rgba(r, g, b, a)
+    })
+}
+
+/// Enter animation progress, eased from 0 to 1 over `duration_ms`. Jumps
+/// straight to 1 when `animate` is off.
+fn use_enter_progress(animate: bool, duration_ms: u32) -> f32 {
+    let progress = use_state(|| if animate { 0.0_f32 } else { 1.0 });
+    let current = progress.get();
+    {
+        let progress = progress.binding();
+        let step = ENTER_FRAME.as_millis() as f32 / duration_ms.max(1) as f32;
+        use_interval(current < 1.0, ENTER_FRAME, move || {
+            progress.update(|progress| *progress = (*progress + step).min(1.0));
+        });
+    }
+    1.0 - (1.0 - current).powi(3)
+}
+
+/// Short label for a data value: integers without decimals, otherwise up
+/// to two decimals.
+fn format_value(value: f64) -> String {
+    if value.fract() == 0.0 {
+        format!("{value:.0}")
+    } else {
+        let text = format!("{value:.2}");
+        text.trim_end_matches('0').trim_end_matches('.').to_string()
+    }
+}
+
+/// SVG paint attribute pair for `color`, e.g. `fill="#3b82f6"
+/// fill-opacity="1"`.
+pub(crate) fn svg_paint(out: &mut String, attribute: &str, color: Color) {
+    let [r, g, b, a] = color.to_rgba_u8();
+    let _ = write!(
+        out,
+        r##" {attribute}="#{r:02x}{g:02x}{b:02x}" {attribute}-opacity="{:.3}""##,
+        a as f32 / 255.0
+    );
+}
+
+fn svg_document(width: f32, height: f32, body: &str) -> String {
+    format!(
+        r#"<svg xmlns="http://www.w3.org/2000/svg" width="{width}" height="{height}" viewBox="0 0 {width} {height}">{body}</svg>"#
+    )
+}
+
+/// Swatch and name for each entry, in a row under the plot.
+fn chart_legend(theme: &Theme, entries: Vec<(String, Color)>) -> RsxNode {
+    let items: Vec<RsxNode> = entries
+        .into_iter()
+        .enumerate()
+        .map(|(index, (name, color))| {
+            rsx! {
+                <Element
+                    key={index}
+                    style={{
+                        layout: Layout::flow().row().no_wrap().align(Align::Center),
+                        gap: theme.spacing.xs,
+                    }}
+                >
+                    <Element style={{
+                        width: Length::px(LEGEND_SWATCH),
+                        height: Length::px(LEGEND_SWATCH),
+                        border_radius: theme.radius.sm,
+                        background: color,
+                    }} />
+                    <Text>{name}</Text>
+                </Element>
+            }
+        })
+        .collect();
+    rsx! {
+        <Element style={{
+            width: Length::percent(100.0),
+            height: Length::px(LEGEND_HEIGHT),
+            layout: Layout::flow().row().no_wrap().align(Align::Center),
+            gap: theme.spacing.md,
+            color: theme.color.text.secondary.clone(),
+            font_size: theme.typography.size.xs,
+        }}>
+            {items}
+        </Element>
+    }
+}
+
+/// Hover tooltip listing `lines`, shown above its parent.
+fn chart_tooltip(lines: Vec<String>) -> RsxNode {
+    let lines: Vec<RsxNode> = lines
+        .into_iter()
+        .enumerate()
+        .map(|(index, line)| rsx! { <Text key={index}>{line}</Text> })
+        .collect();
+    rsx! {
+        <Tooltip placement={TooltipPlacement::Top}>
+            <Element style={{ layout: Layout::flow().column().no_wrap() }}>
+                {lines}
+            </Element>
+        </Tooltip>
+    }
+}
@@ -0,0 +1,261 @@
+use super::{
+    LEGEND_HEIGHT, chart_legend, chart_tooltip, format_value, series_color, svg_document,
+    svg_paint, use_enter_progress,
+};
+use crate::use_theme;
+use rfgui::style::{Anchor, Color, Layout, Length, Position};
+use rfgui::ui::{
+    RsxComponent, RsxNode, component, on_pointer_leave, on_pointer_move, props, rsx, use_state,
+};
+use rfgui::view::{Element, ElementStylePropSchema, ImageFit, RootStyleProps, Svg, SvgSource};
+use std::f32::consts::TAU;
+use std::fmt::Write as _;
+
+/// Gap between the pie and the edge of its plot area.
+const PIE_INSET: f32 = 8.0;
+/// How far the hovered slice is pulled out of the pie.
+const HOVER_OFFSET: f32 = 4.0;
+
+/// One slice of a [`PieChart`].
+#[derive(Clone, Debug, PartialEq)]
+pub struct PieSlice {
+    pub label: String,
+    pub value: f64,
+    /// Defaults to the theme's chart palette by slice index.
+    pub color: Option<Color>,
+}
+
+impl PieSlice {
+    pub fn new(label: impl Into<String>, value: f64) -> Self {
+        Self {
+            label: label.into(),
+            value,
+            color: None,
+        }
+    }
+
+    pub fn color(mut self, color: Color) -> Self {
+        self.color = Some(color);
+        self
+    }
+}
+
+/// Slices sized by their share of the total, clockwise from the top.
+pub struct PieChart;
+
+#[derive(Clone)]
+#[props]
+pub struct PieChartProps {
+    pub slices: Vec<PieSlice>,
+    pub width: Option<f64>,
+    pub height: Option<f64>,
+    /// Sweep the slices in when the chart mounts. Defaults to `true`.
+    pub animate: Option<bool>,
+    /// Stacked over the style of the chart container.
+    pub style: Option<ElementStylePropSchema>,
+    /// [`StyleClasses`](rfgui::view::StyleClasses) names, stacked beneath `style`.
+    pub class: Option<String>,
+}
+
+impl RsxComponent<PieChartProps> for PieChart {
+    fn render(props: PieChartProps, _children: Vec<RsxNode>) -> RsxNode {
+        rsx! {
+            <PieChartView
+                slices={props.slices}
+                width={props.width.unwrap_or(240.0) as f32}
+                height={props.height.unwrap_or(240.0) as f32}
+                animate={props.animate.unwrap_or(true)}
+                root_style={RootStyleProps::new(props.style, props.class)}
+            />
+        }
+    }
+}
+
+#[rfgui::ui::component]
+impl rfgui::ui::RsxTag for PieChart {
+    type Props = __PieChartPropsInit;
+    type StrictProps = PieChartProps;
+    const ACCEPTS_CHILDREN: bool = false;
+
+    fn into_strict(props: Self::Props) -> Self::StrictProps {
+        props.into()
+    }
+
+    fn create_node(
+        props: Self::StrictProps,
+        children: Vec<RsxNode>,
+        _key: Option<rfgui::ui::RsxKey>,
+    ) -> RsxNode {
+        <Self as RsxComponent<PieChartProps>>::render(props, children)
+    }
+}
+
+/// Point at `angle` radians clockwise from the top, `radius` from `center`.
+fn polar(center: (f32, f32), radius: f32, angle: f32) -> (f32, f32) {
+    (
+        center.0 + radius * angle.sin(),
+        center.1 - radius * angle.cos(),
+    )
+}
+
+#[component]
+fn PieChartView(
+    slices: Vec<PieSlice>,
+    width: f32,
+    height: f32,
+    animate: bool,
+    root_style: RootStyleProps,
+) -> RsxNode {
+    let theme = use_theme().0;
+    let progress = use_enter_progress(animate, theme.motion.duration.slow);
+    let hovered = use_state(|| None::<usize>);
+
+    let plot_height = (height - LEGEND_HEIGHT).max(0.0);
+    let center = (width / 2.0, plot_height / 2.0);
+    let radius = (width.min(plot_height) / 2.0 - PIE_INSET).max(0.0);
+    let total: f64 = slices.iter().map(|slice| slice.value.max(0.0)).sum();
+    // (start, end) angle of each slice once fully swept in.
+    let mut angles = Vec::with_capacity(slices.len());
+    let mut start = 0.0_f32;
+    for slice in &slices {
+        let share = if total > 0.0 {
+            (slice.value.max(0.0) / total) as f32
+        } else {
+            0.0
+        };
+        angles.push((start, start + share * TAU));
+        start += share * TAU;
+    }
+    let colors: Vec<_> = slices
+        .iter()
+        .enumerate()
+        .map(|(index, slice)| series_color(&theme, index, slice.color))
+        .collect();
+
+    let mut body = String::new();
+    for (index, ((start, end), color)) in angles.iter().zip(&colors).enumerate() {
+        let (start, end) = (start * progress, end * progress);
+        if end - start <= f32::EPSILON {
+            continue;
+        }
+        let offset = if hovered.get() == Some(index) {
+            HOVER_OFFSET
+        } else {
+            0.0
+        };
+        let origin = polar(center, offset, (start + end) / 2.0);
+        if end - start >= TAU - 1e-4 {
+            let _ = write!(
+                body,
+                r#"<circle cx="{}" cy="{}" r="{radius}""#,
+                origin.0, origin.1
+            );
+        } else {
+            let from = polar(origin, radius, start);
+            let to = polar(origin, radius, end);
+            let large_arc = u8::from(end - start > TAU / 2.0);
+            let _ = write!(
+                body,
+                r#"<path d="M{} {} L{} {} A{radius} {radius} 0 {large_arc} 1 {} {} Z""#,
+                origin.0, origin.1, from.0, from.1, to.0, to.1
+            );
+        }
+        svg_paint(&mut body, "fill", *color);
+        body.push_str("/>");
+    }
+
+    // Slices are not rectangles, so the plot resolves the hovered slice
+    // from the pointer's angle and distance to the center.
+    let track = {
+        let hovered = hovered.binding();
+        let angles = angles.clone();
+        on_pointer_move(move |event| {
+            let bounds = event.meta.current_target().bounds;
+            let dx = event.pointer.viewport_x - bounds.x - center.0;
+            let dy = event.pointer.viewport_y - bounds.y - center.1;
+            let slice = (dx.hypot(dy) <= radius + HOVER_OFFSET)
+                .then(|| dx.atan2(-dy).rem_euclid(TAU))
+                .and_then(|angle| {
+                    angles
+                        .iter()
+                        .position(|(start, end)| angle >= *start && angle < *end)
+                });
+            if hovered.get() != slice {
+                hovered.set(slice);
+            }
+        })
+    };
+    let clear = {
+        let hovered = hovered.binding();
+        on_pointer_leave(move |_| hovered.set(None))
+    };
+    let tooltip = hovered
+        .get()
+        .and_then(|index| Some((index, slices.get(index)?, angles.get(index)?)))
+        .map(|(index, slice, (start, end))| {
+            let (x, y) = polar(center, radius * 0.6, (start + end) / 2.0);
+            let share = if total > 0.0 {
+                slice.value.max(0.0) / total * 100.0
+            } else {
+                0.0
+            };
+            rsx! {
+                <Element
+                    key={index}
+                    style={{
+                        position: Position::absolute()
+                            .left(Length::px(x))
+                            .top(Length::px(y))
+                            .anchor(Anchor::Parent),
+                        width: Length::px(0.0),
+                        height: Length::px(0.0),
+                    }}
+                >
+                    {chart_tooltip(vec![
+                        slice.label.clone(),
+                        format!("{} ({share:.1}%)", format_value(slice.value)),
+                    ])}
+                </Element>
+            }
+        });
+
+    let legend = chart_legend(
+        &theme,
+        slices
+            .iter()
+            .zip(colors)
+            .map(|(slice, color)| (slice.label.clone(), color))
+            .collect(),
+    );
+    let root = rsx! {
+        <Element style={{
+            width: Length::px(width),
+            height: Length::px(height),
+            layout: Layout::flow().column().no_wrap(),
+            color: theme.color.text.secondary.clone(),
+            font_size: theme.typography.size.xs,
+        }}>
+            <Element
+                style={{ width: Length::px(width), height: Length::px(plot_height) }}
+                on_pointer_move={track}
+                on_pointer_leave={clear}
+            >
+                <Svg
+                    source={SvgSource::Content(svg_document(width, plot_height, &body))}
+                    fit={ImageFit::Fill}
+                    style={{
+                        position: Position::absolute()
+                            .left(Length::px(0.0))
+                            .top(Length::px(0.0))
+                            .anchor(Anchor::Parent),
+                        width: Length::px(width),
+                        height: Length::px(plot_height),
+                    }}
+                />
+                {tooltip}
+            </Element>
+            {legend}
+        </Element>
+    };
+    root_style.apply(root)
+}
@@ -0,0 +1,119 @@
+//! Keyboard activation shared by the interactive inputs.
+//!
+//! A focused widget answers Enter / Space the way it answers a click, and
+//! widgets that pick a value also answer the arrow keys and Home / End.
+//! Attach the handler to the element that owns the click handler: key
+//! events bubble to it from whichever descendant took focus on pointer
+//! down.
+
+use rfgui::platform::{Key, PointerType};
+use rfgui::ui::{
+    ClickEvent, ClickHandlerProp, KeyDownEvent, KeyDownHandlerProp, KeyEventData, Modifiers,
+    PointerButtons, PointerEventData,
+};
+
+/// What a key press asks an activatable widget to do.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum KeyActivation {
+    /// Enter or Space: the keyboard equivalent of a click.
+    Activate,
+    /// Left or Up arrow, depending on the widget's [`ArrowAxis`].
+    Previous,
+    /// Right or Down arrow, depending on the widget's [`ArrowAxis`].
+    Next,
+    /// Home.
+    First,
+    /// End.
+    Last,
+}
+
+/// Which arrow keys step a widget's value.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum ArrowAxis {
+    /// Left / Right, for sliders and other horizontal ranges.
+    Horizontal,
+    /// Up / Down, for lists.
+    Vertical,
+}
+
+impl KeyActivation {
+    /// Classify a key press. Arrows and Home / End are only recognised when
+    /// `arrows` is set. Returns `None` for other keys, for auto-repeated
+    /// Enter / Space, while an IME is composing, and when Ctrl, Alt or Meta
+    /// is held (those presses belong to shortcuts).
+    pub fn from_key(key: &KeyEventData, arrows: Option<ArrowAxis>) -> Option<Self> {
+        if key.is_composing
+            || key
+                .modifiers
+                .intersects(Modifiers::CTRL | Modifiers::ALT | Modifiers::META)
+        {
+            return None;
+        }
+        let activation = match (key.key, arrows) {
+            (Key::Enter | Key::NumberPadEnter | Key::Space, _) if !key.repeat => Self::Activate,
+            (Key::ArrowLeft, Some(ArrowAxis::Horizontal))
+            | (Key::ArrowUp, Some(ArrowAxis::Vertical)) => Self::Previous,
+            (Key::ArrowRight, Some(ArrowAxis::Horizontal))
+            | (Key::ArrowDown, Some(ArrowAxis::Vertical)) => Self::Next,
+            (Key::Home, Some(_)) => Self::First,
+            (Key::End, Some(_)) => Self::Last,
+            _ => return None,
+        };
+        Some(activation)
+    }
+}
+
+/// Key handler that runs `on_click` for Enter / Space, so one click
+/// callback serves pointer and keyboard users alike.
+pub fn on_activate(on_click: ClickHandlerProp) -> KeyDownHandlerProp {
+    on_key_activation(None, move |activation, event| {
+        if activation != KeyActivation::Activate {
+            return false;
+        }
+        on_click.call(&mut keyboard_click(event));
+        true
+    })
+}
+
+/// Key handler that classifies presses with [`KeyActivation::from_key`]
+/// and hands them to `handler`. A press `handler` reports as used (by
+/// returning `true`) stops propagating.
+pub fn on_key_activation<F>(arrows: Option<ArrowAxis>, handler: F) -> KeyDownHandlerProp
+where
+    F: Fn(KeyActivation, &mut KeyDownEvent) -> bool + 'static,
+{
+    KeyDownHandlerProp::new(move |event| {
+        let Some(activation) = KeyActivation::from_key(&event.key, arrows) else {
+            return;
+        };
+        if handler(activation, event) {
+            event.meta.stop_propagation();
+        }
+    })
+}
+
+/// Click standing in for a keyboard activation. It shares `event`'s
+/// dispatch state, so propagation and viewport requests made by the click
+/// handler apply to the key press. The pointer sits at the centre of the
+/// element handling the key and reports no button.
+pub fn keyboard_click(event: &KeyDownEvent) -> ClickEvent {
+    let target = event.meta.current_target();
+    let (bounds, local_bounds) = (target.bounds, target.local_bounds);
+    ClickEvent {
+        meta: event.meta.clone(),
+        pointer: PointerEventData {
+            viewport_x: bounds.x + bounds.width * 0.5,
+            viewport_y: bounds.y + bounds.height * 0.5,
+            local_x: local_bounds.width * 0.5,
+            local_y: local_bounds.height * 0.5,
+            button: None,
+            buttons: PointerButtons::default(),
+            modifiers: event.key.modifiers,
+            pointer_id: 0,
+            pointer_type: PointerType::Mouse,
+            pressure: 0.0,
+            timestamp: rfgui::time::Instant::now(),
+        },
+        click_count: 1,
+    }
+}
@@ -1,4 +1,4 @@
-use crate::{ButtonSizeSpec, Theme, use_theme};
+use crate::{ButtonSizeSpec, Theme, on_activate, play_sound, use_theme};
 use rfgui::style::{
     Align, Border, BorderRadius, Color, ColorLike, Cursor, JustifyContent, Layout, Length, Padding,
     Transition, TransitionProperty, Transitions, darken_color,
@@ -9,7 +9,7 @@ use rfgui::ui::{
     component, props, rsx, use_interval, use_state, use_viewport_pointer_move,
     use_viewport_pointer_up,
 };
-use rfgui::view::Element;
+use rfgui::view::{Element, ElementStylePropSchema, RootStyleProps};
 use std::time::Duration;
 
 #[derive(Clone, Copy, Debug, PartialEq, Eq)]
@@ -184,6 +184,13 @@ pub struct ButtonProps {
     pub end_icon: Option<RsxNode>,
     pub on_click: Option<ClickHandlerProp>,
     pub tooltip: Option<RsxNode>,
+    /// Registered sound played on click. Defaults to the theme's
+    /// `sound.click`.
+    pub sound: Option<String>,
+    /// Stacked over the button's own style, so set fields win.
+    pub style: Option<ElementStylePropSchema>,
+    /// [`StyleClasses`](rfgui::view::StyleClasses) names, stacked beneath `style`.
+    pub class: Option<String>,
 }
 
 impl RsxComponent<ButtonProps> for Button {
@@ -200,6 +207,8 @@ impl RsxComponent<ButtonProps> for Button {
                 end_icon={props.end_icon}
                 on_click={props.on_click}
                 tooltip={props.tooltip}
+                sound={props.sound}
+                root_style={RootStyleProps::new(props.style, props.class)}
             >
                 {children}
             </ButtonView>
@@ -267,6 +276,8 @@ fn ButtonView(
     end_icon: Option<RsxNode>,
     on_click: Option<ClickHandlerProp>,
     tooltip: Option<RsxNode>,
+    sound: Option<String>,
+    root_style: RootStyleProps,
     children: Vec<RsxNode>,
 ) -> RsxNode {
     const REPEAT_DELAY: Duration = Duration::from_millis(400);
@@ -279,6 +290,15 @@ fn ButtonView(
     let color = color.unwrap_or(ButtonColor::Primary);
     let disabled = disabled.unwrap_or(false);
     let full_width = full_width.unwrap_or(false);
+    // Pointer, keyboard and repeat clicks all run the handler, so the sound
+    // travels with it.
+    let on_click = match (on_click, sound.or_else(|| theme.sound.click.clone())) {
+        (Some(handler), Some(sound)) => Some(ClickHandlerProp::new(move |event| {
+            play_sound(Some(&sound));
+            handler.call(event);
+        })),
+        (on_click, _) => on_click,
+    };
     let repeat_enabled = repeat.unwrap_or(false) && !disabled && on_click.is_some();
     let repeat_state = use_state(ButtonRepeatState::default);
     let repeat_snapshot = repeat_state.get();
@@ -321,19 +341,28 @@ fn ButtonView(
     let (color_base, color_on) = resolve_color_set(&theme, color);
     let transparent: Box<dyn ColorLike> = Box::new(Color::transparent());
 
+    // High contrast outlines every variant, text buttons included, so the
+    // hit area stays visible without relying on a fill.
+    let border_width = Length::px(if theme.high_contrast { 1.0 } else { 0.5 });
     let border: Border = if disabled {
         match variant {
             ButtonVariant::Contained | ButtonVariant::Outlined => {
-                Border::uniform(Length::px(0.5), theme.color.state.disabled.as_ref())
+                Border::uniform(border_width, theme.color.state.disabled.as_ref())
+            }
+            ButtonVariant::Text if theme.high_contrast => {
+                Border::uniform(border_width, theme.color.state.disabled.as_ref())
             }
-            ButtonVariant::Text => Border::uniform(Length::px(0.5), transparent.as_ref()),
+            ButtonVariant::Text => Border::uniform(border_width, transparent.as_ref()),
         }
     } else {
         match variant {
             ButtonVariant::Contained | ButtonVariant::Outlined => {
-                Border::uniform(Length::px(0.5), color_base.as_ref())
+                Border::uniform(border_width, color_base.as_ref())
+            }
+            ButtonVariant::Text if theme.high_contrast => {
+                Border::uniform(border_width, color_base.as_ref())
             }
-            ButtonVariant::Text => Border::uniform(Length::px(0.5), transparent.as_ref()),
+            ButtonVariant::Text => Border::uniform(border_width, transparent.as_ref()),
         }
     };
 
@@ -496,6 +525,8 @@ fn ButtonView(
         None
     };
 
+    let key_down = on_click.clone().filter(|_| !disabled).map(on_activate);
+
     let root_padding: Padding = spec.padding;
     let root_border_radius: BorderRadius = theme.component.button.radius;
     let icon_gap = spec.icon_gap;
@@ -506,7 +537,7 @@ fn ButtonView(
         None
     };
 
-    rsx! {
+    let root = rsx! {
         <Element
             style={{
                 width: width,
@@ -534,6 +565,7 @@ fn ButtonView(
             on_pointer_down={mouse_down}
             on_pointer_enter={mouse_enter}
             on_pointer_leave={mouse_leave}
+            on_key_down={key_down}
             on_click={if !disabled && !repeat_enabled { on_click } else { None }}
         >
             {start_icon}
@@ -541,5 +573,6 @@ fn ButtonView(
             {end_icon}
             {if tooltip_hovered { tooltip } else { None }}
         </Element>
-    }
+    };
+    root_style.apply(root)
 }
@@ -1,11 +1,11 @@
 use crate::material_symbol::CheckIcon;
-use crate::use_theme;
+use crate::{on_activate, play_sound, use_theme};
 use rfgui::style::{Align, Border, Color, JustifyContent, Layout, Transition, TransitionProperty};
 use rfgui::ui::{
     Binding, ClickHandlerProp, PointerEnterHandlerProp, PointerLeaveHandlerProp, RsxComponent,
     RsxNode, props, rsx, use_state,
 };
-use rfgui::view::{Element, Text};
+use rfgui::view::{Element, ElementStylePropSchema, Text, with_root_class_and_style};
 use std::rc::Rc;
 
 pub struct Checkbox;
@@ -18,6 +18,10 @@ pub struct CheckboxProps {
     pub checked: Option<bool>,
     pub disabled: Option<bool>,
     pub on_change: Option<Rc<dyn Fn(bool)>>,
+    /// Stacked over the style of the row holding box and label.
+    pub style: Option<ElementStylePropSchema>,
+    /// [`StyleClasses`](rfgui::view::StyleClasses) names, stacked beneath `style`.
+    pub class: Option<String>,
 }
 
 impl RsxComponent<CheckboxProps> for Checkbox {
@@ -28,6 +32,8 @@ impl RsxComponent<CheckboxProps> for Checkbox {
         let disabled = props.disabled.unwrap_or(false);
         let on_change = props.on_change;
         let label = props.label;
+        let style = props.style;
+        let class = props.class;
         let theme = use_theme().0;
         let checkbox_theme = &theme.component.checkbox;
         let fallback_checked = use_state(|| checked);
@@ -41,28 +47,32 @@ impl RsxComponent<CheckboxProps> for Checkbox {
         let hover_state_for_leave = hover_state.clone();
         let checked = checked_binding.get();
         let hovered = hover_state.get();
+        let toggle_sound = theme.sound.toggle.clone();
         let click = ClickHandlerProp::new(move |_event| {
             if disabled {
                 return;
             }
             let next = !checked_binding.get();
             checked_binding.set(next);
+            play_sound(toggle_sound.as_deref());
             if let Some(cb) = &on_change {
                 cb(next);
             }
         });
 
+        let key_down = (!disabled).then(|| on_activate(click.clone()));
         let on_pointer_enter =
             PointerEnterHandlerProp::new(move |_event| hover_state_for_enter.set(true));
         let on_pointer_leave =
             PointerLeaveHandlerProp::new(move |_event| hover_state_for_leave.set(false));
 
-        rsx! {
+        let root = rsx! {
             <Element style={{
                 layout: Layout::flow().row().align(Align::Center).no_wrap(),
                 gap: theme.spacing.md,
             }}
             on_click={click}
+            on_key_down={key_down}
             on_pointer_enter={on_pointer_enter}
             on_pointer_leave={on_pointer_leave}
             >
@@ -91,7 +101,14 @@ impl RsxComponent<CheckboxProps> for Checkbox {
                 }} >
                     <CheckIcon style={{
                         color: if checked {
-                            if disabled { theme.color.text.disabled.clone() } else { theme.color.surface.on.clone() }
+                            if disabled {
+                                theme.color.text.disabled.clone()
+                            } else if theme.high_contrast {
+                                // The box is filled with the highlight color.
+                                theme.color.primary.on.clone()
+                            } else {
+                                theme.color.surface.on.clone()
+                            }
                         }else {
                             Color::transparent()
                         },
@@ -108,7 +125,8 @@ impl RsxComponent<CheckboxProps> for Checkbox {
                     {label}
                 </Text>
             </Element>
-        }
+        };
+        with_root_class_and_style(root, class.as_deref(), style)
     }
 }
 
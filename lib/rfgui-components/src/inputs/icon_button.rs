@@ -1,11 +1,11 @@
 use crate::inputs::button::{ButtonColor, ButtonSize, resolve_color_set, size_spec};
-use crate::use_theme;
+use crate::{on_activate, use_theme};
 use rfgui::style::{
     Align, Color, ColorLike, Cursor, JustifyContent, Layout, Transition, TransitionProperty,
     Transitions,
 };
 use rfgui::ui::{ClickHandlerProp, RsxComponent, RsxNode, component, props, rsx};
-use rfgui::view::Element;
+use rfgui::view::{Element, ElementStylePropSchema, RootStyleProps};
 
 #[derive(Clone, Copy, Debug, PartialEq, Eq)]
 pub enum IconButtonEdge {
@@ -53,6 +53,10 @@ pub struct IconButtonProps {
     pub disabled: Option<bool>,
     pub edge: Option<IconButtonEdge>,
     pub on_click: Option<ClickHandlerProp>,
+    /// Stacked over the button's own style, so set fields win.
+    pub style: Option<ElementStylePropSchema>,
+    /// [`StyleClasses`](rfgui::view::StyleClasses) names, stacked beneath `style`.
+    pub class: Option<String>,
 }
 
 impl RsxComponent<IconButtonProps> for IconButton {
@@ -64,6 +68,7 @@ impl RsxComponent<IconButtonProps> for IconButton {
                 disabled={props.disabled}
                 edge={props.edge}
                 on_click={props.on_click}
+                root_style={RootStyleProps::new(props.style, props.class)}
             >
                 {children}
             </IconButtonView>
@@ -102,6 +107,7 @@ fn IconButtonView(
     disabled: Option<bool>,
     edge: Option<IconButtonEdge>,
     on_click: Option<ClickHandlerProp>,
+    root_style: RootStyleProps,
     children: Vec<RsxNode>,
 ) -> RsxNode {
     let theme = use_theme().0;
@@ -125,7 +131,9 @@ fn IconButtonView(
         theme.color.state.hover.clone()
     };
 
-    rsx! {
+    let key_down = on_click.clone().filter(|_| !disabled).map(on_activate);
+
+    let root = rsx! {
         <Element
             style={{
                 width: spec.icon_button_size,
@@ -149,9 +157,11 @@ fn IconButtonView(
                     background: resolve(hover_background.as_ref()),
                 },
             }}
+            on_key_down={key_down}
             on_click={if !disabled { on_click } else { None }}
         >
             {children}
         </Element>
-    }
+    };
+    root_style.apply(root)
 }
@@ -1,3 +1,4 @@
+mod activatable;
 pub(crate) mod button;
 mod checkbox;
 mod icon_button;
@@ -8,6 +9,7 @@ mod switch;
 mod toggle_button;
 pub(crate) mod toggle_button_group;
 
+pub use activatable::*;
 pub use button::*;
 pub use checkbox::*;
 pub use icon_button::*;
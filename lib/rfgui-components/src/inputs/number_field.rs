@@ -6,7 +6,7 @@ use rfgui::ui::{
     Binding, BlurHandlerProp, ClickHandlerProp, RsxComponent, RsxNode, TextChangeHandlerProp,
     props, rsx, use_state,
 };
-use rfgui::view::{Element, TextArea};
+use rfgui::view::{Element, ElementStylePropSchema, TextArea, with_root_class_and_style};
 
 pub struct NumberField<T: NumberFieldValue = f64>(std::marker::PhantomData<T>);
 
@@ -30,6 +30,10 @@ pub struct NumberFieldProps<T: NumberFieldValue> {
     pub step: Option<T>,
     pub disabled: Option<bool>,
     pub label: Option<String>,
+    /// Stacked over the style of the row holding field, steppers and label.
+    pub style: Option<ElementStylePropSchema>,
+    /// [`StyleClasses`](rfgui::view::StyleClasses) names, stacked beneath `style`.
+    pub class: Option<String>,
 }
 
 impl<T> RsxComponent<NumberFieldProps<T>> for NumberField<T>
@@ -42,6 +46,8 @@ where
         let binding = props.binding.unwrap_or_else(|| Binding::new(value));
         let theme = use_theme().0;
         let label = props.label;
+        let style = props.style;
+        let class = props.class;
 
         let fallback_value = use_state(|| value);
         let value_binding = if has_binding {
@@ -121,7 +127,7 @@ where
             }))
         };
 
-        rsx! {
+        let root = rsx! {
             <Element style={{
                 layout: Layout::flex().row().align(Align::Center),
                 width: Length::percent(100.0),
@@ -172,7 +178,8 @@ where
                     text_wrap: TextWrap::NoWrap,
                 }}>{label.unwrap_or_default()}</Element>
             </Element>
-        }
+        };
+        with_root_class_and_style(root, class.as_deref(), style)
     }
 }
 
@@ -2,22 +2,34 @@ use std::any::Any;
 use std::rc::Rc;
 
 use crate::material_symbol::ExpandMoreIcon;
-use crate::use_theme;
+use crate::{ArrowAxis, KeyActivation, Theme, keyboard_click, on_key_activation, use_theme};
 use rfgui::style::flex;
 use rfgui::style::{
-    Align, Angle, ClipMode, Collision, CollisionBoundary, Color, ColorLike, CrossSize, Layout,
-    Length, Operator, Position, Rotate, ScrollDirection, Transform, Transition, TransitionProperty,
+    Align, AnchorPlacement, Angle, Border, ClipMode, Collision, CollisionBoundary, Color,
+    ColorLike, CrossSize, Layout, Length, Operator, Position, Rotate, ScrollDirection, Transform,
+    Transition, TransitionProperty,
 };
 use rfgui::ui::{
     Binding, BlurHandlerProp, ClickHandlerProp, FocusHandlerProp, KeyDownHandlerProp,
     PointerDownHandlerProp, RsxComponent, RsxNode, component, props, rsx, use_state,
 };
-use rfgui::view::{Element, Text};
+use rfgui::view::{Element, ElementStylePropSchema, RootStyleProps, Text};
 
 pub struct Select<DataType = (), ValueType = ()>(std::marker::PhantomData<(DataType, ValueType)>)
 where
     ValueType: 'static;
 
+/// What a [`SelectRenderOption`] closure knows about the row it draws.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct SelectOptionState {
+    pub selected: bool,
+    pub disabled: bool,
+}
+
+/// Draws a menu row's content in place of its plain label. The trigger
+/// keeps showing `to_label`.
+pub type SelectRenderOption<DataType> = Rc<dyn Fn(&DataType, usize, SelectOptionState) -> RsxNode>;
+
 #[derive(Clone)]
 #[props]
 pub struct SelectProps<DataType, ValueType: 'static> {
@@ -25,13 +37,23 @@ pub struct SelectProps<DataType, ValueType: 'static> {
     pub to_label: fn(&DataType, usize) -> String,
     pub to_value: Option<fn(&DataType, usize) -> ValueType>,
     pub to_disabled: Option<fn(&DataType, usize) -> bool>,
+    /// Group title of an option. Consecutive options with the same title
+    /// sit under one header, which cannot be selected.
+    pub to_group: Option<fn(&DataType, usize) -> Option<String>>,
+    pub render_option: Option<SelectRenderOption<DataType>>,
     pub value: Binding<ValueType>,
+    /// Stacked over the style of the element wrapping trigger and menu.
+    pub style: Option<ElementStylePropSchema>,
+    /// [`StyleClasses`](rfgui::view::StyleClasses) names, stacked beneath `style`.
+    pub class: Option<String>,
 }
 
 #[derive(Clone)]
 struct SelectMenuItem {
     key: usize,
     label: String,
+    content: Option<RsxNode>,
+    group: Option<String>,
     selected: bool,
     disabled: bool,
     on_select: ClickHandlerProp,
@@ -61,6 +83,11 @@ where
                     .map(|resolver| resolver(item, index))
                     .unwrap_or(false);
                 let selected = value == selected_value;
+                let content = props
+                    .render_option
+                    .as_ref()
+                    .map(|render| render(item, index, SelectOptionState { selected, disabled }));
+                let group = props.to_group.and_then(|resolver| resolver(item, index));
                 let value_binding = props.value.clone();
                 let on_select = ClickHandlerProp::new(move |event| {
                     if disabled {
@@ -73,6 +100,8 @@ where
                 SelectMenuItem {
                     key: index,
                     label,
+                    content,
+                    group,
                     selected,
                     disabled,
                     on_select,
@@ -84,6 +113,7 @@ where
             <SelectView
                 selected_label={selected_label}
                 menu_items={menu_items}
+                root_style={RootStyleProps::new(props.style, props.class)}
             />
         }
     }
@@ -113,7 +143,11 @@ where
 }
 
 #[component]
-fn SelectView(selected_label: String, menu_items: Vec<SelectMenuItem>) -> RsxNode {
+fn SelectView(
+    selected_label: String,
+    menu_items: Vec<SelectMenuItem>,
+    root_style: RootStyleProps,
+) -> RsxNode {
     const SELECT_TRIGGER_ANCHOR: &str = "__rfgui_select_trigger_anchor";
 
     let fallback_open = use_state(|| false);
@@ -145,6 +179,21 @@ fn SelectView(selected_label: String, menu_items: Vec<SelectMenuItem>) -> RsxNod
     };
     let pseudo_key_down = {
         let open_binding = open_binding.clone();
+        let activation = {
+            let open_binding = open_binding.clone();
+            let menu_items = menu_items.clone();
+            on_key_activation(Some(ArrowAxis::Vertical), move |activation, event| {
+                if activation == KeyActivation::Activate {
+                    open_binding.set(!open_binding.get());
+                    return true;
+                }
+                let Some(item) = keyboard_target(&menu_items, activation) else {
+                    return false;
+                };
+                item.on_select.call(&mut keyboard_click(event));
+                true
+            })
+        };
         KeyDownHandlerProp::new(move |event| {
             use rfgui::platform::Key;
             let key = event.key.key;
@@ -153,14 +202,11 @@ fn SelectView(selected_label: String, menu_items: Vec<SelectMenuItem>) -> RsxNod
                 event.meta.stop_propagation();
                 return;
             }
-            if key == Key::Enter || key == Key::NumberPadEnter {
-                open_binding.set(!open_binding.get());
-                event.meta.stop_propagation();
-                return;
-            }
             if key == Key::Tab {
                 open_binding.set(false);
+                return;
             }
+            activation.call(event);
         })
     };
     let pseudo_mouse_down = {
@@ -254,76 +300,54 @@ fn SelectView(selected_label: String, menu_items: Vec<SelectMenuItem>) -> RsxNod
             .push(build_menu_node(&menu_items, SELECT_TRIGGER_ANCHOR));
     }
 
-    root
+    root_style.apply(root)
 }
 
-fn build_menu_node(menu_items: &[SelectMenuItem], anchor_name: &str) -> RsxNode {
-    let theme = use_theme().0;
-    let option_nodes: Vec<RsxNode> = menu_items
+/// Item a keyboard step selects: the nearest enabled item before or after
+/// the selected one, or the first / last enabled item. `None` when no
+/// enabled item lies that way.
+fn keyboard_target(
+    menu_items: &[SelectMenuItem],
+    activation: KeyActivation,
+) -> Option<&SelectMenuItem> {
+    let selected = menu_items.iter().position(|item| item.selected);
+    let mut enabled = menu_items
         .iter()
-        .map(|item| {
-            let mouse_down = PointerDownHandlerProp::new(move |event| {
-                event.meta.suppress_focus_change();
-                event.meta.stop_propagation();
-            });
-            let option_disabled = item.disabled;
-            let on_select = item.on_select.clone();
-            let click = ClickHandlerProp::new(move |event| {
-                if option_disabled {
-                    return;
-                }
-                on_select.call(event);
-                event.meta.viewport().set_focus(None);
-                event.meta.stop_propagation();
-            });
+        .enumerate()
+        .filter(|(_, item)| !item.disabled);
+    let target = match (activation, selected) {
+        (KeyActivation::Activate, _) => None,
+        (KeyActivation::First, _) | (KeyActivation::Next, None) => enabled.next(),
+        (KeyActivation::Last, _) | (KeyActivation::Previous, None) => enabled.next_back(),
+        (KeyActivation::Next, Some(selected)) => enabled.find(|(index, _)| *index > selected),
+        (KeyActivation::Previous, Some(selected)) => enabled.rfind(|(index, _)| *index < selected),
+    };
+    target.map(|(_, item)| item)
+}
 
-            rsx! {
-                <Element
-                    key={item.key}
-                    style={{
-                        layout: Layout::flex().row(),
-                        width: Length::percent(100.0),
-                        padding: theme.component.input.padding,
-                        background: if item.disabled {
-                            theme.component.select.option_disabled_background.clone()
-                        } else if item.selected {
-                            theme.component.select.option_selected_background.clone()
-                        } else {
-                            Box::new(Color::transparent()) as Box<dyn ColorLike>
-                        },
-                        hover: {
-                            background: theme.component.select.option_hover_background.clone(),
-                        }
-                    }}
-                    on_pointer_down={mouse_down}
-                    on_click={click}
-                >
-                    <Text
-                        style={{
-                            color: if item.disabled {
-                                theme.component.select.option_disabled_text.clone()
-                            } else if item.selected {
-                                theme.component.select.option_selected_text.clone()
-                            } else {
-                                theme.color.background.on.clone()
-                            }
-                        }}
-                    >
-                        {item.label.clone()}
-                    </Text>
-                </Element>
+fn build_menu_node(menu_items: &[SelectMenuItem], anchor_name: &str) -> RsxNode {
+    let theme = use_theme().0;
+    let mut option_nodes: Vec<RsxNode> = Vec::with_capacity(menu_items.len());
+    let mut current_group: Option<&str> = None;
+    for item in menu_items {
+        if item.group.as_deref() != current_group {
+            current_group = item.group.as_deref();
+            if let Some(title) = current_group {
+                option_nodes.push(build_group_header(title, item.key, &theme));
             }
-        })
-        .collect();
+        }
+        option_nodes.push(build_option_node(item, &theme));
+    }
 
     rsx! {
         <Element
             style={{
                 position: Position::absolute()
                     .anchor(anchor_name)
-                    .top(Length::calc(Length::percent(100.0), Operator::subtract, Length::px(1.0)))
-                    .left(Length::px(0.0))
+                    .placement(AnchorPlacement::bottom_start())
+                    .anchor_offset(Length::px(-1.0))
                     .collision(Collision::FlipFit, CollisionBoundary::Viewport)
+                    .collision_padding(Length::px(8.0))
                     .clip(ClipMode::Viewport),
                 max_height: Length::vh(50.0),
                 width: Length::percent(100.0),
@@ -342,6 +366,103 @@ fn build_menu_node(menu_items: &[SelectMenuItem], anchor_name: &str) -> RsxNode
     }
 }
 
+fn build_option_node(item: &SelectMenuItem, theme: &Theme) -> RsxNode {
+    let mouse_down = PointerDownHandlerProp::new(move |event| {
+        event.meta.suppress_focus_change();
+        event.meta.stop_propagation();
+    });
+    let content = item.content.clone().unwrap_or_else(|| {
+        rsx! {
+            <Text
+                style={{
+                    color: if item.disabled {
+                        theme.component.select.option_disabled_text.clone()
+                    } else if item.selected {
+                        theme.component.select.option_selected_text.clone()
+                    } else {
+                        theme.color.background.on.clone()
+                    }
+                }}
+            >
+                {item.label.clone()}
+            </Text>
+        }
+    });
+    let option_disabled = item.disabled;
+    let on_select = item.on_select.clone();
+    let click = ClickHandlerProp::new(move |event| {
+        if option_disabled {
+            return;
+        }
+        on_select.call(event);
+        event.meta.viewport().set_focus(None);
+        event.meta.stop_propagation();
+    });
+    // High contrast also outlines the selected option; the other
+    // rows carry a clear border so none of them shift.
+    let clear = Color::transparent();
+    let option_border = Border::uniform(
+        if theme.high_contrast {
+            Length::px(1.0)
+        } else {
+            Length::Zero
+        },
+        if item.selected && !item.disabled {
+            theme.color.border.as_ref()
+        } else {
+            &clear
+        },
+    );
+
+    rsx! {
+        <Element
+            key={item.key}
+            style={{
+                layout: Layout::flex().row(),
+                width: Length::percent(100.0),
+                padding: theme.component.input.padding,
+                background: if item.disabled {
+                    theme.component.select.option_disabled_background.clone()
+                } else if item.selected {
+                    theme.component.select.option_selected_background.clone()
+                } else {
+                    Box::new(Color::transparent()) as Box<dyn ColorLike>
+                },
+                border: option_border,
+                hover: {
+                    background: theme.component.select.option_hover_background.clone(),
+                }
+            }}
+            on_pointer_down={mouse_down}
+            on_click={click}
+        >
+            {content}
+        </Element>
+    }
+}
+
+/// Non-selectable title above a run of options in the same group.
+fn build_group_header(title: &str, first_key: usize, theme: &Theme) -> RsxNode {
+    let mouse_down = PointerDownHandlerProp::new(move |event| {
+        event.meta.suppress_focus_change();
+        event.meta.stop_propagation();
+    });
+    rsx! {
+        <Element
+            key={("group", first_key)}
+            style={{
+                width: Length::percent(100.0),
+                padding: theme.component.input.padding,
+                font_size: theme.typography.size.xs,
+                color: theme.color.text.secondary.clone(),
+            }}
+            on_pointer_down={mouse_down}
+        >
+            {title.to_string()}
+        </Element>
+    }
+}
+
 fn resolve_option_text<DataType>(
     data: &[DataType],
     selected_index: usize,
@@ -1,4 +1,4 @@
-use crate::use_theme;
+use crate::{ArrowAxis, KeyActivation, on_key_activation, use_theme};
 
 use rfgui::style::flex;
 use rfgui::style::{
@@ -9,7 +9,7 @@ use rfgui::ui::{
     Binding, RsxComponent, RsxNode, on_pointer_down, on_pointer_move, on_pointer_up, props, rsx,
     use_state,
 };
-use rfgui::view::{Element, Text};
+use rfgui::view::{Element, ElementStylePropSchema, Text, with_root_class_and_style};
 
 pub struct Slider;
 
@@ -23,6 +23,10 @@ pub struct SliderProps {
     pub option_count: Option<usize>,
     pub disabled: Option<bool>,
     pub label: Option<String>,
+    /// Stacked over the style of the row holding track and label.
+    pub style: Option<ElementStylePropSchema>,
+    /// [`StyleClasses`](rfgui::view::StyleClasses) names, stacked beneath `style`.
+    pub class: Option<String>,
 }
 
 impl RsxComponent<SliderProps> for Slider {
@@ -37,6 +41,8 @@ impl RsxComponent<SliderProps> for Slider {
         let step_count = resolve_option_count(min, max, props.option_count);
         let disabled = props.disabled.unwrap_or(false);
         let label = props.label;
+        let style = props.style;
+        let class = props.class;
         let theme = use_theme().0;
         let slider_theme = &theme.component.slider;
         let height = slider_theme.height.max(1.0);
@@ -118,7 +124,28 @@ impl RsxComponent<SliderProps> for Slider {
             }))
         };
 
-        rsx! {
+        let key_down = if disabled {
+            None
+        } else {
+            let binding = value_binding.clone();
+            Some(on_key_activation(
+                Some(ArrowAxis::Horizontal),
+                move |activation, _event| {
+                    let value = binding.get().clamp(min, max);
+                    let next = match activation {
+                        KeyActivation::Activate => return false,
+                        KeyActivation::Previous => step_value(value, min, max, step_count, -1),
+                        KeyActivation::Next => step_value(value, min, max, step_count, 1),
+                        KeyActivation::First => min,
+                        KeyActivation::Last => max,
+                    };
+                    binding.set(next);
+                    true
+                },
+            ))
+        };
+
+        let root = rsx! {
             <Element style={{
                 layout: Layout::flex().row().align(Align::Center),
                 width: Length::percent(100.0),
@@ -153,6 +180,7 @@ impl RsxComponent<SliderProps> for Slider {
                 on_pointer_down={mouse_down}
                 on_pointer_move={mouse_move}
                 on_pointer_up={mouse_up}
+                on_key_down={key_down}
                 >
                     <Element style={{
                         position: Position::absolute()
@@ -198,7 +226,8 @@ impl RsxComponent<SliderProps> for Slider {
                     text_wrap: TextWrap::NoWrap,
                 }}>{label.unwrap_or_default()}</Element>
             </Element>
-        }
+        };
+        with_root_class_and_style(root, class.as_deref(), style)
     }
 }
 
@@ -236,6 +265,18 @@ fn value_ratio(value: f64, min: f64, max: f64) -> f64 {
     ((value - min) / (max - min)).clamp(0.0, 1.0)
 }
 
+/// Snap `value` to the nearest option and move `delta` options from it,
+/// stopping at either end.
+fn step_value(value: f64, min: f64, max: f64, step_count: usize, delta: i32) -> f64 {
+    if step_count <= 1 || (max - min).abs() <= f64::EPSILON {
+        return min;
+    }
+
+    let last_index = (step_count - 1) as f64;
+    let index = (value_ratio(value, min, max) * last_index).round() + f64::from(delta);
+    min + (max - min) * (index.clamp(0.0, last_index) / last_index)
+}
+
 fn value_from_drag_position(
     local_x: f32,
     target_width: f32,
@@ -1,13 +1,145 @@
-use crate::use_theme;
+use crate::{SwitchSizeSpec, Theme, on_activate, play_sound, use_theme};
 use rfgui::style::{
-    Align, ColorLike, Layout, Length, Operator, Transition, TransitionProperty, darken_color,
+    Align, ColorLike, Cursor, Layout, Length, Opacity, Operator, Transition, TransitionProperty,
+    darken_color,
 };
 use rfgui::ui::{
     Binding, PointerEnterHandlerProp, PointerLeaveHandlerProp, RsxComponent, RsxNode, on_click,
-    props, rsx, use_state,
+    props, rsx, use_interval, use_state,
 };
-use rfgui::view::{Element, Text};
+use rfgui::view::{Element, ElementStylePropSchema, Text, with_root_class_and_style};
+use std::cell::RefCell;
+use std::future::Future;
+use std::pin::Pin;
 use std::rc::Rc;
+use std::task::{Context, Poll, Waker};
+use std::time::Duration;
+
+/// Which side of the track the label sits on.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub enum SwitchLabelPlacement {
+    Start,
+    #[default]
+    End,
+}
+
+impl From<&str> for SwitchLabelPlacement {
+    fn from(value: &str) -> Self {
+        match value.trim().to_ascii_lowercase().as_str() {
+            "start" => SwitchLabelPlacement::Start,
+            "end" => SwitchLabelPlacement::End,
+            other => panic!("rsx build error on <Switch>. unknown label placement `{other}`"),
+        }
+    }
+}
+
+impl From<String> for SwitchLabelPlacement {
+    fn from(value: String) -> Self {
+        SwitchLabelPlacement::from(value.as_str())
+    }
+}
+
+impl rfgui::ui::IntoOptionalProp<SwitchLabelPlacement> for &str {
+    fn into_optional_prop(self) -> Option<SwitchLabelPlacement> {
+        Some(SwitchLabelPlacement::from(self))
+    }
+}
+
+impl rfgui::ui::IntoOptionalProp<SwitchLabelPlacement> for String {
+    fn into_optional_prop(self) -> Option<SwitchLabelPlacement> {
+        Some(SwitchLabelPlacement::from(self))
+    }
+}
+
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub enum SwitchSize {
+    Small,
+    #[default]
+    Medium,
+    Large,
+}
+
+impl From<&str> for SwitchSize {
+    fn from(value: &str) -> Self {
+        match value.trim().to_ascii_lowercase().as_str() {
+            "small" => SwitchSize::Small,
+            "medium" => SwitchSize::Medium,
+            "large" => SwitchSize::Large,
+            other => panic!("rsx build error on <Switch>. unknown Switch size `{other}`"),
+        }
+    }
+}
+
+impl From<String> for SwitchSize {
+    fn from(value: String) -> Self {
+        SwitchSize::from(value.as_str())
+    }
+}
+
+impl rfgui::ui::IntoOptionalProp<SwitchSize> for &str {
+    fn into_optional_prop(self) -> Option<SwitchSize> {
+        Some(SwitchSize::from(self))
+    }
+}
+
+impl rfgui::ui::IntoOptionalProp<SwitchSize> for String {
+    fn into_optional_prop(self) -> Option<SwitchSize> {
+        Some(SwitchSize::from(self))
+    }
+}
+
+fn size_spec(theme: &Theme, size: SwitchSize) -> SwitchSizeSpec {
+    let sizes = &theme.component.switch.size;
+    match size {
+        SwitchSize::Small => sizes.small.clone(),
+        SwitchSize::Medium => sizes.medium.clone(),
+        SwitchSize::Large => sizes.large.clone(),
+    }
+}
+
+/// Asks whether a toggle may stand. Called with the requested value; the
+/// switch shows it as pending until the future resolves and flips back on
+/// `false`.
+pub type SwitchBeforeChange = Rc<dyn Fn(bool) -> SwitchConfirmFuture>;
+
+pub type SwitchConfirmFuture = Pin<Box<dyn Future<Output = bool>>>;
+
+/// How often a pending `before_change` future is polled. There is no
+/// executor behind components, so the switch drives the future itself.
+const CONFIRM_POLL_INTERVAL: Duration = Duration::from_millis(16);
+
+/// The in-flight `before_change` future, shared across renders.
+#[derive(Clone, Default)]
+struct PendingConfirm(Rc<RefCell<Option<SwitchConfirmFuture>>>);
+
+impl PartialEq for PendingConfirm {
+    fn eq(&self, other: &Self) -> bool {
+        Rc::ptr_eq(&self.0, &other.0)
+    }
+}
+
+impl PendingConfirm {
+    fn start(&self, future: SwitchConfirmFuture) -> Option<bool> {
+        *self.0.borrow_mut() = Some(future);
+        self.poll()
+    }
+
+    /// The future's answer once it is ready; clears the slot.
+    fn poll(&self) -> Option<bool> {
+        let mut slot = self.0.borrow_mut();
+        let future = slot.as_mut()?;
+        match future
+            .as_mut()
+            .poll(&mut Context::from_waker(Waker::noop()))
+        {
+            Poll::Ready(accepted) => {
+                *slot = None;
+                Some(accepted)
+            }
+            Poll::Pending => None,
+        }
+    }
+}
 
 pub struct Switch;
 
@@ -18,7 +150,14 @@ pub struct SwitchProps {
     pub binding: Option<Binding<bool>>,
     pub checked: Option<bool>,
     pub disabled: Option<bool>,
+    pub size: Option<SwitchSize>,
+    pub label_placement: Option<SwitchLabelPlacement>,
     pub on_change: Option<Rc<dyn Fn(bool)>>,
+    pub before_change: Option<SwitchBeforeChange>,
+    /// Stacked over the style of the row holding track and label.
+    pub style: Option<ElementStylePropSchema>,
+    /// [`StyleClasses`](rfgui::view::StyleClasses) names, stacked beneath `style`.
+    pub class: Option<String>,
 }
 
 impl RsxComponent<SwitchProps> for Switch {
@@ -27,21 +166,25 @@ impl RsxComponent<SwitchProps> for Switch {
         let has_binding = props.binding.is_some();
         let binding = props.binding.unwrap_or_else(|| Binding::new(checked));
         let disabled = props.disabled.unwrap_or(false);
+        let label_placement = props.label_placement.unwrap_or_default();
         let label = props.label;
+        let style = props.style;
+        let class = props.class;
         let theme = use_theme().0;
         let switch_theme = &theme.component.switch;
+        let spec = size_spec(&theme, props.size.unwrap_or_default());
         let thumb_travel = Length::calc(
             Length::calc(
                 Length::calc(
-                    switch_theme.track_width,
+                    spec.track_width,
                     Operator::subtract,
-                    switch_theme.track_padding.left,
+                    spec.track_padding.left,
                 ),
                 Operator::subtract,
-                switch_theme.track_padding.right,
+                spec.track_padding.right,
             ),
             Operator::subtract,
-            switch_theme.thumb_width,
+            spec.thumb_width,
         );
         let fallback_checked = use_state(|| checked);
         let checked_binding = if has_binding {
@@ -51,18 +194,61 @@ impl RsxComponent<SwitchProps> for Switch {
         };
         let checked = checked_binding.get();
 
+        // Requested value while `before_change` has not answered yet.
+        let pending_state = use_state(|| None::<bool>);
+        let pending_confirm = use_state(PendingConfirm::default).get();
+        let pending = pending_state.get().is_some();
         let on_change = props.on_change;
+        let resolve: Rc<dyn Fn(bool, bool)> = {
+            let checked_binding = checked_binding.clone();
+            let pending_state = pending_state.clone();
+            let sound = theme.sound.clone();
+            Rc::new(move |requested, accepted| {
+                pending_state.set(None);
+                if !accepted {
+                    checked_binding.set(!requested);
+                    play_sound(sound.error.as_deref());
+                    return;
+                }
+                play_sound(sound.toggle.as_deref());
+                if let Some(cb) = on_change.as_ref() {
+                    cb(requested);
+                }
+            })
+        };
+        {
+            let pending_state = pending_state.clone();
+            let pending_confirm = pending_confirm.clone();
+            let resolve = resolve.clone();
+            use_interval(pending, CONFIRM_POLL_INTERVAL, move || {
+                let Some(requested) = pending_state.get() else {
+                    return;
+                };
+                if let Some(accepted) = pending_confirm.poll() {
+                    resolve(requested, accepted);
+                }
+            });
+        }
+
+        let before_change = props.before_change;
         let click = on_click(move |_event| {
-            if disabled {
+            if disabled || pending_state.get().is_some() {
                 return;
             }
             let next = !checked_binding.get();
             checked_binding.set(next);
-            if let Some(cb) = on_change.as_ref() {
-                cb(next);
+            let Some(before_change) = before_change.as_ref() else {
+                resolve(next, true);
+                return;
+            };
+            match pending_confirm.start(before_change(next)) {
+                Some(accepted) => resolve(next, accepted),
+                None => pending_state.set(Some(next)),
             }
         });
 
+        let key_down = (!disabled).then(|| on_activate(click.clone()));
+
         let hover_state = use_state(|| false);
         let hover_state_for_enter = hover_state.clone();
         let hover_state_for_leave = hover_state.clone();
@@ -79,7 +265,7 @@ impl RsxComponent<SwitchProps> for Switch {
         } else {
             theme.color.border.clone()
         };
-        let track_background: Box<dyn ColorLike> = if !disabled && hovered {
+        let track_background: Box<dyn ColorLike> = if !disabled && !pending && hovered {
             let amount = if checked {
                 theme.color.state.hover_darken
             } else {
@@ -89,58 +275,81 @@ impl RsxComponent<SwitchProps> for Switch {
         } else {
             track_base
         };
+        let cursor = if disabled {
+            Cursor::NotAllowed
+        } else if pending {
+            Cursor::Progress
+        } else {
+            Cursor::Pointer
+        };
 
-        rsx! {
+        let track = rsx! {
+            <Element style={{
+                layout: Layout::flow().row().align(Align::Center).no_wrap(),
+                width: spec.track_width,
+                height: spec.track_height,
+                padding: spec.track_padding,
+                border_radius: spec.track_radius,
+                transition: [
+                    Transition::new(
+                        TransitionProperty::BackgroundColor,
+                        theme.motion.duration.normal,
+                    )
+                    .ease_in_out(),
+                ],
+                background: track_background,
+            }}
+            >
+                <Element style={{
+                    width: if checked { thumb_travel } else { Length::Zero },
+                    height: spec.thumb_height,
+                    transition: [
+                        Transition::new(TransitionProperty::Width, 180).ease_in_out(),
+                    ],
+                }} />
+                <Element style={{
+                    width: spec.thumb_width,
+                    height: spec.thumb_height,
+                    border_radius: spec.thumb_radius,
+                    opacity: Opacity::new(if pending { switch_theme.pending_thumb_opacity } else { 1.0 }),
+                    background: if disabled {
+                        theme.color.layer.raised.clone()
+                    } else {
+                        theme.color.layer.surface.clone()
+                    },
+                }} />
+            </Element>
+        };
+        let label = rsx! {
+            <Text
+                font_size={spec.label_font_size}
+                style={{ color: if disabled { theme.color.text.disabled.clone() } else { theme.color.text.primary.clone() } }}
+            >
+                {label}
+            </Text>
+        };
+        let (first, second) = match label_placement {
+            SwitchLabelPlacement::Start => (label, track),
+            SwitchLabelPlacement::End => (track, label),
+        };
+
+        let root = rsx! {
             <Element style={{
                 layout: Layout::flow().row().align(Align::Center).no_wrap(),
                 gap: theme.spacing.md,
+                cursor: cursor,
+                opacity: Opacity::new(if disabled { switch_theme.disabled_opacity } else { 1.0 }),
             }}
                 on_click={click}
+                on_key_down={key_down}
                 on_pointer_enter={on_pointer_enter}
                 on_pointer_leave={on_pointer_leave}
             >
-                <Element style={{
-                    layout: Layout::flow().row().align(Align::Center).no_wrap(),
-                    width: switch_theme.track_width,
-                    height: switch_theme.track_height,
-                    padding: switch_theme.track_padding,
-                    border_radius: switch_theme.track_radius,
-                    transition: [
-                        Transition::new(
-                            TransitionProperty::BackgroundColor,
-                            theme.motion.duration.normal,
-                        )
-                        .ease_in_out(),
-                    ],
-                    background: track_background,
-                }}
-                >
-                    <Element style={{
-                        width: if checked { thumb_travel } else { Length::Zero },
-                        height: switch_theme.thumb_height,
-                        transition: [
-                            Transition::new(TransitionProperty::Width, 180).ease_in_out(),
-                        ],
-                    }} />
-                    <Element style={{
-                        width: switch_theme.thumb_width,
-                        height: switch_theme.thumb_height,
-                        border_radius: switch_theme.thumb_radius,
-                        background: if disabled {
-                            theme.color.layer.raised.clone()
-                        } else {
-                            theme.color.layer.surface.clone()
-                        },
-                    }} />
-                </Element>
-                <Text
-                    font_size={theme.typography.size.sm}
-                    style={{ color: if disabled { theme.color.text.disabled.clone() } else { theme.color.text.primary.clone() } }}
-                >
-                    {label}
-                </Text>
+                {first}
+                {second}
             </Element>
-        }
+        };
+        with_root_class_and_style(root, class.as_deref(), style)
     }
 }
 
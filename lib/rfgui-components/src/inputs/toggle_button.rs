@@ -1,6 +1,6 @@
 use crate::inputs::button::{ButtonColor, ButtonSize, resolve_color_set, size_spec};
 use crate::inputs::toggle_button_group::ToggleButtonGroupContext;
-use crate::use_theme;
+use crate::{on_activate, use_theme};
 use rfgui::style::{
     Align, Border, Color, ColorLike, Cursor, JustifyContent, Layout, Length, Transition,
     TransitionProperty, Transitions,
@@ -8,7 +8,7 @@ use rfgui::style::{
 use rfgui::ui::{
     ClickEvent, ClickHandlerProp, RsxComponent, RsxNode, component, props, rsx, use_context,
 };
-use rfgui::view::Element;
+use rfgui::view::{Element, ElementStylePropSchema, RootStyleProps};
 
 pub struct ToggleButton;
 
@@ -21,6 +21,10 @@ pub struct ToggleButtonProps {
     pub color: Option<ButtonColor>,
     pub disabled: Option<bool>,
     pub on_click: Option<ClickHandlerProp>,
+    /// Stacked over the button's own style, so set fields win.
+    pub style: Option<ElementStylePropSchema>,
+    /// [`StyleClasses`](rfgui::view::StyleClasses) names, stacked beneath `style`.
+    pub class: Option<String>,
 }
 
 impl RsxComponent<ToggleButtonProps> for ToggleButton {
@@ -29,10 +33,13 @@ impl RsxComponent<ToggleButtonProps> for ToggleButton {
             <ToggleButtonView
                 value={props.value}
                 selected={props.selected}
-                size={props.size}
-                color={props.color}
-                disabled={props.disabled}
+                fallback={GroupFallbackProps {
+                    size: props.size,
+                    color: props.color,
+                    disabled: props.disabled,
+                }}
                 on_click={props.on_click}
+                root_style={RootStyleProps::new(props.style, props.class)}
             >
                 {children}
             </ToggleButtonView>
@@ -64,18 +71,30 @@ fn resolve(color: &dyn ColorLike) -> Color {
     Color::rgba(r, g, b, a)
 }
 
+/// Props that fall back to the enclosing group's when unset.
+#[derive(Clone)]
+struct GroupFallbackProps {
+    size: Option<ButtonSize>,
+    color: Option<ButtonColor>,
+    disabled: Option<bool>,
+}
+
 #[component]
 fn ToggleButtonView(
     value: Option<String>,
     selected: Option<bool>,
-    size: Option<ButtonSize>,
-    color: Option<ButtonColor>,
-    disabled: Option<bool>,
+    fallback: GroupFallbackProps,
     on_click: Option<ClickHandlerProp>,
+    root_style: RootStyleProps,
     children: Vec<RsxNode>,
 ) -> RsxNode {
     let theme = use_theme().0;
     let group_ctx = use_context::<ToggleButtonGroupContext>();
+    let GroupFallbackProps {
+        size,
+        color,
+        disabled,
+    } = fallback;
 
     // Group overrides: selected derived from group binding, click wired to
     // group on_change + binding set, size/color/disabled fall back to group.
@@ -174,7 +193,9 @@ fn ToggleButtonView(
         Some(theme.component.button.toggle_button_radius)
     };
 
-    rsx! {
+    let key_down = on_click.clone().filter(|_| !disabled).map(on_activate);
+
+    let root = rsx! {
         <Element
             style={{
                 layout: Layout::flow()
@@ -198,9 +219,11 @@ fn ToggleButtonView(
                     background: resolve(hover_background.as_ref()),
                 },
             }}
+            on_key_down={key_down}
             on_click={if !disabled { on_click } else { None }}
         >
             {children}
         </Element>
-    }
+    };
+    root_style.apply(root)
 }
@@ -6,7 +6,7 @@ use rfgui::ui::{
     Binding, ClickEvent, IntoOptionalProp, Provider, RsxComponent, RsxNode, RsxTag, component,
     global_state, props, rsx,
 };
-use rfgui::view::Element;
+use rfgui::view::{Element, ElementStylePropSchema, RootStyleProps};
 use std::any::TypeId;
 use std::rc::Rc;
 
@@ -74,6 +74,10 @@ pub struct ToggleButtonGroupProps {
     pub size: Option<ButtonSize>,
     pub color: Option<ButtonColor>,
     pub disabled: Option<bool>,
+    /// Stacked over the style of the bordered group container.
+    pub style: Option<ElementStylePropSchema>,
+    /// [`StyleClasses`](rfgui::view::StyleClasses) names, stacked beneath `style`.
+    pub class: Option<String>,
 }
 
 impl RsxComponent<ToggleButtonGroupProps> for ToggleButtonGroup {
@@ -87,6 +91,7 @@ impl RsxComponent<ToggleButtonGroupProps> for ToggleButtonGroup {
                 size={props.size}
                 color={props.color}
                 disabled={props.disabled}
+                root_style={RootStyleProps::new(props.style, props.class)}
             >
                 {children}
             </ToggleButtonGroupView>
@@ -130,6 +135,7 @@ fn ToggleButtonGroupView(
     size: Option<ButtonSize>,
     color: Option<ButtonColor>,
     disabled: Option<bool>,
+    root_style: RootStyleProps,
     children: Vec<RsxNode>,
 ) -> RsxNode {
     let theme = use_theme().0;
@@ -184,17 +190,22 @@ fn ToggleButtonGroupView(
     let border = Border::uniform(Length::px(1.0), border_color.as_ref());
     let radius = theme.component.button.toggle_button_radius;
 
+    let group = rsx! {
+        <Element style={{
+            width: width,
+            layout: layout,
+            border: border,
+            border_radius: radius,
+            position: Position::static_().clip(ClipMode::Parent),
+        }}>
+            {interleaved}
+        </Element>
+    };
+    let group = root_style.apply(group);
+
     rsx! {
         <Provider::<ToggleButtonGroupContext> value={ctx}>
-            <Element style={{
-                width: width,
-                layout: layout,
-                border: border,
-                border_radius: radius,
-                position: Position::static_().clip(ClipMode::Parent),
-            }}>
-                {interleaved}
-            </Element>
+            {group}
         </Provider>
     }
 }
@@ -6,7 +6,7 @@ use rfgui::style::{
     TransitionProperty,
 };
 use rfgui::ui::{Binding, RsxComponent, RsxNode, component, on_click, props, rsx, use_state};
-use rfgui::view::Element;
+use rfgui::view::{Element, ElementStylePropSchema, RootStyleProps};
 
 pub struct Accordion;
 
@@ -17,6 +17,10 @@ pub struct AccordionProps {
     pub default_expanded: Option<bool>,
     pub expanded_binding: Option<Binding<bool>>,
     pub disabled: Option<bool>,
+    /// Stacked over the style of the bordered accordion container.
+    pub style: Option<ElementStylePropSchema>,
+    /// [`StyleClasses`](rfgui::view::StyleClasses) names, stacked beneath `style`.
+    pub class: Option<String>,
 }
 
 impl RsxComponent<AccordionProps> for Accordion {
@@ -27,6 +31,7 @@ impl RsxComponent<AccordionProps> for Accordion {
                 default_expanded={props.default_expanded.unwrap_or(false)}
                 expanded_binding={props.expanded_binding}
                 disabled={props.disabled.unwrap_or(false)}
+                root_style={RootStyleProps::new(props.style, props.class)}
             >
                 {children}
             </AccordionView>
@@ -59,6 +64,7 @@ fn AccordionView(
     default_expanded: bool,
     expanded_binding: Option<Binding<bool>>,
     disabled: bool,
+    root_style: RootStyleProps,
     children: Vec<RsxNode>,
 ) -> RsxNode {
     let theme = use_theme().0;
@@ -78,7 +84,7 @@ fn AccordionView(
 
     let content_border = Border::uniform(Length::px(0.0), theme.color.border.as_ref())
         .top(Some(Length::px(1.0)), Some(theme.color.border.as_ref()));
-    rsx! {
+    let root = rsx! {
         <Element
             style={{
                 width: Length::percent(100.0),
@@ -186,5 +192,6 @@ fn AccordionView(
                 </Element>
             </Element>
         </Element>
-    }
+    };
+    root_style.apply(root)
 }
@@ -0,0 +1,104 @@
+use crate::use_theme;
+use rfgui::style::{Layout, Transition, TransitionProperty, TransitionTiming, Transitions};
+use rfgui::ui::{RsxComponent, RsxKey, RsxNode, props, rsx};
+use rfgui::view::{Element, ElementStylePropSchema};
+
+/// Keyed list container that animates reordering (FLIP).
+///
+/// Every child is wrapped in an item element that takes over the child's
+/// key and carries a `position` transition. When keyed children reorder,
+/// the layout pass captures each item's previous rect, offsets it back to
+/// where it was drawn, and plays the delta out to the new slot — so
+/// sorting and drag-reorder slide instead of snapping. Unkeyed children
+/// fall back to their index and therefore keep their slot on reorder.
+pub struct AnimatedList;
+
+#[derive(Clone)]
+#[props]
+pub struct AnimatedListProps {
+    pub style: Option<ElementStylePropSchema>,
+    pub item_style: Option<ElementStylePropSchema>,
+    pub duration: Option<u32>,
+    pub timing: Option<TransitionTiming>,
+}
+
+impl RsxComponent<AnimatedListProps> for AnimatedList {
+    fn render(props: AnimatedListProps, children: Vec<RsxNode>) -> RsxNode {
+        let theme = use_theme().0;
+        let move_transition = Transition::new(
+            TransitionProperty::Position,
+            props.duration.unwrap_or(theme.motion.duration.normal),
+        )
+        .timing(props.timing.unwrap_or(theme.motion.easing.standard));
+
+        let mut style = props.style.unwrap_or_default();
+        if style.layout.is_none() {
+            style.layout = Some(Layout::flow().column().no_wrap().into());
+        }
+        let item_style = animated_list_item_style(props.item_style, move_transition);
+
+        let items = children
+            .into_iter()
+            .enumerate()
+            .map(|(index, child)| {
+                let key = child.identity().key.unwrap_or(RsxKey::Local(index as u64));
+                let item = rsx! {
+                    <Element style={item_style.clone()}>
+                        {child}
+                    </Element>
+                };
+                item.with_key(key)
+            })
+            .collect::<Vec<_>>();
+
+        rsx! {
+            <Element style={style}>
+                {items}
+            </Element>
+        }
+    }
+}
+
+#[rfgui::ui::component]
+impl rfgui::ui::RsxTag for AnimatedList {
+    type Props = __AnimatedListPropsInit;
+    type StrictProps = AnimatedListProps;
+    const ACCEPTS_CHILDREN: bool = true;
+
+    fn into_strict(props: Self::Props) -> Self::StrictProps {
+        props.into()
+    }
+
+    fn create_node(
+        props: Self::StrictProps,
+        children: Vec<RsxNode>,
+        _key: Option<RsxKey>,
+    ) -> RsxNode {
+        <Self as RsxComponent<AnimatedListProps>>::render(props, children)
+    }
+}
+
+fn animated_list_item_style(
+    style: Option<ElementStylePropSchema>,
+    move_transition: Transition,
+) -> ElementStylePropSchema {
+    let mut style = style.unwrap_or_default();
+    let mut transitions = style
+        .transition
+        .take()
+        .map(Transitions::into_vec)
+        .unwrap_or_default();
+    transitions.retain(|transition| {
+        !matches!(
+            transition.property,
+            TransitionProperty::Position
+                | TransitionProperty::PositionX
+                | TransitionProperty::PositionY
+                | TransitionProperty::X
+                | TransitionProperty::Y
+        )
+    });
+    transitions.push(move_transition);
+    style.transition = Some(transitions.into());
+    style
+}
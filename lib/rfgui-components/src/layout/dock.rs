@@ -0,0 +1,803 @@
+//! Dockable panel layout.
+//!
+//! A [`DockSpace`] lays panels out from a [`DockLayout`] tree: splits divide
+//! their area between two children, and each leaf is a group of tabbed
+//! panels shown in a maximized [`Window`]. Dragging a tab, or a group's
+//! title bar, over another group offers its edges as split targets and its
+//! middle as a tab target; dropping rewrites the layout binding. The tree
+//! round-trips through [`DockLayout::to_text`] and [`DockLayout::parse`] so
+//! an app can persist its panel arrangement.
+
+use std::iter::Peekable;
+use std::str::Chars;
+
+use super::window::TITLE_BAR_HEIGHT;
+use crate::{Theme, Window, WindowContentStyleSlot, WindowSlotsProp, WindowState, use_theme};
+use rfgui::style::{Align, Anchor, Color, ColorLike, Cursor, Layout, Length, Padding, Position};
+use rfgui::ui::{
+    Binding, PointerButton, RsxComponent, RsxNode, component, on_pointer_down, on_pointer_leave,
+    on_pointer_move, props, rsx, use_state, use_viewport_pointer_move, use_viewport_pointer_up,
+};
+use rfgui::view::{Element, ElementStylePropSchema, RootStyleProps, Text};
+
+/// First line of every saved dock layout; bumped when the line format
+/// changes.
+pub const DOCK_LAYOUT_HEADER: &str = "rfgui-dock-layout 1";
+
+/// Share of a group's width or height, from each edge, that docks beside
+/// the group instead of into its tabs.
+const DOCK_EDGE_ZONE: f32 = 0.25;
+/// Pointer travel before a pressed tab starts dragging.
+const DRAG_THRESHOLD: f32 = 4.0;
+
+/// Direction a split lays its two children out in.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum DockAxis {
+    /// Side by side.
+    Row,
+    /// One above the other.
+    Column,
+}
+
+/// Where a panel lands relative to the group it is dropped on.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum DockPosition {
+    Left,
+    Right,
+    Top,
+    Bottom,
+    /// Joins the group as a tab.
+    Tab,
+}
+
+/// Node of a [`DockLayout`] tree. Panels are referred to by id; their
+/// titles and content come from [`DockSpaceProps::panels`].
+#[derive(Clone, Debug, PartialEq)]
+pub enum DockNode {
+    Split {
+        axis: DockAxis,
+        /// Share of the split taken by `first`, between 0 and 1.
+        ratio: f32,
+        first: Box<DockNode>,
+        second: Box<DockNode>,
+    },
+    Tabs {
+        panels: Vec<String>,
+        active: usize,
+    },
+}
+
+impl DockNode {
+    pub fn tabs<I, S>(panels: I) -> Self
+    where
+        I: IntoIterator<Item = S>,
+        S: Into<String>,
+    {
+        Self::Tabs {
+            panels: panels.into_iter().map(Into::into).collect(),
+            active: 0,
+        }
+    }
+
+    pub fn split(axis: DockAxis, ratio: f32, first: DockNode, second: DockNode) -> Self {
+        Self::Split {
+            axis,
+            ratio: ratio.clamp(0.0, 1.0),
+            first: Box::new(first),
+            second: Box::new(second),
+        }
+    }
+
+    fn group_of(&mut self, panel: &str) -> Option<&mut DockNode> {
+        match self {
+            Self::Split { first, second, .. } => {
+                first.group_of(panel).or_else(|| second.group_of(panel))
+            }
+            Self::Tabs { panels, .. } => panels.iter().any(|id| id == panel).then_some(self),
+        }
+    }
+
+    fn collect_panels<'a>(&'a self, out: &mut Vec<&'a str>) {
+        match self {
+            Self::Split { first, second, .. } => {
+                first.collect_panels(out);
+                second.collect_panels(out);
+            }
+            Self::Tabs { panels, .. } => out.extend(panels.iter().map(String::as_str)),
+        }
+    }
+
+    /// Drop `panel`, returning `None` once nothing is left. Splits that lose
+    /// a side collapse into the other.
+    fn without(self, panel: &str) -> Option<DockNode> {
+        match self {
+            Self::Split {
+                axis,
+                ratio,
+                first,
+                second,
+            } => match (first.without(panel), second.without(panel)) {
+                (Some(first), Some(second)) => Some(Self::split(axis, ratio, first, second)),
+                (Some(node), None) | (None, Some(node)) => Some(node),
+                (None, None) => None,
+            },
+            Self::Tabs {
+                mut panels,
+                mut active,
+            } => {
+                if let Some(index) = panels.iter().position(|id| id == panel) {
+                    panels.remove(index);
+                    if index < active || active >= panels.len() {
+                        active = active.saturating_sub(1);
+                    }
+                }
+                (!panels.is_empty()).then_some(Self::Tabs { panels, active })
+            }
+        }
+    }
+}
+
+/// Arrangement of docked panels. Empty by default.
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct DockLayout {
+    pub root: Option<DockNode>,
+}
+
+impl DockLayout {
+    pub fn new(root: DockNode) -> Self {
+        Self { root: Some(root) }
+    }
+
+    /// Panel ids in layout order.
+    pub fn panels(&self) -> Vec<&str> {
+        let mut panels = Vec::new();
+        if let Some(root) = &self.root {
+            root.collect_panels(&mut panels);
+        }
+        panels
+    }
+
+    pub fn contains(&self, panel: &str) -> bool {
+        self.panels().contains(&panel)
+    }
+
+    /// Take `panel` out of the layout. Returns `false` when it was not
+    /// docked.
+    pub fn remove(&mut self, panel: &str) -> bool {
+        if !self.contains(panel) {
+            return false;
+        }
+        self.root = self.root.take().and_then(|root| root.without(panel));
+        true
+    }
+
+    /// Make `panel` the visible tab of its group.
+    pub fn activate(&mut self, panel: &str) {
+        if let Some(DockNode::Tabs { panels, active }) =
+            self.root.as_mut().and_then(|root| root.group_of(panel))
+        {
+            *active = panels.iter().position(|id| id == panel).unwrap_or(*active);
+        }
+    }
+
+    /// Move `panel` beside, or into, the group holding `target`. A panel
+    /// not docked yet is added; into an empty layout it becomes the only
+    /// group. Returns `false` when `target` is not docked or the move would
+    /// leave the layout as it was.
+    pub fn dock(&mut self, panel: &str, target: &str, position: DockPosition) -> bool {
+        let Some(root) = self.root.as_mut() else {
+            self.root = Some(DockNode::tabs([panel]));
+            return true;
+        };
+        let Some(DockNode::Tabs { panels, .. }) = root.group_of(target) else {
+            return false;
+        };
+        // Anchor on a group member that stays put, so docking a panel beside
+        // its own group still finds the group after the panel leaves it.
+        let Some(anchor) = panels.iter().find(|id| *id != panel).cloned() else {
+            return false;
+        };
+        if position == DockPosition::Tab && panels.iter().any(|id| id == panel) {
+            self.activate(panel);
+            return true;
+        }
+        self.remove(panel);
+        let Some(group) = self.root.as_mut().and_then(|root| root.group_of(&anchor)) else {
+            return false;
+        };
+        let docked = DockNode::tabs([panel]);
+        *group = match (position, group.clone()) {
+            (DockPosition::Tab, DockNode::Tabs { mut panels, .. }) => {
+                panels.push(panel.to_string());
+                DockNode::Tabs {
+                    active: panels.len() - 1,
+                    panels,
+                }
+            }
+            (DockPosition::Left, existing) => DockNode::split(DockAxis::Row, 0.5, docked, existing),
+            (DockPosition::Right, existing) => {
+                DockNode::split(DockAxis::Row, 0.5, existing, docked)
+            }
+            (DockPosition::Top, existing) => {
+                DockNode::split(DockAxis::Column, 0.5, docked, existing)
+            }
+            (_, existing) => DockNode::split(DockAxis::Column, 0.5, existing, docked),
+        };
+        true
+    }
+
+    /// Serialize to the line format [`Self::parse`] reads back: the header,
+    /// then one node per line in pre-order.
+    pub fn to_text(&self) -> String {
+        let mut out = String::from(DOCK_LAYOUT_HEADER);
+        out.push('\n');
+        if let Some(root) = &self.root {
+            write_node(&mut out, root);
+        }
+        out
+    }
+
+    /// Parse the output of [`Self::to_text`]. Blank lines and lines
+    /// starting with `#` are ignored.
+    pub fn parse(text: &str) -> Result<Self, String> {
+        let mut lines = text.lines().enumerate();
+        match lines.next() {
+            Some((_, header)) if header.trim() == DOCK_LAYOUT_HEADER => {}
+            _ => return Err(format!("expected `{DOCK_LAYOUT_HEADER}` header")),
+        }
+        let mut lines = lines
+            .map(|(index, line)| (index + 1, line.trim()))
+            .filter(|(_, line)| !line.is_empty() && !line.starts_with('#'))
+            .peekable();
+        if lines.peek().is_none() {
+            return Ok(Self::default());
+        }
+        let root = parse_node(&mut lines)?;
+        if let Some((number, _)) = lines.next() {
+            return Err(format!("line {number}: unexpected node after the root"));
+        }
+        let layout = Self::new(root);
+        let panels = layout.panels();
+        if let Some(duplicate) = panels
+            .iter()
+            .enumerate()
+            .find_map(|(index, id)| panels[..index].contains(id).then_some(*id))
+        {
+            return Err(format!("panel {duplicate:?} is docked twice"));
+        }
+        Ok(layout)
+    }
+}
+
+fn write_node(out: &mut String, node: &DockNode) {
+    match node {
+        DockNode::Split {
+            axis,
+            ratio,
+            first,
+            second,
+        } => {
+            let axis = match axis {
+                DockAxis::Row => "row",
+                DockAxis::Column => "column",
+            };
+            out.push_str(&format!("split {axis} {ratio}\n"));
+            write_node(out, first);
+            write_node(out, second);
+        }
+        DockNode::Tabs { panels, active } => {
+            out.push_str(&format!("tabs {active}"));
+            for panel in panels {
+                out.push_str(&format!(" {panel:?}"));
+            }
+            out.push('\n');
+        }
+    }
+}
+
+fn parse_node<'a>(
+    lines: &mut Peekable<impl Iterator<Item = (usize, &'a str)>>,
+) -> Result<DockNode, String> {
+    let (number, line) = lines.next().ok_or("missing node")?;
+    let error = |message: String| format!("line {number}: {message}");
+    let (kind, rest) = line.split_once(' ').unwrap_or((line, ""));
+    match kind {
+        "split" => {
+            let mut words = rest.split_whitespace();
+            let axis = match words.next() {
+                Some("row") => DockAxis::Row,
+                Some("column") => DockAxis::Column,
+                other => return Err(error(format!("invalid split axis {other:?}"))),
+            };
+            let ratio = words
+                .next()
+                .and_then(|word| word.parse::<f32>().ok())
+                .filter(|ratio| (0.0..=1.0).contains(ratio))
+                .ok_or_else(|| error("invalid split ratio".to_string()))?;
+            if let Some(extra) = words.next() {
+                return Err(error(format!("unexpected `{extra}`")));
+            }
+            let first = parse_node(lines)?;
+            let second = parse_node(lines)?;
+            Ok(DockNode::split(axis, ratio, first, second))
+        }
+        "tabs" => {
+            let rest = rest.trim_start();
+            let (active, ids) = rest.split_once(' ').unwrap_or((rest, ""));
+            let active: usize = active
+                .parse()
+                .map_err(|_| error(format!("invalid active tab `{active}`")))?;
+            let panels = quoted_ids(ids).map_err(error)?;
+            if panels.is_empty() {
+                return Err(error("tab group without panels".to_string()));
+            }
+            if active >= panels.len() {
+                return Err(error(format!("active tab {active} out of range")));
+            }
+            Ok(DockNode::Tabs { panels, active })
+        }
+        other => Err(error(format!("unknown node `{other}`"))),
+    }
+}
+
+/// Whitespace-separated `{:?}`-quoted panel ids.
+fn quoted_ids(text: &str) -> Result<Vec<String>, String> {
+    let mut ids = Vec::new();
+    let mut chars = text.chars().peekable();
+    while let Some(ch) = chars.next() {
+        match ch {
+            '"' => ids.push(unescape(&mut chars)?),
+            ch if ch.is_whitespace() => {}
+            ch => return Err(format!("expected quoted panel id, found `{ch}`")),
+        }
+    }
+    Ok(ids)
+}
+
+/// Read the rest of a string literal written with `{:?}`; the opening
+/// quote is already consumed.
+fn unescape(chars: &mut Peekable<Chars<'_>>) -> Result<String, String> {
+    let mut text = String::new();
+    loop {
+        match chars.next().ok_or("unterminated string")? {
+            '"' => return Ok(text),
+            '\\' => text.push(match chars.next().ok_or("unterminated escape")? {
+                'n' => '\n',
+                'r' => '\r',
+                't' => '\t',
+                '0' => '\0',
+                '\\' => '\\',
+                '"' => '"',
+                '\'' => '\'',
+                'u' => {
+                    if chars.next() != Some('{') {
+                        return Err("expected `{` after `\\u`".to_string());
+                    }
+                    let mut hex = String::new();
+                    loop {
+                        match chars.next().ok_or("unterminated unicode escape")? {
+                            '}' => break,
+                            digit => hex.push(digit),
+                        }
+                    }
+                    u32::from_str_radix(&hex, 16)
+                        .ok()
+                        .and_then(char::from_u32)
+                        .ok_or_else(|| format!("invalid unicode escape `{hex}`"))?
+                }
+                other => return Err(format!("unknown escape `\\{other}`")),
+            }),
+            ch => text.push(ch),
+        }
+    }
+}
+
+/// Title and content of a panel a [`DockSpace`] can show.
+#[derive(Clone)]
+pub struct DockPanel {
+    pub id: String,
+    pub title: String,
+    pub content: RsxNode,
+}
+
+impl DockPanel {
+    pub fn new(id: impl Into<String>, title: impl Into<String>, content: RsxNode) -> Self {
+        Self {
+            id: id.into(),
+            title: title.into(),
+            content,
+        }
+    }
+}
+
+pub struct DockSpace;
+
+#[derive(Clone)]
+#[props]
+pub struct DockSpaceProps {
+    /// Arrangement to show; drops write the new arrangement back.
+    pub layout: Binding<DockLayout>,
+    /// Panels the layout refers to. Ids the layout does not mention are not
+    /// shown.
+    pub panels: Vec<DockPanel>,
+    /// Stacked over the style of the element filling the dock space.
+    pub style: Option<ElementStylePropSchema>,
+    /// [`StyleClasses`](rfgui::view::StyleClasses) names, stacked beneath `style`.
+    pub class: Option<String>,
+}
+
+impl RsxComponent<DockSpaceProps> for DockSpace {
+    fn render(props: DockSpaceProps, _children: Vec<RsxNode>) -> RsxNode {
+        rsx! {
+            <DockSpaceView
+                layout={props.layout}
+                panels={props.panels}
+                root_style={RootStyleProps::new(props.style, props.class)}
+            />
+        }
+    }
+}
+
+#[rfgui::ui::component]
+impl rfgui::ui::RsxTag for DockSpace {
+    type Props = __DockSpacePropsInit;
+    type StrictProps = DockSpaceProps;
+    const ACCEPTS_CHILDREN: bool = false;
+
+    fn into_strict(props: Self::Props) -> Self::StrictProps {
+        props.into()
+    }
+
+    fn create_node(
+        props: Self::StrictProps,
+        children: Vec<RsxNode>,
+        _key: Option<rfgui::ui::RsxKey>,
+    ) -> RsxNode {
+        <Self as RsxComponent<DockSpaceProps>>::render(props, children)
+    }
+}
+
+/// Panel pressed in a tab or title bar. It becomes a drag once the pointer
+/// travels past [`DRAG_THRESHOLD`]; released before that it is a click that
+/// activates the tab.
+#[derive(Clone, PartialEq)]
+struct DockDrag {
+    panel: String,
+    start: (f32, f32),
+    dragging: bool,
+    /// Panel whose group the pointer is over, and the drop there.
+    target: Option<(String, DockPosition)>,
+}
+
+/// Drop offered at `(x, y)` as fractions of a group's bounds: the nearest
+/// edge within [`DOCK_EDGE_ZONE`], otherwise a tab.
+fn drop_position(x: f32, y: f32) -> DockPosition {
+    [
+        (x, DockPosition::Left),
+        (1.0 - x, DockPosition::Right),
+        (y, DockPosition::Top),
+        (1.0 - y, DockPosition::Bottom),
+    ]
+    .into_iter()
+    .filter(|(distance, _)| *distance < DOCK_EDGE_ZONE)
+    .min_by(|a, b| a.0.total_cmp(&b.0))
+    .map_or(DockPosition::Tab, |(_, position)| position)
+}
+
+#[component]
+fn DockSpaceView(
+    layout: Binding<DockLayout>,
+    panels: Vec<DockPanel>,
+    root_style: RootStyleProps,
+) -> RsxNode {
+    let drag = use_state(|| None::<DockDrag>);
+
+    {
+        let drag = drag.binding();
+        use_viewport_pointer_move(move |event| {
+            let (x, y) = (event.pointer.viewport_x, event.pointer.viewport_y);
+            drag.update(|drag| {
+                if let Some(drag) = drag.as_mut().filter(|drag| !drag.dragging) {
+                    let (start_x, start_y) = drag.start;
+                    drag.dragging = (x - start_x).hypot(y - start_y) > DRAG_THRESHOLD;
+                }
+            });
+        });
+    }
+    {
+        let drag = drag.binding();
+        let layout = layout.clone();
+        use_viewport_pointer_up(move |event| {
+            if event.pointer.button != Some(PointerButton::Left) {
+                return;
+            }
+            let Some(released) = drag.get() else {
+                return;
+            };
+            drag.set(None);
+            match released.target.filter(|_| released.dragging) {
+                Some((target, position)) => {
+                    layout.update(|layout| {
+                        layout.dock(&released.panel, &target, position);
+                    });
+                }
+                None if !released.dragging => {
+                    layout.update(|layout| layout.activate(&released.panel));
+                }
+                None => {}
+            }
+        });
+    }
+
+    let clear_target = {
+        let drag = drag.binding();
+        on_pointer_leave(move |_| {
+            drag.update(|drag| {
+                if let Some(drag) = drag.as_mut() {
+                    drag.target = None;
+                }
+            });
+        })
+    };
+
+    let context = DockRenderContext {
+        theme: use_theme().0,
+        drag: drag.binding(),
+        current: drag.get(),
+        panels,
+    };
+    let root = layout.get().root.map(|root| context.node(&root));
+
+    let space = rsx! {
+        <Element
+            style={{
+                width: Length::percent(100.0),
+                height: Length::percent(100.0),
+            }}
+            on_pointer_leave={clear_target}
+        >
+            {root}
+        </Element>
+    };
+    root_style.apply(space)
+}
+
+struct DockRenderContext {
+    theme: Theme,
+    drag: Binding<Option<DockDrag>>,
+    current: Option<DockDrag>,
+    panels: Vec<DockPanel>,
+}
+
+impl DockRenderContext {
+    fn node(&self, node: &DockNode) -> RsxNode {
+        match node {
+            DockNode::Split {
+                axis,
+                ratio,
+                first,
+                second,
+            } => {
+                let (first_size, second_size) = (ratio * 100.0, (1.0 - ratio) * 100.0);
+                let (layout, first_width, first_height, second_width, second_height) = match axis {
+                    DockAxis::Row => (
+                        Layout::flow().row().no_wrap(),
+                        Length::percent(first_size),
+                        Length::percent(100.0),
+                        Length::percent(second_size),
+                        Length::percent(100.0),
+                    ),
+                    DockAxis::Column => (
+                        Layout::flow().column().no_wrap(),
+                        Length::percent(100.0),
+                        Length::percent(first_size),
+                        Length::percent(100.0),
+                        Length::percent(second_size),
+                    ),
+                };
+                rsx! {
+                    <Element style={{
+                        width: Length::percent(100.0),
+                        height: Length::percent(100.0),
+                        layout: layout,
+                    }}>
+                        <Element style={{ width: first_width, height: first_height }}>
+                            {self.node(first)}
+                        </Element>
+                        <Element style={{ width: second_width, height: second_height }}>
+                            {self.node(second)}
+                        </Element>
+                    </Element>
+                }
+            }
+            DockNode::Tabs { panels, active } => self.group(panels, *active),
+        }
+    }
+
+    fn group(&self, ids: &[String], active: usize) -> RsxNode {
+        let theme = &self.theme;
+        let find = |id: &str| self.panels.iter().find(|panel| panel.id == id);
+        let active_id = ids.get(active).or(ids.first()).cloned().unwrap_or_default();
+        let active_panel = find(&active_id);
+        let title = active_panel
+            .map(|panel| panel.title.clone())
+            .unwrap_or_default();
+        let content = active_panel.map(|panel| panel.content.clone());
+
+        let track_target = {
+            let drag = self.drag.clone();
+            let anchor = active_id.clone();
+            on_pointer_move(move |event| {
+                let bounds = event.meta.current_target().bounds;
+                if bounds.width <= 0.0 || bounds.height <= 0.0 {
+                    return;
+                }
+                let position = drop_position(
+                    (event.pointer.viewport_x - bounds.x) / bounds.width,
+                    (event.pointer.viewport_y - bounds.y) / bounds.height,
+                );
+                let target = Some((anchor.clone(), position));
+                if drag.get().is_some_and(|drag| drag.target != target) {
+                    drag.update(|drag| {
+                        if let Some(drag) = drag.as_mut() {
+                            drag.target = target;
+                        }
+                    });
+                }
+            })
+        };
+        // Presses reach the group from the window's title bar, which does
+        // not drag when the window is docked.
+        let title_down = {
+            let drag = self.drag.clone();
+            let panel = active_id.clone();
+            on_pointer_down(move |event| {
+                let bounds = event.meta.current_target().bounds;
+                if event.pointer.button != Some(PointerButton::Left)
+                    || event.pointer.viewport_y - bounds.y > TITLE_BAR_HEIGHT
+                {
+                    return;
+                }
+                drag.set(Some(DockDrag {
+                    panel: panel.clone(),
+                    start: (event.pointer.viewport_x, event.pointer.viewport_y),
+                    dragging: false,
+                    target: None,
+                }));
+            })
+        };
+
+        let tabs = (ids.len() > 1).then(|| {
+            let tabs: Vec<RsxNode> = ids
+                .iter()
+                .enumerate()
+                .map(|(index, id)| {
+                    let selected = index == active;
+                    let press = {
+                        let drag = self.drag.clone();
+                        let panel = id.clone();
+                        on_pointer_down(move |event| {
+                            if event.pointer.button != Some(PointerButton::Left) {
+                                return;
+                            }
+                            drag.set(Some(DockDrag {
+                                panel: panel.clone(),
+                                start: (event.pointer.viewport_x, event.pointer.viewport_y),
+                                dragging: false,
+                                target: None,
+                            }));
+                            event.meta.stop_propagation();
+                        })
+                    };
+                    let label = find(id)
+                        .map(|panel| panel.title.clone())
+                        .unwrap_or_default();
+                    rsx! {
+                        <Element
+                            key={id.clone()}
+                            style={{
+                                layout: Layout::flow().row().no_wrap().align(Align::Center),
+                                padding: Padding::uniform(theme.spacing.xs).x(theme.spacing.sm),
+                                background: if selected {
+                                    theme.color.layer.surface.clone()
+                                } else {
+                                    Box::new(Color::transparent()) as Box<dyn ColorLike>
+                                },
+                                color: if selected {
+                                    theme.color.text.primary.clone()
+                                } else {
+                                    theme.color.text.secondary.clone()
+                                },
+                                cursor: Cursor::Pointer,
+                                hover: {
+                                    background: theme.color.state.hover.clone(),
+                                }
+                            }}
+                            on_pointer_down={press}
+                        >
+                            <Text>{label}</Text>
+                        </Element>
+                    }
+                })
+                .collect();
+            rsx! {
+                <Element style={{
+                    width: Length::percent(100.0),
+                    layout: Layout::flow().row().no_wrap(),
+                    background: theme.color.layer.raised.clone(),
+                    font_size: theme.typography.size.sm,
+                }}>
+                    {tabs}
+                </Element>
+            }
+        });
+
+        let preview = self
+            .current
+            .as_ref()
+            .filter(|drag| drag.dragging)
+            .and_then(|drag| drag.target.as_ref())
+            .filter(|(target, _)| *target == active_id)
+            .map(|(_, position)| {
+                let (left, top, width, height) = match position {
+                    DockPosition::Left => (0.0, 0.0, 50.0, 100.0),
+                    DockPosition::Right => (50.0, 0.0, 50.0, 100.0),
+                    DockPosition::Top => (0.0, 0.0, 100.0, 50.0),
+                    DockPosition::Bottom => (0.0, 50.0, 100.0, 50.0),
+                    DockPosition::Tab => (0.0, 0.0, 100.0, 100.0),
+                };
+                let [r, g, b, _] = theme.color.primary.base.to_rgba_u8();
+                rsx! {
+                    <Element style={{
+                        position: Position::absolute()
+                            .left(Length::percent(left))
+                            .top(Length::percent(top))
+                            .anchor(Anchor::Parent),
+                        width: Length::percent(width),
+                        height: Length::percent(height),
+                        background: Color::rgba(r, g, b, 64),
+                    }} />
+                }
+            });
+
+        let window_slots = WindowSlotsProp {
+            root_style: None,
+            title_bar_style: None,
+            title_text_style: None,
+            content_style: Some(WindowContentStyleSlot {
+                padding: Some(Padding::uniform(Length::px(0.0))),
+                background: None,
+            }),
+        };
+        rsx! {
+            <Element
+                key={active_id.clone()}
+                style={{
+                    width: Length::percent(100.0),
+                    height: Length::percent(100.0),
+                }}
+                on_pointer_move={track_target}
+                on_pointer_down={title_down}
+            >
+                <Window
+                    title={title}
+                    draggable={false}
+                    maximizable={false}
+                    snappable={false}
+                    state={Binding::new(WindowState::default().maximize())}
+                    window_slots={window_slots}
+                >
+                    {tabs}
+                    <Element style={{
+                        width: Length::percent(100.0),
+                        padding: theme.component.card.padding,
+                        layout: Layout::flow().column(),
+                    }}>
+                        {content}
+                    </Element>
+                </Window>
+                {preview}
+            </Element>
+        }
+    }
+}
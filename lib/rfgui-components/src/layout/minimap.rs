@@ -0,0 +1,267 @@
+use crate::use_theme;
+use rfgui::style::{
+    Anchor, Border, Cursor, Layout, Length, Operator, Position, Scale, ScrollDirection, Transform,
+    TransformOrigin,
+};
+use rfgui::ui::{
+    NodeId, RsxComponent, RsxNode, component, on_pointer_down, on_pointer_move, on_pointer_up,
+    on_scroll, props, rsx, use_state,
+};
+use rfgui::view::{Element, ElementStylePropSchema, RootStyleProps};
+
+/// Scroll offset and extent of the container, plus the unscaled size of
+/// the overview copy, as last reported by layout.
+#[derive(Clone, Copy, Debug, Default, PartialEq)]
+struct MinimapMetrics {
+    offset: (f32, f32),
+    max_scroll: (f32, f32),
+    viewport: (f32, f32),
+    overview: (f32, f32),
+    panel: (f32, f32),
+}
+
+impl MinimapMetrics {
+    /// Full content size. The scroll range is exact once the container has
+    /// reported a scroll; until then the overview copy's own size stands in.
+    fn content(&self) -> (f32, f32) {
+        (
+            (self.viewport.0 + self.max_scroll.0).max(self.overview.0),
+            (self.viewport.1 + self.max_scroll.1).max(self.overview.1),
+        )
+    }
+
+    /// Overview scale that fits the whole content into the panel.
+    fn scale(&self) -> f32 {
+        let (width, height) = self.content();
+        if width <= 0.0 || height <= 0.0 {
+            return 0.0;
+        }
+        (self.panel.0 / width).min(self.panel.1 / height).min(1.0)
+    }
+}
+
+/// Scroll container with a scaled-down overview of its content beside it.
+///
+/// The overview is a second instance of the children drawn through a scale
+/// transform, so it tracks content changes without a separate thumbnail.
+/// Children that keep their own state render that state independently in
+/// each copy. The rectangle over the overview marks the visible part of the
+/// container; pressing the overview centers the view there and dragging
+/// the rectangle scrolls the container.
+pub struct Minimap;
+
+#[derive(Clone)]
+#[props]
+pub struct MinimapProps {
+    /// Width of the overview column in pixels. Defaults to 120.
+    pub width: Option<f64>,
+    /// Stacked over the style of the row holding content and overview.
+    pub style: Option<ElementStylePropSchema>,
+    /// [`StyleClasses`](rfgui::view::StyleClasses) names, stacked beneath `style`.
+    pub class: Option<String>,
+}
+
+impl RsxComponent<MinimapProps> for Minimap {
+    fn render(props: MinimapProps, children: Vec<RsxNode>) -> RsxNode {
+        rsx! {
+            <MinimapView
+                width={props.width.unwrap_or(120.0) as f32}
+                root_style={RootStyleProps::new(props.style, props.class)}
+            >
+                {children}
+            </MinimapView>
+        }
+    }
+}
+
+#[rfgui::ui::component]
+impl rfgui::ui::RsxTag for Minimap {
+    type Props = __MinimapPropsInit;
+    type StrictProps = MinimapProps;
+    const ACCEPTS_CHILDREN: bool = true;
+
+    fn into_strict(props: Self::Props) -> Self::StrictProps {
+        props.into()
+    }
+
+    fn create_node(
+        props: Self::StrictProps,
+        children: Vec<RsxNode>,
+        _key: Option<rfgui::ui::RsxKey>,
+    ) -> RsxNode {
+        <Self as RsxComponent<MinimapProps>>::render(props, children)
+    }
+}
+
+#[component]
+fn MinimapView(width: f32, root_style: RootStyleProps, children: Vec<RsxNode>) -> RsxNode {
+    let theme = use_theme().0;
+    let metrics = use_state(MinimapMetrics::default);
+    // Container being dragged and where in the view rectangle it was
+    // grabbed, in content pixels.
+    let drag = use_state(|| None::<(NodeId, (f32, f32))>);
+    let current = metrics.get();
+    let scale = current.scale();
+
+    let scrolled = {
+        let metrics = metrics.binding();
+        on_scroll(move |event| {
+            metrics.update(|metrics| {
+                metrics.offset = (event.scroll_x, event.scroll_y);
+                metrics.max_scroll = (event.max_scroll_x, event.max_scroll_y);
+            });
+        })
+    };
+    let resized = |apply: fn(&mut MinimapMetrics, (f32, f32))| {
+        let metrics = metrics.binding();
+        rfgui::ui::on_resize(move |event| {
+            let size = (event.width, event.height);
+            metrics.update(|metrics| apply(metrics, size));
+        })
+    };
+
+    // The press lands on the overlay; the container is the first child of
+    // the minimap root, two levels up.
+    let press = {
+        let metrics = metrics.binding();
+        let drag = drag.binding();
+        on_pointer_down(move |event| {
+            let current = metrics.get();
+            let scale = current.scale();
+            let Some(container) = event
+                .meta
+                .current_target()
+                .parent()
+                .and_then(|panel| panel.parent())
+                .and_then(|root| root.children().first().map(|container| container.id))
+            else {
+                return;
+            };
+            if scale <= 0.0 {
+                return;
+            }
+            let point = (event.pointer.local_x / scale, event.pointer.local_y / scale);
+            let inside = point.0 >= current.offset.0
+                && point.0 < current.offset.0 + current.viewport.0
+                && point.1 >= current.offset.1
+                && point.1 < current.offset.1 + current.viewport.1;
+            let grab = if inside {
+                (point.0 - current.offset.0, point.1 - current.offset.1)
+            } else {
+                (current.viewport.0 / 2.0, current.viewport.1 / 2.0)
+            };
+            event
+                .viewport
+                .scroll_to(container, point.0 - grab.0, point.1 - grab.1);
+            drag.set(Some((container, grab)));
+            event.meta.request_pointer_capture();
+            event.meta.stop_propagation();
+        })
+    };
+    let track = {
+        let metrics = metrics.binding();
+        let drag = drag.binding();
+        on_pointer_move(move |event| {
+            let Some((container, grab)) = drag.get() else {
+                return;
+            };
+            if !event.pointer.buttons.left {
+                drag.set(None);
+                return;
+            }
+            let scale = metrics.get().scale();
+            if scale <= 0.0 {
+                return;
+            }
+            event.viewport.scroll_to(
+                container,
+                event.pointer.local_x / scale - grab.0,
+                event.pointer.local_y / scale - grab.1,
+            );
+            event.meta.stop_propagation();
+        })
+    };
+    let release = {
+        let drag = drag.binding();
+        on_pointer_up(move |_| drag.set(None))
+    };
+
+    let view_background = if drag.get().is_some() {
+        theme.color.state.active.clone()
+    } else {
+        theme.color.state.hover.clone()
+    };
+    let panel_border = Border::uniform(Length::px(0.0), theme.color.border.as_ref())
+        .left(Some(Length::px(1.0)), Some(theme.color.border.as_ref()));
+    let root = rsx! {
+        <Element style={{
+            width: Length::percent(100.0),
+            height: Length::percent(100.0),
+            layout: Layout::flow().row().no_wrap(),
+        }}>
+            <Element
+                style={{
+                    width: Length::calc(Length::percent(100.0), Operator::subtract, Length::px(width)),
+                    height: Length::percent(100.0),
+                    layout: Layout::flow().column(),
+                    scroll_direction: ScrollDirection::Both,
+                }}
+                on_scroll={scrolled}
+                on_resize={resized(|metrics, size| metrics.viewport = size)}
+            >
+                {children.clone()}
+            </Element>
+            <Element
+                style={{
+                    width: Length::px(width),
+                    height: Length::percent(100.0),
+                    border: panel_border,
+                    background: theme.color.layer.surface.clone(),
+                }}
+                on_resize={resized(|metrics, size| metrics.panel = size)}
+            >
+                <Element
+                    style={{
+                        position: Position::absolute()
+                            .left(Length::px(0.0))
+                            .top(Length::px(0.0))
+                            .anchor(Anchor::Parent),
+                        width: Length::px(current.viewport.0),
+                        layout: Layout::flow().column(),
+                        transform: Transform::new([Scale::uniform(scale)]),
+                        transform_origin: TransformOrigin::px(0.0, 0.0),
+                    }}
+                    on_resize={resized(|metrics, size| metrics.overview = size)}
+                >
+                    {children}
+                </Element>
+                <Element
+                    style={{
+                        position: Position::absolute()
+                            .left(Length::px(0.0))
+                            .top(Length::px(0.0))
+                            .anchor(Anchor::Parent),
+                        width: Length::percent(100.0),
+                        height: Length::percent(100.0),
+                        cursor: Cursor::Pointer,
+                    }}
+                    on_pointer_down={press}
+                    on_pointer_move={track}
+                    on_pointer_up={release}
+                >
+                    <Element style={{
+                        position: Position::absolute()
+                            .left(Length::px(current.offset.0 * scale))
+                            .top(Length::px(current.offset.1 * scale))
+                            .anchor(Anchor::Parent),
+                        width: Length::px(current.viewport.0 * scale),
+                        height: Length::px(current.viewport.1 * scale),
+                        background: view_background,
+                        border: Border::uniform(Length::px(1.0), theme.color.border.as_ref()),
+                    }} />
+                </Element>
+            </Element>
+        </Element>
+    };
+    root_style.apply(root)
+}
@@ -1,7 +1,15 @@
 mod accordion;
+mod animated_list;
+mod dock;
+mod minimap;
 mod tree_view;
 mod window;
+mod window_taskbar;
 
 pub use accordion::*;
+pub use animated_list::*;
+pub use dock::*;
+pub use minimap::*;
 pub use tree_view::*;
 pub use window::*;
+pub use window_taskbar::*;
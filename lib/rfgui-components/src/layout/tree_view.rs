@@ -61,7 +61,7 @@ use rfgui::ui::{
     on_drag_leave, on_drag_over, on_drag_start, on_drop, on_pointer_down, on_pointer_move,
     on_pointer_up, props, rsx, use_state,
 };
-use rfgui::view::{Element, Text};
+use rfgui::view::{Element, ElementStylePropSchema, RootStyleProps, Text};
 
 // ---------------------------------------------------------------------------
 // Data
@@ -314,6 +314,10 @@ pub struct TreeViewProps<V: 'static> {
     /// mutate `nodes` itself — the host is responsible for computing
     /// the new tree and re-rendering.
     pub on_move: Option<Rc<dyn Fn(TreeMoveEvent<V>)>>,
+    /// Stacked over the style of the column holding the rows.
+    pub style: Option<ElementStylePropSchema>,
+    /// [`StyleClasses`](rfgui::view::StyleClasses) names, stacked beneath `style`.
+    pub class: Option<String>,
 }
 
 impl<V> RsxComponent<TreeViewProps<V>> for TreeView<V>
@@ -329,6 +333,7 @@ where
                 default_selected_item={props.default_selected_item}
                 selected_binding={props.selected_binding}
                 on_move={props.on_move}
+                root_style={RootStyleProps::new(props.style, props.class)}
             />
         }
     }
@@ -369,6 +374,7 @@ fn TreeViewView<V: Clone + PartialEq + std::hash::Hash + 'static>(
     default_selected_item: Option<V>,
     selected_binding: Option<Binding<Option<V>>>,
     on_move: Option<Rc<dyn Fn(TreeMoveEvent<V>)>>,
+    root_style: RootStyleProps,
 ) -> RsxNode {
     let theme = use_theme().0;
 
@@ -413,7 +419,7 @@ fn TreeViewView<V: Clone + PartialEq + std::hash::Hash + 'static>(
         );
     }
 
-    rsx! {
+    let root = rsx! {
         <Element style={{
             width: Length::percent(100.0),
             layout: Layout::flow().column().no_wrap(),
@@ -421,7 +427,8 @@ fn TreeViewView<V: Clone + PartialEq + std::hash::Hash + 'static>(
         }}>
             {row_nodes}
         </Element>
-    }
+    };
+    root_style.apply(root)
 }
 
 fn emit_rows<V: Clone + PartialEq + std::hash::Hash + 'static>(
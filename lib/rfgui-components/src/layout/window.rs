@@ -1,21 +1,27 @@
+use crate::material_symbol::{CropSquareIcon, FilterNoneIcon, MinimizeIcon};
 use crate::use_theme;
 use rfgui::style::ClipMode::{AnchorParent, Parent};
 use rfgui::style::{
     Align, Anchor, Border, BorderRadius, Color, ColorLike, Cursor, FontWeight, JustifyContent,
-    Layout, Length, Padding, Position, ScrollDirection,
+    Layout, Length, Operator, Padding, Position, ScrollDirection,
 };
 use rfgui::ui::{
-    BlurHandlerProp, FocusHandlerProp, Handler, PointerButton, PointerDownHandlerProp,
-    RsxComponent, RsxNode, on_pointer_down, props, rsx, use_state, use_viewport_pointer_move,
-    use_viewport_pointer_up,
+    Binding, BlurHandlerProp, FocusHandlerProp, Handler, PointerButton, PointerDownHandlerProp,
+    RsxComponent, RsxNode, on_click, on_pointer_down, props, rsx, use_state,
+    use_viewport_pointer_move, use_viewport_pointer_up, use_viewport_size,
 };
-use rfgui::view::{Element, Text};
+use rfgui::view::{Element, ElementStylePropSchema, RootStyleProps, Text};
 
 const MIN_WIDTH: f32 = 220.0;
 const MIN_HEIGHT: f32 = 140.0;
-const TITLE_BAR_HEIGHT: f32 = 24.0;
+pub(crate) const TITLE_BAR_HEIGHT: f32 = 24.0;
 const RESIZE_EDGE_THICKNESS: f32 = 2.0;
 const RESIZE_CORNER_SIZE: f32 = 14.0;
+/// Distance from a viewport edge at which a dragged window snaps.
+const SNAP_EDGE: f32 = 8.0;
+/// Height of the corner zones along the left and right edges that snap
+/// to a quadrant instead of a half.
+const SNAP_CORNER: f32 = 64.0;
 
 pub type OnResize = Handler<dyn FnMut(f32, f32)>;
 pub type ResizeHandlerProp = OnResize;
@@ -82,6 +88,113 @@ impl ResizeEdge {
     }
 }
 
+/// Half or quadrant of the parent a snapped window fills.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum WindowSnap {
+    Left,
+    Right,
+    TopLeft,
+    TopRight,
+    BottomLeft,
+    BottomRight,
+}
+
+impl WindowSnap {
+    /// `(left, top, width, height)` as fractions of the parent.
+    fn fractions(self) -> (f32, f32, f32, f32) {
+        match self {
+            Self::Left => (0.0, 0.0, 0.5, 1.0),
+            Self::Right => (0.5, 0.0, 0.5, 1.0),
+            Self::TopLeft => (0.0, 0.0, 0.5, 0.5),
+            Self::TopRight => (0.5, 0.0, 0.5, 0.5),
+            Self::BottomLeft => (0.0, 0.5, 0.5, 0.5),
+            Self::BottomRight => (0.5, 0.5, 0.5, 0.5),
+        }
+    }
+}
+
+/// Where a window sits while it is shown.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub enum WindowPlacement {
+    /// The window's own position and size.
+    #[default]
+    Normal,
+    /// Fills the parent.
+    Maximized,
+    Snapped(WindowSnap),
+}
+
+impl WindowPlacement {
+    /// `(left, top, width, height)` as fractions of the parent, or `None`
+    /// for the window's own bounds.
+    fn fractions(self) -> Option<(f32, f32, f32, f32)> {
+        match self {
+            Self::Normal => None,
+            Self::Maximized => Some((0.0, 0.0, 1.0, 1.0)),
+            Self::Snapped(snap) => Some(snap.fractions()),
+        }
+    }
+}
+
+/// Window state shared through [`WindowProps::state`]. Maximizing and
+/// snapping leave the window's own bounds alone, so returning to
+/// [`WindowPlacement::Normal`] restores them; minimizing keeps the
+/// placement to come back to.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub struct WindowState {
+    pub placement: WindowPlacement,
+    pub minimized: bool,
+}
+
+impl WindowState {
+    pub fn minimize(self) -> Self {
+        Self {
+            minimized: true,
+            ..self
+        }
+    }
+
+    pub fn maximize(self) -> Self {
+        Self {
+            placement: WindowPlacement::Maximized,
+            minimized: false,
+        }
+    }
+
+    /// Bring a minimized window back to its placement, or return a
+    /// maximized or snapped one to its own bounds.
+    pub fn restore(self) -> Self {
+        if self.minimized {
+            Self {
+                minimized: false,
+                ..self
+            }
+        } else {
+            Self::default()
+        }
+    }
+}
+
+/// Placement for a window dropped with the pointer at `(x, y)`: the top
+/// edge maximizes, the left and right edges snap to halves, and their
+/// ends to quadrants.
+fn snap_placement(x: f32, y: f32, viewport: (f32, f32)) -> Option<WindowPlacement> {
+    let (width, height) = viewport;
+    let left = x <= SNAP_EDGE;
+    if !left && x < width - SNAP_EDGE {
+        return (y <= SNAP_EDGE).then_some(WindowPlacement::Maximized);
+    }
+    let snap = match (left, y <= SNAP_CORNER, y >= height - SNAP_CORNER) {
+        (true, true, _) => WindowSnap::TopLeft,
+        (true, _, true) => WindowSnap::BottomLeft,
+        (true, ..) => WindowSnap::Left,
+        (false, true, _) => WindowSnap::TopRight,
+        (false, _, true) => WindowSnap::BottomRight,
+        (false, ..) => WindowSnap::Right,
+    };
+    Some(WindowPlacement::Snapped(snap))
+}
+
 pub struct Window;
 
 #[derive(Clone)]
@@ -98,6 +211,21 @@ pub struct WindowProps {
     pub on_blur: Option<BlurHandlerProp>,
     pub window_slots: Option<WindowSlotsProp>,
     pub scrollable: Option<bool>,
+    /// Maximized / snapped / minimized state. Uncontrolled windows keep
+    /// their own.
+    pub state: Option<Binding<WindowState>>,
+    /// Show a maximize / restore button. Defaults to `true`.
+    pub maximizable: Option<bool>,
+    /// Show a minimize button. Defaults to `false`; pair it with a
+    /// [`WindowTaskbar`](crate::WindowTaskbar) so the window can come back.
+    pub minimizable: Option<bool>,
+    /// Snap to viewport halves and quadrants, or maximize at the top
+    /// edge, when dropped there. Defaults to `true`.
+    pub snappable: Option<bool>,
+    /// Stacked over the style of the window frame, after `window_slots`.
+    pub style: Option<ElementStylePropSchema>,
+    /// [`StyleClasses`](rfgui::view::StyleClasses) names, stacked beneath `style`.
+    pub class: Option<String>,
 }
 
 #[derive(Clone)]
@@ -158,6 +286,11 @@ impl RsxComponent<WindowProps> for Window {
                 on_blur={props.on_blur}
                 window_slots={props.window_slots}
                 scrollable={scrollable}
+                state={props.state}
+                maximizable={props.maximizable.unwrap_or(true)}
+                minimizable={props.minimizable.unwrap_or(false)}
+                snappable={props.snappable.unwrap_or(true)}
+                root_style={RootStyleProps::new(props.style, props.class)}
             >
                 {children}
             </WindowView>
@@ -197,12 +330,21 @@ fn WindowView(
     on_blur: Option<BlurHandlerProp>,
     window_slots: Option<WindowSlotsProp>,
     scrollable: bool,
+    state: Option<Binding<WindowState>>,
+    maximizable: bool,
+    minimizable: bool,
+    snappable: bool,
+    root_style: RootStyleProps,
     children: Vec<RsxNode>,
 ) -> RsxNode {
     let theme = use_theme().0;
     let position_state = use_state(|| position.unwrap_or((0.0, 0.0)));
     let size = use_state(|| (initial_width, initial_height));
     let interaction = use_state(|| WindowInteraction::Idle);
+    let fallback_window_state = use_state(WindowState::default);
+    let window_state = state.unwrap_or_else(|| fallback_window_state.binding());
+    let viewport_size = use_viewport_size();
+    let current_state = window_state.get();
 
     let (x, y) = position.unwrap_or_else(|| position_state.get());
     let (width, height) = size.get();
@@ -365,13 +507,22 @@ fn WindowView(
 
     {
         let interaction_for_up = interaction.binding();
+        let state_for_up = window_state.clone();
         let viewport = rfgui::ui::use_viewport();
         use_viewport_pointer_up(move |up_event| {
             if up_event.pointer.button != Some(PointerButton::Left) {
                 return;
             }
-            if let WindowInteraction::Resizing { .. } = interaction_for_up.get() {
-                viewport.set_cursor(None);
+            match interaction_for_up.get() {
+                WindowInteraction::Resizing { .. } => viewport.set_cursor(None),
+                WindowInteraction::Dragging { .. } if snappable => {
+                    let (pointer_x, pointer_y) =
+                        (up_event.pointer.viewport_x, up_event.pointer.viewport_y);
+                    if let Some(placement) = snap_placement(pointer_x, pointer_y, viewport_size) {
+                        state_for_up.update(|state| state.placement = placement);
+                    }
+                }
+                _ => {}
             }
             interaction_for_up.set(WindowInteraction::Idle);
         });
@@ -379,7 +530,12 @@ fn WindowView(
 
     let title_down: PointerDownHandlerProp = {
         let interaction = interaction.binding();
+        let window_state = window_state.clone();
+        let position_for_down = position_state.binding();
+        let on_move = on_move.clone();
+        let controlled = position.is_some();
         let current_position = (x, y);
+        let placement = current_state.placement;
         on_pointer_down(move |event| {
             if !draggable || event.pointer.button != Some(PointerButton::Left) {
                 return;
@@ -387,7 +543,30 @@ fn WindowView(
             event
                 .viewport
                 .set_focus(Some(event.meta.current_target_id()));
-            let (start_x, start_y) = current_position;
+            let (start_x, start_y) = match placement.fractions() {
+                None => current_position,
+                Some((left, top, fraction_width, _)) => {
+                    // Tear the window off its slot at its own size, keeping
+                    // the grab point at the same share of the title bar.
+                    // The slot is measured against the viewport, which the
+                    // parent usually fills.
+                    let slot_x = left * viewport_size.0;
+                    let slot_width = (fraction_width * viewport_size.0).max(1.0);
+                    let grab = (event.pointer.local_x / slot_width).clamp(0.0, 1.0);
+                    let torn_off = (
+                        slot_x + event.pointer.local_x - width * grab,
+                        top * viewport_size.1,
+                    );
+                    window_state.update(|state| state.placement = WindowPlacement::Normal);
+                    if !controlled {
+                        position_for_down.set(torn_off);
+                    }
+                    if let Some(handler) = &on_move {
+                        handler.call(torn_off.0, torn_off.1);
+                    }
+                    torn_off
+                }
+            };
             interaction.set(WindowInteraction::Dragging {
                 start_mouse_x: event.pointer.viewport_x,
                 start_mouse_y: event.pointer.viewport_y,
@@ -433,16 +612,179 @@ fn WindowView(
     let resize_bottom_left_down = make_resize_down(ResizeEdge::BottomLeft);
     let resize_bottom_right_down = make_resize_down(ResizeEdge::BottomRight);
 
-    rsx! {
+    if current_state.minimized {
+        return rsx! {
+            <Element style={{ position: Position::absolute().anchor(Anchor::Parent) }} />
+        };
+    }
+
+    let normal = current_state.placement == WindowPlacement::Normal;
+    let (left, top, root_width, root_height, content_height) =
+        match current_state.placement.fractions() {
+            None => (
+                Length::px(x),
+                Length::px(y),
+                Length::px(width),
+                Length::px(height),
+                Length::px(content_height),
+            ),
+            Some((left, top, fraction_width, fraction_height)) => (
+                Length::percent(left * 100.0),
+                Length::percent(top * 100.0),
+                Length::percent(fraction_width * 100.0),
+                Length::percent(fraction_height * 100.0),
+                Length::calc(
+                    Length::percent(100.0),
+                    Operator::subtract,
+                    title_bar_height_length,
+                ),
+            ),
+        };
+
+    let title_controls = (minimizable || maximizable).then(|| {
+        let minimize = minimizable.then(|| {
+            let window_state = window_state.clone();
+            title_bar_button(
+                rsx! { <MinimizeIcon style={{ font_size: theme.typography.size.sm }} /> },
+                title_text_color,
+                move || window_state.update(|state| *state = state.minimize()),
+            )
+        });
+        let maximize = maximizable.then(|| {
+            let window_state = window_state.clone();
+            let icon = if normal {
+                rsx! { <CropSquareIcon style={{ font_size: theme.typography.size.sm }} /> }
+            } else {
+                rsx! { <FilterNoneIcon style={{ font_size: theme.typography.size.sm }} /> }
+            };
+            title_bar_button(icon, title_text_color, move || {
+                window_state.update(|state| {
+                    *state = if normal {
+                        state.maximize()
+                    } else {
+                        state.restore()
+                    }
+                })
+            })
+        });
+        rsx! {
+            <Element style={{ layout: Layout::flow().row().no_wrap().align(Align::Center) }}>
+                {minimize}
+                {maximize}
+            </Element>
+        }
+    });
+    let resize_handles = normal.then(|| {
+        rsx! {
         <Element
             style={{
-                position: Position::absolute().left(Length::px(x)).top(Length::px(y)).anchor(Anchor::Parent).clip(Parent),
-                width: Length::px(width),
-                height: Length::px(height),
+                position: Position::absolute()
+                    .left(Length::px(-RESIZE_EDGE_THICKNESS))
+                    .top(Length::px(0.0))
+                    .bottom(Length::px(0.0))
+                    .clip(AnchorParent),
+                width: Length::px(RESIZE_EDGE_THICKNESS * 2.0),
+                cursor: Cursor::EwResize,
+            }}
+            on_pointer_down={resize_left_down}
+        />
+        <Element
+            style={{
+                position: Position::absolute()
+                    .right(Length::px(-RESIZE_EDGE_THICKNESS))
+                    .top(Length::px(0.0))
+                    .bottom(Length::px(0.0))
+                    .clip(AnchorParent),
+                width: Length::px(RESIZE_EDGE_THICKNESS * 2.0),
+                cursor: Cursor::EwResize,
+            }}
+            on_pointer_down={resize_right_down}
+        />
+        <Element
+            style={{
+                position: Position::absolute()
+                    .left(Length::px(0.0))
+                    .right(Length::px(0.0))
+                    .top(Length::px(-RESIZE_EDGE_THICKNESS))
+                    .clip(AnchorParent),
+                height: Length::px(RESIZE_EDGE_THICKNESS * 2.0),
+                cursor: Cursor::NsResize,
+            }}
+            on_pointer_down={resize_top_down}
+        />
+        <Element
+            style={{
+                position: Position::absolute()
+                    .left(Length::px(0.0))
+                    .right(Length::px(0.0))
+                    .bottom(Length::px(-RESIZE_EDGE_THICKNESS))
+                    .clip(AnchorParent),
+                height: Length::px(RESIZE_EDGE_THICKNESS * 2.0),
+                cursor: Cursor::NsResize,
+            }}
+            on_pointer_down={resize_bottom_down}
+        />
+        <Element
+            style={{
+                position: Position::absolute()
+                    .left(Length::px(-RESIZE_CORNER_SIZE / 2.0))
+                    .top(Length::px(-RESIZE_CORNER_SIZE / 2.0))
+                    .clip(AnchorParent),
+                width: Length::px(RESIZE_CORNER_SIZE),
+                height: Length::px(RESIZE_CORNER_SIZE),
+                cursor: Cursor::NwseResize,
+            }}
+            on_pointer_down={resize_top_left_down}
+        />
+        <Element
+            style={{
+                position: Position::absolute()
+                    .right(Length::px(-RESIZE_CORNER_SIZE / 2.0))
+                    .top(Length::px(-RESIZE_CORNER_SIZE / 2.0))
+                    .clip(AnchorParent),
+                width: Length::px(RESIZE_CORNER_SIZE),
+                height: Length::px(RESIZE_CORNER_SIZE),
+                cursor: Cursor::NeswResize,
+            }}
+            on_pointer_down={resize_top_right_down}
+        />
+        <Element
+            style={{
+                position: Position::absolute()
+                    .left(Length::px(-RESIZE_CORNER_SIZE / 2.0))
+                    .bottom(Length::px(-RESIZE_CORNER_SIZE / 2.0))
+                    .clip(AnchorParent),
+                width: Length::px(RESIZE_CORNER_SIZE),
+                height: Length::px(RESIZE_CORNER_SIZE),
+                cursor: Cursor::NeswResize,
+            }}
+            on_pointer_down={resize_bottom_left_down}
+        />
+        <Element
+            style={{
+                position: Position::absolute()
+                    .right(Length::px(-RESIZE_CORNER_SIZE / 2.0))
+                    .bottom(Length::px(-RESIZE_CORNER_SIZE / 2.0))
+                    .clip(AnchorParent),
+                width: Length::px(RESIZE_CORNER_SIZE),
+                height: Length::px(RESIZE_CORNER_SIZE),
+                cursor: Cursor::NwseResize,
+            }}
+            on_pointer_down={resize_bottom_right_down}
+        />
+        }
+    });
+
+    let frame = rsx! {
+        <Element
+            style={{
+                position: Position::absolute().left(left).top(top).anchor(Anchor::Parent).clip(Parent),
+                width: root_width,
+                height: root_height,
                 layout: Layout::flow().column().no_wrap(),
                 background: root_background,
                 border: root_border,
-                border_radius: root_border_radius,
+                border_radius: if normal { root_border_radius } else { BorderRadius::uniform(Length::Zero) },
                 font: theme.typography.font_family.clone(),
                 box_shadow: vec![
                     theme.shadow.level_3,
@@ -467,11 +809,12 @@ fn WindowView(
                 on_pointer_down={title_down}
             >
                 <Text style={{ color: title_text_color, font_weight: title_text_weight }}>{title}</Text>
+                {title_controls}
             </Element>
             <Element
                 style={{
                     width: Length::percent(100.0),
-                    height: Length::px(content_height),
+                    height: content_height,
                     padding: content_padding,
                     layout: Layout::flow().column(),
                     background: content_background,
@@ -481,102 +824,32 @@ fn WindowView(
             >
                 {children}
             </Element>
-            <Element
-                style={{
-                    position: Position::absolute()
-                        .left(Length::px(-RESIZE_EDGE_THICKNESS))
-                        .top(Length::px(0.0))
-                        .bottom(Length::px(0.0))
-                        .clip(AnchorParent),
-                    width: Length::px(RESIZE_EDGE_THICKNESS * 2.0),
-                    cursor: Cursor::EwResize,
-                }}
-                on_pointer_down={resize_left_down}
-            />
-            <Element
-                style={{
-                    position: Position::absolute()
-                        .right(Length::px(-RESIZE_EDGE_THICKNESS))
-                        .top(Length::px(0.0))
-                        .bottom(Length::px(0.0))
-                        .clip(AnchorParent),
-                    width: Length::px(RESIZE_EDGE_THICKNESS * 2.0),
-                    cursor: Cursor::EwResize,
-                }}
-                on_pointer_down={resize_right_down}
-            />
-            <Element
-                style={{
-                    position: Position::absolute()
-                        .left(Length::px(0.0))
-                        .right(Length::px(0.0))
-                        .top(Length::px(-RESIZE_EDGE_THICKNESS))
-                        .clip(AnchorParent),
-                    height: Length::px(RESIZE_EDGE_THICKNESS * 2.0),
-                    cursor: Cursor::NsResize,
-                }}
-                on_pointer_down={resize_top_down}
-            />
-            <Element
-                style={{
-                    position: Position::absolute()
-                        .left(Length::px(0.0))
-                        .right(Length::px(0.0))
-                        .bottom(Length::px(-RESIZE_EDGE_THICKNESS))
-                        .clip(AnchorParent),
-                    height: Length::px(RESIZE_EDGE_THICKNESS * 2.0),
-                    cursor: Cursor::NsResize,
-                }}
-                on_pointer_down={resize_bottom_down}
-            />
-            <Element
-                style={{
-                    position: Position::absolute()
-                        .left(Length::px(-RESIZE_CORNER_SIZE / 2.0))
-                        .top(Length::px(-RESIZE_CORNER_SIZE / 2.0))
-                        .clip(AnchorParent),
-                    width: Length::px(RESIZE_CORNER_SIZE),
-                    height: Length::px(RESIZE_CORNER_SIZE),
-                    cursor: Cursor::NwseResize,
-                }}
-                on_pointer_down={resize_top_left_down}
-            />
-            <Element
-                style={{
-                    position: Position::absolute()
-                        .right(Length::px(-RESIZE_CORNER_SIZE / 2.0))
-                        .top(Length::px(-RESIZE_CORNER_SIZE / 2.0))
-                        .clip(AnchorParent),
-                    width: Length::px(RESIZE_CORNER_SIZE),
-                    height: Length::px(RESIZE_CORNER_SIZE),
-                    cursor: Cursor::NeswResize,
-                }}
-                on_pointer_down={resize_top_right_down}
-            />
-            <Element
-                style={{
-                    position: Position::absolute()
-                        .left(Length::px(-RESIZE_CORNER_SIZE / 2.0))
-                        .bottom(Length::px(-RESIZE_CORNER_SIZE / 2.0))
-                        .clip(AnchorParent),
-                    width: Length::px(RESIZE_CORNER_SIZE),
-                    height: Length::px(RESIZE_CORNER_SIZE),
-                    cursor: Cursor::NeswResize,
-                }}
-                on_pointer_down={resize_bottom_left_down}
-            />
-            <Element
-                style={{
-                    position: Position::absolute()
-                        .right(Length::px(-RESIZE_CORNER_SIZE / 2.0))
-                        .bottom(Length::px(-RESIZE_CORNER_SIZE / 2.0))
-                        .clip(AnchorParent),
-                    width: Length::px(RESIZE_CORNER_SIZE),
-                    height: Length::px(RESIZE_CORNER_SIZE),
-                    cursor: Cursor::NwseResize,
-                }}
-                on_pointer_down={resize_bottom_right_down}
-            />
+            {resize_handles}
+        </Element>
+    };
+    root_style.apply(frame)
+}
+
+/// Borderless title-bar control. Stops the press so it does not start a
+/// drag.
+fn title_bar_button(icon: RsxNode, color: Color, action: impl Fn() + 'static) -> RsxNode {
+    let press = on_pointer_down(|event| event.meta.stop_propagation());
+    let click = on_click(move |event| {
+        action();
+        event.meta.stop_propagation();
+    });
+    rsx! {
+        <Element
+            style={{
+                layout: Layout::flow().row().align(Align::Center),
+                padding: Padding::uniform(Length::px(2.0)),
+                color: color,
+                cursor: Cursor::Pointer,
+            }}
+            on_pointer_down={press}
+            on_click={click}
+        >
+            {icon}
         </Element>
     }
 }
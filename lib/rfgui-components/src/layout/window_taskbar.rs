@@ -0,0 +1,120 @@
+use crate::{WindowState, use_theme};
+use rfgui::style::{Align, Color, ColorLike, Cursor, Layout, Length, Padding};
+use rfgui::ui::{Binding, RsxComponent, RsxNode, on_click, props, rsx};
+use rfgui::view::{Element, ElementStylePropSchema, Text, with_root_class_and_style};
+
+/// One window on a [`WindowTaskbar`]: its title and the same state
+/// binding passed to the window's `state` prop.
+#[derive(Clone)]
+pub struct WindowTaskbarItem {
+    pub title: String,
+    pub state: Binding<WindowState>,
+}
+
+impl WindowTaskbarItem {
+    pub fn new(title: impl Into<String>, state: Binding<WindowState>) -> Self {
+        Self {
+            title: title.into(),
+            state,
+        }
+    }
+}
+
+/// Strip of window entries. Clicking an entry minimizes its window, or
+/// restores it when it is already minimized; minimized entries are dimmed.
+pub struct WindowTaskbar;
+
+#[derive(Clone)]
+#[props]
+pub struct WindowTaskbarProps {
+    pub windows: Vec<WindowTaskbarItem>,
+    /// Stacked over the style of the strip.
+    pub style: Option<ElementStylePropSchema>,
+    /// [`StyleClasses`](rfgui::view::StyleClasses) names, stacked beneath `style`.
+    pub class: Option<String>,
+}
+
+impl RsxComponent<WindowTaskbarProps> for WindowTaskbar {
+    fn render(props: WindowTaskbarProps, _children: Vec<RsxNode>) -> RsxNode {
+        let theme = use_theme().0;
+        let entries: Vec<RsxNode> = props
+            .windows
+            .into_iter()
+            .enumerate()
+            .map(|(index, item)| {
+                let minimized = item.state.get().minimized;
+                let state = item.state.clone();
+                let toggle = on_click(move |event| {
+                    state.update(|state| {
+                        *state = if state.minimized {
+                            state.restore()
+                        } else {
+                            state.minimize()
+                        }
+                    });
+                    event.meta.stop_propagation();
+                });
+                let text_color: Box<dyn ColorLike> = if minimized {
+                    theme.color.text.secondary.clone()
+                } else {
+                    theme.color.text.primary.clone()
+                };
+                rsx! {
+                    <Element
+                        key={index}
+                        style={{
+                            layout: Layout::flow().row().no_wrap().align(Align::Center),
+                            padding: Padding::uniform(Length::px(2.0)).x(theme.spacing.sm),
+                            border_radius: theme.component.button.radius,
+                            background: if minimized {
+                                Box::new(Color::transparent()) as Box<dyn ColorLike>
+                            } else {
+                                theme.color.layer.raised.clone()
+                            },
+                            cursor: Cursor::Pointer,
+                            hover: {
+                                background: theme.component.select.option_hover_background.clone(),
+                            }
+                        }}
+                        on_click={toggle}
+                    >
+                        <Text style={{ color: text_color }}>{item.title}</Text>
+                    </Element>
+                }
+            })
+            .collect();
+
+        let strip = rsx! {
+            <Element style={{
+                layout: Layout::flow().row().no_wrap().align(Align::Center),
+                width: Length::percent(100.0),
+                gap: theme.spacing.xs,
+                padding: Padding::uniform(theme.spacing.xs),
+                background: theme.color.layer.surface.clone(),
+                font_size: theme.typography.size.sm,
+            }}>
+                {entries}
+            </Element>
+        };
+        with_root_class_and_style(strip, props.class.as_deref(), props.style)
+    }
+}
+
+#[rfgui::ui::component]
+impl rfgui::ui::RsxTag for WindowTaskbar {
+    type Props = __WindowTaskbarPropsInit;
+    type StrictProps = WindowTaskbarProps;
+    const ACCEPTS_CHILDREN: bool = false;
+
+    fn into_strict(props: Self::Props) -> Self::StrictProps {
+        props.into()
+    }
+
+    fn create_node(
+        props: Self::StrictProps,
+        children: Vec<rfgui::ui::RsxNode>,
+        _key: Option<rfgui::ui::RsxKey>,
+    ) -> rfgui::ui::RsxNode {
+        <Self as RsxComponent<WindowTaskbarProps>>::render(props, children)
+    }
+}
@@ -1,11 +1,15 @@
+mod chart;
 mod inputs;
 mod layout;
 pub mod material_symbol;
+mod node_graph;
 mod theme;
 mod utils;
 
+pub use chart::*;
 pub use inputs::*;
 pub use layout::*;
+pub use node_graph::*;
 pub use theme::*;
 pub use utils::*;
 
@@ -13,9 +17,11 @@ pub use utils::*;
 mod tests {
     use crate::material_symbol::CloseIcon;
     use crate::{
-        Accordion, BranchNode, Button, ButtonVariant, Checkbox, LeafNode, NumberField, Select,
-        Switch, TreeNode, TreeView, Window,
+        Accordion, AnimatedList, BranchNode, Button, ButtonVariant, Checkbox, LeafNode,
+        NumberField, Select, Slider, Switch, TreeNode, TreeView, Window,
     };
+    use rfgui::platform::Key;
+    use rfgui::ui::Modifiers;
     use rfgui::ui::{
         EventMeta, NodeId, PointerButton as UiPointerButton, PointerEventData, PropValue,
         RsxElementNode, RsxNode, RsxTagDescriptor, TextChangeEvent, UiDirtyState, global_state,
@@ -23,7 +29,7 @@ mod tests {
     };
     use rfgui::view::base_component::{LayoutConstraints, LayoutPlacement};
     use rfgui::view::{
-        Element, Image, NodeArena, NodeKey, Text, TextArea, commit_descriptor_tree,
+        Element, ElementQuery, Image, NodeArena, NodeKey, Text, TextArea, commit_descriptor_tree,
         rsx_to_descriptors_with_context,
     };
 
@@ -998,6 +1004,52 @@ mod tests {
         assert!(expanded.get());
     }
 
+    #[test]
+    fn animated_list_items_take_child_keys_and_animate_position() {
+        use rfgui::style::TransitionProperty;
+        use rfgui::ui::RsxKey;
+
+        let tree = rsx! {
+            <AnimatedList duration={Some(240)}>
+                <Text key={"b"}>"B"</Text>
+                <Text key={"a"}>"A"</Text>
+                <Text>"unkeyed"</Text>
+            </AnimatedList>
+        };
+
+        let RsxNode::Element(root) = tree else {
+            panic!("animated list should render element root");
+        };
+        assert_eq!(root.children.len(), 3);
+        let child_key = |index: usize| match &root.children[index] {
+            RsxNode::Element(item) => match &item.children[0] {
+                RsxNode::Element(text) => text.identity.key,
+                other => panic!("expected wrapped text child, got {other:?}"),
+            },
+            other => panic!("expected item element, got {other:?}"),
+        };
+        for index in 0..2 {
+            assert_eq!(root.children[index].identity().key, child_key(index));
+        }
+        assert_eq!(
+            root.children[2].identity().key,
+            Some(RsxKey::Local(2)),
+            "unkeyed children fall back to their index",
+        );
+
+        let RsxNode::Element(item) = &root.children[0] else {
+            unreachable!();
+        };
+        let style = shared_element_style(item).expect("missing item style");
+        let transitions = style.transition.expect("missing item transition");
+        let position = transitions
+            .as_slice()
+            .iter()
+            .find(|transition| transition.property == TransitionProperty::Position)
+            .expect("items should animate position changes");
+        assert_eq!(position.duration_ms, 240);
+    }
+
     #[test]
     fn accordion_header_title_grows_and_icon_stays_intrinsic() {
         let tree = rsx! {
@@ -1121,4 +1173,733 @@ mod tests {
         );
         assert_eq!(cursor, rfgui::style::Cursor::Pointer);
     }
+
+    struct ClosureApp(Box<dyn FnMut() -> RsxNode>);
+
+    impl rfgui::app::App for ClosureApp {
+        fn build(&mut self, _ctx: &mut rfgui::app::AppContext<'_>) -> RsxNode {
+            (self.0)()
+        }
+    }
+
+    fn headless(build: impl FnMut() -> RsxNode + 'static) -> rfgui::app::headless::HeadlessApp {
+        rfgui::app::headless::HeadlessApp::new(ClosureApp(Box::new(build)), 320, 240)
+    }
+
+    fn press(app: &mut rfgui::app::headless::HeadlessApp, key: Key, modifiers: Modifiers) {
+        app.key_press(key, modifiers);
+        app.settle(std::time::Duration::from_millis(16));
+    }
+
+    /// Press the left button at `from`, move straight to `to` and release.
+    fn drag(app: &mut rfgui::app::headless::HeadlessApp, from: (f32, f32), to: (f32, f32)) {
+        use rfgui::platform::{
+            PlatformPointerButton, PlatformPointerEvent, PlatformPointerEventKind, PointerType,
+        };
+        let pointer = |kind| {
+            rfgui::app::AppEvent::Pointer(PlatformPointerEvent {
+                kind,
+                pointer_id: 0,
+                pointer_type: PointerType::Mouse,
+                pressure: 0.5,
+            })
+        };
+        let button = PlatformPointerButton::Left;
+        app.pointer_move(from.0, from.1);
+        app.send(pointer(PlatformPointerEventKind::Down(button)));
+        app.pointer_move(to.0, to.1);
+        app.send(pointer(PlatformPointerEventKind::Up(button)));
+        app.settle(std::time::Duration::from_millis(16));
+    }
+
+    #[test]
+    fn checkbox_and_switch_toggle_on_enter_and_space() {
+        // `global_state` is keyed by type, so the switch gets its own binding.
+        let checked = global_state(|| false);
+        let on = rfgui::ui::Binding::new(false);
+        let (checked_binding, on_binding) = (checked.binding(), on.clone());
+        let mut app = headless(move || {
+            rsx! {
+                <Element>
+                    <Checkbox label="Wrap lines" binding={checked_binding.clone()} />
+                    <Switch label="Minimap" binding={on_binding.clone()} />
+                </Element>
+            }
+        });
+
+        app.click_on(&ElementQuery::new().text("Wrap lines"));
+        app.settle(std::time::Duration::from_millis(16));
+        assert!(checked.get());
+        press(&mut app, Key::Space, Modifiers::empty());
+        assert!(!checked.get());
+        press(&mut app, Key::Enter, Modifiers::empty());
+        assert!(checked.get());
+        press(&mut app, Key::Enter, Modifiers::CTRL);
+        assert!(checked.get(), "shortcut chords are not activations");
+
+        app.click_on(&ElementQuery::new().text("Minimap"));
+        app.settle(std::time::Duration::from_millis(16));
+        assert!(on.get());
+        press(&mut app, Key::Space, Modifiers::empty());
+        assert!(!on.get());
+        assert!(checked.get());
+    }
+
+    #[test]
+    fn switch_before_change_holds_pending_value_until_answered() {
+        use std::cell::Cell;
+        use std::future::Future;
+        use std::pin::Pin;
+        use std::rc::Rc;
+        use std::task::{Context, Poll};
+
+        struct Answer(Rc<Cell<Option<bool>>>);
+
+        impl Future for Answer {
+            type Output = bool;
+
+            fn poll(self: Pin<&mut Self>, _cx: &mut Context<'_>) -> Poll<bool> {
+                self.0.get().map_or(Poll::Pending, Poll::Ready)
+            }
+        }
+
+        let answer = Rc::new(Cell::new(None::<bool>));
+        let changes = Rc::new(Cell::new(0_u32));
+        let on = rfgui::ui::Binding::new(false);
+        let (answer_for_build, changes_for_build, on_binding) =
+            (answer.clone(), changes.clone(), on.clone());
+        let mut app = headless(move || {
+            let answer = answer_for_build.clone();
+            let changes = changes_for_build.clone();
+            let before_change: crate::SwitchBeforeChange =
+                Rc::new(move |_requested| Box::pin(Answer(answer.clone())));
+            let on_change: Rc<dyn Fn(bool)> = Rc::new(move |_| changes.set(changes.get() + 1));
+            rsx! {
+                <Switch
+                    label="Sync"
+                    size="small"
+                    label_placement="start"
+                    binding={on_binding.clone()}
+                    before_change={before_change}
+                    on_change={on_change}
+                />
+            }
+        });
+        let tick = |app: &mut rfgui::app::headless::HeadlessApp| {
+            app.tick(std::time::Duration::from_millis(16));
+            app.settle(std::time::Duration::from_millis(16));
+        };
+
+        app.click_on(&ElementQuery::new().text("Sync"));
+        tick(&mut app);
+        assert!(on.get(), "the requested value shows while pending");
+        app.click_on(&ElementQuery::new().text("Sync"));
+        tick(&mut app);
+        assert!(on.get(), "clicks are ignored while pending");
+
+        answer.set(Some(false));
+        tick(&mut app);
+        assert!(!on.get(), "a rejected change reverts");
+        assert_eq!(changes.get(), 0);
+
+        app.click_on(&ElementQuery::new().text("Sync"));
+        tick(&mut app);
+        assert!(!on.get(), "a ready answer applies without waiting");
+
+        answer.set(None);
+        app.click_on(&ElementQuery::new().text("Sync"));
+        tick(&mut app);
+        assert!(on.get());
+        answer.set(Some(true));
+        tick(&mut app);
+        assert!(on.get(), "an accepted change stays");
+        assert_eq!(changes.get(), 1);
+    }
+
+    #[test]
+    fn use_theme_follows_contrast_preference() {
+        use rfgui::platform::ContrastPreference;
+
+        let mut app = headless(|| {
+            let theme = crate::use_theme().0;
+            let label = if theme.high_contrast {
+                "high contrast"
+            } else {
+                "standard"
+            };
+            rsx! { <Text>{label}</Text> }
+        });
+        app.get(&ElementQuery::new().text("standard"));
+
+        app.viewport_mut().set_system_high_contrast(true);
+        app.settle(std::time::Duration::from_millis(16));
+        app.get(&ElementQuery::new().text("high contrast"));
+
+        app.viewport_mut()
+            .set_contrast_preference(ContrastPreference::Standard);
+        app.settle(std::time::Duration::from_millis(16));
+        app.get(&ElementQuery::new().text("standard"));
+    }
+
+    #[test]
+    fn high_contrast_theme_drops_shadows_and_strengthens_borders() {
+        let theme = crate::Theme::high_contrast_dark();
+        assert!(theme.high_contrast);
+        for shadow in [&theme.shadow.level_1, &theme.shadow.level_3] {
+            assert_eq!(shadow.color.to_color().to_rgba_u8()[3], 0);
+        }
+        assert_eq!(theme.color.border.to_rgba_u8(), [255, 255, 255, 255]);
+        assert_eq!(
+            crate::Theme::high_contrast_light()
+                .color
+                .text
+                .primary
+                .to_rgba_u8(),
+            [0, 0, 0, 255]
+        );
+    }
+
+    #[test]
+    fn button_enter_runs_click_handler() {
+        let clicks = global_state(|| 0_u32);
+        let clicks_binding = clicks.binding();
+        let mut app = headless(move || {
+            let clicks = clicks_binding.clone();
+            let on_click = rfgui::ui::ClickHandlerProp::new(move |event| {
+                assert_eq!(event.pointer.button, None);
+                clicks.set(clicks.get() + 1);
+            });
+            rsx! {
+                <Button on_click={on_click}>Save</Button>
+            }
+        });
+
+        let label = app.get(&ElementQuery::new().text("Save")).node();
+        app.viewport_mut().set_focused_node_id(Some(label));
+        press(&mut app, Key::Enter, Modifiers::empty());
+        press(&mut app, Key::Space, Modifiers::empty());
+        press(&mut app, Key::KeyA, Modifiers::empty());
+        assert_eq!(clicks.get(), 2);
+    }
+
+    #[test]
+    fn slider_arrows_step_and_home_end_jump() {
+        let value = global_state(|| 30.0_f64);
+        let value_binding = value.binding();
+        let mut app = headless(move || {
+            rsx! {
+                <Slider binding={value_binding.clone()} min={0.0} max={10.0} />
+            }
+        });
+
+        let readout = app.get(&ElementQuery::new().text("10")).node();
+        app.viewport_mut().set_focused_node_id(Some(readout));
+        press(&mut app, Key::ArrowRight, Modifiers::empty());
+        assert_eq!(
+            value.get(),
+            10.0,
+            "out-of-range value clamps before stepping"
+        );
+        press(&mut app, Key::ArrowLeft, Modifiers::empty());
+        assert_eq!(value.get(), 9.0);
+        press(&mut app, Key::ArrowUp, Modifiers::empty());
+        assert_eq!(value.get(), 9.0, "vertical arrows are not bound");
+        press(&mut app, Key::Home, Modifiers::empty());
+        assert_eq!(value.get(), 0.0);
+        press(&mut app, Key::End, Modifiers::empty());
+        assert_eq!(value.get(), 10.0);
+    }
+
+    #[test]
+    fn select_arrows_move_selection_past_disabled_options() {
+        fn option_disabled(item: &String, _: usize) -> bool {
+            item == "Option B"
+        }
+
+        let selected = global_state(|| String::from("Option A"));
+        let selected_binding = selected.binding();
+        let mut app = headless(move || {
+            rsx! {
+                <Select::<String, String>
+                    data={vec![
+                        String::from("Option A"),
+                        String::from("Option B"),
+                        String::from("Option C"),
+                    ]}
+                    to_label={select_label as fn(&String, usize) -> String}
+                    to_disabled={option_disabled as fn(&String, usize) -> bool}
+                    value={selected_binding.clone()}
+                />
+            }
+        });
+
+        let trigger = app.get(&ElementQuery::new().text("Option A")).node();
+        app.viewport_mut().set_focused_node_id(Some(trigger));
+        press(&mut app, Key::ArrowDown, Modifiers::empty());
+        assert_eq!(selected.get(), "Option C");
+        press(&mut app, Key::ArrowDown, Modifiers::empty());
+        assert_eq!(selected.get(), "Option C");
+        press(&mut app, Key::Home, Modifiers::empty());
+        assert_eq!(selected.get(), "Option A");
+        press(&mut app, Key::End, Modifiers::empty());
+        assert_eq!(selected.get(), "Option C");
+        press(&mut app, Key::ArrowUp, Modifiers::empty());
+        assert_eq!(selected.get(), "Option A");
+    }
+
+    #[test]
+    fn select_groups_options_under_headers_and_renders_custom_rows() {
+        let fruit_group: fn(&String, usize) -> Option<String> = |item, _| {
+            let group = if item.starts_with('A') {
+                "Apples"
+            } else {
+                "Berries"
+            };
+            Some(group.to_string())
+        };
+
+        let selected = global_state(|| String::from("Akane"));
+        let selected_binding = selected.binding();
+        let mut app = headless(move || {
+            let render_option: crate::SelectRenderOption<String> =
+                std::rc::Rc::new(|item: &String, _, state: crate::SelectOptionState| {
+                    let marker = if state.selected { "*" } else { "-" };
+                    rsx! { <Text>{format!("{marker} {item}")}</Text> }
+                });
+            rsx! {
+                <Select::<String, String>
+                    data={vec![
+                        String::from("Akane"),
+                        String::from("Ambrosia"),
+                        String::from("Bilberry"),
+                    ]}
+                    to_label={select_label as fn(&String, usize) -> String}
+                    to_group={fruit_group}
+                    render_option={render_option}
+                    value={selected_binding.clone()}
+                />
+            }
+        });
+
+        app.click_on(&ElementQuery::new().text("Akane"));
+        app.settle(std::time::Duration::from_millis(16));
+        app.get(&ElementQuery::new().text("Apples"));
+        app.get(&ElementQuery::new().text("* Akane"));
+
+        app.click_on(&ElementQuery::new().text("Berries"));
+        app.settle(std::time::Duration::from_millis(16));
+        assert_eq!(selected.get(), "Akane", "group headers are not options");
+        app.click_on(&ElementQuery::new().text("- Bilberry"));
+        app.settle(std::time::Duration::from_millis(16));
+        assert_eq!(selected.get(), "Bilberry");
+    }
+
+    #[test]
+    fn window_maximizes_snaps_and_minimizes_to_the_taskbar() {
+        use crate::{WindowPlacement, WindowSnap, WindowState, WindowTaskbar, WindowTaskbarItem};
+
+        let state = rfgui::ui::Binding::new(WindowState::default());
+        let state_binding = state.clone();
+        let mut app = headless(move || {
+            let taskbar = vec![WindowTaskbarItem::new(
+                "Taskbar entry",
+                state_binding.clone(),
+            )];
+            rsx! {
+                <Element style={{
+                    width: rfgui::style::Length::percent(100.0),
+                    height: rfgui::style::Length::percent(100.0),
+                }}>
+                    <WindowTaskbar windows={taskbar} />
+                    <Window
+                        title="Panel"
+                        width={240.0}
+                        position={(40.0, 40.0)}
+                        minimizable={true}
+                        state={state_binding.clone()}
+                    />
+                </Element>
+            }
+        });
+        let settle = |app: &mut rfgui::app::headless::HeadlessApp| {
+            app.settle(std::time::Duration::from_millis(16));
+        };
+        let title_rect = |app: &rfgui::app::headless::HeadlessApp| {
+            app.get(&ElementQuery::new().text("Panel")).rect()
+        };
+
+        app.click_on(&ElementQuery::new().text("crop_square"));
+        settle(&mut app);
+        assert_eq!(state.get().placement, WindowPlacement::Maximized);
+        assert!(title_rect(&app).x < 20.0, "maximized to the parent");
+        app.click_on(&ElementQuery::new().text("filter_none"));
+        settle(&mut app);
+        assert_eq!(state.get(), WindowState::default());
+        assert!(title_rect(&app).x >= 40.0, "restored to its own bounds");
+
+        let rect = title_rect(&app);
+        drag(&mut app, (rect.x + 2.0, rect.y + 2.0), (1.0, 120.0));
+        assert_eq!(
+            state.get().placement,
+            WindowPlacement::Snapped(WindowSnap::Left)
+        );
+
+        app.click_on(&ElementQuery::new().text("minimize"));
+        settle(&mut app);
+        assert!(state.get().minimized);
+        assert!(app.query(&ElementQuery::new().text("Panel")).is_empty());
+        app.click_on(&ElementQuery::new().text("Taskbar entry"));
+        settle(&mut app);
+        assert_eq!(
+            state.get(),
+            WindowState {
+                placement: WindowPlacement::Snapped(WindowSnap::Left),
+                minimized: false,
+            },
+            "restoring keeps the snapped placement"
+        );
+    }
+
+    #[test]
+    fn dock_space_docks_dragged_tabs_and_round_trips_its_layout() {
+        use crate::{DockAxis, DockLayout, DockNode, DockPanel, DockPosition, DockSpace};
+
+        let layout = rfgui::ui::Binding::new(DockLayout::new(DockNode::split(
+            DockAxis::Row,
+            0.5,
+            DockNode::tabs(["files", "outline"]),
+            DockNode::tabs(["editor"]),
+        )));
+        let layout_binding = layout.clone();
+        let mut app = headless(move || {
+            let panels = vec![
+                DockPanel::new("files", "Files", rsx! { <Text>{"file list"}</Text> }),
+                DockPanel::new("outline", "Outline", rsx! { <Text>{"symbols"}</Text> }),
+                DockPanel::new("editor", "Editor", rsx! { <Text>{"source"}</Text> }),
+            ];
+            rsx! {
+                <Element style={{
+                    width: rfgui::style::Length::percent(100.0),
+                    height: rfgui::style::Length::percent(100.0),
+                }}>
+                    <DockSpace layout={layout_binding.clone()} panels={panels} />
+                </Element>
+            }
+        });
+        app.settle(std::time::Duration::from_millis(16));
+        assert!(app.query(&ElementQuery::new().text("symbols")).is_empty());
+
+        let tab = app.get(&ElementQuery::new().text("Outline")).rect();
+        drag(&mut app, (tab.x + 2.0, tab.y + 2.0), (240.0, 232.0));
+        assert_eq!(
+            layout.get(),
+            DockLayout::new(DockNode::split(
+                DockAxis::Row,
+                0.5,
+                DockNode::tabs(["files"]),
+                DockNode::split(
+                    DockAxis::Column,
+                    0.5,
+                    DockNode::tabs(["editor"]),
+                    DockNode::tabs(["outline"]),
+                ),
+            )),
+            "dropping on the bottom edge splits the editor group"
+        );
+        assert!(!app.query(&ElementQuery::new().text("symbols")).is_empty());
+
+        layout.update(|layout| {
+            layout.dock("files", "editor", DockPosition::Tab);
+        });
+        app.settle(std::time::Duration::from_millis(16));
+        app.click_on(&ElementQuery::new().text("Editor"));
+        app.settle(std::time::Duration::from_millis(16));
+        let text = layout.get().to_text();
+        assert_eq!(
+            text,
+            "rfgui-dock-layout 1\nsplit column 0.5\ntabs 0 \"editor\" \"files\"\ntabs 0 \"outline\"\n"
+        );
+        assert_eq!(DockLayout::parse(&text), Ok(layout.get()));
+        assert!(DockLayout::parse("split row 0.5").is_err());
+        assert!(
+            DockLayout::parse("rfgui-dock-layout 1\ntabs 0 \"a\"\ntabs 0 \"b\"").is_err(),
+            "a second root is rejected"
+        );
+    }
+
+    #[test]
+    fn charts_compute_nice_ticks_and_show_hover_tooltips() {
+        use crate::{AxisTicks, BarChart, ChartSeries, PieChart, PieSlice};
+
+        let ticks = AxisTicks::nice(3.0, 97.0, 5);
+        assert_eq!((ticks.min, ticks.max, ticks.step), (0.0, 100.0, 20.0));
+        assert_eq!(ticks.values(), vec![0.0, 20.0, 40.0, 60.0, 80.0, 100.0]);
+        let fine = AxisTicks::nice(0.0, 0.9, 5);
+        assert_eq!(fine.format(0.2), "0.2");
+        assert!(AxisTicks::nice(5.0, 5.0, 5).values().len() > 1);
+
+        let mut app = headless(|| {
+            let labels = vec!["Q1".to_string(), "Q2".to_string()];
+            let series = vec![ChartSeries::new("Revenue", vec![120.0, 80.0])];
+            let slices = vec![PieSlice::new("Rent", 3.0), PieSlice::new("Food", 1.0)];
+            rsx! {
+                <Element style={{ layout: rfgui::style::Layout::flow().row().no_wrap() }}>
+                    <BarChart
+                        labels={labels}
+                        series={series}
+                        width={160.0}
+                        height={200.0}
+                        animate={false}
+                    />
+                    <PieChart slices={slices} width={160.0} height={200.0} animate={false} />
+                </Element>
+            }
+        });
+        app.settle(std::time::Duration::from_millis(16));
+        assert!(
+            !app.query(&ElementQuery::new().text("150")).is_empty(),
+            "value axis labels"
+        );
+
+        let q1 = app.get(&ElementQuery::new().text("Q1")).rect();
+        app.pointer_move(q1.x + q1.width / 2.0, q1.y - 10.0);
+        app.settle(std::time::Duration::from_millis(16));
+        assert!(
+            !app.query(&ElementQuery::new().text("Revenue: 120"))
+                .is_empty()
+        );
+
+        // Right of the pie's center lies in the first, larger slice.
+        app.pointer_move(160.0 + 120.0, 88.0);
+        app.settle(std::time::Duration::from_millis(16));
+        assert!(
+            app.query(&ElementQuery::new().text("Revenue: 120"))
+                .is_empty()
+        );
+        assert!(!app.query(&ElementQuery::new().text("3 (75.0%)")).is_empty());
+    }
+
+    #[test]
+    fn minimap_scrolls_its_container_on_press_and_drag() {
+        use crate::Minimap;
+
+        let mut app = headless(|| {
+            let lines: Vec<RsxNode> = (0..40)
+                .map(|index| {
+                    rsx! {
+                        <Element key={index} style={{ height: rfgui::style::Length::px(20.0) }}>
+                            <Text>{format!("line {index}")}</Text>
+                        </Element>
+                    }
+                })
+                .collect();
+            rsx! {
+                <Element style={{
+                    width: rfgui::style::Length::percent(100.0),
+                    height: rfgui::style::Length::percent(100.0),
+                }}>
+                    <Minimap width={80.0}>{lines}</Minimap>
+                </Element>
+            }
+        });
+        app.settle(std::time::Duration::from_millis(16));
+        app.settle(std::time::Duration::from_millis(16));
+        // The container's copy comes first; the overview holds the second.
+        let first_line = |app: &rfgui::app::headless::HeadlessApp| {
+            app.query(&ElementQuery::new().text("line 0"))[0].rect().y
+        };
+        assert_eq!(app.query(&ElementQuery::new().text("line 0")).len(), 2);
+        let top = first_line(&app);
+
+        // 800px of content in a 240px view: a press near the bottom of the
+        // overview scrolls to the end.
+        drag(&mut app, (280.0, 230.0), (280.0, 230.0));
+        app.settle(std::time::Duration::from_millis(16));
+        assert!((first_line(&app) - (top - 560.0)).abs() < 1.0);
+
+        // Dragging the view rectangle back up to the top scrolls back.
+        drag(&mut app, (280.0, 200.0), (280.0, 0.0));
+        app.settle(std::time::Duration::from_millis(16));
+        assert!((first_line(&app) - top).abs() < 1.0);
+    }
+
+    #[test]
+    fn node_graph_connects_ports_moves_nodes_and_box_selects() {
+        use crate::{GraphEdge, GraphNode, NodeGraph, NodeGraphState};
+
+        let graph = rfgui::ui::Binding::new(NodeGraphState::new(
+            [
+                GraphNode::new("a", "Source", (10.0, 10.0)).output("out"),
+                GraphNode::new("b", "Sink", (140.0, 130.0)).input("in"),
+            ],
+            [],
+        ));
+        let graph_binding = graph.clone();
+        let mut app = headless(move || {
+            rsx! {
+                <Element style={{
+                    width: rfgui::style::Length::percent(100.0),
+                    height: rfgui::style::Length::percent(100.0),
+                }}>
+                    <NodeGraph graph={graph_binding.clone()} />
+                </Element>
+            }
+        });
+        app.settle(std::time::Duration::from_millis(16));
+        assert!(!app.query(&ElementQuery::new().text("Source")).is_empty());
+
+        // Output port of "a" to the input port of "b".
+        drag(&mut app, (170.0, 49.0), (140.0, 169.0));
+        assert_eq!(
+            graph.get().edges,
+            vec![GraphEdge::new("a", "out", "b", "in")]
+        );
+
+        // The curve's midpoint selects the edge.
+        drag(&mut app, (155.0, 109.0), (155.0, 109.0));
+        assert_eq!(graph.get().selected_edge, Some(0));
+
+        drag(&mut app, (50.0, 20.0), (60.0, 30.0));
+        let state = graph.get();
+        assert_eq!(
+            state.node("a").map(|node| node.position),
+            Some((20.0, 20.0))
+        );
+        assert_eq!(state.selected_nodes, vec!["a".to_string()]);
+
+        drag(&mut app, (5.0, 120.0), (315.0, 235.0));
+        assert_eq!(graph.get().selected_nodes, vec!["b".to_string()]);
+
+        graph.update(|state| {
+            state.zoom_at((100.0, 100.0), 2.0);
+            assert_eq!(state.to_graph((100.0, 100.0)), (100.0, 100.0));
+            state.remove_selected();
+        });
+        let state = graph.get();
+        assert!(state.edges.is_empty(), "removing a node drops its edges");
+        assert_eq!(state.nodes.len(), 1);
+        assert_eq!(state.zoom, 2.0);
+    }
+
+    #[test]
+    fn pop_confirm_resolves_by_click_keyboard_and_focus_loss() {
+        use crate::{PopConfirm, TooltipPlacement};
+        use std::rc::Rc;
+
+        let outcomes = global_state(Vec::<&'static str>::new);
+        let outcomes_binding = outcomes.binding();
+        let mut app = headless(move || {
+            let confirmed = outcomes_binding.clone();
+            let cancelled = outcomes_binding.clone();
+            let on_confirm: Rc<dyn Fn()> =
+                Rc::new(move || confirmed.update(|log| log.push("confirm")));
+            let on_cancel: Rc<dyn Fn()> =
+                Rc::new(move || cancelled.update(|log| log.push("cancel")));
+            rsx! {
+                <Element>
+                    <PopConfirm
+                        title="Delete item?"
+                        placement={TooltipPlacement::BottomStart}
+                        on_confirm={on_confirm}
+                        on_cancel={on_cancel}
+                    >
+                        <Button>Delete</Button>
+                    </PopConfirm>
+                    <Text>Elsewhere</Text>
+                </Element>
+            }
+        });
+        let trigger = ElementQuery::new().text("Delete");
+        let title = ElementQuery::new().text("Delete item?");
+
+        app.click_on(&trigger);
+        app.settle(std::time::Duration::from_millis(16));
+        assert_eq!(app.query(&title).len(), 1);
+        app.click_on(&ElementQuery::new().text("OK"));
+        app.settle(std::time::Duration::from_millis(16));
+        assert!(app.query(&title).is_empty());
+        assert_eq!(outcomes.get(), vec!["confirm"]);
+
+        app.click_on(&trigger);
+        app.settle(std::time::Duration::from_millis(16));
+        press(&mut app, Key::Escape, Modifiers::empty());
+        assert!(app.query(&title).is_empty());
+
+        app.click_on(&trigger);
+        app.settle(std::time::Duration::from_millis(16));
+        press(&mut app, Key::Tab, Modifiers::empty());
+        press(&mut app, Key::Tab, Modifiers::empty());
+        press(&mut app, Key::Enter, Modifiers::empty());
+        assert!(app.query(&title).is_empty());
+        assert_eq!(outcomes.get(), vec!["confirm", "cancel", "confirm"]);
+
+        app.click_on(&trigger);
+        app.settle(std::time::Duration::from_millis(16));
+        let elsewhere = app.get(&ElementQuery::new().text("Elsewhere")).rect();
+        app.click(elsewhere.x + 2.0, elsewhere.y + 2.0);
+        app.settle(std::time::Duration::from_millis(16));
+        assert!(app.query(&title).is_empty());
+        assert_eq!(
+            outcomes.get(),
+            vec!["confirm", "cancel", "confirm", "cancel"]
+        );
+    }
+
+    #[test]
+    fn buttons_and_switches_play_theme_sounds() {
+        use rfgui::platform::{SoundCommand, SoundSource};
+        use std::cell::Cell;
+        use std::rc::Rc;
+
+        let themed = Rc::new(Cell::new(false));
+        let mut app = headless(move || {
+            let (mut theme, set_theme) = crate::use_theme();
+            if !themed.replace(true) {
+                theme.sound.click = Some("click".to_string());
+                theme.sound.toggle = Some("toggle".to_string());
+                theme.sound.error = Some("error".to_string());
+                set_theme(theme);
+            }
+            let refuse: crate::SwitchBeforeChange =
+                Rc::new(|_requested| Box::pin(std::future::ready(false)));
+            rsx! {
+                <Element style={{ layout: rfgui::style::Layout::flow().column() }}>
+                    <Button on_click={rfgui::ui::ClickHandlerProp::new(|_| {})}>Save</Button>
+                    <Button sound="chime" on_click={rfgui::ui::ClickHandlerProp::new(|_| {})}>
+                        Send
+                    </Button>
+                    <Switch label="Sync" />
+                    <Switch label="Lock" before_change={refuse} />
+                </Element>
+            }
+        });
+        for name in ["click", "toggle", "error", "chime"] {
+            let source = SoundSource::Path(format!("{name}.wav"));
+            app.viewport_mut().register_sound(name, source);
+        }
+        app.settle(std::time::Duration::from_millis(16));
+        app.take_sound_commands();
+        let plays = |app: &mut rfgui::app::headless::HeadlessApp, label: &str| {
+            app.click_on(&ElementQuery::new().text(label));
+            app.settle(std::time::Duration::from_millis(16));
+            app.take_sound_commands()
+                .into_iter()
+                .filter_map(|command| match command {
+                    SoundCommand::Play { name, .. } => Some(name.to_string()),
+                    SoundCommand::Register { .. } => None,
+                })
+                .collect::<Vec<_>>()
+        };
+
+        assert_eq!(plays(&mut app, "Save"), vec!["click"]);
+        assert_eq!(
+            plays(&mut app, "Send"),
+            vec!["chime"],
+            "the prop overrides the theme"
+        );
+        assert_eq!(plays(&mut app, "Sync"), vec!["toggle"]);
+        assert_eq!(plays(&mut app, "Lock"), vec!["error"]);
+        app.viewport_mut().set_sound_muted(true);
+        assert!(plays(&mut app, "Save").is_empty());
+    }
 }
@@ -0,0 +1,500 @@
+use super::model::{
+    GraphEdge, GraphNode, GraphPort, NODE_HEADER_HEIGHT, NodeGraphState, PORT_ROW_HEIGHT, PortSide,
+    edge_curve,
+};
+use crate::chart::svg_paint;
+use crate::{Theme, use_theme};
+use rfgui::platform::WheelDeltaMode;
+use rfgui::style::{
+    Align, Anchor, Border, BorderRadius, Color, Cursor, JustifyContent, Layout, Length, Padding,
+    Position, Scale, Transform, TransformOrigin,
+};
+use rfgui::ui::{
+    Binding, Modifiers, PointerButton, PointerEventData, Rect, RsxComponent, RsxNode, component,
+    on_pointer_down, on_pointer_move, on_pointer_up, on_resize, on_wheel, props, rsx, use_state,
+};
+use rfgui::view::{
+    Element, ElementStylePropSchema, ImageFit, RootStyleProps, Svg, SvgSource, Text,
+};
+use std::fmt::Write as _;
+
+/// Spacing of the background dot grid, in graph units.
+const GRID_STEP: f32 = 24.0;
+const PORT_RADIUS: f32 = 5.0;
+/// How close, in screen pixels, a press must land to grab a port.
+const PORT_HIT_RADIUS: f32 = 10.0;
+/// How close, in screen pixels, a press must land to select an edge.
+const EDGE_HIT_TOLERANCE: f32 = 6.0;
+/// Wheel travel, in pixels, that scales the view by a factor of e.
+const WHEEL_ZOOM_DISTANCE: f32 = 500.0;
+/// Pixels per wheel line, matching the default line step of scrolling.
+const WHEEL_LINE: f32 = 28.0;
+
+/// Pointer gesture in progress on the canvas. Points are in graph units
+/// except for panning, which works in screen pixels.
+#[derive(Clone, Debug, PartialEq)]
+enum GraphDrag {
+    Idle,
+    Pan {
+        pointer: (f32, f32),
+        pan: (f32, f32),
+    },
+    Move {
+        pointer: (f32, f32),
+        origins: Vec<(String, (f32, f32))>,
+    },
+    Connect {
+        from: GraphPort,
+        pointer: (f32, f32),
+    },
+    Marquee {
+        start: (f32, f32),
+        current: (f32, f32),
+        additive: bool,
+    },
+}
+
+/// Pannable, zoomable canvas of [`GraphNode`]s joined by bezier edges.
+///
+/// - Drag a node to move it, with every other selected node.
+/// - Drag from an output port to an input port to connect them; dragging
+///   from a connected input picks its edge back up.
+/// - Click an edge to select it; drag over empty space to box-select.
+///   Shift adds to the selection.
+/// - Drag with the middle or right button to pan, and use the wheel to
+///   zoom about the pointer.
+///
+/// Everything lives in the [`NodeGraphState`] binding, so deleting the
+/// selection or saving the graph is done on the state directly.
+pub struct NodeGraph;
+
+#[derive(Clone)]
+#[props]
+pub struct NodeGraphProps {
+    pub graph: Binding<NodeGraphState>,
+    /// Stacked over the style of the canvas.
+    pub style: Option<ElementStylePropSchema>,
+    /// [`StyleClasses`](rfgui::view::StyleClasses) names, stacked beneath `style`.
+    pub class: Option<String>,
+}
+
+impl RsxComponent<NodeGraphProps> for NodeGraph {
+    fn render(props: NodeGraphProps, _children: Vec<RsxNode>) -> RsxNode {
+        rsx! {
+            <NodeGraphView
+                graph={props.graph}
+                root_style={RootStyleProps::new(props.style, props.class)}
+            />
+        }
+    }
+}
+
+#[rfgui::ui::component]
+impl rfgui::ui::RsxTag for NodeGraph {
+    type Props = __NodeGraphPropsInit;
+    type StrictProps = NodeGraphProps;
+    const ACCEPTS_CHILDREN: bool = false;
+
+    fn into_strict(props: Self::Props) -> Self::StrictProps {
+        props.into()
+    }
+
+    fn create_node(
+        props: Self::StrictProps,
+        children: Vec<RsxNode>,
+        _key: Option<rfgui::ui::RsxKey>,
+    ) -> RsxNode {
+        <Self as RsxComponent<NodeGraphProps>>::render(props, children)
+    }
+}
+
+/// Pointer position relative to the canvas, which handles every gesture
+/// itself and so is always the current target.
+fn canvas_point(pointer: &PointerEventData, canvas: Rect) -> (f32, f32) {
+    (pointer.viewport_x - canvas.x, pointer.viewport_y - canvas.y)
+}
+
+fn theme_color(color: &dyn rfgui::style::ColorLike) -> Color {
+    let [r, g, b, a] = color.to_rgba_u8();
+    Color::rgba(r, g, b, a)
+}
+
+fn svg_curve(out: &mut String, curve: [(f32, f32); 4]) {
+    let [a, b, c, d] = curve;
+    let _ = write!(
+        out,
+        r#"<path d="M{} {} C{} {} {} {} {} {}" fill="none""#,
+        a.0, a.1, b.0, b.1, c.0, c.1, d.0, d.1
+    );
+}
+
+#[component]
+fn NodeGraphView(graph: Binding<NodeGraphState>, root_style: RootStyleProps) -> RsxNode {
+    let theme = use_theme().0;
+    let size = use_state(|| (0.0_f32, 0.0_f32));
+    let drag = use_state(|| GraphDrag::Idle);
+    let state = graph.get();
+    let (width, height) = size.get();
+
+    let press = {
+        let graph = graph.clone();
+        let drag = drag.binding();
+        on_pointer_down(move |event| {
+            let screen = canvas_point(&event.pointer, event.meta.current_target().bounds);
+            let mut state = graph.get();
+            let point = state.to_graph(screen);
+            let additive = event.pointer.modifiers.contains(Modifiers::SHIFT);
+            let next = match event.pointer.button {
+                Some(PointerButton::Middle | PointerButton::Right) => GraphDrag::Pan {
+                    pointer: screen,
+                    pan: state.pan,
+                },
+                Some(PointerButton::Left) => {
+                    if let Some(port) = state.port_at(point, PORT_HIT_RADIUS / state.zoom) {
+                        let from = match port.side {
+                            PortSide::Output => Some(port),
+                            PortSide::Input => state
+                                .edges
+                                .iter()
+                                .position(|edge| edge.to == port)
+                                .map(|index| {
+                                    state.selected_edge = None;
+                                    state.edges.remove(index).from
+                                }),
+                        };
+                        match from {
+                            Some(from) => GraphDrag::Connect {
+                                from,
+                                pointer: point,
+                            },
+                            None => GraphDrag::Idle,
+                        }
+                    } else if let Some(index) = state.node_at(point) {
+                        let id = state.nodes[index].id.clone();
+                        state.select_node(&id, additive);
+                        let origins = state
+                            .nodes
+                            .iter()
+                            .filter(|node| state.is_selected(&node.id))
+                            .map(|node| (node.id.clone(), node.position))
+                            .collect();
+                        GraphDrag::Move {
+                            pointer: point,
+                            origins,
+                        }
+                    } else if let Some(index) =
+                        state.edge_at(point, EDGE_HIT_TOLERANCE / state.zoom)
+                    {
+                        state.select_edge(Some(index));
+                        GraphDrag::Idle
+                    } else {
+                        if !additive {
+                            state.clear_selection();
+                        }
+                        GraphDrag::Marquee {
+                            start: point,
+                            current: point,
+                            additive,
+                        }
+                    }
+                }
+                _ => return,
+            };
+            graph.set(state);
+            drag.set(next);
+            event.meta.request_pointer_capture();
+            event.meta.stop_propagation();
+        })
+    };
+    let track = {
+        let graph = graph.clone();
+        let drag = drag.binding();
+        on_pointer_move(move |event| {
+            let current = drag.get();
+            if current == GraphDrag::Idle {
+                return;
+            }
+            let screen = canvas_point(&event.pointer, event.meta.current_target().bounds);
+            let point = graph.get().to_graph(screen);
+            match current {
+                GraphDrag::Idle => {}
+                GraphDrag::Pan { pointer, pan } => graph.update(|state| {
+                    state.pan = (pan.0 + screen.0 - pointer.0, pan.1 + screen.1 - pointer.1);
+                }),
+                GraphDrag::Move { pointer, origins } => graph.update(|state| {
+                    let delta = (point.0 - pointer.0, point.1 - pointer.1);
+                    for (id, origin) in &origins {
+                        if let Some(node) = state.nodes.iter_mut().find(|node| node.id == *id) {
+                            node.position = (origin.0 + delta.0, origin.1 + delta.1);
+                        }
+                    }
+                }),
+                GraphDrag::Connect { from, .. } => drag.set(GraphDrag::Connect {
+                    from,
+                    pointer: point,
+                }),
+                GraphDrag::Marquee {
+                    start, additive, ..
+                } => drag.set(GraphDrag::Marquee {
+                    start,
+                    current: point,
+                    additive,
+                }),
+            }
+            event.meta.stop_propagation();
+        })
+    };
+    let release = {
+        let graph = graph.clone();
+        let drag = drag.binding();
+        on_pointer_up(move |event| {
+            let screen = canvas_point(&event.pointer, event.meta.current_target().bounds);
+            let point = graph.get().to_graph(screen);
+            match drag.get() {
+                GraphDrag::Connect { from, .. } => graph.update(|state| {
+                    if let Some(to) = state.port_at(point, PORT_HIT_RADIUS / state.zoom) {
+                        state.connect(GraphEdge { from, to });
+                    }
+                }),
+                GraphDrag::Marquee {
+                    start, additive, ..
+                } => graph.update(|state| state.select_in_rect(start, point, additive)),
+                _ => {}
+            }
+            drag.set(GraphDrag::Idle);
+        })
+    };
+    let wheel = {
+        let graph = graph.clone();
+        on_wheel(move |event| {
+            let canvas = event.meta.current_target().bounds;
+            let screen = (event.viewport_x - canvas.x, event.viewport_y - canvas.y);
+            let delta = match event.delta_mode {
+                WheelDeltaMode::Pixel => event.delta_y,
+                _ => event.delta_y * WHEEL_LINE,
+            };
+            graph.update(|state| state.zoom_at(screen, (-delta / WHEEL_ZOOM_DISTANCE).exp()));
+            event.meta.prevent_default();
+            event.meta.stop_propagation();
+        })
+    };
+    let resized = {
+        let size = size.binding();
+        on_resize(move |event| size.set((event.width, event.height)))
+    };
+
+    // Grid and edges sit in one SVG the size of the canvas, drawn in
+    // screen pixels so they stay crisp at any zoom.
+    let mut body = String::new();
+    let step = GRID_STEP * state.zoom;
+    let _ = write!(
+        body,
+        r#"<defs><pattern id="grid" patternUnits="userSpaceOnUse" x="{}" y="{}" width="{step}" height="{step}"><circle cx="1" cy="1" r="1""#,
+        state.pan.0, state.pan.1
+    );
+    svg_paint(&mut body, "fill", theme_color(theme.color.divider.as_ref()));
+    let _ = write!(
+        body,
+        r#"/></pattern></defs><rect width="{width}" height="{height}" fill="url(#grid)"/>"#
+    );
+    let edge_color = theme_color(theme.color.text.secondary.as_ref());
+    let selected_color = theme_color(theme.color.primary.base.as_ref());
+    for (index, edge) in state.edges.iter().enumerate() {
+        let Some(curve) = state.edge_curve(edge) else {
+            continue;
+        };
+        svg_curve(&mut body, curve.map(|point| state.to_screen(point)));
+        if state.selected_edge == Some(index) {
+            body.push_str(r#" stroke-width="3""#);
+            svg_paint(&mut body, "stroke", selected_color);
+        } else {
+            body.push_str(r#" stroke-width="2""#);
+            svg_paint(&mut body, "stroke", edge_color);
+        }
+        body.push_str("/>");
+    }
+    let edges = graph_svg(width, height, &body);
+
+    let pending = match drag.get() {
+        GraphDrag::Connect { from, pointer } => state.port_position(&from).map(|start| {
+            let mut body = String::new();
+            let curve = edge_curve(start, pointer);
+            svg_curve(&mut body, curve.map(|point| state.to_screen(point)));
+            body.push_str(r#" stroke-width="2" stroke-dasharray="6 4""#);
+            svg_paint(&mut body, "stroke", selected_color);
+            body.push_str("/>");
+            graph_svg(width, height, &body)
+        }),
+        _ => None,
+    };
+    let marquee = match drag.get() {
+        GraphDrag::Marquee { start, current, .. } if start != current => {
+            let (a, b) = (state.to_screen(start), state.to_screen(current));
+            let [r, g, b_, _] = selected_color.to_rgba_u8();
+            Some(rsx! {
+                <Element style={{
+                    position: Position::absolute()
+                        .left(Length::px(a.0.min(b.0)))
+                        .top(Length::px(a.1.min(b.1)))
+                        .anchor(Anchor::Parent),
+                    width: Length::px((a.0 - b.0).abs()),
+                    height: Length::px((a.1 - b.1).abs()),
+                    background: Color::rgba(r, g, b_, 40),
+                    border: Border::uniform(Length::px(1.0), theme.color.primary.base.as_ref()),
+                }} />
+            })
+        }
+        _ => None,
+    };
+
+    let nodes: Vec<RsxNode> = state
+        .nodes
+        .iter()
+        .map(|node| graph_node(&theme, &state, node))
+        .collect();
+
+    let root = rsx! {
+        <Element
+            style={{
+                width: Length::percent(100.0),
+                height: Length::percent(100.0),
+                background: theme.color.layer.app.clone(),
+                cursor: if matches!(drag.get(), GraphDrag::Pan { .. }) {
+                    Cursor::Grabbing
+                } else {
+                    Cursor::Default
+                },
+            }}
+            on_pointer_down={press}
+            on_pointer_move={track}
+            on_pointer_up={release}
+            on_wheel={wheel}
+            on_resize={resized}
+        >
+            {edges}
+            {nodes}
+            {pending}
+            {marquee}
+        </Element>
+    };
+    root_style.apply(root)
+}
+
+fn graph_svg(width: f32, height: f32, body: &str) -> RsxNode {
+    let document = format!(
+        r#"<svg xmlns="http://www.w3.org/2000/svg" width="{width}" height="{height}" viewBox="0 0 {width} {height}">{body}</svg>"#
+    );
+    rsx! {
+        <Svg
+            source={SvgSource::Content(document)}
+            fit={ImageFit::Fill}
+            style={{
+                position: Position::absolute()
+                    .left(Length::px(0.0))
+                    .top(Length::px(0.0))
+                    .anchor(Anchor::Parent),
+                width: Length::px(width),
+                height: Length::px(height),
+            }}
+        />
+    }
+}
+
+/// One node box, laid out in graph units and scaled into place.
+fn graph_node(theme: &Theme, state: &NodeGraphState, node: &GraphNode) -> RsxNode {
+    let (left, top) = state.to_screen(node.position);
+    let (width, height) = node.size();
+    let border_color = if state.is_selected(&node.id) {
+        theme.color.primary.base.as_ref()
+    } else {
+        theme.color.border.as_ref()
+    };
+    let rows = node.inputs.len().max(node.outputs.len());
+    let labels: Vec<RsxNode> = (0..rows)
+        .map(|row| {
+            let input = node.inputs.get(row).cloned().unwrap_or_default();
+            let output = node.outputs.get(row).cloned().unwrap_or_default();
+            rsx! {
+                <Element
+                    key={row}
+                    style={{
+                        width: Length::percent(100.0),
+                        height: Length::px(PORT_ROW_HEIGHT),
+                        padding: Padding::uniform(Length::Zero).x(Length::px(PORT_RADIUS * 2.0)),
+                        layout: Layout::flow()
+                            .row()
+                            .no_wrap()
+                            .align(Align::Center)
+                            .justify_content(JustifyContent::SpaceBetween),
+                    }}
+                >
+                    <Text>{input}</Text>
+                    <Text>{output}</Text>
+                </Element>
+            }
+        })
+        .collect();
+    let ports: Vec<RsxNode> = [PortSide::Input, PortSide::Output]
+        .into_iter()
+        .flat_map(|side| {
+            let count = match side {
+                PortSide::Input => node.inputs.len(),
+                PortSide::Output => node.outputs.len(),
+            };
+            (0..count).map(move |index| (side, index))
+        })
+        .map(|(side, index)| {
+            let (x, y) = node.port_position(side, index);
+            rsx! {
+                <Element
+                    key={format!("{side:?}{index}")}
+                    style={{
+                        position: Position::absolute()
+                            .left(Length::px(x - node.position.0 - PORT_RADIUS))
+                            .top(Length::px(y - node.position.1 - PORT_RADIUS))
+                            .anchor(Anchor::Parent),
+                        width: Length::px(PORT_RADIUS * 2.0),
+                        height: Length::px(PORT_RADIUS * 2.0),
+                        border_radius: Length::px(PORT_RADIUS),
+                        background: theme.color.primary.base.clone(),
+                    }}
+                />
+            }
+        })
+        .collect();
+    rsx! {
+        <Element
+            key={node.id.clone()}
+            style={{
+                position: Position::absolute()
+                    .left(Length::px(left))
+                    .top(Length::px(top))
+                    .anchor(Anchor::Parent),
+                width: Length::px(width),
+                height: Length::px(height),
+                transform: Transform::new([Scale::uniform(state.zoom)]),
+                transform_origin: TransformOrigin::px(0.0, 0.0),
+                layout: Layout::flow().column().no_wrap(),
+                background: theme.color.layer.raised.clone(),
+                border: Border::uniform(Length::px(1.0), border_color),
+                border_radius: theme.radius.md,
+                color: theme.color.text.secondary.clone(),
+                font_size: theme.typography.size.xs,
+            }}
+        >
+            <Element style={{
+                width: Length::percent(100.0),
+                height: Length::px(NODE_HEADER_HEIGHT),
+                padding: Padding::uniform(Length::Zero).x(Length::px(PORT_RADIUS * 2.0)),
+                layout: Layout::flow().row().no_wrap().align(Align::Center),
+                background: theme.color.layer.surface.clone(),
+                border_radius: BorderRadius::uniform(Length::Zero).top(theme.radius.md),
+                color: theme.color.text.primary.clone(),
+            }}>
+                <Text>{node.title.clone()}</Text>
+            </Element>
+            {labels}
+            {ports}
+        </Element>
+    }
+}
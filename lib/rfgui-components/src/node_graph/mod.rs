@@ -0,0 +1,13 @@
+//! Node graph editor.
+//!
+//! A [`NodeGraph`] draws the nodes, ports and edges of a [`NodeGraphState`]
+//! on an endless, pannable and zoomable canvas. Hit testing is geometric
+//! and runs on the state in graph units: ports by distance to their
+//! centers, nodes by their boxes, and edges by distance to their bezier
+//! curves, so the same queries are available to app code.
+
+mod canvas;
+mod model;
+
+pub use canvas::*;
+pub use model::*;
@@ -0,0 +1,400 @@
+/// Width of every node box, in graph units.
+pub const NODE_WIDTH: f32 = 160.0;
+/// Height of the title strip at the top of a node.
+pub const NODE_HEADER_HEIGHT: f32 = 28.0;
+/// Height of one row of ports.
+pub const PORT_ROW_HEIGHT: f32 = 22.0;
+/// Space under the last row of ports.
+const NODE_FOOTER: f32 = 6.0;
+const MIN_ZOOM: f32 = 0.25;
+const MAX_ZOOM: f32 = 4.0;
+/// Straight segments a connection curve is split into for hit testing.
+const EDGE_SAMPLES: usize = 32;
+
+/// Which side of a node a port sits on. Edges run from an output to an
+/// input.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum PortSide {
+    Input,
+    Output,
+}
+
+/// A port addressed by its node id and name.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct GraphPort {
+    pub node: String,
+    pub side: PortSide,
+    pub name: String,
+}
+
+impl GraphPort {
+    pub fn input(node: impl Into<String>, name: impl Into<String>) -> Self {
+        Self {
+            node: node.into(),
+            side: PortSide::Input,
+            name: name.into(),
+        }
+    }
+
+    pub fn output(node: impl Into<String>, name: impl Into<String>) -> Self {
+        Self {
+            node: node.into(),
+            side: PortSide::Output,
+            name: name.into(),
+        }
+    }
+}
+
+/// A titled box with named input ports down its left edge and output ports
+/// down its right edge.
+#[derive(Clone, Debug, PartialEq)]
+pub struct GraphNode {
+    pub id: String,
+    pub title: String,
+    /// Top-left corner in graph units.
+    pub position: (f32, f32),
+    pub inputs: Vec<String>,
+    pub outputs: Vec<String>,
+}
+
+impl GraphNode {
+    pub fn new(id: impl Into<String>, title: impl Into<String>, position: (f32, f32)) -> Self {
+        Self {
+            id: id.into(),
+            title: title.into(),
+            position,
+            inputs: Vec::new(),
+            outputs: Vec::new(),
+        }
+    }
+
+    pub fn input(mut self, name: impl Into<String>) -> Self {
+        self.inputs.push(name.into());
+        self
+    }
+
+    pub fn output(mut self, name: impl Into<String>) -> Self {
+        self.outputs.push(name.into());
+        self
+    }
+
+    pub fn size(&self) -> (f32, f32) {
+        let rows = self.inputs.len().max(self.outputs.len());
+        (
+            NODE_WIDTH,
+            NODE_HEADER_HEIGHT + PORT_ROW_HEIGHT * rows as f32 + NODE_FOOTER,
+        )
+    }
+
+    pub fn contains(&self, point: (f32, f32)) -> bool {
+        let (width, height) = self.size();
+        point.0 >= self.position.0
+            && point.0 < self.position.0 + width
+            && point.1 >= self.position.1
+            && point.1 < self.position.1 + height
+    }
+
+    /// Center of the `index`-th port on `side`, in graph units.
+    pub fn port_position(&self, side: PortSide, index: usize) -> (f32, f32) {
+        let x = match side {
+            PortSide::Input => self.position.0,
+            PortSide::Output => self.position.0 + NODE_WIDTH,
+        };
+        (
+            x,
+            self.position.1 + NODE_HEADER_HEIGHT + PORT_ROW_HEIGHT * (index as f32 + 0.5),
+        )
+    }
+
+    fn ports(&self, side: PortSide) -> &[String] {
+        match side {
+            PortSide::Input => &self.inputs,
+            PortSide::Output => &self.outputs,
+        }
+    }
+}
+
+/// Connection from an output port to an input port.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct GraphEdge {
+    pub from: GraphPort,
+    pub to: GraphPort,
+}
+
+impl GraphEdge {
+    pub fn new(
+        from_node: impl Into<String>,
+        from_port: impl Into<String>,
+        to_node: impl Into<String>,
+        to_port: impl Into<String>,
+    ) -> Self {
+        Self {
+            from: GraphPort::output(from_node, from_port),
+            to: GraphPort::input(to_node, to_port),
+        }
+    }
+}
+
+/// Cubic bezier control points for a connection: it leaves `from`
+/// heading right and enters `to` heading right.
+pub fn edge_curve(from: (f32, f32), to: (f32, f32)) -> [(f32, f32); 4] {
+    let bend = ((to.0 - from.0).abs() / 2.0).max(40.0);
+    [from, (from.0 + bend, from.1), (to.0 - bend, to.1), to]
+}
+
+fn bezier_point(curve: &[(f32, f32); 4], t: f32) -> (f32, f32) {
+    let u = 1.0 - t;
+    let weights = [u * u * u, 3.0 * u * u * t, 3.0 * u * t * t, t * t * t];
+    curve
+        .iter()
+        .zip(weights)
+        .fold((0.0, 0.0), |(x, y), (point, weight)| {
+            (x + point.0 * weight, y + point.1 * weight)
+        })
+}
+
+fn segment_distance(point: (f32, f32), a: (f32, f32), b: (f32, f32)) -> f32 {
+    let (dx, dy) = (b.0 - a.0, b.1 - a.1);
+    let length = dx * dx + dy * dy;
+    let t = if length > 0.0 {
+        (((point.0 - a.0) * dx + (point.1 - a.1) * dy) / length).clamp(0.0, 1.0)
+    } else {
+        0.0
+    };
+    (point.0 - a.0 - dx * t).hypot(point.1 - a.1 - dy * t)
+}
+
+/// Distance from `point` to a connection curve.
+pub fn edge_distance(curve: &[(f32, f32); 4], point: (f32, f32)) -> f32 {
+    let mut previous = curve[0];
+    let mut nearest = f32::INFINITY;
+    for step in 1..=EDGE_SAMPLES {
+        let next = bezier_point(curve, step as f32 / EDGE_SAMPLES as f32);
+        nearest = nearest.min(segment_distance(point, previous, next));
+        previous = next;
+    }
+    nearest
+}
+
+/// Nodes, edges, selection and view transform of a [`NodeGraph`].
+///
+/// Nodes later in `nodes` draw on top. Screen points are relative to the
+/// canvas' top-left corner; graph points are in node units, mapped to the
+/// screen as `point * zoom + pan`.
+///
+/// [`NodeGraph`]: crate::NodeGraph
+#[derive(Clone, Debug, PartialEq)]
+pub struct NodeGraphState {
+    pub nodes: Vec<GraphNode>,
+    pub edges: Vec<GraphEdge>,
+    pub selected_nodes: Vec<String>,
+    pub selected_edge: Option<usize>,
+    pub pan: (f32, f32),
+    pub zoom: f32,
+}
+
+impl Default for NodeGraphState {
+    fn default() -> Self {
+        Self::new([], [])
+    }
+}
+
+impl NodeGraphState {
+    pub fn new(
+        nodes: impl IntoIterator<Item = GraphNode>,
+        edges: impl IntoIterator<Item = GraphEdge>,
+    ) -> Self {
+        Self {
+            nodes: nodes.into_iter().collect(),
+            edges: edges.into_iter().collect(),
+            selected_nodes: Vec::new(),
+            selected_edge: None,
+            pan: (0.0, 0.0),
+            zoom: 1.0,
+        }
+    }
+
+    pub fn node(&self, id: &str) -> Option<&GraphNode> {
+        self.nodes.iter().find(|node| node.id == id)
+    }
+
+    pub fn is_selected(&self, id: &str) -> bool {
+        self.selected_nodes.iter().any(|selected| selected == id)
+    }
+
+    pub fn to_graph(&self, screen: (f32, f32)) -> (f32, f32) {
+        (
+            (screen.0 - self.pan.0) / self.zoom,
+            (screen.1 - self.pan.1) / self.zoom,
+        )
+    }
+
+    pub fn to_screen(&self, graph: (f32, f32)) -> (f32, f32) {
+        (
+            graph.0 * self.zoom + self.pan.0,
+            graph.1 * self.zoom + self.pan.1,
+        )
+    }
+
+    /// Center of `port` in graph units, or `None` if its node or name is
+    /// unknown.
+    pub fn port_position(&self, port: &GraphPort) -> Option<(f32, f32)> {
+        let node = self.node(&port.node)?;
+        let index = node
+            .ports(port.side)
+            .iter()
+            .position(|name| *name == port.name)?;
+        Some(node.port_position(port.side, index))
+    }
+
+    /// Curve of `edge` in graph units.
+    pub fn edge_curve(&self, edge: &GraphEdge) -> Option<[(f32, f32); 4]> {
+        Some(edge_curve(
+            self.port_position(&edge.from)?,
+            self.port_position(&edge.to)?,
+        ))
+    }
+
+    /// Index of the topmost node under the graph point.
+    pub fn node_at(&self, point: (f32, f32)) -> Option<usize> {
+        self.nodes.iter().rposition(|node| node.contains(point))
+    }
+
+    /// Port whose center lies within `radius` graph units of `point`,
+    /// preferring the topmost node.
+    pub fn port_at(&self, point: (f32, f32), radius: f32) -> Option<GraphPort> {
+        self.nodes.iter().rev().find_map(|node| {
+            [PortSide::Input, PortSide::Output]
+                .into_iter()
+                .find_map(|side| {
+                    node.ports(side)
+                        .iter()
+                        .enumerate()
+                        .find_map(|(index, name)| {
+                            let (x, y) = node.port_position(side, index);
+                            ((point.0 - x).hypot(point.1 - y) <= radius).then(|| GraphPort {
+                                node: node.id.clone(),
+                                side,
+                                name: name.clone(),
+                            })
+                        })
+                })
+        })
+    }
+
+    /// Index of the edge nearest to `point`, if within `tolerance` graph
+    /// units of its curve.
+    pub fn edge_at(&self, point: (f32, f32), tolerance: f32) -> Option<usize> {
+        self.edges
+            .iter()
+            .enumerate()
+            .filter_map(|(index, edge)| {
+                let distance = edge_distance(&self.edge_curve(edge)?, point);
+                (distance <= tolerance).then_some((index, distance))
+            })
+            .min_by(|a, b| a.1.total_cmp(&b.1))
+            .map(|(index, _)| index)
+    }
+
+    /// Add `edge`, replacing whatever fed its input before. Rejects edges
+    /// between unknown ports, from an input or into an output, and from a
+    /// node to itself.
+    pub fn connect(&mut self, edge: GraphEdge) -> bool {
+        if edge.from.side != PortSide::Output
+            || edge.to.side != PortSide::Input
+            || edge.from.node == edge.to.node
+            || self.port_position(&edge.from).is_none()
+            || self.port_position(&edge.to).is_none()
+        {
+            return false;
+        }
+        self.edges.retain(|existing| existing.to != edge.to);
+        self.edges.push(edge);
+        self.selected_edge = None;
+        true
+    }
+
+    /// Select `id` alone, or toggle it when `additive`. Raises the node
+    /// above the others.
+    pub fn select_node(&mut self, id: &str, additive: bool) {
+        self.selected_edge = None;
+        if additive {
+            if let Some(index) = self
+                .selected_nodes
+                .iter()
+                .position(|selected| selected == id)
+            {
+                self.selected_nodes.remove(index);
+                return;
+            }
+        } else if self.is_selected(id) {
+            self.raise(id);
+            return;
+        } else {
+            self.selected_nodes.clear();
+        }
+        self.selected_nodes.push(id.to_string());
+        self.raise(id);
+    }
+
+    pub fn select_edge(&mut self, index: Option<usize>) {
+        self.selected_nodes.clear();
+        self.selected_edge = index.filter(|index| *index < self.edges.len());
+    }
+
+    /// Select every node overlapping the rectangle spanned by two graph
+    /// points, adding to the selection when `additive`.
+    pub fn select_in_rect(&mut self, a: (f32, f32), b: (f32, f32), additive: bool) {
+        let (left, right) = (a.0.min(b.0), a.0.max(b.0));
+        let (top, bottom) = (a.1.min(b.1), a.1.max(b.1));
+        if !additive {
+            self.selected_nodes.clear();
+        }
+        self.selected_edge = None;
+        for node in &self.nodes {
+            let (width, height) = node.size();
+            let overlaps = node.position.0 < right
+                && node.position.0 + width > left
+                && node.position.1 < bottom
+                && node.position.1 + height > top;
+            if overlaps && !self.selected_nodes.contains(&node.id) {
+                self.selected_nodes.push(node.id.clone());
+            }
+        }
+    }
+
+    pub fn clear_selection(&mut self) {
+        self.selected_nodes.clear();
+        self.selected_edge = None;
+    }
+
+    /// Drop the selected nodes with their edges, and the selected edge.
+    pub fn remove_selected(&mut self) {
+        if let Some(index) = self.selected_edge.take() {
+            self.edges.remove(index);
+        }
+        let selected = std::mem::take(&mut self.selected_nodes);
+        self.nodes.retain(|node| !selected.contains(&node.id));
+        self.edges.retain(|edge| {
+            !selected.contains(&edge.from.node) && !selected.contains(&edge.to.node)
+        });
+    }
+
+    /// Scale the view by `factor` about a screen point, keeping the graph
+    /// point under it in place.
+    pub fn zoom_at(&mut self, screen: (f32, f32), factor: f32) {
+        let anchor = self.to_graph(screen);
+        self.zoom = (self.zoom * factor).clamp(MIN_ZOOM, MAX_ZOOM);
+        self.pan = (
+            screen.0 - anchor.0 * self.zoom,
+            screen.1 - anchor.1 * self.zoom,
+        );
+    }
+
+    fn raise(&mut self, id: &str) {
+        if let Some(index) = self.nodes.iter().position(|node| node.id == id) {
+            let node = self.nodes.remove(index);
+            self.nodes.push(node);
+        }
+    }
+}
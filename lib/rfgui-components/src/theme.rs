@@ -1,8 +1,8 @@
 use rfgui::style::{
-    Border, BorderRadius, BoxShadow, Color, ColorLike, FontFamily, FontSize, Length, Padding,
-    TransitionTiming,
+    Border, BorderRadius, BoxShadow, Color, ColorLike, ElevationPreset, ElevationScale, FontFamily,
+    FontSize, Length, Padding, TransitionTiming,
 };
-use rfgui::ui::global_state;
+use rfgui::ui::{global_state, use_high_contrast, use_viewport};
 
 #[derive(Clone)]
 pub struct Theme {
@@ -12,7 +12,11 @@ pub struct Theme {
     pub radius: RadiusTheme,
     pub shadow: ShadowTheme,
     pub motion: MotionTheme,
+    pub sound: SoundTheme,
     pub component: ComponentTheme,
+    /// Set on high-contrast token sets. Components mark boundaries and
+    /// selection with borders instead of shades while it is on.
+    pub high_contrast: bool,
 }
 
 impl PartialEq for Theme {
@@ -152,6 +156,9 @@ pub struct ShadowTheme {
     pub level_1: BoxShadow,
     pub level_2: BoxShadow,
     pub level_3: BoxShadow,
+    /// Presets the `elevation` style resolves to while this theme is in
+    /// use.
+    pub elevation: ElevationScale,
 }
 
 #[derive(Clone)]
@@ -174,6 +181,26 @@ pub struct EasingTheme {
     pub exit: TransitionTiming,
 }
 
+/// Named sounds components play on interaction. Names refer to sounds
+/// registered with `ViewportControl::register_sound`; `None`, the default,
+/// keeps the interaction silent.
+#[derive(Clone, Default)]
+pub struct SoundTheme {
+    /// Button presses.
+    pub click: Option<String>,
+    /// Checkbox and Switch changes.
+    pub toggle: Option<String>,
+    /// Rejected input, such as a Switch change refused by `before_change`.
+    pub error: Option<String>,
+}
+
+/// Play `sound` if the theme names one.
+pub(crate) fn play_sound(sound: Option<&str>) {
+    if let Some(name) = sound {
+        use_viewport().play_sound(name);
+    }
+}
+
 #[derive(Clone)]
 pub struct ComponentTheme {
     pub button: ButtonTheme,
@@ -266,6 +293,22 @@ pub struct CheckboxTheme {
 
 #[derive(Clone)]
 pub struct SwitchTheme {
+    pub size: SwitchSizes,
+    /// Opacity of the whole control while disabled.
+    pub disabled_opacity: f32,
+    /// Opacity of the thumb while an async confirm is pending.
+    pub pending_thumb_opacity: f32,
+}
+
+#[derive(Clone)]
+pub struct SwitchSizes {
+    pub small: SwitchSizeSpec,
+    pub medium: SwitchSizeSpec,
+    pub large: SwitchSizeSpec,
+}
+
+#[derive(Clone)]
+pub struct SwitchSizeSpec {
     pub track_width: Length,
     pub track_height: Length,
     pub track_padding: Padding,
@@ -273,6 +316,7 @@ pub struct SwitchTheme {
     pub thumb_width: Length,
     pub thumb_height: Length,
     pub thumb_radius: BorderRadius,
+    pub label_font_size: FontSize,
 }
 
 fn button_sizes() -> ButtonSizes {
@@ -309,6 +353,42 @@ fn button_sizes() -> ButtonSizes {
     }
 }
 
+fn switch_sizes() -> SwitchSizes {
+    // Medium preserves the original Switch sizing.
+    SwitchSizes {
+        small: SwitchSizeSpec {
+            track_width: Length::px(34.0),
+            track_height: Length::px(14.0),
+            track_padding: Padding::uniform(Length::px(2.0)),
+            track_radius: BorderRadius::uniform(Length::px(6.0)),
+            thumb_width: Length::px(16.0),
+            thumb_height: Length::px(10.0),
+            thumb_radius: BorderRadius::uniform(Length::px(8.0)),
+            label_font_size: FontSize::px(12.0),
+        },
+        medium: SwitchSizeSpec {
+            track_width: Length::px(44.0),
+            track_height: Length::px(18.0),
+            track_padding: Padding::uniform(Length::px(2.0)),
+            track_radius: BorderRadius::uniform(Length::px(8.0)),
+            thumb_width: Length::px(20.0),
+            thumb_height: Length::px(14.0),
+            thumb_radius: BorderRadius::uniform(Length::px(10.0)),
+            label_font_size: FontSize::px(14.0),
+        },
+        large: SwitchSizeSpec {
+            track_width: Length::px(54.0),
+            track_height: Length::px(24.0),
+            track_padding: Padding::uniform(Length::px(3.0)),
+            track_radius: BorderRadius::uniform(Length::px(12.0)),
+            thumb_width: Length::px(24.0),
+            thumb_height: Length::px(18.0),
+            thumb_radius: BorderRadius::uniform(Length::px(12.0)),
+            label_font_size: FontSize::px(16.0),
+        },
+    }
+}
+
 impl Theme {
     pub fn light() -> Self {
         let border_color = Color::rgb(220, 223, 230);
@@ -440,6 +520,7 @@ impl Theme {
                     .color(Color::rgba(0, 0, 0, 128))
                     .offset_y(10.0)
                     .blur(24.0),
+                elevation: ElevationScale::default(),
             },
             motion: MotionTheme {
                 duration: DurationScale {
@@ -453,6 +534,7 @@ impl Theme {
                     exit: TransitionTiming::EaseIn,
                 },
             },
+            sound: SoundTheme::default(),
             component: ComponentTheme {
                 button: ButtonTheme {
                     size: button_sizes(),
@@ -503,15 +585,12 @@ impl Theme {
                     border_width: Length::px(1.0),
                 },
                 switch: SwitchTheme {
-                    track_width: Length::px(44.0),
-                    track_height: Length::px(18.0),
-                    track_padding: Padding::uniform(Length::px(2.0)),
-                    track_radius: BorderRadius::uniform(Length::px(8.0)),
-                    thumb_width: Length::px(20.0),
-                    thumb_height: Length::px(14.0),
-                    thumb_radius: BorderRadius::uniform(Length::px(10.0)),
+                    size: switch_sizes(),
+                    disabled_opacity: 0.6,
+                    pending_thumb_opacity: 0.5,
                 },
             },
+            high_contrast: false,
         }
     }
 
@@ -645,6 +724,7 @@ impl Theme {
                     .color(Color::rgba(0, 0, 0, 128))
                     .offset_y(10.0)
                     .blur(24.0),
+                elevation: ElevationScale::default(),
             },
             motion: MotionTheme {
                 duration: DurationScale {
@@ -658,6 +738,7 @@ impl Theme {
                     exit: TransitionTiming::EaseIn,
                 },
             },
+            sound: SoundTheme::default(),
             component: ComponentTheme {
                 button: ButtonTheme {
                     size: button_sizes(),
@@ -708,34 +789,174 @@ impl Theme {
                     border_width: Length::px(1.0),
                 },
                 switch: SwitchTheme {
-                    track_width: Length::px(44.0),
-                    track_height: Length::px(18.0),
-                    track_padding: Padding::uniform(Length::px(2.0)),
-                    track_radius: BorderRadius::uniform(Length::px(8.0)),
-                    thumb_width: Length::px(20.0),
-                    thumb_height: Length::px(14.0),
-                    thumb_radius: BorderRadius::uniform(Length::px(10.0)),
+                    size: switch_sizes(),
+                    disabled_opacity: 0.6,
+                    pending_thumb_opacity: 0.5,
                 },
             },
+            high_contrast: false,
         }
     }
+
+    /// High-contrast counterpart of [`Theme::light`]: black on white.
+    pub fn high_contrast_light() -> Self {
+        Self::light().into_high_contrast()
+    }
+
+    /// High-contrast counterpart of [`Theme::dark`]: white on black.
+    pub fn high_contrast_dark() -> Self {
+        Self::dark().into_high_contrast()
+    }
+
+    /// Derive the high-contrast token set from this theme. Surfaces go
+    /// pure black or white (whichever the background is closer to), text
+    /// and borders take the opposite extreme, selection uses the system
+    /// highlight color of the matching Windows contrast theme, and every
+    /// shadow is dropped. Spacing, typography and motion carry over.
+    pub fn into_high_contrast(mut self) -> Self {
+        let dark = relative_luminance(self.color.background.base.as_ref()) < 0.5;
+        let (bg, fg, highlight, muted) = if dark {
+            (
+                Color::rgb(0, 0, 0),
+                Color::rgb(255, 255, 255),
+                Color::rgb(26, 235, 255),
+                Color::rgb(166, 166, 166),
+            )
+        } else {
+            (
+                Color::rgb(255, 255, 255),
+                Color::rgb(0, 0, 0),
+                Color::rgb(55, 0, 110),
+                Color::rgb(89, 89, 89),
+            )
+        };
+        let [fg_r, fg_g, fg_b, _] = fg.to_rgba_u8();
+        let tint = |alpha: u8| rgba(fg_r, fg_g, fg_b, alpha);
+        let border = Border::uniform(Length::px(1.0), &fg);
+
+        let color = &mut self.color;
+        color.primary = ColorSet {
+            base: Box::new(highlight),
+            on: Box::new(bg),
+        };
+        color.background = ColorSet {
+            base: Box::new(bg),
+            on: Box::new(fg),
+        };
+        color.surface = color.background.clone();
+        color.layer = SurfaceLayerTheme {
+            app: Box::new(bg),
+            surface: Box::new(bg),
+            raised: Box::new(bg),
+            inverse: Box::new(fg),
+            on_inverse: Box::new(bg),
+        };
+        color.text.primary = Box::new(fg);
+        color.text.secondary = Box::new(fg);
+        color.text.disabled = Box::new(muted);
+        color.border = Box::new(fg);
+        color.divider = Box::new(fg);
+        color.state = StateColorSet {
+            hover: tint(38),
+            active: tint(64),
+            pressed: tint(89),
+            focus: Box::new(highlight),
+            disabled: Box::new(muted),
+            hover_darken: color.state.hover_darken,
+        };
+
+        let no_shadow = BoxShadow::new().color(Color::transparent());
+        self.shadow = ShadowTheme {
+            level_0: no_shadow,
+            level_1: no_shadow,
+            level_2: no_shadow,
+            level_3: no_shadow,
+            elevation: ElevationScale::new(
+                self.shadow
+                    .elevation
+                    .levels()
+                    .iter()
+                    .map(|preset| ElevationPreset::flat().z_order(preset.z_order)),
+            ),
+        };
+
+        let component = &mut self.component;
+        component.button.border = border.clone();
+        component.input.border = border.clone();
+        component.card.border = border;
+        component.checkbox.border_width = Length::px(2.0);
+        component.select = SelectTheme {
+            trigger_hover_background: tint(38),
+            option_hover_background: tint(38),
+            option_selected_background: Box::new(highlight),
+            option_disabled_background: Box::new(Color::transparent()),
+            option_selected_text: Box::new(bg),
+            option_disabled_text: Box::new(muted),
+        };
+        let slider = &mut component.slider;
+        slider.frame_background = Box::new(bg);
+        slider.frame_hover_background = tint(38);
+        slider.frame_active_background = tint(64);
+        slider.frame_disabled_background = Box::new(bg);
+        slider.grab_background = Box::new(fg);
+        slider.grab_hover_background = Box::new(highlight);
+        slider.grab_active_background = Box::new(highlight);
+        slider.grab_disabled_background = Box::new(muted);
+
+        self.high_contrast = true;
+        self
+    }
 }
 
+/// App-supplied theme for high-contrast mode; `None` derives one.
+#[derive(Clone, Default, PartialEq)]
+struct HighContrastTheme(Option<Theme>);
+
 /// React-style theme hook. Returns the current theme snapshot plus a
 /// callable setter — `let (theme, set_theme) = use_theme();` then call
 /// `set_theme(Theme::dark())` to switch.
 ///
 /// The setter is an `Rc<dyn Fn(Theme)>` so it can be cloned into event
 /// closures and invoked with plain call syntax.
+///
+/// While high-contrast mode is on the snapshot is the high-contrast theme
+/// (see [`use_high_contrast_theme`]); the setter still replaces the
+/// regular one.
 pub fn use_theme() -> (Theme, std::rc::Rc<dyn Fn(Theme)>) {
     let state = global_state(Theme::dark);
-    let theme = state.get();
+    let theme = if use_high_contrast() {
+        use_high_contrast_theme().0
+    } else {
+        state.get()
+    };
+    ElevationScale::set_current(theme.shadow.elevation.clone());
     let setter_state = state;
     let set: std::rc::Rc<dyn Fn(Theme)> =
         std::rc::Rc::new(move |next: Theme| setter_state.set(next));
     (theme, set)
 }
 
+/// Hook for the theme used in high-contrast mode (see
+/// [`rfgui::ui::use_high_contrast`]), shaped like [`use_theme`]. Until the
+/// setter supplies one, the snapshot is derived from the regular theme with
+/// [`Theme::into_high_contrast`].
+pub fn use_high_contrast_theme() -> (Theme, std::rc::Rc<dyn Fn(Theme)>) {
+    let state = global_state(HighContrastTheme::default);
+    let theme = state
+        .get()
+        .0
+        .unwrap_or_else(|| global_state(Theme::dark).get().into_high_contrast());
+    let set: std::rc::Rc<dyn Fn(Theme)> =
+        std::rc::Rc::new(move |next: Theme| state.set(HighContrastTheme(Some(next))));
+    (theme, set)
+}
+
+/// WCAG relative luminance of `color`, 0 for black to 1 for white.
+fn relative_luminance(color: &dyn ColorLike) -> f32 {
+    let [r, g, b, _] = color.to_linear_srgb_f32();
+    0.2126 * r + 0.7152 * g + 0.0722 * b
+}
+
 fn hex(hex_string: &str) -> Box<dyn ColorLike> {
     let [r, g, b, a] = Color::hex(hex_string).to_rgba_u8();
     Box::new(Color::rgba(r, g, b, a))
@@ -4,7 +4,7 @@ use rfgui::style::{
     Align, Border, Color, ColorLike, Cursor, JustifyContent, Layout, Length, Padding,
 };
 use rfgui::ui::{ClickHandlerProp, RsxComponent, RsxNode, component, props, rsx};
-use rfgui::view::{Element, Text};
+use rfgui::view::{Element, ElementStylePropSchema, RootStyleProps, Text};
 use std::rc::Rc;
 
 #[derive(Clone, Copy, Debug, PartialEq, Eq)]
@@ -103,6 +103,10 @@ pub struct AlertProps {
     pub icon: Option<RsxNode>,
     pub action: Option<RsxNode>,
     pub on_close: Option<Rc<dyn Fn()>>,
+    /// Stacked over the style of the alert surface.
+    pub style: Option<ElementStylePropSchema>,
+    /// [`StyleClasses`](rfgui::view::StyleClasses) names, stacked beneath `style`.
+    pub class: Option<String>,
 }
 
 impl RsxComponent<AlertProps> for Alert {
@@ -114,6 +118,7 @@ impl RsxComponent<AlertProps> for Alert {
                 icon={props.icon}
                 action={props.action}
                 on_close={props.on_close}
+                root_style={RootStyleProps::new(props.style, props.class)}
             >
                 {children}
             </AlertView>
@@ -179,6 +184,7 @@ fn AlertView(
     icon: Option<RsxNode>,
     action: Option<RsxNode>,
     on_close: Option<Rc<dyn Fn()>>,
+    root_style: RootStyleProps,
     children: Vec<RsxNode>,
 ) -> RsxNode {
     let theme = use_theme().0;
@@ -248,7 +254,7 @@ fn AlertView(
         RsxNode::fragment(vec![])
     };
 
-    rsx! {
+    let root = rsx! {
         <Element
             style={{
                 padding: Padding::uniform(theme.spacing.sm).x(theme.spacing.md),
@@ -257,7 +263,7 @@ fn AlertView(
                 border: border,
                 border_radius: theme.radius.sm,
                 font_size: theme.typography.size.sm,
-                box_shadow: vec![theme.shadow.level_2.clone()],
+                elevation: 2,
                 layout: Layout::flow()
                     .row()
                     .no_wrap()
@@ -283,5 +289,6 @@ fn AlertView(
                 {close_button}
             </Element>
         </Element>
-    }
+    };
+    root_style.apply(root)
 }
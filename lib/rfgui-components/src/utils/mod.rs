@@ -1,7 +1,9 @@
 mod alert;
+mod pop_confirm;
 mod snackbar;
 mod tooltip;
 
 pub use alert::*;
+pub use pop_confirm::*;
 pub use snackbar::*;
 pub use tooltip::*;
@@ -0,0 +1,334 @@
+use super::tooltip::placement_position;
+use crate::{Button, ButtonSize, ButtonVariant, TooltipPlacement, use_theme};
+use rfgui::platform::Key;
+use rfgui::style::{Align, Border, JustifyContent, Layout, Length, Padding};
+use rfgui::ui::{
+    ClickHandlerProp, Modifiers, RsxComponent, RsxNode, component, on_blur, on_click, on_focus,
+    on_key_down, on_pointer_enter, on_pointer_leave, props, rsx, use_state,
+};
+use rfgui::view::{Element, ElementStylePropSchema, RootStyleProps, Text};
+use std::rc::Rc;
+
+/// What opens a [`PopConfirm`].
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub enum PopConfirmTrigger {
+    /// Clicking the trigger toggles the popup; it stays open until an
+    /// action is chosen, Escape is pressed or focus leaves it.
+    #[default]
+    Click,
+    /// Hovering the trigger opens the popup; it stays open while the
+    /// pointer is over the trigger or the popup, or focus is inside it.
+    Hover,
+}
+
+/// Confirmation popup anchored to its children.
+///
+/// Unlike [`Tooltip`](crate::Tooltip), the popup is interactive: `content`
+/// may hold inputs or buttons, and the popup closes only once the user
+/// resolves it. While open it keeps keyboard focus to itself: Tab and
+/// Shift+Tab move between the cancel and confirm actions, Enter runs the
+/// highlighted one and Escape cancels. Moving focus outside (for example
+/// by clicking elsewhere) cancels too.
+pub struct PopConfirm;
+
+#[derive(Clone)]
+#[props]
+pub struct PopConfirmProps {
+    pub title: String,
+    /// Extra interactive content shown under the title.
+    pub content: Option<RsxNode>,
+    /// Defaults to "OK".
+    pub confirm_label: Option<String>,
+    /// Defaults to "Cancel".
+    pub cancel_label: Option<String>,
+    /// Defaults to [`TooltipPlacement::Top`].
+    pub placement: Option<TooltipPlacement>,
+    pub trigger: Option<PopConfirmTrigger>,
+    pub on_confirm: Option<Rc<dyn Fn()>>,
+    pub on_cancel: Option<Rc<dyn Fn()>>,
+    /// Stacked over the style of the element wrapping the trigger.
+    pub style: Option<ElementStylePropSchema>,
+    /// [`StyleClasses`](rfgui::view::StyleClasses) names, stacked beneath `style`.
+    pub class: Option<String>,
+}
+
+impl RsxComponent<PopConfirmProps> for PopConfirm {
+    fn render(props: PopConfirmProps, children: Vec<RsxNode>) -> RsxNode {
+        rsx! {
+            <PopConfirmView
+                title={props.title}
+                content={props.content}
+                actions={PopConfirmActions {
+                    confirm_label: props.confirm_label.unwrap_or_else(|| "OK".to_string()),
+                    cancel_label: props.cancel_label.unwrap_or_else(|| "Cancel".to_string()),
+                    on_confirm: props.on_confirm,
+                    on_cancel: props.on_cancel,
+                }}
+                placement={props.placement.unwrap_or(TooltipPlacement::Top)}
+                trigger={props.trigger.unwrap_or_default()}
+                root_style={RootStyleProps::new(props.style, props.class)}
+            >
+                {children}
+            </PopConfirmView>
+        }
+    }
+}
+
+#[rfgui::ui::component]
+impl rfgui::ui::RsxTag for PopConfirm {
+    type Props = __PopConfirmPropsInit;
+    type StrictProps = PopConfirmProps;
+    const ACCEPTS_CHILDREN: bool = true;
+
+    fn into_strict(props: Self::Props) -> Self::StrictProps {
+        props.into()
+    }
+
+    fn create_node(
+        props: Self::StrictProps,
+        children: Vec<RsxNode>,
+        _key: Option<rfgui::ui::RsxKey>,
+    ) -> RsxNode {
+        <Self as RsxComponent<PopConfirmProps>>::render(props, children)
+    }
+}
+
+/// The two actions, in focus order.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+enum PopConfirmAction {
+    Cancel,
+    Confirm,
+}
+
+/// Labels and callbacks of the two actions.
+#[derive(Clone)]
+struct PopConfirmActions {
+    confirm_label: String,
+    cancel_label: String,
+    on_confirm: Option<Rc<dyn Fn()>>,
+    on_cancel: Option<Rc<dyn Fn()>>,
+}
+
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+struct PopConfirmState {
+    open: bool,
+    hovered: bool,
+    focus_within: bool,
+    /// Action highlighted for Enter; `None` until Tab moves onto one.
+    active: Option<PopConfirmAction>,
+}
+
+#[component]
+fn PopConfirmView(
+    title: String,
+    content: Option<RsxNode>,
+    actions: PopConfirmActions,
+    placement: TooltipPlacement,
+    trigger: PopConfirmTrigger,
+    root_style: RootStyleProps,
+    children: Vec<RsxNode>,
+) -> RsxNode {
+    let theme = use_theme().0;
+    let state = use_state(PopConfirmState::default);
+    let current = state.get();
+
+    // Close and hand the outcome to the matching callback.
+    let resolve: Rc<dyn Fn(PopConfirmAction)> = {
+        let state = state.binding();
+        let actions = actions.clone();
+        Rc::new(move |action| {
+            state.update(|state| {
+                state.open = false;
+                state.active = None;
+            });
+            let callback = match action {
+                PopConfirmAction::Confirm => actions.on_confirm.as_ref(),
+                PopConfirmAction::Cancel => actions.on_cancel.as_ref(),
+            };
+            if let Some(callback) = callback {
+                callback();
+            }
+        })
+    };
+
+    let toggle = (trigger == PopConfirmTrigger::Click).then(|| {
+        let state = state.binding();
+        let resolve = resolve.clone();
+        on_click(move |_| {
+            if state.get().open {
+                resolve(PopConfirmAction::Cancel);
+            } else {
+                state.update(|state| state.open = true);
+            }
+        })
+    });
+    let enter = (trigger == PopConfirmTrigger::Hover).then(|| {
+        let state = state.binding();
+        on_pointer_enter(move |_| {
+            state.update(|state| {
+                state.hovered = true;
+                state.open = true;
+            })
+        })
+    });
+    let leave = (trigger == PopConfirmTrigger::Hover).then(|| {
+        let state = state.binding();
+        on_pointer_leave(move |_| {
+            state.update(|state| {
+                state.hovered = false;
+                state.open &= state.focus_within;
+            })
+        })
+    });
+    let focus = {
+        let state = state.binding();
+        on_focus(move |_| state.update(|state| state.focus_within = true))
+    };
+    // Focus moving to a node outside the popup's subtree ends the scope.
+    let blur = {
+        let state = state.binding();
+        let resolve = resolve.clone();
+        on_blur(move |event| {
+            let next = event.meta.related_target().map(|target| target.id);
+            if next.is_some_and(|next| event.meta.current_target().contains(next)) {
+                return;
+            }
+            state.update(|state| state.focus_within = false);
+            let current = state.get();
+            if !current.open {
+                return;
+            }
+            match trigger {
+                PopConfirmTrigger::Click => resolve(PopConfirmAction::Cancel),
+                PopConfirmTrigger::Hover if !current.hovered => {
+                    state.update(|state| state.open = false)
+                }
+                PopConfirmTrigger::Hover => {}
+            }
+        })
+    };
+    let key_down = {
+        let state = state.binding();
+        let resolve = resolve.clone();
+        on_key_down(move |event| {
+            let current = state.get();
+            if event.key.is_composing {
+                return;
+            }
+            if !current.open {
+                if trigger == PopConfirmTrigger::Click
+                    && matches!(event.key.key, Key::Enter | Key::Space)
+                {
+                    state.update(|state| state.open = true);
+                    event.meta.stop_propagation();
+                }
+                return;
+            }
+            match event.key.key {
+                Key::Escape => resolve(PopConfirmAction::Cancel),
+                Key::Tab => {
+                    let backward = event.key.modifiers.contains(Modifiers::SHIFT);
+                    let next = match (current.active, backward) {
+                        (None, false) | (Some(PopConfirmAction::Confirm), _) => {
+                            PopConfirmAction::Cancel
+                        }
+                        (None, true) | (Some(PopConfirmAction::Cancel), _) => {
+                            PopConfirmAction::Confirm
+                        }
+                    };
+                    state.update(|state| state.active = Some(next));
+                }
+                Key::Enter | Key::NumberPadEnter => match current.active {
+                    Some(action) => resolve(action),
+                    None => return,
+                },
+                _ => return,
+            }
+            event.meta.stop_propagation();
+        })
+    };
+
+    let popup = current.open.then(|| {
+        let action_button = |action: PopConfirmAction| {
+            let (label, variant) = match action {
+                PopConfirmAction::Cancel => (actions.cancel_label.clone(), ButtonVariant::Text),
+                PopConfirmAction::Confirm => {
+                    (actions.confirm_label.clone(), ButtonVariant::Contained)
+                }
+            };
+            let resolve = resolve.clone();
+            let click = ClickHandlerProp::new(move |_| resolve(action));
+            let ring = if current.active == Some(action) {
+                theme.color.state.focus.as_ref()
+            } else {
+                theme.color.layer.raised.as_ref()
+            };
+            rsx! {
+                <Element style={{
+                    border: Border::uniform(Length::px(2.0), ring),
+                    border_radius: theme.radius.md,
+                }}>
+                    <Button variant={variant} size={ButtonSize::Small} on_click={click}>
+                        {label}
+                    </Button>
+                </Element>
+            }
+        };
+        // Clicks inside the popup must not reach the trigger's toggle.
+        let contain = on_click(|event| event.meta.stop_propagation());
+        rsx! {
+            // Transparent padding bridges the gap to the trigger so the
+            // pointer can cross it without leaving.
+            <Element
+                style={{
+                    position: placement_position(placement, Length::px(0.0)),
+                    padding: Padding::uniform(Length::px(6.0)),
+                }}
+                on_click={contain}
+            >
+                <Element style={{
+                    layout: Layout::flow().column().no_wrap(),
+                    gap: theme.spacing.sm,
+                    padding: Padding::uniform(theme.spacing.sm).x(theme.spacing.md),
+                    min_width: Length::px(180.0),
+                    background: theme.color.layer.raised.clone(),
+                    border: Border::uniform(Length::px(1.0), theme.color.border.as_ref()),
+                    border_radius: theme.radius.md,
+                    elevation: 2,
+                    color: theme.color.text.primary.clone(),
+                    font_size: theme.typography.size.sm,
+                }}>
+                    <Text>{title.clone()}</Text>
+                    {content.clone()}
+                    <Element style={{
+                        layout: Layout::flow()
+                            .row()
+                            .no_wrap()
+                            .align(Align::Center)
+                            .justify_content(JustifyContent::End),
+                        gap: theme.spacing.xs,
+                    }}>
+                        {action_button(PopConfirmAction::Cancel)}
+                        {action_button(PopConfirmAction::Confirm)}
+                    </Element>
+                </Element>
+            </Element>
+        }
+    });
+
+    let root = rsx! {
+        <Element
+            style={{ layout: Layout::flow().row().no_wrap() }}
+            on_click={toggle}
+            on_pointer_enter={enter}
+            on_pointer_leave={leave}
+            on_focus={focus}
+            on_blur={blur}
+            on_key_down={key_down}
+        >
+            {children}
+            {popup}
+        </Element>
+    };
+    root_style.apply(root)
+}
@@ -1,7 +1,7 @@
 use crate::use_theme;
 use rfgui::style::{Align, Anchor, ClipMode, JustifyContent, Layout, Length, Padding, Position};
 use rfgui::ui::{RsxComponent, RsxKey, RsxNode, component, props, rsx, use_timeout};
-use rfgui::view::Element;
+use rfgui::view::{Element, ElementStylePropSchema, RootStyleProps};
 use std::rc::Rc;
 use std::time::Duration;
 
@@ -97,20 +97,30 @@ pub struct SnackbarProps {
     pub auto_hide_duration: Option<Duration>,
     pub anchor_origin: Option<SnackbarOrigin>,
     pub on_close: Option<Rc<dyn Fn(SnackbarCloseReason)>>,
+    /// Stacked over the style of the positioning wrapper.
+    pub style: Option<ElementStylePropSchema>,
+    /// [`StyleClasses`](rfgui::view::StyleClasses) names, stacked beneath `style`.
+    pub class: Option<String>,
 }
 
 impl RsxComponent<SnackbarProps> for Snackbar {
     fn render(props: SnackbarProps, children: Vec<RsxNode>) -> RsxNode {
+        let body = if children.is_empty() {
+            vec![rsx! {
+                <SnackbarContent message={props.message} action={props.action} />
+            }]
+        } else {
+            children
+        };
         rsx! {
             <SnackbarView
                 open={props.open}
-                message={props.message}
-                action={props.action}
                 auto_hide_duration={props.auto_hide_duration}
                 anchor_origin={props.anchor_origin.unwrap_or_default()}
                 on_close={props.on_close}
+                root_style={RootStyleProps::new(props.style, props.class)}
             >
-                {children}
+                {body}
             </SnackbarView>
         }
     }
@@ -159,11 +169,10 @@ fn horizontal_justify(origin: SnackbarOrigin) -> JustifyContent {
 #[component]
 fn SnackbarView(
     open: bool,
-    message: Option<RsxNode>,
-    action: Option<RsxNode>,
     auto_hide_duration: Option<Duration>,
     anchor_origin: SnackbarOrigin,
     on_close: Option<Rc<dyn Fn(SnackbarCloseReason)>>,
+    root_style: RootStyleProps,
     children: Vec<RsxNode>,
 ) -> RsxNode {
     let timer_enabled = open && auto_hide_duration.is_some();
@@ -183,15 +192,7 @@ fn SnackbarView(
     let position = placement_position(anchor_origin, theme.spacing.lg);
     let justify = horizontal_justify(anchor_origin);
 
-    let body: Vec<RsxNode> = if children.is_empty() {
-        vec![rsx! {
-            <SnackbarContent message={message} action={action} />
-        }]
-    } else {
-        children
-    };
-
-    rsx! {
+    let root = rsx! {
         <Element
             style={{
                 position: position,
@@ -202,9 +203,10 @@ fn SnackbarView(
                     .justify_content(justify),
             }}
         >
-            {body}
+            {children}
         </Element>
-    }
+    };
+    root_style.apply(root)
 }
 
 pub struct SnackbarContent;
@@ -214,12 +216,20 @@ pub struct SnackbarContent;
 pub struct SnackbarContentProps {
     pub message: Option<RsxNode>,
     pub action: Option<RsxNode>,
+    /// Stacked over the style of the message surface.
+    pub style: Option<ElementStylePropSchema>,
+    /// [`StyleClasses`](rfgui::view::StyleClasses) names, stacked beneath `style`.
+    pub class: Option<String>,
 }
 
 impl RsxComponent<SnackbarContentProps> for SnackbarContent {
     fn render(props: SnackbarContentProps, _children: Vec<RsxNode>) -> RsxNode {
         rsx! {
-            <SnackbarContentView message={props.message} action={props.action} />
+            <SnackbarContentView
+                message={props.message}
+                action={props.action}
+                root_style={RootStyleProps::new(props.style, props.class)}
+            />
         }
     }
 }
@@ -244,12 +254,16 @@ impl rfgui::ui::RsxTag for SnackbarContent {
 }
 
 #[component]
-fn SnackbarContentView(message: Option<RsxNode>, action: Option<RsxNode>) -> RsxNode {
+fn SnackbarContentView(
+    message: Option<RsxNode>,
+    action: Option<RsxNode>,
+    root_style: RootStyleProps,
+) -> RsxNode {
     let theme = use_theme().0;
     let message_node = message.unwrap_or_else(|| RsxNode::fragment(vec![]));
     let action_node = action.unwrap_or_else(|| RsxNode::fragment(vec![]));
 
-    rsx! {
+    let root = rsx! {
         <Element
             style={{
                 padding: Padding::uniform(theme.spacing.sm).x(theme.spacing.md),
@@ -257,7 +271,7 @@ fn SnackbarContentView(message: Option<RsxNode>, action: Option<RsxNode>) -> Rsx
                 color: theme.color.layer.on_inverse.clone(),
                 border_radius: theme.radius.sm,
                 font_size: theme.typography.size.sm,
-                box_shadow: vec![theme.shadow.level_2.clone()],
+                elevation: 2,
                 layout: Layout::flow()
                     .row()
                     .no_wrap()
@@ -271,7 +285,8 @@ fn SnackbarContentView(message: Option<RsxNode>, action: Option<RsxNode>) -> Rsx
             {message_node}
             {action_node}
         </Element>
-    }
+    };
+    root_style.apply(root)
 }
 
 /// Convenience: wrap a `Binding<bool>` into a `SnackbarProps::on_close`
@@ -1,10 +1,10 @@
 use crate::use_theme;
 use rfgui::style::{
-    Anchor, ClipMode, Collision, CollisionBoundary, Layout, Length, Operator, Origin, Padding,
+    Anchor, AnchorPlacement, ClipMode, Collision, CollisionBoundary, Layout, Length, Padding,
     Position,
 };
 use rfgui::ui::{Binding, RsxComponent, RsxNode, component, props, rsx, use_state};
-use rfgui::view::Element;
+use rfgui::view::{Element, ElementStylePropSchema, RootStyleProps};
 
 #[derive(Clone, Copy, Debug, PartialEq, Eq)]
 pub enum TooltipPlacement {
@@ -113,6 +113,10 @@ pub struct TooltipProps {
     pub handle: Option<TooltipRef>,
     pub placement: Option<TooltipPlacement>,
     pub arrow: Option<bool>,
+    /// Stacked over the style of the tooltip bubble.
+    pub style: Option<ElementStylePropSchema>,
+    /// [`StyleClasses`](rfgui::view::StyleClasses) names, stacked beneath `style`.
+    pub class: Option<String>,
 }
 
 impl RsxComponent<TooltipProps> for Tooltip {
@@ -122,6 +126,7 @@ impl RsxComponent<TooltipProps> for Tooltip {
                 handle={props.handle}
                 placement={props.placement.unwrap_or_default()}
                 arrow={props.arrow.unwrap_or(false)}
+                root_style={RootStyleProps::new(props.style, props.class)}
             >
                 {children}
             </TooltipView>
@@ -148,39 +153,29 @@ impl rfgui::ui::RsxTag for Tooltip {
     }
 }
 
-fn placement_position(placement: TooltipPlacement, gap: Length) -> Position {
+pub(crate) fn placement_position(placement: TooltipPlacement, gap: Length) -> Position {
     use TooltipPlacement::*;
-    let base = Position::absolute()
+    let placement = match placement {
+        Top => AnchorPlacement::top(),
+        TopStart => AnchorPlacement::top_start(),
+        TopEnd => AnchorPlacement::top_end(),
+        Bottom => AnchorPlacement::bottom(),
+        BottomStart => AnchorPlacement::bottom_start(),
+        BottomEnd => AnchorPlacement::bottom_end(),
+        Left => AnchorPlacement::left(),
+        LeftStart => AnchorPlacement::left_start(),
+        LeftEnd => AnchorPlacement::left_end(),
+        Right => AnchorPlacement::right(),
+        RightStart => AnchorPlacement::right_start(),
+        RightEnd => AnchorPlacement::right_end(),
+    };
+    Position::absolute()
         .anchor(Anchor::Parent)
+        .placement(placement)
+        .anchor_offset(gap)
         .collision(Collision::FlipFit, CollisionBoundary::Viewport)
-        .clip(ClipMode::Viewport);
-    let gap_plus_full = Length::calc(Length::percent(100.0), Operator::plus, gap);
-    match placement {
-        Top => base
-            .bottom(gap_plus_full)
-            .left(Length::percent(50.0))
-            .origin(Origin::top_center()),
-        TopStart => base.bottom(gap_plus_full).left(Length::px(0.0)),
-        TopEnd => base.bottom(gap_plus_full).right(Length::px(0.0)),
-        Bottom => base
-            .top(gap_plus_full)
-            .left(Length::percent(50.0))
-            .origin(Origin::top_center()),
-        BottomStart => base.top(gap_plus_full).left(Length::px(0.0)),
-        BottomEnd => base.top(gap_plus_full).right(Length::px(0.0)),
-        Left => base
-            .right(gap_plus_full)
-            .top(Length::percent(50.0))
-            .origin(Origin::center_left()),
-        LeftStart => base.right(gap_plus_full).top(Length::px(0.0)),
-        LeftEnd => base.right(gap_plus_full).bottom(Length::px(0.0)),
-        Right => base
-            .left(gap_plus_full)
-            .top(Length::percent(50.0))
-            .origin(Origin::center_left()),
-        RightStart => base.left(gap_plus_full).top(Length::px(0.0)),
-        RightEnd => base.left(gap_plus_full).bottom(Length::px(0.0)),
-    }
+        .collision_padding(Length::px(8.0))
+        .clip(ClipMode::Viewport)
 }
 
 #[component]
@@ -188,6 +183,7 @@ fn TooltipView(
     handle: Option<TooltipRef>,
     placement: TooltipPlacement,
     arrow: bool,
+    root_style: RootStyleProps,
     children: Vec<RsxNode>,
 ) -> RsxNode {
     // arrow: accepted but not yet implemented.
@@ -202,7 +198,7 @@ fn TooltipView(
     let gap = Length::px(6.0);
     let position = placement_position(placement, gap);
 
-    rsx! {
+    let root = rsx! {
         <Element
             style={{
                 position: position,
@@ -216,5 +212,6 @@ fn TooltipView(
         >
             {children}
         </Element>
-    }
+    };
+    root_style.apply(root)
 }
@@ -1,24 +1,34 @@
 use proc_macro::TokenStream;
 use proc_macro_crate::{FoundCrate, crate_name};
-use proc_macro2::{Delimiter, Span, TokenTree};
+use proc_macro2::Span;
 use quote::{ToTokens, format_ident, quote, quote_spanned};
-use syn::parse::{Parse, ParseStream};
 use syn::punctuated::Punctuated;
 use syn::spanned::Spanned;
 use syn::{
-    Expr, Fields, FnArg, Ident, ItemFn, ItemStruct, Lit, LitStr, Pat, PatIdent, Path, Result,
-    ReturnType, Stmt, Token, Type, TypePath, braced, parse_quote,
+    Expr, Fields, FnArg, Ident, ItemFn, ItemStruct, Lit, Pat, PatIdent, Path, ReturnType, Stmt,
+    Token, Type, TypePath, parse_quote,
+};
+
+mod parse;
+
+#[cfg(test)]
+use parse::MultipleNodes;
+use parse::{
+    Child, ElementNode, FormatText, MatchNode, ObjectEntry, ObjectValueExpr, Prop, PropValueExpr,
+    SlotNode,
 };
 
 #[proc_macro]
 pub fn rsx(input: TokenStream) -> TokenStream {
     let rfgui = rfgui_path();
-    let nodes = match syn::parse::<MultipleNodes>(input) {
-        Ok(m) => m.nodes,
+    let nodes = match parse::parse_rsx_tokens(input.into()) {
+        Ok(nodes) => nodes,
         Err(err) => return err.to_compile_error().into(),
     };
 
-    let body = if nodes.len() == 1 {
+    let body = if nodes.iter().any(|node| matches!(node, Child::Let(_))) {
+        expand_root_with_bindings(&nodes)
+    } else if nodes.len() == 1 {
         expand_node(&nodes[0])
     } else {
         let children = nodes.iter().map(expand_node);
@@ -50,22 +60,6 @@ fn rfgui_path() -> proc_macro2::TokenStream {
     }
 }
 
-struct MultipleNodes {
-    nodes: Vec<Child>,
-}
-
-impl Parse for MultipleNodes {
-    fn parse(input: ParseStream) -> Result<Self> {
-        let mut parsed = Vec::new();
-        while !input.is_empty() {
-            parsed.push(parse_child(input)?);
-        }
-        Ok(MultipleNodes {
-            nodes: into_html_like_children(parsed),
-        })
-    }
-}
-
 #[proc_macro_attribute]
 pub fn component(_attr: TokenStream, item: TokenStream) -> TokenStream {
     // Two accepted forms:
@@ -100,571 +94,6 @@ pub fn props(attr: TokenStream, item: TokenStream) -> TokenStream {
     expand_prop(input_struct).into()
 }
 
-#[derive(Clone)]
-struct ElementNode {
-    tag: Path,
-    close_tag: Path,
-    props: Vec<Prop>,
-    children: Vec<Child>,
-    diagnostics: Vec<proc_macro2::TokenStream>,
-    source_start: Span,
-    source_end: Span,
-}
-
-#[derive(Clone)]
-struct Prop {
-    key: Ident,
-    value: PropValueExpr,
-}
-
-#[derive(Clone)]
-enum PropValueExpr {
-    Expr(Box<Expr>),
-    Macro(proc_macro2::TokenStream),
-    Object(Vec<ObjectEntry>),
-    Missing,
-    Invalid,
-}
-
-#[derive(Clone)]
-struct ObjectEntry {
-    key: Ident,
-    value: ObjectValueExpr,
-}
-
-#[derive(Clone)]
-enum ObjectValueExpr {
-    Expr(Box<Expr>),
-    Object(Vec<ObjectEntry>),
-    Missing,
-}
-
-#[derive(Clone)]
-enum Child {
-    Element(ElementNode),
-    TextLiteral(LitStr),
-    TextRaw(String),
-    Expr(Expr),
-}
-
-struct ParsedChild {
-    child: Child,
-    source_start: Span,
-    source_end: Span,
-}
-
-impl Parse for ElementNode {
-    fn parse(input: ParseStream) -> Result<Self> {
-        let open: Token![<] = input.parse()?;
-        let source_start = open.span();
-        let tag: Path = input.parse()?;
-
-        let mut props = Vec::new();
-        let mut diagnostics = Vec::new();
-        while !(input.peek(Token![>]) || (input.peek(Token![/]) && input.peek2(Token![>]))) {
-            if input.is_empty() || input.peek(Token![<]) {
-                diagnostics.push(
-                    syn::Error::new(tag.span(), "expected `>` to finish the start tag")
-                        .to_compile_error(),
-                );
-                break;
-            }
-            let key: Ident = input.parse()?;
-            if input.peek(Token![:]) {
-                let colon: Token![:] = input.parse()?;
-                return Err(syn::Error::new(
-                    colon.spans[0],
-                    format!(
-                        "invalid prop syntax on `{}`: use `=` for props (for example `{}={{expr}}`).",
-                        key, key
-                    ),
-                ));
-            }
-            if input.peek(Token![!]) {
-                let bang: Token![!] = input.parse()?;
-                let macro_body: TokenTree = input.parse()?;
-                let TokenTree::Group(group) = macro_body else {
-                    return Err(syn::Error::new(
-                        bang.span(),
-                        format!("expected delimiter group after `{}!`", key),
-                    ));
-                };
-                let delimiter = group.delimiter();
-                if delimiter == Delimiter::None {
-                    return Err(syn::Error::new(
-                        group.span(),
-                        format!("expected delimiter group after `{}!`", key),
-                    ));
-                }
-                let macro_tokens = quote! { #key ! #group };
-                props.push(Prop {
-                    key,
-                    value: PropValueExpr::Macro(macro_tokens),
-                });
-                continue;
-            }
-            if !input.peek(Token![=]) {
-                if can_recover_incomplete_prop(input) {
-                    props.push(Prop {
-                        key,
-                        value: PropValueExpr::Missing,
-                    });
-                    continue;
-                }
-                return Err(syn::Error::new(
-                    input.span(),
-                    format!("expected `=` after prop `{}`", key),
-                ));
-            }
-            input.parse::<Token![=]>()?;
-            let value: PropValueExpr = if input.peek(syn::token::Brace) {
-                let content;
-                braced!(content in input);
-                match parse_prop_value_expr(&key, &content) {
-                    Ok(value) => value,
-                    Err(err) => {
-                        diagnostics.push(err.to_compile_error());
-                        PropValueExpr::Invalid
-                    }
-                }
-            } else {
-                let lit: Lit = input.parse()?;
-                PropValueExpr::Expr(Box::new(parse_quote!(#lit)))
-            };
-            props.push(Prop { key, value });
-        }
-
-        for (index, prop) in props.iter().enumerate() {
-            if props[..index].iter().any(|prior| prior.key == prop.key) {
-                return Err(syn::Error::new(
-                    prop.key.span(),
-                    format!(
-                        "duplicate prop `{}` on `<{}>`; each prop may be specified only once",
-                        prop.key,
-                        tag.to_token_stream(),
-                    ),
-                ));
-            }
-        }
-
-        if input.peek(Token![/]) {
-            input.parse::<Token![/]>()?;
-            let close: Token![>] = input.parse()?;
-            return Ok(Self {
-                tag: tag.clone(),
-                close_tag: tag.clone(),
-                props,
-                children: Vec::new(),
-                diagnostics,
-                source_start,
-                source_end: close.span(),
-            });
-        }
-
-        if input.peek(Token![>]) {
-            let _: Token![>] = input.parse()?;
-        } else {
-            diagnostics.push(
-                syn::Error::new(tag.span(), "expected `>` to finish the start tag")
-                    .to_compile_error(),
-            );
-            if input.is_empty() {
-                return Ok(Self {
-                    tag: tag.clone(),
-                    close_tag: tag.clone(),
-                    props,
-                    children: Vec::new(),
-                    diagnostics,
-                    source_start,
-                    source_end: tag.span(),
-                });
-            }
-        }
-
-        let mut parsed_children = Vec::new();
-        while !(input.is_empty() || (input.peek(Token![<]) && input.peek2(Token![/]))) {
-            parsed_children.push(parse_child(input)?);
-        }
-        let unfinished_source_end = parsed_children
-            .last()
-            .map(|child| child.source_end)
-            .unwrap_or_else(|| tag.span());
-        let children = into_html_like_children(parsed_children);
-
-        if input.is_empty() {
-            diagnostics.push(
-                syn::Error::new(
-                    tag.span(),
-                    format!("missing closing tag for `<{}>`", tag.to_token_stream()),
-                )
-                .to_compile_error(),
-            );
-            return Ok(Self {
-                tag: tag.clone(),
-                close_tag: tag.clone(),
-                props,
-                children,
-                diagnostics,
-                source_start,
-                source_end: unfinished_source_end,
-            });
-        }
-
-        input.parse::<Token![<]>()?;
-        input.parse::<Token![/]>()?;
-        let close_tag: Path = input.parse()?;
-        if path_key(&close_tag) != path_key(&tag) {
-            return Err(syn::Error::new(
-                close_tag.span(),
-                "closing tag does not match",
-            ));
-        }
-        let source_end = if input.peek(Token![>]) {
-            let close: Token![>] = input.parse()?;
-            close.span()
-        } else {
-            diagnostics.push(
-                syn::Error::new(close_tag.span(), "expected `>` after closing tag")
-                    .to_compile_error(),
-            );
-            close_tag.span()
-        };
-
-        Ok(Self {
-            tag,
-            close_tag,
-            props,
-            children,
-            diagnostics,
-            source_start,
-            source_end,
-        })
-    }
-}
-
-fn can_recover_incomplete_prop(input: ParseStream) -> bool {
-    input.peek(Token![>]) || (input.peek(Token![/]) && input.peek2(Token![>])) || input.peek(Ident)
-}
-
-fn parse_prop_value_expr(key: &Ident, input: ParseStream) -> Result<PropValueExpr> {
-    let object_tokens: proc_macro2::TokenStream = input.fork().parse()?;
-    // Double-brace (`prop={{...}}`) is the committed-object signal: the inner
-    // brace group was preserved after the outer `braced!` strip. Once
-    // committed, enable recovery so partial entries (e.g. `w` with no `:`)
-    // survive parse and rust-analyzer can offer field completions.
-    if let Some(inner_tokens) = unwrap_single_brace_group(&object_tokens)
-        && let Ok(entries) = parse_object_entries_from_tokens(inner_tokens, true)
-    {
-        let _: proc_macro2::TokenStream = input.parse()?;
-        return Ok(PropValueExpr::Object(entries));
-    }
-
-    // Single-brace object (`prop={foo: bar}`) — probe strict to avoid
-    // misclassifying plain ident exprs like `prop={ident}` as `{ident: Missing}`.
-    if let Ok(entries) = parse_object_entries_from_tokens(object_tokens.clone(), false) {
-        let _: proc_macro2::TokenStream = input.parse()?;
-        return Ok(PropValueExpr::Object(entries));
-    }
-
-    if input.peek(syn::token::Brace) {
-        let fork = input.fork();
-        let nested;
-        braced!(nested in fork);
-        let nested_tokens: proc_macro2::TokenStream = nested.parse()?;
-        if let Ok(_entries) = parse_object_entries_from_tokens(nested_tokens, false)
-            && fork.is_empty()
-        {
-            let object_content;
-            braced!(object_content in input);
-            let object_tokens: proc_macro2::TokenStream = object_content.parse()?;
-            let entries = parse_object_entries_from_tokens(object_tokens, true)?;
-            if !input.is_empty() {
-                return Err(syn::Error::new(input.span(), "object syntax error"));
-            }
-            return Ok(PropValueExpr::Object(entries));
-        }
-    }
-
-    let expr_tokens: proc_macro2::TokenStream = input.parse()?;
-    match syn::parse2::<Expr>(expr_tokens.clone()) {
-        Ok(expr) => Ok(PropValueExpr::Expr(Box::new(expr))),
-        Err(parse_err) => {
-            if let Some(assign_span) = prop_assignment_like_span(&expr_tokens) {
-                return Err(syn::Error::new(
-                    assign_span,
-                    format!(
-                        "syntax error inside prop `{}`: `{{...}}` must be a valid Rust expression or RSX object. It looks like field assignment (`name=...`). Use `name: value` for RSX objects.",
-                        key
-                    ),
-                ));
-            }
-            Err(syn::Error::new(
-                parse_err.span(),
-                format!(
-                    "invalid Rust expression for prop `{}` inside `{{...}}`: {}",
-                    key, parse_err
-                ),
-            ))
-        }
-    }
-}
-
-fn prop_assignment_like_span(tokens: &proc_macro2::TokenStream) -> Option<Span> {
-    let mut iter = tokens.clone().into_iter().peekable();
-    while let Some(token) = iter.next() {
-        let TokenTree::Ident(_) = token else {
-            continue;
-        };
-        let Some(next) = iter.peek() else {
-            continue;
-        };
-        if let TokenTree::Punct(punct) = next
-            && punct.as_char() == '='
-        {
-            return Some(punct.span());
-        }
-    }
-    None
-}
-
-fn parse_object_entries(input: ParseStream, recover: bool) -> Result<Vec<ObjectEntry>> {
-    let mut entries = Vec::new();
-    while !input.is_empty() {
-        let key: Ident = input.parse()?;
-        if input.peek(Token![=]) {
-            let eq: Token![=] = input.parse()?;
-            return Err(syn::Error::new(
-                eq.spans[0],
-                format!(
-                    "invalid object syntax on `{}`: use `:` inside RSX objects (for example `{}: value`).",
-                    key, key
-                ),
-            ));
-        }
-        if !input.peek(Token![:]) {
-            if recover && can_recover_incomplete_object_entry(input) {
-                entries.push(ObjectEntry {
-                    key,
-                    value: ObjectValueExpr::Missing,
-                });
-                if input.peek(Token![,]) {
-                    input.parse::<Token![,]>()?;
-                }
-                continue;
-            }
-            return Err(syn::Error::new(
-                input.span(),
-                format!("expected `:` after key `{}`", key),
-            ));
-        }
-        input.parse::<Token![:]>()?;
-        let value = if input.peek(syn::token::Brace) {
-            let nested;
-            braced!(nested in input);
-            let nested_tokens: proc_macro2::TokenStream = nested.parse()?;
-            ObjectValueExpr::Object(parse_object_entries_from_tokens(nested_tokens, recover)?)
-        } else {
-            ObjectValueExpr::Expr(Box::new(input.parse()?))
-        };
-        entries.push(ObjectEntry { key, value });
-        if input.peek(Token![,]) {
-            input.parse::<Token![,]>()?;
-        }
-    }
-    Ok(entries)
-}
-
-fn can_recover_incomplete_object_entry(input: ParseStream) -> bool {
-    input.is_empty() || input.peek(Token![,])
-}
-
-fn parse_object_entries_from_tokens(
-    tokens: proc_macro2::TokenStream,
-    recover: bool,
-) -> Result<Vec<ObjectEntry>> {
-    struct ObjectEntries {
-        entries: Vec<ObjectEntry>,
-    }
-    struct ObjectEntriesRecover {
-        entries: Vec<ObjectEntry>,
-    }
-
-    impl Parse for ObjectEntries {
-        fn parse(input: ParseStream) -> Result<Self> {
-            Ok(Self {
-                entries: parse_object_entries(input, false)?,
-            })
-        }
-    }
-    impl Parse for ObjectEntriesRecover {
-        fn parse(input: ParseStream) -> Result<Self> {
-            Ok(Self {
-                entries: parse_object_entries(input, true)?,
-            })
-        }
-    }
-
-    if recover {
-        syn::parse2::<ObjectEntriesRecover>(tokens).map(|p| p.entries)
-    } else {
-        syn::parse2::<ObjectEntries>(tokens).map(|p| p.entries)
-    }
-}
-
-fn unwrap_single_brace_group(
-    tokens: &proc_macro2::TokenStream,
-) -> Option<proc_macro2::TokenStream> {
-    let mut iter = tokens.clone().into_iter();
-    let TokenTree::Group(group) = iter.next()? else {
-        return None;
-    };
-    if group.delimiter() != Delimiter::Brace || iter.next().is_some() {
-        return None;
-    }
-    Some(group.stream())
-}
-
-fn parse_child(input: ParseStream) -> Result<ParsedChild> {
-    if input.peek(Token![<]) && !input.peek2(Token![/]) {
-        let element: ElementNode = input.parse()?;
-        return Ok(ParsedChild {
-            source_start: element.source_start,
-            source_end: element.source_end,
-            child: Child::Element(element),
-        });
-    }
-    if input.peek(LitStr) {
-        let literal: LitStr = input.parse()?;
-        let span = literal.span();
-        return Ok(ParsedChild {
-            child: Child::TextLiteral(literal),
-            source_start: span,
-            source_end: span,
-        });
-    }
-    if input.peek(syn::token::Brace) {
-        let content;
-        let brace = braced!(content in input);
-        return Ok(ParsedChild {
-            child: Child::Expr(content.parse()?),
-            source_start: brace.span.open(),
-            source_end: brace.span.close(),
-        });
-    }
-    parse_raw_text(input)
-}
-
-fn parse_raw_text(input: ParseStream) -> Result<ParsedChild> {
-    let mut tokens = Vec::<TokenTree>::new();
-    while !input.is_empty() && !input.peek(Token![<]) && !input.peek(syn::token::Brace) {
-        tokens.push(input.parse()?);
-    }
-
-    let Some(first) = tokens.first() else {
-        return Err(syn::Error::new(input.span(), "expected RSX child"));
-    };
-    let source_start = first.span();
-    let source_end = tokens.last().expect("first token exists").span();
-    let mut reconstructed = String::new();
-    let mut previous: Option<&TokenTree> = None;
-    for token in &tokens {
-        if let Some(previous) = previous {
-            let separated = spans_have_source_gap(previous.span(), token.span())
-                .unwrap_or_else(|| fallback_needs_space(previous, token));
-            if separated
-                && !reconstructed
-                    .chars()
-                    .last()
-                    .is_some_and(char::is_whitespace)
-            {
-                reconstructed.push(' ');
-            }
-        }
-        if let Some(source_text) = token.span().source_text() {
-            reconstructed.push_str(&source_text);
-        } else {
-            reconstructed.push_str(&token_fallback_text(token));
-        }
-        previous = Some(token);
-    }
-
-    Ok(ParsedChild {
-        child: Child::TextRaw(collapse_html_whitespace(&reconstructed)),
-        source_start,
-        source_end,
-    })
-}
-
-fn token_fallback_text(token: &TokenTree) -> String {
-    token.to_string()
-}
-
-fn fallback_needs_space(previous: &TokenTree, current: &TokenTree) -> bool {
-    matches!(previous, TokenTree::Ident(_) | TokenTree::Literal(_))
-        && matches!(current, TokenTree::Ident(_) | TokenTree::Literal(_))
-}
-
-fn spans_have_source_gap(previous: Span, current: Span) -> Option<bool> {
-    let end = previous.end();
-    let start = current.start();
-    if end.line == 0 || start.line == 0 || start.line < end.line {
-        return None;
-    }
-    if start.line > end.line {
-        return Some(true);
-    }
-    (start.column >= end.column).then_some(start.column > end.column)
-}
-
-fn spans_have_same_line_source_gap(previous: Span, current: Span) -> Option<bool> {
-    let end = previous.end();
-    let start = current.start();
-    if end.line == 0 || start.line == 0 || start.line < end.line {
-        return None;
-    }
-    if start.line > end.line {
-        return Some(false);
-    }
-    (start.column >= end.column).then_some(start.column > end.column)
-}
-
-fn collapse_html_whitespace(input: &str) -> String {
-    input.split_whitespace().collect::<Vec<_>>().join(" ")
-}
-
-fn into_html_like_children(parsed: Vec<ParsedChild>) -> Vec<Child> {
-    let mut children = Vec::<Child>::with_capacity(parsed.len());
-    let mut previous_end = None;
-    for parsed_child in parsed {
-        let mut child = parsed_child.child;
-        if previous_end
-            .and_then(|end| spans_have_same_line_source_gap(end, parsed_child.source_start))
-            .unwrap_or(false)
-        {
-            insert_collapsed_boundary_space(&mut children, &mut child);
-        }
-        children.push(child);
-        previous_end = Some(parsed_child.source_end);
-    }
-    children
-}
-
-fn insert_collapsed_boundary_space(children: &mut Vec<Child>, next: &mut Child) {
-    if let Some(Child::TextRaw(text)) = children.last_mut() {
-        if !text.chars().last().is_some_and(char::is_whitespace) {
-            text.push(' ');
-        }
-        return;
-    }
-    if let Child::TextRaw(text) = next {
-        if !text.chars().next().is_some_and(char::is_whitespace) {
-            text.insert(0, ' ');
-        }
-        return;
-    }
-    children.push(Child::TextRaw(" ".to_string()));
-}
-
 fn component_key_tokens(element: &ElementNode) -> proc_macro2::TokenStream {
     let rfgui = rfgui_path();
     let Some(prop) = element.props.iter().find(|p| p.key == "key") else {
@@ -688,14 +117,15 @@ fn component_key_tokens(element: &ElementNode) -> proc_macro2::TokenStream {
     }
 }
 
-fn expand_prop(input_struct: ItemStruct) -> proc_macro2::TokenStream {
-    let struct_ident = &input_struct.ident;
+fn expand_prop(mut input_struct: ItemStruct) -> proc_macro2::TokenStream {
+    let rfgui = rfgui_path();
+    let struct_ident = input_struct.ident.clone();
     let init_ident = format_ident!("__{}Init", struct_ident);
-    let generics = &input_struct.generics;
+    let generics = input_struct.generics.clone();
     let (impl_generics, ty_generics, where_clause) = generics.split_for_impl();
 
-    let fields = match &input_struct.fields {
-        Fields::Named(named) => &named.named,
+    let fields = match &mut input_struct.fields {
+        Fields::Named(named) => &mut named.named,
         _ => {
             return syn::Error::new(
                 input_struct.fields.span(),
@@ -710,15 +140,20 @@ fn expand_prop(input_struct: ItemStruct) -> proc_macro2::TokenStream {
     let mut init_fields = Vec::new();
     let mut init_default_fields = Vec::new();
     let mut from_init_fields = Vec::new();
+    let mut literal_ranges = Vec::new();
     let mut all_optional = true;
-    for field in fields {
+    for field in fields.iter_mut() {
         let field_ident = match &field.ident {
-            Some(ident) => ident,
+            Some(ident) => ident.clone(),
             None => {
                 return syn::Error::new(field.span(), "#[prop] field must be named")
                     .to_compile_error();
             }
         };
+        let attrs = match PropFieldAttrs::take_from(&mut field.attrs) {
+            Ok(attrs) => attrs,
+            Err(err) => return err.to_compile_error(),
+        };
         let field_ty = &field.ty;
         if field_ident == "children" {
             return syn::Error::new(
@@ -731,32 +166,87 @@ fn expand_prop(input_struct: ItemStruct) -> proc_macro2::TokenStream {
             Some(inner) => (inner.clone(), true),
             None => (field_ty.clone(), false),
         };
-        init_fields.push(quote! {
-            pub #field_ident: ::core::option::Option<#init_inner>,
-        });
-        init_default_fields.push(quote! {
-            #field_ident: ::core::option::Option::None,
-        });
-        if is_already_option {
+        if let Some(error) = attrs.check_field(is_already_option) {
+            return error.to_compile_error();
+        }
+        let init_value = if attrs.into {
+            init_fields.push(quote! {
+                pub #field_ident: #rfgui::ui::__RsxIntoProp<#init_inner>,
+            });
+            init_default_fields.push(quote! {
+                #field_ident: ::core::default::Default::default(),
+            });
+            quote!(__init.#field_ident.0)
+        } else {
+            init_fields.push(quote! {
+                pub #field_ident: ::core::option::Option<#init_inner>,
+            });
+            init_default_fields.push(quote! {
+                #field_ident: ::core::option::Option::None,
+            });
+            quote!(__init.#field_ident)
+        };
+        let field_name = field_ident.to_string();
+        let value = if is_already_option {
             default_fields.push(quote! {
                 #field_ident: ::core::option::Option::None,
             });
-            from_init_fields.push(quote! {
-                #field_ident: __init.#field_ident,
+            init_value
+        } else if let Some(default) = &attrs.default {
+            default_fields.push(quote! {
+                #field_ident: #default,
+            });
+            quote!(#init_value.unwrap_or_else(|| #default))
+        } else if is_node_list_type(field_ty) {
+            default_fields.push(quote! {
+                #field_ident: ::std::vec::Vec::new(),
             });
+            quote!(#init_value.unwrap_or_default())
         } else {
             all_optional = false;
-            let field_name = field_ident.to_string();
-            from_init_fields.push(quote! {
-                #field_ident: __init.#field_ident.expect(concat!(
+            quote! {
+                #init_value.expect(concat!(
                     "missing required prop `",
                     #field_name,
                     "` on <",
                     #struct_name_str,
                     ">"
-                )),
-            });
-        }
+                ))
+            }
+        };
+        let value = match &attrs.range {
+            Some(range) => {
+                let range_text = range.to_token_stream().to_string();
+                if let Some(literal_range) =
+                    literal_prop_range(range, &field_name, &struct_name_str, &range_text, &rfgui)
+                {
+                    literal_ranges.push(literal_range);
+                }
+                let check = quote! {
+                    #rfgui::ui::__rsx_check_prop_range(
+                        __value,
+                        #range,
+                        #field_name,
+                        #struct_name_str,
+                        #range_text,
+                    )
+                };
+                if is_already_option {
+                    quote! {
+                        (#value).map(|__value| #check)
+                    }
+                } else {
+                    quote! {{
+                        let __value = #value;
+                        #check
+                    }}
+                }
+            }
+            None => value,
+        };
+        from_init_fields.push(quote! {
+            #field_ident: #value,
+        });
     }
 
     let optional_default_impl = if all_optional {
@@ -773,6 +263,26 @@ fn expand_prop(input_struct: ItemStruct) -> proc_macro2::TokenStream {
         quote! {}
     };
 
+    // Host tags use the props struct itself as `RsxTag::Props`, components
+    // the init struct; both carry the table `rsx!` checks literals against.
+    let literal_ranges_impl = if literal_ranges.is_empty() {
+        quote! {}
+    } else {
+        quote! {
+            impl #impl_generics #struct_ident #ty_generics #where_clause {
+                #[doc(hidden)]
+                pub const __RSX_PROP_RANGES: &'static [#rfgui::ui::__RsxPropRange] =
+                    &[#(#literal_ranges),*];
+            }
+
+            impl #impl_generics #init_ident #ty_generics #where_clause {
+                #[doc(hidden)]
+                pub const __RSX_PROP_RANGES: &'static [#rfgui::ui::__RsxPropRange] =
+                    <#struct_ident #ty_generics>::__RSX_PROP_RANGES;
+            }
+        }
+    };
+
     quote! {
         #input_struct
 
@@ -798,6 +308,151 @@ fn expand_prop(input_struct: ItemStruct) -> proc_macro2::TokenStream {
         }
 
         #optional_default_impl
+
+        #literal_ranges_impl
+    }
+}
+
+/// `__RsxPropRange` entry for `range` when both of its bounds are numeric
+/// literals, so `rsx!` can reject out-of-range literal props at compile
+/// time. Other ranges are only checked when the props are built.
+fn literal_prop_range(
+    range: &Expr,
+    field_name: &str,
+    struct_name: &str,
+    range_text: &str,
+    rfgui: &proc_macro2::TokenStream,
+) -> Option<proc_macro2::TokenStream> {
+    let Expr::Range(range) = range else {
+        return None;
+    };
+    let bound = |bound: &Option<Box<Expr>>, unbounded: proc_macro2::TokenStream| match bound {
+        Some(bound) => numeric_literal(bound).map(|literal| quote!((#literal) as f64)),
+        None => Some(unbounded),
+    };
+    let start = bound(&range.start, quote!(f64::NEG_INFINITY))?;
+    let end = bound(&range.end, quote!(f64::INFINITY))?;
+    let end_inclusive = matches!(range.limits, syn::RangeLimits::Closed(_));
+    let message = format!("prop `{field_name}` on <{struct_name}> must be in `{range_text}`");
+    Some(quote! {
+        #rfgui::ui::__RsxPropRange {
+            prop: #field_name,
+            start: #start,
+            end: #end,
+            end_inclusive: #end_inclusive,
+            message: #message,
+        }
+    })
+}
+
+/// `expr` when it is an integer or float literal, optionally negated.
+fn numeric_literal(expr: &Expr) -> Option<&Expr> {
+    match expr {
+        Expr::Lit(syn::ExprLit {
+            lit: Lit::Int(_) | Lit::Float(_),
+            ..
+        }) => Some(expr),
+        Expr::Unary(syn::ExprUnary {
+            op: syn::UnOp::Neg(_),
+            expr: inner,
+            ..
+        }) => numeric_literal(inner).map(|_| expr),
+        Expr::Group(group) => numeric_literal(&group.expr),
+        Expr::Paren(paren) => numeric_literal(&paren.expr),
+        _ => None,
+    }
+}
+
+/// Options from `#[prop(...)]` on a `#[props]` field.
+#[derive(Default)]
+struct PropFieldAttrs {
+    /// `default = expr`: value used when the caller leaves the prop out.
+    default: Option<Expr>,
+    /// `into`: accept any `Into<T>` value instead of `IntoOptionalProp<T>`.
+    into: bool,
+    /// `range(a..=b)`: the value must satisfy `range.contains(&value)`.
+    range: Option<Expr>,
+    /// `strip_option`: the field is an `Option<T>` set from a plain `T`.
+    strip_option: Option<Span>,
+}
+
+impl PropFieldAttrs {
+    /// Parses and removes every `#[prop(...)]` attribute, which is not a
+    /// real attribute and must not reach the emitted struct.
+    fn take_from(attrs: &mut Vec<syn::Attribute>) -> syn::Result<Self> {
+        let mut parsed = Self::default();
+        let mut result = Ok(());
+        attrs.retain(|attr| {
+            if !attr.path().is_ident("prop") {
+                return true;
+            }
+            if result.is_ok() {
+                result = parsed.parse_attr(attr);
+            }
+            false
+        });
+        result.map(|()| parsed)
+    }
+
+    fn parse_attr(&mut self, attr: &syn::Attribute) -> syn::Result<()> {
+        attr.parse_nested_meta(|meta| {
+            let duplicate = || meta.error("duplicate prop option");
+            if meta.path.is_ident("default") {
+                if self.default.is_some() {
+                    return Err(duplicate());
+                }
+                self.default = Some(meta.value()?.parse()?);
+            } else if meta.path.is_ident("into") {
+                if self.into {
+                    return Err(duplicate());
+                }
+                self.into = true;
+            } else if meta.path.is_ident("range") {
+                if self.range.is_some() {
+                    return Err(duplicate());
+                }
+                let content;
+                syn::parenthesized!(content in meta.input);
+                let range: Expr = content.parse()?;
+                if !matches!(range, Expr::Range(_)) {
+                    return Err(syn::Error::new(
+                        range.span(),
+                        "`range(...)` expects a range such as `0.0..=1.0`",
+                    ));
+                }
+                self.range = Some(range);
+            } else if meta.path.is_ident("strip_option") {
+                if self.strip_option.is_some() {
+                    return Err(duplicate());
+                }
+                self.strip_option = Some(meta.path.span());
+            } else {
+                return Err(meta.error(
+                    "unknown prop option; expected `default = ...`, `into`, `range(...)` or `strip_option`",
+                ));
+            }
+            Ok(())
+        })
+    }
+
+    fn check_field(&self, is_option: bool) -> Option<syn::Error> {
+        if let Some(default) = &self.default
+            && is_option
+        {
+            return Some(syn::Error::new(
+                default.span(),
+                "`default` has no effect on an `Option<T>` prop, which already defaults to `None`; declare the field as `T`",
+            ));
+        }
+        if let Some(span) = self.strip_option
+            && !is_option
+        {
+            return Some(syn::Error::new(
+                span,
+                "`strip_option` requires an `Option<T>` field",
+            ));
+        }
+        None
     }
 }
 
@@ -819,13 +474,78 @@ fn option_inner_type(ty: &Type) -> Option<&Type> {
     Some(inner_ty)
 }
 
-/// Closure-valued props route through `From<F>` / `From<NoArgHandler<F>>`
-/// so the concrete handler type is selected directly (single matching impl,
-/// no ambiguity). The macro stays ignorant of both event names and the `on_*`
-/// naming convention — handler-ness falls out of the field's declared type
-/// via the `__RsxHandlerField` trait impl. Non-handler props receiving a
-/// closure will fail to compile at the helper bound, which is the correct
-/// signal.
+/// `Vec<RsxNode>` props hold slot content (`<slot:name>`) and, like
+/// `children`, default to empty when the caller leaves them out.
+fn is_node_list_type(ty: &Type) -> bool {
+    let Some(inner) = vec_inner_type(ty) else {
+        return false;
+    };
+    let Type::Path(TypePath { qself: None, path }) = inner else {
+        return false;
+    };
+    path.segments
+        .last()
+        .is_some_and(|segment| segment.ident == "RsxNode")
+}
+
+fn vec_inner_type(ty: &Type) -> Option<&Type> {
+    let Type::Path(TypePath { qself: None, path }) = ty else {
+        return None;
+    };
+    let last = path.segments.last()?;
+    if last.ident != "Vec" {
+        return None;
+    }
+    let syn::PathArguments::AngleBracketed(args) = &last.arguments else {
+        return None;
+    };
+    let syn::GenericArgument::Type(inner_ty) = args.args.first()? else {
+        return None;
+    };
+    Some(inner_ty)
+}
+
+/// Argument types of a `#[component]` parameter declared as `impl Fn(...)`
+/// with no return value; such parameters are stored as `EventHandler`
+/// props.
+fn impl_fn_callback_inputs(ty: &Type) -> Option<Vec<Type>> {
+    let Type::ImplTrait(impl_trait) = ty else {
+        return None;
+    };
+    let mut inputs = None;
+    for bound in &impl_trait.bounds {
+        match bound {
+            syn::TypeParamBound::Trait(trait_bound) if inputs.is_none() => {
+                let last = trait_bound.path.segments.last()?;
+                let syn::PathArguments::Parenthesized(args) = &last.arguments else {
+                    return None;
+                };
+                let returns_unit = match &args.output {
+                    ReturnType::Default => true,
+                    ReturnType::Type(_, output) => {
+                        matches!(output.as_ref(), Type::Tuple(tuple) if tuple.elems.is_empty())
+                    }
+                };
+                if last.ident != "Fn" || !returns_unit {
+                    return None;
+                }
+                inputs = Some(args.inputs.iter().cloned().collect());
+            }
+            syn::TypeParamBound::Lifetime(_) => {}
+            _ => return None,
+        }
+    }
+    inputs
+}
+
+/// Closure-valued props call `__rsx_closure` on the field itself, so the
+/// field's declared type selects the conversion: event handler props resolve
+/// it through `__RsxHandlerField` (`From<F>` / `From<NoArgHandler<F>>`),
+/// callback props (`EventHandler<T>`, `Rc<dyn Fn(T)>`) through
+/// `__RsxCallbackField`. Either way the method's `Fn*` bound drives closure
+/// argument inference. The macro stays ignorant of both event names and the
+/// `on_*` naming convention. Non-handler props receiving a closure fail to
+/// compile at method resolution, which is the correct signal.
 fn expand_event_closure_assignment(
     key: &Ident,
     expr: &Expr,
@@ -835,15 +555,17 @@ fn expand_event_closure_assignment(
     let Expr::Closure(closure) = expr else {
         return None;
     };
-    let helper = if closure.inputs.is_empty() {
-        quote!(#rfgui::ui::__rsx_ev_no_arg_to_handler)
+    let method = if closure.inputs.is_empty() {
+        quote!(__rsx_no_arg_closure)
     } else {
-        quote!(#rfgui::ui::__rsx_ev_to_handler)
+        quote!(__rsx_closure)
     };
     Some(quote_spanned! {key.span()=>
-        #parent_path.#key = ::core::option::Option::Some(
-            #helper(&#parent_path.#key, #expr)
-        );
+        #parent_path.#key = ::core::option::Option::Some({
+            #[allow(unused_imports)]
+            use #rfgui::ui::{__RsxCallbackField as _, __RsxHandlerField as _};
+            #parent_path.#key.#method(#expr)
+        });
     })
 }
 
@@ -874,26 +596,6 @@ fn is_none_expr(expr: &Expr) -> bool {
     }
 }
 
-/// Key used to match an rsx closing tag against its opening tag. Compares
-/// by path segment idents only, stripping `PathArguments` (generics,
-/// parenthesized args). Allows the React-style close form:
-///
-/// ```ignore
-/// <Provider::<Ctx> value={v}>
-///     ...
-/// </Provider>            // generics may be omitted on close
-/// ```
-///
-/// `<Foo>` still rejects `</Bar>` (idents differ), and `<mod::Foo>`
-/// rejects `</Foo>` (segment paths differ).
-fn path_key(path: &Path) -> String {
-    path.segments
-        .iter()
-        .map(|s| s.ident.to_string())
-        .collect::<Vec<_>>()
-        .join("::")
-}
-
 /// True if the path carries any generic / parenthesized arguments on any
 /// segment. Used to decide whether a close tag needs generics-aware
 /// PhantomData emission or whether it's a bare-ident form that must
@@ -1015,6 +717,18 @@ fn expand_component_impl(mut input_impl: syn::ItemImpl) -> proc_macro2::TokenStr
                 ::core::ptr::NonNull::new(raw.cast())
                     .expect("Box::into_raw returns non-null")
             }
+
+            #[doc(hidden)]
+            unsafe fn __rsx_vtable_props_eq_shim(
+                a: ::core::ptr::NonNull<()>,
+                b: ::core::ptr::NonNull<()>,
+            ) -> bool {
+                #[allow(unused_imports)]
+                use #rfgui::ui::{__RsxPropsEqByValue as _, __RsxPropsEqFallback as _};
+                let a: &#strict_props_ty = unsafe { &*a.as_ptr().cast::<#strict_props_ty>() };
+                let b: &#strict_props_ty = unsafe { &*b.as_ptr().cast::<#strict_props_ty>() };
+                (&#rfgui::ui::__RsxPropsEq(a, b)).__rsx_props_eq()
+            }
         }
 
         impl #impl_generics #rfgui::ui::ComponentTag for #self_ty #where_clause {
@@ -1023,7 +737,7 @@ fn expand_component_impl(mut input_impl: syn::ItemImpl) -> proc_macro2::TokenStr
                     render: <#self_ty>::__rsx_vtable_render_shim,
                     drop_props: <#self_ty>::__rsx_vtable_drop_props_shim,
                     clone_props: <#self_ty>::__rsx_vtable_clone_props_shim,
-                    props_eq: ::core::option::Option::None,
+                    props_eq: ::core::option::Option::Some(<#self_ty>::__rsx_vtable_props_eq_shim),
                     type_name: #type_name_str,
                 };
         }
@@ -1073,6 +787,7 @@ fn expand_component(input_fn: ItemFn) -> proc_macro2::TokenStream {
     };
 
     let mut prop_fields = Vec::new();
+    let mut prop_field_idents = Vec::new();
     let mut helper_args = Punctuated::<FnArg, Token![,]>::new();
     let mut helper_call_args = Vec::new();
     let mut accepts_children = false;
@@ -1103,15 +818,59 @@ fn expand_component(input_fn: ItemFn) -> proc_macro2::TokenStream {
             // does not constrain its declared type — the generated
             // `render(props, children)` call fails at the `RsxComponent` trait
             // bound if the user's type is incompatible, which is the right
-            // place for that error.
+            // place for that error. `Option<_>` receives `None` when no
+            // children were passed.
             accepts_children = true;
             helper_args.push(arg.clone());
-            helper_call_args.push(quote!(children));
+            if option_inner_type(&ty).is_some() {
+                helper_call_args.push(quote!((!children.is_empty()).then_some(children)));
+            } else {
+                helper_call_args.push(quote!(children));
+            }
+            continue;
+        } else if let Some(inputs) = impl_fn_callback_inputs(&ty) {
+            // `impl Fn(A, B)` cannot be stored in the props struct, so the
+            // prop becomes an `EventHandler<(A, B)>` and the body still
+            // receives a plain closure that forwards to it.
+            let args: Vec<Ident> = (0..inputs.len())
+                .map(|index| format_ident!("__arg{}", index))
+                .collect();
+            let (arg_ty, value) = if inputs.len() == 1 {
+                (quote!(#(#inputs)*), quote!(#(#args)*))
+            } else {
+                (quote!((#(#inputs),*)), quote!((#(#args),*)))
+            };
+            let props_field_ty: Type = parse_quote!(#rfgui::ui::EventHandler<#arg_ty>);
+            prop_fields.push(quote!(pub #field_ident: #props_field_ty));
+            prop_field_idents.push(field_ident.clone());
+            init_fields.push(quote! {
+                pub #field_ident: ::core::option::Option<#props_field_ty>,
+            });
+            init_default_fields.push(quote! {
+                #field_ident: ::core::option::Option::None,
+            });
+            let field_name = field_ident.to_string();
+            let comp_name_str = comp_name.to_string();
+            from_init_fields.push(quote! {
+                #field_ident: __init.#field_ident.expect(concat!(
+                    "missing required prop `",
+                    #field_name,
+                    "` on <",
+                    #comp_name_str,
+                    ">"
+                )),
+            });
+            helper_args.push(arg.clone());
+            helper_call_args.push(quote! {{
+                let __callback = props.#field_ident;
+                move |#(#args),*| __callback.call(#value)
+            }});
             continue;
         } else {
             ty.clone()
         };
         prop_fields.push(quote!(pub #field_ident: #props_field_ty));
+        prop_field_idents.push(field_ident.clone());
         let (init_inner, is_already_option) = match option_inner_type(&props_field_ty) {
             Some(inner) => (inner.clone(), true),
             None => (props_field_ty.clone(), false),
@@ -1126,6 +885,10 @@ fn expand_component(input_fn: ItemFn) -> proc_macro2::TokenStream {
             from_init_fields.push(quote! {
                 #field_ident: __init.#field_ident,
             });
+        } else if is_node_list_type(&props_field_ty) {
+            from_init_fields.push(quote! {
+                #field_ident: __init.#field_ident.unwrap_or_default(),
+            });
         } else {
             let field_name = field_ident.to_string();
             let comp_name_str = comp_name.to_string();
@@ -1278,6 +1041,22 @@ fn expand_component(input_fn: ItemFn) -> proc_macro2::TokenStream {
                 ::core::ptr::NonNull::new(raw.cast())
                     .expect("Box::into_raw returns non-null")
             }
+
+            // Field-wise so one prop without `PartialEq` (or an `impl Fn`
+            // callback, compared by allocation) only forces a re-render
+            // when that prop is involved, not a compile error.
+            #[doc(hidden)]
+            unsafe fn __rsx_vtable_props_eq_shim(
+                a: ::core::ptr::NonNull<()>,
+                b: ::core::ptr::NonNull<()>,
+            ) -> bool {
+                #[allow(unused_imports)]
+                use #rfgui::ui::{__RsxPropsEqByValue as _, __RsxPropsEqFallback as _};
+                let a: &#props_name #ty_generics = unsafe { &*a.as_ptr().cast::<#props_name #ty_generics>() };
+                let b: &#props_name #ty_generics = unsafe { &*b.as_ptr().cast::<#props_name #ty_generics>() };
+                let _ = (a, b);
+                true #(&& (&#rfgui::ui::__RsxPropsEq(&a.#prop_field_idents, &b.#prop_field_idents)).__rsx_props_eq())*
+            }
         }
 
         impl #impl_generics #rfgui::ui::ComponentTag for #comp_name #ty_generics #where_clause {
@@ -1286,7 +1065,7 @@ fn expand_component(input_fn: ItemFn) -> proc_macro2::TokenStream {
                     render: <Self>::__rsx_vtable_render_shim,
                     drop_props: <Self>::__rsx_vtable_drop_props_shim,
                     clone_props: <Self>::__rsx_vtable_clone_props_shim,
-                    props_eq: ::core::option::Option::None,
+                    props_eq: ::core::option::Option::Some(<Self>::__rsx_vtable_props_eq_shim),
                     type_name: ::core::stringify!(#comp_name),
                 };
         }
@@ -1300,13 +1079,81 @@ fn expand_component(input_fn: ItemFn) -> proc_macro2::TokenStream {
 // v2 expansion: React-style shared createElement path
 // ============================================================================
 
-fn expand_node(child: &Child) -> proc_macro2::TokenStream {
-    let rfgui = rfgui_path();
-    match child {
-        Child::Element(element) => expand_element(element),
-        Child::TextLiteral(text) => quote! { #rfgui::ui::RsxNode::text(#text) },
-        Child::TextRaw(text) => quote! { #rfgui::ui::RsxNode::text(#text) },
-        Child::Expr(expr) => quote! { #rfgui::ui::IntoRsxNode::into_rsx_node(#expr) },
+fn expand_node(child: &Child) -> proc_macro2::TokenStream {
+    let rfgui = rfgui_path();
+    match child {
+        Child::Element(element) => expand_element(element),
+        Child::TextLiteral(text) => quote! { #rfgui::ui::RsxNode::text(#text) },
+        Child::TextRaw(text) => quote! { #rfgui::ui::RsxNode::text(#text) },
+        Child::Expr(expr) => quote! { #rfgui::ui::IntoRsxNode::into_rsx_node(#expr) },
+        Child::Match(node) => expand_match(node, expand_node),
+        Child::Format(FormatText { template, args }) => quote! {
+            #rfgui::ui::RsxNode::text(::std::format!(#template, #(#args),*))
+        },
+        // Bindings are statements; `expand_root_with_bindings` and
+        // `expand_child_append` emit them in place.
+        Child::Let(locals) => quote! {{
+            #(#locals)*
+            #rfgui::ui::RsxNode::fragment(::std::vec::Vec::new())
+        }},
+        // `expand_element` consumes slots of component elements; anywhere
+        // else there is no prop to fill.
+        Child::Slot(slot) => {
+            let message = format!(
+                "`<slot:{}>` must be a direct child of a component element",
+                slot.name
+            );
+            quote_spanned! {slot.name.span()=>
+                compile_error!(#message)
+            }
+        }
+    }
+}
+
+/// Top-level nodes interleaved with `{let ...;}` bindings: evaluate in
+/// source order so each binding is in scope for the nodes after it.
+fn expand_root_with_bindings(nodes: &[Child]) -> proc_macro2::TokenStream {
+    let rfgui = rfgui_path();
+    let mut statements = Vec::new();
+    let mut values = Vec::new();
+    for (index, node) in nodes.iter().enumerate() {
+        if let Child::Let(locals) = node {
+            statements.push(quote! { #(#locals)* });
+            continue;
+        }
+        let value = format_ident!("__rsx_node_{}", index);
+        let node = expand_node(node);
+        statements.push(quote! { let #value = #node; });
+        values.push(value);
+    }
+    let result = match values.as_slice() {
+        [single] => quote! { #single },
+        _ => quote! { #rfgui::ui::RsxNode::fragment(vec![#(#values),*]) },
+    };
+    quote! {{
+        #(#statements)*
+        #result
+    }}
+}
+
+/// `match` with markup arms. `expand_body` turns each arm into a value of
+/// one shared type.
+fn expand_match(
+    node: &MatchNode,
+    expand_body: impl Fn(&Child) -> proc_macro2::TokenStream,
+) -> proc_macro2::TokenStream {
+    let match_token = &node.match_token;
+    let expr = &node.expr;
+    let arms = node.arms.iter().map(|arm| {
+        let pat = &arm.pat;
+        let guard = arm.guard.as_ref().map(|guard| quote! { if #guard });
+        let body = expand_body(&arm.body);
+        quote! { #pat #guard => #body, }
+    });
+    quote! {
+        #match_token #expr {
+            #(#arms)*
+        }
     }
 }
 
@@ -1316,6 +1163,20 @@ fn expand_child_append(child: &Child) -> proc_macro2::TokenStream {
         Child::Expr(expr) => quote! {
             #rfgui::ui::append_rsx_child_node(&mut __rsx_children, #expr);
         },
+        Child::Let(locals) => quote! { #(#locals)* },
+        // In child position arms may also produce `()`, `Option` or `Vec`.
+        Child::Match(node) => {
+            let value = expand_match(node, |body| match body {
+                Child::Expr(expr) => quote! {
+                    #rfgui::ui::IntoRsxChildren::into_rsx_children(#expr)
+                },
+                _ => {
+                    let node = expand_node(body);
+                    quote! { ::std::vec![#node] }
+                }
+            });
+            quote! { __rsx_children.extend(#value); }
+        }
         _ => {
             let node = expand_node(child);
             quote! { __rsx_children.push(#node); }
@@ -1326,8 +1187,11 @@ fn expand_child_append(child: &Child) -> proc_macro2::TokenStream {
 fn expand_element(element: &ElementNode) -> proc_macro2::TokenStream {
     let rfgui = rfgui_path();
     let tag = &element.tag;
-    let has_children = !element.children.is_empty();
-    let child_appends = element.children.iter().map(expand_child_append);
+    let (slots, children): (Vec<&Child>, Vec<&Child>) = element
+        .children
+        .iter()
+        .partition(|child| matches!(child, Child::Slot(_)));
+    let has_children = !children.is_empty();
 
     let parent_path = quote!(__init);
     let prop_assignments = element
@@ -1335,6 +1199,12 @@ fn expand_element(element: &ElementNode) -> proc_macro2::TokenStream {
         .iter()
         .filter(|p| p.key != "key")
         .map(|prop| expand_prop_assignment(prop, &parent_path));
+    let slot_assignments = slots.iter().enumerate().map(|(index, child)| {
+        let Child::Slot(slot) = child else {
+            unreachable!("partitioned on Child::Slot");
+        };
+        expand_slot_assignment(slot, &slots[..index], element, &parent_path)
+    });
 
     // Hoist `Missing`-style / incomplete entry diagnostics to the top of the
     // element block. Emitting `compile_error!` from deep inside the init
@@ -1344,6 +1214,8 @@ fn expand_element(element: &ElementNode) -> proc_macro2::TokenStream {
     let mut diagnostics: Vec<proc_macro2::TokenStream> = element.diagnostics.clone();
     for prop in &element.props {
         collect_prop_missing_errors(prop, &mut diagnostics);
+        collect_style_color_literal_checks(prop, &mut diagnostics);
+        collect_prop_range_literal_checks(tag, prop, &mut diagnostics);
     }
     let component_key = component_key_tokens(element);
     let children_schema_check = if has_children {
@@ -1356,16 +1228,7 @@ fn expand_element(element: &ElementNode) -> proc_macro2::TokenStream {
     } else {
         quote! {}
     };
-    let children_value = if has_children {
-        let static_children_capacity = element.children.len();
-        quote! {{
-            let mut __rsx_children = ::std::vec::Vec::with_capacity(#static_children_capacity);
-            #(#child_appends)*
-            __rsx_children
-        }}
-    } else {
-        quote! { ::std::vec::Vec::new() }
-    };
+    let children_value = expand_children_vec(&children);
 
     // `PhantomData::<#close_tag>` nudges rustc / rust-analyzer to resolve
     // the closing-tag name (enables hover, goto-def, and unused-import
@@ -1390,6 +1253,7 @@ fn expand_element(element: &ElementNode) -> proc_macro2::TokenStream {
             #rfgui::ui::__rsx_create_element::<#tag, _>(
                 |__init: &mut <#tag as #rfgui::ui::RsxTag>::Props| {
                     #(#prop_assignments)*
+                    #(#slot_assignments)*
                 },
                 #children_value,
                 #component_key,
@@ -1398,6 +1262,52 @@ fn expand_element(element: &ElementNode) -> proc_macro2::TokenStream {
     }
 }
 
+/// `Vec<RsxNode>` built from `children`, in the shape `expand_child_append`
+/// expects.
+fn expand_children_vec(children: &[&Child]) -> proc_macro2::TokenStream {
+    if children.is_empty() {
+        return quote! { ::std::vec::Vec::new() };
+    }
+    let static_children_capacity = children
+        .iter()
+        .filter(|child| !matches!(child, Child::Let(_)))
+        .count();
+    let child_appends = children.iter().map(|child| expand_child_append(child));
+    quote! {{
+        let mut __rsx_children = ::std::vec::Vec::with_capacity(#static_children_capacity);
+        #(#child_appends)*
+        __rsx_children
+    }}
+}
+
+/// `<slot:name>` content assigned to the `name` prop. A slot may not repeat
+/// or duplicate an attribute.
+fn expand_slot_assignment(
+    slot: &SlotNode,
+    earlier_slots: &[&Child],
+    element: &ElementNode,
+    parent_path: &proc_macro2::TokenStream,
+) -> proc_macro2::TokenStream {
+    let name = &slot.name;
+    let repeated = earlier_slots
+        .iter()
+        .any(|child| matches!(child, Child::Slot(earlier) if earlier.name == *name));
+    if repeated || element.props.iter().any(|prop| prop.key == *name) {
+        let message = format!(
+            "duplicate `{}` on `<{}>`; pass it either as a prop or as one `<slot:{}>`",
+            name,
+            element.tag.to_token_stream(),
+            name
+        );
+        return quote_spanned! {name.span()=>
+            compile_error!(#message);
+        };
+    }
+    let children: Vec<&Child> = slot.children.iter().collect();
+    let value = expand_children_vec(&children);
+    expand_field_assign(name, parent_path, value)
+}
+
 /// Walks a prop value and pushes a `compile_error!` for every `Missing`
 /// style/object entry onto `out`. Emitted at element-block top level so
 /// rust-analyzer's span mapping survives (see `expand_element`).
@@ -1429,32 +1339,236 @@ fn collect_object_entry_missing(entry: &ObjectEntry, out: &mut Vec<proc_macro2::
     }
 }
 
-fn expand_prop_assignment(
+/// Style object keys whose string values are parsed as CSS colors.
+const STYLE_COLOR_KEYS: &[&str] = &["color", "background", "background_color"];
+
+const STYLE_LENGTH_KEYS: &[&str] = &[
+    "width",
+    "height",
+    "min_width",
+    "max_width",
+    "min_height",
+    "max_height",
+    "gap",
+    "border_radius",
+    "padding_top",
+    "padding_right",
+    "padding_bottom",
+    "padding_left",
+    "margin_top",
+    "margin_right",
+    "margin_bottom",
+    "margin_left",
+];
+
+fn is_style_prop_key(key: &Ident) -> bool {
+    let key = key.to_string();
+    key == "style" || key.ends_with("_style")
+}
+
+/// String literals under length and font-size keys of a style object are
+/// parsed here, so `width: "50%"` becomes `Length::percent(50.0)` and
+/// `width: "50pct"` fails at the literal. Returns `None` for anything the
+/// runtime conversions should handle.
+fn style_literal_value(key: &Ident, expr: &Expr) -> Option<proc_macro2::TokenStream> {
+    let Expr::Lit(syn::ExprLit {
+        lit: Lit::Str(lit), ..
+    }) = expr
+    else {
+        return None;
+    };
+    let key = key.to_string();
+    let (ty, units): (&str, &[&str]) = if STYLE_LENGTH_KEYS.contains(&key.as_str()) {
+        ("Length", &["px", "%", "vw", "vh"])
+    } else if key == "font_size" {
+        ("FontSize", &["px", "em", "rem", "%", "vw", "vh"])
+    } else {
+        return None;
+    };
+    let text = lit.value();
+    let parsed = split_css_number(text.trim()).and_then(|(number, unit)| {
+        if unit.is_empty() && number == 0.0 && ty == "Length" {
+            return Some((number, "zero"));
+        }
+        let unit = units.iter().find(|known| **known == unit)?;
+        Some((number, if *unit == "%" { "percent" } else { unit }))
+    });
+    let span = lit.span();
+    let Some((number, constructor)) = parsed else {
+        let message = format!(
+            "invalid {} `{}` for `{}`: expected a number followed by {}",
+            if ty == "Length" {
+                "length"
+            } else {
+                "font size"
+            },
+            text,
+            key,
+            units
+                .iter()
+                .map(|unit| format!("`{unit}`"))
+                .collect::<Vec<_>>()
+                .join(", ")
+        );
+        return Some(quote_spanned! {span=> compile_error!(#message) });
+    };
+    let rfgui: proc_macro2::TokenStream = rfgui_path()
+        .into_iter()
+        .map(|mut token| {
+            token.set_span(span);
+            token
+        })
+        .collect();
+    let ty = Ident::new(ty, span);
+    if constructor == "zero" {
+        return Some(quote_spanned! {span=> #rfgui::style::#ty::Zero });
+    }
+    let constructor = Ident::new(constructor, span);
+    let number = proc_macro2::Literal::f32_suffixed(number);
+    Some(quote_spanned! {span=> #rfgui::style::#ty::#constructor(#number) })
+}
+
+/// Leading CSS number and the lowercased unit after it.
+fn split_css_number(value: &str) -> Option<(f32, String)> {
+    let unit_start = value
+        .char_indices()
+        .find(|&(index, ch)| {
+            !(ch.is_ascii_digit() || ch == '.' || ((ch == '-' || ch == '+') && index == 0))
+        })
+        .map_or(value.len(), |(index, _)| index);
+    let number = value[..unit_start].parse::<f32>().ok()?;
+    number
+        .is_finite()
+        .then(|| (number, value[unit_start..].to_ascii_lowercase()))
+}
+
+/// Walks `style` / `*_style` objects and pushes a const-evaluated color check
+/// for every string literal under a color key, so `color: "redd"` fails to
+/// compile at the literal instead of rendering transparent.
+fn collect_style_color_literal_checks(prop: &Prop, out: &mut Vec<proc_macro2::TokenStream>) {
+    if !is_style_prop_key(&prop.key) {
+        return;
+    }
+    if let PropValueExpr::Object(entries) = &prop.value {
+        for e in entries {
+            collect_object_entry_color_checks(e, out);
+        }
+    }
+}
+
+/// Checks a numeric literal prop against the literal `#[prop(range(..))]`
+/// bounds of `tag`'s props at compile time. Props without such bounds
+/// resolve `__RSX_PROP_RANGES` to the empty `__RsxNoPropRanges` fallback.
+fn collect_prop_range_literal_checks(
+    tag: &Path,
     prop: &Prop,
+    out: &mut Vec<proc_macro2::TokenStream>,
+) {
+    let PropValueExpr::Expr(expr) = &prop.value else {
+        return;
+    };
+    let Some(literal) = numeric_literal(expr) else {
+        return;
+    };
+    if prop.key == "key" {
+        return;
+    }
+    let span = literal.span();
+    let rfgui: proc_macro2::TokenStream = rfgui_path()
+        .into_iter()
+        .map(|mut token| {
+            token.set_span(span);
+            token
+        })
+        .collect();
+    let key = prop.key.to_string();
+    // A `const {}` block rather than an item like the color checks: the
+    // tag may name generics of the enclosing function.
+    out.push(quote_spanned! {span=>
+        const {
+            #[allow(unused_imports)]
+            use #rfgui::ui::__RsxNoPropRanges as _;
+            #rfgui::ui::__rsx_check_literal_prop(
+                <<#tag as #rfgui::ui::RsxTag>::Props>::__RSX_PROP_RANGES,
+                #key,
+                (#literal) as f64,
+            );
+        };
+    });
+}
+
+fn collect_object_entry_color_checks(entry: &ObjectEntry, out: &mut Vec<proc_macro2::TokenStream>) {
+    match &entry.value {
+        ObjectValueExpr::Expr(expr) => {
+            if let Expr::Lit(syn::ExprLit {
+                lit: Lit::Str(lit), ..
+            }) = &**expr
+                && STYLE_COLOR_KEYS.contains(&entry.key.to_string().as_str())
+            {
+                // Respan the crate path too, so the const-eval error lands on
+                // the literal rather than the whole `rsx!` invocation.
+                let rfgui: proc_macro2::TokenStream = rfgui_path()
+                    .into_iter()
+                    .map(|mut token| {
+                        token.set_span(lit.span());
+                        token
+                    })
+                    .collect();
+                // A `const` item rather than a `const {}` block: `cargo check`
+                // evaluates items but skips inline const blocks.
+                out.push(quote_spanned! {lit.span()=>
+                    const _: () = #rfgui::style::__rsx_assert_css_color(#lit);
+                });
+            }
+        }
+        ObjectValueExpr::Object(inner) => {
+            for e in inner {
+                collect_object_entry_color_checks(e, out);
+            }
+        }
+        ObjectValueExpr::Missing => {}
+    }
+}
+
+/// `parent.key.__rsx_assign(value)`: the init field's type decides which
+/// values it accepts (`IntoOptionalProp` for `Option<T>` slots, `Into` for
+/// `#[prop(into)]` ones), so the macro never needs to know the prop's type.
+fn expand_field_assign(
+    key: &Ident,
     parent_path: &proc_macro2::TokenStream,
+    value: proc_macro2::TokenStream,
 ) -> proc_macro2::TokenStream {
     let rfgui = rfgui_path();
+    quote_spanned! {key.span()=>
+        {
+            #[allow(unused_imports)]
+            use #rfgui::ui::__RsxPropField as _;
+            #parent_path.#key.__rsx_assign(#value);
+        }
+    }
+}
+
+fn expand_prop_assignment(
+    prop: &Prop,
+    parent_path: &proc_macro2::TokenStream,
+) -> proc_macro2::TokenStream {
     let key = &prop.key;
     let key_span = key.span();
     match &prop.value {
         PropValueExpr::Missing => {
-            // `<Element disabled />` shorthand — set to `Some(true)`.
-            quote_spanned! {key_span=>
-                #parent_path.#key = ::core::option::Option::Some(true);
-            }
+            // `<Element disabled />` shorthand — set to `true`.
+            expand_field_assign(key, parent_path, quote_spanned!(key_span=> true))
         }
         PropValueExpr::Expr(expr) => {
             if let Some(tokens) = expand_event_closure_assignment(key, expr, parent_path) {
                 return tokens;
             }
-            quote_spanned! {key_span=>
-                #parent_path.#key = #rfgui::ui::IntoOptionalProp::into_optional_prop(#expr);
-            }
+            expand_field_assign(key, parent_path, expr.to_token_stream())
+        }
+        PropValueExpr::Macro(tokens) => expand_field_assign(key, parent_path, tokens.clone()),
+        PropValueExpr::Object(entries) => {
+            expand_object_literal(key, parent_path, entries, is_style_prop_key(key))
         }
-        PropValueExpr::Macro(tokens) => quote_spanned! {key_span=>
-            #parent_path.#key = #rfgui::ui::IntoOptionalProp::into_optional_prop(#tokens);
-        },
-        PropValueExpr::Object(entries) => expand_object_literal(key, parent_path, entries),
         PropValueExpr::Invalid => quote! {},
     }
 }
@@ -1463,12 +1577,13 @@ fn expand_object_literal(
     key: &Ident,
     parent_path: &proc_macro2::TokenStream,
     entries: &[ObjectEntry],
+    style: bool,
 ) -> proc_macro2::TokenStream {
     let rfgui = rfgui_path();
     let inner_parent = quote!(__obj);
     let inner_assignments: Vec<proc_macro2::TokenStream> = entries
         .iter()
-        .map(|e| expand_object_entry_assignment(e, &inner_parent))
+        .map(|e| expand_object_entry_assignment(e, &inner_parent, style))
         .collect();
 
     quote_spanned! {key.span()=>
@@ -1567,8 +1682,21 @@ fn rewrite_conditional_assignment(
 fn expand_object_entry_assignment(
     entry: &ObjectEntry,
     parent_path: &proc_macro2::TokenStream,
+    style: bool,
 ) -> proc_macro2::TokenStream {
     let key = &entry.key;
+    if let Some(args) = &entry.args {
+        return expand_media_block(entry, args, parent_path, style);
+    }
+    if style
+        && let ObjectValueExpr::Expr(expr) = &entry.value
+        && let Some(value) = style_literal_value(key, expr)
+    {
+        let rfgui = rfgui_path();
+        return quote_spanned! {key.span()=>
+            #parent_path.#key = #rfgui::ui::IntoOptionalProp::into_optional_prop(#value);
+        };
+    }
     match &entry.value {
         // Error hoisted to element-block top by `collect_prop_missing_errors`.
         // Emit a field access at the key span so rust-analyzer resolves the
@@ -1578,7 +1706,62 @@ fn expand_object_entry_assignment(
             let _ = &#parent_path.#key;
         },
         ObjectValueExpr::Expr(expr) => expand_assignment_with_none_rewrite(parent_path, key, expr),
-        ObjectValueExpr::Object(entries) => expand_object_literal(key, parent_path, entries),
+        ObjectValueExpr::Object(entries) => expand_object_literal(key, parent_path, entries, style),
+    }
+}
+
+// `media(min_width: 800.0): { width: "50%" }` appends a
+// `MediaRule { query, style }` to the `media` list of the enclosing object.
+// Arguments name `MediaQuery` fields; the body fills the rule's style the
+// same way a nested style object would.
+fn expand_media_block(
+    entry: &ObjectEntry,
+    args: &[ObjectEntry],
+    parent_path: &proc_macro2::TokenStream,
+    style: bool,
+) -> proc_macro2::TokenStream {
+    let key = &entry.key;
+    if key != "media" {
+        return quote_spanned! {key.span()=>
+            compile_error!("only `media(...)` blocks take arguments inside RSX objects");
+        };
+    }
+    let ObjectValueExpr::Object(entries) = &entry.value else {
+        return quote_spanned! {key.span()=>
+            compile_error!("expected `{ ... }` after `media(...):`");
+        };
+    };
+    let rfgui = rfgui_path();
+    let conditions = args.iter().map(|arg| {
+        let field = &arg.key;
+        match &arg.value {
+            ObjectValueExpr::Expr(expr) => quote_spanned! {field.span()=>
+                __query.#field = ::core::option::Option::Some(#expr);
+            },
+            _ => quote_spanned! {field.span()=>
+                compile_error!("`media(...)` arguments take a value, e.g. `min_width: 800.0`");
+            },
+        }
+    });
+    let inner_parent = quote!(__obj);
+    let inner_assignments: Vec<proc_macro2::TokenStream> = entries
+        .iter()
+        .map(|e| expand_object_entry_assignment(e, &inner_parent, style))
+        .collect();
+
+    quote_spanned! {key.span()=>
+        {
+            let mut __media = #parent_path.#key.take().unwrap_or_default();
+            let mut __query = #rfgui::style::MediaQuery::default();
+            #(#conditions)*
+            let __style = {
+                let mut __obj = #rfgui::ui::__rsx_default_media_style(&__media);
+                #(#inner_assignments)*
+                __obj
+            };
+            __media.push(#rfgui::style::MediaRule::new(__query, __style));
+            #parent_path.#key = ::core::option::Option::Some(__media);
+        }
     }
 }
 
@@ -1771,6 +1954,35 @@ mod tests {
         assert!(expanded.contains("__rsx_default_inner_option"));
     }
 
+    #[test]
+    fn style_color_string_literals_get_compile_time_check() {
+        let parsed = syn::parse_str::<MultipleNodes>(
+            r##"<Element style={{ color: "red", border: { color: "rgb(0 0 0)" }, background: bg }} />"##,
+        )
+        .expect("rsx should parse style colors");
+
+        let expanded = expand_node(&parsed.nodes[0]).to_string();
+        assert_eq!(expanded.matches("__rsx_assert_css_color").count(), 2);
+
+        let parsed = syn::parse_str::<MultipleNodes>(r##"<Label text={{ color: "x" }} />"##)
+            .expect("rsx should parse object prop");
+        let expanded = expand_node(&parsed.nodes[0]).to_string();
+        assert!(!expanded.contains("__rsx_assert_css_color"));
+    }
+
+    #[test]
+    fn numeric_literal_props_get_compile_time_range_check() {
+        let parsed = syn::parse_str::<MultipleNodes>(
+            r#"<Meter value={1.5} size={-2} label="x" ratio={r} />"#,
+        )
+        .expect("rsx should parse numeric props");
+
+        let expanded = expand_node(&parsed.nodes[0]).to_string();
+        assert_eq!(expanded.matches("__rsx_check_literal_prop").count(), 2);
+        assert!(expanded.contains("\"value\" , (1.5) as f64"));
+        assert!(expanded.contains("\"size\" , (- 2) as f64"));
+    }
+
     #[test]
     fn nested_object_prop_expands_via_default_inner_option() {
         let parsed = syn::parse_str::<MultipleNodes>(
@@ -1906,4 +2118,314 @@ mod tests {
         assert!(expanded.contains("does not accept children"));
         assert!(expanded.contains("with_capacity (1usize)"));
     }
+
+    #[test]
+    fn match_with_markup_arms_becomes_a_match_child() {
+        let parsed = syn::parse_str::<MultipleNodes>(
+            r#"<Element>{match state { State::A => <Text>a</Text> State::B(n) if n > 1 => "many", _ => () }}</Element>"#,
+        )
+        .expect("match child should parse");
+        let Child::Element(root) = &parsed.nodes[0] else {
+            panic!("expected root element");
+        };
+        let [Child::Match(node)] = root.children.as_slice() else {
+            panic!("expected one match child");
+        };
+        assert_eq!(node.arms.len(), 3);
+        assert!(matches!(*node.arms[0].body, Child::Element(_)));
+        assert!(node.arms[1].guard.is_some());
+
+        let expanded = expand_node(&parsed.nodes[0]).to_string();
+        assert!(expanded.contains("__rsx_children . extend (match state"));
+        assert!(expanded.contains("into_rsx_children (\"many\")"));
+    }
+
+    #[test]
+    fn match_without_markup_or_with_a_tail_stays_an_expression() {
+        for source in [
+            r#"<Element>{match state { _ => label }}</Element>"#,
+            r#"<Element>{match state { _ => 1 }.to_string()}</Element>"#,
+        ] {
+            let parsed = syn::parse_str::<MultipleNodes>(source).expect("match should parse");
+            let Child::Element(root) = &parsed.nodes[0] else {
+                panic!("expected root element");
+            };
+            assert!(
+                matches!(root.children.as_slice(), [Child::Expr(_)]),
+                "{source}"
+            );
+        }
+    }
+
+    #[test]
+    fn match_arm_errors_point_at_the_arm() {
+        let err = syn::parse_str::<MultipleNodes>(
+            r#"<Element>{match state { A => a B => <C /> }}</Element>"#,
+        )
+        .err()
+        .expect("missing comma should fail");
+        assert_eq!(err.to_string(), "expected `,` after match arm");
+    }
+
+    #[test]
+    fn let_children_bind_for_later_siblings_without_text() {
+        let parsed = syn::parse_str::<MultipleNodes>(
+            "<Element>Hello {let name = user.name(); let n = 2;} {name}</Element>",
+        )
+        .expect("let child should parse");
+        let Child::Element(root) = &parsed.nodes[0] else {
+            panic!("expected root element");
+        };
+        assert_eq!(root.children.len(), 4);
+        assert!(matches!(&root.children[0], Child::TextRaw(text) if text == "Hello"));
+        assert!(matches!(&root.children[1], Child::Let(locals) if locals.len() == 2));
+        assert!(matches!(&root.children[2], Child::TextRaw(text) if text == " "));
+
+        let expanded = expand_node(&parsed.nodes[0]).to_string();
+        assert!(expanded.contains("let name = user . name () ;"));
+        assert!(expanded.contains("with_capacity (3usize)"));
+    }
+
+    #[test]
+    fn let_children_reject_other_statements() {
+        let err = syn::parse_str::<MultipleNodes>("<Element>{let a = 1; a += 1;}</Element>")
+            .err()
+            .expect("non-let statement should fail");
+        assert!(err.to_string().contains("may only contain `let` bindings"));
+    }
+
+    #[test]
+    fn format_shorthand_expands_to_formatted_text() {
+        let parsed = syn::parse_str::<MultipleNodes>(
+            r#"<Text>{"{} of {total}", done} and {=ratio * 100.0}% {"plain"}</Text>"#,
+        )
+        .expect("format shorthand should parse");
+        let Child::Element(text) = &parsed.nodes[0] else {
+            panic!("expected Text element");
+        };
+        let kinds = text
+            .children
+            .iter()
+            .map(|child| match child {
+                Child::Format(format) => format!("format {}", format.template.value()),
+                Child::TextRaw(text) => format!("raw {text}"),
+                Child::Expr(_) => "expr".to_string(),
+                _ => "other".to_string(),
+            })
+            .collect::<Vec<_>>();
+        assert_eq!(
+            kinds,
+            [
+                "format {} of {total}",
+                "raw  and ",
+                "format {}",
+                "raw % ",
+                "expr"
+            ]
+        );
+
+        let expanded = expand_node(&parsed.nodes[0]).to_string();
+        assert!(expanded.contains(":: std :: format ! (\"{} of {total}\" , done)"));
+        assert!(expanded.contains(":: std :: format ! (\"{}\" , ratio * 100.0)"));
+    }
+
+    #[test]
+    fn impl_fn_params_become_event_handler_props() {
+        let input: syn::ItemFn = syn::parse_quote! {
+            fn Picker(on_pick: impl Fn(usize, String), on_close: impl Fn()) -> RsxNode {
+                rsx! { <Element /> }
+            }
+        };
+        let expanded = expand_component(input).to_string();
+        assert!(expanded.contains("ui :: EventHandler < (usize , String) >"));
+        assert!(expanded.contains("on_close : :: rfgui :: ui :: EventHandler < () >"));
+        assert!(
+            expanded.contains("move | __arg0 , __arg1 | __callback . call ((__arg0 , __arg1))")
+        );
+
+        let not_callback: syn::ItemFn = syn::parse_quote! {
+            fn Mapper(map: impl Fn(u8) -> u8) -> RsxNode { rsx! { <Element /> } }
+        };
+        assert!(
+            !expand_component(not_callback)
+                .to_string()
+                .contains("EventHandler")
+        );
+    }
+
+    #[test]
+    fn slots_become_prop_assignments() {
+        let parsed = syn::parse_str::<MultipleNodes>(
+            r#"<Card><slot:header><Text>Title</Text></slot:header> body <slot:footer /></Card>"#,
+        )
+        .expect("slots should parse");
+        let Child::Element(card) = &parsed.nodes[0] else {
+            panic!("expected Card element");
+        };
+        let names = card
+            .children
+            .iter()
+            .filter_map(|child| match child {
+                Child::Slot(slot) => Some(slot.name.to_string()),
+                _ => None,
+            })
+            .collect::<Vec<_>>();
+        assert_eq!(names, ["header", "footer"]);
+
+        let expanded = expand_node(&parsed.nodes[0]).to_string();
+        assert!(expanded.contains("__init . header . __rsx_assign ("));
+        assert!(
+            expanded.contains("__init . footer . __rsx_assign (:: std :: vec :: Vec :: new ())")
+        );
+        assert!(expanded.contains("with_capacity (1usize)"));
+    }
+
+    #[test]
+    fn slot_errors_are_reported() {
+        let err = syn::parse_str::<MultipleNodes>("<Card><slot:header>x</slot:footer></Card>")
+            .err()
+            .expect("mismatched slot close should fail");
+        assert!(err.to_string().contains("closing tag does not match"));
+
+        let duplicate =
+            syn::parse_str::<MultipleNodes>("<Card header={a}><slot:header>x</slot:header></Card>")
+                .expect("duplicate slot parses");
+        assert!(
+            expand_node(&duplicate.nodes[0])
+                .to_string()
+                .contains("duplicate `header`")
+        );
+
+        let stray = syn::parse_str::<MultipleNodes>("<slot:header>x</slot:header>")
+            .expect("root slot parses");
+        assert!(
+            expand_node(&stray.nodes[0])
+                .to_string()
+                .contains("must be a direct child")
+        );
+    }
+
+    #[test]
+    fn generic_tags_thread_type_arguments() {
+        let parsed = syn::parse_str::<MultipleNodes>("<List<Vec<u8>> data={x}>a</List>")
+            .expect("type arguments on the tag should parse");
+        let expanded = expand_node(&parsed.nodes[0]).to_string();
+        assert!(expanded.contains("__rsx_create_element :: < List < Vec < u8 > > , _ >"));
+
+        syn::parse_str::<MultipleNodes>("<List::<Item>>a</List<Item>>")
+            .expect("turbofish and plain arguments close each other");
+        let err = syn::parse_str::<MultipleNodes>("<List<Item>>a</List<Other>>")
+            .err()
+            .expect("mismatched close arguments should fail");
+        assert!(err.to_string().contains("type arguments do not match"));
+    }
+
+    #[test]
+    fn style_length_literals_convert_at_expansion() {
+        let parsed = syn::parse_str::<MultipleNodes>(
+            r#"<Element style={{ width: "50%", height: "0", border_radius: "8px", font_size: "1.5rem", hover: { max_width: "10vw" } }} />"#,
+        )
+        .expect("style literals should parse");
+        let expanded = expand_node(&parsed.nodes[0]).to_string();
+        assert!(expanded.contains(":: rfgui :: style :: Length :: percent (50f32)"));
+        assert!(expanded.contains(":: rfgui :: style :: Length :: Zero"));
+        assert!(expanded.contains(":: rfgui :: style :: Length :: px (8f32)"));
+        assert!(expanded.contains(":: rfgui :: style :: FontSize :: rem (1.5f32)"));
+        assert!(expanded.contains(":: rfgui :: style :: Length :: vw (10f32)"));
+
+        let invalid = syn::parse_str::<MultipleNodes>(
+            r#"<Element style={{ width: "50pct", font_size: "0" }} label={{ width: "wide" }} />"#,
+        )
+        .expect("invalid literals still parse");
+        let expanded = expand_node(&invalid.nodes[0]).to_string();
+        assert!(expanded.contains("invalid length `50pct` for `width`"));
+        assert!(expanded.contains("invalid font size `0` for `font_size`"));
+        assert!(expanded.contains("into_optional_prop (\"wide\")"));
+    }
+
+    #[test]
+    fn boolean_shorthand_and_punned_props() {
+        let parsed = syn::parse_str::<MultipleNodes>("<Item disabled {value} {key} />")
+            .expect("shorthand props should parse");
+        let Child::Element(item) = &parsed.nodes[0] else {
+            panic!("expected Item element");
+        };
+        let keys = item
+            .props
+            .iter()
+            .map(|prop| prop.key.to_string())
+            .collect::<Vec<_>>();
+        assert_eq!(keys, ["disabled", "value", "key"]);
+        let expanded = expand_node(&parsed.nodes[0]).to_string();
+        assert!(expanded.contains("__init . disabled . __rsx_assign (true)"));
+        assert!(expanded.contains("__init . value . __rsx_assign (value)"));
+        assert!(expanded.contains("classify_component_key (& (key))"));
+
+        let err = syn::parse_str::<MultipleNodes>("<Item {self.value} />")
+            .err()
+            .expect("non-identifier shorthand should fail");
+        assert!(err.to_string().contains("single identifier"));
+        let err = syn::parse_str::<MultipleNodes>("<Item {value} value={1} />")
+            .err()
+            .expect("punned and explicit prop collide");
+        assert!(err.to_string().contains("duplicate prop `value`"));
+    }
+
+    #[test]
+    fn prop_field_options_shape_init_and_conversion() {
+        let input: syn::ItemStruct = syn::parse_quote! {
+            pub struct MeterProps {
+                #[prop(into)]
+                pub label: String,
+                #[prop(default = 4.0, range(0.0..=8.0))]
+                pub size: f32,
+            }
+        };
+        let expanded = super::expand_prop(input).to_string();
+        assert!(!expanded.contains("# [prop"));
+        assert!(expanded.contains("pub label : :: rfgui :: ui :: __RsxIntoProp < String >"));
+        assert!(expanded.contains("label : __init . label . 0 . expect"));
+        assert!(expanded.contains("__init . size . unwrap_or_else (|| 4.0)"));
+        assert!(expanded.contains("__rsx_check_prop_range"));
+        assert!(expanded.contains("__RSX_PROP_RANGES"));
+        assert!(expanded.contains("must be in `0.0 ..= 8.0`"));
+
+        let input: syn::ItemStruct = syn::parse_quote! {
+            pub struct P {
+                #[prop(range(0..LIMIT))]
+                pub count: u32,
+            }
+        };
+        let expanded = super::expand_prop(input).to_string();
+        assert!(expanded.contains("__rsx_check_prop_range"));
+        assert!(!expanded.contains("__RSX_PROP_RANGES"));
+
+        let errors = [
+            (
+                syn::parse_quote! { struct P { #[prop(default = None)] a: Option<u8> } },
+                "already defaults to `None`",
+            ),
+            (
+                syn::parse_quote! { struct P { #[prop(strip_option)] a: u8 } },
+                "requires an `Option<T>` field",
+            ),
+            (
+                syn::parse_quote! { struct P { #[prop(range(3))] a: u8 } },
+                "expects a range",
+            ),
+            (
+                syn::parse_quote! { struct P { #[prop(into, into)] a: u8 } },
+                "duplicate prop option",
+            ),
+            (
+                syn::parse_quote! { struct P { #[prop(validate)] a: u8 } },
+                "unknown prop option",
+            ),
+        ];
+        for (input, message) in errors {
+            let input: syn::ItemStruct = input;
+            let expanded = super::expand_prop(input).to_string();
+            assert!(expanded.contains(message), "{expanded}");
+        }
+    }
 }
@@ -0,0 +1,914 @@
+//! RSX token parsing, independent of `proc_macro`.
+//!
+//! Everything here works on `proc_macro2` tokens so the same code backs the
+//! `rsx!` macro and runs outside a compiler invocation: the fuzz targets
+//! under `fuzz/` include this file directly. Malformed input must surface
+//! as a `syn::Error`, never a panic.
+
+use proc_macro2::{Delimiter, Span, TokenTree};
+use quote::{ToTokens, quote};
+use syn::parse::discouraged::Speculative;
+use syn::parse::{Parse, ParseStream};
+use syn::spanned::Spanned;
+use syn::{
+    Expr, Ident, Lit, LitStr, Local, Pat, Path, Result, Stmt, Token, braced, parenthesized,
+    parse_quote,
+};
+
+/// Parse the body of an `rsx!` invocation into its top-level children.
+pub(crate) fn parse_rsx_tokens(tokens: proc_macro2::TokenStream) -> Result<Vec<Child>> {
+    syn::parse2::<MultipleNodes>(tokens).map(|parsed| parsed.nodes)
+}
+
+pub(crate) struct MultipleNodes {
+    pub(crate) nodes: Vec<Child>,
+}
+
+impl Parse for MultipleNodes {
+    fn parse(input: ParseStream) -> Result<Self> {
+        let mut parsed = Vec::new();
+        while !input.is_empty() {
+            parsed.push(parse_child(input)?);
+        }
+        Ok(MultipleNodes {
+            nodes: into_html_like_children(parsed),
+        })
+    }
+}
+
+#[derive(Clone)]
+pub(crate) struct ElementNode {
+    pub(crate) tag: Path,
+    pub(crate) close_tag: Path,
+    pub(crate) props: Vec<Prop>,
+    pub(crate) children: Vec<Child>,
+    pub(crate) diagnostics: Vec<proc_macro2::TokenStream>,
+    pub(crate) source_start: Span,
+    pub(crate) source_end: Span,
+}
+
+#[derive(Clone)]
+pub(crate) struct Prop {
+    pub(crate) key: Ident,
+    pub(crate) value: PropValueExpr,
+}
+
+#[derive(Clone)]
+pub(crate) enum PropValueExpr {
+    Expr(Box<Expr>),
+    Macro(proc_macro2::TokenStream),
+    Object(Vec<ObjectEntry>),
+    Missing,
+    Invalid,
+}
+
+#[derive(Clone)]
+pub(crate) struct ObjectEntry {
+    pub(crate) key: Ident,
+    /// `(...)` arguments of a conditional block key such as
+    /// `media(min_width: 800.0): { ... }`.
+    pub(crate) args: Option<Vec<ObjectEntry>>,
+    pub(crate) value: ObjectValueExpr,
+}
+
+#[derive(Clone)]
+pub(crate) enum ObjectValueExpr {
+    Expr(Box<Expr>),
+    Object(Vec<ObjectEntry>),
+    Missing,
+}
+
+#[derive(Clone)]
+pub(crate) enum Child {
+    Element(ElementNode),
+    TextLiteral(LitStr),
+    TextRaw(String),
+    Expr(Expr),
+    /// `{let pat = expr; ...}`: bindings visible to the following siblings.
+    Let(Vec<Local>),
+    /// `{match expr { ... }}` with at least one markup arm.
+    Match(MatchNode),
+    /// `{"{} items", n}` or `{=expr}`: a text node built with `format!`.
+    Format(FormatText),
+    /// `<slot:name>...</slot:name>`: content for the parent component's
+    /// `name` prop instead of its `children`.
+    Slot(SlotNode),
+}
+
+#[derive(Clone)]
+pub(crate) struct SlotNode {
+    pub(crate) name: Ident,
+    pub(crate) children: Vec<Child>,
+}
+
+#[derive(Clone)]
+pub(crate) struct FormatText {
+    pub(crate) template: LitStr,
+    pub(crate) args: Vec<Expr>,
+}
+
+#[derive(Clone)]
+pub(crate) struct MatchNode {
+    pub(crate) match_token: Token![match],
+    pub(crate) expr: Box<Expr>,
+    pub(crate) arms: Vec<MatchArm>,
+}
+
+#[derive(Clone)]
+pub(crate) struct MatchArm {
+    pub(crate) pat: Pat,
+    pub(crate) guard: Option<Box<Expr>>,
+    /// `Child::Element` for markup arms, `Child::Expr` otherwise.
+    pub(crate) body: Box<Child>,
+}
+
+pub(crate) struct ParsedChild {
+    pub(crate) child: Child,
+    pub(crate) source_start: Span,
+    pub(crate) source_end: Span,
+}
+
+impl Parse for ElementNode {
+    fn parse(input: ParseStream) -> Result<Self> {
+        let open: Token![<] = input.parse()?;
+        let source_start = open.span();
+        let tag: Path = input.parse()?;
+
+        let mut props = Vec::new();
+        let mut diagnostics = Vec::new();
+        while !(input.peek(Token![>]) || (input.peek(Token![/]) && input.peek2(Token![>]))) {
+            if input.is_empty() || input.peek(Token![<]) {
+                diagnostics.push(
+                    syn::Error::new(tag.span(), "expected `>` to finish the start tag")
+                        .to_compile_error(),
+                );
+                break;
+            }
+            if input.peek(syn::token::Brace) {
+                props.push(parse_punned_prop(input)?);
+                continue;
+            }
+            let key: Ident = input.parse()?;
+            if input.peek(Token![:]) {
+                let colon: Token![:] = input.parse()?;
+                return Err(syn::Error::new(
+                    colon.spans[0],
+                    format!(
+                        "invalid prop syntax on `{}`: use `=` for props (for example `{}={{expr}}`).",
+                        key, key
+                    ),
+                ));
+            }
+            if input.peek(Token![!]) {
+                let bang: Token![!] = input.parse()?;
+                let macro_body: TokenTree = input.parse()?;
+                let TokenTree::Group(group) = macro_body else {
+                    return Err(syn::Error::new(
+                        bang.span(),
+                        format!("expected delimiter group after `{}!`", key),
+                    ));
+                };
+                let delimiter = group.delimiter();
+                if delimiter == Delimiter::None {
+                    return Err(syn::Error::new(
+                        group.span(),
+                        format!("expected delimiter group after `{}!`", key),
+                    ));
+                }
+                let macro_tokens = quote! { #key ! #group };
+                props.push(Prop {
+                    key,
+                    value: PropValueExpr::Macro(macro_tokens),
+                });
+                continue;
+            }
+            if !input.peek(Token![=]) {
+                if can_recover_incomplete_prop(input) {
+                    props.push(Prop {
+                        key,
+                        value: PropValueExpr::Missing,
+                    });
+                    continue;
+                }
+                return Err(syn::Error::new(
+                    input.span(),
+                    format!("expected `=` after prop `{}`", key),
+                ));
+            }
+            input.parse::<Token![=]>()?;
+            let value: PropValueExpr = if input.peek(syn::token::Brace) {
+                let content;
+                braced!(content in input);
+                match parse_prop_value_expr(&key, &content) {
+                    Ok(value) => value,
+                    Err(err) => {
+                        diagnostics.push(err.to_compile_error());
+                        PropValueExpr::Invalid
+                    }
+                }
+            } else {
+                let lit: Lit = input.parse()?;
+                PropValueExpr::Expr(Box::new(parse_quote!(#lit)))
+            };
+            props.push(Prop { key, value });
+        }
+
+        for (index, prop) in props.iter().enumerate() {
+            if props[..index].iter().any(|prior| prior.key == prop.key) {
+                return Err(syn::Error::new(
+                    prop.key.span(),
+                    format!(
+                        "duplicate prop `{}` on `<{}>`; each prop may be specified only once",
+                        prop.key,
+                        tag.to_token_stream(),
+                    ),
+                ));
+            }
+        }
+
+        if input.peek(Token![/]) {
+            input.parse::<Token![/]>()?;
+            let close: Token![>] = input.parse()?;
+            return Ok(Self {
+                tag: tag.clone(),
+                close_tag: tag.clone(),
+                props,
+                children: Vec::new(),
+                diagnostics,
+                source_start,
+                source_end: close.span(),
+            });
+        }
+
+        if input.peek(Token![>]) {
+            let _: Token![>] = input.parse()?;
+        } else {
+            diagnostics.push(
+                syn::Error::new(tag.span(), "expected `>` to finish the start tag")
+                    .to_compile_error(),
+            );
+            if input.is_empty() {
+                return Ok(Self {
+                    tag: tag.clone(),
+                    close_tag: tag.clone(),
+                    props,
+                    children: Vec::new(),
+                    diagnostics,
+                    source_start,
+                    source_end: tag.span(),
+                });
+            }
+        }
+
+        let mut parsed_children = Vec::new();
+        while !(input.is_empty() || (input.peek(Token![<]) && input.peek2(Token![/]))) {
+            parsed_children.push(parse_child(input)?);
+        }
+        let unfinished_source_end = parsed_children
+            .last()
+            .map(|child| child.source_end)
+            .unwrap_or_else(|| tag.span());
+        let children = into_html_like_children(parsed_children);
+
+        if input.is_empty() {
+            diagnostics.push(
+                syn::Error::new(
+                    tag.span(),
+                    format!("missing closing tag for `<{}>`", tag.to_token_stream()),
+                )
+                .to_compile_error(),
+            );
+            return Ok(Self {
+                tag: tag.clone(),
+                close_tag: tag.clone(),
+                props,
+                children,
+                diagnostics,
+                source_start,
+                source_end: unfinished_source_end,
+            });
+        }
+
+        input.parse::<Token![<]>()?;
+        input.parse::<Token![/]>()?;
+        let close_tag: Path = input.parse()?;
+        if path_key(&close_tag) != path_key(&tag) {
+            return Err(syn::Error::new(
+                close_tag.span(),
+                "closing tag does not match",
+            ));
+        }
+        if path_has_type_args(&close_tag)
+            && path_type_args_key(&close_tag) != path_type_args_key(&tag)
+        {
+            return Err(syn::Error::new(
+                close_tag.span(),
+                format!(
+                    "closing tag type arguments do not match `<{}>`; repeat them exactly or omit them",
+                    tag.to_token_stream()
+                ),
+            ));
+        }
+        let source_end = if input.peek(Token![>]) {
+            let close: Token![>] = input.parse()?;
+            close.span()
+        } else {
+            diagnostics.push(
+                syn::Error::new(close_tag.span(), "expected `>` after closing tag")
+                    .to_compile_error(),
+            );
+            close_tag.span()
+        };
+
+        Ok(Self {
+            tag,
+            close_tag,
+            props,
+            children,
+            diagnostics,
+            source_start,
+            source_end,
+        })
+    }
+}
+
+/// `{value}` in prop position: shorthand for `value={value}`.
+fn parse_punned_prop(input: ParseStream) -> Result<Prop> {
+    let content;
+    let brace = braced!(content in input);
+    let key = match content.parse::<Ident>() {
+        Ok(key) if content.is_empty() => key,
+        _ => {
+            return Err(syn::Error::new(
+                brace.span.join(),
+                "prop shorthand takes a single identifier, for example `{value}` for `value={value}`",
+            ));
+        }
+    };
+    Ok(Prop {
+        value: PropValueExpr::Expr(Box::new(parse_quote!(#key))),
+        key,
+    })
+}
+
+fn can_recover_incomplete_prop(input: ParseStream) -> bool {
+    input.peek(Token![>])
+        || (input.peek(Token![/]) && input.peek2(Token![>]))
+        || input.peek(Ident)
+        || input.peek(syn::token::Brace)
+}
+
+fn parse_prop_value_expr(key: &Ident, input: ParseStream) -> Result<PropValueExpr> {
+    let object_tokens: proc_macro2::TokenStream = input.fork().parse()?;
+    // Double-brace (`prop={{...}}`) is the committed-object signal: the inner
+    // brace group was preserved after the outer `braced!` strip. Once
+    // committed, enable recovery so partial entries (e.g. `w` with no `:`)
+    // survive parse and rust-analyzer can offer field completions.
+    if let Some(inner_tokens) = unwrap_single_brace_group(&object_tokens)
+        && let Ok(entries) = parse_object_entries_from_tokens(inner_tokens, true)
+    {
+        let _: proc_macro2::TokenStream = input.parse()?;
+        return Ok(PropValueExpr::Object(entries));
+    }
+
+    // Single-brace object (`prop={foo: bar}`) — probe strict to avoid
+    // misclassifying plain ident exprs like `prop={ident}` as `{ident: Missing}`.
+    if let Ok(entries) = parse_object_entries_from_tokens(object_tokens.clone(), false) {
+        let _: proc_macro2::TokenStream = input.parse()?;
+        return Ok(PropValueExpr::Object(entries));
+    }
+
+    if input.peek(syn::token::Brace) {
+        let fork = input.fork();
+        let nested;
+        braced!(nested in fork);
+        let nested_tokens: proc_macro2::TokenStream = nested.parse()?;
+        if let Ok(_entries) = parse_object_entries_from_tokens(nested_tokens, false)
+            && fork.is_empty()
+        {
+            let object_content;
+            braced!(object_content in input);
+            let object_tokens: proc_macro2::TokenStream = object_content.parse()?;
+            let entries = parse_object_entries_from_tokens(object_tokens, true)?;
+            if !input.is_empty() {
+                return Err(syn::Error::new(input.span(), "object syntax error"));
+            }
+            return Ok(PropValueExpr::Object(entries));
+        }
+    }
+
+    let expr_tokens: proc_macro2::TokenStream = input.parse()?;
+    match syn::parse2::<Expr>(expr_tokens.clone()) {
+        Ok(expr) => Ok(PropValueExpr::Expr(Box::new(expr))),
+        Err(parse_err) => {
+            if let Some(assign_span) = prop_assignment_like_span(&expr_tokens) {
+                return Err(syn::Error::new(
+                    assign_span,
+                    format!(
+                        "syntax error inside prop `{}`: `{{...}}` must be a valid Rust expression or RSX object. It looks like field assignment (`name=...`). Use `name: value` for RSX objects.",
+                        key
+                    ),
+                ));
+            }
+            Err(syn::Error::new(
+                parse_err.span(),
+                format!(
+                    "invalid Rust expression for prop `{}` inside `{{...}}`: {}",
+                    key, parse_err
+                ),
+            ))
+        }
+    }
+}
+
+fn prop_assignment_like_span(tokens: &proc_macro2::TokenStream) -> Option<Span> {
+    let mut iter = tokens.clone().into_iter().peekable();
+    while let Some(token) = iter.next() {
+        let TokenTree::Ident(_) = token else {
+            continue;
+        };
+        let Some(next) = iter.peek() else {
+            continue;
+        };
+        if let TokenTree::Punct(punct) = next
+            && punct.as_char() == '='
+        {
+            return Some(punct.span());
+        }
+    }
+    None
+}
+
+fn parse_object_entries(input: ParseStream, recover: bool) -> Result<Vec<ObjectEntry>> {
+    let mut entries = Vec::new();
+    while !input.is_empty() {
+        let key: Ident = input.parse()?;
+        let args = if input.peek(syn::token::Paren) {
+            let args;
+            parenthesized!(args in input);
+            let args_tokens: proc_macro2::TokenStream = args.parse()?;
+            Some(parse_object_entries_from_tokens(args_tokens, false)?)
+        } else {
+            None
+        };
+        if input.peek(Token![=]) {
+            let eq: Token![=] = input.parse()?;
+            return Err(syn::Error::new(
+                eq.spans[0],
+                format!(
+                    "invalid object syntax on `{}`: use `:` inside RSX objects (for example `{}: value`).",
+                    key, key
+                ),
+            ));
+        }
+        if !input.peek(Token![:]) {
+            if recover && can_recover_incomplete_object_entry(input) {
+                entries.push(ObjectEntry {
+                    key,
+                    args,
+                    value: ObjectValueExpr::Missing,
+                });
+                if input.peek(Token![,]) {
+                    input.parse::<Token![,]>()?;
+                }
+                continue;
+            }
+            return Err(syn::Error::new(
+                input.span(),
+                format!("expected `:` after key `{}`", key),
+            ));
+        }
+        input.parse::<Token![:]>()?;
+        let value = if input.peek(syn::token::Brace) {
+            let nested;
+            braced!(nested in input);
+            let nested_tokens: proc_macro2::TokenStream = nested.parse()?;
+            ObjectValueExpr::Object(parse_object_entries_from_tokens(nested_tokens, recover)?)
+        } else {
+            ObjectValueExpr::Expr(Box::new(input.parse()?))
+        };
+        entries.push(ObjectEntry { key, args, value });
+        if input.peek(Token![,]) {
+            input.parse::<Token![,]>()?;
+        }
+    }
+    Ok(entries)
+}
+
+fn can_recover_incomplete_object_entry(input: ParseStream) -> bool {
+    input.is_empty() || input.peek(Token![,])
+}
+
+fn parse_object_entries_from_tokens(
+    tokens: proc_macro2::TokenStream,
+    recover: bool,
+) -> Result<Vec<ObjectEntry>> {
+    struct ObjectEntries {
+        entries: Vec<ObjectEntry>,
+    }
+    struct ObjectEntriesRecover {
+        entries: Vec<ObjectEntry>,
+    }
+
+    impl Parse for ObjectEntries {
+        fn parse(input: ParseStream) -> Result<Self> {
+            Ok(Self {
+                entries: parse_object_entries(input, false)?,
+            })
+        }
+    }
+    impl Parse for ObjectEntriesRecover {
+        fn parse(input: ParseStream) -> Result<Self> {
+            Ok(Self {
+                entries: parse_object_entries(input, true)?,
+            })
+        }
+    }
+
+    if recover {
+        syn::parse2::<ObjectEntriesRecover>(tokens).map(|p| p.entries)
+    } else {
+        syn::parse2::<ObjectEntries>(tokens).map(|p| p.entries)
+    }
+}
+
+fn unwrap_single_brace_group(
+    tokens: &proc_macro2::TokenStream,
+) -> Option<proc_macro2::TokenStream> {
+    let mut iter = tokens.clone().into_iter();
+    let TokenTree::Group(group) = iter.next()? else {
+        return None;
+    };
+    if group.delimiter() != Delimiter::Brace || iter.next().is_some() {
+        return None;
+    }
+    Some(group.stream())
+}
+
+fn parse_child(input: ParseStream) -> Result<ParsedChild> {
+    if is_slot_start(input) {
+        return parse_slot(input);
+    }
+    if input.peek(Token![<]) && !input.peek2(Token![/]) {
+        let element: ElementNode = input.parse()?;
+        return Ok(ParsedChild {
+            source_start: element.source_start,
+            source_end: element.source_end,
+            child: Child::Element(element),
+        });
+    }
+    if input.peek(LitStr) {
+        let literal: LitStr = input.parse()?;
+        let span = literal.span();
+        return Ok(ParsedChild {
+            child: Child::TextLiteral(literal),
+            source_start: span,
+            source_end: span,
+        });
+    }
+    if input.peek(syn::token::Brace) {
+        let content;
+        let brace = braced!(content in input);
+        return Ok(ParsedChild {
+            child: parse_braced_child(&content)?,
+            source_start: brace.span.open(),
+            source_end: brace.span.close(),
+        });
+    }
+    parse_raw_text(input)
+}
+
+mod kw {
+    syn::custom_keyword!(slot);
+}
+
+/// `<slot:` but not a `<slot::path>` tag.
+fn is_slot_start(input: ParseStream) -> bool {
+    let fork = input.fork();
+    fork.parse::<Token![<]>().is_ok()
+        && fork.parse::<kw::slot>().is_ok()
+        && fork.peek(Token![:])
+        && !fork.peek(Token![::])
+}
+
+fn parse_slot(input: ParseStream) -> Result<ParsedChild> {
+    let open: Token![<] = input.parse()?;
+    input.parse::<kw::slot>()?;
+    input.parse::<Token![:]>()?;
+    let name: Ident = input.parse()?;
+    if input.peek(Token![/]) && input.peek2(Token![>]) {
+        input.parse::<Token![/]>()?;
+        let close: Token![>] = input.parse()?;
+        return Ok(ParsedChild {
+            child: Child::Slot(SlotNode {
+                name,
+                children: Vec::new(),
+            }),
+            source_start: open.span,
+            source_end: close.span,
+        });
+    }
+    if !input.peek(Token![>]) {
+        return Err(syn::Error::new(
+            input.span(),
+            format!("`<slot:{name}>` takes no props; expected `>`"),
+        ));
+    }
+    input.parse::<Token![>]>()?;
+
+    let mut parsed_children = Vec::new();
+    while !(input.is_empty() || (input.peek(Token![<]) && input.peek2(Token![/]))) {
+        parsed_children.push(parse_child(input)?);
+    }
+    if input.is_empty() {
+        return Err(syn::Error::new(
+            name.span(),
+            format!("missing closing tag for `<slot:{name}>`"),
+        ));
+    }
+    input.parse::<Token![<]>()?;
+    input.parse::<Token![/]>()?;
+    input.parse::<kw::slot>()?;
+    input.parse::<Token![:]>()?;
+    let close_name: Ident = input.parse()?;
+    if close_name != name {
+        return Err(syn::Error::new(
+            close_name.span(),
+            "closing tag does not match",
+        ));
+    }
+    let close: Token![>] = input.parse()?;
+    Ok(ParsedChild {
+        child: Child::Slot(SlotNode {
+            name,
+            children: into_html_like_children(parsed_children),
+        }),
+        source_start: open.span,
+        source_end: close.span,
+    })
+}
+
+/// Contents of a `{...}` child: `format!` shorthand, `let` bindings, a
+/// `match` with markup arms, or any other expression.
+fn parse_braced_child(input: ParseStream) -> Result<Child> {
+    if input.peek(Token![=]) && !input.peek(Token![==]) {
+        let eq: Token![=] = input.parse()?;
+        let expr: Expr = input.parse()?;
+        return Ok(Child::Format(FormatText {
+            template: LitStr::new("{}", eq.span),
+            args: vec![expr],
+        }));
+    }
+    if input.peek(LitStr) && input.peek2(Token![,]) {
+        let template: LitStr = input.parse()?;
+        input.parse::<Token![,]>()?;
+        let args = syn::punctuated::Punctuated::<Expr, Token![,]>::parse_terminated(input)?;
+        return Ok(Child::Format(FormatText {
+            template,
+            args: args.into_iter().collect(),
+        }));
+    }
+    if input.peek(Token![let]) {
+        let mut locals = Vec::new();
+        for stmt in syn::Block::parse_within(input)? {
+            let Stmt::Local(local) = stmt else {
+                return Err(syn::Error::new(
+                    stmt.span(),
+                    "a `{let ...;}` child may only contain `let` bindings",
+                ));
+            };
+            locals.push(local);
+        }
+        return Ok(Child::Let(locals));
+    }
+    if input.peek(Token![match]) {
+        let fork = input.fork();
+        match parse_match(&fork) {
+            Ok(node) if fork.is_empty() => {
+                // A match without markup arms stays a plain expression so
+                // its value keeps the usual child conversions.
+                if node
+                    .arms
+                    .iter()
+                    .any(|arm| matches!(*arm.body, Child::Element(_)))
+                {
+                    input.advance_to(&fork);
+                    return Ok(Child::Match(node));
+                }
+            }
+            // `match x { ... }` is only the start of a longer expression.
+            Ok(_) => {}
+            Err(error) => return input.parse().map(Child::Expr).map_err(|_| error),
+        }
+    }
+    input.parse().map(Child::Expr)
+}
+
+fn parse_match(input: ParseStream) -> Result<MatchNode> {
+    let match_token: Token![match] = input.parse()?;
+    let expr = Expr::parse_without_eager_brace(input)?;
+    let content;
+    braced!(content in input);
+    let mut arms = Vec::new();
+    while !content.is_empty() {
+        arms.push(parse_match_arm(&content)?);
+    }
+    Ok(MatchNode {
+        match_token,
+        expr: Box::new(expr),
+        arms,
+    })
+}
+
+fn parse_match_arm(input: ParseStream) -> Result<MatchArm> {
+    let pat = Pat::parse_multi_with_leading_vert(input)?;
+    let guard = if input.peek(Token![if]) {
+        input.parse::<Token![if]>()?;
+        Some(Box::new(input.parse()?))
+    } else {
+        None
+    };
+    input.parse::<Token![=>]>()?;
+    let (body, needs_comma) = if input.peek(Token![<]) {
+        (Child::Element(input.parse()?), false)
+    } else {
+        let expr: Expr = input.parse()?;
+        let block_like = matches!(
+            expr,
+            Expr::Block(_)
+                | Expr::If(_)
+                | Expr::Match(_)
+                | Expr::Loop(_)
+                | Expr::While(_)
+                | Expr::ForLoop(_)
+                | Expr::Unsafe(_)
+                | Expr::Const(_)
+        );
+        (Child::Expr(expr), !block_like)
+    };
+    if input.peek(Token![,]) {
+        input.parse::<Token![,]>()?;
+    } else if needs_comma && !input.is_empty() {
+        return Err(input.error("expected `,` after match arm"));
+    }
+    Ok(MatchArm {
+        pat,
+        guard,
+        body: Box::new(body),
+    })
+}
+
+fn parse_raw_text(input: ParseStream) -> Result<ParsedChild> {
+    let mut tokens = Vec::<TokenTree>::new();
+    while !input.is_empty() && !input.peek(Token![<]) && !input.peek(syn::token::Brace) {
+        tokens.push(input.parse()?);
+    }
+
+    let Some(first) = tokens.first() else {
+        return Err(syn::Error::new(input.span(), "expected RSX child"));
+    };
+    let source_start = first.span();
+    let source_end = tokens.last().expect("first token exists").span();
+    let mut reconstructed = String::new();
+    let mut previous: Option<&TokenTree> = None;
+    for token in &tokens {
+        if let Some(previous) = previous {
+            let separated = spans_have_source_gap(previous.span(), token.span())
+                .unwrap_or_else(|| fallback_needs_space(previous, token));
+            if separated
+                && !reconstructed
+                    .chars()
+                    .last()
+                    .is_some_and(char::is_whitespace)
+            {
+                reconstructed.push(' ');
+            }
+        }
+        if let Some(source_text) = token.span().source_text() {
+            reconstructed.push_str(&source_text);
+        } else {
+            reconstructed.push_str(&token_fallback_text(token));
+        }
+        previous = Some(token);
+    }
+
+    Ok(ParsedChild {
+        child: Child::TextRaw(collapse_html_whitespace(&reconstructed)),
+        source_start,
+        source_end,
+    })
+}
+
+fn token_fallback_text(token: &TokenTree) -> String {
+    token.to_string()
+}
+
+fn fallback_needs_space(previous: &TokenTree, current: &TokenTree) -> bool {
+    matches!(previous, TokenTree::Ident(_) | TokenTree::Literal(_))
+        && matches!(current, TokenTree::Ident(_) | TokenTree::Literal(_))
+}
+
+fn spans_have_source_gap(previous: Span, current: Span) -> Option<bool> {
+    let end = previous.end();
+    let start = current.start();
+    if end.line == 0 || start.line == 0 || start.line < end.line {
+        return None;
+    }
+    if start.line > end.line {
+        return Some(true);
+    }
+    (start.column >= end.column).then_some(start.column > end.column)
+}
+
+fn spans_have_same_line_source_gap(previous: Span, current: Span) -> Option<bool> {
+    let end = previous.end();
+    let start = current.start();
+    if end.line == 0 || start.line == 0 || start.line < end.line {
+        return None;
+    }
+    if start.line > end.line {
+        return Some(false);
+    }
+    (start.column >= end.column).then_some(start.column > end.column)
+}
+
+fn collapse_html_whitespace(input: &str) -> String {
+    input.split_whitespace().collect::<Vec<_>>().join(" ")
+}
+
+fn into_html_like_children(parsed: Vec<ParsedChild>) -> Vec<Child> {
+    let mut children = Vec::<Child>::with_capacity(parsed.len());
+    let mut previous_end = None;
+    for parsed_child in parsed {
+        let mut child = parsed_child.child;
+        // Bindings and slots render nothing in place, so whitespace
+        // collapses across them.
+        if matches!(child, Child::Let(_) | Child::Slot(_)) {
+            children.push(child);
+            continue;
+        }
+        if previous_end
+            .and_then(|end| spans_have_same_line_source_gap(end, parsed_child.source_start))
+            .unwrap_or(false)
+        {
+            insert_collapsed_boundary_space(&mut children, &mut child);
+        }
+        children.push(child);
+        previous_end = Some(parsed_child.source_end);
+    }
+    children
+}
+
+fn insert_collapsed_boundary_space(children: &mut Vec<Child>, next: &mut Child) {
+    if let Some(Child::TextRaw(text)) = children.last_mut() {
+        if !text.chars().last().is_some_and(char::is_whitespace) {
+            text.push(' ');
+        }
+        return;
+    }
+    if let Child::TextRaw(text) = next {
+        if !text.chars().next().is_some_and(char::is_whitespace) {
+            text.insert(0, ' ');
+        }
+        return;
+    }
+    children.push(Child::TextRaw(" ".to_string()));
+}
+
+/// Key used to match an rsx closing tag against its opening tag. Compares
+/// by path segment idents only, stripping `PathArguments` (generics,
+/// parenthesized args). Allows the React-style close form:
+///
+/// ```ignore
+/// <Provider::<Ctx> value={v}>
+///     ...
+/// </Provider>            // generics may be omitted on close
+/// ```
+///
+/// `<Foo>` still rejects `</Bar>` (idents differ), and `<mod::Foo>`
+/// rejects `</Foo>` (segment paths differ).
+fn path_key(path: &Path) -> String {
+    path.segments
+        .iter()
+        .map(|s| s.ident.to_string())
+        .collect::<Vec<_>>()
+        .join("::")
+}
+
+fn path_has_type_args(path: &Path) -> bool {
+    path.segments
+        .iter()
+        .any(|segment| !matches!(segment.arguments, syn::PathArguments::None))
+}
+
+/// Type arguments of every segment, ignoring turbofish `::` so
+/// `<List::<T>>` may close with `</List<T>>`.
+fn path_type_args_key(path: &Path) -> Vec<String> {
+    path.segments
+        .iter()
+        .map(|segment| match &segment.arguments {
+            syn::PathArguments::AngleBracketed(args) => args.args.to_token_stream().to_string(),
+            other => other.to_token_stream().to_string(),
+        })
+        .collect()
+}
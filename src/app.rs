@@ -17,13 +17,26 @@
 //!      cursor / clipboard / redraw requests to the real host window.
 
 use crate::platform::{
-    PlatformImePreedit, PlatformKeyEvent, PlatformPointerEvent, PlatformServices,
+    IconImage, PlatformImePreedit, PlatformKeyEvent, PlatformPointerEvent, PlatformServices,
     PlatformTextInput, PlatformWheelEvent,
 };
 use crate::style::Color;
 use crate::ui::RsxNode;
 use crate::view::viewport::ViewportControl;
 
+/// Deterministic GPU-free app driver for integration tests; see
+/// [`headless::HeadlessApp`].
+pub mod headless;
+/// Dev-mode hot reload of view code; see [`hot_reload::HotReloadApp`].
+pub mod hot_reload;
+/// Input capture to a file and deterministic playback; see
+/// [`recording::InputRecording`].
+pub mod recording;
+/// Golden-image regression testing; see
+/// [`assert_snapshot_image!`](crate::assert_snapshot_image).
+#[cfg(not(target_arch = "wasm32"))]
+pub mod snapshot;
+
 /// Host window theme. Pushed via [`AppEvent::ThemeChanged`] when the OS
 /// setting flips. Apps typically use this to re-pick a colour palette.
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
@@ -67,6 +80,10 @@ pub enum AppEvent {
         x: i32,
         y: i32,
     },
+    /// Monitor layout changed or the window moved to another monitor. The
+    /// host has already pushed the new list via `Viewport::set_monitors`;
+    /// read it back through `ViewportControl::monitors`.
+    MonitorsChanged,
     /// Host window minimized.
     Minimized,
     /// Host window maximized.
@@ -139,6 +156,73 @@ impl Default for WheelConfig {
     }
 }
 
+/// Host window behavior applied when the runner creates its window.
+///
+/// Every option also has a runtime counterpart on `ViewportControl`
+/// (`set_window_icon`, `set_resizable`, ...), so apps can start from these
+/// values and change them later.
+#[derive(Debug, Clone, PartialEq)]
+pub struct WindowOptions {
+    pub icon: Option<IconImage>,
+    pub resizable: bool,
+    /// Minimum inner size in logical pixels.
+    pub min_size: Option<(u32, u32)>,
+    /// Maximum inner size in logical pixels.
+    pub max_size: Option<(u32, u32)>,
+    pub always_on_top: bool,
+    /// Start in borderless fullscreen on the current monitor.
+    pub fullscreen: bool,
+}
+
+impl Default for WindowOptions {
+    fn default() -> Self {
+        Self {
+            icon: None,
+            resizable: true,
+            min_size: None,
+            max_size: None,
+            always_on_top: false,
+            fullscreen: false,
+        }
+    }
+}
+
+impl WindowOptions {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn icon(mut self, icon: IconImage) -> Self {
+        self.icon = Some(icon);
+        self
+    }
+
+    pub fn resizable(mut self, resizable: bool) -> Self {
+        self.resizable = resizable;
+        self
+    }
+
+    pub fn min_size(mut self, width: u32, height: u32) -> Self {
+        self.min_size = Some((width, height));
+        self
+    }
+
+    pub fn max_size(mut self, width: u32, height: u32) -> Self {
+        self.max_size = Some((width, height));
+        self
+    }
+
+    pub fn always_on_top(mut self, always_on_top: bool) -> Self {
+        self.always_on_top = always_on_top;
+        self
+    }
+
+    pub fn fullscreen(mut self, fullscreen: bool) -> Self {
+        self.fullscreen = fullscreen;
+        self
+    }
+}
+
 #[derive(Debug, Clone)]
 pub struct AppConfig {
     pub title: String,
@@ -149,6 +233,12 @@ pub struct AppConfig {
     /// also drop the native drop-shadow so the transparent region stays
     /// visually clean. No-op on platforms without transparency support.
     pub transparent: bool,
+    /// Show the native title bar and borders. Frameless apps set this to
+    /// `false` and draw their own chrome, marking title-bar areas with
+    /// `app_region: AppRegion::Drag`.
+    pub decorations: bool,
+    /// Icon, resize limits, stacking and fullscreen for the host window.
+    pub window: WindowOptions,
     /// Initial viewport clear color. `None` means the runner leaves the
     /// viewport's built-in default (opaque black) untouched. Typical
     /// transparent apps set `Color::transparent()` here.
@@ -165,6 +255,8 @@ impl Default for AppConfig {
             initial_size: (1280, 800),
             scale_factor: None,
             transparent: false,
+            decorations: true,
+            window: WindowOptions::default(),
             clear_color: None,
             wheel: WheelConfig::default(),
         }
@@ -243,6 +335,10 @@ mod tests {
         assert_eq!(cfg.initial_size, (1280, 800));
         assert_eq!(cfg.scale_factor, None);
         assert!(!cfg.transparent);
+        assert!(cfg.decorations);
+        assert!(cfg.window.resizable);
+        assert_eq!(cfg.window.min_size, None);
+        assert!(!cfg.window.always_on_top);
         assert!(cfg.clear_color.is_none());
         assert_eq!(cfg.wheel.mouse_line_step, 28.0);
         assert_eq!(cfg.wheel.touchpad_pixel_scale, 1.0);
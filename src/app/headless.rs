@@ -0,0 +1,351 @@
+//! Deterministic, GPU-free driver for integration tests.
+//!
+//! [`HeadlessApp`] owns a surface-less [`Viewport`] and a virtual clock.
+//! Each [`HeadlessApp::tick`] advances the clock by exactly `dt`, fires due
+//! timers, rebuilds the app when state changed, reconciles, lays out and
+//! steps transitions — everything a rendered frame does short of painting.
+//! Input goes through the same `App::on_event` + `Viewport::dispatch_*`
+//! sequence the winit runner uses, so tests exercise whole interaction
+//! flows without a window or a GPU.
+//!
+//! Timer hooks and transitions read the virtual clock while a
+//! `HeadlessApp` is alive; drive one app per thread.
+
+use super::recording::{InputRecording, InputReplayer};
+use super::{App, AppEvent};
+use crate::platform::{
+    Clipboard, HeadlessBackend, Key, Modifiers, NotificationCommand, PlatformImePreedit,
+    PlatformInputType, PlatformKeyEvent, PlatformPointerButton, PlatformPointerEvent,
+    PlatformPointerEventKind, PlatformServices, PlatformTextInput, PlatformWheelEvent, PointerType,
+    SoundCommand, WindowCommand,
+};
+use crate::style::Cursor;
+use crate::time::{Duration, Instant};
+use crate::ui::{run_due_timers, set_timer_clock};
+use crate::view::node_arena::NodeKey;
+use crate::view::query::{ElementHandle, ElementQuery};
+use crate::view::viewport::Viewport;
+
+/// Upper bound on frames [`HeadlessApp::settle`] runs before giving up on
+/// an animation that never finishes.
+const SETTLE_FRAME_LIMIT: usize = 10_000;
+
+pub struct HeadlessApp {
+    viewport: Viewport,
+    backend: HeadlessBackend,
+    now: Instant,
+    redraw_pending: bool,
+    cursor: Cursor,
+    window_commands: Vec<WindowCommand>,
+    notification_commands: Vec<NotificationCommand>,
+    sound_commands: Vec<SoundCommand>,
+}
+
+impl HeadlessApp {
+    /// Mount `app` in a `width` x `height` logical-pixel viewport and run
+    /// the first frame.
+    pub fn new(app: impl App + 'static, width: u32, height: u32) -> Self {
+        let now = Instant::now();
+        let mut viewport = Viewport::new();
+        viewport.set_headless_layout(true);
+        viewport.set_frame_clock(Some(now));
+        viewport.set_size(width, height);
+        viewport.set_app(Box::new(app));
+        let mut headless = Self {
+            viewport,
+            backend: HeadlessBackend::default(),
+            now,
+            redraw_pending: false,
+            cursor: Cursor::Default,
+            window_commands: Vec::new(),
+            notification_commands: Vec::new(),
+            sound_commands: Vec::new(),
+        };
+        set_timer_clock(Some(now));
+        let (viewport, services) = headless.parts();
+        viewport.app_on_ready(services);
+        headless.tick(Duration::ZERO);
+        headless
+    }
+
+    pub fn viewport(&self) -> &Viewport {
+        &self.viewport
+    }
+
+    pub fn viewport_mut(&mut self) -> &mut Viewport {
+        &mut self.viewport
+    }
+
+    /// Current virtual time.
+    pub fn now(&self) -> Instant {
+        self.now
+    }
+
+    /// Last cursor the viewport asked for.
+    pub fn cursor(&self) -> Cursor {
+        self.cursor
+    }
+
+    /// Contents of the fake system clipboard.
+    pub fn clipboard_text(&mut self) -> Option<String> {
+        self.backend.clipboard.get()
+    }
+
+    pub fn set_clipboard_text(&mut self, text: &str) {
+        self.backend.clipboard.set(text);
+    }
+
+    /// Window commands (title, size, close, ...) the app issued since the
+    /// previous call.
+    pub fn take_window_commands(&mut self) -> Vec<WindowCommand> {
+        std::mem::take(&mut self.window_commands)
+    }
+
+    /// Notifications and badge changes the app requested since the
+    /// previous call. Answer them with
+    /// [`Viewport::dispatch_notification_event`].
+    pub fn take_notification_commands(&mut self) -> Vec<NotificationCommand> {
+        std::mem::take(&mut self.notification_commands)
+    }
+
+    /// Sound registrations and plays the app requested since the previous
+    /// call.
+    pub fn take_sound_commands(&mut self) -> Vec<SoundCommand> {
+        std::mem::take(&mut self.sound_commands)
+    }
+
+    /// True when another frame would change something: a redraw request,
+    /// dirty state, a running transition or a due timer.
+    pub fn needs_frame(&self) -> bool {
+        self.redraw_pending
+            || self.viewport.needs_frame()
+            || crate::ui::next_timer_deadline().is_some_and(|deadline| deadline <= self.now)
+    }
+
+    /// Advance the virtual clock by `dt` and run one frame.
+    pub fn tick(&mut self, dt: Duration) {
+        self.now += dt;
+        self.viewport.set_frame_clock(Some(self.now));
+        set_timer_clock(Some(self.now));
+        run_due_timers(self.now);
+        self.redraw_pending = false;
+        let (viewport, services) = self.parts();
+        let _ = viewport.render_frame(services);
+        self.apply_platform_requests();
+    }
+
+    /// Tick by `dt` until nothing is left to do; returns the number of
+    /// frames run. Panics if the app keeps invalidating itself, which
+    /// usually means an infinite animation.
+    pub fn settle(&mut self, dt: Duration) -> usize {
+        let mut frames = 0;
+        while self.needs_frame() {
+            assert!(
+                frames < SETTLE_FRAME_LIMIT,
+                "HeadlessApp::settle: still invalidated after {SETTLE_FRAME_LIMIT} frames"
+            );
+            self.tick(dt);
+            frames += 1;
+        }
+        frames
+    }
+
+    /// Deliver `event` the way a host runner does: to `App::on_event`
+    /// first, then to the viewport's element tree.
+    pub fn send(&mut self, event: AppEvent) {
+        set_timer_clock(Some(self.now));
+        let (viewport, services) = self.parts();
+        viewport.dispatch_app_event(&event, services);
+        match &event {
+            AppEvent::Pointer(pointer) => {
+                match pointer.kind {
+                    PlatformPointerEventKind::Down(button) => {
+                        self.viewport.set_pointer_button_pressed(button, true);
+                    }
+                    PlatformPointerEventKind::Up(button) => {
+                        self.viewport.set_pointer_button_pressed(button, false);
+                    }
+                    _ => {}
+                }
+                let _ = self.viewport.dispatch_platform_pointer_event(pointer);
+            }
+            AppEvent::Wheel(wheel) => {
+                self.viewport
+                    .set_pointer_position_viewport(wheel.position.0, wheel.position.1);
+                let _ = self.viewport.dispatch_platform_wheel_event(wheel);
+            }
+            AppEvent::Key(key) => {
+                let _ = self.viewport.dispatch_platform_key_event(key);
+            }
+            AppEvent::TextInput(text) => {
+                let _ = self.viewport.dispatch_platform_text_input(text);
+            }
+            AppEvent::ImePreedit(preedit) => {
+                let _ = self.viewport.dispatch_platform_ime_preedit(preedit);
+            }
+            AppEvent::Resized { width, height, .. } => {
+                self.viewport.set_size(*width, *height);
+            }
+            AppEvent::ScaleFactorChanged { scale, .. } => {
+                self.viewport.set_scale_factor(*scale);
+            }
+            _ => {}
+        }
+        self.apply_platform_requests();
+    }
+
+    /// Play `recording` back from the current virtual time. The clock
+    /// advances in frames of at most `frame` and lands exactly on each
+    /// event's offset before the event is sent, so timers, transitions and
+    /// input interleave the same way on every run. A zero `frame` jumps
+    /// straight from event to event. Returns once the last event has been
+    /// delivered; [`Self::settle`] lets its effects finish.
+    pub fn replay(&mut self, recording: &InputRecording, frame: Duration) {
+        let mut replayer = InputReplayer::new(recording.clone(), self.now);
+        while let Some(at) = replayer.next_at() {
+            while self.now < at {
+                let remaining = at.duration_since(self.now);
+                let step = if frame.is_zero() {
+                    remaining
+                } else {
+                    remaining.min(frame)
+                };
+                self.tick(step);
+            }
+            for event in replayer.take_due(self.now) {
+                self.send(event);
+            }
+        }
+    }
+
+    pub fn pointer_move(&mut self, x: f32, y: f32) {
+        self.send(pointer_event(PlatformPointerEventKind::Move { x, y }));
+    }
+
+    /// Move to (`x`, `y`) and press, release and click the left button.
+    pub fn click(&mut self, x: f32, y: f32) {
+        let button = PlatformPointerButton::Left;
+        self.pointer_move(x, y);
+        self.send(pointer_event(PlatformPointerEventKind::Down(button)));
+        self.send(pointer_event(PlatformPointerEventKind::Up(button)));
+        self.send(pointer_event(PlatformPointerEventKind::Click(button)));
+    }
+
+    /// Wheel at (`x`, `y`) by pixel deltas.
+    pub fn scroll(&mut self, x: f32, y: f32, delta_x: f32, delta_y: f32) {
+        self.send(AppEvent::Wheel(PlatformWheelEvent {
+            delta_x,
+            delta_y,
+            position: (x, y),
+            modifiers: Modifiers::empty(),
+            delta_mode: Default::default(),
+            phase: Default::default(),
+            timestamp: self.now,
+        }));
+    }
+
+    /// Press and release `key` with `modifiers` held.
+    pub fn key_press(&mut self, key: Key, modifiers: Modifiers) {
+        for pressed in [true, false] {
+            self.send(AppEvent::Key(PlatformKeyEvent {
+                key,
+                characters: None,
+                modifiers,
+                repeat: false,
+                is_composing: false,
+                pressed,
+                timestamp: self.now,
+            }));
+        }
+    }
+
+    /// Commit `text` to the focused element as if typed.
+    pub fn type_text(&mut self, text: &str) {
+        self.send(AppEvent::TextInput(PlatformTextInput {
+            text: text.to_string(),
+            input_type: PlatformInputType::Typing,
+            is_composing: false,
+        }));
+    }
+
+    /// Show `preedit` as the active IME composition.
+    pub fn ime_preedit(&mut self, preedit: PlatformImePreedit) {
+        self.send(AppEvent::ImePreedit(preedit));
+    }
+
+    /// Topmost element under (`x`, `y`) as of the last frame.
+    pub fn node_at(&self, x: f32, y: f32) -> Option<NodeKey> {
+        self.viewport.node_at(x, y)
+    }
+
+    /// Every node matching `query` as of the last frame.
+    pub fn query(&self, query: &ElementQuery) -> Vec<ElementHandle> {
+        self.viewport.query(query)
+    }
+
+    /// First node matching `query`; panics when nothing matches.
+    pub fn get(&self, query: &ElementQuery) -> ElementHandle {
+        self.viewport
+            .query_one(query)
+            .unwrap_or_else(|| panic!("HeadlessApp::get: no element matches {query:?}"))
+    }
+
+    /// Click the centre of the first node matching `query`.
+    pub fn click_on(&mut self, query: &ElementQuery) {
+        let rect = self.get(query).rect();
+        self.click(rect.x + rect.width * 0.5, rect.y + rect.height * 0.5);
+    }
+
+    fn parts(&mut self) -> (&mut Viewport, PlatformServices<'_>) {
+        (
+            &mut self.viewport,
+            PlatformServices {
+                clipboard: &mut self.backend.clipboard,
+                cursor: &mut self.backend.cursor,
+                redraw: &self.backend.redraw,
+            },
+        )
+    }
+
+    fn apply_platform_requests(&mut self) {
+        let requests = self.viewport.drain_platform_requests();
+        self.redraw_pending |= requests.request_redraw;
+        if let Some(cursor) = requests.cursor {
+            self.cursor = cursor;
+        }
+        self.window_commands.extend(requests.window_commands);
+        self.notification_commands
+            .extend(requests.notification_commands);
+        self.sound_commands.extend(requests.sound_commands);
+        if let Some(text) = requests.clipboard_write {
+            self.backend.clipboard.set(&text);
+        }
+        if requests.request_paste
+            && let Some(text) = self.backend.clipboard.get()
+            && !text.is_empty()
+        {
+            let _ = self.viewport.dispatch_paste_event(text);
+        }
+    }
+}
+
+impl Drop for HeadlessApp {
+    fn drop(&mut self) {
+        set_timer_clock(None);
+    }
+}
+
+fn pointer_event(kind: PlatformPointerEventKind) -> AppEvent {
+    AppEvent::Pointer(PlatformPointerEvent {
+        kind,
+        pointer_id: 0,
+        pointer_type: PointerType::Mouse,
+        pressure: match kind {
+            PlatformPointerEventKind::Down(_) => 0.5,
+            _ => 0.0,
+        },
+    })
+}
+
+#[cfg(test)]
+mod tests;
@@ -0,0 +1,103 @@
+use std::cell::Cell;
+use std::rc::Rc;
+
+use super::*;
+use crate::app::AppContext;
+use crate::style::Length;
+use crate::ui::{RsxNode, build_scope, global_state, on_click, render_component, rsx, use_timeout};
+use crate::view::Element as HostElement;
+
+#[derive(Clone, PartialEq)]
+struct Clicks(u32);
+
+struct CounterApp {
+    builds: Rc<Cell<u32>>,
+}
+
+impl App for CounterApp {
+    fn build(&mut self, _ctx: &mut AppContext<'_>) -> RsxNode {
+        self.builds.set(self.builds.get() + 1);
+        let clicks = global_state(|| Clicks(0));
+        let width = 100.0 + clicks.get().0 as f32 * 10.0;
+        rsx! {
+            <HostElement
+                style={{ width: Length::px(width), height: Length::px(40.0) }}
+                on_click={on_click(move |_| clicks.update(|clicks| clicks.0 += 1))}
+            />
+        }
+    }
+}
+
+#[test]
+fn clicks_reach_element_handlers_and_rebuild_the_app() {
+    let builds = Rc::new(Cell::new(0));
+    let mut app = HeadlessApp::new(
+        CounterApp {
+            builds: builds.clone(),
+        },
+        300,
+        200,
+    );
+    assert_eq!(builds.get(), 1);
+    assert!(app.node_at(10.0, 10.0).is_some());
+    assert!(app.node_at(105.0, 10.0).is_none());
+
+    app.click(10.0, 10.0);
+    app.settle(Duration::from_millis(16));
+
+    assert_eq!(global_state(|| Clicks(0)).get().0, 1);
+    assert_eq!(builds.get(), 2);
+    // The rebuilt tree was laid out: the element grew under the probe.
+    assert!(app.node_at(105.0, 10.0).is_some());
+}
+
+struct TimerApp {
+    fired: Rc<Cell<u32>>,
+}
+
+impl App for TimerApp {
+    fn build(&mut self, _ctx: &mut AppContext<'_>) -> RsxNode {
+        let fired = self.fired.clone();
+        build_scope(|| {
+            render_component::<TimerApp, _>(|| {
+                use_timeout(true, Duration::from_secs(1), move || {
+                    fired.set(fired.get() + 1);
+                });
+                RsxNode::text("waiting")
+            })
+        })
+    }
+}
+
+#[test]
+fn timers_follow_the_virtual_clock() {
+    let fired = Rc::new(Cell::new(0));
+    let mut app = HeadlessApp::new(
+        TimerApp {
+            fired: fired.clone(),
+        },
+        100,
+        100,
+    );
+    let start = app.now();
+
+    app.tick(Duration::from_millis(999));
+    assert_eq!(fired.get(), 0);
+    app.tick(Duration::from_millis(1));
+    assert_eq!(fired.get(), 1);
+    assert_eq!(app.now() - start, Duration::from_secs(1));
+}
+
+#[test]
+fn settled_app_needs_no_frame() {
+    let mut app = HeadlessApp::new(
+        CounterApp {
+            builds: Rc::new(Cell::new(0)),
+        },
+        300,
+        200,
+    );
+    app.settle(Duration::from_millis(16));
+    assert!(!app.needs_frame());
+    assert_eq!(app.settle(Duration::from_millis(16)), 0);
+}
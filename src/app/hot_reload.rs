@@ -0,0 +1,362 @@
+//! Dev-mode hot reload of view code.
+//!
+//! [`HotReloadApp`] wraps an [`App`] and replaces its `build` output with a
+//! view function supplied by a [`ViewSource`]: a dynamically recompiled
+//! dylib ([`DylibViewSource`], behind the `hot-reload` feature) or anything
+//! else that can produce a [`HotView`] — an interpreter for a serialized
+//! node format, a test harness publishing through a [`ViewSlot`].
+//!
+//! A swapped-in view goes through the regular rebuild path: the viewport
+//! reconciles the new tree against the retained one, so `global_state`,
+//! component hook state and element state (scroll offsets, focus, caret)
+//! survive wherever node identity still matches. A view that fails to load
+//! or panics while building is reported through
+//! [`HotReloadApp::last_error`] and the previous view keeps rendering.
+//!
+//! Panics are caught at the `build` boundary only; hook bookkeeping of a
+//! component that panicked mid-render is best effort. This is a development
+//! tool, not a sandbox.
+
+use std::cell::RefCell;
+use std::fmt;
+use std::panic::{AssertUnwindSafe, catch_unwind};
+use std::rc::Rc;
+
+use super::{App, AppContext, AppEvent};
+use crate::time::Duration;
+use crate::ui::RsxNode;
+
+/// Reloadable replacement for [`App::build`].
+pub type HotView = Rc<dyn Fn(&mut AppContext<'_>) -> RsxNode>;
+
+/// Symbol [`DylibViewSource`] resolves by default; [`crate::hot_view!`]
+/// exports it.
+pub const HOT_VIEW_SYMBOL: &str = "rfgui_hot_view";
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum HotReloadError {
+    /// The source could not produce a view (missing file, bad symbol, ...).
+    Load(String),
+    /// The new view panicked while building its tree.
+    Build(String),
+}
+
+impl fmt::Display for HotReloadError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Load(message) => write!(f, "failed to load view: {message}"),
+            Self::Build(message) => write!(f, "view panicked while building: {message}"),
+        }
+    }
+}
+
+impl std::error::Error for HotReloadError {}
+
+/// Producer of reloaded views. Polled once per app build.
+pub trait ViewSource {
+    /// Cheap check run every poll interval between builds: `true` when
+    /// [`poll`](Self::poll) has something new. The app only rebuilds when
+    /// it returns `true`.
+    fn changed(&mut self) -> bool;
+
+    /// `Some` when the source changed since the previous poll.
+    fn poll(&mut self) -> Option<Result<HotView, HotReloadError>>;
+}
+
+/// In-process [`ViewSource`]: whoever holds a clone publishes views and the
+/// next build picks up the latest one.
+#[derive(Clone, Default)]
+pub struct ViewSlot {
+    pending: Rc<RefCell<Option<HotView>>>,
+}
+
+impl ViewSlot {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn publish<F>(&self, view: F)
+    where
+        F: Fn(&mut AppContext<'_>) -> RsxNode + 'static,
+    {
+        *self.pending.borrow_mut() = Some(Rc::new(view));
+    }
+}
+
+impl ViewSource for ViewSlot {
+    fn changed(&mut self) -> bool {
+        self.pending.borrow().is_some()
+    }
+
+    fn poll(&mut self) -> Option<Result<HotView, HotReloadError>> {
+        self.pending.borrow_mut().take().map(Ok)
+    }
+}
+
+/// [`App`] wrapper that renders the latest view from a [`ViewSource`],
+/// falling back to the wrapped app's own `build` until one loads.
+pub struct HotReloadApp<A> {
+    inner: A,
+    source: Rc<RefCell<dyn ViewSource>>,
+    view: Option<HotView>,
+    poll_interval: Duration,
+    last_error: Option<HotReloadError>,
+    reload_count: u64,
+}
+
+impl<A: App> HotReloadApp<A> {
+    pub fn new(inner: A, source: impl ViewSource + 'static) -> Self {
+        Self {
+            inner,
+            source: Rc::new(RefCell::new(source)),
+            view: None,
+            poll_interval: Duration::from_millis(500),
+            last_error: None,
+            reload_count: 0,
+        }
+    }
+
+    /// How often the source is checked for changes.
+    pub fn poll_interval(mut self, interval: Duration) -> Self {
+        self.poll_interval = interval;
+        self
+    }
+
+    pub fn inner(&self) -> &A {
+        &self.inner
+    }
+
+    pub fn inner_mut(&mut self) -> &mut A {
+        &mut self.inner
+    }
+
+    /// Most recent load or build failure; cleared by the next successful
+    /// reload.
+    pub fn last_error(&self) -> Option<&HotReloadError> {
+        self.last_error.as_ref()
+    }
+
+    /// Number of views swapped in so far.
+    pub fn reload_count(&self) -> u64 {
+        self.reload_count
+    }
+
+    fn report(&mut self, error: HotReloadError) {
+        eprintln!("[hot-reload] {error}");
+        self.last_error = Some(error);
+    }
+
+    fn poll_source(&mut self) -> Option<HotView> {
+        let polled = self.source.borrow_mut().poll()?;
+        match polled {
+            Ok(view) => Some(view),
+            Err(error) => {
+                self.report(error);
+                None
+            }
+        }
+    }
+}
+
+fn build_guarded(view: &HotView, ctx: &mut AppContext<'_>) -> Result<RsxNode, HotReloadError> {
+    catch_unwind(AssertUnwindSafe(|| view(ctx))).map_err(|payload| {
+        let message = payload
+            .downcast_ref::<&str>()
+            .map(|message| (*message).to_string())
+            .or_else(|| payload.downcast_ref::<String>().cloned())
+            .unwrap_or_else(|| "non-string panic payload".to_string());
+        HotReloadError::Build(message)
+    })
+}
+
+impl<A: App> App for HotReloadApp<A> {
+    fn build(&mut self, ctx: &mut AppContext<'_>) -> RsxNode {
+        let source = self.source.clone();
+        ctx.viewport
+            .poll_for_rebuild(self.poll_interval, move || source.borrow_mut().changed());
+        if let Some(candidate) = self.poll_source() {
+            match build_guarded(&candidate, ctx) {
+                Ok(node) => {
+                    self.view = Some(candidate);
+                    self.reload_count += 1;
+                    self.last_error = None;
+                    return node;
+                }
+                Err(error) => self.report(error),
+            }
+        }
+        if let Some(view) = self.view.clone() {
+            match build_guarded(&view, ctx) {
+                Ok(node) => return node,
+                Err(error) => {
+                    // The last good view broke at runtime; hand control back
+                    // to the compiled-in view until the source changes.
+                    self.report(error);
+                    self.view = None;
+                }
+            }
+        }
+        self.inner.build(ctx)
+    }
+
+    fn on_event(&mut self, event: &AppEvent, ctx: &mut AppContext<'_>) {
+        self.inner.on_event(event, ctx);
+    }
+
+    fn on_ready(&mut self, ctx: &mut AppContext<'_>) {
+        self.inner.on_ready(ctx);
+    }
+
+    fn on_shutdown(&mut self, ctx: &mut AppContext<'_>) {
+        self.inner.on_shutdown(ctx);
+    }
+}
+
+/// Export `view` from a hot-reloadable dylib under [`HOT_VIEW_SYMBOL`].
+///
+/// The dylib must share the host's copy of rfgui (build both against the
+/// same `rfgui` dylib, e.g. with `-C prefer-dynamic`); a statically linked
+/// copy would carry its own `global_state` store.
+#[macro_export]
+macro_rules! hot_view {
+    ($view:path) => {
+        #[unsafe(no_mangle)]
+        pub fn rfgui_hot_view(ctx: &mut $crate::app::AppContext<'_>) -> $crate::ui::RsxNode {
+            $view(ctx)
+        }
+    };
+}
+
+#[cfg(all(feature = "hot-reload", not(target_arch = "wasm32")))]
+pub use self::dylib::DylibViewSource;
+
+#[cfg(all(feature = "hot-reload", not(target_arch = "wasm32")))]
+mod dylib {
+    use std::path::{Path, PathBuf};
+    #[cfg(not(target_arch = "wasm32"))]
+    use std::time::SystemTime;
+
+    use super::{HOT_VIEW_SYMBOL, HotReloadError, HotView, ViewSource};
+    use crate::app::AppContext;
+    use crate::ui::RsxNode;
+
+    type HotViewFn = fn(&mut AppContext<'_>) -> RsxNode;
+
+    /// Watches a dylib on disk and loads its exported view whenever the
+    /// file's modification time changes.
+    ///
+    /// Each generation is copied to a unique temp path before loading so
+    /// the build can overwrite the original and the OS loader never hands
+    /// back a cached image. The previous copy is deleted once the next one
+    /// loads, and the last one when the source is dropped; platforms that
+    /// lock loaded libraries keep theirs until exit. Loaded libraries are
+    /// never unloaded: retained closures may still point into older
+    /// generations.
+    pub struct DylibViewSource {
+        path: PathBuf,
+        symbol: String,
+        #[cfg(not(target_arch = "wasm32"))]
+        last_modified: Option<SystemTime>,
+        generation: u64,
+        shadow: Option<PathBuf>,
+        libraries: Vec<libloading::Library>,
+    }
+
+    impl DylibViewSource {
+        pub fn new(path: impl Into<PathBuf>) -> Self {
+            Self {
+                path: path.into(),
+                symbol: HOT_VIEW_SYMBOL.to_string(),
+                last_modified: None,
+                generation: 0,
+                shadow: None,
+                libraries: Vec::new(),
+            }
+        }
+
+        /// Resolve `symbol` instead of [`HOT_VIEW_SYMBOL`].
+        pub fn symbol(mut self, symbol: impl Into<String>) -> Self {
+            self.symbol = symbol.into();
+            self
+        }
+
+        /// `None` while the file is missing, which usually means the build
+        /// is mid-write.
+        #[cfg(not(target_arch = "wasm32"))]
+        fn modified(&self) -> Option<SystemTime> {
+            std::fs::metadata(&self.path)
+                .and_then(|metadata| metadata.modified())
+                .ok()
+        }
+
+        fn load(&mut self) -> Result<HotView, HotReloadError> {
+            let path = self.path.display().to_string();
+            let load_error =
+                |error: &dyn std::fmt::Display| HotReloadError::Load(format!("{path}: {error}"));
+            self.generation += 1;
+            let extension = self
+                .path
+                .extension()
+                .map(|extension| format!(".{}", extension.to_string_lossy()))
+                .unwrap_or_default();
+            let shadow = std::env::temp_dir().join(format!(
+                "rfgui-hot-{}-{}{extension}",
+                std::process::id(),
+                self.generation
+            ));
+            std::fs::copy(&self.path, &shadow).map_err(|error| load_error(&error))?;
+            let (library, view) = match open(&shadow, &self.symbol) {
+                Ok(loaded) => loaded,
+                Err(error) => {
+                    let _ = std::fs::remove_file(&shadow);
+                    return Err(load_error(&error));
+                }
+            };
+            self.libraries.push(library);
+            if let Some(previous) = self.shadow.replace(shadow) {
+                let _ = std::fs::remove_file(previous);
+            }
+            Ok(std::rc::Rc::new(move |ctx: &mut AppContext<'_>| view(ctx)))
+        }
+    }
+
+    fn open(
+        path: &Path,
+        symbol: &str,
+    ) -> Result<(libloading::Library, HotViewFn), libloading::Error> {
+        // SAFETY: hot reload is a dev-mode opt-in; the dylib is expected to
+        // be built by the same toolchain against the same rfgui, which
+        // `hot_view!` relies on for the Rust-ABI function signature.
+        let library = unsafe { libloading::Library::new(path) }?;
+        // SAFETY: see above; the symbol is produced by `hot_view!`.
+        let view = unsafe { library.get::<HotViewFn>(symbol.as_bytes()) }.map(|symbol| *symbol)?;
+        Ok((library, view))
+    }
+
+    impl Drop for DylibViewSource {
+        fn drop(&mut self) {
+            if let Some(shadow) = self.shadow.take() {
+                let _ = std::fs::remove_file(shadow);
+            }
+        }
+    }
+
+    impl ViewSource for DylibViewSource {
+        fn changed(&mut self) -> bool {
+            self.modified()
+                .is_some_and(|modified| self.last_modified != Some(modified))
+        }
+
+        fn poll(&mut self) -> Option<Result<HotView, HotReloadError>> {
+            let modified = self.modified()?;
+            if self.last_modified == Some(modified) {
+                return None;
+            }
+            self.last_modified = Some(modified);
+            Some(self.load())
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests;
@@ -0,0 +1,139 @@
+use std::cell::Cell;
+use std::rc::Rc;
+
+use super::*;
+use crate::platform::{
+    CallbackCursorSink, CallbackRedrawRequester, NullClipboard, PlatformServices,
+};
+use crate::time::Instant;
+use crate::view::viewport::{FrameSchedule, Viewport, ViewportControl};
+
+struct CompiledApp;
+
+impl App for CompiledApp {
+    fn build(&mut self, _ctx: &mut AppContext<'_>) -> RsxNode {
+        RsxNode::text("compiled")
+    }
+}
+
+fn build_once(app: &mut impl App, viewport: &mut Viewport) -> RsxNode {
+    let mut clipboard = NullClipboard::default();
+    let mut cursor = CallbackCursorSink::new(|_| {});
+    let redraw = CallbackRedrawRequester::new(|| {});
+    let mut ctx = AppContext {
+        viewport: ViewportControl::new(viewport),
+        services: PlatformServices {
+            clipboard: &mut clipboard,
+            cursor: &mut cursor,
+            redraw: &redraw,
+        },
+    };
+    app.build(&mut ctx)
+}
+
+#[test]
+fn published_views_replace_the_compiled_build() {
+    let slot = ViewSlot::new();
+    let mut app = HotReloadApp::new(CompiledApp, slot.clone());
+    let mut viewport = Viewport::new();
+
+    assert_eq!(
+        build_once(&mut app, &mut viewport),
+        RsxNode::text("compiled")
+    );
+
+    let builds = Rc::new(Cell::new(0));
+    let counter = builds.clone();
+    slot.publish(move |_| {
+        counter.set(counter.get() + 1);
+        RsxNode::text("reloaded")
+    });
+    assert_eq!(
+        build_once(&mut app, &mut viewport),
+        RsxNode::text("reloaded")
+    );
+    // The swapped-in view stays active without republishing.
+    assert_eq!(
+        build_once(&mut app, &mut viewport),
+        RsxNode::text("reloaded")
+    );
+    assert_eq!(builds.get(), 2);
+    assert_eq!(app.reload_count(), 1);
+    assert_eq!(app.last_error(), None);
+}
+
+#[test]
+fn panicking_reload_keeps_the_previous_view() {
+    let slot = ViewSlot::new();
+    let mut app = HotReloadApp::new(CompiledApp, slot.clone());
+    let mut viewport = Viewport::new();
+    slot.publish(|_| RsxNode::text("good"));
+    assert_eq!(build_once(&mut app, &mut viewport), RsxNode::text("good"));
+
+    slot.publish(|_| panic!("broken view"));
+    assert_eq!(build_once(&mut app, &mut viewport), RsxNode::text("good"));
+    assert_eq!(
+        app.last_error(),
+        Some(&HotReloadError::Build("broken view".to_string()))
+    );
+    assert_eq!(app.reload_count(), 1);
+}
+
+#[test]
+fn failed_loads_are_reported_and_ignored() {
+    struct FailingSource;
+    impl ViewSource for FailingSource {
+        fn changed(&mut self) -> bool {
+            true
+        }
+
+        fn poll(&mut self) -> Option<Result<HotView, HotReloadError>> {
+            Some(Err(HotReloadError::Load("missing".to_string())))
+        }
+    }
+
+    let mut app = HotReloadApp::new(CompiledApp, FailingSource);
+    let mut viewport = Viewport::new();
+    assert_eq!(
+        build_once(&mut app, &mut viewport),
+        RsxNode::text("compiled")
+    );
+    assert_eq!(
+        app.last_error(),
+        Some(&HotReloadError::Load("missing".to_string()))
+    );
+}
+
+#[test]
+fn builds_schedule_the_next_poll() {
+    let mut app =
+        HotReloadApp::new(CompiledApp, ViewSlot::new()).poll_interval(Duration::from_millis(250));
+    let mut viewport = Viewport::new();
+    let _ = crate::ui::take_state_dirty();
+    let before = Instant::now();
+    let _ = build_once(&mut app, &mut viewport);
+
+    match viewport.frame_schedule(before, false) {
+        FrameSchedule::At(at) => assert!(at >= before + Duration::from_millis(250)),
+        other => panic!("expected a scheduled poll, got {other:?}"),
+    }
+}
+
+#[test]
+fn poll_ticks_rebuild_only_after_the_source_changes() {
+    let slot = ViewSlot::new();
+    let mut app =
+        HotReloadApp::new(CompiledApp, slot.clone()).poll_interval(Duration::from_millis(250));
+    let mut viewport = Viewport::new();
+    let start = Instant::now();
+    viewport.set_frame_clock(Some(start));
+    let _ = build_once(&mut app, &mut viewport);
+
+    assert!(!viewport.run_rebuild_poll(start + Duration::from_millis(100)));
+    assert!(!viewport.run_rebuild_poll(start + Duration::from_millis(300)));
+
+    slot.publish(|_| RsxNode::text("reloaded"));
+    // Not due yet: the previous tick pushed the next check out.
+    assert!(!viewport.run_rebuild_poll(start + Duration::from_millis(400)));
+    assert!(viewport.run_rebuild_poll(start + Duration::from_millis(600)));
+}
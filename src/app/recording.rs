@@ -0,0 +1,830 @@
+//! Input capture and deterministic playback.
+//!
+//! [`Viewport::start_input_recording`] makes the viewport log every input
+//! [`AppEvent`] that reaches [`Viewport::dispatch_app_event`] — pointer,
+//! wheel, key, text, IME preedit, resize and scale changes — stamped with
+//! its offset from the start of the recording on
+//! [`Viewport::frame_now`]. [`InputRecording::save`] writes the log as
+//! plain text, one event per line, so a bug report can carry it and a demo
+//! script can be written by hand. [`HeadlessApp::replay`] feeds a recording
+//! back on the virtual clock, which makes playback identical from run to
+//! run; live hosts drive an [`InputReplayer`] from their own clock instead.
+//!
+//! Host-window events (focus, theme, moves, file drops, ...) are not
+//! recorded: they describe the window rather than the user's input.
+//!
+//! [`Viewport::start_input_recording`]: crate::view::viewport::Viewport::start_input_recording
+//! [`Viewport::dispatch_app_event`]: crate::view::viewport::Viewport::dispatch_app_event
+//! [`Viewport::frame_now`]: crate::view::viewport::Viewport::frame_now
+//! [`HeadlessApp::replay`]: crate::app::headless::HeadlessApp::replay
+
+use std::fmt::Write as _;
+use std::iter::Peekable;
+use std::path::Path;
+use std::str::{Chars, FromStr};
+
+use super::AppEvent;
+use crate::platform::{
+    Key, Modifiers, PlatformImePreedit, PlatformInputType, PlatformKeyEvent, PlatformPointerButton,
+    PlatformPointerEvent, PlatformPointerEventKind, PlatformPreeditAttribute, PlatformPreeditStyle,
+    PlatformTextInput, PlatformWheelEvent, PointerType, WheelDeltaMode, WheelPhase,
+};
+use crate::time::{Duration, Instant};
+
+/// First line of every saved recording; bumped when the line format changes.
+pub const RECORDING_HEADER: &str = "rfgui-input-recording 1";
+
+/// One captured event.
+#[derive(Debug, Clone, PartialEq)]
+pub struct RecordedEvent {
+    /// Offset from the start of the recording.
+    pub at: Duration,
+    pub event: AppEvent,
+}
+
+/// Timestamped input events in delivery order.
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct InputRecording {
+    events: Vec<RecordedEvent>,
+}
+
+impl InputRecording {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Append `event` at offset `at`, after any events already recorded at
+    /// or before that offset. Scripted demos build recordings this way.
+    pub fn push(&mut self, at: Duration, event: AppEvent) {
+        let index = self.events.partition_point(|recorded| recorded.at <= at);
+        self.events.insert(index, RecordedEvent { at, event });
+    }
+
+    pub fn events(&self) -> &[RecordedEvent] {
+        &self.events
+    }
+
+    pub fn len(&self) -> usize {
+        self.events.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.events.is_empty()
+    }
+
+    /// Offset of the last event.
+    pub fn duration(&self) -> Duration {
+        self.events
+            .last()
+            .map_or(Duration::ZERO, |recorded| recorded.at)
+    }
+
+    /// Serialize to the line format [`Self::parse`] reads back.
+    pub fn to_text(&self) -> String {
+        let mut out = String::from(RECORDING_HEADER);
+        out.push('\n');
+        for recorded in &self.events {
+            let _ = write!(out, "{}", recorded.at.as_micros());
+            write_event(&mut out, &recorded.event);
+            out.push('\n');
+        }
+        out
+    }
+
+    /// Parse the output of [`Self::to_text`]. Blank lines and lines
+    /// starting with `#` are ignored.
+    pub fn parse(text: &str) -> Result<Self, String> {
+        let mut lines = text.lines().enumerate();
+        match lines.next() {
+            Some((_, header)) if header.trim() == RECORDING_HEADER => {}
+            _ => return Err(format!("expected `{RECORDING_HEADER}` header")),
+        }
+        let mut recording = Self::new();
+        for (index, line) in lines {
+            let line = line.trim();
+            if line.is_empty() || line.starts_with('#') {
+                continue;
+            }
+            let (at, event) =
+                parse_line(line).map_err(|error| format!("line {}: {error}", index + 1))?;
+            recording.push(at, event);
+        }
+        Ok(recording)
+    }
+
+    pub fn save(&self, path: impl AsRef<Path>) -> std::io::Result<()> {
+        std::fs::write(path, self.to_text())
+    }
+
+    pub fn load(path: impl AsRef<Path>) -> std::io::Result<Self> {
+        let text = std::fs::read_to_string(path)?;
+        Self::parse(&text)
+            .map_err(|error| std::io::Error::new(std::io::ErrorKind::InvalidData, error))
+    }
+}
+
+/// Recording state a viewport keeps while capture is on.
+pub(crate) struct InputRecorder {
+    started: Instant,
+    recording: InputRecording,
+}
+
+impl InputRecorder {
+    pub(crate) fn new(started: Instant) -> Self {
+        Self {
+            started,
+            recording: InputRecording::new(),
+        }
+    }
+
+    pub(crate) fn record(&mut self, now: Instant, event: &AppEvent) {
+        if is_input_event(event) {
+            let at = now.saturating_duration_since(self.started);
+            self.recording.push(at, event.clone());
+        }
+    }
+
+    pub(crate) fn finish(self) -> InputRecording {
+        self.recording
+    }
+}
+
+/// Hands out the events of a recording as a clock reaches them.
+pub struct InputReplayer {
+    start: Instant,
+    events: std::vec::IntoIter<RecordedEvent>,
+    next: Option<RecordedEvent>,
+}
+
+impl InputReplayer {
+    /// Play `recording` with its offsets measured from `start`.
+    pub fn new(recording: InputRecording, start: Instant) -> Self {
+        let mut events = recording.events.into_iter();
+        let next = events.next();
+        Self {
+            start,
+            events,
+            next,
+        }
+    }
+
+    /// When the next event is due; `None` once everything was delivered.
+    pub fn next_at(&self) -> Option<Instant> {
+        self.next.as_ref().map(|recorded| self.start + recorded.at)
+    }
+
+    pub fn is_finished(&self) -> bool {
+        self.next.is_none()
+    }
+
+    /// Every event due at or before `now`, in order. Key and wheel
+    /// timestamps are rewritten to the replay clock.
+    pub fn take_due(&mut self, now: Instant) -> Vec<AppEvent> {
+        let mut due = Vec::new();
+        while let Some(at) = self.next_at().filter(|at| *at <= now) {
+            let mut event = self.next.take().expect("next_at saw an event").event;
+            match &mut event {
+                AppEvent::Key(key) => key.timestamp = at,
+                AppEvent::Wheel(wheel) => wheel.timestamp = at,
+                _ => {}
+            }
+            due.push(event);
+            self.next = self.events.next();
+        }
+        due
+    }
+}
+
+fn is_input_event(event: &AppEvent) -> bool {
+    matches!(
+        event,
+        AppEvent::Pointer(_)
+            | AppEvent::Wheel(_)
+            | AppEvent::Key(_)
+            | AppEvent::TextInput(_)
+            | AppEvent::ImePreedit(_)
+            | AppEvent::Resized { .. }
+            | AppEvent::ScaleFactorChanged { .. }
+    )
+}
+
+fn write_event(out: &mut String, event: &AppEvent) {
+    match event {
+        AppEvent::Pointer(pointer) => {
+            let _ = write!(
+                out,
+                " pointer {} {} {}",
+                pointer_type_name(pointer.pointer_type),
+                pointer.pointer_id,
+                pointer.pressure
+            );
+            let _ = match pointer.kind {
+                PlatformPointerEventKind::Move { x, y } => write!(out, " move {x} {y}"),
+                PlatformPointerEventKind::Down(button) => {
+                    write!(out, " down {}", button_name(button))
+                }
+                PlatformPointerEventKind::Up(button) => write!(out, " up {}", button_name(button)),
+                PlatformPointerEventKind::Click(button) => {
+                    write!(out, " click {}", button_name(button))
+                }
+            };
+        }
+        AppEvent::Wheel(wheel) => {
+            let _ = write!(
+                out,
+                " wheel {} {} {} {} {} {} {}",
+                wheel.delta_x,
+                wheel.delta_y,
+                wheel.position.0,
+                wheel.position.1,
+                wheel.modifiers.bits(),
+                delta_mode_name(wheel.delta_mode),
+                phase_name(wheel.phase)
+            );
+        }
+        AppEvent::Key(key) => {
+            let _ = write!(
+                out,
+                " key {} {:?} {} {} {}",
+                if key.pressed { "down" } else { "up" },
+                key.key,
+                key.modifiers.bits(),
+                u8::from(key.repeat),
+                u8::from(key.is_composing)
+            );
+            match &key.characters {
+                Some(characters) => {
+                    let _ = write!(out, " {:?}", characters.as_str());
+                }
+                None => out.push_str(" -"),
+            }
+        }
+        AppEvent::TextInput(text) => {
+            let _ = write!(
+                out,
+                " text {} {} {:?}",
+                input_type_name(text.input_type),
+                u8::from(text.is_composing),
+                text.text
+            );
+        }
+        AppEvent::ImePreedit(preedit) => {
+            let _ = write!(out, " preedit {:?}", preedit.text);
+            for offset in [
+                preedit.cursor_start,
+                preedit.cursor_end,
+                preedit.selection_start,
+                preedit.selection_end,
+            ] {
+                match offset {
+                    Some(offset) => {
+                        let _ = write!(out, " {offset}");
+                    }
+                    None => out.push_str(" -"),
+                }
+            }
+            for attribute in &preedit.attributes {
+                let _ = write!(
+                    out,
+                    " {}:{}:{}",
+                    attribute.start,
+                    attribute.end,
+                    preedit_style_name(attribute.style)
+                );
+            }
+        }
+        AppEvent::Resized {
+            width,
+            height,
+            scale,
+        } => {
+            let _ = write!(out, " resize {width} {height} {scale}");
+        }
+        AppEvent::ScaleFactorChanged {
+            scale,
+            suggested_size,
+        } => {
+            let _ = match suggested_size {
+                Some((width, height)) => write!(out, " scale {scale} {width} {height}"),
+                None => write!(out, " scale {scale} -"),
+            };
+        }
+        _ => {}
+    }
+}
+
+fn parse_line(line: &str) -> Result<(Duration, AppEvent), String> {
+    let mut fields = Fields::new(line)?;
+    let at = Duration::from_micros(fields.number("timestamp")?);
+    let event = match fields.word("event kind")?.as_str() {
+        "pointer" => {
+            let pointer_type = match fields.word("pointer type")?.as_str() {
+                "mouse" => PointerType::Mouse,
+                "pen" => PointerType::Pen,
+                "touch" => PointerType::Touch,
+                other => return Err(format!("unknown pointer type `{other}`")),
+            };
+            let pointer_id = fields.number("pointer id")?;
+            let pressure = fields.number("pressure")?;
+            let kind = match fields.word("pointer action")?.as_str() {
+                "move" => PlatformPointerEventKind::Move {
+                    x: fields.number("x")?,
+                    y: fields.number("y")?,
+                },
+                "down" => PlatformPointerEventKind::Down(parse_button(&fields.word("button")?)?),
+                "up" => PlatformPointerEventKind::Up(parse_button(&fields.word("button")?)?),
+                "click" => PlatformPointerEventKind::Click(parse_button(&fields.word("button")?)?),
+                other => return Err(format!("unknown pointer action `{other}`")),
+            };
+            AppEvent::Pointer(PlatformPointerEvent {
+                kind,
+                pointer_id,
+                pointer_type,
+                pressure,
+            })
+        }
+        "wheel" => AppEvent::Wheel(PlatformWheelEvent {
+            delta_x: fields.number("delta x")?,
+            delta_y: fields.number("delta y")?,
+            position: (fields.number("x")?, fields.number("y")?),
+            modifiers: fields.modifiers()?,
+            delta_mode: match fields.word("delta mode")?.as_str() {
+                "pixel" => WheelDeltaMode::Pixel,
+                "line" => WheelDeltaMode::Line,
+                "page" => WheelDeltaMode::Page,
+                other => return Err(format!("unknown wheel delta mode `{other}`")),
+            },
+            phase: match fields.word("wheel phase")?.as_str() {
+                "began" => WheelPhase::Began,
+                "changed" => WheelPhase::Changed,
+                "ended" => WheelPhase::Ended,
+                "momentum" => WheelPhase::Momentum,
+                other => return Err(format!("unknown wheel phase `{other}`")),
+            },
+            timestamp: Instant::now(),
+        }),
+        "key" => {
+            let pressed = match fields.word("key direction")?.as_str() {
+                "down" => true,
+                "up" => false,
+                other => return Err(format!("expected `down` or `up`, found `{other}`")),
+            };
+            AppEvent::Key(PlatformKeyEvent {
+                key: parse_key(&fields.word("key")?)?,
+                modifiers: fields.modifiers()?,
+                repeat: fields.flag("repeat")?,
+                is_composing: fields.flag("composing")?,
+                characters: fields.optional_text("characters")?.map(Into::into),
+                pressed,
+                timestamp: Instant::now(),
+            })
+        }
+        "text" => AppEvent::TextInput(PlatformTextInput {
+            input_type: match fields.word("input type")?.as_str() {
+                "typing" => PlatformInputType::Typing,
+                "paste" => PlatformInputType::Paste,
+                "drop" => PlatformInputType::Drop,
+                "ime-commit" => PlatformInputType::ImeCommit,
+                "programmatic" => PlatformInputType::Programmatic,
+                other => return Err(format!("unknown input type `{other}`")),
+            },
+            is_composing: fields.flag("composing")?,
+            text: fields.text("text")?,
+        }),
+        "preedit" => {
+            let text = fields.text("preedit text")?;
+            let cursor_start = fields.optional_number("cursor start")?;
+            let cursor_end = fields.optional_number("cursor end")?;
+            let selection_start = fields.optional_number("selection start")?;
+            let selection_end = fields.optional_number("selection end")?;
+            let mut attributes = Vec::new();
+            while let Some(token) = fields.next_word() {
+                attributes.push(parse_preedit_attribute(&token)?);
+            }
+            AppEvent::ImePreedit(PlatformImePreedit {
+                text,
+                cursor_start,
+                cursor_end,
+                selection_start,
+                selection_end,
+                attributes,
+            })
+        }
+        "resize" => AppEvent::Resized {
+            width: fields.number("width")?,
+            height: fields.number("height")?,
+            scale: fields.number("scale")?,
+        },
+        "scale" => {
+            let scale = fields.number("scale")?;
+            let suggested_size = match fields.optional_number("suggested width")? {
+                Some(width) => Some((width, fields.number("suggested height")?)),
+                None => None,
+            };
+            AppEvent::ScaleFactorChanged {
+                scale,
+                suggested_size,
+            }
+        }
+        other => return Err(format!("unknown event kind `{other}`")),
+    };
+    fields.finish()?;
+    Ok((at, event))
+}
+
+enum Token {
+    Word(String),
+    Text(String),
+}
+
+/// Whitespace-separated words and Rust-escaped `"..."` strings.
+struct Fields {
+    tokens: std::vec::IntoIter<Token>,
+}
+
+impl Fields {
+    fn new(line: &str) -> Result<Self, String> {
+        let mut tokens = Vec::new();
+        let mut chars = line.chars().peekable();
+        while let Some(&ch) = chars.peek() {
+            if ch.is_whitespace() {
+                chars.next();
+            } else if ch == '"' {
+                chars.next();
+                tokens.push(Token::Text(unescape(&mut chars)?));
+            } else {
+                let mut word = String::new();
+                while let Some(&ch) = chars.peek().filter(|ch| !ch.is_whitespace()) {
+                    word.push(ch);
+                    chars.next();
+                }
+                tokens.push(Token::Word(word));
+            }
+        }
+        Ok(Self {
+            tokens: tokens.into_iter(),
+        })
+    }
+
+    fn next_word(&mut self) -> Option<String> {
+        match self.tokens.next()? {
+            Token::Word(word) => Some(word),
+            Token::Text(text) => Some(format!("{text:?}")),
+        }
+    }
+
+    fn word(&mut self, what: &str) -> Result<String, String> {
+        match self.tokens.next() {
+            Some(Token::Word(word)) => Ok(word),
+            Some(Token::Text(_)) => Err(format!("expected {what}, found a string")),
+            None => Err(format!("missing {what}")),
+        }
+    }
+
+    fn number<T: FromStr>(&mut self, what: &str) -> Result<T, String> {
+        let word = self.word(what)?;
+        word.parse().map_err(|_| format!("invalid {what} `{word}`"))
+    }
+
+    fn optional_number<T: FromStr>(&mut self, what: &str) -> Result<Option<T>, String> {
+        let word = self.word(what)?;
+        if word == "-" {
+            return Ok(None);
+        }
+        word.parse()
+            .map(Some)
+            .map_err(|_| format!("invalid {what} `{word}`"))
+    }
+
+    fn flag(&mut self, what: &str) -> Result<bool, String> {
+        match self.word(what)?.as_str() {
+            "0" => Ok(false),
+            "1" => Ok(true),
+            other => Err(format!("invalid {what} flag `{other}`")),
+        }
+    }
+
+    fn modifiers(&mut self) -> Result<Modifiers, String> {
+        let bits = self.number("modifiers")?;
+        Modifiers::from_bits(bits).ok_or_else(|| format!("invalid modifiers `{bits}`"))
+    }
+
+    fn text(&mut self, what: &str) -> Result<String, String> {
+        match self.tokens.next() {
+            Some(Token::Text(text)) => Ok(text),
+            Some(Token::Word(word)) => Err(format!("expected quoted {what}, found `{word}`")),
+            None => Err(format!("missing {what}")),
+        }
+    }
+
+    fn optional_text(&mut self, what: &str) -> Result<Option<String>, String> {
+        match self.tokens.next() {
+            Some(Token::Text(text)) => Ok(Some(text)),
+            Some(Token::Word(word)) if word == "-" => Ok(None),
+            Some(Token::Word(word)) => Err(format!("expected quoted {what}, found `{word}`")),
+            None => Err(format!("missing {what}")),
+        }
+    }
+
+    fn finish(mut self) -> Result<(), String> {
+        match self.next_word() {
+            Some(extra) => Err(format!("unexpected `{extra}`")),
+            None => Ok(()),
+        }
+    }
+}
+
+/// Read the rest of a string literal written with `{:?}`; the opening
+/// quote is already consumed.
+fn unescape(chars: &mut Peekable<Chars<'_>>) -> Result<String, String> {
+    let mut text = String::new();
+    loop {
+        match chars.next().ok_or("unterminated string")? {
+            '"' => return Ok(text),
+            '\\' => text.push(match chars.next().ok_or("unterminated escape")? {
+                'n' => '\n',
+                'r' => '\r',
+                't' => '\t',
+                '0' => '\0',
+                '\\' => '\\',
+                '"' => '"',
+                '\'' => '\'',
+                'u' => {
+                    if chars.next() != Some('{') {
+                        return Err("expected `{` after `\\u`".to_string());
+                    }
+                    let mut hex = String::new();
+                    loop {
+                        match chars.next().ok_or("unterminated unicode escape")? {
+                            '}' => break,
+                            digit => hex.push(digit),
+                        }
+                    }
+                    u32::from_str_radix(&hex, 16)
+                        .ok()
+                        .and_then(char::from_u32)
+                        .ok_or_else(|| format!("invalid unicode escape `{hex}`"))?
+                }
+                other => return Err(format!("unknown escape `\\{other}`")),
+            }),
+            ch => text.push(ch),
+        }
+    }
+}
+
+fn pointer_type_name(pointer_type: PointerType) -> &'static str {
+    match pointer_type {
+        PointerType::Mouse => "mouse",
+        PointerType::Pen => "pen",
+        PointerType::Touch => "touch",
+    }
+}
+
+fn button_name(button: PlatformPointerButton) -> String {
+    match button {
+        PlatformPointerButton::Left => "left".to_string(),
+        PlatformPointerButton::Right => "right".to_string(),
+        PlatformPointerButton::Middle => "middle".to_string(),
+        PlatformPointerButton::Back => "back".to_string(),
+        PlatformPointerButton::Forward => "forward".to_string(),
+        PlatformPointerButton::Other(code) => format!("other:{code}"),
+    }
+}
+
+fn parse_button(word: &str) -> Result<PlatformPointerButton, String> {
+    Ok(match word {
+        "left" => PlatformPointerButton::Left,
+        "right" => PlatformPointerButton::Right,
+        "middle" => PlatformPointerButton::Middle,
+        "back" => PlatformPointerButton::Back,
+        "forward" => PlatformPointerButton::Forward,
+        _ => PlatformPointerButton::Other(
+            word.strip_prefix("other:")
+                .and_then(|code| code.parse().ok())
+                .ok_or_else(|| format!("unknown pointer button `{word}`"))?,
+        ),
+    })
+}
+
+fn delta_mode_name(mode: WheelDeltaMode) -> &'static str {
+    match mode {
+        WheelDeltaMode::Pixel => "pixel",
+        WheelDeltaMode::Line => "line",
+        WheelDeltaMode::Page => "page",
+    }
+}
+
+fn phase_name(phase: WheelPhase) -> &'static str {
+    match phase {
+        WheelPhase::Began => "began",
+        WheelPhase::Changed => "changed",
+        WheelPhase::Ended => "ended",
+        WheelPhase::Momentum => "momentum",
+    }
+}
+
+fn input_type_name(input_type: PlatformInputType) -> &'static str {
+    match input_type {
+        PlatformInputType::Typing => "typing",
+        PlatformInputType::Paste => "paste",
+        PlatformInputType::Drop => "drop",
+        PlatformInputType::ImeCommit => "ime-commit",
+        PlatformInputType::Programmatic => "programmatic",
+    }
+}
+
+fn preedit_style_name(style: PlatformPreeditStyle) -> &'static str {
+    match style {
+        PlatformPreeditStyle::Underline => "underline",
+        PlatformPreeditStyle::DottedUnderline => "dotted-underline",
+        PlatformPreeditStyle::Highlight => "highlight",
+    }
+}
+
+fn parse_preedit_attribute(word: &str) -> Result<PlatformPreeditAttribute, String> {
+    let invalid = || format!("invalid preedit attribute `{word}`");
+    let mut parts = word.splitn(3, ':');
+    let start = parts
+        .next()
+        .and_then(|start| start.parse().ok())
+        .ok_or_else(invalid)?;
+    let end = parts
+        .next()
+        .and_then(|end| end.parse().ok())
+        .ok_or_else(invalid)?;
+    let style = match parts.next() {
+        Some("underline") => PlatformPreeditStyle::Underline,
+        Some("dotted-underline") => PlatformPreeditStyle::DottedUnderline,
+        Some("highlight") => PlatformPreeditStyle::Highlight,
+        _ => return Err(invalid()),
+    };
+    Ok(PlatformPreeditAttribute { start, end, style })
+}
+
+/// Keys are written with their `Debug` name.
+fn parse_key(word: &str) -> Result<Key, String> {
+    if let Some(code) = word
+        .strip_prefix("Unidentified(")
+        .and_then(|rest| rest.strip_suffix(')'))
+    {
+        return match code {
+            "None" => Ok(Key::Unidentified(None)),
+            _ => code
+                .strip_prefix("Some(")
+                .and_then(|rest| rest.strip_suffix(')'))
+                .and_then(|code| code.parse().ok())
+                .map(|code| Key::Unidentified(Some(code)))
+                .ok_or_else(|| format!("invalid key `{word}`")),
+        };
+    }
+    NAMED_KEYS
+        .iter()
+        .copied()
+        .find(|key| format!("{key:?}") == word)
+        .ok_or_else(|| format!("unknown key `{word}`"))
+}
+
+const NAMED_KEYS: &[Key] = &[
+    Key::KeyA,
+    Key::KeyB,
+    Key::KeyC,
+    Key::KeyD,
+    Key::KeyE,
+    Key::KeyF,
+    Key::KeyG,
+    Key::KeyH,
+    Key::KeyI,
+    Key::KeyJ,
+    Key::KeyK,
+    Key::KeyL,
+    Key::KeyM,
+    Key::KeyN,
+    Key::KeyO,
+    Key::KeyP,
+    Key::KeyQ,
+    Key::KeyR,
+    Key::KeyS,
+    Key::KeyT,
+    Key::KeyU,
+    Key::KeyV,
+    Key::KeyW,
+    Key::KeyX,
+    Key::KeyY,
+    Key::KeyZ,
+    Key::Digit0,
+    Key::Digit1,
+    Key::Digit2,
+    Key::Digit3,
+    Key::Digit4,
+    Key::Digit5,
+    Key::Digit6,
+    Key::Digit7,
+    Key::Digit8,
+    Key::Digit9,
+    Key::NumberPad0,
+    Key::NumberPad1,
+    Key::NumberPad2,
+    Key::NumberPad3,
+    Key::NumberPad4,
+    Key::NumberPad5,
+    Key::NumberPad6,
+    Key::NumberPad7,
+    Key::NumberPad8,
+    Key::NumberPad9,
+    Key::NumberPadAdd,
+    Key::NumberPadSubtract,
+    Key::NumberPadMultiply,
+    Key::NumberPadDivide,
+    Key::NumberPadDecimal,
+    Key::NumberPadEnter,
+    Key::NumberPadEqual,
+    Key::ShiftLeft,
+    Key::ShiftRight,
+    Key::ControlLeft,
+    Key::ControlRight,
+    Key::AltLeft,
+    Key::AltRight,
+    Key::MetaLeft,
+    Key::MetaRight,
+    Key::F1,
+    Key::F2,
+    Key::F3,
+    Key::F4,
+    Key::F5,
+    Key::F6,
+    Key::F7,
+    Key::F8,
+    Key::F9,
+    Key::F10,
+    Key::F11,
+    Key::F12,
+    Key::F13,
+    Key::F14,
+    Key::F15,
+    Key::F16,
+    Key::F17,
+    Key::F18,
+    Key::F19,
+    Key::F20,
+    Key::F21,
+    Key::F22,
+    Key::F23,
+    Key::F24,
+    Key::ArrowUp,
+    Key::ArrowDown,
+    Key::ArrowLeft,
+    Key::ArrowRight,
+    Key::Home,
+    Key::End,
+    Key::PageUp,
+    Key::PageDown,
+    Key::Insert,
+    Key::Delete,
+    Key::Enter,
+    Key::Tab,
+    Key::Space,
+    Key::Backspace,
+    Key::Escape,
+    Key::CapsLock,
+    Key::NumLock,
+    Key::ScrollLock,
+    Key::PrintScreen,
+    Key::Pause,
+    Key::ContextMenu,
+    Key::Backquote,
+    Key::Minus,
+    Key::Equal,
+    Key::BracketLeft,
+    Key::BracketRight,
+    Key::Backslash,
+    Key::Semicolon,
+    Key::Quote,
+    Key::Comma,
+    Key::Period,
+    Key::Slash,
+    Key::IntlYen,
+    Key::IntlRo,
+    Key::IntlBackslash,
+    Key::Lang1,
+    Key::Lang2,
+    Key::Convert,
+    Key::NonConvert,
+    Key::KanaMode,
+    Key::AudioVolumeUp,
+    Key::AudioVolumeDown,
+    Key::AudioVolumeMute,
+    Key::MediaPlayPause,
+    Key::MediaStop,
+    Key::MediaTrackNext,
+    Key::MediaTrackPrev,
+    Key::BrowserBack,
+    Key::BrowserForward,
+    Key::BrowserRefresh,
+    Key::BrowserHome,
+    Key::LaunchMail,
+    Key::LaunchApp1,
+    Key::LaunchApp2,
+];
+
+#[cfg(test)]
+mod tests;
@@ -0,0 +1,244 @@
+use std::cell::RefCell;
+use std::rc::Rc;
+
+use super::*;
+use crate::app::headless::HeadlessApp;
+use crate::app::{App, AppContext};
+use crate::style::Length;
+use crate::ui::{RsxNode, global_state, on_click, rsx};
+use crate::view::Element as HostElement;
+
+fn sample_recording() -> InputRecording {
+    let mut recording = InputRecording::new();
+    recording.push(
+        Duration::from_millis(5),
+        AppEvent::Pointer(PlatformPointerEvent {
+            kind: PlatformPointerEventKind::Move { x: 10.5, y: -3.25 },
+            pointer_id: 7,
+            pointer_type: PointerType::Pen,
+            pressure: 0.5,
+        }),
+    );
+    recording.push(
+        Duration::from_millis(6),
+        AppEvent::Pointer(PlatformPointerEvent {
+            kind: PlatformPointerEventKind::Down(PlatformPointerButton::Other(9)),
+            pointer_id: 0,
+            pointer_type: PointerType::Mouse,
+            pressure: 0.0,
+        }),
+    );
+    recording.push(
+        Duration::from_micros(6_500),
+        AppEvent::Wheel(PlatformWheelEvent {
+            delta_x: 0.0,
+            delta_y: -120.0,
+            position: (1.0, 2.0),
+            modifiers: Modifiers::SHIFT | Modifiers::CAPS_LOCK,
+            delta_mode: WheelDeltaMode::Line,
+            phase: WheelPhase::Momentum,
+            timestamp: Instant::now(),
+        }),
+    );
+    for (key, characters) in [
+        (Key::KeyQ, Some("q \"quoted\"\n\u{7}")),
+        (Key::Unidentified(Some(42)), None),
+        (Key::Unidentified(None), None),
+    ] {
+        recording.push(
+            Duration::from_millis(20),
+            AppEvent::Key(PlatformKeyEvent {
+                key,
+                characters: characters.map(Into::into),
+                modifiers: Modifiers::CTRL,
+                repeat: true,
+                is_composing: false,
+                pressed: true,
+                timestamp: Instant::now(),
+            }),
+        );
+    }
+    recording.push(
+        Duration::from_millis(30),
+        AppEvent::TextInput(PlatformTextInput {
+            text: "日本 語".to_string(),
+            input_type: PlatformInputType::ImeCommit,
+            is_composing: true,
+        }),
+    );
+    recording.push(
+        Duration::from_millis(31),
+        AppEvent::ImePreedit(PlatformImePreedit {
+            text: "にほ".to_string(),
+            cursor_start: Some(3),
+            cursor_end: Some(6),
+            selection_start: None,
+            selection_end: None,
+            attributes: vec![PlatformPreeditAttribute {
+                start: 0,
+                end: 6,
+                style: PlatformPreeditStyle::DottedUnderline,
+            }],
+        }),
+    );
+    recording.push(
+        Duration::from_secs(1),
+        AppEvent::Resized {
+            width: 640,
+            height: 480,
+            scale: 1.5,
+        },
+    );
+    recording.push(
+        Duration::from_secs(2),
+        AppEvent::ScaleFactorChanged {
+            scale: 2.0,
+            suggested_size: Some((1280, 960)),
+        },
+    );
+    recording
+}
+
+/// Timestamps are not serialized, so compare with them zeroed out.
+fn without_timestamps(recording: &InputRecording, now: Instant) -> Vec<RecordedEvent> {
+    let mut events = recording.events().to_vec();
+    for recorded in &mut events {
+        match &mut recorded.event {
+            AppEvent::Key(key) => key.timestamp = now,
+            AppEvent::Wheel(wheel) => wheel.timestamp = now,
+            _ => {}
+        }
+    }
+    events
+}
+
+#[test]
+fn text_format_round_trips_every_input_event() {
+    let recording = sample_recording();
+    let text = recording.to_text();
+    assert!(text.starts_with(RECORDING_HEADER));
+    let parsed = InputRecording::parse(&text).expect("round trip");
+    let now = Instant::now();
+    assert_eq!(
+        without_timestamps(&parsed, now),
+        without_timestamps(&recording, now)
+    );
+    assert_eq!(parsed.duration(), Duration::from_secs(2));
+}
+
+#[test]
+fn parse_reports_the_offending_line() {
+    let error = InputRecording::parse(&format!(
+        "{RECORDING_HEADER}\n# comment\n\n10 pointer mouse 0 0 move 1 2\n20 key down NotAKey 0 0 0 -\n"
+    ))
+    .unwrap_err();
+    assert!(error.starts_with("line 5:"), "{error}");
+    assert!(error.contains("NotAKey"), "{error}");
+
+    assert!(InputRecording::parse("10 resize 1 2 1").is_err());
+    assert!(InputRecording::parse(&format!("{RECORDING_HEADER}\n10 resize 1 2 1 extra")).is_err());
+    assert!(
+        InputRecording::parse(&format!("{RECORDING_HEADER}\n10 text typing 0 \"open")).is_err()
+    );
+}
+
+#[test]
+fn replayer_releases_events_as_the_clock_reaches_them() {
+    let start = Instant::now();
+    let mut replayer = InputReplayer::new(sample_recording(), start);
+    assert_eq!(replayer.next_at(), Some(start + Duration::from_millis(5)));
+    assert!(replayer.take_due(start).is_empty());
+    assert_eq!(replayer.take_due(start + Duration::from_millis(6)).len(), 2);
+
+    let due = replayer.take_due(start + Duration::from_millis(20));
+    assert_eq!(due.len(), 4);
+    let AppEvent::Key(key) = &due[1] else {
+        panic!("expected a key event, got {:?}", due[1]);
+    };
+    assert_eq!(key.timestamp, start + Duration::from_millis(20));
+
+    replayer.take_due(start + Duration::from_secs(5));
+    assert!(replayer.is_finished());
+    assert_eq!(replayer.next_at(), None);
+}
+
+#[derive(Clone, PartialEq)]
+struct ClickLog(Vec<u32>);
+
+struct ClickApp {
+    seen: Rc<RefCell<Vec<String>>>,
+}
+
+impl App for ClickApp {
+    fn build(&mut self, _ctx: &mut AppContext<'_>) -> RsxNode {
+        let log = global_state(|| ClickLog(Vec::new()));
+        rsx! {
+            <HostElement
+                style={{ width: Length::px(100.0), height: Length::px(40.0) }}
+                on_click={on_click(move |_| log.update(|log| log.0.push(log.0.len() as u32)))}
+            />
+        }
+    }
+
+    fn on_event(&mut self, event: &AppEvent, _ctx: &mut AppContext<'_>) {
+        if let AppEvent::Resized { width, height, .. } = event {
+            self.seen
+                .borrow_mut()
+                .push(format!("resize {width}x{height}"));
+        }
+    }
+}
+
+#[test]
+fn viewport_records_input_and_headless_replay_reproduces_it() {
+    let seen = Rc::new(RefCell::new(Vec::new()));
+    let mut app = HeadlessApp::new(ClickApp { seen: seen.clone() }, 300, 200);
+    app.viewport_mut().start_input_recording();
+    assert!(app.viewport().is_recording_input());
+    app.tick(Duration::from_millis(100));
+    app.click(10.0, 10.0);
+    app.send(AppEvent::HostFocus(true));
+    app.tick(Duration::from_millis(250));
+    app.send(AppEvent::Resized {
+        width: 200,
+        height: 100,
+        scale: 1.0,
+    });
+    app.click(20.0, 20.0);
+    app.settle(Duration::from_millis(16));
+
+    let recording = app
+        .viewport_mut()
+        .stop_input_recording()
+        .expect("recording");
+    assert!(!app.viewport().is_recording_input());
+    // Two clicks of four pointer events each plus the resize; host focus is
+    // not input.
+    assert_eq!(recording.len(), 9);
+    assert_eq!(recording.events()[0].at, Duration::from_millis(100));
+    assert_eq!(recording.duration(), Duration::from_millis(350));
+    assert_eq!(global_state(|| ClickLog(Vec::new())).get().0, vec![0, 1]);
+    drop(app);
+
+    let path = std::env::temp_dir().join(format!("rfgui-recording-{}.txt", std::process::id()));
+    recording.save(&path).expect("save");
+    let loaded = InputRecording::load(&path).expect("load");
+    let _ = std::fs::remove_file(&path);
+
+    global_state(|| ClickLog(Vec::new())).set(ClickLog(Vec::new()));
+    let replayed = Rc::new(RefCell::new(Vec::new()));
+    let mut app = HeadlessApp::new(
+        ClickApp {
+            seen: replayed.clone(),
+        },
+        300,
+        200,
+    );
+    let start = app.now();
+    app.replay(&loaded, Duration::from_millis(16));
+    assert_eq!(app.now(), start + Duration::from_millis(350));
+    app.settle(Duration::from_millis(16));
+    assert_eq!(global_state(|| ClickLog(Vec::new())).get().0, vec![0, 1]);
+    assert_eq!(*replayed.borrow(), *seen.borrow());
+    assert_eq!(app.viewport().logical_size(), (200.0, 100.0));
+}
@@ -0,0 +1,259 @@
+//! Golden-image regression tests.
+//!
+//! [`assert_snapshot_image!`](crate::assert_snapshot_image) renders an RSX
+//! tree through an offscreen [`Viewport`] and compares the pixels against
+//! `tests/snapshots/<name>.png` in the calling crate. Two pixels differ when
+//! their perceptual distance (the YIQ metric pixelmatch uses) exceeds
+//! [`SnapshotOptions::threshold`], which absorbs antialiasing and driver
+//! noise; the assertion fails once more than
+//! [`SnapshotOptions::max_diff_ratio`] of the image differs, after writing
+//! `<name>.actual.png` and `<name>.diff.png` under `tests/snapshots/failures/`.
+//!
+//! A missing baseline is recorded rather than failed. Run with
+//! `RFGUI_UPDATE_SNAPSHOTS=1` to re-record baselines that changed on
+//! purpose.
+
+use std::future::Future;
+use std::path::Path;
+use std::sync::Arc;
+use std::task::{Context, Poll, Wake, Waker};
+
+use image::{Rgba, RgbaImage};
+
+use crate::ui::RsxNode;
+use crate::view::viewport::Viewport;
+
+/// Environment variable that re-records every baseline it touches.
+pub const UPDATE_SNAPSHOTS_ENV: &str = "RFGUI_UPDATE_SNAPSHOTS";
+
+/// Largest value the YIQ distance in [`color_delta`] can reach.
+const MAX_YIQ_DELTA: f32 = 35215.0;
+
+/// Tolerances for [`assert_snapshot_image`].
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct SnapshotOptions {
+    /// Per-pixel perceptual distance in `0.0..=1.0` below which two pixels
+    /// count as equal.
+    pub threshold: f32,
+    /// Fraction of differing pixels tolerated before the assertion fails.
+    pub max_diff_ratio: f32,
+}
+
+impl Default for SnapshotOptions {
+    fn default() -> Self {
+        Self {
+            threshold: 0.1,
+            max_diff_ratio: 0.001,
+        }
+    }
+}
+
+/// Outcome of [`diff_images`].
+#[derive(Clone, Debug)]
+pub struct ImageDiff {
+    /// Pixels whose distance exceeded the threshold.
+    pub diff_pixels: usize,
+    pub total_pixels: usize,
+    /// Faded grayscale copy of the expected image with differing pixels
+    /// painted red.
+    pub image: RgbaImage,
+}
+
+impl ImageDiff {
+    pub fn diff_ratio(&self) -> f32 {
+        if self.total_pixels == 0 {
+            0.0
+        } else {
+            self.diff_pixels as f32 / self.total_pixels as f32
+        }
+    }
+}
+
+/// Render `tree` into a fresh `width` x `height` offscreen viewport and read
+/// the first frame back as sRGB RGBA.
+pub fn render_to_rgba(tree: &RsxNode, (width, height): (u32, u32)) -> Result<RgbaImage, String> {
+    let mut viewport = Viewport::new();
+    viewport.set_size(width, height);
+    block_on(viewport.attach_offscreen())?;
+    viewport.render_rsx(tree)?;
+    viewport.read_pixels()
+}
+
+/// Compare `actual` against `expected` pixel by pixel. Fails when the
+/// dimensions differ.
+pub fn diff_images(
+    expected: &RgbaImage,
+    actual: &RgbaImage,
+    threshold: f32,
+) -> Result<ImageDiff, String> {
+    if expected.dimensions() != actual.dimensions() {
+        return Err(format!(
+            "image size mismatch: expected {:?}, got {:?}",
+            expected.dimensions(),
+            actual.dimensions()
+        ));
+    }
+    let max_delta = MAX_YIQ_DELTA * threshold * threshold;
+    let mut diff_pixels = 0;
+    let mut image = RgbaImage::new(expected.width(), expected.height());
+    for ((expected, actual), out) in expected
+        .pixels()
+        .zip(actual.pixels())
+        .zip(image.pixels_mut())
+    {
+        if color_delta(expected.0, actual.0) > max_delta {
+            diff_pixels += 1;
+            *out = Rgba([255, 0, 0, 255]);
+        } else {
+            let [r, g, b, _] = blend_over_white(expected.0);
+            let gray = 0.299 * r + 0.587 * g + 0.114 * b;
+            let faded = (255.0 - (255.0 - gray) * 0.1) as u8;
+            *out = Rgba([faded, faded, faded, 255]);
+        }
+    }
+    Ok(ImageDiff {
+        diff_pixels,
+        total_pixels: (expected.width() * expected.height()) as usize,
+        image,
+    })
+}
+
+/// Render `tree` and compare it against `<manifest_dir>/tests/snapshots/<name>.png`;
+/// see the module docs. Prefer the [`assert_snapshot_image!`](crate::assert_snapshot_image)
+/// macro, which fills in the calling crate's manifest directory.
+pub fn assert_snapshot_image(
+    tree: &RsxNode,
+    size: (u32, u32),
+    name: &str,
+    manifest_dir: impl AsRef<Path>,
+    options: SnapshotOptions,
+) {
+    let actual = render_to_rgba(tree, size)
+        .unwrap_or_else(|error| panic!("snapshot `{name}`: render failed: {error}"));
+    let snapshots = manifest_dir.as_ref().join("tests").join("snapshots");
+    let baseline = snapshots.join(format!("{name}.png"));
+    let update = std::env::var_os(UPDATE_SNAPSHOTS_ENV).is_some_and(|value| value != "0");
+    if update || !baseline.exists() {
+        save_png(&actual, &baseline);
+        eprintln!("snapshot `{name}`: recorded {}", baseline.display());
+        return;
+    }
+
+    let expected = image::open(&baseline)
+        .unwrap_or_else(|error| panic!("snapshot `{name}`: cannot read baseline: {error}"))
+        .into_rgba8();
+    let failures = snapshots.join("failures");
+    let actual_path = failures.join(format!("{name}.actual.png"));
+    let diff_path = failures.join(format!("{name}.diff.png"));
+    let diff = match diff_images(&expected, &actual, options.threshold) {
+        Ok(diff) => diff,
+        Err(error) => {
+            save_png(&actual, &actual_path);
+            panic!(
+                "snapshot `{name}`: {error}; wrote {}",
+                actual_path.display()
+            );
+        }
+    };
+    if diff.diff_ratio() <= options.max_diff_ratio {
+        let _ = std::fs::remove_file(&actual_path);
+        let _ = std::fs::remove_file(&diff_path);
+        return;
+    }
+    save_png(&actual, &actual_path);
+    save_png(&diff.image, &diff_path);
+    panic!(
+        "snapshot `{name}`: {} of {} pixels differ ({:.3}% > {:.3}%); wrote {} and {}",
+        diff.diff_pixels,
+        diff.total_pixels,
+        diff.diff_ratio() * 100.0,
+        options.max_diff_ratio * 100.0,
+        actual_path.display(),
+        diff_path.display()
+    );
+}
+
+/// Render an RSX tree offscreen and compare it against a stored PNG.
+///
+/// `assert_snapshot_image!(tree, (width, height), "name")` reads
+/// `tests/snapshots/name.png` relative to the calling crate; pass a
+/// [`SnapshotOptions`](crate::app::snapshot::SnapshotOptions) as a fourth
+/// argument to loosen the tolerances.
+#[macro_export]
+macro_rules! assert_snapshot_image {
+    ($tree:expr, $size:expr, $name:expr $(,)?) => {
+        $crate::assert_snapshot_image!(
+            $tree,
+            $size,
+            $name,
+            $crate::app::snapshot::SnapshotOptions::default()
+        )
+    };
+    ($tree:expr, $size:expr, $name:expr, $options:expr $(,)?) => {
+        $crate::app::snapshot::assert_snapshot_image(
+            &$tree,
+            $size,
+            $name,
+            ::core::env!("CARGO_MANIFEST_DIR"),
+            $options,
+        )
+    };
+}
+
+fn save_png(image: &RgbaImage, path: &Path) {
+    if let Some(parent) = path.parent() {
+        std::fs::create_dir_all(parent)
+            .unwrap_or_else(|error| panic!("cannot create {}: {error}", parent.display()));
+    }
+    image
+        .save(path)
+        .unwrap_or_else(|error| panic!("cannot write {}: {error}", path.display()));
+}
+
+fn blend_over_white([r, g, b, a]: [u8; 4]) -> [f32; 4] {
+    let alpha = f32::from(a) / 255.0;
+    let blend = |channel: u8| 255.0 + (f32::from(channel) - 255.0) * alpha;
+    [blend(r), blend(g), blend(b), 255.0]
+}
+
+/// Squared YIQ distance between two pixels after compositing both over
+/// white, as in pixelmatch. Ranges over `0.0..=MAX_YIQ_DELTA`.
+fn color_delta(a: [u8; 4], b: [u8; 4]) -> f32 {
+    if a == b {
+        return 0.0;
+    }
+    let [r1, g1, b1, _] = blend_over_white(a);
+    let [r2, g2, b2, _] = blend_over_white(b);
+    let y = |r: f32, g: f32, b: f32| r * 0.298_895_3 + g * 0.586_622_5 + b * 0.114_482_2;
+    let i = |r: f32, g: f32, b: f32| r * 0.595_977_99 - g * 0.274_171_6 - b * 0.321_801_8;
+    let q = |r: f32, g: f32, b: f32| r * 0.211_470_2 - g * 0.522_617_4 + b * 0.311_146_9;
+    let dy = y(r1, g1, b1) - y(r2, g2, b2);
+    let di = i(r1, g1, b1) - i(r2, g2, b2);
+    let dq = q(r1, g1, b1) - q(r2, g2, b2);
+    0.5053 * dy * dy + 0.299 * di * di + 0.1957 * dq * dq
+}
+
+/// Drive `future` to completion on this thread. wgpu's native adapter and
+/// device requests resolve without an executor, so parking is enough.
+fn block_on<F: Future>(future: F) -> F::Output {
+    struct ThreadWaker(std::thread::Thread);
+
+    impl Wake for ThreadWaker {
+        fn wake(self: Arc<Self>) {
+            self.0.unpark();
+        }
+    }
+
+    let waker = Waker::from(Arc::new(ThreadWaker(std::thread::current())));
+    let mut context = Context::from_waker(&waker);
+    let mut future = std::pin::pin!(future);
+    loop {
+        match future.as_mut().poll(&mut context) {
+            Poll::Ready(output) => return output,
+            Poll::Pending => std::thread::park(),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests;
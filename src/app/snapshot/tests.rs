@@ -0,0 +1,130 @@
+use super::*;
+use crate::style::{Color, Length};
+use crate::ui::rsx;
+use crate::view::Element as HostElement;
+
+fn solid(width: u32, height: u32, color: [u8; 4]) -> RgbaImage {
+    RgbaImage::from_pixel(width, height, Rgba(color))
+}
+
+#[test]
+fn identical_images_have_no_diff() {
+    let image = solid(4, 3, [30, 60, 90, 255]);
+    let diff = diff_images(&image, &image, 0.1).expect("same size");
+    assert_eq!(diff.diff_pixels, 0);
+    assert_eq!(diff.total_pixels, 12);
+    assert_eq!(diff.diff_ratio(), 0.0);
+}
+
+#[test]
+fn small_color_drift_stays_under_the_threshold() {
+    let expected = solid(2, 2, [120, 120, 120, 255]);
+    let actual = solid(2, 2, [122, 119, 121, 255]);
+    assert_eq!(diff_images(&expected, &actual, 0.1).unwrap().diff_pixels, 0);
+    assert_eq!(diff_images(&expected, &actual, 0.0).unwrap().diff_pixels, 4);
+}
+
+#[test]
+fn changed_pixels_are_counted_and_painted_red() {
+    let expected = solid(3, 1, [255, 255, 255, 255]);
+    let mut actual = expected.clone();
+    actual.put_pixel(1, 0, Rgba([0, 0, 0, 255]));
+
+    let diff = diff_images(&expected, &actual, 0.1).unwrap();
+    assert_eq!(diff.diff_pixels, 1);
+    assert_eq!(diff.image.get_pixel(1, 0), &Rgba([255, 0, 0, 255]));
+    assert_eq!(diff.image.get_pixel(0, 0), &Rgba([255, 255, 255, 255]));
+}
+
+#[test]
+fn transparent_pixels_compare_as_composited_over_white() {
+    let expected = solid(1, 1, [255, 255, 255, 255]);
+    let actual = solid(1, 1, [0, 0, 0, 0]);
+    assert_eq!(diff_images(&expected, &actual, 0.1).unwrap().diff_pixels, 0);
+}
+
+#[test]
+fn size_mismatch_is_an_error() {
+    let error = diff_images(&solid(2, 2, [0; 4]), &solid(3, 2, [0; 4]), 0.1).unwrap_err();
+    assert!(error.contains("size mismatch"), "{error}");
+}
+
+#[test]
+#[ignore = "requires native GPU adapter"]
+fn renders_tree_and_round_trips_a_snapshot() {
+    let tree = rsx! {
+        <HostElement style={{
+            width: Length::px(20.0),
+            height: Length::px(10.0),
+            background: Color::rgb(255, 0, 0),
+        }} />
+    };
+    let image = render_to_rgba(&tree, (40, 20)).expect("offscreen render");
+    assert_eq!(image.dimensions(), (40, 20));
+    assert_eq!(image.get_pixel(5, 5), &Rgba([255, 0, 0, 255]));
+    assert_eq!(image.get_pixel(30, 15), &Rgba([0, 0, 0, 255]));
+
+    let dir = std::env::temp_dir().join(format!("rfgui-snapshot-{}", std::process::id()));
+    let baseline = dir.join("tests/snapshots/red_box.png");
+    assert_snapshot_image(&tree, (40, 20), "red_box", &dir, SnapshotOptions::default());
+    assert!(baseline.exists());
+    assert_snapshot_image(&tree, (40, 20), "red_box", &dir, SnapshotOptions::default());
+    let _ = std::fs::remove_dir_all(&dir);
+}
+
+#[test]
+#[ignore = "requires native GPU adapter"]
+fn blend_modes_and_filters_match_css_gamma_space_results() {
+    use crate::style::{Filter, MixBlendMode};
+    let tree = rsx! {
+        <HostElement style={{
+            width: Length::px(40.0),
+            height: Length::px(20.0),
+            background: Color::rgb(255, 255, 0),
+        }}>
+            <HostElement style={{
+                width: Length::px(10.0),
+                height: Length::px(10.0),
+                background: Color::rgb(0, 255, 255),
+                mix_blend_mode: MixBlendMode::Multiply,
+            }} />
+            <HostElement style={{
+                width: Length::px(10.0),
+                height: Length::px(10.0),
+                background: Color::rgb(255, 0, 0),
+                filter: vec![Filter::grayscale(1.0)],
+            }} />
+        </HostElement>
+    };
+    let image = render_to_rgba(&tree, (40, 20)).expect("offscreen render");
+    assert_eq!(image.get_pixel(5, 5), &Rgba([0, 255, 0, 255]));
+    assert_eq!(image.get_pixel(15, 5), &Rgba([54, 54, 54, 255]));
+    assert_eq!(image.get_pixel(30, 15), &Rgba([255, 255, 0, 255]));
+}
+
+#[test]
+#[ignore = "requires native GPU adapter"]
+fn gradient_mask_hides_the_element_where_the_mask_is_transparent() {
+    use crate::style::{Gradient, SideOrCorner};
+    let mask = Gradient::linear(SideOrCorner::Right)
+        .stop(Color::rgba(0, 0, 0, 255), Some(Length::percent(50.0)))
+        .stop(Color::rgba(0, 0, 0, 0), Some(Length::percent(50.0)));
+    let tree = rsx! {
+        <HostElement style={{
+            width: Length::px(40.0),
+            height: Length::px(20.0),
+            background: Color::rgb(255, 255, 0),
+        }}>
+            <HostElement style={{
+                width: Length::px(40.0),
+                height: Length::px(10.0),
+                background: Color::rgb(0, 0, 255),
+                mask: mask,
+            }} />
+        </HostElement>
+    };
+    let image = render_to_rgba(&tree, (40, 20)).expect("offscreen render");
+    assert_eq!(image.get_pixel(10, 5), &Rgba([0, 0, 255, 255]));
+    assert_eq!(image.get_pixel(30, 5), &Rgba([255, 255, 0, 255]));
+    assert_eq!(image.get_pixel(10, 15), &Rgba([255, 255, 0, 255]));
+}
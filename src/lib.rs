@@ -92,6 +92,116 @@ macro_rules! style {
     }};
 }
 
+/// Stack style layers in order, later layers on top.
+///
+/// ```ignore
+/// let style = styles![
+///     style! { padding: Padding::uniform(Length::px(8.0)) },
+///     hovered.then_some(style! { background: theme.hover }),
+///     props.style,
+/// ];
+/// ```
+///
+/// Each layer is a [`Style`](crate::style::Style) or an
+/// [`ElementStylePropSchema`](crate::view::ElementStylePropSchema), or an
+/// `Option` of one; `None` layers are skipped. All layers must be of the
+/// first layer's kind. See [`crate::style::ComposeStyle`] for the
+/// precedence rules.
+#[macro_export]
+macro_rules! styles {
+    ($first:expr $(, $layer:expr)* $(,)?) => {{
+        let mut __rfgui_styles = $crate::style::IntoStyleLayer::into_style_layer($first)
+            .unwrap_or_default();
+        $(
+            $crate::style::__compose_style_layer(
+                &mut __rfgui_styles,
+                $crate::style::IntoStyleLayer::into_style_layer($layer),
+            );
+        )*
+        __rfgui_styles
+    }};
+}
+
+/// Declare a component that renders an existing tag with preset props.
+///
+/// ```ignore
+/// styled! {
+///     pub PrimaryButton = Button {
+///         variant: ButtonVariant::Contained,
+///         color: ButtonColor::Primary,
+///     }
+/// }
+///
+/// rsx! { <PrimaryButton on_click={save}>Save</PrimaryButton> }
+/// ```
+///
+/// The new tag takes exactly the props of the wrapped one. Presets only
+/// fill props the call site leaves unset, so `<PrimaryButton
+/// color={ButtonColor::Error} />` still overrides the color. A braced
+/// value (`style: { padding: Padding::uniform(Length::px(8.0)) }`) is
+/// built as a style object, as with `style={{ ... }}` in `rsx!`. Style
+/// presets are the exception: a call-site `style` is stacked on top of the
+/// preset one rather than replacing it.
+#[macro_export]
+macro_rules! styled {
+    (
+        $(#[$meta:meta])*
+        $vis:vis $name:ident = $base:path { $($presets:tt)* }
+    ) => {
+        $(#[$meta])*
+        $vis struct $name;
+
+        impl $crate::ui::RsxTag for $name {
+            type Props = <$base as $crate::ui::RsxTag>::Props;
+            type StrictProps = <$base as $crate::ui::RsxTag>::StrictProps;
+            const ACCEPTS_CHILDREN: bool = <$base as $crate::ui::RsxTag>::ACCEPTS_CHILDREN;
+            const IS_HOST_TAG: bool = <$base as $crate::ui::RsxTag>::IS_HOST_TAG;
+            const HOST_BUILDER: ::core::option::Option<$crate::ui::ErasedHostBuilder> =
+                <$base as $crate::ui::RsxTag>::HOST_BUILDER;
+
+            fn into_strict(#[allow(unused_mut)] mut props: Self::Props) -> Self::StrictProps {
+                $crate::__rfgui_styled_presets!(props, $($presets)*);
+                <$base as $crate::ui::RsxTag>::into_strict(props)
+            }
+
+            fn create_node(
+                props: Self::StrictProps,
+                children: ::std::vec::Vec<$crate::ui::RsxNode>,
+                key: ::core::option::Option<$crate::ui::RsxKey>,
+            ) -> $crate::ui::RsxNode {
+                <$base as $crate::ui::RsxTag>::create_node(props, children, key)
+            }
+
+            fn component_vtable() -> ::core::option::Option<&'static $crate::ui::ComponentVTable> {
+                <$base as $crate::ui::RsxTag>::component_vtable()
+            }
+        }
+    };
+}
+
+#[doc(hidden)]
+#[macro_export]
+macro_rules! __rfgui_styled_presets {
+    ($props:ident,) => {};
+    ($props:ident, $key:ident : { $($inner:tt)* } $(, $($rest:tt)*)?) => {
+        let mut __rfgui_style_target = $crate::ui::__rsx_default_inner_option(&$props.$key);
+        $crate::__rfgui_style_entries!(__rfgui_style_target, element, $($inner)*);
+        $crate::ui::__rsx_preset_style(&mut $props.$key, Some(__rfgui_style_target));
+        $crate::__rfgui_styled_presets!($props, $($($rest)*)?);
+    };
+    ($props:ident, style : $value:expr $(, $($rest:tt)*)?) => {
+        $crate::ui::__rsx_preset_style(
+            &mut $props.style,
+            $crate::ui::IntoOptionalProp::into_optional_prop($value),
+        );
+        $crate::__rfgui_styled_presets!($props, $($($rest)*)?);
+    };
+    ($props:ident, $key:ident : $value:expr $(, $($rest:tt)*)?) => {
+        $crate::ui::__RsxPropPreset::__rsx_preset(&mut $props.$key, $value);
+        $crate::__rfgui_styled_presets!($props, $($($rest)*)?);
+    };
+}
+
 /// `App` trait + supporting types — contract between user code and host
 /// runners. The engine itself never drives an event loop.
 pub mod app;
@@ -104,6 +214,8 @@ pub mod platform;
 /// Typed style system: colors, gradients, layout primitives, transforms,
 /// transitions/animations, and the parsed/computed style trees.
 pub mod style;
+/// Optional `tracing` spans around the frame pipeline (feature `tracing`).
+pub(crate) mod trace;
 pub mod time {
     pub use std::time::Duration;
     #[cfg(not(target_arch = "wasm32"))]
@@ -0,0 +1,35 @@
+//! High-contrast (forced-colors) mode.
+//!
+//! Hosts read the OS setting from their platform (Windows high-contrast
+//! themes, macOS "Increase contrast", the web's `forced-colors` media
+//! feature) and push it into the viewport with
+//! [`crate::view::Viewport::set_system_high_contrast`] at startup and
+//! whenever they notice it may have changed (window focus is a cheap
+//! trigger, since the setting lives in system preferences). Apps can
+//! pin either mode with a [`ContrastPreference`] through
+//! `ViewportControl::set_contrast_preference`; components read the result
+//! with [`crate::ui::use_high_contrast`].
+
+/// App override for the high-contrast mode reported by the host.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Hash)]
+pub enum ContrastPreference {
+    /// Follow the system setting.
+    #[default]
+    System,
+    /// Standard contrast regardless of the system setting.
+    Standard,
+    /// High contrast regardless of the system setting.
+    High,
+}
+
+impl ContrastPreference {
+    /// Whether high contrast is in effect when the system reports
+    /// `system_high_contrast`.
+    pub fn resolve(self, system_high_contrast: bool) -> bool {
+        match self {
+            Self::System => system_high_contrast,
+            Self::Standard => false,
+            Self::High => true,
+        }
+    }
+}
@@ -0,0 +1,300 @@
+//! Platform-neutral application menu and system tray description.
+//!
+//! Apps describe a window/application menu (File / Edit / ...) and an
+//! optional tray icon as plain data and hand it to the viewport
+//! ([`crate::view::Viewport::set_window_menu`],
+//! [`crate::view::Viewport::set_tray_icon`]). The viewport forwards the
+//! description to the host through [`super::PlatformRequests::menu_commands`]
+//! and keeps the callbacks on its side: the host only ever reports back a
+//! [`MenuItemId`] or a [`TrayEvent`], which the viewport routes to the
+//! matching callback inside the rfgui event loop.
+//!
+//! Shortcuts are matched by the viewport itself unless the host reports that
+//! its native menu already owns key equivalents
+//! ([`crate::view::Viewport::set_native_menu_shortcuts`]).
+
+use std::fmt;
+use std::rc::Rc;
+
+use smol_str::SmolStr;
+
+use super::IconImage;
+use super::input::{Key, Modifiers, PlatformKeyEvent};
+use crate::view::viewport::ViewportControl;
+
+/// Stable identifier of a selectable menu entry. Hosts echo it back when
+/// the native item is activated.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct MenuItemId(pub SmolStr);
+
+impl MenuItemId {
+    pub fn new(id: impl Into<SmolStr>) -> Self {
+        Self(id.into())
+    }
+
+    pub fn as_str(&self) -> &str {
+        &self.0
+    }
+}
+
+impl From<&str> for MenuItemId {
+    fn from(id: &str) -> Self {
+        Self::new(id)
+    }
+}
+
+impl From<String> for MenuItemId {
+    fn from(id: String) -> Self {
+        Self::new(id)
+    }
+}
+
+/// Keyboard shortcut bound to a menu entry.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct Accelerator {
+    pub modifiers: Modifiers,
+    pub key: Key,
+}
+
+impl Accelerator {
+    pub fn new(modifiers: Modifiers, key: Key) -> Self {
+        Self { modifiers, key }
+    }
+
+    /// `key` with the platform-canonical shortcut modifier (Cmd on macOS,
+    /// Ctrl elsewhere). See [`Modifiers::command`].
+    pub fn command(key: Key) -> Self {
+        #[cfg(target_os = "macos")]
+        let modifiers = Modifiers::META;
+        #[cfg(not(target_os = "macos"))]
+        let modifiers = Modifiers::CTRL;
+        Self::new(modifiers, key)
+    }
+
+    pub fn with_shift(mut self) -> Self {
+        self.modifiers |= Modifiers::SHIFT;
+        self
+    }
+
+    pub fn with_alt(mut self) -> Self {
+        self.modifiers |= Modifiers::ALT;
+        self
+    }
+
+    /// True for a key press of `key` with exactly `modifiers` held. Lock
+    /// keys are ignored; releases and IME-composing presses never match.
+    pub fn matches(&self, event: &PlatformKeyEvent) -> bool {
+        event.pressed
+            && !event.is_composing
+            && event.key == self.key
+            && event.modifiers.exactly(self.modifiers)
+    }
+}
+
+/// Callback run when a menu entry is activated, either from the host menu
+/// or through its accelerator.
+pub type MenuCallback = Rc<dyn Fn(&mut ViewportControl<'_>)>;
+
+/// Selectable menu entry.
+#[derive(Clone)]
+pub struct MenuAction {
+    pub id: MenuItemId,
+    pub label: String,
+    pub accelerator: Option<Accelerator>,
+    pub enabled: bool,
+    /// `Some` renders the entry as a check item in that state.
+    pub checked: Option<bool>,
+    on_select: Option<MenuCallback>,
+}
+
+impl MenuAction {
+    pub fn new(id: impl Into<MenuItemId>, label: impl Into<String>) -> Self {
+        Self {
+            id: id.into(),
+            label: label.into(),
+            accelerator: None,
+            enabled: true,
+            checked: None,
+            on_select: None,
+        }
+    }
+
+    pub fn accelerator(mut self, accelerator: Accelerator) -> Self {
+        self.accelerator = Some(accelerator);
+        self
+    }
+
+    pub fn enabled(mut self, enabled: bool) -> Self {
+        self.enabled = enabled;
+        self
+    }
+
+    pub fn checked(mut self, checked: bool) -> Self {
+        self.checked = Some(checked);
+        self
+    }
+
+    pub fn on_select<F>(mut self, callback: F) -> Self
+    where
+        F: Fn(&mut ViewportControl<'_>) + 'static,
+    {
+        self.on_select = Some(Rc::new(callback));
+        self
+    }
+
+    pub fn callback(&self) -> Option<&MenuCallback> {
+        self.on_select.as_ref()
+    }
+}
+
+impl fmt::Debug for MenuAction {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("MenuAction")
+            .field("id", &self.id)
+            .field("label", &self.label)
+            .field("accelerator", &self.accelerator)
+            .field("enabled", &self.enabled)
+            .field("checked", &self.checked)
+            .field("on_select", &self.on_select.is_some())
+            .finish()
+    }
+}
+
+#[derive(Debug, Clone)]
+pub enum MenuItem {
+    Action(MenuAction),
+    Submenu(Menu),
+    Separator,
+}
+
+/// Labelled list of menu entries. The root menu's label is ignored by hosts
+/// that show a menu bar; its submenus become the top-level titles.
+#[derive(Debug, Clone, Default)]
+pub struct Menu {
+    pub label: String,
+    pub items: Vec<MenuItem>,
+}
+
+impl Menu {
+    pub fn new(label: impl Into<String>) -> Self {
+        Self {
+            label: label.into(),
+            items: Vec::new(),
+        }
+    }
+
+    pub fn action(mut self, action: MenuAction) -> Self {
+        self.items.push(MenuItem::Action(action));
+        self
+    }
+
+    pub fn submenu(mut self, menu: Menu) -> Self {
+        self.items.push(MenuItem::Submenu(menu));
+        self
+    }
+
+    pub fn separator(mut self) -> Self {
+        self.items.push(MenuItem::Separator);
+        self
+    }
+
+    /// Find the action with `id` anywhere in this menu tree.
+    pub fn find(&self, id: &MenuItemId) -> Option<&MenuAction> {
+        self.items.iter().find_map(|item| match item {
+            MenuItem::Action(action) if &action.id == id => Some(action),
+            MenuItem::Action(_) | MenuItem::Separator => None,
+            MenuItem::Submenu(menu) => menu.find(id),
+        })
+    }
+
+    /// First enabled action whose accelerator matches `event`, depth-first
+    /// in declaration order.
+    pub fn find_accelerator(&self, event: &PlatformKeyEvent) -> Option<&MenuAction> {
+        self.items.iter().find_map(|item| match item {
+            MenuItem::Action(action)
+                if action.enabled
+                    && action
+                        .accelerator
+                        .is_some_and(|accelerator| accelerator.matches(event)) =>
+            {
+                Some(action)
+            }
+            MenuItem::Action(_) | MenuItem::Separator => None,
+            MenuItem::Submenu(menu) => menu.find_accelerator(event),
+        })
+    }
+}
+
+/// Pointer interaction with the tray icon itself (menu entries report
+/// through [`MenuItemId`] instead).
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum TrayEvent {
+    Click,
+    DoubleClick,
+    RightClick,
+}
+
+/// Callback run for [`TrayEvent`]s.
+pub type TrayCallback = Rc<dyn Fn(TrayEvent, &mut ViewportControl<'_>)>;
+
+/// System tray / status-bar icon with an optional context menu.
+#[derive(Clone, Default)]
+pub struct TrayIcon {
+    pub tooltip: String,
+    pub icon: Option<IconImage>,
+    pub menu: Option<Menu>,
+    on_event: Option<TrayCallback>,
+}
+
+impl TrayIcon {
+    pub fn new(tooltip: impl Into<String>) -> Self {
+        Self {
+            tooltip: tooltip.into(),
+            ..Self::default()
+        }
+    }
+
+    pub fn icon(mut self, icon: IconImage) -> Self {
+        self.icon = Some(icon);
+        self
+    }
+
+    pub fn menu(mut self, menu: Menu) -> Self {
+        self.menu = Some(menu);
+        self
+    }
+
+    pub fn on_event<F>(mut self, callback: F) -> Self
+    where
+        F: Fn(TrayEvent, &mut ViewportControl<'_>) + 'static,
+    {
+        self.on_event = Some(Rc::new(callback));
+        self
+    }
+
+    pub fn callback(&self) -> Option<&TrayCallback> {
+        self.on_event.as_ref()
+    }
+}
+
+impl fmt::Debug for TrayIcon {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("TrayIcon")
+            .field("tooltip", &self.tooltip)
+            .field("icon", &self.icon)
+            .field("menu", &self.menu)
+            .field("on_event", &self.on_event.is_some())
+            .finish()
+    }
+}
+
+/// Menu / tray change the host should mirror natively. `None` removes the
+/// menu or icon.
+#[derive(Debug, Clone)]
+pub enum MenuCommand {
+    SetWindowMenu(Option<Menu>),
+    SetTrayIcon(Option<TrayIcon>),
+}
+
+#[cfg(test)]
+mod tests;
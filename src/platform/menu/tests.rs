@@ -0,0 +1,65 @@
+use super::*;
+use crate::time::Instant;
+
+fn key_down(key: Key, modifiers: Modifiers) -> PlatformKeyEvent {
+    PlatformKeyEvent {
+        key,
+        characters: None,
+        modifiers,
+        repeat: false,
+        is_composing: false,
+        pressed: true,
+        timestamp: Instant::now(),
+    }
+}
+
+fn file_menu() -> Menu {
+    Menu::new("").submenu(
+        Menu::new("File")
+            .action(
+                MenuAction::new("file.save", "Save")
+                    .accelerator(Accelerator::new(Modifiers::CTRL, Key::KeyS)),
+            )
+            .separator()
+            .action(
+                MenuAction::new("file.export", "Export")
+                    .accelerator(Accelerator::new(Modifiers::CTRL, Key::KeyE))
+                    .enabled(false),
+            ),
+    )
+}
+
+#[test]
+fn find_walks_submenus() {
+    let menu = file_menu();
+    assert_eq!(
+        menu.find(&"file.export".into())
+            .map(|action| action.label.as_str()),
+        Some("Export")
+    );
+    assert!(menu.find(&"file.missing".into()).is_none());
+}
+
+#[test]
+fn accelerator_requires_exact_modifiers_and_enabled_action() {
+    let menu = file_menu();
+    let save = key_down(Key::KeyS, Modifiers::CTRL | Modifiers::CAPS_LOCK);
+    assert_eq!(
+        menu.find_accelerator(&save)
+            .map(|action| action.id.as_str()),
+        Some("file.save"),
+        "lock keys are ignored",
+    );
+    assert!(
+        menu.find_accelerator(&key_down(Key::KeyS, Modifiers::CTRL | Modifiers::SHIFT))
+            .is_none()
+    );
+    assert!(
+        menu.find_accelerator(&key_down(Key::KeyE, Modifiers::CTRL))
+            .is_none(),
+        "disabled actions do not claim their shortcut",
+    );
+    let mut composing = save.clone();
+    composing.is_composing = true;
+    assert!(menu.find_accelerator(&composing).is_none());
+}
@@ -21,8 +21,13 @@ use crate::style::Cursor;
 use wgpu::rwh::{HasDisplayHandle, HasWindowHandle};
 
 pub mod callback;
+pub mod contrast;
 pub mod headless;
 pub mod input;
+pub mod menu;
+pub mod monitor;
+pub mod notification;
+pub mod sound;
 pub mod word_segmenter;
 
 #[cfg(target_arch = "wasm32")]
@@ -35,12 +40,23 @@ pub mod desktop_backend;
 pub mod web_backend;
 
 pub use callback::{CallbackCursorSink, CallbackRedrawRequester};
+pub use contrast::ContrastPreference;
 pub use headless::{HeadlessBackend, NullClipboard, NullCursorSink, NullRedrawRequester};
 pub use input::{
     Key, Modifiers, PlatformImePreedit, PlatformInputType, PlatformKeyEvent, PlatformPointerButton,
     PlatformPointerEvent, PlatformPointerEventKind, PlatformPreeditAttribute, PlatformPreeditStyle,
     PlatformTextInput, PlatformWheelEvent, PointerType, WheelDeltaMode, WheelPhase,
 };
+pub use menu::{
+    Accelerator, Menu, MenuAction, MenuCallback, MenuCommand, MenuItem, MenuItemId, TrayCallback,
+    TrayEvent, TrayIcon,
+};
+pub use monitor::MonitorInfo;
+pub use notification::{
+    AppBadge, Notification, NotificationAction, NotificationCallback, NotificationCommand,
+    NotificationEvent, NotificationId,
+};
+pub use sound::{SoundCommand, SoundSource};
 pub use word_segmenter::{
     GraphemeSegmenter, LineSegmenter, TextSegmenter, WordSegmenter, next_word_boundary,
     prev_word_boundary, word_segmenter,
@@ -94,6 +110,15 @@ pub struct PlatformServices<'a> {
     pub redraw: &'a dyn RedrawRequester,
 }
 
+/// RGBA8 icon bitmap, row-major, `width * height * 4` bytes. Used for the
+/// window icon and the tray icon.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct IconImage {
+    pub rgba: Vec<u8>,
+    pub width: u32,
+    pub height: u32,
+}
+
 /// Host window lifecycle action requested by a handler.
 #[derive(Debug, Clone, PartialEq, Eq)]
 pub enum WindowCommand {
@@ -105,10 +130,34 @@ pub enum WindowCommand {
     Maximize,
     /// Undo minimize / maximize.
     Restore,
+    /// Maximize, or restore when already maximized.
+    ToggleMaximize,
+    /// Start an OS-driven window move from the current pointer press.
+    /// Issued when the primary button goes down over an
+    /// [`crate::style::AppRegion::Drag`] area; the platform keeps snapping
+    /// and edge-tiling behavior of a native title bar.
+    DragWindow,
     /// Enter (true) or exit (false) fullscreen.
     SetFullscreen(bool),
     /// Replace the host window title.
     SetTitle(String),
+    /// Replace (or clear with `None`) the window icon.
+    SetIcon(Option<IconImage>),
+    /// Allow or forbid user resizing.
+    SetResizable(bool),
+    /// Minimum inner size in logical pixels; `None` lifts the limit.
+    SetMinSize(Option<(u32, u32)>),
+    /// Maximum inner size in logical pixels; `None` lifts the limit.
+    SetMaxSize(Option<(u32, u32)>),
+    /// Keep the window above normal windows.
+    SetAlwaysOnTop(bool),
+    /// Let the desktop show through transparent pixels. The viewport
+    /// switches its surface alpha mode alongside this request.
+    SetTransparent(bool),
+    /// Move the window's outer top-left corner, in physical desktop pixels.
+    /// Pair with [`MonitorInfo::clamp_window_position`] when restoring a
+    /// saved position.
+    SetPosition(i32, i32),
 }
 
 /// IME control action.
@@ -160,6 +209,16 @@ pub struct PlatformRequests {
     /// [`crate::ui::PasteEvent`]. Coalesced to a single request per
     /// frame — duplicates are idempotent.
     pub request_paste: bool,
+    /// Window menu / tray icon changes in request order. Hosts without a
+    /// native menu API may ignore them; shortcuts keep working through the
+    /// viewport's own accelerator matching.
+    pub menu_commands: Vec<MenuCommand>,
+    /// Notifications and badge changes in request order. Host-implemented:
+    /// rfgui only queues them, see [`notification`] for what a host must do.
+    pub notification_commands: Vec<NotificationCommand>,
+    /// Sound registrations and plays in request order. Host-implemented:
+    /// rfgui only queues them, see [`sound`] for what a host must do.
+    pub sound_commands: Vec<SoundCommand>,
 }
 
 impl PlatformRequests {
@@ -171,6 +230,9 @@ impl PlatformRequests {
             && self.ime_commands.is_empty()
             && self.pending_drags.is_empty()
             && !self.request_paste
+            && self.menu_commands.is_empty()
+            && self.notification_commands.is_empty()
+            && self.sound_commands.is_empty()
     }
 }
 
@@ -0,0 +1,69 @@
+//! Platform-neutral monitor description.
+//!
+//! Hosts enumerate their displays and push them into the viewport with
+//! [`crate::view::Viewport::set_monitors`] at startup and whenever the window
+//! moves between screens or a scale factor changes. Apps read them back via
+//! `ViewportControl::monitors` / `current_monitor` to restore window
+//! positions, size animation budgets to the refresh rate, and keep popups on
+//! the right screen.
+
+use crate::time::Duration;
+
+/// One display as reported by the host. Bounds are in physical pixels in
+/// the desktop coordinate space shared by all monitors.
+#[derive(Debug, Clone, PartialEq)]
+pub struct MonitorInfo {
+    pub name: Option<String>,
+    pub position: (i32, i32),
+    pub size: (u32, u32),
+    pub scale_factor: f32,
+    /// Refresh rate in millihertz (60 Hz = 60_000), when the host knows it.
+    pub refresh_rate_millihertz: Option<u32>,
+    pub is_primary: bool,
+}
+
+impl MonitorInfo {
+    /// Bounds in logical pixels `(x, y, width, height)`.
+    pub fn logical_bounds(&self) -> (f32, f32, f32, f32) {
+        let scale = self.scale_factor.max(0.0001);
+        (
+            self.position.0 as f32 / scale,
+            self.position.1 as f32 / scale,
+            self.size.0 as f32 / scale,
+            self.size.1 as f32 / scale,
+        )
+    }
+
+    /// True when the physical desktop point lies on this monitor.
+    pub fn contains(&self, x: i32, y: i32) -> bool {
+        let (left, top) = self.position;
+        x >= left
+            && y >= top
+            && i64::from(x) < i64::from(left) + i64::from(self.size.0)
+            && i64::from(y) < i64::from(top) + i64::from(self.size.1)
+    }
+
+    /// Duration of one refresh, for refresh-rate-aware animation budgets.
+    pub fn frame_interval(&self) -> Option<Duration> {
+        self.refresh_rate_millihertz
+            .filter(|&millihertz| millihertz > 0)
+            .map(|millihertz| Duration::from_secs_f64(1000.0 / f64::from(millihertz)))
+    }
+
+    /// Move a window of physical `size` at `position` so it lies on this
+    /// monitor, keeping as much of the requested placement as fits. Used to
+    /// restore saved positions after the monitor layout changed.
+    pub fn clamp_window_position(&self, position: (i32, i32), size: (u32, u32)) -> (i32, i32) {
+        let clamp_axis = |pos: i32, len: u32, origin: i32, extent: u32| {
+            let max = i64::from(origin) + i64::from(extent) - i64::from(len);
+            i64::from(pos).min(max).max(i64::from(origin)) as i32
+        };
+        (
+            clamp_axis(position.0, size.0, self.position.0, self.size.0),
+            clamp_axis(position.1, size.1, self.position.1, self.size.1),
+        )
+    }
+}
+
+#[cfg(test)]
+mod tests;
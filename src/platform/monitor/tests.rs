@@ -0,0 +1,59 @@
+use super::*;
+
+fn monitor() -> MonitorInfo {
+    MonitorInfo {
+        name: Some(String::from("DELL U2720Q")),
+        position: (1920, 0),
+        size: (3840, 2160),
+        scale_factor: 2.0,
+        refresh_rate_millihertz: Some(60_000),
+        is_primary: false,
+    }
+}
+
+#[test]
+fn logical_bounds_divide_by_scale_factor() {
+    assert_eq!(monitor().logical_bounds(), (960.0, 0.0, 1920.0, 1080.0));
+}
+
+#[test]
+fn contains_uses_half_open_physical_bounds() {
+    let monitor = monitor();
+    assert!(monitor.contains(1920, 0));
+    assert!(monitor.contains(5759, 2159));
+    assert!(!monitor.contains(5760, 0));
+    assert!(!monitor.contains(1919, 10));
+}
+
+#[test]
+fn frame_interval_follows_refresh_rate() {
+    let mut monitor = monitor();
+    assert_eq!(
+        monitor.frame_interval(),
+        Some(Duration::from_secs_f64(1.0 / 60.0))
+    );
+    monitor.refresh_rate_millihertz = None;
+    assert_eq!(monitor.frame_interval(), None);
+}
+
+#[test]
+fn clamp_window_position_pulls_windows_back_on_screen() {
+    let monitor = monitor();
+    assert_eq!(
+        monitor.clamp_window_position((0, -40), (800, 600)),
+        (1920, 0)
+    );
+    assert_eq!(
+        monitor.clamp_window_position((5600, 2000), (800, 600)),
+        (4960, 1560)
+    );
+    assert_eq!(
+        monitor.clamp_window_position((2500, 300), (800, 600)),
+        (2500, 300)
+    );
+    assert_eq!(
+        monitor.clamp_window_position((2500, 300), (4000, 600)),
+        (1920, 300),
+        "oversized windows pin to the monitor origin",
+    );
+}
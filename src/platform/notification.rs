@@ -0,0 +1,191 @@
+//! Platform-neutral system notifications and app badge.
+//!
+//! Apps post a [`Notification`] through [`crate::view::Viewport::notify`]
+//! (or the same method on `ViewportControl` and `EventViewport`). As with
+//! menus, the host only mirrors plain data through
+//! [`super::PlatformRequests::notification_commands`] while the callbacks
+//! stay with the viewport: the host reports back the [`NotificationId`] and
+//! a [`NotificationEvent`], which the viewport routes to the matching
+//! callback inside the rfgui event loop
+//! ([`crate::view::Viewport::dispatch_notification_event`]).
+//!
+//! # Host-implemented
+//!
+//! rfgui ships no notification backend. Showing the toast, asking for
+//! permission, setting the badge and reporting clicks and action presses
+//! are all up to the host runner (e.g. `notify-rust` on desktop or the Web
+//! Notifications API in a browser). Until a host does that, no notification
+//! appears and no [`NotificationEvent`] is ever dispatched. The bundled
+//! example runners show notifications through `notify-rust` and the Web
+//! Notifications API, and set the badge where the platform has one.
+
+use std::fmt;
+use std::rc::Rc;
+use std::sync::atomic::{AtomicU64, Ordering};
+
+use super::IconImage;
+use crate::view::viewport::ViewportControl;
+
+/// Identifier of a posted notification. Allocated when the
+/// [`Notification`] is built, so handlers can keep it before the viewport
+/// has seen the notification.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct NotificationId(pub u64);
+
+impl NotificationId {
+    fn next() -> Self {
+        static NEXT_ID: AtomicU64 = AtomicU64::new(1);
+        Self(NEXT_ID.fetch_add(1, Ordering::Relaxed))
+    }
+}
+
+/// Callback run when the user responds to a notification.
+pub type NotificationCallback = Rc<dyn Fn(&mut ViewportControl<'_>)>;
+
+/// Button shown on a notification.
+#[derive(Clone)]
+pub struct NotificationAction {
+    pub label: String,
+    on_select: Option<NotificationCallback>,
+}
+
+impl NotificationAction {
+    pub fn new(label: impl Into<String>) -> Self {
+        Self {
+            label: label.into(),
+            on_select: None,
+        }
+    }
+
+    pub fn on_select<F>(mut self, callback: F) -> Self
+    where
+        F: Fn(&mut ViewportControl<'_>) + 'static,
+    {
+        self.on_select = Some(Rc::new(callback));
+        self
+    }
+
+    pub fn callback(&self) -> Option<&NotificationCallback> {
+        self.on_select.as_ref()
+    }
+}
+
+impl fmt::Debug for NotificationAction {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("NotificationAction")
+            .field("label", &self.label)
+            .field("on_select", &self.on_select.is_some())
+            .finish()
+    }
+}
+
+/// OS-level notification with optional action buttons.
+#[derive(Clone)]
+pub struct Notification {
+    pub id: NotificationId,
+    pub title: String,
+    pub body: String,
+    pub icon: Option<IconImage>,
+    pub actions: Vec<NotificationAction>,
+    on_click: Option<NotificationCallback>,
+    on_dismiss: Option<NotificationCallback>,
+}
+
+impl Notification {
+    pub fn new(title: impl Into<String>, body: impl Into<String>) -> Self {
+        Self {
+            id: NotificationId::next(),
+            title: title.into(),
+            body: body.into(),
+            icon: None,
+            actions: Vec::new(),
+            on_click: None,
+            on_dismiss: None,
+        }
+    }
+
+    pub fn icon(mut self, icon: IconImage) -> Self {
+        self.icon = Some(icon);
+        self
+    }
+
+    pub fn action(mut self, action: NotificationAction) -> Self {
+        self.actions.push(action);
+        self
+    }
+
+    /// Run when the notification body is clicked.
+    pub fn on_click<F>(mut self, callback: F) -> Self
+    where
+        F: Fn(&mut ViewportControl<'_>) + 'static,
+    {
+        self.on_click = Some(Rc::new(callback));
+        self
+    }
+
+    /// Run when the user closes the notification without choosing an
+    /// action.
+    pub fn on_dismiss<F>(mut self, callback: F) -> Self
+    where
+        F: Fn(&mut ViewportControl<'_>) + 'static,
+    {
+        self.on_dismiss = Some(Rc::new(callback));
+        self
+    }
+
+    /// Callback for `event`, if one is set. `None` also for action indices
+    /// out of range.
+    pub fn callback(&self, event: NotificationEvent) -> Option<&NotificationCallback> {
+        match event {
+            NotificationEvent::Click => self.on_click.as_ref(),
+            NotificationEvent::Action(index) => self
+                .actions
+                .get(index)
+                .and_then(NotificationAction::callback),
+            NotificationEvent::Dismiss => self.on_dismiss.as_ref(),
+        }
+    }
+}
+
+impl fmt::Debug for Notification {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("Notification")
+            .field("id", &self.id)
+            .field("title", &self.title)
+            .field("body", &self.body)
+            .field("icon", &self.icon)
+            .field("actions", &self.actions)
+            .field("on_click", &self.on_click.is_some())
+            .field("on_dismiss", &self.on_dismiss.is_some())
+            .finish()
+    }
+}
+
+/// User response to a notification, reported by the host. Each response
+/// resolves the notification.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum NotificationEvent {
+    Click,
+    /// Index into [`Notification::actions`].
+    Action(usize),
+    Dismiss,
+}
+
+/// Marker on the taskbar button / dock icon.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum AppBadge {
+    /// Attention dot without a number. Hosts that only show counts may
+    /// render it as `1`.
+    Dot,
+    Count(u32),
+}
+
+/// Notification / badge change the host should apply natively.
+#[derive(Debug, Clone)]
+pub enum NotificationCommand {
+    Post(Notification),
+    /// Remove a notification that is still shown.
+    Withdraw(NotificationId),
+    /// `None` clears the badge.
+    SetBadge(Option<AppBadge>),
+}
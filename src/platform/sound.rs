@@ -0,0 +1,39 @@
+//! Platform-neutral UI sound feedback.
+//!
+//! Apps register named sounds once ([`crate::view::Viewport::register_sound`])
+//! and play them by name from handlers (`EventViewport::play_sound`) or from
+//! any callback through [`crate::ui::use_viewport`]. The viewport owns the
+//! registry, the global mute and the master volume; the host only sees
+//! [`SoundCommand`]s in [`super::PlatformRequests::sound_commands`] and does
+//! the decoding and mixing, so apps need no audio crate of their own.
+//!
+//! # Host-implemented
+//!
+//! rfgui ships no audio output. Decoding, mixing and applying the mute and
+//! master volume are up to the host runner (e.g. `rodio` on desktop or Web
+//! Audio in a browser); until a host does that, theme and per-button sounds
+//! stay silent. The bundled example runners play them through `rodio` and
+//! `HTMLAudioElement`.
+
+use std::sync::Arc;
+
+use smol_str::SmolStr;
+
+/// Encoded audio the host decodes (WAV, OGG, ... — whatever its audio
+/// backend accepts).
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum SoundSource {
+    Bytes(Arc<[u8]>),
+    /// File path on desktop, URL on the web.
+    Path(String),
+}
+
+/// Sound change or playback the host should apply.
+#[derive(Debug, Clone, PartialEq)]
+pub enum SoundCommand {
+    /// Load (or replace) the sound played under `name`.
+    Register { name: SmolStr, source: SoundSource },
+    /// Play `name` once at `volume` in `0.0..=1.0`, master volume already
+    /// applied. Never issued while muted.
+    Play { name: SmolStr, volume: f32 },
+}
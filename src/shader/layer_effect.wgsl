@@ -0,0 +1,140 @@
+@group(0) @binding(0)
+var layer_tex: texture_2d<f32>;
+
+@group(0) @binding(1)
+var layer_sampler: sampler;
+
+// Pixel-for-pixel copy of the target being composited into; only read by
+// the blend-mode entry points.
+@group(0) @binding(2)
+var backdrop_tex: texture_2d<f32>;
+
+// Same size as the layer; its alpha scales the layer when `masked` is set.
+@group(0) @binding(3)
+var mask_tex: texture_2d<f32>;
+
+// Set when the target is an sRGB format: samples then arrive linear, but
+// filters and blend modes are defined on gamma-encoded channels.
+override linear_target: bool = false;
+
+struct VertexOutput {
+    @builtin(position) position: vec4<f32>,
+    @location(0) screen_uv: vec2<f32>,
+    @location(1) alpha: f32,
+    @location(2) masked: f32,
+    @location(3) matrix_r: vec4<f32>,
+    @location(4) matrix_g: vec4<f32>,
+    @location(5) matrix_b: vec4<f32>,
+}
+
+@vertex
+fn vs_main(
+    @location(0) position: vec2<f32>,
+    @location(1) screen_uv: vec2<f32>,
+    @location(2) alpha: f32,
+    @location(3) masked: f32,
+    @location(4) matrix_r: vec4<f32>,
+    @location(5) matrix_g: vec4<f32>,
+    @location(6) matrix_b: vec4<f32>,
+) -> VertexOutput {
+    var out: VertexOutput;
+    out.position = vec4<f32>(position, 0.0, 1.0);
+    out.screen_uv = screen_uv;
+    out.alpha = alpha;
+    out.masked = masked;
+    out.matrix_r = matrix_r;
+    out.matrix_g = matrix_g;
+    out.matrix_b = matrix_b;
+    return out;
+}
+
+fn unpremultiply(color: vec4<f32>) -> vec3<f32> {
+    if color.a <= 0.0 {
+        return vec3<f32>(0.0);
+    }
+    return color.rgb / color.a;
+}
+
+fn encode(linear: vec3<f32>) -> vec3<f32> {
+    if !linear_target {
+        return linear;
+    }
+    let low = linear * 12.92;
+    let high = 1.055 * pow(linear, vec3<f32>(1.0 / 2.4)) - 0.055;
+    return select(high, low, linear <= vec3<f32>(0.0031308));
+}
+
+fn decode(encoded: vec3<f32>) -> vec3<f32> {
+    if !linear_target {
+        return encoded;
+    }
+    let low = encoded / 12.92;
+    let high = pow((encoded + 0.055) / 1.055, vec3<f32>(2.4));
+    return select(high, low, encoded <= vec3<f32>(0.04045));
+}
+
+// Unpremultiplied, gamma-encoded channels of a premultiplied sample.
+fn channels(color: vec4<f32>) -> vec3<f32> {
+    return encode(unpremultiply(color));
+}
+
+// Layer sample with the color matrix applied and opacity and mask folded
+// in, premultiplied.
+fn filtered_source(in: VertexOutput) -> vec4<f32> {
+    let sample_color = textureSample(layer_tex, layer_sampler, in.screen_uv);
+    let rgb = vec4<f32>(channels(sample_color), 1.0);
+    let filtered = clamp(
+        vec3<f32>(dot(in.matrix_r, rgb), dot(in.matrix_g, rgb), dot(in.matrix_b, rgb)),
+        vec3<f32>(0.0),
+        vec3<f32>(1.0),
+    );
+    let mask_alpha = textureSample(mask_tex, layer_sampler, in.screen_uv).a;
+    let coverage = select(1.0, mask_alpha, in.masked > 0.5);
+    let alpha = sample_color.a * clamp(in.alpha, 0.0, 1.0) * coverage;
+    return vec4<f32>(decode(filtered) * alpha, alpha);
+}
+
+fn backdrop(in: VertexOutput) -> vec4<f32> {
+    return textureLoad(backdrop_tex, vec2<i32>(floor(in.position.xy)), 0);
+}
+
+// Separable blend composited source-over, both inputs premultiplied and
+// `blended` gamma-encoded.
+fn composite_blended(source: vec4<f32>, dest: vec4<f32>, blended: vec3<f32>) -> vec4<f32> {
+    let color = source.rgb * (1.0 - dest.a) + dest.rgb * (1.0 - source.a)
+        + source.a * dest.a * decode(clamp(blended, vec3<f32>(0.0), vec3<f32>(1.0)));
+    return vec4<f32>(color, source.a + dest.a * (1.0 - source.a));
+}
+
+@fragment
+fn fs_filter(in: VertexOutput) -> @location(0) vec4<f32> {
+    return filtered_source(in);
+}
+
+@fragment
+fn fs_multiply(in: VertexOutput) -> @location(0) vec4<f32> {
+    let source = filtered_source(in);
+    let dest = backdrop(in);
+    return composite_blended(source, dest, channels(source) * channels(dest));
+}
+
+@fragment
+fn fs_screen(in: VertexOutput) -> @location(0) vec4<f32> {
+    let source = filtered_source(in);
+    let dest = backdrop(in);
+    let cs = channels(source);
+    let cb = channels(dest);
+    return composite_blended(source, dest, cs + cb - cs * cb);
+}
+
+@fragment
+fn fs_overlay(in: VertexOutput) -> @location(0) vec4<f32> {
+    let source = filtered_source(in);
+    let dest = backdrop(in);
+    let cs = channels(source);
+    let cb = channels(dest);
+    let multiplied = 2.0 * cs * cb;
+    let screened = 1.0 - 2.0 * (1.0 - cs) * (1.0 - cb);
+    let blended = select(screened, multiplied, cb <= vec3<f32>(0.5));
+    return composite_blended(source, dest, blended);
+}
@@ -0,0 +1,32 @@
+@group(0) @binding(0)
+var source_tex: texture_2d<f32>;
+
+@group(0) @binding(1)
+var tex_sampler: sampler;
+
+struct VertexOutput {
+    @builtin(position) position: vec4<f32>,
+    @location(0) uv: vec2<f32>,
+    @location(1) opacity: f32,
+}
+
+@vertex
+fn vs_main(
+    @location(0) position: vec2<f32>,
+    @location(1) uv: vec2<f32>,
+    @location(2) opacity: f32,
+) -> VertexOutput {
+    var out: VertexOutput;
+    out.position = vec4<f32>(position, 0.0, 1.0);
+    out.uv = uv;
+    out.opacity = opacity;
+    return out;
+}
+
+@fragment
+fn fs_main(in: VertexOutput) -> @location(0) vec4<f32> {
+    // Sources are straight alpha; the target blends premultiplied.
+    let color = textureSample(source_tex, tex_sampler, in.uv);
+    let alpha = color.a * clamp(in.opacity, 0.0, 1.0);
+    return vec4<f32>(color.rgb * alpha, alpha);
+}
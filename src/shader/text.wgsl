@@ -1,6 +1,7 @@
 struct ScreenUniform {
     screen_size: vec2<f32>,
-    _pad: vec2<f32>,
+    gamma: f32,
+    contrast: f32,
 }
 
 struct FragmentUniform {
@@ -74,13 +75,23 @@ fn vs_main(@builtin(vertex_index) vertex_index: u32, glyph: GlyphInstance) -> Vs
     return out;
 }
 
+// Gamma plus stem darkening: light text reads thinner than dark text at
+// the same coverage, so its coverage is raised in proportion to luminance.
+// The defaults (gamma 1, contrast 0) leave coverage untouched.
+fn adjust_coverage(coverage: f32, color: vec3<f32>) -> f32 {
+    let luminance = dot(color, vec3<f32>(0.2126, 0.7152, 0.0722));
+    let exponent = 1.0 / (screen.gamma * (1.0 + screen.contrast * luminance));
+    return pow(clamp(coverage, 0.0, 1.0), exponent);
+}
+
 @fragment
 fn fs_mask(in: VsOut) -> @location(0) vec4<f32> {
     if in.pixel.x < in.clip_min.x || in.pixel.y < in.clip_min.y ||
        in.pixel.x > in.clip_max.x || in.pixel.y > in.clip_max.y {
         discard;
     }
-    let coverage = textureSample(glyph_atlas, glyph_sampler, in.uv).r;
+    let texel = textureSample(glyph_atlas, glyph_sampler, in.uv);
+    let coverage = adjust_coverage(texel.r, in.color.rgb);
     let out_alpha = coverage * in.opacity * in.color.a;
     return vec4<f32>(in.color.rgb * out_alpha, out_alpha);
 }
@@ -0,0 +1,27 @@
+// Appended to text.wgsl behind `enable dual_source_blending;`.
+
+struct SubpixelOut {
+    @location(0) @blend_src(0) color: vec4<f32>,
+    @location(0) @blend_src(1) coverage: vec4<f32>,
+}
+
+@fragment
+fn fs_subpixel(in: VsOut) -> SubpixelOut {
+    if in.pixel.x < in.clip_min.x || in.pixel.y < in.clip_min.y ||
+       in.pixel.x > in.clip_max.x || in.pixel.y > in.clip_max.y {
+        discard;
+    }
+    let texel = textureSample(glyph_atlas, glyph_sampler, in.uv);
+    let weight = in.opacity * in.color.a;
+    let coverage = vec3<f32>(
+        adjust_coverage(texel.r, in.color.rgb),
+        adjust_coverage(texel.g, in.color.rgb),
+        adjust_coverage(texel.b, in.color.rgb),
+    ) * weight;
+    let alpha = max(coverage.r, max(coverage.g, coverage.b));
+
+    var out: SubpixelOut;
+    out.color = vec4<f32>(in.color.rgb * coverage, alpha);
+    out.coverage = vec4<f32>(coverage, alpha);
+    return out;
+}
@@ -2,7 +2,7 @@
 
 //! Background value types used by the typed style system.
 
-use crate::style::color::{Color, ColorLike, HexColor, OklchColor};
+use crate::style::color::{Color, ColorLike, HexColor, OklchColor, parse_color_or_transparent};
 use crate::style::gradient::{ConicBuilder, Gradient, LinearBuilder, RadialBuilder};
 
 /// A typed background declaration — accepts either a solid color or a gradient.
@@ -67,7 +67,7 @@ impl<'a> From<HexColor<'a>> for Background {
 
 impl From<&str> for Background {
     fn from(value: &str) -> Self {
-        Color::hex(value).into()
+        Self::Color(Box::new(parse_color_or_transparent(value)))
     }
 }
 
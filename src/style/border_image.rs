@@ -0,0 +1,116 @@
+#![allow(missing_docs)]
+
+//! Border image value types used by the typed style system.
+
+use crate::style::gradient::{ConicBuilder, Gradient, LinearBuilder, RadialBuilder};
+use crate::view::ImageSource;
+
+/// A typed `border_image` declaration — either a gradient painted into the
+/// border ring or an image sliced into a [`NinePatch`].
+#[derive(Clone, Debug, PartialEq)]
+pub enum BorderImage {
+    Gradient(Gradient),
+    NinePatch(NinePatch),
+}
+
+/// How the edges and center of a [`NinePatch`] fill the space between its
+/// corners.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Hash)]
+pub enum NinePatchRepeat {
+    /// Scale the slice to cover the whole span.
+    #[default]
+    Stretch,
+    /// Tile the slice at its natural scale, centered, clipping the ends.
+    Repeat,
+    /// Tile the slice, rescaled so a whole number of tiles fits.
+    Round,
+}
+
+/// An image cut into a 3x3 grid by four insets. Corners are drawn at their
+/// border width, edges and the (optional) center fill the rest according to
+/// [`NinePatchRepeat`].
+#[derive(Clone, Debug, PartialEq)]
+pub struct NinePatch {
+    pub source: ImageSource,
+    /// Insets into the source image in image pixels: `[top, right, bottom, left]`.
+    pub slice: [f32; 4],
+    /// Drawn size of each side in logical pixels; `None` draws the slices
+    /// at one logical pixel per image pixel.
+    pub width: Option<[f32; 4]>,
+    pub repeat: NinePatchRepeat,
+    /// Whether the center slice is drawn.
+    pub fill: bool,
+}
+
+impl NinePatch {
+    /// Slices `source` by the same `inset` on every side.
+    pub fn new(source: ImageSource, inset: f32) -> Self {
+        Self {
+            source,
+            slice: [inset.max(0.0); 4],
+            width: None,
+            repeat: NinePatchRepeat::Stretch,
+            fill: true,
+        }
+    }
+
+    pub fn slice(mut self, top: f32, right: f32, bottom: f32, left: f32) -> Self {
+        self.slice = [top, right, bottom, left].map(|value| value.max(0.0));
+        self
+    }
+
+    pub fn width(mut self, value: f32) -> Self {
+        self.width = Some([value.max(0.0); 4]);
+        self
+    }
+
+    pub fn width_sides(mut self, top: f32, right: f32, bottom: f32, left: f32) -> Self {
+        self.width = Some([top, right, bottom, left].map(|value| value.max(0.0)));
+        self
+    }
+
+    pub fn repeat(mut self, repeat: NinePatchRepeat) -> Self {
+        self.repeat = repeat;
+        self
+    }
+
+    pub fn fill(mut self, fill: bool) -> Self {
+        self.fill = fill;
+        self
+    }
+
+    /// Drawn side widths, `[top, right, bottom, left]`.
+    pub fn resolved_width(&self) -> [f32; 4] {
+        self.width.unwrap_or(self.slice)
+    }
+}
+
+impl From<Gradient> for BorderImage {
+    fn from(value: Gradient) -> Self {
+        Self::Gradient(value)
+    }
+}
+
+impl From<LinearBuilder> for BorderImage {
+    fn from(value: LinearBuilder) -> Self {
+        Self::Gradient(value.build())
+    }
+}
+
+impl From<RadialBuilder> for BorderImage {
+    fn from(value: RadialBuilder) -> Self {
+        Self::Gradient(value.build())
+    }
+}
+
+impl From<ConicBuilder> for BorderImage {
+    fn from(value: ConicBuilder) -> Self {
+        Self::Gradient(value.build())
+    }
+}
+
+impl From<NinePatch> for BorderImage {
+    fn from(value: NinePatch) -> Self {
+        Self::NinePatch(value)
+    }
+}
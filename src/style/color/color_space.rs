@@ -0,0 +1,83 @@
+#![allow(missing_docs)]
+
+//! Source color spaces and the single conversion from a color's own linear
+//! values into what the renderer writes to the surface.
+//!
+//! Colors stay in their authored primaries until they are drawn. The
+//! renderer always works in linear sRGB; on a standard surface that is
+//! clamped to the sRGB gamut, while an extended-range (scRGB) surface keeps
+//! values below `0.0` and above `1.0`, which is how Display-P3 and other
+//! wide-gamut colors reach the display without clipping.
+
+use std::cell::Cell;
+
+/// Primaries of the linear RGBA a [`ColorLike`](super::ColorLike) reports.
+/// Both spaces share the D65 white point and the sRGB transfer function.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+pub enum ColorSpace {
+    #[default]
+    Srgb,
+    DisplayP3,
+}
+
+/// How the current surface interprets the linear values the renderer writes.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+pub enum OutputColorSpace {
+    /// Standard surface: linear sRGB clamped to `0.0..=1.0`.
+    #[default]
+    Srgb,
+    /// `Rgba16Float` surface in extended linear sRGB (scRGB); out-of-gamut
+    /// components are kept so wide-gamut displays can show them.
+    ExtendedSrgbLinear,
+}
+
+thread_local! {
+    static OUTPUT_COLOR_SPACE: Cell<OutputColorSpace> = const { Cell::new(OutputColorSpace::Srgb) };
+}
+
+/// The output space of the viewport currently rendering on this thread.
+pub fn output_color_space() -> OutputColorSpace {
+    OUTPUT_COLOR_SPACE.with(Cell::get)
+}
+
+pub(crate) fn set_output_color_space(space: OutputColorSpace) {
+    OUTPUT_COLOR_SPACE.with(|current| current.set(space));
+}
+
+// Linear-light primary conversions, D65 on both sides.
+const SRGB_TO_DISPLAY_P3: [[f32; 3]; 3] = [
+    [0.822_462_1, 0.177_538, 0.0],
+    [0.033_194_2, 0.966_805_8, 0.0],
+    [0.017_082_7, 0.072_397_4, 0.910_519_9],
+];
+const DISPLAY_P3_TO_SRGB: [[f32; 3]; 3] = [
+    [1.224_940_1, -0.224_940_4, 0.0],
+    [-0.042_056_9, 1.042_057_1, 0.0],
+    [-0.019_637_6, -0.078_636_1, 1.098_273_5],
+];
+
+/// Re-express linear RGBA from `from` primaries in `to` primaries. The result
+/// is not clamped; components outside `0.0..=1.0` mean the color lies
+/// outside the target gamut.
+pub fn convert_linear_rgba(rgba: [f32; 4], from: ColorSpace, to: ColorSpace) -> [f32; 4] {
+    let matrix = match (from, to) {
+        (ColorSpace::Srgb, ColorSpace::DisplayP3) => &SRGB_TO_DISPLAY_P3,
+        (ColorSpace::DisplayP3, ColorSpace::Srgb) => &DISPLAY_P3_TO_SRGB,
+        _ => return rgba,
+    };
+    let [r, g, b, a] = rgba;
+    let row = |m: [f32; 3]| m[0] * r + m[1] * g + m[2] * b;
+    [row(matrix[0]), row(matrix[1]), row(matrix[2]), a]
+}
+
+/// Map linear sRGB into the current [`output_color_space`].
+pub(crate) fn linear_srgb_to_output(rgba: [f32; 4]) -> [f32; 4] {
+    let [r, g, b, a] = rgba;
+    match output_color_space() {
+        OutputColorSpace::Srgb => [r.clamp(0.0, 1.0), g.clamp(0.0, 1.0), b.clamp(0.0, 1.0), a],
+        OutputColorSpace::ExtendedSrgbLinear => rgba,
+    }
+}
+
+#[cfg(test)]
+mod tests;
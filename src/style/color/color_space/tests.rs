@@ -0,0 +1,42 @@
+use super::{
+    ColorSpace, OutputColorSpace, convert_linear_rgba, output_color_space, set_output_color_space,
+};
+use crate::style::{Color, ColorLike, DisplayP3Color, StyleColor};
+
+#[test]
+fn primaries_round_trip_and_p3_red_leaves_srgb_gamut() {
+    let rgba = [0.2, 0.5, 0.8, 0.6];
+    let p3 = convert_linear_rgba(rgba, ColorSpace::Srgb, ColorSpace::DisplayP3);
+    let back = convert_linear_rgba(p3, ColorSpace::DisplayP3, ColorSpace::Srgb);
+    for (a, b) in rgba.iter().zip(back) {
+        assert!((a - b).abs() < 1e-4, "{rgba:?} -> {back:?}");
+    }
+
+    let [r, g, b, _] = DisplayP3Color::new(1.0, 0.0, 0.0, 1.0).to_linear_srgb_f32();
+    assert!(r > 1.0 && g < 0.0 && b < 0.0, "P3 red is outside sRGB");
+    let white = DisplayP3Color::new(1.0, 1.0, 1.0, 1.0).to_linear_srgb_f32();
+    assert!(
+        white.iter().all(|c| (c - 1.0).abs() < 1e-4),
+        "shared white point"
+    );
+}
+
+#[test]
+fn output_space_decides_whether_wide_gamut_colors_clip() {
+    let p3_red = StyleColor::from(DisplayP3Color::new(1.0, 0.0, 0.0, 1.0));
+    assert_eq!(output_color_space(), OutputColorSpace::Srgb);
+    assert_eq!(p3_red.to_output_rgba_f32(), [1.0, 0.0, 0.0, 1.0]);
+    assert_eq!(p3_red.to_rgba_u8(), [255, 0, 0, 255]);
+
+    set_output_color_space(OutputColorSpace::ExtendedSrgbLinear);
+    let [r, g, _, _] = p3_red.to_output_rgba_f32();
+    let srgb = Color::rgb(10, 200, 30);
+    let srgb_output = srgb.to_output_rgba_f32();
+    set_output_color_space(OutputColorSpace::Srgb);
+    assert!(r > 1.0 && g < 0.0);
+    assert_eq!(
+        srgb_output,
+        srgb.to_rgba_f32(),
+        "sRGB colors are unaffected"
+    );
+}
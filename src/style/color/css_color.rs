@@ -0,0 +1,714 @@
+#![allow(missing_docs)]
+
+//! CSS color strings: named colors, hex, and the `rgb()`, `hsl()`,
+//! `oklch()` and `color(display-p3 ...)` functional notations.
+//!
+//! The parser itself is a `const fn` so `rsx!` can reject bad literals in
+//! style objects while compiling; runtime string conversions go through the
+//! same code and report the same errors.
+
+use crate::style::color::{Color, DisplayP3Color, OklchColor, StyleColor};
+use std::fmt;
+use std::str::FromStr;
+
+/// Why a color string was rejected.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ColorParseError {
+    Empty,
+    InvalidHex,
+    UnknownName,
+    UnknownFunction,
+    UnclosedFunction,
+    ArgumentCount,
+    MixedSeparators,
+    InvalidNumber,
+    InvalidUnit,
+}
+
+impl ColorParseError {
+    pub const fn message(self) -> &'static str {
+        match self {
+            Self::Empty => "color string is empty",
+            Self::InvalidHex => "hex colors must be `#rgb`, `#rgba`, `#rrggbb` or `#rrggbbaa`",
+            Self::UnknownName => "unknown color name",
+            Self::UnknownFunction => {
+                "expected `rgb()`, `rgba()`, `hsl()`, `hsla()`, `oklch()` or `color(display-p3 ...)`"
+            }
+            Self::UnclosedFunction => "color function is missing its closing `)`",
+            Self::ArgumentCount => "color functions take three components plus an optional alpha",
+            Self::MixedSeparators => {
+                "color function arguments must be all comma-separated or all space-separated"
+            }
+            Self::InvalidNumber => "color component is not a number",
+            Self::InvalidUnit => "color component has a unit that is not valid in this position",
+        }
+    }
+}
+
+impl fmt::Display for ColorParseError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(self.message())
+    }
+}
+
+impl std::error::Error for ColorParseError {}
+
+/// Parse any supported CSS color string. `oklch()` keeps its OKLCH
+/// coordinates; every other form resolves to sRGB.
+pub fn parse_color(input: &str) -> Result<StyleColor, ColorParseError> {
+    Ok(match parse_css_color(input.as_bytes())? {
+        CssColor::Rgba([r, g, b, a]) => StyleColor::Srgb(Color::rgba(
+            unit_to_u8(r),
+            unit_to_u8(g),
+            unit_to_u8(b),
+            unit_to_u8(a),
+        )),
+        CssColor::Hsla([h, s, l, a]) => StyleColor::Srgb(Color::from_hsla(h, s, l, a)),
+        CssColor::Oklch([l, c, h, a]) => StyleColor::Oklch(OklchColor::new(l, c, h, a)),
+        CssColor::DisplayP3([r, g, b, a]) => StyleColor::DisplayP3(DisplayP3Color::new(r, g, b, a)),
+    })
+}
+
+/// String conversions that cannot return an error log the reason and fall
+/// back to transparent, which is what an invalid hex literal always produced.
+pub(crate) fn parse_color_or_transparent(input: &str) -> StyleColor {
+    match parse_color(input) {
+        Ok(color) => color,
+        Err(error) => {
+            eprintln!("[Color] invalid color {input:?}: {error}");
+            StyleColor::Srgb(Color::transparent())
+        }
+    }
+}
+
+impl FromStr for StyleColor {
+    type Err = ColorParseError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        parse_color(s)
+    }
+}
+
+impl FromStr for Color {
+    type Err = ColorParseError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        parse_color(s).map(|color| color.to_color())
+    }
+}
+
+/// Compile-time check emitted by `rsx!` for string literals under color keys.
+#[doc(hidden)]
+pub const fn __rsx_assert_css_color(input: &str) {
+    if let Err(error) = parse_css_color(input.as_bytes()) {
+        panic!("{}", error.message());
+    }
+}
+
+enum CssColor {
+    /// sRGB channels and alpha in `0.0..=1.0`.
+    Rgba([f32; 4]),
+    /// Hue in degrees; saturation, lightness and alpha in `0.0..=1.0`.
+    Hsla([f32; 4]),
+    /// Lightness and alpha in `0.0..=1.0`, chroma unbounded, hue in degrees.
+    Oklch([f32; 4]),
+    /// Encoded Display-P3 channels and alpha in `0.0..=1.0`.
+    DisplayP3([f32; 4]),
+}
+
+#[derive(Clone, Copy, PartialEq, Eq)]
+enum Function {
+    Rgb,
+    Hsl,
+    Oklch,
+    DisplayP3,
+}
+
+#[derive(Clone, Copy, PartialEq, Eq)]
+enum Unit {
+    None,
+    Percent,
+    Deg,
+    Rad,
+    Grad,
+    Turn,
+}
+
+#[derive(Clone, Copy, PartialEq, Eq)]
+enum Separator {
+    Unknown,
+    Comma,
+    Space,
+}
+
+const fn parse_css_color(bytes: &[u8]) -> Result<CssColor, ColorParseError> {
+    let mut start = 0;
+    let mut end = bytes.len();
+    while start < end && bytes[start].is_ascii_whitespace() {
+        start += 1;
+    }
+    while end > start && bytes[end - 1].is_ascii_whitespace() {
+        end -= 1;
+    }
+    if start == end {
+        return Err(ColorParseError::Empty);
+    }
+    if bytes[start] == b'#' {
+        return parse_hex(bytes, start + 1, end);
+    }
+
+    let mut name_end = start;
+    while name_end < end && bytes[name_end].is_ascii_alphabetic() {
+        name_end += 1;
+    }
+    if name_end == end {
+        return parse_named(bytes, start, end);
+    }
+    if bytes[name_end] != b'(' {
+        return Err(ColorParseError::UnknownName);
+    }
+    let function = if eq_ignore_case(bytes, start, name_end, "rgb")
+        || eq_ignore_case(bytes, start, name_end, "rgba")
+    {
+        Function::Rgb
+    } else if eq_ignore_case(bytes, start, name_end, "hsl")
+        || eq_ignore_case(bytes, start, name_end, "hsla")
+    {
+        Function::Hsl
+    } else if eq_ignore_case(bytes, start, name_end, "oklch") {
+        Function::Oklch
+    } else if eq_ignore_case(bytes, start, name_end, "color") {
+        Function::DisplayP3
+    } else {
+        return Err(ColorParseError::UnknownFunction);
+    };
+    if bytes[end - 1] != b')' {
+        return Err(ColorParseError::UnclosedFunction);
+    }
+    let mut args_start = name_end + 1;
+    if matches!(function, Function::DisplayP3) {
+        // `color()` names its space first; only Display-P3 is supported.
+        let space_start = skip_whitespace(bytes, args_start, end - 1);
+        let mut space_end = space_start;
+        while space_end < end - 1
+            && (bytes[space_end].is_ascii_alphanumeric() || bytes[space_end] == b'-')
+        {
+            space_end += 1;
+        }
+        if !eq_ignore_case(bytes, space_start, space_end, "display-p3") {
+            return Err(ColorParseError::UnknownFunction);
+        }
+        args_start = space_end;
+    }
+    parse_function(function, bytes, args_start, end - 1)
+}
+
+const fn parse_hex(bytes: &[u8], start: usize, end: usize) -> Result<CssColor, ColorParseError> {
+    let len = end - start;
+    if len != 3 && len != 4 && len != 6 && len != 8 {
+        return Err(ColorParseError::InvalidHex);
+    }
+    let mut digits = [0u8; 8];
+    let mut i = 0;
+    while i < len {
+        digits[i] = match hex_digit(bytes[start + i]) {
+            Some(digit) => digit,
+            None => return Err(ColorParseError::InvalidHex),
+        };
+        i += 1;
+    }
+    let mut rgba = [255u8; 4];
+    let short = len <= 4;
+    let channels = if short { len } else { len / 2 };
+    let mut channel = 0;
+    while channel < channels {
+        rgba[channel] = if short {
+            digits[channel] * 17
+        } else {
+            digits[channel * 2] * 16 + digits[channel * 2 + 1]
+        };
+        channel += 1;
+    }
+    Ok(rgba_u8(rgba))
+}
+
+const fn parse_named(bytes: &[u8], start: usize, end: usize) -> Result<CssColor, ColorParseError> {
+    if eq_ignore_case(bytes, start, end, "transparent") {
+        return Ok(CssColor::Rgba([0.0; 4]));
+    }
+    let mut i = 0;
+    while i < NAMED_COLORS.len() {
+        let (name, rgb) = NAMED_COLORS[i];
+        if eq_ignore_case(bytes, start, end, name) {
+            return Ok(rgba_u8([
+                (rgb >> 16) as u8,
+                (rgb >> 8) as u8,
+                rgb as u8,
+                255,
+            ]));
+        }
+        i += 1;
+    }
+    Err(ColorParseError::UnknownName)
+}
+
+const fn parse_function(
+    function: Function,
+    bytes: &[u8],
+    start: usize,
+    end: usize,
+) -> Result<CssColor, ColorParseError> {
+    let mut values = [0.0f32; 4];
+    let mut units = [Unit::None; 4];
+    let mut count = 0;
+    let mut separator = Separator::Unknown;
+    let mut slash = false;
+    let mut i = start;
+    loop {
+        let before_space = i;
+        i = skip_whitespace(bytes, i, end);
+        if i == end {
+            break;
+        }
+        if count > 0 {
+            if bytes[i] == b',' {
+                if matches!(separator, Separator::Space) || slash {
+                    return Err(ColorParseError::MixedSeparators);
+                }
+                separator = Separator::Comma;
+                i = skip_whitespace(bytes, i + 1, end);
+            } else if bytes[i] == b'/' {
+                if matches!(separator, Separator::Comma) {
+                    return Err(ColorParseError::MixedSeparators);
+                }
+                if count != 3 {
+                    return Err(ColorParseError::ArgumentCount);
+                }
+                slash = true;
+                i = skip_whitespace(bytes, i + 1, end);
+            } else if i == before_space {
+                return Err(ColorParseError::InvalidNumber);
+            } else {
+                if matches!(separator, Separator::Comma) {
+                    return Err(ColorParseError::MixedSeparators);
+                }
+                if count == 3 {
+                    return Err(ColorParseError::ArgumentCount);
+                }
+                separator = Separator::Space;
+            }
+        }
+        if count == 4 || i == end {
+            return Err(ColorParseError::ArgumentCount);
+        }
+        let (value, unit, next) = match parse_component(bytes, i, end) {
+            Ok(component) => component,
+            Err(error) => return Err(error),
+        };
+        values[count] = value;
+        units[count] = unit;
+        count += 1;
+        i = next;
+    }
+    if count < 3 {
+        return Err(ColorParseError::ArgumentCount);
+    }
+
+    let alpha = if count == 4 {
+        match units[3] {
+            Unit::None => clamp_unit(values[3]),
+            Unit::Percent => clamp_unit(values[3] / 100.0),
+            _ => return Err(ColorParseError::InvalidUnit),
+        }
+    } else {
+        1.0
+    };
+    match function {
+        Function::Rgb => {
+            let mut rgba = [0.0f32, 0.0, 0.0, alpha];
+            let mut channel = 0;
+            while channel < 3 {
+                rgba[channel] = match units[channel] {
+                    Unit::None => clamp_unit(values[channel] / 255.0),
+                    Unit::Percent => clamp_unit(values[channel] / 100.0),
+                    _ => return Err(ColorParseError::InvalidUnit),
+                };
+                channel += 1;
+            }
+            Ok(CssColor::Rgba(rgba))
+        }
+        Function::Hsl => {
+            let hue = match hue_degrees(values[0], units[0]) {
+                Ok(hue) => hue,
+                Err(error) => return Err(error),
+            };
+            // Bare numbers are accepted as percentages, as CSS Color 4 does.
+            if is_angle(units[1]) || is_angle(units[2]) {
+                return Err(ColorParseError::InvalidUnit);
+            }
+            Ok(CssColor::Hsla([
+                hue,
+                clamp_unit(values[1] / 100.0),
+                clamp_unit(values[2] / 100.0),
+                alpha,
+            ]))
+        }
+        Function::Oklch => {
+            let lightness = match units[0] {
+                Unit::None => values[0],
+                Unit::Percent => values[0] / 100.0,
+                _ => return Err(ColorParseError::InvalidUnit),
+            };
+            // `100%` chroma is 0.4 per CSS Color 4.
+            let chroma = match units[1] {
+                Unit::None => values[1],
+                Unit::Percent => values[1] / 100.0 * 0.4,
+                _ => return Err(ColorParseError::InvalidUnit),
+            };
+            let hue = match hue_degrees(values[2], units[2]) {
+                Ok(hue) => hue,
+                Err(error) => return Err(error),
+            };
+            Ok(CssColor::Oklch([clamp_unit(lightness), chroma, hue, alpha]))
+        }
+        Function::DisplayP3 => {
+            if matches!(separator, Separator::Comma) {
+                return Err(ColorParseError::MixedSeparators);
+            }
+            let mut rgba = [0.0f32, 0.0, 0.0, alpha];
+            let mut channel = 0;
+            while channel < 3 {
+                rgba[channel] = match units[channel] {
+                    Unit::None => clamp_unit(values[channel]),
+                    Unit::Percent => clamp_unit(values[channel] / 100.0),
+                    _ => return Err(ColorParseError::InvalidUnit),
+                };
+                channel += 1;
+            }
+            Ok(CssColor::DisplayP3(rgba))
+        }
+    }
+}
+
+/// Parses one number with an optional `%` or angle unit, returning the
+/// value, the unit and the index just past it.
+const fn parse_component(
+    bytes: &[u8],
+    start: usize,
+    end: usize,
+) -> Result<(f32, Unit, usize), ColorParseError> {
+    let mut i = start;
+    let mut negative = false;
+    if i < end && (bytes[i] == b'-' || bytes[i] == b'+') {
+        negative = bytes[i] == b'-';
+        i += 1;
+    }
+    let mut mantissa = 0.0f64;
+    let mut digits = 0;
+    let mut scale = 0i32;
+    while i < end && bytes[i].is_ascii_digit() {
+        mantissa = mantissa * 10.0 + (bytes[i] - b'0') as f64;
+        digits += 1;
+        i += 1;
+    }
+    if i < end && bytes[i] == b'.' {
+        i += 1;
+        while i < end && bytes[i].is_ascii_digit() {
+            mantissa = mantissa * 10.0 + (bytes[i] - b'0') as f64;
+            digits += 1;
+            scale -= 1;
+            i += 1;
+        }
+    }
+    if digits == 0 {
+        return Err(ColorParseError::InvalidNumber);
+    }
+    if i + 1 < end && (bytes[i] == b'e' || bytes[i] == b'E') {
+        let mut j = i + 1;
+        let mut exponent_negative = false;
+        if bytes[j] == b'-' || bytes[j] == b'+' {
+            exponent_negative = bytes[j] == b'-';
+            j += 1;
+        }
+        if j < end && bytes[j].is_ascii_digit() {
+            let mut exponent = 0i32;
+            while j < end && bytes[j].is_ascii_digit() {
+                exponent = exponent
+                    .saturating_mul(10)
+                    .saturating_add((bytes[j] - b'0') as i32);
+                j += 1;
+            }
+            scale += if exponent_negative {
+                -exponent
+            } else {
+                exponent
+            };
+            i = j;
+        }
+    }
+    // Anything past this is 0 or infinity in `f32` anyway.
+    scale = if scale > 64 {
+        64
+    } else if scale < -64 {
+        -64
+    } else {
+        scale
+    };
+    let mut value = mantissa;
+    while scale > 0 {
+        value *= 10.0;
+        scale -= 1;
+    }
+    while scale < 0 {
+        value /= 10.0;
+        scale += 1;
+    }
+    if negative {
+        value = -value;
+    }
+
+    let unit_start = i;
+    if i < end && bytes[i] == b'%' {
+        return Ok((value as f32, Unit::Percent, i + 1));
+    }
+    while i < end && bytes[i].is_ascii_alphabetic() {
+        i += 1;
+    }
+    let unit = if i == unit_start {
+        Unit::None
+    } else if eq_ignore_case(bytes, unit_start, i, "deg") {
+        Unit::Deg
+    } else if eq_ignore_case(bytes, unit_start, i, "rad") {
+        Unit::Rad
+    } else if eq_ignore_case(bytes, unit_start, i, "grad") {
+        Unit::Grad
+    } else if eq_ignore_case(bytes, unit_start, i, "turn") {
+        Unit::Turn
+    } else {
+        return Err(ColorParseError::InvalidUnit);
+    };
+    Ok((value as f32, unit, i))
+}
+
+const fn hue_degrees(value: f32, unit: Unit) -> Result<f32, ColorParseError> {
+    match unit {
+        Unit::None | Unit::Deg => Ok(value),
+        Unit::Rad => Ok(value * (180.0 / std::f32::consts::PI)),
+        Unit::Grad => Ok(value * 0.9),
+        Unit::Turn => Ok(value * 360.0),
+        Unit::Percent => Err(ColorParseError::InvalidUnit),
+    }
+}
+
+const fn is_angle(unit: Unit) -> bool {
+    matches!(unit, Unit::Deg | Unit::Rad | Unit::Grad | Unit::Turn)
+}
+
+const fn skip_whitespace(bytes: &[u8], mut i: usize, end: usize) -> usize {
+    while i < end && bytes[i].is_ascii_whitespace() {
+        i += 1;
+    }
+    i
+}
+
+const fn eq_ignore_case(bytes: &[u8], start: usize, end: usize, expected: &str) -> bool {
+    let expected = expected.as_bytes();
+    if end - start != expected.len() {
+        return false;
+    }
+    let mut i = 0;
+    while i < expected.len() {
+        if bytes[start + i].to_ascii_lowercase() != expected[i] {
+            return false;
+        }
+        i += 1;
+    }
+    true
+}
+
+const fn hex_digit(c: u8) -> Option<u8> {
+    match c {
+        b'0'..=b'9' => Some(c - b'0'),
+        b'a'..=b'f' => Some(c - b'a' + 10),
+        b'A'..=b'F' => Some(c - b'A' + 10),
+        _ => None,
+    }
+}
+
+const fn clamp_unit(value: f32) -> f32 {
+    if value < 0.0 {
+        0.0
+    } else if value > 1.0 {
+        1.0
+    } else {
+        value
+    }
+}
+
+const fn rgba_u8(rgba: [u8; 4]) -> CssColor {
+    CssColor::Rgba([
+        rgba[0] as f32 / 255.0,
+        rgba[1] as f32 / 255.0,
+        rgba[2] as f32 / 255.0,
+        rgba[3] as f32 / 255.0,
+    ])
+}
+
+fn unit_to_u8(value: f32) -> u8 {
+    (value * 255.0).round() as u8
+}
+
+/// CSS Color 4 named colors as `0xRRGGBB`.
+const NAMED_COLORS: &[(&str, u32)] = &[
+    ("aliceblue", 0xf0f8ff),
+    ("antiquewhite", 0xfaebd7),
+    ("aqua", 0x00ffff),
+    ("aquamarine", 0x7fffd4),
+    ("azure", 0xf0ffff),
+    ("beige", 0xf5f5dc),
+    ("bisque", 0xffe4c4),
+    ("black", 0x000000),
+    ("blanchedalmond", 0xffebcd),
+    ("blue", 0x0000ff),
+    ("blueviolet", 0x8a2be2),
+    ("brown", 0xa52a2a),
+    ("burlywood", 0xdeb887),
+    ("cadetblue", 0x5f9ea0),
+    ("chartreuse", 0x7fff00),
+    ("chocolate", 0xd2691e),
+    ("coral", 0xff7f50),
+    ("cornflowerblue", 0x6495ed),
+    ("cornsilk", 0xfff8dc),
+    ("crimson", 0xdc143c),
+    ("cyan", 0x00ffff),
+    ("darkblue", 0x00008b),
+    ("darkcyan", 0x008b8b),
+    ("darkgoldenrod", 0xb8860b),
+    ("darkgray", 0xa9a9a9),
+    ("darkgreen", 0x006400),
+    ("darkgrey", 0xa9a9a9),
+    ("darkkhaki", 0xbdb76b),
+    ("darkmagenta", 0x8b008b),
+    ("darkolivegreen", 0x556b2f),
+    ("darkorange", 0xff8c00),
+    ("darkorchid", 0x9932cc),
+    ("darkred", 0x8b0000),
+    ("darksalmon", 0xe9967a),
+    ("darkseagreen", 0x8fbc8f),
+    ("darkslateblue", 0x483d8b),
+    ("darkslategray", 0x2f4f4f),
+    ("darkslategrey", 0x2f4f4f),
+    ("darkturquoise", 0x00ced1),
+    ("darkviolet", 0x9400d3),
+    ("deeppink", 0xff1493),
+    ("deepskyblue", 0x00bfff),
+    ("dimgray", 0x696969),
+    ("dimgrey", 0x696969),
+    ("dodgerblue", 0x1e90ff),
+    ("firebrick", 0xb22222),
+    ("floralwhite", 0xfffaf0),
+    ("forestgreen", 0x228b22),
+    ("fuchsia", 0xff00ff),
+    ("gainsboro", 0xdcdcdc),
+    ("ghostwhite", 0xf8f8ff),
+    ("gold", 0xffd700),
+    ("goldenrod", 0xdaa520),
+    ("gray", 0x808080),
+    ("green", 0x008000),
+    ("greenyellow", 0xadff2f),
+    ("grey", 0x808080),
+    ("honeydew", 0xf0fff0),
+    ("hotpink", 0xff69b4),
+    ("indianred", 0xcd5c5c),
+    ("indigo", 0x4b0082),
+    ("ivory", 0xfffff0),
+    ("khaki", 0xf0e68c),
+    ("lavender", 0xe6e6fa),
+    ("lavenderblush", 0xfff0f5),
+    ("lawngreen", 0x7cfc00),
+    ("lemonchiffon", 0xfffacd),
+    ("lightblue", 0xadd8e6),
+    ("lightcoral", 0xf08080),
+    ("lightcyan", 0xe0ffff),
+    ("lightgoldenrodyellow", 0xfafad2),
+    ("lightgray", 0xd3d3d3),
+    ("lightgreen", 0x90ee90),
+    ("lightgrey", 0xd3d3d3),
+    ("lightpink", 0xffb6c1),
+    ("lightsalmon", 0xffa07a),
+    ("lightseagreen", 0x20b2aa),
+    ("lightskyblue", 0x87cefa),
+    ("lightslategray", 0x778899),
+    ("lightslategrey", 0x778899),
+    ("lightsteelblue", 0xb0c4de),
+    ("lightyellow", 0xffffe0),
+    ("lime", 0x00ff00),
+    ("limegreen", 0x32cd32),
+    ("linen", 0xfaf0e6),
+    ("magenta", 0xff00ff),
+    ("maroon", 0x800000),
+    ("mediumaquamarine", 0x66cdaa),
+    ("mediumblue", 0x0000cd),
+    ("mediumorchid", 0xba55d3),
+    ("mediumpurple", 0x9370db),
+    ("mediumseagreen", 0x3cb371),
+    ("mediumslateblue", 0x7b68ee),
+    ("mediumspringgreen", 0x00fa9a),
+    ("mediumturquoise", 0x48d1cc),
+    ("mediumvioletred", 0xc71585),
+    ("midnightblue", 0x191970),
+    ("mintcream", 0xf5fffa),
+    ("mistyrose", 0xffe4e1),
+    ("moccasin", 0xffe4b5),
+    ("navajowhite", 0xffdead),
+    ("navy", 0x000080),
+    ("oldlace", 0xfdf5e6),
+    ("olive", 0x808000),
+    ("olivedrab", 0x6b8e23),
+    ("orange", 0xffa500),
+    ("orangered", 0xff4500),
+    ("orchid", 0xda70d6),
+    ("palegoldenrod", 0xeee8aa),
+    ("palegreen", 0x98fb98),
+    ("paleturquoise", 0xafeeee),
+    ("palevioletred", 0xdb7093),
+    ("papayawhip", 0xffefd5),
+    ("peachpuff", 0xffdab9),
+    ("peru", 0xcd853f),
+    ("pink", 0xffc0cb),
+    ("plum", 0xdda0dd),
+    ("powderblue", 0xb0e0e6),
+    ("purple", 0x800080),
+    ("rebeccapurple", 0x663399),
+    ("red", 0xff0000),
+    ("rosybrown", 0xbc8f8f),
+    ("royalblue", 0x4169e1),
+    ("saddlebrown", 0x8b4513),
+    ("salmon", 0xfa8072),
+    ("sandybrown", 0xf4a460),
+    ("seagreen", 0x2e8b57),
+    ("seashell", 0xfff5ee),
+    ("sienna", 0xa0522d),
+    ("silver", 0xc0c0c0),
+    ("skyblue", 0x87ceeb),
+    ("slateblue", 0x6a5acd),
+    ("slategray", 0x708090),
+    ("slategrey", 0x708090),
+    ("snow", 0xfffafa),
+    ("springgreen", 0x00ff7f),
+    ("steelblue", 0x4682b4),
+    ("tan", 0xd2b48c),
+    ("teal", 0x008080),
+    ("thistle", 0xd8bfd8),
+    ("tomato", 0xff6347),
+    ("turquoise", 0x40e0d0),
+    ("violet", 0xee82ee),
+    ("wheat", 0xf5deb3),
+    ("white", 0xffffff),
+    ("whitesmoke", 0xf5f5f5),
+    ("yellow", 0xffff00),
+    ("yellowgreen", 0x9acd32),
+];
+
+#[cfg(test)]
+mod tests;
@@ -0,0 +1,77 @@
+use super::{ColorParseError, parse_color};
+use crate::style::{Color, StyleColor};
+
+fn srgb(input: &str) -> [u8; 4] {
+    input.parse::<Color>().expect(input).to_rgba_u8()
+}
+
+#[test]
+fn parses_names_hex_and_rgb_forms() {
+    assert_eq!(srgb("red"), [255, 0, 0, 255]);
+    assert_eq!(srgb(" RebeccaPurple "), [102, 51, 153, 255]);
+    assert_eq!(srgb("transparent"), [0, 0, 0, 0]);
+    assert_eq!(srgb("#0f08"), [0, 255, 0, 136]);
+    assert_eq!(srgb("#336699"), [51, 102, 153, 255]);
+    assert_eq!(srgb("rgb(255 0 0 / 0.5)"), [255, 0, 0, 128]);
+    assert_eq!(srgb("rgba(0, 128, 255, 50%)"), [0, 128, 255, 128]);
+    assert_eq!(srgb("rgb(100% 50% 0%)"), [255, 128, 0, 255]);
+}
+
+#[test]
+fn parses_hsl_and_oklch_forms() {
+    assert_eq!(srgb("hsl(0, 100%, 50%)"), [255, 0, 0, 255]);
+    assert_eq!(
+        srgb("hsl(200, 50%, 40%)"),
+        Color::from_hsl(200.0, 0.5, 0.4).to_rgba_u8()
+    );
+    assert_eq!(srgb("hsl(0.5turn 100% 25% / 1)"), [0, 128, 128, 255]);
+
+    let StyleColor::Oklch(oklch) = parse_color("oklch(62.8% 0.25 29.23 / 0.75)").unwrap() else {
+        panic!("oklch() should stay in OKLCH");
+    };
+    assert!((oklch.l() - 0.628).abs() < 1e-6);
+    assert!((oklch.c() - 0.25).abs() < 1e-6);
+    assert!((oklch.h() - 29.23).abs() < 1e-4);
+    assert!((oklch.a() - 0.75).abs() < 1e-6);
+}
+
+#[test]
+fn parses_display_p3_color_function() {
+    let StyleColor::DisplayP3(p3) = parse_color("color(display-p3 1 0 0 / 50%)").unwrap() else {
+        panic!("color(display-p3) should stay in Display-P3");
+    };
+    assert_eq!(p3.raw(), [1.0, 0.0, 0.0, 0.5]);
+    assert_eq!(
+        parse_color("color(rec2020 1 0 0)").err(),
+        Some(ColorParseError::UnknownFunction)
+    );
+}
+
+#[test]
+fn rejects_malformed_strings_with_specific_errors() {
+    let cases = [
+        ("", ColorParseError::Empty),
+        ("#12345", ColorParseError::InvalidHex),
+        ("#ggg", ColorParseError::InvalidHex),
+        ("redd", ColorParseError::UnknownName),
+        ("lab(50% 0 0)", ColorParseError::UnknownFunction),
+        ("rgb(1 2 3", ColorParseError::UnclosedFunction),
+        ("rgb(1 2)", ColorParseError::ArgumentCount),
+        ("rgb(1 2 3 4)", ColorParseError::ArgumentCount),
+        ("rgb(1, 2 3)", ColorParseError::MixedSeparators),
+        ("rgb(1 2 x)", ColorParseError::InvalidNumber),
+        ("hsl(20% 50% 50%)", ColorParseError::InvalidUnit),
+    ];
+    for (input, expected) in cases {
+        assert_eq!(parse_color(input).err(), Some(expected), "{input:?}");
+    }
+}
+
+#[test]
+fn string_props_accept_css_forms_and_fall_back_to_transparent() {
+    use crate::style::IntoColor;
+    let color: Color = "oklch(0.7 0.1 200)".into_color();
+    assert_eq!(color.to_rgba_u8()[3], 255);
+    let invalid: Color = "not-a-color".into_color();
+    assert_eq!(invalid, Color::transparent());
+}
@@ -0,0 +1,57 @@
+#![allow(missing_docs)]
+
+//! Display-P3 color values for wide-gamut authoring.
+
+use crate::style::color::{ColorLike, ColorSpace, linear_to_srgb_f32, srgb_to_linear_f32};
+
+/// A color in the Display-P3 space with alpha. Components are gamma-encoded
+/// in `0.0..=1.0`, like CSS `color(display-p3 r g b / a)`.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct DisplayP3Color {
+    raw: [f32; 4],   // encoded [r, g, b, a]
+    value: [f32; 4], // linear P3 RGBA
+}
+
+impl DisplayP3Color {
+    pub fn new(r: f32, g: f32, b: f32, a: f32) -> Self {
+        let raw = [r, g, b, a].map(|channel| channel.clamp(0.0, 1.0));
+        Self {
+            raw,
+            value: [
+                srgb_to_linear_f32(raw[0]),
+                srgb_to_linear_f32(raw[1]),
+                srgb_to_linear_f32(raw[2]),
+                raw[3],
+            ],
+        }
+    }
+
+    /// From linear P3 RGBA, clamped to the P3 gamut.
+    pub fn from_linear_rgba(value: [f32; 4]) -> Self {
+        let [r, g, b, a] = value.map(|channel| channel.clamp(0.0, 1.0));
+        Self::new(
+            linear_to_srgb_f32(r),
+            linear_to_srgb_f32(g),
+            linear_to_srgb_f32(b),
+            a,
+        )
+    }
+
+    pub fn raw(&self) -> [f32; 4] {
+        self.raw
+    }
+}
+
+impl ColorLike for DisplayP3Color {
+    fn box_clone(&self) -> Box<dyn ColorLike> {
+        Box::new(*self)
+    }
+
+    fn to_rgba_f32(&self) -> [f32; 4] {
+        self.value
+    }
+
+    fn color_space(&self) -> ColorSpace {
+        ColorSpace::DisplayP3
+    }
+}
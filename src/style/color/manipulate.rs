@@ -0,0 +1,154 @@
+#![allow(missing_docs)]
+
+//! HSL/HSV constructors and derived-shade helpers on [`Color`].
+//!
+//! Hue is in degrees, every other component is in `0.0..=1.0`. Lightness
+//! and saturation adjustments work in OKLCH so equal amounts look equally
+//! strong across hues, matching [`darken_color`].
+
+use crate::style::color::{Color, ColorLike, OklchColor, darken_color};
+use crate::style::interpolate::Interpolate;
+
+impl Color {
+    pub fn from_hsl(h: f32, s: f32, l: f32) -> Self {
+        Self::from_hsla(h, s, l, 1.0)
+    }
+
+    pub fn from_hsla(h: f32, s: f32, l: f32, a: f32) -> Self {
+        let s = s.clamp(0.0, 1.0);
+        let l = l.clamp(0.0, 1.0);
+        let chroma = (1.0 - (2.0 * l - 1.0).abs()) * s;
+        from_hue_chroma(h, chroma, l - chroma * 0.5, a)
+    }
+
+    pub fn from_hsv(h: f32, s: f32, v: f32) -> Self {
+        Self::from_hsva(h, s, v, 1.0)
+    }
+
+    pub fn from_hsva(h: f32, s: f32, v: f32, a: f32) -> Self {
+        let s = s.clamp(0.0, 1.0);
+        let v = v.clamp(0.0, 1.0);
+        let chroma = v * s;
+        from_hue_chroma(h, chroma, v - chroma, a)
+    }
+
+    /// `[h, s, l, a]`.
+    pub fn to_hsla(self) -> [f32; 4] {
+        let [r, g, b, a] = self.unit_rgba();
+        let (max, min, hue) = max_min_hue(r, g, b);
+        let l = (max + min) * 0.5;
+        let delta = max - min;
+        let s = if delta <= f32::EPSILON {
+            0.0
+        } else {
+            delta / (1.0 - (2.0 * l - 1.0).abs())
+        };
+        [hue, s.clamp(0.0, 1.0), l, a]
+    }
+
+    /// `[h, s, v, a]`.
+    pub fn to_hsva(self) -> [f32; 4] {
+        let [r, g, b, a] = self.unit_rgba();
+        let (max, min, hue) = max_min_hue(r, g, b);
+        let s = if max <= f32::EPSILON {
+            0.0
+        } else {
+            (max - min) / max
+        };
+        [hue, s, max, a]
+    }
+
+    pub fn with_alpha(self, alpha: f32) -> Self {
+        let [r, g, b, _] = self.to_rgba_u8();
+        Self::rgba(r, g, b, unit_to_u8(alpha))
+    }
+
+    /// Raise OKLCH lightness by `amount`.
+    pub fn lighten(self, amount: f32) -> Self {
+        self.darken(-amount)
+    }
+
+    /// Lower OKLCH lightness by `amount`.
+    pub fn darken(self, amount: f32) -> Self {
+        let [r, g, b, a] = darken_color(&self, amount).to_rgba_u8();
+        Self::rgba(r, g, b, a)
+    }
+
+    /// Scale OKLCH chroma by `1.0 + amount`; negative amounts desaturate and
+    /// `-1.0` yields the gray of the same lightness.
+    pub fn saturate(self, amount: f32) -> Self {
+        let oklch = OklchColor::from_linear_rgba(self.to_rgba_f32());
+        let chroma = oklch.c() * (1.0 + amount).max(0.0);
+        let [r, g, b, a] = OklchColor::new(oklch.l(), chroma, oklch.h(), oklch.a()).to_rgba_u8();
+        Self::rgba(r, g, b, a)
+    }
+
+    pub fn desaturate(self, amount: f32) -> Self {
+        self.saturate(-amount)
+    }
+
+    /// Blend toward `other` by `t` in OKLab, the same space color
+    /// transitions use by default.
+    pub fn mix(self, other: Self, t: f32) -> Self {
+        Self::interpolate(&self, &other, t)
+    }
+
+    /// WCAG 2 relative luminance; alpha is ignored.
+    pub fn relative_luminance(self) -> f32 {
+        let [r, g, b, _] = self.to_rgba_f32();
+        0.2126 * r + 0.7152 * g + 0.0722 * b
+    }
+
+    /// WCAG 2 contrast ratio against `other`, from `1.0` to `21.0`.
+    pub fn contrast_ratio(self, other: Self) -> f32 {
+        let a = self.relative_luminance();
+        let b = other.relative_luminance();
+        (a.max(b) + 0.05) / (a.min(b) + 0.05)
+    }
+
+    fn unit_rgba(self) -> [f32; 4] {
+        self.to_rgba_u8().map(|channel| channel as f32 / 255.0)
+    }
+}
+
+fn from_hue_chroma(h: f32, chroma: f32, m: f32, a: f32) -> Color {
+    let sector = h.rem_euclid(360.0) / 60.0;
+    let x = chroma * (1.0 - (sector % 2.0 - 1.0).abs());
+    let (r, g, b) = match sector as u32 {
+        0 => (chroma, x, 0.0),
+        1 => (x, chroma, 0.0),
+        2 => (0.0, chroma, x),
+        3 => (0.0, x, chroma),
+        4 => (x, 0.0, chroma),
+        _ => (chroma, 0.0, x),
+    };
+    Color::rgba(
+        unit_to_u8(r + m),
+        unit_to_u8(g + m),
+        unit_to_u8(b + m),
+        unit_to_u8(a),
+    )
+}
+
+fn max_min_hue(r: f32, g: f32, b: f32) -> (f32, f32, f32) {
+    let max = r.max(g).max(b);
+    let min = r.min(g).min(b);
+    let delta = max - min;
+    let hue = if delta <= f32::EPSILON {
+        0.0
+    } else if max == r {
+        60.0 * ((g - b) / delta).rem_euclid(6.0)
+    } else if max == g {
+        60.0 * ((b - r) / delta + 2.0)
+    } else {
+        60.0 * ((r - g) / delta + 4.0)
+    };
+    (max, min, hue)
+}
+
+fn unit_to_u8(value: f32) -> u8 {
+    (value.clamp(0.0, 1.0) * 255.0).round() as u8
+}
+
+#[cfg(test)]
+mod tests;
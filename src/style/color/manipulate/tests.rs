@@ -0,0 +1,38 @@
+use crate::style::Color;
+
+#[test]
+fn hsl_and_hsv_round_trip_through_srgb() {
+    assert_eq!(Color::from_hsl(0.0, 1.0, 0.5), Color::rgb(255, 0, 0));
+    assert_eq!(Color::from_hsl(120.0, 1.0, 0.25), Color::rgb(0, 128, 0));
+    assert_eq!(Color::from_hsv(240.0, 1.0, 1.0), Color::rgb(0, 0, 255));
+    assert_eq!(Color::from_hsla(200.0, 0.5, 0.4, 0.5).to_rgba_u8()[3], 128);
+
+    let [h, s, l, _] = Color::rgb(51, 153, 204).to_hsla();
+    assert!((h - 200.0).abs() < 0.5 && (s - 0.6).abs() < 0.01 && (l - 0.5).abs() < 0.01);
+    let [h, s, v, _] = Color::rgb(255, 128, 0).to_hsva();
+    assert!((h - 30.0).abs() < 0.5 && s == 1.0 && v == 1.0);
+}
+
+#[test]
+fn derived_shades_move_in_the_expected_direction() {
+    let base = Color::rgb(40, 120, 200);
+    assert!(base.lighten(0.1).relative_luminance() > base.relative_luminance());
+    assert!(base.darken(0.1).relative_luminance() < base.relative_luminance());
+    let [_, gray_s, _, _] = base.desaturate(1.0).to_hsla();
+    assert!(gray_s < 0.02, "fully desaturated is gray, got {gray_s}");
+    assert_eq!(base.with_alpha(0.0).to_rgba_u8()[3], 0);
+    assert_eq!(base.mix(Color::rgb(0, 0, 0), 0.0), base);
+}
+
+#[test]
+fn contrast_ratio_follows_wcag() {
+    let black = Color::rgb(0, 0, 0);
+    let white = Color::rgb(255, 255, 255);
+    assert!((black.contrast_ratio(white) - 21.0).abs() < 0.01);
+    assert!((white.contrast_ratio(white) - 1.0).abs() < 0.001);
+    assert_eq!(
+        black.contrast_ratio(white),
+        white.contrast_ratio(black),
+        "ratio is symmetric"
+    );
+}
@@ -4,9 +4,16 @@
 
 use once_cell::sync::Lazy;
 
+mod color_space;
+mod css_color;
+mod display_p3_color;
 mod hex_color;
+mod manipulate;
 mod oklch_color;
 
+pub use color_space::*;
+pub use css_color::*;
+pub use display_p3_color::*;
 pub use hex_color::*;
 pub use oklch_color::*;
 
@@ -45,6 +52,7 @@ impl Color {
 pub enum StyleColor {
     Srgb(Color),
     Oklch(OklchColor),
+    DisplayP3(DisplayP3Color),
 }
 
 impl StyleColor {
@@ -57,20 +65,34 @@ impl StyleColor {
 /// A trait implemented by color values that can be resolved into RGBA output.
 pub trait ColorLike {
     fn box_clone(&self) -> Box<dyn ColorLike>;
+    /// Linear RGBA in the primaries reported by [`Self::color_space`].
     fn to_rgba_f32(&self) -> [f32; 4];
+    fn color_space(&self) -> ColorSpace {
+        ColorSpace::Srgb
+    }
+    /// Linear sRGB, unclamped so wide-gamut colors survive as extended values.
+    fn to_linear_srgb_f32(&self) -> [f32; 4] {
+        convert_linear_rgba(self.to_rgba_f32(), self.color_space(), ColorSpace::Srgb)
+    }
+    /// What the renderer writes for this color on the current surface.
+    fn to_output_rgba_f32(&self) -> [f32; 4] {
+        linear_srgb_to_output(self.to_linear_srgb_f32())
+    }
     fn as_oklch(&self) -> Option<&OklchColor> {
         None
     }
     fn to_style_color(&self) -> StyleColor {
         if let Some(oklch) = self.as_oklch() {
             StyleColor::Oklch(oklch.clone())
+        } else if self.color_space() == ColorSpace::DisplayP3 {
+            StyleColor::DisplayP3(DisplayP3Color::from_linear_rgba(self.to_rgba_f32()))
         } else {
             let [r, g, b, a] = self.to_rgba_u8();
             StyleColor::Srgb(Color::rgba(r, g, b, a))
         }
     }
     fn to_rgba_u8(&self) -> [u8; 4] {
-        let rgba_f32 = self.to_rgba_f32();
+        let rgba_f32 = self.to_linear_srgb_f32();
         [
             (linear_to_srgb_f32(rgba_f32[0].clamp(0.0, 1.0)) * 255.0).round() as u8,
             (linear_to_srgb_f32(rgba_f32[1].clamp(0.0, 1.0)) * 255.0).round() as u8,
@@ -79,7 +101,7 @@ pub trait ColorLike {
         ]
     }
     fn to_wgpu_color(&self) -> wgpu::Color {
-        let rgba = self.to_rgba_f32();
+        let rgba = self.to_output_rgba_f32();
         wgpu::Color {
             r: rgba[0] as f64,
             g: rgba[1] as f64,
@@ -108,6 +130,10 @@ impl ColorLike for Box<dyn ColorLike> {
         (**self).to_rgba_f32()
     }
 
+    fn color_space(&self) -> ColorSpace {
+        (**self).color_space()
+    }
+
     fn as_oklch(&self) -> Option<&OklchColor> {
         (**self).as_oklch()
     }
@@ -137,13 +163,21 @@ impl ColorLike for StyleColor {
         match self {
             Self::Srgb(color) => color.to_rgba_f32(),
             Self::Oklch(color) => color.to_rgba_f32(),
+            Self::DisplayP3(color) => color.to_rgba_f32(),
+        }
+    }
+
+    fn color_space(&self) -> ColorSpace {
+        match self {
+            Self::DisplayP3(_) => ColorSpace::DisplayP3,
+            Self::Srgb(_) | Self::Oklch(_) => ColorSpace::Srgb,
         }
     }
 
     fn as_oklch(&self) -> Option<&OklchColor> {
         match self {
             Self::Oklch(color) => Some(color),
-            Self::Srgb(_) => None,
+            Self::Srgb(_) | Self::DisplayP3(_) => None,
         }
     }
 
@@ -165,8 +199,7 @@ pub trait IntoColor<T> {
 
 impl IntoColor<Color> for &str {
     fn into_color(self) -> Color {
-        let [r, g, b, a] = Color::hex(self).to_rgba_u8();
-        Color::rgba(r, g, b, a)
+        parse_color_or_transparent(self).to_color()
     }
 }
 
@@ -176,6 +209,18 @@ impl IntoColor<Color> for String {
     }
 }
 
+impl IntoColor<StyleColor> for &str {
+    fn into_color(self) -> StyleColor {
+        parse_color_or_transparent(self)
+    }
+}
+
+impl IntoColor<StyleColor> for String {
+    fn into_color(self) -> StyleColor {
+        parse_color_or_transparent(&self)
+    }
+}
+
 impl<'a> IntoColor<HexColor<'a>> for &'a str {
     fn into_color(self) -> HexColor<'a> {
         HexColor::new(self)
@@ -210,6 +255,12 @@ impl From<OklchColor> for StyleColor {
     }
 }
 
+impl From<DisplayP3Color> for StyleColor {
+    fn from(value: DisplayP3Color) -> Self {
+        Self::DisplayP3(value)
+    }
+}
+
 static SRGB8_TO_LINEAR: Lazy<[f32; 256]> = Lazy::new(|| {
     let mut t = [0.0f32; 256];
     for i in 0..256 {
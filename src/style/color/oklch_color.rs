@@ -8,7 +8,7 @@ use crate::style::color::ColorLike;
 #[derive(Debug, Clone, Copy, PartialEq)]
 pub struct OklchColor {
     raw: [f32; 4],   // [l, c, h, a]
-    value: [f32; 4], // linear RGBA
+    value: [f32; 4], // linear sRGB RGBA, unclamped outside the sRGB gamut
 }
 
 impl OklchColor {
@@ -51,7 +51,7 @@ impl OklchColor {
 
 /// Shift `base` lightness in OKLCH space by `-amount` (positive = darker, negative = lighter).
 pub fn darken_color(base: &dyn ColorLike, amount: f32) -> OklchColor {
-    let rgba = base.to_rgba_f32();
+    let rgba = base.to_linear_srgb_f32();
     let o = OklchColor::from_linear_rgba(rgba);
     OklchColor::new((o.l() - amount).clamp(0.0, 1.0), o.c(), o.h(), o.a())
 }
@@ -89,7 +89,7 @@ fn oklch_to_linear_rgba(l: f32, c: f32, h_deg: f32, a: f32) -> [f32; 4] {
     let g = -1.2684380046 * l + 2.6097574011 * m - 0.3413193965 * s;
     let b = -0.0041960863 * l - 0.7034186147 * m + 1.7076147010 * s;
 
-    [r.clamp(0.0, 1.0), g.clamp(0.0, 1.0), b.clamp(0.0, 1.0), a]
+    [r, g, b, a]
 }
 
 fn linear_rgba_to_oklch(r: f32, g: f32, b: f32) -> [f32; 3] {
@@ -0,0 +1,82 @@
+//! Layering several styles into one, for components that combine their own
+//! defaults with an incoming `style` prop.
+//!
+//! ```ignore
+//! let style = styles![
+//!     base_style(&theme),
+//!     hovered.then_some(hover_style),
+//!     selected.then_some(selected_style),
+//!     props.style,
+//! ];
+//! ```
+//!
+//! Precedence is stable and positional:
+//!
+//! - a property set by a later layer replaces the earlier value; properties
+//!   a layer leaves unset keep theirs;
+//! - absent (`None`) layers are skipped;
+//! - `hover` and `selection` blocks merge by the same rules;
+//! - `media` rules are concatenated in layer order, so where several match,
+//!   the later layer's rule wins;
+//! - a shorthand (`padding`, `margin`, `background`) in a later layer
+//!   replaces the longhands it covers from earlier layers.
+
+use crate::style::Style;
+
+/// Style value that later layers can be stacked onto.
+pub trait ComposeStyle: Default + Clone {
+    /// Stack `later` on top of `self`.
+    fn extend(&mut self, later: Self);
+
+    /// `layers` stacked in order, the last one on top.
+    fn merge(layers: &[Self]) -> Self {
+        let mut merged = Self::default();
+        for layer in layers {
+            merged.extend(layer.clone());
+        }
+        merged
+    }
+}
+
+/// Argument of [`styles!`](crate::styles): a style, or an optional one.
+pub trait IntoStyleLayer {
+    type Style: ComposeStyle;
+
+    fn into_style_layer(self) -> Option<Self::Style>;
+}
+
+impl ComposeStyle for Style {
+    fn extend(&mut self, later: Self) {
+        Style::extend(self, later);
+    }
+
+    fn merge(layers: &[Self]) -> Self {
+        Style::merge(layers)
+    }
+}
+
+impl IntoStyleLayer for Style {
+    type Style = Style;
+
+    fn into_style_layer(self) -> Option<Self::Style> {
+        Some(self)
+    }
+}
+
+impl IntoStyleLayer for Option<Style> {
+    type Style = Style;
+
+    fn into_style_layer(self) -> Option<Self::Style> {
+        self
+    }
+}
+
+#[doc(hidden)]
+pub fn __compose_style_layer<S: ComposeStyle>(target: &mut S, layer: Option<S>) {
+    if let Some(layer) = layer {
+        target.extend(layer);
+    }
+}
+
+#[cfg(test)]
+mod tests;
@@ -0,0 +1,26 @@
+use crate::style::{Color, ComposeStyle, Cursor, FontSize, Length, Padding, Style};
+
+#[test]
+fn later_layers_win_per_property() {
+    let base = Style::new()
+        .with_cursor(Cursor::Default)
+        .with_padding(Padding::uniform(Length::px(4.0)))
+        .with_hover(Style::new().with_color(Color::hex("#ff0000")));
+    let extra = Style::new()
+        .with_cursor(Cursor::Pointer)
+        .with_hover(Style::new().with_font_size(FontSize::px(18.0)));
+
+    let merged = crate::styles![base.clone(), Some(extra.clone()), None::<Style>];
+    assert_eq!(merged, Style::merge(&[base, extra]));
+
+    let expected = Style::new()
+        .with_cursor(Cursor::Pointer)
+        .with_padding(Padding::uniform(Length::px(4.0)))
+        .with_hover(
+            Style::new()
+                .with_color(Color::hex("#ff0000"))
+                .with_font_size(FontSize::px(18.0)),
+        );
+    assert_eq!(merged, expected);
+    assert_eq!(<Style as ComposeStyle>::merge(&[]), Style::new());
+}
@@ -2,14 +2,20 @@
 
 //! Computed style data used by layout, rendering, and interaction passes.
 
+use crate::style::border_image::NinePatch;
 use crate::style::color::Color;
+use crate::style::elevation::ElevationScale;
+use crate::style::filter::{Filter, Mask, MixBlendMode};
 use crate::style::gradient::Gradient;
+use crate::style::hit_shape::HitShape;
+use crate::style::media::MediaContext;
 use crate::style::parsed_style::{
-    Align, Animator, BoxShadow, CrossSize, Cursor, FontSize, Layout, Length, ParsedValue, Position,
-    PropertyId, ScrollDirection, Style, TextWrap, Transform, TransformOrigin, Transitions,
-    VerticalAlign,
+    Align, Animator, AppRegion, BoxShadow, CrossSize, Cursor, FontSize, Layout, Length,
+    ParsedValue, PointerEvents, Position, PropertyId, ScrollDirection, ScrollbarStyle, Style,
+    TextWrap, Transform, TransformOrigin, Transitions, VerticalAlign,
 };
 use crate::style::style_props::apply_inherited_properties;
+use crate::style::user_agent::UserAgentStyle;
 
 /// A resolved size value used by computed style.
 #[derive(Debug, Clone, Copy, PartialEq)]
@@ -55,12 +61,19 @@ pub struct ComputedStyle {
     pub padding: EdgeInsets<Length>,
     pub gap: Length,
     pub scroll_direction: ScrollDirection,
+    pub scrollbar: ScrollbarStyle,
     pub cursor: Cursor,
+    pub app_region: AppRegion,
+    pub pointer_events: PointerEvents,
+    pub hit_shape: HitShape,
     pub color: Color,
     pub selection_background_color: Color,
     pub background_color: Color,
     pub background_image: Option<Gradient>,
     pub border_image: Option<Gradient>,
+    /// Sliced image drawn over the border box in place of the border
+    /// stroke. Mutually exclusive with `border_image`.
+    pub border_nine_patch: Option<NinePatch>,
     pub font_families: Vec<String>,
     pub font_size: f32,
     pub font_weight: u16,
@@ -79,6 +92,14 @@ pub struct ComputedStyle {
     pub border_colors: EdgeInsets<Color>,
     pub opacity: f32,
     pub box_shadow: Vec<BoxShadow>,
+    /// Declared `elevation` level; its preset shadows are already in
+    /// `box_shadow` unless that was set explicitly.
+    pub elevation: u8,
+    pub mix_blend_mode: MixBlendMode,
+    /// Applied in order to the element's layer before it is composited.
+    pub filter: Vec<Filter>,
+    /// Alpha source for the element's composited output.
+    pub mask: Option<Mask>,
     pub transform: Transform,
     pub transform_origin: TransformOrigin,
     pub transition: Transitions,
@@ -115,12 +136,17 @@ impl Default for ComputedStyle {
             },
             gap: Length::Px(0.0),
             scroll_direction: ScrollDirection::None,
+            scrollbar: ScrollbarStyle::new(),
             cursor: Cursor::Default,
+            app_region: AppRegion::Auto,
+            pointer_events: PointerEvents::Auto,
+            hit_shape: HitShape::Box,
             color: Color::rgb(0, 0, 0),
             selection_background_color: Color::rgba(0, 0, 0, 0),
             background_color: Color::rgba(0, 0, 0, 0),
             background_image: None,
             border_image: None,
+            border_nine_patch: None,
             font_families: Vec::new(),
             font_size: 16.0,
             font_weight: 400,
@@ -150,6 +176,10 @@ impl Default for ComputedStyle {
             },
             opacity: 1.0,
             box_shadow: Vec::new(),
+            elevation: 0,
+            mix_blend_mode: MixBlendMode::Normal,
+            filter: Vec::new(),
+            mask: None,
             transform: Transform::default(),
             transform_origin: TransformOrigin::center(),
             transition: Transitions::default(),
@@ -182,6 +212,8 @@ impl ComputedStyle {
             && self.padding == other.padding
             && self.gap == other.gap
             && self.scroll_direction == other.scroll_direction
+            && self.scrollbar.resolved_mode() == other.scrollbar.resolved_mode()
+            && self.scrollbar.resolved_width() == other.scrollbar.resolved_width()
             && self.font_families == other.font_families
             && self.font_size == other.font_size
             && self.font_weight == other.font_weight
@@ -280,14 +312,16 @@ pub fn compute_style(parsed: &Style, parent: Option<&ComputedStyle>) -> Computed
             viewport_height: 0.0,
             root_font_size: 16.0,
             hovered: false,
+            media: MediaContext::current(),
         },
     )
 }
 
 /// Runtime context for style computation.
 ///
-/// Font-size relative units are resolved from this context. Hovered state
-/// selects the authored hover style before declarations are computed.
+/// Font-size relative units are resolved from this context. Matching
+/// `media` blocks and, when hovered, the authored hover style are merged
+/// over the base declarations before they are computed.
 #[derive(Debug, Clone, Copy)]
 pub struct StyleComputeContext<'a> {
     pub parent: Option<&'a ComputedStyle>,
@@ -295,18 +329,33 @@ pub struct StyleComputeContext<'a> {
     pub viewport_height: f32,
     pub root_font_size: f32,
     pub hovered: bool,
+    pub media: MediaContext,
 }
 
 pub fn compute_style_with_context(parsed: &Style, ctx: StyleComputeContext<'_>) -> ComputedStyle {
-    let effective_style = ctx
-        .hovered
-        .then(|| parsed.hover().map(|hover| parsed.clone() + hover.clone()))
-        .flatten();
+    let mut effective_style: Option<Style> = None;
+    for rule in parsed.media() {
+        if rule.query.matches(&ctx.media) {
+            let base = effective_style.unwrap_or_else(|| parsed.clone());
+            effective_style = Some(base + rule.style.clone());
+        }
+    }
+    if ctx.hovered
+        && let Some(hover) = parsed.hover()
+    {
+        let base = effective_style.unwrap_or_else(|| parsed.clone());
+        effective_style = Some(base + hover.clone());
+    }
     let parsed = effective_style.as_ref().unwrap_or(parsed);
 
-    let mut computed = ComputedStyle::default();
+    let mut computed = ComputedStyle {
+        scrollbar: UserAgentStyle::with_current(|user_agent| user_agent.scrollbar),
+        ..ComputedStyle::default()
+    };
     let mut has_explicit_cross_size = false;
     let mut has_explicit_align = false;
+    let mut has_explicit_box_shadow = false;
+    let mut has_elevation = false;
 
     if let Some(parent) = ctx.parent {
         apply_inherited_properties(parent, &mut computed);
@@ -412,11 +461,31 @@ pub fn compute_style_with_context(parsed: &Style, ctx: StyleComputeContext<'_>)
                     computed.scroll_direction = *value;
                 }
             }
+            PropertyId::Scrollbar => {
+                if let ParsedValue::Scrollbar(value) = &declaration.value {
+                    computed.scrollbar = *value;
+                }
+            }
             PropertyId::Cursor => {
                 if let ParsedValue::Cursor(value) = &declaration.value {
                     computed.cursor = *value;
                 }
             }
+            PropertyId::AppRegion => {
+                if let ParsedValue::AppRegion(value) = &declaration.value {
+                    computed.app_region = *value;
+                }
+            }
+            PropertyId::PointerEvents => {
+                if let ParsedValue::PointerEvents(value) = &declaration.value {
+                    computed.pointer_events = *value;
+                }
+            }
+            PropertyId::HitShape => {
+                if let ParsedValue::HitShape(value) = &declaration.value {
+                    computed.hit_shape = value.clone();
+                }
+            }
             PropertyId::Color => {
                 computed.color = parse_color(&declaration.value).unwrap_or(computed.color)
             }
@@ -429,11 +498,17 @@ pub fn compute_style_with_context(parsed: &Style, ctx: StyleComputeContext<'_>)
                     computed.background_image = Some(value.clone());
                 }
             }
-            PropertyId::BorderImage => {
-                if let ParsedValue::Gradient(value) = &declaration.value {
+            PropertyId::BorderImage => match &declaration.value {
+                ParsedValue::Gradient(value) => {
                     computed.border_image = Some(value.clone());
+                    computed.border_nine_patch = None;
                 }
-            }
+                ParsedValue::NinePatch(value) => {
+                    computed.border_image = None;
+                    computed.border_nine_patch = Some(value.clone());
+                }
+                _ => {}
+            },
             PropertyId::FontFamily => {
                 if let ParsedValue::FontFamily(value) = &declaration.value {
                     computed.font_families = value.as_slice().to_vec();
@@ -543,6 +618,28 @@ pub fn compute_style_with_context(parsed: &Style, ctx: StyleComputeContext<'_>)
             PropertyId::BoxShadow => {
                 if let ParsedValue::BoxShadow(value) = &declaration.value {
                     computed.box_shadow = value.clone();
+                    has_explicit_box_shadow = true;
+                }
+            }
+            PropertyId::Elevation => {
+                if let ParsedValue::Elevation(value) = &declaration.value {
+                    computed.elevation = *value;
+                    has_elevation = true;
+                }
+            }
+            PropertyId::MixBlendMode => {
+                if let ParsedValue::MixBlendMode(value) = &declaration.value {
+                    computed.mix_blend_mode = *value;
+                }
+            }
+            PropertyId::Filter => {
+                if let ParsedValue::Filter(value) = &declaration.value {
+                    computed.filter = value.clone();
+                }
+            }
+            PropertyId::Mask => {
+                if let ParsedValue::Mask(value) = &declaration.value {
+                    computed.mask = Some(value.clone());
                 }
             }
             PropertyId::Transform => {
@@ -588,6 +685,11 @@ pub fn compute_style_with_context(parsed: &Style, ctx: StyleComputeContext<'_>)
     if !has_explicit_align {
         computed.align = computed.layout_axis_align();
     }
+    if has_elevation && !has_explicit_box_shadow {
+        computed.box_shadow = ElevationScale::current()
+            .level(computed.elevation)
+            .box_shadows();
+    }
 
     computed
 }
@@ -639,7 +741,9 @@ fn max4(a: f32, b: f32, c: f32, d: f32) -> f32 {
 
 #[cfg(test)]
 mod tests {
-    use super::{StyleComputeContext, compute_style, compute_style_with_context};
+    use super::{MediaContext, StyleComputeContext, compute_style, compute_style_with_context};
+    use crate::app::WindowTheme;
+    use crate::style::MediaQuery;
     use crate::style::{
         Align, CrossAxis, CrossSize, FlowDirection, FlowWrap, JustifyContent, Layout, Length,
     };
@@ -671,6 +775,23 @@ mod tests {
         assert_eq!(computed.box_shadow[1].offset_y, -1.5);
     }
 
+    #[test]
+    fn compute_style_resolves_elevation_unless_box_shadow_is_explicit() {
+        let preset = crate::style::ElevationScale::current().level(2);
+        let computed = compute_style(&Style::new().with_elevation(2), None);
+        assert_eq!(computed.elevation, 2);
+        assert_eq!(computed.box_shadow, preset.box_shadows());
+
+        let explicit = vec![BoxShadow::new().offset(1.0)];
+        let computed = compute_style(
+            &Style::new()
+                .with_box_shadow(explicit.clone())
+                .with_elevation(2),
+            None,
+        );
+        assert_eq!(computed.box_shadow, explicit);
+    }
+
     #[test]
     fn compute_style_resolves_font_size_relative_to_parent() {
         let mut parent_style = Style::new();
@@ -729,6 +850,7 @@ mod tests {
                 viewport_height: 480.0,
                 root_font_size: 24.0,
                 hovered: true,
+                media: MediaContext::default(),
             },
         );
 
@@ -763,6 +885,7 @@ mod tests {
                 viewport_height: 0.0,
                 root_font_size: 16.0,
                 hovered: true,
+                media: MediaContext::default(),
             },
         );
 
@@ -794,6 +917,7 @@ mod tests {
                 viewport_height: 0.0,
                 root_font_size: 16.0,
                 hovered: false,
+                media: MediaContext::default(),
             },
         );
 
@@ -818,12 +942,85 @@ mod tests {
                 viewport_height: 0.0,
                 root_font_size: 16.0,
                 hovered: true,
+                media: MediaContext::default(),
             },
         );
 
         assert_eq!(computed.opacity, 0.8);
     }
 
+    fn opacity_style(value: f32) -> Style {
+        let mut style = Style::new();
+        style.insert(
+            PropertyId::Opacity,
+            ParsedValue::Opacity(Opacity::new(value)),
+        );
+        style
+    }
+
+    fn compute_with_media(style: &Style, media: MediaContext, hovered: bool) -> f32 {
+        compute_style_with_context(
+            style,
+            StyleComputeContext {
+                parent: None,
+                viewport_width: media.viewport_width,
+                viewport_height: media.viewport_height,
+                root_font_size: 16.0,
+                hovered,
+                media,
+            },
+        )
+        .opacity
+    }
+
+    #[test]
+    fn matching_media_blocks_apply_in_authoring_order() {
+        let style = opacity_style(0.2)
+            .with_media(
+                MediaQuery {
+                    min_width: Some(800.0),
+                    ..MediaQuery::default()
+                },
+                opacity_style(0.5),
+            )
+            .with_media(
+                MediaQuery {
+                    min_width: Some(1200.0),
+                    theme: Some(WindowTheme::Dark),
+                    ..MediaQuery::default()
+                },
+                opacity_style(0.7),
+            )
+            .with_hover(opacity_style(0.9));
+        let media = |viewport_width, theme| MediaContext {
+            viewport_width,
+            viewport_height: 600.0,
+            scale_factor: 1.0,
+            theme,
+        };
+
+        assert_eq!(
+            compute_with_media(&style, media(640.0, WindowTheme::Dark), false),
+            0.2
+        );
+        assert_eq!(
+            compute_with_media(&style, media(800.0, WindowTheme::Dark), false),
+            0.5
+        );
+        assert_eq!(
+            compute_with_media(&style, media(1280.0, WindowTheme::Light), false),
+            0.5
+        );
+        assert_eq!(
+            compute_with_media(&style, media(1280.0, WindowTheme::Dark), false),
+            0.7
+        );
+        assert_eq!(
+            compute_with_media(&style, media(1280.0, WindowTheme::Dark), true),
+            0.9
+        );
+    }
+
     #[test]
     fn legacy_compute_style_does_not_apply_hover_style() {
         let mut style = Style::new();
@@ -859,6 +1056,7 @@ mod tests {
                 viewport_height: 0.0,
                 root_font_size: 16.0,
                 hovered: true,
+                media: MediaContext::default(),
             },
         );
 
@@ -897,6 +1095,7 @@ mod tests {
                 viewport_height: 600.0,
                 root_font_size: 20.0,
                 hovered: false,
+                media: MediaContext::default(),
             },
         );
 
@@ -918,6 +1117,7 @@ mod tests {
                 viewport_height: 600.0,
                 root_font_size: 16.0,
                 hovered: false,
+                media: MediaContext::default(),
             },
         );
 
@@ -934,6 +1134,7 @@ mod tests {
                 viewport_height: 600.0,
                 root_font_size: 16.0,
                 hovered: false,
+                media: MediaContext::default(),
             },
         );
 
@@ -963,6 +1164,7 @@ mod tests {
                 viewport_height: 600.0,
                 root_font_size: 24.0,
                 hovered: false,
+                media: MediaContext::default(),
             },
         );
 
@@ -979,6 +1181,7 @@ mod tests {
                 viewport_height: 600.0,
                 root_font_size: 24.0,
                 hovered: false,
+                media: MediaContext::default(),
             },
         );
 
@@ -1099,4 +1302,76 @@ mod tests {
         assert_eq!(computed.layout_axis_align(), Align::Start);
         assert_eq!(computed.layout_axis_cross_size(), CrossSize::Fit);
     }
+
+    #[test]
+    fn border_image_nine_patch_replaces_gradient() {
+        let source = crate::view::ImageSource::Path("frame.png".into());
+        let mut parent = Style::new();
+        parent.set_border_image(crate::style::Gradient::linear(
+            crate::style::SideOrCorner::Right,
+        ));
+        let mut style = parent.clone();
+        style.set_border_image(crate::style::NinePatch::new(source, 8.0));
+
+        let computed = compute_style(&style, None);
+        assert!(computed.border_image.is_none());
+        assert_eq!(
+            computed.border_nine_patch.map(|patch| patch.slice),
+            Some([8.0; 4])
+        );
+
+        let computed = compute_style(&parent, None);
+        assert!(computed.border_image.is_some());
+        assert!(computed.border_nine_patch.is_none());
+    }
+
+    #[test]
+    fn layer_effects_are_not_inherited() {
+        let mut parent = Style::new();
+        parent.set_mix_blend_mode(crate::style::MixBlendMode::Multiply);
+        parent.set_filter(vec![
+            crate::style::Filter::grayscale(1.0),
+            crate::style::Filter::blur(2.0),
+        ]);
+        parent.set_mask(crate::style::Gradient::linear(
+            crate::style::SideOrCorner::Bottom,
+        ));
+
+        let parent_computed = compute_style(&parent, None);
+        assert_eq!(
+            parent_computed.mix_blend_mode,
+            crate::style::MixBlendMode::Multiply
+        );
+        assert_eq!(parent_computed.filter.len(), 2);
+        assert!(matches!(
+            parent_computed.mask,
+            Some(crate::style::Mask::Gradient(_))
+        ));
+
+        let child = compute_style(&Style::new(), Some(&parent_computed));
+        assert_eq!(child.mix_blend_mode, crate::style::MixBlendMode::Normal);
+        assert!(child.filter.is_empty());
+        assert!(child.mask.is_none());
+    }
+
+    #[test]
+    fn mask_resolves_to_the_declared_source() {
+        let source = crate::view::ImageSource::Path("mask.png".into());
+        let mut style = Style::new();
+        style.set_mask(source.clone());
+        assert_eq!(
+            compute_style(&style, None).mask,
+            Some(crate::style::Mask::Image(source))
+        );
+
+        let gradient = crate::style::Gradient::linear(crate::style::SideOrCorner::Right)
+            .stop(Color::rgba(0, 0, 0, 255), None)
+            .stop(Color::rgba(0, 0, 0, 0), None)
+            .build();
+        style.set_mask(gradient.clone());
+        assert_eq!(
+            compute_style(&style, None).mask,
+            Some(crate::style::Mask::Gradient(gradient))
+        );
+    }
 }
@@ -0,0 +1,151 @@
+//! Elevation levels resolved to theme-defined shadow presets.
+//!
+//! ```ignore
+//! style={{
+//!     elevation: 1,
+//!     transition: [Transition::new(TransitionProperty::BoxShadow, 150)],
+//!     hover: { elevation: 3 },
+//! }}
+//! ```
+//!
+//! `elevation` computes to the ambient and key shadow of the matching
+//! [`ElevationPreset`] of the [`ElevationScale`] published on this thread,
+//! so changing the level animates like any other `box_shadow` change. An
+//! explicit `box_shadow` on the same element wins over the preset.
+
+use std::cell::{Cell, RefCell};
+use std::sync::Arc;
+
+use crate::style::{BoxShadow, Color};
+
+/// Shadows and stacking hint for one elevation level.
+#[derive(Debug, Clone, Copy, PartialEq, Default)]
+pub struct ElevationPreset {
+    /// Soft, wide shadow from diffuse light.
+    pub ambient: BoxShadow,
+    /// Sharper, offset shadow from the key light above.
+    pub key: BoxShadow,
+    /// Relative stacking order of surfaces at this level; higher levels
+    /// are expected to layer above lower ones.
+    pub z_order: i32,
+}
+
+impl ElevationPreset {
+    pub const fn new(ambient: BoxShadow, key: BoxShadow) -> Self {
+        Self {
+            ambient,
+            key,
+            z_order: 0,
+        }
+    }
+
+    /// No shadow, stacking order `0`.
+    pub fn flat() -> Self {
+        let none = BoxShadow::new().color(Color::transparent());
+        Self::new(none, none)
+    }
+
+    pub const fn z_order(mut self, z_order: i32) -> Self {
+        self.z_order = z_order;
+        self
+    }
+
+    /// Box shadows painting this level, ambient first. Fully transparent
+    /// shadows are dropped.
+    pub fn box_shadows(&self) -> Vec<BoxShadow> {
+        [self.ambient, self.key]
+            .into_iter()
+            .filter(|shadow| shadow.color.to_color().to_rgba_u8()[3] > 0)
+            .collect()
+    }
+}
+
+/// Presets for elevation levels `0..len`. Levels past the last preset use
+/// the last one.
+#[derive(Debug, Clone, PartialEq)]
+pub struct ElevationScale {
+    levels: Arc<[ElevationPreset]>,
+}
+
+impl Default for ElevationScale {
+    /// Six levels, `0` flat through `5` for dialogs and menus.
+    fn default() -> Self {
+        let shadow = |y: f32, blur: f32, spread: f32, alpha: u8| {
+            BoxShadow::new()
+                .color(Color::rgba(0, 0, 0, alpha))
+                .offset_y(y)
+                .blur(blur)
+                .spread(spread)
+        };
+        let raised = (1..=5).map(|level| {
+            let step = level as f32;
+            ElevationPreset::new(
+                shadow(step * 1.5, step * 5.0, step * 0.5, 31),
+                shadow(step, step * 2.0 + 1.0, 0.0, 61),
+            )
+            .z_order(level)
+        });
+        Self::new(std::iter::once(ElevationPreset::flat()).chain(raised))
+    }
+}
+
+impl ElevationScale {
+    pub fn new(levels: impl IntoIterator<Item = ElevationPreset>) -> Self {
+        Self {
+            levels: levels.into_iter().collect(),
+        }
+    }
+
+    pub fn levels(&self) -> &[ElevationPreset] {
+        &self.levels
+    }
+
+    /// Preset for `level`, clamped to the highest defined level. A scale
+    /// without levels resolves everything to [`ElevationPreset::flat`].
+    pub fn level(&self, level: u8) -> ElevationPreset {
+        let index = usize::from(level).min(self.levels.len().saturating_sub(1));
+        self.levels
+            .get(index)
+            .copied()
+            .unwrap_or_else(ElevationPreset::flat)
+    }
+}
+
+thread_local! {
+    static CURRENT_ELEVATION: RefCell<ElevationScale> = RefCell::new(ElevationScale::default());
+    static ELEVATION_GENERATION: Cell<u64> = const { Cell::new(0) };
+}
+
+impl ElevationScale {
+    /// Scale `elevation` resolves against on this thread.
+    pub fn current() -> Self {
+        CURRENT_ELEVATION.with(|scale| scale.borrow().clone())
+    }
+
+    /// Publish `scale` for subsequent style computation. Viewports restyle
+    /// elevated elements on their next frame. Returns whether it differs
+    /// from the previous scale.
+    pub fn set_current(scale: Self) -> bool {
+        let changed = CURRENT_ELEVATION.with(|current| {
+            let mut current = current.borrow_mut();
+            if *current == scale {
+                return false;
+            }
+            *current = scale;
+            true
+        });
+        if changed {
+            ELEVATION_GENERATION.with(|generation| generation.set(generation.get() + 1));
+        }
+        changed
+    }
+
+    /// Bumped by every [`ElevationScale::set_current`] that changed the
+    /// scale.
+    pub(crate) fn generation() -> u64 {
+        ELEVATION_GENERATION.with(Cell::get)
+    }
+}
+
+#[cfg(test)]
+mod tests;
@@ -0,0 +1,11 @@
+use super::*;
+
+#[test]
+fn levels_clamp_to_the_highest_preset_and_drop_transparent_shadows() {
+    let scale = ElevationScale::default();
+    assert!(scale.level(0).box_shadows().is_empty());
+    assert_eq!(scale.level(2).box_shadows().len(), 2);
+    assert_eq!(scale.level(200), scale.level(5));
+    assert_eq!(scale.level(5).z_order, 5);
+    assert_eq!(ElevationScale::new([]).level(3), ElevationPreset::flat());
+}
@@ -0,0 +1,98 @@
+#![allow(missing_docs)]
+
+//! Layer effect value types: `mix_blend_mode`, `filter` and `mask`.
+
+use crate::style::gradient::{ConicBuilder, Gradient, LinearBuilder, RadialBuilder};
+use crate::view::ImageSource;
+
+/// How an element's composited layer combines with what is already drawn
+/// behind it.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Hash)]
+pub enum MixBlendMode {
+    #[default]
+    Normal,
+    /// Darkens: backdrop and source channels are multiplied.
+    Multiply,
+    /// Lightens: the inverse of multiplying the inverted channels.
+    Screen,
+    /// Multiplies dark backdrop channels and screens light ones.
+    Overlay,
+}
+
+/// One entry of a `filter` list. Filters apply in order to the element's
+/// layer before it is composited.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum Filter {
+    /// Desaturates by `amount` in `0..=1`.
+    Grayscale(f32),
+    /// Scales color channels; `1` leaves the layer unchanged.
+    Brightness(f32),
+    /// Scales saturation; `0` is fully gray, values above `1` oversaturate.
+    Saturate(f32),
+    /// Gaussian blur radius in logical pixels.
+    Blur(f32),
+}
+
+impl Filter {
+    pub fn grayscale(amount: f32) -> Self {
+        Self::Grayscale(amount.clamp(0.0, 1.0))
+    }
+
+    pub fn brightness(amount: f32) -> Self {
+        Self::Brightness(amount.max(0.0))
+    }
+
+    pub fn saturate(amount: f32) -> Self {
+        Self::Saturate(amount.max(0.0))
+    }
+
+    pub fn blur(radius: f32) -> Self {
+        Self::Blur(radius.max(0.0))
+    }
+
+    /// Whether this entry leaves every pixel unchanged.
+    pub(crate) fn is_identity(self) -> bool {
+        match self {
+            Self::Grayscale(amount) | Self::Blur(amount) => amount <= 0.0,
+            Self::Brightness(amount) | Self::Saturate(amount) => amount == 1.0,
+        }
+    }
+}
+
+/// Where a `mask` takes its alpha from. The mask is stretched over the
+/// element's border box, and the element shows only where it is opaque.
+#[derive(Clone, Debug, PartialEq)]
+pub enum Mask {
+    Image(ImageSource),
+    Gradient(Gradient),
+}
+
+impl From<ImageSource> for Mask {
+    fn from(value: ImageSource) -> Self {
+        Self::Image(value)
+    }
+}
+
+impl From<Gradient> for Mask {
+    fn from(value: Gradient) -> Self {
+        Self::Gradient(value)
+    }
+}
+
+impl From<LinearBuilder> for Mask {
+    fn from(value: LinearBuilder) -> Self {
+        Self::Gradient(value.build())
+    }
+}
+
+impl From<RadialBuilder> for Mask {
+    fn from(value: RadialBuilder) -> Self {
+        Self::Gradient(value.build())
+    }
+}
+
+impl From<ConicBuilder> for Mask {
+    fn from(value: ConicBuilder) -> Self {
+        Self::Gradient(value.build())
+    }
+}
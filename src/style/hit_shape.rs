@@ -0,0 +1,181 @@
+//! Pointer hit regions beyond the rounded border box, used by `hit_shape`.
+
+use std::sync::Arc;
+
+use resvg::tiny_skia::PathSegment;
+use resvg::usvg;
+
+/// Line segments a quadratic / cubic curve is flattened into.
+const CURVE_STEPS: usize = 16;
+
+/// Region of an element that responds to the pointer.
+///
+/// Coordinates are logical pixels relative to the top-left corner of the
+/// border box. Outside the shape the pointer falls through to whatever lies
+/// beneath, exactly as outside the box.
+#[derive(Debug, Clone, PartialEq, Default)]
+pub enum HitShape {
+    /// The border box, honouring `border_radius`.
+    #[default]
+    Box,
+    /// Ellipse inscribed in the border box; a circle for square boxes.
+    Ellipse,
+    /// Closed polygon, even-odd filled.
+    Polygon(Arc<[(f32, f32)]>),
+    /// SVG path, non-zero filled. Build with [`HitShape::path`].
+    Path(HitPath),
+    /// Pixels whose alpha is at least the threshold in `0.0..=1.0`. Only
+    /// images carry pixels; other elements hit-test as [`HitShape::Box`].
+    Alpha(f32),
+}
+
+impl HitShape {
+    pub fn polygon(points: impl IntoIterator<Item = (f32, f32)>) -> Self {
+        Self::Polygon(points.into_iter().collect())
+    }
+
+    /// Parse SVG path data (`d` attribute syntax). `None` when the data
+    /// does not describe a fillable path.
+    pub fn path(data: &str) -> Option<Self> {
+        HitPath::parse(data).map(Self::Path)
+    }
+
+    /// Whether the border-box-local point (`x`, `y`) lies inside the shape
+    /// of a `width` x `height` box. [`HitShape::Box`] and
+    /// [`HitShape::Alpha`] always contain it; the box test is done by the
+    /// caller.
+    pub fn contains(&self, x: f32, y: f32, width: f32, height: f32) -> bool {
+        match self {
+            Self::Box | Self::Alpha(_) => true,
+            Self::Ellipse => {
+                let (rx, ry) = (width * 0.5, height * 0.5);
+                if rx <= 0.0 || ry <= 0.0 {
+                    return false;
+                }
+                let (dx, dy) = ((x - rx) / rx, (y - ry) / ry);
+                dx * dx + dy * dy <= 1.0
+            }
+            Self::Polygon(points) => winding(points, x, y).1,
+            Self::Path(path) => path.contains(x, y),
+        }
+    }
+}
+
+/// Flattened SVG path for [`HitShape::Path`].
+#[derive(Debug, Clone, PartialEq)]
+pub struct HitPath {
+    source: Arc<str>,
+    contours: Arc<[Vec<(f32, f32)>]>,
+}
+
+impl HitPath {
+    pub fn parse(data: &str) -> Option<Self> {
+        let document = format!(
+            r#"<svg xmlns="http://www.w3.org/2000/svg"><path d="{}"/></svg>"#,
+            data.replace('"', "")
+        );
+        let tree = usvg::Tree::from_str(&document, &usvg::Options::default()).ok()?;
+        let mut contours = Vec::new();
+        collect_contours(tree.root(), &mut contours);
+        if contours.is_empty() {
+            return None;
+        }
+        Some(Self {
+            source: data.into(),
+            contours: contours.into(),
+        })
+    }
+
+    /// Path data this shape was parsed from.
+    pub fn source(&self) -> &str {
+        &self.source
+    }
+
+    fn contains(&self, x: f32, y: f32) -> bool {
+        self.contours
+            .iter()
+            .map(|contour| winding(contour, x, y).0)
+            .sum::<i32>()
+            != 0
+    }
+}
+
+fn collect_contours(group: &usvg::Group, contours: &mut Vec<Vec<(f32, f32)>>) {
+    for node in group.children() {
+        match node {
+            usvg::Node::Group(group) => collect_contours(group, contours),
+            usvg::Node::Path(path) => flatten(path.data(), contours),
+            _ => {}
+        }
+    }
+}
+
+fn flatten(path: &resvg::tiny_skia::Path, contours: &mut Vec<Vec<(f32, f32)>>) {
+    let mut current: Vec<(f32, f32)> = Vec::new();
+    for segment in path.segments() {
+        let last = current.last().copied().unwrap_or((0.0, 0.0));
+        match segment {
+            PathSegment::MoveTo(point) => {
+                if current.len() > 2 {
+                    contours.push(std::mem::take(&mut current));
+                }
+                current.clear();
+                current.push((point.x, point.y));
+            }
+            PathSegment::LineTo(point) => current.push((point.x, point.y)),
+            PathSegment::QuadTo(control, point) => {
+                for step in 1..=CURVE_STEPS {
+                    let t = step as f32 / CURVE_STEPS as f32;
+                    let u = 1.0 - t;
+                    current.push((
+                        u * u * last.0 + 2.0 * u * t * control.x + t * t * point.x,
+                        u * u * last.1 + 2.0 * u * t * control.y + t * t * point.y,
+                    ));
+                }
+            }
+            PathSegment::CubicTo(first, second, point) => {
+                for step in 1..=CURVE_STEPS {
+                    let t = step as f32 / CURVE_STEPS as f32;
+                    let u = 1.0 - t;
+                    let (a, b, c, d) = (u * u * u, 3.0 * u * u * t, 3.0 * u * t * t, t * t * t);
+                    current.push((
+                        a * last.0 + b * first.x + c * second.x + d * point.x,
+                        a * last.1 + b * first.y + c * second.y + d * point.y,
+                    ));
+                }
+            }
+            PathSegment::Close => {
+                if current.len() > 2 {
+                    contours.push(std::mem::take(&mut current));
+                }
+                current.clear();
+            }
+        }
+    }
+    if current.len() > 2 {
+        contours.push(current);
+    }
+}
+
+/// Non-zero winding number of `contour` around (`x`, `y`), and whether the
+/// point is inside under the even-odd rule.
+fn winding(contour: &[(f32, f32)], x: f32, y: f32) -> (i32, bool) {
+    let mut winding = 0;
+    let mut crossings = 0;
+    let Some(&last) = contour.last() else {
+        return (0, false);
+    };
+    let mut previous = last;
+    for &point in contour {
+        let ((x0, y0), (x1, y1)) = (previous, point);
+        if (y0 <= y) != (y1 <= y) {
+            let cross_x = x0 + (y - y0) / (y1 - y0) * (x1 - x0);
+            if x < cross_x {
+                crossings += 1;
+                winding += if y1 > y0 { 1 } else { -1 };
+            }
+        }
+        previous = point;
+    }
+    (winding, crossings % 2 == 1)
+}
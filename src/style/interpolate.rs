@@ -1,8 +1,9 @@
 #![allow(missing_docs)]
 
 use crate::style::{
-    Angle, BoxShadow, Color, ColorLike, Length, OklchColor, StyleColor, Transform, TransformEntry,
-    TransformKind, TransformOrigin, linear_to_srgb_f32,
+    Angle, BoxShadow, Color, ColorLike, ColorSpace, DisplayP3Color, Length, OklchColor, StyleColor,
+    Transform, TransformEntry, TransformKind, TransformOrigin, convert_linear_rgba,
+    linear_to_srgb_f32,
 };
 use glam::{EulerRot, Mat4, Quat, Vec2, Vec3, Vec4};
 
@@ -215,6 +216,55 @@ impl Interpolate for Color {
     }
 }
 
+/// Color space a color transition blends through.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+pub enum ColorInterpolation {
+    /// Blend the gamma-encoded sRGB channels directly.
+    Srgb,
+    /// Blend in the perceptually uniform OKLab space.
+    #[default]
+    Oklab,
+    /// Blend lightness, chroma, and hue along the shorter arc in OKLCH, which
+    /// keeps saturated pairs vivid through the midpoint.
+    Oklch,
+}
+
+/// Interpolate two colors through `space`.
+pub fn interpolate_color_in(from: &Color, to: &Color, t: f32, space: ColorInterpolation) -> Color {
+    let t = t.clamp(0.0, 1.0);
+    match space {
+        ColorInterpolation::Srgb => {
+            let from = from.to_rgba_u8();
+            let to = to.to_rgba_u8();
+            let channel = |index: usize| {
+                f32::interpolate(&(from[index] as f32), &(to[index] as f32), t).round() as u8
+            };
+            Color::rgba(channel(0), channel(1), channel(2), channel(3))
+        }
+        ColorInterpolation::Oklab => interpolate_oklab_colorlike(from, to, t),
+        ColorInterpolation::Oklch => {
+            let mut from = OklchColor::from_linear_rgba(from.to_rgba_f32());
+            let mut to = OklchColor::from_linear_rgba(to.to_rgba_f32());
+            // An achromatic end has no meaningful hue; borrow the other
+            // end's so grays fade in and out of a color without a hue sweep.
+            if from.c() < ACHROMATIC_CHROMA {
+                from = OklchColor::new(from.l(), from.c(), to.h(), from.a());
+            } else if to.c() < ACHROMATIC_CHROMA {
+                to = OklchColor::new(to.l(), to.c(), from.h(), to.a());
+            }
+            let [r, g, b, a] = interpolate_oklch(&from, &to, t).to_rgba_f32();
+            Color::rgba(
+                linear_to_u8(r),
+                linear_to_u8(g),
+                linear_to_u8(b),
+                (a.clamp(0.0, 1.0) * 255.0).round() as u8,
+            )
+        }
+    }
+}
+
+const ACHROMATIC_CHROMA: f32 = 1e-4;
+
 impl Interpolate for StyleColor {
     fn interpolate(from: &Self, to: &Self, t: f32) -> Self {
         let t = t.clamp(0.0, 1.0);
@@ -222,6 +272,14 @@ impl Interpolate for StyleColor {
             (StyleColor::Oklch(from), StyleColor::Oklch(to)) => {
                 StyleColor::Oklch(interpolate_oklch(from, to, t))
             }
+            (StyleColor::DisplayP3(_), _) | (_, StyleColor::DisplayP3(_)) => {
+                let linear = interpolate_oklab_linear(from, to, t);
+                StyleColor::DisplayP3(DisplayP3Color::from_linear_rgba(convert_linear_rgba(
+                    linear,
+                    ColorSpace::Srgb,
+                    ColorSpace::DisplayP3,
+                )))
+            }
             _ => StyleColor::Srgb(interpolate_oklab_colorlike(from, to, t)),
         }
     }
@@ -257,13 +315,7 @@ fn interpolate_oklch(from: &OklchColor, to: &OklchColor, t: f32) -> OklchColor {
 }
 
 fn interpolate_oklab_colorlike(from: &dyn ColorLike, to: &dyn ColorLike, t: f32) -> Color {
-    let [fl, fa, fb, falpha] = rgba_to_oklab(from.to_rgba_f32());
-    let [tl, ta, tb, talpha] = rgba_to_oklab(to.to_rgba_f32());
-    let l = f32::interpolate(&fl, &tl, t);
-    let a = f32::interpolate(&fa, &ta, t);
-    let b = f32::interpolate(&fb, &tb, t);
-    let alpha = f32::interpolate(&falpha, &talpha, t);
-    let [r, g, b] = oklab_to_linear_rgb(l, a, b);
+    let [r, g, b, alpha] = interpolate_oklab_linear(from, to, t);
     Color::rgba(
         linear_to_u8(r),
         linear_to_u8(g),
@@ -272,6 +324,19 @@ fn interpolate_oklab_colorlike(from: &dyn ColorLike, to: &dyn ColorLike, t: f32)
     )
 }
 
+/// OKLab blend returning unclamped linear sRGB, so wide-gamut ends stay
+/// outside the sRGB gamut mid-transition.
+fn interpolate_oklab_linear(from: &dyn ColorLike, to: &dyn ColorLike, t: f32) -> [f32; 4] {
+    let [fl, fa, fb, falpha] = rgba_to_oklab(from.to_linear_srgb_f32());
+    let [tl, ta, tb, talpha] = rgba_to_oklab(to.to_linear_srgb_f32());
+    let l = f32::interpolate(&fl, &tl, t);
+    let a = f32::interpolate(&fa, &ta, t);
+    let b = f32::interpolate(&fb, &tb, t);
+    let alpha = f32::interpolate(&falpha, &talpha, t);
+    let [r, g, b] = oklab_to_linear_rgb(l, a, b);
+    [r, g, b, alpha]
+}
+
 fn rgba_to_oklab(rgba: [f32; 4]) -> [f32; 4] {
     let l = 0.412_221_46 * rgba[0] + 0.536_332_55 * rgba[1] + 0.051_445_995 * rgba[2];
     let m = 0.211_903_5 * rgba[0] + 0.680_699_5 * rgba[1] + 0.107_396_96 * rgba[2];
@@ -297,9 +362,9 @@ fn oklab_to_linear_rgb(l: f32, a: f32, b: f32) -> [f32; 3] {
     let s = s_.powi(3);
 
     [
-        (4.076_741_7 * l - 3.307_711_6 * m + 0.230_969_94 * s).clamp(0.0, 1.0),
-        (-1.268_438 * l + 2.609_757_4 * m - 0.341_319_38 * s).clamp(0.0, 1.0),
-        (-0.004_196_086_3 * l - 0.703_418_6 * m + 1.707_614_7 * s).clamp(0.0, 1.0),
+        4.076_741_7 * l - 3.307_711_6 * m + 0.230_969_94 * s,
+        -1.268_438 * l + 2.609_757_4 * m - 0.341_319_38 * s,
+        -0.004_196_086_3 * l - 0.703_418_6 * m + 1.707_614_7 * s,
     ]
 }
 
@@ -488,8 +553,8 @@ impl Interpolate for Vec<BoxShadow> {
 #[cfg(test)]
 mod tests {
     use super::{
-        Interpolate, interpolate_transform_origin_with_reference_box,
-        interpolate_transform_with_reference_box,
+        ColorInterpolation, Interpolate, interpolate_color_in,
+        interpolate_transform_origin_with_reference_box, interpolate_transform_with_reference_box,
     };
     use crate::style::{
         Angle, BoxShadow, Color, ColorLike, Length, OklchColor, Rotate, Scale, StyleColor,
@@ -507,6 +572,29 @@ mod tests {
         assert_eq!(value.to_rgba_u8(), [99, 99, 99, 128]);
     }
 
+    #[test]
+    fn color_interpolation_space_changes_the_midpoint() {
+        let red = Color::rgba(255, 0, 0, 255);
+        let blue = Color::rgba(0, 0, 255, 255);
+        let srgb = interpolate_color_in(&red, &blue, 0.5, ColorInterpolation::Srgb);
+        assert_eq!(srgb.to_rgba_u8(), [128, 0, 128, 255]);
+        assert_eq!(
+            interpolate_color_in(&red, &blue, 0.5, ColorInterpolation::Oklab),
+            Color::interpolate(&red, &blue, 0.5),
+        );
+
+        let chroma = |color: Color| OklchColor::from_linear_rgba(color.to_rgba_f32()).c();
+        let oklch = interpolate_color_in(&red, &blue, 0.5, ColorInterpolation::Oklch);
+        assert!(
+            chroma(oklch) > chroma(srgb),
+            "OKLCH keeps the midpoint saturated"
+        );
+
+        let gray = Color::rgba(128, 128, 128, 255);
+        let start = interpolate_color_in(&gray, &red, 0.0, ColorInterpolation::Oklch);
+        assert_eq!(start.to_rgba_u8(), gray.to_rgba_u8());
+    }
+
     #[test]
     fn style_color_prefers_oklch_for_oklch_pairs() {
         let from = StyleColor::Oklch(OklchColor::new(0.3, 0.15, 20.0, 1.0));
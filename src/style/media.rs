@@ -0,0 +1,100 @@
+//! Conditional style blocks evaluated against the viewport.
+//!
+//! ```ignore
+//! style={{
+//!     width: "100%",
+//!     media(min_width: 800.0): { width: "50%" },
+//!     media(theme: WindowTheme::Dark): { background_color: "#111" },
+//! }}
+//! ```
+//!
+//! Matching blocks are merged over the base declarations in authoring
+//! order by [`crate::style::compute_style_with_context`]. The viewport
+//! publishes its [`MediaContext`] whenever its size, scale factor or window
+//! theme changes and restyles only the elements whose set of matching
+//! blocks changed.
+
+use std::cell::Cell;
+
+use crate::app::WindowTheme;
+
+/// Conditions of one `media(...)` block. Unset bounds always match; set
+/// bounds are inclusive, in logical pixels for the sizes.
+#[derive(Clone, Copy, Debug, Default, PartialEq)]
+pub struct MediaQuery {
+    pub min_width: Option<f32>,
+    pub max_width: Option<f32>,
+    pub min_height: Option<f32>,
+    pub max_height: Option<f32>,
+    pub min_scale_factor: Option<f32>,
+    pub max_scale_factor: Option<f32>,
+    pub theme: Option<WindowTheme>,
+}
+
+impl MediaQuery {
+    pub fn matches(&self, media: &MediaContext) -> bool {
+        fn within(value: f32, min: Option<f32>, max: Option<f32>) -> bool {
+            min.is_none_or(|min| value >= min) && max.is_none_or(|max| value <= max)
+        }
+        within(media.viewport_width, self.min_width, self.max_width)
+            && within(media.viewport_height, self.min_height, self.max_height)
+            && within(
+                media.scale_factor,
+                self.min_scale_factor,
+                self.max_scale_factor,
+            )
+            && self.theme.is_none_or(|theme| theme == media.theme)
+    }
+}
+
+/// A `media(...)` block: `style` applies while `query` matches.
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct MediaRule<S> {
+    pub query: MediaQuery,
+    pub style: S,
+}
+
+impl<S> MediaRule<S> {
+    pub fn new(query: MediaQuery, style: S) -> Self {
+        Self { query, style }
+    }
+}
+
+/// Viewport state media queries are evaluated against.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct MediaContext {
+    /// Logical width of the viewport.
+    pub viewport_width: f32,
+    /// Logical height of the viewport.
+    pub viewport_height: f32,
+    pub scale_factor: f32,
+    pub theme: WindowTheme,
+}
+
+impl Default for MediaContext {
+    fn default() -> Self {
+        Self {
+            viewport_width: 0.0,
+            viewport_height: 0.0,
+            scale_factor: 1.0,
+            theme: WindowTheme::Light,
+        }
+    }
+}
+
+thread_local! {
+    static CURRENT_MEDIA: Cell<MediaContext> = Cell::new(MediaContext::default());
+}
+
+impl MediaContext {
+    /// Context last published by the viewport on this thread.
+    pub fn current() -> Self {
+        CURRENT_MEDIA.with(Cell::get)
+    }
+
+    /// Publish `media` for subsequent style computation. Returns whether
+    /// it differs from the previous context.
+    pub(crate) fn set_current(media: Self) -> bool {
+        CURRENT_MEDIA.with(|slot| slot.replace(media)) != media
+    }
+}
@@ -1,14 +1,30 @@
 mod background;
+mod border_image;
 mod color;
+mod compose;
 mod computed_style;
+mod elevation;
+mod filter;
 mod gradient;
+mod hit_shape;
 mod interpolate;
+mod media;
 mod parsed_style;
 pub(crate) mod style_props;
+mod style_string;
+mod user_agent;
 
 pub use background::*;
+pub use border_image::*;
 pub use color::*;
+pub use compose::*;
 pub use computed_style::*;
+pub use elevation::*;
+pub use filter::*;
 pub use gradient::*;
+pub use hit_shape::*;
 pub use interpolate::*;
+pub use media::*;
 pub use parsed_style::*;
+pub use style_string::*;
+pub use user_agent::*;
@@ -3,8 +3,14 @@
 //! Typed parsed-style declarations accepted by RSX and style construction APIs.
 
 use crate::style::background::Background;
+use crate::style::border_image::{BorderImage, NinePatch};
 use crate::style::color::{Color, ColorLike, IntoColor, OklchColor, StyleColor};
+use crate::style::filter::{Filter, Mask, MixBlendMode};
 use crate::style::gradient::Gradient;
+use crate::style::hit_shape::HitShape;
+use crate::style::interpolate::ColorInterpolation;
+use crate::style::media::{MediaContext, MediaQuery, MediaRule};
+use crate::transition::{CubicBezier, StepPosition};
 
 use rustc_hash::FxHashMap;
 use std::ops::Add;
@@ -37,7 +43,11 @@ pub(crate) enum PropertyId {
     PaddingLeft,
     Gap,
     ScrollDirection,
+    Scrollbar,
     Cursor,
+    AppRegion,
+    PointerEvents,
+    HitShape,
     Color,
     BackgroundColor,
     BackgroundImage,
@@ -64,6 +74,10 @@ pub(crate) enum PropertyId {
     BorderLeftColor,
     Opacity,
     BoxShadow,
+    Elevation,
+    MixBlendMode,
+    Filter,
+    Mask,
     Transform,
     TransformOrigin,
     Transition,
@@ -100,6 +114,20 @@ pub enum TransitionTiming {
     EaseIn,
     EaseOut,
     EaseInOut,
+    CubicBezier(CubicBezier),
+    Steps(u32, StepPosition),
+}
+
+impl TransitionTiming {
+    /// CSS `cubic-bezier(x1, y1, x2, y2)`.
+    pub const fn cubic_bezier(x1: f32, y1: f32, x2: f32, y2: f32) -> Self {
+        Self::CubicBezier(CubicBezier::new(x1, y1, x2, y2))
+    }
+
+    /// CSS `steps(count, position)`.
+    pub const fn steps(count: u32, position: StepPosition) -> Self {
+        Self::Steps(count, position)
+    }
 }
 
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
@@ -247,6 +275,14 @@ impl Animation {
         self.timing(TransitionTiming::EaseInOut)
     }
 
+    pub const fn cubic_bezier(self, x1: f32, y1: f32, x2: f32, y2: f32) -> Self {
+        self.timing(TransitionTiming::cubic_bezier(x1, y1, x2, y2))
+    }
+
+    pub const fn steps(self, count: u32, position: StepPosition) -> Self {
+        self.timing(TransitionTiming::steps(count, position))
+    }
+
     pub const fn repeat(mut self, repeat: Repeat) -> Self {
         self.repeat = Some(repeat);
         self
@@ -390,6 +426,14 @@ impl Animator {
         self.timing(TransitionTiming::EaseInOut)
     }
 
+    pub const fn cubic_bezier(self, x1: f32, y1: f32, x2: f32, y2: f32) -> Self {
+        self.timing(TransitionTiming::cubic_bezier(x1, y1, x2, y2))
+    }
+
+    pub const fn steps(self, count: u32, position: StepPosition) -> Self {
+        self.timing(TransitionTiming::steps(count, position))
+    }
+
     pub fn is_empty(&self) -> bool {
         self.animations.is_empty()
     }
@@ -441,6 +485,8 @@ pub struct Transition {
     pub duration_ms: u32,
     pub delay_ms: u32,
     pub timing: TransitionTiming,
+    /// Color space color properties blend through.
+    pub color_interpolation: ColorInterpolation,
 }
 
 impl Transition {
@@ -450,6 +496,7 @@ impl Transition {
             duration_ms,
             delay_ms: 0,
             timing: TransitionTiming::Linear,
+            color_interpolation: ColorInterpolation::Oklab,
         }
     }
 
@@ -478,6 +525,19 @@ impl Transition {
     pub const fn ease_in_out(self) -> Self {
         self.timing(TransitionTiming::EaseInOut)
     }
+
+    pub const fn cubic_bezier(self, x1: f32, y1: f32, x2: f32, y2: f32) -> Self {
+        self.timing(TransitionTiming::cubic_bezier(x1, y1, x2, y2))
+    }
+
+    pub const fn steps(self, count: u32, position: StepPosition) -> Self {
+        self.timing(TransitionTiming::steps(count, position))
+    }
+
+    pub const fn color_interpolation(mut self, space: ColorInterpolation) -> Self {
+        self.color_interpolation = space;
+        self
+    }
 }
 
 #[derive(Debug, Clone, PartialEq, Eq, Default)]
@@ -735,6 +795,141 @@ pub enum ScrollDirection {
     Both,
 }
 
+/// How a scroll container's scrollbars share space with its content.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ScrollbarMode {
+    /// Scrollbars float above the content and take no layout space.
+    Overlay,
+    /// Layout reserves a gutter on the trailing edge of each scrollable axis
+    /// and the scrollbar is drawn inside it.
+    Gutter,
+}
+
+/// Appearance and visibility of a scroll container's scrollbars.
+///
+/// The default reproduces the built-in look: a 6px overlay bar with a fully
+/// rounded thumb that fades out 900ms after the last interaction.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct ScrollbarStyle {
+    width: f32,
+    thumb_color: Option<Color>,
+    track_color: Option<Color>,
+    thumb_radius: Option<f32>,
+    mode: ScrollbarMode,
+    auto_hide: Option<(u32, u32)>,
+}
+
+impl ScrollbarStyle {
+    pub const DEFAULT_WIDTH: f32 = 6.0;
+    pub const DEFAULT_HOLD_MS: u32 = 900;
+    pub const DEFAULT_FADE_MS: u32 = 350;
+
+    pub const fn new() -> Self {
+        Self {
+            width: Self::DEFAULT_WIDTH,
+            thumb_color: None,
+            track_color: None,
+            thumb_radius: None,
+            mode: ScrollbarMode::Overlay,
+            auto_hide: Some((Self::DEFAULT_HOLD_MS, Self::DEFAULT_FADE_MS)),
+        }
+    }
+
+    /// Thickness of the track and thumb in px.
+    pub const fn width(mut self, width: f32) -> Self {
+        self.width = width;
+        self
+    }
+
+    /// Sets thumb and track colors, in that order.
+    pub fn color<T: ColorLike, U: ColorLike>(mut self, thumb: T, track: U) -> Self {
+        self.thumb_color = Some(thumb.to_style_color().to_color());
+        self.track_color = Some(track.to_style_color().to_color());
+        self
+    }
+
+    pub fn thumb_color<T: ColorLike>(mut self, color: T) -> Self {
+        self.thumb_color = Some(color.to_style_color().to_color());
+        self
+    }
+
+    pub fn track_color<T: ColorLike>(mut self, color: T) -> Self {
+        self.track_color = Some(color.to_style_color().to_color());
+        self
+    }
+
+    /// Corner radius of the thumb and track. Defaults to half the width.
+    pub const fn thumb_radius(mut self, radius: f32) -> Self {
+        self.thumb_radius = Some(radius);
+        self
+    }
+
+    pub const fn mode(mut self, mode: ScrollbarMode) -> Self {
+        self.mode = mode;
+        self
+    }
+
+    pub const fn overlay(self) -> Self {
+        self.mode(ScrollbarMode::Overlay)
+    }
+
+    pub const fn gutter(self) -> Self {
+        self.mode(ScrollbarMode::Gutter)
+    }
+
+    /// Keeps the scrollbar opaque for `hold_ms` after the last interaction,
+    /// then fades it out over `fade_ms`.
+    pub const fn auto_hide(mut self, hold_ms: u32, fade_ms: u32) -> Self {
+        self.auto_hide = Some((hold_ms, fade_ms));
+        self
+    }
+
+    /// Keeps the scrollbar visible whenever the container can scroll.
+    pub const fn always_visible(mut self) -> Self {
+        self.auto_hide = None;
+        self
+    }
+
+    pub fn resolved_width(&self) -> f32 {
+        if self.width.is_finite() {
+            self.width.max(0.0)
+        } else {
+            Self::DEFAULT_WIDTH
+        }
+    }
+
+    pub const fn resolved_thumb_color(&self) -> Option<Color> {
+        self.thumb_color
+    }
+
+    pub const fn resolved_track_color(&self) -> Option<Color> {
+        self.track_color
+    }
+
+    pub fn resolved_thumb_radius(&self) -> f32 {
+        let width = self.resolved_width();
+        self.thumb_radius
+            .filter(|radius| radius.is_finite())
+            .map(|radius| radius.clamp(0.0, width * 0.5))
+            .unwrap_or(width * 0.5)
+    }
+
+    pub const fn resolved_mode(&self) -> ScrollbarMode {
+        self.mode
+    }
+
+    /// `Some((hold_ms, fade_ms))`, or `None` when the bar never hides.
+    pub const fn auto_hide_timing(&self) -> Option<(u32, u32)> {
+        self.auto_hide
+    }
+}
+
+impl Default for ScrollbarStyle {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
 pub enum Cursor {
     Default,
@@ -775,12 +970,41 @@ pub enum Cursor {
     AllResize,
 }
 
+/// How a region of a frameless window reacts to a primary-button press.
+///
+/// `Drag` marks client-side title-bar areas: pressing moves the host window
+/// and a double press runs the viewport's title-bar double-click action.
+/// `NoDrag` carves interactive children (window buttons, tabs) back out of an
+/// enclosing drag area. `Auto` defers to the nearest ancestor that sets one.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum AppRegion {
+    #[default]
+    Auto,
+    Drag,
+    NoDrag,
+}
+
+/// Whether an element takes part in pointer hit testing.
+///
+/// `None` makes the element and its whole subtree transparent to the
+/// pointer, so decorative overlays (gradients, badges, drag ghosts) never
+/// steal hover or clicks from what lies beneath.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum PointerEvents {
+    #[default]
+    Auto,
+    None,
+}
+
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
 pub enum PositionMode {
     Static,
     Relative,
     Absolute,
     Fixed,
+    /// Stays in normal flow, but is shifted by its insets to remain inside the
+    /// nearest scroll container's visible rect while its parent is in view.
+    Sticky,
 }
 
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
@@ -797,6 +1021,110 @@ pub enum CollisionBoundary {
     Parent,
 }
 
+/// Where an anchored element sits along one axis of its anchor's rect.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum AnchorAlign {
+    /// Outside, ending where the anchor starts (above / to the left).
+    Before,
+    /// Start edges aligned.
+    Start,
+    /// Centered on the anchor.
+    Center,
+    /// End edges aligned.
+    End,
+    /// Outside, starting where the anchor ends (below / to the right).
+    After,
+}
+
+impl AnchorAlign {
+    /// `Before` and `After` swapped; inner alignments are unchanged.
+    pub const fn mirrored(self) -> Self {
+        match self {
+            Self::Before => Self::After,
+            Self::After => Self::Before,
+            other => other,
+        }
+    }
+}
+
+/// Alignment of an anchored element against both axes of its anchor.
+///
+/// The presets name the side the element sits on, then where it lines up
+/// along that side: `bottom_start()` hangs below the anchor with left edges
+/// aligned.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct AnchorPlacement {
+    x: AnchorAlign,
+    y: AnchorAlign,
+}
+
+impl AnchorPlacement {
+    pub const fn new(x: AnchorAlign, y: AnchorAlign) -> Self {
+        Self { x, y }
+    }
+
+    pub const fn top() -> Self {
+        Self::new(AnchorAlign::Center, AnchorAlign::Before)
+    }
+
+    pub const fn top_start() -> Self {
+        Self::new(AnchorAlign::Start, AnchorAlign::Before)
+    }
+
+    pub const fn top_end() -> Self {
+        Self::new(AnchorAlign::End, AnchorAlign::Before)
+    }
+
+    pub const fn bottom() -> Self {
+        Self::new(AnchorAlign::Center, AnchorAlign::After)
+    }
+
+    pub const fn bottom_start() -> Self {
+        Self::new(AnchorAlign::Start, AnchorAlign::After)
+    }
+
+    pub const fn bottom_end() -> Self {
+        Self::new(AnchorAlign::End, AnchorAlign::After)
+    }
+
+    pub const fn left() -> Self {
+        Self::new(AnchorAlign::Before, AnchorAlign::Center)
+    }
+
+    pub const fn left_start() -> Self {
+        Self::new(AnchorAlign::Before, AnchorAlign::Start)
+    }
+
+    pub const fn left_end() -> Self {
+        Self::new(AnchorAlign::Before, AnchorAlign::End)
+    }
+
+    pub const fn right() -> Self {
+        Self::new(AnchorAlign::After, AnchorAlign::Center)
+    }
+
+    pub const fn right_start() -> Self {
+        Self::new(AnchorAlign::After, AnchorAlign::Start)
+    }
+
+    pub const fn right_end() -> Self {
+        Self::new(AnchorAlign::After, AnchorAlign::End)
+    }
+
+    pub const fn x(self) -> AnchorAlign {
+        self.x
+    }
+
+    pub const fn y(self) -> AnchorAlign {
+        self.y
+    }
+
+    /// The same placement on the opposite side(s) of the anchor.
+    pub const fn mirrored(self) -> Self {
+        Self::new(self.x.mirrored(), self.y.mirrored())
+    }
+}
+
 /// Clip rect chosen for an absolutely-positioned element.
 ///
 /// Only meaningful when [`Position::mode`] is [`PositionMode::Absolute`] (or
@@ -975,7 +1303,14 @@ pub struct Position {
     bottom: Option<Length>,
     left: Option<Length>,
     self_origin: Option<Origin>,
+    translate_x: Option<Length>,
+    translate_y: Option<Length>,
+    placement: Option<AnchorPlacement>,
+    fallback_placements: Vec<AnchorPlacement>,
+    anchor_offset: Option<Length>,
     collision: Collision,
+    collision_padding: Option<Length>,
+    boundary_insets: Option<Box<Padding>>,
     collision_boundary: CollisionBoundary,
     clip_mode: ClipMode,
 }
@@ -997,6 +1332,10 @@ impl Position {
         Self::new(PositionMode::Fixed)
     }
 
+    pub const fn sticky() -> Self {
+        Self::new(PositionMode::Sticky)
+    }
+
     pub const fn mode(&self) -> PositionMode {
         self.mode
     }
@@ -1032,6 +1371,23 @@ impl Position {
         self
     }
 
+    /// Space kept between self and every edge of the collision boundary
+    /// when fallbacks are tried and when `Fit` clamps. `Length` percent
+    /// base is the boundary's shorter side.
+    pub const fn collision_padding(mut self, value: Length) -> Self {
+        self.collision_padding = Some(value);
+        self
+    }
+
+    /// Shrinks the collision boundary per edge before
+    /// [`Self::collision_padding`] applies, e.g. to keep clear of a title
+    /// bar or an OS safe area. `Length` percent base is the boundary's
+    /// width for `left` / `right` and its height for `top` / `bottom`.
+    pub fn boundary_insets(mut self, insets: Padding) -> Self {
+        self.boundary_insets = Some(Box::new(insets));
+        self
+    }
+
     pub const fn clip(mut self, mode: ClipMode) -> Self {
         self.clip_mode = mode;
         self
@@ -1049,6 +1405,72 @@ impl Position {
         self.self_origin
     }
 
+    /// Shifts self horizontally after insets and `origin` are applied,
+    /// before collision handling. `Length` percent base is self's width,
+    /// so `translate_x(Length::percent(-50.0))` centers self on the
+    /// placement point. Only applied when `mode` is `Absolute` or `Fixed`.
+    pub const fn translate_x(mut self, value: Length) -> Self {
+        self.translate_x = Some(value);
+        self
+    }
+
+    /// Vertical counterpart of [`Self::translate_x`]; percent base is
+    /// self's height.
+    pub const fn translate_y(mut self, value: Length) -> Self {
+        self.translate_y = Some(value);
+        self
+    }
+
+    pub const fn translation_x(&self) -> Option<Length> {
+        self.translate_x
+    }
+
+    pub const fn translation_y(&self) -> Option<Length> {
+        self.translate_y
+    }
+
+    /// Places self against the anchor rect by alignment instead of insets:
+    /// insets and `origin` are ignored for positioning, `translate_x` /
+    /// `translate_y` still apply on top. Only applied when `mode` is
+    /// `Absolute` or `Fixed`.
+    pub const fn placement(mut self, placement: AnchorPlacement) -> Self {
+        self.placement = Some(placement);
+        self
+    }
+
+    /// Placements tried in order when the [`Self::placement`] box overflows
+    /// the collision boundary; the first one that fits wins. With
+    /// `Collision::Flip` / `FlipFit` the mirrored primary placement is tried
+    /// last. When nothing fits, the primary placement is kept and `Fit`
+    /// clamping (if enabled) takes over.
+    pub fn fallback_placements(
+        mut self,
+        placements: impl IntoIterator<Item = AnchorPlacement>,
+    ) -> Self {
+        self.fallback_placements = placements.into_iter().collect();
+        self
+    }
+
+    /// Gap between self and the anchor on axes where the placement is
+    /// `Before` or `After`. Flips with the placement, unlike `translate_*`.
+    /// `Length` percent base is the anchor's size on that axis.
+    pub const fn anchor_offset(mut self, value: Length) -> Self {
+        self.anchor_offset = Some(value);
+        self
+    }
+
+    pub const fn anchor_placement(&self) -> Option<AnchorPlacement> {
+        self.placement
+    }
+
+    pub fn fallback_placement_list(&self) -> &[AnchorPlacement] {
+        &self.fallback_placements
+    }
+
+    pub const fn anchor_offset_length(&self) -> Option<Length> {
+        self.anchor_offset
+    }
+
     pub fn anchor_ref(&self) -> Option<&Anchor> {
         self.anchor.as_ref()
     }
@@ -1085,6 +1507,14 @@ impl Position {
         self.collision_boundary
     }
 
+    pub const fn collision_padding_length(&self) -> Option<Length> {
+        self.collision_padding
+    }
+
+    pub fn boundary_inset_edges(&self) -> Option<Padding> {
+        self.boundary_insets.as_deref().copied()
+    }
+
     pub const fn clip_mode(&self) -> ClipMode {
         self.clip_mode
     }
@@ -1098,7 +1528,14 @@ impl Position {
             bottom: None,
             left: None,
             self_origin: None,
+            translate_x: None,
+            translate_y: None,
+            placement: None,
+            fallback_placements: Vec::new(),
+            anchor_offset: None,
             collision: Collision::None,
+            collision_padding: None,
+            boundary_insets: None,
             collision_boundary: CollisionBoundary::Viewport,
             clip_mode: ClipMode::Parent,
         }
@@ -1119,6 +1556,9 @@ pub enum Length {
     Vh(f32),
     Calc(LengthCalc),
     Zero,
+    /// Exactly one device pixel when painted as a border width, whatever
+    /// the scale factor. Anywhere else, including layout, it is `1px`.
+    Hairline,
 }
 
 #[derive(Debug, Clone, Copy, PartialEq)]
@@ -1437,6 +1877,10 @@ impl LengthCalc {
             },
             Length::Calc(v) => v,
             Length::Zero => Self::zero(),
+            Length::Hairline => Self {
+                px: 1.0,
+                ..Self::zero()
+            },
         }
     }
 
@@ -1525,6 +1969,10 @@ impl Length {
         Self::Vh(value)
     }
 
+    pub const fn hairline() -> Self {
+        Self::Hairline
+    }
+
     pub fn calc<Op, Rhs>(lhs: Length, operator: Op, rhs: Rhs) -> Self
     where
         Self: CalcRule<Op, Rhs>,
@@ -1545,6 +1993,7 @@ impl Length {
             Self::Vh(v) => Some(viewport_height.max(0.0) * v * 0.01),
             Self::Calc(calc) => calc.resolve(percent_base, viewport_width, viewport_height),
             Self::Zero => Some(0.0),
+            Self::Hairline => Some(1.0),
         }
     }
 
@@ -1556,6 +2005,7 @@ impl Length {
             Self::Vh(v) => viewport_height.max(0.0) * v * 0.01,
             Self::Calc(calc) => calc.resolve_without_percent_base(viewport_width, viewport_height),
             Self::Zero => 0.0,
+            Self::Hairline => 1.0,
         }
     }
 
@@ -1779,6 +2229,9 @@ impl Padding {
     }
 }
 
+/// Per-side margins; shares the [`Padding`] builder API.
+pub type Margin = Padding;
+
 #[derive(Debug, Clone, Copy, PartialEq)]
 pub struct BorderRadius {
     pub top_left: Length,
@@ -2470,7 +2923,11 @@ pub(crate) enum ParsedValue {
     Align(Align),
     Flex(Flex),
     ScrollDirection(ScrollDirection),
+    Scrollbar(ScrollbarStyle),
     Cursor(Cursor),
+    AppRegion(AppRegion),
+    PointerEvents(PointerEvents),
+    HitShape(HitShape),
     Position(Position),
     Auto,
     Length(Length),
@@ -2481,12 +2938,17 @@ pub(crate) enum ParsedValue {
     TextWrap(TextWrap),
     Opacity(Opacity),
     BoxShadow(Vec<BoxShadow>),
+    Elevation(u8),
+    MixBlendMode(MixBlendMode),
+    Filter(Vec<Filter>),
+    Mask(Mask),
     Transform(Transform),
     TransformOrigin(TransformOrigin),
     Transition(Transitions),
     Animator(Animator),
     Color(StyleColor),
     Gradient(Gradient),
+    NinePatch(NinePatch),
     VerticalAlign(VerticalAlign),
 }
 
@@ -2539,6 +3001,7 @@ pub struct Style {
     index: FxHashMap<PropertyId, usize>,
     hover: Option<Box<Style>>,
     selection: Option<Box<SelectionStyle>>,
+    media: Vec<MediaRule<Style>>,
 }
 
 pub trait IntoStyleFieldValue<T> {
@@ -2649,13 +3112,13 @@ impl<'a> IntoStyleFieldValue<StyleColor> for crate::style::HexColor<'a> {
 
 impl IntoStyleFieldValue<StyleColor> for &str {
     fn into_style_field_value(self) -> StyleColor {
-        StyleColor::Srgb(self.into_color())
+        self.into_color()
     }
 }
 
 impl IntoStyleFieldValue<StyleColor> for String {
     fn into_style_field_value(self) -> StyleColor {
-        StyleColor::Srgb(self.into_color())
+        self.into_color()
     }
 }
 
@@ -2812,9 +3275,26 @@ impl Style {
         if let Some(hover) = self.hover.take() {
             self.hover = Some(Box::new(hover.without_properties_recursive(properties)));
         }
+        for rule in &mut self.media {
+            rule.style = std::mem::take(&mut rule.style).without_properties_recursive(properties);
+        }
         self
     }
 
+    /// Whether `property` is declared here or in the hover or any media
+    /// style.
+    pub(crate) fn declares_recursive(&self, property: PropertyId) -> bool {
+        self.get(property).is_some()
+            || self
+                .hover
+                .as_ref()
+                .is_some_and(|hover| hover.declares_recursive(property))
+            || self
+                .media
+                .iter()
+                .any(|rule| rule.style.declares_recursive(property))
+    }
+
     pub fn hover(&self) -> Option<&Style> {
         self.hover.as_deref()
     }
@@ -2823,6 +3303,19 @@ impl Style {
         self.selection.as_deref()
     }
 
+    /// Conditional blocks in authoring order; see [`crate::style::MediaQuery`].
+    pub fn media(&self) -> &[MediaRule<Style>] {
+        &self.media
+    }
+
+    /// Whether each [`Self::media`] block matches `media`.
+    pub(crate) fn matching_media(&self, media: &MediaContext) -> Vec<bool> {
+        self.media
+            .iter()
+            .map(|rule| rule.query.matches(media))
+            .collect()
+    }
+
     pub fn set_hover(&mut self, hover: Style) {
         self.hover = Some(Box::new(hover));
     }
@@ -2836,28 +3329,47 @@ impl Style {
         self
     }
 
+    pub fn push_media(&mut self, query: MediaQuery, style: Style) {
+        self.media.push(MediaRule::new(query, style));
+    }
+
+    pub fn with_media(mut self, query: MediaQuery, style: Style) -> Self {
+        self.push_media(query, style);
+        self
+    }
+
     pub fn with_selection(mut self, selection: SelectionStyle) -> Self {
         self.set_selection(selection);
         self
     }
 
-    pub fn merge(self, rhs: Self) -> Self {
-        let mut merged = self;
-        for declaration in rhs.declarations {
-            merged.insert(declaration.property, declaration.value);
+    /// Stack `later` on top of this style: its declarations replace the
+    /// ones here, `hover` and `selection` merge the same way and its media
+    /// rules are appended.
+    pub fn extend(&mut self, later: Self) {
+        for declaration in later.declarations {
+            self.insert(declaration.property, declaration.value);
         }
-        merged.hover = match (merged.hover.take(), rhs.hover) {
-            (Some(lhs), Some(rhs)) => Some(Box::new((*lhs).merge(*rhs))),
-            (Some(lhs), None) => Some(lhs),
-            (None, Some(rhs)) => Some(rhs),
-            (None, None) => None,
+        self.hover = match (self.hover.take(), later.hover) {
+            (Some(mut lhs), Some(rhs)) => {
+                lhs.extend(*rhs);
+                Some(lhs)
+            }
+            (lhs, rhs) => rhs.or(lhs),
         };
-        merged.selection = match (merged.selection.take(), rhs.selection) {
+        self.selection = match (self.selection.take(), later.selection) {
             (Some(lhs), Some(rhs)) => Some(Box::new((*lhs).merge(*rhs))),
-            (Some(lhs), None) => Some(lhs),
-            (None, Some(rhs)) => Some(rhs),
-            (None, None) => None,
+            (lhs, rhs) => rhs.or(lhs),
         };
+        self.media.extend(later.media);
+    }
+
+    /// `styles` stacked in order with [`Style::extend`], the last on top.
+    pub fn merge(styles: &[Style]) -> Self {
+        let mut merged = Style::new();
+        for style in styles {
+            merged.extend(style.clone());
+        }
         merged
     }
 
@@ -2876,6 +3388,18 @@ impl Style {
         self
     }
 
+    pub fn set_margin(&mut self, margin: Margin) {
+        self.insert(PropertyId::MarginTop, ParsedValue::Length(margin.top));
+        self.insert(PropertyId::MarginRight, ParsedValue::Length(margin.right));
+        self.insert(PropertyId::MarginBottom, ParsedValue::Length(margin.bottom));
+        self.insert(PropertyId::MarginLeft, ParsedValue::Length(margin.left));
+    }
+
+    pub fn with_margin(mut self, margin: Margin) -> Self {
+        self.set_margin(margin);
+        self
+    }
+
     pub fn set_background(&mut self, background: Background) {
         match background {
             Background::Color(color) => {
@@ -2905,12 +3429,16 @@ impl Style {
         self
     }
 
-    pub fn set_border_image(&mut self, gradient: Gradient) {
-        self.insert(PropertyId::BorderImage, ParsedValue::Gradient(gradient));
+    pub fn set_border_image(&mut self, image: impl Into<BorderImage>) {
+        let value = match image.into() {
+            BorderImage::Gradient(gradient) => ParsedValue::Gradient(gradient),
+            BorderImage::NinePatch(nine_patch) => ParsedValue::NinePatch(nine_patch),
+        };
+        self.insert(PropertyId::BorderImage, value);
     }
 
-    pub fn with_border_image(mut self, gradient: Gradient) -> Self {
-        self.set_border_image(gradient);
+    pub fn with_border_image(mut self, image: impl Into<BorderImage>) -> Self {
+        self.set_border_image(image);
         self
     }
 
@@ -2923,6 +3451,36 @@ impl Style {
         self
     }
 
+    pub fn set_app_region(&mut self, region: AppRegion) {
+        self.insert(PropertyId::AppRegion, ParsedValue::AppRegion(region));
+    }
+
+    pub fn with_app_region(mut self, region: AppRegion) -> Self {
+        self.set_app_region(region);
+        self
+    }
+
+    pub fn set_pointer_events(&mut self, pointer_events: PointerEvents) {
+        self.insert(
+            PropertyId::PointerEvents,
+            ParsedValue::PointerEvents(pointer_events),
+        );
+    }
+
+    pub fn with_pointer_events(mut self, pointer_events: PointerEvents) -> Self {
+        self.set_pointer_events(pointer_events);
+        self
+    }
+
+    pub fn set_hit_shape(&mut self, shape: HitShape) {
+        self.insert(PropertyId::HitShape, ParsedValue::HitShape(shape));
+    }
+
+    pub fn with_hit_shape(mut self, shape: HitShape) -> Self {
+        self.set_hit_shape(shape);
+        self
+    }
+
     pub fn set_text_wrap(&mut self, text_wrap: TextWrap) {
         self.insert(PropertyId::TextWrap, ParsedValue::TextWrap(text_wrap));
     }
@@ -2932,6 +3490,36 @@ impl Style {
         self
     }
 
+    pub fn set_color<T: ColorLike>(&mut self, color: T) {
+        self.insert(
+            PropertyId::Color,
+            ParsedValue::Color(color.to_style_color()),
+        );
+    }
+
+    pub fn with_color<T: ColorLike>(mut self, color: T) -> Self {
+        self.set_color(color);
+        self
+    }
+
+    pub fn set_font_family(&mut self, font_family: FontFamily) {
+        self.insert(PropertyId::FontFamily, ParsedValue::FontFamily(font_family));
+    }
+
+    pub fn with_font_family(mut self, font_family: FontFamily) -> Self {
+        self.set_font_family(font_family);
+        self
+    }
+
+    pub fn set_font_size(&mut self, font_size: FontSize) {
+        self.insert(PropertyId::FontSize, ParsedValue::FontSize(font_size));
+    }
+
+    pub fn with_font_size(mut self, font_size: FontSize) -> Self {
+        self.set_font_size(font_size);
+        self
+    }
+
     /// Multiplier-style line height. Inherited typography prop.
     /// Pass the raw `f32` (e.g. `1.2`); construction matches the
     /// existing `LineHeight::new` API.
@@ -3036,6 +3624,43 @@ impl Style {
         self
     }
 
+    /// Shadow level resolved through [`crate::style::ElevationScale`].
+    pub fn set_elevation(&mut self, level: u8) {
+        self.insert(PropertyId::Elevation, ParsedValue::Elevation(level));
+    }
+
+    pub fn with_elevation(mut self, level: u8) -> Self {
+        self.set_elevation(level);
+        self
+    }
+
+    pub fn set_mix_blend_mode(&mut self, mode: MixBlendMode) {
+        self.insert(PropertyId::MixBlendMode, ParsedValue::MixBlendMode(mode));
+    }
+
+    pub fn with_mix_blend_mode(mut self, mode: MixBlendMode) -> Self {
+        self.set_mix_blend_mode(mode);
+        self
+    }
+
+    pub fn set_filter(&mut self, filter: Vec<Filter>) {
+        self.insert(PropertyId::Filter, ParsedValue::Filter(filter));
+    }
+
+    pub fn with_filter(mut self, filter: Vec<Filter>) -> Self {
+        self.set_filter(filter);
+        self
+    }
+
+    pub fn set_mask(&mut self, mask: impl Into<Mask>) {
+        self.insert(PropertyId::Mask, ParsedValue::Mask(mask.into()));
+    }
+
+    pub fn with_mask(mut self, mask: impl Into<Mask>) -> Self {
+        self.set_mask(mask);
+        self
+    }
+
     pub fn set_transform(&mut self, transform: Transform) {
         self.insert(PropertyId::Transform, ParsedValue::Transform(transform));
     }
@@ -3070,7 +3695,8 @@ impl Style {
 impl Add for Style {
     type Output = Style;
 
-    fn add(self, rhs: Self) -> Self::Output {
-        self.merge(rhs)
+    fn add(mut self, rhs: Self) -> Self::Output {
+        self.extend(rhs);
+        self
     }
 }
@@ -154,7 +154,11 @@ style_prop_registry! {
     PaddingLeft => { inherited: false, animatable: true },
     Gap => { inherited: false, animatable: true },
     ScrollDirection => { inherited: false, animatable: false },
+    Scrollbar => { inherited: false, animatable: false },
     Cursor => { inherited: true, animatable: false },
+    AppRegion => { inherited: false, animatable: false },
+    PointerEvents => { inherited: false, animatable: false },
+    HitShape => { inherited: false, animatable: false },
     Color => { inherited: true, animatable: true },
     BackgroundColor => { inherited: false, animatable: true },
     BackgroundImage => { inherited: false, animatable: false },
@@ -181,6 +185,10 @@ style_prop_registry! {
     BorderLeftColor => { inherited: false, animatable: true },
     Opacity => { inherited: false, animatable: true },
     BoxShadow => { inherited: false, animatable: true },
+    Elevation => { inherited: false, animatable: true },
+    MixBlendMode => { inherited: false, animatable: false },
+    Filter => { inherited: false, animatable: false },
+    Mask => { inherited: false, animatable: false },
     Transform => { inherited: false, animatable: true },
     TransformOrigin => { inherited: false, animatable: true },
     Transition => { inherited: false, animatable: false },
@@ -326,7 +334,11 @@ mod tests {
             PropertyId::PaddingLeft,
             PropertyId::Gap,
             PropertyId::ScrollDirection,
+            PropertyId::Scrollbar,
             PropertyId::Cursor,
+            PropertyId::AppRegion,
+            PropertyId::PointerEvents,
+            PropertyId::HitShape,
             PropertyId::Color,
             PropertyId::BackgroundColor,
             PropertyId::BackgroundImage,
@@ -353,6 +365,10 @@ mod tests {
             PropertyId::BorderLeftColor,
             PropertyId::Opacity,
             PropertyId::BoxShadow,
+            PropertyId::Elevation,
+            PropertyId::MixBlendMode,
+            PropertyId::Filter,
+            PropertyId::Mask,
             PropertyId::Transform,
             PropertyId::TransformOrigin,
             PropertyId::Transition,
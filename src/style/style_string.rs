@@ -0,0 +1,326 @@
+//! Inline CSS declaration strings such as `"width: 50%; color: #333"`.
+//!
+//! This covers the box, typography and border properties a stylesheet or a
+//! `style="..."` attribute most often carries. The parser never panics:
+//! anything it does not understand is reported as a [`StyleParseError`],
+//! which keeps it usable as a fuzzing entry point.
+
+use crate::style::{
+    FontFamily, FontSize, FontWeight, Length, LineHeight, Opacity, ParsedValue, PropertyId, Style,
+    parse_color,
+};
+use std::fmt;
+
+/// Why a declaration string was rejected.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum StyleParseError {
+    /// A declaration has no `:` between its name and value.
+    MissingColon {
+        declaration: String,
+    },
+    UnknownProperty {
+        property: String,
+    },
+    InvalidValue {
+        property: String,
+        value: String,
+    },
+}
+
+impl fmt::Display for StyleParseError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::MissingColon { declaration } => {
+                write!(f, "declaration {declaration:?} is missing `:`")
+            }
+            Self::UnknownProperty { property } => write!(f, "unknown style property `{property}`"),
+            Self::InvalidValue { property, value } => {
+                write!(f, "invalid value {value:?} for `{property}`")
+            }
+        }
+    }
+}
+
+impl std::error::Error for StyleParseError {}
+
+/// Parse `;`-separated `name: value` declarations into a [`Style`]. Later
+/// declarations override earlier ones, as in CSS.
+pub fn parse_style_string(input: &str) -> Result<Style, StyleParseError> {
+    let mut style = Style::new();
+    for declaration in input.split(';') {
+        let declaration = declaration.trim();
+        if declaration.is_empty() {
+            continue;
+        }
+        let Some((name, value)) = declaration.split_once(':') else {
+            return Err(StyleParseError::MissingColon {
+                declaration: declaration.to_string(),
+            });
+        };
+        let name = name.trim().to_ascii_lowercase();
+        let value = value.trim();
+        let invalid = || StyleParseError::InvalidValue {
+            property: name.clone(),
+            value: value.to_string(),
+        };
+        if value.is_empty() {
+            return Err(invalid());
+        }
+        apply_declaration(&mut style, &name, value).ok_or_else(invalid)??;
+    }
+    Ok(style)
+}
+
+/// `None` means the property is known but `value` is not valid for it.
+fn apply_declaration(
+    style: &mut Style,
+    name: &str,
+    value: &str,
+) -> Option<Result<(), StyleParseError>> {
+    let single =
+        |property| parse_length(value).map(|length| (property, ParsedValue::Length(length)));
+    let declaration = match name {
+        "width" | "height" | "min-width" | "min-height" | "max-width" | "max-height" => {
+            let property = match name {
+                "width" => PropertyId::Width,
+                "height" => PropertyId::Height,
+                "min-width" => PropertyId::MinWidth,
+                "min-height" => PropertyId::MinHeight,
+                "max-width" => PropertyId::MaxWidth,
+                _ => PropertyId::MaxHeight,
+            };
+            if value.eq_ignore_ascii_case("auto") {
+                (property, ParsedValue::Auto)
+            } else {
+                single(property)?
+            }
+        }
+        "gap" => single(PropertyId::Gap)?,
+        "margin" => {
+            insert_sides(style, MARGIN_SIDES, value)?;
+            return Some(Ok(()));
+        }
+        "padding" => {
+            insert_sides(style, PADDING_SIDES, value)?;
+            return Some(Ok(()));
+        }
+        "border-width" => {
+            insert_sides(style, BORDER_WIDTH_SIDES, value)?;
+            return Some(Ok(()));
+        }
+        "border-radius" => {
+            insert_sides(style, BORDER_RADIUS_CORNERS, value)?;
+            return Some(Ok(()));
+        }
+        "border-color" => {
+            let color = ParsedValue::Color(parse_color(value).ok()?);
+            for property in BORDER_COLOR_SIDES {
+                style.insert(property, color.clone());
+            }
+            return Some(Ok(()));
+        }
+        "color" => (
+            PropertyId::Color,
+            ParsedValue::Color(parse_color(value).ok()?),
+        ),
+        "background" | "background-color" => (
+            PropertyId::BackgroundColor,
+            ParsedValue::Color(parse_color(value).ok()?),
+        ),
+        "font-family" => {
+            let family = FontFamily::from_csv(value.replace(['"', '\''], ""));
+            if family.as_slice().is_empty() {
+                return None;
+            }
+            (PropertyId::FontFamily, ParsedValue::FontFamily(family))
+        }
+        "font-size" => (
+            PropertyId::FontSize,
+            ParsedValue::FontSize(parse_font_size(value)?),
+        ),
+        "font-weight" => (
+            PropertyId::FontWeight,
+            ParsedValue::FontWeight(parse_font_weight(value)?),
+        ),
+        "line-height" => (
+            PropertyId::LineHeight,
+            ParsedValue::LineHeight(LineHeight::new(parse_number(value)?)),
+        ),
+        "opacity" => (
+            PropertyId::Opacity,
+            ParsedValue::Opacity(Opacity::new(parse_number(value)?.clamp(0.0, 1.0))),
+        ),
+        _ => {
+            if let Some(property) = side_property(name) {
+                (
+                    property,
+                    ParsedValue::Length(parse_side_length(property, value)?),
+                )
+            } else if let Some(property) = border_side_color(name) {
+                (property, ParsedValue::Color(parse_color(value).ok()?))
+            } else {
+                return Some(Err(StyleParseError::UnknownProperty {
+                    property: name.to_string(),
+                }));
+            }
+        }
+    };
+    style.insert(declaration.0, declaration.1);
+    Some(Ok(()))
+}
+
+const MARGIN_SIDES: [PropertyId; 4] = [
+    PropertyId::MarginTop,
+    PropertyId::MarginRight,
+    PropertyId::MarginBottom,
+    PropertyId::MarginLeft,
+];
+const PADDING_SIDES: [PropertyId; 4] = [
+    PropertyId::PaddingTop,
+    PropertyId::PaddingRight,
+    PropertyId::PaddingBottom,
+    PropertyId::PaddingLeft,
+];
+const BORDER_WIDTH_SIDES: [PropertyId; 4] = [
+    PropertyId::BorderTopWidth,
+    PropertyId::BorderRightWidth,
+    PropertyId::BorderBottomWidth,
+    PropertyId::BorderLeftWidth,
+];
+const BORDER_COLOR_SIDES: [PropertyId; 4] = [
+    PropertyId::BorderTopColor,
+    PropertyId::BorderRightColor,
+    PropertyId::BorderBottomColor,
+    PropertyId::BorderLeftColor,
+];
+const BORDER_RADIUS_CORNERS: [PropertyId; 4] = [
+    PropertyId::BorderTopLeftRadius,
+    PropertyId::BorderTopRightRadius,
+    PropertyId::BorderBottomRightRadius,
+    PropertyId::BorderBottomLeftRadius,
+];
+
+/// Expand the one-to-four value shorthand (`top right bottom left`, with
+/// the usual CSS fallbacks) onto `properties`.
+fn insert_sides(style: &mut Style, properties: [PropertyId; 4], value: &str) -> Option<()> {
+    let mut lengths = Vec::with_capacity(4);
+    for part in value.split_ascii_whitespace() {
+        if lengths.len() == 4 {
+            return None;
+        }
+        lengths.push(parse_side_length(properties[0], part)?);
+    }
+    let [top, right, bottom, left] = match lengths.as_slice() {
+        [all] => [*all; 4],
+        [y, x] => [*y, *x, *y, *x],
+        [top, x, bottom] => [*top, *x, *bottom, *x],
+        [top, right, bottom, left] => [*top, *right, *bottom, *left],
+        _ => return None,
+    };
+    for (property, length) in properties.into_iter().zip([top, right, bottom, left]) {
+        style.insert(property, ParsedValue::Length(length));
+    }
+    Some(())
+}
+
+fn side_property(name: &str) -> Option<PropertyId> {
+    Some(match name {
+        "margin-top" => PropertyId::MarginTop,
+        "margin-right" => PropertyId::MarginRight,
+        "margin-bottom" => PropertyId::MarginBottom,
+        "margin-left" => PropertyId::MarginLeft,
+        "padding-top" => PropertyId::PaddingTop,
+        "padding-right" => PropertyId::PaddingRight,
+        "padding-bottom" => PropertyId::PaddingBottom,
+        "padding-left" => PropertyId::PaddingLeft,
+        "border-top-width" => PropertyId::BorderTopWidth,
+        "border-right-width" => PropertyId::BorderRightWidth,
+        "border-bottom-width" => PropertyId::BorderBottomWidth,
+        "border-left-width" => PropertyId::BorderLeftWidth,
+        "border-top-left-radius" => PropertyId::BorderTopLeftRadius,
+        "border-top-right-radius" => PropertyId::BorderTopRightRadius,
+        "border-bottom-right-radius" => PropertyId::BorderBottomRightRadius,
+        "border-bottom-left-radius" => PropertyId::BorderBottomLeftRadius,
+        _ => return None,
+    })
+}
+
+fn border_side_color(name: &str) -> Option<PropertyId> {
+    Some(match name {
+        "border-top-color" => PropertyId::BorderTopColor,
+        "border-right-color" => PropertyId::BorderRightColor,
+        "border-bottom-color" => PropertyId::BorderBottomColor,
+        "border-left-color" => PropertyId::BorderLeftColor,
+        _ => return None,
+    })
+}
+
+/// Split `12.5px` into its finite number and lowercased unit.
+fn split_number(value: &str) -> Option<(f32, String)> {
+    let unit_start = value
+        .char_indices()
+        .find(|&(index, ch)| {
+            !(ch.is_ascii_digit() || ch == '.' || ((ch == '-' || ch == '+') && index == 0))
+        })
+        .map_or(value.len(), |(index, _)| index);
+    let number = value[..unit_start].parse::<f32>().ok()?;
+    number
+        .is_finite()
+        .then(|| (number, value[unit_start..].to_ascii_lowercase()))
+}
+
+fn parse_number(value: &str) -> Option<f32> {
+    match split_number(value)? {
+        (number, unit) if unit.is_empty() => Some(number),
+        _ => None,
+    }
+}
+
+fn parse_length(value: &str) -> Option<Length> {
+    let (number, unit) = split_number(value)?;
+    Some(match unit.as_str() {
+        "" if number == 0.0 => Length::Zero,
+        "px" => Length::Px(number),
+        "%" => Length::Percent(number),
+        "vw" => Length::Vw(number),
+        "vh" => Length::Vh(number),
+        _ => return None,
+    })
+}
+
+/// [`parse_length`], plus the `hairline` keyword for border widths.
+fn parse_side_length(property: PropertyId, value: &str) -> Option<Length> {
+    if BORDER_WIDTH_SIDES.contains(&property) && value.eq_ignore_ascii_case("hairline") {
+        return Some(Length::Hairline);
+    }
+    parse_length(value)
+}
+
+fn parse_font_size(value: &str) -> Option<FontSize> {
+    let (number, unit) = split_number(value)?;
+    Some(match unit.as_str() {
+        "px" => FontSize::Px(number),
+        "em" => FontSize::Em(number),
+        "rem" => FontSize::Rem(number),
+        "%" => FontSize::Percent(number),
+        "vw" => FontSize::Vw(number),
+        "vh" => FontSize::Vh(number),
+        _ => return None,
+    })
+}
+
+fn parse_font_weight(value: &str) -> Option<FontWeight> {
+    match value.to_ascii_lowercase().as_str() {
+        "normal" => Some(FontWeight::NORMAL),
+        "bold" => Some(FontWeight::BOLD),
+        _ => {
+            let weight = value.parse::<u16>().ok()?;
+            (1..=1000)
+                .contains(&weight)
+                .then(|| FontWeight::new(weight))
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests;
@@ -0,0 +1,122 @@
+use super::{StyleParseError, parse_style_string};
+use crate::style::{Color, FontSize, FontWeight, Length, ParsedValue, PropertyId};
+
+#[test]
+fn parses_declarations_and_shorthands() {
+    let style = parse_style_string(
+        " width: 50%; Height:auto; margin: 4px 8px; color: #ff0000 ; font-weight: bold;",
+    )
+    .unwrap();
+    assert_eq!(
+        style.get(PropertyId::Width),
+        Some(&ParsedValue::Length(Length::Percent(50.0)))
+    );
+    assert_eq!(style.get(PropertyId::Height), Some(&ParsedValue::Auto));
+    assert_eq!(
+        style.get(PropertyId::MarginLeft),
+        Some(&ParsedValue::Length(Length::Px(8.0)))
+    );
+    assert_eq!(
+        style.get(PropertyId::MarginBottom),
+        Some(&ParsedValue::Length(Length::Px(4.0)))
+    );
+    assert_eq!(
+        style.get(PropertyId::Color),
+        Some(&ParsedValue::color_like(Color::rgb(255, 0, 0)))
+    );
+    assert_eq!(
+        style.get(PropertyId::FontWeight),
+        Some(&ParsedValue::FontWeight(FontWeight::BOLD))
+    );
+}
+
+#[test]
+fn later_declarations_override_earlier_ones() {
+    let style = parse_style_string("font-size: 12px; font-size: 1.5em").unwrap();
+    assert_eq!(
+        style.get(PropertyId::FontSize),
+        Some(&ParsedValue::FontSize(FontSize::Em(1.5)))
+    );
+}
+
+#[test]
+fn hairline_is_only_a_border_width_keyword() {
+    let style =
+        parse_style_string("border-width: hairline 2px; border-left-width: hairline").unwrap();
+    assert_eq!(
+        style.get(PropertyId::BorderTopWidth),
+        Some(&ParsedValue::Length(Length::Hairline))
+    );
+    assert_eq!(
+        style.get(PropertyId::BorderRightWidth),
+        Some(&ParsedValue::Length(Length::Px(2.0)))
+    );
+    assert_eq!(
+        style.get(PropertyId::BorderLeftWidth),
+        Some(&ParsedValue::Length(Length::Hairline))
+    );
+    for input in [
+        "width: hairline",
+        "padding: hairline",
+        "margin-top: hairline",
+    ] {
+        assert!(
+            matches!(
+                parse_style_string(input),
+                Err(StyleParseError::InvalidValue { .. })
+            ),
+            "{input}"
+        );
+    }
+}
+
+#[test]
+fn reports_malformed_declarations() {
+    assert_eq!(
+        parse_style_string("width 10px"),
+        Err(StyleParseError::MissingColon {
+            declaration: "width 10px".to_string()
+        })
+    );
+    assert_eq!(
+        parse_style_string("colour: red"),
+        Err(StyleParseError::UnknownProperty {
+            property: "colour".to_string()
+        })
+    );
+    for input in [
+        "width: 10",
+        "width: px",
+        "width: -",
+        "margin: 1px 2px 3px 4px 5px",
+        "opacity: NaN",
+        "font-size: 1e40px",
+        "color: oklch(0.5 0.1",
+        "padding:",
+    ] {
+        assert!(
+            matches!(
+                parse_style_string(input),
+                Err(StyleParseError::InvalidValue { .. })
+            ),
+            "{input}"
+        );
+    }
+}
+
+#[test]
+fn malformed_input_never_panics() {
+    for input in [
+        ":",
+        ";;;",
+        "::::",
+        "width:\u{00e9}px",
+        "margin: \u{2003}",
+        "color: #",
+        "a:b:c",
+        "font-weight: 99999",
+        "border-radius: +.px",
+    ] {
+        let _ = parse_style_string(input);
+    }
+}
@@ -0,0 +1,66 @@
+//! Baseline styles host elements start from before their own `style`.
+//!
+//! ```ignore
+//! viewport.set_user_agent_style(UserAgentStyle {
+//!     root: Style::new()
+//!         .with_font_family(FontFamily::new(["Inter"]))
+//!         .with_font_size(FontSize::px(14.0))
+//!         .with_color(Color::hex("#1f2328")),
+//!     interactive_cursor: Cursor::Pointer,
+//!     ..UserAgentStyle::default()
+//! });
+//! ```
+//!
+//! The viewport publishes its [`UserAgentStyle`] on the thread it renders
+//! on and rebuilds its tree whenever it changes.
+
+use std::cell::RefCell;
+
+use crate::style::{Color, Cursor, FontSize, ScrollbarStyle, Style};
+
+/// Default stylesheet of a viewport.
+#[derive(Clone, Debug, PartialEq)]
+pub struct UserAgentStyle {
+    /// Inherited by every tree the viewport renders, beneath the viewport
+    /// style: font family and size, text color, line height.
+    pub root: Style,
+    /// Cursor over elements with click or pointer-down handlers whose
+    /// style sets none.
+    pub interactive_cursor: Cursor,
+    /// Scrollbar of scroll containers whose style sets none.
+    pub scrollbar: ScrollbarStyle,
+}
+
+impl Default for UserAgentStyle {
+    /// 16px `#111111` text, the platform cursor and the built-in overlay
+    /// scrollbar.
+    fn default() -> Self {
+        Self {
+            root: Style::new()
+                .with_font_size(FontSize::px(16.0))
+                .with_color(Color::hex("#111111")),
+            interactive_cursor: Cursor::Default,
+            scrollbar: ScrollbarStyle::new(),
+        }
+    }
+}
+
+thread_local! {
+    static CURRENT_USER_AGENT_STYLE: RefCell<UserAgentStyle> =
+        RefCell::new(UserAgentStyle::default());
+}
+
+impl UserAgentStyle {
+    /// Stylesheet last published by a viewport on this thread.
+    pub fn current() -> Self {
+        Self::with_current(Self::clone)
+    }
+
+    pub(crate) fn with_current<R>(f: impl FnOnce(&Self) -> R) -> R {
+        CURRENT_USER_AGENT_STYLE.with(|current| f(&current.borrow()))
+    }
+
+    pub(crate) fn set_current(style: Self) {
+        CURRENT_USER_AGENT_STYLE.with(|current| *current.borrow_mut() = style);
+    }
+}
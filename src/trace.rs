@@ -0,0 +1,58 @@
+//! Optional `tracing` instrumentation of the frame pipeline.
+//!
+//! With the `tracing` feature enabled, app build, reconciliation, measure,
+//! place, frame-graph build / compile / execute, every recorded pass and
+//! platform event dispatch open spans on the `rfgui` target, and per-frame
+//! counters are emitted as events on the `rfgui::counters` target. Install
+//! any subscriber in the host (`tracing-chrome`, `tracing-tracy`, ...) to
+//! capture them. Without the feature both macros compile to nothing and
+//! their field expressions are never evaluated.
+
+/// Entered span; leaving scope (or [`SpanGuard::exit`]) closes it.
+pub(crate) struct SpanGuard {
+    #[cfg(feature = "tracing")]
+    _entered: tracing::span::EnteredSpan,
+}
+
+impl SpanGuard {
+    #[cfg(feature = "tracing")]
+    pub(crate) fn enter(span: tracing::Span) -> Self {
+        Self {
+            _entered: span.entered(),
+        }
+    }
+
+    #[cfg(not(feature = "tracing"))]
+    pub(crate) fn disabled() -> Self {
+        Self {}
+    }
+
+    /// Close the span before the end of the enclosing scope.
+    pub(crate) fn exit(self) {}
+}
+
+/// `span!("measure")` / `span!("pass", name = pass_name)` — enter an
+/// `info`-level span on the `rfgui` target and return its [`SpanGuard`].
+macro_rules! span {
+    ($name:literal $(, $($field:tt)+)?) => {{
+        #[cfg(feature = "tracing")]
+        let guard = $crate::trace::SpanGuard::enter(
+            ::tracing::info_span!(target: "rfgui", $name $(, $($field)+)?),
+        );
+        #[cfg(not(feature = "tracing"))]
+        let guard = $crate::trace::SpanGuard::disabled();
+        guard
+    }};
+}
+
+/// `counters!(patches = n, passes = m)` — emit a `trace`-level event on
+/// the `rfgui::counters` target carrying the given numeric fields.
+macro_rules! counters {
+    ($($field:ident = $value:expr),+ $(,)?) => {
+        #[cfg(feature = "tracing")]
+        ::tracing::trace!(target: "rfgui::counters", $($field = $value),+);
+    };
+}
+
+pub(crate) use counters;
+pub(crate) use span;
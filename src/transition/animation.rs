@@ -5,6 +5,7 @@ use rustc_hash::{FxHashMap, FxHashSet};
 
 use super::{
     LayoutField, LayoutSample, RunResult, StyleField, StyleSample, StyleValue, TimeFunction,
+    finish_timeline,
 };
 use crate::style::TransitionTiming;
 use crate::style::{
@@ -15,6 +16,9 @@ use crate::style::{
 pub struct AnimationRequest {
     pub target: u64,
     pub animator: Animator,
+    /// Added to every animation's own delay, e.g. by a
+    /// [`TransitionGroup`](super::TransitionGroup) stagger.
+    pub delay_offset_ms: u32,
 }
 
 #[derive(Clone, Debug, PartialEq)]
@@ -85,7 +89,10 @@ impl AnimationPlugin {
             .map(|animation| ActiveAnimation {
                 keyframes: compile_keyframes(animation.keyframes()),
                 duration_ms: request.animator.resolved_duration_ms(animation),
-                delay_ms: request.animator.resolved_delay_ms(animation),
+                delay_ms: request
+                    .animator
+                    .resolved_delay_ms(animation)
+                    .saturating_add(request.delay_offset_ms.min(i32::MAX as u32) as i32),
                 timing: map_animation_timing(request.animator.resolved_timing(animation)),
                 repeat: request.animator.resolved_repeat(animation),
                 direction: request.animator.resolved_direction(animation),
@@ -125,6 +132,20 @@ impl AnimationPlugin {
         !self.animators.is_empty() || !self.completed_animators.is_empty()
     }
 
+    /// Jump every running finite animation to its end on the next run.
+    /// Infinitely repeating animations have no end and keep running.
+    pub fn finish_all_animations(&mut self) {
+        for animation in self
+            .animators
+            .values_mut()
+            .flat_map(|animator| animator.animations.iter_mut())
+        {
+            if matches!(animation.repeat, Repeat::Count(_)) {
+                finish_timeline(&mut animation.started_at_seconds);
+            }
+        }
+    }
+
     pub fn active_targets(&self) -> FxHashSet<u64> {
         self.animators.keys().copied().collect()
     }
@@ -282,6 +303,14 @@ fn compile_style_fields(style: &Style) -> FxHashMap<StyleField, StyleValue> {
                     out.insert(StyleField::BoxShadow, StyleValue::BoxShadow(value.clone()));
                 }
             }
+            PropertyId::Elevation if style.get(PropertyId::BoxShadow).is_none() => {
+                if let ParsedValue::Elevation(level) = &declaration.value {
+                    let shadows = crate::style::ElevationScale::current()
+                        .level(*level)
+                        .box_shadows();
+                    out.insert(StyleField::BoxShadow, StyleValue::BoxShadow(shadows));
+                }
+            }
             PropertyId::Transform => {
                 if let ParsedValue::Transform(value) = &declaration.value {
                     out.insert(StyleField::Transform, StyleValue::Transform(value.clone()));
@@ -353,6 +382,8 @@ fn map_animation_timing(timing: TransitionTiming) -> TimeFunction {
         TransitionTiming::EaseIn => TimeFunction::EaseIn,
         TransitionTiming::EaseOut => TimeFunction::EaseOut,
         TransitionTiming::EaseInOut => TimeFunction::EaseInOut,
+        TransitionTiming::CubicBezier(curve) => TimeFunction::CubicBezier(curve),
+        TransitionTiming::Steps(count, position) => TimeFunction::Steps(count, position),
     }
 }
 
@@ -627,6 +658,7 @@ mod tests {
                 Keyframe::new(1.0, opacity_style(1.0)),
             ])
             .duration(1000)]),
+            delay_offset_ms: 0,
         });
 
         let result = plugin.run_animations(0.5, 0.5);
@@ -664,6 +696,7 @@ mod tests {
                 ),
             ])
             .duration(1000)]),
+            delay_offset_ms: 0,
         });
 
         let result = plugin.run_animations(0.5, 0.5);
@@ -695,6 +728,7 @@ mod tests {
             animator: Animator::new([Animation::new([Keyframe::new(1.0, style)])
                 .duration(100)
                 .fill_mode(FillMode::Forwards)]),
+            delay_offset_ms: 0,
         });
 
         let _ = plugin.run_animations(0.2, 0.2);
@@ -720,6 +754,7 @@ mod tests {
         plugin.start_animator(AnimationRequest {
             target: 21,
             animator: animator.clone(),
+            delay_offset_ms: 0,
         });
 
         let first = plugin.run_animations(0.2, 0.2);
@@ -729,6 +764,7 @@ mod tests {
         plugin.start_animator(AnimationRequest {
             target: 21,
             animator,
+            delay_offset_ms: 0,
         });
         let second = plugin.run_animations(0.0, 0.2);
         assert!(!second.keep_running);
@@ -746,6 +782,7 @@ mod tests {
         plugin.start_animator(AnimationRequest {
             target: 33,
             animator: animator.clone(),
+            delay_offset_ms: 0,
         });
         let _ = plugin.run_animations(0.2, 0.2);
 
@@ -756,6 +793,7 @@ mod tests {
         plugin.start_animator(AnimationRequest {
             target: 33,
             animator,
+            delay_offset_ms: 0,
         });
         let restarted = plugin.run_animations(0.0, 0.0);
         assert!(restarted.keep_running);
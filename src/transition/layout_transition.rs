@@ -6,8 +6,9 @@ use rustc_hash::FxHashMap;
 use rustc_hash::FxHashSet;
 
 use super::{
-    ChannelId, ClaimMode, RunResult, StartTrackError, TimeFunction, TrackKey, TrackTarget,
-    Transition, TransitionFrame, TransitionHost, TransitionPluginId, elapsed_seconds_from_frame,
+    ChannelId, ClaimMode, RunResult, StartTrackError, TimeFunction, TrackKey, TrackLifecycleEvent,
+    TrackPhase, TrackTarget, Transition, TransitionFrame, TransitionHost, TransitionPluginId,
+    elapsed_seconds_from_frame, finish_timeline, hold_timeline_for_frame,
     normalized_timeline_progress,
 };
 
@@ -84,6 +85,7 @@ struct LayoutTrackState {
     to: f32,
     current: f32,
     started_at_seconds: Option<f64>,
+    paused: bool,
     transition: LayoutTransition,
 }
 
@@ -92,6 +94,7 @@ pub struct LayoutTransitionPlugin {
     plugin_id: TransitionPluginId,
     tracks: FxHashMap<TrackKey<TrackTarget>, LayoutTrackState>,
     frame_samples: Vec<LayoutSample>,
+    lifecycle_events: Vec<TrackLifecycleEvent<TrackTarget>>,
 }
 
 impl Default for LayoutTransitionPlugin {
@@ -112,6 +115,7 @@ impl LayoutTransitionPlugin {
             plugin_id,
             tracks: FxHashMap::default(),
             frame_samples: Vec::new(),
+            lifecycle_events: Vec::new(),
         }
     }
 
@@ -135,6 +139,7 @@ impl LayoutTransitionPlugin {
                 if (existing.current - existing.to).abs() <= 0.0001 {
                     self.tracks.remove(&key);
                     host.release_track_claim(self.plugin_id, key);
+                    self.push_lifecycle_event(key, TrackPhase::End);
                 }
                 return Ok(());
             }
@@ -146,16 +151,21 @@ impl LayoutTransitionPlugin {
         if !host.claim_track(self.plugin_id, key, ClaimMode::Replace) {
             return Err(StartTrackError::ClaimRejected(key));
         }
-        self.tracks.insert(
+        let replaced = self.tracks.insert(
             key,
             LayoutTrackState {
                 from: next_from,
                 to,
                 current: next_from,
                 started_at_seconds: None,
+                paused: false,
                 transition,
             },
         );
+        if replaced.is_some() {
+            self.push_lifecycle_event(key, TrackPhase::Cancel);
+        }
+        self.push_lifecycle_event(key, TrackPhase::Start);
         Ok(())
     }
 
@@ -163,6 +173,45 @@ impl LayoutTransitionPlugin {
         std::mem::take(&mut self.frame_samples)
     }
 
+    /// Freeze or unfreeze the track at `key` in place. Returns `false` when
+    /// no such track is running.
+    pub fn set_track_paused(&mut self, key: TrackKey<TrackTarget>, paused: bool) -> bool {
+        let Some(state) = self.tracks.get_mut(&key) else {
+            return false;
+        };
+        state.paused = paused;
+        true
+    }
+
+    /// Jump the track at `key` to its target on the next run. Returns
+    /// `false` when no such track is running.
+    pub fn finish_track(&mut self, key: TrackKey<TrackTarget>) -> bool {
+        let Some(state) = self.tracks.get_mut(&key) else {
+            return false;
+        };
+        state.paused = false;
+        finish_timeline(&mut state.started_at_seconds);
+        true
+    }
+
+    /// Jump every running track to its target on the next run.
+    pub fn finish_all_tracks(&mut self) {
+        for state in self.tracks.values_mut() {
+            state.paused = false;
+            finish_timeline(&mut state.started_at_seconds);
+        }
+    }
+
+    /// Drain the start/end/cancel events recorded since the last call.
+    pub fn take_lifecycle_events(&mut self) -> Vec<TrackLifecycleEvent<TrackTarget>> {
+        std::mem::take(&mut self.lifecycle_events)
+    }
+
+    fn push_lifecycle_event(&mut self, key: TrackKey<TrackTarget>, phase: TrackPhase) {
+        self.lifecycle_events
+            .push(TrackLifecycleEvent { key, phase });
+    }
+
     pub(crate) fn active_track_keys(&self) -> Vec<TrackKey<TrackTarget>> {
         self.tracks.keys().copied().collect()
     }
@@ -202,7 +251,9 @@ impl Transition<TrackTarget> for LayoutTransitionPlugin {
         key: TrackKey<TrackTarget>,
         host: &mut dyn TransitionHost<TrackTarget>,
     ) {
-        self.tracks.remove(&key);
+        if self.tracks.remove(&key).is_some() {
+            self.push_lifecycle_event(key, TrackPhase::Cancel);
+        }
         host.release_track_claim(self.plugin_id, key);
     }
 
@@ -215,13 +266,15 @@ impl Transition<TrackTarget> for LayoutTransitionPlugin {
         let mut finished = Vec::new();
 
         for (key, state) in &mut self.tracks {
+            if state.paused {
+                hold_timeline_for_frame(frame, &mut state.started_at_seconds);
+            }
             let elapsed_seconds = elapsed_seconds_from_frame(frame, &mut state.started_at_seconds);
             let delay = (state.transition.delay_ms as f32) * 0.001;
             let duration = (state.transition.duration_ms as f32) * 0.001;
-            let Some(progress) = normalized_timeline_progress(elapsed_seconds, delay, duration)
-            else {
-                continue;
-            };
+            // Hold the start value while the track is still delayed.
+            let progress =
+                normalized_timeline_progress(elapsed_seconds, delay, duration).unwrap_or(0.0);
             let eased = state.transition.timing.sample(progress);
             let value = state.from + (state.to - state.from) * eased;
             state.current = value;
@@ -246,6 +299,7 @@ impl Transition<TrackTarget> for LayoutTransitionPlugin {
         for key in finished {
             self.tracks.remove(&key);
             host.release_track_claim(self.plugin_id, key);
+            self.push_lifecycle_event(key, TrackPhase::End);
         }
 
         RunResult {
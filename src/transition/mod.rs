@@ -4,15 +4,19 @@ use std::fmt;
 
 mod animation;
 mod layout_transition;
+mod playback;
 mod scroll_transition;
 mod style_transition;
 mod time_function;
+mod transition_group;
 mod visual_transition;
 pub use animation::*;
 pub use layout_transition::*;
+pub use playback::*;
 pub use scroll_transition::*;
 pub use style_transition::*;
 pub use time_function::*;
+pub use transition_group::*;
 pub use visual_transition::*;
 
 /// The default target identifier type used by built-in transition helpers.
@@ -30,6 +34,26 @@ pub struct TrackKey<TargetType> {
     pub channel: ChannelId,
 }
 
+/// Lifecycle phase reported by a [`TrackLifecycleEvent`].
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub enum TrackPhase {
+    /// The track was created; fires before any delay elapses.
+    Start,
+    /// The track reached its target value.
+    End,
+    /// The track was removed or replaced before reaching its target.
+    Cancel,
+}
+
+/// A track changing lifecycle phase. Plugins queue these until the host
+/// drains them with their `take_lifecycle_events`.
+#[allow(missing_docs)]
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct TrackLifecycleEvent<TargetType> {
+    pub key: TrackKey<TargetType>,
+    pub phase: TrackPhase,
+}
+
 /// Identifies a transition plugin implementation within a host runtime.
 #[derive(Clone, Copy, Debug, PartialEq, Eq, Hash, Ord, PartialOrd)]
 pub struct TransitionPluginId(pub u32);
@@ -60,6 +84,23 @@ pub(crate) fn elapsed_seconds_from_frame(
     (now - *start).max(0.0) as f32
 }
 
+/// Shift a paused track's start forward by the frame's `dt` so its elapsed
+/// time stays frozen.
+pub(crate) fn hold_timeline_for_frame(
+    frame: TransitionFrame,
+    started_at_seconds: &mut Option<f64>,
+) {
+    if let Some(start) = started_at_seconds {
+        *start += frame.dt_seconds.max(0.0) as f64;
+    }
+}
+
+/// Move a track's start infinitely far into the past so its next run
+/// samples the end of its timeline.
+pub(crate) fn finish_timeline(started_at_seconds: &mut Option<f64>) {
+    *started_at_seconds = Some(f64::NEG_INFINITY);
+}
+
 /// The aggregate result of running a batch of transition tracks for one frame.
 #[allow(missing_docs)]
 #[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
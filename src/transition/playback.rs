@@ -0,0 +1,91 @@
+#![allow(missing_docs)]
+
+//! Global playback controls for the transition engine.
+use super::TransitionFrame;
+
+/// A pausable, rate-scaled clock that drives transition plugins.
+///
+/// The host feeds it the real frame time; plugins only ever see the
+/// transition clock, so pausing freezes every timeline in place and a
+/// rate of `0.1` plays them back ten times slower. Feeding the same host
+/// time twice (e.g. the pre- and post-layout ticks of one frame) does not
+/// advance the clock again.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct TransitionPlayback {
+    paused: bool,
+    rate: f32,
+    clock_seconds: f64,
+    last_host_seconds: Option<f64>,
+}
+
+impl Default for TransitionPlayback {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl TransitionPlayback {
+    pub const fn new() -> Self {
+        Self {
+            paused: false,
+            rate: 1.0,
+            clock_seconds: 0.0,
+            last_host_seconds: None,
+        }
+    }
+
+    pub fn pause(&mut self) {
+        self.paused = true;
+    }
+
+    pub fn resume(&mut self) {
+        self.paused = false;
+    }
+
+    pub const fn is_paused(&self) -> bool {
+        self.paused
+    }
+
+    /// Set the playback-rate multiplier. Negative and non-finite rates are
+    /// treated as `0.0`.
+    pub fn set_rate(&mut self, rate: f32) {
+        self.rate = if rate.is_finite() { rate.max(0.0) } else { 0.0 };
+    }
+
+    pub const fn rate(&self) -> f32 {
+        self.rate
+    }
+
+    /// Advance the clock to the host time `now_seconds` and return the
+    /// frame plugins should run with.
+    pub fn frame(&mut self, dt_seconds: f32, now_seconds: f64) -> TransitionFrame {
+        match self.last_host_seconds {
+            None => {
+                self.clock_seconds = now_seconds;
+                self.last_host_seconds = Some(now_seconds);
+            }
+            // A host time behind the latest one (a zero-dt resample from
+            // another time source) neither advances nor rewinds the clock.
+            Some(last) if now_seconds > last => {
+                self.clock_seconds += (now_seconds - last) * self.effective_rate() as f64;
+                self.last_host_seconds = Some(now_seconds);
+            }
+            Some(_) => {}
+        }
+        self.scaled_frame(dt_seconds)
+    }
+
+    fn effective_rate(&self) -> f32 {
+        if self.paused { 0.0 } else { self.rate }
+    }
+
+    fn scaled_frame(&self, dt_seconds: f32) -> TransitionFrame {
+        TransitionFrame {
+            dt_seconds: dt_seconds.max(0.0) * self.effective_rate(),
+            now_seconds: self.clock_seconds,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests;
@@ -0,0 +1,29 @@
+use super::*;
+
+#[test]
+fn rate_scales_and_pause_freezes_the_clock() {
+    let mut playback = TransitionPlayback::new();
+    assert_eq!(playback.frame(0.0, 10.0).now_seconds, 10.0);
+
+    playback.set_rate(0.5);
+    let frame = playback.frame(1.0, 11.0);
+    assert_eq!(frame.now_seconds, 10.5);
+    assert_eq!(frame.dt_seconds, 0.5);
+    assert_eq!(
+        playback.frame(1.0, 11.0).now_seconds,
+        10.5,
+        "the same host time does not advance the clock twice",
+    );
+    assert_eq!(playback.frame(0.0, 3.0).now_seconds, 10.5);
+
+    playback.pause();
+    let frame = playback.frame(1.0, 12.0);
+    assert_eq!(frame.now_seconds, 10.5);
+    assert_eq!(frame.dt_seconds, 0.0);
+
+    playback.resume();
+    playback.set_rate(f32::NAN);
+    assert_eq!(playback.rate(), 0.0);
+    playback.set_rate(2.0);
+    assert_eq!(playback.frame(0.5, 12.5).now_seconds, 11.5);
+}
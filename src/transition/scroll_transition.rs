@@ -70,6 +70,62 @@ impl ScrollTransition {
     }
 }
 
+/// How wheel and trackpad deltas drive the scroll transition track.
+///
+/// Discrete wheel ticks animate towards their destination with `smoothing`
+/// (falling back to the viewport's shared scroll transition, the same curve
+/// scrollbar-track clicks use). A tick that lands while an earlier one is
+/// still animating extends that track's destination instead of restarting
+/// from the current position, and glides there with the `inertia` curve so
+/// fast spins build up speed. Phased trackpad gestures already carry OS
+/// momentum and, with `native_momentum`, apply their deltas directly.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct WheelScrollTransition {
+    pub smoothing: Option<ScrollTransition>,
+    pub inertia: ScrollTransition,
+    pub native_momentum: bool,
+}
+
+impl WheelScrollTransition {
+    pub const fn new() -> Self {
+        Self {
+            smoothing: None,
+            inertia: ScrollTransition::new(400).ease_out(),
+            native_momentum: true,
+        }
+    }
+
+    /// Apply every wheel delta immediately, without animating.
+    pub const fn instant() -> Self {
+        Self {
+            smoothing: Some(ScrollTransition::new(0)),
+            inertia: ScrollTransition::new(0),
+            native_momentum: true,
+        }
+    }
+
+    pub const fn smoothing(mut self, smoothing: ScrollTransition) -> Self {
+        self.smoothing = Some(smoothing);
+        self
+    }
+
+    pub const fn inertia(mut self, inertia: ScrollTransition) -> Self {
+        self.inertia = inertia;
+        self
+    }
+
+    pub const fn native_momentum(mut self, native_momentum: bool) -> Self {
+        self.native_momentum = native_momentum;
+        self
+    }
+}
+
+impl Default for WheelScrollTransition {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
 #[derive(Clone, Copy, Debug, PartialEq)]
 pub struct ScrollSample {
     pub target: TrackTarget,
@@ -144,6 +200,16 @@ impl ScrollTransitionPlugin {
         Ok(())
     }
 
+    /// Destination of the running track for `target` on `axis`, if any.
+    pub fn pending_scroll_target(&self, target: TrackTarget, axis: ScrollAxis) -> Option<f32> {
+        self.tracks
+            .get(&TrackKey {
+                target,
+                channel: axis.channel_id(),
+            })
+            .map(|state| state.to)
+    }
+
     pub fn take_samples(&mut self) -> Vec<ScrollSample> {
         std::mem::take(&mut self.frame_samples)
     }
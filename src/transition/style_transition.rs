@@ -4,11 +4,15 @@
 use rustc_hash::FxHashMap;
 
 use super::{
-    ChannelId, ClaimMode, RunResult, StartTrackError, TimeFunction, TrackKey, TrackTarget,
-    Transition, TransitionFrame, TransitionHost, TransitionPluginId, elapsed_seconds_from_frame,
+    ChannelId, ClaimMode, RunResult, StartTrackError, TimeFunction, TrackKey, TrackLifecycleEvent,
+    TrackPhase, TrackTarget, Transition, TransitionFrame, TransitionHost, TransitionPluginId,
+    elapsed_seconds_from_frame, finish_timeline, hold_timeline_for_frame,
     normalized_timeline_progress,
 };
-use crate::style::{BoxShadow, Color, Interpolate, Transform, TransformOrigin};
+use crate::style::{
+    BoxShadow, Color, ColorInterpolation, Interpolate, Transform, TransformOrigin,
+    interpolate_color_in,
+};
 
 pub const CHANNEL_STYLE_OPACITY: ChannelId = ChannelId(30_001);
 pub const CHANNEL_STYLE_BORDER_RADIUS: ChannelId = ChannelId(30_002);
@@ -21,6 +25,11 @@ pub const CHANNEL_STYLE_BORDER_LEFT_COLOR: ChannelId = ChannelId(30_008);
 pub const CHANNEL_STYLE_TRANSFORM: ChannelId = ChannelId(30_009);
 pub const CHANNEL_STYLE_TRANSFORM_ORIGIN: ChannelId = ChannelId(30_010);
 pub const CHANNEL_STYLE_BOX_SHADOW: ChannelId = ChannelId(30_011);
+pub const CHANNEL_STYLE_GAP: ChannelId = ChannelId(30_012);
+pub const CHANNEL_STYLE_PADDING_TOP: ChannelId = ChannelId(30_013);
+pub const CHANNEL_STYLE_PADDING_RIGHT: ChannelId = ChannelId(30_014);
+pub const CHANNEL_STYLE_PADDING_BOTTOM: ChannelId = ChannelId(30_015);
+pub const CHANNEL_STYLE_PADDING_LEFT: ChannelId = ChannelId(30_016);
 
 #[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
 pub enum StyleField {
@@ -35,6 +44,11 @@ pub enum StyleField {
     Transform,
     TransformOrigin,
     BoxShadow,
+    Gap,
+    PaddingTop,
+    PaddingRight,
+    PaddingBottom,
+    PaddingLeft,
 }
 
 impl StyleField {
@@ -51,12 +65,58 @@ impl StyleField {
             Self::Transform => CHANNEL_STYLE_TRANSFORM,
             Self::TransformOrigin => CHANNEL_STYLE_TRANSFORM_ORIGIN,
             Self::BoxShadow => CHANNEL_STYLE_BOX_SHADOW,
+            Self::Gap => CHANNEL_STYLE_GAP,
+            Self::PaddingTop => CHANNEL_STYLE_PADDING_TOP,
+            Self::PaddingRight => CHANNEL_STYLE_PADDING_RIGHT,
+            Self::PaddingBottom => CHANNEL_STYLE_PADDING_BOTTOM,
+            Self::PaddingLeft => CHANNEL_STYLE_PADDING_LEFT,
         }
     }
 
+    pub const fn from_channel_id(channel: ChannelId) -> Option<Self> {
+        Some(match channel {
+            CHANNEL_STYLE_OPACITY => Self::Opacity,
+            CHANNEL_STYLE_BORDER_RADIUS => Self::BorderRadius,
+            CHANNEL_STYLE_BACKGROUND_COLOR => Self::BackgroundColor,
+            CHANNEL_STYLE_COLOR => Self::Color,
+            CHANNEL_STYLE_BORDER_TOP_COLOR => Self::BorderTopColor,
+            CHANNEL_STYLE_BORDER_RIGHT_COLOR => Self::BorderRightColor,
+            CHANNEL_STYLE_BORDER_BOTTOM_COLOR => Self::BorderBottomColor,
+            CHANNEL_STYLE_BORDER_LEFT_COLOR => Self::BorderLeftColor,
+            CHANNEL_STYLE_TRANSFORM => Self::Transform,
+            CHANNEL_STYLE_TRANSFORM_ORIGIN => Self::TransformOrigin,
+            CHANNEL_STYLE_BOX_SHADOW => Self::BoxShadow,
+            CHANNEL_STYLE_GAP => Self::Gap,
+            CHANNEL_STYLE_PADDING_TOP => Self::PaddingTop,
+            CHANNEL_STYLE_PADDING_RIGHT => Self::PaddingRight,
+            CHANNEL_STYLE_PADDING_BOTTOM => Self::PaddingBottom,
+            CHANNEL_STYLE_PADDING_LEFT => Self::PaddingLeft,
+            _ => return None,
+        })
+    }
+
+    /// Whether samples of this field change the box geometry and therefore
+    /// need a layout pass rather than just a repaint.
+    pub const fn affects_layout(self) -> bool {
+        matches!(
+            self,
+            Self::Gap
+                | Self::PaddingTop
+                | Self::PaddingRight
+                | Self::PaddingBottom
+                | Self::PaddingLeft
+        )
+    }
+
     pub fn default_value(self) -> StyleValue {
         match self {
-            Self::Opacity | Self::BorderRadius => StyleValue::Scalar(0.0),
+            Self::Opacity
+            | Self::BorderRadius
+            | Self::Gap
+            | Self::PaddingTop
+            | Self::PaddingRight
+            | Self::PaddingBottom
+            | Self::PaddingLeft => StyleValue::Scalar(0.0),
             Self::BackgroundColor
             | Self::Color
             | Self::BorderTopColor
@@ -70,8 +130,26 @@ impl StyleField {
     }
 
     pub fn interpolate_value(self, from: StyleValue, to: StyleValue, t: f32) -> StyleValue {
+        self.interpolate_value_in(from, to, t, ColorInterpolation::default())
+    }
+
+    /// Like [`Self::interpolate_value`], blending color fields through
+    /// `color_space`.
+    pub fn interpolate_value_in(
+        self,
+        from: StyleValue,
+        to: StyleValue,
+        t: f32,
+        color_space: ColorInterpolation,
+    ) -> StyleValue {
         match self {
-            Self::Opacity | Self::BorderRadius => match (from, to) {
+            Self::Opacity
+            | Self::BorderRadius
+            | Self::Gap
+            | Self::PaddingTop
+            | Self::PaddingRight
+            | Self::PaddingBottom
+            | Self::PaddingLeft => match (from, to) {
                 (StyleValue::Scalar(from), StyleValue::Scalar(to)) => {
                     StyleValue::Scalar(f32::interpolate(&from, &to, t))
                 }
@@ -84,7 +162,7 @@ impl StyleField {
             | Self::BorderBottomColor
             | Self::BorderLeftColor => match (from, to) {
                 (StyleValue::Color(from), StyleValue::Color(to)) => {
-                    StyleValue::Color(Color::interpolate(&from, &to, t))
+                    StyleValue::Color(interpolate_color_in(&from, &to, t, color_space))
                 }
                 (_, to) => to,
             },
@@ -149,6 +227,7 @@ pub struct StyleTransition {
     pub duration_ms: u32,
     pub delay_ms: u32,
     pub timing: TimeFunction,
+    pub color_interpolation: ColorInterpolation,
 }
 
 impl StyleTransition {
@@ -157,6 +236,7 @@ impl StyleTransition {
             duration_ms,
             delay_ms: 0,
             timing: TimeFunction::EaseOut,
+            color_interpolation: ColorInterpolation::Oklab,
         }
     }
 
@@ -169,6 +249,11 @@ impl StyleTransition {
         self.timing = timing;
         self
     }
+
+    pub const fn color_interpolation(mut self, color_interpolation: ColorInterpolation) -> Self {
+        self.color_interpolation = color_interpolation;
+        self
+    }
 }
 
 #[derive(Clone, Debug, PartialEq)]
@@ -211,6 +296,7 @@ struct StyleTrackState {
     from: StyleValue,
     to: StyleValue,
     started_at_seconds: Option<f64>,
+    paused: bool,
     transition: StyleTransition,
 }
 
@@ -219,6 +305,7 @@ pub struct StyleTransitionPlugin {
     plugin_id: TransitionPluginId,
     tracks: FxHashMap<TrackKey<TrackTarget>, StyleTrackState>,
     frame_samples: Vec<StyleSample>,
+    lifecycle_events: Vec<TrackLifecycleEvent<TrackTarget>>,
 }
 
 impl Default for StyleTransitionPlugin {
@@ -239,6 +326,7 @@ impl StyleTransitionPlugin {
             plugin_id,
             tracks: FxHashMap::default(),
             frame_samples: Vec::new(),
+            lifecycle_events: Vec::new(),
         }
     }
 
@@ -269,21 +357,65 @@ impl StyleTransitionPlugin {
         if !host.claim_track(self.plugin_id, key, ClaimMode::Replace) {
             return Err(StartTrackError::ClaimRejected(key));
         }
-        self.tracks.insert(
+        let replaced = self.tracks.insert(
             key,
             StyleTrackState {
                 from,
                 to,
                 started_at_seconds: None,
+                paused: false,
                 transition,
             },
         );
+        if replaced.is_some() {
+            self.push_lifecycle_event(key, TrackPhase::Cancel);
+        }
+        self.push_lifecycle_event(key, TrackPhase::Start);
         Ok(())
     }
 
     pub fn take_samples(&mut self) -> Vec<StyleSample> {
         std::mem::take(&mut self.frame_samples)
     }
+
+    /// Freeze or unfreeze the track at `key` in place. Returns `false` when
+    /// no such track is running.
+    pub fn set_track_paused(&mut self, key: TrackKey<TrackTarget>, paused: bool) -> bool {
+        let Some(state) = self.tracks.get_mut(&key) else {
+            return false;
+        };
+        state.paused = paused;
+        true
+    }
+
+    /// Jump the track at `key` to its target on the next run. Returns
+    /// `false` when no such track is running.
+    pub fn finish_track(&mut self, key: TrackKey<TrackTarget>) -> bool {
+        let Some(state) = self.tracks.get_mut(&key) else {
+            return false;
+        };
+        state.paused = false;
+        finish_timeline(&mut state.started_at_seconds);
+        true
+    }
+
+    /// Jump every running track to its target on the next run.
+    pub fn finish_all_tracks(&mut self) {
+        for state in self.tracks.values_mut() {
+            state.paused = false;
+            finish_timeline(&mut state.started_at_seconds);
+        }
+    }
+
+    /// Drain the start/end/cancel events recorded since the last call.
+    pub fn take_lifecycle_events(&mut self) -> Vec<TrackLifecycleEvent<TrackTarget>> {
+        std::mem::take(&mut self.lifecycle_events)
+    }
+
+    fn push_lifecycle_event(&mut self, key: TrackKey<TrackTarget>, phase: TrackPhase) {
+        self.lifecycle_events
+            .push(TrackLifecycleEvent { key, phase });
+    }
 }
 
 impl Transition<TrackTarget> for StyleTransitionPlugin {
@@ -304,6 +436,11 @@ impl Transition<TrackTarget> for StyleTransitionPlugin {
             CHANNEL_STYLE_TRANSFORM,
             CHANNEL_STYLE_TRANSFORM_ORIGIN,
             CHANNEL_STYLE_BOX_SHADOW,
+            CHANNEL_STYLE_GAP,
+            CHANNEL_STYLE_PADDING_TOP,
+            CHANNEL_STYLE_PADDING_RIGHT,
+            CHANNEL_STYLE_PADDING_BOTTOM,
+            CHANNEL_STYLE_PADDING_LEFT,
         ]
     }
 
@@ -312,19 +449,8 @@ impl Transition<TrackTarget> for StyleTransitionPlugin {
         key: TrackKey<TrackTarget>,
         host: &mut dyn TransitionHost<TrackTarget>,
     ) -> Result<(), StartTrackError<TrackTarget>> {
-        let field = match key.channel {
-            CHANNEL_STYLE_OPACITY => StyleField::Opacity,
-            CHANNEL_STYLE_BORDER_RADIUS => StyleField::BorderRadius,
-            CHANNEL_STYLE_BACKGROUND_COLOR => StyleField::BackgroundColor,
-            CHANNEL_STYLE_COLOR => StyleField::Color,
-            CHANNEL_STYLE_BORDER_TOP_COLOR => StyleField::BorderTopColor,
-            CHANNEL_STYLE_BORDER_RIGHT_COLOR => StyleField::BorderRightColor,
-            CHANNEL_STYLE_BORDER_BOTTOM_COLOR => StyleField::BorderBottomColor,
-            CHANNEL_STYLE_BORDER_LEFT_COLOR => StyleField::BorderLeftColor,
-            CHANNEL_STYLE_TRANSFORM => StyleField::Transform,
-            CHANNEL_STYLE_TRANSFORM_ORIGIN => StyleField::TransformOrigin,
-            CHANNEL_STYLE_BOX_SHADOW => StyleField::BoxShadow,
-            _ => return Err(StartTrackError::ChannelNotRegistered(key.channel)),
+        let Some(field) = StyleField::from_channel_id(key.channel) else {
+            return Err(StartTrackError::ChannelNotRegistered(key.channel));
         };
         self.start_style_track(
             host,
@@ -341,7 +467,9 @@ impl Transition<TrackTarget> for StyleTransitionPlugin {
         key: TrackKey<TrackTarget>,
         host: &mut dyn TransitionHost<TrackTarget>,
     ) {
-        self.tracks.remove(&key);
+        if self.tracks.remove(&key).is_some() {
+            self.push_lifecycle_event(key, TrackPhase::Cancel);
+        }
         host.release_track_claim(self.plugin_id, key);
     }
 
@@ -354,29 +482,25 @@ impl Transition<TrackTarget> for StyleTransitionPlugin {
         let mut finished = Vec::new();
 
         for (key, state) in &mut self.tracks {
+            if state.paused {
+                hold_timeline_for_frame(frame, &mut state.started_at_seconds);
+            }
             let elapsed_seconds = elapsed_seconds_from_frame(frame, &mut state.started_at_seconds);
             let delay = (state.transition.delay_ms as f32) * 0.001;
             let duration = (state.transition.duration_ms as f32) * 0.001;
-            let Some(progress) = normalized_timeline_progress(elapsed_seconds, delay, duration)
-            else {
-                continue;
-            };
+            // Hold `from` through the delay instead of showing the target early.
+            let progress =
+                normalized_timeline_progress(elapsed_seconds, delay, duration).unwrap_or(0.0);
             let eased = state.transition.timing.sample(progress);
-            let field = match key.channel {
-                CHANNEL_STYLE_OPACITY => StyleField::Opacity,
-                CHANNEL_STYLE_BORDER_RADIUS => StyleField::BorderRadius,
-                CHANNEL_STYLE_BACKGROUND_COLOR => StyleField::BackgroundColor,
-                CHANNEL_STYLE_COLOR => StyleField::Color,
-                CHANNEL_STYLE_BORDER_TOP_COLOR => StyleField::BorderTopColor,
-                CHANNEL_STYLE_BORDER_RIGHT_COLOR => StyleField::BorderRightColor,
-                CHANNEL_STYLE_BORDER_BOTTOM_COLOR => StyleField::BorderBottomColor,
-                CHANNEL_STYLE_BORDER_LEFT_COLOR => StyleField::BorderLeftColor,
-                CHANNEL_STYLE_TRANSFORM => StyleField::Transform,
-                CHANNEL_STYLE_TRANSFORM_ORIGIN => StyleField::TransformOrigin,
-                CHANNEL_STYLE_BOX_SHADOW => StyleField::BoxShadow,
-                _ => continue,
+            let Some(field) = StyleField::from_channel_id(key.channel) else {
+                continue;
             };
-            let value = field.interpolate_value(state.from.clone(), state.to.clone(), eased);
+            let value = field.interpolate_value_in(
+                state.from.clone(),
+                state.to.clone(),
+                eased,
+                state.transition.color_interpolation,
+            );
             self.frame_samples.push(StyleSample {
                 target: key.target,
                 field,
@@ -390,10 +514,14 @@ impl Transition<TrackTarget> for StyleTransitionPlugin {
         for key in finished {
             self.tracks.remove(&key);
             host.release_track_claim(self.plugin_id, key);
+            self.push_lifecycle_event(key, TrackPhase::End);
         }
 
         RunResult {
-            needs_layout: false,
+            needs_layout: self
+                .frame_samples
+                .iter()
+                .any(|sample| sample.field.affects_layout()),
             needs_paint: !self.frame_samples.is_empty(),
             keep_running: !self.tracks.is_empty(),
         }
@@ -428,6 +556,22 @@ mod tests {
         assert!((value - 0.35).abs() < 0.0001);
     }
 
+    #[test]
+    fn gap_and_padding_fields_interpolate_as_layout_scalars() {
+        let value = StyleField::PaddingLeft.interpolate_value(
+            StyleValue::Scalar(4.0),
+            StyleValue::Scalar(12.0),
+            0.5,
+        );
+        assert_eq!(value, StyleValue::Scalar(8.0));
+        assert!(StyleField::Gap.affects_layout());
+        assert!(!StyleField::BoxShadow.affects_layout());
+        assert_eq!(
+            StyleField::from_channel_id(StyleField::PaddingBottom.channel_id()),
+            Some(StyleField::PaddingBottom)
+        );
+    }
+
     #[test]
     fn field_default_values_match_property_kind() {
         assert_eq!(StyleField::Opacity.default_value(), StyleValue::Scalar(0.0));
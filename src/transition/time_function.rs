@@ -8,6 +8,112 @@ pub enum TimeFunction {
     EaseIn,
     EaseOut,
     EaseInOut,
+    CubicBezier(CubicBezier),
+    Steps(u32, StepPosition),
+}
+
+/// A CSS `cubic-bezier(x1, y1, x2, y2)` easing curve from `(0, 0)` to
+/// `(1, 1)`. The `x` coordinates are clamped into `0..=1` when sampling so
+/// the curve stays a function of time; `y` may overshoot.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct CubicBezier {
+    x1: f32,
+    y1: f32,
+    x2: f32,
+    y2: f32,
+}
+
+// Sound because `new` is the only constructor and rejects NaN.
+impl Eq for CubicBezier {}
+
+impl CubicBezier {
+    /// Panics unless every control point is finite.
+    pub const fn new(x1: f32, y1: f32, x2: f32, y2: f32) -> Self {
+        assert!(
+            x1.is_finite() && y1.is_finite() && x2.is_finite() && y2.is_finite(),
+            "cubic-bezier control points must be finite"
+        );
+        Self { x1, y1, x2, y2 }
+    }
+
+    /// The control points as `[x1, y1, x2, y2]`.
+    pub const fn control_points(self) -> [f32; 4] {
+        [self.x1, self.y1, self.x2, self.y2]
+    }
+
+    pub fn sample(self, t: f32) -> f32 {
+        if t <= 0.0 || t >= 1.0 {
+            return t.clamp(0.0, 1.0);
+        }
+        let x1 = self.x1.clamp(0.0, 1.0);
+        let x2 = self.x2.clamp(0.0, 1.0);
+        let curve = |p1: f32, p2: f32, u: f32| {
+            let inv = 1.0 - u;
+            3.0 * inv * inv * u * p1 + 3.0 * inv * u * u * p2 + u * u * u
+        };
+        let slope = |p1: f32, p2: f32, u: f32| {
+            let inv = 1.0 - u;
+            3.0 * inv * inv * p1 + 6.0 * inv * u * (p2 - p1) + 3.0 * u * u * (1.0 - p2)
+        };
+
+        // Newton steps converge fast on well-behaved curves; fall back to
+        // bisection where the slope flattens out.
+        let mut u = t;
+        for _ in 0..8 {
+            let error = curve(x1, x2, u) - t;
+            if error.abs() < 1e-6 {
+                return curve(self.y1, self.y2, u);
+            }
+            let d = slope(x1, x2, u);
+            if d.abs() < 1e-6 {
+                break;
+            }
+            u = (u - error / d).clamp(0.0, 1.0);
+        }
+        let (mut lo, mut hi) = (0.0_f32, 1.0_f32);
+        u = t;
+        for _ in 0..32 {
+            let x = curve(x1, x2, u);
+            if (x - t).abs() < 1e-6 {
+                break;
+            }
+            if x < t {
+                lo = u;
+            } else {
+                hi = u;
+            }
+            u = (lo + hi) * 0.5;
+        }
+        curve(self.y1, self.y2, u)
+    }
+}
+
+/// Where the jumps of a CSS `steps(n, <position>)` easing fall.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Hash)]
+pub enum StepPosition {
+    /// First jump happens as the timeline starts (`jump-start` / `start`).
+    JumpStart,
+    /// Last jump happens as the timeline ends (`jump-end` / `end`).
+    #[default]
+    JumpEnd,
+    /// No jump at either end; holds `0` and `1` for one step each.
+    JumpNone,
+    /// Jumps at both ends.
+    JumpBoth,
+}
+
+fn sample_steps(steps: u32, position: StepPosition, t: f32) -> f32 {
+    let steps = steps.max(1);
+    let jumps = match position {
+        StepPosition::JumpStart | StepPosition::JumpEnd => steps,
+        StepPosition::JumpNone => steps.saturating_sub(1).max(1),
+        StepPosition::JumpBoth => steps.saturating_add(1),
+    };
+    let mut step = (t * steps as f32).floor();
+    if matches!(position, StepPosition::JumpStart | StepPosition::JumpBoth) {
+        step += 1.0;
+    }
+    step.clamp(0.0, jumps as f32) / jumps as f32
 }
 
 impl TimeFunction {
@@ -24,6 +130,8 @@ impl TimeFunction {
                     1.0 - ((-2.0 * t + 2.0).powi(2) * 0.5)
                 }
             }
+            Self::CubicBezier(curve) => curve.sample(t),
+            Self::Steps(steps, position) => sample_steps(steps, position, t),
         }
     }
 }
@@ -41,3 +149,6 @@ pub fn normalized_timeline_progress(
     }
     Some(((elapsed_seconds - delay_seconds) / duration_seconds).clamp(0.0, 1.0))
 }
+
+#[cfg(test)]
+mod tests;
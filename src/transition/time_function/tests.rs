@@ -0,0 +1,39 @@
+use super::*;
+
+#[test]
+fn cubic_bezier_matches_css_keyword_curves() {
+    let linear = CubicBezier::new(0.0, 0.0, 1.0, 1.0);
+    assert!((linear.sample(0.3) - 0.3).abs() < 1e-4);
+
+    // CSS `ease-in-out` is symmetric about its midpoint.
+    let ease_in_out = CubicBezier::new(0.42, 0.0, 0.58, 1.0);
+    assert!((ease_in_out.sample(0.5) - 0.5).abs() < 1e-4);
+    assert!((ease_in_out.sample(0.25) + ease_in_out.sample(0.75) - 1.0).abs() < 1e-4);
+
+    let overshoot = CubicBezier::new(0.3, 1.6, 0.7, 1.6);
+    assert!(
+        overshoot.sample(0.6) > 1.0,
+        "y control points may overshoot"
+    );
+    assert_eq!(overshoot.sample(1.0), 1.0);
+}
+
+#[test]
+#[should_panic(expected = "control points must be finite")]
+fn cubic_bezier_rejects_non_finite_control_points() {
+    let _ = CubicBezier::new(0.25, f32::NAN, 0.25, 1.0);
+}
+
+#[test]
+fn steps_follow_css_jump_positions() {
+    let sample = |position, t| TimeFunction::Steps(4, position).sample(t);
+    assert_eq!(sample(StepPosition::JumpEnd, 0.0), 0.0);
+    assert_eq!(sample(StepPosition::JumpEnd, 0.3), 0.25);
+    assert_eq!(sample(StepPosition::JumpEnd, 1.0), 1.0);
+    assert_eq!(sample(StepPosition::JumpStart, 0.0), 0.25);
+    assert_eq!(sample(StepPosition::JumpStart, 0.3), 0.5);
+    assert_eq!(sample(StepPosition::JumpNone, 0.3), 1.0 / 3.0);
+    assert_eq!(sample(StepPosition::JumpNone, 0.8), 1.0);
+    assert_eq!(sample(StepPosition::JumpBoth, 0.0), 0.2);
+    assert_eq!(sample(StepPosition::JumpBoth, 1.0), 1.0);
+}
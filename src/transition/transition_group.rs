@@ -0,0 +1,87 @@
+#![allow(missing_docs)]
+
+//! Stagger orchestration for the transitions of a group's children.
+use rustc_hash::{FxHashMap, FxHashSet};
+
+use super::TrackTarget;
+
+/// Staggers the transitions and animations started by a host's children.
+///
+/// Children that start tracks in the same frame form a wave; the n-th of
+/// them (in child order) is delayed by `delay_ms + n * stagger_ms` on top
+/// of its own transition delay. A lone child starting a track — a hover on
+/// one menu item — is the first of its wave and only gets `delay_ms`.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Hash)]
+pub struct TransitionGroup {
+    pub delay_ms: u32,
+    pub stagger_ms: u32,
+}
+
+impl TransitionGroup {
+    pub const fn new(stagger_ms: u32) -> Self {
+        Self {
+            delay_ms: 0,
+            stagger_ms,
+        }
+    }
+
+    pub const fn delay(mut self, delay_ms: u32) -> Self {
+        self.delay_ms = delay_ms;
+        self
+    }
+
+    /// Extra delay for the member at `position` within its wave.
+    pub const fn member_delay_ms(self, position: usize) -> u32 {
+        let position = if position > u32::MAX as usize {
+            u32::MAX
+        } else {
+            position as u32
+        };
+        self.delay_ms
+            .saturating_add(self.stagger_ms.saturating_mul(position))
+    }
+}
+
+/// Tracks which members of each group still have tracks in flight so the
+/// host can report when a whole staggered wave has settled.
+#[derive(Debug, Default)]
+pub(crate) struct TransitionGroupTracker {
+    pending: FxHashMap<TrackTarget, FxHashSet<TrackTarget>>,
+}
+
+impl TransitionGroupTracker {
+    /// Record `members` as part of `group`'s current wave. Waves that start
+    /// before the previous one settled are merged into it.
+    pub(crate) fn begin_wave(
+        &mut self,
+        group: TrackTarget,
+        members: impl IntoIterator<Item = TrackTarget>,
+    ) {
+        self.pending.entry(group).or_default().extend(members);
+    }
+
+    pub(crate) fn is_empty(&self) -> bool {
+        self.pending.is_empty()
+    }
+
+    /// Remove and return every group none of whose members is still
+    /// `is_active`, paired with the number of members in its wave.
+    pub(crate) fn take_settled(
+        &mut self,
+        is_active: impl Fn(TrackTarget) -> bool,
+    ) -> Vec<(TrackTarget, usize)> {
+        let settled = self
+            .pending
+            .iter()
+            .filter(|(_, members)| !members.iter().any(|member| is_active(*member)))
+            .map(|(group, members)| (*group, members.len()))
+            .collect::<Vec<_>>();
+        for (group, _) in &settled {
+            self.pending.remove(group);
+        }
+        settled
+    }
+}
+
+#[cfg(test)]
+mod tests;
@@ -0,0 +1,33 @@
+use super::*;
+
+#[test]
+fn member_delay_grows_by_stagger_from_group_delay() {
+    let group = TransitionGroup::new(40).delay(100);
+    assert_eq!(group.member_delay_ms(0), 100);
+    assert_eq!(group.member_delay_ms(3), 220);
+    assert_eq!(
+        TransitionGroup::new(u32::MAX).member_delay_ms(2),
+        u32::MAX,
+        "delays saturate instead of wrapping",
+    );
+}
+
+#[test]
+fn tracker_settles_group_once_every_member_is_idle() {
+    let mut tracker = TransitionGroupTracker::default();
+    tracker.begin_wave(1, [10, 11]);
+    tracker.begin_wave(1, [12]);
+    tracker.begin_wave(2, [20]);
+
+    let active = FxHashSet::from_iter([11_u64, 20]);
+    assert!(tracker.take_settled(|id| active.contains(&id)).is_empty());
+
+    let active = FxHashSet::from_iter([20_u64]);
+    assert_eq!(
+        tracker.take_settled(|id| active.contains(&id)),
+        vec![(1, 3)]
+    );
+    assert!(!tracker.is_empty());
+    assert_eq!(tracker.take_settled(|_| false), vec![(2, 1)]);
+    assert!(tracker.is_empty());
+}
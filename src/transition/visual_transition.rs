@@ -6,8 +6,9 @@ use rustc_hash::FxHashMap;
 use rustc_hash::FxHashSet;
 
 use super::{
-    ChannelId, ClaimMode, RunResult, StartTrackError, TimeFunction, TrackKey, TrackTarget,
-    Transition, TransitionFrame, TransitionHost, TransitionPluginId, elapsed_seconds_from_frame,
+    ChannelId, ClaimMode, RunResult, StartTrackError, TimeFunction, TrackKey, TrackLifecycleEvent,
+    TrackPhase, TrackTarget, Transition, TransitionFrame, TransitionHost, TransitionPluginId,
+    elapsed_seconds_from_frame, finish_timeline, hold_timeline_for_frame,
     normalized_timeline_progress,
 };
 
@@ -58,6 +59,7 @@ struct VisualTrackState {
     to: f32,
     current: f32,
     started_at_seconds: Option<f64>,
+    paused: bool,
     transition: VisualTransition,
 }
 
@@ -66,6 +68,7 @@ pub struct VisualTransitionPlugin {
     plugin_id: TransitionPluginId,
     tracks: FxHashMap<TrackKey<TrackTarget>, VisualTrackState>,
     frame_samples: Vec<VisualSample>,
+    lifecycle_events: Vec<TrackLifecycleEvent<TrackTarget>>,
 }
 
 impl Default for VisualTransitionPlugin {
@@ -86,6 +89,7 @@ impl VisualTransitionPlugin {
             plugin_id,
             tracks: FxHashMap::default(),
             frame_samples: Vec::new(),
+            lifecycle_events: Vec::new(),
         }
     }
 
@@ -109,6 +113,7 @@ impl VisualTransitionPlugin {
                 if (existing.current - existing.to).abs() <= 0.0001 {
                     self.tracks.remove(&key);
                     host.release_track_claim(self.plugin_id, key);
+                    self.push_lifecycle_event(key, TrackPhase::End);
                 }
                 return Ok(());
             }
@@ -120,22 +125,66 @@ impl VisualTransitionPlugin {
         if !host.claim_track(self.plugin_id, key, ClaimMode::Replace) {
             return Err(StartTrackError::ClaimRejected(key));
         }
-        self.tracks.insert(
+        let replaced = self.tracks.insert(
             key,
             VisualTrackState {
                 from: next_from,
                 to,
                 current: next_from,
                 started_at_seconds: None,
+                paused: false,
                 transition,
             },
         );
+        if replaced.is_some() {
+            self.push_lifecycle_event(key, TrackPhase::Cancel);
+        }
+        self.push_lifecycle_event(key, TrackPhase::Start);
         Ok(())
     }
 
     pub fn take_samples(&mut self) -> Vec<VisualSample> {
         std::mem::take(&mut self.frame_samples)
     }
+
+    /// Freeze or unfreeze the track at `key` in place. Returns `false` when
+    /// no such track is running.
+    pub fn set_track_paused(&mut self, key: TrackKey<TrackTarget>, paused: bool) -> bool {
+        let Some(state) = self.tracks.get_mut(&key) else {
+            return false;
+        };
+        state.paused = paused;
+        true
+    }
+
+    /// Jump the track at `key` to its target on the next run. Returns
+    /// `false` when no such track is running.
+    pub fn finish_track(&mut self, key: TrackKey<TrackTarget>) -> bool {
+        let Some(state) = self.tracks.get_mut(&key) else {
+            return false;
+        };
+        state.paused = false;
+        finish_timeline(&mut state.started_at_seconds);
+        true
+    }
+
+    /// Jump every running track to its target on the next run.
+    pub fn finish_all_tracks(&mut self) {
+        for state in self.tracks.values_mut() {
+            state.paused = false;
+            finish_timeline(&mut state.started_at_seconds);
+        }
+    }
+
+    /// Drain the start/end/cancel events recorded since the last call.
+    pub fn take_lifecycle_events(&mut self) -> Vec<TrackLifecycleEvent<TrackTarget>> {
+        std::mem::take(&mut self.lifecycle_events)
+    }
+
+    fn push_lifecycle_event(&mut self, key: TrackKey<TrackTarget>, phase: TrackPhase) {
+        self.lifecycle_events
+            .push(TrackLifecycleEvent { key, phase });
+    }
 }
 
 impl Transition<TrackTarget> for VisualTransitionPlugin {
@@ -176,7 +225,9 @@ impl Transition<TrackTarget> for VisualTransitionPlugin {
         key: TrackKey<TrackTarget>,
         host: &mut dyn TransitionHost<TrackTarget>,
     ) {
-        self.tracks.remove(&key);
+        if self.tracks.remove(&key).is_some() {
+            self.push_lifecycle_event(key, TrackPhase::Cancel);
+        }
         host.release_track_claim(self.plugin_id, key);
     }
 
@@ -189,13 +240,15 @@ impl Transition<TrackTarget> for VisualTransitionPlugin {
         let mut finished = Vec::new();
 
         for (key, state) in &mut self.tracks {
+            if state.paused {
+                hold_timeline_for_frame(frame, &mut state.started_at_seconds);
+            }
             let elapsed_seconds = elapsed_seconds_from_frame(frame, &mut state.started_at_seconds);
             let delay = (state.transition.delay_ms as f32) * 0.001;
             let duration = (state.transition.duration_ms as f32) * 0.001;
-            let Some(progress) = normalized_timeline_progress(elapsed_seconds, delay, duration)
-            else {
-                continue;
-            };
+            // A delayed track keeps its `from` offset until the delay elapses.
+            let progress =
+                normalized_timeline_progress(elapsed_seconds, delay, duration).unwrap_or(0.0);
             let eased = state.transition.timing.sample(progress);
             let value = state.from + (state.to - state.from) * eased;
             state.current = value;
@@ -218,6 +271,7 @@ impl Transition<TrackTarget> for VisualTransitionPlugin {
         for key in finished {
             self.tracks.remove(&key);
             host.release_track_claim(self.plugin_id, key);
+            self.push_lifecycle_event(key, TrackPhase::End);
         }
 
         RunResult {
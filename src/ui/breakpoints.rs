@@ -0,0 +1,61 @@
+//! Responsive layout breakpoints over [`use_viewport_size`].
+//!
+//! ```ignore
+//! let columns = match use_breakpoint(&Breakpoints::default()) {
+//!     Breakpoint::Xs | Breakpoint::Sm => 1,
+//!     Breakpoint::Md => 2,
+//!     Breakpoint::Lg => 3,
+//! };
+//! ```
+
+use super::state::use_viewport_size;
+
+/// Width class of the viewport, narrowest first.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub enum Breakpoint {
+    /// Narrower than [`Breakpoints::sm`].
+    Xs,
+    Sm,
+    Md,
+    Lg,
+}
+
+/// Minimum logical widths at which each [`Breakpoint`] starts.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct Breakpoints {
+    pub sm: f32,
+    pub md: f32,
+    pub lg: f32,
+}
+
+impl Default for Breakpoints {
+    /// 640 / 768 / 1024 logical pixels.
+    fn default() -> Self {
+        Self {
+            sm: 640.0,
+            md: 768.0,
+            lg: 1024.0,
+        }
+    }
+}
+
+impl Breakpoints {
+    pub fn classify(&self, width: f32) -> Breakpoint {
+        if width >= self.lg {
+            Breakpoint::Lg
+        } else if width >= self.md {
+            Breakpoint::Md
+        } else if width >= self.sm {
+            Breakpoint::Sm
+        } else {
+            Breakpoint::Xs
+        }
+    }
+}
+
+/// Breakpoint of the current viewport width. Reads
+/// [`use_viewport_size`], so the calling component re-renders on every
+/// resize, not only when the breakpoint changes.
+pub fn use_breakpoint(breakpoints: &Breakpoints) -> Breakpoint {
+    breakpoints.classify(use_viewport_size().0)
+}
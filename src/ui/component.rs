@@ -38,6 +38,185 @@ impl<T> IntoOptionalProp<T> for T {
     }
 }
 
+/// Init-struct slot of a `#[prop(into)]` field: accepts any `Into<T>`
+/// value instead of going through [`IntoOptionalProp`].
+#[doc(hidden)]
+pub struct __RsxIntoProp<T>(pub Option<T>);
+
+impl<T> Default for __RsxIntoProp<T> {
+    fn default() -> Self {
+        Self(None)
+    }
+}
+
+/// Receiver-side prop assignment used by `rsx!`. The init field's type
+/// picks the accepted values: `Option<T>` fields take anything that is
+/// [`IntoOptionalProp<T>`], `#[prop(into)]` fields anything `Into<T>`.
+#[doc(hidden)]
+pub trait __RsxPropField<V> {
+    fn __rsx_assign(&mut self, value: V);
+}
+
+impl<T, V: IntoOptionalProp<T>> __RsxPropField<V> for Option<T> {
+    fn __rsx_assign(&mut self, value: V) {
+        *self = value.into_optional_prop();
+    }
+}
+
+impl<T, V: Into<T>> __RsxPropField<V> for __RsxIntoProp<T> {
+    fn __rsx_assign(&mut self, value: V) {
+        self.0 = Some(value.into());
+    }
+}
+
+/// Default-value assignment used by [`styled!`](crate::styled): like
+/// [`__RsxPropField`], but leaves fields the call site already set alone.
+#[doc(hidden)]
+pub trait __RsxPropPreset<V> {
+    fn __rsx_preset(&mut self, value: V);
+}
+
+impl<T, V: IntoOptionalProp<T>> __RsxPropPreset<V> for Option<T> {
+    fn __rsx_preset(&mut self, value: V) {
+        if self.is_none() {
+            *self = value.into_optional_prop();
+        }
+    }
+}
+
+impl<T, V: Into<T>> __RsxPropPreset<V> for __RsxIntoProp<T> {
+    fn __rsx_preset(&mut self, value: V) {
+        if self.0.is_none() {
+            self.0 = Some(value.into());
+        }
+    }
+}
+
+/// Style preset used by [`styled!`](crate::styled): unlike other presets
+/// it is not dropped when the call site passes a style, but stacked
+/// beneath it.
+#[doc(hidden)]
+pub fn __rsx_preset_style<S: crate::style::ComposeStyle>(slot: &mut Option<S>, preset: Option<S>) {
+    let Some(mut merged) = preset else {
+        return;
+    };
+    if let Some(call_site) = slot.take() {
+        merged.extend(call_site);
+    }
+    *slot = Some(merged);
+}
+
+/// Runtime side of `#[prop(range(..))]`. An out-of-range value panics in
+/// debug builds; release builds log it and clamp it to the nearest
+/// inclusive bound, so data-driven values cannot take the app down.
+#[doc(hidden)]
+pub fn __rsx_check_prop_range<T, R>(
+    value: T,
+    range: R,
+    prop: &str,
+    props: &str,
+    range_text: &str,
+) -> T
+where
+    T: PartialOrd + Clone + std::fmt::Debug,
+    R: std::ops::RangeBounds<T>,
+{
+    use std::ops::Bound;
+
+    if range.contains(&value) {
+        return value;
+    }
+    let message = format!("prop `{prop}` on <{props}> must be in `{range_text}`, got {value:?}");
+    debug_assert!(false, "{message}");
+    eprintln!("[warn] {message}; clamping");
+    match (range.start_bound(), range.end_bound()) {
+        (Bound::Included(start), _) if value < *start => start.clone(),
+        (_, Bound::Included(end)) if value > *end => end.clone(),
+        _ => value,
+    }
+}
+
+/// Literal `#[prop(range(..))]` bounds of one prop, listed in the
+/// `__RSX_PROP_RANGES` table `#[props]` emits.
+#[doc(hidden)]
+pub struct __RsxPropRange {
+    pub prop: &'static str,
+    pub start: f64,
+    pub end: f64,
+    pub end_inclusive: bool,
+    pub message: &'static str,
+}
+
+/// Empty `__RSX_PROP_RANGES` for props types without literal ranges; the
+/// inherent table `#[props]` emits takes precedence over it.
+#[doc(hidden)]
+pub trait __RsxNoPropRanges {
+    const __RSX_PROP_RANGES: &'static [__RsxPropRange] = &[];
+}
+
+impl<T: ?Sized> __RsxNoPropRanges for T {}
+
+/// Compile-time check `rsx!` emits for numeric literal props.
+#[doc(hidden)]
+pub const fn __rsx_check_literal_prop(ranges: &[__RsxPropRange], prop: &str, value: f64) {
+    let mut index = 0;
+    while index < ranges.len() {
+        let range = &ranges[index];
+        let past_end = if range.end_inclusive {
+            value > range.end
+        } else {
+            value >= range.end
+        };
+        if str_eq(range.prop, prop) && (value < range.start || past_end) {
+            panic!("{}", range.message);
+        }
+        index += 1;
+    }
+}
+
+const fn str_eq(a: &str, b: &str) -> bool {
+    let (a, b) = (a.as_bytes(), b.as_bytes());
+    if a.len() != b.len() {
+        return false;
+    }
+    let mut index = 0;
+    while index < a.len() {
+        if a[index] != b[index] {
+            return false;
+        }
+        index += 1;
+    }
+    true
+}
+
+/// Pair of prop values compared by a `#[component]` props-equality shim.
+/// `(&__RsxPropsEq(a, b)).__rsx_props_eq()` resolves to `a == b` when the
+/// type is `PartialEq` and to `false` (always re-render) otherwise.
+#[doc(hidden)]
+pub struct __RsxPropsEq<'a, T>(pub &'a T, pub &'a T);
+
+#[doc(hidden)]
+pub trait __RsxPropsEqByValue {
+    fn __rsx_props_eq(&self) -> bool;
+}
+
+impl<T: PartialEq> __RsxPropsEqByValue for __RsxPropsEq<'_, T> {
+    fn __rsx_props_eq(&self) -> bool {
+        self.0 == self.1
+    }
+}
+
+#[doc(hidden)]
+pub trait __RsxPropsEqFallback {
+    fn __rsx_props_eq(&self) -> bool;
+}
+
+impl<T> __RsxPropsEqFallback for &__RsxPropsEq<'_, T> {
+    fn __rsx_props_eq(&self) -> bool {
+        false
+    }
+}
+
 macro_rules! impl_event_into_optional_prop {
     ($handler_ty:ty, $event_ty:ty) => {
         impl<F> IntoOptionalProp<$handler_ty> for F
@@ -86,7 +265,20 @@ impl_event_into_optional_prop!(
     crate::ui::ContextMenuHandlerProp,
     crate::ui::ContextMenuEvent
 );
+impl_event_into_optional_prop!(crate::ui::LongPressHandlerProp, crate::ui::LongPressEvent);
 impl_event_into_optional_prop!(crate::ui::WheelHandlerProp, crate::ui::WheelEvent);
+impl_event_into_optional_prop!(crate::ui::ScrollHandlerProp, crate::ui::ScrollEvent);
+impl_event_into_optional_prop!(crate::ui::ReachEndHandlerProp, crate::ui::ReachEndEvent);
+impl_event_into_optional_prop!(crate::ui::ResizeHandlerProp, crate::ui::ResizeEvent);
+impl_event_into_optional_prop!(
+    crate::ui::VisibilityChangeHandlerProp,
+    crate::ui::VisibilityChangeEvent
+);
+impl_event_into_optional_prop!(
+    crate::ui::TransitionGroupEndHandlerProp,
+    crate::ui::TransitionGroupEndEvent
+);
+impl_event_into_optional_prop!(crate::ui::TransitionHandlerProp, crate::ui::TransitionEvent);
 impl_event_into_optional_prop!(crate::ui::ImeCommitHandlerProp, crate::ui::ImeCommitEvent);
 impl_event_into_optional_prop!(crate::ui::ImeEnabledHandlerProp, crate::ui::ImeEnabledEvent);
 impl_event_into_optional_prop!(
@@ -151,6 +343,22 @@ impl_no_arg_event_into_optional_prop!(
     crate::ui::into_text_change_handler
 );
 
+impl<T, F> IntoOptionalProp<crate::ui::EventHandler<T>> for F
+where
+    T: 'static,
+    F: Fn(T) + 'static,
+{
+    fn into_optional_prop(self) -> Option<crate::ui::EventHandler<T>> {
+        Some(crate::ui::EventHandler::new(self))
+    }
+}
+
+impl<T> IntoOptionalProp<crate::ui::EventHandler<T>> for std::rc::Rc<dyn Fn(T)> {
+    fn into_optional_prop(self) -> Option<crate::ui::EventHandler<T>> {
+        Some(crate::ui::EventHandler::from(self))
+    }
+}
+
 impl<'a> IntoOptionalProp<crate::style::Color> for crate::style::HexColor<'a> {
     fn into_optional_prop(self) -> Option<crate::style::Color> {
         Some(crate::style::IntoColor::<crate::style::Color>::into_color(
@@ -162,7 +370,7 @@ impl<'a> IntoOptionalProp<crate::style::Color> for crate::style::HexColor<'a> {
 impl IntoOptionalProp<Box<dyn crate::style::ColorLike>> for &str {
     fn into_optional_prop(self) -> Option<Box<dyn crate::style::ColorLike>> {
         Some(Box::new(
-            crate::style::IntoColor::<crate::style::Color>::into_color(self),
+            crate::style::IntoColor::<crate::style::StyleColor>::into_color(self),
         ))
     }
 }
@@ -170,7 +378,7 @@ impl IntoOptionalProp<Box<dyn crate::style::ColorLike>> for &str {
 impl IntoOptionalProp<Box<dyn crate::style::ColorLike>> for String {
     fn into_optional_prop(self) -> Option<Box<dyn crate::style::ColorLike>> {
         Some(Box::new(
-            crate::style::IntoColor::<crate::style::Color>::into_color(self),
+            crate::style::IntoColor::<crate::style::StyleColor>::into_color(self),
         ))
     }
 }
@@ -238,7 +446,7 @@ impl<'a> IntoOptionalProp<crate::style::Background> for crate::style::HexColor<'
 impl IntoOptionalProp<crate::style::Background> for &str {
     fn into_optional_prop(self) -> Option<crate::style::Background> {
         Some(crate::style::Background::Color(Box::new(
-            crate::style::IntoColor::<crate::style::Color>::into_color(self),
+            crate::style::IntoColor::<crate::style::StyleColor>::into_color(self),
         )))
     }
 }
@@ -246,12 +454,12 @@ impl IntoOptionalProp<crate::style::Background> for &str {
 impl IntoOptionalProp<crate::style::Background> for String {
     fn into_optional_prop(self) -> Option<crate::style::Background> {
         Some(crate::style::Background::Color(Box::new(
-            crate::style::IntoColor::<crate::style::Color>::into_color(self),
+            crate::style::IntoColor::<crate::style::StyleColor>::into_color(self),
         )))
     }
 }
 
-// Gradient (for background_image / border_image fields): accept builders too.
+// Gradient (for background_image fields): accept builders too.
 impl IntoOptionalProp<crate::style::Gradient> for crate::style::LinearBuilder {
     fn into_optional_prop(self) -> Option<crate::style::Gradient> {
         Some(self.build())
@@ -270,6 +478,68 @@ impl IntoOptionalProp<crate::style::Gradient> for crate::style::ConicBuilder {
     }
 }
 
+// BorderImage: a gradient (or its builder) or a nine-patch.
+impl IntoOptionalProp<crate::style::BorderImage> for crate::style::Gradient {
+    fn into_optional_prop(self) -> Option<crate::style::BorderImage> {
+        Some(self.into())
+    }
+}
+
+impl IntoOptionalProp<crate::style::BorderImage> for crate::style::LinearBuilder {
+    fn into_optional_prop(self) -> Option<crate::style::BorderImage> {
+        Some(self.into())
+    }
+}
+
+impl IntoOptionalProp<crate::style::BorderImage> for crate::style::RadialBuilder {
+    fn into_optional_prop(self) -> Option<crate::style::BorderImage> {
+        Some(self.into())
+    }
+}
+
+impl IntoOptionalProp<crate::style::BorderImage> for crate::style::ConicBuilder {
+    fn into_optional_prop(self) -> Option<crate::style::BorderImage> {
+        Some(self.into())
+    }
+}
+
+impl IntoOptionalProp<crate::style::BorderImage> for crate::style::NinePatch {
+    fn into_optional_prop(self) -> Option<crate::style::BorderImage> {
+        Some(self.into())
+    }
+}
+
+// Mask: an image source or a gradient (or its builder).
+impl IntoOptionalProp<crate::style::Mask> for crate::view::ImageSource {
+    fn into_optional_prop(self) -> Option<crate::style::Mask> {
+        Some(self.into())
+    }
+}
+
+impl IntoOptionalProp<crate::style::Mask> for crate::style::Gradient {
+    fn into_optional_prop(self) -> Option<crate::style::Mask> {
+        Some(self.into())
+    }
+}
+
+impl IntoOptionalProp<crate::style::Mask> for crate::style::LinearBuilder {
+    fn into_optional_prop(self) -> Option<crate::style::Mask> {
+        Some(self.into())
+    }
+}
+
+impl IntoOptionalProp<crate::style::Mask> for crate::style::RadialBuilder {
+    fn into_optional_prop(self) -> Option<crate::style::Mask> {
+        Some(self.into())
+    }
+}
+
+impl IntoOptionalProp<crate::style::Mask> for crate::style::ConicBuilder {
+    fn into_optional_prop(self) -> Option<crate::style::Mask> {
+        Some(self.into())
+    }
+}
+
 impl IntoOptionalProp<String> for &str {
     fn into_optional_prop(self) -> Option<String> {
         Some(self.to_string())
@@ -508,6 +778,19 @@ impl IntoRsxChildren for Option<RsxNode> {
     }
 }
 
+/// A `{items.iter().map(|item| (item.id, rsx! { ... }))}` child: each node
+/// is keyed, so the list reconciles by key instead of by position.
+impl<I, F, K> IntoRsxChildren for std::iter::Map<I, F>
+where
+    I: Iterator,
+    F: FnMut(I::Item) -> (K, RsxNode),
+    K: std::hash::Hash + std::any::Any,
+{
+    fn into_rsx_children(self) -> Vec<RsxNode> {
+        crate::ui::rsx_tree::keyed_children(self)
+    }
+}
+
 pub fn append_rsx_child_node<T>(children: &mut Vec<RsxNode>, value: T)
 where
     T: IntoRsxChildren,
@@ -544,8 +827,8 @@ pub struct ComponentVTable {
     /// struct and the corresponding shim.
     pub clone_props: unsafe fn(NonNull<()>) -> NonNull<()>,
     /// Structural equality of two boxed `T::Props`. `None` opts out of
-    /// memoization (each render re-invokes `render`). Emitted as
-    /// `Some(_)` only when the user derives `PartialEq` on the props struct.
+    /// memoization (each render re-invokes `render`). `#[component]` always
+    /// emits `Some(_)`; props without `PartialEq` compare unequal.
     pub props_eq: Option<unsafe fn(NonNull<()>, NonNull<()>) -> bool>,
     pub type_name: &'static str,
 }
@@ -825,41 +1108,98 @@ pub fn unwrap_components(node: RsxNode) -> RsxNode {
                 props,
                 vtable,
             } = parts;
-            with_component_key(key, || {
-                crate::ui::render_component_by_type_id(type_id, || {
-                    // Safety: `props` was produced by `Box::into_raw(Box::new(T::Props))`
-                    // during Component construction, and `vtable.render` is the
-                    // monomorphized shim that `Box::from_raw`s it back to the
-                    // exact same T. `children` is owned here after
-                    // `into_render_parts` — the shim consumes both.
-                    let rendered = unsafe { (vtable.render)(props, children) };
-                    let mut walked = unwrap_components(rendered);
-                    walked.set_identity(identity);
-                    // Mirror pre-P2 `build_tag_node` behaviour: stamp the
-                    // outer component's `RsxTagDescriptor` onto the
-                    // rendered root. Preserves `tag_descriptor == Outer`
-                    // semantics consumers rely on (e.g. `<Window>` wraps
-                    // `<WindowView>` — tree root's descriptor remains
-                    // `Window`, not `WindowView`).
-                    if let RsxNode::Element(el) = &mut walked {
-                        // Phase 6b: preserve the rendered root's
-                        // `host_builder` so dispatch still works after
-                        // the outer component's `type_id`/`type_name`
-                        // is stamped for stable identity.
-                        let inner_builder = el.tag_descriptor.and_then(|d| d.host_builder);
-                        std::rc::Rc::make_mut(el).tag_descriptor = Some(RsxTagDescriptor {
-                            type_id,
-                            type_name: identity.invocation_type,
-                            host_builder: inner_builder,
-                        });
-                    }
-                    walked
-                })
+            let render = move |props: NonNull<()>, children: Vec<RsxNode>| {
+                // Safety: `props` was produced by `Box::into_raw(Box::new(T::Props))`
+                // during Component construction (or cloned from such a box by
+                // `clone_props`), and `vtable.render` is the monomorphized
+                // shim that `Box::from_raw`s it back to the exact same T.
+                // The shim consumes both `props` and `children`.
+                let rendered = unsafe { (vtable.render)(props, children) };
+                let mut walked = unwrap_components(rendered);
+                walked.set_identity(identity);
+                // Mirror pre-P2 `build_tag_node` behaviour: stamp the
+                // outer component's `RsxTagDescriptor` onto the
+                // rendered root. Preserves `tag_descriptor == Outer`
+                // semantics consumers rely on (e.g. `<Window>` wraps
+                // `<WindowView>` — tree root's descriptor remains
+                // `Window`, not `WindowView`).
+                if let RsxNode::Element(el) = &mut walked {
+                    // Phase 6b: preserve the rendered root's
+                    // `host_builder` so dispatch still works after
+                    // the outer component's `type_id`/`type_name`
+                    // is stamped for stable identity.
+                    let inner_builder = el.tag_descriptor.and_then(|d| d.host_builder);
+                    std::rc::Rc::make_mut(el).tag_descriptor = Some(RsxTagDescriptor {
+                        type_id,
+                        type_name: identity.invocation_type,
+                        host_builder: inner_builder,
+                    });
+                }
+                walked
+            };
+            with_component_key(key, || match vtable.props_eq {
+                Some(_) if crate::ui::scoped_rerender_enabled() => {
+                    // Scoped re-render: the memo cache owns these props and
+                    // children; the render (on a miss) works on a clone.
+                    let memo_props = MemoProps {
+                        props,
+                        children,
+                        vtable,
+                    };
+                    crate::ui::render_memoized_component_by_type_id(
+                        type_id,
+                        vtable.type_name,
+                        Box::new(memo_props),
+                        memo_props_eq,
+                        |cached| {
+                            let cached = cached
+                                .downcast_ref::<MemoProps>()
+                                .expect("walker memo entries hold MemoProps");
+                            // Safety: `cached.props` is a live box of the
+                            // vtable's props type, owned by `cached`.
+                            let props = unsafe { (vtable.clone_props)(cached.props) };
+                            render(props, cached.children.clone())
+                        },
+                    )
+                }
+                _ => crate::ui::render_named_component(type_id, vtable.type_name, || {
+                    render(props, children)
+                }),
             })
         }
     }
 }
 
+/// Props and children of a component render, as kept by the memo cache.
+/// Owns the boxed props and frees them through the vtable on drop.
+struct MemoProps {
+    props: NonNull<()>,
+    children: Vec<RsxNode>,
+    vtable: &'static ComponentVTable,
+}
+
+impl Drop for MemoProps {
+    fn drop(&mut self) {
+        // Safety: `props` is the boxed props value handed over by
+        // `into_render_parts`; nothing else frees it.
+        unsafe { (self.vtable.drop_props)(self.props) };
+    }
+}
+
+fn memo_props_eq(a: &dyn std::any::Any, b: &dyn std::any::Any) -> bool {
+    let (Some(a), Some(b)) = (a.downcast_ref::<MemoProps>(), b.downcast_ref::<MemoProps>()) else {
+        return false;
+    };
+    let Some(props_eq) = a.vtable.props_eq else {
+        return false;
+    };
+    // Safety: both boxes hold the props type of the same vtable, which is
+    // the type `props_eq` was monomorphized for.
+    std::ptr::eq(a.vtable, b.vtable)
+        && a.children == b.children
+        && unsafe { props_eq(a.props, b.props) }
+}
+
 impl std::fmt::Debug for ComponentNodeInner {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         f.debug_struct("ComponentNodeInner")
@@ -1047,6 +1387,12 @@ pub fn __rsx_default_inner_option<T: Default>(_: &Option<T>) -> T {
     T::default()
 }
 
+#[doc(hidden)]
+#[inline(always)]
+pub fn __rsx_default_media_style<S: Default>(_: &[crate::style::MediaRule<S>]) -> S {
+    S::default()
+}
+
 #[doc(hidden)]
 #[inline(always)]
 pub fn __rsx_infer_inner_option<T>(_: &Option<T>) -> std::marker::PhantomData<T> {
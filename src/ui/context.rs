@@ -32,6 +32,7 @@ thread_local! {
 /// provider of `T` is currently in scope.
 pub fn use_context<T: Clone + 'static>() -> Option<T> {
     let tid = TypeId::of::<T>();
+    super::state::memo_stack_record_context_read(tid, context_value_addr(tid));
     CONTEXT_STACK.with(|s| {
         s.borrow()
             .get(&tid)
@@ -52,6 +53,18 @@ pub fn use_context_expect<T: Clone + 'static>() -> T {
     })
 }
 
+/// Address of the innermost provided value of `type_id`. Memoized renders
+/// compare it to tell whether the provider in scope still hands out the
+/// value they read.
+pub(crate) fn context_value_addr(type_id: TypeId) -> Option<usize> {
+    CONTEXT_STACK.with(|s| {
+        s.borrow()
+            .get(&type_id)
+            .and_then(|stack| stack.last())
+            .map(|rc| Rc::as_ptr(rc).cast::<()>() as usize)
+    })
+}
+
 /// Push a raw, type-erased value onto `CONTEXT_STACK` for the duration
 /// of `f`, then pop. Walker-ancestry helper: used by `unwrap_components`
 /// when it encounters [`crate::ui::RsxNode::Provider`] so child subtree
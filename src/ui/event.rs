@@ -168,6 +168,13 @@ pub enum EventCommand {
         target_id: NodeId,
         options: ScrollIntoViewOptions,
     },
+    /// Scroll the given scroll container to an absolute offset, clamped
+    /// to its scroll range.
+    ScrollTo {
+        target_id: NodeId,
+        x: f32,
+        y: f32,
+    },
     /// Acquire (`Some(node_id)`) or release (`None`) the keyboard capture.
     /// While captured, all key events route to that node regardless of
     /// focus (used for modal overlays, tooltips absorbing Esc, …).
@@ -188,6 +195,12 @@ pub enum EventCommand {
     /// Ask the runner to read the system clipboard and dispatch the
     /// result as a [`PasteEvent`] to the focused node.
     RequestPaste,
+    /// Post a system notification.
+    Notify(crate::platform::Notification),
+    /// Set or clear the taskbar / dock badge.
+    SetBadge(Option<crate::platform::AppBadge>),
+    /// Play a registered UI sound.
+    PlaySound(SmolStr),
 }
 
 struct EventMetaState {
@@ -518,9 +531,13 @@ impl EventMeta {
         self.state.borrow_mut().cancelable = cancelable;
     }
 
-    /// Tell the dispatcher to skip the platform's default action for this
-    /// event (e.g. suppress a textarea inserting a character, block the
-    /// browser from scrolling on Space). No-op if the event is not
+    /// Tell the dispatcher to skip the built-in default action for this
+    /// event. Handlers run before the defaults, so this overrides them per
+    /// element: a pointer down keeps the scrollbar from starting a drag and
+    /// consuming the click, a key down keeps a `TextArea` from editing (and
+    /// drops the text the key types), a paste keeps it from inserting, and
+    /// a wheel skips built-in scrolling. Built-in keyboard behaviors such as
+    /// focus traversal check it too. No-op if the event is not
     /// [`cancelable`](Self::cancelable).
     pub fn prevent_default(&mut self) {
         let mut state = self.state.borrow_mut();
@@ -699,6 +716,13 @@ impl EventViewport {
         self.push_action(EventCommand::ScrollIntoView { target_id, options });
     }
 
+    /// Scroll `target_id` itself to the offset `(x, y)`, clamped to its
+    /// scroll range. For controls that drive another container's scroll
+    /// position, such as minimaps and synced panes.
+    pub fn scroll_to(&mut self, target_id: NodeId, x: f32, y: f32) {
+        self.push_action(EventCommand::ScrollTo { target_id, x, y });
+    }
+
     /// Take keyboard capture for `node_id`. All subsequent key events
     /// route to this node regardless of focus, until
     /// [`Self::release_keyboard_capture`] or another `acquire` replaces it.
@@ -716,6 +740,27 @@ impl EventViewport {
         self.push_action(EventCommand::Window(command));
     }
 
+    /// Close the host window. For custom close buttons on frameless windows.
+    pub fn close_window(&mut self) {
+        self.window_command(crate::platform::WindowCommand::Close);
+    }
+
+    /// Minimize the host window.
+    pub fn minimize_window(&mut self) {
+        self.window_command(crate::platform::WindowCommand::Minimize);
+    }
+
+    /// Maximize the host window, or restore it when already maximized.
+    pub fn toggle_maximize_window(&mut self) {
+        self.window_command(crate::platform::WindowCommand::ToggleMaximize);
+    }
+
+    /// Move the host window with the pointer from a press handler, for drag
+    /// areas that cannot be expressed with `app_region: drag`.
+    pub fn drag_window(&mut self) {
+        self.window_command(crate::platform::WindowCommand::DragWindow);
+    }
+
     /// Queue an IME control command (enable / disable / cursor rect).
     pub fn ime_command(&mut self, command: crate::platform::ImeCommand) {
         self.push_action(EventCommand::Ime(command));
@@ -743,6 +788,43 @@ impl EventViewport {
         self.push_action(EventCommand::RequestPaste);
     }
 
+    /// Post a system notification with `actions` as its buttons. The
+    /// returned id matches the one the host reports responses for.
+    pub fn notify(
+        &mut self,
+        title: impl Into<String>,
+        body: impl Into<String>,
+        actions: Vec<crate::platform::NotificationAction>,
+    ) -> crate::platform::NotificationId {
+        let notification = actions.into_iter().fold(
+            crate::platform::Notification::new(title, body),
+            crate::platform::Notification::action,
+        );
+        self.post_notification(notification)
+    }
+
+    /// Post a fully configured notification (icon, click / dismiss
+    /// callbacks).
+    pub fn post_notification(
+        &mut self,
+        notification: crate::platform::Notification,
+    ) -> crate::platform::NotificationId {
+        let id = notification.id;
+        self.push_action(EventCommand::Notify(notification));
+        id
+    }
+
+    /// Set (or clear with `None`) the taskbar / dock badge.
+    pub fn set_badge(&mut self, badge: Option<crate::platform::AppBadge>) {
+        self.push_action(EventCommand::SetBadge(badge));
+    }
+
+    /// Play a sound registered with `Viewport::register_sound`. Unknown
+    /// names and plays while muted are dropped.
+    pub fn play_sound(&mut self, name: impl Into<SmolStr>) {
+        self.push_action(EventCommand::PlaySound(name.into()));
+    }
+
     fn push_action(&mut self, action: EventCommand) {
         self.state
             .borrow_mut()
@@ -915,6 +997,95 @@ pub struct ContextMenuEvent {
     pub pointer: PointerEventData,
 }
 
+/// Fires once the primary button has been held on an element with an
+/// `on_long_press` handler for `duration` without the pointer travelling
+/// beyond the click slop radius. `pointer` carries the press position.
+/// The click that would otherwise follow the release is suppressed.
+/// Non-bubbling.
+#[derive(Debug, Clone)]
+pub struct LongPressEvent {
+    pub meta: EventMeta,
+    pub pointer: PointerEventData,
+    pub duration: crate::time::Duration,
+}
+
+/// Fires after a scroll container's offset changed, whatever moved it —
+/// wheel input, scrollbar drags, or programmatic scrolls. Delivered once per
+/// frame after layout, carrying the settled offset. Non-bubbling.
+#[derive(Debug, Clone)]
+pub struct ScrollEvent {
+    pub meta: EventMeta,
+    pub scroll_x: f32,
+    pub scroll_y: f32,
+    pub max_scroll_x: f32,
+    pub max_scroll_y: f32,
+}
+
+/// Fires after layout when an element's laid-out border-box size changed,
+/// and once for the first layout after the handler is installed (with a
+/// zero previous size). Delivered once per frame with the settled size, so
+/// canvases can reallocate their surfaces and components can switch
+/// layouts on their own width. Non-bubbling.
+#[derive(Debug, Clone)]
+pub struct ResizeEvent {
+    pub meta: EventMeta,
+    pub width: f32,
+    pub height: f32,
+    pub previous_width: f32,
+    pub previous_height: f32,
+}
+
+/// Fires after layout when the share of an element's border box left
+/// uncovered by its scroll-container ancestors and the viewport crosses
+/// `threshold`, and once for the first layout it sees. `ratio` is the
+/// visible fraction of the box; the element counts as `visible` once
+/// `ratio` reaches `threshold` (any overlap at all for a zero threshold),
+/// so lazy images and autoplaying media can start and stop on it.
+/// Non-bubbling.
+#[derive(Debug, Clone)]
+pub struct VisibilityChangeEvent {
+    pub meta: EventMeta,
+    pub visible: bool,
+    pub ratio: f32,
+    pub threshold: f32,
+}
+
+/// Fires when a scroll container comes within `threshold` logical pixels of
+/// the end of its scrollable range (or its content does not fill it). Fires
+/// once per content extent: it re-arms after scrolling back out of the
+/// threshold or when the content grows, so list components can page in more
+/// items from it. Non-bubbling.
+#[derive(Debug, Clone)]
+pub struct ReachEndEvent {
+    pub meta: EventMeta,
+    pub scroll_x: f32,
+    pub scroll_y: f32,
+    pub max_scroll_x: f32,
+    pub max_scroll_y: f32,
+    pub threshold: f32,
+}
+
+/// Fires on a host with a [`TransitionGroup`](crate::transition::TransitionGroup)
+/// once every member track started by its latest staggered wave has
+/// finished or been cancelled. Waves that overlap are reported together.
+/// Non-bubbling.
+#[derive(Debug, Clone)]
+pub struct TransitionGroupEndEvent {
+    pub meta: EventMeta,
+    /// Number of children that started tracks in the settled wave.
+    pub member_count: usize,
+}
+
+/// Fires on an element when one of its style, layout, or position
+/// transition tracks starts, reaches its target, or is cancelled (removed,
+/// or replaced by a retarget). A `border_color` transition reports once per
+/// side. Non-bubbling.
+#[derive(Debug, Clone)]
+pub struct TransitionEvent {
+    pub meta: EventMeta,
+    pub property: crate::style::TransitionProperty,
+}
+
 #[derive(Debug, Clone)]
 pub struct KeyDownEvent {
     pub meta: EventMeta,
@@ -972,7 +1143,7 @@ pub struct TextChangeEvent {
 /// Visual style hint attached to a span of preedit text. Mirrors the
 /// subset of IME underline / highlight semantics used on Windows TSF,
 /// macOS NSTextInputClient, and X11 XIM.
-#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
 pub enum PreeditStyle {
     /// Solid underline — typical default for the whole preedit.
     Underline,
@@ -986,7 +1157,7 @@ pub enum PreeditStyle {
 
 /// One styled run inside the preedit text. Byte offsets are indices into
 /// [`ImePreeditEvent::text`].
-#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
 pub struct PreeditAttribute {
     pub start: usize,
     pub end: usize,
@@ -1365,6 +1536,59 @@ impl<A: 'static, B: 'static> Handler<dyn FnMut(A, B)> {
     }
 }
 
+/// Callback prop for custom components.
+///
+/// Wraps a shared `Fn(T)` so a parent can hand a capturing closure to a
+/// child: `on_change={move |value| count.set(value)}`. Multiple arguments
+/// travel as a tuple. Like [`Handler`], equality is pointer identity, so a
+/// re-render that passes the same handler keeps props equal.
+pub struct EventHandler<T> {
+    handler: Rc<dyn Fn(T)>,
+}
+
+impl<T: 'static> EventHandler<T> {
+    pub fn new<F>(handler: F) -> Self
+    where
+        F: Fn(T) + 'static,
+    {
+        Self {
+            handler: Rc::new(handler),
+        }
+    }
+
+    pub fn call(&self, value: T) {
+        (self.handler)(value);
+    }
+}
+
+impl<T> Clone for EventHandler<T> {
+    fn clone(&self) -> Self {
+        Self {
+            handler: self.handler.clone(),
+        }
+    }
+}
+
+impl<T> PartialEq for EventHandler<T> {
+    fn eq(&self, other: &Self) -> bool {
+        Rc::ptr_eq(&self.handler, &other.handler)
+    }
+}
+
+impl<T> fmt::Debug for EventHandler<T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("EventHandler")
+            .field("handler", &Rc::as_ptr(&self.handler))
+            .finish()
+    }
+}
+
+impl<T> From<Rc<dyn Fn(T)>> for EventHandler<T> {
+    fn from(handler: Rc<dyn Fn(T)>) -> Self {
+        Self { handler }
+    }
+}
+
 pub type OnPointerDown = Handler<dyn FnMut(&mut PointerDownEvent)>;
 pub type OnPointerUp = Handler<dyn FnMut(&mut PointerUpEvent)>;
 pub type OnPointerMove = Handler<dyn FnMut(&mut PointerMoveEvent)>;
@@ -1372,7 +1596,14 @@ pub type OnPointerEnter = Handler<dyn FnMut(&mut PointerEnterEvent)>;
 pub type OnPointerLeave = Handler<dyn FnMut(&mut PointerLeaveEvent)>;
 pub type OnClick = Handler<dyn FnMut(&mut ClickEvent)>;
 pub type OnContextMenu = Handler<dyn FnMut(&mut ContextMenuEvent)>;
+pub type OnLongPress = Handler<dyn FnMut(&mut LongPressEvent)>;
 pub type OnWheel = Handler<dyn FnMut(&mut WheelEvent)>;
+pub type OnScroll = Handler<dyn FnMut(&mut ScrollEvent)>;
+pub type OnReachEnd = Handler<dyn FnMut(&mut ReachEndEvent)>;
+pub type OnResize = Handler<dyn FnMut(&mut ResizeEvent)>;
+pub type OnVisibilityChange = Handler<dyn FnMut(&mut VisibilityChangeEvent)>;
+pub type OnTransitionGroupEnd = Handler<dyn FnMut(&mut TransitionGroupEndEvent)>;
+pub type OnTransition = Handler<dyn FnMut(&mut TransitionEvent)>;
 pub type OnKeyDown = Handler<dyn FnMut(&mut KeyDownEvent)>;
 pub type OnKeyUp = Handler<dyn FnMut(&mut KeyUpEvent)>;
 pub type OnFocus = Handler<dyn FnMut(&mut FocusEvent)>;
@@ -1399,7 +1630,14 @@ pub type PointerEnterHandlerProp = OnPointerEnter;
 pub type PointerLeaveHandlerProp = OnPointerLeave;
 pub type ClickHandlerProp = OnClick;
 pub type ContextMenuHandlerProp = OnContextMenu;
+pub type LongPressHandlerProp = OnLongPress;
 pub type WheelHandlerProp = OnWheel;
+pub type ScrollHandlerProp = OnScroll;
+pub type ReachEndHandlerProp = OnReachEnd;
+pub type ResizeHandlerProp = OnResize;
+pub type VisibilityChangeHandlerProp = OnVisibilityChange;
+pub type TransitionGroupEndHandlerProp = OnTransitionGroupEnd;
+pub type TransitionHandlerProp = OnTransition;
 pub type KeyDownHandlerProp = OnKeyDown;
 pub type KeyUpHandlerProp = OnKeyUp;
 pub type FocusHandlerProp = OnFocus;
@@ -1433,35 +1671,76 @@ pub trait IntoEventHandlerProp<T> {
 ///
 /// Implemented automatically by `impl_handler_prop!` for every
 /// `Option<HandlerProp>`. Used by `rsx!` to anchor closure argument inference
-/// without the macro knowing any event names: the macro passes the field
-/// reference to `__rsx_ev_to_handler`, which constrains the closure arg type
-/// via `F: FnMut(&mut O::Event)` and converts straight into `H` via `From<F>`
-/// — one generic call instead of two.
+/// without the macro knowing any event names: the macro calls
+/// `__rsx_closure` on the field, whose `F: FnMut(&mut Self::Event)` bound
+/// constrains the closure arg type, and converts straight into `H` via
+/// `From<F>` — one generic call instead of two. Callback props resolve the
+/// same method through [`__RsxCallbackField`] instead.
 #[doc(hidden)]
 pub trait __RsxHandlerField {
     type Event;
+
+    #[inline]
+    fn __rsx_closure<F, H>(&self, f: F) -> H
+    where
+        F: FnMut(&mut Self::Event) + 'static,
+        H: From<F>,
+    {
+        H::from(f)
+    }
+
+    #[inline]
+    fn __rsx_no_arg_closure<F, H>(&self, f: F) -> H
+    where
+        F: FnMut() + 'static,
+        H: From<NoArgHandler<F>>,
+    {
+        H::from(NoArgHandler(f))
+    }
 }
 
+/// [`__RsxHandlerField`] for callback props: `Option<EventHandler<T>>` and
+/// `Option<Rc<dyn Fn(T)>>`. Closures bind to `Fn(Self::Arg)` here.
 #[doc(hidden)]
-#[inline]
-pub fn __rsx_ev_to_handler<O, F, H>(_field: &O, f: F) -> H
-where
-    O: __RsxHandlerField,
-    F: FnMut(&mut O::Event) + 'static,
-    H: From<F>,
-{
-    H::from(f)
+pub trait __RsxCallbackField {
+    type Arg: 'static;
+    type Callback;
+
+    fn __rsx_callback(callback: Rc<dyn Fn(Self::Arg)>) -> Self::Callback;
+
+    #[inline]
+    fn __rsx_closure<F>(&self, f: F) -> Self::Callback
+    where
+        F: Fn(Self::Arg) + 'static,
+    {
+        Self::__rsx_callback(Rc::new(f))
+    }
+
+    #[inline]
+    fn __rsx_no_arg_closure<F>(&self, f: F) -> Self::Callback
+    where
+        F: Fn() + 'static,
+    {
+        Self::__rsx_callback(Rc::new(move |_: Self::Arg| f()))
+    }
 }
 
-#[doc(hidden)]
-#[inline]
-pub fn __rsx_ev_no_arg_to_handler<O, F, H>(_field: &O, f: F) -> H
-where
-    O: __RsxHandlerField,
-    F: FnMut() + 'static,
-    H: From<NoArgHandler<F>>,
-{
-    H::from(NoArgHandler(f))
+impl<T: 'static> __RsxCallbackField for Option<EventHandler<T>> {
+    type Arg = T;
+    type Callback = EventHandler<T>;
+
+    fn __rsx_callback(callback: Rc<dyn Fn(T)>) -> EventHandler<T> {
+        EventHandler::from(callback)
+    }
+}
+
+impl<T: 'static> __RsxCallbackField for Option<Rc<dyn Fn(T)>> {
+    type Arg = T;
+    type Callback = Rc<dyn Fn(T)>;
+
+    fn __rsx_callback(callback: Rc<dyn Fn(T)>) -> Rc<dyn Fn(T)> {
+        callback
+    }
 }
 
 #[doc(hidden)]
@@ -1569,7 +1848,14 @@ impl_handler_prop!(PointerEnterHandlerProp, PointerEnterEvent);
 impl_handler_prop!(PointerLeaveHandlerProp, PointerLeaveEvent);
 impl_handler_prop!(ClickHandlerProp, ClickEvent);
 impl_handler_prop!(ContextMenuHandlerProp, ContextMenuEvent);
+impl_handler_prop!(LongPressHandlerProp, LongPressEvent);
 impl_handler_prop!(WheelHandlerProp, WheelEvent);
+impl_handler_prop!(ScrollHandlerProp, ScrollEvent);
+impl_handler_prop!(ReachEndHandlerProp, ReachEndEvent);
+impl_handler_prop!(ResizeHandlerProp, ResizeEvent);
+impl_handler_prop!(VisibilityChangeHandlerProp, VisibilityChangeEvent);
+impl_handler_prop!(TransitionGroupEndHandlerProp, TransitionGroupEndEvent);
+impl_handler_prop!(TransitionHandlerProp, TransitionEvent);
 impl_handler_prop!(KeyDownHandlerProp, KeyDownEvent);
 impl_handler_prop!(KeyUpHandlerProp, KeyUpEvent);
 impl_handler_prop!(FocusHandlerProp, FocusEvent);
@@ -1620,7 +1906,30 @@ impl_into_event_handler_prop!(
     ContextMenuEvent,
     into_context_menu_handler
 );
+impl_into_event_handler_prop!(
+    LongPressHandlerProp,
+    LongPressEvent,
+    into_long_press_handler
+);
 impl_into_event_handler_prop!(WheelHandlerProp, WheelEvent, into_wheel_handler);
+impl_into_event_handler_prop!(ScrollHandlerProp, ScrollEvent, into_scroll_handler);
+impl_into_event_handler_prop!(ReachEndHandlerProp, ReachEndEvent, into_reach_end_handler);
+impl_into_event_handler_prop!(ResizeHandlerProp, ResizeEvent, into_resize_handler);
+impl_into_event_handler_prop!(
+    VisibilityChangeHandlerProp,
+    VisibilityChangeEvent,
+    into_visibility_change_handler
+);
+impl_into_event_handler_prop!(
+    TransitionGroupEndHandlerProp,
+    TransitionGroupEndEvent,
+    into_transition_group_end_handler
+);
+impl_into_event_handler_prop!(
+    TransitionHandlerProp,
+    TransitionEvent,
+    into_transition_handler
+);
 impl_into_event_handler_prop!(KeyDownHandlerProp, KeyDownEvent, into_key_down_handler);
 impl_into_event_handler_prop!(KeyUpHandlerProp, KeyUpEvent, into_key_up_handler);
 impl_into_event_handler_prop!(FocusHandlerProp, FocusEvent, into_focus_handler);
@@ -1721,6 +2030,13 @@ where
     ContextMenuHandlerProp::new(handler)
 }
 
+pub fn on_long_press<F>(handler: F) -> LongPressHandlerProp
+where
+    F: FnMut(&mut LongPressEvent) + 'static,
+{
+    LongPressHandlerProp::new(handler)
+}
+
 pub fn on_wheel<F>(handler: F) -> WheelHandlerProp
 where
     F: FnMut(&mut WheelEvent) + 'static,
@@ -1728,6 +2044,48 @@ where
     WheelHandlerProp::new(handler)
 }
 
+pub fn on_scroll<F>(handler: F) -> ScrollHandlerProp
+where
+    F: FnMut(&mut ScrollEvent) + 'static,
+{
+    ScrollHandlerProp::new(handler)
+}
+
+pub fn on_reach_end<F>(handler: F) -> ReachEndHandlerProp
+where
+    F: FnMut(&mut ReachEndEvent) + 'static,
+{
+    ReachEndHandlerProp::new(handler)
+}
+
+pub fn on_resize<F>(handler: F) -> ResizeHandlerProp
+where
+    F: FnMut(&mut ResizeEvent) + 'static,
+{
+    ResizeHandlerProp::new(handler)
+}
+
+pub fn on_visibility_change<F>(handler: F) -> VisibilityChangeHandlerProp
+where
+    F: FnMut(&mut VisibilityChangeEvent) + 'static,
+{
+    VisibilityChangeHandlerProp::new(handler)
+}
+
+pub fn on_transition_group_end<F>(handler: F) -> TransitionGroupEndHandlerProp
+where
+    F: FnMut(&mut TransitionGroupEndEvent) + 'static,
+{
+    TransitionGroupEndHandlerProp::new(handler)
+}
+
+pub fn on_transition<F>(handler: F) -> TransitionHandlerProp
+where
+    F: FnMut(&mut TransitionEvent) + 'static,
+{
+    TransitionHandlerProp::new(handler)
+}
+
 pub fn on_key_down<F>(handler: F) -> KeyDownHandlerProp
 where
     F: FnMut(&mut KeyDownEvent) + 'static,
@@ -2000,6 +2358,34 @@ mod tests {
         ));
         assert!(matches!(actions[3], EventCommand::RequestPaste));
     }
+
+    #[test]
+    fn window_chrome_helpers_queue_window_commands() {
+        use crate::platform::WindowCommand;
+        let mut meta = new_meta();
+        let mut vp = meta.viewport();
+        vp.close_window();
+        vp.minimize_window();
+        vp.toggle_maximize_window();
+        vp.drag_window();
+        let commands: Vec<_> = meta
+            .take_viewport_listener_actions()
+            .into_iter()
+            .map(|action| match action {
+                EventCommand::Window(command) => command,
+                other => panic!("unexpected action {other:?}"),
+            })
+            .collect();
+        assert_eq!(
+            commands,
+            vec![
+                WindowCommand::Close,
+                WindowCommand::Minimize,
+                WindowCommand::ToggleMaximize,
+                WindowCommand::DragWindow,
+            ]
+        );
+    }
 }
 
 pub fn on_change<F>(handler: F) -> TextChangeHandlerProp
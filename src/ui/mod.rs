@@ -1,9 +1,11 @@
 //! Public UI authoring APIs for RSX components, events, state, and rendering.
 
+mod breakpoints;
 pub(crate) mod component;
 mod context;
 mod event;
 mod node_id;
+mod profiler;
 mod provider;
 mod reconciler;
 mod render_backend;
@@ -12,10 +14,14 @@ mod runtime;
 mod state;
 mod use_viewport;
 
+pub use breakpoints::{Breakpoint, Breakpoints, use_breakpoint};
 pub use component::*;
 pub use context::{provide_context_node, use_context, use_context_expect, with_pushed_context_raw};
 pub use event::*;
-pub use node_id::{EventTarget, NodeId, Rect};
+pub use node_id::{AriaRole, CoordinateSpace, EventTarget, NodeId, Rect};
+pub use profiler::{
+    ComponentInfo, ComponentRender, ReconcilerObserver, RenderReason, set_reconciler_observer,
+};
 pub use provider::{Provider, ProviderProps};
 pub use reconciler::*;
 pub use render_backend::*;
@@ -52,6 +52,20 @@ impl Rect {
     }
 }
 
+/// Coordinate space of a point or rect, relative to one node.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub enum CoordinateSpace {
+    /// The viewport, as in `PointerEventData::viewport_x` / `viewport_y`.
+    Viewport,
+    /// The space the node's parent lays its children out in: origin at
+    /// the parent's top-left, shifted by the parent's scroll offset. The
+    /// viewport for root nodes.
+    Parent,
+    /// The node's own border box, origin at its top-left, as in
+    /// `PointerEventData::local_x` / `local_y`.
+    Local,
+}
+
 /// Observable per-node state a handler may want to read on any target in
 /// the tree. Read-only mirror of the engine's own flags — flipping fields
 /// here does nothing; go through the normal `EventCommand` / viewport APIs
@@ -65,9 +79,10 @@ pub struct NodeState {
     pub visible: bool,
 }
 
-/// ARIA role skeleton. Placeholder — no prop binding yet, so every live
-/// accessor returns `None`. Wired up when the a11y layer lands.
-#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+/// ARIA role of a host node, set with the `role` prop on `Element`. Like
+/// the `aria_*` props it only records semantics: nothing is announced until
+/// an accessibility backend reads it.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
 pub enum AriaRole {
     Button,
     Link,
@@ -91,6 +106,79 @@ pub enum AriaRole {
     Region,
 }
 
+impl AriaRole {
+    const ALL: [Self; 20] = [
+        Self::Button,
+        Self::Link,
+        Self::TextBox,
+        Self::Checkbox,
+        Self::Radio,
+        Self::Slider,
+        Self::Switch,
+        Self::Menu,
+        Self::MenuItem,
+        Self::Tab,
+        Self::TabList,
+        Self::TabPanel,
+        Self::Dialog,
+        Self::Tooltip,
+        Self::Image,
+        Self::Heading,
+        Self::List,
+        Self::ListItem,
+        Self::Group,
+        Self::Region,
+    ];
+
+    /// WAI-ARIA token for the role (`"button"`, `"menuitem"`, ...).
+    pub const fn as_str(self) -> &'static str {
+        match self {
+            Self::Button => "button",
+            Self::Link => "link",
+            Self::TextBox => "textbox",
+            Self::Checkbox => "checkbox",
+            Self::Radio => "radio",
+            Self::Slider => "slider",
+            Self::Switch => "switch",
+            Self::Menu => "menu",
+            Self::MenuItem => "menuitem",
+            Self::Tab => "tab",
+            Self::TabList => "tablist",
+            Self::TabPanel => "tabpanel",
+            Self::Dialog => "dialog",
+            Self::Tooltip => "tooltip",
+            Self::Image => "img",
+            Self::Heading => "heading",
+            Self::List => "list",
+            Self::ListItem => "listitem",
+            Self::Group => "group",
+            Self::Region => "region",
+        }
+    }
+
+    /// Inverse of [`Self::as_str`].
+    pub fn from_token(token: &str) -> Option<Self> {
+        Self::ALL.into_iter().find(|role| role.as_str() == token)
+    }
+}
+
+impl crate::ui::IntoPropValue for AriaRole {
+    fn into_prop_value(self) -> crate::ui::PropValue {
+        crate::ui::PropValue::String(self.as_str().to_string())
+    }
+}
+
+impl crate::ui::FromPropValue for AriaRole {
+    fn from_prop_value(value: crate::ui::PropValue) -> Result<Self, String> {
+        match value {
+            crate::ui::PropValue::String(token) => {
+                Self::from_token(&token).ok_or_else(|| format!("unknown ARIA role `{token}`"))
+            }
+            _ => Err("expected AriaRole value".to_string()),
+        }
+    }
+}
+
 /// Stable view of a node as an event target.
 ///
 /// Carries id + bounds eagerly (hot-path reads stay cheap) plus an optional
@@ -169,6 +257,18 @@ impl<'a> EventTarget<'a> {
         }
     }
 
+    /// Direct children in tree order. Empty when no viewport is attached.
+    pub fn children(&self) -> Vec<EventTarget<'a>> {
+        let Some(vp) = self.viewport else {
+            return Vec::new();
+        };
+        vp.node_arena()
+            .children_of(self.id)
+            .into_iter()
+            .map(|key| target_from_viewport(vp, key))
+            .collect()
+    }
+
     /// First ancestor (inclusive of `self`) for which `pred` returns `true`.
     pub fn closest<F>(&self, mut pred: F) -> Option<EventTarget<'a>>
     where
@@ -277,10 +377,12 @@ impl<'a> EventTarget<'a> {
         })
     }
 
-    /// ARIA role placeholder. Always `None` until the a11y layer lands.
-    #[inline]
+    /// The node's `role` prop. Read back from the last committed RSX
+    /// tree, so this walks it; `None` for synthetic / detached targets.
     pub fn role(&self) -> Option<AriaRole> {
-        None
+        let vp = self.viewport?;
+        vp.query_one(&crate::view::ElementQuery::new().node(self.id))?
+            .role()
     }
 
     /// Read-only snapshot of the node's hover / focus / press / disabled
@@ -301,6 +403,18 @@ impl<'a> EventTarget<'a> {
         self.state().disabled
     }
 
+    /// Stack `style` over the node's `style` prop without re-rendering.
+    /// Same as [`crate::view::ElementHandle::set_style`].
+    pub fn set_style(&self, style: crate::style::Style) {
+        self.update_style(move |current| current.extend(style));
+    }
+
+    /// Edit the node's `style` prop in place without re-rendering. Same
+    /// as [`crate::view::ElementHandle::update_style`].
+    pub fn update_style(&self, update: impl FnOnce(&mut crate::style::Style) + 'static) {
+        crate::view::style_patch::queue_style_update(self.id, update);
+    }
+
     /// 2D affine transform applied to the node (scale / rotation / skew),
     /// in the form `[a, b, c, d, e, f]` = `[[a, c, e], [b, d, f], [0, 0, 1]]`.
     /// `None` when no non-identity transform is recorded. Placeholder —
@@ -310,6 +424,29 @@ impl<'a> EventTarget<'a> {
         None
     }
 
+    /// Convert `point` between coordinate spaces relative to this node.
+    /// `None` for synthetic / detached targets.
+    pub fn convert_point(
+        &self,
+        point: (f32, f32),
+        from: CoordinateSpace,
+        to: CoordinateSpace,
+    ) -> Option<(f32, f32)> {
+        self.viewport?.convert_point(self.id, point, from, to)
+    }
+
+    /// Convert `rect` between coordinate spaces relative to this node; the
+    /// result bounds the converted corners. `None` for synthetic /
+    /// detached targets.
+    pub fn convert_rect(
+        &self,
+        rect: Rect,
+        from: CoordinateSpace,
+        to: CoordinateSpace,
+    ) -> Option<Rect> {
+        self.viewport?.convert_rect(self.id, rect, from, to)
+    }
+
     /// Bounds in host-window (screen) coordinates. Currently aliases
     /// `bounds` — window offset plumbing lands when the host-window API
     /// exposes it. Always returns a finite rect (never `None`).
@@ -0,0 +1,171 @@
+//! Reconciler profiling hook.
+//!
+//! A [`ReconcilerObserver`] registered with [`set_reconciler_observer`] is
+//! told about every component the walker renders, skips, mounts and
+//! unmounts, which is enough to drive a flamegraph-style devtools panel.
+//! With no observer registered the walker takes no timestamps.
+
+use std::cell::RefCell;
+use std::rc::Rc;
+
+use rustc_hash::{FxHashMap, FxHashSet};
+
+use crate::time::{Duration, Instant};
+
+/// Receives component lifecycle events from the component walker.
+///
+/// Events arrive synchronously on the building thread. A render is
+/// reported once it returns, so children are reported before their
+/// parent; [`ComponentInfo::depth`] restores the nesting.
+pub trait ReconcilerObserver {
+    /// A component's render function ran.
+    fn component_rendered(&self, _render: &ComponentRender) {}
+
+    /// A component reused its previous output without rendering (see
+    /// `set_scoped_rerender`). Components below it are not reported.
+    fn component_skipped(&self, _component: &ComponentInfo) {}
+
+    /// A component rendered for the first time. Reported just before its
+    /// `component_rendered` event.
+    fn component_mounted(&self, _component: &ComponentInfo) {}
+
+    /// A component that rendered in an earlier build is gone from the tree.
+    fn component_unmounted(&self, _component: &ComponentInfo) {}
+
+    /// The outermost build finished, after every other event it caused.
+    fn build_finished(&self, _duration: Duration) {}
+}
+
+/// A component instance as seen by a [`ReconcilerObserver`].
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct ComponentInfo {
+    /// The component's name as written in `rsx!`.
+    pub name: &'static str,
+    /// Identifies the instance across builds for as long as it stays
+    /// mounted.
+    pub id: u64,
+    /// Number of component ancestors.
+    pub depth: usize,
+}
+
+/// One component render.
+#[derive(Clone, Debug)]
+pub struct ComponentRender {
+    pub component: ComponentInfo,
+    pub reason: RenderReason,
+    /// Wall time of the render, including every component rendered
+    /// below it.
+    pub duration: Duration,
+}
+
+/// Why a component rendered instead of reusing its previous output.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub enum RenderReason {
+    /// First render of this instance.
+    Mount,
+    /// A `use_state` slot owned by the component changed.
+    StateChanged,
+    /// Its props or children changed.
+    PropsChanged,
+    /// A component below it has changed state.
+    DescendantChanged,
+    /// A `State`, `Binding` or context value it read changed.
+    DependencyChanged,
+    /// There was no previous output to compare against: the component
+    /// is not memoized, scoped re-render is off, or an ownerless state
+    /// change cleared every cached render.
+    Unmemoized,
+}
+
+thread_local! {
+    static OBSERVER: RefCell<Option<Rc<dyn ReconcilerObserver>>> = const { RefCell::new(None) };
+    static MOUNTED: RefCell<FxHashMap<u64, ComponentInfo>> = RefCell::new(FxHashMap::default());
+    static BUILD_STARTED: RefCell<Option<Instant>> = const { RefCell::new(None) };
+}
+
+/// Register `observer` for builds on this thread, replacing any previous
+/// one. `None` unregisters it. Components already on screen are reported
+/// as mounted when they next render.
+pub fn set_reconciler_observer(observer: Option<Rc<dyn ReconcilerObserver>>) {
+    if observer.is_none() {
+        MOUNTED.with(|mounted| mounted.borrow_mut().clear());
+    }
+    OBSERVER.with(|slot| *slot.borrow_mut() = observer);
+}
+
+fn current_observer() -> Option<Rc<dyn ReconcilerObserver>> {
+    OBSERVER.with(|slot| slot.borrow().clone())
+}
+
+pub(crate) fn observer_registered() -> bool {
+    OBSERVER.with(|slot| slot.borrow().is_some())
+}
+
+pub(crate) fn note_build_started() {
+    if observer_registered() {
+        BUILD_STARTED.with(|started| *started.borrow_mut() = Some(Instant::now()));
+    }
+}
+
+/// Start timing a render. Returns `None` when no observer is registered.
+pub(crate) fn render_started() -> Option<Instant> {
+    observer_registered().then(Instant::now)
+}
+
+pub(crate) fn note_rendered(component: ComponentInfo, reason: RenderReason, started: Instant) {
+    let Some(observer) = current_observer() else {
+        return;
+    };
+    let duration = started.elapsed();
+    let first_render = MOUNTED.with(|mounted| {
+        mounted
+            .borrow_mut()
+            .insert(component.id, component.clone())
+            .is_none()
+    });
+    let reason = if first_render {
+        observer.component_mounted(&component);
+        RenderReason::Mount
+    } else {
+        reason
+    };
+    observer.component_rendered(&ComponentRender {
+        component,
+        reason,
+        duration,
+    });
+}
+
+pub(crate) fn note_skipped(component: ComponentInfo) {
+    if let Some(observer) = current_observer() {
+        observer.component_skipped(&component);
+    }
+}
+
+/// Close an outermost build. `live` holds the id of every component still
+/// in the tree when the build swept unmounted state, and is `None` when it
+/// did not (so nothing unmounted).
+pub(crate) fn note_build_finished(live: Option<&FxHashSet<u64>>) {
+    let Some(observer) = current_observer() else {
+        return;
+    };
+    if let Some(live) = live {
+        let unmounted = MOUNTED.with(|mounted| {
+            let mut mounted = mounted.borrow_mut();
+            let gone = mounted
+                .keys()
+                .filter(|id| !live.contains(id))
+                .copied()
+                .collect::<Vec<_>>();
+            gone.into_iter()
+                .filter_map(|id| mounted.remove(&id))
+                .collect::<Vec<_>>()
+        });
+        for component in &unmounted {
+            observer.component_unmounted(component);
+        }
+    }
+    if let Some(started) = BUILD_STARTED.with(|started| started.borrow_mut().take()) {
+        observer.build_finished(started.elapsed());
+    }
+}
@@ -661,3 +661,6 @@ mod bailout_tests {
         assert!(matches!(patches[0], Patch::SetText { .. }));
     }
 }
+
+#[cfg(test)]
+mod move_tests;
@@ -0,0 +1,75 @@
+use super::*;
+use crate::ui::RsxKey;
+
+fn keyed(id: u64, text: &str) -> RsxNode {
+    RsxNode::text(text).with_key(RsxKey::Local(id))
+}
+
+fn list(items: &[(u64, &str)]) -> RsxNode {
+    RsxNode::fragment(items.iter().map(|&(id, text)| keyed(id, text)).collect())
+}
+
+#[test]
+fn lis_mask_keeps_the_longest_ordered_run() {
+    assert_eq!(lis_stable_mask(&[0, 1, 2]), [true, true, true]);
+    assert_eq!(lis_stable_mask(&[2, 0, 1]), [false, true, true]);
+    assert_eq!(
+        lis_stable_mask(&[4, 0, 1, 2, 3]),
+        [false, true, true, true, true]
+    );
+    assert_eq!(
+        lis_stable_mask(&[3, 2, 1, 0])
+            .iter()
+            .filter(|&&m| m)
+            .count(),
+        1
+    );
+    assert!(lis_stable_mask(&[]).is_empty());
+}
+
+#[test]
+fn moving_one_keyed_child_emits_a_single_move() {
+    let old = list(&[(0, "a"), (1, "b"), (2, "c"), (3, "d"), (4, "e")]);
+    let new = list(&[(4, "e"), (0, "a"), (1, "b"), (2, "c"), (3, "d")]);
+
+    assert_eq!(
+        reconcile(Some(&old), &new),
+        [Patch::MoveChild {
+            parent_path: vec![],
+            from: 4,
+            to: 0,
+        }]
+    );
+}
+
+#[test]
+fn reversed_keyed_children_move_all_but_one() {
+    let old = list(&[(0, "a"), (1, "b"), (2, "c"), (3, "d")]);
+    let new = list(&[(3, "d"), (2, "c"), (1, "b"), (0, "a")]);
+
+    let patches = reconcile(Some(&old), &new);
+    assert_eq!(patches.len(), 3, "{patches:?}");
+    assert!(
+        patches
+            .iter()
+            .all(|patch| matches!(patch, Patch::MoveChild { .. }))
+    );
+}
+
+#[test]
+fn moved_child_is_patched_in_place_not_rebuilt() {
+    let old = list(&[(0, "a"), (1, "b"), (2, "c")]);
+    let new = list(&[(2, "c!"), (0, "a"), (1, "b")]);
+
+    let patches = reconcile(Some(&old), &new);
+    assert!(patches.contains(&Patch::SetText {
+        path: vec![2],
+        text: "c!".to_string(),
+    }));
+    assert!(patches.contains(&Patch::MoveChild {
+        parent_path: vec![],
+        from: 2,
+        to: 0,
+    }));
+    assert_eq!(patches.len(), 2, "{patches:?}");
+}
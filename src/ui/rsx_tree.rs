@@ -9,9 +9,11 @@ use crate::ui::{
     CutHandlerProp, DragEndHandlerProp, DragLeaveHandlerProp, DragOverHandlerProp,
     DragStartHandlerProp, DropHandlerProp, FocusHandlerProp, ImeCommitHandlerProp,
     ImeDisabledHandlerProp, ImeEnabledHandlerProp, KeyDownHandlerProp, KeyUpHandlerProp,
-    PasteHandlerProp, PointerDownHandlerProp, PointerEnterHandlerProp, PointerLeaveHandlerProp,
-    PointerMoveHandlerProp, PointerUpHandlerProp, TextAreaFocusHandlerProp,
-    TextAreaRenderHandlerProp, TextChangeHandlerProp, WheelHandlerProp,
+    LongPressHandlerProp, PasteHandlerProp, PointerDownHandlerProp, PointerEnterHandlerProp,
+    PointerLeaveHandlerProp, PointerMoveHandlerProp, PointerUpHandlerProp, ReachEndHandlerProp,
+    ResizeHandlerProp, ScrollHandlerProp, TextAreaFocusHandlerProp, TextAreaRenderHandlerProp,
+    TextChangeHandlerProp, TransitionGroupEndHandlerProp, TransitionHandlerProp,
+    VisibilityChangeHandlerProp, WheelHandlerProp,
 };
 use std::any::{Any, TypeId};
 use std::fmt;
@@ -381,6 +383,14 @@ impl RsxNode {
         }))
     }
 
+    /// A fragment that reconciles as one unit under `key`, so a multi-root
+    /// list item keeps its identity when its siblings are reordered. The key
+    /// is classified like the `key` prop: a [`GlobalKey`] stays global, any
+    /// other value becomes a local key.
+    pub fn keyed_fragment<K: Hash + Any>(key: K, children: Vec<RsxNode>) -> Self {
+        Self::fragment(children).with_key(crate::ui::classify_component_key(&key))
+    }
+
     pub fn identity(&self) -> &RsxNodeIdentity {
         match self {
             Self::Element(node) => &node.identity,
@@ -500,6 +510,28 @@ impl IntoRsxNode for Vec<RsxNode> {
     }
 }
 
+/// `items.iter().map(|item| (item.id, rsx! { ... }))` becomes a fragment whose
+/// children carry their keys.
+impl<I, F, K> IntoRsxNode for std::iter::Map<I, F>
+where
+    I: Iterator,
+    F: FnMut(I::Item) -> (K, RsxNode),
+    K: Hash + Any,
+{
+    fn into_rsx_node(self) -> RsxNode {
+        RsxNode::fragment(keyed_children(self))
+    }
+}
+
+/// Attaches each key to its node, classified like the `key` prop.
+pub(crate) fn keyed_children<K: Hash + Any>(
+    items: impl Iterator<Item = (K, RsxNode)>,
+) -> Vec<RsxNode> {
+    items
+        .map(|(key, node)| node.with_key(crate::ui::classify_component_key(&key)))
+        .collect()
+}
+
 #[derive(Clone, Debug, PartialEq)]
 pub enum PropValue {
     Bool(bool),
@@ -514,7 +546,14 @@ pub enum PropValue {
     OnPointerLeave(PointerLeaveHandlerProp),
     OnClick(ClickHandlerProp),
     OnContextMenu(ContextMenuHandlerProp),
+    OnLongPress(LongPressHandlerProp),
     OnWheel(WheelHandlerProp),
+    OnScroll(ScrollHandlerProp),
+    OnReachEnd(ReachEndHandlerProp),
+    OnResize(ResizeHandlerProp),
+    OnVisibilityChange(VisibilityChangeHandlerProp),
+    OnTransitionGroupEnd(TransitionGroupEndHandlerProp),
+    OnTransition(TransitionHandlerProp),
     OnKeyDown(KeyDownHandlerProp),
     OnKeyUp(KeyUpHandlerProp),
     OnFocus(FocusHandlerProp),
@@ -641,11 +680,47 @@ impl From<ContextMenuHandlerProp> for PropValue {
     }
 }
 
+impl From<LongPressHandlerProp> for PropValue {
+    fn from(value: LongPressHandlerProp) -> Self {
+        PropValue::OnLongPress(value)
+    }
+}
+
 impl From<WheelHandlerProp> for PropValue {
     fn from(value: WheelHandlerProp) -> Self {
         PropValue::OnWheel(value)
     }
 }
+impl From<ScrollHandlerProp> for PropValue {
+    fn from(value: ScrollHandlerProp) -> Self {
+        PropValue::OnScroll(value)
+    }
+}
+impl From<ReachEndHandlerProp> for PropValue {
+    fn from(value: ReachEndHandlerProp) -> Self {
+        PropValue::OnReachEnd(value)
+    }
+}
+impl From<ResizeHandlerProp> for PropValue {
+    fn from(value: ResizeHandlerProp) -> Self {
+        PropValue::OnResize(value)
+    }
+}
+impl From<VisibilityChangeHandlerProp> for PropValue {
+    fn from(value: VisibilityChangeHandlerProp) -> Self {
+        PropValue::OnVisibilityChange(value)
+    }
+}
+impl From<TransitionGroupEndHandlerProp> for PropValue {
+    fn from(value: TransitionGroupEndHandlerProp) -> Self {
+        PropValue::OnTransitionGroupEnd(value)
+    }
+}
+impl From<TransitionHandlerProp> for PropValue {
+    fn from(value: TransitionHandlerProp) -> Self {
+        PropValue::OnTransition(value)
+    }
+}
 
 impl From<KeyDownHandlerProp> for PropValue {
     fn from(value: KeyDownHandlerProp) -> Self {
@@ -853,11 +928,47 @@ impl IntoPropValue for ContextMenuHandlerProp {
     }
 }
 
+impl IntoPropValue for LongPressHandlerProp {
+    fn into_prop_value(self) -> PropValue {
+        PropValue::OnLongPress(self)
+    }
+}
+
 impl IntoPropValue for WheelHandlerProp {
     fn into_prop_value(self) -> PropValue {
         PropValue::OnWheel(self)
     }
 }
+impl IntoPropValue for ScrollHandlerProp {
+    fn into_prop_value(self) -> PropValue {
+        PropValue::OnScroll(self)
+    }
+}
+impl IntoPropValue for ReachEndHandlerProp {
+    fn into_prop_value(self) -> PropValue {
+        PropValue::OnReachEnd(self)
+    }
+}
+impl IntoPropValue for ResizeHandlerProp {
+    fn into_prop_value(self) -> PropValue {
+        PropValue::OnResize(self)
+    }
+}
+impl IntoPropValue for VisibilityChangeHandlerProp {
+    fn into_prop_value(self) -> PropValue {
+        PropValue::OnVisibilityChange(self)
+    }
+}
+impl IntoPropValue for TransitionGroupEndHandlerProp {
+    fn into_prop_value(self) -> PropValue {
+        PropValue::OnTransitionGroupEnd(self)
+    }
+}
+impl IntoPropValue for TransitionHandlerProp {
+    fn into_prop_value(self) -> PropValue {
+        PropValue::OnTransition(self)
+    }
+}
 
 impl IntoPropValue for KeyDownHandlerProp {
     fn into_prop_value(self) -> PropValue {
@@ -1132,6 +1243,21 @@ impl_from_prop_value_event!(DragEndHandlerProp, OnDragEnd, "drag end");
 impl_from_prop_value_event!(CopyHandlerProp, OnCopy, "copy");
 impl_from_prop_value_event!(CutHandlerProp, OnCut, "cut");
 impl_from_prop_value_event!(PasteHandlerProp, OnPaste, "paste");
+impl_from_prop_value_event!(ScrollHandlerProp, OnScroll, "scroll");
+impl_from_prop_value_event!(ReachEndHandlerProp, OnReachEnd, "reach end");
+impl_from_prop_value_event!(ResizeHandlerProp, OnResize, "resize");
+impl_from_prop_value_event!(
+    VisibilityChangeHandlerProp,
+    OnVisibilityChange,
+    "visibility change"
+);
+impl_from_prop_value_event!(LongPressHandlerProp, OnLongPress, "long press");
+impl_from_prop_value_event!(
+    TransitionGroupEndHandlerProp,
+    OnTransitionGroupEnd,
+    "transition group end"
+);
+impl_from_prop_value_event!(TransitionHandlerProp, OnTransition, "transition");
 
 impl FromPropValue for TextAreaFocusHandlerProp {
     fn from_prop_value(value: PropValue) -> Result<Self, String> {
@@ -1578,3 +1704,6 @@ pub fn as_usize(value: &PropValue, key: &str) -> Result<Option<usize>, String> {
         _ => Err(format!("prop `{key}` expects numeric value")),
     }
 }
+
+#[cfg(test)]
+mod tests;
@@ -0,0 +1,603 @@
+use super::*;
+use crate::ui::{EventHandler, RsxComponent, RsxTag, component, props, rsx};
+use crate::view::{Element as HostElement, Text as HostText};
+use std::cell::{Cell, RefCell};
+use std::rc::Rc;
+
+enum Status {
+    Loading,
+    Ready(u32),
+    Failed,
+}
+
+/// Text content of every text node under `node`, in document order.
+fn texts(node: &RsxNode) -> Vec<String> {
+    match node {
+        RsxNode::Text(text) => vec![text.content.clone()],
+        _ => node
+            .children()
+            .unwrap_or_default()
+            .iter()
+            .flat_map(texts)
+            .collect(),
+    }
+}
+
+fn status_view(status: &Status) -> RsxNode {
+    rsx! {
+        <HostElement>
+            {let label = "status";}
+            {label}:
+            {match status {
+                Status::Loading => <HostText>loading</HostText>,
+                Status::Ready(0) => "empty",
+                Status::Ready(count) => <HostText>{format!("{count} items")}</HostText>
+                Status::Failed => (),
+            }}
+        </HostElement>
+    }
+}
+
+#[test]
+fn match_children_take_markup_and_expression_arms() {
+    assert_eq!(
+        texts(&status_view(&Status::Loading)),
+        ["status", ":", "loading"]
+    );
+    assert_eq!(
+        texts(&status_view(&Status::Ready(0))),
+        ["status", ":", "empty"]
+    );
+    assert_eq!(
+        texts(&status_view(&Status::Ready(3))),
+        ["status", ":", "3 items"]
+    );
+    assert_eq!(texts(&status_view(&Status::Failed)), ["status", ":"]);
+}
+
+#[test]
+fn let_bindings_scope_over_later_siblings_and_the_root() {
+    let items = ["a", "b"];
+    let node = rsx! {
+        {let count = items.len();}
+        {let heading = format!("{count} items");}
+        <HostElement>
+            {heading.clone()}
+            {let first = items[0];}
+            <HostText>{first}</HostText>
+        </HostElement>
+    };
+    let RsxNode::Element(element) = &node else {
+        panic!("a single root node stays unwrapped");
+    };
+    assert_eq!(element.children.len(), 2);
+    assert_eq!(texts(&node), ["2 items", "a"]);
+}
+
+#[test]
+fn format_shorthand_children_render_formatted_text() {
+    let done = 2;
+    let total = 5;
+    let node = rsx! {
+        <HostElement>
+            <HostText>{"{done} of {}", total}</HostText>
+            <HostText>{=total * 10}</HostText>
+        </HostElement>
+    };
+    assert_eq!(texts(&node), ["2 of 5", "50"]);
+}
+
+#[component]
+fn Stepper(
+    on_step: impl Fn(i32),
+    on_reset: Option<EventHandler<()>>,
+    on_label: Option<Rc<dyn Fn(String)>>,
+) -> RsxNode {
+    on_step(2);
+    on_step(3);
+    if let Some(on_reset) = on_reset {
+        on_reset.call(());
+    }
+    if let Some(on_label) = on_label {
+        on_label("stepper".to_string());
+    }
+    rsx! { <HostText>stepper</HostText> }
+}
+
+#[test]
+fn callback_props_accept_capturing_closures() {
+    let total = Rc::new(Cell::new(0));
+    let resets = Rc::new(Cell::new(0));
+    let labels = Rc::new(RefCell::new(Vec::new()));
+    let (step_total, reset_count, seen) = (total.clone(), resets.clone(), labels.clone());
+    let node = rsx! {
+        <Stepper
+            on_step={move |by| step_total.set(step_total.get() + by)}
+            on_reset={move || reset_count.set(reset_count.get() + 1)}
+            on_label={move |label| seen.borrow_mut().push(label)}
+        />
+    };
+    assert_eq!(texts(&node), ["stepper"]);
+    assert_eq!(total.get(), 5);
+    assert_eq!(resets.get(), 1);
+    assert_eq!(*labels.borrow(), ["stepper"]);
+}
+
+#[test]
+fn event_handler_equality_follows_the_shared_closure() {
+    let handler = EventHandler::new(|_: u8| {});
+    assert_eq!(handler, handler.clone());
+    assert_ne!(handler, EventHandler::new(|_: u8| {}));
+}
+
+#[component]
+fn Card(
+    header: Vec<RsxNode>,
+    footer: Option<Vec<RsxNode>>,
+    children: Option<Vec<RsxNode>>,
+) -> RsxNode {
+    let body = children.unwrap_or_else(|| vec![RsxNode::text("(empty)")]);
+    rsx! {
+        <HostElement>
+            <HostElement>{header}</HostElement>
+            <HostElement>{body}</HostElement>
+            {footer.map(|footer| rsx! { <HostElement>{footer}</HostElement> })}
+        </HostElement>
+    }
+}
+
+#[test]
+fn named_slots_fill_component_props() {
+    let node = rsx! {
+        <Card>
+            <slot:header><HostText>Title</HostText></slot:header>
+            <HostText>Body</HostText>
+            <slot:footer>
+                <HostText>OK</HostText>
+                <HostText>Cancel</HostText>
+            </slot:footer>
+        </Card>
+    };
+    assert_eq!(texts(&node), ["Title", "Body", "OK", "Cancel"]);
+}
+
+#[test]
+fn slots_and_children_are_optional() {
+    let node = rsx! { <Card /> };
+    assert_eq!(texts(&node), ["(empty)"]);
+
+    let node = rsx! {
+        <Card>
+            <slot:header>Only a header</slot:header>
+        </Card>
+    };
+    assert_eq!(texts(&node), ["Only a header", "(empty)"]);
+}
+
+#[component]
+fn Labels<T: Clone + ToString + 'static>(items: Vec<T>, children: Vec<RsxNode>) -> RsxNode {
+    let labels = items
+        .iter()
+        .map(|item| rsx! { <HostText>{item.to_string()}</HostText> })
+        .collect::<Vec<_>>();
+    rsx! {
+        <HostElement>
+            {labels}
+            {children}
+        </HostElement>
+    }
+}
+
+#[test]
+fn generic_components_take_type_arguments_on_the_tag() {
+    let node = rsx! {
+        <HostElement>
+            <Labels<u32> items={vec![1, 2]} />
+            <Labels::<&'static str> items={vec!["a"]}>
+                <HostText>!</HostText>
+            </Labels>
+            <Labels<Box<str>> items={vec!["b".into()]}></Labels<Box<str>>>
+        </HostElement>
+    };
+    assert_eq!(texts(&node), ["1", "2", "a", "!", "b"]);
+}
+
+#[test]
+fn style_string_literals_compile_to_typed_values() {
+    let node = rsx! {
+        <HostElement style={{
+            width: "50%",
+            min_height: "0",
+            border_radius: "8px",
+            font_size: "1.5em",
+            hover: { max_width: "10vw" },
+        }}>
+            <HostText>styled</HostText>
+        </HostElement>
+    };
+    assert_eq!(texts(&node), ["styled"]);
+}
+
+#[component]
+fn Badge(label: String, muted: Option<bool>) -> RsxNode {
+    let text = if muted.unwrap_or(false) {
+        format!("({label})")
+    } else {
+        label
+    };
+    rsx! { <HostText>{text}</HostText> }
+}
+
+#[test]
+fn shorthand_and_punned_props_expand_to_full_props() {
+    let label = "new".to_string();
+    let node = rsx! {
+        <HostElement>
+            <Badge {label} muted />
+            <Badge label="plain" />
+        </HostElement>
+    };
+    assert_eq!(texts(&node), ["(new)", "plain"]);
+}
+
+struct Meter;
+
+#[derive(Clone)]
+#[props]
+struct MeterProps {
+    #[prop(into)]
+    label: String,
+    #[prop(range(0.0..=1.0))]
+    value: f64,
+    #[prop(default = 4.0)]
+    size: f32,
+    #[prop(strip_option, into)]
+    unit: Option<String>,
+}
+
+impl RsxComponent<MeterProps> for Meter {
+    fn render(props: MeterProps, _children: Vec<RsxNode>) -> RsxNode {
+        let unit = props.unit.unwrap_or_default();
+        let text = format!("{} {}{unit} @{}", props.label, props.value, props.size);
+        rsx! { <HostText>{text}</HostText> }
+    }
+}
+
+#[component]
+impl RsxTag for Meter {
+    type Props = __MeterPropsInit;
+    type StrictProps = MeterProps;
+    const ACCEPTS_CHILDREN: bool = false;
+
+    fn into_strict(props: Self::Props) -> Self::StrictProps {
+        props.into()
+    }
+
+    fn create_node(
+        props: Self::StrictProps,
+        children: Vec<RsxNode>,
+        _key: Option<RsxKey>,
+    ) -> RsxNode {
+        <Self as RsxComponent<MeterProps>>::render(props, children)
+    }
+}
+
+#[test]
+fn prop_attributes_apply_defaults_and_conversions() {
+    let label = std::borrow::Cow::Borrowed("cpu");
+    let node = rsx! {
+        <HostElement>
+            <Meter {label} value={0.5} />
+            <Meter label={'m'} value={1.0} size={2.0} unit={'%'} />
+        </HostElement>
+    };
+    assert_eq!(texts(&node), ["cpu 0.5 @4", "m 1% @2"]);
+}
+
+// Literal values are rejected at compile time; this covers runtime values.
+#[test]
+#[cfg_attr(
+    debug_assertions,
+    should_panic(expected = "prop `value` on <MeterProps> must be in `0.0 ..= 1.0`, got 1.5")
+)]
+fn prop_range_rejects_out_of_range_values() {
+    let value = 1.5;
+    let node = rsx! {
+        <HostElement>
+            <Meter label="disk" {value} />
+        </HostElement>
+    };
+    assert_eq!(texts(&node), ["disk 1 @4"]);
+}
+
+crate::styled! {
+    /// Meter with a percent unit and a larger default size.
+    PercentMeter = Meter {
+        size: 8.0,
+        unit: '%',
+    }
+}
+
+crate::styled! {
+    Caption = HostText { style: { color: crate::style::Color::hex("#444") } }
+}
+
+#[test]
+fn styled_tags_preset_props_the_call_site_leaves_unset() {
+    let node = rsx! {
+        <HostElement>
+            <PercentMeter label="cpu" value={0.5} />
+            <PercentMeter label="gpu" value={1.0} size={2.0} unit="x" />
+            <Caption>note</Caption>
+        </HostElement>
+    };
+    assert_eq!(texts(&node), ["cpu 0.5% @8", "gpu 1x @2", "note"]);
+    let caption = &node.children().expect("children")[2];
+    let RsxNode::Element(caption) = caption else {
+        panic!("styled host tag renders an element, got {caption:?}");
+    };
+    assert!(caption.props.iter().any(|(name, _)| *name == "style"));
+}
+
+#[test]
+fn styled_style_presets_stack_under_the_call_site_style() {
+    use crate::style::{Color, ColorLike, FontSize, ParsedValue, PropertyId};
+    use crate::ui::FromPropValue;
+    use crate::view::TextStylePropSchema;
+
+    let node = rsx! {
+        <Caption style={{ font_size: FontSize::px(20.0) }}>note</Caption>
+    };
+    let RsxNode::Element(caption) = &node else {
+        panic!("styled host tag renders an element, got {node:?}");
+    };
+    let style = caption
+        .props
+        .iter()
+        .find(|(name, _)| *name == "style")
+        .and_then(|(_, value)| TextStylePropSchema::from_prop_value(value.clone()).ok())
+        .expect("style prop")
+        .to_style();
+    assert_eq!(
+        style.get(PropertyId::Color),
+        Some(&ParsedValue::Color(Color::hex("#444").to_style_color()))
+    );
+    assert_eq!(
+        style.get(PropertyId::FontSize),
+        Some(&ParsedValue::FontSize(FontSize::px(20.0)))
+    );
+}
+
+struct Row {
+    id: u32,
+    name: &'static str,
+}
+
+fn row_list(rows: &[Row]) -> RsxNode {
+    rsx! {
+        <HostElement>
+            {rows.iter().map(|row| (row.id, rsx! {
+                <HostText>{row.name}</HostText>
+                <HostText>{"#{}", row.id}</HostText>
+            }))}
+        </HostElement>
+    }
+}
+
+#[test]
+fn keyed_iterator_children_carry_their_keys() {
+    let node = row_list(&[Row { id: 7, name: "a" }, Row { id: 9, name: "b" }]);
+    let keys = node
+        .children()
+        .unwrap()
+        .iter()
+        .map(|child| child.identity().key)
+        .collect::<Vec<_>>();
+    assert_eq!(
+        keys,
+        [
+            Some(RsxKey::Local(crate::ui::component_key_token(&7u32))),
+            Some(RsxKey::Local(crate::ui::component_key_token(&9u32))),
+        ]
+    );
+    assert_eq!(texts(&node), ["a", "#7", "b", "#9"]);
+
+    let fragment = RsxNode::keyed_fragment(GlobalKey::from("row"), vec![RsxNode::text("x")]);
+    assert_eq!(
+        fragment.identity().key,
+        Some(RsxKey::Global(GlobalKey::from("row")))
+    );
+}
+
+#[test]
+fn reordered_keyed_fragments_move_instead_of_rebuilding() {
+    let old = row_list(&[Row { id: 1, name: "a" }, Row { id: 2, name: "b" }]);
+    let new = row_list(&[Row { id: 2, name: "b" }, Row { id: 1, name: "a" }]);
+    let patches = crate::ui::reconcile(Some(&old), &new);
+    assert!(
+        patches
+            .iter()
+            .all(|patch| matches!(patch, crate::ui::Patch::MoveChild { .. })),
+        "{patches:?}"
+    );
+    assert_eq!(patches.len(), 1);
+}
+
+thread_local! {
+    static TALLY_RENDERS: RefCell<Vec<String>> = const { RefCell::new(Vec::new()) };
+    static TALLY_STATES: RefCell<Vec<crate::ui::State<u32>>> = const { RefCell::new(Vec::new()) };
+}
+
+#[component]
+fn Tally(name: String) -> RsxNode {
+    TALLY_RENDERS.with(|renders| renders.borrow_mut().push(name.clone()));
+    let count = crate::ui::use_state(|| 0u32);
+    TALLY_STATES.with(|states| states.borrow_mut().push(count.clone()));
+    rsx! { <HostText>{format!("{name}: {}", count.get())}</HostText> }
+}
+
+#[component]
+fn TallyReadout(count: crate::ui::Binding<u32>) -> RsxNode {
+    TALLY_RENDERS.with(|renders| renders.borrow_mut().push("readout".to_string()));
+    rsx! { <HostText>{=count.get()}</HostText> }
+}
+
+#[component]
+fn TallyOwner() -> RsxNode {
+    let count = crate::ui::use_state(|| 0u32);
+    TALLY_STATES.with(|states| states.borrow_mut().push(count.clone()));
+    rsx! { <HostElement><TallyReadout count={count.binding()} /></HostElement> }
+}
+
+fn tally_board() -> RsxNode {
+    TALLY_RENDERS.with(|renders| renders.borrow_mut().clear());
+    TALLY_STATES.with(|states| states.borrow_mut().clear());
+    rsx! {
+        <HostElement>
+            <Tally name="left" />
+            <Tally name="right" />
+        </HostElement>
+    }
+}
+
+fn tally_renders() -> Vec<String> {
+    TALLY_RENDERS.with(|renders| renders.borrow().clone())
+}
+
+fn tally_state(index: usize) -> crate::ui::State<u32> {
+    TALLY_STATES.with(|states| states.borrow()[index].clone())
+}
+
+#[test]
+fn state_change_rerenders_only_the_owning_component() {
+    let first = tally_board();
+    assert_eq!(tally_renders(), ["left", "right"]);
+
+    tally_state(0).set(1);
+    let _ = crate::ui::take_state_dirty();
+    let second = tally_board();
+    assert_eq!(tally_renders(), ["left"]);
+    assert_eq!(texts(&second), ["left: 1", "right: 0"]);
+
+    let (first, second) = (first.children().unwrap(), second.children().unwrap());
+    assert!(!RsxNode::ptr_eq(&first[0], &second[0]));
+    assert!(
+        RsxNode::ptr_eq(&first[1], &second[1]),
+        "the untouched sibling must keep its rendered node"
+    );
+}
+
+#[test]
+fn components_reading_changed_state_rerender_with_equal_props() {
+    let first = rsx! { <TallyOwner /> };
+    TALLY_STATES.with(|states| states.borrow()[0].set(4));
+    let _ = crate::ui::take_state_dirty();
+    TALLY_RENDERS.with(|renders| renders.borrow_mut().clear());
+    let second = rsx! { <TallyOwner /> };
+    assert_eq!(tally_renders(), ["readout"]);
+    assert_eq!(texts(&first), ["0"]);
+    assert_eq!(texts(&second), ["4"]);
+}
+
+#[test]
+fn disabling_scoped_rerender_renders_every_component() {
+    crate::ui::set_scoped_rerender(false);
+    let _ = tally_board();
+    tally_state(0).set(1);
+    let _ = crate::ui::take_state_dirty();
+    let second = tally_board();
+    crate::ui::set_scoped_rerender(true);
+    assert_eq!(tally_renders(), ["left", "right"]);
+    assert_eq!(texts(&second), ["left: 1", "right: 0"]);
+}
+
+#[derive(Default)]
+struct ProfileLog(RefCell<Vec<String>>);
+
+impl crate::ui::ReconcilerObserver for ProfileLog {
+    fn component_rendered(&self, render: &crate::ui::ComponentRender) {
+        let name = render.component.name;
+        self.0
+            .borrow_mut()
+            .push(format!("render {name} {:?}", render.reason));
+    }
+
+    fn component_skipped(&self, component: &crate::ui::ComponentInfo) {
+        self.0.borrow_mut().push(format!("skip {}", component.name));
+    }
+
+    fn component_mounted(&self, component: &crate::ui::ComponentInfo) {
+        let name = component.name;
+        self.0.borrow_mut().push(format!("mount {name}"));
+    }
+
+    fn component_unmounted(&self, component: &crate::ui::ComponentInfo) {
+        self.0
+            .borrow_mut()
+            .push(format!("unmount {}", component.name));
+    }
+}
+
+#[test]
+fn reconciler_observer_reports_renders_skips_and_unmounts() {
+    let log = std::rc::Rc::new(ProfileLog::default());
+    crate::ui::set_reconciler_observer(Some(log.clone()));
+    let take = || std::mem::take(&mut *log.0.borrow_mut());
+
+    let _ = tally_board();
+    assert_eq!(
+        take(),
+        [
+            "mount Tally",
+            "render Tally Mount",
+            "mount Tally",
+            "render Tally Mount"
+        ]
+    );
+
+    tally_state(1).set(1);
+    let _ = crate::ui::take_state_dirty();
+    let _ = tally_board();
+    assert_eq!(take(), ["skip Tally", "render Tally StateChanged"]);
+
+    let _ = rsx! { <HostElement><Tally name="left" /></HostElement> };
+    assert_eq!(take(), ["skip Tally", "unmount Tally"]);
+
+    crate::ui::set_reconciler_observer(None);
+}
+
+#[component]
+fn WidthLabel() -> RsxNode {
+    TALLY_RENDERS.with(|renders| renders.borrow_mut().push("width".to_string()));
+    let (width, _) = crate::ui::use_viewport_size();
+    let breakpoint = crate::ui::use_breakpoint(&crate::ui::Breakpoints::default());
+    rsx! { <HostText>{format!("{width} {breakpoint:?}")}</HostText> }
+}
+
+fn responsive_board() -> RsxNode {
+    TALLY_RENDERS.with(|renders| renders.borrow_mut().clear());
+    rsx! {
+        <HostElement>
+            <WidthLabel />
+            <Tally name="fixed" />
+        </HostElement>
+    }
+}
+
+#[test]
+fn viewport_resize_rerenders_only_size_readers() {
+    crate::ui::set_viewport_size(480.0, 320.0);
+    let first = responsive_board();
+    assert_eq!(tally_renders(), ["width", "fixed"]);
+    assert_eq!(texts(&first), ["480 Xs", "fixed: 0"]);
+
+    let _ = crate::ui::take_state_dirty();
+    crate::ui::set_viewport_size(800.0, 600.0);
+    assert!(crate::ui::take_state_dirty().needs_rebuild());
+    let second = responsive_board();
+    assert_eq!(tally_renders(), ["width"]);
+    assert_eq!(texts(&second), ["800 Md", "fixed: 0"]);
+
+    crate::ui::set_viewport_size(800.0, 600.0);
+    assert!(!crate::ui::take_state_dirty().needs_rebuild());
+}
@@ -4,6 +4,7 @@
 use rustc_hash::{FxHashMap, FxHashSet};
 
 use crate::time::{Duration, Instant};
+use crate::ui::profiler::{self, ComponentInfo, RenderReason};
 use crate::ui::{
     EventMetaSnapshot, FromPropValue, GlobalKey, IntoPropValue, PointerButtons, PropValue, RsxKey,
     SharedPropValue, ViewportPointerDownEvent, ViewportPointerMoveEvent, ViewportPointerState,
@@ -101,6 +102,7 @@ impl<T: 'static> Binding<T> {
 
 impl<T: Clone + PartialEq + 'static> Binding<T> {
     pub fn get(&self) -> T {
+        memo_stack_record_state_read(&self.prop_payload.owner_component);
         self.cell().borrow().clone()
     }
 
@@ -163,6 +165,7 @@ pub struct State<T: 'static> {
 
 impl<T: Clone + PartialEq + 'static> State<T> {
     pub fn get(&self) -> T {
+        memo_stack_record_state_read(&self.payload.owner_component);
         self.payload.cell.borrow().clone()
     }
 
@@ -237,6 +240,23 @@ impl Hash for ComponentKey {
     }
 }
 
+impl ComponentKey {
+    fn profiler_id(&self) -> u64 {
+        let mut hasher = DefaultHasher::new();
+        self.hash(&mut hasher);
+        hasher.finish()
+    }
+
+    /// Describe this component for the profiler, at the current frame depth.
+    fn profiler_info(&self, name: &'static str) -> ComponentInfo {
+        ComponentInfo {
+            name,
+            id: self.profiler_id(),
+            depth: CONTEXT.with(|context| context.borrow().frames.len()),
+        }
+    }
+}
+
 struct Frame {
     key: ComponentKey,
     path: Vec<usize>,
@@ -271,9 +291,15 @@ struct StateStore {
     /// were registered during that render, so we can keep them alive on a
     /// memo hit without re-entering the render function.
     memo_cache: FxHashMap<ComponentKey, MemoEntry>,
-    /// Components whose own state slots changed since their last render.
-    /// A memo hit for a key in this set is forbidden — we must re-render.
+    /// Components whose own state slots changed since the current build
+    /// started. Moved into `build_dirty_components` when the next outermost
+    /// build begins, so a state write made mid-render survives to the
+    /// following build instead of being swallowed by this one.
     dirty_memo_components: FxHashSet<ComponentKey>,
+    /// Components whose state changed before the current build began. A memo
+    /// hit is forbidden for these keys and for any cached render that
+    /// contains or read from one of them.
+    build_dirty_components: FxHashSet<ComponentKey>,
 }
 
 /// A cached component render. `props` holds a type-erased clone of the last
@@ -282,22 +308,64 @@ struct MemoEntry {
     props: Box<dyn Any>,
     node: crate::ui::RsxNode,
     props_eq: fn(&dyn Any, &dyn Any) -> bool,
-    live_keys: FxHashSet<ComponentKey>,
-    live_global_keys: FxHashSet<GlobalKey>,
-    live_timer_hooks: FxHashSet<TimerHookKey>,
-    live_viewport_pointer_hooks: FxHashSet<ViewportPointerHookKey>,
+    deps: MemoFrame,
+}
+
+impl MemoEntry {
+    /// Why the cached render is no longer valid for the current build, or
+    /// `None` when it still is: no descendant component and no state owner
+    /// it read from changed, and every context value it read still resolves
+    /// to the same allocation.
+    fn stale_reason(&self, build_dirty: &FxHashSet<ComponentKey>) -> Option<RenderReason> {
+        let deps = &self.deps;
+        if !build_dirty.is_empty() {
+            if deps.live_keys.iter().any(|k| build_dirty.contains(k)) {
+                return Some(RenderReason::DescendantChanged);
+            }
+            if deps.state_reads.iter().any(|k| build_dirty.contains(k)) {
+                return Some(RenderReason::DependencyChanged);
+            }
+        }
+        let contexts_unchanged = deps
+            .context_reads
+            .iter()
+            .all(|(type_id, addr)| super::context::context_value_addr(*type_id) == *addr);
+        (!contexts_unchanged).then_some(RenderReason::DependencyChanged)
+    }
 }
 
 /// A scope that captures which keys/hooks were registered during a render
 /// inside a memoized component. Pushed by `render_memoized_component` and
 /// popped once the render returns; the captured sets are stored in the
 /// resulting [`MemoEntry`].
-#[derive(Default)]
+#[derive(Clone, Default)]
 struct MemoFrame {
     live_keys: FxHashSet<ComponentKey>,
     live_global_keys: FxHashSet<GlobalKey>,
     live_timer_hooks: FxHashSet<TimerHookKey>,
+    live_mount_hooks: FxHashSet<MountHookKey>,
     live_viewport_pointer_hooks: FxHashSet<ViewportPointerHookKey>,
+    /// Owners of the `State` / `Binding` cells read during the render.
+    state_reads: FxHashSet<ComponentKey>,
+    /// Context values read during the render, as `(type, allocation)`.
+    context_reads: Vec<(TypeId, Option<usize>)>,
+}
+
+impl MemoFrame {
+    fn absorb(&mut self, other: &MemoFrame) {
+        self.live_keys.extend(other.live_keys.iter().cloned());
+        self.live_global_keys
+            .extend(other.live_global_keys.iter().copied());
+        self.live_timer_hooks
+            .extend(other.live_timer_hooks.iter().cloned());
+        self.live_mount_hooks
+            .extend(other.live_mount_hooks.iter().cloned());
+        self.live_viewport_pointer_hooks
+            .extend(other.live_viewport_pointer_hooks.iter().cloned());
+        self.state_reads.extend(other.state_reads.iter().cloned());
+        self.context_reads
+            .extend(other.context_reads.iter().copied());
+    }
 }
 
 fn memo_props_eq<P: PartialEq + 'static>(a: &dyn Any, b: &dyn Any) -> bool {
@@ -340,6 +408,13 @@ struct TimerEntry {
     callback: Rc<RefCell<dyn FnMut()>>,
 }
 
+type AnimationFrameCallback = Rc<RefCell<dyn FnMut(Duration)>>;
+
+struct AnimationFrameEntry {
+    last_frame: Option<Instant>,
+    callback: AnimationFrameCallback,
+}
+
 #[derive(Clone, Eq)]
 struct MountHookKey {
     component: ComponentKey,
@@ -425,6 +500,9 @@ thread_local! {
     static STATE_DIRTY: Cell<UiDirtyState> = const { Cell::new(UiDirtyState::NONE) };
     static TIMER_STORE: RefCell<FxHashMap<TimerHookKey, TimerEntry>> = RefCell::new(FxHashMap::default());
     static LIVE_TIMER_HOOKS: RefCell<FxHashSet<TimerHookKey>> = RefCell::new(FxHashSet::default());
+    /// `use_animation_frame` callbacks. Keyed like timers and pruned with
+    /// them through `LIVE_TIMER_HOOKS`.
+    static ANIMATION_FRAME_STORE: RefCell<FxHashMap<TimerHookKey, AnimationFrameEntry>> = RefCell::new(FxHashMap::default());
     static MOUNT_STORE: RefCell<FxHashMap<MountHookKey, MountEntry>> = RefCell::new(FxHashMap::default());
     static LIVE_MOUNT_HOOKS: RefCell<FxHashSet<MountHookKey>> = RefCell::new(FxHashSet::default());
     static VIEWPORT_POINTER_DOWN_HOOKS: RefCell<FxHashMap<ViewportPointerHookKey, ViewportPointerDownCallback>> = RefCell::new(FxHashMap::default());
@@ -434,11 +512,18 @@ thread_local! {
     static LIVE_VIEWPORT_POINTER_HOOKS: RefCell<FxHashSet<ViewportPointerHookKey>> = RefCell::new(FxHashSet::default());
     static VIEWPORT_POINTER_STATE: RefCell<ViewportPointerState> = RefCell::new(ViewportPointerState::default());
     static PENDING_MOUNTS: RefCell<Vec<Box<dyn FnOnce()>>> = const { RefCell::new(Vec::new()) };
+    static TIMER_CLOCK: Cell<Option<Instant>> = const { Cell::new(None) };
+    static HIGH_CONTRAST: Cell<bool> = const { Cell::new(false) };
+    static VIEWPORT_SIZE_HOOKS: RefCell<FxHashSet<ViewportPointerHookKey>> = RefCell::new(FxHashSet::default());
+    static VIEWPORT_SIZE: Cell<(f32, f32)> = const { Cell::new((0.0, 0.0)) };
     /// Stack of in-progress memoized-component renders. Every registration of
-    /// a `ComponentKey`, `GlobalKey`, or timer hook while this stack is
-    /// non-empty is also recorded on the innermost frame so it can be
-    /// reattached on a future memo hit.
+    /// a `ComponentKey`, `GlobalKey` or hook, and every state or context read,
+    /// made while this stack is non-empty is also recorded on the innermost
+    /// frame so it can be reattached (or re-validated) on a future memo hit.
     static MEMO_STACK: RefCell<Vec<MemoFrame>> = const { RefCell::new(Vec::new()) };
+    /// Whether the `unwrap_components` walker memoizes components. See
+    /// [`set_scoped_rerender`].
+    static SCOPED_RERENDER: Cell<bool> = const { Cell::new(true) };
 }
 
 fn memo_stack_record_component_key(key: &ComponentKey) {
@@ -477,6 +562,38 @@ fn memo_stack_record_viewport_pointer_hook(key: &ViewportPointerHookKey) {
     });
 }
 
+fn memo_stack_record_mount_hook(key: &MountHookKey) {
+    MEMO_STACK.with(|s| {
+        let mut stack = s.borrow_mut();
+        if let Some(top) = stack.last_mut() {
+            top.live_mount_hooks.insert(key.clone());
+        }
+    });
+}
+
+fn memo_stack_record_state_read(owner: &Option<ComponentKey>) {
+    let Some(owner) = owner else {
+        return;
+    };
+    MEMO_STACK.with(|s| {
+        let mut stack = s.borrow_mut();
+        if let Some(top) = stack.last_mut() {
+            top.state_reads.insert(owner.clone());
+        }
+    });
+}
+
+/// Record a `use_context` read on the innermost memo frame, so a later memo
+/// hit is rejected once the provider in scope hands out a different value.
+pub(crate) fn memo_stack_record_context_read(type_id: TypeId, addr: Option<usize>) {
+    MEMO_STACK.with(|s| {
+        let mut stack = s.borrow_mut();
+        if let Some(top) = stack.last_mut() {
+            top.context_reads.push((type_id, addr));
+        }
+    });
+}
+
 #[derive(Clone)]
 pub struct GlobalState<T: 'static> {
     payload: Rc<BindingPropPayload<T>>,
@@ -571,6 +688,8 @@ pub fn build_scope<R>(f: impl FnOnce() -> R) -> R {
             store.live_global_keys.clear();
             store.active_build_global_keys.clear();
             store.components_rendered_in_build = false;
+            store.build_dirty_components = std::mem::take(&mut store.dirty_memo_components);
+            profiler::note_build_started();
             LIVE_TIMER_HOOKS.with(|hooks| hooks.borrow_mut().clear());
             LIVE_MOUNT_HOOKS.with(|hooks| hooks.borrow_mut().clear());
             LIVE_VIEWPORT_POINTER_HOOKS.with(|hooks| hooks.borrow_mut().clear());
@@ -580,9 +699,23 @@ pub fn build_scope<R>(f: impl FnOnce() -> R) -> R {
 
     let out = f();
 
-    STORE.with(|store| {
+    let finished = STORE.with(|store| {
         let mut store = store.borrow_mut();
         store.build_depth = store.build_depth.saturating_sub(1);
+        if store.build_depth != 0 {
+            return None;
+        }
+        store.build_dirty_components.clear();
+        let report_unmounts = store.components_rendered_in_build && profiler::observer_registered();
+        let live_ids = report_unmounts.then(|| {
+            let live = store.live_keys.iter();
+            live.map(ComponentKey::profiler_id).collect()
+        });
+        Some(live_ids)
+    });
+
+    STORE.with(|store| {
+        let mut store = store.borrow_mut();
         if store.build_depth == 0 && store.components_rendered_in_build {
             let live = store.live_keys.clone();
             let live_global = store.live_global_keys.clone();
@@ -605,6 +738,11 @@ pub fn build_scope<R>(f: impl FnOnce() -> R) -> R {
                     timers.retain(|key, _| live_hooks.contains(key));
                     shrink_map_if_sparse(&mut timers);
                 });
+                ANIMATION_FRAME_STORE.with(|frames| {
+                    let mut frames = frames.borrow_mut();
+                    frames.retain(|key, _| live_hooks.contains(key));
+                    shrink_map_if_sparse(&mut frames);
+                });
             });
             // Prune mount entries for unmounted components first so their
             // cleanups (via MountEntry::Drop) run before the newly queued
@@ -639,11 +777,20 @@ pub fn build_scope<R>(f: impl FnOnce() -> R) -> R {
                     store.retain(|key| live_hooks.contains(key));
                     shrink_set_if_sparse(&mut store);
                 });
+                VIEWPORT_SIZE_HOOKS.with(|store| {
+                    let mut store = store.borrow_mut();
+                    store.retain(|key| live_hooks.contains(key));
+                    shrink_set_if_sparse(&mut store);
+                });
             });
             drain_pending_mounts();
         }
     });
 
+    if let Some(live_ids) = finished {
+        profiler::note_build_finished(live_ids.as_ref());
+    }
+
     out
 }
 
@@ -691,17 +838,10 @@ fn current_rsx_key() -> Option<RsxKey> {
     COMPONENT_KEY_STACK.with(|stack| stack.borrow().last().cloned().flatten())
 }
 
-/// Compute the `ComponentKey` for the next component invocation using the
-/// same path algorithm as [`render_component`]. Advances parent/root cursors
+/// Compute the `ComponentKey` for the next component invocation. Takes a
+/// runtime `TypeId` so a type-erased `ComponentNodeInner` can compute its own
+/// key during the `unwrap_components` traversal. Advances parent/root cursors
 /// as a side effect, so this must be called exactly once per component.
-fn next_component_key<T: 'static>() -> ComponentKey {
-    next_component_key_by_type_id(TypeId::of::<T>())
-}
-
-/// Type-id-driven variant for the React parity walker (P2). Identical path
-/// algorithm to [`next_component_key`] but accepts a runtime `TypeId` so a
-/// type-erased `ComponentNodeInner` can compute its own key during the
-/// `unwrap_components` traversal.
 fn next_component_key_by_type_id(type_id: TypeId) -> ComponentKey {
     const KEYED_PATH_MARKER: usize = usize::MAX;
     const GLOBAL_KEYED_PATH_MARKER: usize = usize::MAX - 1;
@@ -742,7 +882,7 @@ fn next_component_key_by_type_id(type_id: TypeId) -> ComponentKey {
 }
 
 pub fn render_component<T: 'static, R>(f: impl FnOnce() -> R) -> R {
-    render_component_by_type_id(TypeId::of::<T>(), f)
+    render_named_component(TypeId::of::<T>(), std::any::type_name::<T>(), f)
 }
 
 /// Type-id-driven variant of [`render_component`] for the React parity
@@ -750,6 +890,18 @@ pub fn render_component<T: 'static, R>(f: impl FnOnce() -> R) -> R {
 /// `ComponentNodeInner` — the concrete `T` is lost, so the frame / live
 /// keys / context push machinery is parameterized by `TypeId`.
 pub fn render_component_by_type_id<R>(type_id: TypeId, f: impl FnOnce() -> R) -> R {
+    render_named_component(type_id, "<component>", f)
+}
+
+/// [`render_component_by_type_id`] with the name a [`ReconcilerObserver`]
+/// reports the component under.
+///
+/// [`ReconcilerObserver`]: crate::ui::ReconcilerObserver
+pub(crate) fn render_named_component<R>(
+    type_id: TypeId,
+    name: &'static str,
+    f: impl FnOnce() -> R,
+) -> R {
     let key = next_component_key_by_type_id(type_id);
 
     STORE.with(|store| {
@@ -776,11 +928,15 @@ pub fn render_component_by_type_id<R>(type_id: TypeId, f: impl FnOnce() -> R) ->
         });
     });
 
+    let started = profiler::render_started();
     let out = f();
 
     CONTEXT.with(|context| {
         let _ = context.borrow_mut().frames.pop();
     });
+    if let Some(started) = started {
+        profiler::note_rendered(key.profiler_info(name), RenderReason::Unmemoized, started);
+    }
 
     out
 }
@@ -790,10 +946,12 @@ pub fn render_component_by_type_id<R>(type_id: TypeId, f: impl FnOnce() -> R) ->
 /// Semantics (React `memo` equivalent):
 /// 1. Compute the `ComponentKey` just like [`render_component`].
 /// 2. If the component is NOT marked dirty (its own `use_state` slots are
-///    unchanged since the last render) AND the cached props compare equal to
-///    `props`, return a clone of the cached `RsxNode` and replay the set of
-///    descendant component/global keys and timer hooks so the GC in
-///    [`build_scope`] keeps them alive.
+///    unchanged since the last render), no component rendered underneath it
+///    and no state owner it read from is dirty, every context value it read
+///    is unchanged, AND the cached props compare equal to `props`, return a
+///    clone of the cached `RsxNode` and replay the set of descendant
+///    component/global keys and hooks so the GC in [`build_scope`] keeps
+///    them alive.
 /// 3. Otherwise, push a `MemoFrame` and a component `Frame`, invoke `render`,
 ///    capture all keys registered underneath, store the new `MemoEntry` and
 ///    return the rendered node.
@@ -811,7 +969,38 @@ where
     T: 'static,
     P: PartialEq + Clone + 'static,
 {
-    let key = next_component_key::<T>();
+    render_memoized_component_by_type_id(
+        TypeId::of::<T>(),
+        std::any::type_name::<T>(),
+        Box::new(props),
+        memo_props_eq::<P>,
+        |props| {
+            // P2 (React parity): memo cache stores resolved trees (no
+            // `RsxNode::Component` variants). If we cached lazy trees, the
+            // cache hit would `Rc::clone` the Component node, sharing its
+            // `Rc` with the cached copy — the walker later panics on
+            // `Rc::try_unwrap`. Unwrap eagerly inside the memo frame so the
+            // component's render subtree is fully flattened before caching.
+            let props = props
+                .downcast_ref::<P>()
+                .expect("memoized props keep their type");
+            crate::ui::unwrap_components(render(props))
+        },
+    )
+}
+
+/// Type-id-driven variant of [`render_memoized_component`] for the
+/// `unwrap_components` walker. `props` is compared against the cached value
+/// with `props_eq` and moved into the cache on a miss; `render` must return
+/// a fully unwrapped tree. `name` is what a `ReconcilerObserver` reports.
+pub(crate) fn render_memoized_component_by_type_id(
+    type_id: TypeId,
+    name: &'static str,
+    props: Box<dyn Any>,
+    props_eq: fn(&dyn Any, &dyn Any) -> bool,
+    render: impl FnOnce(&dyn Any) -> crate::ui::RsxNode,
+) -> crate::ui::RsxNode {
+    let key = next_component_key_by_type_id(type_id);
     let current_key = current_rsx_key();
 
     // Register this component as live regardless of memo hit / miss — it
@@ -830,71 +1019,63 @@ where
         memo_stack_record_global_key(global_key);
     }
 
-    // Can we take the fast path? Only if: the component is NOT dirty AND the
-    // cached props match the new props.
-    let cached_hit = STORE.with(|store| {
-        let mut store = store.borrow_mut();
-        let was_dirty = store.dirty_memo_components.remove(&key);
-        if was_dirty {
-            return None;
+    // Can we take the fast path? Only if the cached render is still fresh
+    // and the cached props match the new props.
+    let cached = STORE.with(|store| {
+        let store = store.borrow();
+        if store.build_dirty_components.contains(&key) {
+            return Err(RenderReason::StateChanged);
         }
-        let entry = store.memo_cache.get(&key)?;
-        let eq = (entry.props_eq)(&*entry.props, &props as &dyn Any);
-        if !eq {
-            return None;
+        let Some(entry) = store.memo_cache.get(&key) else {
+            return Err(RenderReason::Unmemoized);
+        };
+        if let Some(reason) = entry.stale_reason(&store.build_dirty_components) {
+            return Err(reason);
+        }
+        if !(entry.props_eq)(&*entry.props, &*props) {
+            return Err(RenderReason::PropsChanged);
         }
-        Some((
-            entry.node.clone(),
-            entry.live_keys.clone(),
-            entry.live_global_keys.clone(),
-            entry.live_timer_hooks.clone(),
-            entry.live_viewport_pointer_hooks.clone(),
-        ))
+        Ok((entry.node.clone(), entry.deps.clone()))
     });
 
-    if let Some((node, lk, lgk, lth, lvph)) = cached_hit {
-        // Replay descendants — both into the thread-local live sets that
-        // `build_scope` uses for GC, and into any enclosing memo frame.
-        STORE.with(|store| {
-            let mut store = store.borrow_mut();
-            for k in &lk {
-                store.live_keys.insert(k.clone());
-            }
-            for k in &lgk {
-                store.live_global_keys.insert(*k);
-            }
-        });
-        LIVE_TIMER_HOOKS.with(|hooks| {
-            let mut hooks = hooks.borrow_mut();
-            for k in &lth {
-                hooks.insert(k.clone());
-            }
-        });
-        LIVE_VIEWPORT_POINTER_HOOKS.with(|hooks| {
-            let mut hooks = hooks.borrow_mut();
-            for k in &lvph {
-                hooks.insert(k.clone());
-            }
-        });
-        MEMO_STACK.with(|stack| {
-            let mut stack = stack.borrow_mut();
-            if let Some(top) = stack.last_mut() {
-                for k in &lk {
-                    top.live_keys.insert(k.clone());
-                }
-                for k in &lgk {
-                    top.live_global_keys.insert(*k);
-                }
-                for k in &lth {
-                    top.live_timer_hooks.insert(k.clone());
-                }
-                for k in &lvph {
-                    top.live_viewport_pointer_hooks.insert(k.clone());
+    let reason = match cached {
+        Ok((node, deps)) => {
+            // Replay descendants — both into the thread-local live sets that
+            // `build_scope` uses for GC, and into any enclosing memo frame.
+            STORE.with(|store| {
+                let mut store = store.borrow_mut();
+                store.live_keys.extend(deps.live_keys.iter().cloned());
+                store
+                    .live_global_keys
+                    .extend(deps.live_global_keys.iter().copied());
+            });
+            LIVE_TIMER_HOOKS.with(|hooks| {
+                hooks
+                    .borrow_mut()
+                    .extend(deps.live_timer_hooks.iter().cloned());
+            });
+            LIVE_MOUNT_HOOKS.with(|hooks| {
+                hooks
+                    .borrow_mut()
+                    .extend(deps.live_mount_hooks.iter().cloned());
+            });
+            LIVE_VIEWPORT_POINTER_HOOKS.with(|hooks| {
+                hooks
+                    .borrow_mut()
+                    .extend(deps.live_viewport_pointer_hooks.iter().cloned());
+            });
+            MEMO_STACK.with(|stack| {
+                if let Some(top) = stack.borrow_mut().last_mut() {
+                    top.absorb(&deps);
                 }
+            });
+            if profiler::observer_registered() {
+                profiler::note_skipped(key.profiler_info(name));
             }
-        });
-        return node;
-    }
+            return node;
+        }
+        Err(reason) => reason,
+    };
 
     // Miss — run the render closure under a fresh `MemoFrame` so we can
     // capture every descendant key that gets registered.
@@ -911,17 +1092,15 @@ where
         });
     });
 
-    // P2 (React parity): memo cache stores resolved trees (no
-    // `RsxNode::Component` variants). If we cached lazy trees, the cache
-    // hit would `Rc::clone` the Component node, sharing its `Rc` with the
-    // cached copy — the walker later panics on `Rc::try_unwrap`. Unwrap
-    // eagerly inside the memo frame so the component's render subtree
-    // is fully flattened before caching and returning.
-    let node = crate::ui::unwrap_components(render(&props));
+    let started = profiler::render_started();
+    let node = render(&*props);
 
     CONTEXT.with(|context| {
         let _ = context.borrow_mut().frames.pop();
     });
+    if let Some(started) = started {
+        profiler::note_rendered(key.profiler_info(name), reason, started);
+    }
     let frame = MEMO_STACK
         .with(|stack| stack.borrow_mut().pop())
         .unwrap_or_default();
@@ -929,20 +1108,8 @@ where
     // Propagate the captured descendants into any enclosing memo frame so
     // a memo hit on an outer component keeps our subtree alive too.
     MEMO_STACK.with(|stack| {
-        let mut stack = stack.borrow_mut();
-        if let Some(top) = stack.last_mut() {
-            for k in &frame.live_keys {
-                top.live_keys.insert(k.clone());
-            }
-            for k in &frame.live_global_keys {
-                top.live_global_keys.insert(*k);
-            }
-            for k in &frame.live_timer_hooks {
-                top.live_timer_hooks.insert(k.clone());
-            }
-            for k in &frame.live_viewport_pointer_hooks {
-                top.live_viewport_pointer_hooks.insert(k.clone());
-            }
+        if let Some(top) = stack.borrow_mut().last_mut() {
+            top.absorb(&frame);
         }
     });
 
@@ -950,13 +1117,10 @@ where
         store.borrow_mut().memo_cache.insert(
             key,
             MemoEntry {
-                props: Box::new(props),
+                props,
                 node: node.clone(),
-                props_eq: memo_props_eq::<P>,
-                live_keys: frame.live_keys,
-                live_global_keys: frame.live_global_keys,
-                live_timer_hooks: frame.live_timer_hooks,
-                live_viewport_pointer_hooks: frame.live_viewport_pointer_hooks,
+                props_eq,
+                deps: frame,
             },
         );
     });
@@ -964,6 +1128,31 @@ where
     node
 }
 
+/// Choose whether a state change re-renders only the components it affects
+/// (the default) or every component from the root.
+///
+/// When enabled, the `unwrap_components` walker renders each
+/// `#[component]` through the memo cache: a component re-renders only when
+/// its props or children changed, its own state changed, a component below
+/// it has changed state, or a `State` / `Binding` / context value it read
+/// changed.
+/// Everything else returns its previous output, so the reconciler skips it
+/// and the viewport keeps its element nodes untouched.
+///
+/// Disable it for components that read data the runtime cannot observe,
+/// such as a `RefCell` shared outside of `use_state`.
+pub fn set_scoped_rerender(enabled: bool) {
+    SCOPED_RERENDER.with(|flag| flag.set(enabled));
+    if !enabled {
+        STORE.with(|store| store.borrow_mut().memo_cache.clear());
+    }
+}
+
+/// Whether the walker memoizes components. See [`set_scoped_rerender`].
+pub fn scoped_rerender_enabled() -> bool {
+    SCOPED_RERENDER.with(Cell::get)
+}
+
 pub fn use_state<T: Clone + PartialEq + 'static>(init: impl FnOnce() -> T) -> State<T> {
     use_state_with_dirty_state(init, UiDirtyState::REBUILD)
 }
@@ -1007,16 +1196,13 @@ pub fn use_state_with_dirty_state<T: Clone + PartialEq + 'static>(
     })
 }
 
-fn use_timer<F>(mode: TimerMode, enabled: bool, duration: Duration, callback: F)
-where
-    F: FnMut() + 'static,
-{
+fn next_timer_hook_key(name: &str) -> TimerHookKey {
     let (component, hook_index) = CONTEXT.with(|context| {
         let mut context = context.borrow_mut();
         let frame = context
             .frames
             .last_mut()
-            .expect("timer hooks must be called inside #[component] render");
+            .unwrap_or_else(|| panic!("{name}() must be called inside #[component] render"));
         let index = frame.hook_cursor;
         frame.hook_cursor += 1;
         (frame.key.clone(), index)
@@ -1030,10 +1216,21 @@ where
         hooks.borrow_mut().insert(key.clone());
     });
     memo_stack_record_timer_hook(&key);
+    key
+}
+
+fn use_timer<F>(mode: TimerMode, enabled: bool, duration: Duration, callback: F)
+where
+    F: FnMut() + 'static,
+{
+    let key = next_timer_hook_key(match mode {
+        TimerMode::Timeout => "use_timeout",
+        TimerMode::Interval => "use_interval",
+    });
 
     TIMER_STORE.with(|timers| {
         let mut timers = timers.borrow_mut();
-        let now = Instant::now();
+        let now = timer_now();
         let callback: Rc<RefCell<dyn FnMut()>> = Rc::new(RefCell::new(callback));
         match timers.get_mut(&key) {
             Some(entry) => {
@@ -1077,6 +1274,34 @@ where
     use_timer(TimerMode::Interval, enabled, interval, callback);
 }
 
+/// Run `callback` once per frame, before the app rebuilds and lays out,
+/// with the time since the previous frame (zero on the first). While any
+/// component holds one, hosts keep rendering frames; it is dropped when
+/// the component unmounts. State set from the callback shows up in the
+/// same frame.
+pub fn use_animation_frame<F>(callback: F)
+where
+    F: FnMut(Duration) + 'static,
+{
+    let key = next_timer_hook_key("use_animation_frame");
+    let callback: AnimationFrameCallback = Rc::new(RefCell::new(callback));
+    ANIMATION_FRAME_STORE.with(|frames| {
+        let mut frames = frames.borrow_mut();
+        match frames.get_mut(&key) {
+            Some(entry) => entry.callback = callback,
+            None => {
+                frames.insert(
+                    key,
+                    AnimationFrameEntry {
+                        last_frame: None,
+                        callback,
+                    },
+                );
+            }
+        }
+    });
+}
+
 fn next_viewport_pointer_hook_key(name: &str) -> ViewportPointerHookKey {
     let (component, hook_index) = CONTEXT.with(|context| {
         let mut context = context.borrow_mut();
@@ -1144,6 +1369,47 @@ pub fn use_viewport_pointer_state() -> ViewportPointerState {
     VIEWPORT_POINTER_STATE.with(|state| state.borrow().clone())
 }
 
+/// Logical size of the viewport as `(width, height)`. A resize re-renders
+/// only the components that called this hook (and walks down to them
+/// through their ancestors); the rest of the tree keeps its memoized output.
+pub fn use_viewport_size() -> (f32, f32) {
+    let key = next_viewport_pointer_hook_key("use_viewport_size");
+    VIEWPORT_SIZE_HOOKS.with(|store| {
+        store.borrow_mut().insert(key);
+    });
+    VIEWPORT_SIZE.with(Cell::get)
+}
+
+pub(crate) fn set_viewport_size(width: f32, height: f32) {
+    if VIEWPORT_SIZE.with(|size| size.replace((width, height))) == (width, height) {
+        return;
+    }
+    let readers = VIEWPORT_SIZE_HOOKS.with(|hooks| {
+        hooks
+            .borrow()
+            .iter()
+            .map(|key| key.component.clone())
+            .collect::<FxHashSet<_>>()
+    });
+    for component in readers {
+        notify_state_changed(UiDirtyState::REBUILD, Some(component));
+    }
+}
+
+/// Whether high-contrast mode is in effect: the host's system setting
+/// unless the app pinned a `ContrastPreference`. Components should swap to
+/// stronger colors, drop subtle shadows and mark boundaries with borders
+/// while it is set. Flipping the mode rebuilds the app.
+pub fn use_high_contrast() -> bool {
+    HIGH_CONTRAST.with(Cell::get)
+}
+
+pub(crate) fn set_high_contrast(active: bool) {
+    if HIGH_CONTRAST.with(|slot| slot.replace(active)) != active {
+        notify_state_changed(UiDirtyState::REBUILD, None);
+    }
+}
+
 pub fn use_viewport_pointer_position() -> Option<(f32, f32)> {
     use_viewport_pointer_state().position
 }
@@ -1274,6 +1540,7 @@ where
     LIVE_MOUNT_HOOKS.with(|hooks| {
         hooks.borrow_mut().insert(key.clone());
     });
+    memo_stack_record_mount_hook(&key);
 
     let is_first = MOUNT_STORE.with(|store| {
         let mut store = store.borrow_mut();
@@ -1322,6 +1589,18 @@ fn drain_pending_mounts() {
     }
 }
 
+/// Pin the clock timer hooks schedule their deadlines against. Hosts with
+/// a virtual clock (`HeadlessApp`) set it before every frame so deadlines
+/// line up with the `now` they pass to [`run_due_timers`]; `None` restores
+/// the wall clock.
+pub fn set_timer_clock(now: Option<Instant>) {
+    TIMER_CLOCK.with(|clock| clock.set(now));
+}
+
+fn timer_now() -> Instant {
+    TIMER_CLOCK.with(Cell::get).unwrap_or_else(Instant::now)
+}
+
 pub fn next_timer_deadline() -> Option<Instant> {
     TIMER_STORE.with(|timers| {
         timers
@@ -1358,6 +1637,31 @@ pub fn run_due_timers(now: Instant) {
     }
 }
 
+/// Whether a mounted component registered `use_animation_frame`, so the
+/// host should keep producing frames.
+pub fn has_animation_frame_hooks() -> bool {
+    ANIMATION_FRAME_STORE.with(|frames| !frames.borrow().is_empty())
+}
+
+/// Run every `use_animation_frame` callback for the frame at `now`.
+/// Viewports call this at the start of each frame, before the rebuild.
+pub fn run_animation_frame_hooks(now: Instant) {
+    let mut callbacks: Vec<(AnimationFrameCallback, Duration)> = Vec::new();
+    ANIMATION_FRAME_STORE.with(|frames| {
+        for entry in frames.borrow_mut().values_mut() {
+            let dt = entry
+                .last_frame
+                .map_or(Duration::ZERO, |last| now.saturating_duration_since(last));
+            entry.last_frame = Some(now);
+            callbacks.push((entry.callback.clone(), dt));
+        }
+    });
+
+    for (callback, dt) in callbacks {
+        (callback.borrow_mut())(dt);
+    }
+}
+
 fn global_payload_with_init<T: Clone + PartialEq + 'static>(
     init: impl FnOnce() -> T,
 ) -> Rc<BindingPropPayload<T>> {
@@ -1421,8 +1725,9 @@ pub fn use_global_state<T: Clone + PartialEq + 'static>() -> GlobalState<T> {
 #[cfg(test)]
 mod tests {
     use super::{
-        UiDirtyState, build_scope, next_timer_deadline, render_memoized_component, run_due_timers,
-        take_state_dirty, use_interval, use_mount, use_state, use_timeout, with_component_key,
+        UiDirtyState, build_scope, has_animation_frame_hooks, next_timer_deadline,
+        render_memoized_component, run_animation_frame_hooks, run_due_timers, take_state_dirty,
+        use_animation_frame, use_interval, use_mount, use_state, use_timeout, with_component_key,
     };
     use crate::time::{Duration, Instant};
     use crate::ui::{GlobalKey, RsxKey, RsxNode};
@@ -1624,6 +1929,32 @@ mod tests {
         clear_test_timers();
     }
 
+    #[test]
+    fn use_animation_frame_reports_frame_deltas_until_unmount() {
+        clear_test_timers();
+        let deltas = Rc::new(std::cell::RefCell::new(Vec::new()));
+        let sink = deltas.clone();
+        build_scope(|| {
+            crate::ui::render_component::<u32, _>(|| {
+                use_animation_frame(move |dt| sink.borrow_mut().push(dt));
+            })
+        });
+        assert!(has_animation_frame_hooks());
+
+        let start = Instant::now();
+        run_animation_frame_hooks(start);
+        run_animation_frame_hooks(start + Duration::from_millis(16));
+        assert_eq!(
+            *deltas.borrow(),
+            vec![Duration::ZERO, Duration::from_millis(16)]
+        );
+
+        clear_test_timers();
+        assert!(!has_animation_frame_hooks());
+        run_animation_frame_hooks(start + Duration::from_millis(32));
+        assert_eq!(deltas.borrow().len(), 2);
+    }
+
     #[test]
     fn set_same_value_does_not_mark_dirty() {
         let state = build_scope(|| {
@@ -17,6 +17,7 @@
 //! applies the buffer at the top of each render pass.
 
 use crate::style::{Color, Cursor};
+use smol_str::SmolStr;
 use std::cell::RefCell;
 
 thread_local! {
@@ -26,7 +27,7 @@ thread_local! {
 /// Queued mutation to be applied to the live `Viewport` on the next
 /// render pass. Variants map 1:1 to `ViewportControl` setters so the
 /// dispatch site stays mechanical.
-#[derive(Debug, Clone, Copy, PartialEq)]
+#[derive(Debug, Clone, PartialEq)]
 pub enum ViewportAction {
     SetDebugTraceFps(bool),
     SetDebugTraceRenderTime(bool),
@@ -41,6 +42,7 @@ pub enum ViewportAction {
     SetClearColor(Color),
     SetCursor(Option<Cursor>),
     RequestRedraw,
+    PlaySound(SmolStr),
 }
 
 /// Handle returned by [`use_viewport`]. Methods do not touch the live
@@ -105,6 +107,12 @@ impl ViewportHandle {
     pub fn request_redraw(&self) {
         Self::push(ViewportAction::RequestRedraw);
     }
+
+    /// Play a sound registered with `Viewport::register_sound`. Unknown
+    /// names and plays while muted are dropped.
+    pub fn play_sound(&self, name: impl Into<SmolStr>) {
+        Self::push(ViewportAction::PlaySound(name.into()));
+    }
 }
 
 /// Component-side hook returning a [`ViewportHandle`]. Call inside a
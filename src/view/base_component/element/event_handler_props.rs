@@ -8,7 +8,7 @@
 
 use crate::ui::PropValue;
 
-/// `&'static str` table of the 23 RSX event handler prop names. Used
+/// `&'static str` table of the 32 RSX event handler prop names. Used
 /// by the incremental fiber_work whitelist gate so every `on_*` prop
 /// that the cold path recognises is also committable incrementally.
 pub(crate) const RSX_EVENT_HANDLER_PROPS: &[&str] = &[
@@ -19,6 +19,7 @@ pub(crate) const RSX_EVENT_HANDLER_PROPS: &[&str] = &[
     "on_pointer_leave",
     "on_click",
     "on_context_menu",
+    "on_long_press",
     "on_wheel",
     "on_key_down",
     "on_key_up",
@@ -35,9 +36,17 @@ pub(crate) const RSX_EVENT_HANDLER_PROPS: &[&str] = &[
     "on_copy",
     "on_cut",
     "on_paste",
+    "on_scroll",
+    "on_reach_end",
+    "on_resize",
+    "on_visibility_change",
+    "on_transition_group_end",
+    "on_transition_start",
+    "on_transition_end",
+    "on_transition_cancel",
 ];
 
-/// Try to install one of the 23 RSX event-handler props on `element`.
+/// Try to install one of the 32 RSX event-handler props on `element`.
 /// Returns `Ok(true)` if `key` matched a handler prop; `Ok(false)` if
 /// `key` is not a handler prop; `Err` on `PropValue` decode failure.
 pub(crate) fn try_assign_event_handler_prop(
@@ -74,10 +83,49 @@ pub(crate) fn try_assign_event_handler_prop(
             let handler = as_context_menu_handler(value, key)?;
             element.on_context_menu(move |event, _control| handler.call(event));
         }
+        "on_long_press" => {
+            let handler = as_long_press_handler(value, key)?;
+            let duration = element.long_press_duration();
+            element.on_long_press(duration, move |event, _control| handler.call(event));
+        }
         "on_wheel" => {
             let handler = as_wheel_handler(value, key)?;
             element.on_wheel(move |event, _control| handler.call(event));
         }
+        "on_scroll" => {
+            let handler = as_scroll_handler(value, key)?;
+            element.on_scroll(move |event| handler.call(event));
+        }
+        "on_reach_end" => {
+            let handler = as_reach_end_handler(value, key)?;
+            let threshold = element.reach_end_threshold();
+            element.on_reach_end(threshold, move |event| handler.call(event));
+        }
+        "on_resize" => {
+            let handler = as_resize_handler(value, key)?;
+            element.on_resize(move |event| handler.call(event));
+        }
+        "on_visibility_change" => {
+            let handler = as_visibility_change_handler(value, key)?;
+            let threshold = element.visibility_threshold();
+            element.on_visibility_change(threshold, move |event| handler.call(event));
+        }
+        "on_transition_group_end" => {
+            let handler = as_transition_group_end_handler(value, key)?;
+            element.on_transition_group_end(move |event| handler.call(event));
+        }
+        "on_transition_start" => {
+            let handler = as_transition_handler(value, key)?;
+            element.on_transition_start(move |event| handler.call(event));
+        }
+        "on_transition_end" => {
+            let handler = as_transition_handler(value, key)?;
+            element.on_transition_end(move |event| handler.call(event));
+        }
+        "on_transition_cancel" => {
+            let handler = as_transition_handler(value, key)?;
+            element.on_transition_cancel(move |event| handler.call(event));
+        }
         "on_key_down" => {
             let handler = as_key_down_handler(value, key)?;
             element.on_key_down(move |event, _control| handler.call(event));
@@ -313,3 +361,45 @@ as_event_handler_fn!(
     OnPaste,
     "paste"
 );
+as_event_handler_fn!(
+    as_long_press_handler,
+    crate::ui::LongPressHandlerProp,
+    OnLongPress,
+    "long press"
+);
+as_event_handler_fn!(
+    as_scroll_handler,
+    crate::ui::ScrollHandlerProp,
+    OnScroll,
+    "scroll"
+);
+as_event_handler_fn!(
+    as_reach_end_handler,
+    crate::ui::ReachEndHandlerProp,
+    OnReachEnd,
+    "reach end"
+);
+as_event_handler_fn!(
+    as_resize_handler,
+    crate::ui::ResizeHandlerProp,
+    OnResize,
+    "resize"
+);
+as_event_handler_fn!(
+    as_visibility_change_handler,
+    crate::ui::VisibilityChangeHandlerProp,
+    OnVisibilityChange,
+    "visibility change"
+);
+as_event_handler_fn!(
+    as_transition_group_end_handler,
+    crate::ui::TransitionGroupEndHandlerProp,
+    OnTransitionGroupEnd,
+    "transition group end"
+);
+as_event_handler_fn!(
+    as_transition_handler,
+    crate::ui::TransitionHandlerProp,
+    OnTransition,
+    "transition"
+);
@@ -6,17 +6,21 @@ impl EventTarget for Element {
         _arena: &crate::view::node_arena::NodeArena,
         self_key: crate::view::node_arena::NodeKey,
     ) {
-        if self.handle_scrollbar_pointer_down(event, control, self_key) {
-            event.meta.suppress_focus_change();
-            event.meta.stop_propagation();
-            return;
-        }
         if let Some(h) = &mut self.event_handlers {
             for handler in &mut h.pointer_down {
                 handler(event, control);
                 if event.meta.immediate_propagation_stopped() { break; }
             }
         }
+        // Scrollbar presses are the default action: handlers run first and
+        // can keep the press (and the click that follows) for themselves.
+        self.scrollbar_default_prevented = event.meta.default_prevented();
+        if !self.scrollbar_default_prevented
+            && self.handle_scrollbar_pointer_down(event, control, self_key)
+        {
+            event.meta.suppress_focus_change();
+            event.meta.stop_propagation();
+        }
     }
 
     fn dispatch_pointer_up(
@@ -26,7 +30,9 @@ impl EventTarget for Element {
         _arena: &crate::view::node_arena::NodeArena,
         self_key: crate::view::node_arena::NodeKey,
     ) {
-        if self.handle_scrollbar_pointer_up(event, control, self_key) {
+        if self.scrollbar_drag.is_some()
+            && self.handle_scrollbar_pointer_up(event, control, self_key)
+        {
             event.meta.stop_propagation();
             return;
         }
@@ -36,6 +42,12 @@ impl EventTarget for Element {
                 if event.meta.immediate_propagation_stopped() { break; }
             }
         }
+        if !event.meta.default_prevented()
+            && !self.scrollbar_default_prevented
+            && self.handle_scrollbar_pointer_up(event, control, self_key)
+        {
+            event.meta.stop_propagation();
+        }
     }
 
     fn dispatch_pointer_move(
@@ -45,7 +57,7 @@ impl EventTarget for Element {
         _arena: &crate::view::node_arena::NodeArena,
         _self_key: crate::view::node_arena::NodeKey,
     ) {
-        if self.handle_scrollbar_pointer_move(event, control) {
+        if self.scrollbar_drag.is_some() && self.handle_scrollbar_pointer_move(event, control) {
             event.meta.stop_propagation();
             return;
         }
@@ -55,6 +67,9 @@ impl EventTarget for Element {
                 if event.meta.immediate_propagation_stopped() { break; }
             }
         }
+        if !event.meta.default_prevented() {
+            self.handle_scrollbar_pointer_move(event, control);
+        }
     }
 
     fn dispatch_click(
@@ -64,7 +79,9 @@ impl EventTarget for Element {
         _arena: &crate::view::node_arena::NodeArena,
         _self_key: crate::view::node_arena::NodeKey,
     ) {
-        if self.is_scrollbar_hit(event.pointer.local_x, event.pointer.local_y) {
+        if !self.scrollbar_default_prevented
+            && self.is_scrollbar_hit(event.pointer.local_x, event.pointer.local_y)
+        {
             event.meta.stop_propagation();
             return;
         }
@@ -98,7 +115,9 @@ impl EventTarget for Element {
         _arena: &crate::view::node_arena::NodeArena,
         _self_key: crate::view::node_arena::NodeKey,
     ) {
-        if self.is_scrollbar_hit(event.pointer.local_x, event.pointer.local_y) {
+        if !self.scrollbar_default_prevented
+            && self.is_scrollbar_hit(event.pointer.local_x, event.pointer.local_y)
+        {
             event.meta.stop_propagation();
             return;
         }
@@ -110,6 +129,23 @@ impl EventTarget for Element {
         }
     }
 
+    fn dispatch_long_press(
+        &mut self,
+        event: &mut crate::ui::LongPressEvent,
+        control: &mut ViewportControl<'_>,
+        _arena: &crate::view::node_arena::NodeArena,
+        _self_key: crate::view::node_arena::NodeKey,
+    ) {
+        if let Some(h) = &mut self.event_handlers {
+            for handler in &mut h.long_press {
+                handler(event, control);
+                if event.meta.immediate_propagation_stopped() {
+                    break;
+                }
+            }
+        }
+    }
+
     fn dispatch_key_down(
         &mut self,
         event: &mut KeyDownEvent,
@@ -351,7 +387,7 @@ impl EventTarget for Element {
         // Both edges are animation boundaries. Enter forces opacity; leave
         // starts a fresh hold/fade epoch at the next viewport frame sample.
         self.note_scrollbar_interaction();
-        self.recompute_style();
+        self.recompute_hover_style();
         true
     }
 
@@ -456,9 +492,76 @@ impl EventTarget for Element {
     }
 
     fn cursor(&self) -> Cursor {
+        let interactive = self.event_handlers.as_deref().is_some_and(|handlers| {
+            !handlers.click.is_empty() || !handlers.pointer_down.is_empty()
+        });
+        if interactive
+            && !self
+                .parsed_style
+                .declares_recursive(crate::style::PropertyId::Cursor)
+        {
+            return crate::style::UserAgentStyle::with_current(|user_agent| {
+                user_agent.interactive_cursor
+            });
+        }
         self.computed_style.cursor
     }
 
+    fn app_region(&self) -> AppRegion {
+        self.computed_style.app_region
+    }
+
+    fn long_press_hold(&self) -> Option<Duration> {
+        self.event_handlers
+            .as_deref()
+            .filter(|handlers| !handlers.long_press.is_empty())
+            .map(|_| self.long_press_duration())
+    }
+
+    fn hover_intent(&self) -> Option<crate::view::viewport::HoverIntent> {
+        self.event_handlers
+            .as_deref()
+            .and_then(|handlers| handlers.hover_intent)
+    }
+
+    fn spatial_navigation(&self) -> bool {
+        self.event_handlers
+            .as_deref()
+            .is_some_and(|handlers| handlers.spatial_navigation)
+    }
+
+    fn spatial_focusable(&self) -> bool {
+        self.event_handlers.as_deref().is_some_and(|handlers| {
+            !handlers.click.is_empty()
+                || !handlers.pointer_down.is_empty()
+                || !handlers.key_down.is_empty()
+                || !handlers.focus.is_empty()
+        })
+    }
+
+    fn observes_visibility(&self) -> bool {
+        self.event_handlers
+            .as_deref()
+            .is_some_and(|handlers| !handlers.visibility_change.is_empty())
+    }
+
+    fn dispatch_visibility(&mut self, ratio: f32) {
+        self.dispatch_visibility_change(ratio);
+    }
+
+    fn scrollport_rect(&self) -> Option<crate::ui::Rect> {
+        if matches!(self.scroll_direction, ScrollDirection::None) {
+            return None;
+        }
+        let inner = self.inner_clip_rect();
+        Some(crate::ui::Rect::new(
+            inner.x,
+            inner.y,
+            inner.width,
+            inner.height,
+        ))
+    }
+
     fn wants_animation_frame(&self) -> bool {
         self.scrollbar_interaction_pending
             || (!self.is_hovered
@@ -161,6 +161,93 @@ fn apply_collision(
     }
 }
 
+/// `rect` with its edges moved inwards, never inverted.
+fn shrink_rect(rect: Rect, top: f32, right: f32, bottom: f32, left: f32) -> Rect {
+    Rect {
+        x: rect.x + left,
+        y: rect.y + top,
+        width: (rect.width - left - right).max(0.0),
+        height: (rect.height - top - bottom).max(0.0),
+    }
+}
+
+/// Start of a box of `size` aligned by `align` against the anchor span
+/// `start..start + span`, `offset` away from it when outside.
+fn anchor_align_start(align: AnchorAlign, start: f32, span: f32, size: f32, offset: f32) -> f32 {
+    match align {
+        AnchorAlign::Before => start - size - offset,
+        AnchorAlign::Start => start,
+        AnchorAlign::Center => start + (span - size) * 0.5,
+        AnchorAlign::End => start + span - size,
+        AnchorAlign::After => start + span + offset,
+    }
+}
+
+/// Top-left of a `width` × `height` box for the first of `candidates` that
+/// lies inside `boundary`, or for the first candidate when none does.
+/// `shift` is added to every candidate before the test.
+fn resolve_anchor_placement(
+    candidates: impl IntoIterator<Item = AnchorPlacement>,
+    boundary: Rect,
+    anchor: AnchorSnapshot,
+    width: f32,
+    height: f32,
+    offset: (f32, f32),
+    shift: (f32, f32),
+) -> Option<(f32, f32)> {
+    let place = |placement: AnchorPlacement| {
+        (
+            anchor_align_start(placement.x(), anchor.x, anchor.width, width, offset.0) + shift.0,
+            anchor_align_start(placement.y(), anchor.y, anchor.height, height, offset.1) + shift.1,
+        )
+    };
+    let fits = |(x, y): (f32, f32)| {
+        x >= boundary.x - 0.5
+            && y >= boundary.y - 0.5
+            && x + width <= boundary.x + boundary.width + 0.5
+            && y + height <= boundary.y + boundary.height + 0.5
+    };
+    let mut first = None;
+    for candidate in candidates {
+        let origin = place(candidate);
+        if fits(origin) {
+            return Some(origin);
+        }
+        first.get_or_insert(origin);
+    }
+    first
+}
+
+/// Offset along one axis that keeps a sticky box of `size` at `start` within
+/// `leading`/`trailing` of the scroll viewport, without letting it leave its
+/// containing rect. The leading inset wins when both constrain the box.
+#[allow(clippy::too_many_arguments)]
+fn sticky_shift(
+    start: f32,
+    size: f32,
+    viewport_start: f32,
+    viewport_size: f32,
+    containing_start: f32,
+    containing_size: f32,
+    leading: Option<f32>,
+    trailing: Option<f32>,
+) -> f32 {
+    if let Some(leading) = leading {
+        let wanted = viewport_start + leading - start;
+        let room = containing_start + containing_size - (start + size);
+        let shift = wanted.min(room).max(0.0);
+        if shift > 0.0 {
+            return shift;
+        }
+    }
+    if let Some(trailing) = trailing {
+        let wanted = start + size - (viewport_start + viewport_size - trailing);
+        let room = start - containing_start;
+        return -wanted.min(room).max(0.0);
+    }
+    0.0
+}
+
 pub(crate) fn main_axis_start_and_gap(
     main_limit: f32,
     occupied_main: f32,
@@ -389,6 +476,8 @@ fn map_transition_timing(timing: TransitionTiming) -> TimeFunction {
         TransitionTiming::EaseIn => TimeFunction::EaseIn,
         TransitionTiming::EaseOut => TimeFunction::EaseOut,
         TransitionTiming::EaseInOut => TimeFunction::EaseInOut,
+        TransitionTiming::CubicBezier(curve) => TimeFunction::CubicBezier(curve),
+        TransitionTiming::Steps(count, position) => TimeFunction::Steps(count, position),
     }
 }
 
@@ -411,6 +500,11 @@ fn push_transition_channels(property: TransitionProperty, out: &mut Vec<ChannelI
                 CHANNEL_STYLE_BOX_SHADOW,
                 CHANNEL_STYLE_TRANSFORM,
                 CHANNEL_STYLE_TRANSFORM_ORIGIN,
+                CHANNEL_STYLE_GAP,
+                CHANNEL_STYLE_PADDING_TOP,
+                CHANNEL_STYLE_PADDING_RIGHT,
+                CHANNEL_STYLE_PADDING_BOTTOM,
+                CHANNEL_STYLE_PADDING_LEFT,
             ]);
         }
         TransitionProperty::Position => {
@@ -433,8 +527,14 @@ fn push_transition_channels(property: TransitionProperty, out: &mut Vec<ChannelI
             CHANNEL_STYLE_BORDER_BOTTOM_COLOR,
             CHANNEL_STYLE_BORDER_LEFT_COLOR,
         ]),
-        TransitionProperty::Gap | TransitionProperty::Padding | TransitionProperty::BorderWidth => {
-        }
+        TransitionProperty::Gap => out.push(CHANNEL_STYLE_GAP),
+        TransitionProperty::Padding => out.extend([
+            CHANNEL_STYLE_PADDING_TOP,
+            CHANNEL_STYLE_PADDING_RIGHT,
+            CHANNEL_STYLE_PADDING_BOTTOM,
+            CHANNEL_STYLE_PADDING_LEFT,
+        ]),
+        TransitionProperty::BorderWidth => {}
     }
 }
 
@@ -605,7 +705,7 @@ fn resolve_gradient_paint(
         .take(n)
         .enumerate()
         .map(|(k, stop)| {
-            let color = stop.color.to_rgba_f32();
+            let color = stop.color.to_output_rgba_f32();
             let p = positions[k].unwrap_or(0.0);
             GradientStopGpu {
                 color,
@@ -6,6 +6,11 @@ impl Element {
         self.core.id = stable_id;
     }
 
+    #[cfg(test)]
+    pub(crate) fn is_hovered_for_test(&self) -> bool {
+        self.is_hovered
+    }
+
     const SHOULD_RENDER_OVERSCAN_PX: f32 = 24.0;
 
     fn has_visible_background(&self) -> bool {
@@ -90,6 +95,24 @@ impl Element {
             && intersect_scissor_rects(ctx.scissor_rect(), self.inner_clip_scissor_rect()).is_some()
     }
 
+    /// Scissor this element is built under as an in-flow child of a
+    /// scrolled container: its paint bounds (box shadows and own transform
+    /// included) grown by the should-render overscan, intersected with the
+    /// scrollport scissor already on `ctx`. `None` when it lies wholly
+    /// outside, so the parent can skip its passes — placement only culls
+    /// against the immediate scrollport, not the whole ancestor chain.
+    fn scrolled_child_visible_scissor_rect(&self, ctx: &UiBuildContext) -> Option<[u32; 4]> {
+        let bounds = self.untransformed_paint_bounds();
+        let overscan = Self::SHOULD_RENDER_OVERSCAN_PX.max(0.0);
+        let visible = self.transformed_bounding_rect_for_rect(Rect {
+            x: bounds.x - overscan,
+            y: bounds.y - overscan,
+            width: bounds.width + overscan * 2.0,
+            height: bounds.height + overscan * 2.0,
+        });
+        intersect_scissor_rects(ctx.scissor_rect(), Some(rect_to_scissor_rect(visible)?))
+    }
+
     pub(crate) fn absolute_clip_scissor_rect(&self) -> Option<[u32; 4]> {
         if self.computed_style.position.mode() != PositionMode::Absolute {
             return None;
@@ -676,6 +699,9 @@ impl Element {
             parsed_style: style,
             text_cascade_style: None,
             computed_style: ComputedStyle::default(),
+            computed_style_variants: [None, None],
+            media_matches: Vec::new(),
+            elevation_generation: None,
             padding: EdgeInsets {
                 left: 0.0,
                 right: 0.0,
@@ -698,6 +724,8 @@ impl Element {
             border_radii: CornerRadii::zero(),
             border_radius: 0.0,
             box_shadows: Vec::new(),
+            border_image_handle: None,
+            mask_image_handle: None,
             transform: Transform::default(),
             transform_origin: TransformOrigin::center(),
             resolved_transform: None,
@@ -711,12 +739,14 @@ impl Element {
             inline_ifc_rollout_packages: ElementInlineIfcRolloutPackages::default(),
             inline_ifc_layout_call_site: ElementInlineIfcLayoutCallSiteState::default(),
             scrollbar_drag: None,
+            scrollbar_default_prevented: false,
             last_scrollbar_interaction: None,
             scrollbar_interaction_pending: false,
             sampled_scrollbar_alpha: 0.0,
             scrollbar_shadow_blur_radius: 3.0,
             transition_requests: None,
             last_started_animator: None,
+            transition_group: None,
             has_style_snapshot: false,
             has_layout_snapshot: false,
             layout_transition_visual_offset_x: 0.0,
@@ -727,6 +757,13 @@ impl Element {
             layout_transition_target_y: None,
             layout_transition_target_width: None,
             layout_transition_target_height: None,
+            style_transition_gap: None,
+            style_transition_padding: crate::style::EdgeInsets {
+                top: None,
+                right: None,
+                bottom: None,
+                left: None,
+            },
             last_parent_layout_x: x,
             last_parent_layout_y: y,
             layout_assigned_width: None,
@@ -737,6 +774,7 @@ impl Element {
             dirty_flags: DirtyFlags::ALL,
             last_layout_placement: None,
             last_layout_proposal: None,
+            measure_cache: Default::default(),
             flex_info: None,
             has_absolute_descendant_for_hit_test: false,
             absolute_clip_rect: None,
@@ -802,6 +840,32 @@ impl Element {
             self.layout_transition_target_height = None;
             needs_layout = true;
         }
+        if !has_channel(CHANNEL_STYLE_GAP) && self.style_transition_gap.is_some() {
+            self.style_transition_gap = None;
+            needs_layout = true;
+        }
+        for (channel, side) in [
+            (
+                CHANNEL_STYLE_PADDING_TOP,
+                &mut self.style_transition_padding.top,
+            ),
+            (
+                CHANNEL_STYLE_PADDING_RIGHT,
+                &mut self.style_transition_padding.right,
+            ),
+            (
+                CHANNEL_STYLE_PADDING_BOTTOM,
+                &mut self.style_transition_padding.bottom,
+            ),
+            (
+                CHANNEL_STYLE_PADDING_LEFT,
+                &mut self.style_transition_padding.left,
+            ),
+        ] {
+            if !has_channel(channel) && side.take().is_some() {
+                needs_layout = true;
+            }
+        }
 
         if needs_layout {
             self.mark_layout_dirty();
@@ -822,6 +886,10 @@ impl Element {
         self.mark_place_dirty();
     }
 
+    pub fn anchor_name(&self) -> Option<&AnchorName> {
+        self.anchor_name.as_ref()
+    }
+
     pub fn debug_type(&self) -> DebugType {
         self.debug_type
     }
@@ -830,6 +898,11 @@ impl Element {
         self.debug_type = debug_type;
     }
 
+    /// Style resolved for the current hover state.
+    pub fn computed_style(&self) -> &ComputedStyle {
+        &self.computed_style
+    }
+
     pub fn set_x(&mut self, x: f32) {
         self.core.set_x(x);
         self.mark_place_dirty();
@@ -861,6 +934,7 @@ impl Element {
 
     pub fn mark_layout_dirty(&mut self) {
         self.layout_dirty = true;
+        crate::view::layout::measure_cache::bump_layout_generation();
         self.mark_local_dirty(DirtyFlags::ALL);
     }
 
@@ -1068,6 +1142,72 @@ impl Element {
         self.mark_layout_dirty();
     }
 
+    /// Store a `gap` or `padding` transition sample. Returns `false` for
+    /// fields that are not spacing fields.
+    pub fn set_spacing_transition_sample(&mut self, field: StyleField, value: f32) -> bool {
+        if !self.write_spacing_transition_sample(field, value) {
+            return false;
+        }
+        self.mark_layout_dirty();
+        true
+    }
+
+    pub fn set_spacing_transition_sample_with_invalidation(
+        &mut self,
+        field: StyleField,
+        value: f32,
+        cx: &mut InvalidationContext<'_>,
+    ) -> bool {
+        if !self.write_spacing_transition_sample(field, value) {
+            return false;
+        }
+        self.mark_layout_dirty_with(cx);
+        true
+    }
+
+    fn write_spacing_transition_sample(&mut self, field: StyleField, value: f32) -> bool {
+        let value = Some(value.max(0.0));
+        match field {
+            StyleField::Gap => self.style_transition_gap = value,
+            StyleField::PaddingTop => self.style_transition_padding.top = value,
+            StyleField::PaddingRight => self.style_transition_padding.right = value,
+            StyleField::PaddingBottom => self.style_transition_padding.bottom = value,
+            StyleField::PaddingLeft => self.style_transition_padding.left = value,
+            _ => return false,
+        }
+        true
+    }
+
+    /// `computed_style.gap`, or the in-flight gap transition sample.
+    pub(crate) fn transitioned_gap(&self) -> Length {
+        self.style_transition_gap
+            .map(Length::Px)
+            .unwrap_or(self.computed_style.gap)
+    }
+
+    /// `computed_style.padding` with any in-flight padding transition
+    /// samples applied per side.
+    pub(crate) fn transitioned_padding(&self) -> crate::style::EdgeInsets<Length> {
+        let padding = self.computed_style.padding;
+        let overrides = self.style_transition_padding;
+        crate::style::EdgeInsets {
+            top: overrides.top.map(Length::Px).unwrap_or(padding.top),
+            right: overrides.right.map(Length::Px).unwrap_or(padding.right),
+            bottom: overrides.bottom.map(Length::Px).unwrap_or(padding.bottom),
+            left: overrides.left.map(Length::Px).unwrap_or(padding.left),
+        }
+    }
+
+    pub fn transition_group(&self) -> Option<crate::transition::TransitionGroup> {
+        self.transition_group
+    }
+
+    /// Stagger the transitions and animations this element's children
+    /// start; see [`crate::transition::TransitionGroup`].
+    pub fn set_transition_group(&mut self, group: Option<crate::transition::TransitionGroup>) {
+        self.transition_group = group;
+    }
+
     pub fn seed_layout_transition_snapshot(
         &mut self,
         layout_x: f32,
@@ -1287,20 +1427,72 @@ impl Element {
     }
 
     fn recompute_style(&mut self) {
-        let previous_snapshot = self
-            .has_style_snapshot
-            .then(|| self.capture_style_snapshot());
-        let old_computed = self.computed_style.clone();
-        let next_computed = compute_style_with_context(
+        self.computed_style_variants = [None, None];
+        self.media_matches = self
+            .parsed_style
+            .matching_media(&crate::style::MediaContext::current());
+        self.elevation_generation = self
+            .parsed_style
+            .declares_recursive(crate::style::PropertyId::Elevation)
+            .then(crate::style::ElevationScale::generation);
+        self.apply_style_variant();
+    }
+
+    /// Whether a `media` block of `parsed_style` flipped since the last
+    /// resolution, e.g. after the viewport published a new
+    /// [`crate::style::MediaContext`], or its `elevation` presets were
+    /// replaced.
+    pub(crate) fn media_style_stale(&self) -> bool {
+        let media_stale = !self.parsed_style.media().is_empty()
+            && self
+                .parsed_style
+                .matching_media(&crate::style::MediaContext::current())
+                != self.media_matches;
+        media_stale
+            || self
+                .elevation_generation
+                .is_some_and(|generation| generation != crate::style::ElevationScale::generation())
+    }
+
+    pub(crate) fn refresh_media_style(&mut self) {
+        self.recompute_style();
+    }
+
+    /// Hover only picks between the two resolutions of `parsed_style`, so
+    /// a toggle reuses the cached one and is a no-op without a hover style.
+    fn recompute_hover_style(&mut self) {
+        if self.parsed_style.hover().is_none() {
+            return;
+        }
+        self.apply_style_variant();
+    }
+
+    fn computed_style_variant(&mut self) -> ComputedStyle {
+        let hovered = self.is_hovered;
+        if let Some(computed) = &self.computed_style_variants[usize::from(hovered)] {
+            return computed.clone();
+        }
+        let computed = compute_style_with_context(
             &self.parsed_style,
             StyleComputeContext {
                 parent: None,
                 viewport_width: 0.0,
                 viewport_height: 0.0,
                 root_font_size: 16.0,
-                hovered: self.is_hovered,
+                hovered,
+                media: crate::style::MediaContext::current(),
             },
         );
+        self.computed_style_variants[usize::from(hovered)] = Some(computed.clone());
+        computed
+    }
+
+    fn apply_style_variant(&mut self) {
+        let previous_snapshot = self
+            .has_style_snapshot
+            .then(|| self.capture_style_snapshot());
+        let old_computed = self.computed_style.clone();
+        let next_computed = self.computed_style_variant();
         ComputedStyleConsumer::apply_computed_style(
             self,
             next_computed,
@@ -1327,6 +1519,7 @@ impl Element {
             .push(crate::transition::AnimationRequest {
                 target: self.core.id,
                 animator,
+                delay_offset_ms: 0,
             });
         self.last_started_animator = next_animator;
     }
@@ -1360,6 +1553,7 @@ impl Element {
                 duration_ms: transition.duration_ms,
                 delay_ms: transition.delay_ms,
                 timing: map_transition_timing(transition.timing),
+                color_interpolation: transition.color_interpolation,
             };
             match transition.property {
                 TransitionProperty::All => {
@@ -1500,6 +1694,38 @@ impl Element {
                             });
                     }
                 }
+                TransitionProperty::Gap if changed_fields.contains(&StyleField::Gap) => {
+                    queue_transition_requests(&mut self.transition_requests)
+                        .style
+                        .push(StyleTrackRequest {
+                            target: self.core.id,
+                            field: StyleField::Gap,
+                            from: previous.value_for(StyleField::Gap),
+                            to: previous.current_value_for(&self.computed_style, StyleField::Gap),
+                            transition: runtime,
+                        });
+                }
+                TransitionProperty::Padding => {
+                    const PADDING_FIELDS: [StyleField; 4] = [
+                        StyleField::PaddingTop,
+                        StyleField::PaddingRight,
+                        StyleField::PaddingBottom,
+                        StyleField::PaddingLeft,
+                    ];
+                    for field in PADDING_FIELDS {
+                        if changed_fields.contains(&field) {
+                            queue_transition_requests(&mut self.transition_requests)
+                                .style
+                                .push(StyleTrackRequest {
+                                    target: self.core.id,
+                                    field,
+                                    from: previous.value_for(field),
+                                    to: previous.current_value_for(&self.computed_style, field),
+                                    transition: runtime,
+                                });
+                        }
+                    }
+                }
                 _ => {}
             }
         }
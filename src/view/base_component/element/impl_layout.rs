@@ -119,7 +119,25 @@ impl Element {
         }
     }
 
+    /// Border and padding insets for `proposal`, including any gutter the
+    /// scrollbar style reserves on the trailing edges.
+    fn resolved_layout_insets(&self, proposal: LayoutProposal) -> ResolvedLayoutInsets {
+        let mut insets = resolve_layout_insets(
+            &self.computed_style.border_widths,
+            &self.transitioned_padding(),
+            proposal.percent_base_width,
+            proposal.percent_base_height,
+            proposal.viewport_width,
+            proposal.viewport_height,
+        );
+        let (gutter_right, gutter_bottom) = self.scrollbar_gutter_insets();
+        insets.padding_r += gutter_right;
+        insets.padding_b += gutter_bottom;
+        insets
+    }
+
     fn resolve_lengths_from_parent_inner(&mut self, proposal: LayoutProposal) {
+        let padding = self.transitioned_padding();
         self.border_widths.left = resolve_px_or_zero(
             self.computed_style.border_widths.left,
             proposal.percent_base_width,
@@ -149,9 +167,10 @@ impl Element {
             .parsed_style
             .get(crate::style::PropertyId::PaddingLeft)
             .is_some()
+            || self.style_transition_padding.left.is_some()
         {
             self.padding.left = resolve_px_or_zero(
-                self.computed_style.padding.left,
+                padding.left,
                 proposal.percent_base_width,
                 proposal.viewport_width,
                 proposal.viewport_height,
@@ -161,21 +180,23 @@ impl Element {
             .parsed_style
             .get(crate::style::PropertyId::PaddingRight)
             .is_some()
+            || self.style_transition_padding.right.is_some()
         {
             self.padding.right = resolve_px_or_zero(
-                self.computed_style.padding.right,
+                padding.right,
                 proposal.percent_base_width,
                 proposal.viewport_width,
                 proposal.viewport_height,
-            );
+            ) + self.scrollbar_gutter_insets().0;
         }
         if self
             .parsed_style
             .get(crate::style::PropertyId::PaddingTop)
             .is_some()
+            || self.style_transition_padding.top.is_some()
         {
             self.padding.top = resolve_px_or_zero(
-                self.computed_style.padding.top,
+                padding.top,
                 proposal.percent_base_height,
                 proposal.viewport_width,
                 proposal.viewport_height,
@@ -185,13 +206,14 @@ impl Element {
             .parsed_style
             .get(crate::style::PropertyId::PaddingBottom)
             .is_some()
+            || self.style_transition_padding.bottom.is_some()
         {
             self.padding.bottom = resolve_px_or_zero(
-                self.computed_style.padding.bottom,
+                padding.bottom,
                 proposal.percent_base_height,
                 proposal.viewport_width,
                 proposal.viewport_height,
-            );
+            ) + self.scrollbar_gutter_insets().1;
         }
     }
 
@@ -355,6 +377,64 @@ impl Element {
         self.scroll_offset.y = self.scroll_offset.y.clamp(0.0, max_y);
     }
 
+    /// Memo key for a measure under `proposal`. An auto height can count
+    /// as known because of the size left by the previous measure (see
+    /// `height_is_known`), so that bit is part of the key. Read it before
+    /// `measure_self` runs.
+    fn measure_cache_key(&self, proposal: LayoutProposal) -> (LayoutProposal, bool) {
+        (proposal, self.height_is_known(proposal))
+    }
+
+    /// Restores the memoized measure for `proposal`, replaying each child's
+    /// recorded constraints so the subtree ends up as a full measure would
+    /// leave it. Children hit their own caches, so this stays cheap.
+    fn replay_cached_measure(&mut self, proposal: LayoutProposal, arena: &mut NodeArena) -> bool {
+        let key = self.measure_cache_key(proposal);
+        let Some(cached) = self.measure_cache.get(&key).cloned() else {
+            return false;
+        };
+        for (child_key, constraints) in cached.child_constraints {
+            arena.with_element_taken(child_key, |child, arena| {
+                child.measure(constraints, arena);
+            });
+        }
+        self.core.set_width(cached.size.width);
+        self.core.set_height(cached.size.height);
+        self.layout_state.content_size = cached.content_size;
+        self.flex_info = cached.flex_info;
+        true
+    }
+
+    fn store_measure_cache(&mut self, key: (LayoutProposal, bool), arena: &NodeArena) {
+        // Inline IFC fragments shape against state outside this subtree,
+        // and an IFC root sizes its children through the shared line stack
+        // rather than by measuring them, so neither can be replayed.
+        if self.inline_ifc_owned_by_root
+            || (self.computed_style.layout == Layout::Inline && !self.children.is_empty())
+        {
+            return;
+        }
+        let mut child_constraints = Vec::with_capacity(self.children.len());
+        for child_key in self.children.iter().copied() {
+            let Some(constraints) = arena
+                .get(child_key)
+                .and_then(|node| node.element.last_measure_constraints())
+            else {
+                return;
+            };
+            child_constraints.push((child_key, constraints));
+        }
+        self.measure_cache.insert(
+            key,
+            MeasuredLayout {
+                size: self.core.size,
+                content_size: self.layout_state.content_size,
+                flex_info: self.flex_info.clone(),
+                child_constraints,
+            },
+        );
+    }
+
     fn update_size_from_measured_children(
         &mut self,
         arena: &crate::view::node_arena::NodeArena,
@@ -388,14 +468,7 @@ impl Element {
             percent_base_height: None,
         });
 
-        let insets = resolve_layout_insets(
-            &self.computed_style.border_widths,
-            &self.computed_style.padding,
-            proposal.percent_base_width,
-            proposal.percent_base_height,
-            proposal.viewport_width,
-            proposal.viewport_height,
-        );
+        let insets = self.resolved_layout_insets(proposal);
 
         if self.computed_style.width == SizeValue::Auto {
             self.core.set_width(max_w + insets.horizontal());
@@ -521,10 +594,26 @@ impl Element {
                 target_rel_x -= ox;
                 target_rel_y -= oy;
             }
+            let translate = |value: Option<Length>, base: f32| {
+                value
+                    .and_then(|v| {
+                        resolve_signed_px_with_base(
+                            v,
+                            Some(base),
+                            proposal.viewport_width,
+                            proposal.viewport_height,
+                        )
+                    })
+                    .unwrap_or(0.0)
+            };
+            let shift_x = translate(self.computed_style.position.translation_x(), target_width);
+            let shift_y = translate(self.computed_style.position.translation_y(), target_height);
+            target_rel_x += shift_x;
+            target_rel_y += shift_y;
 
             let mut abs_x = parent_x + target_rel_x;
             let mut abs_y = parent_y + target_rel_y;
-            let boundary = match self.computed_style.position.collision_boundary() {
+            let mut boundary = match self.computed_style.position.collision_boundary() {
                 CollisionBoundary::Parent => Rect {
                     x: parent_x,
                     y: parent_y,
@@ -541,6 +630,49 @@ impl Element {
                     }
                 }
             };
+            if let Some(insets) = self.computed_style.position.boundary_inset_edges() {
+                let top = translate(Some(insets.top), boundary.height);
+                let right = translate(Some(insets.right), boundary.width);
+                let bottom = translate(Some(insets.bottom), boundary.height);
+                let left = translate(Some(insets.left), boundary.width);
+                boundary = shrink_rect(boundary, top, right, bottom, left);
+            }
+            if let Some(padding) = self.computed_style.position.collision_padding_length() {
+                let padding = translate(Some(padding), boundary.width.min(boundary.height));
+                boundary = shrink_rect(boundary, padding, padding, padding, padding);
+            }
+            let collision = self.computed_style.position.collision_mode();
+            let placement = self.computed_style.position.anchor_placement();
+            if let Some(placement) = placement {
+                let offset = self.computed_style.position.anchor_offset_length();
+                let offset = (
+                    translate(offset, anchor.width),
+                    translate(offset, anchor.height),
+                );
+                let mirror = matches!(collision, Collision::Flip | Collision::FlipFit)
+                    .then(|| placement.mirrored());
+                let candidates = std::iter::once(placement)
+                    .chain(
+                        self.computed_style
+                            .position
+                            .fallback_placement_list()
+                            .iter()
+                            .copied(),
+                    )
+                    .chain(mirror);
+                if let Some((x, y)) = resolve_anchor_placement(
+                    candidates,
+                    boundary,
+                    anchor,
+                    target_width,
+                    target_height,
+                    offset,
+                    (shift_x, shift_y),
+                ) {
+                    abs_x = x;
+                    abs_y = y;
+                }
+            }
             let clip_mode = self.computed_style.position.clip_mode();
             let has_anchor = self.computed_style.position.anchor_ref().is_some();
             absolute_clip_rect = Some(match clip_mode {
@@ -559,24 +691,70 @@ impl Element {
                     .with(|r| r.borrow().ancestor_stack.last().map(|s| s.parent_clip_rect))
                     .unwrap_or(parent_clip_rect),
             });
+            // A placement already tried its flips; only `Fit` clamping is left.
+            let insets = if placement.is_some() {
+                (None, None, None, None)
+            } else {
+                (left, right, top, bottom)
+            };
             apply_collision(
-                self.computed_style.position.collision_mode(),
+                collision,
                 boundary,
                 &mut abs_x,
                 &mut abs_y,
                 target_width,
                 target_height,
                 anchor,
-                left,
-                right,
-                top,
-                bottom,
+                insets.0,
+                insets.1,
+                insets.2,
+                insets.3,
             );
             target_rel_x = abs_x - parent_x;
             target_rel_y = abs_y - parent_y;
             self.layout_assigned_width = Some(target_width.max(0.0));
             self.layout_assigned_height = Some(target_height.max(0.0));
         }
+        let sticky_scope = if self.is_sticky_positioned() {
+            self.current_parent_sticky_scope()
+        } else {
+            None
+        };
+        if let Some((scope, viewport)) =
+            sticky_scope.and_then(|scope| scope.scroll_viewport.map(|viewport| (scope, viewport)))
+        {
+            let position = &self.computed_style.position;
+            let resolve_inset = |inset: Option<Length>, base: f32| {
+                inset.and_then(|v| {
+                    resolve_signed_px_with_base(
+                        v,
+                        Some(base),
+                        proposal.viewport_width,
+                        proposal.viewport_height,
+                    )
+                })
+            };
+            target_rel_x += sticky_shift(
+                parent_x + target_rel_x,
+                target_width,
+                viewport.x,
+                viewport.width,
+                scope.containing_rect.x,
+                scope.containing_rect.width,
+                resolve_inset(position.left_inset(), viewport.width),
+                resolve_inset(position.right_inset(), viewport.width),
+            );
+            target_rel_y += sticky_shift(
+                parent_y + target_rel_y,
+                target_height,
+                viewport.y,
+                viewport.height,
+                scope.containing_rect.y,
+                scope.containing_rect.height,
+                resolve_inset(position.top_inset(), viewport.height),
+                resolve_inset(position.bottom_inset(), viewport.height),
+            );
+        }
         let has_x_transition = self.computed_style.transition.as_slice().iter().any(|t| {
             matches!(
                 t.property,
@@ -1045,6 +1223,7 @@ impl Element {
                 runtime.ancestor_stack.clear();
                 runtime.child_clip_stack.clear();
                 runtime.hit_test_clip_stack.clear();
+                runtime.sticky_scope_stack.clear();
                 runtime.viewport_width = placement.viewport_width.max(0.0);
                 runtime.viewport_height = placement.viewport_height.max(0.0);
             }
@@ -1063,6 +1242,7 @@ impl Element {
                 runtime.ancestor_stack.clear();
                 runtime.child_clip_stack.clear();
                 runtime.hit_test_clip_stack.clear();
+                runtime.sticky_scope_stack.clear();
             }
         });
     }
@@ -1099,6 +1279,80 @@ impl Element {
         PLACEMENT_RUNTIME.with(|runtime| runtime.borrow().hit_test_clip_stack.last().copied())
     }
 
+    fn push_sticky_scope(&self, scope: StickyScope) {
+        PLACEMENT_RUNTIME.with(|runtime| {
+            runtime.borrow_mut().sticky_scope_stack.push(scope);
+        });
+    }
+
+    fn pop_sticky_scope(&self) {
+        PLACEMENT_RUNTIME.with(|runtime| {
+            runtime.borrow_mut().sticky_scope_stack.pop();
+        });
+    }
+
+    fn current_parent_sticky_scope(&self) -> Option<StickyScope> {
+        PLACEMENT_RUNTIME.with(|runtime| runtime.borrow().sticky_scope_stack.last().copied())
+    }
+
+    /// Scope handed to this element's children. A scroll host contributes its
+    /// visible inner rect as the sticky viewport and its scrolled content as
+    /// the containing rect; any other element keeps the inherited viewport
+    /// and narrows the containing rect to its own content box.
+    fn child_sticky_scope(&self) -> StickyScope {
+        let inner = Rect {
+            x: self.layout_state.layout_flow_inner_position.x,
+            y: self.layout_state.layout_flow_inner_position.y,
+            width: self.layout_state.layout_inner_size.width.max(0.0),
+            height: self.layout_state.layout_inner_size.height.max(0.0),
+        };
+        if self.scroll_direction != ScrollDirection::None {
+            return StickyScope {
+                containing_rect: Rect {
+                    x: inner.x - self.scroll_offset.x,
+                    y: inner.y - self.scroll_offset.y,
+                    width: self.layout_state.content_size.width.max(inner.width),
+                    height: self.layout_state.content_size.height.max(inner.height),
+                },
+                scroll_viewport: Some(inner),
+            };
+        }
+        StickyScope {
+            containing_rect: inner,
+            scroll_viewport: self
+                .current_parent_sticky_scope()
+                .and_then(|scope| scope.scroll_viewport),
+        }
+    }
+
+    pub(crate) fn is_sticky_positioned(&self) -> bool {
+        self.computed_style.position.mode() == PositionMode::Sticky
+    }
+
+    /// Children of each line from the last flex solve, plus whether the main
+    /// axis is horizontal. `None` unless this element is a real flex container
+    /// that has been laid out.
+    pub(crate) fn flex_line_children(&self) -> Option<(bool, Vec<Vec<NodeKey>>)> {
+        if !matches!(self.computed_style.layout, Layout::Flex { .. }) {
+            return None;
+        }
+        let info = self.flex_info.as_ref()?;
+        let is_row = matches!(
+            self.computed_style.layout_axis_direction(),
+            FlowDirection::Row
+        );
+        let lines = info
+            .lines
+            .iter()
+            .map(|line| {
+                line.iter()
+                    .filter_map(|item| self.children.get(item.child_index).copied())
+                    .collect()
+            })
+            .collect();
+        Some((is_row, lines))
+    }
+
     pub(crate) fn current_inherited_hit_test_clip_rect(&self, placement: LayoutPlacement) -> Rect {
         self.current_parent_hit_test_clip_rect().unwrap_or(Rect {
             x: placement.parent_x + placement.visual_offset_x,
@@ -1388,7 +1642,10 @@ impl Element {
             contains_anchor_ref: self.computed_style.position.anchor_ref().is_some(),
             contains_absolute_descendant: self.is_absolute_positioned_for_hit_test()
                 || self.has_absolute_descendant_for_hit_test,
-            contains_runtime_layout_state: self.active_layout_transition_runtime_state(),
+            // A sticky element's offset depends on the scroll position of an
+            // ancestor, so a translated replay would carry a stale clamp.
+            contains_runtime_layout_state: self.active_layout_transition_runtime_state()
+                || self.is_sticky_positioned(),
             // Element implements `translate_in_place`, so it is translatable
             // on its own behalf — except as an inline-formatting-context
             // host, whose descendant glyph boxes are installed at absolute
@@ -1413,7 +1670,7 @@ impl Element {
         if self.has_absolute_descendant_for_hit_test {
             return Some(PlacementSkipFailureReason::AbsoluteDescendant);
         }
-        if self.active_layout_transition_runtime_state() {
+        if self.active_layout_transition_runtime_state() || self.is_sticky_positioned() {
             return Some(PlacementSkipFailureReason::RuntimeState);
         }
         if self.last_layout_placement != Some(placement) {
@@ -1536,6 +1793,7 @@ impl Element {
         arena: &mut crate::view::node_arena::NodeArena,
     ) {
         let absolute_mask = self.compute_children_absolute_mask(arena);
+        self.push_sticky_scope(self.child_sticky_scope());
         let child_parent_hit_test_clip = self.current_child_hit_test_clip_rect();
         self.last_child_hit_test_clip_rect = Some(child_parent_hit_test_clip);
         self.push_hit_test_clip_scope(child_parent_hit_test_clip);
@@ -1676,6 +1934,7 @@ impl Element {
         });
         self.pop_child_clip_scope();
         self.pop_hit_test_clip_scope();
+        self.pop_sticky_scope();
     }
 
     fn place_flex_children(
@@ -1716,7 +1975,7 @@ impl Element {
             child_inner_height
         };
         let gap = resolve_px(
-            self.computed_style.gap,
+            self.transitioned_gap(),
             gap_base,
             viewport_width,
             viewport_height,
@@ -1832,7 +2091,7 @@ fn clean_placement_skip_node_failure(
         if child.has_absolute_descendant_for_hit_test {
             return Some(PlacementSkipFailureReason::AbsoluteDescendant);
         }
-        if child.active_layout_transition_runtime_state() {
+        if child.active_layout_transition_runtime_state() || child.is_sticky_positioned() {
             return Some(PlacementSkipFailureReason::RuntimeState);
         }
         if child.dirty_flags.intersects(DirtyPassMask::PLACEMENT) {
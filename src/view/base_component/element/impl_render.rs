@@ -350,7 +350,7 @@ impl Element {
         ctx: UiBuildContext,
         force_self_opaque: bool,
     ) -> BuildState {
-        self.build_base_descendants_only_inner(graph, arena, ctx, force_self_opaque, true)
+        self.build_base_descendants_only_inner(graph, arena, ctx, force_self_opaque, true, true)
     }
 
     fn build_base_descendants_only_inner(
@@ -360,7 +360,17 @@ impl Element {
         mut ctx: UiBuildContext,
         force_self_opaque: bool,
         allow_transform: bool,
+        allow_layer_effect: bool,
     ) -> BuildState {
+        if allow_layer_effect && self.layout_state.should_render && self.has_layer_effect() {
+            return self.build_layer_effect_subtree(
+                graph,
+                arena,
+                ctx,
+                force_self_opaque,
+                allow_transform,
+            );
+        }
         let accumulated_render_transform =
             self.resolved_transform
                 .map(|transform| match ctx.current_render_transform() {
@@ -425,12 +435,34 @@ impl Element {
         let inner_visible = self.has_visible_inner_render_area(&ctx);
         let render_children_passes = should_render_children && inner_visible;
 
+        // Inside a scrolled clip scope each in-flow child builds under its
+        // own visible rect, and children scrolled wholly past the scrollport
+        // (overscan included) build nothing at all.
+        let (max_scroll_x, max_scroll_y) = self.max_scroll();
+        let cull_scrolled_children =
+            child_clip_scope.is_some() && (max_scroll_x > 0.0 || max_scroll_y > 0.0);
+
         let child_keys: Vec<crate::view::node_arena::NodeKey> = self.children.clone();
         if render_children_passes {
             for (idx, child_key) in child_keys.iter().copied().enumerate() {
                 if overflow_child_indices.get(idx).copied().unwrap_or(false) {
                     continue;
                 }
+                let visible_scissor = if cull_scrolled_children {
+                    arena.get(child_key).and_then(|node| {
+                        node.element
+                            .as_any()
+                            .downcast_ref::<Element>()
+                            .map(|child| child.scrolled_child_visible_scissor_rect(&ctx))
+                    })
+                } else {
+                    None
+                };
+                let previous_child_scissor = match visible_scissor {
+                    Some(None) => continue,
+                    Some(Some(scissor)) => Some(ctx.push_scissor_rect(Some(scissor))),
+                    None => None,
+                };
                 let viewport = ctx.viewport();
                 let taken_state = ctx.state_clone();
                 let ctx_in = UiBuildContext::from_parts(viewport.clone(), taken_state);
@@ -450,6 +482,9 @@ impl Element {
                 if let Some(c) = next_ctx {
                     ctx = c;
                 }
+                if let Some(previous) = previous_child_scissor {
+                    ctx.restore_scissor_rect(previous);
+                }
             }
         }
 
@@ -506,14 +541,7 @@ impl Element {
         proposal: LayoutProposal,
         arena: &mut crate::view::node_arena::NodeArena,
     ) {
-        let insets = resolve_layout_insets(
-            &self.computed_style.border_widths,
-            &self.computed_style.padding,
-            proposal.percent_base_width,
-            proposal.percent_base_height,
-            proposal.viewport_width,
-            proposal.viewport_height,
-        );
+        let insets = self.resolved_layout_insets(proposal);
 
         let sizes = self.resolve_layout_sizes(proposal);
         let measure_w = if self.computed_style.width == SizeValue::Auto
@@ -559,7 +587,7 @@ impl Element {
             !is_real_flex && matches!(self.computed_style.layout_flow_wrap(), FlowWrap::Wrap);
         let main_limit = if is_row { inner_w } else { inner_h };
         let solver_gap = resolve_px(
-            self.computed_style.gap,
+            self.transitioned_gap(),
             if is_row { inner_w } else { inner_h },
             proposal.viewport_width,
             proposal.viewport_height,
@@ -636,9 +664,60 @@ impl Element {
             pass.set_render_mode(op.mode);
             ctx.emit_draw_rect_pass(graph, pass);
         }
+        self.emit_border_image_pass(graph, &mut ctx, opacity);
         ctx.into_state()
     }
 
+    fn emit_border_image_pass(
+        &self,
+        graph: &mut FrameGraph,
+        ctx: &mut UiBuildContext,
+        opacity: f32,
+    ) {
+        let Some(nine_patch) = self.computed_style.border_nine_patch.as_ref() else {
+            return;
+        };
+        let Some((upload, image_size)) = self.border_image_upload() else {
+            return;
+        };
+        let paint_offset = ctx.paint_offset();
+        let bounds = [
+            self.layout_state.layout_position.x + paint_offset[0],
+            self.layout_state.layout_position.y + paint_offset[1],
+            self.layout_state.layout_size.width.max(0.0),
+            self.layout_state.layout_size.height.max(0.0),
+        ];
+        let quads = nine_patch_quads(bounds, image_size, nine_patch);
+        if quads.is_empty() {
+            return;
+        }
+        let target = ctx.current_target().unwrap_or_else(|| {
+            let target = ctx.allocate_target(graph);
+            ctx.set_current_target(target);
+            target
+        });
+        graph.add_graphics_pass(NinePatchPass::new(
+            quads,
+            opacity,
+            upload,
+            ctx.graphics_pass_context(),
+            target,
+        ));
+        ctx.set_current_target(target);
+    }
+
+    /// Elements mid-transition keep fractional positions; snapping them
+    /// would make the motion step from pixel to pixel.
+    fn wants_pixel_snap(&self) -> bool {
+        !self.active_layout_transition_runtime_state() && !self.has_active_animator()
+    }
+
+    fn hairline_border_edges(&self) -> [bool; 4] {
+        let widths = &self.computed_style.border_widths;
+        [widths.left, widths.right, widths.top, widths.bottom]
+            .map(|width| width == Length::Hairline)
+    }
+
     pub(super) fn self_decoration_paint_ops(
         &self,
         opacity: f32,
@@ -647,7 +726,7 @@ impl Element {
         if !self.core.should_paint {
             return SelfDecorationPaintOps::empty();
         }
-        let fill_color = self.background_color.as_ref().to_rgba_f32();
+        let fill_color = self.background_color.as_ref().to_output_rgba_f32();
         let gradient_paint = self.computed_style.background_image.as_ref().map(|g| {
             resolve_gradient_paint(
                 g,
@@ -682,6 +761,8 @@ impl Element {
             self.layout_state.layout_position.x + paint_offset[0],
             self.layout_state.layout_position.y + paint_offset[1],
         ];
+        let pixel_snap = self.wants_pixel_snap();
+        let hairline_edges = self.hairline_border_edges();
         let mut fill = RectPassParams {
             position,
             size: [
@@ -691,6 +772,8 @@ impl Element {
             fill_color,
             opacity,
             gradient: gradient_paint,
+            pixel_snap,
+            hairline_edges,
             ..Default::default()
         };
         fill.set_border_widths(left, right, top, bottom);
@@ -703,6 +786,13 @@ impl Element {
         if left <= 0.0 && right <= 0.0 && top <= 0.0 && bottom <= 0.0 {
             return ops;
         }
+        // A nine-patch replaces the stroke unless its image failed to load,
+        // in which case the declared border still draws.
+        if self.computed_style.border_nine_patch.is_some()
+            && !matches!(self.border_image_snapshot(), Some(ImageSnapshot::Error(_)))
+        {
+            return ops;
+        }
 
         let mut border = RectPassParams {
             position,
@@ -713,13 +803,15 @@ impl Element {
             fill_color: [0.0, 0.0, 0.0, 0.0],
             opacity,
             border_gradient: border_gradient_paint,
+            pixel_snap,
+            hairline_edges,
             ..Default::default()
         };
         border.set_border_side_colors(
-            self.border_colors.left.as_ref().to_rgba_f32(),
-            self.border_colors.right.as_ref().to_rgba_f32(),
-            self.border_colors.top.as_ref().to_rgba_f32(),
-            self.border_colors.bottom.as_ref().to_rgba_f32(),
+            self.border_colors.left.as_ref().to_output_rgba_f32(),
+            self.border_colors.right.as_ref().to_output_rgba_f32(),
+            self.border_colors.top.as_ref().to_output_rgba_f32(),
+            self.border_colors.bottom.as_ref().to_output_rgba_f32(),
         );
         border.set_border_widths(left, right, top, bottom);
         border.set_border_radii(outer_radii.to_array());
@@ -881,6 +973,14 @@ impl Element {
         {
             return Err(LegacyPaintReason::Transform);
         }
+        // Nine-patch border images only have a legacy pass so far.
+        if self.computed_style.border_nine_patch.is_some() {
+            return Err(LegacyPaintReason::BorderImage);
+        }
+        // Blend modes and filters composite through a legacy layer.
+        if self.has_layer_effect() {
+            return Err(LegacyPaintReason::LayerEffect);
+        }
         if self.inline_ifc_owned_by_root {
             return Err(LegacyPaintReason::InlineIfc);
         }
@@ -1034,6 +1134,12 @@ impl Element {
         {
             return Some(ShadowPaintBlocker::BoxShadow);
         }
+        if self.computed_style.border_nine_patch.is_some() {
+            return Some(ShadowPaintBlocker::BorderImage);
+        }
+        if self.has_layer_effect() {
+            return Some(ShadowPaintBlocker::LayerEffect);
+        }
         if self.inline_ifc_owned_by_root {
             return Some(ShadowPaintBlocker::InlineIfc);
         }
@@ -1124,7 +1230,7 @@ impl Element {
                 {
                     return None;
                 }
-                let color = shadow.color.to_rgba_f32();
+                let color = shadow.color.to_output_rgba_f32();
                 if color
                     .iter()
                     .any(|channel| !channel.is_finite() || !(0.0..=1.0).contains(channel))
@@ -1578,6 +1684,8 @@ impl Element {
         self.background_color = Box::new(self.computed_style.background_color);
         self.foreground_color = self.computed_style.color;
         self.box_shadows = self.computed_style.box_shadow.clone();
+        self.sync_border_image_handle();
+        self.sync_mask_image_handle();
         self.transform = self.computed_style.transform.clone();
         self.transform_origin = self.computed_style.transform_origin;
         self.border_colors.left = Box::new(self.computed_style.border_colors.left);
@@ -1639,30 +1747,45 @@ impl Element {
         self.opacity = self.computed_style.opacity.clamp(0.0, 1.0);
         self.update_resolved_transform();
         self.scroll_direction = self.computed_style.scroll_direction;
-        self.padding.left = resolve_px(
-            self.computed_style.padding.left,
-            self.core.size.width,
-            0.0,
-            0.0,
-        );
-        self.padding.right = resolve_px(
-            self.computed_style.padding.right,
-            self.core.size.width,
-            0.0,
-            0.0,
-        );
-        self.padding.top = resolve_px(
-            self.computed_style.padding.top,
-            self.core.size.height,
-            0.0,
-            0.0,
-        );
-        self.padding.bottom = resolve_px(
-            self.computed_style.padding.bottom,
-            self.core.size.height,
-            0.0,
-            0.0,
-        );
+        let padding = self.transitioned_padding();
+        self.padding.left = resolve_px(padding.left, self.core.size.width, 0.0, 0.0);
+        self.padding.right = resolve_px(padding.right, self.core.size.width, 0.0, 0.0);
+        self.padding.top = resolve_px(padding.top, self.core.size.height, 0.0, 0.0);
+        self.padding.bottom = resolve_px(padding.bottom, self.core.size.height, 0.0, 0.0);
+        let (gutter_right, gutter_bottom) = self.scrollbar_gutter_insets();
+        self.padding.right += gutter_right;
+        self.padding.bottom += gutter_bottom;
+    }
+
+    fn sync_border_image_handle(&mut self) {
+        let source = self
+            .computed_style
+            .border_nine_patch
+            .as_ref()
+            .map(|nine_patch| &nine_patch.source);
+        sync_image_handle(&mut self.border_image_handle, source);
+    }
+
+    fn border_image_snapshot(&self) -> Option<ImageSnapshot> {
+        held_image_snapshot(&self.border_image_handle)
+    }
+
+    /// Upload for the nine-patch border image once it has loaded; `None`
+    /// while loading or failed, so only the regular decorations paint.
+    fn border_image_upload(&self) -> Option<(SampledTextureUpload, [f32; 2])> {
+        ready_image_upload(self.border_image_snapshot()?)
+    }
+
+    fn sync_mask_image_handle(&mut self) {
+        let source = match &self.computed_style.mask {
+            Some(crate::style::Mask::Image(source)) => Some(source),
+            _ => None,
+        };
+        sync_image_handle(&mut self.mask_image_handle, source);
+    }
+
+    fn mask_image_snapshot(&self) -> Option<ImageSnapshot> {
+        held_image_snapshot(&self.mask_image_handle)
     }
 
     fn update_resolved_transform(&mut self) {
@@ -1752,14 +1875,14 @@ impl Element {
                     height: self.layout_state.layout_size.height.max(0.0),
                 }]
             };
-        let shadows = self.box_shadows.clone();
-        for fragment in fragment_rects {
+        let mut shadow_passes = Vec::new();
+        for (fragment_index, fragment) in fragment_rects.into_iter().enumerate() {
             if fragment.width <= 0.0 || fragment.height <= 0.0 {
                 continue;
             }
             let outer_radii =
                 normalize_corner_radii(self.border_radii, fragment.width, fragment.height);
-            for shadow in shadows.iter().cloned() {
+            for (shadow_index, shadow) in self.box_shadows.iter().enumerate() {
                 let spread = shadow.spread;
                 let shadow_radii = expand_corner_radii_for_spread(
                     outer_radii,
@@ -1780,38 +1903,46 @@ impl Element {
                     offset_x: shadow.offset_x,
                     offset_y: shadow.offset_y,
                     blur_radius: shadow.blur.max(0.0),
-                    color: shadow.color.to_rgba_f32(),
+                    color: shadow.color.to_output_rgba_f32(),
                     opacity: opacity.clamp(0.0, 1.0),
                     spread: 0.0,
                     clip_to_geometry: shadow.inset,
                 };
-                let next_state = self.push_shadow_pass(
-                    mesh,
-                    params,
-                    graph,
-                    UiBuildContext::from_parts(ctx.viewport(), ctx.state_clone()),
+                let raster_cache = ctx.shadow_raster_slot(
+                    self.stable_id(),
+                    ("box-shadow", fragment_index, shadow_index),
                 );
-                ctx.set_state(next_state);
+                shadow_passes.push((mesh, params, raster_cache));
             }
         }
+        let next_state = self.push_shadow_passes(
+            shadow_passes,
+            graph,
+            UiBuildContext::from_parts(ctx.viewport(), ctx.state_clone()),
+        );
+        ctx.set_state(next_state);
         ctx.into_state()
     }
 
-    fn push_shadow_pass(
+    /// Emits `shadows` into the current target in order; compatible ones
+    /// share a layer (see [`build_shadow_batch_module`]).
+    pub(super) fn push_shadow_passes(
         &mut self,
-        mesh: ShadowMesh,
-        params: ShadowParams,
+        shadows: Vec<(ShadowMesh, ShadowParams, Option<ShadowRasterCacheSlot>)>,
         graph: &mut FrameGraph,
         mut ctx: UiBuildContext,
     ) -> BuildState {
+        if shadows.is_empty() {
+            return ctx.into_state();
+        }
         self.ensure_current_render_target(graph, &mut ctx);
         let output = ctx
             .current_target()
             .unwrap_or_else(|| ctx.allocate_target(graph));
         ctx.set_current_target(output);
-        let built = build_shadow_module(
-            graph,
-            ShadowModuleSpec {
+        let specs = shadows
+            .into_iter()
+            .map(|(mesh, params, raster_cache)| ShadowModuleSpec {
                 mesh,
                 params,
                 viewport_width: ctx.viewport.target_width,
@@ -1819,9 +1950,10 @@ impl Element {
                 scale_factor: ctx.viewport.scale_factor,
                 pass_context: ctx.graphics_pass_context(),
                 output,
-            },
-        );
-        if built {
+                raster_cache,
+            })
+            .collect();
+        if build_shadow_batch_module(graph, specs) {
             ctx.set_current_target(output);
         }
         ctx.into_state()
@@ -1968,6 +2100,7 @@ impl Element {
             layer_ctx,
             force_self_opaque,
             false,
+            false,
         );
         ctx.state.merge_child_render_state(&layer_state);
 
@@ -1996,6 +2129,225 @@ impl Element {
         ctx.into_state()
     }
 
+    fn has_layer_effect(&self) -> bool {
+        self.computed_style.mix_blend_mode != crate::style::MixBlendMode::Normal
+            || self
+                .computed_style
+                .filter
+                .iter()
+                .any(|filter| !filter.is_identity())
+            || self.computed_style.mask.is_some()
+    }
+
+    /// Renders the subtree into a viewport-sized layer, then composites that
+    /// layer through the element's `filter` list, `mask` and
+    /// `mix_blend_mode`.
+    fn build_layer_effect_subtree(
+        &mut self,
+        graph: &mut FrameGraph,
+        arena: &mut crate::view::node_arena::NodeArena,
+        mut ctx: UiBuildContext,
+        force_self_opaque: bool,
+        allow_transform: bool,
+    ) -> BuildState {
+        let mut layer_ctx = UiBuildContext::from_parts(
+            ctx.viewport(),
+            ctx.layer_subtree_state_with_ancestor_clip(AncestorClipContext::default()),
+        );
+        layer_ctx.set_current_render_transform(ctx.current_render_transform());
+        let layer_target = layer_ctx.allocate_target(graph);
+        layer_ctx.set_current_target(layer_target);
+        graph.add_graphics_pass(crate::view::frame_graph::ClearPass::new(
+            crate::view::render_pass::clear_pass::ClearParams::new([0.0, 0.0, 0.0, 0.0]),
+            crate::view::render_pass::clear_pass::ClearInput {
+                pass_context: layer_ctx.graphics_pass_context(),
+                clear_depth_stencil: true,
+            },
+            crate::view::render_pass::clear_pass::ClearOutput {
+                render_target: layer_target,
+            },
+        ));
+        let layer_state = self.build_base_descendants_only_inner(
+            graph,
+            arena,
+            layer_ctx,
+            force_self_opaque,
+            allow_transform,
+            false,
+        );
+        ctx.state.merge_child_render_state(&layer_state);
+        let Some(mut source) = layer_target.handle() else {
+            return ctx.into_state();
+        };
+
+        let blur_radius =
+            filter_blur_radius(&self.computed_style.filter) * ctx.viewport.scale_factor();
+        if blur_radius > 0.0
+            && let Some(desc) = graph.texture_desc(source)
+        {
+            let blurred: RenderTargetOut = graph.declare_texture(
+                desc.clone()
+                    .with_sample_count(1)
+                    .with_label("Layer Effect / Blurred"),
+            );
+            let built = build_blur_module(
+                graph,
+                BlurModuleParams {
+                    blur_radius,
+                    intermediate_format: desc.format(),
+                },
+                BlurModuleInput {
+                    layer: LayerIn::with_handle(source),
+                    pass_context: Default::default(),
+                },
+                BlurModuleOutput {
+                    render_target: blurred,
+                },
+            );
+            if built && let Some(handle) = blurred.handle() {
+                source = handle;
+            }
+        }
+
+        let mask = self
+            .computed_style
+            .mask
+            .as_ref()
+            .map(|mask| self.build_mask_layer(graph, &ctx, mask));
+
+        let parent_target = ctx.current_target().unwrap_or_else(|| {
+            let target = ctx.allocate_target(graph);
+            ctx.set_current_target(target);
+            target
+        });
+        let effect = CompositeLayerEffect::new(
+            self.computed_style.mix_blend_mode,
+            &self.computed_style.filter,
+        );
+        // Blend modes read what is already in the parent target, so copy it
+        // aside first; the composite cannot sample its own attachment.
+        let mut backdrop = LayerIn::default();
+        if effect.needs_backdrop()
+            && let Some(parent_handle) = parent_target.handle()
+            && let Some(desc) = graph.texture_desc(parent_handle)
+        {
+            let backdrop_target: RenderTargetOut = graph.declare_texture(
+                desc.with_sample_count(1)
+                    .with_label("Layer Effect / Backdrop"),
+            );
+            if let Some(backdrop_handle) = backdrop_target.handle() {
+                graph.add_graphics_pass(LayerBackdropCopyPass::new(
+                    LayerIn::with_handle(parent_handle),
+                    backdrop_target,
+                ));
+                backdrop = LayerIn::with_handle(backdrop_handle);
+            }
+        }
+
+        let viewport = ctx.viewport();
+        let mut composite = CompositeLayerPass::new(
+            CompositeLayerParams {
+                rect_pos: [0.0, 0.0],
+                rect_size: [
+                    viewport.target_width() as f32 / viewport.scale_factor(),
+                    viewport.target_height() as f32 / viewport.scale_factor(),
+                ],
+                corner_radii: [0.0; 4],
+                opacity: 1.0,
+                scissor_rect: None,
+                clear_target: false,
+            },
+            CompositeLayerInput {
+                layer: LayerIn::with_handle(source),
+                pass_context: ctx.graphics_pass_context(),
+            },
+            CompositeLayerOutput {
+                render_target: parent_target,
+            },
+        );
+        composite.set_effect(effect, backdrop);
+        if let Some(mask) = mask {
+            composite.set_mask(mask);
+        }
+        graph.add_graphics_pass(composite);
+        ctx.set_current_target(parent_target);
+        ctx.into_state()
+    }
+
+    /// Draws `mask` stretched over the border box into a layer matching the
+    /// effect layer. An image that has not loaded leaves the layer clear,
+    /// hiding the element. The element's own transform is not applied.
+    fn build_mask_layer(
+        &self,
+        graph: &mut FrameGraph,
+        ctx: &UiBuildContext,
+        mask: &crate::style::Mask,
+    ) -> LayerIn {
+        let mut mask_ctx = UiBuildContext::from_parts(
+            ctx.viewport(),
+            ctx.layer_subtree_state_with_ancestor_clip(AncestorClipContext::default()),
+        );
+        let target = mask_ctx.allocate_target(graph);
+        mask_ctx.set_current_target(target);
+        graph.add_graphics_pass(crate::view::frame_graph::ClearPass::new(
+            crate::view::render_pass::clear_pass::ClearParams::new([0.0, 0.0, 0.0, 0.0]),
+            crate::view::render_pass::clear_pass::ClearInput {
+                pass_context: mask_ctx.graphics_pass_context(),
+                clear_depth_stencil: true,
+            },
+            crate::view::render_pass::clear_pass::ClearOutput {
+                render_target: target,
+            },
+        ));
+        let [offset_x, offset_y] = ctx.paint_offset();
+        let width = self.layout_state.layout_size.width.max(0.0);
+        let height = self.layout_state.layout_size.height.max(0.0);
+        let position = [
+            self.layout_state.layout_position.x + offset_x,
+            self.layout_state.layout_position.y + offset_y,
+        ];
+        match mask {
+            crate::style::Mask::Gradient(gradient) => {
+                let mut pass = DrawRectPass::new(
+                    RectPassParams {
+                        position,
+                        size: [width, height],
+                        fill_color: [0.0, 0.0, 0.0, 0.0],
+                        opacity: 1.0,
+                        gradient: Some(resolve_gradient_paint(gradient, width, height)),
+                        ..Default::default()
+                    },
+                    DrawRectInput::default(),
+                    DrawRectOutput::default(),
+                );
+                pass.set_render_mode(RectRenderMode::FillOnly);
+                mask_ctx.emit_draw_rect_pass(graph, pass);
+            }
+            crate::style::Mask::Image(source) => {
+                if let Some((upload, image_size)) =
+                    self.mask_image_snapshot().and_then(ready_image_upload)
+                {
+                    let quads = nine_patch_quads(
+                        [position[0], position[1], width, height],
+                        image_size,
+                        &crate::style::NinePatch::new(source.clone(), 0.0),
+                    );
+                    graph.add_graphics_pass(NinePatchPass::new(
+                        quads,
+                        1.0,
+                        upload,
+                        mask_ctx.graphics_pass_context(),
+                        target,
+                    ));
+                }
+            }
+        }
+        target
+            .handle()
+            .map(LayerIn::with_handle)
+            .unwrap_or_default()
+    }
+
     pub(crate) fn build_base_only(
         &mut self,
         graph: &mut FrameGraph,
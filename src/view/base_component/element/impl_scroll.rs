@@ -21,8 +21,35 @@ impl Element {
         )
     }
 
+    fn scrollbar_metrics(&self) -> ScrollbarMetrics {
+        let style = &self.computed_style.scrollbar;
+        let thickness = style.resolved_width();
+        let gutter = match style.resolved_mode() {
+            ScrollbarMode::Overlay => 0.0,
+            ScrollbarMode::Gutter => thickness + SCROLLBAR_MARGIN * 2.0,
+        };
+        ScrollbarMetrics { thickness, gutter }
+    }
+
+    /// Extra trailing (right, bottom) padding reserved for gutter-mode
+    /// scrollbars on each configured scroll axis, whether or not it overflows.
+    fn scrollbar_gutter_insets(&self) -> (f32, f32) {
+        let gutter = self.scrollbar_metrics().gutter;
+        if gutter <= 0.0 {
+            return (0.0, 0.0);
+        }
+        match self.computed_style.scroll_direction {
+            ScrollDirection::None => (0.0, 0.0),
+            ScrollDirection::Vertical => (gutter, 0.0),
+            ScrollDirection::Horizontal => (0.0, gutter),
+            ScrollDirection::Both => (gutter, gutter),
+        }
+    }
+
     fn scrollbar_visibility_alpha(&self) -> f32 {
-        if self.scrollbar_interaction_pending {
+        if self.scrollbar_interaction_pending
+            || self.computed_style.scrollbar.auto_hide_timing().is_none()
+        {
             1.0
         } else {
             self.sampled_scrollbar_alpha
@@ -30,8 +57,13 @@ impl Element {
     }
 
     fn tick_scrollbar_visibility(&mut self, now: Instant) -> bool {
-        const HOLD: Duration = Duration::from_millis(900);
-        const FADE: Duration = Duration::from_millis(350);
+        let scrollbar = &self.computed_style.scrollbar;
+        let auto_hide = scrollbar.auto_hide_timing().map(|(hold_ms, fade_ms)| {
+            (
+                Duration::from_millis(u64::from(hold_ms)),
+                Duration::from_millis(u64::from(fade_ms)),
+            )
+        });
 
         let lifecycle_changed = self.scrollbar_interaction_pending;
         if lifecycle_changed {
@@ -54,21 +86,29 @@ impl Element {
             0.0
         } else if forced_opaque {
             1.0
-        } else if let Some(last) = self.last_scrollbar_interaction {
-            let elapsed = now.duration_since(last);
-            if elapsed <= HOLD {
-                1.0
-            } else {
-                let fade_elapsed = elapsed - HOLD;
-                if fade_elapsed >= FADE {
-                    self.last_scrollbar_interaction = None;
-                    0.0
-                } else {
-                    1.0 - (fade_elapsed.as_secs_f32() / FADE.as_secs_f32())
+        } else if let Some((hold, fade)) = auto_hide {
+            match self.last_scrollbar_interaction {
+                Some(last) => {
+                    let elapsed = now.duration_since(last);
+                    if elapsed <= hold {
+                        1.0
+                    } else {
+                        let fade_elapsed = elapsed - hold;
+                        if fade_elapsed >= fade {
+                            self.last_scrollbar_interaction = None;
+                            0.0
+                        } else {
+                            1.0 - (fade_elapsed.as_secs_f32() / fade.as_secs_f32())
+                        }
+                    }
                 }
+                None => 0.0,
             }
         } else {
-            0.0
+            // Never auto-hides: nothing to fade, so drop the timestamp that
+            // would otherwise keep requesting animation frames.
+            self.last_scrollbar_interaction = None;
+            1.0
         };
         let changed = lifecycle_changed
             || self.sampled_scrollbar_alpha.to_bits() != next_alpha.to_bits();
@@ -87,9 +127,10 @@ impl Element {
             self.scroll_direction,
             ScrollDirection::Vertical | ScrollDirection::Both
         ) && max_scroll_y > 0.0;
+        let metrics = self.scrollbar_metrics();
 
         if can_scroll_y {
-            if let Some((track, thumb)) = canonical_vertical_scrollbar_geometry(
+            if let Some((track, thumb)) = vertical_scrollbar_geometry(
                 Rect {
                     x: inner_x,
                     y: inner_y,
@@ -99,6 +140,7 @@ impl Element {
                 self.layout_state.content_size.height,
                 self.scroll_offset.y,
                 can_scroll_x,
+                metrics,
             ) {
                 geometry.vertical_track = Some(track);
                 geometry.vertical_thumb = Some(thumb);
@@ -106,7 +148,7 @@ impl Element {
         }
 
         if can_scroll_x {
-            if let Some((track, thumb)) = canonical_horizontal_scrollbar_geometry(
+            if let Some((track, thumb)) = horizontal_scrollbar_geometry(
                 Rect {
                     x: inner_x,
                     y: inner_y,
@@ -116,6 +158,7 @@ impl Element {
                 self.layout_state.content_size.width,
                 self.scroll_offset.x,
                 can_scroll_y,
+                metrics,
             ) {
                 geometry.horizontal_track = Some(track);
                 geometry.horizontal_thumb = Some(thumb);
@@ -396,6 +439,7 @@ impl Element {
         rect: Rect,
         border_radius: f32,
         color: [f32; 4],
+        slot: u8,
     ) -> BuildState {
         let [rect_x, rect_y] = ctx.paint_point(rect.x, rect.y);
         let mesh = ShadowMesh::rounded_rect(
@@ -415,9 +459,9 @@ impl Element {
             clip_to_geometry: true,
         };
 
-        let next_state = self.push_shadow_pass(
-            mesh,
-            params,
+        let raster_cache = ctx.shadow_raster_slot(self.stable_id(), ("scrollbar-shadow", slot));
+        let next_state = self.push_shadow_passes(
+            vec![(mesh, params, raster_cache)],
             graph,
             UiBuildContext::from_parts(ctx.viewport(), ctx.state_clone()),
         );
@@ -434,21 +478,33 @@ impl Element {
         const THUMB_SHADOW_ALPHA: f32 = 0.5;
         let geometry =
             self.scrollbar_geometry(self.layout_state.layout_inner_position.x, self.layout_state.layout_inner_position.y);
-        let track_alpha = (0.35 * alpha).clamp(0.0, 1.0);
-        let thumb_alpha = (0.58 * alpha).clamp(0.0, 1.0);
+        let scrollbar_style = self.computed_style.scrollbar;
+        let scrollbar_color = |color: Option<Color>, default_alpha: f32| {
+            let [r, g, b, a] = color.map_or([0.95, 0.95, 0.95, default_alpha], |color| {
+                color.to_output_rgba_f32()
+            });
+            [r, g, b, (a * alpha).clamp(0.0, 1.0)]
+        };
         let track_shadow_alpha = (TRACK_SHADOW_ALPHA * alpha).clamp(0.0, 1.0);
         let thumb_shadow_alpha = (THUMB_SHADOW_ALPHA * alpha).clamp(0.0, 1.0);
         let track_shadow_color = [0.0, 0.0, 0.0, track_shadow_alpha];
         let thumb_shadow_color = [0.0, 0.0, 0.0, thumb_shadow_alpha];
-        let track_color = [0.95, 0.95, 0.95, track_alpha];
-        let thumb_color = [0.95, 0.95, 0.95, thumb_alpha];
+        let track_color = scrollbar_color(scrollbar_style.resolved_track_color(), 0.35);
+        let thumb_color = scrollbar_color(scrollbar_style.resolved_thumb_color(), 0.58);
+        let radius = |rect: Rect| {
+            scrollbar_style
+                .resolved_thumb_radius()
+                .min(rect.width.min(rect.height) * 0.5)
+                .max(0.0)
+        };
         if let Some(track) = geometry.vertical_track {
             let shadow_state = self.render_scrollbar_shadow(
                 graph,
                 UiBuildContext::from_parts(ctx.viewport(), ctx.state_clone()),
                 track,
-                (track.width * 0.5).max(0.0),
+                radius(track),
                 track_shadow_color,
+                0,
             );
             ctx.set_state(shadow_state);
 
@@ -464,7 +520,7 @@ impl Element {
                 DrawRectOutput::default(),
             );
             pass.set_border_width(0.0);
-            pass.set_border_radius((track.width * 0.5).max(0.0));
+            pass.set_border_radius(radius(track));
             self.push_rect_pass_auto(graph, &mut ctx, pass);
         }
         if let Some(track) = geometry.horizontal_track {
@@ -472,8 +528,9 @@ impl Element {
                 graph,
                 UiBuildContext::from_parts(ctx.viewport(), ctx.state_clone()),
                 track,
-                (track.height * 0.5).max(0.0),
+                radius(track),
                 track_shadow_color,
+                1,
             );
             ctx.set_state(shadow_state);
 
@@ -489,7 +546,7 @@ impl Element {
                 DrawRectOutput::default(),
             );
             pass.set_border_width(0.0);
-            pass.set_border_radius((track.height * 0.5).max(0.0));
+            pass.set_border_radius(radius(track));
             self.push_rect_pass_auto(graph, &mut ctx, pass);
         }
         if let Some(thumb) = geometry.vertical_thumb {
@@ -497,8 +554,9 @@ impl Element {
                 graph,
                 UiBuildContext::from_parts(ctx.viewport(), ctx.state_clone()),
                 thumb,
-                (thumb.width * 0.5).max(0.0),
+                radius(thumb),
                 thumb_shadow_color,
+                2,
             );
             ctx.set_state(shadow_state);
 
@@ -514,7 +572,7 @@ impl Element {
                 DrawRectOutput::default(),
             );
             pass.set_border_width(0.0);
-            pass.set_border_radius((thumb.width * 0.5).max(0.0));
+            pass.set_border_radius(radius(thumb));
             self.push_rect_pass_auto(graph, &mut ctx, pass);
         }
         if let Some(thumb) = geometry.horizontal_thumb {
@@ -522,8 +580,9 @@ impl Element {
                 graph,
                 UiBuildContext::from_parts(ctx.viewport(), ctx.state_clone()),
                 thumb,
-                (thumb.height * 0.5).max(0.0),
+                radius(thumb),
                 thumb_shadow_color,
+                3,
             );
             ctx.set_state(shadow_state);
 
@@ -539,7 +598,7 @@ impl Element {
                 DrawRectOutput::default(),
             );
             pass.set_border_width(0.0);
-            pass.set_border_radius((thumb.height * 0.5).max(0.0));
+            pass.set_border_radius(radius(thumb));
             self.push_rect_pass_auto(graph, &mut ctx, pass);
         }
         ctx.into_state()
@@ -597,6 +656,58 @@ impl Element {
             .push(Box::new(handler));
     }
 
+    /// Fire `handler` once the primary button has been held on this element
+    /// for `duration` without moving beyond the click slop radius. The click
+    /// that would follow the release is suppressed.
+    pub fn on_long_press<F>(&mut self, duration: Duration, handler: F)
+    where
+        F: FnMut(&mut crate::ui::LongPressEvent, &mut ViewportControl<'_>) + 'static,
+    {
+        self.set_long_press_duration(duration);
+        self.event_handlers
+            .get_or_insert_with(Default::default)
+            .long_press
+            .push(Box::new(handler));
+    }
+
+    /// Hold time `on_long_press` handlers wait for; defaults to
+    /// [`DEFAULT_LONG_PRESS_DURATION`].
+    pub fn long_press_duration(&self) -> Duration {
+        self.event_handlers
+            .as_deref()
+            .and_then(|handlers| handlers.long_press_duration)
+            .unwrap_or(DEFAULT_LONG_PRESS_DURATION)
+    }
+
+    pub fn set_long_press_duration(&mut self, duration: Duration) {
+        self.event_handlers
+            .get_or_insert_with(Default::default)
+            .long_press_duration = Some(duration);
+    }
+
+    /// Hold back hover changes on this element: it gains hover only after
+    /// the pointer rested on it for the enter delay and keeps it for the
+    /// exit delay, or while the pointer heads for an anchored popup.
+    pub fn set_hover_intent(&mut self, intent: Option<crate::view::viewport::HoverIntent>) {
+        if intent.is_none() && self.event_handlers.is_none() {
+            return;
+        }
+        self.event_handlers
+            .get_or_insert_with(Default::default)
+            .hover_intent = intent;
+    }
+
+    /// Let arrow keys move focus between the interactive descendants of
+    /// this element by on-screen position, for TV and kiosk style UIs.
+    pub fn set_spatial_navigation(&mut self, enabled: bool) {
+        if !enabled && self.event_handlers.is_none() {
+            return;
+        }
+        self.event_handlers
+            .get_or_insert_with(Default::default)
+            .spatial_navigation = enabled;
+    }
+
     pub fn on_wheel<F>(&mut self, handler: F)
     where
         F: FnMut(&mut crate::ui::WheelEvent, &mut ViewportControl<'_>) + 'static,
@@ -745,6 +856,307 @@ impl Element {
             .push(Box::new(handler));
     }
 
+    pub fn on_scroll<F>(&mut self, handler: F)
+    where
+        F: FnMut(&mut crate::ui::ScrollEvent) + 'static,
+    {
+        self.event_handlers
+            .get_or_insert_with(Default::default)
+            .scroll
+            .push(Box::new(handler));
+    }
+
+    /// Fire `handler` whenever the scroll offset comes within `threshold`
+    /// logical pixels of the end of the scrollable range.
+    pub fn on_reach_end<F>(&mut self, threshold: f32, handler: F)
+    where
+        F: FnMut(&mut crate::ui::ReachEndEvent) + 'static,
+    {
+        self.set_reach_end_threshold(threshold);
+        self.event_handlers
+            .get_or_insert_with(Default::default)
+            .reach_end
+            .push(Box::new(handler));
+    }
+
+    /// Fire `handler` whenever the visible fraction of this element's box
+    /// crosses `threshold` (0 to 1), and once for the first layout it sees.
+    pub fn on_visibility_change<F>(&mut self, threshold: f32, handler: F)
+    where
+        F: FnMut(&mut crate::ui::VisibilityChangeEvent) + 'static,
+    {
+        self.set_visibility_threshold(threshold);
+        self.event_handlers
+            .get_or_insert_with(Default::default)
+            .visibility_change
+            .push(Box::new(handler));
+    }
+
+    /// Fire `handler` after layout whenever this element's laid-out size
+    /// changes, and once for the first layout it sees.
+    pub fn on_resize<F>(&mut self, handler: F)
+    where
+        F: FnMut(&mut crate::ui::ResizeEvent) + 'static,
+    {
+        self.event_handlers
+            .get_or_insert_with(Default::default)
+            .resize
+            .push(Box::new(handler));
+    }
+
+    /// Fire `handler` once every track started by the latest staggered
+    /// wave of this element's [`crate::transition::TransitionGroup`] has
+    /// finished or been cancelled.
+    pub fn on_transition_group_end<F>(&mut self, handler: F)
+    where
+        F: FnMut(&mut crate::ui::TransitionGroupEndEvent) + 'static,
+    {
+        self.event_handlers
+            .get_or_insert_with(Default::default)
+            .transition_group_end
+            .push(Box::new(handler));
+    }
+
+    pub(crate) fn dispatch_transition_group_end(&mut self, member_count: usize) {
+        let Some(handlers) = self.event_handlers.as_deref_mut() else {
+            return;
+        };
+        let mut event = crate::ui::TransitionGroupEndEvent {
+            meta: crate::ui::EventMeta::new(crate::ui::NodeId::default()),
+            member_count,
+        };
+        for handler in &mut handlers.transition_group_end {
+            handler(&mut event);
+        }
+    }
+
+    /// Fire `handler` when a style, layout, or position transition track
+    /// on this element starts.
+    pub fn on_transition_start<F>(&mut self, handler: F)
+    where
+        F: FnMut(&mut crate::ui::TransitionEvent) + 'static,
+    {
+        self.event_handlers
+            .get_or_insert_with(Default::default)
+            .transition_start
+            .push(Box::new(handler));
+    }
+
+    /// Fire `handler` when a transition track on this element reaches its
+    /// target value.
+    pub fn on_transition_end<F>(&mut self, handler: F)
+    where
+        F: FnMut(&mut crate::ui::TransitionEvent) + 'static,
+    {
+        self.event_handlers
+            .get_or_insert_with(Default::default)
+            .transition_end
+            .push(Box::new(handler));
+    }
+
+    /// Fire `handler` when a transition track on this element is removed
+    /// or retargeted before it finished.
+    pub fn on_transition_cancel<F>(&mut self, handler: F)
+    where
+        F: FnMut(&mut crate::ui::TransitionEvent) + 'static,
+    {
+        self.event_handlers
+            .get_or_insert_with(Default::default)
+            .transition_cancel
+            .push(Box::new(handler));
+    }
+
+    pub(crate) fn dispatch_transition_lifecycle(
+        &mut self,
+        phase: crate::transition::TrackPhase,
+        property: crate::style::TransitionProperty,
+    ) {
+        let Some(handlers) = self.event_handlers.as_deref_mut() else {
+            return;
+        };
+        let handlers = match phase {
+            crate::transition::TrackPhase::Start => &mut handlers.transition_start,
+            crate::transition::TrackPhase::End => &mut handlers.transition_end,
+            crate::transition::TrackPhase::Cancel => &mut handlers.transition_cancel,
+        };
+        let mut event = crate::ui::TransitionEvent {
+            meta: crate::ui::EventMeta::new(crate::ui::NodeId::default()),
+            property,
+        };
+        for handler in handlers {
+            handler(&mut event);
+        }
+    }
+
+    pub fn visibility_threshold(&self) -> f32 {
+        self.event_handlers
+            .as_deref()
+            .map_or(0.0, |handlers| handlers.visibility_threshold)
+    }
+
+    pub fn set_visibility_threshold(&mut self, threshold: f32) {
+        let handlers = self.event_handlers.get_or_insert_with(Default::default);
+        handlers.visibility_threshold = threshold.clamp(0.0, 1.0);
+    }
+
+    pub fn reach_end_threshold(&self) -> f32 {
+        self.event_handlers
+            .as_deref()
+            .map_or(0.0, |handlers| handlers.reach_end_threshold)
+    }
+
+    /// Re-arms `on_reach_end` only when the threshold actually changes, so
+    /// re-applying the same props does not fire again for the same extent.
+    pub fn set_reach_end_threshold(&mut self, threshold: f32) {
+        let handlers = self.event_handlers.get_or_insert_with(Default::default);
+        let threshold = threshold.max(0.0);
+        if !approx_eq(handlers.reach_end_threshold, threshold) {
+            handlers.reach_end_threshold = threshold;
+            handlers.reach_end_fired_extent = None;
+        }
+    }
+
+    /// Deliver `on_scroll` / `on_reach_end` for offset changes since the
+    /// previous frame. Runs from the post-layout tick, so wheel input,
+    /// scrollbar drags, and programmatic scrolls are reported alike and
+    /// against the final content extent.
+    fn dispatch_scroll_notifications(&mut self) {
+        let offset = (self.scroll_offset.x, self.scroll_offset.y);
+        let (max_scroll_x, max_scroll_y) = self.max_scroll();
+        let direction = self.scroll_direction;
+        let Some(handlers) = self.event_handlers.as_deref_mut() else {
+            return;
+        };
+        if handlers.reach_end.is_empty() {
+            // A handler installed later starts fresh. Replacing one on
+            // re-render (clear + assign) finishes before this tick runs.
+            handlers.reach_end_fired_extent = None;
+        }
+        if handlers.scroll.is_empty() && handlers.reach_end.is_empty() {
+            return;
+        }
+
+        if !approx_eq(offset.0, handlers.reported_scroll_offset.0)
+            || !approx_eq(offset.1, handlers.reported_scroll_offset.1)
+        {
+            handlers.reported_scroll_offset = offset;
+            let mut event = crate::ui::ScrollEvent {
+                meta: crate::ui::EventMeta::new(crate::ui::NodeId::default()),
+                scroll_x: offset.0,
+                scroll_y: offset.1,
+                max_scroll_x,
+                max_scroll_y,
+            };
+            for handler in &mut handlers.scroll {
+                handler(&mut event);
+            }
+        }
+
+        if handlers.reach_end.is_empty() {
+            return;
+        }
+        // `Both` watches whichever axis actually overflows, falling back to
+        // the block axis when the content fits entirely.
+        let (watch_x, watch_y) = match direction {
+            ScrollDirection::None => return,
+            ScrollDirection::Horizontal => (true, false),
+            ScrollDirection::Vertical => (false, true),
+            ScrollDirection::Both => (
+                max_scroll_x > 0.0,
+                max_scroll_y > 0.0 || max_scroll_x <= 0.0,
+            ),
+        };
+        let threshold = handlers.reach_end_threshold;
+        let reached = (watch_x && max_scroll_x - offset.0 <= threshold)
+            || (watch_y && max_scroll_y - offset.1 <= threshold);
+        if !reached {
+            handlers.reach_end_fired_extent = None;
+            return;
+        }
+        let extent = (max_scroll_x, max_scroll_y);
+        if handlers
+            .reach_end_fired_extent
+            .is_some_and(|fired| approx_eq(fired.0, extent.0) && approx_eq(fired.1, extent.1))
+        {
+            return;
+        }
+        handlers.reach_end_fired_extent = Some(extent);
+        let mut event = crate::ui::ReachEndEvent {
+            meta: crate::ui::EventMeta::new(crate::ui::NodeId::default()),
+            scroll_x: offset.0,
+            scroll_y: offset.1,
+            max_scroll_x,
+            max_scroll_y,
+            threshold,
+        };
+        for handler in &mut handlers.reach_end {
+            handler(&mut event);
+        }
+    }
+
+    /// Deliver `on_resize` when the laid-out size differs from the one last
+    /// reported. Runs from the post-layout tick, so it sees the settled
+    /// frame size, layout transitions included.
+    fn dispatch_resize_notifications(&mut self) {
+        let (width, height) = self.current_layout_frame_size();
+        let Some(handlers) = self.event_handlers.as_deref_mut() else {
+            return;
+        };
+        if handlers.resize.is_empty() {
+            return;
+        }
+        let previous = handlers.reported_size;
+        if previous.is_some_and(|(previous_width, previous_height)| {
+            approx_eq(width, previous_width) && approx_eq(height, previous_height)
+        }) {
+            return;
+        }
+        handlers.reported_size = Some((width, height));
+        let (previous_width, previous_height) = previous.unwrap_or((0.0, 0.0));
+        let mut event = crate::ui::ResizeEvent {
+            meta: crate::ui::EventMeta::new(crate::ui::NodeId::default()),
+            width,
+            height,
+            previous_width,
+            previous_height,
+        };
+        for handler in &mut handlers.resize {
+            handler(&mut event);
+        }
+    }
+
+    /// Deliver `on_visibility_change` when `ratio` puts the element on the
+    /// other side of its threshold than last reported. Called by the
+    /// viewport after layout with the fraction its scroll-container
+    /// ancestors and the viewport leave visible.
+    pub(crate) fn dispatch_visibility_change(&mut self, ratio: f32) {
+        let Some(handlers) = self.event_handlers.as_deref_mut() else {
+            return;
+        };
+        if handlers.visibility_change.is_empty() {
+            return;
+        }
+        let threshold = handlers.visibility_threshold;
+        let visible = if threshold <= 0.0 {
+            ratio > 0.0
+        } else {
+            ratio >= threshold
+        };
+        if handlers.reported_visible == Some(visible) {
+            return;
+        }
+        handlers.reported_visible = Some(visible);
+        let mut event = crate::ui::VisibilityChangeEvent {
+            meta: crate::ui::EventMeta::new(crate::ui::NodeId::default()),
+            visible,
+            ratio,
+            threshold,
+        };
+        for handler in &mut handlers.visibility_change {
+            handler(&mut event);
+        }
+    }
+
     /// Clear the per-event handler list matching a canonical RSX prop
     /// name (`on_pointer_down`, `on_click`, …). Returns `true` if the
     /// prop name maps to a known event bucket (even if that bucket was
@@ -771,6 +1183,7 @@ impl Element {
                     | "on_pointer_leave"
                     | "on_click"
                     | "on_context_menu"
+                    | "on_long_press"
                     | "on_wheel"
                     | "on_key_down"
                     | "on_key_up"
@@ -787,6 +1200,14 @@ impl Element {
                     | "on_copy"
                     | "on_cut"
                     | "on_paste"
+                    | "on_scroll"
+                    | "on_reach_end"
+                    | "on_resize"
+                    | "on_visibility_change"
+                    | "on_transition_group_end"
+                    | "on_transition_start"
+                    | "on_transition_end"
+                    | "on_transition_cancel"
             );
         };
         match prop {
@@ -797,6 +1218,7 @@ impl Element {
             "on_pointer_leave" => handlers.pointer_leave.clear(),
             "on_click" => handlers.click.clear(),
             "on_context_menu" => handlers.context_menu.clear(),
+            "on_long_press" => handlers.long_press.clear(),
             "on_wheel" => handlers.wheel.clear(),
             "on_key_down" => handlers.key_down.clear(),
             "on_key_up" => handlers.key_up.clear(),
@@ -813,6 +1235,14 @@ impl Element {
             "on_copy" => handlers.copy.clear(),
             "on_cut" => handlers.cut.clear(),
             "on_paste" => handlers.paste.clear(),
+            "on_scroll" => handlers.scroll.clear(),
+            "on_reach_end" => handlers.reach_end.clear(),
+            "on_resize" => handlers.resize.clear(),
+            "on_visibility_change" => handlers.visibility_change.clear(),
+            "on_transition_group_end" => handlers.transition_group_end.clear(),
+            "on_transition_start" => handlers.transition_start.clear(),
+            "on_transition_end" => handlers.transition_end.clear(),
+            "on_transition_cancel" => handlers.transition_cancel.clear(),
             _ => return false,
         }
         true
@@ -835,6 +1265,7 @@ impl Element {
             "on_pointer_leave" => handlers.pointer_leave.len(),
             "on_click" => handlers.click.len(),
             "on_context_menu" => handlers.context_menu.len(),
+            "on_long_press" => handlers.long_press.len(),
             "on_wheel" => handlers.wheel.len(),
             "on_key_down" => handlers.key_down.len(),
             "on_key_up" => handlers.key_up.len(),
@@ -851,6 +1282,14 @@ impl Element {
             "on_copy" => handlers.copy.len(),
             "on_cut" => handlers.cut.len(),
             "on_paste" => handlers.paste.len(),
+            "on_scroll" => handlers.scroll.len(),
+            "on_reach_end" => handlers.reach_end.len(),
+            "on_resize" => handlers.resize.len(),
+            "on_visibility_change" => handlers.visibility_change.len(),
+            "on_transition_group_end" => handlers.transition_group_end.len(),
+            "on_transition_start" => handlers.transition_start.len(),
+            "on_transition_end" => handlers.transition_end.len(),
+            "on_transition_cancel" => handlers.transition_cancel.len(),
             _ => 0,
         }
     }
@@ -903,6 +1342,10 @@ impl Element {
         self.computed_style.position.clip_mode()
     }
 
+    pub(crate) fn ignores_pointer_for_hit_test(&self) -> bool {
+        self.computed_style.pointer_events == crate::style::PointerEvents::None
+    }
+
     pub(crate) fn should_append_to_root_viewport_render(&self) -> bool {
         self.computed_style.position.mode() == PositionMode::Absolute
             && self.computed_style.position.clip_mode() == ClipMode::Viewport
@@ -75,9 +75,18 @@ impl Layoutable for Element {
             DirtyPassMask::LAYOUT,
             LayoutGateCandidatePhase::Measure,
         );
-        if !self.layout_dirty && !child_layout_dirty && self.last_layout_proposal == Some(proposal)
-        {
-            return;
+        if !self.layout_dirty && !child_layout_dirty {
+            if self.last_layout_proposal == Some(proposal) {
+                return;
+            }
+            if self.replay_cached_measure(proposal, arena) {
+                with_layout_place_profile(|p| p.measure_cache_hits += 1);
+                self.last_layout_proposal = Some(proposal);
+                self.dirty_flags = self.dirty_flags.without(DirtyPassMask::LAYOUT);
+                return;
+            }
+        } else {
+            self.measure_cache.clear();
         }
 
         with_layout_place_profile(|p| {
@@ -107,6 +116,7 @@ impl Layoutable for Element {
             }
         });
 
+        let cache_key = self.measure_cache_key(proposal);
         self.measure_self(proposal);
         self.apply_size_constraints(proposal, false);
 
@@ -119,14 +129,7 @@ impl Layoutable for Element {
         if is_axis_layout {
             self.measure_flex_children(proposal, arena);
         } else {
-            let insets = resolve_layout_insets(
-                &self.computed_style.border_widths,
-                &self.computed_style.padding,
-                proposal.percent_base_width,
-                proposal.percent_base_height,
-                proposal.viewport_width,
-                proposal.viewport_height,
-            );
+            let insets = self.resolved_layout_insets(proposal);
 
             let sizes = self.resolve_layout_sizes(proposal);
             let layout_w = sizes.target.width;
@@ -224,6 +227,7 @@ impl Layoutable for Element {
             }
         }
         self.apply_size_constraints(proposal, true);
+        self.store_measure_cache(cache_key, arena);
 
         self.last_layout_proposal = Some(proposal);
         self.layout_dirty = false;
@@ -368,6 +372,18 @@ impl Layoutable for Element {
         (size.width, size.height)
     }
 
+    fn last_measure_constraints(&self) -> Option<LayoutConstraints> {
+        let proposal = self.last_layout_proposal?;
+        Some(LayoutConstraints {
+            max_width: proposal.width,
+            max_height: proposal.height,
+            viewport_width: proposal.viewport_width,
+            viewport_height: proposal.viewport_height,
+            percent_base_width: proposal.percent_base_width,
+            percent_base_height: proposal.percent_base_height,
+        })
+    }
+
     fn layout_target_size(&self) -> (f32, f32) {
         self.current_layout_target_size()
     }
@@ -7,21 +7,24 @@ use super::{
 };
 use crate::style::ColorLike;
 use crate::style::{
-    Align, AnchorName, BoxShadow, ClipMode, Collision, CollisionBoundary, Color, ComputedStyle,
-    Cursor, FlowDirection, FlowWrap, JustifyContent, Layout, Length, PositionMode, ScrollDirection,
-    SizeValue, Style, StyleComputeContext, TextWrap, Transform, TransformKind, TransformOrigin,
-    TransitionProperty, TransitionTiming, VerticalAlign, compute_style_with_context,
+    Align, AnchorAlign, AnchorName, AnchorPlacement, AppRegion, BoxShadow, ClipMode, Collision,
+    CollisionBoundary, Color, ComputedStyle, Cursor, FlowDirection, FlowWrap, JustifyContent,
+    Layout, Length, PositionMode, ScrollDirection, ScrollbarMode, ScrollbarStyle, SizeValue, Style,
+    StyleComputeContext, TextWrap, Transform, TransformKind, TransformOrigin, TransitionProperty,
+    TransitionTiming, VerticalAlign, compute_style_with_context,
     interpolate_transform_with_reference_box,
 };
 use crate::transition::{
     AnimationRequest, CHANNEL_LAYOUT_HEIGHT, CHANNEL_LAYOUT_WIDTH, CHANNEL_STYLE_BACKGROUND_COLOR,
     CHANNEL_STYLE_BORDER_BOTTOM_COLOR, CHANNEL_STYLE_BORDER_LEFT_COLOR,
     CHANNEL_STYLE_BORDER_RADIUS, CHANNEL_STYLE_BORDER_RIGHT_COLOR, CHANNEL_STYLE_BORDER_TOP_COLOR,
-    CHANNEL_STYLE_BOX_SHADOW, CHANNEL_STYLE_COLOR, CHANNEL_STYLE_OPACITY, CHANNEL_STYLE_TRANSFORM,
-    CHANNEL_STYLE_TRANSFORM_ORIGIN, CHANNEL_VISUAL_X, CHANNEL_VISUAL_Y, ChannelId, LayoutField,
-    LayoutTrackRequest, LayoutTransition as RuntimeLayoutTransition, ScrollAxis, StyleField,
-    StyleTrackRequest, StyleTransition as RuntimeStyleTransition, StyleValue, TimeFunction,
-    VisualField, VisualTrackRequest, VisualTransition as RuntimeVisualTransition,
+    CHANNEL_STYLE_BOX_SHADOW, CHANNEL_STYLE_COLOR, CHANNEL_STYLE_GAP, CHANNEL_STYLE_OPACITY,
+    CHANNEL_STYLE_PADDING_BOTTOM, CHANNEL_STYLE_PADDING_LEFT, CHANNEL_STYLE_PADDING_RIGHT,
+    CHANNEL_STYLE_PADDING_TOP, CHANNEL_STYLE_TRANSFORM, CHANNEL_STYLE_TRANSFORM_ORIGIN,
+    CHANNEL_VISUAL_X, CHANNEL_VISUAL_Y, ChannelId, LayoutField, LayoutTrackRequest,
+    LayoutTransition as RuntimeLayoutTransition, ScrollAxis, StyleField, StyleTrackRequest,
+    StyleTransition as RuntimeStyleTransition, StyleValue, TimeFunction, VisualField,
+    VisualTrackRequest, VisualTransition as RuntimeVisualTransition,
 };
 use crate::ui::{
     BlurEvent, ClickEvent, FocusEvent, KeyDownEvent, KeyUpEvent, PointerButton as UiPointerButton,
@@ -34,6 +37,9 @@ use crate::view::frame_graph::texture_resource::TextureHandle;
 use crate::view::frame_graph::{
     AttachmentTarget, FrameGraph, PersistentTextureKey, RetainedTextureRole, TextureDesc,
 };
+use crate::view::image_resource::{
+    ImageHandle, ImageSnapshot, acquire_image_resource, snapshot_image,
+};
 use crate::view::inline_formatting_context::{
     InlineFormattingContext, InlineIfcAtomicBoxPlacement, InlineIfcAtomicBoxPlacementPackage,
     InlineIfcAtomicMeasureConstraints, InlineIfcAtomicSizingRules, InlineIfcCacheKey,
@@ -49,15 +55,26 @@ use crate::view::inline_formatting_context::{
 #[cfg(test)]
 use crate::view::inline_text_pass_adapter::inline_ifc_paint_input_to_text_pass_staging_input;
 use crate::view::node_arena::{NodeArena, NodeKey};
+use crate::view::render_pass::blur_module::{
+    BlurModuleInput, BlurModuleOutput, BlurModuleParams, build_blur_module,
+};
+use crate::view::render_pass::composite_layer_pass::{
+    CompositeLayerEffect, CompositeLayerInput, CompositeLayerOutput, CompositeLayerParams,
+    CompositeLayerPass, LayerBackdropCopyPass, LayerIn, filter_blur_radius,
+};
 use crate::view::render_pass::draw_rect_pass::DrawRectInput;
 use crate::view::render_pass::draw_rect_pass::{DrawRectOutput, RectPassParams};
 use crate::view::render_pass::draw_rect_pass::{RenderTargetIn, RenderTargetOut, RenderTargetTag};
+use crate::view::render_pass::nine_patch_pass::{NinePatchPass, nine_patch_quads};
 use crate::view::render_pass::render_target::GraphicsPassContext;
+use crate::view::render_pass::shadow_module::ShadowRasterFrame;
 use crate::view::render_pass::{
     DrawRectPass, GraphicsPass, OpaqueRectPass, RectRenderMode, ShadowMesh, ShadowModuleSpec,
-    ShadowParams, build_shadow_module,
+    ShadowParams, ShadowRasterCacheSlot, build_shadow_batch_module,
 };
+use crate::view::sampled_texture::{SampledTextureAlphaMode, SampledTextureUpload};
 use crate::view::viewport::ViewportControl;
+use crate::view::{ImageSampling, ImageSource};
 use glam::{Mat4, Vec3, Vec4};
 use slotmap::Key;
 use std::cell::RefCell;
@@ -133,6 +150,41 @@ fn emit_draw_rect_io_pass<P: GraphicsPass + DrawRectIoPass + 'static>(
     ctx.set_current_target(input);
 }
 
+/// Keeps `slot` holding a resource for `source`, reacquiring only when the
+/// source changes.
+fn sync_image_handle(slot: &mut Option<(ImageSource, ImageHandle)>, source: Option<&ImageSource>) {
+    match (source, &*slot) {
+        (Some(source), Some((held, _))) if held == source => {}
+        (Some(source), _) => *slot = Some((source.clone(), acquire_image_resource(source))),
+        (None, _) => *slot = None,
+    }
+}
+
+fn held_image_snapshot(slot: &Option<(ImageSource, ImageHandle)>) -> Option<ImageSnapshot> {
+    let (_, handle) = slot.as_ref()?;
+    snapshot_image(handle.asset_id())
+}
+
+/// Straight-alpha upload of a loaded image and its size in image pixels.
+fn ready_image_upload(snapshot: ImageSnapshot) -> Option<(SampledTextureUpload, [f32; 2])> {
+    let ImageSnapshot::Ready(image) = snapshot else {
+        return None;
+    };
+    let size = [image.width as f32, image.height as f32];
+    let upload = SampledTextureUpload {
+        id: image.sampled_texture_id,
+        generation: image.generation,
+        width: image.width,
+        height: image.height,
+        format: wgpu::TextureFormat::Rgba8UnormSrgb,
+        alpha_mode: SampledTextureAlphaMode::Straight,
+        pixels: image.pixels,
+        sampling: ImageSampling::Linear,
+    };
+    upload.validate_rgba8()?;
+    Some((upload, size))
+}
+
 #[derive(Clone, Copy, Debug)]
 struct EdgeInsets {
     left: f32,
@@ -151,6 +203,17 @@ struct LayoutProposal {
     percent_base_height: Option<f32>,
 }
 
+/// What a measure leaves behind on an element, memoized per proposal. The
+/// constraints each child was last measured with are kept so a cache hit
+/// can replay them and leave the subtree in the same state.
+#[derive(Clone, Debug)]
+struct MeasuredLayout {
+    size: Size,
+    content_size: Size,
+    flex_info: Option<crate::view::layout::FlexLayoutInfo>,
+    child_constraints: Vec<(NodeKey, LayoutConstraints)>,
+}
+
 #[derive(Clone, Copy, Debug)]
 struct LayoutFrame {
     x: f32,
@@ -308,6 +371,22 @@ pub(crate) const SCROLLBAR_THICKNESS: f32 = 6.0;
 pub(crate) const SCROLLBAR_MARGIN: f32 = 3.0;
 pub(crate) const SCROLLBAR_MIN_THUMB: f32 = 24.0;
 
+/// Per-element scrollbar sizing resolved from [`crate::style::ScrollbarStyle`].
+/// `gutter` is the layout space reserved past the viewport's trailing edge;
+/// zero for overlay scrollbars.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub(crate) struct ScrollbarMetrics {
+    pub thickness: f32,
+    pub gutter: f32,
+}
+
+impl ScrollbarMetrics {
+    pub(crate) const LEGACY: Self = Self {
+        thickness: SCROLLBAR_THICKNESS,
+        gutter: 0.0,
+    };
+}
+
 /// Reconstructs the exact legacy vertical scrollbar geometry without reading
 /// element state or wall-clock time. Both legacy paint and retained-scroll
 /// validation use this helper so the compiler cannot accept a merely
@@ -317,26 +396,46 @@ pub(crate) fn canonical_vertical_scrollbar_geometry(
     content_height: f32,
     scroll_offset_y: f32,
     reserve_horizontal_scrollbar: bool,
+) -> Option<(Rect, Rect)> {
+    vertical_scrollbar_geometry(
+        viewport,
+        content_height,
+        scroll_offset_y,
+        reserve_horizontal_scrollbar,
+        ScrollbarMetrics::LEGACY,
+    )
+}
+
+/// Vertical track/thumb for arbitrary metrics. With a gutter the track sits
+/// inside the reserved strip right of `viewport` and no longer shortens
+/// itself for a horizontal bar, which has its own gutter below.
+pub(crate) fn vertical_scrollbar_geometry(
+    viewport: Rect,
+    content_height: f32,
+    scroll_offset_y: f32,
+    reserve_horizontal_scrollbar: bool,
+    metrics: ScrollbarMetrics,
 ) -> Option<(Rect, Rect)> {
     let max_scroll_y = (content_height - viewport.height).max(0.0);
     if max_scroll_y <= 0.0 {
         return None;
     }
-    let reserve_h = if reserve_horizontal_scrollbar {
-        SCROLLBAR_THICKNESS + SCROLLBAR_MARGIN
+    let thickness = metrics.thickness;
+    let reserve_h = if reserve_horizontal_scrollbar && metrics.gutter <= 0.0 {
+        thickness + SCROLLBAR_MARGIN
     } else {
         0.0
     };
-    let track_x = viewport.x + viewport.width - SCROLLBAR_THICKNESS - SCROLLBAR_MARGIN;
+    let track_x = viewport.x + viewport.width + metrics.gutter - thickness - SCROLLBAR_MARGIN;
     let track_y = viewport.y + SCROLLBAR_MARGIN;
     let track_h = (viewport.height - SCROLLBAR_MARGIN * 2.0 - reserve_h).max(0.0);
-    if track_h <= 0.0 {
+    if track_h <= 0.0 || thickness <= 0.0 {
         return None;
     }
     let track = Rect {
         x: track_x,
         y: track_y,
-        width: SCROLLBAR_THICKNESS,
+        width: thickness,
         height: track_h,
     };
     let ratio = (viewport.height / content_height.max(1.0)).clamp(0.0, 1.0);
@@ -366,27 +465,45 @@ pub(crate) fn canonical_horizontal_scrollbar_geometry(
     content_width: f32,
     scroll_offset_x: f32,
     reserve_vertical_scrollbar: bool,
+) -> Option<(Rect, Rect)> {
+    horizontal_scrollbar_geometry(
+        viewport,
+        content_width,
+        scroll_offset_x,
+        reserve_vertical_scrollbar,
+        ScrollbarMetrics::LEGACY,
+    )
+}
+
+/// Horizontal counterpart of [`vertical_scrollbar_geometry`].
+pub(crate) fn horizontal_scrollbar_geometry(
+    viewport: Rect,
+    content_width: f32,
+    scroll_offset_x: f32,
+    reserve_vertical_scrollbar: bool,
+    metrics: ScrollbarMetrics,
 ) -> Option<(Rect, Rect)> {
     let max_scroll_x = (content_width - viewport.width).max(0.0);
     if max_scroll_x <= 0.0 {
         return None;
     }
-    let reserve_v = if reserve_vertical_scrollbar {
-        SCROLLBAR_THICKNESS + SCROLLBAR_MARGIN
+    let thickness = metrics.thickness;
+    let reserve_v = if reserve_vertical_scrollbar && metrics.gutter <= 0.0 {
+        thickness + SCROLLBAR_MARGIN
     } else {
         0.0
     };
     let track_x = viewport.x + SCROLLBAR_MARGIN;
-    let track_y = viewport.y + viewport.height - SCROLLBAR_THICKNESS - SCROLLBAR_MARGIN;
+    let track_y = viewport.y + viewport.height + metrics.gutter - thickness - SCROLLBAR_MARGIN;
     let track_w = (viewport.width - SCROLLBAR_MARGIN * 2.0 - reserve_v).max(0.0);
-    if track_w <= 0.0 {
+    if track_w <= 0.0 || thickness <= 0.0 {
         return None;
     }
     let track = Rect {
         x: track_x,
         y: track_y,
         width: track_w,
-        height: SCROLLBAR_THICKNESS,
+        height: thickness,
     };
     let ratio = (viewport.width / content_width.max(1.0)).clamp(0.0, 1.0);
     let thumb_w = (track_w * ratio).clamp(SCROLLBAR_MIN_THUMB.min(track_w), track_w);
@@ -1109,6 +1226,16 @@ struct AnchorSnapshot {
     parent_clip_rect: Rect,
 }
 
+/// Bounds a `position: sticky` child is clamped against, in flow space.
+/// `containing_rect` is the parent's content box (the full scrolled content
+/// for a scroll host); `scroll_viewport` is the visible inner rect of the
+/// nearest scroll container, `None` when no ancestor scrolls.
+#[derive(Clone, Copy, Debug)]
+struct StickyScope {
+    containing_rect: Rect,
+    scroll_viewport: Option<Rect>,
+}
+
 #[derive(Default)]
 struct PlacementRuntime {
     depth: usize,
@@ -1118,6 +1245,7 @@ struct PlacementRuntime {
     ancestor_stack: Vec<AnchorSnapshot>,
     child_clip_stack: Vec<Rect>,
     hit_test_clip_stack: Vec<Rect>,
+    sticky_scope_stack: Vec<StickyScope>,
 }
 
 thread_local! {
@@ -1160,6 +1288,8 @@ pub(crate) struct LayoutPlaceProfile {
     pub measure_ran_self_dirty: usize,
     pub measure_ran_child_dirty: usize,
     pub measure_ran_proposal_changed: usize,
+    /// Proposal changes answered from the per-node measure cache.
+    pub measure_cache_hits: usize,
     pub proposal_changed_size: usize,
     pub proposal_changed_viewport: usize,
     pub proposal_changed_percent_base: usize,
@@ -1566,6 +1696,8 @@ pub(crate) struct FramePreparation {
     deferred_nodes: Vec<DeferredRenderNode>,
     deferred_seen: FxHashSet<crate::view::node_arena::NodeKey>,
     deferred_cursor: usize,
+    /// Shadow rasters kept across frames; absent outside viewport frames.
+    shadow_rasters: Option<Arc<Mutex<ShadowRasterFrame>>>,
 }
 
 impl FramePreparation {
@@ -1720,6 +1852,46 @@ pub(crate) fn texture_desc_for_logical_bounds(
         .with_origin(origin_x, origin_y)
 }
 
+/// Upper bound on how much denser than the viewport a transformed layer is
+/// rasterized. Beyond this the texture cost outweighs the sharpness gain.
+const MAX_LAYER_RASTER_SCALE: f32 = 4.0;
+
+/// Extra raster density for a layer composited through `render_transform`.
+///
+/// A layer drawn at the viewport scale and then magnified by its transform
+/// samples glyphs and edges below their displayed size. Rasterizing at the
+/// transform's largest axis scale keeps them sharp. The scale is rounded up to
+/// quarter steps so a scale animation re-rasterizes in a few discrete steps
+/// instead of every frame, and is capped so the texture stays within the
+/// default device limits.
+pub(crate) fn transformed_layer_raster_scale(
+    bounds: RetainedSurfaceBounds,
+    scale_factor: f32,
+    render_transform: Option<Mat4>,
+) -> f32 {
+    let Some(transform) = render_transform else {
+        return 1.0;
+    };
+    let axis_scale = transform
+        .x_axis
+        .truncate()
+        .truncate()
+        .length()
+        .max(transform.y_axis.truncate().truncate().length());
+    if !axis_scale.is_finite() || axis_scale <= 1.0 {
+        return 1.0;
+    }
+    let max_extent = bounds.width.max(bounds.height).max(1.0) * scale_factor.max(0.0001);
+    let max_dimension = wgpu::Limits::default().max_texture_dimension_2d as f32;
+    let fits = (max_dimension / max_extent).max(1.0);
+    // The epsilon keeps float noise in rotated matrices from bumping an exact
+    // quarter step to the next one.
+    ((axis_scale * 4.0 - 1.0e-3).ceil() / 4.0)
+        .min(MAX_LAYER_RASTER_SCALE)
+        .min(fits)
+        .max(1.0)
+}
+
 pub(crate) fn label_for_persistent_target(stable_key: PersistentTextureKey) -> String {
     match stable_key {
         PersistentTextureKey::Retained {
@@ -1858,12 +2030,18 @@ impl UiBuildContext {
         stable_key: PersistentTextureKey,
         bounds: RetainedSurfaceBounds,
     ) -> RenderTargetOut {
-        let desc = texture_desc_for_logical_bounds(
+        let raster_scale = transformed_layer_raster_scale(
             bounds,
             self.viewport.scale_factor,
             self.viewport.render_transform,
-            self.viewport.target_format,
         );
+        let desc = texture_desc_for_logical_bounds(
+            bounds,
+            self.viewport.scale_factor * raster_scale,
+            self.viewport.render_transform,
+            self.viewport.target_format,
+        )
+        .with_raster_scale(raster_scale);
         self.next_persistent_target_with_desc(graph, desc, stable_key)
     }
 
@@ -2103,6 +2281,32 @@ impl UiBuildContext {
             .next_deferred()
     }
 
+    pub(crate) fn set_shadow_raster_frame(&mut self, rasters: Arc<Mutex<ShadowRasterFrame>>) {
+        self.state
+            .frame_preparation
+            .lock()
+            .expect("frame preparation lock poisoned")
+            .shadow_rasters = Some(rasters);
+    }
+
+    /// The cross-frame raster slot for shadow `slot` of the node `stable_id`.
+    pub(crate) fn shadow_raster_slot(
+        &self,
+        stable_id: u64,
+        slot: impl Hash,
+    ) -> Option<ShadowRasterCacheSlot> {
+        let rasters = self
+            .state
+            .frame_preparation
+            .lock()
+            .expect("frame preparation lock poisoned")
+            .shadow_rasters
+            .clone()?;
+        let mut hasher = DefaultHasher::new();
+        (stable_id, slot).hash(&mut hasher);
+        Some(ShadowRasterCacheSlot::new(hasher.finish(), rasters))
+    }
+
     pub(crate) fn next_opaque_rect_order(&mut self) -> u32 {
         let order = self.state.dfs_opaque_rect_order;
         self.state.dfs_opaque_rect_order = self.state.dfs_opaque_rect_order.saturating_add(1);
@@ -2118,7 +2322,10 @@ impl UiBuildContext {
             .replay_opaque_rect_order_exact(expected_start, terminal);
     }
 
-    pub(crate) fn graphics_pass_context(&self) -> GraphicsPassContext {
+    /// Clip state for passes added at this point of the build. Custom hosts
+    /// pass it to their composites, or build pipelines from it, so their
+    /// output clips to ancestor rounded corners like built-in content.
+    pub fn graphics_pass_context(&self) -> GraphicsPassContext {
         GraphicsPassContext {
             scissor_rect: self.scissor_rect(),
             stencil_clip_id: self.active_clip_id(),
@@ -2534,6 +2741,12 @@ pub trait Layoutable {
     );
     fn place(&mut self, placement: LayoutPlacement, arena: &mut crate::view::node_arena::NodeArena);
     fn measured_size(&self) -> (f32, f32);
+    /// Constraints of the last completed `measure`, if the host tracks
+    /// them. A parent only memoizes its own measure when every child
+    /// reports these, since a cache hit replays them.
+    fn last_measure_constraints(&self) -> Option<LayoutConstraints> {
+        None
+    }
     fn layout_target_size(&self) -> (f32, f32) {
         self.measured_size()
     }
@@ -2612,6 +2825,14 @@ pub trait EventTarget {
         _self_key: crate::view::node_arena::NodeKey,
     ) {
     }
+    fn dispatch_long_press(
+        &mut self,
+        _event: &mut crate::ui::LongPressEvent,
+        _control: &mut ViewportControl<'_>,
+        _arena: &crate::view::node_arena::NodeArena,
+        _self_key: crate::view::node_arena::NodeKey,
+    ) {
+    }
     fn dispatch_wheel(
         &mut self,
         _event: &mut crate::ui::WheelEvent,
@@ -2800,6 +3021,41 @@ pub trait EventTarget {
     fn cursor(&self) -> Cursor {
         Cursor::Default
     }
+    fn app_region(&self) -> AppRegion {
+        AppRegion::Auto
+    }
+    /// How long a press must be held before `dispatch_long_press` fires, or
+    /// `None` when this target does not handle long presses.
+    fn long_press_hold(&self) -> Option<Duration> {
+        None
+    }
+    /// Enter/exit delays and safe triangle the viewport applies before this
+    /// target gains or loses hover.
+    fn hover_intent(&self) -> Option<crate::view::viewport::HoverIntent> {
+        None
+    }
+    /// Whether arrow keys pressed inside this container move focus to the
+    /// geometrically nearest focusable descendant.
+    fn spatial_navigation(&self) -> bool {
+        false
+    }
+    /// Whether spatial navigation may move focus onto this target.
+    fn spatial_focusable(&self) -> bool {
+        false
+    }
+    /// Whether the viewport should report this target's visible fraction
+    /// through `dispatch_visibility` after layout.
+    fn observes_visibility(&self) -> bool {
+        false
+    }
+    /// Receive the visible fraction of this target's border box after
+    /// layout; only called when `observes_visibility` is true.
+    fn dispatch_visibility(&mut self, _ratio: f32) {}
+    /// Viewport-space rect a scroll container clips its content to, or
+    /// `None` when this target does not scroll.
+    fn scrollport_rect(&self) -> Option<crate::ui::Rect> {
+        None
+    }
     fn wants_animation_frame(&self) -> bool {
         false
     }
@@ -2841,6 +3097,8 @@ pub trait ElementTypeName {
 pub enum ShadowPaintBlocker {
     Transform,
     BoxShadow,
+    BorderImage,
+    LayerEffect,
     InlineIfc,
     ScrollContainer,
     SelfClip,
@@ -3207,6 +3465,17 @@ pub trait ElementTrait:
         RetainedPaintProperties::default()
     }
 
+    /// Whether this node's opacity can be premultiplied into its own pass
+    /// colors instead of compositing through an isolated layer.
+    ///
+    /// Only a node that paints as one draw with no descendants may answer
+    /// `true`: group opacity and per-draw opacity agree exactly when nothing
+    /// inside the group overlaps.
+    #[doc(hidden)]
+    fn bakes_opacity_into_self_paint(&self) -> bool {
+        false
+    }
+
     /// Whether this native host admits the exact single-root opacity artifact
     /// contract. The viewport still validates the complete property-tree,
     /// child topology, recording identity, resources, and generations before
@@ -3650,13 +3919,33 @@ pub trait ElementTrait:
         parent.without_text_area_child_authority()
     }
 
-    // Phase B: `snapshot_state` / `restore_state` removed. The
-    // host-state save/restore hack lived here so the cold-rebuild
-    // pipeline could re-seed Element-internal state (scroll offset,
-    // hover, layout-transition snapshots, …) on every render. With
-    // the incremental-commit path keeping Element instances alive
-    // across renders, the hack is dead weight on the happy path;
-    // remaining fallback paths accept the documented state loss.
+    /// Capture interaction state (caret, selection, IME composition, …)
+    /// that a replacement instance cannot rebuild from props. The
+    /// incremental commit keeps instances alive, so this only runs
+    /// around the paths that still mint a fresh element for the same
+    /// `stable_id`: the full-rebuild fallback and cross-parent keyed
+    /// moves. Scroll offsets and layout-transition snapshots are carried
+    /// separately. Default: nothing to keep.
+    fn snapshot_state(&self) -> Option<Box<dyn std::any::Any>> {
+        None
+    }
+
+    /// Re-apply a [`Self::snapshot_state`] value taken from the instance
+    /// this element replaces. Values must be clamped against the new
+    /// props. Returns the dirty flags the restore needs.
+    fn restore_state(&mut self, _snapshot: Box<dyn std::any::Any>) -> DirtyFlags {
+        DirtyFlags::NONE
+    }
+
+    /// Whether `media` style blocks need re-resolving against the context
+    /// the viewport just published.
+    fn media_style_stale(&self) -> bool {
+        false
+    }
+
+    /// Re-resolve `media` style blocks; only called when
+    /// [`Self::media_style_stale`] reports a flip.
+    fn refresh_media_style(&mut self) {}
 
     fn intercepts_pointer_at(&self, _viewport_x: f32, _viewport_y: f32) -> bool {
         false
@@ -3666,6 +3955,12 @@ pub trait ElementTrait:
         true
     }
 
+    /// Whether the point lies inside this element's `hit_shape`; only
+    /// asked once the point is inside the border box.
+    fn hit_shape_contains(&self, _viewport_x: f32, _viewport_y: f32) -> bool {
+        true
+    }
+
     fn is_deferred_to_root_viewport_render(&self) -> bool {
         false
     }
@@ -3937,6 +4232,12 @@ pub trait ElementTrait:
     ) {
     }
 
+    /// Runs once when the reconciler (or any other arena removal) drops
+    /// this element. The slot is already vacant, so the hook must not look
+    /// the element up again; it is the place to release handlers, cancel
+    /// tasks and hand GPU resources back through `ctx`. Default no-op.
+    fn on_removed(&mut self, _ctx: &mut crate::view::node_arena::RemovalContext<'_>) {}
+
     /// 軌 1 #14 Phase 5: build the descriptor list for this host's
     /// arena children. Default walks `node.children`, flattens
     /// fragments, and recurses through the adapter's
@@ -4661,7 +4962,14 @@ type PointerLeaveHandler = Box<dyn FnMut(&mut PointerLeaveEvent)>;
 type ClickHandler = Box<dyn FnMut(&mut ClickEvent, &mut ViewportControl<'_>)>;
 type ContextMenuHandler =
     Box<dyn FnMut(&mut crate::ui::ContextMenuEvent, &mut ViewportControl<'_>)>;
+type LongPressHandler = Box<dyn FnMut(&mut crate::ui::LongPressEvent, &mut ViewportControl<'_>)>;
 type WheelHandler = Box<dyn FnMut(&mut crate::ui::WheelEvent, &mut ViewportControl<'_>)>;
+type ScrollHandler = Box<dyn FnMut(&mut crate::ui::ScrollEvent)>;
+type ReachEndHandler = Box<dyn FnMut(&mut crate::ui::ReachEndEvent)>;
+type ResizeHandler = Box<dyn FnMut(&mut crate::ui::ResizeEvent)>;
+type VisibilityChangeHandler = Box<dyn FnMut(&mut crate::ui::VisibilityChangeEvent)>;
+type TransitionGroupEndHandler = Box<dyn FnMut(&mut crate::ui::TransitionGroupEndEvent)>;
+type TransitionHandler = Box<dyn FnMut(&mut crate::ui::TransitionEvent)>;
 type KeyDownHandler = Box<dyn FnMut(&mut KeyDownEvent, &mut ViewportControl<'_>)>;
 type KeyUpHandler = Box<dyn FnMut(&mut KeyUpEvent, &mut ViewportControl<'_>)>;
 type FocusHandler = Box<dyn FnMut(&mut FocusEvent, &mut ViewportControl<'_>)>;
@@ -4679,6 +4987,9 @@ type CopyHandler = Box<dyn FnMut(&mut crate::ui::CopyEvent, &mut ViewportControl
 type CutHandler = Box<dyn FnMut(&mut crate::ui::CutEvent, &mut ViewportControl<'_>)>;
 type PasteHandler = Box<dyn FnMut(&mut crate::ui::PasteEvent, &mut ViewportControl<'_>)>;
 
+/// Hold time `on_long_press` waits for when no duration was given.
+pub const DEFAULT_LONG_PRESS_DURATION: Duration = Duration::from_millis(500);
+
 /// Cold-path storage for event handlers. Boxed and lazily allocated so that
 /// elements without handlers pay only 8 bytes (the `Option<Box<_>>` pointer).
 #[derive(Default)]
@@ -4690,6 +5001,15 @@ struct ElementEventHandlers {
     pointer_leave: Vec<PointerLeaveHandler>,
     click: Vec<ClickHandler>,
     context_menu: Vec<ContextMenuHandler>,
+    long_press: Vec<LongPressHandler>,
+    /// Hold time set through `on_long_press` or the `long_press_duration`
+    /// prop; `None` falls back to [`DEFAULT_LONG_PRESS_DURATION`].
+    long_press_duration: Option<Duration>,
+    /// Delays and safe triangle applied before this element gains or loses
+    /// hover; `None` follows the pointer immediately.
+    hover_intent: Option<crate::view::viewport::HoverIntent>,
+    /// Arrow keys move focus between interactive descendants by geometry.
+    spatial_navigation: bool,
     wheel: Vec<WheelHandler>,
     key_down: Vec<KeyDownHandler>,
     key_up: Vec<KeyUpHandler>,
@@ -4706,6 +5026,28 @@ struct ElementEventHandlers {
     copy: Vec<CopyHandler>,
     cut: Vec<CutHandler>,
     paste: Vec<PasteHandler>,
+    scroll: Vec<ScrollHandler>,
+    reach_end: Vec<ReachEndHandler>,
+    reach_end_threshold: f32,
+    /// Offset last delivered to `scroll` handlers; the post-layout tick
+    /// fires them when the live offset has moved away from it.
+    reported_scroll_offset: (f32, f32),
+    /// Max-scroll extent `reach_end` last fired for. Cleared once the
+    /// offset leaves the threshold so the next approach fires again.
+    reach_end_fired_extent: Option<(f32, f32)>,
+    resize: Vec<ResizeHandler>,
+    /// Size last delivered to `resize` handlers; `None` until the first
+    /// delivery, which reports a zero previous size.
+    reported_size: Option<(f32, f32)>,
+    visibility_change: Vec<VisibilityChangeHandler>,
+    visibility_threshold: f32,
+    /// Visibility last delivered to `visibility_change` handlers; `None`
+    /// until the first delivery.
+    reported_visible: Option<bool>,
+    transition_group_end: Vec<TransitionGroupEndHandler>,
+    transition_start: Vec<TransitionHandler>,
+    transition_end: Vec<TransitionHandler>,
+    transition_cancel: Vec<TransitionHandler>,
 }
 
 /// Cold-path storage for pending transition/animation requests. Boxed and
@@ -5744,7 +6086,7 @@ impl ElementInlineIfcMetadataCollectorState<'_> {
 
     fn resolved_gap(&self, element: &Element) -> f32 {
         resolve_px(
-            element.computed_style.gap,
+            element.transitioned_gap(),
             self.max_width,
             self.viewport_width,
             self.viewport_height,
@@ -5817,6 +6159,8 @@ pub(crate) struct ElementStyleSnapshot {
     box_shadows: Vec<BoxShadow>,
     transform: Transform,
     transform_origin: TransformOrigin,
+    gap: f32,
+    padding: EdgeInsets,
 }
 
 #[derive(Clone, Debug)]
@@ -5862,6 +6206,16 @@ pub struct Element {
     parsed_style: Style,
     text_cascade_style: Option<Style>,
     computed_style: ComputedStyle,
+    /// `parsed_style` resolved without and with its hover sub-style,
+    /// indexed by the hovered flag. Cleared whenever `parsed_style` is
+    /// replaced, so hover toggles reuse them instead of recomputing.
+    computed_style_variants: [Option<ComputedStyle>; 2],
+    /// Which `media` blocks of `parsed_style` matched when the variants
+    /// were last cleared; a viewport change only restyles on a flip.
+    media_matches: Vec<bool>,
+    /// [`crate::style::ElevationScale`] generation `parsed_style` was
+    /// resolved against; `None` when it declares no `elevation`.
+    elevation_generation: Option<u64>,
     padding: EdgeInsets,
     background_color: Box<dyn ColorLike>,
     border_colors: EdgeColors,
@@ -5869,6 +6223,10 @@ pub struct Element {
     border_radii: CornerRadii,
     border_radius: f32,
     box_shadows: Vec<BoxShadow>,
+    /// Keeps the nine-patch border image loaded; reacquired only when the
+    /// computed source changes.
+    border_image_handle: Option<(ImageSource, ImageHandle)>,
+    mask_image_handle: Option<(ImageSource, ImageHandle)>,
     transform: Transform,
     transform_origin: TransformOrigin,
     resolved_transform: Option<Mat4>,
@@ -5885,12 +6243,17 @@ pub struct Element {
     inline_ifc_rollout_packages: ElementInlineIfcRolloutPackages,
     inline_ifc_layout_call_site: ElementInlineIfcLayoutCallSiteState,
     scrollbar_drag: Option<ScrollbarDragState>,
+    /// The last pointer press had its default prevented, so neither it nor
+    /// the click that follows is consumed by the scrollbar.
+    scrollbar_default_prevented: bool,
     last_scrollbar_interaction: Option<Instant>,
     scrollbar_interaction_pending: bool,
     sampled_scrollbar_alpha: f32,
     scrollbar_shadow_blur_radius: f32,
     transition_requests: Option<Box<ElementTransitionRequests>>,
     last_started_animator: Option<crate::style::Animator>,
+    /// Stagger applied to the tracks this element's children start.
+    transition_group: Option<crate::transition::TransitionGroup>,
     has_style_snapshot: bool,
     has_layout_snapshot: bool,
     layout_transition_visual_offset_x: f32,
@@ -5901,6 +6264,10 @@ pub struct Element {
     layout_transition_target_y: Option<f32>,
     layout_transition_target_width: Option<f32>,
     layout_transition_target_height: Option<f32>,
+    /// In-flight `gap` transition sample in px; overrides `computed_style.gap`.
+    style_transition_gap: Option<f32>,
+    /// In-flight per-side `padding` transition samples in px.
+    style_transition_padding: crate::style::EdgeInsets<Option<f32>>,
     last_parent_layout_x: f32,
     last_parent_layout_y: f32,
     layout_assigned_width: Option<f32>,
@@ -5911,6 +6278,8 @@ pub struct Element {
     dirty_flags: DirtyFlags,
     last_layout_placement: Option<LayoutPlacement>,
     last_layout_proposal: Option<LayoutProposal>,
+    measure_cache:
+        crate::view::layout::measure_cache::MeasureCache<(LayoutProposal, bool), MeasuredLayout>,
     flex_info: Option<crate::view::layout::FlexLayoutInfo>,
     has_absolute_descendant_for_hit_test: bool,
     absolute_clip_rect: Option<Rect>,
@@ -5954,7 +6323,10 @@ impl Element {
             && !self.should_append_to_root_viewport_render()
             && self.absolute_clip_scissor_rect().is_none()
             && !self.retained_paint_properties().has_rounded_clip
-            && self.computed_style.position.mode() != PositionMode::Absolute
+            && !matches!(
+                self.computed_style.position.mode(),
+                PositionMode::Absolute | PositionMode::Sticky
+            )
     }
 
     fn is_exact_retained_scroll_forest_neutral_wrapper(&self) -> bool {
@@ -5981,7 +6353,10 @@ impl Element {
             && !self.should_append_to_root_viewport_render()
             && self.absolute_clip_scissor_rect().is_none()
             && !self.retained_paint_properties().has_rounded_clip
-            && self.computed_style.position.mode() != PositionMode::Absolute
+            && !matches!(
+                self.computed_style.position.mode(),
+                PositionMode::Absolute | PositionMode::Sticky
+            )
     }
 
     /// Recorder-owned oracle for the only content root admitted by the A1
@@ -6602,7 +6977,10 @@ impl Element {
             || self.should_append_to_root_viewport_render()
             || self.absolute_clip_scissor_rect().is_some()
             || self.retained_paint_properties().has_rounded_clip
-            || self.computed_style.position.mode() == PositionMode::Absolute
+            || matches!(
+                self.computed_style.position.mode(),
+                PositionMode::Absolute | PositionMode::Sticky
+            )
             || parent_offset.iter().any(|value| !value.is_finite())
         {
             return None;
@@ -8119,7 +8497,7 @@ impl Element {
             crate::view::inline_formatting_context::InlineIfcPaintStyleKey {
                 brush: self.computed_style.background_color.to_rgba_u8(),
             },
-            self.background_color.as_ref().to_rgba_f32(),
+            self.background_color.as_ref().to_output_rgba_f32(),
             self.opacity,
             [
                 self.border_widths.left,
@@ -8128,10 +8506,10 @@ impl Element {
                 self.border_widths.bottom,
             ],
             [
-                self.border_colors.left.as_ref().to_rgba_f32(),
-                self.border_colors.right.as_ref().to_rgba_f32(),
-                self.border_colors.top.as_ref().to_rgba_f32(),
-                self.border_colors.bottom.as_ref().to_rgba_f32(),
+                self.border_colors.left.as_ref().to_output_rgba_f32(),
+                self.border_colors.right.as_ref().to_output_rgba_f32(),
+                self.border_colors.top.as_ref().to_output_rgba_f32(),
+                self.border_colors.bottom.as_ref().to_output_rgba_f32(),
             ],
         );
         InlineIfcElementDecorationPackageSource::new(source, insets, style)
@@ -8147,11 +8525,14 @@ impl Element {
         let [br_r, br_g, br_b, br_a] = self.border_colors.right.as_ref().to_rgba_u8();
         let [bb_r, bb_g, bb_b, bb_a] = self.border_colors.bottom.as_ref().to_rgba_u8();
         let [bl_r, bl_g, bl_b, bl_a] = self.border_colors.left.as_ref().to_rgba_u8();
+        let width = self.core.size.width;
+        let height = self.core.size.height;
+        let padding = self.transitioned_padding();
         ElementStyleSnapshot {
             opacity: self.opacity,
             border_radius: self.border_radius,
-            width: self.core.size.width,
-            height: self.core.size.height,
+            width,
+            height,
             background_color: Color::rgba(bg_r, bg_g, bg_b, bg_a),
             foreground_color: self.foreground_color,
             border_top_color: Color::rgba(bt_r, bt_g, bt_b, bt_a),
@@ -8161,6 +8542,13 @@ impl Element {
             box_shadows: self.box_shadows.clone(),
             transform: self.transform.clone(),
             transform_origin: self.transform_origin,
+            gap: resolve_px(self.transitioned_gap(), width, 0.0, 0.0),
+            padding: EdgeInsets {
+                left: resolve_px(padding.left, width, 0.0, 0.0),
+                right: resolve_px(padding.right, width, 0.0, 0.0),
+                top: resolve_px(padding.top, height, 0.0, 0.0),
+                bottom: resolve_px(padding.bottom, height, 0.0, 0.0),
+            },
         }
     }
 
@@ -8179,6 +8567,23 @@ impl Element {
         Some((mapped.x / w, mapped.y / w))
     }
 
+    /// Inverse of [`Self::map_viewport_to_paint_space`]; `None` without a
+    /// transform.
+    pub(crate) fn map_paint_to_viewport_space(
+        &self,
+        paint_x: f32,
+        paint_y: f32,
+    ) -> Option<(f32, f32)> {
+        let matrix = self.resolved_transform?;
+        let mapped = matrix * Vec4::new(paint_x, paint_y, 0.0, 1.0);
+        let w = if mapped.w.abs() <= 0.000_001 {
+            1.0
+        } else {
+            mapped.w
+        };
+        Some((mapped.x / w, mapped.y / w))
+    }
+
     fn transformed_bounding_rect_for_rect(&self, rect: Rect) -> Rect {
         let Some(matrix) = self.resolved_transform else {
             return rect;
@@ -8477,6 +8882,19 @@ impl ElementStyleSnapshot {
             StyleField::BoxShadow => StyleValue::BoxShadow(current.box_shadow.clone()),
             StyleField::Transform => StyleValue::Transform(current.transform.clone()),
             StyleField::TransformOrigin => StyleValue::TransformOrigin(current.transform_origin),
+            StyleField::Gap => StyleValue::Scalar(resolve_px(current.gap, self.width, 0.0, 0.0)),
+            StyleField::PaddingTop => {
+                StyleValue::Scalar(resolve_px(current.padding.top, self.height, 0.0, 0.0))
+            }
+            StyleField::PaddingRight => {
+                StyleValue::Scalar(resolve_px(current.padding.right, self.width, 0.0, 0.0))
+            }
+            StyleField::PaddingBottom => {
+                StyleValue::Scalar(resolve_px(current.padding.bottom, self.height, 0.0, 0.0))
+            }
+            StyleField::PaddingLeft => {
+                StyleValue::Scalar(resolve_px(current.padding.left, self.width, 0.0, 0.0))
+            }
         }
     }
 
@@ -8493,11 +8911,16 @@ impl ElementStyleSnapshot {
             StyleField::BoxShadow => StyleValue::BoxShadow(self.box_shadows.clone()),
             StyleField::Transform => StyleValue::Transform(self.transform.clone()),
             StyleField::TransformOrigin => StyleValue::TransformOrigin(self.transform_origin),
+            StyleField::Gap => StyleValue::Scalar(self.gap),
+            StyleField::PaddingTop => StyleValue::Scalar(self.padding.top),
+            StyleField::PaddingRight => StyleValue::Scalar(self.padding.right),
+            StyleField::PaddingBottom => StyleValue::Scalar(self.padding.bottom),
+            StyleField::PaddingLeft => StyleValue::Scalar(self.padding.left),
         }
     }
 
     fn diff(&self, current: &ComputedStyle) -> Vec<StyleField> {
-        const FIELDS: [StyleField; 11] = [
+        const FIELDS: [StyleField; 16] = [
             StyleField::Opacity,
             StyleField::BorderRadius,
             StyleField::BackgroundColor,
@@ -8509,6 +8932,11 @@ impl ElementStyleSnapshot {
             StyleField::BoxShadow,
             StyleField::Transform,
             StyleField::TransformOrigin,
+            StyleField::Gap,
+            StyleField::PaddingTop,
+            StyleField::PaddingRight,
+            StyleField::PaddingBottom,
+            StyleField::PaddingLeft,
         ];
         let mut out = Vec::new();
         for field in FIELDS {
@@ -8535,6 +8963,14 @@ impl ElementTrait for Element {
         self.core.id
     }
 
+    fn media_style_stale(&self) -> bool {
+        Element::media_style_stale(self)
+    }
+
+    fn refresh_media_style(&mut self) {
+        Element::refresh_media_style(self);
+    }
+
     fn retained_scroll_normalized_paint_capability(
         &self,
     ) -> Option<RetainedScrollNormalizedPaintCapability> {
@@ -8579,6 +9015,8 @@ impl ElementTrait for Element {
     }
 
     fn tick_post_layout_animation_frame(&mut self, now: crate::time::Instant) -> DirtyFlags {
+        self.dispatch_scroll_notifications();
+        self.dispatch_resize_notifications();
         if self.tick_scrollbar_visibility(now) {
             DirtyFlags::PAINT
         } else {
@@ -8597,6 +9035,11 @@ impl ElementTrait for Element {
             ScrollDirection::Horizontal => ScrollAxisSnapshot::Horizontal,
             ScrollDirection::Both => ScrollAxisSnapshot::Both,
         };
+        // Retained scrollbar overlays replay the built-in look; a styled
+        // scrollbar stays on the legacy paint path that honors it.
+        if self.computed_style.scrollbar != ScrollbarStyle::default() {
+            return ScrollGeometryObservation::Unsupported;
+        }
         let geometry_dirty = DirtyPassMask::LAYOUT.union(DirtyPassMask::PLACEMENT);
         let sampled_layout_transition_is_exact = !self.active_layout_transition_runtime_state()
             || self
@@ -8941,8 +9384,6 @@ impl ElementTrait for Element {
         self
     }
 
-    // Phase B: snapshot_state / restore_state removed (see trait def).
-
     fn intercepts_pointer_at(&self, viewport_x: f32, viewport_y: f32) -> bool {
         let local_x = viewport_x - self.layout_state.layout_position.x;
         let local_y = viewport_y - self.layout_state.layout_position.y;
@@ -8954,6 +9395,17 @@ impl ElementTrait for Element {
             .map_or(true, |rect| rect.contains(viewport_x, viewport_y))
     }
 
+    fn hit_shape_contains(&self, viewport_x: f32, viewport_y: f32) -> bool {
+        let position = self.layout_state.layout_position;
+        let (width, height) = self.current_layout_frame_size();
+        self.computed_style.hit_shape.contains(
+            viewport_x - position.x,
+            viewport_y - position.y,
+            width,
+            height,
+        )
+    }
+
     fn retained_paint_properties(&self) -> RetainedPaintProperties {
         let border_width_sum = self.border_widths.left
             + self.border_widths.right
@@ -8968,6 +9420,22 @@ impl ElementTrait for Element {
         }
     }
 
+    fn bakes_opacity_into_self_paint(&self) -> bool {
+        // A borderless box paints background color and gradient in a single
+        // fill op; borders, shadows, scrollbars and inline fragments add
+        // overlapping draws.
+        let properties = self.retained_paint_properties();
+        self.children.is_empty()
+            && !properties.has_border
+            && !properties.has_box_shadow
+            && !properties.is_scroll_container
+            && self.resolved_transform.is_none()
+            && !self.inline_ifc_owned_by_root
+            && !self.is_owning_inline_ifc_root_role()
+            && !self.is_fragmentable_inline_element()
+            && !self.should_append_to_root_viewport_render()
+    }
+
     fn has_active_animator(&self) -> bool {
         self.last_started_animator.is_some()
     }
@@ -8976,6 +9444,7 @@ impl ElementTrait for Element {
         let mut hasher = DefaultHasher::new();
         self.layout_state.should_render.hash(&mut hasher);
         self.core.should_paint.hash(&mut hasher);
+        self.wants_pixel_snap().hash(&mut hasher);
         hash_f32(&mut hasher, self.layout_state.layout_position.x);
         hash_f32(&mut hasher, self.layout_state.layout_position.y);
         hash_f32(&mut hasher, self.layout_state.layout_size.width.max(0.0));
@@ -9044,6 +9513,53 @@ impl ElementTrait for Element {
             paint_width,
             paint_height,
         );
+        self.computed_style
+            .border_nine_patch
+            .is_some()
+            .hash(&mut hasher);
+        if let Some(nine_patch) = &self.computed_style.border_nine_patch {
+            for value in nine_patch
+                .slice
+                .into_iter()
+                .chain(nine_patch.resolved_width())
+            {
+                hash_f32(&mut hasher, value);
+            }
+            nine_patch.repeat.hash(&mut hasher);
+            nine_patch.fill.hash(&mut hasher);
+            crate::view::base_component::image::hash_image_snapshot(
+                self.border_image_snapshot().as_ref(),
+                &mut hasher,
+            );
+        }
+        self.computed_style.mix_blend_mode.hash(&mut hasher);
+        match &self.computed_style.mask {
+            None => 0_u8.hash(&mut hasher),
+            Some(crate::style::Mask::Gradient(gradient)) => hash_resolved_gradient_paint(
+                &mut hasher,
+                0xB3,
+                Some(gradient),
+                paint_width,
+                paint_height,
+            ),
+            Some(crate::style::Mask::Image(_)) => {
+                1_u8.hash(&mut hasher);
+                crate::view::base_component::image::hash_image_snapshot(
+                    self.mask_image_snapshot().as_ref(),
+                    &mut hasher,
+                );
+            }
+        }
+        for filter in &self.computed_style.filter {
+            let (kind, amount) = match *filter {
+                crate::style::Filter::Grayscale(amount) => (0_u8, amount),
+                crate::style::Filter::Brightness(amount) => (1, amount),
+                crate::style::Filter::Saturate(amount) => (2, amount),
+                crate::style::Filter::Blur(amount) => (3, amount),
+            };
+            kind.hash(&mut hasher);
+            hash_f32(&mut hasher, amount);
+        }
         self.foreground_color.to_rgba_u8().hash(&mut hasher);
         self.border_colors
             .top
@@ -9069,12 +9585,13 @@ impl ElementTrait for Element {
         hash_f32(&mut hasher, self.border_widths.right);
         hash_f32(&mut hasher, self.border_widths.top);
         hash_f32(&mut hasher, self.border_widths.bottom);
+        self.hairline_border_edges().hash(&mut hasher);
         hash_f32(&mut hasher, self.border_radii.top_left);
         hash_f32(&mut hasher, self.border_radii.top_right);
         hash_f32(&mut hasher, self.border_radii.bottom_right);
         hash_f32(&mut hasher, self.border_radii.bottom_left);
         for shadow in &self.box_shadows {
-            for channel in shadow.color.to_rgba_f32() {
+            for channel in shadow.color.to_output_rgba_f32() {
                 hash_f32(&mut hasher, channel);
             }
             hash_f32(&mut hasher, shadow.offset_x);
@@ -9256,13 +9773,16 @@ impl ElementTrait for Element {
 
     fn ingest_props(&mut self, node: &crate::ui::RsxElementNode) -> Result<(), String> {
         use crate::ui::FromPropValue;
-        use crate::view::renderer_adapter::{as_f32, as_owned_string};
+        use crate::view::renderer_adapter::{as_bool, as_f32, as_owned_string};
         for (key, value) in node.props.iter() {
             match *key {
                 // Identity ("key") and layered "style" are owned by
                 // the cold convert shell — it merges base + user style
-                // before this hook runs. Skip both here.
-                "key" | "style" => {}
+                // before this hook runs. Skip both here. `test_id` and
+                // the accessibility props are only read back from the RSX
+                // tree by `ElementQuery`.
+                "key" | "style" | "test_id" | "role" | "aria_label" | "aria_checked"
+                | "aria_disabled" | "aria_expanded" | "aria_selected" | "reading_order" => {}
                 "anchor" => self.set_anchor_name(Some(crate::style::AnchorName::new(
                     as_owned_string(value, key)?,
                 ))),
@@ -9275,6 +9795,18 @@ impl ElementTrait for Element {
                 "padding_top" => self.set_padding_top(as_f32(value, key)?),
                 "padding_bottom" => self.set_padding_bottom(as_f32(value, key)?),
                 "opacity" => self.set_opacity(as_f32(value, key)?),
+                "reach_end_threshold" => self.set_reach_end_threshold(as_f32(value, key)?),
+                "visibility_threshold" => self.set_visibility_threshold(as_f32(value, key)?),
+                "long_press_duration" => self.set_long_press_duration(Duration::from_millis(
+                    as_f32(value, key)?.max(0.0).round() as u64,
+                )),
+                "hover_intent" => self.set_hover_intent(Some(
+                    crate::view::viewport::HoverIntent::from_prop_value(value.clone())?,
+                )),
+                "spatial_navigation" => self.set_spatial_navigation(as_bool(value, key)?),
+                "transition_group" => self.set_transition_group(Some(
+                    crate::transition::TransitionGroup::from_prop_value(value.clone())?,
+                )),
                 other => {
                     if !try_assign_event_handler_prop(self, other, value)? {
                         return Err(format!("unknown prop `{}` on <{}>", key, node.tag));
@@ -9342,6 +9874,50 @@ impl ElementTrait for Element {
                 self.set_debug_type(debug_type);
                 PropApplyOutcome::Applied
             }
+            "test_id" | "role" | "aria_label" | "aria_checked" | "aria_disabled"
+            | "aria_expanded" | "aria_selected" | "reading_order" => PropApplyOutcome::Applied,
+            "reach_end_threshold" => {
+                let Ok(threshold) = crate::view::renderer_adapter::as_f32(&value, name) else {
+                    return PropApplyOutcome::DecodeFailed(name);
+                };
+                self.set_reach_end_threshold(threshold);
+                PropApplyOutcome::Applied
+            }
+            "visibility_threshold" => {
+                let Ok(threshold) = crate::view::renderer_adapter::as_f32(&value, name) else {
+                    return PropApplyOutcome::DecodeFailed(name);
+                };
+                self.set_visibility_threshold(threshold);
+                PropApplyOutcome::Applied
+            }
+            "long_press_duration" => {
+                let Ok(ms) = crate::view::renderer_adapter::as_f32(&value, name) else {
+                    return PropApplyOutcome::DecodeFailed(name);
+                };
+                self.set_long_press_duration(Duration::from_millis(ms.max(0.0).round() as u64));
+                PropApplyOutcome::Applied
+            }
+            "hover_intent" => {
+                let Ok(intent) = crate::view::viewport::HoverIntent::from_prop_value(value) else {
+                    return PropApplyOutcome::DecodeFailed(name);
+                };
+                self.set_hover_intent(Some(intent));
+                PropApplyOutcome::Applied
+            }
+            "spatial_navigation" => {
+                let Ok(enabled) = crate::view::renderer_adapter::as_bool(&value, name) else {
+                    return PropApplyOutcome::DecodeFailed(name);
+                };
+                self.set_spatial_navigation(enabled);
+                PropApplyOutcome::Applied
+            }
+            "transition_group" => {
+                let Ok(group) = crate::transition::TransitionGroup::from_prop_value(value) else {
+                    return PropApplyOutcome::DecodeFailed(name);
+                };
+                self.set_transition_group(Some(group));
+                PropApplyOutcome::Applied
+            }
             other if RSX_EVENT_HANDLER_PROPS.contains(&other) => {
                 // M4 #4: replace semantics for RSX event handlers.
                 // Cold-path setters push onto a Vec; clear first to
@@ -9399,10 +9975,36 @@ impl ElementTrait for Element {
                 self.set_debug_type(DebugType::empty());
                 PropApplyOutcome::Applied
             }
+            "test_id" | "role" | "aria_label" | "aria_checked" | "aria_disabled"
+            | "aria_expanded" | "aria_selected" | "reading_order" => PropApplyOutcome::Applied,
             "opacity" => {
                 self.set_opacity(1.0);
                 PropApplyOutcome::Applied
             }
+            "reach_end_threshold" => {
+                self.set_reach_end_threshold(0.0);
+                PropApplyOutcome::Applied
+            }
+            "visibility_threshold" => {
+                self.set_visibility_threshold(0.0);
+                PropApplyOutcome::Applied
+            }
+            "long_press_duration" => {
+                self.set_long_press_duration(DEFAULT_LONG_PRESS_DURATION);
+                PropApplyOutcome::Applied
+            }
+            "hover_intent" => {
+                self.set_hover_intent(None);
+                PropApplyOutcome::Applied
+            }
+            "spatial_navigation" => {
+                self.set_spatial_navigation(false);
+                PropApplyOutcome::Applied
+            }
+            "transition_group" => {
+                self.set_transition_group(None);
+                PropApplyOutcome::Applied
+            }
             other if RSX_EVENT_HANDLER_PROPS.contains(&other) => {
                 self.clear_rsx_event_handler(other);
                 PropApplyOutcome::Applied
@@ -20,7 +20,7 @@ use crate::style::Layout;
 use crate::style::{
     Align, AnchorName, Border, BorderRadius, BoxShadow, ClipMode, Collision,
     CollisionBoundary, Color, ComputedStyle, CrossSize, JustifyContent, Length, Opacity,
-    Operator, Origin, Position, ScrollDirection, Style, Transform, TransformOrigin,
+    Operator, Origin, Padding, Position, ScrollDirection, Style, Transform, TransformOrigin,
     Translate, VerticalAlign,
 };
 use crate::style::{ParsedValue, PropertyId, Transition, TransitionProperty, Transitions};
@@ -429,6 +429,7 @@ mod flow_layout_tests;
 mod flex_layout_tests;
 mod absolute_positioning_tests;
 mod absolute_clip_tests;
+mod sticky_positioning_tests;
 mod anchor_resolution_tests;
 mod viewport_anchored_tests;
 mod viewport_anchored_snackbar_tests;
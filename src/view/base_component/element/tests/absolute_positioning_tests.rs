@@ -357,6 +357,194 @@ fn absolute_self_origin_top_center_for_popover_pattern() {
     assert_eq!(snapshot.y, 120.0);
 }
 
+#[test]
+fn absolute_translate_percent_is_relative_to_self_size() {
+    // translate(-50%, -100%) on top of a px nudge: self bottom-center sits
+    // on the placement point, then shifts 4px further up.
+    let parent = Element::new(0.0, 0.0, 200.0, 120.0);
+    let mut child = Element::new(0.0, 0.0, 80.0, 50.0);
+    let mut child_style = Style::new();
+    child_style.insert(
+        PropertyId::Position,
+        ParsedValue::Position(
+            Position::absolute()
+                .left(Length::percent(50.0))
+                .top(Length::px(100.0))
+                .origin(Origin::px(0.0, 4.0))
+                .translate_x(Length::percent(-50.0))
+                .translate_y(Length::percent(-100.0)),
+        ),
+    );
+    child.apply_style(child_style);
+
+    let mut arena = new_test_arena();
+    let parent_key = commit_element(&mut arena, Box::new(parent));
+    let _child_key = commit_child(&mut arena, parent_key, Box::new(child));
+
+    measure_and_place(
+        &mut arena,
+        parent_key,
+        LayoutConstraints {
+            max_width: 800.0,
+            max_height: 600.0,
+            viewport_width: 800.0,
+            percent_base_width: Some(800.0),
+            percent_base_height: Some(600.0),
+            viewport_height: 600.0,
+        },
+        LayoutPlacement {
+            parent_x: 0.0,
+            parent_y: 0.0,
+            visual_offset_x: 0.0,
+            visual_offset_y: 0.0,
+            available_width: 800.0,
+            available_height: 600.0,
+            viewport_width: 800.0,
+            percent_base_width: Some(800.0),
+            percent_base_height: Some(600.0),
+            viewport_height: 600.0,
+        },
+    );
+
+    let snapshot = nth_child_snapshot(&arena, parent_key, 0);
+    // placement point = (100, 100); x: 100 - 50% of 80 = 60;
+    // y: 100 - 4 - 100% of 50 = 46.
+    assert_eq!(snapshot.x, 60.0);
+    assert_eq!(snapshot.y, 46.0);
+    assert_eq!(snapshot.width, 80.0);
+}
+
+#[test]
+fn absolute_placement_tries_fallbacks_in_order() {
+    // No anchor: the parent rect is the anchor, and with a parent boundary
+    // every placement outside it collides.
+    let parent = Element::new(0.0, 0.0, 200.0, 120.0);
+    let mut fallback_child = Element::new(0.0, 0.0, 80.0, 50.0);
+    let mut fallback_style = Style::new();
+    fallback_style.insert(
+        PropertyId::Position,
+        ParsedValue::Position(
+            Position::absolute()
+                .placement(AnchorPlacement::top())
+                .fallback_placements([
+                    AnchorPlacement::right_start(),
+                    AnchorPlacement::new(AnchorAlign::End, AnchorAlign::End),
+                    AnchorPlacement::new(AnchorAlign::Start, AnchorAlign::Start),
+                ])
+                .collision(Collision::None, CollisionBoundary::Parent),
+        ),
+    );
+    fallback_child.apply_style(fallback_style);
+    let mut unplaceable_child = Element::new(0.0, 0.0, 80.0, 50.0);
+    let mut unplaceable_style = Style::new();
+    unplaceable_style.insert(
+        PropertyId::Position,
+        ParsedValue::Position(
+            Position::absolute()
+                .placement(AnchorPlacement::top())
+                .anchor_offset(Length::px(4.0))
+                .collision(Collision::Flip, CollisionBoundary::Parent),
+        ),
+    );
+    unplaceable_child.apply_style(unplaceable_style);
+
+    let mut arena = new_test_arena();
+    let parent_key = commit_element(&mut arena, Box::new(parent));
+    let _ = commit_child(&mut arena, parent_key, Box::new(fallback_child));
+    let _ = commit_child(&mut arena, parent_key, Box::new(unplaceable_child));
+
+    measure_and_place(
+        &mut arena,
+        parent_key,
+        LayoutConstraints {
+            max_width: 800.0,
+            max_height: 600.0,
+            viewport_width: 800.0,
+            percent_base_width: Some(800.0),
+            percent_base_height: Some(600.0),
+            viewport_height: 600.0,
+        },
+        LayoutPlacement {
+            parent_x: 0.0,
+            parent_y: 0.0,
+            visual_offset_x: 0.0,
+            visual_offset_y: 0.0,
+            available_width: 800.0,
+            available_height: 600.0,
+            viewport_width: 800.0,
+            percent_base_width: Some(800.0),
+            percent_base_height: Some(600.0),
+            viewport_height: 600.0,
+        },
+    );
+
+    // top and right_start overflow; the inner end/end placement fits.
+    let placed = nth_child_snapshot(&arena, parent_key, 0);
+    assert_eq!(placed.x, 120.0);
+    assert_eq!(placed.y, 70.0);
+    // Neither top nor its mirror (bottom) fits: the primary is kept.
+    let unplaceable = nth_child_snapshot(&arena, parent_key, 1);
+    assert_eq!(unplaceable.x, 60.0);
+    assert_eq!(unplaceable.y, -54.0);
+}
+
+#[test]
+fn absolute_fit_keeps_collision_padding_inside_boundary_insets() {
+    let parent = Element::new(0.0, 0.0, 200.0, 120.0);
+    let mut child = Element::new(0.0, 0.0, 80.0, 50.0);
+    let mut child_style = Style::new();
+    child_style.insert(
+        PropertyId::Position,
+        ParsedValue::Position(
+            Position::absolute()
+                .left(Length::px(190.0))
+                .top(Length::px(-30.0))
+                .collision(Collision::Fit, CollisionBoundary::Parent)
+                .boundary_insets(
+                    Padding::new()
+                        .top(Length::px(10.0))
+                        .right(Length::percent(10.0)),
+                )
+                .collision_padding(Length::px(5.0)),
+        ),
+    );
+    child.apply_style(child_style);
+
+    let mut arena = new_test_arena();
+    let parent_key = commit_element(&mut arena, Box::new(parent));
+    let _child_key = commit_child(&mut arena, parent_key, Box::new(child));
+
+    measure_and_place(
+        &mut arena,
+        parent_key,
+        LayoutConstraints {
+            max_width: 800.0,
+            max_height: 600.0,
+            viewport_width: 800.0,
+            percent_base_width: Some(800.0),
+            percent_base_height: Some(600.0),
+            viewport_height: 600.0,
+        },
+        LayoutPlacement {
+            parent_x: 0.0,
+            parent_y: 0.0,
+            visual_offset_x: 0.0,
+            visual_offset_y: 0.0,
+            available_width: 800.0,
+            available_height: 600.0,
+            viewport_width: 800.0,
+            percent_base_width: Some(800.0),
+            percent_base_height: Some(600.0),
+            viewport_height: 600.0,
+        },
+    );
+
+    let snapshot = nth_child_snapshot(&arena, parent_key, 0);
+    // x: 200 - 10% of 200 - 5 - 80 = 95; y: 10 + 5 = 15.
+    assert_eq!(snapshot.x, 95.0);
+    assert_eq!(snapshot.y, 15.0);
+}
+
 #[test]
 fn absolute_self_origin_with_auto_size_via_child() {
     // Mirror tooltip pattern: absolute element with Auto width/height,
@@ -230,3 +230,35 @@ fn inner_clip_rect_uses_flex_assigned_width() {
     assert!((snapshot.width - 240.0).abs() < 0.01);
     assert!((inner.width - 240.0).abs() < 0.01);
 }
+
+#[test]
+fn on_resize_reports_first_layout_and_each_size_change() {
+    let mut element = Element::new(0.0, 0.0, 100.0, 40.0);
+    element.layout_state.layout_size = Size {
+        width: 100.0,
+        height: 40.0,
+    };
+    let seen = std::rc::Rc::new(std::cell::RefCell::new(Vec::new()));
+    let sink = seen.clone();
+    element.on_resize(move |event| {
+        sink.borrow_mut().push((
+            event.previous_width,
+            event.previous_height,
+            event.width,
+            event.height,
+        ));
+    });
+    let frame = crate::time::Instant::now();
+
+    let _ = element.tick_post_layout_animation_frame(frame);
+    assert_eq!(*seen.borrow(), vec![(0.0, 0.0, 100.0, 40.0)]);
+    let _ = element.tick_post_layout_animation_frame(frame);
+    assert_eq!(seen.borrow().len(), 1, "an unchanged size must not fire");
+
+    element.layout_state.layout_size.width = 160.0;
+    let _ = element.tick_post_layout_animation_frame(frame);
+    assert_eq!(
+        seen.borrow().last().copied(),
+        Some((100.0, 40.0, 160.0, 40.0))
+    );
+}
@@ -670,3 +670,102 @@ fn flex_place_retains_measured_plan_for_placement_only_reuse() {
     let root = crate::view::test_support::get_element::<Element>(&arena, root_key);
     assert!(root.flex_info.is_some());
 }
+
+#[test]
+fn measure_reuses_cached_subtree_when_toggling_between_proposals() {
+    let constraints_for = |width: f32| LayoutConstraints {
+        max_width: width,
+        max_height: 120.0,
+        viewport_width: width,
+        viewport_height: 120.0,
+        percent_base_width: Some(width),
+        percent_base_height: Some(120.0),
+    };
+
+    let mut arena = new_test_arena();
+    let mut root = Element::new(0.0, 0.0, 0.0, 0.0);
+    let mut root_style = Style::new();
+    root_style.insert(
+        PropertyId::Layout,
+        ParsedValue::Layout(Layout::flex().row().into()),
+    );
+    root_style.insert(
+        PropertyId::Width,
+        ParsedValue::Length(Length::percent(100.0)),
+    );
+    root_style.insert(PropertyId::Height, ParsedValue::Length(Length::px(120.0)));
+    root.apply_style(root_style);
+    let root_key = commit_element(&mut arena, Box::new(root));
+
+    let mut child = Element::new(0.0, 0.0, 0.0, 20.0);
+    let mut child_style = Style::new();
+    child_style.insert(
+        PropertyId::Width,
+        ParsedValue::Length(Length::percent(50.0)),
+    );
+    child_style.insert(PropertyId::Height, ParsedValue::Length(Length::px(20.0)));
+    child.apply_style(child_style);
+    let child_key = commit_child(&mut arena, root_key, Box::new(child));
+
+    let measure = |arena: &mut NodeArena, width: f32| {
+        arena.refresh_subtree_dirty_cache(root_key);
+        arena.with_element_taken(root_key, |el, a| el.measure(constraints_for(width), a));
+        crate::view::test_support::get_element::<Element>(arena, child_key)
+            .measured_size()
+            .0
+    };
+
+    assert_eq!(measure(&mut arena, 200.0), 100.0);
+    assert_eq!(measure(&mut arena, 400.0), 200.0);
+    // The viewport clears commit-time arena dirt once the frame lands.
+    arena.clear_arena_dirty_subtree(root_key, DirtyFlags::ALL);
+
+    crate::view::base_component::set_layout_place_profile_enabled(true);
+    crate::view::base_component::reset_layout_place_profile();
+    assert_eq!(measure(&mut arena, 200.0), 100.0);
+    assert_eq!(measure(&mut arena, 400.0), 200.0);
+    let profile = crate::view::base_component::take_layout_place_profile();
+    crate::view::base_component::set_layout_place_profile_enabled(false);
+
+    assert_eq!(profile.measure_cache_hits, 4);
+    assert_eq!(profile.measure_ran_proposal_changed, 0);
+}
+
+#[test]
+fn layout_dirty_invalidates_cached_measure() {
+    let constraints_for = |width: f32| LayoutConstraints {
+        max_width: width,
+        max_height: 120.0,
+        viewport_width: width,
+        viewport_height: 120.0,
+        percent_base_width: Some(width),
+        percent_base_height: Some(120.0),
+    };
+
+    let mut arena = new_test_arena();
+    let mut root = Element::new(0.0, 0.0, 0.0, 0.0);
+    let mut root_style = Style::new();
+    root_style.insert(
+        PropertyId::Layout,
+        ParsedValue::Layout(Layout::flow().row().no_wrap().into()),
+    );
+    root.apply_style(root_style);
+    let root_key = commit_element(&mut arena, Box::new(root));
+    let text_key = commit_child(&mut arena, root_key, Box::new(Text::from_content("a")));
+
+    let measure = |arena: &mut NodeArena, width: f32| {
+        arena.refresh_subtree_dirty_cache(root_key);
+        arena.with_element_taken(root_key, |el, a| el.measure(constraints_for(width), a));
+        crate::view::test_support::get_element::<Element>(arena, root_key)
+            .measured_size()
+            .0
+    };
+
+    let narrow_before = measure(&mut arena, 200.0);
+    measure(&mut arena, 400.0);
+    crate::view::test_support::get_element_mut::<Text>(&arena, text_key)
+        .set_text("a much longer child");
+    measure(&mut arena, 400.0);
+
+    assert!(measure(&mut arena, 200.0) > narrow_before + 1.0);
+}
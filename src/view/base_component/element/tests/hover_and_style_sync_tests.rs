@@ -56,6 +56,51 @@ fn layout_affecting_hover_style_marks_layout_dirty() {
     assert!(el.local_dirty_flags().contains(DirtyFlags::LAYOUT));
 }
 
+#[test]
+fn hover_toggle_reuses_cached_variants_until_style_changes() {
+    let mut el = Element::new(0.0, 0.0, 100.0, 40.0);
+    let mut style = Style::new();
+    style.set_background(Color::rgb(10, 20, 30).into());
+    let mut hover_style = Style::new();
+    hover_style.set_background(Color::rgb(200, 150, 100).into());
+    style.set_hover(hover_style);
+    el.apply_style(style);
+    assert!(el.computed_style_variants[0].is_some());
+    assert!(el.computed_style_variants[1].is_none());
+
+    assert!(el.set_hovered(true));
+    let hovered = el.computed_style.clone();
+    assert!(el.set_hovered(false));
+    assert!(el.set_hovered(true));
+    assert_eq!(el.computed_style, hovered);
+    assert_eq!(el.computed_style_variants[1].as_ref(), Some(&hovered));
+
+    let mut restyle = Style::new();
+    restyle.insert(PropertyId::Opacity, ParsedValue::Opacity(Opacity::new(0.5)));
+    el.apply_style(restyle);
+    assert!(
+        el.computed_style_variants[0].is_none(),
+        "a style change drops the cached unhovered resolution"
+    );
+    assert!(el.computed_style_variants[1].is_some());
+    assert!((el.debug_render_state().opacity - 0.5).abs() < 0.001);
+}
+
+#[test]
+fn hover_toggle_without_hover_style_leaves_style_untouched() {
+    let mut el = Element::new(0.0, 0.0, 100.0, 40.0);
+    let mut style = Style::new();
+    style.insert(PropertyId::Width, ParsedValue::Length(Length::px(100.0)));
+    el.apply_style(style);
+    el.layout_dirty = false;
+    el.clear_local_dirty_flags(DirtyFlags::ALL);
+
+    assert!(el.set_hovered(true));
+    assert!(!el.layout_dirty);
+    assert!(!el.local_dirty_flags().contains(DirtyFlags::LAYOUT));
+    assert!(el.computed_style_variants[1].is_none());
+}
+
 #[test]
 fn hover_style_emits_transition_request() {
     let mut el = Element::new(0.0, 0.0, 100.0, 40.0);
@@ -29,6 +29,70 @@ fn texture_desc_for_logical_bounds_keeps_logical_scale_mapping() {
     assert_eq!(scaled.height(), 40);
 }
 
+#[test]
+fn persistent_target_under_scaled_transform_rasterizes_at_transform_scale() {
+    let bounds = super::super::RetainedSurfaceBounds {
+        x: 10.0,
+        y: 20.0,
+        width: 30.0,
+        height: 40.0,
+        corner_radii: [0.0; 4],
+    };
+    let mut graph = FrameGraph::new();
+    let mut ctx = UiBuildContext::new(120, 120, wgpu::TextureFormat::Bgra8Unorm, 1.0);
+    ctx.set_current_render_transform(Some(Mat4::from_scale(Vec3::new(2.0, 2.0, 1.0))));
+
+    let target = ctx.allocate_persistent_target_with_key(
+        &mut graph,
+        super::super::transformed_layer_stable_key(7),
+        bounds,
+    );
+    let desc = graph
+        .texture_desc(target.handle().expect("layer target"))
+        .expect("layer descriptor");
+
+    assert_eq!(desc.raster_scale(), 2.0);
+    assert_eq!((desc.width(), desc.height()), (60, 80));
+    assert_eq!(desc.origin(), (20, 40));
+}
+
+#[test]
+fn transformed_layer_raster_scale_rounds_up_and_stays_bounded() {
+    let bounds = super::super::RetainedSurfaceBounds {
+        x: 0.0,
+        y: 0.0,
+        width: 100.0,
+        height: 50.0,
+        corner_radii: [0.0; 4],
+    };
+    let scale = |x: f32, y: f32| Some(Mat4::from_scale(Vec3::new(x, y, 1.0)));
+    let raster = super::super::transformed_layer_raster_scale;
+
+    assert_eq!(raster(bounds, 1.0, None), 1.0);
+    assert_eq!(raster(bounds, 1.0, scale(0.5, 0.5)), 1.0);
+    assert_eq!(raster(bounds, 1.0, scale(1.1, 0.8)), 1.25);
+    assert_eq!(raster(bounds, 1.0, scale(1.0, 3.0)), 3.0);
+    assert_eq!(raster(bounds, 1.0, scale(10.0, 10.0)), 4.0);
+    assert_eq!(
+        raster(
+            bounds,
+            1.0,
+            Some(Mat4::from_rotation_z(0.5) * Mat4::from_scale(Vec3::splat(2.0)))
+        ),
+        2.0
+    );
+
+    let wide = super::super::RetainedSurfaceBounds {
+        width: 4096.0,
+        ..bounds
+    };
+    let max_dimension = wgpu::Limits::default().max_texture_dimension_2d as f32;
+    assert_eq!(
+        raster(wide, 1.0, scale(3.0, 3.0)),
+        (max_dimension / 4096.0).max(1.0)
+    );
+}
+
 #[test]
 fn build_context_render_transform_propagates_to_child_without_leaking_back() {
     let mut parent_ctx = UiBuildContext::new(120, 120, wgpu::TextureFormat::Bgra8Unorm, 1.0);
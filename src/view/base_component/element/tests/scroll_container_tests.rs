@@ -466,3 +466,219 @@ fn flow_cross_size_stretch_aligns_using_current_then_final_cross_size() {
         );
     }
 }
+
+#[test]
+fn always_visible_scrollbar_stays_opaque_without_fade_frames() {
+    let mut element = Element::new(0.0, 0.0, 100.0, 80.0);
+    let mut style = Style::new();
+    style.insert(
+        PropertyId::ScrollDirection,
+        ParsedValue::ScrollDirection(ScrollDirection::Vertical),
+    );
+    style.insert(
+        PropertyId::Scrollbar,
+        ParsedValue::Scrollbar(ScrollbarStyle::new().always_visible()),
+    );
+    element.apply_style(style);
+    element.layout_state.content_size = Size {
+        width: 100.0,
+        height: 300.0,
+    };
+
+    let frame = crate::time::Instant::now();
+    assert!(element.set_hovered(true));
+    let _ = element.tick_post_layout_animation_frame(frame);
+    assert!(element.set_hovered(false));
+    let leave_frame = frame + crate::time::Duration::from_millis(10);
+    let _ = element.tick_post_layout_animation_frame(leave_frame);
+
+    assert!(!element.wants_animation_frame());
+    let later = frame + crate::time::Duration::from_millis(5_000);
+    assert!(element.tick_post_layout_animation_frame(later).is_empty());
+    assert_eq!(
+        element.scrollbar_visibility_alpha().to_bits(),
+        1.0_f32.to_bits()
+    );
+}
+
+#[test]
+fn gutter_scrollbar_reserves_inline_space_and_honors_width() {
+    let mut parent = Element::new(0.0, 0.0, 120.0, 120.0);
+    let mut parent_style = Style::new();
+    parent_style.insert(
+        PropertyId::Layout,
+        ParsedValue::Layout(Layout::flow().column().no_wrap().into()),
+    );
+    parent_style.insert(PropertyId::Width, ParsedValue::Length(Length::px(120.0)));
+    parent_style.insert(PropertyId::Height, ParsedValue::Length(Length::px(120.0)));
+    parent_style.insert(
+        PropertyId::ScrollDirection,
+        ParsedValue::ScrollDirection(ScrollDirection::Vertical),
+    );
+    parent_style.insert(
+        PropertyId::Scrollbar,
+        ParsedValue::Scrollbar(ScrollbarStyle::new().width(8.0).gutter()),
+    );
+    parent.apply_style(parent_style);
+
+    let mut child = Element::new(0.0, 0.0, 0.0, 0.0);
+    let mut child_style = Style::new();
+    child_style.insert(
+        PropertyId::Width,
+        ParsedValue::Length(Length::percent(100.0)),
+    );
+    child_style.insert(PropertyId::Height, ParsedValue::Length(Length::px(300.0)));
+    child.apply_style(child_style);
+
+    let mut arena = new_test_arena();
+    let parent_key = commit_element(&mut arena, Box::new(parent));
+    let _ = commit_child(&mut arena, parent_key, Box::new(child));
+
+    measure_and_place(
+        &mut arena,
+        parent_key,
+        LayoutConstraints {
+            max_width: 120.0,
+            max_height: 120.0,
+            viewport_width: 120.0,
+            viewport_height: 120.0,
+            percent_base_width: Some(120.0),
+            percent_base_height: Some(120.0),
+        },
+        LayoutPlacement {
+            parent_x: 0.0,
+            parent_y: 0.0,
+            visual_offset_x: 0.0,
+            visual_offset_y: 0.0,
+            available_width: 120.0,
+            available_height: 120.0,
+            viewport_width: 120.0,
+            viewport_height: 120.0,
+            percent_base_width: Some(120.0),
+            percent_base_height: Some(120.0),
+        },
+    );
+
+    // Gutter = thickness plus a margin on each side of the track.
+    let gutter = 8.0 + SCROLLBAR_MARGIN * 2.0;
+    let child_snapshot = nth_child_snapshot(&arena, parent_key, 0);
+    assert!((child_snapshot.width - (120.0 - gutter)).abs() < 0.01);
+
+    let parent_ref = crate::view::test_support::get_element::<Element>(&arena, parent_key);
+    let inner = parent_ref.layout_state.layout_inner_position;
+    let track = parent_ref
+        .scrollbar_geometry(inner.x, inner.y)
+        .vertical_track
+        .expect("vertical track");
+    assert!((track.width - 8.0).abs() < 0.01);
+    assert!(track.x >= 120.0 - gutter - 0.01);
+    assert!((track.x + track.width - (120.0 - SCROLLBAR_MARGIN)).abs() < 0.01);
+}
+
+fn vertical_scroll_host(content_height: f32) -> Element {
+    let mut element = Element::new(0.0, 0.0, 100.0, 80.0);
+    let mut style = Style::new();
+    style.insert(
+        PropertyId::ScrollDirection,
+        ParsedValue::ScrollDirection(ScrollDirection::Vertical),
+    );
+    element.apply_style(style);
+    element.layout_state.content_size = Size {
+        width: 100.0,
+        height: content_height,
+    };
+    element
+}
+
+#[test]
+fn on_scroll_reports_settled_offset_once_per_frame_for_every_source() {
+    let mut element = vertical_scroll_host(300.0);
+    let seen = std::rc::Rc::new(std::cell::RefCell::new(Vec::new()));
+    let sink = seen.clone();
+    element.on_scroll(move |event| {
+        sink.borrow_mut().push((event.scroll_y, event.max_scroll_y));
+    });
+
+    let frame = crate::time::Instant::now();
+    let _ = element.tick_post_layout_animation_frame(frame);
+    assert!(seen.borrow().is_empty(), "no scroll yet");
+
+    assert!(element.scroll_by(0.0, 30.0));
+    assert!(element.scroll_by(0.0, 30.0));
+    let _ = element.tick_post_layout_animation_frame(frame);
+    assert_eq!(*seen.borrow(), vec![(60.0, 220.0)]);
+
+    // Programmatic scrolls (and the scroll track's samples) go through
+    // `set_scroll_offset`; a probe that restores the offset reports nothing.
+    element.set_scroll_offset((0.0, 120.0));
+    element.set_scroll_offset((0.0, 60.0));
+    let _ = element.tick_post_layout_animation_frame(frame);
+    assert_eq!(seen.borrow().len(), 1);
+
+    element.set_scroll_offset((0.0, 100.0));
+    let _ = element.tick_post_layout_animation_frame(frame);
+    assert_eq!(seen.borrow().last().copied(), Some((100.0, 220.0)));
+}
+
+#[test]
+fn on_reach_end_fires_once_per_extent_and_rearms_when_content_grows() {
+    let mut element = vertical_scroll_host(300.0);
+    let fired = std::rc::Rc::new(std::cell::Cell::new(0));
+    let counter = fired.clone();
+    element.on_reach_end(20.0, move |event| {
+        assert!(event.max_scroll_y - event.scroll_y <= event.threshold);
+        counter.set(counter.get() + 1);
+    });
+    let frame = crate::time::Instant::now();
+
+    element.set_scroll_offset((0.0, 190.0));
+    let _ = element.tick_post_layout_animation_frame(frame);
+    assert_eq!(fired.get(), 0);
+
+    assert!(element.scroll_by(0.0, 15.0));
+    let _ = element.tick_post_layout_animation_frame(frame);
+    assert_eq!(fired.get(), 1);
+    assert!(element.scroll_by(0.0, 10.0));
+    let _ = element.tick_post_layout_animation_frame(frame);
+    assert_eq!(fired.get(), 1, "same extent must not fire twice");
+
+    // More items appended: the end moves away and the next approach fires.
+    element.layout_state.content_size.height = 400.0;
+    let _ = element.tick_post_layout_animation_frame(frame);
+    assert_eq!(fired.get(), 1);
+    assert!(element.scroll_by(0.0, 100.0));
+    let _ = element.tick_post_layout_animation_frame(frame);
+    assert_eq!(fired.get(), 2);
+}
+
+#[test]
+fn scrolled_child_builds_under_visible_rect_and_is_skipped_past_overscan() {
+    let mut child = Element::new(0.0, 0.0, 100.0, 40.0);
+    child.layout_state.layout_size = Size {
+        width: 100.0,
+        height: 40.0,
+    };
+    let mut ctx = UiBuildContext::new(120, 120, wgpu::TextureFormat::Bgra8Unorm, 1.0);
+    ctx.push_scissor_rect(Some([0, 0, 120, 120]));
+
+    child.layout_state.layout_position = LayoutPosition { x: 10.0, y: 100.0 };
+    assert_eq!(
+        child.scrolled_child_visible_scissor_rect(&ctx),
+        Some([0, 76, 120, 44]),
+        "partially scrolled child should build under its overscanned visible rect"
+    );
+
+    child.layout_state.layout_position = LayoutPosition { x: 10.0, y: 130.0 };
+    assert_eq!(
+        child.scrolled_child_visible_scissor_rect(&ctx),
+        Some([0, 106, 120, 14]),
+        "child just past the scrollport stays built within the overscan"
+    );
+
+    child.layout_state.layout_position = LayoutPosition { x: 10.0, y: 150.0 };
+    assert_eq!(
+        child.scrolled_child_visible_scissor_rect(&ctx),
+        None,
+        "child beyond the overscan should be skipped"
+    );
+}
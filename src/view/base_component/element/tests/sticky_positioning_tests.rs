@@ -0,0 +1,163 @@
+use super::*;
+
+fn scroll_container(width: f32, height: f32) -> Element {
+    let mut container = Element::new(0.0, 0.0, width, height);
+    let mut style = Style::new();
+    style.insert(
+        PropertyId::Layout,
+        ParsedValue::Layout(Layout::flow().column().no_wrap().into()),
+    );
+    style.insert(
+        PropertyId::ScrollDirection,
+        ParsedValue::ScrollDirection(ScrollDirection::Vertical),
+    );
+    container.apply_style(style);
+    container
+}
+
+fn column(width: f32, height: f32) -> Element {
+    let mut section = Element::new(0.0, 0.0, width, height);
+    let mut style = Style::new();
+    style.insert(
+        PropertyId::Layout,
+        ParsedValue::Layout(Layout::flow().column().no_wrap().into()),
+    );
+    section.apply_style(style);
+    section
+}
+
+fn sticky_top(width: f32, height: f32, top: f32) -> Element {
+    let mut header = Element::new(0.0, 0.0, width, height);
+    let mut style = Style::new();
+    style.insert(
+        PropertyId::Position,
+        ParsedValue::Position(Position::sticky().top(Length::px(top))),
+    );
+    header.apply_style(style);
+    header
+}
+
+fn layout(arena: &mut NodeArena, root: NodeKey) {
+    measure_and_place(
+        arena,
+        root,
+        LayoutConstraints {
+            max_width: 800.0,
+            max_height: 600.0,
+            viewport_width: 800.0,
+            percent_base_width: Some(800.0),
+            percent_base_height: Some(600.0),
+            viewport_height: 600.0,
+        },
+        LayoutPlacement {
+            parent_x: 0.0,
+            parent_y: 0.0,
+            visual_offset_x: 0.0,
+            visual_offset_y: 0.0,
+            available_width: 800.0,
+            available_height: 600.0,
+            viewport_width: 800.0,
+            percent_base_width: Some(800.0),
+            percent_base_height: Some(600.0),
+            viewport_height: 600.0,
+        },
+    );
+}
+
+#[test]
+fn sticky_child_stays_in_flow_without_scroll() {
+    let mut arena = new_test_arena();
+    let container_key = commit_element(&mut arena, Box::new(scroll_container(100.0, 100.0)));
+    commit_child(
+        &mut arena,
+        container_key,
+        Box::new(Element::new(0.0, 0.0, 100.0, 30.0)),
+    );
+    commit_child(
+        &mut arena,
+        container_key,
+        Box::new(sticky_top(100.0, 20.0, 0.0)),
+    );
+    commit_child(
+        &mut arena,
+        container_key,
+        Box::new(Element::new(0.0, 0.0, 100.0, 400.0)),
+    );
+
+    layout(&mut arena, container_key);
+
+    assert_eq!(nth_child_snapshot(&arena, container_key, 1).y, 30.0);
+    assert_eq!(nth_child_snapshot(&arena, container_key, 2).y, 50.0);
+}
+
+#[test]
+fn sticky_child_clamps_to_scroll_container_top() {
+    let mut arena = new_test_arena();
+    let container_key = commit_element(&mut arena, Box::new(scroll_container(100.0, 100.0)));
+    commit_child(
+        &mut arena,
+        container_key,
+        Box::new(Element::new(0.0, 0.0, 100.0, 30.0)),
+    );
+    commit_child(
+        &mut arena,
+        container_key,
+        Box::new(sticky_top(100.0, 20.0, 4.0)),
+    );
+    commit_child(
+        &mut arena,
+        container_key,
+        Box::new(Element::new(0.0, 0.0, 100.0, 400.0)),
+    );
+    layout(&mut arena, container_key);
+
+    assert!(
+        crate::view::test_support::get_element_mut::<Element>(&arena, container_key)
+            .scroll_by(0.0, 120.0)
+    );
+    layout(&mut arena, container_key);
+
+    // In-flow y would be 30 - 120 = -90; the top inset pins it at 4.
+    assert_eq!(nth_child_snapshot(&arena, container_key, 1).y, 4.0);
+    // Siblings keep their scrolled flow position.
+    assert_eq!(nth_child_snapshot(&arena, container_key, 2).y, -70.0);
+}
+
+#[test]
+fn sticky_child_is_released_when_parent_scrolls_out() {
+    let mut arena = new_test_arena();
+    let container_key = commit_element(&mut arena, Box::new(scroll_container(100.0, 100.0)));
+    let section_key = commit_child(&mut arena, container_key, Box::new(column(100.0, 150.0)));
+    commit_child(
+        &mut arena,
+        section_key,
+        Box::new(sticky_top(100.0, 20.0, 0.0)),
+    );
+    commit_child(
+        &mut arena,
+        section_key,
+        Box::new(Element::new(0.0, 0.0, 100.0, 130.0)),
+    );
+    commit_child(
+        &mut arena,
+        container_key,
+        Box::new(Element::new(0.0, 0.0, 100.0, 400.0)),
+    );
+    layout(&mut arena, container_key);
+
+    assert!(
+        crate::view::test_support::get_element_mut::<Element>(&arena, container_key)
+            .scroll_by(0.0, 100.0)
+    );
+    layout(&mut arena, container_key);
+    assert_eq!(nth_child_snapshot(&arena, section_key, 0).y, 0.0);
+
+    // Section spans -140..10: the header is pushed up with the section's
+    // bottom edge instead of overflowing it.
+    assert!(
+        crate::view::test_support::get_element_mut::<Element>(&arena, container_key)
+            .scroll_by(0.0, 40.0)
+    );
+    layout(&mut arena, container_key);
+    assert_eq!(nth_child_snapshot(&arena, section_key, 0).y, -10.0);
+}
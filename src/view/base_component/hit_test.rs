@@ -141,6 +141,9 @@ fn hit_test_subtree(
 ) -> Option<NodeKey> {
     let node = arena.get(key)?;
     let element = node.element.as_ref();
+    if element_ignores_pointer(element) {
+        return None;
+    }
     if parent_gate == ParentGate::OutsideParentSelf && !element_can_escape_parent_hit_gate(element)
     {
         return None;
@@ -154,8 +157,9 @@ fn hit_test_subtree(
         return None;
     }
 
-    let in_self =
-        point_in_box_model(&snapshot, hit_x, hit_y) && element.hit_test_visible_at(hit_x, hit_y);
+    let in_self = point_in_box_model(&snapshot, hit_x, hit_y)
+        && element.hit_test_visible_at(hit_x, hit_y)
+        && element.hit_shape_contains(hit_x, hit_y);
     if !in_self && !has_escape_descendant {
         return None;
     }
@@ -188,6 +192,14 @@ fn hit_test_point_for_node(node: &dyn ElementTrait, x: f32, y: f32) -> (f32, f32
         .unwrap_or((x, y))
 }
 
+/// `pointer_events: none` removes the element and its whole subtree from
+/// hit testing.
+fn element_ignores_pointer(node: &dyn ElementTrait) -> bool {
+    node.as_any()
+        .downcast_ref::<Element>()
+        .is_some_and(Element::ignores_pointer_for_hit_test)
+}
+
 fn element_has_parent_hit_gate_escape_descendant(node: &dyn ElementTrait) -> bool {
     node.as_any()
         .downcast_ref::<Element>()
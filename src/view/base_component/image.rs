@@ -296,6 +296,34 @@ impl Image {
         }
     }
 
+    /// Alpha in `0.0..=1.0` of the pixel drawn at the viewport point, or
+    /// `None` outside the drawn image and while it is not loaded.
+    fn alpha_at(&self, viewport_x: f32, viewport_y: f32) -> Option<f32> {
+        let ImageSnapshot::Ready(image) = self.frozen_snapshot.as_ref()? else {
+            return None;
+        };
+        let (inner_x, inner_y, inner_w, inner_h) = self.element.inner_content_rect_for_render();
+        let (draw, uv) = compute_image_mapping(
+            self.fit,
+            image.width as f32,
+            image.height as f32,
+            inner_w,
+            inner_h,
+        );
+        if draw[2] <= 0.0 || draw[3] <= 0.0 {
+            return None;
+        }
+        let u = (viewport_x - inner_x - draw[0]) / draw[2];
+        let v = (viewport_y - inner_y - draw[1]) / draw[3];
+        if !(0.0..1.0).contains(&u) || !(0.0..1.0).contains(&v) {
+            return None;
+        }
+        let x = ((uv[0] + u * uv[2]) as u32).min(image.width.saturating_sub(1));
+        let y = ((uv[1] + v * uv[3]) as u32).min(image.height.saturating_sub(1));
+        let index = (y as usize * image.width as usize + x as usize) * 4 + 3;
+        image.pixels.get(index).map(|&alpha| alpha as f32 / 255.0)
+    }
+
     fn frozen_upload(&self) -> Option<SampledTextureUpload> {
         let ImageSnapshot::Ready(image) = self.frozen_snapshot.as_ref()? else {
             return None;
@@ -645,6 +673,23 @@ impl ElementTrait for Image {
         self.element.stable_id()
     }
 
+    fn media_style_stale(&self) -> bool {
+        self.element.media_style_stale()
+    }
+
+    fn refresh_media_style(&mut self) {
+        self.element.refresh_media_style();
+    }
+
+    fn on_removed(&mut self, ctx: &mut crate::view::node_arena::RemovalContext<'_>) {
+        // Other `<Image>`s showing the same asset keep sampling its texture.
+        if self.source_handle.is_sole_handle() {
+            ctx.release_sampled_texture(crate::view::sampled_texture::SampledTextureId::Image(
+                self.source_handle.asset_id(),
+            ));
+        }
+    }
+
     fn retained_scroll_normalized_paint_capability(
         &self,
     ) -> Option<super::RetainedScrollNormalizedPaintCapability> {
@@ -982,6 +1027,15 @@ impl ElementTrait for Image {
         self.element.hit_test_visible_at(viewport_x, viewport_y)
     }
 
+    fn hit_shape_contains(&self, viewport_x: f32, viewport_y: f32) -> bool {
+        let &crate::style::HitShape::Alpha(threshold) = &self.element.computed_style().hit_shape
+        else {
+            return self.element.hit_shape_contains(viewport_x, viewport_y);
+        };
+        self.alpha_at(viewport_x, viewport_y)
+            .is_some_and(|alpha| alpha >= threshold)
+    }
+
     fn has_active_animator(&self) -> bool {
         self.element.has_active_animator()
     }
@@ -1260,6 +1314,10 @@ impl Layoutable for Image {
         self.element.measured_size()
     }
 
+    fn last_measure_constraints(&self) -> Option<LayoutConstraints> {
+        self.element.last_measure_constraints()
+    }
+
     fn set_layout_width(&mut self, width: f32) {
         self.element.set_layout_width(width);
     }
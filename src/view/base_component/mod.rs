@@ -39,43 +39,6 @@ pub(crate) fn round_layout_value(value: f32) -> f32 {
     }
 }
 
-pub(crate) fn build_node_by_id(
-    node: &mut dyn ElementTrait,
-    node_id: u64,
-    graph: &mut crate::view::frame_graph::FrameGraph,
-    arena: &mut crate::view::node_arena::NodeArena,
-    ctx: &mut UiBuildContext,
-) -> bool {
-    if node.stable_id() == node_id {
-        let next_state = node.build(
-            graph,
-            arena,
-            UiBuildContext::from_parts(ctx.viewport(), ctx.state_clone()),
-        );
-        ctx.set_state(next_state);
-        return true;
-    }
-    // Recurse into arena-resident children. The current `node` is already
-    // out of the arena (taken by our caller via `with_element_taken`), so
-    // we clone the child-key list and reborrow the arena per child.
-    let child_keys: Vec<crate::view::node_arena::NodeKey> = node
-        .as_any()
-        .downcast_ref::<Element>()
-        .map(|el| el.children().to_vec())
-        .unwrap_or_default();
-    for child_key in child_keys {
-        let found = arena
-            .with_element_taken(child_key, |child, arena| {
-                build_node_by_id(child.as_mut(), node_id, graph, arena, ctx)
-            })
-            .unwrap_or(false);
-        if found {
-            return true;
-        }
-    }
-    false
-}
-
 pub(crate) fn build_node_by_key(
     node_key: crate::view::node_arena::NodeKey,
     stable_id: u64,
@@ -83,9 +46,18 @@ pub(crate) fn build_node_by_key(
     arena: &mut crate::view::node_arena::NodeArena,
     ctx: &mut UiBuildContext,
 ) -> bool {
+    let Some(target_key) = arena.find_in_subtree_by_stable_id(node_key, stable_id) else {
+        return false;
+    };
     arena
-        .with_element_taken(node_key, |node, arena| {
-            build_node_by_id(node.as_mut(), stable_id, graph, arena, ctx)
+        .with_element_taken(target_key, |node, arena| {
+            let next_state = node.build(
+                graph,
+                arena,
+                UiBuildContext::from_parts(ctx.viewport(), ctx.state_clone()),
+            );
+            ctx.set_state(next_state);
+            true
         })
         .unwrap_or(false)
 }
@@ -95,18 +67,8 @@ pub fn get_ime_cursor_rect_by_id(
     root_key: crate::view::node_arena::NodeKey,
     stable_id: u64,
 ) -> Option<(f32, f32, f32, f32)> {
-    let node = arena.get(root_key)?;
-    if node.element.stable_id() == stable_id {
-        return node.element.ime_cursor_rect();
-    }
-    let children: Vec<_> = node.children.clone();
-    drop(node);
-    for child_key in children {
-        if let Some(rect) = get_ime_cursor_rect_by_id(arena, child_key, stable_id) {
-            return Some(rect);
-        }
-    }
-    None
+    let key = arena.find_in_subtree_by_stable_id(root_key, stable_id)?;
+    arena.get(key)?.element.ime_cursor_rect()
 }
 
 pub fn get_cursor_by_id(
@@ -114,18 +76,8 @@ pub fn get_cursor_by_id(
     root_key: crate::view::node_arena::NodeKey,
     stable_id: u64,
 ) -> Option<crate::style::Cursor> {
-    let node = arena.get(root_key)?;
-    if node.element.stable_id() == stable_id {
-        return Some(node.element.cursor());
-    }
-    let children: Vec<_> = node.children.clone();
-    drop(node);
-    for child_key in children {
-        if let Some(cursor) = get_cursor_by_id(arena, child_key, stable_id) {
-            return Some(cursor);
-        }
-    }
-    None
+    let key = arena.find_in_subtree_by_stable_id(root_key, stable_id)?;
+    Some(arena.get(key)?.element.cursor())
 }
 
 pub(crate) fn select_all_text_by_id(
@@ -133,23 +85,17 @@ pub(crate) fn select_all_text_by_id(
     root_key: crate::view::node_arena::NodeKey,
     node_id: u64,
 ) -> bool {
+    let Some(key) = arena.find_in_subtree_by_stable_id(root_key, node_id) else {
+        return false;
+    };
     arena
-        .mutate_element_ref_with_invalidation(root_key, |element, cx| {
-            if element.stable_id() == node_id {
-                if let Some(text_area) = element.as_any_mut().downcast_mut::<TextArea>() {
-                    text_area.select_all();
-                    cx.invalidate(element.local_dirty_flags());
-                    return true;
-                }
+        .mutate_element_ref_with_invalidation(key, |element, cx| {
+            let Some(text_area) = element.as_any_mut().downcast_mut::<TextArea>() else {
                 return false;
-            }
-            let children: Vec<_> = element.children().to_vec();
-            for child_key in children {
-                if select_all_text_by_id(cx.arena(), child_key, node_id) {
-                    return true;
-                }
-            }
-            false
+            };
+            text_area.select_all();
+            cx.invalidate(element.local_dirty_flags());
+            true
         })
         .unwrap_or(false)
 }
@@ -161,23 +107,17 @@ pub(crate) fn select_text_range_by_id(
     start: usize,
     end: usize,
 ) -> bool {
+    let Some(key) = arena.find_in_subtree_by_stable_id(root_key, node_id) else {
+        return false;
+    };
     arena
-        .mutate_element_ref_with_invalidation(root_key, |element, cx| {
-            if element.stable_id() == node_id {
-                if let Some(text_area) = element.as_any_mut().downcast_mut::<TextArea>() {
-                    text_area.select_range(start, end);
-                    cx.invalidate(element.local_dirty_flags());
-                    return true;
-                }
+        .mutate_element_ref_with_invalidation(key, |element, cx| {
+            let Some(text_area) = element.as_any_mut().downcast_mut::<TextArea>() else {
                 return false;
-            }
-            let children: Vec<_> = element.children().to_vec();
-            for child_key in children {
-                if select_text_range_by_id(cx.arena(), child_key, node_id, start, end) {
-                    return true;
-                }
-            }
-            false
+            };
+            text_area.select_range(start, end);
+            cx.invalidate(element.local_dirty_flags());
+            true
         })
         .unwrap_or(false)
 }
@@ -383,6 +323,15 @@ macro_rules! forward_event_target {
         ) {
             self.$field.dispatch_context_menu(event, control, arena, self_key);
         }
+        fn dispatch_long_press(
+            &mut self,
+            event: &mut $crate::ui::LongPressEvent,
+            control: &mut $crate::view::viewport::ViewportControl<'_>,
+            arena: &$crate::view::node_arena::NodeArena,
+            self_key: $crate::view::node_arena::NodeKey,
+        ) {
+            self.$field.dispatch_long_press(event, control, arena, self_key);
+        }
         fn dispatch_wheel(
             &mut self,
             event: &mut $crate::ui::WheelEvent,
@@ -566,6 +515,30 @@ macro_rules! forward_event_target {
         fn cursor(&self) -> $crate::style::Cursor {
             self.$field.cursor()
         }
+        fn app_region(&self) -> $crate::style::AppRegion {
+            self.$field.app_region()
+        }
+        fn long_press_hold(&self) -> Option<$crate::time::Duration> {
+            self.$field.long_press_hold()
+        }
+        fn hover_intent(&self) -> Option<$crate::view::viewport::HoverIntent> {
+            self.$field.hover_intent()
+        }
+        fn spatial_navigation(&self) -> bool {
+            self.$field.spatial_navigation()
+        }
+        fn spatial_focusable(&self) -> bool {
+            self.$field.spatial_focusable()
+        }
+        fn observes_visibility(&self) -> bool {
+            self.$field.observes_visibility()
+        }
+        fn dispatch_visibility(&mut self, ratio: f32) {
+            self.$field.dispatch_visibility(ratio);
+        }
+        fn scrollport_rect(&self) -> Option<$crate::ui::Rect> {
+            self.$field.scrollport_rect()
+        }
         fn wants_animation_frame(&self) -> bool {
             self.$field.wants_animation_frame()
         }
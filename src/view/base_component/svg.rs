@@ -1256,6 +1256,14 @@ impl ElementTrait for Svg {
         self.element.stable_id()
     }
 
+    fn media_style_stale(&self) -> bool {
+        self.element.media_style_stale()
+    }
+
+    fn refresh_media_style(&mut self) {
+        self.element.refresh_media_style();
+    }
+
     fn retained_scroll_normalized_paint_capability(
         &self,
     ) -> Option<super::RetainedScrollNormalizedPaintCapability> {
@@ -1595,6 +1603,10 @@ impl ElementTrait for Svg {
         self.element.hit_test_visible_at(viewport_x, viewport_y)
     }
 
+    fn hit_shape_contains(&self, viewport_x: f32, viewport_y: f32) -> bool {
+        self.element.hit_shape_contains(viewport_x, viewport_y)
+    }
+
     fn has_active_animator(&self) -> bool {
         self.element.has_active_animator()
     }
@@ -1917,6 +1929,10 @@ impl Layoutable for Svg {
         self.element.measured_size()
     }
 
+    fn last_measure_constraints(&self) -> Option<LayoutConstraints> {
+        self.element.last_measure_constraints()
+    }
+
     fn set_layout_width(&mut self, width: f32) {
         self.element.set_layout_width(width);
     }
@@ -13,6 +13,10 @@ impl Layoutable for Text {
         (self.size.width, self.size.height)
     }
 
+    fn last_measure_constraints(&self) -> Option<LayoutConstraints> {
+        self.last_layout_constraints
+    }
+
     fn set_layout_width(&mut self, width: f32) {
         self.layout_override_width = Some(width.max(0.0));
     }
@@ -1048,8 +1048,6 @@ impl ElementTrait for Text {
         self
     }
 
-    // Phase B: snapshot_state / restore_state removed (see ElementTrait def).
-
     fn retained_paint_properties(&self) -> super::RetainedPaintProperties {
         super::RetainedPaintProperties {
             opacity: self.opacity,
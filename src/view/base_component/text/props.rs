@@ -12,8 +12,10 @@ impl Text {
         for (key, value) in node.props.iter() {
             match *key {
                 // Cold-path shell owns identity, layered style, and
-                // cascade-resolved font_size.
-                "key" | "style" | "font_size" => {}
+                // cascade-resolved font_size; `test_id` and
+                // `reading_order` are only read back from the RSX tree by
+                // `ElementQuery`.
+                "key" | "style" | "font_size" | "test_id" | "reading_order" => {}
                 "line_height" => self.set_line_height(as_f32(value, key)?),
                 "align" => self.set_text_align(as_text_align(value, key)?),
                 "font" => self.set_font(as_string(value, key)?),
@@ -104,6 +106,7 @@ impl Text {
                 self.set_font(family);
                 PropApplyOutcome::Applied
             }
+            "test_id" | "reading_order" => PropApplyOutcome::Applied,
             _ => PropApplyOutcome::UnknownProp,
         }
     }
@@ -123,6 +126,7 @@ impl Text {
                 self.set_opacity(1.0);
                 PropApplyOutcome::Applied
             }
+            "test_id" | "reading_order" => PropApplyOutcome::Applied,
             "style" => {
                 // 軌 1 #8: `style` removed entirely. Reset every
                 // explicit flag and replay ancestor cascade so all
@@ -160,7 +160,7 @@ impl Text {
             opacity,
             0,
             1.0,
-            Some(self.color.to_rgba_f32()),
+            Some(self.color.to_output_rgba_f32()),
         );
         let params = (!staging_input.glyphs.is_empty()).then(|| TextPassPreparedParams {
             staging_input,
@@ -292,7 +292,7 @@ impl Text {
                 opacity,
                 0,
                 1.0,
-                Some(self.color.to_rgba_f32()),
+                Some(self.color.to_output_rgba_f32()),
             ),
         )
     }
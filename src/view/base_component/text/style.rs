@@ -1,8 +1,8 @@
 //! Text typography setters + style/inherited cascade.
 
 use crate::style::{
-    ColorLike, ComputedStyle, Cursor, Length, SizeValue, Style, StyleComputeContext, TextAlign,
-    TextWrap, compute_style_with_context,
+    ColorLike, ComputedStyle, Cursor, Length, MediaContext, SizeValue, Style, StyleComputeContext,
+    TextAlign, TextWrap, compute_style_with_context,
 };
 use crate::view::base_component::{DirtyFlags, Position, Size};
 use crate::view::inline_formatting_context::InlineIfcAlignment;
@@ -53,6 +53,7 @@ impl TextComputedStyleBridge {
                 viewport_height: inherited.viewport_height,
                 root_font_size: inherited.root_font_size,
                 hovered: false,
+                media: MediaContext::current(),
             },
         );
 
@@ -97,6 +98,7 @@ fn authored_size_value_px(
         SizeValue::Auto => Ok(None),
         SizeValue::Length(Length::Px(value)) => Ok(Some(value)),
         SizeValue::Length(Length::Zero) => Ok(Some(0.0)),
+        SizeValue::Length(Length::Hairline) => Ok(Some(1.0)),
         SizeValue::Length(length @ Length::Calc(_)) => {
             if length.needs_percent_base() {
                 return Err(format!("{context} does not support relative length"));
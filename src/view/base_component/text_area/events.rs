@@ -765,14 +765,11 @@ fn set_platform_ime(meta: &EventMeta, enabled: bool) {
 }
 
 fn set_platform_ime_cursor_rect(text_area: &TextArea, meta: &EventMeta, arena: &NodeArena) {
-    if !text_area.is_focused {
-        return;
-    }
-    let Some((x, y, height)) = text_area.caret_screen_position(arena) else {
+    let Some((x, y, width, height)) = text_area.refresh_ime_cursor_rect(arena) else {
         return;
     };
     let mut vp = meta.viewport();
-    vp.ime_command(ImeCommand::SetCursorRect(x, y, 1.0, height.max(1.0)));
+    vp.ime_command(ImeCommand::SetCursorRect(x, y, width, height));
 }
 
 impl EventTarget for TextArea {
@@ -780,10 +777,21 @@ impl EventTarget for TextArea {
         self.cursor
     }
 
+    fn ime_cursor_rect(&self) -> Option<(f32, f32, f32, f32)> {
+        if !self.is_focused {
+            return None;
+        }
+        self.ime_cursor_rect.get()
+    }
+
     fn wants_animation_frame(&self) -> bool {
         self.is_focused && self.layout_state.should_render
     }
 
+    fn spatial_focusable(&self) -> bool {
+        true
+    }
+
     fn block_key_down_child_event(&self) -> bool {
         true
     }
@@ -922,11 +930,14 @@ impl EventTarget for TextArea {
     ) {
         // Blur commits any in-flight preedit (v2 divergence from v1,
         // which dropped it) so the user's composing text isn't lost when
-        // focus leaves. Selection clears + drag-select ends as in v1;
+        // focus leaves; `commit_preedit_on_blur={false}` restores the drop.
+        // Selection clears + drag-select ends as in v1;
         // `set_focused(false)` already resets `pointer_selecting`.
         self.set_focused(false);
         self.clear_selection();
-        if self.commit_preedit() {
+        if !self.commit_preedit_on_blur {
+            self.clear_preedit();
+        } else if self.commit_preedit() {
             self.notify_change_handlers();
         }
         self.route_preedit_to_runs(arena);
@@ -944,6 +955,12 @@ impl EventTarget for TextArea {
         arena: &NodeArena,
         _self_key: NodeKey,
     ) {
+        for handler in &self.on_key_down_handlers {
+            handler.call(event);
+        }
+        if event.meta.default_prevented() {
+            return;
+        }
         // Decision A7 / P4.1: keep keydown inert while the IME is composing.
         // Otherwise Enter / Backspace / arrows would mutate committed text
         // before the platform either commits or cancels the preedit.
@@ -1135,6 +1152,7 @@ impl EventTarget for TextArea {
             self.clear_preedit();
         } else {
             self.set_preedit(event.text.clone(), event.cursor);
+            self.set_preedit_attributes(&event.attributes);
         }
         self.route_preedit_to_runs(arena);
         self.scroll_caret_into_view(arena);
@@ -1244,7 +1262,10 @@ impl EventTarget for TextArea {
         arena: &NodeArena,
         _self_key: NodeKey,
     ) {
-        if self.read_only {
+        for handler in &self.on_paste_handlers {
+            handler.call(event);
+        }
+        if self.read_only || event.meta.default_prevented() {
             event.meta.stop_propagation();
             return;
         }
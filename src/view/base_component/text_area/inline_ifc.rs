@@ -544,11 +544,31 @@ impl TextAreaUnifiedIfcRootPackage {
         out
     }
 
-    pub(crate) fn preedit_underline_rects(&self) -> Vec<Rect> {
+    /// Underline rects for the spliced preedit. `attributes` are the IME
+    /// clause styles (byte offsets into the preedit); without any, the
+    /// whole preedit gets a plain underline. Every rect is 1px tall so the
+    /// retained underline payload stays a list of hairlines.
+    pub(crate) fn preedit_underline_rects(
+        &self,
+        attributes: &[crate::ui::PreeditAttribute],
+    ) -> Vec<Rect> {
         self.source_segments
             .iter()
             .filter_map(|segment| segment.preedit_backing_byte_range.clone())
-            .flat_map(|range| self.underline_rects_for_backing_byte_range(range))
+            .flat_map(|range| {
+                if attributes.is_empty() {
+                    return self.underline_rects_for_backing_byte_range(range);
+                }
+                attributes
+                    .iter()
+                    .flat_map(|attribute| {
+                        let start = (range.start + attribute.start).min(range.end);
+                        let end = (range.start + attribute.end).min(range.end);
+                        let clause = self.underline_rects_for_backing_byte_range(start..end);
+                        styled_preedit_underline(clause, attribute.style)
+                    })
+                    .collect()
+            })
             .collect()
     }
 
@@ -2059,6 +2079,47 @@ fn caret_affinity_from_ifc(affinity: InlineIfcCaretAffinity) -> super::caret_map
     }
 }
 
+/// Turn plain clause underlines into the IME clause look: highlighted
+/// (target) clauses get a double-thickness line, dotted clauses a dashed
+/// one. Clauses are inset by 1px on the right so adjacent clauses read as
+/// separate segments.
+fn styled_preedit_underline(rects: Vec<Rect>, style: crate::ui::PreeditStyle) -> Vec<Rect> {
+    const DASH: f32 = 2.0;
+    const CLAUSE_GAP: f32 = 1.0;
+    let mut out = Vec::with_capacity(rects.len());
+    for rect in rects {
+        let width = if rect.width > CLAUSE_GAP * 2.0 {
+            rect.width - CLAUSE_GAP
+        } else {
+            rect.width
+        };
+        let rect = Rect { width, ..rect };
+        match style {
+            crate::ui::PreeditStyle::Underline => out.push(rect),
+            crate::ui::PreeditStyle::Highlight => {
+                out.push(rect);
+                out.push(Rect {
+                    y: rect.y - rect.height,
+                    ..rect
+                });
+            }
+            crate::ui::PreeditStyle::DottedUnderline => {
+                let right = rect.x + rect.width;
+                let mut x = rect.x;
+                while x < right {
+                    out.push(Rect {
+                        x,
+                        width: DASH.min(right - x),
+                        ..rect
+                    });
+                    x += DASH * 2.0;
+                }
+            }
+        }
+    }
+    out
+}
+
 #[cfg(test)]
 mod tests {
     use super::super::run::InlinePreedit;
@@ -2256,10 +2317,46 @@ mod tests {
                     .expect("text run segment");
                 assert_eq!(segment.preedit_backing_byte_range, Some(2.."he中".len()));
                 assert!(
-                    !package.preedit_underline_rects().is_empty(),
+                    !package.preedit_underline_rects(&[]).is_empty(),
                     "root package should expose underline rects for the spliced preedit"
                 );
             })
             .expect("TextArea root");
     }
+
+    #[test]
+    fn preedit_clause_styles_stay_hairlines() {
+        let clause = vec![Rect {
+            x: 10.0,
+            y: 20.0,
+            width: 11.0,
+            height: 1.0,
+        }];
+
+        let plain = styled_preedit_underline(clause.clone(), crate::ui::PreeditStyle::Underline);
+        assert_eq!(plain.len(), 1);
+        assert_eq!(plain[0].width, 10.0, "clauses keep a 1px gap on the right");
+
+        let target = styled_preedit_underline(clause.clone(), crate::ui::PreeditStyle::Highlight);
+        assert_eq!(
+            target.iter().map(|rect| rect.y).collect::<Vec<_>>(),
+            vec![20.0, 19.0]
+        );
+
+        let dotted = styled_preedit_underline(clause, crate::ui::PreeditStyle::DottedUnderline);
+        assert_eq!(
+            dotted
+                .iter()
+                .map(|rect| (rect.x, rect.width))
+                .collect::<Vec<_>>(),
+            vec![(10.0, 2.0), (14.0, 2.0), (18.0, 2.0)]
+        );
+        assert!(
+            plain
+                .iter()
+                .chain(&target)
+                .chain(&dotted)
+                .all(|rect| rect.height == 1.0)
+        );
+    }
 }
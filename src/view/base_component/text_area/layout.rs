@@ -146,6 +146,9 @@ impl Layoutable for TextArea {
             }
             self.scroll_caret_into_ancestor_views(arena);
         }
+        // Placement also runs when an ancestor scrolls or moves, so this
+        // keeps the IME candidate window docked to the caret.
+        self.refresh_ime_cursor_rect(arena);
         self.dirty_flags = self.dirty_flags.without(
             DirtyFlags::PLACE
                 .union(DirtyFlags::BOX_MODEL)
@@ -160,6 +163,10 @@ impl Layoutable for TextArea {
         )
     }
 
+    fn last_measure_constraints(&self) -> Option<LayoutConstraints> {
+        self.last_measure_constraints
+    }
+
     fn set_layout_width(&mut self, width: f32) {
         self.layout_state.layout_size.width = width.max(0.0);
         self.viewport_size.width = self
@@ -748,6 +748,9 @@ pub struct TextArea {
     pub(crate) placeholder: String,
     pub(crate) placeholder_color: crate::style::Color,
     pub(crate) read_only: bool,
+    /// Blur inserts an in-flight preedit into `content` (default) instead
+    /// of dropping it.
+    pub(crate) commit_preedit_on_blur: bool,
     pub(crate) multiline: bool,
     pub(crate) auto_wrap: bool,
     pub(crate) max_length: Option<usize>,
@@ -779,6 +782,13 @@ pub struct TextArea {
     pub(crate) pending_caret_scroll: bool,
     pub(crate) ime_preedit: String,
     pub(crate) ime_preedit_cursor: Option<(usize, usize)>,
+    /// Clause styles reported by the IME, byte offsets into `ime_preedit`.
+    /// Empty means "underline the whole preedit".
+    pub(crate) ime_preedit_attributes: Vec<crate::ui::PreeditAttribute>,
+    /// Last caret rect pushed to the platform IME, in viewport
+    /// coordinates. Refreshed on every placement so the candidate window
+    /// follows the caret when the field or an ancestor scrolls.
+    pub(crate) ime_cursor_rect: std::cell::Cell<Option<(f32, f32, f32, f32)>>,
     pub(crate) vertical_cursor_x: Option<f32>,
     /// Retained caret phase consumed by paint and metadata.
     pub(crate) caret_visible: bool,
@@ -830,6 +840,10 @@ pub struct TextArea {
     pub(crate) on_change_handlers: Vec<TextChangeHandlerProp>,
     pub(crate) on_focus_handlers: Vec<TextAreaFocusHandlerProp>,
     pub(crate) on_blur_handlers: Vec<BlurHandlerProp>,
+    /// Run before the built-in editing keys; `prevent_default` skips them.
+    pub(crate) on_key_down_handlers: Vec<crate::ui::KeyDownHandlerProp>,
+    /// Run before pasted text is inserted; `prevent_default` skips it.
+    pub(crate) on_paste_handlers: Vec<crate::ui::PasteHandlerProp>,
 
     // identity
     pub(crate) node_id: u64,
@@ -843,6 +857,7 @@ impl Default for TextArea {
             placeholder: String::new(),
             placeholder_color: crate::style::Color::rgba(125, 133, 150, 255),
             read_only: false,
+            commit_preedit_on_blur: true,
             multiline: true,
             auto_wrap: true,
             max_length: None,
@@ -871,6 +886,8 @@ impl Default for TextArea {
             pending_caret_scroll: false,
             ime_preedit: String::new(),
             ime_preedit_cursor: None,
+            ime_preedit_attributes: Vec::new(),
+            ime_cursor_rect: std::cell::Cell::new(None),
             vertical_cursor_x: None,
             caret_visible: false,
             caret_blink_epoch: None,
@@ -900,6 +917,8 @@ impl Default for TextArea {
             on_change_handlers: Vec::new(),
             on_focus_handlers: Vec::new(),
             on_blur_handlers: Vec::new(),
+            on_key_down_handlers: Vec::new(),
+            on_paste_handlers: Vec::new(),
 
             node_id: next_ui_node_id(),
             parent_id: None,
@@ -1424,6 +1443,18 @@ impl ElementTrait for TextArea {
         self.dirty_flags = self.dirty_flags.without(flags);
     }
 
+    fn snapshot_state(&self) -> Option<Box<dyn std::any::Any>> {
+        self.interaction_snapshot()
+            .map(|snapshot| Box::new(snapshot) as Box<dyn std::any::Any>)
+    }
+
+    fn restore_state(&mut self, snapshot: Box<dyn std::any::Any>) -> DirtyFlags {
+        match snapshot.downcast::<state::TextAreaInteractionSnapshot>() {
+            Ok(snapshot) => self.restore_interaction_snapshot(*snapshot),
+            Err(_) => DirtyFlags::NONE,
+        }
+    }
+
     /// Hash every visible-state field so retained paint generations advance
     /// on edit, cursor, selection, IME, focus, and blink changes.
     fn retained_paint_signature(&self) -> u64 {
@@ -1476,6 +1507,7 @@ impl ElementTrait for TextArea {
         self.scroll_y.to_bits().hash(&mut hasher);
         self.ime_preedit.hash(&mut hasher);
         self.ime_preedit_cursor.hash(&mut hasher);
+        self.ime_preedit_attributes.hash(&mut hasher);
         self.is_focused.hash(&mut hasher);
         self.caret_visible.hash(&mut hasher);
         self.children.len().hash(&mut hasher);
@@ -1548,6 +1580,7 @@ impl ElementTrait for TextArea {
                 "multiline" => self.multiline = as_bool(value, key)?,
                 "auto_wrap" => self.auto_wrap = as_bool(value, key)?,
                 "read_only" => self.read_only = as_bool(value, key)?,
+                "commit_preedit_on_blur" => self.commit_preedit_on_blur = as_bool(value, key)?,
                 "max_length" => self.max_length = as_usize(value, key)?,
                 "on_focus" => self.on_focus_handlers.push(
                     crate::ui::TextAreaFocusHandlerProp::from_prop_value(value.clone()).map_err(
@@ -1555,6 +1588,14 @@ impl ElementTrait for TextArea {
                     )?,
                 ),
                 "on_blur" => self.on_blur_handlers.push(as_blur_handler(value, key)?),
+                "on_key_down" => self.on_key_down_handlers.push(
+                    crate::ui::KeyDownHandlerProp::from_prop_value(value.clone())
+                        .map_err(|_| format!("prop `{key}` expects key down handler value"))?,
+                ),
+                "on_paste" => self.on_paste_handlers.push(
+                    crate::ui::PasteHandlerProp::from_prop_value(value.clone())
+                        .map_err(|_| format!("prop `{key}` expects paste handler value"))?,
+                ),
                 "on_change" => self.on_change_handlers.push(
                     crate::ui::TextChangeHandlerProp::from_prop_value(value.clone())
                         .map_err(|_| format!("prop `{key}` expects text change handler value"))?,
@@ -1669,6 +1710,13 @@ impl ElementTrait for TextArea {
                 self.read_only = v;
                 PropApplyOutcome::Applied
             }
+            "commit_preedit_on_blur" => {
+                let Ok(v) = bool::from_prop_value(value) else {
+                    return PropApplyOutcome::DecodeFailed(name);
+                };
+                self.commit_preedit_on_blur = v;
+                PropApplyOutcome::Applied
+            }
             "max_length" => {
                 let v = match &value {
                     crate::ui::PropValue::I64(i) => Some((*i).max(0) as usize),
@@ -1731,6 +1779,22 @@ impl ElementTrait for TextArea {
                 self.on_blur_handlers.push(handler);
                 PropApplyOutcome::Applied
             }
+            "on_key_down" => {
+                let Ok(handler) = crate::ui::KeyDownHandlerProp::from_prop_value(value) else {
+                    return PropApplyOutcome::DecodeFailed(name);
+                };
+                self.on_key_down_handlers.clear();
+                self.on_key_down_handlers.push(handler);
+                PropApplyOutcome::Applied
+            }
+            "on_paste" => {
+                let Ok(handler) = crate::ui::PasteHandlerProp::from_prop_value(value) else {
+                    return PropApplyOutcome::DecodeFailed(name);
+                };
+                self.on_paste_handlers.clear();
+                self.on_paste_handlers.push(handler);
+                PropApplyOutcome::Applied
+            }
             "on_render" => {
                 let Ok(handler) = crate::ui::TextAreaRenderHandlerProp::from_prop_value(value)
                 else {
@@ -1783,6 +1847,10 @@ impl ElementTrait for TextArea {
                 self.read_only = false;
                 PropApplyOutcome::Applied
             }
+            "commit_preedit_on_blur" => {
+                self.commit_preedit_on_blur = true;
+                PropApplyOutcome::Applied
+            }
             "max_length" => {
                 self.max_length = None;
                 PropApplyOutcome::Applied
@@ -1799,6 +1867,14 @@ impl ElementTrait for TextArea {
                 self.on_blur_handlers.clear();
                 PropApplyOutcome::Applied
             }
+            "on_key_down" => {
+                self.on_key_down_handlers.clear();
+                PropApplyOutcome::Applied
+            }
+            "on_paste" => {
+                self.on_paste_handlers.clear();
+                PropApplyOutcome::Applied
+            }
             "on_render" => {
                 self.on_render_handler = None;
                 self.mark_content_dirty();
@@ -1820,6 +1896,8 @@ fn known_prop(name: &str) -> bool {
             | "style"
             | "on_focus"
             | "on_blur"
+            | "on_key_down"
+            | "on_paste"
             | "on_change"
             | "on_render"
             | "placeholder"
@@ -1828,6 +1906,7 @@ fn known_prop(name: &str) -> bool {
             | "multiline"
             | "auto_wrap"
             | "read_only"
+            | "commit_preedit_on_blur"
             | "max_length"
     )
 }
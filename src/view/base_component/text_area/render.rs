@@ -227,7 +227,7 @@ impl TextArea {
             .end
             .min(segment.char_range.end)
             .saturating_sub(segment.char_range.start);
-        let fill = self.selection_background_color.to_rgba_f32();
+        let fill = self.selection_background_color.to_output_rgba_f32();
         let witness = crate::view::paint::PaintTextSelectionWitness {
             target_owner,
             target_stable_id,
@@ -947,7 +947,7 @@ impl TextArea {
         let Some(range) = self.exact_plain_selection_range()? else {
             return Ok(Vec::new());
         };
-        let fill = self.selection_background_color.to_rgba_f32();
+        let fill = self.selection_background_color.to_output_rgba_f32();
         package
             .selection_rects_for_char_range(range)
             .into_iter()
@@ -1021,9 +1021,9 @@ impl TextArea {
         if self.ime_preedit.is_empty() {
             return Ok(Vec::new());
         }
-        let fill = self.color.to_rgba_f32();
+        let fill = self.color.to_output_rgba_f32();
         package
-            .preedit_underline_rects()
+            .preedit_underline_rects(&self.ime_preedit_attributes)
             .into_iter()
             .map(|rect| {
                 let params = RectPassParams {
@@ -1112,7 +1112,7 @@ impl TextArea {
                 ShadowPaintBlocker::TextAreaSelection,
             ));
         }
-        let fill = self.color.to_rgba_f32();
+        let fill = self.color.to_output_rgba_f32();
         let ops = rects
             .into_iter()
             .map(|rect| {
@@ -1188,7 +1188,7 @@ impl TextArea {
         let params = RectPassParams {
             position: [x + paint_offset[0], y + paint_offset[1]],
             size: [CARET_WIDTH, line_height.max(1.0)],
-            fill_color: self.color.to_rgba_f32(),
+            fill_color: self.color.to_output_rgba_f32(),
             opacity: 1.0,
             ..Default::default()
         };
@@ -1462,6 +1462,23 @@ impl TextArea {
     /// the cursor (boundary cases prefer the *following* Run per the caret
     /// boundary rules). Falls back to TextArea's own layout origin when
     /// no Run exists (empty content, no placeholder).
+    /// Recompute the caret rect handed to the platform IME `(x, y, w, h)`
+    /// and remember it for `EventTarget::ime_cursor_rect`. `None` while
+    /// unfocused.
+    pub(crate) fn refresh_ime_cursor_rect(
+        &self,
+        arena: &NodeArena,
+    ) -> Option<(f32, f32, f32, f32)> {
+        let rect = if self.is_focused {
+            self.caret_screen_position(arena)
+                .map(|(x, y, height)| (x, y, 1.0, height.max(1.0)))
+        } else {
+            None
+        };
+        self.ime_cursor_rect.set(rect);
+        rect
+    }
+
     pub(crate) fn caret_screen_position(&self, arena: &NodeArena) -> Option<(f32, f32, f32)> {
         if self.children.is_empty() {
             // No child Run yet — caret pinned to TextArea's own origin.
@@ -1768,7 +1785,7 @@ impl TextArea {
             let origin_x = self.layout_state.layout_position.x - self.scroll_x;
             let origin_y = self.layout_state.layout_position.y - self.scroll_y;
             let rects = package
-                .preedit_underline_rects()
+                .preedit_underline_rects(&self.ime_preedit_attributes)
                 .into_iter()
                 .map(|rect| Rect {
                     x: origin_x + rect.x,
@@ -1875,7 +1892,7 @@ impl TextArea {
         Some(TextAreaSelectionRenderContext {
             start: local_start,
             end: local_end,
-            fill: self.selection_background_color.to_rgba_f32(),
+            fill: self.selection_background_color.to_output_rgba_f32(),
         })
     }
 
@@ -2011,7 +2028,7 @@ impl Renderable for TextArea {
             && !self.ime_preedit.is_empty()
             && let Some(rects) = self.projection_preedit_underline_screen_rects(arena)
         {
-            let fill = self.color.to_rgba_f32();
+            let fill = self.color.to_output_rgba_f32();
             preedit_underline_ops = rects
                 .into_iter()
                 .filter_map(|rect| {
@@ -2120,7 +2137,7 @@ impl TextArea {
             end_char: anchor.max(focus),
             color_rgba_bits: self
                 .selection_background_color
-                .to_rgba_f32()
+                .to_output_rgba_f32()
                 .map(f32::to_bits),
         };
         let atomic_source = self.exact_retained_property_scroll_atomic_projection_source(
@@ -2292,7 +2309,7 @@ impl TextArea {
                 ime_preedit_cursor: self.ime_preedit_cursor,
                 cursor_char: self.cursor_char,
                 cursor_affinity: self.cursor_affinity,
-                foreground_color_bits: self.color.to_rgba_f32().map(f32::to_bits),
+                foreground_color_bits: self.color.to_output_rgba_f32().map(f32::to_bits),
                 glyph_bounds_bits: [
                     payload.glyph_bounds.x,
                     payload.glyph_bounds.y,
@@ -2328,7 +2345,7 @@ impl TextArea {
                     || caret.op.params.size[1] <= 0.0
                     || caret.op.params.opacity.to_bits() != 1.0_f32.to_bits()
                     || caret.op.params.fill_color.map(f32::to_bits)
-                        != self.color.to_rgba_f32().map(f32::to_bits)
+                        != self.color.to_output_rgba_f32().map(f32::to_bits)
                 {
                     return None;
                 }
@@ -2353,7 +2370,7 @@ impl TextArea {
                 focused: self.is_focused,
                 should_render: self.layout_state.should_render,
                 caret_visible: self.caret_visible,
-                foreground_color_bits: self.color.to_rgba_f32().map(f32::to_bits),
+                foreground_color_bits: self.color.to_output_rgba_f32().map(f32::to_bits),
                 cursor_char: self.cursor_char,
                 cursor_affinity: self.cursor_affinity,
                 ime_preedit_cursor: self.ime_preedit_cursor,
@@ -2743,7 +2760,7 @@ impl TextArea {
             end_char: range.end,
             color_rgba_bits: self
                 .selection_background_color
-                .to_rgba_f32()
+                .to_output_rgba_f32()
                 .map(f32::to_bits),
         })
     }
@@ -2852,7 +2869,7 @@ impl TextArea {
                         end_char: range.end,
                         color_rgba_bits: self
                             .selection_background_color
-                            .to_rgba_f32()
+                            .to_output_rgba_f32()
                             .map(f32::to_bits),
                     },
                 )
@@ -2949,7 +2966,7 @@ impl TextArea {
                 .get()
                 .map(|(x, y, revision)| (x.to_bits(), y.to_bits(), revision)),
             generated_topology: generated_topology.into(),
-            foreground_color_bits: self.color.to_rgba_f32().map(f32::to_bits),
+            foreground_color_bits: self.color.to_output_rgba_f32().map(f32::to_bits),
             glyph_bounds_bits: [
                 actual_payload.glyph_bounds.x,
                 actual_payload.glyph_bounds.y,
@@ -3077,7 +3094,7 @@ impl TextArea {
                 focused: self.is_focused,
                 should_render: self.layout_state.should_render,
                 caret_visible: self.caret_visible,
-                foreground_color_bits: self.color.to_rgba_f32().map(f32::to_bits),
+                foreground_color_bits: self.color.to_output_rgba_f32().map(f32::to_bits),
                 cursor_char: self.cursor_char,
                 cursor_affinity: self.cursor_affinity,
                 ime_preedit_cursor: self.ime_preedit_cursor,
@@ -261,6 +261,32 @@ impl TextArea {
         true
     }
 
+    /// Replace the IME clause styles of the current preedit. Spans that are
+    /// empty, out of range or split a char are dropped. Returns `true` when
+    /// the styles changed.
+    pub(super) fn set_preedit_attributes(
+        &mut self,
+        attributes: &[crate::ui::PreeditAttribute],
+    ) -> bool {
+        let preedit = self.ime_preedit.as_str();
+        let attributes = attributes
+            .iter()
+            .copied()
+            .filter(|attribute| {
+                attribute.start < attribute.end
+                    && attribute.end <= preedit.len()
+                    && preedit.is_char_boundary(attribute.start)
+                    && preedit.is_char_boundary(attribute.end)
+            })
+            .collect::<Vec<_>>();
+        if self.ime_preedit_attributes == attributes {
+            return false;
+        }
+        self.ime_preedit_attributes = attributes;
+        self.dirty_flags = self.dirty_flags.union(DirtyFlags::PAINT);
+        true
+    }
+
     /// Commit the active preedit by inserting it into `content` and
     /// clearing preedit state. Mirrors v1's
     /// `commit_preedit_preserving_render_fragments`. Returns `true` if
@@ -272,11 +298,13 @@ impl TextArea {
         }
         let text = std::mem::take(&mut self.ime_preedit);
         self.ime_preedit_cursor = None;
+        self.ime_preedit_attributes.clear();
         self.dirty_flags = self.dirty_flags.union(DirtyFlags::ALL);
         self.insert_text(&text)
     }
 
     pub(super) fn clear_preedit(&mut self) -> bool {
+        self.ime_preedit_attributes.clear();
         if self.ime_preedit.is_empty() && self.ime_preedit_cursor.is_none() {
             return false;
         }
@@ -310,6 +338,74 @@ impl TextArea {
         }
         true
     }
+
+    pub(super) fn interaction_snapshot(&self) -> Option<TextAreaInteractionSnapshot> {
+        let untouched = self.cursor_char == 0
+            && self.selection_anchor_char.is_none()
+            && self.selection_focus_char.is_none()
+            && !self.is_focused
+            && self.scroll_x == 0.0
+            && self.scroll_y == 0.0
+            && self.ime_preedit.is_empty();
+        if untouched {
+            return None;
+        }
+        Some(TextAreaInteractionSnapshot {
+            cursor_char: self.cursor_char,
+            cursor_affinity: self.cursor_affinity,
+            selection_anchor_char: self.selection_anchor_char,
+            selection_focus_char: self.selection_focus_char,
+            vertical_cursor_x: self.vertical_cursor_x,
+            is_focused: self.is_focused,
+            scroll: (self.scroll_x, self.scroll_y),
+            ime_preedit: self.ime_preedit.clone(),
+            ime_preedit_cursor: self.ime_preedit_cursor,
+            ime_preedit_attributes: self.ime_preedit_attributes.clone(),
+        })
+    }
+
+    /// Re-seed caret, selection, focus, scroll and composition from the
+    /// instance this one replaces. Char indices are clamped to the new
+    /// content, and a preedit only survives while the field is focused.
+    pub(super) fn restore_interaction_snapshot(
+        &mut self,
+        snapshot: TextAreaInteractionSnapshot,
+    ) -> DirtyFlags {
+        self.cursor_char = self.clamp_char(snapshot.cursor_char);
+        self.cursor_affinity = snapshot.cursor_affinity;
+        self.selection_anchor_char = snapshot.selection_anchor_char.map(|c| self.clamp_char(c));
+        self.selection_focus_char = snapshot.selection_focus_char.map(|c| self.clamp_char(c));
+        self.vertical_cursor_x = snapshot.vertical_cursor_x;
+        self.is_focused = snapshot.is_focused;
+        self.scroll_x = snapshot.scroll.0;
+        self.scroll_y = snapshot.scroll.1;
+        if snapshot.is_focused && !snapshot.ime_preedit.is_empty() {
+            self.children_dirty = true;
+            self.ime_preedit = snapshot.ime_preedit;
+            self.ime_preedit_cursor = snapshot.ime_preedit_cursor;
+            self.ime_preedit_attributes = snapshot.ime_preedit_attributes;
+            self.bump_unified_ifc_source_revision();
+        }
+        self.reset_caret_blink();
+        self.dirty_flags = self.dirty_flags.union(DirtyFlags::ALL);
+        DirtyFlags::ALL
+    }
+}
+
+/// Interaction state carried from a replaced TextArea to its successor
+/// (see `ElementTrait::snapshot_state`). Content is not part of it: the
+/// new instance takes its text from props or its binding.
+pub(super) struct TextAreaInteractionSnapshot {
+    cursor_char: usize,
+    cursor_affinity: CaretAffinity,
+    selection_anchor_char: Option<usize>,
+    selection_focus_char: Option<usize>,
+    vertical_cursor_x: Option<f32>,
+    is_focused: bool,
+    scroll: (f32, f32),
+    ime_preedit: String,
+    ime_preedit_cursor: Option<(usize, usize)>,
+    ime_preedit_attributes: Vec<crate::ui::PreeditAttribute>,
 }
 
 #[cfg(test)]
@@ -1,7 +1,7 @@
 //! TextArea local style bridge.
 
 use crate::style::{
-    ComputedStyle, PropertyId, Style, StyleComputeContext, compute_style_with_context,
+    ComputedStyle, MediaContext, PropertyId, Style, StyleComputeContext, compute_style_with_context,
 };
 use crate::view::renderer_adapter::{StyleCascadeContext, computed_parent_from_style_cascade};
 
@@ -42,6 +42,7 @@ impl TextAreaComputedStyleBridge {
                 viewport_height: inherited.viewport_height,
                 root_font_size: inherited.root_font_size,
                 hovered: false,
+                media: MediaContext::current(),
             },
         );
 
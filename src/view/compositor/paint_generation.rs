@@ -29,6 +29,7 @@ struct NodeGenerationRecord {
     observed_transform_generation: Option<u64>,
     observed_effect_generation: Option<u64>,
     observed_scroll_generation: Option<u64>,
+    observed_baked_opacity_bits: Option<u32>,
     observed_parent: Option<NodeKey>,
     observed_children: Vec<NodeKey>,
     coverage: PaintGenerationCoverage,
@@ -59,6 +60,9 @@ pub(crate) struct PaintGenerationTracker {
     observed_roots: Vec<NodeKey>,
     root_topology_revision: u64,
     epoch: u64,
+    /// `next_revision` when the current frame began; any revision above it
+    /// was allocated during this frame.
+    frame_start_revision: u64,
 }
 
 #[derive(Clone, Copy, Debug, PartialEq, Eq)]
@@ -130,6 +134,7 @@ impl PaintGenerationTracker {
 
     pub(crate) fn begin_frame(&mut self, roots: &[NodeKey]) {
         self.epoch = self.epoch.wrapping_add(1);
+        self.frame_start_revision = self.next_revision;
         if self.observed_roots != roots {
             self.root_topology_revision = self.allocate_revision();
             self.observed_roots.clear();
@@ -150,6 +155,9 @@ impl PaintGenerationTracker {
         let transform_generation = property_trees.transform_generation_for_owner(key);
         let effect_generation = property_trees.effect_generation_for_owner(key);
         let scroll_generation = property_trees.scroll_generation_for_owner(key);
+        // Opacity the property trees bake into the node's own pass colors is
+        // self paint rather than composite state.
+        let baked_opacity_bits = property_trees.baked_opacity_for(key).map(f32::to_bits);
 
         let (self_paint_revision, composite_revision, topology_revision) =
             if self.nodes.contains_key(&key) {
@@ -167,7 +175,8 @@ impl PaintGenerationTracker {
                             || previous.coverage != coverage
                             || previous.observed_self_signature != self_signature
                             || previous.observed_transform_generation != transform_generation
-                            || previous.observed_scroll_generation != scroll_generation,
+                            || previous.observed_scroll_generation != scroll_generation
+                            || previous.observed_baked_opacity_bits != baked_opacity_bits,
                         previous.coverage != coverage
                             || previous.observed_effect_generation != effect_generation,
                         !previous.active
@@ -212,6 +221,7 @@ impl PaintGenerationTracker {
             record.observed_transform_generation = transform_generation;
             record.observed_effect_generation = effect_generation;
             record.observed_scroll_generation = scroll_generation;
+            record.observed_baked_opacity_bits = baked_opacity_bits;
             record.observed_parent = parent;
             if record.observed_children.as_slice() != children {
                 record.observed_children.clear();
@@ -231,6 +241,7 @@ impl PaintGenerationTracker {
                     observed_transform_generation: transform_generation,
                     observed_effect_generation: effect_generation,
                     observed_scroll_generation: scroll_generation,
+                    observed_baked_opacity_bits: baked_opacity_bits,
                     observed_parent: parent,
                     observed_children: children.to_vec(),
                     coverage,
@@ -266,6 +277,19 @@ impl PaintGenerationTracker {
         self.nodes.retain(|key, _| arena.contains_key(*key));
     }
 
+    /// Live nodes whose self paint revision advanced during the current
+    /// frame, newly observed nodes included. Untracked hosts advance every
+    /// frame whether or not they changed, so they are left out.
+    pub(crate) fn repainted_this_frame(&self) -> impl Iterator<Item = NodeKey> + '_ {
+        self.nodes.iter().filter_map(|(&key, record)| {
+            (record.active
+                && record.last_seen_epoch == self.epoch
+                && record.coverage == PaintGenerationCoverage::RetainedSignatureObserved
+                && record.self_paint_revision > self.frame_start_revision)
+                .then_some(key)
+        })
+    }
+
     pub(crate) fn root_topology_revision_value(&self) -> u64 {
         self.root_topology_revision
     }
@@ -325,6 +349,7 @@ impl PaintGenerationTracker {
             // ancient live record.
             self.nodes.clear();
             self.next_revision = 1;
+            self.frame_start_revision = 0;
         }
         self.next_revision
     }
@@ -437,6 +462,35 @@ mod tests {
         assert_eq!(tracker.snapshot(root).unwrap(), first);
     }
 
+    #[test]
+    fn repainted_this_frame_reports_only_nodes_whose_paint_changed() {
+        let mut arena = NodeArena::new();
+        let root = insert_element(&mut arena, 1);
+        let child = insert_element(&mut arena, 2);
+        attach(&mut arena, root, child);
+        let mut trees = PropertyTrees::default();
+        let mut tracker = PaintGenerationTracker::default();
+
+        sync(&mut tracker, &mut trees, &arena, &[root]);
+        let mut first = tracker.repainted_this_frame().collect::<Vec<_>>();
+        first.sort();
+        let mut expected = vec![root, child];
+        expected.sort();
+        assert_eq!(first, expected);
+
+        sync(&mut tracker, &mut trees, &arena, &[root]);
+        assert_eq!(tracker.repainted_this_frame().count(), 0);
+
+        mutate_element(&arena, child, |element| {
+            element.set_background_color_value(crate::style::Color::rgba(1, 2, 3, 255));
+        });
+        sync(&mut tracker, &mut trees, &arena, &[root]);
+        assert_eq!(
+            tracker.repainted_this_frame().collect::<Vec<_>>(),
+            vec![child]
+        );
+    }
+
     #[test]
     fn unchanged_topology_reuses_observed_children_allocation() {
         let mut arena = NodeArena::new();
@@ -491,6 +545,27 @@ mod tests {
         assert_eq!(second.topology_revision, first.topology_revision);
     }
 
+    #[test]
+    fn baked_opacity_change_bumps_self_paint() {
+        let mut arena = NodeArena::new();
+        let root = insert_element(&mut arena, 1);
+        let leaf = insert_element(&mut arena, 2);
+        attach(&mut arena, root, leaf);
+        let mut trees = PropertyTrees::default();
+        let mut tracker = PaintGenerationTracker::default();
+        mutate_element(&arena, leaf, |element| element.set_opacity(0.5));
+        sync(&mut tracker, &mut trees, &arena, &[root]);
+        let first = tracker.snapshot(leaf).unwrap();
+
+        mutate_element(&arena, leaf, |element| element.set_opacity(0.25));
+        sync(&mut tracker, &mut trees, &arena, &[root]);
+        let second = tracker.snapshot(leaf).unwrap();
+
+        assert_ne!(second.self_paint_revision, first.self_paint_revision);
+        assert_eq!(second.composite_revision, first.composite_revision);
+        assert_eq!(second.topology_revision, first.topology_revision);
+    }
+
     #[test]
     fn scroll_generation_conservatively_bumps_self_paint() {
         let mut arena = NodeArena::new();
@@ -469,6 +469,9 @@ pub(crate) struct PropertyTrees {
     pub(crate) transforms: FxHashMap<TransformNodeId, TransformNode>,
     pub(crate) clips: FxHashMap<ClipNodeId, ClipNode>,
     pub(crate) effects: FxHashMap<EffectNodeId, EffectNode>,
+    /// Translucent nodes whose opacity is premultiplied into their own pass
+    /// colors instead of owning an effect node.
+    pub(crate) baked_opacity: FxHashMap<NodeKey, f32>,
     pub(crate) scrolls: FxHashMap<ScrollNodeId, ScrollNode>,
     transform_generations: FxHashMap<TransformNodeId, u64>,
     clip_generations: FxHashMap<ClipNodeId, u64>,
@@ -490,6 +493,10 @@ impl PropertyTrees {
         self.effect_generations.get(&EffectNodeId(owner)).copied()
     }
 
+    pub(super) fn baked_opacity_for(&self, owner: NodeKey) -> Option<f32> {
+        self.baked_opacity.get(&owner).copied()
+    }
+
     pub(super) fn transform_generation_for_owner(&self, owner: NodeKey) -> Option<u64> {
         self.transform_generations
             .get(&TransformNodeId(owner))
@@ -713,7 +720,21 @@ impl PropertyTrees {
             None
         };
         let opacity = properties.opacity.clamp(0.0, 1.0);
-        let effect = if opacity.to_bits() == 1.0_f32.to_bits() {
+        // A single-draw leaf outside any other opacity group gets the same
+        // result from premultiplied pass colors as from an isolated layer.
+        // Frame roots keep their effect node so the exact root-opacity
+        // authorities stay in charge of them, and nodes inside a group keep
+        // theirs because that group's surface records them neutrally.
+        let bakes_opacity = opacity.to_bits() != 1.0_f32.to_bits()
+            && !is_frame_root
+            && inherited.effect.is_none()
+            && node.element.bakes_opacity_into_self_paint();
+        if bakes_opacity {
+            self.baked_opacity.insert(key, opacity);
+        } else {
+            self.baked_opacity.remove(&key);
+        }
+        let effect = if opacity.to_bits() == 1.0_f32.to_bits() || bakes_opacity {
             if self.effects.remove(&EffectNodeId(key)).is_some() {
                 self.bump_effect_generation(EffectNodeId(key));
                 self.mark_change(
@@ -990,6 +1011,7 @@ impl PropertyTrees {
         self.transforms.retain(|id, _| seen.contains(&id.0));
         self.clips.retain(|id, _| seen.contains(&id.owner));
         self.effects.retain(|id, _| seen.contains(&id.0));
+        self.baked_opacity.retain(|key, _| seen.contains(key));
         self.scrolls.retain(|id, _| seen.contains(&id.0));
         // Active property state follows the current roots, but tombstone
         // counters follow the owner's generational arena lifetime. A node can
@@ -2279,6 +2301,43 @@ mod tests {
         assert!(trees.clips[&id].generation > first);
     }
 
+    #[test]
+    fn single_draw_leaf_bakes_opacity_instead_of_owning_an_effect() {
+        let mut arena = NodeArena::new();
+        let root = insert_element(&mut arena, 1);
+        let leaf = insert_element(&mut arena, 2);
+        let group = insert_element(&mut arena, 3);
+        let nested = insert_element(&mut arena, 4);
+        append_child(&mut arena, root, leaf);
+        append_child(&mut arena, root, group);
+        append_child(&mut arena, group, nested);
+        set_opacity(&arena, leaf, 0.5);
+        set_opacity(&arena, group, 0.5);
+        set_opacity(&arena, nested, 0.25);
+        let mut trees = PropertyTrees::default();
+        trees.sync(&arena, &[root]);
+
+        assert!(!trees.effects.contains_key(&EffectNodeId(leaf)));
+        assert_eq!(trees.states[&leaf].paint.effect, None);
+        assert_eq!(trees.baked_opacity_for(leaf), Some(0.5));
+        assert!(trees.effects.contains_key(&EffectNodeId(group)));
+        assert_eq!(
+            trees.effects[&EffectNodeId(nested)].parent,
+            Some(EffectNodeId(group))
+        );
+        assert_eq!(trees.baked_opacity_for(nested), None);
+
+        set_opacity(&arena, group, 1.0);
+        trees.sync(&arena, &[root]);
+        assert!(!trees.effects.contains_key(&EffectNodeId(nested)));
+        assert_eq!(trees.baked_opacity_for(nested), Some(0.25));
+        assert!(
+            trees
+                .changes_for(nested)
+                .contains(PropertyChangeFlags::EFFECT)
+        );
+    }
+
     #[test]
     fn opacity_change_marks_effect_without_other_property_changes() {
         let mut arena = NodeArena::new();
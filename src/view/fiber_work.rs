@@ -1368,6 +1368,63 @@ fn apply_update_work(
     Ok(())
 }
 
+/// Restyle the `Element` at `key` outside a reconcile: `update` edits its
+/// authored style, which is then applied like a `style` prop update, with
+/// the same transitions, invalidation and text recascade. Returns `false`
+/// when `key` is not a live `Element`.
+pub(crate) fn update_element_style(
+    arena: &mut NodeArena,
+    ctx: ApplyContext<'_>,
+    key: NodeKey,
+    update: impl FnOnce(&mut Style),
+) -> bool {
+    use crate::view::base_component::{Element, ElementTrait};
+    use crate::view::renderer_adapter::{
+        StyleCascadeContext, element_base_style_from_inherited, style_cascade_at_parent,
+    };
+
+    if !matches!(classify_target(arena, key), TargetKind::Element) {
+        return false;
+    }
+    let inherited = arena.parent_of(key).map_or_else(
+        || {
+            StyleCascadeContext::from_viewport_style(
+                ctx.viewport_style,
+                ctx.viewport_width,
+                ctx.viewport_height,
+            )
+        },
+        |parent| {
+            style_cascade_at_parent(
+                arena,
+                parent,
+                ctx.viewport_style,
+                ctx.viewport_width,
+                ctx.viewport_height,
+            )
+        },
+    );
+    let cascade_dirty = arena.mutate_element_with_invalidation(key, |element, cx| {
+        let element = element.as_any_mut().downcast_mut::<Element>()?;
+        let mut authored = element.text_cascade_style().clone();
+        update(&mut authored);
+        let cascade_dirty = TEXT_CASCADING_PROPS
+            .iter()
+            .any(|pid| element.parsed_style().get(*pid) != authored.get(*pid));
+        element.replace_style(element_base_style_from_inherited(&inherited) + authored.clone());
+        element.set_text_cascade_style(authored);
+        cx.invalidate(element.local_dirty_flags());
+        Some(cascade_dirty)
+    });
+    let Some(cascade_dirty) = cascade_dirty.flatten() else {
+        return false;
+    };
+    if cascade_dirty && arena_has_descendants(arena, key) {
+        recascade_text_subtree(arena, ctx, key);
+    }
+    true
+}
+
 /// 軌 A #9: resolve a `font_size` prop to pixels with full inherited
 /// context. `parent_font_size` comes from the arena ancestor walk
 /// (`style_cascade_at_parent`); `root_font_size` is the
@@ -1,5 +1,7 @@
 use crate::time::Instant;
+use bumpalo::Bump;
 use rustc_hash::{FxHashMap, FxHashSet};
+use std::cell::RefCell;
 use std::collections::VecDeque;
 use std::panic::{AssertUnwindSafe, catch_unwind};
 use std::sync::atomic::{AtomicU64, Ordering};
@@ -58,6 +60,8 @@ pub enum RetainedTextureRole {
     ScrollHostDepthStencil,
     ScrollContentColor,
     ScrollContentDepthStencil,
+    ShadowColor,
+    ShadowMask,
 }
 
 #[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
@@ -1010,6 +1014,9 @@ pub struct FrameGraph {
     compiled: bool,
     build_errors: Vec<FrameGraphError>,
     execute_steps: Vec<ExecuteStep>,
+    /// Cleared usage lists from passes dropped by [`FrameGraph::reset`],
+    /// handed to the next passes added so a steady frame stops allocating.
+    spare_usages: Vec<Vec<PassResourceUsage>>,
 }
 
 #[derive(Clone, Debug, Default)]
@@ -1155,29 +1162,41 @@ impl FrameGraph {
             compiled: false,
             build_errors: Vec::new(),
             execute_steps: Vec::new(),
+            spare_usages: Vec::new(),
         }
     }
 
-    pub fn add_graphics_pass<P: GraphicsPass + 'static>(&mut self, pass: P) -> PassHandle {
-        let name = std::any::type_name::<P>();
-        let node = PassNode {
-            pass: Box::new(GraphicsPassWrapper { pass }),
-            descriptor: PassDescriptor::graphics(name),
-            usages: Vec::new(),
-        };
-        let handle = PassHandle(self.passes.len());
-        self.passes.push(node);
+    /// Empty the graph for the next frame while keeping every buffer's
+    /// capacity, so a viewport can rebuild into last frame's storage
+    /// instead of allocating a fresh graph.
+    pub(crate) fn reset(&mut self) {
+        for node in self.passes.drain(..) {
+            let mut usages = node.usages;
+            usages.clear();
+            self.spare_usages.push(usages);
+        }
+        self.textures.clear();
+        self.texture_attachment_pairs.clear();
+        self.buffers.clear();
+        self.texture_metadata.clear();
+        self.buffer_metadata.clear();
+        self.external_sinks.clear();
         self.compiled_graph = None;
+        self.order.clear();
         self.compiled = false;
-        handle
+        self.build_errors.clear();
+        self.execute_steps.clear();
     }
 
-    pub fn add_compute_pass<P: ComputePass + 'static>(&mut self, pass: P) -> PassHandle {
-        let name = std::any::type_name::<P>();
+    fn push_pass_node(
+        &mut self,
+        pass: Box<dyn PassNodeDyn>,
+        descriptor: PassDescriptor,
+    ) -> PassHandle {
         let node = PassNode {
-            pass: Box::new(ComputePassWrapper { pass }),
-            descriptor: PassDescriptor::compute(name),
-            usages: Vec::new(),
+            pass,
+            descriptor,
+            usages: self.spare_usages.pop().unwrap_or_default(),
         };
         let handle = PassHandle(self.passes.len());
         self.passes.push(node);
@@ -1186,18 +1205,28 @@ impl FrameGraph {
         handle
     }
 
+    pub fn add_graphics_pass<P: GraphicsPass + 'static>(&mut self, pass: P) -> PassHandle {
+        let name = std::any::type_name::<P>();
+        self.push_pass_node(
+            Box::new(GraphicsPassWrapper { pass }),
+            PassDescriptor::graphics(name),
+        )
+    }
+
+    pub fn add_compute_pass<P: ComputePass + 'static>(&mut self, pass: P) -> PassHandle {
+        let name = std::any::type_name::<P>();
+        self.push_pass_node(
+            Box::new(ComputePassWrapper { pass }),
+            PassDescriptor::compute(name),
+        )
+    }
+
     pub fn add_transfer_pass<P: TransferPass + 'static>(&mut self, pass: P) -> PassHandle {
         let name = std::any::type_name::<P>();
-        let node = PassNode {
-            pass: Box::new(TransferPassWrapper { pass }),
-            descriptor: PassDescriptor::transfer(name),
-            usages: Vec::new(),
-        };
-        let handle = PassHandle(self.passes.len());
-        self.passes.push(node);
-        self.compiled_graph = None;
-        self.compiled = false;
-        handle
+        self.push_pass_node(
+            Box::new(TransferPassWrapper { pass }),
+            PassDescriptor::transfer(name),
+        )
     }
 
     pub(crate) fn pair_texture_attachment(
@@ -2180,6 +2209,17 @@ impl FrameGraph {
 
     fn build_compiled_graph_profiled(
         &self,
+    ) -> Result<(CompiledGraph, CompileGraphProfile), FrameGraphError> {
+        COMPILE_SCRATCH.with(|scratch| {
+            let mut scratch = scratch.borrow_mut();
+            scratch.reset();
+            self.build_compiled_graph_in(&scratch)
+        })
+    }
+
+    fn build_compiled_graph_in(
+        &self,
+        scratch: &Bump,
     ) -> Result<(CompiledGraph, CompileGraphProfile), FrameGraphError> {
         let total_started_at = Instant::now();
 
@@ -2206,7 +2246,7 @@ impl FrameGraph {
 
         let build_live_dependency_graph_started_at = Instant::now();
         let (graph_edges, indegree) =
-            self.build_live_dependency_graph(&live_passes, &version_producers)?;
+            self.build_live_dependency_graph(&live_passes, &version_producers, scratch)?;
         let build_live_dependency_graph_ms = build_live_dependency_graph_started_at
             .elapsed()
             .as_secs_f64()
@@ -2403,13 +2443,16 @@ impl FrameGraph {
         Ok(live)
     }
 
-    fn build_live_dependency_graph(
+    fn build_live_dependency_graph<'s>(
         &self,
         live_passes: &FxHashSet<usize>,
         version_producers: &FxHashMap<ResourceVersionId, usize>,
-    ) -> Result<(Vec<FxHashSet<usize>>, Vec<usize>), FrameGraphError> {
+        scratch: &'s Bump,
+    ) -> Result<(Vec<Consumers<'s>>, Vec<usize>), FrameGraphError> {
         let mut indegree = vec![0usize; self.passes.len()];
-        let mut graph_edges: Vec<FxHashSet<usize>> = vec![FxHashSet::default(); self.passes.len()];
+        let mut graph_edges: Vec<Consumers<'s>> = (0..self.passes.len())
+            .map(|_| Consumers::new_in(scratch))
+            .collect();
 
         self.validate_live_passes(live_passes, version_producers)?;
 
@@ -2418,10 +2461,13 @@ impl FrameGraph {
                 let Some(version) = usage.read_version else {
                     continue;
                 };
+                // Edges into `index` are only added while visiting it, so a
+                // repeat edge is always the producer's last one.
                 if let Some(&producer) = version_producers.get(&version)
                     && producer != index
-                    && graph_edges[producer].insert(index)
+                    && graph_edges[producer].last() != Some(&index)
                 {
+                    graph_edges[producer].push(index);
                     indegree[index] += 1;
                 }
             }
@@ -2529,7 +2575,7 @@ impl FrameGraph {
     fn compute_batch_anchor_info(
         &self,
         live_passes: &FxHashSet<usize>,
-        graph_edges: &[FxHashSet<usize>],
+        graph_edges: &[Consumers<'_>],
         indegree: &[usize],
         compatibility_keys: &[Option<RenderPassCompatibilityKey>],
     ) -> Vec<BatchAnchorInfo> {
@@ -2575,7 +2621,7 @@ impl FrameGraph {
     fn toposort_live_passes(
         &self,
         live_passes: &FxHashSet<usize>,
-        graph_edges: &[FxHashSet<usize>],
+        graph_edges: &[Consumers<'_>],
         indegree: &[usize],
     ) -> Result<Vec<usize>, FrameGraphError> {
         let mut indegree = indegree.to_vec();
@@ -3242,6 +3288,7 @@ impl FrameGraph {
         timings: &mut PassTimingCollector,
     ) -> Result<(), FrameGraphError> {
         let pass_name = self.passes[index].pass.name();
+        let _span = crate::trace::span!("pass", name = pass_name);
         let pass_started_at = timings.start();
         let encoder_ptr = {
             let Some(parts) = ctx.viewport.frame_parts() else {
@@ -3274,6 +3321,7 @@ impl FrameGraph {
         timings: &mut PassTimingCollector,
     ) -> Result<(), FrameGraphError> {
         let pass_name = self.passes[index].pass.name();
+        let _span = crate::trace::span!("pass", name = pass_name);
         let pass_started_at = timings.start();
         let encoder_ptr = {
             let Some(parts) = ctx.viewport.frame_parts() else {
@@ -3444,6 +3492,7 @@ impl FrameGraph {
 
         for &index in pass_indices {
             let pass_name = self.passes[index].pass.name();
+            let _span = crate::trace::span!("pass", name = pass_name);
             let pass_started_at = timings.start();
             let mut graphics_ctx = GraphicsRecordContext::new(ctx);
             let mut pass_ctx = GraphicsCtx::new(&mut graphics_ctx, &mut render_pass);
@@ -4123,12 +4172,22 @@ fn validate_pass_descriptor(
     Ok(())
 }
 
+/// A live pass's dependents, allocated in the compile scratch arena.
+type Consumers<'s> = bumpalo::collections::Vec<'s, usize>;
+
+thread_local! {
+    /// Arena for the dependency lists built while compiling a graph. Reset at
+    /// the start of each compile, so once it has grown to a frame's size the
+    /// per-pass lists stop hitting the allocator.
+    static COMPILE_SCRATCH: RefCell<Bump> = RefCell::new(Bump::new());
+}
+
 fn select_next_ready_node(
     queue: &FxHashSet<usize>,
     signatures: &[Option<RenderPassCompatibilityKey>],
     batch_anchor_info: &[BatchAnchorInfo],
     last_signature: Option<&RenderPassCompatibilityKey>,
-    graph_edges: &[FxHashSet<usize>],
+    graph_edges: &[Consumers<'_>],
     indegree: &[usize],
     live_passes: &FxHashSet<usize>,
 ) -> usize {
@@ -4239,7 +4298,7 @@ fn select_next_ready_node(
 
 fn topological_order_for_analysis(
     live_passes: &FxHashSet<usize>,
-    graph_edges: &[FxHashSet<usize>],
+    graph_edges: &[Consumers<'_>],
     indegree: &[usize],
 ) -> Vec<usize> {
     let mut indegree = indegree.to_vec();
@@ -4273,7 +4332,7 @@ fn estimate_compatible_run_length(
     ready: &mut FxHashSet<usize>,
     sim_indegree: &mut Vec<usize>,
     signatures: &[Option<RenderPassCompatibilityKey>],
-    graph_edges: &[FxHashSet<usize>],
+    graph_edges: &[Consumers<'_>],
     live_passes: &FxHashSet<usize>,
 ) -> usize {
     // Undo logs so we can restore `ready` and `sim_indegree` after the simulation
@@ -4680,7 +4739,7 @@ fn build_allocation_plan(
     )
 }
 
-fn count_graph_edges(graph_edges: &[FxHashSet<usize>], live_passes: &FxHashSet<usize>) -> usize {
+fn count_graph_edges(graph_edges: &[Consumers<'_>], live_passes: &FxHashSet<usize>) -> usize {
     live_passes
         .iter()
         .map(|&index| graph_edges[index].len())
@@ -4798,7 +4857,7 @@ fn summarize_degree_counts(
 fn summarize_outdegree_counts(
     graph: &FrameGraph,
     live_passes: &FxHashSet<usize>,
-    graph_edges: &[FxHashSet<usize>],
+    graph_edges: &[Consumers<'_>],
     limit: usize,
 ) -> Vec<CompileDegreeStat> {
     let mut items = live_passes
@@ -5359,6 +5418,21 @@ pub fn dump_cache_stats() -> Vec<CacheStatSnapshot> {
         .collect()
 }
 
+/// Summed (hits, misses) of every registered pipeline cache.
+pub(crate) fn pipeline_cache_totals() -> (u64, u64) {
+    cache_stats_registry()
+        .lock()
+        .unwrap()
+        .iter()
+        .filter(|s| s.name.ends_with("_pipeline"))
+        .fold((0, 0), |(hits, misses), s| {
+            (
+                hits + s.hits.load(Ordering::Relaxed),
+                misses + s.misses.load(Ordering::Relaxed),
+            )
+        })
+}
+
 pub struct ResourceCache<T> {
     store: FxHashMap<u64, T>,
     stats: Option<&'static CacheStats>,
@@ -5842,6 +5916,63 @@ mod tests {
         assert_eq!(graph.order, vec![writer.0, modify_a.0, modify_b.0]);
     }
 
+    #[test]
+    fn dependency_lists_are_rebuilt_after_the_scratch_arena_resets() {
+        for _ in 0..2 {
+            let mut graph = FrameGraph::new();
+            let texture = graph.declare_texture::<()>(test_texture_desc());
+            let writer = graph.add_graphics_pass(WritePass {
+                output: texture.clone(),
+            });
+            let modify = graph.add_graphics_pass(ModifyPass { target: texture });
+            graph.compile().expect("compile should succeed");
+            let dependencies = graph
+                .compiled_graph()
+                .expect("compiled graph")
+                .passes
+                .iter()
+                .map(|pass| (pass.original_index, pass.dependencies.clone()))
+                .collect::<Vec<_>>();
+            assert_eq!(
+                dependencies,
+                vec![(writer.0, vec![modify.0]), (modify.0, Vec::new())]
+            );
+        }
+    }
+
+    #[test]
+    fn reset_graph_recompiles_into_recycled_storage() {
+        let mut graph = FrameGraph::new();
+        for _ in 0..2 {
+            graph.reset();
+            let texture = graph.declare_texture::<()>(test_texture_desc());
+            let writer = graph.add_graphics_pass(WritePass {
+                output: texture.clone(),
+            });
+            let modify = graph.add_graphics_pass(ModifyPass { target: texture });
+            graph.compile().expect("compile should succeed");
+            assert_eq!(graph.order, vec![writer.0, modify.0]);
+            assert_eq!(graph.textures.len(), 1);
+        }
+        let usage_capacity: Vec<usize> = graph
+            .passes
+            .iter()
+            .map(|node| node.usages.capacity())
+            .collect();
+
+        graph.reset();
+        assert!(graph.passes.is_empty());
+        assert!(graph.textures.is_empty());
+        assert!(graph.compiled_graph().is_none());
+        assert_eq!(graph.spare_usages.len(), 2);
+        assert!(
+            graph
+                .spare_usages
+                .iter()
+                .all(|usages| usages.is_empty() && usage_capacity.contains(&usages.capacity()))
+        );
+    }
+
     #[test]
     fn compile_populates_version_metadata_for_write_then_read() {
         let mut graph = FrameGraph::new();
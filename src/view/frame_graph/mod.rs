@@ -9,7 +9,6 @@ pub(crate) use crate::view::render_pass::ClearPass;
 pub(crate) use buffer_resource::{BufferDesc, BufferResource};
 pub(crate) use builder::PassBuilderState;
 pub use builder::{BufferReadUsage, ComputePassBuilder, GraphicsPassBuilder, TransferPassBuilder};
-pub(crate) use frame_graph::TopologyCacheKey;
 pub use frame_graph::{
     AllocationId, AttachmentLoadOp, AttachmentTarget, CacheStatSnapshot, CacheStats,
     CompileProfile, CompiledGraph, ComputeRecordContext, ExternalSinkKind, FrameGraph,
@@ -20,4 +19,5 @@ pub use frame_graph::{
 };
 #[cfg(test)]
 pub(crate) use frame_graph::{FrameGraphTestSnapshot, FramePassTestPayload, TopologySignature};
+pub(crate) use frame_graph::{TopologyCacheKey, pipeline_cache_totals};
 pub use texture_resource::TextureDesc;
@@ -11,6 +11,7 @@ pub struct TextureDesc {
     dimension: wgpu::TextureDimension,
     usage: wgpu::TextureUsages,
     sample_count: u32,
+    raster_scale_bits: u32,
 }
 
 #[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
@@ -43,6 +44,7 @@ impl TextureDesc {
                 | wgpu::TextureUsages::COPY_SRC
                 | wgpu::TextureUsages::COPY_DST,
             sample_count: 1,
+            raster_scale_bits: 1.0_f32.to_bits(),
         }
     }
 
@@ -73,6 +75,19 @@ impl TextureDesc {
         self
     }
 
+    /// Extra pixel density of this target on top of the viewport scale
+    /// factor. Transformed layers raise it so scaled-up content is
+    /// rasterized at the size it is displayed at.
+    pub fn with_raster_scale(mut self, raster_scale: f32) -> Self {
+        let raster_scale = if raster_scale.is_finite() {
+            raster_scale.max(1.0)
+        } else {
+            1.0
+        };
+        self.raster_scale_bits = raster_scale.to_bits();
+        self
+    }
+
     pub fn width(&self) -> u32 {
         self.width
     }
@@ -104,4 +119,8 @@ impl TextureDesc {
     pub fn sample_count(&self) -> u32 {
         self.sample_count
     }
+
+    pub fn raster_scale(&self) -> f32 {
+        f32::from_bits(self.raster_scale_bits)
+    }
 }
@@ -182,6 +182,16 @@ impl ImageHandle {
     pub(crate) fn asset_id(&self) -> ImageAssetId {
         self.asset_id
     }
+
+    /// Whether no other handle holds this asset.
+    pub(crate) fn is_sole_handle(&self) -> bool {
+        image_registry()
+            .lock()
+            .unwrap()
+            .entries
+            .get(&self.asset_id)
+            .is_none_or(|entry| entry.ref_count <= 1)
+    }
 }
 
 impl Drop for ImageHandle {
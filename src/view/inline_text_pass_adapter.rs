@@ -6,7 +6,9 @@ use crate::view::render_pass::text_pass::{
     TextPassRasterGlyphInput,
 };
 #[cfg(test)]
-use crate::view::render_pass::text_pass::{TextRasterKey, text_raster_key_for_raster_input};
+use crate::view::render_pass::text_pass::{
+    TextRasterKey, TextRasterMode, text_raster_key_for_raster_input,
+};
 
 #[cfg(test)]
 use crate::view::render_pass::text_pass::{CachedRasterImage, rasterize_text_pass_glyph_input};
@@ -259,7 +261,11 @@ pub(crate) fn inline_text_pass_prepare_comparable_package_for_test(
                 |(glyph_index, glyph)| InlineTextPassPrepareComparableGlyph {
                     glyph_index,
                     batch_index: batch_index_for_glyph[glyph_index],
-                    raster_key: text_raster_key_for_raster_input(&glyph.raster, scale_factor),
+                    raster_key: text_raster_key_for_raster_input(
+                        &glyph.raster,
+                        scale_factor,
+                        TextRasterMode::default(),
+                    ),
                     paint: glyph.paint,
                     raster: glyph.raster.clone(),
                 },
@@ -307,8 +313,14 @@ pub(crate) fn rasterize_first_bridged_glyph_for_test(
         .glyphs
         .into_iter()
         .find(|glyph| glyph.raster.font_data.is_some())?;
-    let image =
-        rasterize_text_pass_glyph_input(scale_context, raster_cache, 1, &glyph.raster, 1.0)?;
+    let image = rasterize_text_pass_glyph_input(
+        scale_context,
+        raster_cache,
+        1,
+        &glyph.raster,
+        1.0,
+        TextRasterMode::default(),
+    )?;
     Some((glyph, image.data.len()))
 }
 
@@ -320,7 +332,7 @@ mod tests {
         InlineIfcMeasuredAtomicBox, InlineIfcSize, InlineIfcSourceId, InlineIfcStyle,
     };
     use crate::view::render_pass::text_pass::{
-        CachedRasterImage, TextRasterKey, rasterize_text_pass_glyph_input,
+        CachedRasterImage, TextRasterKey, TextRasterMode, rasterize_text_pass_glyph_input,
         text_raster_key_for_raster_input,
     };
     use rustc_hash::FxHashMap;
@@ -455,6 +467,7 @@ mod tests {
             101,
             &raster,
             1.0,
+            TextRasterMode::default(),
         )
         .expect("bridged IFC glyph should rasterize through the existing TextPass helper");
 
@@ -489,12 +502,28 @@ mod tests {
 
         assert_eq!(low_opacity.raster, high_opacity.raster);
         assert_eq!(
-            text_raster_key_for_raster_input(&low_opacity.raster, scale_factor),
-            text_raster_key_for_raster_input(&high_opacity.raster, scale_factor)
+            text_raster_key_for_raster_input(
+                &low_opacity.raster,
+                scale_factor,
+                TextRasterMode::default()
+            ),
+            text_raster_key_for_raster_input(
+                &high_opacity.raster,
+                scale_factor,
+                TextRasterMode::default()
+            )
         );
         assert_eq!(
-            text_raster_key_for_raster_input(&first.raster, scale_factor),
-            text_raster_key_for_raster_input(&low_opacity.raster, scale_factor)
+            text_raster_key_for_raster_input(
+                &first.raster,
+                scale_factor,
+                TextRasterMode::default()
+            ),
+            text_raster_key_for_raster_input(
+                &low_opacity.raster,
+                scale_factor,
+                TextRasterMode::default()
+            )
         );
         assert_ne!(low_opacity.paint.opacity, high_opacity.paint.opacity);
         assert_ne!(
@@ -755,8 +784,12 @@ mod tests {
         let mut expected_keys = Vec::new();
 
         for glyph in &package.glyphs {
-            let key = text_raster_key_for_raster_input(&glyph.raster, scale_factor)
-                .expect("packaged glyph should produce a raster key");
+            let key = text_raster_key_for_raster_input(
+                &glyph.raster,
+                scale_factor,
+                TextRasterMode::default(),
+            )
+            .expect("packaged glyph should produce a raster key");
             expected_keys.push(key);
             let image = rasterize_text_pass_glyph_input(
                 &mut scale_context,
@@ -764,6 +797,7 @@ mod tests {
                 301,
                 &glyph.raster,
                 scale_factor,
+                TextRasterMode::default(),
             )
             .expect("packaged glyph should rasterize through the existing TextPass path");
             assert!(!image.data.is_empty());
@@ -826,7 +860,11 @@ mod tests {
             assert_eq!(comparable_glyph.raster, package_glyph.raster);
             assert_eq!(
                 comparable_glyph.raster_key,
-                text_raster_key_for_raster_input(&package_glyph.raster, 1.5)
+                text_raster_key_for_raster_input(
+                    &package_glyph.raster,
+                    1.5,
+                    TextRasterMode::default()
+                )
             );
             assert_eq!(comparable_glyph.paint.opacity, 0.625);
             assert_eq!(comparable_glyph.paint.fragment_index, 14);
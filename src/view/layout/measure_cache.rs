@@ -0,0 +1,90 @@
+//! Per-node measure memoization keyed by the incoming proposal.
+//!
+//! A node normally only remembers the last proposal it was measured with,
+//! so anything that alternates between a few proposals — flex negotiation
+//! re-measuring a child at its base and then its used main size, or a window
+//! toggling between two sizes — redoes the whole subtree every time. A
+//! [`MeasureCache`] keeps the results of the last few proposals instead.
+//!
+//! Entries are stamped with the thread's layout generation, which is bumped
+//! whenever any node is marked layout-dirty, so a style change anywhere
+//! invalidates every cached result at once. Hosts still clear their own
+//! cache when they or a descendant are dirty; the generation covers changes
+//! that reach a subtree by other routes.
+
+use std::cell::Cell;
+
+thread_local! {
+    static LAYOUT_GENERATION: Cell<u64> = const { Cell::new(0) };
+}
+
+pub(crate) fn layout_generation() -> u64 {
+    LAYOUT_GENERATION.with(Cell::get)
+}
+
+pub(crate) fn bump_layout_generation() {
+    LAYOUT_GENERATION.with(|generation| generation.set(generation.get().wrapping_add(1)));
+}
+
+/// Results kept per node. Enough for flex negotiation plus a window
+/// toggling between two sizes.
+const MEASURE_CACHE_CAPACITY: usize = 4;
+
+struct MeasureCacheEntry<K, V> {
+    key: K,
+    generation: u64,
+    value: V,
+}
+
+/// Small most-recently-used cache of measure results for one node.
+pub(crate) struct MeasureCache<K, V> {
+    /// Most recently used first.
+    entries: Vec<MeasureCacheEntry<K, V>>,
+}
+
+impl<K, V> Default for MeasureCache<K, V> {
+    fn default() -> Self {
+        Self {
+            entries: Vec::new(),
+        }
+    }
+}
+
+impl<K: PartialEq, V> MeasureCache<K, V> {
+    /// Result for `key` from the current layout generation, if cached.
+    pub(crate) fn get(&mut self, key: &K) -> Option<&V> {
+        let generation = layout_generation();
+        self.entries.retain(|entry| entry.generation == generation);
+        let index = self.entries.iter().position(|entry| entry.key == *key)?;
+        let entry = self.entries.remove(index);
+        self.entries.insert(0, entry);
+        Some(&self.entries[0].value)
+    }
+
+    pub(crate) fn insert(&mut self, key: K, value: V) {
+        let generation = layout_generation();
+        self.entries
+            .retain(|entry| entry.generation == generation && entry.key != key);
+        self.entries.truncate(MEASURE_CACHE_CAPACITY - 1);
+        self.entries.insert(
+            0,
+            MeasureCacheEntry {
+                key,
+                generation,
+                value,
+            },
+        );
+    }
+
+    pub(crate) fn clear(&mut self) {
+        self.entries.clear();
+    }
+
+    #[cfg(test)]
+    pub(crate) fn len(&self) -> usize {
+        self.entries.len()
+    }
+}
+
+#[cfg(test)]
+mod tests;
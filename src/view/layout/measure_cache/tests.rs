@@ -0,0 +1,30 @@
+use super::{MEASURE_CACHE_CAPACITY, MeasureCache, bump_layout_generation};
+
+#[test]
+fn keeps_recent_proposals_and_drops_least_recently_used() {
+    let mut cache = MeasureCache::<u32, &str>::default();
+    for key in 0..MEASURE_CACHE_CAPACITY as u32 {
+        cache.insert(key, "measured");
+    }
+    assert_eq!(cache.get(&0), Some(&"measured"));
+
+    cache.insert(99, "new");
+    assert_eq!(cache.len(), MEASURE_CACHE_CAPACITY);
+    assert_eq!(cache.get(&1), None, "1 was least recently used");
+    assert_eq!(cache.get(&0), Some(&"measured"));
+    assert_eq!(cache.get(&99), Some(&"new"));
+}
+
+#[test]
+fn generation_bump_invalidates_every_entry() {
+    let mut cache = MeasureCache::<u32, u32>::default();
+    cache.insert(1, 10);
+    cache.insert(2, 20);
+
+    bump_layout_generation();
+
+    assert_eq!(cache.get(&1), None);
+    assert_eq!(cache.len(), 0);
+    cache.insert(1, 11);
+    assert_eq!(cache.get(&1), Some(&11));
+}
@@ -6,6 +6,7 @@
 
 pub(crate) mod flex_solver;
 pub(crate) mod measure;
+pub(crate) mod measure_cache;
 pub(crate) mod place;
 mod types;
 
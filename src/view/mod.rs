@@ -15,12 +15,15 @@ pub(crate) mod layout;
 pub mod node_arena;
 pub(crate) mod paint;
 pub mod popup_stack;
+/// Element lookup by component, key, text or `test_id`.
+pub mod query;
 pub(crate) mod raster_cost;
 pub mod render_pass;
 mod renderer_adapter;
 #[cfg(test)]
 mod renderer_adapter_tests;
 pub(crate) mod sampled_texture;
+pub(crate) mod style_patch;
 pub(crate) mod svg_resource;
 mod tags;
 /// The retained viewport runtime and platform-facing integration surface.
@@ -41,6 +44,11 @@ pub use host_element::{
     host_builder_node, host_builder_of,
 };
 pub use node_arena::{NodeArena, NodeKey, NodeRef, ViewportRef};
+pub use query::{ElementHandle, ElementQuery};
+pub use render_pass::{
+    GlyphAtlasMetrics, TextAntialiasing, TextAtlasMetrics, TextHinting, TextRenderOptions,
+    text_atlas_metrics,
+};
 pub use renderer_adapter::{
     ElementDescriptor, commit_descriptor_tree, rsx_to_descriptors_with_context,
 };
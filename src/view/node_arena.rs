@@ -347,7 +347,8 @@ pub struct NodeArena {
     /// Secondary index: `ElementTrait::stable_id()` → `NodeKey`. Powers
     /// the Phase A React-alignment Fiber lookup (`patch_to_fiber_work`
     /// needs to translate `Patch` paths anchored on stable ids into
-    /// arena keys without a full tree walk).
+    /// arena keys without a full tree walk) and the per-frame `*_by_id`
+    /// accessors via [`Self::find_in_subtree_by_stable_id`].
     ///
     /// Invariants:
     /// - Only non-zero stable_ids are indexed (Placeholder returns 0;
@@ -365,6 +366,9 @@ pub struct NodeArena {
     /// element callback. Stable-id lookup may trust the wrapper index only for
     /// these explicitly tracked transient placeholders.
     taken_depths: RefCell<FxHashMap<NodeKey, u32>>,
+    /// Sampled textures released by [`ElementTrait::on_removed`], waiting
+    /// for the viewport to drop them from its GPU cache.
+    released_sampled_textures: Vec<crate::view::sampled_texture::SampledTextureId>,
 }
 
 /// Mutation-scoped handle for recording arena-owned invalidation.
@@ -396,6 +400,32 @@ impl InvalidationContext<'_> {
     }
 }
 
+/// Teardown handle passed to [`ElementTrait::on_removed`].
+///
+/// By the time the hook runs the element's slot is already vacant, so the
+/// context carries no arena access; it only lets the element hand back
+/// resources that outlive the element itself.
+pub struct RemovalContext<'a> {
+    key: NodeKey,
+    released_sampled_textures: &'a mut Vec<crate::view::sampled_texture::SampledTextureId>,
+}
+
+impl RemovalContext<'_> {
+    /// The key the element occupied.
+    pub fn key(&self) -> NodeKey {
+        self.key
+    }
+
+    /// Drop `id` from the viewport's GPU texture cache before the next frame
+    /// instead of waiting for it to go stale.
+    pub(crate) fn release_sampled_texture(
+        &mut self,
+        id: crate::view::sampled_texture::SampledTextureId,
+    ) {
+        self.released_sampled_textures.push(id);
+    }
+}
+
 /// Read-side mutation-scoped handle for recording arena-owned invalidation.
 pub struct RefInvalidationContext<'a> {
     arena: &'a NodeArena,
@@ -491,13 +521,30 @@ impl NodeArena {
     /// children — callers must walk the subtree (use
     /// [`Self::remove_subtree`] for recursive removal).
     pub fn remove(&mut self, key: NodeKey) -> Option<Node> {
-        let node = self.slots.remove(key)?;
+        let mut node = self.slots.remove(key)?;
         self.arena_sync_nodes.retain(|&candidate| candidate != key);
         self.stable_id_index
             .retain(|_, indexed_key| *indexed_key != key);
+        self.notify_removed(key, &mut node);
         Some(node)
     }
 
+    fn notify_removed(&mut self, key: NodeKey, node: &mut Node) {
+        let mut ctx = RemovalContext {
+            key,
+            released_sampled_textures: &mut self.released_sampled_textures,
+        };
+        node.element.get_mut().on_removed(&mut ctx);
+    }
+
+    /// Take the sampled textures released by removed elements since the
+    /// last call.
+    pub(crate) fn take_released_sampled_textures(
+        &mut self,
+    ) -> Vec<crate::view::sampled_texture::SampledTextureId> {
+        std::mem::take(&mut self.released_sampled_textures)
+    }
+
     /// Recursively remove `key` and all descendants. Returns the number of
     /// nodes actually removed.
     pub fn remove_subtree(&mut self, key: NodeKey) -> usize {
@@ -533,7 +580,8 @@ impl NodeArena {
             .retain(|_, indexed_key| !removed_keys.contains(indexed_key));
         let mut removed = 0;
         for k in to_remove {
-            if self.slots.remove(k).is_some() {
+            if let Some(mut node) = self.slots.remove(k) {
+                self.notify_removed(k, &mut node);
                 removed += 1;
             }
         }
@@ -568,6 +616,50 @@ impl NodeArena {
         (actual_id == 0 && self.taken_depths.borrow().contains_key(&key)).then_some(key)
     }
 
+    /// Resolve `id` to a node in the subtree rooted at `root` (inclusive).
+    ///
+    /// Goes through the stable-id index and then checks the parent chain,
+    /// so per-frame `*_by_id` writes cost O(depth) rather than a walk of
+    /// the whole tree. Falls back to a DFS when the index misses or lands
+    /// outside `root`: an id renamed in place without a refresh, or a
+    /// duplicate id left behind by a cross-parent move whose parent links
+    /// have not converged yet.
+    pub fn find_in_subtree_by_stable_id(&self, root: NodeKey, id: u64) -> Option<NodeKey> {
+        let root_node = self.slots.get(root)?;
+        if root_node.element.borrow().stable_id() == id {
+            return Some(root);
+        }
+        if let Some(key) = self.find_by_stable_id(id)
+            && self.is_in_subtree(key, root)
+        {
+            return Some(key);
+        }
+        self.walk_subtree_for_stable_id(root, id)
+    }
+
+    /// True when `key` is `root` or one of its descendants.
+    pub fn is_in_subtree(&self, key: NodeKey, root: NodeKey) -> bool {
+        let mut current = Some(key);
+        while let Some(node) = current {
+            if node == root {
+                return true;
+            }
+            current = self.parent_of(node);
+        }
+        false
+    }
+
+    fn walk_subtree_for_stable_id(&self, key: NodeKey, id: u64) -> Option<NodeKey> {
+        let node = self.slots.get(key)?;
+        if node.element.borrow().stable_id() == id {
+            return Some(key);
+        }
+        (0..node.children.len()).find_map(|index| {
+            self.child_key_at(key, index)
+                .and_then(|child| self.walk_subtree_for_stable_id(child, id))
+        })
+    }
+
     /// Borrow the full stable-id → NodeKey index. Used by the Phase A
     /// incremental commit path (`fiber_work`) which wants a
     /// `&FxHashMap<u64, NodeKey>` to pass into
@@ -1374,6 +1466,8 @@ mod tests {
     thread_local! {
         static RECORDED_BUILDS: std::cell::RefCell<Vec<&'static str>> =
             const { std::cell::RefCell::new(Vec::new()) };
+        static REMOVED_IDS: std::cell::RefCell<Vec<u64>> =
+            const { std::cell::RefCell::new(Vec::new()) };
     }
 
     struct RecordingElement {
@@ -1455,6 +1549,10 @@ mod tests {
             self.dirty_flags = self.dirty_flags.without(flags);
         }
 
+        fn on_removed(&mut self, _ctx: &mut RemovalContext<'_>) {
+            REMOVED_IDS.with(|ids| ids.borrow_mut().push(self.stable_id));
+        }
+
         fn as_any(&self) -> &dyn std::any::Any {
             self
         }
@@ -1598,6 +1696,27 @@ mod tests {
         assert_eq!(arena.find_by_stable_id(3), None);
     }
 
+    #[test]
+    fn removal_runs_on_removed_once_per_dropped_element() {
+        let mut arena = NodeArena::new();
+        let root = insert_test_node(&mut arena, 1, DirtyFlags::NONE);
+        let child = insert_test_node(&mut arena, 2, DirtyFlags::NONE);
+        let side_root = insert_test_node(&mut arena, 3, DirtyFlags::NONE);
+        let lone = insert_test_node(&mut arena, 4, DirtyFlags::NONE);
+        arena.set_parent(child, Some(root));
+        arena.set_children(root, vec![child]);
+        arena.set_parent(side_root, Some(root));
+
+        arena.remove_subtree(root);
+        let mut removed = REMOVED_IDS.with(|ids| std::mem::take(&mut *ids.borrow_mut()));
+        removed.sort_unstable();
+        assert_eq!(removed, [1, 2, 3]);
+
+        arena.remove(lone);
+        assert_eq!(REMOVED_IDS.with(|ids| ids.borrow().clone()), [4]);
+        assert!(arena.take_released_sampled_textures().is_empty());
+    }
+
     #[test]
     fn remove_subtree_detaches_surviving_parent_and_root_registry() {
         let mut arena = NodeArena::new();
@@ -1723,6 +1842,46 @@ mod tests {
         });
     }
 
+    #[test]
+    fn find_in_subtree_by_stable_id_scopes_index_hits_to_the_subtree() {
+        let mut arena = NodeArena::new();
+        let first_root = insert_test_node(&mut arena, 1, DirtyFlags::NONE);
+        let second_root = insert_test_node(&mut arena, 2, DirtyFlags::NONE);
+        let middle = insert_test_node(&mut arena, 3, DirtyFlags::NONE);
+        let leaf = insert_test_node(&mut arena, 4, DirtyFlags::NONE);
+        link_child(&mut arena, first_root, middle);
+        link_child(&mut arena, middle, leaf);
+
+        assert_eq!(
+            arena.find_in_subtree_by_stable_id(first_root, 4),
+            Some(leaf)
+        );
+        assert_eq!(arena.find_in_subtree_by_stable_id(middle, 4), Some(leaf));
+        assert_eq!(arena.find_in_subtree_by_stable_id(leaf, 4), Some(leaf));
+        assert_eq!(arena.find_in_subtree_by_stable_id(second_root, 4), None);
+        assert_eq!(arena.find_in_subtree_by_stable_id(first_root, 99), None);
+    }
+
+    #[test]
+    fn find_in_subtree_by_stable_id_walks_when_an_id_changed_in_place() {
+        let mut arena = NodeArena::new();
+        let root = insert_test_node(&mut arena, 1, DirtyFlags::NONE);
+        let child = insert_test_node(&mut arena, 2, DirtyFlags::NONE);
+        link_child(&mut arena, root, child);
+
+        arena
+            .get_mut(child)
+            .unwrap()
+            .element
+            .as_any_mut()
+            .downcast_mut::<TestElement>()
+            .unwrap()
+            .stable_id = 7;
+
+        assert_eq!(arena.find_by_stable_id(7), None);
+        assert_eq!(arena.find_in_subtree_by_stable_id(root, 7), Some(child));
+    }
+
     #[test]
     fn viewport_deferred_collection_uses_trait_and_preserves_nested_dfs_order() {
         let mut arena = NodeArena::new();
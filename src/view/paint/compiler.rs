@@ -6659,6 +6659,7 @@ fn compile_validated_artifact_segment(
                             scale_factor: viewport.scale_factor(),
                             pass_context: ctx.graphics_pass_context(),
                             output,
+                            raster_cache: None,
                         },
                     ) {
                         ctx.set_current_target(output);
@@ -6857,6 +6858,7 @@ fn emit_prepared_scrollbar_shadow(
             scale_factor: viewport.scale_factor(),
             pass_context: ctx.graphics_pass_context(),
             output,
+            raster_cache: None,
         },
     ) {
         ctx.set_current_target(output);
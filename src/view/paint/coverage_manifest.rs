@@ -1670,6 +1670,8 @@ fn legacy_reason(blocker: ShadowPaintBlocker) -> LegacyPaintReason {
     match blocker {
         ShadowPaintBlocker::Transform => LegacyPaintReason::Transform,
         ShadowPaintBlocker::BoxShadow => LegacyPaintReason::BoxShadow,
+        ShadowPaintBlocker::BorderImage => LegacyPaintReason::BorderImage,
+        ShadowPaintBlocker::LayerEffect => LegacyPaintReason::LayerEffect,
         ShadowPaintBlocker::InlineIfc => LegacyPaintReason::InlineIfc,
         ShadowPaintBlocker::ScrollContainer => LegacyPaintReason::ScrollContainer,
         ShadowPaintBlocker::SelfClip => LegacyPaintReason::SelfClip,
@@ -14,6 +14,8 @@ pub(crate) enum LegacyPaintReason {
     HasChildren,
     Transform,
     BoxShadow,
+    BorderImage,
+    LayerEffect,
     InlineIfc,
     ScrollContainer,
     SelfClip,
@@ -1896,7 +1896,7 @@ fn assert_pool_matrix_pass_order(
     let shadow_fill_alphas = payloads
         .iter()
         .filter_map(|payload| match payload {
-            FramePassTestPayload::ShadowFill(fill) => Some(fill.color_bits[3]),
+            FramePassTestPayload::ShadowFill(fill) => Some(fill.vertex_color_bits[0][3]),
             _ => None,
         })
         .collect::<Vec<_>>();
@@ -566,10 +566,17 @@ fn property_scroll_b0_rejects_transform_effect_nested_scroll_and_colocation() {
             .set_opacity(0.5);
         properties.sync(&arena, &[root]);
         generations.sync(&arena, &[root], &properties);
-        assert!(
+        // The single-draw content leaf bakes its opacity instead of owning an
+        // effect node; the plan must still reject the stateful paint.
+        assert_eq!(
             properties
                 .effects
-                .contains_key(&crate::view::compositor::property_tree::EffectNodeId(owner))
+                .contains_key(&crate::view::compositor::property_tree::EffectNodeId(owner)),
+            effect_owner_is_root
+        );
+        assert_eq!(
+            properties.baked_opacity.contains_key(&owner),
+            !effect_owner_is_root
         );
         if effect_owner_is_root {
             assert!(
@@ -236,14 +236,20 @@ fn culled_subtree_keeps_root_effect_and_deferred_fail_closed() {
     );
 
     let (arena, root, child) = hidden_element_subtree(166, 167);
-    arena
-        .get_mut(child)
-        .unwrap()
-        .element
-        .as_any_mut()
-        .downcast_mut::<Element>()
-        .unwrap()
-        .set_opacity(0.5);
+    {
+        let mut child_node = arena.get_mut(child).unwrap();
+        let child_element = child_node
+            .element
+            .as_any_mut()
+            .downcast_mut::<Element>()
+            .unwrap();
+        // A bordered child paints two overlapping draws, so its opacity keeps
+        // an effect node instead of being baked into its pass colors.
+        let mut border_style = Style::new();
+        border_style.set_border(Border::uniform(Length::px(3.0), &Color::hex("#102030")));
+        child_element.apply_style(border_style);
+        child_element.set_opacity(0.5);
+    }
     let (properties, generations) = sync_identity(&arena, &[root]);
     take_full_artifact_record_count();
     let child_effect = record_frame_artifact(
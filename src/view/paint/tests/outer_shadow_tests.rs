@@ -55,11 +55,11 @@ fn outer_shadow_artifact_owns_ordered_fractional_payload_and_strict_pass_sequenc
         .collect::<Vec<_>>();
     assert_eq!(shadow_fills.len(), 2);
     assert_eq!(
-        shadow_fills[0].color_bits,
+        shadow_fills[0].vertex_color_bits[0],
         Color::rgb(220, 30, 20).to_rgba_f32().map(f32::to_bits)
     );
     assert_eq!(
-        shadow_fills[1].color_bits,
+        shadow_fills[1].vertex_color_bits[0],
         Color::rgb(20, 40, 220).to_rgba_f32().map(f32::to_bits)
     );
     let first_rect = payloads
@@ -210,7 +210,7 @@ fn outer_shadow_artifact_respects_baked_and_root_group_opacity_authority() {
             .pass_payloads()
             .iter()
             .filter_map(|payload| match payload {
-                FramePassTestPayload::ShadowFill(fill) => Some(fill.color_bits[3]),
+                FramePassTestPayload::ShadowFill(fill) => Some(fill.vertex_color_bits[0][3]),
                 _ => None,
             })
             .all(|alpha| alpha == 1.0_f32.to_bits())
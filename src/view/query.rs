@@ -0,0 +1,480 @@
+//! Read-only element lookup over the live tree.
+//!
+//! An [`ElementQuery`] matches rendered nodes by the component that
+//! produced them, their RSX `key`, their text, a `test_id` prop, or their
+//! accessibility props (`role`, `aria_label`, `aria_checked`, ...).
+//! Matches come back in document order, or in the reading order set by
+//! `reading_order` props with [`ElementQuery::in_reading_order`].
+//! Component, key and props are authoring-time facts that only the
+//! last committed RSX tree remembers, so matching walks that tree, resolves
+//! each node to its arena entry through the same stable ids the converter
+//! mints, and snapshots the result into an [`ElementHandle`]. Handles are
+//! plain data: they stay valid after the viewport moves on, and re-running
+//! the query is how callers observe a later frame.
+
+use std::any::{Any, TypeId};
+use std::hash::Hash;
+
+use rustc_hash::FxHashMap;
+
+use crate::style::{ComputedStyle, Style};
+use crate::ui::{
+    AriaRole, GlobalNodePath, PropValue, Rect, RsxElementNode, RsxKey, RsxNode,
+    child_global_node_path, child_identity_token, classify_component_key, current_global_node_path,
+    next_identity_ordinal, stable_node_id_from_parts,
+};
+use crate::view::base_component::{Element, Text, TextArea};
+use crate::view::node_arena::{NodeArena, NodeKey};
+
+#[derive(Clone, Debug, PartialEq)]
+enum TextMatch {
+    Exact(String),
+    Contains(String),
+}
+
+/// Selector over the element tree. Every criterion set must hold; an
+/// empty query matches every rendered node.
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct ElementQuery {
+    component: Option<TypeId>,
+    component_name: Option<String>,
+    key: Option<RsxKey>,
+    text: Option<TextMatch>,
+    test_id: Option<String>,
+    role: Option<AriaRole>,
+    aria_label: Option<String>,
+    node: Option<NodeKey>,
+    reading_order: bool,
+}
+
+impl ElementQuery {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Nodes rendered as the root of component `T`, or host tag `T`
+    /// (`Element`, `Text`, ...) when used directly.
+    pub fn component<T: Any>(mut self) -> Self {
+        self.component = Some(TypeId::of::<T>());
+        self
+    }
+
+    /// Like [`Self::component`], by the component's type name.
+    pub fn component_named(mut self, name: impl Into<String>) -> Self {
+        self.component_name = Some(name.into());
+        self
+    }
+
+    /// Nodes whose RSX `key` equals `key`.
+    pub fn key<K: Hash + Any>(mut self, key: K) -> Self {
+        self.key = Some(classify_component_key(&key));
+        self
+    }
+
+    /// Text nodes whose content is exactly `text`.
+    pub fn text(mut self, text: impl Into<String>) -> Self {
+        self.text = Some(TextMatch::Exact(text.into()));
+        self
+    }
+
+    /// Text nodes whose content contains `text`.
+    pub fn text_contains(mut self, text: impl Into<String>) -> Self {
+        self.text = Some(TextMatch::Contains(text.into()));
+        self
+    }
+
+    /// Nodes carrying `test_id={id}`.
+    pub fn test_id(mut self, id: impl Into<String>) -> Self {
+        self.test_id = Some(id.into());
+        self
+    }
+
+    /// Nodes carrying `role={role}`.
+    pub fn role(mut self, role: AriaRole) -> Self {
+        self.role = Some(role);
+        self
+    }
+
+    /// Nodes carrying `aria_label={label}`.
+    pub fn aria_label(mut self, label: impl Into<String>) -> Self {
+        self.aria_label = Some(label.into());
+        self
+    }
+
+    /// Return matches in reading order: siblings are visited by ascending
+    /// `reading_order` prop (unset counts as 0, ties keep document order),
+    /// which is the order assistive technology reads them in.
+    pub fn in_reading_order(mut self) -> Self {
+        self.reading_order = true;
+        self
+    }
+
+    /// The node backed by arena entry `node`, if it is still rendered.
+    pub(crate) fn node(mut self, node: NodeKey) -> Self {
+        self.node = Some(node);
+        self
+    }
+
+    fn matches_source(&self, source: &NodeSource<'_>) -> bool {
+        if let Some(type_id) = self.component
+            && source.component_type != Some(type_id)
+        {
+            return false;
+        }
+        if let Some(name) = &self.component_name
+            && source.component != name
+        {
+            return false;
+        }
+        if self.key.is_some() && source.key != self.key {
+            return false;
+        }
+        if let Some(test_id) = &self.test_id
+            && source.test_id != Some(test_id.as_str())
+        {
+            return false;
+        }
+        if self.role.is_some() && source.role != self.role {
+            return false;
+        }
+        if let Some(label) = &self.aria_label
+            && source.aria_label != Some(label.as_str())
+        {
+            return false;
+        }
+        true
+    }
+
+    fn matches_node(&self, node: NodeKey) -> bool {
+        self.node.is_none_or(|expected| expected == node)
+    }
+
+    fn matches_text(&self, text: Option<&str>) -> bool {
+        match (&self.text, text) {
+            (None, _) => true,
+            (Some(_), None) => false,
+            (Some(TextMatch::Exact(expected)), Some(text)) => text == expected,
+            (Some(TextMatch::Contains(needle)), Some(text)) => text.contains(needle.as_str()),
+        }
+    }
+}
+
+/// Snapshot of one matched node, taken when the query ran.
+#[derive(Clone, Debug)]
+pub struct ElementHandle {
+    node: NodeKey,
+    stable_id: u64,
+    element_type: &'static str,
+    component: &'static str,
+    key: Option<RsxKey>,
+    test_id: Option<String>,
+    role: Option<AriaRole>,
+    aria_label: Option<String>,
+    aria_states: AriaStates,
+    reading_order: Option<i64>,
+    rect: Rect,
+    visible: bool,
+    computed_style: Option<ComputedStyle>,
+    text: Option<String>,
+}
+
+impl ElementHandle {
+    pub fn node(&self) -> NodeKey {
+        self.node
+    }
+
+    pub fn stable_id(&self) -> u64 {
+        self.stable_id
+    }
+
+    /// Host type backing the node (`"Element"`, `"Text"`, ...).
+    pub fn element_type(&self) -> &'static str {
+        self.element_type
+    }
+
+    /// Component whose root this node is, or the host tag name.
+    pub fn component(&self) -> &'static str {
+        self.component
+    }
+
+    pub fn key(&self) -> Option<RsxKey> {
+        self.key
+    }
+
+    pub fn test_id(&self) -> Option<&str> {
+        self.test_id.as_deref()
+    }
+
+    pub fn role(&self) -> Option<AriaRole> {
+        self.role
+    }
+
+    pub fn aria_label(&self) -> Option<&str> {
+        self.aria_label.as_deref()
+    }
+
+    /// `aria_checked` prop; `None` when the node does not set it.
+    pub fn aria_checked(&self) -> Option<bool> {
+        self.aria_states.checked
+    }
+
+    pub fn aria_disabled(&self) -> Option<bool> {
+        self.aria_states.disabled
+    }
+
+    pub fn aria_expanded(&self) -> Option<bool> {
+        self.aria_states.expanded
+    }
+
+    pub fn aria_selected(&self) -> Option<bool> {
+        self.aria_states.selected
+    }
+
+    /// `reading_order` prop; `None` when the node does not set it.
+    pub fn reading_order(&self) -> Option<i64> {
+        self.reading_order
+    }
+
+    /// Border box in viewport coordinates as of the last layout.
+    pub fn rect(&self) -> Rect {
+        self.rect
+    }
+
+    /// False when layout culled the node.
+    pub fn is_visible(&self) -> bool {
+        self.visible
+    }
+
+    /// Resolved style of `Element` nodes; `None` for other hosts.
+    pub fn computed_style(&self) -> Option<&ComputedStyle> {
+        self.computed_style.as_ref()
+    }
+
+    /// Content of `Text` and `TextArea` nodes.
+    pub fn text(&self) -> Option<&str> {
+        self.text.as_deref()
+    }
+
+    /// Stack `style` over the node's `style` prop without re-rendering,
+    /// e.g. to move a drag preview on every pointer move. See
+    /// [`Self::update_style`].
+    pub fn set_style(&self, style: Style) {
+        self.update_style(move |current| current.extend(style));
+    }
+
+    /// Edit the node's `style` prop in place without re-rendering. The
+    /// viewport applies queued updates before its next frame, animating
+    /// changed properties through their `transition`s as a re-render
+    /// would. Only `Element` nodes restyle; others ignore updates.
+    ///
+    /// The edit lasts until the node is rendered with a different `style`
+    /// or rebuilt, so state that must survive re-renders belongs in the
+    /// authored style.
+    pub fn update_style(&self, update: impl FnOnce(&mut Style) + 'static) {
+        crate::view::style_patch::queue_style_update(self.node, update);
+    }
+}
+
+#[derive(Clone, Copy, Debug, Default)]
+struct AriaStates {
+    checked: Option<bool>,
+    disabled: Option<bool>,
+    expanded: Option<bool>,
+    selected: Option<bool>,
+}
+
+#[derive(Default)]
+struct NodeSource<'a> {
+    component: &'static str,
+    component_type: Option<TypeId>,
+    key: Option<RsxKey>,
+    test_id: Option<&'a str>,
+    role: Option<AriaRole>,
+    aria_label: Option<&'a str>,
+    aria_states: AriaStates,
+    reading_order: Option<i64>,
+}
+
+/// Run `query` against `root` (the last committed RSX tree) and return the
+/// matching arena nodes in document order, or in reading order when the
+/// query asks for it.
+pub(crate) fn query_elements(
+    root: Option<&RsxNode>,
+    arena: &NodeArena,
+    query: &ElementQuery,
+) -> Vec<ElementHandle> {
+    let mut out = Vec::new();
+    if let Some(root) = root {
+        let global_path = current_global_node_path(root, None);
+        visit(root, &mut Vec::new(), global_path, arena, query, &mut out);
+    }
+    out
+}
+
+fn visit(
+    node: &RsxNode,
+    path: &mut Vec<u64>,
+    global_path: Option<GlobalNodePath>,
+    arena: &NodeArena,
+    query: &ElementQuery,
+    out: &mut Vec<ElementHandle>,
+) {
+    let current_global_path = current_global_node_path(node, global_path.as_ref());
+    match node {
+        RsxNode::Element(element) => {
+            let source = element_source(element);
+            if query.matches_source(&source)
+                && let Some(key) =
+                    resolve_element(element, path, current_global_path.as_ref(), arena)
+                && query.matches_node(key)
+                && let Some(handle) = snapshot(arena, key, &source, query)
+            {
+                out.push(handle);
+            }
+        }
+        RsxNode::Text(text) => {
+            let source = NodeSource {
+                component: "TextNode",
+                key: text.identity.key,
+                ..NodeSource::default()
+            };
+            if query.matches_source(&source)
+                && let Some(key) = arena.find_by_stable_id(stable_node_id_from_parts(
+                    "TextNode",
+                    path,
+                    current_global_path.as_ref(),
+                ))
+                && query.matches_node(key)
+                && let Some(handle) = snapshot(arena, key, &source, query)
+            {
+                out.push(handle);
+            }
+        }
+        RsxNode::Fragment(_) | RsxNode::Component(_) | RsxNode::Provider(_) => {}
+    }
+    let Some(children) = node.children() else {
+        return;
+    };
+    // Identity tokens follow document order whatever order the children
+    // are visited in.
+    let mut ordinals = FxHashMap::<&'static str, usize>::default();
+    let mut children = children
+        .iter()
+        .map(|child| {
+            let ordinal = next_identity_ordinal(&mut ordinals, child.identity());
+            (child, child_identity_token(child, ordinal))
+        })
+        .collect::<Vec<_>>();
+    if query.reading_order {
+        children.sort_by_key(|(child, _)| reading_order(child).unwrap_or(0));
+    }
+    for (child, token) in children {
+        path.push(token);
+        let child_global_path = child_global_node_path(current_global_path.as_ref(), child, token);
+        visit(child, path, child_global_path, arena, query, out);
+        path.pop();
+    }
+}
+
+fn element_source(element: &RsxElementNode) -> NodeSource<'_> {
+    let mut source = NodeSource {
+        component: element.tag_descriptor.map_or(element.tag, |descriptor| {
+            short_type_name(descriptor.type_name)
+        }),
+        component_type: element.tag_descriptor.map(|descriptor| descriptor.type_id),
+        key: element.identity.key,
+        ..NodeSource::default()
+    };
+    for (name, value) in element.props.iter() {
+        match (*name, value) {
+            ("test_id", PropValue::String(id)) => source.test_id = Some(id),
+            ("role", PropValue::String(token)) => source.role = AriaRole::from_token(token),
+            ("aria_label", PropValue::String(label)) => source.aria_label = Some(label),
+            ("aria_checked", PropValue::Bool(value)) => source.aria_states.checked = Some(*value),
+            ("aria_disabled", PropValue::Bool(value)) => source.aria_states.disabled = Some(*value),
+            ("aria_expanded", PropValue::Bool(value)) => source.aria_states.expanded = Some(*value),
+            ("aria_selected", PropValue::Bool(value)) => source.aria_states.selected = Some(*value),
+            ("reading_order", PropValue::I64(order)) => source.reading_order = Some(*order),
+            _ => {}
+        }
+    }
+    source
+}
+
+fn reading_order(node: &RsxNode) -> Option<i64> {
+    let RsxNode::Element(element) = node else {
+        return None;
+    };
+    element
+        .props
+        .iter()
+        .find_map(|(name, value)| match (*name, value) {
+            ("reading_order", PropValue::I64(order)) => Some(*order),
+            _ => None,
+        })
+}
+
+/// `type_name` paths without module prefix or generic arguments:
+/// `my_app::widgets::Badge<T>` becomes `Badge`.
+fn short_type_name(type_name: &'static str) -> &'static str {
+    let base = type_name.split('<').next().unwrap_or(type_name);
+    base.rsplit("::").next().unwrap_or(base)
+}
+
+/// Container hosts mint their stable id from the component name stamped on
+/// the root; leaf hosts (`Text`, `Image`, ...) always use their tag.
+fn resolve_element(
+    element: &RsxElementNode,
+    path: &[u64],
+    global_path: Option<&GlobalNodePath>,
+    arena: &NodeArena,
+) -> Option<NodeKey> {
+    let runtime_name = crate::ui::element_runtime_name(element);
+    let key = arena.find_by_stable_id(stable_node_id_from_parts(runtime_name, path, global_path));
+    if key.is_some() || runtime_name == element.tag {
+        return key;
+    }
+    arena.find_by_stable_id(stable_node_id_from_parts(element.tag, path, global_path))
+}
+
+fn snapshot(
+    arena: &NodeArena,
+    key: NodeKey,
+    source: &NodeSource<'_>,
+    query: &ElementQuery,
+) -> Option<ElementHandle> {
+    let node = arena.get(key)?;
+    let element = node.element.as_ref();
+    let any = element.as_any();
+    let text = if let Some(text) = any.downcast_ref::<Text>() {
+        Some(text.content().to_string())
+    } else {
+        any.downcast_ref::<TextArea>()
+            .map(|text_area| text_area.content.clone())
+    };
+    if !query.matches_text(text.as_deref()) {
+        return None;
+    }
+    let snapshot = element.box_model_snapshot();
+    Some(ElementHandle {
+        node: key,
+        stable_id: element.stable_id(),
+        element_type: short_type_name(element.element_type_name()),
+        component: source.component,
+        key: source.key,
+        test_id: source.test_id.map(str::to_string),
+        role: source.role,
+        aria_label: source.aria_label.map(str::to_string),
+        aria_states: source.aria_states,
+        reading_order: source.reading_order,
+        rect: Rect::new(snapshot.x, snapshot.y, snapshot.width, snapshot.height),
+        visible: snapshot.should_render,
+        computed_style: any
+            .downcast_ref::<Element>()
+            .map(|element| element.computed_style().clone()),
+        text,
+    })
+}
+
+#[cfg(test)]
+mod tests;
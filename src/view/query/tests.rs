@@ -0,0 +1,232 @@
+use super::*;
+use crate::app::headless::HeadlessApp;
+use crate::app::{App, AppContext};
+use crate::style::Length;
+use crate::ui::rsx;
+use crate::view::{Element as HostElement, Text as HostText};
+
+#[crate::ui::component]
+fn QueryBadge(label: String) -> RsxNode {
+    rsx! {
+        <HostElement style={{ width: Length::px(60.0), height: Length::px(20.0) }}>
+            <HostText>{label}</HostText>
+        </HostElement>
+    }
+}
+
+struct ListApp;
+
+impl App for ListApp {
+    fn build(&mut self, _ctx: &mut AppContext<'_>) -> RsxNode {
+        rsx! {
+            <HostElement style={{ width: Length::px(200.0), height: Length::px(100.0) }}>
+                <QueryBadge key={"first"} label={"Save".to_string()} />
+                <QueryBadge key={"second"} label={"Save as".to_string()} />
+                <HostElement
+                    test_id="footer"
+                    style={{ width: Length::px(80.0), height: Length::px(30.0) }}
+                />
+            </HostElement>
+        }
+    }
+}
+
+#[test]
+fn finds_component_roots_by_type_name_and_key() {
+    let app = HeadlessApp::new(ListApp, 200, 100);
+
+    let badges = app.query(&ElementQuery::new().component::<QueryBadge>());
+    assert_eq!(badges.len(), 2);
+    assert!(badges.iter().all(|badge| badge.component() == "QueryBadge"));
+    assert_eq!(
+        app.query(&ElementQuery::new().component_named("QueryBadge"))
+            .len(),
+        2
+    );
+
+    let second = app.get(&ElementQuery::new().component::<QueryBadge>().key("second"));
+    assert_eq!(second.node(), badges[1].node());
+    assert_eq!(second.rect().width, 60.0);
+    let style = second.computed_style().expect("element style");
+    assert_eq!(
+        style.height,
+        crate::style::SizeValue::Length(Length::px(20.0))
+    );
+}
+
+#[test]
+fn finds_text_by_exact_or_partial_content() {
+    let app = HeadlessApp::new(ListApp, 200, 100);
+
+    let exact = app.query(&ElementQuery::new().text("Save"));
+    assert_eq!(exact.len(), 1);
+    assert_eq!(exact[0].text(), Some("Save"));
+    assert!(exact[0].computed_style().is_none());
+
+    assert_eq!(
+        app.query(&ElementQuery::new().text_contains("Save")).len(),
+        2
+    );
+    assert!(app.query(&ElementQuery::new().text("Open")).is_empty());
+}
+
+#[test]
+fn finds_nodes_by_test_id() {
+    let app = HeadlessApp::new(ListApp, 200, 100);
+
+    let footer = app.get(&ElementQuery::new().test_id("footer"));
+    assert_eq!(footer.test_id(), Some("footer"));
+    assert_eq!(footer.element_type(), "Element");
+    assert_eq!(footer.rect().width, 80.0);
+    assert_eq!(
+        app.node_at(footer.rect().x + 1.0, footer.rect().y + 1.0),
+        Some(footer.node())
+    );
+    assert!(app.query(&ElementQuery::new().test_id("header")).is_empty());
+}
+
+struct SettingsApp;
+
+impl App for SettingsApp {
+    fn build(&mut self, _ctx: &mut AppContext<'_>) -> RsxNode {
+        rsx! {
+            <HostElement style={{ width: Length::px(200.0), height: Length::px(100.0) }}>
+                <HostElement
+                    role={AriaRole::Checkbox}
+                    aria_label="Wrap lines"
+                    aria_checked={true}
+                    style={{ width: Length::px(20.0), height: Length::px(20.0) }}
+                />
+                <HostElement
+                    role={AriaRole::Checkbox}
+                    aria_label="Show minimap"
+                    aria_checked={false}
+                    aria_disabled={true}
+                    style={{ width: Length::px(20.0), height: Length::px(20.0) }}
+                />
+                <HostElement role={AriaRole::Button} aria_label="Apply" />
+            </HostElement>
+        }
+    }
+}
+
+#[test]
+fn finds_nodes_by_role_and_accessible_name() {
+    let app = HeadlessApp::new(SettingsApp, 200, 100);
+
+    let checkboxes = app.query(&ElementQuery::new().role(AriaRole::Checkbox));
+    assert_eq!(checkboxes.len(), 2);
+    assert_eq!(checkboxes[0].aria_label(), Some("Wrap lines"));
+    assert_eq!(checkboxes[0].aria_checked(), Some(true));
+    assert_eq!(checkboxes[0].aria_disabled(), None);
+    assert_eq!(checkboxes[1].aria_checked(), Some(false));
+    assert_eq!(checkboxes[1].aria_disabled(), Some(true));
+
+    let apply = app.get(&ElementQuery::new().aria_label("Apply"));
+    assert_eq!(apply.role(), Some(AriaRole::Button));
+    assert_eq!(apply.aria_checked(), None);
+    let target = crate::ui::EventTarget {
+        viewport: Some(app.viewport()),
+        ..crate::ui::EventTarget::bare(apply.node())
+    };
+    assert_eq!(target.role(), Some(AriaRole::Button));
+    assert!(
+        app.query(
+            &ElementQuery::new()
+                .role(AriaRole::Button)
+                .aria_label("Wrap lines")
+        )
+        .is_empty()
+    );
+}
+
+struct DialogApp;
+
+impl App for DialogApp {
+    fn build(&mut self, _ctx: &mut AppContext<'_>) -> RsxNode {
+        // The actions are declared first so they paint under the body, but
+        // should be read after it.
+        rsx! {
+            <HostElement style={{ width: Length::px(200.0), height: Length::px(100.0) }}>
+                <HostElement reading_order={1} aria_label="Actions">
+                    <HostText>{"Cancel"}</HostText>
+                    <HostText reading_order={-1}>{"Discard"}</HostText>
+                </HostElement>
+                <HostElement aria_label="Body">
+                    <HostText>{"Unsaved changes"}</HostText>
+                </HostElement>
+            </HostElement>
+        }
+    }
+}
+
+#[test]
+fn reading_order_overrides_document_order_among_siblings() {
+    let app = HeadlessApp::new(DialogApp, 200, 100);
+    let texts = |query: ElementQuery| {
+        app.query(&query.component::<HostText>())
+            .iter()
+            .filter_map(|handle| handle.text().map(str::to_string))
+            .collect::<Vec<_>>()
+    };
+
+    assert_eq!(
+        texts(ElementQuery::new()),
+        ["Cancel", "Discard", "Unsaved changes"]
+    );
+    assert_eq!(
+        texts(ElementQuery::new().in_reading_order()),
+        ["Unsaved changes", "Discard", "Cancel"]
+    );
+    let actions = app.get(&ElementQuery::new().aria_label("Actions"));
+    assert_eq!(actions.reading_order(), Some(1));
+    assert_eq!(
+        app.get(&ElementQuery::new().aria_label("Body"))
+            .reading_order(),
+        None
+    );
+}
+
+struct DragApp;
+
+impl App for DragApp {
+    fn build(&mut self, _ctx: &mut AppContext<'_>) -> RsxNode {
+        rsx! {
+            <HostElement style={{ width: Length::px(200.0), height: Length::px(100.0) }}>
+                <HostElement
+                    test_id="preview"
+                    style={{ width: Length::px(40.0), height: Length::px(20.0) }}
+                />
+            </HostElement>
+        }
+    }
+}
+
+#[test]
+fn style_updates_restyle_the_live_element() {
+    use crate::style::{Cursor, PropertyId};
+
+    let mut app = HeadlessApp::new(DragApp, 200, 100);
+    let preview = app.get(&ElementQuery::new().test_id("preview"));
+    preview.set_style(crate::style! { width: Length::px(120.0) });
+    preview.update_style(|style| {
+        assert!(style.get(PropertyId::Height).is_some());
+        style.set_cursor(Cursor::Pointer);
+    });
+    app.tick(std::time::Duration::from_millis(16));
+
+    let preview = app.get(&ElementQuery::new().test_id("preview"));
+    assert_eq!(preview.rect().width, 120.0);
+    assert_eq!(preview.rect().height, 20.0);
+    let style = preview.computed_style().expect("element style");
+    assert_eq!(style.cursor, Cursor::Pointer);
+
+    // Unchanged props leave the update in place across frames.
+    app.tick(std::time::Duration::from_millis(16));
+    assert_eq!(
+        app.get(&ElementQuery::new().test_id("preview"))
+            .rect()
+            .width,
+        120.0
+    );
+}
@@ -200,23 +200,30 @@ impl GraphicsPass for BlurStagePass {
             if resources.pipeline_format != format {
                 *resources = create_resources(&device, format);
             }
-            let bind_group = device.create_bind_group(&wgpu::BindGroupDescriptor {
-                label: Some("BlurModule Bind Group"),
-                layout: &resources.bind_group_layout,
-                entries: &[
-                    wgpu::BindGroupEntry {
-                        binding: 0,
-                        resource: wgpu::BindingResource::TextureView(&layer_view),
-                    },
-                    wgpu::BindGroupEntry {
-                        binding: 1,
-                        resource: wgpu::BindingResource::Sampler(&resources.sampler),
-                    },
-                    wgpu::BindGroupEntry {
-                        binding: 2,
-                        resource: params_buffer.as_entire_binding(),
-                    },
-                ],
+            let bind_group_key =
+                super::BindGroupCacheKey::new("BlurModule", &resources.bind_group_layout)
+                    .view(&layer_view)
+                    .sampler(&resources.sampler)
+                    .buffer(&params_buffer);
+            let bind_group = ctx.viewport().get_or_create_bind_group(bind_group_key, || {
+                device.create_bind_group(&wgpu::BindGroupDescriptor {
+                    label: Some("BlurModule Bind Group"),
+                    layout: &resources.bind_group_layout,
+                    entries: &[
+                        wgpu::BindGroupEntry {
+                            binding: 0,
+                            resource: wgpu::BindingResource::TextureView(&layer_view),
+                        },
+                        wgpu::BindGroupEntry {
+                            binding: 1,
+                            resource: wgpu::BindingResource::Sampler(&resources.sampler),
+                        },
+                        wgpu::BindGroupEntry {
+                            binding: 2,
+                            resource: params_buffer.as_entire_binding(),
+                        },
+                    ],
+                })
             });
             ctx.set_pipeline(&resources.pipeline);
             ctx.set_bind_group(0, &bind_group, &[]);
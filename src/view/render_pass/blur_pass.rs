@@ -9,7 +9,7 @@ use crate::view::render_pass::composite_layer_pass::LayerIn;
 use crate::view::render_pass::draw_rect_pass::RenderTargetOut;
 use crate::view::render_pass::render_target::{
     logical_scissor_to_target_physical, render_target_format, render_target_origin,
-    render_target_ref, render_target_view,
+    render_target_ref, render_target_scale_factor, render_target_view,
 };
 use crate::view::render_pass::{GraphicsCtx, GraphicsPass};
 use std::sync::{Mutex, OnceLock};
@@ -178,28 +178,38 @@ impl GraphicsPass for BlurPass {
                 *resources = create_resources(device, format);
             }
 
-            let bind_group = device.create_bind_group(&wgpu::BindGroupDescriptor {
-                label: Some("Blur Bind Group"),
-                layout: &resources.bind_group_layout,
-                entries: &[
-                    wgpu::BindGroupEntry {
-                        binding: 0,
-                        resource: wgpu::BindingResource::TextureView(&layer_view),
-                    },
-                    wgpu::BindGroupEntry {
-                        binding: 1,
-                        resource: wgpu::BindingResource::Sampler(&resources.sampler),
-                    },
-                    wgpu::BindGroupEntry {
-                        binding: 2,
-                        resource: params_buffer.as_entire_binding(),
-                    },
-                ],
+            let bind_group_key =
+                super::BindGroupCacheKey::new("Blur", &resources.bind_group_layout)
+                    .view(&layer_view)
+                    .sampler(&resources.sampler)
+                    .buffer(&params_buffer);
+            let bind_group = ctx.viewport().get_or_create_bind_group(bind_group_key, || {
+                device.create_bind_group(&wgpu::BindGroupDescriptor {
+                    label: Some("Blur Bind Group"),
+                    layout: &resources.bind_group_layout,
+                    entries: &[
+                        wgpu::BindGroupEntry {
+                            binding: 0,
+                            resource: wgpu::BindingResource::TextureView(&layer_view),
+                        },
+                        wgpu::BindGroupEntry {
+                            binding: 1,
+                            resource: wgpu::BindingResource::Sampler(&resources.sampler),
+                        },
+                        wgpu::BindGroupEntry {
+                            binding: 2,
+                            resource: params_buffer.as_entire_binding(),
+                        },
+                    ],
+                })
             });
 
             let scissor_rect_physical = self.params.scissor_rect.and_then(|scissor_rect| {
                 logical_scissor_to_target_physical(
-                    ctx.viewport(),
+                    render_target_scale_factor(
+                        ctx.frame_resources(),
+                        self.output.render_target.handle(),
+                    ),
                     scissor_rect,
                     self.output
                         .render_target
@@ -19,6 +19,23 @@ impl ClearParams {
     pub fn new(color: [f32; 4]) -> Self {
         Self { color }
     }
+
+    /// Clear color for a surface composited with `alpha_mode`, from a
+    /// straight-alpha RGBA. Non-opaque modes get premultiplied color to match
+    /// every other pass; `Opaque` forces alpha to one since the compositor
+    /// ignores it anyway and translucent texels would only leak through
+    /// later blends.
+    pub fn for_alpha_mode(color: [f32; 4], alpha_mode: wgpu::CompositeAlphaMode) -> Self {
+        let alpha = color[3].clamp(0.0, 1.0);
+        let color = match alpha_mode {
+            wgpu::CompositeAlphaMode::Opaque => [color[0], color[1], color[2], 1.0],
+            wgpu::CompositeAlphaMode::PreMultiplied | wgpu::CompositeAlphaMode::PostMultiplied => {
+                [color[0] * alpha, color[1] * alpha, color[2] * alpha, alpha]
+            }
+            wgpu::CompositeAlphaMode::Auto | wgpu::CompositeAlphaMode::Inherit => color,
+        };
+        Self { color }
+    }
 }
 
 #[derive(Default)]
@@ -92,3 +109,6 @@ impl GraphicsPass for ClearPass {
         // Clear work is fully represented by attachment load ops declared in setup().
     }
 }
+
+#[cfg(test)]
+mod tests;
@@ -0,0 +1,22 @@
+use super::*;
+
+#[test]
+fn clear_color_follows_surface_alpha_mode() {
+    let straight = [1.0, 0.5, 0.25, 0.5];
+    assert_eq!(
+        ClearParams::for_alpha_mode(straight, wgpu::CompositeAlphaMode::PreMultiplied).color,
+        [0.5, 0.25, 0.125, 0.5]
+    );
+    assert_eq!(
+        ClearParams::for_alpha_mode(straight, wgpu::CompositeAlphaMode::PostMultiplied).color,
+        [0.5, 0.25, 0.125, 0.5]
+    );
+    assert_eq!(
+        ClearParams::for_alpha_mode(straight, wgpu::CompositeAlphaMode::Opaque).color,
+        [1.0, 0.5, 0.25, 1.0]
+    );
+    assert_eq!(
+        ClearParams::for_alpha_mode(straight, wgpu::CompositeAlphaMode::Auto).color,
+        straight
+    );
+}
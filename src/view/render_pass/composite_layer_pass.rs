@@ -10,11 +10,19 @@ use crate::view::frame_graph::{
 use crate::view::render_pass::draw_rect_pass::RenderTargetOut;
 use crate::view::render_pass::render_target::{
     GraphicsPassContext as RenderPassContext, logical_scissor_to_target_physical,
-    render_target_origin, render_target_sample_count, render_target_view, resolve_texture_ref,
+    render_target_origin, render_target_sample_count, render_target_scale_factor,
+    render_target_view, resolve_texture_ref,
 };
 use crate::view::render_pass::{GraphicsCtx, GraphicsPass};
 use rustc_hash::FxHashSet;
 
+mod effect;
+
+pub use effect::{
+    CompositeLayerEffect, LayerBackdropCopyPass, clear_layer_effect_resources_cache,
+    filter_blur_radius,
+};
+
 const COMPOSITE_LAYER_RESOURCES: u64 = 201;
 
 #[derive(Clone, Copy)]
@@ -29,6 +37,9 @@ pub struct CompositeLayerPass {
     index_buffer: CompositeIndexBufferOut,
     prepared_vertices: Vec<CompositeVertex>,
     prepared_indices: Vec<u32>,
+    effect: CompositeLayerEffect,
+    backdrop: LayerIn,
+    mask: LayerIn,
     input: CompositeLayerInput,
     output: CompositeLayerOutput,
 }
@@ -103,11 +114,28 @@ impl CompositeLayerPass {
             index_buffer: CompositeIndexBufferOut::default(),
             prepared_vertices: Vec::new(),
             prepared_indices: Vec::new(),
+            effect: CompositeLayerEffect::default(),
+            backdrop: LayerIn::default(),
+            mask: LayerIn::default(),
             input,
             output,
         }
     }
 
+    /// Composites through `effect` instead of plain source-over. Blend modes
+    /// read `backdrop`, a copy of the output target taken before this pass;
+    /// without one they fall back to normal blending.
+    pub fn set_effect(&mut self, effect: CompositeLayerEffect, backdrop: LayerIn) {
+        self.effect = effect;
+        self.backdrop = backdrop;
+    }
+
+    /// Scales the layer by the alpha of `mask`, a texture with the same
+    /// extent as the layer.
+    pub fn set_mask(&mut self, mask: LayerIn) {
+        self.mask = mask;
+    }
+
     #[cfg(test)]
     pub(crate) fn test_params(&self) -> &CompositeLayerParams {
         &self.params
@@ -163,6 +191,12 @@ impl GraphicsPass for CompositeLayerPass {
         if let Some(source) = self.input.layer.handle().map(OutSlot::with_handle) {
             builder.read_texture(&mut self.input.layer, &source);
         }
+        if let Some(backdrop) = self.backdrop.handle().map(OutSlot::with_handle) {
+            builder.read_texture(&mut self.backdrop, &backdrop);
+        }
+        if let Some(mask) = self.mask.handle().map(OutSlot::with_handle) {
+            builder.read_texture(&mut self.mask, &mask);
+        }
         if let Some(target) = builder.texture_target(&self.output.render_target) {
             let _ = target;
             builder.write_color(
@@ -218,7 +252,11 @@ impl GraphicsPass for CompositeLayerPass {
             .and_then(|handle| render_target_origin(ctx, handle))
             .unwrap_or((0, 0));
         let layer_origin = render_target_origin(ctx, layer_handle).unwrap_or((0, 0));
-        let scale = ctx.viewport.scale_factor();
+        let scale = render_target_scale_factor(ctx, self.output.render_target.handle());
+        // Layer texels per target pixel. A viewport-sized isolation layer
+        // composited into a scaled transformed layer is sampled at a lower
+        // density than the pixels it covers.
+        let layer_density = render_target_scale_factor(ctx, Some(layer_handle)) / scale;
         let scaled_rect_pos = [
             self.params.rect_pos[0] * scale - target_origin.0 as f32
                 + target_meta.logical_origin.0 as f32,
@@ -237,11 +275,13 @@ impl GraphicsPass for CompositeLayerPass {
             self.params.opacity,
             target_w as f32,
             target_h as f32,
-            layer_w as f32,
-            layer_h as f32,
+            layer_w as f32 / layer_density,
+            layer_h as f32 / layer_density,
             [
-                target_origin.0 as f32 - layer_origin.0 as f32 + layer_meta.logical_origin.0 as f32,
-                target_origin.1 as f32 - layer_origin.1 as f32 + layer_meta.logical_origin.1 as f32,
+                target_origin.0 as f32
+                    - (layer_origin.0 as f32 - layer_meta.logical_origin.0 as f32) / layer_density,
+                target_origin.1 as f32
+                    - (layer_origin.1 as f32 - layer_meta.logical_origin.1 as f32) / layer_density,
             ],
         );
         self.prepared_vertices = vertices;
@@ -277,6 +317,8 @@ impl GraphicsPass for CompositeLayerPass {
             .handle()
             .and_then(|handle| render_target_origin(ctx.frame_resources(), handle))
             .unwrap_or((0, 0));
+        let target_scale =
+            render_target_scale_factor(ctx.frame_resources(), self.output.render_target.handle());
         let device = match ctx.viewport().device() {
             Some(device) => device.clone(),
             None => {
@@ -291,6 +333,14 @@ impl GraphicsPass for CompositeLayerPass {
             .handle()
             .and_then(|handle| render_target_sample_count(ctx.frame_resources(), handle))
             .unwrap_or_else(|| ctx.viewport().msaa_sample_count());
+        let scissor_rect_physical = self.params.scissor_rect.and_then(|scissor_rect| {
+            logical_scissor_to_target_physical(
+                target_scale,
+                scissor_rect,
+                target_origin,
+                (target_w, target_h),
+            )
+        });
         with_composite_layer_resources_cache(|cache| {
             let resources = cache.get_or_insert_with(COMPOSITE_LAYER_RESOURCES, || {
                 create_resources(&device, format, sample_count)
@@ -304,6 +354,22 @@ impl GraphicsPass for CompositeLayerPass {
             if self.prepared_vertices.is_empty() || self.prepared_indices.is_empty() {
                 return;
             }
+            if !self.effect.is_noop() || self.mask.handle().is_some() {
+                if let Some([x, y, width, height]) = scissor_rect_physical {
+                    ctx.set_scissor_rect(x, y, width, height);
+                } else {
+                    ctx.set_scissor_rect(0, 0, target_w, target_h);
+                }
+                self.draw_with_effect(
+                    ctx,
+                    &device,
+                    format,
+                    sample_count,
+                    &layer_view,
+                    &resources.sampler,
+                );
+                return;
+            }
             let Some(vertex_buffer) = self
                 .vertex_buffer
                 .handle()
@@ -321,29 +387,26 @@ impl GraphicsPass for CompositeLayerPass {
                 return;
             };
 
-            let bind_group = device.create_bind_group(&wgpu::BindGroupDescriptor {
-                label: Some("CompositeLayer Bind Group"),
-                layout: &resources.bind_group_layout,
-                entries: &[
-                    wgpu::BindGroupEntry {
-                        binding: 0,
-                        resource: wgpu::BindingResource::TextureView(&layer_view),
-                    },
-                    wgpu::BindGroupEntry {
-                        binding: 1,
-                        resource: wgpu::BindingResource::Sampler(&resources.sampler),
-                    },
-                ],
-            });
-            let scissor_rect_physical = self.params.scissor_rect.and_then(|scissor_rect| {
-                logical_scissor_to_target_physical(
-                    ctx.viewport(),
-                    scissor_rect,
-                    target_origin,
-                    (target_w, target_h),
-                )
+            let bind_group_key =
+                super::BindGroupCacheKey::new("CompositeLayer", &resources.bind_group_layout)
+                    .view(&layer_view)
+                    .sampler(&resources.sampler);
+            let bind_group = ctx.viewport().get_or_create_bind_group(bind_group_key, || {
+                device.create_bind_group(&wgpu::BindGroupDescriptor {
+                    label: Some("CompositeLayer Bind Group"),
+                    layout: &resources.bind_group_layout,
+                    entries: &[
+                        wgpu::BindGroupEntry {
+                            binding: 0,
+                            resource: wgpu::BindingResource::TextureView(&layer_view),
+                        },
+                        wgpu::BindGroupEntry {
+                            binding: 1,
+                            resource: wgpu::BindingResource::Sampler(&resources.sampler),
+                        },
+                    ],
+                })
             });
-
             let debug_geometry_overlay = ctx.viewport().debug_options().geometry_overlay;
             let pipeline = if self.input.pass_context.stencil_clip_id.is_some() {
                 &resources.pipeline_stencil_test
@@ -0,0 +1,617 @@
+//! Blend modes, color filters and masks applied while compositing a layer,
+//! plus the backdrop copy the blend modes read from.
+
+use super::{
+    CompositeLayerPass, CompositeLayerStencilMode, CompositeVertex, LayerIn,
+    composite_layer_depth_stencil_state,
+};
+use crate::style::{Filter, MixBlendMode};
+use crate::view::frame_graph::slot::OutSlot;
+use crate::view::frame_graph::{GraphicsColorAttachmentOps, GraphicsPassBuilder};
+use crate::view::render_pass::draw_rect_pass::RenderTargetOut;
+use crate::view::render_pass::render_target::{render_target_format, render_target_view};
+use crate::view::render_pass::{GraphicsCtx, GraphicsPass};
+use std::hash::{Hash, Hasher};
+
+/// Rows of a 3x4 color matrix over unpremultiplied RGB; the fourth column is
+/// an additive offset.
+pub type ColorMatrix = [[f32; 4]; 3];
+
+const IDENTITY_MATRIX: ColorMatrix = [
+    [1.0, 0.0, 0.0, 0.0],
+    [0.0, 1.0, 0.0, 0.0],
+    [0.0, 0.0, 1.0, 0.0],
+];
+
+/// How a layer is combined with its target when composited.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct CompositeLayerEffect {
+    pub blend_mode: MixBlendMode,
+    pub color_matrix: ColorMatrix,
+}
+
+impl Default for CompositeLayerEffect {
+    fn default() -> Self {
+        Self {
+            blend_mode: MixBlendMode::Normal,
+            color_matrix: IDENTITY_MATRIX,
+        }
+    }
+}
+
+impl CompositeLayerEffect {
+    /// Folds the color filters of a `filter` list into one matrix. Blur
+    /// entries are skipped; they run as a separate pass over the layer.
+    pub fn new(blend_mode: MixBlendMode, filters: &[Filter]) -> Self {
+        let color_matrix = filters
+            .iter()
+            .filter_map(|filter| filter_matrix(*filter))
+            .fold(IDENTITY_MATRIX, |applied, next| multiply(&next, &applied));
+        Self {
+            blend_mode,
+            color_matrix,
+        }
+    }
+
+    /// Whether compositing with this effect matches a plain composite.
+    pub fn is_noop(&self) -> bool {
+        self.blend_mode == MixBlendMode::Normal && self.color_matrix == IDENTITY_MATRIX
+    }
+
+    /// Whether the effect reads the pixels already in the target.
+    pub fn needs_backdrop(&self) -> bool {
+        self.blend_mode != MixBlendMode::Normal
+    }
+}
+
+/// Combined radius of the blur entries in a `filter` list. Successive
+/// gaussian blurs compose into one whose variance is the sum.
+pub fn filter_blur_radius(filters: &[Filter]) -> f32 {
+    filters
+        .iter()
+        .map(|filter| match filter {
+            Filter::Blur(radius) => radius * radius,
+            _ => 0.0,
+        })
+        .sum::<f32>()
+        .sqrt()
+}
+
+fn filter_matrix(filter: Filter) -> Option<ColorMatrix> {
+    // Luminance weights from the Filter Effects spec.
+    let luma = |amount: f32| -> ColorMatrix {
+        let keep = 1.0 - amount;
+        [
+            [
+                0.2126 + 0.7874 * keep,
+                0.7152 - 0.7152 * keep,
+                0.0722 - 0.0722 * keep,
+                0.0,
+            ],
+            [
+                0.2126 - 0.2126 * keep,
+                0.7152 + 0.2848 * keep,
+                0.0722 - 0.0722 * keep,
+                0.0,
+            ],
+            [
+                0.2126 - 0.2126 * keep,
+                0.7152 - 0.7152 * keep,
+                0.0722 + 0.9278 * keep,
+                0.0,
+            ],
+        ]
+    };
+    match filter {
+        Filter::Blur(_) => None,
+        Filter::Grayscale(amount) => Some(luma(amount)),
+        Filter::Saturate(amount) => Some(luma(1.0 - amount)),
+        Filter::Brightness(amount) => Some([
+            [amount, 0.0, 0.0, 0.0],
+            [0.0, amount, 0.0, 0.0],
+            [0.0, 0.0, amount, 0.0],
+        ]),
+    }
+}
+
+/// `a * b`, treating both as affine 4x4 matrices with an implicit
+/// `[0, 0, 0, 1]` last row.
+fn multiply(a: &ColorMatrix, b: &ColorMatrix) -> ColorMatrix {
+    let mut out = [[0.0; 4]; 3];
+    for (row, out_row) in out.iter_mut().enumerate() {
+        for (col, value) in out_row.iter_mut().enumerate() {
+            *value = (0..3).map(|k| a[row][k] * b[k][col]).sum::<f32>();
+            if col == 3 {
+                *value += a[row][3];
+            }
+        }
+    }
+    out
+}
+
+#[derive(Default, Clone, Copy, bytemuck::Pod, bytemuck::Zeroable)]
+#[repr(C)]
+struct EffectVertex {
+    position: [f32; 2],
+    screen_uv: [f32; 2],
+    alpha: f32,
+    masked: f32,
+    matrix: [[f32; 4]; 3],
+}
+
+fn effect_vertices(
+    vertices: &[CompositeVertex],
+    effect: &CompositeLayerEffect,
+    masked: bool,
+) -> Vec<EffectVertex> {
+    vertices
+        .iter()
+        .map(|vertex| EffectVertex {
+            position: vertex.position,
+            screen_uv: vertex.screen_uv,
+            alpha: vertex.alpha,
+            masked: if masked { 1.0 } else { 0.0 },
+            matrix: effect.color_matrix,
+        })
+        .collect()
+}
+
+struct EffectResources {
+    /// Indexed by `[effect_pipeline_index(..)][stencil test]`.
+    pipelines: [[wgpu::RenderPipeline; 2]; 4],
+    bind_group_layout: wgpu::BindGroupLayout,
+}
+
+impl EffectResources {
+    /// Pipeline for `blend_mode`. A blend mode without a backdrop falls back
+    /// to the filter-only pipeline.
+    fn pipeline(
+        &self,
+        blend_mode: MixBlendMode,
+        has_backdrop: bool,
+        stencil_test: bool,
+    ) -> &wgpu::RenderPipeline {
+        let variant = if has_backdrop {
+            effect_pipeline_index(blend_mode)
+        } else {
+            0
+        };
+        &self.pipelines[variant][usize::from(stencil_test)]
+    }
+}
+
+fn effect_pipeline_index(blend_mode: MixBlendMode) -> usize {
+    match blend_mode {
+        MixBlendMode::Normal => 0,
+        MixBlendMode::Multiply => 1,
+        MixBlendMode::Screen => 2,
+        MixBlendMode::Overlay => 3,
+    }
+}
+
+crate::static_resource_cache! {
+    fn with_layer_effect_resources_cache -> ResourceCache<EffectResources>
+        = stats("layer_effect_pipeline")
+}
+
+crate::static_resource_cache! {
+    fn with_backdrop_copy_resources_cache -> ResourceCache<BackdropCopyResources>
+        = stats("layer_backdrop_copy_pipeline")
+}
+
+pub fn clear_layer_effect_resources_cache() {
+    with_layer_effect_resources_cache(|cache| {
+        cache.clear();
+    });
+    with_backdrop_copy_resources_cache(|cache| {
+        cache.clear();
+    });
+}
+
+fn resources_key(format: wgpu::TextureFormat, sample_count: u32) -> u64 {
+    let mut hasher = rustc_hash::FxHasher::default();
+    format.hash(&mut hasher);
+    sample_count.hash(&mut hasher);
+    hasher.finish()
+}
+
+fn with_effect_resources<R>(
+    device: &wgpu::Device,
+    format: wgpu::TextureFormat,
+    sample_count: u32,
+    f: impl FnOnce(&EffectResources) -> R,
+) -> R {
+    with_layer_effect_resources_cache(|cache| {
+        let resources = cache.get_or_insert_with(resources_key(format, sample_count), || {
+            create_effect_resources(device, format, sample_count)
+        });
+        f(resources)
+    })
+}
+
+fn create_effect_resources(
+    device: &wgpu::Device,
+    format: wgpu::TextureFormat,
+    sample_count: u32,
+) -> EffectResources {
+    let shader = device.create_shader_module(wgpu::ShaderModuleDescriptor {
+        label: Some("LayerEffect Shader"),
+        source: wgpu::ShaderSource::Wgsl(include_str!("../../../shader/layer_effect.wgsl").into()),
+    });
+    let texture_entry = |binding, filterable| wgpu::BindGroupLayoutEntry {
+        binding,
+        visibility: wgpu::ShaderStages::FRAGMENT,
+        ty: wgpu::BindingType::Texture {
+            sample_type: wgpu::TextureSampleType::Float { filterable },
+            view_dimension: wgpu::TextureViewDimension::D2,
+            multisampled: false,
+        },
+        count: None,
+    };
+    let bind_group_layout = device.create_bind_group_layout(&wgpu::BindGroupLayoutDescriptor {
+        label: Some("LayerEffect Bind Group Layout"),
+        entries: &[
+            texture_entry(0, true),
+            wgpu::BindGroupLayoutEntry {
+                binding: 1,
+                visibility: wgpu::ShaderStages::FRAGMENT,
+                ty: wgpu::BindingType::Sampler(wgpu::SamplerBindingType::Filtering),
+                count: None,
+            },
+            texture_entry(2, false),
+            texture_entry(3, true),
+        ],
+    });
+    let pipeline_layout = device.create_pipeline_layout(&wgpu::PipelineLayoutDescriptor {
+        label: Some("LayerEffect Pipeline Layout"),
+        bind_group_layouts: &[Some(&bind_group_layout)],
+        immediate_size: 0,
+    });
+    let pipelines = ["fs_filter", "fs_multiply", "fs_screen", "fs_overlay"].map(|entry_point| {
+        [
+            CompositeLayerStencilMode::Disabled,
+            CompositeLayerStencilMode::Test,
+        ]
+        .map(|stencil_mode| {
+            create_effect_pipeline(
+                device,
+                &pipeline_layout,
+                &shader,
+                entry_point,
+                format,
+                sample_count,
+                stencil_mode,
+            )
+        })
+    });
+    EffectResources {
+        pipelines,
+        bind_group_layout,
+    }
+}
+
+fn create_effect_pipeline(
+    device: &wgpu::Device,
+    pipeline_layout: &wgpu::PipelineLayout,
+    shader: &wgpu::ShaderModule,
+    entry_point: &str,
+    format: wgpu::TextureFormat,
+    sample_count: u32,
+    stencil_mode: CompositeLayerStencilMode,
+) -> wgpu::RenderPipeline {
+    // The blend modes composite against the backdrop copy in the shader, so
+    // their output replaces the target pixel.
+    let blend = if entry_point == "fs_filter" {
+        let premultiplied = wgpu::BlendComponent {
+            src_factor: wgpu::BlendFactor::One,
+            dst_factor: wgpu::BlendFactor::OneMinusSrcAlpha,
+            operation: wgpu::BlendOperation::Add,
+        };
+        wgpu::BlendState {
+            color: premultiplied,
+            alpha: premultiplied,
+        }
+    } else {
+        wgpu::BlendState::REPLACE
+    };
+    let float4 = |offset: usize, shader_location| wgpu::VertexAttribute {
+        format: wgpu::VertexFormat::Float32x4,
+        offset: offset as u64,
+        shader_location,
+    };
+    device.create_render_pipeline(&wgpu::RenderPipelineDescriptor {
+        label: Some("LayerEffect Pipeline"),
+        layout: Some(pipeline_layout),
+        vertex: wgpu::VertexState {
+            module: shader,
+            entry_point: Some("vs_main"),
+            buffers: &[Some(wgpu::VertexBufferLayout {
+                array_stride: std::mem::size_of::<EffectVertex>() as u64,
+                step_mode: wgpu::VertexStepMode::Vertex,
+                attributes: &[
+                    wgpu::VertexAttribute {
+                        format: wgpu::VertexFormat::Float32x2,
+                        offset: 0,
+                        shader_location: 0,
+                    },
+                    wgpu::VertexAttribute {
+                        format: wgpu::VertexFormat::Float32x2,
+                        offset: std::mem::size_of::<[f32; 2]>() as u64,
+                        shader_location: 1,
+                    },
+                    wgpu::VertexAttribute {
+                        format: wgpu::VertexFormat::Float32,
+                        offset: std::mem::size_of::<[f32; 4]>() as u64,
+                        shader_location: 2,
+                    },
+                    wgpu::VertexAttribute {
+                        format: wgpu::VertexFormat::Float32,
+                        offset: std::mem::size_of::<[f32; 5]>() as u64,
+                        shader_location: 3,
+                    },
+                    float4(std::mem::size_of::<[f32; 6]>(), 4),
+                    float4(std::mem::size_of::<[f32; 10]>(), 5),
+                    float4(std::mem::size_of::<[f32; 14]>(), 6),
+                ],
+            })],
+            compilation_options: wgpu::PipelineCompilationOptions::default(),
+        },
+        fragment: Some(wgpu::FragmentState {
+            module: shader,
+            entry_point: Some(entry_point),
+            targets: &[Some(wgpu::ColorTargetState {
+                format,
+                blend: Some(blend),
+                write_mask: wgpu::ColorWrites::ALL,
+            })],
+            compilation_options: wgpu::PipelineCompilationOptions {
+                constants: &[("linear_target", f64::from(u8::from(format.is_srgb())))],
+                ..Default::default()
+            },
+        }),
+        primitive: wgpu::PrimitiveState {
+            topology: wgpu::PrimitiveTopology::TriangleList,
+            ..Default::default()
+        },
+        depth_stencil: Some(composite_layer_depth_stencil_state(stencil_mode)),
+        multisample: wgpu::MultisampleState {
+            count: sample_count,
+            mask: !0,
+            alpha_to_coverage_enabled: false,
+        },
+        multiview_mask: None,
+        cache: None,
+    })
+}
+
+impl CompositeLayerPass {
+    /// Draws the prepared composite geometry through the effect pipelines.
+    /// The caller has already set the scissor rect.
+    pub(super) fn draw_with_effect(
+        &self,
+        ctx: &mut GraphicsCtx<'_, '_, '_, '_>,
+        device: &wgpu::Device,
+        format: wgpu::TextureFormat,
+        sample_count: u32,
+        layer_view: &wgpu::TextureView,
+        sampler: &wgpu::Sampler,
+    ) {
+        let backdrop_view = if self.effect.needs_backdrop() {
+            self.backdrop
+                .handle()
+                .and_then(|handle| render_target_view(ctx.frame_resources(), handle))
+        } else {
+            None
+        };
+        let has_backdrop = backdrop_view.is_some();
+        let mask_view = self
+            .mask
+            .handle()
+            .and_then(|handle| render_target_view(ctx.frame_resources(), handle));
+        let masked = mask_view.is_some();
+        // Unused bindings are filled with the layer itself: the filter-only
+        // pipeline never reads the backdrop, and unmasked vertices skip the
+        // mask sample.
+        let backdrop_view = backdrop_view.unwrap_or_else(|| layer_view.clone());
+        let mask_view = mask_view.unwrap_or_else(|| layer_view.clone());
+        let vertices = effect_vertices(&self.prepared_vertices, &self.effect, masked);
+        with_effect_resources(device, format, sample_count, |resources| {
+            let bind_group_key =
+                super::super::BindGroupCacheKey::new("LayerEffect", &resources.bind_group_layout)
+                    .view(layer_view)
+                    .sampler(sampler)
+                    .view(&backdrop_view)
+                    .view(&mask_view);
+            let bind_group = ctx.viewport().get_or_create_bind_group(bind_group_key, || {
+                device.create_bind_group(&wgpu::BindGroupDescriptor {
+                    label: Some("LayerEffect Bind Group"),
+                    layout: &resources.bind_group_layout,
+                    entries: &[
+                        wgpu::BindGroupEntry {
+                            binding: 0,
+                            resource: wgpu::BindingResource::TextureView(layer_view),
+                        },
+                        wgpu::BindGroupEntry {
+                            binding: 1,
+                            resource: wgpu::BindingResource::Sampler(sampler),
+                        },
+                        wgpu::BindGroupEntry {
+                            binding: 2,
+                            resource: wgpu::BindingResource::TextureView(&backdrop_view),
+                        },
+                        wgpu::BindGroupEntry {
+                            binding: 3,
+                            resource: wgpu::BindingResource::TextureView(&mask_view),
+                        },
+                    ],
+                })
+            });
+            let vertex_buffer = super::super::create_transient_buffer(
+                device,
+                &wgpu::util::BufferInitDescriptor {
+                    label: Some("LayerEffect Vertex Buffer"),
+                    contents: bytemuck::cast_slice(&vertices),
+                    usage: wgpu::BufferUsages::VERTEX,
+                },
+            );
+            let index_buffer = super::super::create_transient_buffer(
+                device,
+                &wgpu::util::BufferInitDescriptor {
+                    label: Some("LayerEffect Index Buffer"),
+                    contents: bytemuck::cast_slice(&self.prepared_indices),
+                    usage: wgpu::BufferUsages::INDEX,
+                },
+            );
+            let stencil_clip_id = self.input.pass_context.stencil_clip_id;
+            ctx.set_stencil_reference(stencil_clip_id.map_or(0, |clip_id| clip_id as u32));
+            ctx.set_pipeline(resources.pipeline(
+                self.effect.blend_mode,
+                has_backdrop,
+                stencil_clip_id.is_some(),
+            ));
+            ctx.set_bind_group(0, &bind_group, &[]);
+            ctx.set_vertex_buffer(0, vertex_buffer.slice(..));
+            ctx.set_index_buffer(index_buffer.slice(..), wgpu::IndexFormat::Uint32);
+            ctx.draw_indexed(0..self.prepared_indices.len() as u32, 0, 0..1);
+        });
+    }
+}
+
+/// Copies a render target pixel for pixel so a blend-mode composite can read
+/// what it is drawing over.
+pub struct LayerBackdropCopyPass {
+    source: LayerIn,
+    output: RenderTargetOut,
+}
+
+impl LayerBackdropCopyPass {
+    pub fn new(source: LayerIn, output: RenderTargetOut) -> Self {
+        Self { source, output }
+    }
+}
+
+struct BackdropCopyResources {
+    pipeline: wgpu::RenderPipeline,
+    bind_group_layout: wgpu::BindGroupLayout,
+}
+
+impl GraphicsPass for LayerBackdropCopyPass {
+    fn setup(&mut self, builder: &mut GraphicsPassBuilder<'_, '_>) {
+        if let Some(source) = self.source.handle().map(OutSlot::with_handle) {
+            builder.read_texture(&mut self.source, &source);
+        }
+        builder.write_color(
+            &self.output,
+            GraphicsColorAttachmentOps::clear([0.0, 0.0, 0.0, 0.0]),
+        );
+    }
+
+    fn execute(&mut self, ctx: &mut GraphicsCtx<'_, '_, '_, '_>) {
+        let (Some(source_handle), Some(output_handle)) =
+            (self.source.handle(), self.output.handle())
+        else {
+            ctx.mark_execution_failed();
+            return;
+        };
+        let Some(source_view) = render_target_view(ctx.frame_resources(), source_handle) else {
+            ctx.mark_execution_failed();
+            return;
+        };
+        let Some(format) = render_target_format(ctx.frame_resources(), output_handle) else {
+            ctx.mark_execution_failed();
+            return;
+        };
+        let Some(device) = ctx.viewport().device().cloned() else {
+            ctx.mark_execution_failed();
+            return;
+        };
+        with_backdrop_copy_resources_cache(|cache| {
+            let resources = cache.get_or_insert_with(resources_key(format, 1), || {
+                create_backdrop_copy_resources(&device, format)
+            });
+            let bind_group_key = super::super::BindGroupCacheKey::new(
+                "LayerBackdropCopy",
+                &resources.bind_group_layout,
+            )
+            .view(&source_view);
+            let bind_group = ctx.viewport().get_or_create_bind_group(bind_group_key, || {
+                device.create_bind_group(&wgpu::BindGroupDescriptor {
+                    label: Some("LayerBackdropCopy Bind Group"),
+                    layout: &resources.bind_group_layout,
+                    entries: &[wgpu::BindGroupEntry {
+                        binding: 0,
+                        resource: wgpu::BindingResource::TextureView(&source_view),
+                    }],
+                })
+            });
+            ctx.set_pipeline(&resources.pipeline);
+            ctx.set_bind_group(0, &bind_group, &[]);
+            ctx.draw(0..3, 0..1);
+        });
+    }
+}
+
+fn create_backdrop_copy_resources(
+    device: &wgpu::Device,
+    format: wgpu::TextureFormat,
+) -> BackdropCopyResources {
+    let shader = device.create_shader_module(wgpu::ShaderModuleDescriptor {
+        label: Some("LayerBackdropCopy Shader"),
+        source: wgpu::ShaderSource::Wgsl(
+            include_str!("../../../shader/layer_backdrop_copy.wgsl").into(),
+        ),
+    });
+    let bind_group_layout = device.create_bind_group_layout(&wgpu::BindGroupLayoutDescriptor {
+        label: Some("LayerBackdropCopy Bind Group Layout"),
+        entries: &[wgpu::BindGroupLayoutEntry {
+            binding: 0,
+            visibility: wgpu::ShaderStages::FRAGMENT,
+            ty: wgpu::BindingType::Texture {
+                sample_type: wgpu::TextureSampleType::Float { filterable: false },
+                view_dimension: wgpu::TextureViewDimension::D2,
+                multisampled: false,
+            },
+            count: None,
+        }],
+    });
+    let pipeline_layout = device.create_pipeline_layout(&wgpu::PipelineLayoutDescriptor {
+        label: Some("LayerBackdropCopy Pipeline Layout"),
+        bind_group_layouts: &[Some(&bind_group_layout)],
+        immediate_size: 0,
+    });
+    let pipeline = device.create_render_pipeline(&wgpu::RenderPipelineDescriptor {
+        label: Some("LayerBackdropCopy Pipeline"),
+        layout: Some(&pipeline_layout),
+        vertex: wgpu::VertexState {
+            module: &shader,
+            entry_point: Some("vs_main"),
+            buffers: &[],
+            compilation_options: wgpu::PipelineCompilationOptions::default(),
+        },
+        fragment: Some(wgpu::FragmentState {
+            module: &shader,
+            entry_point: Some("fs_main"),
+            targets: &[Some(wgpu::ColorTargetState {
+                format,
+                blend: None,
+                write_mask: wgpu::ColorWrites::ALL,
+            })],
+            compilation_options: wgpu::PipelineCompilationOptions::default(),
+        }),
+        primitive: wgpu::PrimitiveState {
+            topology: wgpu::PrimitiveTopology::TriangleList,
+            ..Default::default()
+        },
+        depth_stencil: None,
+        multisample: wgpu::MultisampleState::default(),
+        multiview_mask: None,
+        cache: None,
+    });
+    BackdropCopyResources {
+        pipeline,
+        bind_group_layout,
+    }
+}
+
+#[cfg(test)]
+mod tests;
@@ -0,0 +1,76 @@
+use super::*;
+
+fn apply(matrix: &ColorMatrix, rgb: [f32; 3]) -> [f32; 3] {
+    matrix.map(|row| row[0] * rgb[0] + row[1] * rgb[1] + row[2] * rgb[2] + row[3])
+}
+
+fn assert_rgb_close(actual: [f32; 3], expected: [f32; 3]) {
+    for (actual, expected) in actual.into_iter().zip(expected) {
+        assert!(
+            (actual - expected).abs() < 1e-4,
+            "expected {expected:?}, got {actual:?}"
+        );
+    }
+}
+
+#[test]
+fn identity_filters_leave_the_effect_a_noop() {
+    let effect = CompositeLayerEffect::new(
+        MixBlendMode::Normal,
+        &[
+            Filter::grayscale(0.0),
+            Filter::brightness(1.0),
+            Filter::saturate(1.0),
+            Filter::blur(4.0),
+        ],
+    );
+
+    assert!(effect.is_noop());
+    assert!(!effect.needs_backdrop());
+}
+
+#[test]
+fn grayscale_maps_colors_to_their_luminance() {
+    let effect = CompositeLayerEffect::new(MixBlendMode::Normal, &[Filter::grayscale(1.0)]);
+
+    assert_rgb_close(
+        apply(&effect.color_matrix, [1.0, 0.0, 0.0]),
+        [0.2126, 0.2126, 0.2126],
+    );
+    assert_rgb_close(
+        apply(&effect.color_matrix, [1.0, 1.0, 1.0]),
+        [1.0, 1.0, 1.0],
+    );
+}
+
+#[test]
+fn filters_apply_in_list_order() {
+    let color = [0.5, 0.25, 0.0];
+    let grayscale = filter_matrix(Filter::grayscale(1.0)).unwrap();
+    let brightness = filter_matrix(Filter::brightness(2.0)).unwrap();
+    let expected = apply(&brightness, apply(&grayscale, color));
+
+    let effect = CompositeLayerEffect::new(
+        MixBlendMode::Normal,
+        &[Filter::grayscale(1.0), Filter::brightness(2.0)],
+    );
+
+    assert_rgb_close(apply(&effect.color_matrix, color), expected);
+}
+
+#[test]
+fn blend_modes_need_a_backdrop() {
+    let effect = CompositeLayerEffect::new(MixBlendMode::Multiply, &[]);
+
+    assert!(!effect.is_noop());
+    assert!(effect.needs_backdrop());
+}
+
+#[test]
+fn blur_radii_combine_as_gaussian_variances() {
+    assert_eq!(filter_blur_radius(&[Filter::grayscale(1.0)]), 0.0);
+    assert_eq!(
+        filter_blur_radius(&[Filter::blur(3.0), Filter::grayscale(1.0), Filter::blur(4.0)]),
+        5.0
+    );
+}
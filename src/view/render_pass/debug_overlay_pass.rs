@@ -1,7 +1,14 @@
-use crate::view::render_pass::GraphicsCtx;
-use crate::view::render_pass::render_target::{render_target_ref, render_target_sample_count};
+use crate::view::frame_graph::{
+    GraphicsColorAttachmentOps, GraphicsPassBuilder, GraphicsPassMergePolicy, PrepareContext,
+};
+use crate::view::render_pass::draw_rect_pass::RenderTargetOut;
+use crate::view::render_pass::render_target::{
+    render_target_format, render_target_ref, render_target_sample_count,
+};
+use crate::view::render_pass::{GraphicsCtx, GraphicsPass};
 
 const DEBUG_OVERLAY_RESOURCES: u64 = 402;
+const DEBUG_OVERLAY_TARGET_RESOURCES: u64 = 403;
 
 #[derive(Default, Clone, Copy, bytemuck::Pod, bytemuck::Zeroable)]
 #[repr(C)]
@@ -23,17 +30,143 @@ pub(crate) fn draw_debug_overlay(
         return;
     }
 
-    let Some(device) = ctx.viewport().device().cloned() else {
-        return;
-    };
     let format = ctx.viewport().surface_format();
     let sample_count = match target_handle {
         Some(handle) => render_target_sample_count(ctx.frame_resources(), handle).unwrap_or(1),
         None => 1,
     };
-    let uses_depth_stencil = target_handle.is_some();
+    let surface_size = ctx.viewport().surface_size();
+    let target_size = match target_handle {
+        Some(handle) => render_target_ref(ctx.frame_resources(), handle)
+            .map(|texture_ref| texture_ref.physical_size())
+            .unwrap_or(surface_size),
+        None => surface_size,
+    };
+    encode_debug_overlay(
+        ctx,
+        DEBUG_OVERLAY_RESOURCES,
+        &vertices,
+        &indices,
+        DebugOverlayTargetInfo {
+            format,
+            sample_count,
+            uses_depth_stencil: target_handle.is_some(),
+            size: target_size,
+        },
+    );
+}
+
+/// Geometry a [`DebugOverlayPass`] draws.
+pub enum DebugOverlayGeometry {
+    /// Built while the frame graph was assembled.
+    Prebuilt {
+        vertices: Vec<DebugOverlayVertex>,
+        indices: Vec<u32>,
+    },
+    /// Outlines of the scissor rects earlier passes recorded while preparing,
+    /// gathered in this pass's own prepare.
+    ScissorRects,
+}
+
+/// Draws one debug mode's overlay on top of a render target, appended after
+/// everything else that writes to it. No depth or stencil is attached, so the
+/// overlay ignores clipping and sits above all content.
+pub struct DebugOverlayPass {
+    name: &'static str,
+    geometry: DebugOverlayGeometry,
+    output: RenderTargetOut,
+}
+
+impl DebugOverlayPass {
+    pub fn new(
+        name: &'static str,
+        geometry: DebugOverlayGeometry,
+        output: RenderTargetOut,
+    ) -> Self {
+        Self {
+            name,
+            geometry,
+            output,
+        }
+    }
+}
+
+impl GraphicsPass for DebugOverlayPass {
+    fn setup(&mut self, builder: &mut GraphicsPassBuilder<'_, '_>) {
+        builder.set_graphics_merge_policy(GraphicsPassMergePolicy::RequiresOwnPass);
+        builder.write_color(&self.output, GraphicsColorAttachmentOps::load());
+    }
+
+    fn prepare(&mut self, ctx: &mut PrepareContext<'_, '_>) {
+        if matches!(self.geometry, DebugOverlayGeometry::ScissorRects) {
+            let (vertices, indices) = ctx.viewport.take_debug_scissor_overlay_geometry();
+            self.geometry = DebugOverlayGeometry::Prebuilt { vertices, indices };
+        }
+    }
+
+    fn execute(&mut self, ctx: &mut GraphicsCtx<'_, '_, '_, '_>) {
+        let DebugOverlayGeometry::Prebuilt { vertices, indices } = &self.geometry else {
+            return;
+        };
+        if vertices.is_empty() || indices.is_empty() {
+            return;
+        }
+        let Some(handle) = self.output.handle() else {
+            return;
+        };
+        let Some(format) = render_target_format(ctx.frame_resources(), handle) else {
+            return;
+        };
+        let Some(size) = render_target_ref(ctx.frame_resources(), handle)
+            .map(|texture_ref| texture_ref.physical_size())
+        else {
+            return;
+        };
+        let sample_count = render_target_sample_count(ctx.frame_resources(), handle).unwrap_or(1);
+        encode_debug_overlay(
+            ctx,
+            DEBUG_OVERLAY_TARGET_RESOURCES,
+            vertices,
+            indices,
+            DebugOverlayTargetInfo {
+                format,
+                sample_count,
+                uses_depth_stencil: false,
+                size,
+            },
+        );
+    }
+
+    fn name(&self) -> &'static str {
+        self.name
+    }
+}
+
+struct DebugOverlayTargetInfo {
+    format: wgpu::TextureFormat,
+    sample_count: u32,
+    uses_depth_stencil: bool,
+    size: (u32, u32),
+}
+
+fn encode_debug_overlay(
+    ctx: &mut GraphicsCtx<'_, '_, '_, '_>,
+    cache_key: u64,
+    vertices: &[DebugOverlayVertex],
+    indices: &[u32],
+    target: DebugOverlayTargetInfo,
+) {
+    let Some(device) = ctx.viewport().device().cloned() else {
+        return;
+    };
+    let DebugOverlayTargetInfo {
+        format,
+        sample_count,
+        uses_depth_stencil,
+        size: (target_w, target_h),
+    } = target;
     with_debug_overlay_resources_cache(|cache| {
-        let resources = cache.get_or_insert_with(DEBUG_OVERLAY_RESOURCES, || {
+        let resources = cache.get_or_insert_with(cache_key, || {
             DebugOverlayResources::new(&device, format, sample_count, uses_depth_stencil)
         });
         if resources.pipeline_format != format
@@ -48,7 +181,7 @@ pub(crate) fn draw_debug_overlay(
             &device,
             &wgpu::util::BufferInitDescriptor {
                 label: Some("Frame Debug Overlay Vertex Buffer"),
-                contents: bytemuck::cast_slice(&vertices),
+                contents: bytemuck::cast_slice(vertices),
                 usage: wgpu::BufferUsages::VERTEX,
             },
         );
@@ -56,18 +189,11 @@ pub(crate) fn draw_debug_overlay(
             &device,
             &wgpu::util::BufferInitDescriptor {
                 label: Some("Frame Debug Overlay Index Buffer"),
-                contents: bytemuck::cast_slice(&indices),
+                contents: bytemuck::cast_slice(indices),
                 usage: wgpu::BufferUsages::INDEX,
             },
         );
 
-        let surface_size = ctx.viewport().surface_size();
-        let (target_w, target_h) = match target_handle {
-            Some(handle) => render_target_ref(ctx.frame_resources(), handle)
-                .map(|texture_ref| texture_ref.physical_size())
-                .unwrap_or(surface_size),
-            None => surface_size,
-        };
         ctx.set_pipeline(&resources.pipeline);
         ctx.set_scissor_rect(0, 0, target_w, target_h);
         ctx.set_vertex_buffer(0, vertex_buffer.slice(..));
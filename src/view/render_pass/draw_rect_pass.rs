@@ -5,7 +5,8 @@ use crate::view::frame_graph::{
 };
 use crate::view::render_pass::render_target::{
     GraphicsPassContext as RenderPassContext, logical_scissor_to_target_physical,
-    render_target_origin, render_target_sample_count, resolve_texture_ref,
+    render_target_origin, render_target_sample_count, render_target_scale_factor,
+    resolve_texture_ref,
 };
 use crate::view::render_pass::{GraphicsCtx, GraphicsPass};
 use rustc_hash::FxHashSet;
@@ -65,6 +66,11 @@ pub struct RectPassParams {
     pub depth: f32,
     pub gradient: Option<GradientPaint>,
     pub border_gradient: Option<GradientPaint>,
+    /// Round the outer edges and border widths to whole target pixels when
+    /// the viewport has pixel snapping enabled.
+    pub pixel_snap: bool,
+    /// Left, right, top, bottom: borders drawn exactly one target pixel wide.
+    pub hairline_edges: [bool; 4],
 }
 
 impl RectPassParams {
@@ -193,6 +199,8 @@ pub(crate) struct RectPassTestSnapshot {
     pub(crate) depth_bits: u32,
     pub(crate) gradient: Option<GradientPaintTestSnapshot>,
     pub(crate) border_gradient: Option<GradientPaintTestSnapshot>,
+    pub(crate) pixel_snap: bool,
+    pub(crate) hairline_edges: [bool; 4],
     pub(crate) mode: RectRenderMode,
     pub(crate) explicit_scissor_rect: Option<[u32; 4]>,
     pub(crate) effective_scissor_rect: Option<[u32; 4]>,
@@ -228,6 +236,8 @@ impl RectPassTestSnapshot {
             depth_bits: pass.params.depth.to_bits(),
             gradient: pass.params.gradient.as_ref().map(Into::into),
             border_gradient: pass.params.border_gradient.as_ref().map(Into::into),
+            pixel_snap: pass.params.pixel_snap,
+            hairline_edges: pass.params.hairline_edges,
             mode: pass.render_mode,
             explicit_scissor_rect: pass.scissor_rect,
             effective_scissor_rect: intersect_scissor_rects(
@@ -453,6 +463,8 @@ impl DrawRectPass {
             render_mode: self.render_mode,
             gradient: self.params.gradient.clone(),
             border_gradient: self.params.border_gradient.clone(),
+            pixel_snap: self.params.pixel_snap,
+            hairline_edges: self.params.hairline_edges,
         }
     }
 
@@ -471,15 +483,19 @@ impl DrawRectPass {
             .handle()
             .and_then(|target| render_target_origin(ctx, target))
             .unwrap_or((0, 0));
-        let scale = ctx.viewport.scale_factor();
-        let scaled_position = [
-            self.params.position[0] * scale - target_origin.0 as f32
-                + target_meta.logical_origin.0 as f32,
-            self.params.position[1] * scale - target_origin.1 as f32
-                + target_meta.logical_origin.1 as f32,
-        ];
-        let scaled_size = [self.params.size[0] * scale, self.params.size[1] * scale];
-        let scaled_border_widths = self.params.border_widths.map(|v| v * scale);
+        let scale = render_target_scale_factor(ctx, self.output.render_target.handle());
+        let (scaled_position, scaled_size, scaled_border_widths) = target_rect_geometry(
+            self.params.position,
+            self.params.size,
+            self.params.border_widths,
+            self.params.hairline_edges,
+            scale,
+            [
+                target_meta.logical_origin.0 as f32 - target_origin.0 as f32,
+                target_meta.logical_origin.1 as f32 - target_origin.1 as f32,
+            ],
+            self.params.pixel_snap && ctx.viewport.pixel_snapping(),
+        );
         let scaled_border_radii = self
             .params
             .border_radii
@@ -525,6 +541,9 @@ impl DrawRectPass {
             gradient_upload.as_ref(),
             border_gradient_upload.as_ref(),
         );
+        let effective_scissor_rect =
+            intersect_scissor_rects(self.input.pass_context.scissor_rect, self.scissor_rect);
+        ctx.viewport.note_debug_scissor_rect(effective_scissor_rect);
         if ctx.viewport.debug_options().geometry_overlay {
             let (overlay_w, overlay_h) = ctx.viewport.surface_size();
             let (debug_vertices, debug_indices) = build_rect_debug_overlay_geometry(
@@ -752,6 +771,8 @@ pub struct DrawRectDraw {
     render_mode: RectRenderMode,
     gradient: Option<GradientPaint>,
     border_gradient: Option<GradientPaint>,
+    pixel_snap: bool,
+    hairline_edges: [bool; 4],
 }
 
 impl GraphicsPass for DrawRectPass {
@@ -996,6 +1017,50 @@ impl RectShaderShape {
     }
 }
 
+/// Target-pixel position, size and border widths of a rect whose logical
+/// geometry is scaled by `scale` and shifted by `offset`. Snapping rounds
+/// the outer edges to whole pixels without collapsing a non-empty rect, and
+/// rounds each non-zero border width to at least one pixel. Hairline edges
+/// are one pixel either way.
+fn target_rect_geometry(
+    position: [f32; 2],
+    size: [f32; 2],
+    border_widths: [f32; 4],
+    hairline_edges: [bool; 4],
+    scale: f32,
+    offset: [f32; 2],
+    snap: bool,
+) -> ([f32; 2], [f32; 2], [f32; 4]) {
+    let mut min = [
+        position[0] * scale + offset[0],
+        position[1] * scale + offset[1],
+    ];
+    let mut extent = [size[0] * scale, size[1] * scale];
+    let mut widths = border_widths.map(|width| width * scale);
+    if snap {
+        for axis in 0..2 {
+            let start = min[axis].round();
+            let end = (min[axis] + extent[axis]).round();
+            let floor = if extent[axis] > 0.0 { 1.0 } else { 0.0 };
+            min[axis] = start;
+            extent[axis] = (end - start).max(floor);
+        }
+        widths = widths.map(|width| {
+            if width > 0.0 {
+                width.round().max(1.0)
+            } else {
+                width
+            }
+        });
+    }
+    for (width, hairline) in widths.iter_mut().zip(hairline_edges) {
+        if hairline {
+            *width = 1.0;
+        }
+    }
+    (min, extent, widths)
+}
+
 fn stencil_class_and_reference(stencil_mode: RectStencilMode) -> (RectStencilClass, Option<u8>) {
     match stencil_mode {
         RectStencilMode::Disabled => (RectStencilClass::None, None),
@@ -1019,7 +1084,7 @@ fn encode_draw_rect_into_existing_pass(
         .color_target
         .and_then(|handle| render_target_origin(ctx.frame_resources(), handle))
         .unwrap_or((0, 0));
-    let scale = ctx.viewport().scale_factor();
+    let scale = render_target_scale_factor(ctx.frame_resources(), draw.color_target);
     let device = match ctx.viewport().device() {
         Some(device) => device.clone(),
         None => {
@@ -1032,12 +1097,18 @@ fn encode_draw_rect_into_existing_pass(
         .color_target
         .and_then(|handle| render_target_sample_count(ctx.frame_resources(), handle))
         .unwrap_or_else(|| ctx.viewport().msaa_sample_count());
-    let scaled_position = [
-        draw.position[0] * scale - target_origin.0 as f32 + target_meta.logical_origin.0 as f32,
-        draw.position[1] * scale - target_origin.1 as f32 + target_meta.logical_origin.1 as f32,
-    ];
-    let scaled_size = [draw.size[0] * scale, draw.size[1] * scale];
-    let scaled_border_widths = draw.border_widths.map(|v| v * scale);
+    let (scaled_position, scaled_size, scaled_border_widths) = target_rect_geometry(
+        draw.position,
+        draw.size,
+        draw.border_widths,
+        draw.hairline_edges,
+        scale,
+        [
+            target_meta.logical_origin.0 as f32 - target_origin.0 as f32,
+            target_meta.logical_origin.1 as f32 - target_origin.1 as f32,
+        ],
+        draw.pixel_snap && ctx.viewport().pixel_snapping(),
+    );
     let scaled_border_radii = draw
         .border_radii
         .map(|r| [r[0].max(0.0) * scale, r[1].max(0.0) * scale]);
@@ -1173,12 +1244,7 @@ fn encode_draw_rect_into_existing_pass(
         })
     };
     let scissor_rect_physical = draw.scissor_rect.and_then(|scissor_rect| {
-        logical_scissor_to_target_physical(
-            ctx.viewport(),
-            scissor_rect,
-            target_origin,
-            (target_w, target_h),
-        )
+        logical_scissor_to_target_physical(scale, scissor_rect, target_origin, (target_w, target_h))
     });
     ctx.set_pipeline(&pipeline);
     ctx.set_vertex_buffer(0, vertex_buffer.slice(..));
@@ -1982,6 +2048,55 @@ mod tests {
         assert!(right_sum <= 60.0 + 1e-4);
     }
 
+    #[test]
+    fn snapped_geometry_lands_on_whole_target_pixels() {
+        // A 1px border at x = 10.3 on a 1.5x target spans 15.45..16.95.
+        let (position, size, widths) = target_rect_geometry(
+            [10.3, 4.0],
+            [20.2, 0.2],
+            [1.0, 0.0, 1.0, 0.0],
+            [false; 4],
+            1.5,
+            [0.0, 0.0],
+            true,
+        );
+        assert_eq!(position, [15.0, 6.0]);
+        // 15.45 + 30.3 rounds to 46; the 0.3px-tall divider keeps one pixel.
+        assert_eq!(size, [31.0, 1.0]);
+        assert_eq!(widths, [2.0, 0.0, 2.0, 0.0]);
+
+        let (position, size, widths) = target_rect_geometry(
+            [10.3, 4.0],
+            [20.2, 0.2],
+            [1.0, 0.0, 1.0, 0.0],
+            [false; 4],
+            1.5,
+            [0.0, 0.0],
+            false,
+        );
+        assert_eq!(position, [10.3 * 1.5, 6.0]);
+        assert_eq!(size, [20.2 * 1.5, 0.2 * 1.5]);
+        assert_eq!(widths, [1.5, 0.0, 1.5, 0.0]);
+    }
+
+    #[test]
+    fn hairline_edges_are_one_target_pixel_with_or_without_snapping() {
+        for snap in [false, true] {
+            let (_, _, widths) = target_rect_geometry(
+                [0.0, 0.0],
+                [10.0, 10.0],
+                [1.0, 1.0, 1.0, 1.0],
+                [true, false, false, true],
+                2.5,
+                [0.0, 0.0],
+                snap,
+            );
+            assert_eq!(widths[0], 1.0);
+            assert_eq!(widths[3], 1.0);
+            assert_eq!(widths[1], if snap { 3.0 } else { 2.5 });
+        }
+    }
+
     #[test]
     fn opaque_rect_depth_is_derived_from_build_time_order() {
         let base = DrawRectPass::new(
@@ -10,6 +10,7 @@ pub mod clear_pass;
 pub mod composite_layer_pass;
 pub mod debug_overlay_pass;
 pub mod draw_rect_pass;
+pub mod nine_patch_pass;
 pub mod present_surface_pass;
 mod rect_shader;
 pub mod render_target;
@@ -18,8 +19,14 @@ pub mod text_pass;
 pub mod texture_composite_pass;
 pub use clear_pass::ClearPass;
 pub use draw_rect_pass::{DrawRectPass, OpaqueRectPass, RectRenderMode};
-pub use shadow_module::{ShadowMesh, ShadowModuleSpec, ShadowParams, build_shadow_module};
-pub use text_pass::prewarm_text_pipeline;
+pub use shadow_module::{
+    ShadowMesh, ShadowModuleSpec, ShadowParams, ShadowRasterCacheSlot, build_shadow_batch_module,
+    build_shadow_module,
+};
+pub use text_pass::{
+    GlyphAtlasMetrics, TextAntialiasing, TextAtlasMetrics, TextHinting, TextRenderOptions,
+    prewarm_text_pipeline, text_atlas_metrics,
+};
 pub use texture_composite_pass::{
     TextureCompositeInput, TextureCompositeMaskIn, TextureCompositeOutput, TextureCompositeParams,
     TextureCompositePass, TextureCompositeSourceIn,
@@ -240,6 +247,64 @@ pub(crate) fn create_transient_buffer(
     buffer
 }
 
+/// Object bound by a cached bind group. wgpu compares these by identity;
+/// the clone keeps the object alive, so its identity cannot be handed to a
+/// new object while a cache entry still refers to it.
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub(crate) enum BoundResource {
+    Layout(wgpu::BindGroupLayout),
+    TextureView(wgpu::TextureView),
+    Sampler(wgpu::Sampler),
+    Buffer(wgpu::Buffer),
+}
+
+/// Key for [`Viewport::get_or_create_bind_group`]: the layout and every
+/// bound resource, held by clone. A hit compares them all, so it binds
+/// exactly the objects `create` would.
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub(crate) struct BindGroupCacheKey {
+    label: &'static str,
+    resources: Vec<BoundResource>,
+}
+
+impl BindGroupCacheKey {
+    pub(crate) fn new(label: &'static str, layout: &wgpu::BindGroupLayout) -> Self {
+        Self {
+            label,
+            resources: vec![BoundResource::Layout(layout.clone())],
+        }
+    }
+
+    pub(crate) fn view(mut self, view: &wgpu::TextureView) -> Self {
+        self.resources
+            .push(BoundResource::TextureView(view.clone()));
+        self
+    }
+
+    pub(crate) fn sampler(mut self, sampler: &wgpu::Sampler) -> Self {
+        self.resources.push(BoundResource::Sampler(sampler.clone()));
+        self
+    }
+
+    pub(crate) fn buffer(mut self, buffer: &wgpu::Buffer) -> Self {
+        self.resources.push(BoundResource::Buffer(buffer.clone()));
+        self
+    }
+
+    /// Whether any bound view was created from `texture`.
+    pub(crate) fn references_texture(&self, texture: &wgpu::Texture) -> bool {
+        self.resources.iter().any(|resource| {
+            matches!(resource, BoundResource::TextureView(view) if view.texture() == texture)
+        })
+    }
+
+    pub(crate) fn references_buffer(&self, buffer: &wgpu::Buffer) -> bool {
+        self.resources
+            .iter()
+            .any(|resource| matches!(resource, BoundResource::Buffer(bound) if bound == buffer))
+    }
+}
+
 /// Destroy all transient buffers that were created during the current frame.
 /// Call once after `queue.submit()` or after dropping an unsubmitted encoder.
 #[cfg(target_arch = "wasm32")]
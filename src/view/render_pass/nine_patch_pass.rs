@@ -0,0 +1,556 @@
+//! Draws a [`NinePatch`] border image: every corner, edge tile and center
+//! tile of the sliced source becomes one textured quad, all submitted in a
+//! single draw.
+
+use crate::style::{NinePatch, NinePatchRepeat};
+use crate::view::ImageSampling;
+use crate::view::frame_graph::{
+    GraphicsColorAttachmentOps, GraphicsPassBuilder, GraphicsPassMergePolicy,
+};
+use crate::view::render_pass::draw_rect_pass::RenderTargetOut;
+use crate::view::render_pass::render_target::{
+    GraphicsPassContext as RenderPassContext, logical_scissor_to_target_physical,
+    render_target_sample_count, render_target_scale_factor, resolve_texture_ref,
+};
+use crate::view::render_pass::{GraphicsCtx, GraphicsPass};
+use crate::view::sampled_texture::SampledTextureUpload;
+use std::hash::{Hash, Hasher};
+
+/// Beyond this many tiles along one side the slice is stretched instead;
+/// a tiny tile on a huge box would otherwise cost thousands of quads.
+const MAX_TILES_PER_AXIS: f32 = 256.0;
+
+/// One drawn piece of a nine-patch: a logical `[x, y, width, height]` rect
+/// and the normalized `[u0, v0, u1, v1]` of the source it samples.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub(crate) struct NinePatchQuad {
+    pub(crate) rect: [f32; 4],
+    pub(crate) uv: [f32; 4],
+}
+
+/// Slices an `image_size` source by `nine_patch` and lays the pieces out
+/// over the logical `bounds`.
+pub(crate) fn nine_patch_quads(
+    bounds: [f32; 4],
+    image_size: [f32; 2],
+    nine_patch: &NinePatch,
+) -> Vec<NinePatchQuad> {
+    let [x, y, w, h] = bounds;
+    let [image_w, image_h] = image_size;
+    if w <= 0.0 || h <= 0.0 || image_w <= 0.0 || image_h <= 0.0 {
+        return Vec::new();
+    }
+    let [slice_top, slice_right, slice_bottom, slice_left] =
+        fit_insets(nine_patch.slice, image_w, image_h);
+    let [top, right, bottom, left] = fit_insets(nine_patch.resolved_width(), w, h);
+    let source_x = [0.0, slice_left, image_w - slice_right, image_w];
+    let source_y = [0.0, slice_top, image_h - slice_bottom, image_h];
+    let dest_x = [x, x + left, x + w - right, x + w];
+    let dest_y = [y, y + top, y + h - bottom, y + h];
+    // Edge tiles keep the scale of the side they belong to; the center
+    // follows the top and left edges.
+    let row_scale = [
+        side_scale(top, slice_top),
+        side_scale(top, slice_top),
+        side_scale(bottom, slice_bottom),
+    ];
+    let column_scale = [
+        side_scale(left, slice_left),
+        side_scale(left, slice_left),
+        side_scale(right, slice_right),
+    ];
+
+    let mut quads = Vec::new();
+    for row in 0..3 {
+        for column in 0..3 {
+            if row == 1 && column == 1 && !nine_patch.fill {
+                continue;
+            }
+            let source_w = source_x[column + 1] - source_x[column];
+            let source_h = source_y[row + 1] - source_y[row];
+            let dest_w = dest_x[column + 1] - dest_x[column];
+            let dest_h = dest_y[row + 1] - dest_y[row];
+            if source_w <= 0.0 || source_h <= 0.0 || dest_w <= 0.0 || dest_h <= 0.0 {
+                continue;
+            }
+            let columns = if column == 1 {
+                tile_axis(
+                    dest_x[column],
+                    dest_w,
+                    row_scale[row].map(|scale| source_w * scale),
+                    nine_patch.repeat,
+                )
+            } else {
+                vec![(dest_x[column], dest_w, 0.0, 1.0)]
+            };
+            let rows = if row == 1 {
+                tile_axis(
+                    dest_y[row],
+                    dest_h,
+                    column_scale[column].map(|scale| source_h * scale),
+                    nine_patch.repeat,
+                )
+            } else {
+                vec![(dest_y[row], dest_h, 0.0, 1.0)]
+            };
+            for &(tile_y, tile_h, v0, v1) in &rows {
+                for &(tile_x, tile_w, u0, u1) in &columns {
+                    quads.push(NinePatchQuad {
+                        rect: [tile_x, tile_y, tile_w, tile_h],
+                        uv: [
+                            (source_x[column] + u0 * source_w) / image_w,
+                            (source_y[row] + v0 * source_h) / image_h,
+                            (source_x[column] + u1 * source_w) / image_w,
+                            (source_y[row] + v1 * source_h) / image_h,
+                        ],
+                    });
+                }
+            }
+        }
+    }
+    quads
+}
+
+/// Scales `[top, right, bottom, left]` down uniformly until opposite sides
+/// fit in `width` x `height`.
+fn fit_insets(insets: [f32; 4], width: f32, height: f32) -> [f32; 4] {
+    let insets = insets.map(|value| value.max(0.0));
+    let [top, right, bottom, left] = insets;
+    let fit = |available: f32, used: f32| {
+        if used > 0.0 {
+            available / used
+        } else {
+            f32::INFINITY
+        }
+    };
+    let scale = fit(width, left + right)
+        .min(fit(height, top + bottom))
+        .min(1.0);
+    insets.map(|value| value * scale)
+}
+
+fn side_scale(dest: f32, source: f32) -> Option<f32> {
+    (dest > 0.0 && source > 0.0).then(|| dest / source)
+}
+
+/// Splits `[start, start + len)` into `(start, len, t0, t1)` tiles, `t0..t1`
+/// being the fraction of the source slice each one samples.
+fn tile_axis(
+    start: f32,
+    len: f32,
+    tile: Option<f32>,
+    repeat: NinePatchRepeat,
+) -> Vec<(f32, f32, f32, f32)> {
+    let stretch = vec![(start, len, 0.0, 1.0)];
+    let Some(tile) = tile.filter(|tile| *tile > 0.0 && len / *tile <= MAX_TILES_PER_AXIS) else {
+        return stretch;
+    };
+    match repeat {
+        NinePatchRepeat::Stretch => stretch,
+        NinePatchRepeat::Round => {
+            let count = (len / tile).round().max(1.0);
+            let tile = len / count;
+            (0..count as usize)
+                .map(|index| (start + index as f32 * tile, tile, 0.0, 1.0))
+                .collect()
+        }
+        NinePatchRepeat::Repeat => {
+            // One tile sits centered on the span; the rest are laid out from
+            // it and clipped at both ends.
+            let centered = start + (len - tile) * 0.5;
+            let end = start + len;
+            let mut tile_start = centered - ((centered - start) / tile).ceil() * tile;
+            let mut tiles = Vec::new();
+            while tile_start < end {
+                let lo = tile_start.max(start);
+                let hi = (tile_start + tile).min(end);
+                if hi > lo {
+                    tiles.push((
+                        lo,
+                        hi - lo,
+                        (lo - tile_start) / tile,
+                        (hi - tile_start) / tile,
+                    ));
+                }
+                tile_start += tile;
+            }
+            tiles
+        }
+    }
+}
+
+pub struct NinePatchPass {
+    quads: Vec<NinePatchQuad>,
+    opacity: f32,
+    source: SampledTextureUpload,
+    pass_context: RenderPassContext,
+    output: RenderTargetOut,
+}
+
+impl NinePatchPass {
+    pub(crate) fn new(
+        quads: Vec<NinePatchQuad>,
+        opacity: f32,
+        source: SampledTextureUpload,
+        pass_context: RenderPassContext,
+        output: RenderTargetOut,
+    ) -> Self {
+        Self {
+            quads,
+            opacity,
+            source,
+            pass_context,
+            output,
+        }
+    }
+}
+
+#[derive(Default, Clone, Copy, bytemuck::Pod, bytemuck::Zeroable)]
+#[repr(C)]
+struct NinePatchVertex {
+    position: [f32; 2],
+    uv: [f32; 2],
+    opacity: f32,
+}
+
+impl GraphicsPass for NinePatchPass {
+    fn setup(&mut self, builder: &mut GraphicsPassBuilder<'_, '_>) {
+        builder.set_graphics_merge_policy(GraphicsPassMergePolicy::Mergeable);
+        if builder.texture_target(&self.output).is_some() {
+            builder.write_color(&self.output, GraphicsColorAttachmentOps::load());
+        } else {
+            builder.write_surface_color(GraphicsColorAttachmentOps::load());
+        }
+        if self.pass_context.uses_depth_stencil {
+            builder.read_output_depth();
+            builder.read_output_stencil();
+        }
+    }
+
+    fn execute(&mut self, ctx: &mut GraphicsCtx<'_, '_, '_, '_>) {
+        if self.quads.is_empty() {
+            return;
+        }
+        if !ctx.viewport().ensure_sampled_texture(&self.source) {
+            ctx.mark_execution_failed();
+            return;
+        }
+        let Some(source_view) = ctx.viewport().sampled_texture_view(self.source.id) else {
+            ctx.mark_execution_failed();
+            return;
+        };
+        let Some(device) = ctx.viewport().device().cloned() else {
+            ctx.mark_execution_failed();
+            return;
+        };
+        let format = ctx.viewport().offscreen_format();
+        let sample_count = self
+            .output
+            .handle()
+            .and_then(|handle| render_target_sample_count(ctx.frame_resources(), handle))
+            .unwrap_or_else(|| ctx.viewport().msaa_sample_count());
+        let surface_size = ctx.viewport().surface_size();
+        let target = resolve_texture_ref(
+            self.output.handle(),
+            ctx.frame_resources(),
+            surface_size,
+            None,
+        );
+        let (target_w, target_h) = target.physical_size;
+        if target_w == 0 || target_h == 0 {
+            return;
+        }
+        let scale = render_target_scale_factor(ctx.frame_resources(), self.output.handle());
+        let origin = target.global_origin_f32();
+        let logical_origin = target.logical_origin_f32();
+        let to_ndc = |x: f32, y: f32| {
+            [
+                ((x * scale - origin[0] + logical_origin[0]) / target_w as f32) * 2.0 - 1.0,
+                1.0 - ((y * scale - origin[1] + logical_origin[1]) / target_h as f32) * 2.0,
+            ]
+        };
+        let mut vertices = Vec::with_capacity(self.quads.len() * 4);
+        let mut indices = Vec::with_capacity(self.quads.len() * 6);
+        for quad in &self.quads {
+            let [x, y, w, h] = quad.rect;
+            let [u0, v0, u1, v1] = quad.uv;
+            let base = vertices.len() as u32;
+            for (position, uv) in [
+                (to_ndc(x, y + h), [u0, v1]),
+                (to_ndc(x + w, y + h), [u1, v1]),
+                (to_ndc(x + w, y), [u1, v0]),
+                (to_ndc(x, y), [u0, v0]),
+            ] {
+                vertices.push(NinePatchVertex {
+                    position,
+                    uv,
+                    opacity: self.opacity,
+                });
+            }
+            indices.extend([base, base + 1, base + 2, base, base + 2, base + 3]);
+        }
+        let scissor_rect = self.pass_context.scissor_rect.and_then(|scissor_rect| {
+            logical_scissor_to_target_physical(
+                scale,
+                scissor_rect,
+                target.global_origin,
+                (target_w, target_h),
+            )
+        });
+
+        with_nine_patch_resources_cache(|cache| {
+            let key = nine_patch_resources_key(format, sample_count);
+            let resources =
+                cache.get_or_insert_with(key, || create_resources(&device, format, sample_count));
+            let sampler = match self.source.sampling {
+                ImageSampling::Nearest => &resources.nearest_sampler,
+                ImageSampling::Linear => &resources.linear_sampler,
+            };
+            let bind_group_key =
+                super::BindGroupCacheKey::new("NinePatch", &resources.bind_group_layout)
+                    .view(&source_view)
+                    .sampler(sampler);
+            let bind_group = ctx.viewport().get_or_create_bind_group(bind_group_key, || {
+                device.create_bind_group(&wgpu::BindGroupDescriptor {
+                    label: Some("NinePatch Bind Group"),
+                    layout: &resources.bind_group_layout,
+                    entries: &[
+                        wgpu::BindGroupEntry {
+                            binding: 0,
+                            resource: wgpu::BindingResource::TextureView(&source_view),
+                        },
+                        wgpu::BindGroupEntry {
+                            binding: 1,
+                            resource: wgpu::BindingResource::Sampler(sampler),
+                        },
+                    ],
+                })
+            });
+            let vertex_buffer = super::create_transient_buffer(
+                &device,
+                &wgpu::util::BufferInitDescriptor {
+                    label: Some("NinePatch Vertex Buffer"),
+                    contents: bytemuck::cast_slice(&vertices),
+                    usage: wgpu::BufferUsages::VERTEX,
+                },
+            );
+            let index_buffer = super::create_transient_buffer(
+                &device,
+                &wgpu::util::BufferInitDescriptor {
+                    label: Some("NinePatch Index Buffer"),
+                    contents: bytemuck::cast_slice(&indices),
+                    usage: wgpu::BufferUsages::INDEX,
+                },
+            );
+            let pipeline = match (
+                self.pass_context.uses_depth_stencil,
+                self.pass_context.stencil_clip_id.is_some(),
+            ) {
+                (true, true) => &resources.pipeline_stencil_test,
+                (true, false) => &resources.pipeline_depth_no_stencil,
+                (false, _) => &resources.pipeline_no_depth,
+            };
+            match scissor_rect {
+                Some([x, y, w, h]) => ctx.set_scissor_rect(x, y, w, h),
+                None => ctx.set_scissor_rect(0, 0, target_w, target_h),
+            }
+            ctx.set_stencil_reference(self.pass_context.stencil_reference());
+            ctx.set_pipeline(pipeline);
+            ctx.set_bind_group(0, &bind_group, &[]);
+            ctx.set_vertex_buffer(0, vertex_buffer.slice(..));
+            ctx.set_index_buffer(index_buffer.slice(..), wgpu::IndexFormat::Uint32);
+            ctx.draw_indexed(0..indices.len() as u32, 0, 0..1);
+        });
+    }
+}
+
+struct NinePatchResources {
+    pipeline_no_depth: wgpu::RenderPipeline,
+    pipeline_depth_no_stencil: wgpu::RenderPipeline,
+    pipeline_stencil_test: wgpu::RenderPipeline,
+    bind_group_layout: wgpu::BindGroupLayout,
+    linear_sampler: wgpu::Sampler,
+    nearest_sampler: wgpu::Sampler,
+}
+
+crate::static_resource_cache! {
+    fn with_nine_patch_resources_cache -> ResourceCache<NinePatchResources>
+        = stats("nine_patch_pipeline")
+}
+
+pub fn clear_nine_patch_resources_cache() {
+    with_nine_patch_resources_cache(|cache| {
+        cache.clear();
+    });
+}
+
+fn nine_patch_resources_key(format: wgpu::TextureFormat, sample_count: u32) -> u64 {
+    let mut hasher = std::collections::hash_map::DefaultHasher::new();
+    format.hash(&mut hasher);
+    sample_count.hash(&mut hasher);
+    hasher.finish()
+}
+
+#[derive(Clone, Copy)]
+enum NinePatchDepthMode {
+    None,
+    DepthNoStencil,
+    DepthStencilTest,
+}
+
+fn create_resources(
+    device: &wgpu::Device,
+    format: wgpu::TextureFormat,
+    sample_count: u32,
+) -> NinePatchResources {
+    let shader = device.create_shader_module(wgpu::ShaderModuleDescriptor {
+        label: Some("NinePatch Shader"),
+        source: wgpu::ShaderSource::Wgsl(include_str!("../../shader/nine_patch.wgsl").into()),
+    });
+    let bind_group_layout = device.create_bind_group_layout(&wgpu::BindGroupLayoutDescriptor {
+        label: Some("NinePatch Bind Group Layout"),
+        entries: &[
+            wgpu::BindGroupLayoutEntry {
+                binding: 0,
+                visibility: wgpu::ShaderStages::FRAGMENT,
+                ty: wgpu::BindingType::Texture {
+                    sample_type: wgpu::TextureSampleType::Float { filterable: true },
+                    view_dimension: wgpu::TextureViewDimension::D2,
+                    multisampled: false,
+                },
+                count: None,
+            },
+            wgpu::BindGroupLayoutEntry {
+                binding: 1,
+                visibility: wgpu::ShaderStages::FRAGMENT,
+                ty: wgpu::BindingType::Sampler(wgpu::SamplerBindingType::Filtering),
+                count: None,
+            },
+        ],
+    });
+    let sampler = |label, filter| {
+        device.create_sampler(&wgpu::SamplerDescriptor {
+            label: Some(label),
+            address_mode_u: wgpu::AddressMode::ClampToEdge,
+            address_mode_v: wgpu::AddressMode::ClampToEdge,
+            address_mode_w: wgpu::AddressMode::ClampToEdge,
+            mag_filter: filter,
+            min_filter: filter,
+            mipmap_filter: wgpu::MipmapFilterMode::Nearest,
+            ..Default::default()
+        })
+    };
+    let linear_sampler = sampler("NinePatch Linear Sampler", wgpu::FilterMode::Linear);
+    let nearest_sampler = sampler("NinePatch Nearest Sampler", wgpu::FilterMode::Nearest);
+    let pipeline_layout = device.create_pipeline_layout(&wgpu::PipelineLayoutDescriptor {
+        label: Some("NinePatch Pipeline Layout"),
+        bind_group_layouts: &[Some(&bind_group_layout)],
+        immediate_size: 0,
+    });
+    let pipeline = |depth_mode| {
+        create_pipeline(
+            device,
+            &pipeline_layout,
+            &shader,
+            format,
+            sample_count,
+            depth_mode,
+        )
+    };
+    NinePatchResources {
+        pipeline_no_depth: pipeline(NinePatchDepthMode::None),
+        pipeline_depth_no_stencil: pipeline(NinePatchDepthMode::DepthNoStencil),
+        pipeline_stencil_test: pipeline(NinePatchDepthMode::DepthStencilTest),
+        bind_group_layout,
+        linear_sampler,
+        nearest_sampler,
+    }
+}
+
+fn create_pipeline(
+    device: &wgpu::Device,
+    pipeline_layout: &wgpu::PipelineLayout,
+    shader: &wgpu::ShaderModule,
+    format: wgpu::TextureFormat,
+    sample_count: u32,
+    depth_mode: NinePatchDepthMode,
+) -> wgpu::RenderPipeline {
+    let premultiplied = wgpu::BlendComponent {
+        src_factor: wgpu::BlendFactor::One,
+        dst_factor: wgpu::BlendFactor::OneMinusSrcAlpha,
+        operation: wgpu::BlendOperation::Add,
+    };
+    let depth_stencil = match depth_mode {
+        NinePatchDepthMode::None => None,
+        NinePatchDepthMode::DepthNoStencil => Some(wgpu::DepthStencilState {
+            format: wgpu::TextureFormat::Depth24PlusStencil8,
+            depth_write_enabled: Some(false),
+            depth_compare: Some(wgpu::CompareFunction::Always),
+            stencil: wgpu::StencilState::default(),
+            bias: wgpu::DepthBiasState::default(),
+        }),
+        NinePatchDepthMode::DepthStencilTest => RenderPassContext {
+            uses_depth_stencil: true,
+            ..Default::default()
+        }
+        .clip_depth_stencil_state(),
+    };
+    device.create_render_pipeline(&wgpu::RenderPipelineDescriptor {
+        label: Some("NinePatch Pipeline"),
+        layout: Some(pipeline_layout),
+        vertex: wgpu::VertexState {
+            module: shader,
+            entry_point: Some("vs_main"),
+            buffers: &[Some(wgpu::VertexBufferLayout {
+                array_stride: std::mem::size_of::<NinePatchVertex>() as u64,
+                step_mode: wgpu::VertexStepMode::Vertex,
+                attributes: &[
+                    wgpu::VertexAttribute {
+                        format: wgpu::VertexFormat::Float32x2,
+                        offset: 0,
+                        shader_location: 0,
+                    },
+                    wgpu::VertexAttribute {
+                        format: wgpu::VertexFormat::Float32x2,
+                        offset: std::mem::size_of::<[f32; 2]>() as u64,
+                        shader_location: 1,
+                    },
+                    wgpu::VertexAttribute {
+                        format: wgpu::VertexFormat::Float32,
+                        offset: std::mem::size_of::<[f32; 4]>() as u64,
+                        shader_location: 2,
+                    },
+                ],
+            })],
+            compilation_options: wgpu::PipelineCompilationOptions::default(),
+        },
+        fragment: Some(wgpu::FragmentState {
+            module: shader,
+            entry_point: Some("fs_main"),
+            targets: &[Some(wgpu::ColorTargetState {
+                format,
+                blend: Some(wgpu::BlendState {
+                    color: premultiplied,
+                    alpha: premultiplied,
+                }),
+                write_mask: wgpu::ColorWrites::ALL,
+            })],
+            compilation_options: wgpu::PipelineCompilationOptions::default(),
+        }),
+        primitive: wgpu::PrimitiveState {
+            topology: wgpu::PrimitiveTopology::TriangleList,
+            ..Default::default()
+        },
+        depth_stencil,
+        multisample: wgpu::MultisampleState {
+            count: sample_count,
+            mask: !0,
+            alpha_to_coverage_enabled: false,
+        },
+        multiview_mask: None,
+        cache: None,
+    })
+}
+
+#[cfg(test)]
+mod tests;
@@ -0,0 +1,125 @@
+use super::*;
+use crate::view::ImageSource;
+use std::sync::Arc;
+
+const THIRD: f32 = 1.0 / 3.0;
+
+fn patch() -> NinePatch {
+    NinePatch::new(
+        ImageSource::Rgba {
+            width: 30,
+            height: 30,
+            pixels: Arc::from(vec![0_u8; 30 * 30 * 4]),
+        },
+        10.0,
+    )
+}
+
+fn top_edge(quads: &[NinePatchQuad], right: f32) -> Vec<NinePatchQuad> {
+    quads
+        .iter()
+        .copied()
+        .filter(|quad| quad.rect[1] == 0.0 && quad.rect[0] >= 10.0 && quad.rect[0] < right)
+        .collect()
+}
+
+fn assert_close(actual: f32, expected: f32) {
+    assert!(
+        (actual - expected).abs() < 1e-4,
+        "expected {expected}, got {actual}"
+    );
+}
+
+#[test]
+fn stretch_lays_out_corners_edges_and_center() {
+    let quads = nine_patch_quads([0.0, 0.0, 100.0, 60.0], [30.0, 30.0], &patch());
+
+    assert_eq!(quads.len(), 9);
+    assert_eq!(
+        quads[0],
+        NinePatchQuad {
+            rect: [0.0, 0.0, 10.0, 10.0],
+            uv: [0.0, 0.0, THIRD, THIRD],
+        }
+    );
+    assert_eq!(
+        quads[4],
+        NinePatchQuad {
+            rect: [10.0, 10.0, 80.0, 40.0],
+            uv: [THIRD, THIRD, 20.0 / 30.0, 20.0 / 30.0],
+        }
+    );
+    assert_eq!(quads[8].rect, [90.0, 50.0, 10.0, 10.0]);
+    assert_eq!(quads[8].uv, [20.0 / 30.0, 20.0 / 30.0, 1.0, 1.0]);
+}
+
+#[test]
+fn unfilled_patch_skips_the_center() {
+    let quads = nine_patch_quads([0.0, 0.0, 100.0, 60.0], [30.0, 30.0], &patch().fill(false));
+
+    assert_eq!(quads.len(), 8);
+    assert!(
+        quads
+            .iter()
+            .all(|quad| quad.rect != [10.0, 10.0, 80.0, 40.0])
+    );
+}
+
+#[test]
+fn round_rescales_edge_tiles_to_a_whole_count() {
+    let quads = nine_patch_quads(
+        [0.0, 0.0, 95.0, 60.0],
+        [30.0, 30.0],
+        &patch().repeat(NinePatchRepeat::Round),
+    );
+
+    // 75px of edge at a 10px tile rounds to 8 tiles of 9.375px.
+    let edge = top_edge(&quads, 85.0);
+    assert_eq!(edge.len(), 8);
+    for (index, quad) in edge.iter().enumerate() {
+        assert_close(quad.rect[0], 10.0 + index as f32 * 9.375);
+        assert_close(quad.rect[2], 9.375);
+        assert_eq!([quad.uv[0], quad.uv[2]], [THIRD, 20.0 / 30.0]);
+    }
+}
+
+#[test]
+fn repeat_centers_tiles_and_clips_both_ends() {
+    let quads = nine_patch_quads(
+        [0.0, 0.0, 95.0, 60.0],
+        [30.0, 30.0],
+        &patch().repeat(NinePatchRepeat::Repeat),
+    );
+
+    let edge = top_edge(&quads, 85.0);
+    assert_eq!(edge.len(), 9);
+    let first = edge.first().unwrap();
+    assert_close(first.rect[0], 10.0);
+    assert_close(first.rect[2], 2.5);
+    assert_close(first.uv[0], 17.5 / 30.0);
+    assert_close(first.uv[2], 20.0 / 30.0);
+    let last = edge.last().unwrap();
+    assert_close(last.rect[0], 82.5);
+    assert_close(last.rect[2], 2.5);
+    assert_close(last.uv[0], THIRD);
+    assert_close(last.uv[2], 12.5 / 30.0);
+}
+
+#[test]
+fn oversized_widths_shrink_to_fit_the_box() {
+    let quads = nine_patch_quads([0.0, 0.0, 60.0, 60.0], [30.0, 30.0], &patch().width(40.0));
+
+    assert_eq!(quads[0].rect, [0.0, 0.0, 30.0, 30.0]);
+    assert!(
+        quads
+            .iter()
+            .all(|quad| quad.rect[2] > 0.0 && quad.rect[3] > 0.0)
+    );
+    assert_eq!(quads.len(), 4);
+}
+
+#[test]
+fn empty_bounds_or_image_produce_no_quads() {
+    assert!(nine_patch_quads([0.0, 0.0, 0.0, 60.0], [30.0, 30.0], &patch()).is_empty());
+    assert!(nine_patch_quads([0.0, 0.0, 100.0, 60.0], [0.0, 30.0], &patch()).is_empty());
+}
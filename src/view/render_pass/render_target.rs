@@ -2,6 +2,14 @@ use crate::view::frame_graph::texture_resource::{TextureDesc, TextureHandle};
 use crate::view::frame_graph::{AllocationId, FrameResourceContext, PersistentTextureKey};
 use rustc_hash::FxHashMap;
 
+/// Clip state a graphics pass inherits from the element tree.
+///
+/// Ancestor clips, including rounded corners, are written into the target's
+/// stencil buffer: every nested clip scope increments the stencil inside its
+/// rounded inner box, and `stencil_clip_id` is the value that marks the
+/// pixels all enclosing scopes admit. Drawing with an `Equal` stencil test
+/// against it clips to every ancestor at once, wherever the content has
+/// been scrolled, without an intermediate layer per ancestor.
 #[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
 pub struct GraphicsPassContext {
     pub scissor_rect: Option<[u32; 4]>,
@@ -9,6 +17,48 @@ pub struct GraphicsPassContext {
     pub uses_depth_stencil: bool,
 }
 
+impl GraphicsPassContext {
+    /// Depth/stencil state for a custom pipeline drawing into the current
+    /// target. `None` when the target has no depth/stencil attachment.
+    pub fn clip_depth_stencil_state(&self) -> Option<wgpu::DepthStencilState> {
+        if !self.uses_depth_stencil {
+            return None;
+        }
+        let face = wgpu::StencilFaceState {
+            compare: wgpu::CompareFunction::Equal,
+            fail_op: wgpu::StencilOperation::Keep,
+            depth_fail_op: wgpu::StencilOperation::Keep,
+            pass_op: wgpu::StencilOperation::Keep,
+        };
+        Some(wgpu::DepthStencilState {
+            format: wgpu::TextureFormat::Depth24PlusStencil8,
+            depth_write_enabled: Some(false),
+            depth_compare: Some(wgpu::CompareFunction::Always),
+            stencil: wgpu::StencilState {
+                front: face,
+                back: face,
+                read_mask: 0xFF,
+                write_mask: 0x00,
+            },
+            bias: wgpu::DepthBiasState::default(),
+        })
+    }
+
+    /// Declares the depth/stencil reads a pass using
+    /// [`Self::clip_depth_stencil_state`] needs in `setup`.
+    pub fn read_clip_attachments(&self, builder: &mut super::GraphicsPassBuilder<'_, '_>) {
+        if self.uses_depth_stencil {
+            builder.read_output_depth();
+            builder.read_output_stencil();
+        }
+    }
+
+    /// Stencil reference to set before drawing.
+    pub fn stencil_reference(&self) -> u32 {
+        self.stencil_clip_id.map_or(0, u32::from)
+    }
+}
+
 struct RenderTargetEntry {
     texture: wgpu::Texture,
     view: wgpu::TextureView,
@@ -163,6 +213,9 @@ pub(crate) struct OffscreenRenderTargetPool {
     persistent_bindings: FxHashMap<PersistentTextureKey, PersistentRenderTargetBinding>,
     frame_epoch: u64,
     next_entry_id: u32,
+    /// Color textures destroyed since the viewport last dropped the bind
+    /// groups built from their views.
+    released_textures: Vec<wgpu::Texture>,
 }
 
 #[derive(Clone, Copy)]
@@ -196,6 +249,7 @@ impl OffscreenRenderTargetPool {
             persistent_bindings: FxHashMap::default(),
             frame_epoch: 0,
             next_entry_id: 0,
+            released_textures: Vec::new(),
         }
     }
 
@@ -272,6 +326,11 @@ impl OffscreenRenderTargetPool {
         self.persistent_bindings.clear();
         self.frame_epoch = 0;
         self.next_entry_id = 0;
+        self.released_textures.clear();
+    }
+
+    pub(crate) fn take_released_textures(&mut self) -> Vec<wgpu::Texture> {
+        std::mem::take(&mut self.released_textures)
     }
 
     pub fn acquire(
@@ -572,6 +631,7 @@ impl OffscreenRenderTargetPool {
             if let Some(msaa) = entry.msaa_texture.as_ref() {
                 msaa.destroy();
             }
+            self.released_textures.push(entry.texture);
         }
         self.frame_bindings
             .retain(|_, bound_id| *bound_id != entry_id);
@@ -733,13 +793,26 @@ pub(crate) fn render_target_sample_count(
     Some(texture_desc_for_handle(ctx, handle)?.sample_count().max(1))
 }
 
+/// Physical pixels per logical pixel when drawing into `handle`: the viewport
+/// scale factor times the target's own raster scale. The surface (`None`)
+/// always uses the viewport scale factor.
+pub(crate) fn render_target_scale_factor(
+    ctx: &mut impl FrameResourceContext,
+    handle: Option<TextureHandle>,
+) -> f32 {
+    let raster_scale = handle
+        .and_then(|handle| texture_desc_for_handle(ctx, handle))
+        .map_or(1.0, |desc| desc.raster_scale());
+    ctx.viewport().scale_factor().max(0.0001) * raster_scale
+}
+
 pub(crate) fn logical_scissor_to_target_physical(
-    viewport: &crate::view::viewport::Viewport,
+    scale_factor: f32,
     scissor_rect: [u32; 4],
     target_origin: (u32, u32),
     target_size: (u32, u32),
 ) -> Option<[u32; 4]> {
-    let scale = viewport.scale_factor().max(0.0001);
+    let scale = scale_factor.max(0.0001);
     let [x, y, width, height] = scissor_rect;
     let left = (x as f32 * scale).floor().max(0.0) as i64 - target_origin.0 as i64;
     let top = (y as f32 * scale).floor().max(0.0) as i64 - target_origin.1 as i64;
@@ -786,6 +859,29 @@ mod tests {
         }
     }
 
+    #[test]
+    fn clip_depth_stencil_state_tests_against_the_ancestor_clip_id() {
+        assert!(
+            GraphicsPassContext::default()
+                .clip_depth_stencil_state()
+                .is_none()
+        );
+
+        let context = GraphicsPassContext {
+            scissor_rect: None,
+            stencil_clip_id: Some(2),
+            uses_depth_stencil: true,
+        };
+        let state = context
+            .clip_depth_stencil_state()
+            .expect("depth/stencil target");
+        assert_eq!(state.stencil.front.compare, wgpu::CompareFunction::Equal);
+        assert_eq!(state.stencil.front.pass_op, wgpu::StencilOperation::Keep);
+        assert_eq!(state.stencil.write_mask, 0);
+        assert_eq!(context.stencil_reference(), 2);
+        assert_eq!(GraphicsPassContext::default().stencil_reference(), 0);
+    }
+
     #[test]
     fn persistent_compatibility_query_is_read_only_and_rejects_missing_binding() {
         let pool = OffscreenRenderTargetPool::new();
@@ -861,8 +957,12 @@ mod tests {
         let mut viewport = Viewport::new();
         viewport.set_scale_factor(1.25);
 
-        let physical =
-            logical_scissor_to_target_physical(&viewport, [10, 20, 101, 51], (3, 7), (200, 200));
+        let physical = logical_scissor_to_target_physical(
+            viewport.scale_factor(),
+            [10, 20, 101, 51],
+            (3, 7),
+            (200, 200),
+        );
 
         assert_eq!(physical, Some([9, 18, 127, 64]));
     }
@@ -1,5 +1,6 @@
 use crate::view::frame_graph::{
-    FrameGraph, GraphicsColorAttachmentOps, GraphicsPassBuilder, TextureDesc,
+    FrameGraph, GraphicsColorAttachmentOps, GraphicsPassBuilder, PersistentTextureKey,
+    RetainedTextureRole, TextureDesc,
 };
 use crate::view::render_pass::blur_module::{
     BlurModuleInput, BlurModuleOutput, BlurModuleParams, build_blur_module,
@@ -13,6 +14,12 @@ use crate::view::render_pass::texture_composite_pass::{
     TextureCompositePass, TextureCompositeSourceIn,
 };
 use crate::view::render_pass::{ClearPass, GraphicsPass};
+use rustc_hash::FxHashMap;
+use std::cell::RefCell;
+use std::collections::hash_map::DefaultHasher;
+use std::hash::{Hash, Hasher};
+use std::rc::Rc;
+use std::sync::{Arc, Mutex};
 
 const SHADOW_RESOURCES: u64 = 203;
 const SHADOW_INTERMEDIATE_FORMAT: wgpu::TextureFormat = wgpu::TextureFormat::Rgba16Float;
@@ -44,64 +51,102 @@ impl ShadowMesh {
         if w <= 0.0 || h <= 0.0 {
             return Self::default();
         }
-        let [tl, tr, br, bl] = normalize_corner_radii(radii, w, h);
-        if tl <= 0.001 && tr <= 0.001 && br <= 0.001 && bl <= 0.001 {
-            return Self {
-                vertices: vec![[x, y], [x + w, y], [x + w, y + h], [x, y + h]],
-                indices: vec![0, 1, 2, 0, 2, 3],
-            };
+        let key = [
+            w.to_bits(),
+            h.to_bits(),
+            radii[0].to_bits(),
+            radii[1].to_bits(),
+            radii[2].to_bits(),
+            radii[3].to_bits(),
+        ];
+        // Tessellation only depends on size and radii; cache it at the
+        // origin and translate, since the same shapes recur every frame.
+        let tessellation = ROUNDED_RECT_TESSELLATIONS.with(|cache| {
+            let mut cache = cache.borrow_mut();
+            if let Some(mesh) = cache.get(&key) {
+                return mesh.clone();
+            }
+            if cache.len() >= ROUNDED_RECT_TESSELLATION_CAPACITY {
+                cache.clear();
+            }
+            let mesh = Rc::new(tessellate_rounded_rect(w, h, radii));
+            cache.insert(key, mesh.clone());
+            mesh
+        });
+        Self {
+            vertices: tessellation
+                .vertices
+                .iter()
+                .map(|[vx, vy]| [x + vx, y + vy])
+                .collect(),
+            indices: tessellation.indices.clone(),
         }
-        const ARC_SEGMENTS: usize = 6;
-        let mut ring = Vec::with_capacity(ARC_SEGMENTS * 4 + 4);
-        append_arc(
-            &mut ring,
-            [x + w - tr, y + tr],
-            tr,
-            -std::f32::consts::FRAC_PI_2,
-            0.0,
-            ARC_SEGMENTS,
-        );
-        append_arc(
-            &mut ring,
-            [x + w - br, y + h - br],
-            br,
-            0.0,
-            std::f32::consts::FRAC_PI_2,
-            ARC_SEGMENTS,
-        );
-        append_arc(
-            &mut ring,
-            [x + bl, y + h - bl],
-            bl,
-            std::f32::consts::FRAC_PI_2,
-            std::f32::consts::PI,
-            ARC_SEGMENTS,
-        );
-        append_arc(
-            &mut ring,
-            [x + tl, y + tl],
-            tl,
-            std::f32::consts::PI,
-            std::f32::consts::PI * 1.5,
-            ARC_SEGMENTS,
-        );
+    }
+}
 
-        let cx = x + w * 0.5;
-        let cy = y + h * 0.5;
-        let mut vertices = Vec::with_capacity(ring.len() + 1);
-        vertices.push([cx, cy]);
-        vertices.extend(ring.iter().copied());
-
-        let mut indices = Vec::with_capacity(ring.len() * 3);
-        let ring_start = 1_u32;
-        let ring_len = ring.len() as u32;
-        for i in 0..ring_len {
-            let a = ring_start + i;
-            let b = ring_start + ((i + 1) % ring_len);
-            indices.extend_from_slice(&[0, a, b]);
-        }
-        Self { vertices, indices }
+const ROUNDED_RECT_TESSELLATION_CAPACITY: usize = 256;
+
+thread_local! {
+    static ROUNDED_RECT_TESSELLATIONS: RefCell<FxHashMap<[u32; 6], Rc<ShadowMesh>>> =
+        RefCell::new(FxHashMap::default());
+}
+
+fn tessellate_rounded_rect(w: f32, h: f32, radii: [f32; 4]) -> ShadowMesh {
+    let [tl, tr, br, bl] = normalize_corner_radii(radii, w, h);
+    if tl <= 0.001 && tr <= 0.001 && br <= 0.001 && bl <= 0.001 {
+        return ShadowMesh {
+            vertices: vec![[0.0, 0.0], [w, 0.0], [w, h], [0.0, h]],
+            indices: vec![0, 1, 2, 0, 2, 3],
+        };
     }
+    const ARC_SEGMENTS: usize = 6;
+    let mut ring = Vec::with_capacity(ARC_SEGMENTS * 4 + 4);
+    append_arc(
+        &mut ring,
+        [w - tr, tr],
+        tr,
+        -std::f32::consts::FRAC_PI_2,
+        0.0,
+        ARC_SEGMENTS,
+    );
+    append_arc(
+        &mut ring,
+        [w - br, h - br],
+        br,
+        0.0,
+        std::f32::consts::FRAC_PI_2,
+        ARC_SEGMENTS,
+    );
+    append_arc(
+        &mut ring,
+        [bl, h - bl],
+        bl,
+        std::f32::consts::FRAC_PI_2,
+        std::f32::consts::PI,
+        ARC_SEGMENTS,
+    );
+    append_arc(
+        &mut ring,
+        [tl, tl],
+        tl,
+        std::f32::consts::PI,
+        std::f32::consts::PI * 1.5,
+        ARC_SEGMENTS,
+    );
+
+    let mut vertices = Vec::with_capacity(ring.len() + 1);
+    vertices.push([w * 0.5, h * 0.5]);
+    vertices.extend(ring.iter().copied());
+
+    let mut indices = Vec::with_capacity(ring.len() * 3);
+    let ring_start = 1_u32;
+    let ring_len = ring.len() as u32;
+    for i in 0..ring_len {
+        let a = ring_start + i;
+        let b = ring_start + ((i + 1) % ring_len);
+        indices.extend_from_slice(&[0, a, b]);
+    }
+    ShadowMesh { vertices, indices }
 }
 
 fn append_arc(
@@ -193,11 +238,95 @@ pub struct ShadowModuleSpec {
     pub scale_factor: f32,
     pub pass_context: GraphicsPassContext,
     pub output: RenderTargetOut,
+    /// Keeps the blurred raster in a persistent texture across frames while
+    /// the shadow is unchanged. `None` rasterizes the shadow every frame.
+    pub raster_cache: Option<ShadowRasterCacheSlot>,
+}
+
+/// Identifies one shadow across frames; handed out by the build context.
+#[derive(Clone)]
+pub struct ShadowRasterCacheSlot {
+    id: u64,
+    rasters: Arc<Mutex<ShadowRasterFrame>>,
+}
+
+impl ShadowRasterCacheSlot {
+    pub(crate) fn new(id: u64, rasters: Arc<Mutex<ShadowRasterFrame>>) -> Self {
+        Self { id, rasters }
+    }
+}
+
+/// Upper bound on shadow rasters one frame keeps persistent; shadows past
+/// it rasterize into transient textures as usual.
+const MAX_SHADOW_RASTERS_PER_FRAME: usize = 256;
+
+/// Blurred shadow rasters that earlier frames left in persistent textures,
+/// and the ones the current frame rasterized or reused. The viewport seeds
+/// `resident` with the entries still in its texture pool and commits
+/// `current` once the frame is submitted.
+#[derive(Default)]
+pub(crate) struct ShadowRasterFrame {
+    resident: FxHashMap<u64, ShadowRasterEntry>,
+    current: FxHashMap<u64, ShadowRasterEntry>,
+}
+
+impl ShadowRasterFrame {
+    pub(crate) fn new(resident: FxHashMap<u64, ShadowRasterEntry>) -> Self {
+        Self {
+            resident,
+            current: FxHashMap::default(),
+        }
+    }
+
+    pub(crate) fn take_current(&mut self) -> FxHashMap<u64, ShadowRasterEntry> {
+        std::mem::take(&mut self.current)
+    }
+
+    fn reusable(&self, id: u64, stamp: u64) -> bool {
+        self.current
+            .get(&id)
+            .or_else(|| self.resident.get(&id))
+            .is_some_and(|entry| entry.stamp == stamp)
+    }
+
+    /// A raster may be written once per frame, so a second, different use
+    /// of the same id in one frame falls back to transient textures.
+    fn can_raster(&self, id: u64) -> bool {
+        !self.current.contains_key(&id) && self.current.len() < MAX_SHADOW_RASTERS_PER_FRAME
+    }
+}
+
+#[derive(Clone, Debug)]
+pub(crate) struct ShadowRasterEntry {
+    stamp: u64,
+    color: TextureDesc,
+    mask: Option<TextureDesc>,
+}
+
+impl ShadowRasterEntry {
+    /// Whether every texture of the raster is still pooled, as reported by
+    /// `is_pooled`.
+    pub(crate) fn is_pooled(
+        &self,
+        id: u64,
+        mut is_pooled: impl FnMut(PersistentTextureKey, &TextureDesc) -> bool,
+    ) -> bool {
+        is_pooled(
+            shadow_raster_key(id, RetainedTextureRole::ShadowColor),
+            &self.color,
+        ) && self.mask.as_ref().is_none_or(|mask| {
+            is_pooled(shadow_raster_key(id, RetainedTextureRole::ShadowMask), mask)
+        })
+    }
+}
+
+fn shadow_raster_key(id: u64, role: RetainedTextureRole) -> PersistentTextureKey {
+    PersistentTextureKey::retained(role, id)
 }
 
 pub(crate) struct ShadowFillPass {
     mesh: ShadowMesh,
-    color: [f32; 4],
+    vertex_colors: Vec<[f32; 4]>,
     render_target: RenderTargetOut,
 }
 
@@ -212,7 +341,11 @@ impl ShadowFillPass {
                 .map(|vertex| vertex.map(f32::to_bits))
                 .collect(),
             indices: self.mesh.indices.clone(),
-            color_bits: self.color.map(f32::to_bits),
+            vertex_color_bits: self
+                .vertex_colors
+                .iter()
+                .map(|color| color.map(f32::to_bits))
+                .collect(),
             render_target: self.render_target.handle(),
         }
     }
@@ -223,7 +356,7 @@ impl ShadowFillPass {
 pub(crate) struct ShadowFillPassTestSnapshot {
     pub(crate) vertices_bits: Vec<[u32; 2]>,
     pub(crate) indices: Vec<u32>,
-    pub(crate) color_bits: [u32; 4],
+    pub(crate) vertex_color_bits: Vec<[u32; 4]>,
     pub(crate) render_target: Option<crate::view::frame_graph::texture_resource::TextureHandle>,
 }
 
@@ -281,67 +414,253 @@ impl GraphicsPass for ShadowFillPass {
             target_h as f32,
             &self.mesh.vertices,
             &self.mesh.indices,
-            self.color,
+            &self.vertex_colors,
         );
     }
 }
 
+/// One shadow in target pixels, ready to share a layer with others.
+struct PreparedShadow {
+    spec: ShadowModuleSpec,
+    scale: f32,
+    raster_scale: f32,
+    shadow_vertices: Vec<[f32; 2]>,
+    mask_vertices: Vec<[f32; 2]>,
+    /// Pixel-aligned `[left, top, right, bottom]` of the blurred shadow.
+    bounds: [f32; 4],
+    /// `bounds` plus the clip geometry, i.e. every pixel the shadow reads
+    /// or writes.
+    footprint: [f32; 4],
+}
+
+impl PreparedShadow {
+    fn new(graph: &FrameGraph, spec: ShadowModuleSpec) -> Option<Self> {
+        // Shadows drawn into a raster-scaled target (a scaled-up transformed
+        // layer) are filled and blurred at that target's density.
+        let raster_scale = spec
+            .output
+            .handle()
+            .and_then(|handle| graph.texture_desc(handle))
+            .map_or(1.0, |desc| desc.raster_scale());
+        let scale = spec.scale_factor.max(0.0001) * raster_scale;
+        let mask_vertices = spec
+            .mesh
+            .vertices
+            .iter()
+            .map(|[x, y]| [x * scale, y * scale])
+            .collect::<Vec<_>>();
+        let mut shadow_vertices = mask_vertices.clone();
+        apply_spread(&mut shadow_vertices, (spec.params.spread * scale).max(0.0));
+        for v in &mut shadow_vertices {
+            v[0] += spec.params.offset_x * scale;
+            v[1] += spec.params.offset_y * scale;
+        }
+        let [min_x, min_y, max_x, max_y] = vertex_bounds(&shadow_vertices)?;
+        let blur_padding = ((spec.params.blur_radius.max(0.0) * scale) * 1.5).ceil();
+        let target_w = spec.viewport_width as f32 * raster_scale;
+        let target_h = spec.viewport_height as f32 * raster_scale;
+        let clamp_to_target = |[left, top, right, bottom]: [f32; 4]| {
+            [
+                left.floor().max(0.0).min(target_w),
+                top.floor().max(0.0).min(target_h),
+                right.ceil().max(0.0).min(target_w),
+                bottom.ceil().max(0.0).min(target_h),
+            ]
+        };
+        let bounds = clamp_to_target([
+            min_x - blur_padding,
+            min_y - blur_padding,
+            max_x + blur_padding,
+            max_y + blur_padding,
+        ]);
+        if bounds[2] <= bounds[0] || bounds[3] <= bounds[1] {
+            return None;
+        }
+        let mut footprint = bounds;
+        if spec.params.clip_to_geometry
+            && let Some(mask_bounds) = vertex_bounds(&mask_vertices).map(clamp_to_target)
+        {
+            footprint = union_bounds(footprint, mask_bounds);
+        }
+        Some(Self {
+            spec,
+            scale,
+            raster_scale,
+            shadow_vertices,
+            mask_vertices,
+            bounds,
+            footprint,
+        })
+    }
+
+    fn fill_color(&self) -> [f32; 4] {
+        let params = &self.spec.params;
+        [
+            params.color[0],
+            params.color[1],
+            params.color[2],
+            (params.color[3] * params.opacity).clamp(0.0, 1.0),
+        ]
+    }
+
+    fn blur_radius_px(&self) -> f32 {
+        (self.spec.params.blur_radius.max(0.0) * self.scale).max(0.0)
+    }
+
+    fn shares_layer_with(&self, other: &Self) -> bool {
+        self.blur_radius_px().to_bits() == other.blur_radius_px().to_bits()
+            && self.raster_scale.to_bits() == other.raster_scale.to_bits()
+            && self.spec.params.clip_to_geometry == other.spec.params.clip_to_geometry
+            && self.spec.pass_context == other.spec.pass_context
+            && self.spec.output.handle() == other.spec.output.handle()
+            && self.spec.raster_cache.is_some() == other.spec.raster_cache.is_some()
+    }
+
+    fn overlaps(&self, other: &Self) -> bool {
+        let [al, at, ar, ab] = self.footprint;
+        let [bl, bt, br, bb] = other.footprint;
+        al < br && bl < ar && at < bb && bt < ab
+    }
+}
+
+/// A batched layer may cover at most this multiple of its shadows' own
+/// area; blurring empty space between distant shadows costs more than the
+/// passes batching saves.
+const MAX_BATCH_AREA_GROWTH: f32 = 2.0;
+
+struct ShadowBatch {
+    shadows: Vec<PreparedShadow>,
+}
+
+impl ShadowBatch {
+    fn bounds(&self) -> [f32; 4] {
+        self.shadows
+            .iter()
+            .map(|shadow| shadow.bounds)
+            .reduce(union_bounds)
+            .unwrap_or_default()
+    }
+
+    fn overlaps(&self, shadow: &PreparedShadow) -> bool {
+        self.shadows.iter().any(|member| member.overlaps(shadow))
+    }
+
+    fn accepts(&self, shadow: &PreparedShadow) -> bool {
+        if !self.shadows[0].shares_layer_with(shadow) || self.overlaps(shadow) {
+            return false;
+        }
+        let own_area = self
+            .shadows
+            .iter()
+            .map(|member| bounds_area(member.bounds))
+            .sum::<f32>()
+            + bounds_area(shadow.bounds);
+        bounds_area(union_bounds(self.bounds(), shadow.bounds)) <= own_area * MAX_BATCH_AREA_GROWTH
+    }
+}
+
+fn vertex_bounds(vertices: &[[f32; 2]]) -> Option<[f32; 4]> {
+    let first = vertices.first().copied()?;
+    Some(vertices.iter().skip(1).fold(
+        [first[0], first[1], first[0], first[1]],
+        |[min_x, min_y, max_x, max_y], [x, y]| {
+            [min_x.min(*x), min_y.min(*y), max_x.max(*x), max_y.max(*y)]
+        },
+    ))
+}
+
+fn union_bounds(a: [f32; 4], b: [f32; 4]) -> [f32; 4] {
+    [
+        a[0].min(b[0]),
+        a[1].min(b[1]),
+        a[2].max(b[2]),
+        a[3].max(b[3]),
+    ]
+}
+
+fn bounds_area([left, top, right, bottom]: [f32; 4]) -> f32 {
+    (right - left).max(0.0) * (bottom - top).max(0.0)
+}
+
 pub fn build_shadow_module(graph: &mut FrameGraph, spec: ShadowModuleSpec) -> bool {
-    let scale = spec.scale_factor.max(0.0001);
-    let base_vertices = spec
-        .mesh
-        .vertices
-        .iter()
-        .map(|[x, y]| [x * scale, y * scale])
-        .collect::<Vec<_>>();
-    let mut shadow_vertices = base_vertices.clone();
-    apply_spread(&mut shadow_vertices, (spec.params.spread * scale).max(0.0));
-    for v in &mut shadow_vertices {
-        v[0] += spec.params.offset_x * scale;
-        v[1] += spec.params.offset_y * scale;
-    }
-    let Some(first) = shadow_vertices.first().copied() else {
-        return false;
-    };
-    let (mut min_x, mut min_y, mut max_x, mut max_y) = (first[0], first[1], first[0], first[1]);
-    for [x, y] in shadow_vertices.iter().copied().skip(1) {
-        min_x = min_x.min(x);
-        min_y = min_y.min(y);
-        max_x = max_x.max(x);
-        max_y = max_y.max(y);
-    }
-    let blur_padding = ((spec.params.blur_radius.max(0.0) * scale) * 1.5).ceil();
-    min_x -= blur_padding;
-    min_y -= blur_padding;
-    max_x += blur_padding;
-    max_y += blur_padding;
-    let target_w = spec.viewport_width as f32;
-    let target_h = spec.viewport_height as f32;
-    let bx = min_x.floor().max(0.0).min(target_w);
-    let by = min_y.floor().max(0.0).min(target_h);
-    let br = max_x.ceil().max(0.0).min(target_w);
-    let bb = max_y.ceil().max(0.0).min(target_h);
-    if br <= bx || bb <= by {
-        return false;
+    build_shadow_batch_module(graph, vec![spec])
+}
+
+/// Builds `specs` in paint order, letting shadows share one layer, fill,
+/// blur and composite when batching cannot change the result: they blur
+/// alike into the same target, and their footprints overlap neither each
+/// other nor any shadow painted in between. Returns whether any shadow was
+/// built.
+pub fn build_shadow_batch_module(graph: &mut FrameGraph, specs: Vec<ShadowModuleSpec>) -> bool {
+    let mut batches: Vec<ShadowBatch> = Vec::new();
+    for spec in specs {
+        let Some(shadow) = PreparedShadow::new(graph, spec) else {
+            continue;
+        };
+        let mut joined = None;
+        for (index, batch) in batches.iter().enumerate().rev() {
+            if batch.accepts(&shadow) {
+                joined = Some(index);
+                break;
+            }
+            if batch.overlaps(&shadow) {
+                break;
+            }
+        }
+        match joined {
+            Some(index) => batches[index].shadows.push(shadow),
+            None => batches.push(ShadowBatch {
+                shadows: vec![shadow],
+            }),
+        }
+    }
+    let built = !batches.is_empty();
+    for batch in batches {
+        build_shadow_batch(graph, batch);
     }
+    built
+}
+
+/// Layer-local vertices of a translated shape pick up float noise, so the
+/// raster stamp compares them at 1/256 px rather than bit for bit.
+fn quantize_stamp_coord(value: f32) -> i32 {
+    (value * 256.0).round() as i32
+}
 
+fn build_shadow_batch(graph: &mut FrameGraph, batch: ShadowBatch) {
+    let [bx, by, br, bb] = batch.bounds();
+    let first = &batch.shadows[0];
+    let scale = first.scale;
+    let raster_scale = first.raster_scale;
+    let clip_to_geometry = first.spec.params.clip_to_geometry;
+    let pass_context = first.spec.pass_context;
+    let output = first.spec.output;
+    let blur_radius_px = first.blur_radius_px();
     let layer_w = (br - bx).max(1.0) as u32;
     let layer_h = (bb - by).max(1.0) as u32;
-    let local_shadow_mesh = ShadowMesh::new(
-        shadow_vertices
-            .iter()
-            .map(|[x, y]| [x - bx, y - by])
-            .collect(),
-        spec.mesh.indices.clone(),
-    );
-    let local_mask_mesh = ShadowMesh::new(
-        base_vertices
-            .iter()
-            .map(|[x, y]| [x - bx, y - by])
-            .collect(),
-        spec.mesh.indices.clone(),
-    );
-    let shadow_layer = graph.declare_texture(
+
+    let mut local_shadow_mesh = ShadowMesh::default();
+    let mut local_mask_mesh = ShadowMesh::default();
+    let mut shadow_colors = Vec::new();
+    for shadow in &batch.shadows {
+        let base = local_shadow_mesh.vertices.len() as u32;
+        local_shadow_mesh
+            .vertices
+            .extend(shadow.shadow_vertices.iter().map(|[x, y]| [x - bx, y - by]));
+        local_mask_mesh
+            .vertices
+            .extend(shadow.mask_vertices.iter().map(|[x, y]| [x - bx, y - by]));
+        let indices = shadow.spec.mesh.indices.iter().map(|index| index + base);
+        local_shadow_mesh.indices.extend(indices.clone());
+        local_mask_mesh.indices.extend(indices);
+        shadow_colors.extend(std::iter::repeat_n(
+            shadow.fill_color(),
+            shadow.shadow_vertices.len(),
+        ));
+    }
+    let mask_colors = vec![[1.0, 1.0, 1.0, 1.0]; local_mask_mesh.vertices.len()];
+
+    let layer_desc = |label: &str| {
         TextureDesc::new(
             layer_w,
             layer_h,
@@ -350,122 +669,191 @@ pub fn build_shadow_module(graph: &mut FrameGraph, spec: ShadowModuleSpec) -> bo
         )
         .with_origin(bx as u32, by as u32)
         .with_sample_count(1)
-        .with_label("Shadow Layer"),
-    );
-    let shadow_mask_layer = if spec.params.clip_to_geometry {
-        graph.declare_texture(
-            TextureDesc::new(
-                layer_w,
-                layer_h,
-                SHADOW_INTERMEDIATE_FORMAT,
-                wgpu::TextureDimension::D2,
-            )
-            .with_origin(bx as u32, by as u32)
-            .with_sample_count(1)
-            .with_label("Shadow Mask Layer"),
-        )
-    } else {
-        RenderTargetOut::default()
+        .with_raster_scale(raster_scale)
+        .with_label(label)
     };
 
-    graph.add_graphics_pass(ClearPass::new(
-        ClearParams::new([0.0, 0.0, 0.0, 0.0]),
-        ClearInput {
-            pass_context: spec.pass_context,
-            clear_depth_stencil: false,
-        },
-        ClearOutput {
-            render_target: shadow_layer,
-            ..Default::default()
-        },
-    ));
-    let shadow_fill_color = [
-        spec.params.color[0],
-        spec.params.color[1],
-        spec.params.color[2],
-        (spec.params.color[3] * spec.params.opacity).clamp(0.0, 1.0),
-    ];
-    graph.add_graphics_pass(ShadowFillPass {
-        mesh: local_shadow_mesh,
-        color: shadow_fill_color,
-        render_target: shadow_layer,
-    });
-    if spec.params.clip_to_geometry {
+    // Only blurred shadows are worth keeping: an unblurred one is a single
+    // fill either way.
+    let raster_cache = (blur_radius_px > 0.001)
+        .then(|| {
+            batch
+                .shadows
+                .iter()
+                .map(|shadow| shadow.spec.raster_cache.as_ref())
+                .collect::<Option<Vec<_>>>()
+        })
+        .flatten()
+        .map(|slots| {
+            let mut hasher = DefaultHasher::new();
+            slots.iter().for_each(|slot| slot.id.hash(&mut hasher));
+            let id = hasher.finish();
+            let mut hasher = DefaultHasher::new();
+            (layer_w, layer_h, raster_scale.to_bits()).hash(&mut hasher);
+            (blur_radius_px.to_bits(), clip_to_geometry).hash(&mut hasher);
+            for vertex in &local_shadow_mesh.vertices {
+                vertex.map(quantize_stamp_coord).hash(&mut hasher);
+            }
+            local_shadow_mesh.indices.hash(&mut hasher);
+            for color in &shadow_colors {
+                color.map(f32::to_bits).hash(&mut hasher);
+            }
+            if clip_to_geometry {
+                for vertex in &local_mask_mesh.vertices {
+                    vertex.map(quantize_stamp_coord).hash(&mut hasher);
+                }
+            }
+            (slots[0].rasters.clone(), id, hasher.finish())
+        })
+        .filter(|(rasters, id, stamp)| {
+            let rasters = rasters.lock().expect("shadow raster cache lock poisoned");
+            rasters.reusable(*id, *stamp) || rasters.can_raster(*id)
+        });
+    let cached_texture = |graph: &mut FrameGraph, id: u64, role, label| -> RenderTargetOut {
+        graph.declare_persistent_texture_internal(layer_desc(label), shadow_raster_key(id, role))
+    };
+
+    let mut composite_source = RenderTargetOut::default();
+    let mut shadow_mask_layer = RenderTargetOut::default();
+    let mut reused = false;
+    if let Some((rasters, id, stamp)) = &raster_cache {
+        let mut rasters = rasters.lock().expect("shadow raster cache lock poisoned");
+        if rasters.reusable(*id, *stamp) {
+            composite_source = cached_texture(
+                graph,
+                *id,
+                RetainedTextureRole::ShadowColor,
+                "Shadow Layer / Blurred",
+            );
+            if clip_to_geometry {
+                shadow_mask_layer = cached_texture(
+                    graph,
+                    *id,
+                    RetainedTextureRole::ShadowMask,
+                    "Shadow Mask Layer",
+                );
+            }
+            rasters.current.insert(
+                *id,
+                ShadowRasterEntry {
+                    stamp: *stamp,
+                    color: layer_desc("Shadow Layer / Blurred"),
+                    mask: clip_to_geometry.then(|| layer_desc("Shadow Mask Layer")),
+                },
+            );
+            reused = true;
+        }
+    }
+
+    if !reused {
+        let shadow_layer = graph.declare_texture(layer_desc("Shadow Layer"));
+        if clip_to_geometry {
+            shadow_mask_layer = match &raster_cache {
+                Some((_, id, _)) => cached_texture(
+                    graph,
+                    *id,
+                    RetainedTextureRole::ShadowMask,
+                    "Shadow Mask Layer",
+                ),
+                None => graph.declare_texture(layer_desc("Shadow Mask Layer")),
+            };
+        }
+
         graph.add_graphics_pass(ClearPass::new(
             ClearParams::new([0.0, 0.0, 0.0, 0.0]),
             ClearInput {
-                pass_context: spec.pass_context,
+                pass_context,
                 clear_depth_stencil: false,
             },
             ClearOutput {
-                render_target: shadow_mask_layer,
+                render_target: shadow_layer,
                 ..Default::default()
             },
         ));
         graph.add_graphics_pass(ShadowFillPass {
-            mesh: local_mask_mesh,
-            color: [1.0, 1.0, 1.0, 1.0],
-            render_target: shadow_mask_layer,
+            mesh: local_shadow_mesh,
+            vertex_colors: shadow_colors,
+            render_target: shadow_layer,
         });
-    }
+        if clip_to_geometry {
+            graph.add_graphics_pass(ClearPass::new(
+                ClearParams::new([0.0, 0.0, 0.0, 0.0]),
+                ClearInput {
+                    pass_context,
+                    clear_depth_stencil: false,
+                },
+                ClearOutput {
+                    render_target: shadow_mask_layer,
+                    ..Default::default()
+                },
+            ));
+            graph.add_graphics_pass(ShadowFillPass {
+                mesh: local_mask_mesh,
+                vertex_colors: mask_colors,
+                render_target: shadow_mask_layer,
+            });
+        }
 
-    let blur_radius_px = (spec.params.blur_radius.max(0.0) * scale).max(0.0);
-    let mut composite_source = shadow_layer;
-    if blur_radius_px > 0.001 {
-        let blurred = graph.declare_texture(
-            TextureDesc::new(
-                layer_w,
-                layer_h,
-                SHADOW_INTERMEDIATE_FORMAT,
-                wgpu::TextureDimension::D2,
-            )
-            .with_origin(bx as u32, by as u32)
-            .with_sample_count(1)
-            .with_label("Shadow Layer / Blurred"),
-        );
-        let built = build_blur_module(
-            graph,
-            BlurModuleParams {
-                blur_radius: blur_radius_px,
-                intermediate_format: SHADOW_INTERMEDIATE_FORMAT,
-            },
-            BlurModuleInput {
-                layer: shadow_layer
-                    .handle()
-                    .map(LayerIn::with_handle)
-                    .unwrap_or_default(),
-                pass_context: spec.pass_context,
-            },
-            BlurModuleOutput {
-                render_target: blurred,
-            },
-        );
-        if built {
-            composite_source = blurred;
+        composite_source = shadow_layer;
+        if blur_radius_px > 0.001 {
+            let blurred = match &raster_cache {
+                Some((_, id, _)) => cached_texture(
+                    graph,
+                    *id,
+                    RetainedTextureRole::ShadowColor,
+                    "Shadow Layer / Blurred",
+                ),
+                None => graph.declare_texture(layer_desc("Shadow Layer / Blurred")),
+            };
+            let built = build_blur_module(
+                graph,
+                BlurModuleParams {
+                    blur_radius: blur_radius_px,
+                    intermediate_format: SHADOW_INTERMEDIATE_FORMAT,
+                },
+                BlurModuleInput {
+                    layer: shadow_layer
+                        .handle()
+                        .map(LayerIn::with_handle)
+                        .unwrap_or_default(),
+                    pass_context,
+                },
+                BlurModuleOutput {
+                    render_target: blurred,
+                },
+            );
+            if built {
+                composite_source = blurred;
+                if let Some((rasters, id, stamp)) = &raster_cache {
+                    rasters
+                        .lock()
+                        .expect("shadow raster cache lock poisoned")
+                        .current
+                        .insert(
+                            *id,
+                            ShadowRasterEntry {
+                                stamp: *stamp,
+                                color: layer_desc("Shadow Layer / Blurred"),
+                                mask: clip_to_geometry.then(|| layer_desc("Shadow Mask Layer")),
+                            },
+                        );
+                }
+            }
         }
     }
 
+    let layer_bounds = [
+        bx / scale,
+        by / scale,
+        layer_w as f32 / scale,
+        layer_h as f32 / scale,
+    ];
     graph.add_graphics_pass(TextureCompositePass::new(
         TextureCompositeParams {
-            bounds: [
-                bx / scale,
-                by / scale,
-                layer_w as f32 / scale,
-                layer_h as f32 / scale,
-            ],
-            uv_bounds: Some([
-                bx / scale,
-                by / scale,
-                layer_w as f32 / scale,
-                layer_h as f32 / scale,
-            ]),
-            mask_uv_bounds: spec.params.clip_to_geometry.then_some([
-                bx / scale,
-                by / scale,
-                layer_w as f32 / scale,
-                layer_h as f32 / scale,
-            ]),
-            use_mask: spec.params.clip_to_geometry,
+            bounds: layer_bounds,
+            uv_bounds: Some(layer_bounds),
+            mask_uv_bounds: clip_to_geometry.then_some(layer_bounds),
+            use_mask: clip_to_geometry,
             source_is_premultiplied: true,
             opacity: 1.0,
             ..Default::default()
@@ -479,13 +867,12 @@ pub fn build_shadow_module(graph: &mut FrameGraph, spec: ShadowModuleSpec) -> bo
                 .handle()
                 .map(TextureCompositeMaskIn::with_handle)
                 .unwrap_or_default(),
-            spec.pass_context,
+            pass_context,
         ),
         TextureCompositeOutput {
-            render_target: spec.output,
+            render_target: output,
         },
     ));
-    true
 }
 
 crate::static_resource_cache! {
@@ -576,7 +963,7 @@ fn encode_mesh_fill_into_pass(
     target_h: f32,
     vertices: &[[f32; 2]],
     indices: &[u32],
-    color: [f32; 4],
+    colors: &[[f32; 4]],
 ) {
     if vertices.is_empty() || indices.is_empty() || target_w <= 0.0 || target_h <= 0.0 {
         return;
@@ -588,7 +975,8 @@ fn encode_mesh_fill_into_pass(
             contents: bytemuck::cast_slice(
                 &vertices
                     .iter()
-                    .map(|position| FillVertex {
+                    .zip(colors)
+                    .map(|(position, &color)| FillVertex {
                         position: [
                             (position[0] / target_w).clamp(0.0, 1.0) * 2.0 - 1.0,
                             1.0 - (position[1] / target_h).clamp(0.0, 1.0) * 2.0,
@@ -640,28 +1028,4 @@ fn apply_spread(vertices: &mut [[f32; 2]], spread: f32) {
 }
 
 #[cfg(test)]
-mod tests {
-    use super::ShadowMesh;
-
-    #[test]
-    fn rounded_rect_uniform_matches_per_corner_api() {
-        let uniform = ShadowMesh::rounded_rect(10.0, 20.0, 120.0, 70.0, 14.0);
-        let per_corner =
-            ShadowMesh::rounded_rect_with_radii(10.0, 20.0, 120.0, 70.0, [14.0, 14.0, 14.0, 14.0]);
-        assert_eq!(uniform.vertices, per_corner.vertices);
-        assert_eq!(uniform.indices, per_corner.indices);
-    }
-
-    #[test]
-    fn rounded_rect_per_corner_uses_distinct_corner_radii() {
-        let mesh =
-            ShadowMesh::rounded_rect_with_radii(0.0, 0.0, 100.0, 60.0, [30.0, 10.0, 20.0, 5.0]);
-        assert!(mesh.vertices.len() > 4);
-        let first_ring = mesh.vertices[1];
-        let last_ring = mesh.vertices[mesh.vertices.len() - 1];
-        assert!((first_ring[0] - 90.0).abs() < 0.001);
-        assert!((first_ring[1] - 0.0).abs() < 0.001);
-        assert!((last_ring[0] - 30.0).abs() < 0.001);
-        assert!((last_ring[1] - 0.0).abs() < 0.001);
-    }
-}
+mod tests;
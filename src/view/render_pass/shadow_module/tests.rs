@@ -0,0 +1,141 @@
+use super::{
+    ShadowFillPass, ShadowMesh, ShadowModuleSpec, ShadowParams, ShadowRasterCacheSlot,
+    ShadowRasterFrame, build_shadow_batch_module,
+};
+use crate::view::frame_graph::FrameGraph;
+use crate::view::render_pass::texture_composite_pass::TextureCompositePass;
+use std::sync::{Arc, Mutex};
+
+fn blurred_shadow_spec(
+    x: f32,
+    y: f32,
+    raster_cache: Option<ShadowRasterCacheSlot>,
+) -> ShadowModuleSpec {
+    ShadowModuleSpec {
+        mesh: ShadowMesh::rounded_rect(x, y, 40.0, 30.0, 6.0),
+        params: ShadowParams {
+            offset_x: 2.0,
+            offset_y: 2.0,
+            blur_radius: 4.0,
+            ..Default::default()
+        },
+        viewport_width: 400,
+        viewport_height: 300,
+        scale_factor: 1.0,
+        pass_context: Default::default(),
+        output: Default::default(),
+        raster_cache,
+    }
+}
+
+#[test]
+fn rounded_rect_uniform_matches_per_corner_api() {
+    let uniform = ShadowMesh::rounded_rect(10.0, 20.0, 120.0, 70.0, 14.0);
+    let per_corner =
+        ShadowMesh::rounded_rect_with_radii(10.0, 20.0, 120.0, 70.0, [14.0, 14.0, 14.0, 14.0]);
+    assert_eq!(uniform.vertices, per_corner.vertices);
+    assert_eq!(uniform.indices, per_corner.indices);
+}
+
+#[test]
+fn rounded_rect_per_corner_uses_distinct_corner_radii() {
+    let mesh = ShadowMesh::rounded_rect_with_radii(0.0, 0.0, 100.0, 60.0, [30.0, 10.0, 20.0, 5.0]);
+    assert!(mesh.vertices.len() > 4);
+    let first_ring = mesh.vertices[1];
+    let last_ring = mesh.vertices[mesh.vertices.len() - 1];
+    assert!((first_ring[0] - 90.0).abs() < 0.001);
+    assert!((first_ring[1] - 0.0).abs() < 0.001);
+    assert!((last_ring[0] - 30.0).abs() < 0.001);
+    assert!((last_ring[1] - 0.0).abs() < 0.001);
+}
+
+#[test]
+fn rounded_rect_translation_reuses_origin_tessellation() {
+    let origin = ShadowMesh::rounded_rect(0.0, 0.0, 64.0, 32.0, 8.0);
+    let moved = ShadowMesh::rounded_rect(10.0, 20.0, 64.0, 32.0, 8.0);
+    assert_eq!(origin.indices, moved.indices);
+    for (a, b) in origin.vertices.iter().zip(&moved.vertices) {
+        assert_eq!([a[0] + 10.0, a[1] + 20.0], *b);
+    }
+}
+
+#[test]
+fn disjoint_shadows_share_one_fill_and_overlapping_ones_do_not() {
+    let mut graph = FrameGraph::new();
+    assert!(build_shadow_batch_module(
+        &mut graph,
+        vec![
+            blurred_shadow_spec(10.0, 10.0, None),
+            blurred_shadow_spec(10.0, 70.0, None),
+        ],
+    ));
+    let fills = graph.test_graphics_passes::<ShadowFillPass>();
+    assert_eq!(fills.len(), 1);
+    let snapshot = fills[0].test_snapshot();
+    assert_eq!(
+        snapshot.vertices_bits.len(),
+        snapshot.vertex_color_bits.len()
+    );
+    assert_eq!(
+        graph.test_graphics_passes::<TextureCompositePass>().len(),
+        1
+    );
+
+    let mut graph = FrameGraph::new();
+    build_shadow_batch_module(
+        &mut graph,
+        vec![
+            blurred_shadow_spec(10.0, 10.0, None),
+            blurred_shadow_spec(20.0, 20.0, None),
+        ],
+    );
+    assert_eq!(graph.test_graphics_passes::<ShadowFillPass>().len(), 2);
+}
+
+#[test]
+fn shadow_batches_cannot_jump_over_an_overlapping_shadow() {
+    let mut distinct_blur = blurred_shadow_spec(10.0, 30.0, None);
+    distinct_blur.params.blur_radius = 8.0;
+    let mut graph = FrameGraph::new();
+    build_shadow_batch_module(
+        &mut graph,
+        vec![
+            blurred_shadow_spec(10.0, 10.0, None),
+            distinct_blur,
+            blurred_shadow_spec(10.0, 70.0, None),
+        ],
+    );
+    assert_eq!(graph.test_graphics_passes::<ShadowFillPass>().len(), 3);
+}
+
+#[test]
+fn unchanged_shadow_reuses_its_blurred_raster() {
+    let build = |rasters: &Arc<Mutex<ShadowRasterFrame>>, x: f32, color: [f32; 4]| {
+        let mut spec = blurred_shadow_spec(
+            x,
+            10.0,
+            Some(ShadowRasterCacheSlot::new(7, rasters.clone())),
+        );
+        spec.params.color = color;
+        let mut graph = FrameGraph::new();
+        assert!(build_shadow_batch_module(&mut graph, vec![spec]));
+        (
+            graph.test_graphics_passes::<ShadowFillPass>().len(),
+            graph.test_graphics_passes::<TextureCompositePass>().len(),
+        )
+    };
+    let black = [0.0, 0.0, 0.0, 1.0];
+    let first = Arc::new(Mutex::new(ShadowRasterFrame::default()));
+    assert_eq!(build(&first, 10.0, black), (1, 1));
+    let committed = first.lock().unwrap().take_current();
+    assert_eq!(committed.len(), 1);
+
+    // A whole-pixel move keeps the local raster, so only the composite
+    // is emitted.
+    let second = Arc::new(Mutex::new(ShadowRasterFrame::new(committed.clone())));
+    assert_eq!(build(&second, 30.0, black), (0, 1));
+    assert_eq!(second.lock().unwrap().take_current().len(), 1);
+
+    let third = Arc::new(Mutex::new(ShadowRasterFrame::new(committed)));
+    assert_eq!(build(&third, 10.0, [1.0, 0.0, 0.0, 1.0]), (1, 1));
+}
@@ -1,11 +1,12 @@
 use crate::view::frame_graph::{
-    FrameResourceContext, GraphicsColorAttachmentOps, GraphicsPassBuilder, GraphicsPassMergePolicy,
-    PrepareContext,
+    CacheStats, FrameResourceContext, GraphicsColorAttachmentOps, GraphicsPassBuilder,
+    GraphicsPassMergePolicy, PrepareContext, register_cache_stats,
 };
 use crate::view::render_pass::draw_rect_pass::RenderTargetOut;
 use crate::view::render_pass::render_target::{
     GraphicsPassContext as RenderPassContext, logical_scissor_to_target_physical,
-    render_target_format, render_target_origin, render_target_sample_count, resolve_texture_ref,
+    render_target_format, render_target_origin, render_target_sample_count,
+    render_target_scale_factor, resolve_texture_ref,
 };
 use crate::view::render_pass::{GraphicsCtx, GraphicsPass};
 use parley::FontData as ParleyFontData;
@@ -50,6 +51,87 @@ pub struct TextOutput {
     pub render_target: RenderTargetOut,
 }
 
+/// How mask (non-color) glyphs are antialiased.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Hash)]
+pub enum TextAntialiasing {
+    #[default]
+    Grayscale,
+    /// Per-channel coverage for horizontal RGB stripe panels. Needs
+    /// dual-source blending; adapters without it fall back to grayscale.
+    /// Only looks right over opaque backgrounds.
+    Subpixel,
+}
+
+/// When glyph outlines are hinted to the pixel grid. Hinting sharpens
+/// stems on low-density displays but distorts shapes, which is rarely worth
+/// it once there are enough device pixels per point.
+#[derive(Clone, Copy, Debug, Default, PartialEq)]
+pub enum TextHinting {
+    #[default]
+    Never,
+    Always,
+    /// Hint only while the viewport scale factor is below this value.
+    BelowScaleFactor(f32),
+}
+
+impl TextHinting {
+    pub fn applies_at(self, scale_factor: f32) -> bool {
+        match self {
+            Self::Never => false,
+            Self::Always => true,
+            Self::BelowScaleFactor(limit) => scale_factor < limit,
+        }
+    }
+}
+
+/// Text rendering quality knobs read by `TextPass` each frame. The default
+/// reproduces plain linear grayscale coverage.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct TextRenderOptions {
+    pub antialiasing: TextAntialiasing,
+    pub hinting: TextHinting,
+    /// Exponent applied as `coverage^(1 / gamma)`; values above `1.0`
+    /// thicken antialiased edges.
+    pub gamma: f32,
+    /// Stem darkening for light text in `0.0..=1.0`. Light-on-dark glyphs
+    /// look thinner than dark-on-light ones at the same coverage; this
+    /// boosts coverage in proportion to the text color's luminance.
+    pub contrast: f32,
+}
+
+impl Default for TextRenderOptions {
+    fn default() -> Self {
+        Self {
+            antialiasing: TextAntialiasing::Grayscale,
+            hinting: TextHinting::Never,
+            gamma: 1.0,
+            contrast: 0.0,
+        }
+    }
+}
+
+/// The part of [`TextRenderOptions`] that changes rasterized glyph bitmaps,
+/// resolved for one scale factor and adapter.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Hash)]
+pub(crate) struct TextRasterMode {
+    pub(crate) hint: bool,
+    pub(crate) subpixel: bool,
+}
+
+impl TextRasterMode {
+    pub(crate) fn resolve(
+        options: &TextRenderOptions,
+        scale_factor: f32,
+        dual_source_blending: bool,
+    ) -> Self {
+        Self {
+            hint: options.hinting.applies_at(scale_factor),
+            subpixel: dual_source_blending
+                && matches!(options.antialiasing, TextAntialiasing::Subpixel),
+        }
+    }
+}
+
 impl TextPreparedInputPass {
     pub(crate) fn new(
         params: TextPassPreparedParams,
@@ -145,6 +227,7 @@ pub(crate) struct TextPreparedPassTestSnapshot {
 
 struct TextPreparedState {
     renderer_key: TextRendererKey,
+    mask_pipeline: TextPipelineKind,
     globals_bind_group: wgpu::BindGroup,
     screen_buffer: wgpu::Buffer,
     fragment_buffer: wgpu::Buffer,
@@ -169,9 +252,14 @@ struct PreparedTextDraw {
 
 /// Which atlas a prepared draw samples from: the shared persistent atlas
 /// (owned by [`TextResources`], survives across frames) or a transient
-/// per-pass atlas built when the persistent one overflowed this frame.
+/// per-pass atlas built when the persistent one was full this frame.
+/// Persistent draws are split into runs of consecutive instances that live
+/// on the same page, so paint order is preserved across page switches.
 enum PreparedAtlasBinding {
-    Persistent(AtlasKind),
+    Persistent {
+        kind: AtlasKind,
+        batches: Vec<PersistentAtlasBatch>,
+    },
     Transient {
         texture: wgpu::Texture,
         _view: wgpu::TextureView,
@@ -179,6 +267,23 @@ enum PreparedAtlasBinding {
     },
 }
 
+struct PersistentAtlasBatch {
+    page: u32,
+    instances: std::ops::Range<u32>,
+}
+
+impl PreparedAtlasBinding {
+    fn persistent_pages(&self) -> impl Iterator<Item = (AtlasKind, u32)> + '_ {
+        let (kind, batches) = match self {
+            Self::Persistent { kind, batches } => (Some(*kind), batches.as_slice()),
+            Self::Transient { .. } => (None, &[][..]),
+        };
+        batches
+            .iter()
+            .filter_map(move |batch| Some((kind?, batch.page)))
+    }
+}
+
 impl Drop for PreparedTextDraw {
     fn drop(&mut self) {
         self.vertex_buffer.destroy();
@@ -188,52 +293,259 @@ impl Drop for PreparedTextDraw {
     }
 }
 
-/// Shared cross-frame glyph atlas: glyphs are uploaded once (per raster
-/// key) into a fixed-size texture; steady-state frames sample it with no
-/// texture creation or pixel uploads at all.
+/// Shared cross-frame glyph atlas for one [`AtlasKind`]: glyphs are
+/// uploaded once (per raster key) and steady-state frames sample them with
+/// no texture creation or pixel uploads at all. It grows by adding pages up
+/// to a fixed budget; past that, the least recently used page is evicted
+/// and refilled.
 struct PersistentAtlas {
+    allocator: AtlasPageAllocator,
+    pages: Vec<PersistentAtlasPage>,
+    /// Passes that could not be served from the persistent pages and
+    /// packed a one-off transient atlas instead.
+    transient_fallbacks: u64,
+}
+
+struct PersistentAtlasPage {
     texture: wgpu::Texture,
     _view: wgpu::TextureView,
     bind_group: wgpu::BindGroup,
-    width: u32,
-    height: u32,
-    cursor_x: u32,
-    cursor_y: u32,
-    row_height: u32,
-    slots: FxHashMap<TextRasterKey, PersistentAtlasSlot>,
-    /// Set when an insert failed; the atlas resets at the next frame
-    /// boundary (mid-frame resets would invalidate slots already baked
-    /// into earlier passes' instance buffers).
-    overflowed: bool,
 }
 
-#[derive(Clone, Copy)]
+#[derive(Clone, Copy, Debug, PartialEq)]
 struct PersistentAtlasSlot {
+    page: u32,
     uv_min: [f32; 2],
     uv_max: [f32; 2],
 }
 
 const PERSISTENT_ATLAS_PADDING: u32 = 1;
+const PERSISTENT_ATLAS_MAX_PAGES: usize = 4;
+
+/// Occupancy and churn of one glyph atlas kind.
+#[derive(Clone, Copy, Debug, Default, PartialEq)]
+pub struct GlyphAtlasMetrics {
+    pub pages: usize,
+    pub resident_glyphs: usize,
+    /// Fraction of allocated page area covered by resident glyphs.
+    pub occupancy: f32,
+    pub page_evictions: u64,
+    pub evicted_glyphs: u64,
+    pub transient_fallbacks: u64,
+}
+
+/// Glyph atlas metrics for the text pipeline on the current thread.
+#[derive(Clone, Copy, Debug, Default, PartialEq)]
+pub struct TextAtlasMetrics {
+    pub mask: GlyphAtlasMetrics,
+    pub color: GlyphAtlasMetrics,
+}
+
+/// CPU-side bookkeeping for an atlas: shelf packing per page, the glyph to
+/// slot map, and per-page LRU stamps. Holds no GPU handles so eviction can
+/// be exercised without an adapter.
+struct AtlasPageAllocator {
+    page_width: u32,
+    page_height: u32,
+    max_pages: usize,
+    pages: Vec<AtlasPageState>,
+    slots: FxHashMap<TextRasterKey, PersistentAtlasSlot>,
+    page_evictions: u64,
+    evicted_glyphs: u64,
+}
+
+#[derive(Default)]
+struct AtlasPageState {
+    cursor_x: u32,
+    cursor_y: u32,
+    row_height: u32,
+    used_area: u64,
+    glyphs: usize,
+    last_used_frame: u64,
+}
+
+/// Where a newly placed glyph goes. `evicted_page` is set when the page had
+/// to be cleared first; anything that baked that page's UVs is stale.
+#[derive(Clone, Copy, Debug, PartialEq)]
+struct AtlasPlacement {
+    slot: PersistentAtlasSlot,
+    x: u32,
+    y: u32,
+    evicted_page: Option<u32>,
+}
+
+impl AtlasPageState {
+    fn new(frame_epoch: u64) -> Self {
+        Self {
+            cursor_x: PERSISTENT_ATLAS_PADDING,
+            cursor_y: PERSISTENT_ATLAS_PADDING,
+            last_used_frame: frame_epoch,
+            ..Self::default()
+        }
+    }
+
+    /// Shelf-pack a `w`×`h` glyph; a failed attempt leaves the page as it
+    /// was so a later, smaller glyph can still use the current row.
+    fn allocate(&mut self, width: u32, height: u32, w: u32, h: u32) -> Option<(u32, u32)> {
+        let (mut x, mut y, mut row_height) = (self.cursor_x, self.cursor_y, self.row_height);
+        if x + w + PERSISTENT_ATLAS_PADDING > width {
+            y += row_height + PERSISTENT_ATLAS_PADDING;
+            x = PERSISTENT_ATLAS_PADDING;
+            row_height = 0;
+        }
+        if y + h + PERSISTENT_ATLAS_PADDING > height || x + w + PERSISTENT_ATLAS_PADDING > width {
+            return None;
+        }
+        let position = (x, y);
+        self.cursor_x = x + w + PERSISTENT_ATLAS_PADDING;
+        self.cursor_y = y;
+        self.row_height = row_height.max(h);
+        self.used_area += u64::from(w) * u64::from(h);
+        self.glyphs += 1;
+        Some(position)
+    }
+}
+
+impl AtlasPageAllocator {
+    fn new(page_width: u32, page_height: u32, max_pages: usize) -> Self {
+        Self {
+            page_width,
+            page_height,
+            max_pages: max_pages.max(1),
+            pages: Vec::new(),
+            slots: FxHashMap::default(),
+            page_evictions: 0,
+            evicted_glyphs: 0,
+        }
+    }
+
+    fn for_kind(kind: AtlasKind) -> Self {
+        match kind {
+            AtlasKind::Mask => Self::new(2048, 2048, PERSISTENT_ATLAS_MAX_PAGES),
+            AtlasKind::Color => Self::new(1024, 1024, PERSISTENT_ATLAS_MAX_PAGES),
+        }
+    }
+
+    /// Return the resident slot for `key`, marking its page used this frame.
+    fn lookup(&mut self, key: &TextRasterKey, frame_epoch: u64) -> Option<PersistentAtlasSlot> {
+        let slot = *self.slots.get(key)?;
+        self.touch_page(slot.page, frame_epoch);
+        Some(slot)
+    }
+
+    fn touch_page(&mut self, page: u32, frame_epoch: u64) {
+        if let Some(state) = self.pages.get_mut(page as usize) {
+            state.last_used_frame = frame_epoch;
+        }
+    }
+
+    /// Place a `w`×`h` glyph: first fit in an existing page, then a new
+    /// page, then the least recently used page not touched this frame.
+    /// `None` means every page is in use by the current frame (or the glyph
+    /// is larger than a page).
+    fn allocate(
+        &mut self,
+        key: TextRasterKey,
+        w: u32,
+        h: u32,
+        frame_epoch: u64,
+    ) -> Option<AtlasPlacement> {
+        let (width, height) = (self.page_width, self.page_height);
+        let mut evicted_page = None;
+        let mut placed = self
+            .pages
+            .iter_mut()
+            .enumerate()
+            .find_map(|(page, state)| Some((page, state.allocate(width, height, w, h)?)));
+        if placed.is_none() && self.pages.len() < self.max_pages {
+            let mut state = AtlasPageState::new(frame_epoch);
+            if let Some(position) = state.allocate(width, height, w, h) {
+                self.pages.push(state);
+                placed = Some((self.pages.len() - 1, position));
+            }
+        }
+        if placed.is_none() {
+            let (page, _) = self
+                .pages
+                .iter()
+                .enumerate()
+                .filter(|(_, state)| state.last_used_frame < frame_epoch)
+                .min_by_key(|(_, state)| state.last_used_frame)?;
+            let mut state = AtlasPageState::new(frame_epoch);
+            let position = state.allocate(width, height, w, h)?;
+            self.evict_page(page as u32);
+            self.pages[page] = state;
+            evicted_page = Some(page as u32);
+            placed = Some((page, position));
+        }
+        let (page, (x, y)) = placed?;
+        self.pages[page].last_used_frame = frame_epoch;
+        let slot = PersistentAtlasSlot {
+            page: page as u32,
+            uv_min: [x as f32 / width as f32, y as f32 / height as f32],
+            uv_max: [
+                (x + w) as f32 / width as f32,
+                (y + h) as f32 / height as f32,
+            ],
+        };
+        self.slots.insert(key, slot);
+        Some(AtlasPlacement {
+            slot,
+            x,
+            y,
+            evicted_page,
+        })
+    }
+
+    fn evict_page(&mut self, page: u32) {
+        let before = self.slots.len();
+        self.slots.retain(|_, slot| slot.page != page);
+        self.evicted_glyphs += (before - self.slots.len()) as u64;
+        self.page_evictions += 1;
+    }
+
+    fn metrics(&self) -> GlyphAtlasMetrics {
+        let page_area = u64::from(self.page_width) * u64::from(self.page_height);
+        let used_area = self.pages.iter().map(|page| page.used_area).sum::<u64>();
+        GlyphAtlasMetrics {
+            pages: self.pages.len(),
+            resident_glyphs: self.slots.len(),
+            occupancy: if self.pages.is_empty() {
+                0.0
+            } else {
+                used_area as f32 / (page_area * self.pages.len() as u64) as f32
+            },
+            page_evictions: self.page_evictions,
+            evicted_glyphs: self.evicted_glyphs,
+            transient_fallbacks: 0,
+        }
+    }
+}
 
 impl PersistentAtlas {
-    fn new(
+    fn new(kind: AtlasKind) -> Self {
+        Self {
+            allocator: AtlasPageAllocator::for_kind(kind),
+            pages: Vec::new(),
+            transient_fallbacks: 0,
+        }
+    }
+
+    fn create_page(
+        &self,
         device: &wgpu::Device,
         atlas_layout: &wgpu::BindGroupLayout,
         sampler: &wgpu::Sampler,
         kind: AtlasKind,
-    ) -> Self {
-        let (width, height) = match kind {
-            AtlasKind::Mask => (2048, 2048),
-            AtlasKind::Color => (1024, 1024),
-        };
+    ) -> PersistentAtlasPage {
         let texture = device.create_texture(&wgpu::TextureDescriptor {
             label: Some(match kind {
                 AtlasKind::Mask => "Text Persistent Mask Atlas",
                 AtlasKind::Color => "Text Persistent Color Atlas",
             }),
             size: wgpu::Extent3d {
-                width,
-                height,
+                width: self.allocator.page_width,
+                height: self.allocator.page_height,
                 depth_or_array_layers: 1,
             },
             mip_level_count: 1,
@@ -258,58 +570,38 @@ impl PersistentAtlas {
                 },
             ],
         });
-        Self {
+        PersistentAtlasPage {
             texture,
             _view: view,
             bind_group,
-            width,
-            height,
-            cursor_x: PERSISTENT_ATLAS_PADDING,
-            cursor_y: PERSISTENT_ATLAS_PADDING,
-            row_height: 0,
-            slots: FxHashMap::default(),
-            overflowed: false,
         }
     }
 
-    fn reset(&mut self) {
-        self.cursor_x = PERSISTENT_ATLAS_PADDING;
-        self.cursor_y = PERSISTENT_ATLAS_PADDING;
-        self.row_height = 0;
-        self.slots.clear();
-        self.overflowed = false;
-    }
-
-    /// Ensure `image` is resident and return its UVs. `None` means the
-    /// atlas is full; the caller falls back to a transient atlas for this
-    /// pass and the atlas resets next frame.
+    /// Ensure `image` is resident and return its slot, plus the page that
+    /// was evicted to make room, if any. `None` means no page could take the
+    /// glyph this frame; the caller falls back to a transient atlas.
+    #[allow(clippy::too_many_arguments)]
     fn ensure_slot(
         &mut self,
+        device: &wgpu::Device,
         queue: &wgpu::Queue,
+        atlas_layout: &wgpu::BindGroupLayout,
+        sampler: &wgpu::Sampler,
         kind: AtlasKind,
         key: TextRasterKey,
         image: &SwashRasterImage,
-    ) -> Option<PersistentAtlasSlot> {
-        if let Some(slot) = self.slots.get(&key) {
-            return Some(*slot);
+        frame_epoch: u64,
+    ) -> Option<(PersistentAtlasSlot, Option<u32>)> {
+        if let Some(slot) = self.allocator.lookup(&key, frame_epoch) {
+            return Some((slot, None));
         }
         let w = image.placement.width.max(1);
         let h = image.placement.height.max(1);
-        if self.cursor_x + w + PERSISTENT_ATLAS_PADDING > self.width {
-            self.cursor_y += self.row_height + PERSISTENT_ATLAS_PADDING;
-            self.cursor_x = PERSISTENT_ATLAS_PADDING;
-            self.row_height = 0;
-        }
-        if self.cursor_y + h + PERSISTENT_ATLAS_PADDING > self.height
-            || self.cursor_x + w + PERSISTENT_ATLAS_PADDING > self.width
-        {
-            self.overflowed = true;
-            return None;
+        let placement = self.allocator.allocate(key, w, h, frame_epoch)?;
+        while self.pages.len() <= placement.slot.page as usize {
+            let page = self.create_page(device, atlas_layout, sampler, kind);
+            self.pages.push(page);
         }
-        let dst_x = self.cursor_x;
-        let dst_y = self.cursor_y;
-        self.cursor_x += w + PERSISTENT_ATLAS_PADDING;
-        self.row_height = self.row_height.max(h);
 
         // Convert this glyph alone through the shared copy helper and
         // upload just its region.
@@ -317,11 +609,11 @@ impl PersistentAtlas {
         copy_glyph_to_atlas(kind, image, &mut pixels, w, 0, 0);
         queue.write_texture(
             wgpu::TexelCopyTextureInfo {
-                texture: &self.texture,
+                texture: &self.pages[placement.slot.page as usize].texture,
                 mip_level: 0,
                 origin: wgpu::Origin3d {
-                    x: dst_x,
-                    y: dst_y,
+                    x: placement.x,
+                    y: placement.y,
                     z: 0,
                 },
                 aspect: wgpu::TextureAspect::All,
@@ -339,20 +631,34 @@ impl PersistentAtlas {
             },
         );
         if std::env::var_os("RFGUI_ATLAS_DEBUG").is_some() {
-            eprintln!("[ATLASDBG] upload {}x{} at ({}, {})", w, h, dst_x, dst_y);
+            eprintln!(
+                "[ATLASDBG] upload {}x{} at ({}, {}) page {}{}",
+                w,
+                h,
+                placement.x,
+                placement.y,
+                placement.slot.page,
+                if placement.evicted_page.is_some() {
+                    " (evicted)"
+                } else {
+                    ""
+                }
+            );
+        }
+        Some((placement.slot, placement.evicted_page))
+    }
+
+    fn metrics(&self) -> GlyphAtlasMetrics {
+        GlyphAtlasMetrics {
+            transient_fallbacks: self.transient_fallbacks,
+            ..self.allocator.metrics()
+        }
+    }
+
+    fn destroy(&mut self) {
+        for page in self.pages.drain(..) {
+            page.texture.destroy();
         }
-        let slot = PersistentAtlasSlot {
-            uv_min: [
-                dst_x as f32 / self.width as f32,
-                dst_y as f32 / self.height as f32,
-            ],
-            uv_max: [
-                (dst_x + w) as f32 / self.width as f32,
-                (dst_y + h) as f32 / self.height as f32,
-            ],
-        };
-        self.slots.insert(key, slot);
-        Some(slot)
     }
 }
 
@@ -366,6 +672,7 @@ struct TextRendererKey {
 #[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
 enum TextPipelineKind {
     Mask,
+    Subpixel,
     Color,
 }
 
@@ -379,7 +686,8 @@ enum AtlasKind {
 #[derive(Clone, Copy, Debug, bytemuck::Pod, bytemuck::Zeroable)]
 struct ScreenUniform {
     screen_size: [f32; 2],
-    _pad: [f32; 2],
+    gamma: f32,
+    contrast: f32,
 }
 
 #[repr(C)]
@@ -479,6 +787,7 @@ pub(crate) struct TextRasterKey {
     font_size_bits: u32,
     scale_factor_bits: u32,
     normalized_coords_hash: u64,
+    mode: TextRasterMode,
 }
 
 pub(crate) struct CachedRasterImage {
@@ -509,10 +818,29 @@ struct CachedTextDrawEntry {
     last_used_frame: u64,
 }
 
+const TEXT_SHADER_SOURCE: &str = include_str!("../../shader/text.wgsl");
+/// The `enable` directive has to lead the module, so the subpixel variant
+/// is its own module with the shared shader appended.
+const TEXT_SUBPIXEL_SHADER_SOURCE: &str = concat!(
+    "enable dual_source_blending;\n",
+    include_str!("../../shader/text.wgsl"),
+    include_str!("../../shader/text_subpixel.wgsl"),
+);
+
 thread_local! {
     static TEXT_RESOURCES: RefCell<TextResources> = RefCell::new(TextResources::default());
 }
 
+static TEXT_PIPELINE_STATS: CacheStats = CacheStats::new("text_pipeline");
+
+/// Text pipelines live in per-thread resources rather than a
+/// `static_resource_cache!`, so they register their counters by hand.
+fn text_pipeline_stats() -> &'static CacheStats {
+    static REGISTERED: std::sync::Once = std::sync::Once::new();
+    REGISTERED.call_once(|| register_cache_stats(&TEXT_PIPELINE_STATS));
+    &TEXT_PIPELINE_STATS
+}
+
 impl GraphicsPass for TextPreparedInputPass {
     fn setup(&mut self, builder: &mut GraphicsPassBuilder<'_, '_>) {
         builder.set_graphics_merge_policy(GraphicsPassMergePolicy::Mergeable);
@@ -553,7 +881,7 @@ impl GraphicsPass for TextPreparedInputPass {
         draw_prepared_text(
             ctx,
             prepared,
-            TextPipelineKind::Mask,
+            prepared.mask_pipeline,
             prepared.mask_draw.as_deref(),
         );
         draw_prepared_text(
@@ -580,16 +908,31 @@ fn prepare_text_prepared_input_pass(
     }
 
     let target_handle = output.render_target.handle();
-    let (device, queue, surface_format, surface_size, scale_factor) = {
+    let (device, queue, surface_format, surface_size, options) = {
         let viewport = ctx.viewport();
         (
             viewport.device()?.clone(),
             viewport.queue()?.clone(),
             viewport.surface_format(),
             viewport.surface_size(),
-            viewport.scale_factor().max(0.0001),
+            viewport.text_render_options(),
         )
     };
+    // Glyphs are rasterized at the target's own density, so text inside a
+    // scaled-up transformed layer stays sharp instead of being magnified.
+    let scale_factor = render_target_scale_factor(ctx, target_handle);
+    let raster_mode = TextRasterMode::resolve(
+        &options,
+        scale_factor,
+        device
+            .features()
+            .contains(wgpu::Features::DUAL_SOURCE_BLENDING),
+    );
+    let mask_pipeline = if raster_mode.subpixel {
+        TextPipelineKind::Subpixel
+    } else {
+        TextPipelineKind::Mask
+    };
 
     let target_format = target_handle
         .and_then(|handle| render_target_format(ctx, handle))
@@ -602,17 +945,11 @@ fn prepare_text_prepared_input_pass(
     let target_origin = target_handle
         .and_then(|handle| render_target_origin(ctx, handle))
         .unwrap_or((0, 0));
-    let scissor_rect = params
-        .scissor_rect
-        .or(input.pass_context.scissor_rect)
-        .and_then(|rect| {
-            logical_scissor_to_target_physical(
-                ctx.viewport(),
-                rect,
-                target_origin,
-                target.physical_size,
-            )
-        });
+    let logical_scissor_rect = params.scissor_rect.or(input.pass_context.scissor_rect);
+    ctx.viewport().note_debug_scissor_rect(logical_scissor_rect);
+    let scissor_rect = logical_scissor_rect.and_then(|rect| {
+        logical_scissor_to_target_physical(scale_factor, rect, target_origin, target.physical_size)
+    });
     let stencil_clip_id = params
         .stencil_clip_id
         .or(input.pass_context.stencil_clip_id);
@@ -650,15 +987,30 @@ fn prepare_text_prepared_input_pass(
     // Instance data is origin-independent apart from sub-pixel snapping,
     // so a content hash keyed on glyphs + fragment origin fractions lets
     // scroll/move frames reuse the previous vertex buffers outright.
-    let draw_cache_key =
-        text_draw_cache_key(&params.staging_input, fragments.as_slice(), scale_factor);
+    let draw_cache_key = text_draw_cache_key(
+        &params.staging_input,
+        fragments.as_slice(),
+        scale_factor,
+        raster_mode,
+    );
     let cached_draws = TEXT_RESOURCES.with(|slot| {
         let mut resources = slot.borrow_mut();
         let frame_epoch = resources.frame_epoch;
-        resources.draw_cache.get_mut(&draw_cache_key).map(|entry| {
-            entry.last_used_frame = frame_epoch;
-            (entry.mask_draw.clone(), entry.color_draw.clone())
-        })
+        let TextResources {
+            draw_cache,
+            persistent_atlases,
+            ..
+        } = &mut *resources;
+        let entry = draw_cache.get_mut(&draw_cache_key)?;
+        entry.last_used_frame = frame_epoch;
+        // A reused draw samples its pages this frame just like a fresh one,
+        // which keeps them out of LRU eviction until the frame ends.
+        for (kind, page) in entry.draws().flat_map(|draw| draw.atlas.persistent_pages()) {
+            if let Some(atlas) = persistent_atlases.get_mut(&kind) {
+                atlas.allocator.touch_page(page, frame_epoch);
+            }
+        }
+        Some((entry.mask_draw.clone(), entry.color_draw.clone()))
     });
 
     let mut pending = Vec::new();
@@ -678,6 +1030,7 @@ fn prepare_text_prepared_input_pass(
                 &params.staging_input,
                 fragments.as_slice(),
                 scale_factor,
+                raster_mode,
                 &mut pending,
             );
         });
@@ -692,7 +1045,8 @@ fn prepare_text_prepared_input_pass(
             target.physical_size.0.max(1) as f32,
             target.physical_size.1.max(1) as f32,
         ],
-        _pad: [0.0, 0.0],
+        gamma: options.gamma.max(0.01),
+        contrast: options.contrast.clamp(0.0, 1.0),
     };
     let screen_buffer = super::create_transient_buffer(
         &device,
@@ -758,7 +1112,7 @@ fn prepare_text_prepared_input_pass(
                 // persistent one resets at the next frame boundary.
                 let cacheable = |draw: &Option<std::rc::Rc<PreparedTextDraw>>| {
                     draw.as_ref().is_none_or(|draw| {
-                        matches!(draw.atlas, PreparedAtlasBinding::Persistent(_))
+                        matches!(draw.atlas, PreparedAtlasBinding::Persistent { .. })
                     })
                 };
                 if (mask_draw.is_some() || color_draw.is_some())
@@ -778,7 +1132,7 @@ fn prepare_text_prepared_input_pass(
                 (mask_draw, color_draw)
             }
         };
-        resources.ensure_pipeline(&device, renderer_key, TextPipelineKind::Mask);
+        resources.ensure_pipeline(&device, renderer_key, mask_pipeline);
         resources.ensure_pipeline(&device, renderer_key, TextPipelineKind::Color);
         (globals_bind_group, mask_draw, color_draw)
     });
@@ -791,6 +1145,7 @@ fn prepare_text_prepared_input_pass(
 
     Some(TextPreparedState {
         renderer_key,
+        mask_pipeline,
         globals_bind_group,
         screen_buffer,
         fragment_buffer,
@@ -802,18 +1157,20 @@ fn prepare_text_prepared_input_pass(
 }
 
 /// Content hash of everything that shapes vertex-buffer bytes: glyph
-/// raster identity, paint colors/opacity, fragment indices, the scale
-/// factor, and each fragment origin's sub-pixel fraction and sign (the
+/// raster identity (including the raster mode), paint colors/opacity,
+/// fragment indices, the scale factor, and each fragment origin's sub-pixel fraction and sign (the
 /// snap in `collect_prepared_staging_glyphs` depends only on those, so
 /// integer-pixel moves and scrolls hash identically).
 fn text_draw_cache_key(
     input: &TextPassPreparedStagingInput,
     fragments: &[FragmentUniform],
     scale_factor: f32,
+    raster_mode: TextRasterMode,
 ) -> u64 {
     use std::hash::{Hash, Hasher};
     let mut hasher = rustc_hash::FxHasher::default();
     scale_factor.to_bits().hash(&mut hasher);
+    raster_mode.hash(&mut hasher);
     fragments.len().hash(&mut hasher);
     input.glyphs.len().hash(&mut hasher);
     for glyph in input.glyphs.iter() {
@@ -833,6 +1190,7 @@ fn text_draw_cache_key(
     hasher.finish()
 }
 
+#[allow(clippy::too_many_arguments)]
 fn collect_prepared_staging_glyphs(
     scale_context: &mut SwashScaleContext,
     raster_cache: &mut FxHashMap<TextRasterKey, CachedRasterImage>,
@@ -840,6 +1198,7 @@ fn collect_prepared_staging_glyphs(
     input: &TextPassPreparedStagingInput,
     fragments: &[FragmentUniform],
     scale_factor: f32,
+    raster_mode: TextRasterMode,
     out: &mut Vec<PendingGlyphInstance>,
 ) {
     for glyph in input.glyphs.iter() {
@@ -852,6 +1211,7 @@ fn collect_prepared_staging_glyphs(
             frame_epoch,
             &glyph.raster,
             scale_factor,
+            raster_mode,
         ) else {
             continue;
         };
@@ -874,7 +1234,7 @@ fn collect_prepared_staging_glyphs(
         let _ = fragment;
         out.push(PendingGlyphInstance {
             kind,
-            raster_key: text_raster_key_for_raster_input(&glyph.raster, scale_factor),
+            raster_key: text_raster_key_for_raster_input(&glyph.raster, scale_factor, raster_mode),
             local_pos,
             size: [width, height],
             image,
@@ -909,6 +1269,7 @@ pub(crate) fn build_text_pass_prepared_staging_probe(
                     frame_epoch,
                     &glyph.raster,
                     scale_factor,
+                    TextRasterMode::default(),
                 )?;
                 let width = image.placement.width.max(1) as f32;
                 let height = image.placement.height.max(1) as f32;
@@ -934,7 +1295,11 @@ pub(crate) fn build_text_pass_prepared_staging_probe(
                 );
                 Some(TextPassPreparedStagingGlyph {
                     glyph_index,
-                    raster_key: text_raster_key_for_raster_input(&glyph.raster, scale_factor),
+                    raster_key: text_raster_key_for_raster_input(
+                        &glyph.raster,
+                        scale_factor,
+                        TextRasterMode::default(),
+                    ),
                     paint: glyph.paint,
                     final_paint_pos: glyph.final_paint_pos,
                     instance_local_pos: local_pos,
@@ -974,9 +1339,10 @@ pub(crate) fn rasterize_text_pass_glyph_input(
     frame_epoch: u64,
     glyph: &TextPassRasterGlyphInput,
     scale_factor: f32,
+    mode: TextRasterMode,
 ) -> Option<std::sync::Arc<SwashRasterImage>> {
     let font_data = glyph.font_data.as_ref()?;
-    let key = text_raster_key_for_raster_input(glyph, scale_factor)?;
+    let key = text_raster_key_for_raster_input(glyph, scale_factor, mode)?;
     if let Some(entry) = raster_cache.get_mut(&key) {
         entry.last_used_frame = frame_epoch;
         return Some(entry.image.clone());
@@ -986,7 +1352,7 @@ pub(crate) fn rasterize_text_pass_glyph_input(
     let mut scaler = scale_context
         .builder(font_ref)
         .size((glyph.font_size * scale_factor).max(1.0))
-        .hint(false)
+        .hint(mode.hint)
         .build();
     let sources = [
         Source::ColorBitmap(SwashStrikeWith::BestFit),
@@ -996,7 +1362,11 @@ pub(crate) fn rasterize_text_pass_glyph_input(
     ];
     let mut image = SwashRasterImage::new();
     let rendered = SwashRender::new(&sources)
-        .format(SwashFormat::Alpha)
+        .format(if mode.subpixel {
+            SwashFormat::Subpixel
+        } else {
+            SwashFormat::Alpha
+        })
         .offset(SwashVector::new(0.0, 0.0))
         .render_into(&mut scaler, glyph.glyph_id as u16, &mut image);
     if !rendered {
@@ -1016,6 +1386,7 @@ pub(crate) fn rasterize_text_pass_glyph_input(
 pub(crate) fn text_raster_key_for_raster_input(
     glyph: &TextPassRasterGlyphInput,
     scale_factor: f32,
+    mode: TextRasterMode,
 ) -> Option<TextRasterKey> {
     let font_data = glyph.font_data.as_ref()?;
     if font_data.data.id() != glyph.font_data_id || font_data.index != glyph.font_index {
@@ -1028,6 +1399,7 @@ pub(crate) fn text_raster_key_for_raster_input(
         glyph.font_size,
         scale_factor,
         glyph.normalized_coords_hash,
+        mode,
     ))
 }
 
@@ -1038,6 +1410,7 @@ fn text_raster_key_from_parts(
     font_size: f32,
     scale_factor: f32,
     normalized_coords_hash: u64,
+    mode: TextRasterMode,
 ) -> TextRasterKey {
     TextRasterKey {
         font_blob_id,
@@ -1046,6 +1419,7 @@ fn text_raster_key_from_parts(
         font_size_bits: font_size.to_bits(),
         scale_factor_bits: scale_factor.to_bits(),
         normalized_coords_hash,
+        mode,
     }
 }
 
@@ -1064,7 +1438,7 @@ fn build_prepared_draw<'a>(
     if glyphs.is_empty() {
         return None;
     }
-    if let Some(instances) =
+    if let Some((instances, batches)) =
         build_persistent_atlas_instances(device, queue, resources, atlas_kind, glyphs.as_slice())
     {
         let vertex_buffer = device.create_buffer_init(&wgpu::util::BufferInitDescriptor {
@@ -1075,45 +1449,75 @@ fn build_prepared_draw<'a>(
         return Some(PreparedTextDraw {
             vertex_buffer,
             instance_count: instances.len() as u32,
-            atlas: PreparedAtlasBinding::Persistent(atlas_kind),
+            atlas: PreparedAtlasBinding::Persistent {
+                kind: atlas_kind,
+                batches,
+            },
         });
     }
+    if let Some(atlas) = resources.persistent_atlases.get_mut(&atlas_kind) {
+        atlas.transient_fallbacks += 1;
+    }
     build_transient_prepared_draw(device, queue, resources, atlas_kind, glyphs.as_slice())
 }
 
 /// Try to serve every glyph from the persistent atlas. Returns `None` when
-/// the atlas overflowed (or a glyph has no stable raster key); the caller
-/// then falls back to a transient per-pass atlas and the persistent atlas
-/// resets at the next frame boundary.
+/// no page could take a glyph this frame (or a glyph has no stable raster
+/// key); the caller then falls back to a transient per-pass atlas. Pages
+/// evicted along the way drop every cached draw that sampled them, so a
+/// rehomed glyph is never drawn with stale UVs.
 fn build_persistent_atlas_instances(
     device: &wgpu::Device,
     queue: &wgpu::Queue,
     resources: &mut TextResources,
     atlas_kind: AtlasKind,
     glyphs: &[&PendingGlyphInstance],
-) -> Option<Vec<TextGlyphInstance>> {
+) -> Option<(Vec<TextGlyphInstance>, Vec<PersistentAtlasBatch>)> {
     resources.ensure_common(device);
-    if !resources.persistent_atlases.contains_key(&atlas_kind) {
-        let atlas_layout = resources
-            .atlas_layout
-            .as_ref()
-            .expect("atlas bind group layout initialized");
-        let sampler = resources.sampler.as_ref().expect("sampler initialized");
-        let atlas = PersistentAtlas::new(device, atlas_layout, sampler, atlas_kind);
-        resources.persistent_atlases.insert(atlas_kind, atlas);
-    }
-    let atlas = resources
-        .persistent_atlases
-        .get_mut(&atlas_kind)
-        .expect("persistent atlas initialized");
-    if atlas.overflowed {
-        return None;
-    }
+    let frame_epoch = resources.frame_epoch;
+    let TextResources {
+        atlas_layout,
+        sampler,
+        persistent_atlases,
+        draw_cache,
+        ..
+    } = resources;
+    let atlas_layout = atlas_layout
+        .as_ref()
+        .expect("atlas bind group layout initialized");
+    let sampler = sampler.as_ref().expect("sampler initialized");
+    let atlas = persistent_atlases
+        .entry(atlas_kind)
+        .or_insert_with(|| PersistentAtlas::new(atlas_kind));
     let mut instances = Vec::with_capacity(glyphs.len());
+    let mut batches = Vec::<PersistentAtlasBatch>::new();
     for glyph in glyphs {
-        let slot = glyph
-            .raster_key
-            .and_then(|key| atlas.ensure_slot(queue, atlas_kind, key, &glyph.image))?;
+        let (slot, evicted_page) = glyph.raster_key.and_then(|key| {
+            atlas.ensure_slot(
+                device,
+                queue,
+                atlas_layout,
+                sampler,
+                atlas_kind,
+                key,
+                &glyph.image,
+                frame_epoch,
+            )
+        })?;
+        if let Some(page) = evicted_page {
+            // Earlier instances of this pass were resident on other pages
+            // (pages touched this frame are never evicted), so only the
+            // cross-frame cache can refer to the evicted page.
+            draw_cache.retain(|_, entry| !entry.uses_page(atlas_kind, page));
+        }
+        let index = instances.len() as u32;
+        match batches.last_mut() {
+            Some(batch) if batch.page == slot.page => batch.instances.end = index + 1,
+            _ => batches.push(PersistentAtlasBatch {
+                page: slot.page,
+                instances: index..index + 1,
+            }),
+        }
         instances.push(TextGlyphInstance {
             local_pos: glyph.local_pos,
             size: glyph.size,
@@ -1124,7 +1528,7 @@ fn build_persistent_atlas_instances(
             fragment_index: glyph.fragment_index,
         });
     }
-    Some(instances)
+    Some((instances, batches))
 }
 
 fn build_transient_prepared_draw(
@@ -1323,15 +1727,15 @@ fn copy_glyph_to_atlas(
                     }
                 }
                 (_, SwashRasterContent::SubpixelMask) => {
+                    // Keep per-channel coverage; `fs_subpixel` blends each
+                    // channel separately and plain masks read `.r`.
                     let src = ((row * width + col) * 4) as usize;
                     if src + 3 < image.data.len() && dst + 3 < pixels.len() {
                         let coverage = image.data[src]
                             .max(image.data[src + 1])
                             .max(image.data[src + 2]);
-                        pixels[dst] = coverage;
-                        pixels[dst + 1] = coverage;
-                        pixels[dst + 2] = coverage;
-                        pixels[dst + 3] = image.data[src + 3].max(coverage);
+                        pixels[dst..dst + 3].copy_from_slice(&image.data[src..src + 3]);
+                        pixels[dst + 3] = coverage;
                     }
                 }
                 (AtlasKind::Mask, SwashRasterContent::Color) => {}
@@ -1358,21 +1762,29 @@ fn draw_prepared_text(
             ctx.mark_execution_failed();
             return;
         };
-        let atlas_bind_group = match &draw.atlas {
-            PreparedAtlasBinding::Persistent(kind) => {
+        ctx.set_pipeline(pipeline);
+        ctx.set_bind_group(0, &prepared.globals_bind_group, &[]);
+        ctx.set_vertex_buffer(0, draw.vertex_buffer.slice(..));
+        match &draw.atlas {
+            PreparedAtlasBinding::Persistent { kind, batches } => {
                 let Some(atlas) = resources.persistent_atlases.get(kind) else {
                     ctx.mark_execution_failed();
                     return;
                 };
-                &atlas.bind_group
+                for batch in batches {
+                    let Some(page) = atlas.pages.get(batch.page as usize) else {
+                        ctx.mark_execution_failed();
+                        return;
+                    };
+                    ctx.set_bind_group(1, &page.bind_group, &[]);
+                    ctx.draw(0..6, batch.instances.clone());
+                }
             }
-            PreparedAtlasBinding::Transient { bind_group, .. } => bind_group,
-        };
-        ctx.set_pipeline(pipeline);
-        ctx.set_bind_group(0, &prepared.globals_bind_group, &[]);
-        ctx.set_bind_group(1, atlas_bind_group, &[]);
-        ctx.set_vertex_buffer(0, draw.vertex_buffer.slice(..));
-        ctx.draw(0..6, 0..draw.instance_count);
+            PreparedAtlasBinding::Transient { bind_group, .. } => {
+                ctx.set_bind_group(1, bind_group, &[]);
+                ctx.draw(0..6, 0..draw.instance_count);
+            }
+        }
     });
 }
 
@@ -1380,18 +1792,6 @@ impl TextResources {
     fn begin_frame(&mut self) {
         self.frame_epoch = self.frame_epoch.wrapping_add(1);
         self.evict_raster_cache();
-        let mut atlas_reset = false;
-        for atlas in self.persistent_atlases.values_mut() {
-            if atlas.overflowed {
-                atlas.reset();
-                atlas_reset = true;
-            }
-        }
-        if atlas_reset {
-            // Cached instance buffers bake atlas UVs; a reset invalidates
-            // every slot.
-            self.draw_cache.clear();
-        }
         self.evict_draw_cache();
     }
 
@@ -1471,9 +1871,10 @@ impl TextResources {
                 &wgpu::BindGroupLayoutDescriptor {
                     label: Some("Text Globals Bind Group Layout"),
                     entries: &[
+                        // Fragment stages read gamma/contrast from it.
                         wgpu::BindGroupLayoutEntry {
                             binding: 0,
-                            visibility: wgpu::ShaderStages::VERTEX,
+                            visibility: wgpu::ShaderStages::VERTEX_FRAGMENT,
                             ty: wgpu::BindingType::Buffer {
                                 ty: wgpu::BufferBindingType::Uniform,
                                 has_dynamic_offset: false,
@@ -1541,9 +1942,16 @@ impl TextResources {
         kind: TextPipelineKind,
     ) {
         self.ensure_common(device);
+        let stats = text_pipeline_stats();
         if self.pipelines.contains_key(&(key, kind)) {
+            stats
+                .hits
+                .fetch_add(1, std::sync::atomic::Ordering::Relaxed);
             return;
         }
+        stats
+            .misses
+            .fetch_add(1, std::sync::atomic::Ordering::Relaxed);
         let screen_layout = self.screen_layout.as_ref().expect("screen layout");
         let atlas_layout = self.atlas_layout.as_ref().expect("atlas layout");
         let pipeline_layout = device.create_pipeline_layout(&wgpu::PipelineLayoutDescriptor {
@@ -1551,14 +1959,25 @@ impl TextResources {
             bind_group_layouts: &[Some(screen_layout), Some(atlas_layout)],
             immediate_size: 0,
         });
+        let source = match kind {
+            TextPipelineKind::Subpixel => TEXT_SUBPIXEL_SHADER_SOURCE,
+            TextPipelineKind::Mask | TextPipelineKind::Color => TEXT_SHADER_SOURCE,
+        };
         let shader = device.create_shader_module(wgpu::ShaderModuleDescriptor {
             label: Some("Text Shader"),
-            source: wgpu::ShaderSource::Wgsl(include_str!("../../shader/text.wgsl").into()),
+            source: wgpu::ShaderSource::Wgsl(source.into()),
         });
         let fragment_entry = match kind {
             TextPipelineKind::Mask => "fs_mask",
+            TextPipelineKind::Subpixel => "fs_subpixel",
             TextPipelineKind::Color => "fs_color",
         };
+        // Subpixel output carries per-channel coverage in the second blend
+        // source, so each destination channel keeps `1 - coverage` of itself.
+        let dst_factor = match kind {
+            TextPipelineKind::Subpixel => wgpu::BlendFactor::OneMinusSrc1,
+            TextPipelineKind::Mask | TextPipelineKind::Color => wgpu::BlendFactor::OneMinusSrcAlpha,
+        };
         let pipeline = device.create_render_pipeline(&wgpu::RenderPipelineDescriptor {
             label: Some("Text Pipeline"),
             layout: Some(&pipeline_layout),
@@ -1577,12 +1996,12 @@ impl TextResources {
                     blend: Some(wgpu::BlendState {
                         color: wgpu::BlendComponent {
                             src_factor: wgpu::BlendFactor::One,
-                            dst_factor: wgpu::BlendFactor::OneMinusSrcAlpha,
+                            dst_factor,
                             operation: wgpu::BlendOperation::Add,
                         },
                         alpha: wgpu::BlendComponent {
                             src_factor: wgpu::BlendFactor::One,
-                            dst_factor: wgpu::BlendFactor::OneMinusSrcAlpha,
+                            dst_factor,
                             operation: wgpu::BlendOperation::Add,
                         },
                     }),
@@ -1614,8 +2033,8 @@ impl TextResources {
         self.pipelines.clear();
         self.raster_cache.clear();
         self.draw_cache.clear();
-        for (_, atlas) in self.persistent_atlases.drain() {
-            atlas.texture.destroy();
+        for (_, mut atlas) in self.persistent_atlases.drain() {
+            atlas.destroy();
         }
         self.screen_layout = None;
         self.atlas_layout = None;
@@ -1625,10 +2044,20 @@ impl TextResources {
 }
 
 impl CachedTextDrawEntry {
-    fn estimated_bytes(&self) -> usize {
-        [self.mask_draw.as_ref(), self.color_draw.as_ref()]
+    fn draws(&self) -> impl Iterator<Item = &PreparedTextDraw> {
+        [self.mask_draw.as_deref(), self.color_draw.as_deref()]
             .into_iter()
             .flatten()
+    }
+
+    fn uses_page(&self, kind: AtlasKind, page: u32) -> bool {
+        self.draws()
+            .flat_map(|draw| draw.atlas.persistent_pages())
+            .any(|used| used == (kind, page))
+    }
+
+    fn estimated_bytes(&self) -> usize {
+        self.draws()
             .map(|draw| draw.instance_count as usize * std::mem::size_of::<TextGlyphInstance>())
             .sum()
     }
@@ -1714,6 +2143,23 @@ pub fn begin_text_resources_frame() {
     TEXT_RESOURCES.with(|slot| slot.borrow_mut().begin_frame());
 }
 
+pub fn text_atlas_metrics() -> TextAtlasMetrics {
+    TEXT_RESOURCES.with(|slot| {
+        let resources = slot.borrow();
+        let metrics = |kind| {
+            resources
+                .persistent_atlases
+                .get(&kind)
+                .map(PersistentAtlas::metrics)
+                .unwrap_or_default()
+        };
+        TextAtlasMetrics {
+            mask: metrics(AtlasKind::Mask),
+            color: metrics(AtlasKind::Color),
+        }
+    })
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -1779,8 +2225,9 @@ mod tests {
         let input = first_renderable_raster_input();
         let scale_factor = 1.75;
 
-        let input_key = text_raster_key_for_raster_input(&input, scale_factor)
-            .expect("neutral input should produce a raster key");
+        let input_key =
+            text_raster_key_for_raster_input(&input, scale_factor, TextRasterMode::default())
+                .expect("neutral input should produce a raster key");
 
         assert_eq!(input_key.glyph_id, input.glyph_id);
         assert_eq!(input_key.font_size_bits, input.font_size.to_bits());
@@ -1798,14 +2245,14 @@ mod tests {
         let mut input = first_renderable_raster_input();
         input.font_data_id = input.font_data_id.wrapping_add(1);
 
-        assert!(text_raster_key_for_raster_input(&input, 1.0).is_none());
+        assert!(text_raster_key_for_raster_input(&input, 1.0, TextRasterMode::default()).is_none());
     }
 
     #[test]
     fn raster_input_uses_existing_rasterize_path() {
         let input = first_renderable_raster_input();
         let scale_factor = 1.0;
-        let key = text_raster_key_for_raster_input(&input, scale_factor)
+        let key = text_raster_key_for_raster_input(&input, scale_factor, TextRasterMode::default())
             .expect("neutral input should produce a raster key");
         let mut scale_context = SwashScaleContext::new();
         let mut raster_cache = FxHashMap::default();
@@ -1816,6 +2263,7 @@ mod tests {
             42,
             &input,
             scale_factor,
+            TextRasterMode::default(),
         )
         .expect("neutral input should rasterize through the existing glyph path");
 
@@ -1823,6 +2271,107 @@ mod tests {
         assert!(raster_cache.contains_key(&key));
     }
 
+    #[test]
+    fn raster_mode_resolves_hinting_per_scale_factor_and_subpixel_per_adapter() {
+        let options = TextRenderOptions {
+            antialiasing: TextAntialiasing::Subpixel,
+            hinting: TextHinting::BelowScaleFactor(2.0),
+            ..TextRenderOptions::default()
+        };
+
+        let low_dpi = TextRasterMode::resolve(&options, 1.0, true);
+        let high_dpi = TextRasterMode::resolve(&options, 2.0, true);
+        let no_dual_source = TextRasterMode::resolve(&options, 1.0, false);
+
+        assert!(low_dpi.hint && low_dpi.subpixel);
+        assert!(!high_dpi.hint && high_dpi.subpixel);
+        assert!(!no_dual_source.subpixel);
+        assert_eq!(
+            TextRasterMode::resolve(&TextRenderOptions::default(), 1.0, true),
+            TextRasterMode::default()
+        );
+    }
+
+    #[test]
+    fn raster_mode_keys_and_rasterizes_glyphs_separately() {
+        let input = first_renderable_raster_input();
+        let subpixel = TextRasterMode {
+            hint: true,
+            subpixel: true,
+        };
+        let mut scale_context = SwashScaleContext::new();
+        let mut raster_cache = FxHashMap::default();
+
+        let grayscale = rasterize_text_pass_glyph_input(
+            &mut scale_context,
+            &mut raster_cache,
+            7,
+            &input,
+            1.0,
+            TextRasterMode::default(),
+        )
+        .expect("grayscale glyph");
+        let colored = rasterize_text_pass_glyph_input(
+            &mut scale_context,
+            &mut raster_cache,
+            7,
+            &input,
+            1.0,
+            subpixel,
+        )
+        .expect("subpixel glyph");
+
+        assert_eq!(grayscale.content, SwashRasterContent::Mask);
+        assert_eq!(colored.content, SwashRasterContent::SubpixelMask);
+        assert_eq!(raster_cache.len(), 2);
+        assert_ne!(
+            text_raster_key_for_raster_input(&input, 1.0, TextRasterMode::default()),
+            text_raster_key_for_raster_input(&input, 1.0, subpixel)
+        );
+    }
+
+    fn atlas_test_key(glyph_id: u32) -> TextRasterKey {
+        text_raster_key_from_parts(1, 0, glyph_id, 16.0, 1.0, 0, TextRasterMode::default())
+    }
+
+    #[test]
+    fn atlas_allocator_grows_pages_then_evicts_least_recently_used() {
+        // Each 30x30 page fits exactly one 20x20 glyph.
+        let mut allocator = AtlasPageAllocator::new(30, 30, 2);
+
+        let first = allocator.allocate(atlas_test_key(1), 20, 20, 1).unwrap();
+        let second = allocator.allocate(atlas_test_key(2), 20, 20, 2).unwrap();
+        assert_eq!((first.slot.page, second.slot.page), (0, 1));
+        assert_eq!(first.evicted_page, None);
+        assert_eq!(second.evicted_page, None);
+
+        // Glyph 1 was used again more recently, so page 1 is the LRU page.
+        assert!(allocator.lookup(&atlas_test_key(1), 3).is_some());
+        let third = allocator.allocate(atlas_test_key(3), 20, 20, 4).unwrap();
+        assert_eq!(third.slot.page, 1);
+        assert_eq!(third.evicted_page, Some(1));
+        assert!(allocator.lookup(&atlas_test_key(2), 4).is_none());
+        assert!(allocator.lookup(&atlas_test_key(1), 4).is_some());
+
+        let metrics = allocator.metrics();
+        assert_eq!(metrics.pages, 2);
+        assert_eq!(metrics.resident_glyphs, 2);
+        assert_eq!(metrics.page_evictions, 1);
+        assert_eq!(metrics.evicted_glyphs, 1);
+        assert!((metrics.occupancy - 800.0 / 1800.0).abs() < 1e-6);
+    }
+
+    #[test]
+    fn atlas_allocator_never_evicts_pages_used_this_frame() {
+        let mut allocator = AtlasPageAllocator::new(30, 30, 1);
+        allocator.allocate(atlas_test_key(1), 20, 20, 5).unwrap();
+
+        assert_eq!(allocator.allocate(atlas_test_key(2), 20, 20, 5), None);
+        assert_eq!(allocator.allocate(atlas_test_key(3), 40, 40, 6), None);
+        assert!(allocator.lookup(&atlas_test_key(1), 6).is_some());
+        assert_eq!(allocator.metrics().page_evictions, 0);
+    }
+
     #[test]
     fn paint_input_is_separate_from_raster_key_fields() {
         let input = first_renderable_raster_input();
@@ -1839,9 +2388,9 @@ mod tests {
             fragment_index: 7,
         };
 
-        let before = text_raster_key_for_raster_input(&input, 2.0)
+        let before = text_raster_key_for_raster_input(&input, 2.0, TextRasterMode::default())
             .expect("neutral input should produce a raster key");
-        let after = text_raster_key_for_raster_input(&input, 2.0)
+        let after = text_raster_key_for_raster_input(&input, 2.0, TextRasterMode::default())
             .expect("paint changes are not part of raster key input");
 
         assert_ne!(first_paint, second_paint);
@@ -1875,7 +2424,7 @@ mod tests {
         assert_eq!(staged.glyph_index, 0);
         assert_eq!(
             staged.raster_key,
-            text_raster_key_for_raster_input(&raster, scale_factor)
+            text_raster_key_for_raster_input(&raster, scale_factor, TextRasterMode::default())
         );
         assert_eq!(staged.paint, paint);
         assert_eq!(staged.final_paint_pos, input.glyphs[0].final_paint_pos);
@@ -1901,4 +2450,16 @@ mod shader_validation_tests {
         .validate(&module)
         .expect("validate text.wgsl");
     }
+
+    #[test]
+    fn text_subpixel_wgsl_validates_with_dual_source_blending() {
+        let module = naga29::front::wgsl::parse_str(super::TEXT_SUBPIXEL_SHADER_SOURCE)
+            .expect("parse subpixel text shader");
+        naga29::valid::Validator::new(
+            naga29::valid::ValidationFlags::default(),
+            naga29::valid::Capabilities::DUAL_SOURCE_BLENDING,
+        )
+        .validate(&module)
+        .expect("validate subpixel text shader");
+    }
 }
@@ -9,8 +9,8 @@ use crate::view::frame_graph::{
 use crate::view::render_pass::draw_rect_pass::RenderTargetOut;
 use crate::view::render_pass::render_target::{
     GraphicsPassContext as RenderPassContext, ResolvedTextureRef,
-    logical_scissor_to_target_physical, render_target_sample_count, render_target_view,
-    resolve_texture_ref,
+    logical_scissor_to_target_physical, render_target_sample_count, render_target_scale_factor,
+    render_target_view, resolve_texture_ref,
 };
 use crate::view::render_pass::{GraphicsCtx, GraphicsPass};
 use crate::view::sampled_texture::SampledTextureUpload;
@@ -297,7 +297,6 @@ impl GraphicsPass for TextureCompositePass {
 
     fn prepare(&mut self, ctx: &mut PrepareContext<'_, '_>) {
         let surface_size = ctx.viewport.surface_size();
-        let scale = ctx.viewport.scale_factor();
         let resolved = resolve_composite_geometry(
             &self.params,
             self.input.source.handle(),
@@ -309,7 +308,6 @@ impl GraphicsPass for TextureCompositePass {
             self.output.render_target.handle(),
             ctx,
             surface_size,
-            scale,
         );
         let (target_w, target_h) = resolved.target_meta.physical_size;
         if target_w == 0 || target_h == 0 {
@@ -407,11 +405,51 @@ impl GraphicsPass for TextureCompositePass {
                 .uniform_buffer
                 .handle()
                 .and_then(|h| ctx.frame_resources().acquire_buffer(h));
-            let fallback_uniform_buffer;
-            let uniform_binding = if let Some(buffer) = acquired_uniform_buffer.as_ref() {
-                buffer.as_entire_binding()
+            let sampler = match self
+                .input
+                .sampled_source
+                .as_ref()
+                .map(|source| source.sampling)
+            {
+                Some(ImageSampling::Nearest) => &resources.nearest_sampler,
+                _ => &resources.linear_sampler,
+            };
+            let mask_or_source_view = mask_view.as_ref().unwrap_or(&source_view);
+            let create_bind_group = |uniform_binding: wgpu::BindingResource<'_>| {
+                device.create_bind_group(&wgpu::BindGroupDescriptor {
+                    label: Some("TextureComposite Bind Group"),
+                    layout: &resources.bind_group_layout,
+                    entries: &[
+                        wgpu::BindGroupEntry {
+                            binding: 0,
+                            resource: wgpu::BindingResource::TextureView(&source_view),
+                        },
+                        wgpu::BindGroupEntry {
+                            binding: 1,
+                            resource: wgpu::BindingResource::TextureView(mask_or_source_view),
+                        },
+                        wgpu::BindGroupEntry {
+                            binding: 2,
+                            resource: wgpu::BindingResource::Sampler(sampler),
+                        },
+                        wgpu::BindGroupEntry {
+                            binding: 3,
+                            resource: uniform_binding,
+                        },
+                    ],
+                })
+            };
+            let bind_group = if let Some(buffer) = acquired_uniform_buffer.as_ref() {
+                let key =
+                    super::BindGroupCacheKey::new("TextureComposite", &resources.bind_group_layout)
+                        .view(&source_view)
+                        .view(mask_or_source_view)
+                        .sampler(sampler)
+                        .buffer(buffer);
+                ctx.viewport()
+                    .get_or_create_bind_group(key, || create_bind_group(buffer.as_entire_binding()))
             } else {
-                fallback_uniform_buffer = super::create_transient_buffer(
+                let fallback_uniform_buffer = super::create_transient_buffer(
                     &device,
                     &wgpu::util::BufferInitDescriptor {
                         label: Some("TextureComposite Uniform (Fallback)"),
@@ -428,44 +466,9 @@ impl GraphicsPass for TextureCompositePass {
                         usage: wgpu::BufferUsages::UNIFORM,
                     },
                 );
-                fallback_uniform_buffer.as_entire_binding()
+                create_bind_group(fallback_uniform_buffer.as_entire_binding())
             };
 
-            let bind_group = device.create_bind_group(&wgpu::BindGroupDescriptor {
-                label: Some("TextureComposite Bind Group"),
-                layout: &resources.bind_group_layout,
-                entries: &[
-                    wgpu::BindGroupEntry {
-                        binding: 0,
-                        resource: wgpu::BindingResource::TextureView(&source_view),
-                    },
-                    wgpu::BindGroupEntry {
-                        binding: 1,
-                        resource: wgpu::BindingResource::TextureView(
-                            mask_view.as_ref().unwrap_or(&source_view),
-                        ),
-                    },
-                    wgpu::BindGroupEntry {
-                        binding: 2,
-                        resource: wgpu::BindingResource::Sampler(
-                            match self
-                                .input
-                                .sampled_source
-                                .as_ref()
-                                .map(|source| source.sampling)
-                            {
-                                Some(ImageSampling::Nearest) => &resources.nearest_sampler,
-                                _ => &resources.linear_sampler,
-                            },
-                        ),
-                    },
-                    wgpu::BindGroupEntry {
-                        binding: 3,
-                        resource: uniform_binding,
-                    },
-                ],
-            });
-
             #[cfg(test)]
             let force_transient_geometry_fallback = self.force_transient_geometry_fallback;
             #[cfg(not(test))]
@@ -494,7 +497,6 @@ impl GraphicsPass for TextureCompositePass {
                     (vb, ib)
                 } else {
                     let surface_size = ctx.viewport().surface_size();
-                    let scale = ctx.viewport().scale_factor();
                     let resolved = resolve_composite_geometry(
                         &self.params,
                         self.input.source.handle(),
@@ -506,7 +508,6 @@ impl GraphicsPass for TextureCompositePass {
                         self.output.render_target.handle(),
                         ctx.frame_resources(),
                         surface_size,
-                        scale,
                     );
                     fallback_vertex_buffer = super::create_transient_buffer(
                         &device,
@@ -535,9 +536,13 @@ impl GraphicsPass for TextureCompositePass {
                 None,
             );
             let (target_w, target_h) = target_meta.physical_size;
+            let target_scale = render_target_scale_factor(
+                ctx.frame_resources(),
+                self.output.render_target.handle(),
+            );
             let scissor_rect_physical = self.params.scissor_rect.and_then(|scissor_rect| {
                 logical_scissor_to_target_physical(
-                    ctx.viewport(),
+                    target_scale,
                     scissor_rect,
                     target_meta.global_origin,
                     (target_w, target_h),
@@ -589,8 +594,13 @@ fn resolve_composite_geometry(
     target_handle: Option<crate::view::frame_graph::texture_resource::TextureHandle>,
     ctx: &mut impl FrameResourceContext,
     surface_size: (u32, u32),
-    scale: f32,
 ) -> ResolvedCompositeGeometry {
+    // Source, mask and target may each be rasterized at a different density
+    // (a transformed layer composited into its parent, for example), so every
+    // logical rect is mapped with the scale of the texture it addresses.
+    let scale = render_target_scale_factor(ctx, target_handle);
+    let source_scale = render_target_scale_factor(ctx, source_handle);
+    let mask_scale = render_target_scale_factor(ctx, mask_handle.or(source_handle));
     let target_meta = resolve_target_meta(target_handle, ctx, surface_size, None);
     let source_meta = resolve_target_meta(source_handle, ctx, surface_size, sampled_extent);
     let mask_meta = resolve_target_meta(mask_handle, ctx, source_meta.physical_size, None)
@@ -607,7 +617,11 @@ fn resolve_composite_geometry(
     );
     let source_uv_bounds = resolve_uv_bounds(
         params.uv_bounds,
-        if sampled_extent.is_some() { 1.0 } else { scale },
+        if sampled_extent.is_some() {
+            1.0
+        } else {
+            source_scale
+        },
         source_meta.physical_size.0 as f32,
         source_meta.physical_size.1 as f32,
         source_meta.global_origin_f32(),
@@ -615,7 +629,7 @@ fn resolve_composite_geometry(
     );
     let mask_uv_bounds = resolve_uv_bounds(
         params.mask_uv_bounds.or(params.uv_bounds),
-        scale,
+        mask_scale,
         mask_meta.physical_size.0 as f32,
         mask_meta.physical_size.1 as f32,
         mask_meta.global_origin_f32(),
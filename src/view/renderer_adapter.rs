@@ -5,7 +5,9 @@ use rustc_hash::{FxHashMap, FxHashSet};
 
 use crate::style::style_props::{StylePropTrait, property_is_inherited, validate_style};
 use crate::style::{Color, Cursor, Length, ParsedValue, Position, PropertyId, TextWrap};
-use crate::style::{ComputedStyle, Style, StyleComputeContext, compute_style_with_context};
+use crate::style::{
+    ComputedStyle, MediaContext, Style, StyleComputeContext, compute_style_with_context,
+};
 use crate::ui::{FromPropValue, PropValue, RsxElementNode, RsxNode, RsxTextNode, use_context};
 use crate::view::base_component::text_area::TextAreaProjectionSegment;
 use crate::view::base_component::{
@@ -58,6 +60,7 @@ impl StyleCascadeContext {
                 viewport_height,
                 root_font_size: 16.0,
                 hovered: false,
+                media: MediaContext::current(),
             },
         );
         let root_font_size = parent.font_size;
@@ -85,6 +88,7 @@ impl StyleCascadeContext {
                 viewport_height: self.viewport_height,
                 root_font_size: self.root_font_size,
                 hovered: false,
+                media: MediaContext::current(),
             },
         );
         self.active_inherited_properties
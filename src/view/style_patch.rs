@@ -0,0 +1,34 @@
+//! Style changes queued against live elements between renders.
+//!
+//! [`crate::view::ElementHandle::set_style`] and
+//! [`crate::ui::EventTarget::set_style`] push onto a thread-local queue
+//! that the viewport drains with its `use_viewport` actions, so a handler
+//! can restyle a node without knowing which viewport owns it.
+
+use std::cell::RefCell;
+
+use crate::style::Style;
+use crate::view::fiber_work::{ApplyContext, update_element_style};
+use crate::view::node_arena::{NodeArena, NodeKey};
+
+type StyleUpdate = Box<dyn FnOnce(&mut Style)>;
+
+thread_local! {
+    static PENDING_STYLE_PATCHES: RefCell<Vec<(NodeKey, StyleUpdate)>> =
+        const { RefCell::new(Vec::new()) };
+}
+
+pub(crate) fn queue_style_update(node: NodeKey, update: impl FnOnce(&mut Style) + 'static) {
+    PENDING_STYLE_PATCHES.with(|queue| queue.borrow_mut().push((node, Box::new(update))));
+}
+
+/// Apply every queued update in order. Updates for nodes that are gone or
+/// are not `Element`s are dropped. Returns whether any node restyled.
+pub(crate) fn apply_pending_style_patches(arena: &mut NodeArena, ctx: ApplyContext<'_>) -> bool {
+    let patches = PENDING_STYLE_PATCHES.with(|queue| std::mem::take(&mut *queue.borrow_mut()));
+    let mut changed = false;
+    for (node, update) in patches {
+        changed |= update_element_style(arena, ctx, node, update);
+    }
+    changed
+}
@@ -4,20 +4,25 @@
 
 use crate::style::style_props::{AllStyleSet, NoStylePropSchema, StylePropTrait, TextStyleSet};
 use crate::style::{
-    Align, Animator, BorderRadius, BoxShadow, ColorLike, CrossSize, Cursor, Flex, FontFamily,
-    FontSize, FontWeight, IntoAnimationStyle, Layout, Length, Opacity, Padding, Position,
-    ScrollDirection, SelectionStyle, Style, TextAlign, TextWrap, Transform, TransformOrigin,
+    Align, Animator, AppRegion, BorderRadius, BoxShadow, ColorLike, ComposeStyle, CrossSize,
+    Cursor, Flex, FontFamily, FontSize, FontWeight, HitShape, IntoAnimationStyle, IntoStyleLayer,
+    Layout, Length, Margin, MediaRule, Opacity, Padding, PointerEvents, Position, ScrollDirection,
+    ScrollbarStyle, SelectionStyle, Style, TextAlign, TextWrap, Transform, TransformOrigin,
     Transitions, VerticalAlign,
 };
 use crate::ui::RsxNode;
 use crate::ui::{
     BlurHandlerProp, ClickHandlerProp, DragEndHandlerProp, DragLeaveHandlerProp,
     DragOverHandlerProp, DragStartHandlerProp, DropHandlerProp, FocusHandlerProp, FromPropValue,
-    IntoPropValue, KeyDownHandlerProp, KeyUpHandlerProp, PointerDownHandlerProp,
-    PointerEnterHandlerProp, PointerLeaveHandlerProp, PointerMoveHandlerProp, PointerUpHandlerProp,
-    RsxComponent, SharedPropValue, TextAreaFocusHandlerProp, TextAreaRenderHandlerProp,
-    TextChangeHandlerProp, props,
+    IntoPropValue, KeyDownHandlerProp, KeyUpHandlerProp, LongPressHandlerProp, PasteHandlerProp,
+    PointerDownHandlerProp, PointerEnterHandlerProp, PointerLeaveHandlerProp,
+    PointerMoveHandlerProp, PointerUpHandlerProp, ReachEndHandlerProp, ResizeHandlerProp,
+    RsxComponent, ScrollHandlerProp, SharedPropValue, TextAreaFocusHandlerProp,
+    TextAreaRenderHandlerProp, TextChangeHandlerProp, TransitionGroupEndHandlerProp,
+    TransitionHandlerProp, VisibilityChangeHandlerProp, WheelHandlerProp, props,
 };
+use rustc_hash::FxHashMap;
+use std::any::TypeId;
 use std::path::PathBuf;
 use std::rc::Rc;
 use std::sync::Arc;
@@ -78,6 +83,22 @@ pub enum SvgSource {
 pub struct ElementPropSchema {
     pub anchor: Option<String>,
     pub debug_type: Option<crate::view::debug::DebugType>,
+    /// Identifier for [`crate::view::ElementQuery::test_id`]; no effect on
+    /// rendering.
+    pub test_id: Option<String>,
+    /// Accessibility semantics, read back through [`crate::view::ElementQuery`]
+    /// and [`crate::ui::EventTarget::role`]; no effect on rendering.
+    pub role: Option<crate::ui::AriaRole>,
+    pub aria_label: Option<String>,
+    pub aria_checked: Option<bool>,
+    pub aria_disabled: Option<bool>,
+    pub aria_expanded: Option<bool>,
+    pub aria_selected: Option<bool>,
+    /// Position among siblings in reading order, for when visual order
+    /// (e.g. absolute positioning) differs from logical order. Lower reads
+    /// first; unset counts as 0 and ties keep document order. See
+    /// [`crate::view::ElementQuery::in_reading_order`].
+    pub reading_order: Option<i64>,
     pub style: Option<ElementStylePropSchema>,
     pub on_pointer_down: Option<PointerDownHandlerProp>,
     pub on_pointer_up: Option<PointerUpHandlerProp>,
@@ -85,6 +106,16 @@ pub struct ElementPropSchema {
     pub on_pointer_enter: Option<PointerEnterHandlerProp>,
     pub on_pointer_leave: Option<PointerLeaveHandlerProp>,
     pub on_click: Option<ClickHandlerProp>,
+    pub on_long_press: Option<LongPressHandlerProp>,
+    /// How long, in milliseconds, a press must be held before
+    /// `on_long_press` fires. Defaults to 500.
+    pub long_press_duration: Option<f32>,
+    /// Enter/exit delays and safe triangle applied before this element
+    /// gains or loses hover.
+    pub hover_intent: Option<crate::view::viewport::HoverIntent>,
+    /// Arrow keys move focus to the nearest interactive descendant in the
+    /// pressed direction.
+    pub spatial_navigation: Option<bool>,
     pub on_drag_start: Option<DragStartHandlerProp>,
     pub on_drag_over: Option<DragOverHandlerProp>,
     pub on_drag_leave: Option<DragLeaveHandlerProp>,
@@ -94,6 +125,24 @@ pub struct ElementPropSchema {
     pub on_key_up: Option<KeyUpHandlerProp>,
     pub on_focus: Option<FocusHandlerProp>,
     pub on_blur: Option<BlurHandlerProp>,
+    pub on_wheel: Option<WheelHandlerProp>,
+    pub on_scroll: Option<ScrollHandlerProp>,
+    pub on_reach_end: Option<ReachEndHandlerProp>,
+    /// Distance from the end of the scroll range, in logical pixels, at
+    /// which `on_reach_end` fires.
+    pub reach_end_threshold: Option<f32>,
+    pub on_resize: Option<ResizeHandlerProp>,
+    pub on_visibility_change: Option<VisibilityChangeHandlerProp>,
+    /// Visible fraction of the border box, from 0 to 1, at which
+    /// `on_visibility_change` flips to visible.
+    pub visibility_threshold: Option<f32>,
+    /// Staggers the transitions and animations started by this element's
+    /// children.
+    pub transition_group: Option<crate::transition::TransitionGroup>,
+    pub on_transition_group_end: Option<TransitionGroupEndHandlerProp>,
+    pub on_transition_start: Option<TransitionHandlerProp>,
+    pub on_transition_end: Option<TransitionHandlerProp>,
+    pub on_transition_cancel: Option<TransitionHandlerProp>,
 }
 
 #[derive(Clone)]
@@ -112,13 +161,17 @@ pub struct ElementStylePropSchema {
     pub flex: Option<Flex>,
     pub gap: Option<Length>,
     pub scroll_direction: Option<ScrollDirection>,
+    pub scrollbar: Option<ScrollbarStyle>,
     pub cursor: Option<Cursor>,
+    pub app_region: Option<AppRegion>,
+    pub pointer_events: Option<PointerEvents>,
+    pub hit_shape: Option<HitShape>,
     pub color: Option<Box<dyn ColorLike>>,
     pub border: Option<crate::style::Border>,
     pub background: Option<crate::style::Background>,
     pub background_color: Option<Box<dyn ColorLike>>,
     pub background_image: Option<crate::style::Gradient>,
-    pub border_image: Option<crate::style::Gradient>,
+    pub border_image: Option<crate::style::BorderImage>,
     pub font: Option<FontFamily>,
     pub font_size: Option<FontSize>,
     pub font_weight: Option<FontWeight>,
@@ -127,10 +180,26 @@ pub struct ElementStylePropSchema {
     pub vertical_align: Option<VerticalAlign>,
     pub border_radius: Option<BorderRadius>,
     pub hover: Option<HoverElementStylePropSchema>,
+    /// Conditional blocks, written `media(min_width: 800.0): { ... }`; see
+    /// [`crate::style::MediaQuery`].
+    pub media: Option<Vec<MediaRule<HoverElementStylePropSchema>>>,
     pub selection: Option<SelectionStylePropSchema>,
     pub opacity: Option<Opacity>,
     pub box_shadow: Option<Vec<BoxShadow>>,
+    pub elevation: Option<u8>,
+    pub mix_blend_mode: Option<crate::style::MixBlendMode>,
+    pub filter: Option<Vec<crate::style::Filter>>,
+    pub mask: Option<crate::style::Mask>,
     pub padding: Option<Padding>,
+    pub padding_top: Option<Length>,
+    pub padding_right: Option<Length>,
+    pub padding_bottom: Option<Length>,
+    pub padding_left: Option<Length>,
+    pub margin: Option<Margin>,
+    pub margin_top: Option<Length>,
+    pub margin_right: Option<Length>,
+    pub margin_bottom: Option<Length>,
+    pub margin_left: Option<Length>,
     pub transform: Option<Transform>,
     pub transform_origin: Option<TransformOrigin>,
     pub transition: Option<Transitions>,
@@ -153,13 +222,17 @@ pub struct HoverElementStylePropSchema {
     pub flex: Option<Flex>,
     pub gap: Option<Length>,
     pub scroll_direction: Option<ScrollDirection>,
+    pub scrollbar: Option<ScrollbarStyle>,
     pub cursor: Option<Cursor>,
+    pub app_region: Option<AppRegion>,
+    pub pointer_events: Option<PointerEvents>,
+    pub hit_shape: Option<HitShape>,
     pub color: Option<Box<dyn ColorLike>>,
     pub border: Option<crate::style::Border>,
     pub background: Option<crate::style::Background>,
     pub background_color: Option<Box<dyn ColorLike>>,
     pub background_image: Option<crate::style::Gradient>,
-    pub border_image: Option<crate::style::Gradient>,
+    pub border_image: Option<crate::style::BorderImage>,
     pub font: Option<FontFamily>,
     pub font_size: Option<FontSize>,
     pub font_weight: Option<FontWeight>,
@@ -170,7 +243,20 @@ pub struct HoverElementStylePropSchema {
     pub selection: Option<SelectionStylePropSchema>,
     pub opacity: Option<Opacity>,
     pub box_shadow: Option<Vec<BoxShadow>>,
+    pub elevation: Option<u8>,
+    pub mix_blend_mode: Option<crate::style::MixBlendMode>,
+    pub filter: Option<Vec<crate::style::Filter>>,
+    pub mask: Option<crate::style::Mask>,
     pub padding: Option<Padding>,
+    pub padding_top: Option<Length>,
+    pub padding_right: Option<Length>,
+    pub padding_bottom: Option<Length>,
+    pub padding_left: Option<Length>,
+    pub margin: Option<Margin>,
+    pub margin_top: Option<Length>,
+    pub margin_right: Option<Length>,
+    pub margin_bottom: Option<Length>,
+    pub margin_left: Option<Length>,
     pub transform: Option<Transform>,
     pub transform_origin: Option<TransformOrigin>,
     pub transition: Option<Transitions>,
@@ -227,6 +313,11 @@ pub struct BorderStylePropSchema {
 
 #[props]
 pub struct TextPropSchema {
+    /// Identifier for [`crate::view::ElementQuery::test_id`]; no effect on
+    /// rendering.
+    pub test_id: Option<String>,
+    /// See [`ElementPropSchema::reading_order`].
+    pub reading_order: Option<i64>,
     pub style: Option<TextStylePropSchema>,
     pub align: Option<TextAlign>,
     pub font_size: Option<FontSize>,
@@ -250,12 +341,15 @@ pub struct TextAreaPropSchema {
     pub on_render: Option<TextAreaRenderHandlerProp>,
     pub on_blur: Option<BlurHandlerProp>,
     pub on_change: Option<TextChangeHandlerProp>,
+    pub on_key_down: Option<KeyDownHandlerProp>,
+    pub on_paste: Option<PasteHandlerProp>,
     pub placeholder: Option<String>,
     pub font_size: Option<FontSize>,
     pub font: Option<String>,
     pub multiline: Option<bool>,
     pub auto_wrap: Option<bool>,
     pub read_only: Option<bool>,
+    pub commit_preedit_on_blur: Option<bool>,
     pub max_length: Option<i64>,
 }
 
@@ -292,6 +386,30 @@ impl RsxComponent<ElementPropSchema> for Element {
                 crate::ui::IntoPropValue::into_prop_value(debug_type),
             );
         }
+        if let Some(test_id) = props.test_id {
+            node = node.with_prop("test_id", test_id);
+        }
+        if let Some(role) = props.role {
+            node = node.with_prop("role", crate::ui::IntoPropValue::into_prop_value(role));
+        }
+        if let Some(label) = props.aria_label {
+            node = node.with_prop("aria_label", label);
+        }
+        if let Some(checked) = props.aria_checked {
+            node = node.with_prop("aria_checked", checked);
+        }
+        if let Some(disabled) = props.aria_disabled {
+            node = node.with_prop("aria_disabled", disabled);
+        }
+        if let Some(expanded) = props.aria_expanded {
+            node = node.with_prop("aria_expanded", expanded);
+        }
+        if let Some(selected) = props.aria_selected {
+            node = node.with_prop("aria_selected", selected);
+        }
+        if let Some(order) = props.reading_order {
+            node = node.with_prop("reading_order", order);
+        }
         if let Some(style) = props.style {
             node = node.with_prop("style", style);
         }
@@ -313,6 +431,18 @@ impl RsxComponent<ElementPropSchema> for Element {
         if let Some(handler) = props.on_click {
             node = node.with_prop("on_click", handler);
         }
+        if let Some(handler) = props.on_long_press {
+            node = node.with_prop("on_long_press", handler);
+        }
+        if let Some(duration) = props.long_press_duration {
+            node = node.with_prop("long_press_duration", duration);
+        }
+        if let Some(intent) = props.hover_intent {
+            node = node.with_prop("hover_intent", intent);
+        }
+        if let Some(enabled) = props.spatial_navigation {
+            node = node.with_prop("spatial_navigation", enabled);
+        }
         if let Some(handler) = props.on_drag_start {
             node = node.with_prop("on_drag_start", handler);
         }
@@ -340,6 +470,42 @@ impl RsxComponent<ElementPropSchema> for Element {
         if let Some(handler) = props.on_blur {
             node = node.with_prop("on_blur", handler);
         }
+        if let Some(handler) = props.on_wheel {
+            node = node.with_prop("on_wheel", handler);
+        }
+        if let Some(handler) = props.on_scroll {
+            node = node.with_prop("on_scroll", handler);
+        }
+        if let Some(handler) = props.on_reach_end {
+            node = node.with_prop("on_reach_end", handler);
+        }
+        if let Some(threshold) = props.reach_end_threshold {
+            node = node.with_prop("reach_end_threshold", threshold);
+        }
+        if let Some(handler) = props.on_resize {
+            node = node.with_prop("on_resize", handler);
+        }
+        if let Some(handler) = props.on_visibility_change {
+            node = node.with_prop("on_visibility_change", handler);
+        }
+        if let Some(threshold) = props.visibility_threshold {
+            node = node.with_prop("visibility_threshold", threshold);
+        }
+        if let Some(group) = props.transition_group {
+            node = node.with_prop("transition_group", group);
+        }
+        if let Some(handler) = props.on_transition_group_end {
+            node = node.with_prop("on_transition_group_end", handler);
+        }
+        if let Some(handler) = props.on_transition_start {
+            node = node.with_prop("on_transition_start", handler);
+        }
+        if let Some(handler) = props.on_transition_end {
+            node = node.with_prop("on_transition_end", handler);
+        }
+        if let Some(handler) = props.on_transition_cancel {
+            node = node.with_prop("on_transition_cancel", handler);
+        }
         for child in children {
             node = node.with_child(child);
         }
@@ -350,6 +516,12 @@ impl RsxComponent<ElementPropSchema> for Element {
 impl RsxComponent<TextPropSchema> for Text {
     fn render(props: TextPropSchema, children: Vec<RsxNode>) -> RsxNode {
         let mut node = RsxNode::tagged("Text", crate::ui::RsxTagDescriptor::for_tag::<Text>());
+        if let Some(test_id) = props.test_id {
+            node = node.with_prop("test_id", test_id);
+        }
+        if let Some(order) = props.reading_order {
+            node = node.with_prop("reading_order", order);
+        }
         if let Some(style) = props.style {
             node = node.with_prop("style", style);
         }
@@ -444,6 +616,12 @@ impl RsxComponent<TextAreaPropSchema> for TextArea {
         if let Some(handler) = props.on_change {
             node = node.with_prop("on_change", handler);
         }
+        if let Some(handler) = props.on_key_down {
+            node = node.with_prop("on_key_down", handler);
+        }
+        if let Some(handler) = props.on_paste {
+            node = node.with_prop("on_paste", handler);
+        }
         if let Some(placeholder) = props.placeholder
             && !placeholder.is_empty()
         {
@@ -468,6 +646,9 @@ impl RsxComponent<TextAreaPropSchema> for TextArea {
         if let Some(read_only) = props.read_only {
             node = node.with_prop("read_only", read_only);
         }
+        if let Some(commit) = props.commit_preedit_on_blur {
+            node = node.with_prop("commit_preedit_on_blur", commit);
+        }
         if let Some(max_length) = props.max_length
             && max_length != 0
         {
@@ -713,6 +894,22 @@ mod v2_poc_tests {
         }
     }
 
+    #[test]
+    fn rsx_media_block_object() {
+        let node = rsx! {
+            <Element style={{
+                width: "100%",
+                media(min_width: 800.0, theme: crate::app::WindowTheme::Dark): {
+                    width: "50%",
+                },
+            }} />
+        };
+        match node {
+            RsxNode::Element(_) => {}
+            _ => panic!("expected element"),
+        }
+    }
+
     // ---------- #[component] + rsx end-to-end ----------
 
     #[crate::ui::component]
@@ -1237,17 +1434,28 @@ struct ElementStyleFields<'a> {
     flex: Option<Flex>,
     gap: Option<Length>,
     scroll_direction: Option<ScrollDirection>,
+    scrollbar: Option<ScrollbarStyle>,
+    app_region: Option<AppRegion>,
+    pointer_events: Option<PointerEvents>,
+    hit_shape: &'a Option<HitShape>,
     border: &'a Option<crate::style::Border>,
     background: &'a Option<crate::style::Background>,
     background_color: &'a Option<Box<dyn ColorLike>>,
     background_image: &'a Option<crate::style::Gradient>,
-    border_image: &'a Option<crate::style::Gradient>,
+    border_image: &'a Option<crate::style::BorderImage>,
     line_height: Option<f64>,
     vertical_align: Option<VerticalAlign>,
     border_radius: Option<BorderRadius>,
     selection: &'a Option<SelectionStylePropSchema>,
     box_shadow: &'a Option<Vec<BoxShadow>>,
+    elevation: Option<u8>,
+    mix_blend_mode: Option<crate::style::MixBlendMode>,
+    filter: &'a Option<Vec<crate::style::Filter>>,
+    mask: &'a Option<crate::style::Mask>,
     padding: Option<Padding>,
+    padding_sides: [Option<Length>; 4],
+    margin: Option<Margin>,
+    margin_sides: [Option<Length>; 4],
     transform: &'a Option<Transform>,
     transform_origin: Option<TransformOrigin>,
     animator: &'a Option<Animator>,
@@ -1272,6 +1480,10 @@ impl ElementStylePropFields for ElementStylePropSchema {
             flex: self.flex,
             gap: self.gap,
             scroll_direction: self.scroll_direction,
+            scrollbar: self.scrollbar,
+            app_region: self.app_region,
+            pointer_events: self.pointer_events,
+            hit_shape: &self.hit_shape,
             border: &self.border,
             background: &self.background,
             background_color: &self.background_color,
@@ -1282,7 +1494,24 @@ impl ElementStylePropFields for ElementStylePropSchema {
             border_radius: self.border_radius,
             selection: &self.selection,
             box_shadow: &self.box_shadow,
+            elevation: self.elevation,
+            mix_blend_mode: self.mix_blend_mode,
+            filter: &self.filter,
+            mask: &self.mask,
             padding: self.padding,
+            padding_sides: [
+                self.padding_top,
+                self.padding_right,
+                self.padding_bottom,
+                self.padding_left,
+            ],
+            margin: self.margin,
+            margin_sides: [
+                self.margin_top,
+                self.margin_right,
+                self.margin_bottom,
+                self.margin_left,
+            ],
             transform: &self.transform,
             transform_origin: self.transform_origin,
             animator: &self.animator,
@@ -1305,6 +1534,10 @@ impl ElementStylePropFields for HoverElementStylePropSchema {
             flex: self.flex,
             gap: self.gap,
             scroll_direction: self.scroll_direction,
+            scrollbar: self.scrollbar,
+            app_region: self.app_region,
+            pointer_events: self.pointer_events,
+            hit_shape: &self.hit_shape,
             border: &self.border,
             background: &self.background,
             background_color: &self.background_color,
@@ -1315,7 +1548,24 @@ impl ElementStylePropFields for HoverElementStylePropSchema {
             border_radius: self.border_radius,
             selection: &self.selection,
             box_shadow: &self.box_shadow,
+            elevation: self.elevation,
+            mix_blend_mode: self.mix_blend_mode,
+            filter: &self.filter,
+            mask: &self.mask,
             padding: self.padding,
+            padding_sides: [
+                self.padding_top,
+                self.padding_right,
+                self.padding_bottom,
+                self.padding_left,
+            ],
+            margin: self.margin,
+            margin_sides: [
+                self.margin_top,
+                self.margin_right,
+                self.margin_bottom,
+                self.margin_left,
+            ],
             transform: &self.transform,
             transform_origin: self.transform_origin,
             animator: &self.animator,
@@ -1323,6 +1573,34 @@ impl ElementStylePropFields for HoverElementStylePropSchema {
     }
 }
 
+const PADDING_SIDE_PROPERTIES: [crate::style::PropertyId; 4] = [
+    crate::style::PropertyId::PaddingTop,
+    crate::style::PropertyId::PaddingRight,
+    crate::style::PropertyId::PaddingBottom,
+    crate::style::PropertyId::PaddingLeft,
+];
+
+const MARGIN_SIDE_PROPERTIES: [crate::style::PropertyId; 4] = [
+    crate::style::PropertyId::MarginTop,
+    crate::style::PropertyId::MarginRight,
+    crate::style::PropertyId::MarginBottom,
+    crate::style::PropertyId::MarginLeft,
+];
+
+/// Per-side keys such as `padding_top` override the matching side of their
+/// shorthand, so they are applied after it.
+fn apply_side_lengths(
+    style: &mut Style,
+    properties: [crate::style::PropertyId; 4],
+    sides: [Option<Length>; 4],
+) {
+    for (property, side) in properties.into_iter().zip(sides) {
+        if let Some(length) = side {
+            crate::style::insert_style_length(style, property, length);
+        }
+    }
+}
+
 fn apply_shared_size_style_fields(style: &mut Style, fields: &SharedStyleFields<'_>) {
     if let Some(width) = fields.width {
         crate::style::insert_style_length(style, crate::style::PropertyId::Width, width);
@@ -1450,7 +1728,31 @@ where
             crate::style::ParsedValue::ScrollDirection(scroll_direction),
         );
     }
+    if let Some(scrollbar) = fields.scrollbar {
+        style.insert(
+            crate::style::PropertyId::Scrollbar,
+            crate::style::ParsedValue::Scrollbar(scrollbar),
+        );
+    }
     apply_shared_cursor_style_field(style, &shared);
+    if let Some(app_region) = fields.app_region {
+        style.insert(
+            crate::style::PropertyId::AppRegion,
+            crate::style::ParsedValue::AppRegion(app_region),
+        );
+    }
+    if let Some(pointer_events) = fields.pointer_events {
+        style.insert(
+            crate::style::PropertyId::PointerEvents,
+            crate::style::ParsedValue::PointerEvents(pointer_events),
+        );
+    }
+    if let Some(hit_shape) = fields.hit_shape {
+        style.insert(
+            crate::style::PropertyId::HitShape,
+            crate::style::ParsedValue::HitShape(hit_shape.clone()),
+        );
+    }
     apply_shared_color_style_field(style, &shared);
     apply_background(style, fields.background.as_ref());
     apply_box_color(
@@ -1464,11 +1766,8 @@ where
             crate::style::ParsedValue::Gradient(gradient.clone()),
         );
     }
-    if let Some(gradient) = fields.border_image {
-        style.insert(
-            crate::style::PropertyId::BorderImage,
-            crate::style::ParsedValue::Gradient(gradient.clone()),
-        );
+    if let Some(image) = fields.border_image {
+        style.set_border_image(image.clone());
     }
     if let Some(border) = fields.border {
         style.set_border(border.clone());
@@ -1495,9 +1794,26 @@ where
             crate::style::ParsedValue::BoxShadow(box_shadow.clone()),
         );
     }
+    if let Some(level) = fields.elevation {
+        style.set_elevation(level);
+    }
+    if let Some(mode) = fields.mix_blend_mode {
+        style.set_mix_blend_mode(mode);
+    }
+    if let Some(filter) = fields.filter {
+        style.set_filter(filter.clone());
+    }
+    if let Some(mask) = fields.mask {
+        style.set_mask(mask.clone());
+    }
     if let Some(padding) = fields.padding {
         style.set_padding(padding);
     }
+    apply_side_lengths(style, PADDING_SIDE_PROPERTIES, fields.padding_sides);
+    if let Some(margin) = fields.margin {
+        style.set_margin(margin);
+    }
+    apply_side_lengths(style, MARGIN_SIDE_PROPERTIES, fields.margin_sides);
     if let Some(transform) = fields.transform {
         style.set_transform(transform.clone());
     }
@@ -1537,6 +1853,9 @@ impl ElementStylePropSchema {
         if let Some(hover) = &self.hover {
             style.set_hover(hover.to_style());
         }
+        for rule in self.media.iter().flatten() {
+            style.push_media(rule.query, rule.style.to_style());
+        }
         style
     }
 }
@@ -1549,6 +1868,255 @@ impl StylePropTrait for ElementStylePropSchema {
     }
 }
 
+/// Stack the fields both element style schemas share. Shorthands drop the
+/// longhands they cover from `$target` first, since lowering applies
+/// longhands after shorthands.
+macro_rules! extend_element_style_fields {
+    (text: $target:ident, $later:ident) => {
+        extend_element_style_fields!(
+            $target,
+            $later,
+            [
+                width, height, color, font, font_size, font_weight, text_wrap, cursor, opacity,
+                transform, transform_origin, transition,
+            ]
+        );
+    };
+    ($target:ident, $later:ident) => {
+        if $later.padding.is_some() {
+            $target.padding_top = None;
+            $target.padding_right = None;
+            $target.padding_bottom = None;
+            $target.padding_left = None;
+        }
+        if $later.margin.is_some() {
+            $target.margin_top = None;
+            $target.margin_right = None;
+            $target.margin_bottom = None;
+            $target.margin_left = None;
+        }
+        if $later.background.is_some() {
+            $target.background_color = None;
+            $target.background_image = None;
+        }
+        $target.selection = match ($target.selection.take(), $later.selection) {
+            (Some(earlier), Some(later)) => Some(SelectionStylePropSchema {
+                background: later.background.or(earlier.background),
+            }),
+            (earlier, later) => later.or(earlier),
+        };
+        extend_element_style_fields!(
+            $target,
+            $later,
+            [
+                position, width, height, min_width, max_width, min_height, max_height, layout,
+                cross_size, align, flex, gap, scroll_direction, scrollbar, cursor, app_region,
+                pointer_events, hit_shape, color, border, background, background_color,
+                background_image, border_image, font, font_size, font_weight, text_wrap,
+                line_height, vertical_align, border_radius, opacity, box_shadow, elevation,
+                mix_blend_mode, filter, mask, padding, padding_top, padding_right,
+                padding_bottom, padding_left, margin, margin_top, margin_right, margin_bottom,
+                margin_left, transform, transform_origin, transition, animator,
+            ]
+        );
+    };
+    ($target:ident, $later:ident, [$($field:ident),* $(,)?]) => {
+        $(
+            if $later.$field.is_some() {
+                $target.$field = $later.$field;
+            }
+        )*
+    };
+}
+
+impl ComposeStyle for HoverElementStylePropSchema {
+    fn extend(&mut self, later: Self) {
+        extend_element_style_fields!(self, later);
+    }
+}
+
+impl ComposeStyle for ElementStylePropSchema {
+    /// Field-wise stacking: `hover` merges recursively and `media` rules
+    /// are appended.
+    fn extend(&mut self, later: Self) {
+        self.hover = match (self.hover.take(), later.hover) {
+            (Some(mut earlier), Some(later)) => {
+                earlier.extend(later);
+                Some(earlier)
+            }
+            (earlier, later) => later.or(earlier),
+        };
+        if let Some(media) = later.media {
+            self.media.get_or_insert_with(Vec::new).extend(media);
+        }
+        extend_element_style_fields!(self, later);
+    }
+}
+
+impl ComposeStyle for HoverTextStylePropSchema {
+    fn extend(&mut self, later: Self) {
+        extend_element_style_fields!(text: self, later);
+    }
+}
+
+impl ComposeStyle for TextStylePropSchema {
+    /// Field-wise stacking; `hover` merges recursively.
+    fn extend(&mut self, later: Self) {
+        self.hover = match (self.hover.take(), later.hover) {
+            (Some(mut earlier), Some(later)) => {
+                earlier.extend(later);
+                Some(earlier)
+            }
+            (earlier, later) => later.or(earlier),
+        };
+        extend_element_style_fields!(text: self, later);
+    }
+}
+
+impl IntoStyleLayer for ElementStylePropSchema {
+    type Style = Self;
+
+    fn into_style_layer(self) -> Option<Self> {
+        Some(self)
+    }
+}
+
+impl IntoStyleLayer for Option<ElementStylePropSchema> {
+    type Style = ElementStylePropSchema;
+
+    fn into_style_layer(self) -> Option<ElementStylePropSchema> {
+        self
+    }
+}
+
+/// `node` with `style` stacked over the `style` prop of its root host tag,
+/// for components forwarding a caller's `style` to the element they render.
+/// Roots that are not an [`Element`], [`TextArea`], [`Image`] or [`Svg`]
+/// (text, fragments, nested components) are wrapped in an `Element`
+/// carrying `style`.
+pub fn with_root_style(mut node: RsxNode, style: Option<ElementStylePropSchema>) -> RsxNode {
+    let Some(style) = style else {
+        return node;
+    };
+    if !accepts_element_style(&node) {
+        return wrap_in_styled_element(node, style);
+    }
+    if let RsxNode::Element(element) = &mut node {
+        let props = Rc::make_mut(&mut Rc::make_mut(element).props);
+        match props.iter_mut().find(|(key, _)| *key == "style") {
+            Some((_, value)) => {
+                let mut base =
+                    ElementStylePropSchema::from_prop_value(value.clone()).unwrap_or_default();
+                base.extend(style);
+                *value = base.into_prop_value();
+            }
+            None => props.push(("style", style.into_prop_value())),
+        }
+    }
+    node
+}
+
+/// [`with_root_style`] with the [`StyleClasses`] named by `class` stacked
+/// beneath `style`, for components taking both a `class` and a `style`
+/// prop.
+pub fn with_root_class_and_style(
+    node: RsxNode,
+    class: Option<&str>,
+    style: Option<ElementStylePropSchema>,
+) -> RsxNode {
+    let style = match (class.and_then(StyleClasses::resolve), style) {
+        (Some(mut class_style), Some(style)) => {
+            class_style.extend(style);
+            Some(class_style)
+        }
+        (class_style, style) => style.or(class_style),
+    };
+    with_root_style(node, style)
+}
+
+/// A component's `style` and `class` props, bundled so they reach the view
+/// rendering its root as a single prop.
+#[derive(Clone, Default)]
+pub struct RootStyleProps {
+    pub style: Option<ElementStylePropSchema>,
+    pub class: Option<String>,
+}
+
+impl RootStyleProps {
+    pub fn new(style: Option<ElementStylePropSchema>, class: Option<String>) -> Self {
+        Self { style, class }
+    }
+
+    /// [`with_root_class_and_style`] with these props.
+    pub fn apply(self, node: RsxNode) -> RsxNode {
+        with_root_class_and_style(node, self.class.as_deref(), self.style)
+    }
+}
+
+fn accepts_element_style(node: &RsxNode) -> bool {
+    let RsxNode::Element(element) = node else {
+        return false;
+    };
+    element.tag_descriptor.as_ref().is_some_and(|tag| {
+        [
+            TypeId::of::<Element>(),
+            TypeId::of::<TextArea>(),
+            TypeId::of::<Image>(),
+            TypeId::of::<Svg>(),
+        ]
+        .contains(&tag.type_id)
+    })
+}
+
+fn wrap_in_styled_element(node: RsxNode, style: ElementStylePropSchema) -> RsxNode {
+    let props = ElementPropSchema {
+        style: Some(style),
+        ..Default::default()
+    };
+    crate::ui::create_element::<Element>(props, vec![node], None)
+}
+
+/// Named element styles that a component's `class` prop refers to,
+/// published to a subtree with `<Provider<StyleClasses> value={...}>`.
+///
+/// ```ignore
+/// let dense = ElementStylePropSchema {
+///     padding: Some(Padding::uniform(Length::px(2.0))),
+///     ..Default::default()
+/// };
+/// let classes = StyleClasses::new().with_class("dense", dense);
+/// ```
+#[derive(Clone, Default)]
+pub struct StyleClasses(Rc<FxHashMap<String, ElementStylePropSchema>>);
+
+impl StyleClasses {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Register (or replace) the class `name`.
+    pub fn with_class(mut self, name: impl Into<String>, style: ElementStylePropSchema) -> Self {
+        Rc::make_mut(&mut self.0).insert(name.into(), style);
+        self
+    }
+
+    pub fn get(&self, name: &str) -> Option<&ElementStylePropSchema> {
+        self.0.get(name)
+    }
+
+    /// Styles of the whitespace-separated class names in `class`, looked up
+    /// in the innermost provided `StyleClasses` and stacked in order. Names
+    /// that are not registered are skipped.
+    pub fn resolve(class: &str) -> Option<ElementStylePropSchema> {
+        let classes = crate::ui::use_context::<StyleClasses>()?;
+        let layers = class
+            .split_whitespace()
+            .filter_map(|name| classes.get(name).cloned())
+            .collect::<Vec<_>>();
+        (!layers.is_empty()).then(|| ElementStylePropSchema::merge(&layers))
+    }
+}
+
 impl HoverTextStylePropSchema {
     pub fn to_style(&self) -> Style {
         let mut style = Style::new();
@@ -1827,6 +2395,119 @@ mod style_lowering_tests {
         ));
     }
 
+    /// Shorthand ids the schema writes through their per-side longhands.
+    const LONGHAND_ONLY_PROPERTIES: [PropertyId; 3] = [
+        PropertyId::BorderRadius,
+        PropertyId::BorderWidth,
+        PropertyId::BorderColor,
+    ];
+
+    #[test]
+    fn element_style_schema_covers_every_registered_property() {
+        let gradient = || crate::style::Gradient::rainbow(crate::style::SideOrCorner::Right);
+        let schema = ElementStylePropSchema {
+            position: Some(Position::absolute()),
+            min_width: Some(Length::px(1.0)),
+            max_width: Some(Length::px(2.0)),
+            min_height: Some(Length::px(3.0)),
+            max_height: Some(Length::px(4.0)),
+            cross_size: Some(CrossSize::Stretch),
+            align: Some(Align::Center),
+            flex: Some(Flex::new().grow(1.0)),
+            gap: Some(Length::px(5.0)),
+            scroll_direction: Some(ScrollDirection::Vertical),
+            scrollbar: Some(ScrollbarStyle::new()),
+            app_region: Some(AppRegion::Drag),
+            pointer_events: Some(PointerEvents::None),
+            hit_shape: Some(HitShape::Ellipse),
+            border: Some(crate::style::Border::uniform(
+                Length::px(1.0),
+                &Color::hex("#000000"),
+            )),
+            background_image: Some(gradient()),
+            border_image: Some(gradient().into()),
+            line_height: Some(1.5),
+            vertical_align: Some(VerticalAlign::Middle),
+            border_radius: Some(BorderRadius::uniform(Length::px(6.0))),
+            box_shadow: Some(vec![BoxShadow::new()]),
+            elevation: Some(2),
+            padding: Some(Padding::uniform(Length::px(7.0))),
+            margin: Some(Margin::uniform(Length::px(8.0))),
+            animator: Some(Animator::new([])),
+            mix_blend_mode: Some(crate::style::MixBlendMode::Multiply),
+            filter: Some(vec![crate::style::Filter::Grayscale(1.0)]),
+            mask: Some(gradient().into()),
+            ..element_style()
+        };
+        let style = schema.to_style();
+
+        for &property in crate::style::style_props::ALL_STYLE_PROPERTIES {
+            if LONGHAND_ONLY_PROPERTIES.contains(&property) {
+                continue;
+            }
+            assert!(
+                style.get(property).is_some(),
+                "no style key writes {property:?}"
+            );
+        }
+    }
+
+    #[test]
+    fn side_keys_override_their_shorthand() {
+        let style = ElementStylePropSchema {
+            padding: Some(Padding::uniform(Length::px(4.0))),
+            padding_left: Some(Length::px(12.0)),
+            margin_top: Some(Length::Zero),
+            ..Default::default()
+        }
+        .to_style();
+
+        assert_eq!(
+            style.get(PropertyId::PaddingLeft),
+            Some(&ParsedValue::Length(Length::px(12.0)))
+        );
+        assert_eq!(
+            style.get(PropertyId::PaddingRight),
+            Some(&ParsedValue::Length(Length::px(4.0)))
+        );
+        assert_eq!(
+            style.get(PropertyId::MarginTop),
+            Some(&ParsedValue::Length(Length::Zero))
+        );
+        assert_eq!(style.get(PropertyId::MarginBottom), None);
+    }
+
+    #[test]
+    fn mask_key_lowers_image_and_gradient_sources() {
+        let source = ImageSource::Path("mask.png".into());
+        let style = ElementStylePropSchema {
+            mask: Some(source.clone().into()),
+            ..Default::default()
+        }
+        .to_style();
+        assert_eq!(
+            style.get(PropertyId::Mask),
+            Some(&ParsedValue::Mask(crate::style::Mask::Image(source)))
+        );
+
+        let gradient = crate::style::Gradient::rainbow(crate::style::SideOrCorner::Right);
+        let style = ElementStylePropSchema {
+            mask: Some(gradient.clone().into()),
+            ..Default::default()
+        }
+        .to_style();
+        assert_eq!(
+            style.get(PropertyId::Mask),
+            Some(&ParsedValue::Mask(crate::style::Mask::Gradient(gradient)))
+        );
+        assert!(
+            ElementStylePropSchema::default()
+                .to_style()
+                .get(PropertyId::Mask)
+                .is_none()
+        );
+    }
+
     #[test]
     fn hover_lowering_keeps_shared_fields() {
         let schema = TextStylePropSchema {
@@ -1838,6 +2519,39 @@ mod style_lowering_tests {
         assert_shared_fields(style.hover().expect("hover style should lower"));
     }
 
+    #[test]
+    fn media_blocks_lower_in_authoring_order() {
+        let query = |min_width| crate::style::MediaQuery {
+            min_width: Some(min_width),
+            ..Default::default()
+        };
+        let block = |width| HoverElementStylePropSchema {
+            width: Some(Length::px(width)),
+            ..Default::default()
+        };
+        let style = ElementStylePropSchema {
+            media: Some(vec![
+                MediaRule::new(query(800.0), block(400.0)),
+                MediaRule::new(query(1200.0), block(600.0)),
+            ]),
+            ..Default::default()
+        }
+        .to_style();
+
+        let lowered: Vec<_> = style
+            .media()
+            .iter()
+            .map(|rule| (rule.query.min_width, rule.style.get(PropertyId::Width)))
+            .collect();
+        assert_eq!(
+            lowered,
+            [
+                (Some(800.0), Some(&ParsedValue::Length(Length::px(400.0)))),
+                (Some(1200.0), Some(&ParsedValue::Length(Length::px(600.0)))),
+            ]
+        );
+    }
+
     #[test]
     fn inherent_and_trait_to_style_match_for_element_style() {
         let schema = ElementStylePropSchema {
@@ -1867,6 +2581,164 @@ mod style_lowering_tests {
             <TextStylePropSchema as StylePropTrait>::to_style(&schema)
         );
     }
+
+    #[test]
+    fn composed_element_styles_let_later_shorthands_replace_longhands() {
+        let defaults = ElementStylePropSchema {
+            padding_top: Some(Length::px(2.0)),
+            margin: Some(Margin::uniform(Length::px(1.0))),
+            cursor: Some(Cursor::Pointer),
+            hover: Some(HoverElementStylePropSchema {
+                opacity: Some(Opacity::new(0.5)),
+                ..Default::default()
+            }),
+            ..Default::default()
+        };
+        let incoming = ElementStylePropSchema {
+            padding: Some(Padding::uniform(Length::px(8.0))),
+            margin_left: Some(Length::px(4.0)),
+            hover: Some(HoverElementStylePropSchema {
+                cursor: Some(Cursor::Text),
+                ..Default::default()
+            }),
+            ..Default::default()
+        };
+
+        let composed = crate::styles![
+            defaults,
+            false.then(ElementStylePropSchema::default),
+            Some(incoming)
+        ];
+        let style = composed.to_style();
+
+        assert_eq!(
+            style.get(PropertyId::PaddingTop),
+            Some(&ParsedValue::Length(Length::px(8.0)))
+        );
+        assert_eq!(
+            style.get(PropertyId::MarginTop),
+            Some(&ParsedValue::Length(Length::px(1.0)))
+        );
+        assert_eq!(
+            style.get(PropertyId::MarginLeft),
+            Some(&ParsedValue::Length(Length::px(4.0)))
+        );
+        assert_eq!(
+            style.get(PropertyId::Cursor),
+            Some(&ParsedValue::Cursor(Cursor::Pointer))
+        );
+        let hover = style.hover().expect("merged hover");
+        assert!(hover.get(PropertyId::Opacity).is_some());
+        assert_eq!(
+            hover.get(PropertyId::Cursor),
+            Some(&ParsedValue::Cursor(Cursor::Text))
+        );
+    }
+
+    #[test]
+    fn root_style_stacks_over_the_root_element_style() {
+        let root_style = |node: &RsxNode| match node {
+            RsxNode::Element(element) => element
+                .props
+                .iter()
+                .find(|(key, _)| *key == "style")
+                .and_then(|(_, value)| ElementStylePropSchema::from_prop_value(value.clone()).ok()),
+            _ => None,
+        };
+        let incoming = ElementStylePropSchema {
+            cursor: Some(Cursor::Text),
+            ..Default::default()
+        };
+
+        let root = crate::ui::rsx! {
+            <Element style={{
+                cursor: Cursor::Pointer,
+                padding: Padding::uniform(Length::px(4.0)),
+            }} />
+        };
+        let style = root_style(&with_root_style(root, Some(incoming.clone())))
+            .expect("root style")
+            .to_style();
+        assert_eq!(
+            style.get(PropertyId::Cursor),
+            Some(&ParsedValue::Cursor(Cursor::Text))
+        );
+        assert_eq!(
+            style.get(PropertyId::PaddingTop),
+            Some(&ParsedValue::Length(Length::px(4.0)))
+        );
+
+        let bare = with_root_style(crate::ui::rsx! { <Element /> }, Some(incoming.clone()));
+        assert_eq!(
+            root_style(&bare).map(|style| style.to_style()),
+            Some(incoming.to_style())
+        );
+
+        // A root that takes no element style is wrapped in one that does.
+        let text = with_root_style(
+            crate::ui::rsx! { <Text>{"label"}</Text> },
+            Some(incoming.clone()),
+        );
+        assert_eq!(
+            root_style(&text).map(|style| style.to_style()),
+            Some(incoming.to_style())
+        );
+        assert_eq!(text.children().map(<[RsxNode]>::len), Some(1));
+    }
+
+    #[test]
+    fn root_class_styles_stack_beneath_the_root_style() {
+        let classes = StyleClasses::new()
+            .with_class(
+                "dense",
+                ElementStylePropSchema {
+                    cursor: Some(Cursor::Pointer),
+                    padding: Some(Padding::uniform(Length::px(2.0))),
+                    ..Default::default()
+                },
+            )
+            .with_class(
+                "quiet",
+                ElementStylePropSchema {
+                    cursor: Some(Cursor::Text),
+                    ..Default::default()
+                },
+            );
+        let style = ElementStylePropSchema {
+            padding: Some(Padding::uniform(Length::px(6.0))),
+            ..Default::default()
+        };
+        let label = || crate::ui::rsx! { <Text>{"label"}</Text> };
+
+        let node = crate::ui::with_pushed_context_raw(
+            TypeId::of::<StyleClasses>(),
+            Rc::new(classes),
+            || with_root_class_and_style(label(), Some("dense quiet unknown"), Some(style)),
+        );
+        let RsxNode::Element(wrapper) = &node else {
+            panic!("text root is wrapped in an element, got {node:?}");
+        };
+        let resolved = wrapper
+            .props
+            .iter()
+            .find(|(key, _)| *key == "style")
+            .and_then(|(_, value)| ElementStylePropSchema::from_prop_value(value.clone()).ok())
+            .expect("root style")
+            .to_style();
+        assert_eq!(
+            resolved.get(PropertyId::Cursor),
+            Some(&ParsedValue::Cursor(Cursor::Text))
+        );
+        assert_eq!(
+            resolved.get(PropertyId::PaddingTop),
+            Some(&ParsedValue::Length(Length::px(6.0)))
+        );
+
+        // Without a provider, class names resolve to nothing and the root
+        // is left as it is.
+        let bare = with_root_class_and_style(label(), Some("dense"), None);
+        assert!(!accepts_element_style(&bare));
+    }
 }
 
 impl crate::ui::IntoPropValue for ImageFit {
@@ -1907,6 +2779,56 @@ impl crate::ui::FromPropValue for ImageFit {
     }
 }
 
+impl crate::ui::IntoPropValue for crate::transition::TransitionGroup {
+    fn into_prop_value(self) -> crate::ui::PropValue {
+        crate::ui::PropValue::Shared(crate::ui::SharedPropValue::new(Rc::new(self)))
+    }
+}
+
+impl From<crate::transition::TransitionGroup> for crate::ui::PropValue {
+    fn from(value: crate::transition::TransitionGroup) -> Self {
+        crate::ui::IntoPropValue::into_prop_value(value)
+    }
+}
+
+impl crate::ui::FromPropValue for crate::transition::TransitionGroup {
+    fn from_prop_value(value: crate::ui::PropValue) -> Result<Self, String> {
+        match value {
+            crate::ui::PropValue::Shared(shared) => shared
+                .value()
+                .downcast::<crate::transition::TransitionGroup>()
+                .map(|value| *value)
+                .map_err(|_| "expected TransitionGroup value".to_string()),
+            _ => Err("expected TransitionGroup value".to_string()),
+        }
+    }
+}
+
+impl crate::ui::IntoPropValue for crate::view::viewport::HoverIntent {
+    fn into_prop_value(self) -> crate::ui::PropValue {
+        crate::ui::PropValue::Shared(crate::ui::SharedPropValue::new(Rc::new(self)))
+    }
+}
+
+impl From<crate::view::viewport::HoverIntent> for crate::ui::PropValue {
+    fn from(value: crate::view::viewport::HoverIntent) -> Self {
+        crate::ui::IntoPropValue::into_prop_value(value)
+    }
+}
+
+impl crate::ui::FromPropValue for crate::view::viewport::HoverIntent {
+    fn from_prop_value(value: crate::ui::PropValue) -> Result<Self, String> {
+        match value {
+            crate::ui::PropValue::Shared(shared) => shared
+                .value()
+                .downcast::<crate::view::viewport::HoverIntent>()
+                .map(|value| *value)
+                .map_err(|_| "expected HoverIntent value".to_string()),
+            _ => Err("expected HoverIntent value".to_string()),
+        }
+    }
+}
+
 impl crate::ui::IntoPropValue for ImageSource {
     fn into_prop_value(self) -> crate::ui::PropValue {
         crate::ui::PropValue::Shared(crate::ui::SharedPropValue::new(Rc::new(self)))
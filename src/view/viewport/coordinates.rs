@@ -0,0 +1,161 @@
+//! Point and rect conversion between the viewport, a node's parent and the
+//! node's own coordinate space.
+//!
+//! Every conversion goes through viewport space. Layout boxes already
+//! include ancestor scroll offsets; element transforms along the ancestor
+//! chain are applied on top, so custom drag and drawing widgets don't have
+//! to duplicate that math.
+
+use super::*;
+use crate::ui::{CoordinateSpace, Rect};
+use crate::view::base_component::Element;
+use crate::view::node_arena::{NodeArena, NodeKey};
+
+impl Viewport {
+    /// Convert `point` from `from` to `to`, both relative to `node`.
+    /// `None` when the node is not in the tree.
+    pub fn convert_point(
+        &self,
+        node: NodeId,
+        point: (f32, f32),
+        from: CoordinateSpace,
+        to: CoordinateSpace,
+    ) -> Option<(f32, f32)> {
+        let arena = &self.scene.node_arena;
+        arena.get(node)?;
+        let viewport_point = match from {
+            CoordinateSpace::Viewport => point,
+            CoordinateSpace::Local => local_to_viewport(arena, node, point),
+            CoordinateSpace::Parent => match arena.parent_of(node) {
+                Some(parent) => {
+                    let (scroll_x, scroll_y) = scroll_offset(arena, parent);
+                    local_to_viewport(arena, parent, (point.0 - scroll_x, point.1 - scroll_y))
+                }
+                None => point,
+            },
+        };
+        Some(match to {
+            CoordinateSpace::Viewport => viewport_point,
+            CoordinateSpace::Local => viewport_to_local(arena, node, viewport_point),
+            CoordinateSpace::Parent => match arena.parent_of(node) {
+                Some(parent) => {
+                    let (x, y) = viewport_to_local(arena, parent, viewport_point);
+                    let (scroll_x, scroll_y) = scroll_offset(arena, parent);
+                    (x + scroll_x, y + scroll_y)
+                }
+                None => viewport_point,
+            },
+        })
+    }
+
+    /// Convert `rect` from `from` to `to`, both relative to `node`. Under a
+    /// rotation or skew the result is the box bounding the converted
+    /// corners.
+    pub fn convert_rect(
+        &self,
+        node: NodeId,
+        rect: Rect,
+        from: CoordinateSpace,
+        to: CoordinateSpace,
+    ) -> Option<Rect> {
+        let corners = [
+            (rect.x, rect.y),
+            (rect.x + rect.width, rect.y),
+            (rect.x + rect.width, rect.y + rect.height),
+            (rect.x, rect.y + rect.height),
+        ];
+        let mut min = (f32::INFINITY, f32::INFINITY);
+        let mut max = (f32::NEG_INFINITY, f32::NEG_INFINITY);
+        for corner in corners {
+            let (x, y) = self.convert_point(node, corner, from, to)?;
+            min = (min.0.min(x), min.1.min(y));
+            max = (max.0.max(x), max.1.max(y));
+        }
+        Some(Rect::new(min.0, min.1, max.0 - min.0, max.1 - min.1))
+    }
+}
+
+impl ViewportControl<'_> {
+    pub fn convert_point(
+        &self,
+        node: NodeId,
+        point: (f32, f32),
+        from: CoordinateSpace,
+        to: CoordinateSpace,
+    ) -> Option<(f32, f32)> {
+        self.viewport.convert_point(node, point, from, to)
+    }
+
+    pub fn convert_rect(
+        &self,
+        node: NodeId,
+        rect: Rect,
+        from: CoordinateSpace,
+        to: CoordinateSpace,
+    ) -> Option<Rect> {
+        self.viewport.convert_rect(node, rect, from, to)
+    }
+}
+
+/// `key` and its ancestors, nearest first.
+fn self_and_ancestors(arena: &NodeArena, key: NodeKey) -> Vec<NodeKey> {
+    let mut chain = vec![key];
+    let mut current = arena.parent_of(key);
+    while let Some(parent) = current {
+        chain.push(parent);
+        current = arena.parent_of(parent);
+    }
+    chain
+}
+
+fn scroll_offset(arena: &NodeArena, key: NodeKey) -> (f32, f32) {
+    arena
+        .get(key)
+        .map_or((0.0, 0.0), |node| node.element.get_scroll_offset())
+}
+
+fn local_to_viewport(arena: &NodeArena, key: NodeKey, point: (f32, f32)) -> (f32, f32) {
+    let Some(node) = arena.get(key) else {
+        return point;
+    };
+    let snapshot = node.element.box_model_snapshot();
+    drop(node);
+    let mut point = (point.0 + snapshot.x, point.1 + snapshot.y);
+    // The node's own transform applies first, then each ancestor's.
+    for key in self_and_ancestors(arena, key) {
+        let Some(node) = arena.get(key) else {
+            continue;
+        };
+        if let Some(mapped) = node
+            .element
+            .as_any()
+            .downcast_ref::<Element>()
+            .and_then(|element| element.map_paint_to_viewport_space(point.0, point.1))
+        {
+            point = mapped;
+        }
+    }
+    point
+}
+
+fn viewport_to_local(arena: &NodeArena, key: NodeKey, point: (f32, f32)) -> (f32, f32) {
+    let mut point = point;
+    for key in self_and_ancestors(arena, key).into_iter().rev() {
+        let Some(node) = arena.get(key) else {
+            continue;
+        };
+        if let Some(mapped) = node
+            .element
+            .as_any()
+            .downcast_ref::<Element>()
+            .and_then(|element| element.map_viewport_to_paint_space(point.0, point.1))
+        {
+            point = mapped;
+        }
+    }
+    let Some(node) = arena.get(key) else {
+        return point;
+    };
+    let snapshot = node.element.box_model_snapshot();
+    (point.0 - snapshot.x, point.1 - snapshot.y)
+}
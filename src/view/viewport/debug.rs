@@ -479,7 +479,7 @@ pub(super) fn build_layout_place_trace_nodes(
         ),
         TraceRenderNode::new(
             format!(
-                "measure_ran (self_dirty={}, child_dirty={}, proposal_changed={} [size={}, viewport={}, percent_base={}, first={}])",
+                "measure_ran (self_dirty={}, child_dirty={}, proposal_changed={} [size={}, viewport={}, percent_base={}, first={}], cache_hits={})",
                 profile.measure_ran_self_dirty,
                 profile.measure_ran_child_dirty,
                 profile.measure_ran_proposal_changed,
@@ -487,6 +487,7 @@ pub(super) fn build_layout_place_trace_nodes(
                 profile.proposal_changed_viewport,
                 profile.proposal_changed_percent_base,
                 profile.proposal_changed_first,
+                profile.measure_cache_hits,
             ),
             0.0,
         ),
@@ -617,19 +618,7 @@ pub(super) fn build_layout_traversal_trace_nodes(
 }
 
 pub(super) fn style_field_requires_relayout(field: StyleField) -> bool {
-    match field {
-        StyleField::Opacity
-        | StyleField::BorderRadius
-        | StyleField::BackgroundColor
-        | StyleField::Color
-        | StyleField::BorderTopColor
-        | StyleField::BorderRightColor
-        | StyleField::BorderBottomColor
-        | StyleField::BorderLeftColor
-        | StyleField::BoxShadow
-        | StyleField::Transform
-        | StyleField::TransformOrigin => false,
-    }
+    field.affects_layout()
 }
 
 #[derive(Clone, Copy, Debug, Default)]
@@ -705,6 +694,143 @@ fn append_overlay_rect_quad(
     indices.extend_from_slice(&[base, base + 1, base + 2, base, base + 2, base + 3]);
 }
 
+/// Fills a logical `[x, y, width, height]` rect.
+pub(super) fn append_overlay_fill_rect(
+    vertices: &mut Vec<super::super::render_pass::debug_overlay_pass::DebugOverlayVertex>,
+    indices: &mut Vec<u32>,
+    rect: [f32; 4],
+    scale: f32,
+    color: [f32; 4],
+    screen_w: f32,
+    screen_h: f32,
+) {
+    let [x, y, width, height] = rect;
+    append_overlay_rect_quad(
+        vertices,
+        indices,
+        x * scale,
+        y * scale,
+        (x + width) * scale,
+        (y + height) * scale,
+        color,
+        screen_w,
+        screen_h,
+    );
+}
+
+/// Outlines a logical `[x, y, width, height]` rect with a one device pixel
+/// stroke drawn just inside its edges.
+pub(super) fn append_overlay_outline_rect(
+    vertices: &mut Vec<super::super::render_pass::debug_overlay_pass::DebugOverlayVertex>,
+    indices: &mut Vec<u32>,
+    rect: [f32; 4],
+    scale: f32,
+    color: [f32; 4],
+    screen_w: f32,
+    screen_h: f32,
+) {
+    let [x, y, width, height] = rect;
+    let left = x * scale;
+    let top = y * scale;
+    let right = (x + width) * scale;
+    let bottom = (y + height) * scale;
+    if right <= left || bottom <= top {
+        return;
+    }
+    let stroke = 1.0_f32.min(right - left).min(bottom - top);
+    for [l, t, r, b] in [
+        [left, top, right, top + stroke],
+        [left, bottom - stroke, right, bottom],
+        [left, top + stroke, left + stroke, bottom - stroke],
+        [right - stroke, top + stroke, right, bottom - stroke],
+    ] {
+        append_overlay_rect_quad(vertices, indices, l, t, r, b, color, screen_w, screen_h);
+    }
+}
+
+/// Translucent tint for an element `depth` levels below its root. Adjacent
+/// depths get clearly different hues; the palette repeats every six levels.
+pub(super) fn depth_tint_color(depth: usize) -> [f32; 4] {
+    const PALETTE: [[f32; 3]; 6] = [
+        [0.25, 0.55, 1.0],
+        [0.2, 0.85, 0.45],
+        [1.0, 0.8, 0.2],
+        [1.0, 0.4, 0.25],
+        [0.75, 0.35, 1.0],
+        [0.2, 0.85, 0.9],
+    ];
+    let [r, g, b] = PALETTE[depth % PALETTE.len()];
+    [r, g, b, 0.15]
+}
+
+/// Line boxes and gap rects of one flex container, from the logical
+/// `[x, y, width, height]` rects of its items grouped by line. A line box is
+/// the union of its items. Gaps are the space between neighbouring items on
+/// a line, spanning the line's cross extent, and the space between
+/// neighbouring lines, spanning both lines' main extent.
+pub(super) fn flex_debug_rects(
+    lines: &[Vec<[f32; 4]>],
+    is_row: bool,
+) -> (Vec<[f32; 4]>, Vec<[f32; 4]>) {
+    // Work in (main start, cross start, main size, cross size) and swap back
+    // for columns.
+    let to_axis = |[x, y, w, h]: [f32; 4]| if is_row { [x, y, w, h] } else { [y, x, h, w] };
+    let from_axis = to_axis;
+    let mut line_boxes = Vec::new();
+    let mut gaps = Vec::new();
+    for line in lines {
+        let mut items = line.iter().copied().map(to_axis).collect::<Vec<_>>();
+        if items.is_empty() {
+            continue;
+        }
+        items.sort_by(|a, b| a[0].total_cmp(&b[0]));
+        let main_start = items.iter().map(|r| r[0]).fold(f32::INFINITY, f32::min);
+        let main_end = items
+            .iter()
+            .map(|r| r[0] + r[2])
+            .fold(f32::NEG_INFINITY, f32::max);
+        let cross_start = items.iter().map(|r| r[1]).fold(f32::INFINITY, f32::min);
+        let cross_end = items
+            .iter()
+            .map(|r| r[1] + r[3])
+            .fold(f32::NEG_INFINITY, f32::max);
+        for pair in items.windows(2) {
+            let gap_start = pair[0][0] + pair[0][2];
+            let gap_end = pair[1][0];
+            if gap_end > gap_start {
+                gaps.push(from_axis([
+                    gap_start,
+                    cross_start,
+                    gap_end - gap_start,
+                    cross_end - cross_start,
+                ]));
+            }
+        }
+        line_boxes.push([
+            main_start,
+            cross_start,
+            main_end - main_start,
+            cross_end - cross_start,
+        ]);
+    }
+    line_boxes.sort_by(|a, b| a[1].total_cmp(&b[1]));
+    for pair in line_boxes.windows(2) {
+        let gap_start = pair[0][1] + pair[0][3];
+        let gap_end = pair[1][1];
+        if gap_end > gap_start {
+            let main_start = pair[0][0].min(pair[1][0]);
+            let main_end = (pair[0][0] + pair[0][2]).max(pair[1][0] + pair[1][2]);
+            gaps.push(from_axis([
+                main_start,
+                gap_start,
+                main_end - main_start,
+                gap_end - gap_start,
+            ]));
+        }
+    }
+    (line_boxes.into_iter().map(from_axis).collect(), gaps)
+}
+
 fn digit_segments(digit: char) -> Option<&'static [usize]> {
     match digit {
         '0' => Some(&[0, 1, 2, 4, 5, 6]),
@@ -901,6 +1027,49 @@ mod tests {
         assert!(trace.contains("skipped_child_place_calls (count=2)"));
     }
 
+    #[test]
+    fn flex_debug_rects_cover_lines_and_gaps_in_row_and_column() {
+        // Two wrapped lines of 10px items with a 5px main gap and 4px cross gap.
+        let lines = vec![
+            vec![[0.0, 0.0, 10.0, 10.0], [15.0, 0.0, 10.0, 8.0]],
+            vec![[0.0, 14.0, 10.0, 10.0]],
+        ];
+        let (line_boxes, gaps) = flex_debug_rects(&lines, true);
+        assert_eq!(
+            line_boxes,
+            vec![[0.0, 0.0, 25.0, 10.0], [0.0, 14.0, 10.0, 10.0]]
+        );
+        assert_eq!(gaps, vec![[10.0, 0.0, 5.0, 10.0], [0.0, 10.0, 25.0, 4.0]]);
+
+        // The same layout transposed into a column, items listed in reverse.
+        let columns = vec![vec![[0.0, 15.0, 8.0, 10.0], [0.0, 0.0, 10.0, 10.0]]];
+        let (line_boxes, gaps) = flex_debug_rects(&columns, false);
+        assert_eq!(line_boxes, vec![[0.0, 0.0, 10.0, 25.0]]);
+        assert_eq!(gaps, vec![[0.0, 10.0, 10.0, 5.0]]);
+    }
+
+    #[test]
+    fn outline_rect_stays_inside_the_rect() {
+        let mut vertices = Vec::new();
+        let mut indices = Vec::new();
+        append_overlay_outline_rect(
+            &mut vertices,
+            &mut indices,
+            [10.0, 10.0, 20.0, 10.0],
+            2.0,
+            [1.0; 4],
+            100.0,
+            100.0,
+        );
+        assert_eq!(vertices.len(), 16);
+        assert_eq!(indices.len(), 24);
+        for vertex in &vertices {
+            let x = ((vertex.position[0] + 1.0) * 50.0).round();
+            let y = ((1.0 - vertex.position[1]) * 50.0).round();
+            assert!((20.0..=60.0).contains(&x) && (20.0..=40.0).contains(&y));
+        }
+    }
+
     #[test]
     fn layout_place_trace_nests_overlapping_place_timings() {
         let profile = crate::view::base_component::LayoutPlaceProfile {
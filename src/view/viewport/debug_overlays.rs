@@ -0,0 +1,273 @@
+//! Runtime-toggleable debug overlays.
+//!
+//! Each enabled mode in [`ViewportDebugOptions`] becomes one
+//! [`DebugOverlayPass`] appended after everything else that draws into the
+//! frame's root target, so the overlays sit above all content and need no
+//! cooperation from the element build.
+
+use super::debug::{
+    append_overlay_fill_rect, append_overlay_outline_rect, depth_tint_color, flex_debug_rects,
+};
+use super::*;
+use crate::view::base_component::Element;
+use crate::view::node_arena::NodeKey;
+use crate::view::render_pass::debug_overlay_pass::{
+    DebugOverlayGeometry, DebugOverlayPass, DebugOverlayVertex,
+};
+use crate::view::render_pass::draw_rect_pass::RenderTargetOut;
+
+/// How long a repainted region stays visible under paint flashing.
+const PAINT_FLASH_DURATION: Duration = Duration::from_millis(300);
+const PAINT_FLASH_COLOR: [f32; 3] = [1.0, 0.2, 0.6];
+const PAINT_FLASH_PEAK_ALPHA: f32 = 0.45;
+const FLEX_LINE_COLOR: [f32; 4] = [0.7, 0.3, 1.0, 0.9];
+const FLEX_GAP_COLOR: [f32; 4] = [0.7, 0.3, 1.0, 0.3];
+const SCISSOR_COLOR: [f32; 4] = [0.1, 0.9, 0.9, 0.95];
+
+/// A repainted region, in logical viewport coordinates, fading out.
+pub(super) struct PaintFlash {
+    rect: [f32; 4],
+    started_at: Instant,
+}
+
+/// Overlay geometry in clip space over a `screen_w` x `screen_h` physical
+/// target, from logical rects.
+struct OverlayGeometry {
+    vertices: Vec<DebugOverlayVertex>,
+    indices: Vec<u32>,
+    scale: f32,
+    screen_w: f32,
+    screen_h: f32,
+}
+
+impl OverlayGeometry {
+    fn new(scale: f32, (screen_w, screen_h): (u32, u32)) -> Self {
+        Self {
+            vertices: Vec::new(),
+            indices: Vec::new(),
+            scale: scale.max(0.0001),
+            screen_w: screen_w.max(1) as f32,
+            screen_h: screen_h.max(1) as f32,
+        }
+    }
+
+    fn fill(&mut self, rect: [f32; 4], color: [f32; 4]) {
+        append_overlay_fill_rect(
+            &mut self.vertices,
+            &mut self.indices,
+            rect,
+            self.scale,
+            color,
+            self.screen_w,
+            self.screen_h,
+        );
+    }
+
+    fn outline(&mut self, rect: [f32; 4], color: [f32; 4]) {
+        append_overlay_outline_rect(
+            &mut self.vertices,
+            &mut self.indices,
+            rect,
+            self.scale,
+            color,
+            self.screen_w,
+            self.screen_h,
+        );
+    }
+
+    fn into_geometry(self) -> DebugOverlayGeometry {
+        DebugOverlayGeometry::Prebuilt {
+            vertices: self.vertices,
+            indices: self.indices,
+        }
+    }
+}
+
+/// Alpha of a paint flash `age` after it started, or `None` once it has
+/// faded out.
+pub(super) fn paint_flash_alpha(age: Duration) -> Option<f32> {
+    if age >= PAINT_FLASH_DURATION {
+        return None;
+    }
+    let remaining = 1.0 - age.as_secs_f32() / PAINT_FLASH_DURATION.as_secs_f32();
+    Some(PAINT_FLASH_PEAK_ALPHA * remaining)
+}
+
+fn snapshot_rect(snapshot: &crate::view::base_component::BoxModelSnapshot) -> Option<[f32; 4]> {
+    (snapshot.should_render && snapshot.width > 0.0 && snapshot.height > 0.0).then_some([
+        snapshot.x,
+        snapshot.y,
+        snapshot.width,
+        snapshot.height,
+    ])
+}
+
+impl Viewport {
+    /// Appends one overlay pass per enabled debug mode, drawing over `target`
+    /// once everything else in it has been drawn. Called after the build walk
+    /// and before the present pass is added.
+    pub(super) fn append_debug_overlay_passes(
+        &mut self,
+        graph: &mut FrameGraph,
+        target: RenderTargetOut,
+        roots: &[NodeKey],
+        now: Instant,
+    ) {
+        let options = self.debug_options;
+        if options.paint_flashing {
+            self.record_paint_flashes(now);
+        }
+        if !(options.depth_tint
+            || options.flex_overlay
+            || options.paint_flashing
+            || options.scissor_overlay)
+            || target.handle().is_none()
+        {
+            return;
+        }
+
+        let scale = self.scale_factor;
+        let surface_size = self.surface_size();
+        let mut depth_tint = OverlayGeometry::new(scale, surface_size);
+        let mut flex = OverlayGeometry::new(scale, surface_size);
+        if options.depth_tint || options.flex_overlay {
+            let arena = &self.scene.node_arena;
+            let mut stack = roots
+                .iter()
+                .rev()
+                .map(|&root| (root, 0))
+                .collect::<Vec<_>>();
+            while let Some((key, depth)) = stack.pop() {
+                let Some(node) = arena.get(key) else {
+                    continue;
+                };
+                let snapshot = node.element.box_model_snapshot();
+                if !snapshot.should_render {
+                    continue;
+                }
+                if options.depth_tint
+                    && let Some(rect) = snapshot_rect(&snapshot)
+                {
+                    depth_tint.fill(rect, depth_tint_color(depth));
+                }
+                if options.flex_overlay
+                    && let Some((is_row, lines)) = node
+                        .element
+                        .as_any()
+                        .downcast_ref::<Element>()
+                        .and_then(Element::flex_line_children)
+                {
+                    let item_rects = lines
+                        .iter()
+                        .map(|line| {
+                            line.iter()
+                                .filter_map(|&child| {
+                                    snapshot_rect(&arena.get(child)?.element.box_model_snapshot())
+                                })
+                                .collect::<Vec<_>>()
+                        })
+                        .collect::<Vec<_>>();
+                    let (line_boxes, gaps) = flex_debug_rects(&item_rects, is_row);
+                    for gap in gaps {
+                        flex.fill(gap, FLEX_GAP_COLOR);
+                    }
+                    for line_box in line_boxes {
+                        flex.outline(line_box, FLEX_LINE_COLOR);
+                    }
+                }
+                stack.extend(
+                    node.children()
+                        .iter()
+                        .rev()
+                        .map(|&child| (child, depth + 1)),
+                );
+            }
+        }
+
+        if options.depth_tint {
+            graph.add_graphics_pass(DebugOverlayPass::new(
+                "debug_depth_tint",
+                depth_tint.into_geometry(),
+                target,
+            ));
+        }
+        if options.flex_overlay {
+            graph.add_graphics_pass(DebugOverlayPass::new(
+                "debug_flex_overlay",
+                flex.into_geometry(),
+                target,
+            ));
+        }
+        if options.paint_flashing && !self.frame.paint_flashes.is_empty() {
+            let mut flashes = OverlayGeometry::new(scale, surface_size);
+            for flash in &self.frame.paint_flashes {
+                let Some(alpha) = paint_flash_alpha(now.duration_since(flash.started_at)) else {
+                    continue;
+                };
+                let [r, g, b] = PAINT_FLASH_COLOR;
+                flashes.fill(flash.rect, [r, g, b, alpha]);
+            }
+            graph.add_graphics_pass(DebugOverlayPass::new(
+                "debug_paint_flashing",
+                flashes.into_geometry(),
+                target,
+            ));
+            // Keep frames coming until every flash has faded out.
+            self.request_redraw();
+        }
+        if options.scissor_overlay {
+            graph.add_graphics_pass(DebugOverlayPass::new(
+                "debug_scissor_overlay",
+                DebugOverlayGeometry::ScissorRects,
+                target,
+            ));
+        }
+    }
+
+    /// Starts a flash over every element whose paint changed this frame and
+    /// drops flashes that have faded out.
+    fn record_paint_flashes(&mut self, now: Instant) {
+        self.frame
+            .paint_flashes
+            .retain(|flash| paint_flash_alpha(now.duration_since(flash.started_at)).is_some());
+        let arena = &self.scene.node_arena;
+        let repainted = self
+            .compositor
+            .paint_generations
+            .repainted_this_frame()
+            .filter_map(|key| snapshot_rect(&arena.get(key)?.element.box_model_snapshot()));
+        self.frame
+            .paint_flashes
+            .extend(repainted.map(|rect| PaintFlash {
+                rect,
+                started_at: now,
+            }));
+    }
+
+    /// Records a logical scissor rect a draw is about to be clipped to, for
+    /// the scissor overlay.
+    pub(crate) fn note_debug_scissor_rect(&mut self, scissor_rect: Option<[u32; 4]>) {
+        if !self.debug_options.scissor_overlay {
+            return;
+        }
+        if let Some(rect) = scissor_rect
+            && !self.frame.debug_scissor_rects.contains(&rect)
+        {
+            self.frame.debug_scissor_rects.push(rect);
+        }
+    }
+
+    /// Outlines of the scissor rects recorded so far this frame.
+    pub(crate) fn take_debug_scissor_overlay_geometry(
+        &mut self,
+    ) -> (Vec<DebugOverlayVertex>, Vec<u32>) {
+        let mut geometry = OverlayGeometry::new(self.scale_factor, self.surface_size());
+        for [x, y, width, height] in std::mem::take(&mut self.frame.debug_scissor_rects) {
+            geometry.outline(
+                [x as f32, y as f32, width as f32, height as f32],
+                SCISSOR_COLOR,
+            );
+        }
+        (geometry.vertices, geometry.indices)
+    }
+}
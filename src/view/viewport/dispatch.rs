@@ -18,12 +18,36 @@ impl Viewport {
             .map(|target| (target.root_key, target.target_key))
     }
 
+    /// Topmost hit-testable element under the viewport point (`x`, `y`),
+    /// popups first, using the geometry of the last layout.
+    pub fn node_at(&self, x: f32, y: f32) -> Option<crate::view::node_arena::NodeKey> {
+        Self::hit_test_pointer_target(
+            &self.scene.node_arena,
+            &self.scene.popup_stack,
+            &self.scene.ui_root_keys,
+            x,
+            y,
+        )
+        .map(|(_, target)| target)
+    }
+
     #[doc(hidden)]
     pub fn dispatch_pointer_down_event(&mut self, button: PointerButton) -> bool {
+        let handled = self.dispatch_pointer_down_to_scene(button);
+        self.notify_global_pointer_listeners(
+            |listeners| &listeners.pointer_down,
+            Some(button),
+            handled,
+        );
+        handled
+    }
+
+    fn dispatch_pointer_down_to_scene(&mut self, button: PointerButton) -> bool {
         let Some((x, y)) = self.pointer_position_viewport() else {
             return false;
         };
         self.input_state.pending_click = None;
+        self.input_state.pending_long_press = None;
         let focus_before = self.focused_node_id();
         let buttons = self.current_ui_pointer_buttons();
         let meta = EventMeta::new(NodeId::default());
@@ -86,8 +110,17 @@ impl Viewport {
                 viewport_y: y,
             });
         }
+        if matches!(button, PointerButton::Left)
+            && let Some((_, target_key)) = hit_target
+        {
+            self.arm_long_press(target_key, x, y);
+        }
         if let Some(capture_target_id) = event.meta.pointer_capture_target_id() {
             self.input_state.pointer_capture_node_id = Some(capture_target_id);
+        } else if let Some((_, target_key)) = hit_target {
+            // A handler that captured the pointer owns the gesture; only
+            // uncaptured presses may move the host window.
+            self.apply_app_region_press(target_key, button, x, y);
         }
         self.apply_viewport_listener_actions(event.meta.take_viewport_listener_actions());
         crate::ui::dispatch_viewport_pointer_down_hook(crate::ui::ViewportPointerDownEvent {
@@ -134,6 +167,17 @@ impl Viewport {
 
     #[doc(hidden)]
     pub fn dispatch_pointer_up_event(&mut self, button: PointerButton) -> bool {
+        let handled = self.dispatch_pointer_up_to_scene(button);
+        self.notify_global_pointer_listeners(
+            |listeners| &listeners.pointer_up,
+            Some(button),
+            handled,
+        );
+        handled
+    }
+
+    fn dispatch_pointer_up_to_scene(&mut self, button: PointerButton) -> bool {
+        self.input_state.pending_long_press = None;
         let Some((x, y)) = self.pointer_position_viewport() else {
             self.input_state.pointer_capture_node_id = None;
             let root_keys = self.scene.ui_root_keys.clone();
@@ -225,9 +269,20 @@ impl Viewport {
 
     #[doc(hidden)]
     pub fn dispatch_pointer_move_event(&mut self) -> bool {
+        let handled = self.dispatch_pointer_move_to_scene();
+        self.notify_global_pointer_listeners(|listeners| &listeners.pointer_move, None, handled);
+        handled
+    }
+
+    fn dispatch_pointer_move_to_scene(&mut self) -> bool {
         let Some((x, y)) = self.pointer_position_viewport() else {
             return false;
         };
+        if self.input_state.pending_long_press.is_some_and(|press| {
+            distance_sq(x, y, press.viewport_x, press.viewport_y) > LONG_PRESS_MAX_TRAVEL_SQ
+        }) {
+            self.input_state.pending_long_press = None;
+        }
         // Drag-active: route the move through DragOver / DragLeave
         // instead of the normal hover+move path.
         if self.input_state.drag_state.is_some() {
@@ -244,6 +299,7 @@ impl Viewport {
         );
         let hover_target = hit_target.map(|(_, t)| t);
         let buttons = self.current_ui_pointer_buttons();
+        let now = self.frame_now();
         let pointer_data = PointerEventData {
             viewport_x: x,
             viewport_y: y,
@@ -261,8 +317,10 @@ impl Viewport {
             &self.scene.node_arena,
             &root_keys,
             &mut self.input_state.hovered_node_id,
+            &mut self.input_state.hovered_path,
             hover_target,
             pointer_data,
+            Some((&mut self.input_state.hover_intent, now)),
         );
         let meta = EventMeta::new(NodeId::default());
         let mut event = PointerMoveEvent {
@@ -319,6 +377,118 @@ impl Viewport {
         handled || hover_changed || hover_event_dispatched
     }
 
+    /// Arm a long press on the nearest element at or above `target_key`
+    /// that handles one, timed from the current frame clock.
+    fn arm_long_press(&mut self, target_key: crate::view::node_arena::NodeKey, x: f32, y: f32) {
+        let now = self.frame_now();
+        let arena = &self.scene.node_arena;
+        let mut current = Some(target_key);
+        while let Some(key) = current {
+            let Some(node) = arena.get(key) else {
+                return;
+            };
+            if let Some(duration) = node.element.long_press_hold() {
+                self.input_state.pending_long_press = Some(PendingLongPress {
+                    target_id: key,
+                    viewport_x: x,
+                    viewport_y: y,
+                    duration,
+                    fires_at: now + duration,
+                });
+                return;
+            }
+            current = node.parent;
+        }
+    }
+
+    /// Fire the pending long press once its hold time has elapsed at `now`.
+    /// The press no longer produces a click when it is released. Runs at
+    /// the start of every frame; [`Self::frame_schedule`] wakes the host
+    /// for the deadline.
+    #[doc(hidden)]
+    pub fn dispatch_long_press_if_due(&mut self, now: Instant) -> bool {
+        let Some(pending) = self
+            .input_state
+            .pending_long_press
+            .filter(|press| press.fires_at <= now)
+        else {
+            return false;
+        };
+        self.input_state.pending_long_press = None;
+        self.input_state.pending_click = None;
+        let root_keys = self.scene.ui_root_keys.clone();
+        let mut event = crate::ui::LongPressEvent {
+            meta: EventMeta::new(NodeId::default()),
+            pointer: PointerEventData {
+                viewport_x: pending.viewport_x,
+                viewport_y: pending.viewport_y,
+                local_x: 0.0,
+                local_y: 0.0,
+                button: Some(PointerButton::Left),
+                buttons: self.current_ui_pointer_buttons(),
+                modifiers: self.current_key_modifiers(),
+                pointer_id: 0,
+                pointer_type: PointerType::Mouse,
+                pressure: 0.5,
+                timestamp: now,
+            },
+            duration: pending.duration,
+        };
+        let mut handled = false;
+        {
+            event.meta.attach_dispatch_ctx(&*self);
+            let (arena, mut control) = self.borrow_for_dispatch();
+            for &root_key in root_keys.iter().rev() {
+                if crate::view::viewport::dispatch::dispatch_long_press_to_target(
+                    arena,
+                    root_key,
+                    pending.target_id,
+                    &mut event,
+                    &mut control,
+                ) {
+                    handled = true;
+                    break;
+                }
+            }
+        }
+        event.meta.detach_dispatch_ctx();
+        self.apply_viewport_listener_actions(event.meta.take_viewport_listener_actions());
+        if handled {
+            self.request_redraw();
+        }
+        handled
+    }
+
+    /// Apply the hover changes held back by hover intent whose delay has
+    /// run out by `now`. Called once per frame, like the long-press timer.
+    #[doc(hidden)]
+    pub fn dispatch_hover_intent_if_due(&mut self, now: Instant) -> bool {
+        if !self.input_state.hover_intent.has_due(now) {
+            return false;
+        }
+        let pointer = self.pointer_position_viewport().unwrap_or((0.0, 0.0));
+        let pointer_data = synthetic_pointer_data(
+            pointer,
+            self.current_key_modifiers(),
+            self.current_ui_pointer_buttons(),
+        );
+        let root_keys = self.scene.ui_root_keys.clone();
+        let hover_target = self.input_state.hovered_node_id;
+        let (hover_changed, hover_event_dispatched) = Self::sync_hover_target(
+            &self.scene.node_arena,
+            &root_keys,
+            &mut self.input_state.hovered_node_id,
+            &mut self.input_state.hovered_path,
+            hover_target,
+            pointer_data,
+            Some((&mut self.input_state.hover_intent, now)),
+        );
+        if hover_changed || hover_event_dispatched {
+            self.request_redraw();
+        }
+        hover_changed || hover_event_dispatched
+    }
+
     #[doc(hidden)]
     pub fn dispatch_click_event(&mut self, button: PointerButton) -> bool {
         let Some((x, y)) = self.pointer_position_viewport() else {
@@ -455,10 +625,47 @@ impl Viewport {
         delta_y: f32,
         delta_mode: crate::platform::input::WheelDeltaMode,
         phase: crate::platform::input::WheelPhase,
+    ) -> bool {
+        let handled = self.dispatch_pointer_wheel_to_scene(delta_x, delta_y, delta_mode, phase);
+        if let Some((x, y)) = self.pointer_position_viewport() {
+            let event = GlobalWheelEvent {
+                viewport_x: x,
+                viewport_y: y,
+                delta_x,
+                delta_y,
+                modifiers: self.current_key_modifiers(),
+                handled,
+            };
+            self.notify_global_listeners(|listeners| &listeners.wheel, &event);
+        }
+        handled
+    }
+
+    fn dispatch_pointer_wheel_to_scene(
+        &mut self,
+        delta_x: f32,
+        delta_y: f32,
+        delta_mode: crate::platform::input::WheelDeltaMode,
+        phase: crate::platform::input::WheelPhase,
     ) -> bool {
         let Some((x, y)) = self.pointer_position_viewport() else {
             return false;
         };
+        // Phased trackpad streams bracket a gesture with `Began`/`Ended` and
+        // follow it with OS momentum. Track the bracket so the `Changed`
+        // deltas in between are recognised as part of the gesture too.
+        let in_wheel_gesture = match phase {
+            crate::platform::input::WheelPhase::Began => {
+                self.input_state.wheel_gesture_active = true;
+                true
+            }
+            crate::platform::input::WheelPhase::Changed => self.input_state.wheel_gesture_active,
+            crate::platform::input::WheelPhase::Ended => {
+                self.input_state.wheel_gesture_active = false;
+                true
+            }
+            crate::platform::input::WheelPhase::Momentum => true,
+        };
         // Surface the event to user handlers first. A handler that calls
         // `meta.prevent_default()` suppresses the built-in scroll routing
         // — lets apps trap ctrl+wheel as zoom, implement custom scroll
@@ -511,7 +718,12 @@ impl Viewport {
             }
             return wheel_user_handled;
         }
-        let mut pending_scroll_track: Option<(TrackTarget, (f32, f32), (f32, f32))> = None;
+        let wheel_scroll = self.transitions.wheel_scroll_transition;
+        let passthrough = wheel_scroll.native_momentum
+            && in_wheel_gesture
+            && delta_mode == crate::platform::input::WheelDeltaMode::Pixel;
+        let mut pending_scroll_track: Option<(TrackTarget, (f32, f32), (f32, f32), bool)> = None;
+        let mut applied_directly = false;
         let root_keys = self.scene.ui_root_keys.clone();
         let Some((root_index, target_key)) = Self::find_scroll_handler_at_pointer(
             &self.scene.node_arena,
@@ -537,6 +749,30 @@ impl Viewport {
                 root_key,
                 target_stable_id,
             ) {
+                // A tick landing mid-animation continues from the running
+                // track's destination so unconsumed distance is not lost.
+                let pending_x = self
+                    .transitions
+                    .scroll_transition_plugin
+                    .pending_scroll_target(target_stable_id, ScrollAxis::X);
+                let pending_y = self
+                    .transitions
+                    .scroll_transition_plugin
+                    .pending_scroll_target(target_stable_id, ScrollAxis::Y);
+                let continues_track = !passthrough && (pending_x.is_some() || pending_y.is_some());
+                if passthrough {
+                    // The OS owns the momentum curve; a smoothing track would
+                    // fight it, so drop any in flight and apply directly.
+                    self.cancel_scroll_track(target_stable_id, ScrollAxis::X);
+                    self.cancel_scroll_track(target_stable_id, ScrollAxis::Y);
+                } else if continues_track {
+                    let _ = crate::view::viewport::dispatch::set_scroll_offset_by_id(
+                        &self.scene.node_arena,
+                        root_key,
+                        target_stable_id,
+                        (pending_x.unwrap_or(from.0), pending_y.unwrap_or(from.1)),
+                    );
+                }
                 let _ = crate::view::viewport::dispatch::dispatch_scroll_to_target(
                     &self.scene.node_arena,
                     root_key,
@@ -549,22 +785,33 @@ impl Viewport {
                     root_key,
                     target_stable_id,
                 ) {
-                    let _ = crate::view::viewport::dispatch::set_scroll_offset_by_id(
-                        &self.scene.node_arena,
-                        root_key,
-                        target_stable_id,
-                        from,
-                    );
-
-                    if (to.0 - from.0).abs() > 0.001 || (to.1 - from.1).abs() > 0.001 {
-                        pending_scroll_track = Some((target_stable_id, from, to));
+                    let moved = (to.0 - from.0).abs() > 0.001 || (to.1 - from.1).abs() > 0.001;
+                    if passthrough {
+                        applied_directly = moved;
+                    } else {
+                        let _ = crate::view::viewport::dispatch::set_scroll_offset_by_id(
+                            &self.scene.node_arena,
+                            root_key,
+                            target_stable_id,
+                            from,
+                        );
+                        if moved {
+                            pending_scroll_track =
+                                Some((target_stable_id, from, to, continues_track));
+                        }
                     }
                 }
             }
         }
-        let mut handled = false;
-        if let Some((target_id, from, to)) = pending_scroll_track {
-            let transition_spec = self.transitions.scroll_transition;
+        let mut handled = applied_directly;
+        if let Some((target_id, from, to, continues_track)) = pending_scroll_track {
+            let transition_spec = if continues_track {
+                wheel_scroll.inertia
+            } else {
+                wheel_scroll
+                    .smoothing
+                    .unwrap_or(self.transitions.scroll_transition)
+            };
             let mut host = TransitionHostAdapter {
                 registered_channels: &self.transitions.transition_channels,
                 claims: &mut self.transitions.transition_claims,
@@ -637,6 +884,18 @@ impl Viewport {
 
     #[doc(hidden)]
     pub fn dispatch_key_down_event(&mut self, data: KeyEventData) -> bool {
+        let focus_before = self.focused_node_id();
+        let handled = self.dispatch_key_down_to_focus(data.clone());
+        // Handlers that moved focus themselves already navigated.
+        let handled =
+            (self.focused_node_id() == focus_before && self.navigate_spatially(&data)) || handled;
+        let event = GlobalKeyEvent { key: data, handled };
+        self.notify_global_listeners(|listeners| &listeners.key_down, &event);
+        handled
+    }
+
+    fn dispatch_key_down_to_focus(&mut self, data: KeyEventData) -> bool {
+        self.input_state.key_down_default_prevented = false;
         let Some(target_id) = self.keyboard_dispatch_target() else {
             return false;
         };
@@ -663,6 +922,7 @@ impl Viewport {
             }
         }
         event.meta.detach_dispatch_ctx();
+        self.input_state.key_down_default_prevented = event.meta.default_prevented();
         let pending_actions = event.meta.take_viewport_listener_actions();
         self.apply_viewport_listener_actions(pending_actions);
         if handled {
@@ -673,6 +933,14 @@ impl Viewport {
 
     #[doc(hidden)]
     pub fn dispatch_key_up_event(&mut self, data: KeyEventData) -> bool {
+        self.input_state.key_down_default_prevented = false;
+        let handled = self.dispatch_key_up_to_focus(data.clone());
+        let event = GlobalKeyEvent { key: data, handled };
+        self.notify_global_listeners(|listeners| &listeners.key_up, &event);
+        handled
+    }
+
+    fn dispatch_key_up_to_focus(&mut self, data: KeyEventData) -> bool {
         let Some(target_id) = self.keyboard_dispatch_target() else {
             return false;
         };
@@ -722,6 +990,13 @@ impl Viewport {
         if text.is_empty() {
             return false;
         }
+        // Like the DOM, preventing a key press's default also drops the
+        // characters it types.
+        if matches!(input_type, crate::ui::InputType::Typing)
+            && std::mem::take(&mut self.input_state.key_down_default_prevented)
+        {
+            return false;
+        }
         let Some(target_id) = self.keyboard_dispatch_target() else {
             return false;
         };
@@ -1404,6 +1679,7 @@ impl Viewport {
     /// those remain public for now so component tests and existing callers
     /// keep working. New backend code should only ever see this method.
     pub fn dispatch_platform_pointer_event(&mut self, event: &PlatformPointerEvent) -> bool {
+        let _span = crate::trace::span!("dispatch", event = "pointer", kind = ?event.kind);
         match event.kind {
             PlatformPointerEventKind::Down(button) => self.dispatch_pointer_down_event(button),
             PlatformPointerEventKind::Up(button) => self.dispatch_pointer_up_event(button),
@@ -1416,6 +1692,7 @@ impl Viewport {
     }
 
     pub fn dispatch_platform_wheel_event(&mut self, event: &PlatformWheelEvent) -> bool {
+        let _span = crate::trace::span!("dispatch", event = "wheel");
         self.dispatch_pointer_wheel_event_full(
             event.delta_x,
             event.delta_y,
@@ -1425,6 +1702,22 @@ impl Viewport {
     }
 
     pub fn dispatch_platform_key_event(&mut self, event: &PlatformKeyEvent) -> bool {
+        let _span = crate::trace::span!(
+            "dispatch",
+            event = "key",
+            key = ?event.key,
+            pressed = event.pressed
+        );
+        if !self.native_menu_shortcuts {
+            let shortcut = self
+                .window_menu
+                .as_ref()
+                .and_then(|menu| menu.find_accelerator(event))
+                .map(|action| action.id.clone());
+            if let Some(id) = shortcut {
+                return self.dispatch_menu_event(&id);
+            }
+        }
         let data = KeyEventData {
             key: event.key,
             characters: event.characters.clone(),
@@ -1441,7 +1734,66 @@ impl Viewport {
         }
     }
 
+    /// Run the callback of the window or tray menu entry `id`. Hosts call
+    /// this when a native menu item is activated; accelerators route here
+    /// too. Returns false for unknown or disabled entries.
+    pub fn dispatch_menu_event(&mut self, id: &MenuItemId) -> bool {
+        let _span = crate::trace::span!("dispatch", event = "menu");
+        let action = self
+            .window_menu
+            .as_ref()
+            .and_then(|menu| menu.find(id))
+            .or_else(|| {
+                self.tray_icon
+                    .as_ref()
+                    .and_then(|tray| tray.menu.as_ref())
+                    .and_then(|menu| menu.find(id))
+            });
+        let Some(action) = action.filter(|action| action.enabled) else {
+            return false;
+        };
+        if let Some(callback) = action.callback().cloned() {
+            callback(&mut ViewportControl::new(self));
+        }
+        self.request_redraw();
+        true
+    }
+
+    /// Run the tray icon's event callback. Returns false when no tray icon
+    /// is installed.
+    pub fn dispatch_tray_event(&mut self, event: TrayEvent) -> bool {
+        let _span = crate::trace::span!("dispatch", event = "tray");
+        let Some(tray) = self.tray_icon.as_ref() else {
+            return false;
+        };
+        if let Some(callback) = tray.callback().cloned() {
+            callback(event, &mut ViewportControl::new(self));
+        }
+        self.request_redraw();
+        true
+    }
+
+    /// Route the host's report of a user response to notification `id`.
+    /// Any response resolves the notification; returns false when `id` is
+    /// unknown or already resolved.
+    pub fn dispatch_notification_event(
+        &mut self,
+        id: NotificationId,
+        event: NotificationEvent,
+    ) -> bool {
+        let _span = crate::trace::span!("dispatch", event = "notification");
+        let Some(notification) = self.notifications.remove(&id) else {
+            return false;
+        };
+        if let Some(callback) = notification.callback(event).cloned() {
+            callback(&mut ViewportControl::new(self));
+        }
+        self.request_redraw();
+        true
+    }
+
     pub fn dispatch_platform_text_input(&mut self, event: &PlatformTextInput) -> bool {
+        let _span = crate::trace::span!("dispatch", event = "text_input");
         self.dispatch_text_input_event_full(
             event.text.clone(),
             ui_input_type_from_platform(event.input_type),
@@ -1450,6 +1802,7 @@ impl Viewport {
     }
 
     pub fn dispatch_platform_ime_preedit(&mut self, event: &PlatformImePreedit) -> bool {
+        let _span = crate::trace::span!("dispatch", event = "ime_preedit");
         let cursor = match (event.cursor_start, event.cursor_end) {
             (Some(start), Some(end)) => Some((start, end)),
             _ => None,
@@ -1509,6 +1862,26 @@ impl Viewport {
         None
     }
 
+    /// Run the global pointer listeners of `list` for the event just
+    /// dispatched at the current pointer position, if there is one.
+    fn notify_global_pointer_listeners(
+        &mut self,
+        list: fn(&GlobalListeners) -> &GlobalListenerList<GlobalPointerEvent>,
+        button: Option<PointerButton>,
+        handled: bool,
+    ) {
+        let Some(position) = self.pointer_position_viewport() else {
+            return;
+        };
+        let mut pointer = synthetic_pointer_data(
+            position,
+            self.current_key_modifiers(),
+            self.current_ui_pointer_buttons(),
+        );
+        pointer.button = button;
+        self.notify_global_listeners(list, &GlobalPointerEvent { pointer, handled });
+    }
+
     fn current_key_modifiers(&self) -> Modifiers {
         self.input_state.modifiers
     }
@@ -1551,6 +1924,49 @@ impl Viewport {
         }
     }
 
+    /// Turn a primary press on an `app_region: drag` area into a window
+    /// drag, or into the title-bar double-click action when it follows a
+    /// previous drag press within the multi-click window.
+    fn apply_app_region_press(
+        &mut self,
+        target_key: crate::view::node_arena::NodeKey,
+        button: PointerButton,
+        x: f32,
+        y: f32,
+    ) {
+        if button != PointerButton::Left
+            || resolve_app_region(&self.scene.node_arena, target_key) != AppRegion::Drag
+        {
+            self.input_state.last_drag_region_press = None;
+            return;
+        }
+        use crate::view::viewport::input::{
+            CLICK_COUNT_INTERVAL, CLICK_COUNT_MAX_TRAVEL_SQ, distance_sq,
+        };
+        let now = crate::time::Instant::now();
+        let is_double =
+            self.input_state
+                .last_drag_region_press
+                .is_some_and(|(at, last_x, last_y)| {
+                    now.duration_since(at) <= CLICK_COUNT_INTERVAL
+                        && distance_sq(x, y, last_x, last_y) <= CLICK_COUNT_MAX_TRAVEL_SQ
+                });
+        let command = if is_double {
+            self.input_state.last_drag_region_press = None;
+            match self.title_bar_double_click {
+                TitleBarDoubleClick::ToggleMaximize => Some(WindowCommand::ToggleMaximize),
+                TitleBarDoubleClick::Minimize => Some(WindowCommand::Minimize),
+                TitleBarDoubleClick::None => None,
+            }
+        } else {
+            self.input_state.last_drag_region_press = Some((now, x, y));
+            Some(WindowCommand::DragWindow)
+        };
+        if let Some(command) = command {
+            self.pending_platform_requests.window_commands.push(command);
+        }
+    }
+
     pub(super) fn resolve_cursor(&self) -> Cursor {
         if let Some(cursor) = self.cursor_override {
             return cursor;
@@ -1558,10 +1974,20 @@ impl Viewport {
         let Some(target_key) = self.input_state.hovered_node_id else {
             return Cursor::Default;
         };
-        self.scene
-            .node_arena
-            .get(target_key)
-            .map(|node| node.element.cursor())
+        let arena = &self.scene.node_arena;
+        let Some(node) = arena.get(target_key) else {
+            return Cursor::Default;
+        };
+        let cursor = node.element.cursor();
+        if cursor != Cursor::Default || node.element.as_any().is::<Element>() {
+            return cursor;
+        }
+        drop(node);
+        // Text inside an interactive element shows its user-agent cursor.
+        arena
+            .parent_of(target_key)
+            .and_then(|parent_key| arena.get(parent_key))
+            .map(|parent| parent.element.cursor())
             .unwrap_or(Cursor::Default)
     }
 
@@ -1578,6 +2004,24 @@ impl Viewport {
     }
 }
 
+/// Nearest `app_region` set on `key` or its ancestors; `Auto` when none is.
+fn resolve_app_region(
+    arena: &crate::view::node_arena::NodeArena,
+    key: crate::view::node_arena::NodeKey,
+) -> AppRegion {
+    let mut current = Some(key);
+    while let Some(node_key) = current {
+        let Some(node) = arena.get(node_key) else {
+            break;
+        };
+        match node.element.app_region() {
+            AppRegion::Auto => current = arena.parent_of(node_key),
+            region => return region,
+        }
+    }
+    AppRegion::Auto
+}
+
 impl Viewport {
     pub fn has_viewport_pointer_listeners(&self) -> bool {
         crate::ui::has_viewport_pointer_hooks()
@@ -1656,6 +2100,24 @@ impl Viewport {
                         self.request_redraw();
                     }
                 }
+                EventCommand::ScrollTo { target_id, x, y } => {
+                    let scrolled = self
+                        .scene
+                        .node_arena
+                        .mutate_element_ref_with_invalidation(target_id, |element, cx| {
+                            let before = element.get_scroll_offset();
+                            element.scroll_by(x - before.0, y - before.1);
+                            let changed = element.get_scroll_offset() != before;
+                            if changed {
+                                cx.invalidate(DirtyPassMask::RUNTIME);
+                            }
+                            changed
+                        })
+                        .unwrap_or(false);
+                    if scrolled {
+                        self.request_redraw();
+                    }
+                }
                 EventCommand::KeyboardCapture(node_id) => {
                     self.input_state.keyboard_capture_node_id = node_id;
                 }
@@ -1705,6 +2167,15 @@ impl Viewport {
                 EventCommand::RequestPaste => {
                     self.pending_platform_requests.request_paste = true;
                 }
+                EventCommand::Notify(notification) => {
+                    self.post_notification(notification);
+                }
+                EventCommand::SetBadge(badge) => {
+                    self.set_badge(badge);
+                }
+                EventCommand::PlaySound(name) => {
+                    self.play_sound(&name);
+                }
             }
         }
         if selection_changed {
@@ -1731,6 +2202,7 @@ impl Viewport {
             .unwrap_or((0.0, 0.0));
         self.input_state.pointer_position_viewport = None;
         self.input_state.pointer_capture_node_id = None;
+        self.input_state.hover_intent.clear();
         let root_keys = self.scene.ui_root_keys.clone();
         let pointer_data = synthetic_pointer_data(
             last_pos,
@@ -1741,8 +2213,10 @@ impl Viewport {
             &self.scene.node_arena,
             &root_keys,
             &mut self.input_state.hovered_node_id,
+            &mut self.input_state.hovered_path,
             None,
             pointer_data,
+            None,
         );
         let pointer_changed = Self::cancel_pointer_interactions(&self.scene.node_arena, &root_keys);
         if hover_changed || hover_event_dispatched || pointer_changed {
@@ -1791,8 +2265,10 @@ impl Viewport {
         self.set_focused_node_id(None);
         self.sync_focus_dispatch();
         let previous_hovered_node_id = self.input_state.hovered_node_id;
+        let previous_hovered_path = std::mem::take(&mut self.input_state.hovered_path);
         self.input_state = InputState::default();
         self.input_state.hovered_node_id = previous_hovered_node_id;
+        self.input_state.hovered_path = previous_hovered_path;
         self.dispatched_focus_node_id = None;
         let root_keys = self.scene.ui_root_keys.clone();
         let pointer_data = synthetic_pointer_data(
@@ -1806,8 +2282,10 @@ impl Viewport {
             &self.scene.node_arena,
             &root_keys,
             &mut self.input_state.hovered_node_id,
+            &mut self.input_state.hovered_path,
             None,
             pointer_data,
+            None,
         );
         let pointer_changed = Self::cancel_pointer_interactions(&self.scene.node_arena, &root_keys);
         if hover_changed || hover_event_dispatched || pointer_changed {
@@ -1841,6 +2319,7 @@ impl Viewport {
         if hover_target == self.input_state.hovered_node_id {
             return false;
         }
+        let now = self.frame_now();
         let pointer_data = synthetic_pointer_data(
             (x, y),
             self.current_key_modifiers(),
@@ -1850,8 +2329,10 @@ impl Viewport {
             &self.scene.node_arena,
             &root_keys,
             &mut self.input_state.hovered_node_id,
+            &mut self.input_state.hovered_path,
             hover_target,
             pointer_data,
+            Some((&mut self.input_state.hover_intent, now)),
         );
         if hover_changed || hover_event_dispatched {
             self.request_redraw();
@@ -2123,6 +2604,49 @@ pub(crate) fn dispatch_context_menu_to_target(
     dispatch_context_menu_bubble(arena, target_key, event, control)
 }
 
+/// Deliver a long press to `target_key` only; long presses do not bubble.
+pub(crate) fn dispatch_long_press_to_target(
+    arena: &crate::view::node_arena::NodeArena,
+    root_key: crate::view::node_arena::NodeKey,
+    target_key: crate::view::node_arena::NodeKey,
+    event: &mut crate::ui::LongPressEvent,
+    control: &mut ViewportControl<'_>,
+) -> bool {
+    if !arena.contains_key(target_key) {
+        return false;
+    }
+    event.meta.set_target_id(target_key);
+    event
+        .meta
+        .set_path(composed_path_for_target(arena, root_key, target_key));
+    event.meta.set_bubbles(false);
+    event.meta.set_phase(crate::ui::EventPhase::AtTarget);
+    let dispatched = arena
+        .mutate_element_ref_with_invalidation(target_key, |element, cx| {
+            let snapshot = element.box_model_snapshot();
+            let (local_x, local_y) = local_point_for_node(
+                element.as_ref(),
+                &snapshot,
+                event.pointer.viewport_x,
+                event.pointer.viewport_y,
+            );
+            event.pointer.local_x = local_x;
+            event.pointer.local_y = local_y;
+            let ct = crate::ui::EventTarget::snapshot(
+                target_key,
+                crate::ui::Rect::new(snapshot.x, snapshot.y, snapshot.width, snapshot.height),
+                crate::ui::Rect::new(0.0, 0.0, snapshot.width, snapshot.height),
+            );
+            event.meta.set_current_target(ct);
+            element.dispatch_long_press(event, control, cx.arena(), target_key);
+            cx.invalidate(element.local_dirty_flags());
+            true
+        })
+        .unwrap_or(false);
+    event.meta.set_phase(crate::ui::EventPhase::None);
+    dispatched
+}
+
 pub fn dispatch_scroll_from_hit_test(
     arena: &crate::view::node_arena::NodeArena,
     root_key: crate::view::node_arena::NodeKey,
@@ -2266,18 +2790,8 @@ pub fn get_scroll_offset_by_id(
     root_key: crate::view::node_arena::NodeKey,
     stable_id: u64,
 ) -> Option<(f32, f32)> {
-    let node = arena.get(root_key)?;
-    if node.element.stable_id() == stable_id {
-        return Some(node.element.get_scroll_offset());
-    }
-    let children: Vec<_> = node.children.clone();
-    drop(node);
-    for child_key in children {
-        if let Some(offset) = get_scroll_offset_by_id(arena, child_key, stable_id) {
-            return Some(offset);
-        }
-    }
-    None
+    let key = arena.find_in_subtree_by_stable_id(root_key, stable_id)?;
+    Some(arena.get(key)?.element.get_scroll_offset())
 }
 
 pub fn set_scroll_offset_by_id(
@@ -2286,39 +2800,19 @@ pub fn set_scroll_offset_by_id(
     stable_id: u64,
     offset: (f32, f32),
 ) -> bool {
-    fn walk(
-        arena: &crate::view::node_arena::NodeArena,
-        key: crate::view::node_arena::NodeKey,
-        stable_id: u64,
-        offset: (f32, f32),
-    ) -> bool {
-        let Some(node) = arena.get(key) else {
-            return false;
-        };
-        if node.element.stable_id() == stable_id {
-            drop(node);
-            return arena
-                .mutate_element_ref_with_invalidation(key, |element, cx| {
-                    let before = element.get_scroll_offset();
-                    element.set_scroll_offset(offset);
-                    if before != offset {
-                        cx.invalidate(DirtyPassMask::RUNTIME);
-                    }
-                    true
-                })
-                .unwrap_or(false);
-        }
-        let children = node.children.clone();
-        drop(node);
-        for child_key in children {
-            if walk(arena, child_key, stable_id, offset) {
-                return true;
+    let Some(key) = arena.find_in_subtree_by_stable_id(root_key, stable_id) else {
+        return false;
+    };
+    arena
+        .mutate_element_ref_with_invalidation(key, |element, cx| {
+            let before = element.get_scroll_offset();
+            element.set_scroll_offset(offset);
+            if before != offset {
+                cx.invalidate(DirtyPassMask::RUNTIME);
             }
-        }
-        false
-    }
-
-    walk(arena, root_key, stable_id, offset)
+            true
+        })
+        .unwrap_or(false)
 }
 
 pub(crate) fn dispatch_key_down_bubble(
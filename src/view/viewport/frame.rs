@@ -1,4 +1,5 @@
 use super::*;
+use std::hash::{Hash, Hasher};
 use std::time::Duration;
 
 pub(super) struct BeginFrameProfile {
@@ -80,10 +81,72 @@ impl FrameStats {
     }
 }
 
+/// When the host should produce frames.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum RedrawMode {
+    /// Redraw only when something invalidated the viewport (state change,
+    /// transition, animation-frame request, input); sleep otherwise.
+    #[default]
+    OnDemand,
+    /// Redraw every frame regardless of invalidation, for games and
+    /// simulations that advance on their own clock.
+    Continuous,
+}
+
+/// Host-facing answer to "when is the next frame due?", see
+/// [`Viewport::frame_schedule`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum FrameSchedule {
+    /// Nothing is invalidated; the host may block until the next event.
+    Idle,
+    /// Render as soon as possible.
+    Now,
+    /// A frame is due but the FPS cap holds it back until this instant.
+    At(Instant),
+}
+
+/// Redraw mode plus optional FPS cap, and the start time of the last
+/// rendered frame the cap is measured from.
+#[derive(Debug, Clone, Default)]
+pub(super) struct FramePacer {
+    pub mode: RedrawMode,
+    max_fps: Option<f32>,
+    last_frame_at: Option<Instant>,
+}
+
+impl FramePacer {
+    pub(super) fn max_fps(&self) -> Option<f32> {
+        self.max_fps
+    }
+
+    /// Non-finite or non-positive caps are treated as "uncapped".
+    pub(super) fn set_max_fps(&mut self, max_fps: Option<f32>) {
+        self.max_fps = max_fps.filter(|fps| fps.is_finite() && *fps > 0.0);
+    }
+
+    pub(super) fn record_frame(&mut self, now: Instant) {
+        self.last_frame_at = Some(now);
+    }
+
+    pub(super) fn schedule(&self, now: Instant, invalidated: bool) -> FrameSchedule {
+        if !invalidated && self.mode == RedrawMode::OnDemand {
+            return FrameSchedule::Idle;
+        }
+        let (Some(max_fps), Some(last_frame_at)) = (self.max_fps, self.last_frame_at) else {
+            return FrameSchedule::Now;
+        };
+        let due = last_frame_at + Duration::from_secs_f64(1.0 / f64::from(max_fps));
+        if due <= now {
+            FrameSchedule::Now
+        } else {
+            FrameSchedule::At(due)
+        }
+    }
+}
+
 pub(super) struct FrameState {
-    #[cfg(not(test))]
-    pub render_texture: wgpu::SurfaceTexture,
-    #[cfg(test)]
+    /// Acquired swapchain image; `None` when rendering into the viewport's
+    /// offscreen target.
     pub render_texture: Option<wgpu::SurfaceTexture>,
     #[cfg(test)]
     pub offscreen_texture: Option<wgpu::Texture>,
@@ -234,3 +297,106 @@ impl<'a> FrameParts<'a> {
             })
     }
 }
+
+/// How much of the last submitted frame's GPU work was carried over from the
+/// frame before it instead of being rebuilt.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub struct RenderReuseMetrics {
+    /// The compiled frame graph was reused because its topology matched.
+    pub compiled_graph_reused: bool,
+    /// Rect uniform slots whose contents changed and were uploaded.
+    pub rect_uniforms_written: u64,
+    /// Rect uniform slots that already held this frame's contents on the
+    /// GPU, so their upload was skipped.
+    pub rect_uniforms_reused: u64,
+    /// Render pipelines created because no cached pipeline matched.
+    pub pipelines_created: u64,
+    /// Pipeline lookups served from the pipeline caches.
+    pub pipelines_reused: u64,
+    /// Bind groups created because no cached bind group matched.
+    pub bind_groups_created: u64,
+    /// Bind group lookups served from the viewport's bind group cache.
+    pub bind_groups_reused: u64,
+}
+
+impl RenderReuseMetrics {
+    /// Fraction of rect uniform slots reused, or 0.0 when nothing was drawn.
+    pub fn rect_uniform_reuse_rate(&self) -> f64 {
+        let total = self.rect_uniforms_written + self.rect_uniforms_reused;
+        if total == 0 {
+            return 0.0;
+        }
+        self.rect_uniforms_reused as f64 / total as f64
+    }
+
+    /// Fraction of pipeline lookups served from cache, or 0.0 when none ran.
+    pub fn pipeline_hit_rate(&self) -> f64 {
+        let total = self.pipelines_created + self.pipelines_reused;
+        if total == 0 {
+            return 0.0;
+        }
+        self.pipelines_reused as f64 / total as f64
+    }
+
+    /// Fraction of bind group lookups served from cache, or 0.0 when none ran.
+    pub fn bind_group_hit_rate(&self) -> f64 {
+        let total = self.bind_groups_created + self.bind_groups_reused;
+        if total == 0 {
+            return 0.0;
+        }
+        self.bind_groups_reused as f64 / total as f64
+    }
+}
+
+/// How the translucent nodes of the last synced frame reach the target.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub struct OpacityGroupMetrics {
+    /// Opacity groups composited through their own isolated layer.
+    pub isolated_groups: usize,
+    /// Single-draw nodes whose opacity was premultiplied into their pass
+    /// colors, with no layer of their own.
+    pub baked_nodes: usize,
+}
+
+/// Content hashes of the slots in one pooled uniform buffer, so an unchanged
+/// pass can keep the bytes the previous frame left at its slot. Passes upload
+/// in frame order, so a slot keeps its offset for as long as the passes
+/// drawn before it are unchanged.
+///
+/// Writes only become known GPU contents once their frame is submitted; an
+/// aborted frame leaves the slots it touched unknown.
+#[derive(Default)]
+pub(crate) struct RetainedUniformSlots {
+    committed: FxHashMap<u64, u64>,
+    pending: Vec<(u64, u64)>,
+}
+
+impl RetainedUniformSlots {
+    pub(super) fn content_hash(data: &[u8], slot_size: u64) -> u64 {
+        let mut hasher = rustc_hash::FxHasher::default();
+        slot_size.hash(&mut hasher);
+        data.hash(&mut hasher);
+        hasher.finish()
+    }
+
+    /// Whether the slot at `offset` already holds `hash` on the GPU.
+    pub(super) fn holds(&self, offset: u64, hash: u64) -> bool {
+        self.committed.get(&offset) == Some(&hash)
+    }
+
+    pub(super) fn record_write(&mut self, offset: u64, hash: u64) {
+        self.committed.remove(&offset);
+        self.pending.push((offset, hash));
+    }
+
+    pub(super) fn commit(&mut self) {
+        self.committed.extend(self.pending.drain(..));
+    }
+
+    pub(super) fn discard_pending(&mut self) {
+        self.pending.clear();
+    }
+}
+
+#[cfg(test)]
+mod retained_uniform_slot_tests;
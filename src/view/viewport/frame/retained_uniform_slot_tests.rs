@@ -0,0 +1,45 @@
+use super::{RenderReuseMetrics, RetainedUniformSlots};
+
+#[test]
+fn submitted_writes_are_reused_and_aborted_writes_are_forgotten() {
+    let mut slots = RetainedUniformSlots::default();
+    let a = RetainedUniformSlots::content_hash(&[1, 2, 3], 256);
+    let b = RetainedUniformSlots::content_hash(&[4, 5, 6], 256);
+    assert!(!slots.holds(0, a));
+
+    slots.record_write(0, a);
+    assert!(
+        !slots.holds(0, a),
+        "unsubmitted writes are not GPU contents"
+    );
+    slots.commit();
+    assert!(slots.holds(0, a));
+
+    slots.record_write(0, b);
+    slots.discard_pending();
+    assert!(
+        !slots.holds(0, a),
+        "an aborted write may have clobbered the slot"
+    );
+    assert!(!slots.holds(0, b));
+}
+
+#[test]
+fn content_hash_covers_slot_size() {
+    assert_ne!(
+        RetainedUniformSlots::content_hash(&[1, 2, 3], 256),
+        RetainedUniformSlots::content_hash(&[1, 2, 3], 512)
+    );
+}
+
+#[test]
+fn reuse_rate_is_zero_without_uploads() {
+    let metrics = RenderReuseMetrics::default();
+    assert_eq!(metrics.rect_uniform_reuse_rate(), 0.0);
+    let metrics = RenderReuseMetrics {
+        rect_uniforms_written: 1,
+        rect_uniforms_reused: 3,
+        ..RenderReuseMetrics::default()
+    };
+    assert_eq!(metrics.rect_uniform_reuse_rate(), 0.75);
+}
@@ -0,0 +1,245 @@
+//! Viewport-level listeners that see every pointer, wheel and key event.
+//!
+//! Element handlers only receive the events routed to them by hit testing
+//! or focus. Global listeners run after that capture/bubble dispatch for
+//! every event, whatever its target — or when there is none — so apps can
+//! build marquee selection, app-wide shortcuts or idle detection without a
+//! full-screen transparent element.
+
+use super::*;
+use crate::platform::input::Modifiers;
+use std::cell::RefCell;
+use std::rc::Rc;
+
+/// Handle returned when a global listener is added; pass it to
+/// [`Viewport::remove_global_listener`] to remove the listener again.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct GlobalListenerId(u64);
+
+/// A key press or release as seen by a global listener.
+#[derive(Clone, Debug)]
+pub struct GlobalKeyEvent {
+    pub key: KeyEventData,
+    /// Whether the focused element handled the event first.
+    pub handled: bool,
+}
+
+/// A pointer press, move or release as seen by a global listener.
+#[derive(Clone, Copy, Debug)]
+pub struct GlobalPointerEvent {
+    pub pointer: PointerEventData,
+    /// Whether element dispatch reported the event as handled: it reached
+    /// an element, or, for a move, changed which elements are hovered.
+    pub handled: bool,
+}
+
+/// A wheel or trackpad scroll as seen by a global listener.
+#[derive(Clone, Copy, Debug)]
+pub struct GlobalWheelEvent {
+    pub viewport_x: f32,
+    pub viewport_y: f32,
+    pub delta_x: f32,
+    pub delta_y: f32,
+    pub modifiers: Modifiers,
+    /// Whether an element handler or built-in scrolling consumed the event
+    /// first.
+    pub handled: bool,
+}
+
+type GlobalListener<E> = Rc<RefCell<dyn FnMut(&E, &mut ViewportControl<'_>)>>;
+
+/// Listeners of one event kind, in registration order.
+pub(super) struct GlobalListenerList<E> {
+    entries: Vec<(GlobalListenerId, GlobalListener<E>)>,
+}
+
+impl<E> Default for GlobalListenerList<E> {
+    fn default() -> Self {
+        Self {
+            entries: Vec::new(),
+        }
+    }
+}
+
+#[derive(Default)]
+pub(super) struct GlobalListeners {
+    next_id: u64,
+    pub(super) key_down: GlobalListenerList<GlobalKeyEvent>,
+    pub(super) key_up: GlobalListenerList<GlobalKeyEvent>,
+    pub(super) pointer_down: GlobalListenerList<GlobalPointerEvent>,
+    pub(super) pointer_move: GlobalListenerList<GlobalPointerEvent>,
+    pub(super) pointer_up: GlobalListenerList<GlobalPointerEvent>,
+    pub(super) wheel: GlobalListenerList<GlobalWheelEvent>,
+}
+
+impl GlobalListeners {
+    fn add<E>(
+        &mut self,
+        list: fn(&mut Self) -> &mut GlobalListenerList<E>,
+        listener: GlobalListener<E>,
+    ) -> GlobalListenerId {
+        self.next_id += 1;
+        let id = GlobalListenerId(self.next_id);
+        list(self).entries.push((id, listener));
+        id
+    }
+
+    fn remove(&mut self, id: GlobalListenerId) -> bool {
+        fn remove_from<E>(list: &mut GlobalListenerList<E>, id: GlobalListenerId) -> bool {
+            let len = list.entries.len();
+            list.entries.retain(|(entry_id, _)| *entry_id != id);
+            list.entries.len() != len
+        }
+        remove_from(&mut self.key_down, id)
+            || remove_from(&mut self.key_up, id)
+            || remove_from(&mut self.pointer_down, id)
+            || remove_from(&mut self.pointer_move, id)
+            || remove_from(&mut self.pointer_up, id)
+            || remove_from(&mut self.wheel, id)
+    }
+}
+
+impl Viewport {
+    /// Call `handler` for every key press, after the focused element (if
+    /// any) has handled it.
+    pub fn on_global_key_down<F>(&mut self, handler: F) -> GlobalListenerId
+    where
+        F: FnMut(&GlobalKeyEvent, &mut ViewportControl<'_>) + 'static,
+    {
+        self.global_listeners.add(
+            |listeners| &mut listeners.key_down,
+            Rc::new(RefCell::new(handler)),
+        )
+    }
+
+    /// Call `handler` for every key release, after the focused element (if
+    /// any) has handled it.
+    pub fn on_global_key_up<F>(&mut self, handler: F) -> GlobalListenerId
+    where
+        F: FnMut(&GlobalKeyEvent, &mut ViewportControl<'_>) + 'static,
+    {
+        self.global_listeners.add(
+            |listeners| &mut listeners.key_up,
+            Rc::new(RefCell::new(handler)),
+        )
+    }
+
+    /// Call `handler` for every pointer press, after the element under the
+    /// pointer (if any) has handled it.
+    pub fn on_global_pointer_down<F>(&mut self, handler: F) -> GlobalListenerId
+    where
+        F: FnMut(&GlobalPointerEvent, &mut ViewportControl<'_>) + 'static,
+    {
+        self.global_listeners.add(
+            |listeners| &mut listeners.pointer_down,
+            Rc::new(RefCell::new(handler)),
+        )
+    }
+
+    /// Call `handler` for every pointer move, including moves during a drag
+    /// or while another element holds the pointer capture.
+    pub fn on_global_pointer_move<F>(&mut self, handler: F) -> GlobalListenerId
+    where
+        F: FnMut(&GlobalPointerEvent, &mut ViewportControl<'_>) + 'static,
+    {
+        self.global_listeners.add(
+            |listeners| &mut listeners.pointer_move,
+            Rc::new(RefCell::new(handler)),
+        )
+    }
+
+    /// Call `handler` for every pointer release, after the element under
+    /// the pointer or holding the capture (if any) has handled it.
+    pub fn on_global_pointer_up<F>(&mut self, handler: F) -> GlobalListenerId
+    where
+        F: FnMut(&GlobalPointerEvent, &mut ViewportControl<'_>) + 'static,
+    {
+        self.global_listeners.add(
+            |listeners| &mut listeners.pointer_up,
+            Rc::new(RefCell::new(handler)),
+        )
+    }
+
+    /// Call `handler` for every wheel or trackpad scroll, after element
+    /// handlers and built-in scrolling have handled it.
+    pub fn on_global_wheel<F>(&mut self, handler: F) -> GlobalListenerId
+    where
+        F: FnMut(&GlobalWheelEvent, &mut ViewportControl<'_>) + 'static,
+    {
+        self.global_listeners.add(
+            |listeners| &mut listeners.wheel,
+            Rc::new(RefCell::new(handler)),
+        )
+    }
+
+    /// Remove a listener added by one of the `on_global_*` methods. Returns
+    /// false when it was already removed.
+    pub fn remove_global_listener(&mut self, id: GlobalListenerId) -> bool {
+        self.global_listeners.remove(id)
+    }
+
+    /// Run the listeners of `list` with `event`. Listeners added or removed
+    /// by a listener take effect from the next event.
+    pub(super) fn notify_global_listeners<E>(
+        &mut self,
+        list: fn(&GlobalListeners) -> &GlobalListenerList<E>,
+        event: &E,
+    ) {
+        let listeners = list(&self.global_listeners)
+            .entries
+            .iter()
+            .map(|(_, listener)| listener.clone())
+            .collect::<Vec<_>>();
+        for listener in listeners {
+            (listener.borrow_mut())(event, &mut ViewportControl::new(self));
+        }
+    }
+}
+
+impl ViewportControl<'_> {
+    pub fn on_global_key_down<F>(&mut self, handler: F) -> GlobalListenerId
+    where
+        F: FnMut(&GlobalKeyEvent, &mut ViewportControl<'_>) + 'static,
+    {
+        self.viewport.on_global_key_down(handler)
+    }
+
+    pub fn on_global_key_up<F>(&mut self, handler: F) -> GlobalListenerId
+    where
+        F: FnMut(&GlobalKeyEvent, &mut ViewportControl<'_>) + 'static,
+    {
+        self.viewport.on_global_key_up(handler)
+    }
+
+    pub fn on_global_pointer_down<F>(&mut self, handler: F) -> GlobalListenerId
+    where
+        F: FnMut(&GlobalPointerEvent, &mut ViewportControl<'_>) + 'static,
+    {
+        self.viewport.on_global_pointer_down(handler)
+    }
+
+    pub fn on_global_pointer_move<F>(&mut self, handler: F) -> GlobalListenerId
+    where
+        F: FnMut(&GlobalPointerEvent, &mut ViewportControl<'_>) + 'static,
+    {
+        self.viewport.on_global_pointer_move(handler)
+    }
+
+    pub fn on_global_pointer_up<F>(&mut self, handler: F) -> GlobalListenerId
+    where
+        F: FnMut(&GlobalPointerEvent, &mut ViewportControl<'_>) + 'static,
+    {
+        self.viewport.on_global_pointer_up(handler)
+    }
+
+    pub fn on_global_wheel<F>(&mut self, handler: F) -> GlobalListenerId
+    where
+        F: FnMut(&GlobalWheelEvent, &mut ViewportControl<'_>) + 'static,
+    {
+        self.viewport.on_global_wheel(handler)
+    }
+
+    pub fn remove_global_listener(&mut self, id: GlobalListenerId) -> bool {
+        self.viewport.remove_global_listener(id)
+    }
+}
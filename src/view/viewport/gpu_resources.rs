@@ -1626,6 +1626,18 @@ impl Viewport {
             .frame
             .draw_rect_uniform_cursor
             .min(self.frame.draw_rect_uniform_pool.len());
+        // Cached bind groups keep their textures and buffers alive, so ones
+        // whose resources left the pools must not outlive them for long.
+        self.frame.bind_group_cache.retain(|_, entry| {
+            frame_number.saturating_sub(entry.last_used_frame) <= MAX_IDLE_FRAMES
+        });
+        for texture in self
+            .frame
+            .offscreen_render_target_pool
+            .take_released_textures()
+        {
+            self.evict_bind_groups_for_texture(&texture);
+        }
 
         let Some(entry) = self.frame.gradient_stops_buffer.as_ref() else {
             return;
@@ -1728,6 +1740,22 @@ impl Viewport {
             .has_compatible_persistent(stable_key, desc, desc.sample_count().max(1))
     }
 
+    /// Starts the shadow raster cache for a new frame: drops committed
+    /// rasters whose textures left the pool and hands the rest to the build.
+    pub(crate) fn begin_shadow_raster_frame(&mut self) -> Arc<std::sync::Mutex<ShadowRasterFrame>> {
+        let pool = &self.frame.offscreen_render_target_pool;
+        self.frame.shadow_rasters.retain(|&id, entry| {
+            entry.is_pooled(id, |key, desc| {
+                pool.has_compatible_persistent(key, desc, desc.sample_count().max(1))
+            })
+        });
+        let rasters = Arc::new(std::sync::Mutex::new(ShadowRasterFrame::new(
+            self.frame.shadow_rasters.clone(),
+        )));
+        self.frame.pending_shadow_rasters = Some(rasters.clone());
+        rasters
+    }
+
     pub(crate) fn has_compatible_persistent_render_target_pair(
         &self,
         color_key: crate::view::frame_graph::PersistentTextureKey,
@@ -1760,10 +1788,10 @@ impl Viewport {
         let Some(validated) = upload.validate_rgba8() else {
             return false;
         };
-        let Some(device) = self.gpu.device.as_ref() else {
+        let Some(device) = self.gpu.device.clone() else {
             return false;
         };
-        let Some(queue) = self.gpu.queue.as_ref() else {
+        let Some(queue) = self.gpu.queue.clone() else {
             return false;
         };
         let width = validated.width;
@@ -1783,7 +1811,7 @@ impl Viewport {
             // Destroy the old texture explicitly before replacing it, so GPU
             // memory is freed immediately rather than waiting for JS GC.
             if let Some(old) = self.frame.sampled_texture_cache.remove(&upload.id) {
-                old.texture.destroy();
+                self.destroy_sampled_texture(&old.texture);
             }
             let texture = device.create_texture(&wgpu::TextureDescriptor {
                 label: Some("Sampled Image Texture"),
@@ -1864,10 +1892,33 @@ impl Viewport {
         id: crate::view::sampled_texture::SampledTextureId,
     ) {
         if let Some(entry) = self.frame.sampled_texture_cache.remove(&id) {
-            entry.texture.destroy();
+            self.destroy_sampled_texture(&entry.texture);
+        }
+    }
+
+    /// Destroy the sampled textures handed back by removed elements, along
+    /// with the cached bind groups that sample them.
+    pub(crate) fn release_removed_sampled_textures(&mut self) {
+        for id in self.scene.node_arena.take_released_sampled_textures() {
+            if let Some(entry) = self.frame.sampled_texture_cache.remove(&id) {
+                self.destroy_sampled_texture(&entry.texture);
+            }
         }
     }
 
+    /// Drop the cached bind groups built from `texture`'s views, then
+    /// destroy it, so no cached bind group refers to a destroyed texture.
+    fn destroy_sampled_texture(&mut self, texture: &wgpu::Texture) {
+        self.evict_bind_groups_for_texture(texture);
+        texture.destroy();
+    }
+
+    fn evict_bind_groups_for_texture(&mut self, texture: &wgpu::Texture) {
+        self.frame
+            .bind_group_cache
+            .retain(|key, _| !key.references_texture(texture));
+    }
+
     fn total_sampled_texture_bytes(&self) -> u64 {
         self.frame
             .sampled_texture_cache
@@ -1905,7 +1956,7 @@ impl Viewport {
                 .collect::<Vec<_>>();
             for key in &stale_keys {
                 if let Some(entry) = self.frame.sampled_texture_cache.remove(key) {
-                    entry.texture.destroy();
+                    self.destroy_sampled_texture(&entry.texture);
                     total_bytes = total_bytes.saturating_sub(entry.byte_size);
                 }
             }
@@ -1924,7 +1975,7 @@ impl Viewport {
                 break;
             }
             if let Some(entry) = self.frame.sampled_texture_cache.remove(&key) {
-                entry.texture.destroy();
+                self.destroy_sampled_texture(&entry.texture);
                 total_bytes = total_bytes.saturating_sub(entry.byte_size);
             }
         }
@@ -1958,6 +2009,9 @@ impl Viewport {
                     usage: desc.usage,
                 },
             ) {
+                self.frame
+                    .bind_group_cache
+                    .retain(|key, _| !key.references_buffer(&old.buffer));
                 old.buffer.destroy();
             }
         }
@@ -2086,6 +2140,7 @@ impl Viewport {
                     size: required_size,
                     last_used_frame: self.frame.frame_number,
                     bind_groups: FxHashMap::default(),
+                    slots: RetainedUniformSlots::default(),
                 });
         } else if self.frame.draw_rect_uniform_pool[target_index].size < required_size {
             // Buffer reallocated — invalidate all cached bind groups for this slot.
@@ -2101,6 +2156,7 @@ impl Viewport {
                     size: required_size,
                     last_used_frame: self.frame.frame_number,
                     bind_groups: FxHashMap::default(),
+                    slots: RetainedUniformSlots::default(),
                 },
             );
             old.buffer.destroy();
@@ -2110,6 +2166,17 @@ impl Viewport {
         let buffer = self.frame.draw_rect_uniform_pool[target_index]
             .buffer
             .clone();
+        // An unchanged pass lands on the slot it used last frame, which still
+        // holds its bytes; skip the upload and keep the cached bind group.
+        let content_hash = RetainedUniformSlots::content_hash(data, slot_size);
+        if self.frame.draw_rect_uniform_pool[target_index]
+            .slots
+            .holds(dynamic_offset, content_hash)
+        {
+            self.frame.render_reuse.rect_uniforms_reused += 1;
+            self.frame.draw_rect_uniform_offset = dynamic_offset.saturating_add(slot_size);
+            return Some((buffer, dynamic_offset as u32, target_index));
+        }
         #[cfg(target_arch = "wasm32")]
         {
             let queue = self.gpu.queue.as_ref()?;
@@ -2130,6 +2197,10 @@ impl Viewport {
             mapped.slice(..data.len()).copy_from_slice(data);
             drop(mapped);
         }
+        self.frame.draw_rect_uniform_pool[target_index]
+            .slots
+            .record_write(dynamic_offset, content_hash);
+        self.frame.render_reuse.rect_uniforms_written += 1;
         self.frame.draw_rect_uniform_offset = self
             .frame
             .draw_rect_uniform_offset
@@ -2262,8 +2333,10 @@ impl Viewport {
     ) -> Option<wgpu::BindGroup> {
         let entry = self.frame.draw_rect_uniform_pool.get(pool_index)?;
         if let Some(bg) = entry.bind_groups.get(&layout_cache_key) {
+            self.frame.render_reuse.bind_groups_reused += 1;
             return Some(bg.clone());
         }
+        self.frame.render_reuse.bind_groups_created += 1;
         // Ensure the gradient stops buffer exists so binding 1 can resolve.
         self.ensure_gradient_stops_buffer();
         let stops_buffer = self.frame.gradient_stops_buffer.as_ref()?.buffer.clone();
@@ -2300,7 +2373,36 @@ impl Viewport {
         Some(bg)
     }
 
+    /// Return the bind group cached under `key`, creating it with `create`
+    /// on a miss. The key holds the layout and every bound resource, so a
+    /// hit binds exactly what `create` would; idle entries go with the
+    /// other frame pools, and released textures and buffers take theirs
+    /// along.
+    pub(crate) fn get_or_create_bind_group(
+        &mut self,
+        key: crate::view::render_pass::BindGroupCacheKey,
+        create: impl FnOnce() -> wgpu::BindGroup,
+    ) -> wgpu::BindGroup {
+        let frame_number = self.frame.frame_number;
+        if let Some(entry) = self.frame.bind_group_cache.get_mut(&key) {
+            entry.last_used_frame = frame_number;
+            self.frame.render_reuse.bind_groups_reused += 1;
+            return entry.bind_group.clone();
+        }
+        self.frame.render_reuse.bind_groups_created += 1;
+        let bind_group = create();
+        self.frame.bind_group_cache.insert(
+            key,
+            CachedBindGroupEntry {
+                bind_group: bind_group.clone(),
+                last_used_frame: frame_number,
+            },
+        );
+        bind_group
+    }
+
     pub fn release_render_resource_caches(&mut self) {
+        self.frame.bind_group_cache.clear();
         self.invalidate_root_effect_retained();
         self.invalidate_retained_surfaces();
         crate::view::render_pass::draw_rect_pass::clear_draw_rect_resources_cache();
@@ -2308,6 +2410,8 @@ impl Viewport {
         crate::view::render_pass::text_pass::clear_text_resources_cache();
         crate::view::render_pass::blur_module::clear_blur_resources_cache();
         crate::view::render_pass::composite_layer_pass::clear_composite_layer_resources_cache();
+        crate::view::render_pass::nine_patch_pass::clear_nine_patch_resources_cache();
+        crate::view::render_pass::composite_layer_pass::clear_layer_effect_resources_cache();
         crate::view::render_pass::texture_composite_pass::clear_texture_composite_resources_cache(
             self.render_resource_scope_id(),
         );
@@ -2350,6 +2454,9 @@ mod persistent_pair_witness_tests {
     }
 }
 
+#[cfg(all(test, not(target_arch = "wasm32")))]
+mod sampled_texture_release_tests;
+
 #[cfg(all(test, not(target_arch = "wasm32")))]
 mod sampled_texture_tests {
     use super::Viewport;
@@ -2599,4 +2706,77 @@ mod sampled_texture_tests {
         assert_eq!(texture_composite_resources_cache_len(), baseline);
         Ok(())
     }
+
+    #[test]
+    #[ignore = "requires a native GPU adapter"]
+    fn bind_groups_are_reused_across_frames_until_idle() -> Result<(), String> {
+        let (_instance, device, queue) = request_gpu()?;
+        let mut viewport = Viewport::new();
+        attach_gpu(&mut viewport, &device, &queue);
+        let layout = device.create_bind_group_layout(&wgpu::BindGroupLayoutDescriptor {
+            label: Some("bind group cache test layout"),
+            entries: &[],
+        });
+        let key = crate::view::render_pass::BindGroupCacheKey::new("Test", &layout);
+        let create = || {
+            device.create_bind_group(&wgpu::BindGroupDescriptor {
+                label: Some("bind group cache test"),
+                layout: &layout,
+                entries: &[],
+            })
+        };
+
+        let first = viewport.get_or_create_bind_group(key.clone(), create);
+        viewport.frame.frame_number += 1;
+        let second = viewport.get_or_create_bind_group(key, || panic!("cached bind group"));
+        assert_eq!(first, second);
+        assert_eq!(viewport.frame.render_reuse.bind_groups_created, 1);
+        assert_eq!(viewport.frame.render_reuse.bind_groups_reused, 1);
+
+        viewport.frame.frame_number += 200;
+        viewport.reclaim_idle_frame_gpu_pools();
+        assert!(viewport.frame.bind_group_cache.is_empty());
+        Ok(())
+    }
+
+    #[test]
+    #[ignore = "requires a native GPU adapter"]
+    fn bind_groups_leave_with_the_sampled_texture_they_bind() -> Result<(), String> {
+        let (_instance, device, queue) = request_gpu()?;
+        let mut viewport = Viewport::new();
+        attach_gpu(&mut viewport, &device, &queue);
+        let id = SampledTextureId::Image(ImageAssetId::for_test(91));
+        assert!(viewport.ensure_sampled_texture(&upload(id, 1)));
+        let view = viewport.sampled_texture_view(id).unwrap();
+        let layout = device.create_bind_group_layout(&wgpu::BindGroupLayoutDescriptor {
+            label: Some("bind group eviction test layout"),
+            entries: &[wgpu::BindGroupLayoutEntry {
+                binding: 0,
+                visibility: wgpu::ShaderStages::FRAGMENT,
+                ty: wgpu::BindingType::Texture {
+                    sample_type: wgpu::TextureSampleType::Float { filterable: true },
+                    view_dimension: wgpu::TextureViewDimension::D2,
+                    multisampled: false,
+                },
+                count: None,
+            }],
+        });
+        let key = crate::view::render_pass::BindGroupCacheKey::new("Test", &layout).view(&view);
+        assert!(key.references_texture(view.texture()));
+        viewport.get_or_create_bind_group(key, || {
+            device.create_bind_group(&wgpu::BindGroupDescriptor {
+                label: Some("bind group eviction test"),
+                layout: &layout,
+                entries: &[wgpu::BindGroupEntry {
+                    binding: 0,
+                    resource: wgpu::BindingResource::TextureView(&view),
+                }],
+            })
+        });
+        assert_eq!(viewport.frame.bind_group_cache.len(), 1);
+
+        viewport.evict_sampled_texture_for_test(id);
+        assert!(viewport.frame.bind_group_cache.is_empty());
+        Ok(())
+    }
 }
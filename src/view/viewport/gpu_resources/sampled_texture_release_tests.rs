@@ -0,0 +1,57 @@
+use crate::style::Length;
+use crate::ui::{RsxNode, rsx};
+use crate::view::sampled_texture::SampledTextureId;
+use crate::view::viewport::Viewport;
+use crate::view::{Element as HostElement, Image as HostImage, ImageSource};
+
+fn tree(show_image: bool) -> RsxNode {
+    let pixels: std::sync::Arc<[u8]> = [255_u8; 16].into();
+    let image = show_image.then(|| {
+        rsx! {
+            <HostImage
+                source={ImageSource::Rgba { width: 2, height: 2, pixels }}
+                style={{ width: Length::px(20.0), height: Length::px(20.0) }}
+            />
+        }
+    });
+    rsx! {
+        <HostElement style={{ width: Length::px(40.0), height: Length::px(40.0) }}>
+            {image}
+        </HostElement>
+    }
+}
+
+#[test]
+#[ignore = "requires a native GPU adapter"]
+fn removing_an_image_releases_its_sampled_texture_on_the_next_frame() -> Result<(), String> {
+    let mut viewport = Viewport::new();
+    viewport.set_size(40, 40);
+    pollster::block_on(viewport.attach_offscreen())?;
+    viewport.render_rsx(&tree(true))?;
+    let image_ids = viewport
+        .frame
+        .sampled_texture_cache
+        .keys()
+        .copied()
+        .filter(|id| matches!(id, SampledTextureId::Image(_)))
+        .collect::<Vec<_>>();
+    let [id] = image_ids[..] else {
+        return Err(format!("expected one image texture, found {image_ids:?}"));
+    };
+    let texture = viewport.frame.sampled_texture_cache[&id].texture.clone();
+
+    // The commit queues the release; the frame it renders applies it.
+    viewport.render_rsx(&tree(false))?;
+    assert!(!viewport.frame.sampled_texture_cache.contains_key(&id));
+    assert!(
+        viewport
+            .frame
+            .bind_group_cache
+            .keys()
+            .all(|key| !key.references_texture(&texture))
+    );
+
+    viewport.render_rsx(&tree(false))?;
+    assert!(!viewport.frame.sampled_texture_cache.contains_key(&id));
+    Ok(())
+}
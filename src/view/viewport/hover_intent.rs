@@ -0,0 +1,251 @@
+//! Hover intent: per-element enter/exit delays and a safe triangle toward
+//! an anchored popup, applied on top of the hit-tested hover chain.
+//!
+//! The hit test still decides which chain sits under the pointer; this
+//! module only decides when elements that opted in gain or lose hover, so
+//! menus and tooltips don't flicker when the pointer briefly crosses other
+//! elements on its way somewhere.
+
+use super::*;
+use crate::view::base_component::Element;
+use crate::view::node_arena::{NodeArena, NodeKey};
+
+/// Hover-intent configuration for one element.
+///
+/// The element gains hover (`:hover` styles, `PointerEnter`) only once the
+/// pointer has stayed on it for `enter_delay_ms`, and keeps it for
+/// `exit_delay_ms` after the pointer left. With `safe_triangle` it also
+/// keeps hover while the pointer stays inside the area spanned by the point
+/// it left at and any popup anchored to it — by its `anchor` name, or as a
+/// child positioned against `Anchor::Parent`.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Hash)]
+pub struct HoverIntent {
+    pub enter_delay_ms: u32,
+    pub exit_delay_ms: u32,
+    pub safe_triangle: bool,
+}
+
+impl HoverIntent {
+    pub const fn new() -> Self {
+        Self {
+            enter_delay_ms: 0,
+            exit_delay_ms: 0,
+            safe_triangle: false,
+        }
+    }
+
+    pub const fn enter_delay(mut self, enter_delay_ms: u32) -> Self {
+        self.enter_delay_ms = enter_delay_ms;
+        self
+    }
+
+    pub const fn exit_delay(mut self, exit_delay_ms: u32) -> Self {
+        self.exit_delay_ms = exit_delay_ms;
+        self
+    }
+
+    pub const fn safe_triangle(mut self, safe_triangle: bool) -> Self {
+        self.safe_triangle = safe_triangle;
+        self
+    }
+}
+
+#[derive(Debug, Clone)]
+enum PendingHover {
+    /// Under the pointer, waiting for its enter delay.
+    Enter { due: Instant },
+    /// No longer under the pointer but still hovered until `due`, or while
+    /// the pointer stays in the safe area from `origin` to `popups`.
+    Exit {
+        due: Instant,
+        origin: Option<(f32, f32)>,
+        popups: Vec<[f32; 4]>,
+    },
+}
+
+impl PendingHover {
+    fn due(&self) -> Instant {
+        match self {
+            Self::Enter { due } | Self::Exit { due, .. } => *due,
+        }
+    }
+}
+
+/// Elements whose hover change is being held back by their [`HoverIntent`].
+#[derive(Debug, Clone, Default)]
+pub(super) struct HoverIntentState {
+    pending: FxHashMap<NodeKey, PendingHover>,
+}
+
+impl HoverIntentState {
+    /// Turn the hit-tested root-to-target chain `raw_path` into the nodes
+    /// that carry hover at `now`, given the nodes that carried it before.
+    /// Nodes without a hover intent follow `raw_path` exactly.
+    pub(super) fn resolve(
+        &mut self,
+        arena: &NodeArena,
+        root_keys: &[NodeKey],
+        previous_path: &[NodeKey],
+        raw_path: &[NodeKey],
+        pointer: Option<(f32, f32)>,
+        now: Instant,
+    ) -> Vec<NodeKey> {
+        let mut next_path = Vec::with_capacity(raw_path.len());
+        for &key in previous_path {
+            if raw_path.contains(&key) {
+                continue;
+            }
+            let Some(intent) = hover_intent_of(arena, key) else {
+                self.pending.remove(&key);
+                continue;
+            };
+            let pending = match self.pending.remove(&key) {
+                Some(pending @ PendingHover::Exit { .. }) => pending,
+                _ => PendingHover::Exit {
+                    due: now + Duration::from_millis(intent.exit_delay_ms.into()),
+                    origin: pointer,
+                    popups: if intent.safe_triangle {
+                        anchored_popup_rects(arena, root_keys, key)
+                    } else {
+                        Vec::new()
+                    },
+                },
+            };
+            let PendingHover::Exit {
+                due,
+                origin,
+                popups,
+            } = &pending
+            else {
+                continue;
+            };
+            let in_safe_area = match (*origin, pointer) {
+                (Some(origin), Some(point)) => popups
+                    .iter()
+                    .any(|&popup| point_in_safe_area(origin, popup, point)),
+                _ => false,
+            };
+            if now < *due || in_safe_area {
+                next_path.push(key);
+                self.pending.insert(key, pending);
+            }
+        }
+        for &key in raw_path {
+            if previous_path.contains(&key) {
+                self.pending.remove(&key);
+                next_path.push(key);
+                continue;
+            }
+            let enter_delay = hover_intent_of(arena, key)
+                .map_or(0, |intent| intent.enter_delay_ms)
+                .into();
+            let due = match self.pending.get(&key) {
+                Some(PendingHover::Enter { due }) => *due,
+                _ => now + Duration::from_millis(enter_delay),
+            };
+            if due <= now {
+                self.pending.remove(&key);
+                next_path.push(key);
+            } else {
+                self.pending.insert(key, PendingHover::Enter { due });
+            }
+        }
+        // An enter the pointer left before its delay ran out never happens.
+        self.pending.retain(|key, pending| {
+            matches!(pending, PendingHover::Exit { .. }) || raw_path.contains(key)
+        });
+        next_path
+    }
+
+    /// Earliest instant at which a held-back hover change becomes due.
+    pub(super) fn next_deadline(&self) -> Option<Instant> {
+        self.pending.values().map(PendingHover::due).min()
+    }
+
+    /// Whether a held-back hover change is due at `now`.
+    pub(super) fn has_due(&self, now: Instant) -> bool {
+        self.pending.values().any(|pending| pending.due() <= now)
+    }
+
+    pub(super) fn clear(&mut self) {
+        self.pending.clear();
+    }
+}
+
+fn hover_intent_of(arena: &NodeArena, key: NodeKey) -> Option<HoverIntent> {
+    arena.get(key)?.element.hover_intent()
+}
+
+/// Boxes of the elements positioned against `trigger`: by its `anchor`
+/// name anywhere in the tree, or as its children anchored to their parent.
+fn anchored_popup_rects(
+    arena: &NodeArena,
+    root_keys: &[NodeKey],
+    trigger: NodeKey,
+) -> Vec<[f32; 4]> {
+    let Some(node) = arena.get(trigger) else {
+        return Vec::new();
+    };
+    let anchor_name = node
+        .element
+        .as_any()
+        .downcast_ref::<Element>()
+        .and_then(|element| element.anchor_name().cloned());
+    drop(node);
+
+    let mut rects = Vec::new();
+    let mut stack = root_keys.to_vec();
+    while let Some(key) = stack.pop() {
+        let Some(node) = arena.get(key) else {
+            continue;
+        };
+        let anchored = node
+            .element
+            .as_any()
+            .downcast_ref::<Element>()
+            .and_then(|element| element.computed_style().position.anchor_ref())
+            .is_some_and(|anchor| match anchor {
+                crate::style::Anchor::Name(name) => anchor_name.as_ref() == Some(name),
+                crate::style::Anchor::Parent => node.parent == Some(trigger),
+                _ => false,
+            });
+        if anchored {
+            let snapshot = node.element.box_model_snapshot();
+            if snapshot.width > 0.0 && snapshot.height > 0.0 {
+                rects.push([snapshot.x, snapshot.y, snapshot.width, snapshot.height]);
+            }
+        }
+        stack.extend(node.children().iter().copied());
+    }
+    rects
+}
+
+/// Whether `point` lies in the convex hull of `origin` and the `rect`
+/// (`[x, y, width, height]`): inside the rect, or inside one of the
+/// triangles fanned from `origin` over its edges.
+pub(super) fn point_in_safe_area(origin: (f32, f32), rect: [f32; 4], point: (f32, f32)) -> bool {
+    let [x, y, width, height] = rect;
+    if point.0 >= x && point.0 <= x + width && point.1 >= y && point.1 <= y + height {
+        return true;
+    }
+    let corners = [
+        (x, y),
+        (x + width, y),
+        (x + width, y + height),
+        (x, y + height),
+    ];
+    (0..corners.len())
+        .any(|i| point_in_triangle(point, origin, corners[i], corners[(i + 1) % corners.len()]))
+}
+
+fn point_in_triangle(p: (f32, f32), a: (f32, f32), b: (f32, f32), c: (f32, f32)) -> bool {
+    fn cross(o: (f32, f32), a: (f32, f32), b: (f32, f32)) -> f32 {
+        (a.0 - o.0) * (b.1 - o.1) - (a.1 - o.1) * (b.0 - o.0)
+    }
+    let d1 = cross(a, b, p);
+    let d2 = cross(b, c, p);
+    let d3 = cross(c, a, p);
+    let has_negative = d1 < 0.0 || d2 < 0.0 || d3 < 0.0;
+    let has_positive = d1 > 0.0 || d2 > 0.0 || d3 > 0.0;
+    !(has_negative && has_positive)
+}
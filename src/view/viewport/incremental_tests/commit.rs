@@ -50,3 +50,4 @@ mod reorder_tests;
 mod prop_setter_tests;
 mod fragment_tests;
 mod fallback_tests;
+mod component_scope_tests;
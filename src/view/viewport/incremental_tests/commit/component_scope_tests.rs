@@ -0,0 +1,52 @@
+use super::*;
+use crate::ui::{State, component, use_state};
+use crate::view::Text as HostText;
+use std::cell::RefCell;
+
+thread_local! {
+    static COUNTERS: RefCell<Vec<State<u32>>> = const { RefCell::new(Vec::new()) };
+}
+
+#[component]
+fn Counter(label: String) -> RsxNode {
+    let count = use_state(|| 0u32);
+    COUNTERS.with(|counters| counters.borrow_mut().push(count.clone()));
+    rsx! {
+        <HostElement>
+            <HostText>{format!("{label}={}", count.get())}</HostText>
+        </HostElement>
+    }
+}
+
+fn tree() -> RsxNode {
+    COUNTERS.with(|counters| counters.borrow_mut().clear());
+    rsx! {
+        <HostElement>
+            <Counter label="a" />
+            <Counter label="b" />
+        </HostElement>
+    }
+}
+
+/// A state change in one component patches only that component's slice of
+/// the element tree; its sibling keeps every node.
+#[test]
+fn state_change_patches_only_the_owning_component_subtree() {
+    let mut viewport = Viewport::new();
+    viewport.set_use_incremental_commit(true);
+    viewport.render_rsx(&tree()).expect("cold render");
+    let root = viewport.scene.ui_root_keys[0];
+    let arena = &viewport.scene.node_arena;
+    let rows_before = arena.children_of(root);
+    let sibling_text = find_text_node(arena, root, "b=0").expect("sibling text");
+
+    COUNTERS.with(|counters| counters.borrow()[0].set(1));
+    let _ = crate::ui::take_state_dirty();
+    viewport.render_rsx(&tree()).expect("scoped re-render");
+
+    assert_eq!(viewport.scene.ui_root_keys[0], root);
+    let arena = &viewport.scene.node_arena;
+    assert_eq!(arena.children_of(root), rows_before);
+    assert_eq!(find_text_node(arena, root, "b=0"), Some(sibling_text));
+    assert!(find_text_node(arena, root, "a=1").is_some());
+}
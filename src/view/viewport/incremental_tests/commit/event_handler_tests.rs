@@ -106,3 +106,73 @@ fn incremental_commit_removes_event_handler_prop_clears_handler_list() {
         "removed handler prop must clear the handler list",
     );
 }
+
+/// Re-rendering swaps in a fresh `on_reach_end` closure (handler props
+/// compare by pointer), which must not re-arm the once-per-extent guard:
+/// staying at the same end fires the callback once, not once per render.
+#[test]
+fn incremental_commit_replacing_reach_end_handler_does_not_refire_at_same_extent() {
+    use std::cell::Cell;
+    use std::rc::Rc;
+
+    let fired = Rc::new(Cell::new(0));
+    let tree = |fired: &Rc<Cell<u32>>| {
+        let counter = fired.clone();
+        rsx! {
+            <HostElement
+                style={{
+                    width: Length::px(100.0),
+                    height: Length::px(100.0),
+                    scroll_direction: ScrollDirection::Vertical,
+                }}
+                reach_end_threshold={20.0}
+                on_reach_end={crate::ui::on_reach_end(move |_| counter.set(counter.get() + 1))}
+            >
+                <HostElement style={{ width: Length::px(100.0), height: Length::px(300.0) }} />
+            </HostElement>
+        }
+    };
+    let tick = |viewport: &mut Viewport| {
+        run_layout_for_test(viewport, 100.0, 100.0);
+        let roots = viewport.scene.ui_root_keys.clone();
+        crate::view::base_component::tick_post_layout_animation_frames(
+            &mut viewport.scene.node_arena,
+            &roots,
+            crate::time::Instant::now(),
+        );
+    };
+
+    let mut viewport = Viewport::new();
+    viewport.set_use_incremental_commit(true);
+    viewport.set_size(100, 100);
+    viewport.render_rsx(&tree(&fired)).expect("cold render");
+    tick(&mut viewport);
+    let root_key = viewport.scene.ui_root_keys[0];
+    let root_id = viewport
+        .scene
+        .node_arena
+        .get(root_key)
+        .expect("root")
+        .element
+        .stable_id();
+    assert!(crate::view::viewport::dispatch::set_scroll_offset_by_id(
+        &viewport.scene.node_arena,
+        root_key,
+        root_id,
+        (0.0, 200.0),
+    ));
+    tick(&mut viewport);
+    assert_eq!(fired.get(), 1, "scrolling to the end fires once");
+
+    viewport
+        .render_rsx(&tree(&fired))
+        .expect("re-render with a fresh on_reach_end closure");
+    assert_eq!(viewport.scene.ui_root_keys, vec![root_key]);
+    tick(&mut viewport);
+    tick(&mut viewport);
+    assert_eq!(
+        fired.get(),
+        1,
+        "a replaced handler must not fire again for the same extent"
+    );
+}
@@ -101,6 +101,88 @@ fn keyed_row_internal_shape_change_plus_reorder_does_not_duplicate() {
     );
 }
 
+/// `key` on `<Element>` / `<Text>` hosts: reordering keyed host siblings
+/// moves their arena nodes, so each label keeps the element instance (and
+/// the scroll offset / selection state it owns) it had before the move.
+#[test]
+fn keyed_host_siblings_keep_their_node_keys_across_reorder() {
+    use crate::view::Text as HostText;
+
+    fn tree(labels: &[&'static str]) -> RsxNode {
+        rsx! {
+            <HostElement>
+                {labels.iter().map(|label| rsx! {
+                    <HostElement key={*label}>
+                        <HostText key={*label}>{*label}</HostText>
+                    </HostElement>
+                }).collect::<Vec<_>>()}
+            </HostElement>
+        }
+    }
+
+    let mut viewport = Viewport::new();
+    viewport.set_use_incremental_commit(true);
+    viewport
+        .render_rsx(&tree(&["a", "b", "c"]))
+        .expect("cold render");
+    let root = viewport.scene.ui_root_keys[0];
+    let arena = &viewport.scene.node_arena;
+    let before = ["a", "b", "c"].map(|label| find_text_node(arena, root, label).unwrap());
+    let rows_before = arena.children_of(root);
+
+    viewport
+        .render_rsx(&tree(&["c", "a", "b"]))
+        .expect("keyed reorder");
+    assert_eq!(viewport.scene.ui_root_keys[0], root);
+    let arena = &viewport.scene.node_arena;
+    let after = ["a", "b", "c"].map(|label| find_text_node(arena, root, label).unwrap());
+    assert_eq!(after, before, "keyed <Text> nodes must survive the reorder");
+    assert_eq!(
+        arena.children_of(root),
+        vec![rows_before[2], rows_before[0], rows_before[1]],
+        "keyed <Element> rows must move rather than be rebuilt",
+    );
+}
+
+/// A keyed row that moves and changes its text in the same render is
+/// moved and patched in place: neither the row nor its text node is
+/// rebuilt.
+#[test]
+fn moved_keyed_subtree_is_patched_without_rebuilding() {
+    use crate::view::Text as HostText;
+
+    fn tree(rows: &[(&'static str, &str)]) -> RsxNode {
+        rsx! {
+            <HostElement>
+                {rows.iter().map(|&(key, label)| rsx! {
+                    <HostElement key={key}>
+                        <HostText>{label.to_string()}</HostText>
+                    </HostElement>
+                }).collect::<Vec<_>>()}
+            </HostElement>
+        }
+    }
+
+    let mut viewport = Viewport::new();
+    viewport.set_use_incremental_commit(true);
+    viewport
+        .render_rsx(&tree(&[("a", "a"), ("b", "b"), ("c", "c")]))
+        .expect("cold render");
+    let root = viewport.scene.ui_root_keys[0];
+    let rows_before = viewport.scene.node_arena.children_of(root);
+    let text_before = find_text_node(&viewport.scene.node_arena, root, "c").unwrap();
+
+    viewport
+        .render_rsx(&tree(&[("c", "c!"), ("a", "a"), ("b", "b")]))
+        .expect("move + text change");
+    let arena = &viewport.scene.node_arena;
+    assert_eq!(
+        arena.children_of(root),
+        vec![rows_before[2], rows_before[0], rows_before[1]]
+    );
+    assert_eq!(find_text_node(arena, root, "c!"), Some(text_before));
+}
+
 // ---------------------------------------------------------------------------
 // M4 #1: non-additive replace_style
 // ---------------------------------------------------------------------------
@@ -163,3 +245,88 @@ fn incremental_commit_inserts_appended_child_preserves_sibling_keys() {
 // M6 boundary: text-cascading style updates must fall back when
 // descendants exist
 // ---------------------------------------------------------------------------
+
+/// Keyed reorder of rows that declare a `position` transition must start
+/// from the rows' previous rects (the FLIP "invert" step) instead of
+/// snapping to the new slots, and queue a visual track back to zero.
+#[test]
+fn keyed_reorder_with_position_transition_starts_from_previous_rects() {
+    fn tree(order: &[&'static str]) -> RsxNode {
+        rsx! {
+            <HostElement style={{ layout: Layout::flow().column().no_wrap() }}>
+                {order
+                    .iter()
+                    .map(|label| rsx! {
+                        <HostElement
+                            key={*label}
+                            style={{
+                                width: Length::px(80.0),
+                                height: Length::px(20.0),
+                                transition: [Transition::new(TransitionProperty::Position, 200)],
+                            }}
+                        />
+                    })
+                    .collect::<Vec<_>>()}
+            </HostElement>
+        }
+    }
+
+    fn row_positions(viewport: &Viewport) -> Vec<(u64, f32)> {
+        let arena = &viewport.scene.node_arena;
+        arena
+            .children_of(viewport.scene.ui_root_keys[0])
+            .iter()
+            .map(|&key| {
+                let node = arena.get(key).expect("row exists");
+                (
+                    node.element.stable_id(),
+                    node.element.box_model_snapshot().y,
+                )
+            })
+            .collect()
+    }
+
+    let mut viewport = Viewport::new();
+    viewport.set_use_incremental_commit(true);
+    viewport
+        .render_rsx(&tree(&["a", "b", "c"]))
+        .expect("cold render");
+    run_layout_for_test(&mut viewport, 100.0, 100.0);
+    let before = row_positions(&viewport);
+    assert_eq!(
+        before.iter().map(|(_, y)| *y).collect::<Vec<_>>(),
+        vec![0.0, 20.0, 40.0]
+    );
+
+    viewport
+        .render_rsx(&tree(&["c", "a", "b"]))
+        .expect("keyed reorder render");
+    run_layout_for_test(&mut viewport, 100.0, 100.0);
+    let after = row_positions(&viewport);
+    assert_eq!(after[0].0, before[2].0, "keyed rows keep their identity");
+    for (id, y) in &after {
+        let previous = before
+            .iter()
+            .find(|(before_id, _)| before_id == id)
+            .map(|(_, y)| *y)
+            .expect("row existed before reorder");
+        assert!(
+            (y - previous).abs() < 0.01,
+            "row {id:#x} should start from its previous rect ({previous}), got {y}",
+        );
+    }
+
+    let arena = &viewport.scene.node_arena;
+    let moved = arena.children_of(viewport.scene.ui_root_keys[0])[0];
+    let requests = arena
+        .get_mut(moved)
+        .expect("moved row")
+        .element
+        .take_visual_transition_requests();
+    let request = requests
+        .iter()
+        .find(|request| request.field == crate::transition::VisualField::Y)
+        .expect("moved row should queue a y visual track");
+    assert!((request.from - 40.0).abs() < 0.01);
+    assert_eq!(request.to, 0.0);
+}
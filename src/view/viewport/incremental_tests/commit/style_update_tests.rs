@@ -259,3 +259,279 @@ fn incremental_commit_reset_element_style_restores_inherited_text_base() {
         wrapper_key
     );
 }
+
+/// Children of a `transition_group` host that start tracks in the same
+/// frame are delayed by their rank times the stagger, and the host's
+/// `on_transition_group_end` fires once after the last one settles.
+#[test]
+fn transition_group_staggers_children_and_reports_group_end() {
+    use crate::transition::TransitionGroup;
+    use crate::ui::TransitionGroupEndHandlerProp;
+    use std::cell::RefCell;
+    use std::rc::Rc;
+
+    let ended = Rc::new(RefCell::new(Vec::new()));
+    let tree = |opacity: f32| {
+        let ended = ended.clone();
+        rsx! {
+            <HostElement
+                transition_group={TransitionGroup::new(50)}
+                on_transition_group_end={TransitionGroupEndHandlerProp::new(move |event| {
+                    ended.borrow_mut().push(event.member_count);
+                })}
+            >
+                {(0..3)
+                    .map(|_| rsx! {
+                        <HostElement
+                            style={{
+                                width: Length::px(40.0),
+                                height: Length::px(20.0),
+                                opacity: opacity,
+                                transition: [Transition::new(TransitionProperty::Opacity, 100)],
+                            }}
+                        />
+                    })
+                    .collect::<Vec<_>>()}
+            </HostElement>
+        }
+    };
+    fn child_opacities(viewport: &Viewport) -> Vec<f32> {
+        let arena = &viewport.scene.node_arena;
+        arena
+            .children_of(viewport.scene.ui_root_keys[0])
+            .iter()
+            .map(|&key| {
+                arena
+                    .get(key)
+                    .expect("child exists")
+                    .element
+                    .as_any()
+                    .downcast_ref::<crate::view::base_component::Element>()
+                    .expect("Element child")
+                    .opacity()
+            })
+            .collect()
+    }
+
+    let mut viewport = Viewport::new();
+    viewport.set_use_incremental_commit(true);
+    viewport.render_rsx(&tree(0.0)).expect("cold render");
+    run_layout_for_test(&mut viewport, 200.0, 100.0);
+    viewport.run_post_layout_transitions(0.0, 0.0);
+
+    viewport.render_rsx(&tree(1.0)).expect("opacity render");
+    run_layout_for_test(&mut viewport, 200.0, 100.0);
+    viewport.run_post_layout_transitions(0.0, 1.0);
+    viewport.run_post_layout_transitions(0.075, 1.075);
+    let mid = child_opacities(&viewport);
+    assert!(
+        mid[0] > mid[1] && mid[1] > 0.0,
+        "first child should lead the second, got {mid:?}",
+    );
+    assert_eq!(mid[2], 0.0, "third child is still inside its 100ms stagger");
+    assert!(ended.borrow().is_empty(), "group has not settled yet");
+
+    viewport.run_post_layout_transitions(0.5, 1.5);
+    viewport.run_post_layout_transitions(0.016, 1.516);
+    assert_eq!(child_opacities(&viewport), vec![1.0, 1.0, 1.0]);
+    assert_eq!(
+        *ended.borrow(),
+        vec![3],
+        "group end fires once for the whole wave"
+    );
+}
+
+/// `on_transition_start` / `on_transition_cancel` / `on_transition_end`
+/// report each track's lifecycle: a retarget cancels the running track
+/// before starting the next, and `end` fires once it reaches its target.
+#[test]
+fn transition_lifecycle_callbacks_report_start_cancel_and_end() {
+    use crate::ui::TransitionHandlerProp;
+    use std::cell::RefCell;
+    use std::rc::Rc;
+
+    let log = Rc::new(RefCell::new(Vec::new()));
+    let tree = |opacity: f32| {
+        let record = |phase: &'static str| {
+            let log = log.clone();
+            TransitionHandlerProp::new(move |event| {
+                log.borrow_mut().push((phase, event.property));
+            })
+        };
+        rsx! {
+            <HostElement
+                style={{
+                    width: Length::px(40.0),
+                    height: Length::px(20.0),
+                    opacity: opacity,
+                    transition: [Transition::new(TransitionProperty::Opacity, 100)],
+                }}
+                on_transition_start={record("start")}
+                on_transition_end={record("end")}
+                on_transition_cancel={record("cancel")}
+            />
+        }
+    };
+
+    let mut viewport = Viewport::new();
+    viewport.set_use_incremental_commit(true);
+    viewport.render_rsx(&tree(0.0)).expect("cold render");
+    run_layout_for_test(&mut viewport, 200.0, 100.0);
+    viewport.run_post_layout_transitions(0.0, 0.0);
+    log.borrow_mut().clear();
+
+    viewport.render_rsx(&tree(1.0)).expect("fade-in render");
+    run_layout_for_test(&mut viewport, 200.0, 100.0);
+    viewport.run_post_layout_transitions(0.0, 1.0);
+    viewport.run_post_layout_transitions(0.05, 1.05);
+    assert_eq!(*log.borrow(), vec![("start", TransitionProperty::Opacity)]);
+
+    viewport.render_rsx(&tree(0.2)).expect("retarget render");
+    run_layout_for_test(&mut viewport, 200.0, 100.0);
+    viewport.run_post_layout_transitions(0.0, 1.05);
+    viewport.run_post_layout_transitions(0.5, 1.55);
+    assert_eq!(
+        *log.borrow(),
+        vec![
+            ("start", TransitionProperty::Opacity),
+            ("cancel", TransitionProperty::Opacity),
+            ("start", TransitionProperty::Opacity),
+            ("end", TransitionProperty::Opacity),
+        ],
+    );
+}
+
+/// Pausing freezes transition timelines, the playback rate scales them,
+/// and `finish_transitions` jumps the rest of the way on the next tick.
+#[test]
+fn transition_playback_pause_rate_and_finish() {
+    let tree = |opacity: f32| {
+        rsx! {
+            <HostElement
+                style={{
+                    width: Length::px(40.0),
+                    height: Length::px(20.0),
+                    opacity: opacity,
+                    transition: [Transition::new(TransitionProperty::Opacity, 100)],
+                }}
+            />
+        }
+    };
+    fn root_opacity(viewport: &Viewport) -> f32 {
+        viewport
+            .scene
+            .node_arena
+            .get(viewport.scene.ui_root_keys[0])
+            .expect("root exists")
+            .element
+            .as_any()
+            .downcast_ref::<crate::view::base_component::Element>()
+            .expect("Element root")
+            .opacity()
+    }
+
+    let mut viewport = Viewport::new();
+    viewport.set_use_incremental_commit(true);
+    viewport.render_rsx(&tree(0.0)).expect("cold render");
+    run_layout_for_test(&mut viewport, 200.0, 100.0);
+    viewport.run_post_layout_transitions(0.0, 0.0);
+
+    viewport.render_rsx(&tree(1.0)).expect("fade-in render");
+    run_layout_for_test(&mut viewport, 200.0, 100.0);
+    viewport.run_post_layout_transitions(0.0, 1.0);
+    viewport.pause_transitions();
+    viewport.run_post_layout_transitions(0.5, 1.5);
+    assert_eq!(root_opacity(&viewport), 0.0, "paused timeline stays put");
+
+    viewport.resume_transitions();
+    viewport.set_transition_playback_rate(0.5);
+    viewport.run_post_layout_transitions(0.1, 1.6);
+    let slowed = root_opacity(&viewport);
+    assert!(
+        (slowed - 0.5).abs() < 0.01,
+        "100ms at half speed covers half the 100ms track, got {slowed}",
+    );
+
+    viewport.finish_transitions();
+    viewport.run_post_layout_transitions(0.0, 1.6);
+    assert_eq!(root_opacity(&viewport), 1.0);
+}
+
+#[test]
+fn padding_and_gap_transitions_relayout_children_each_frame() {
+    let tree = |spacing: f32| {
+        rsx! {
+            <HostElement
+                style={{
+                    layout: Layout::flow().row().no_wrap(),
+                    width: Length::px(200.0),
+                    height: Length::px(40.0),
+                    padding: Padding::uniform(Length::px(spacing)),
+                    gap: Length::px(spacing),
+                    transition: [
+                        Transition::new(TransitionProperty::Padding, 100),
+                        Transition::new(TransitionProperty::Gap, 100),
+                    ],
+                }}
+            >
+                <HostElement style={{ width: Length::px(10.0), height: Length::px(10.0) }} />
+                <HostElement style={{ width: Length::px(10.0), height: Length::px(10.0) }} />
+            </HostElement>
+        }
+    };
+    fn child_x(viewport: &Viewport, index: usize) -> f32 {
+        let root = viewport
+            .scene
+            .node_arena
+            .get(viewport.scene.ui_root_keys[0])
+            .expect("root exists");
+        let child_key = root.children[index];
+        drop(root);
+        viewport
+            .scene
+            .node_arena
+            .get(child_key)
+            .expect("child exists")
+            .element
+            .box_model_snapshot()
+            .x
+    }
+
+    let mut viewport = Viewport::new();
+    viewport.set_use_incremental_commit(true);
+    viewport.render_rsx(&tree(0.0)).expect("cold render");
+    run_layout_for_test(&mut viewport, 200.0, 100.0);
+    viewport.run_post_layout_transitions(0.0, 0.0);
+    assert_eq!((child_x(&viewport, 0), child_x(&viewport, 1)), (0.0, 10.0));
+
+    viewport.render_rsx(&tree(20.0)).expect("spacing render");
+    run_layout_for_test(&mut viewport, 200.0, 100.0);
+    let started = viewport.run_post_layout_transitions(0.0, 1.0);
+    assert!(
+        started.relayout_required,
+        "spacing samples need a layout pass"
+    );
+    run_layout_for_test(&mut viewport, 200.0, 100.0);
+    assert_eq!(
+        (child_x(&viewport, 0), child_x(&viewport, 1)),
+        (0.0, 10.0),
+        "the first frame holds the previous spacing",
+    );
+
+    viewport.run_post_layout_transitions(0.05, 1.05);
+    run_layout_for_test(&mut viewport, 200.0, 100.0);
+    let (first, second) = (child_x(&viewport, 0), child_x(&viewport, 1));
+    assert!(
+        first > 0.0 && first < 20.0,
+        "padding mid-flight, got {first}"
+    );
+    assert!(
+        second - first > 10.0 && second - first < 30.0,
+        "gap mid-flight, got {}",
+        second - first,
+    );
+
+    viewport.run_post_layout_transitions(0.1, 1.2);
+    run_layout_for_test(&mut viewport, 200.0, 100.0);
+    assert_eq!((child_x(&viewport, 0), child_x(&viewport, 1)), (20.0, 50.0));
+}
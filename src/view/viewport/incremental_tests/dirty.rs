@@ -621,3 +621,84 @@ fn layout_pass_clears_consumed_arena_layout_place_and_box_dirty() {
         "paint remains dirty until the render graph consumes it"
     );
 }
+
+#[test]
+fn media_style_blocks_restyle_when_viewport_crosses_breakpoint() {
+    let mut viewport = Viewport::new();
+    viewport.set_size(640, 480);
+    let tree = rsx! {
+        <HostElement style={{
+            width: "100%",
+            height: Length::px(20.0),
+            media(min_width: 800.0): { width: "50%" },
+        }} />
+    };
+    viewport.render_rsx(&tree).expect("cold render");
+    run_layout_for_test(&mut viewport, 640.0, 480.0);
+    let root_key = viewport.scene.ui_root_keys[0];
+    let root_width = |viewport: &Viewport| {
+        viewport
+            .scene
+            .node_arena
+            .get(root_key)
+            .expect("root exists")
+            .element
+            .box_model_snapshot()
+            .width
+    };
+    assert_eq!(root_width(&viewport), 640.0);
+
+    viewport.set_size(720, 480);
+    run_layout_for_test(&mut viewport, 720.0, 480.0);
+    assert_eq!(root_width(&viewport), 720.0);
+
+    viewport.set_size(1000, 480);
+    run_layout_for_test(&mut viewport, 1000.0, 480.0);
+    assert_eq!(root_width(&viewport), 500.0);
+
+    viewport.set_size(640, 480);
+    run_layout_for_test(&mut viewport, 640.0, 480.0);
+    assert_eq!(root_width(&viewport), 640.0);
+}
+
+#[test]
+fn elevated_elements_restyle_when_a_new_elevation_scale_is_published() {
+    use crate::style::{BoxShadow, ElevationPreset, ElevationScale};
+
+    let mut viewport = Viewport::new();
+    viewport.set_size(200, 100);
+    let tree = rsx! {
+        <HostElement style={{ width: Length::px(40.0), height: Length::px(20.0), elevation: 1 }} />
+    };
+    viewport.render_rsx(&tree).expect("cold render");
+    let root_key = viewport.scene.ui_root_keys[0];
+    let shadows = |viewport: &Viewport| {
+        let node = viewport
+            .scene
+            .node_arena
+            .get(root_key)
+            .expect("root exists");
+        let element = node
+            .element
+            .as_any()
+            .downcast_ref::<crate::view::base_component::Element>()
+            .expect("host element");
+        element.computed_style().box_shadow.clone()
+    };
+    assert_eq!(
+        shadows(&viewport),
+        ElevationScale::default().level(1).box_shadows()
+    );
+
+    let key = BoxShadow::new()
+        .color(Color::rgba(0, 0, 0, 90))
+        .offset_y(3.0);
+    ElevationScale::set_current(ElevationScale::new([
+        ElevationPreset::flat(),
+        ElevationPreset::new(BoxShadow::new().color(Color::transparent()), key),
+    ]));
+    viewport.sync_elevation_scale();
+    assert_eq!(shadows(&viewport), vec![key]);
+
+    ElevationScale::set_current(ElevationScale::default());
+}
@@ -273,6 +273,21 @@ fn projection_caret_probe_at(cursor_char: usize) {
     );
 }
 
+mod app_region_tests;
+mod coordinate_space_tests;
+mod frame_pacing_tests;
+mod global_listener_tests;
 mod hit_test_tests;
+mod hover_intent_tests;
+mod ime_tests;
+mod long_press_tests;
+mod menu_tests;
+mod notification_tests;
+mod prevent_default_tests;
 mod rerender_hit_test_tests;
+mod spatial_navigation_tests;
+mod sound_tests;
 mod projection_text_area_tests;
+mod synthetic_event_tests;
+mod visibility_tests;
+mod wheel_scroll_tests;
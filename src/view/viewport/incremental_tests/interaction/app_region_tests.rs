@@ -0,0 +1,86 @@
+use super::*;
+use crate::platform::WindowCommand;
+use crate::style::AppRegion;
+use crate::view::viewport::{PointerButton, TitleBarDoubleClick};
+
+/// 32px drag title bar holding a no-drag button, above a plain body.
+fn title_bar_tree() -> RsxNode {
+    rsx! {
+        <HostElement style={{
+            width: Length::px(200.0),
+            height: Length::px(120.0),
+            layout: Layout::flow().column().no_wrap(),
+        }}>
+            <HostElement style={{
+                width: Length::px(200.0),
+                height: Length::px(32.0),
+                app_region: AppRegion::Drag,
+                layout: Layout::flow().row().no_wrap(),
+            }}>
+                <HostElement style={{
+                    width: Length::px(40.0),
+                    height: Length::px(32.0),
+                    app_region: AppRegion::NoDrag,
+                }} />
+                <HostElement style={{ width: Length::px(40.0), height: Length::px(32.0) }} />
+            </HostElement>
+            <HostElement style={{ width: Length::px(200.0), height: Length::px(88.0) }} />
+        </HostElement>
+    }
+}
+
+fn title_bar_viewport() -> Viewport {
+    let mut viewport = Viewport::new();
+    viewport.set_size(200, 120);
+    viewport
+        .render_rsx(&title_bar_tree())
+        .expect("render title bar tree");
+    run_layout_for_test(&mut viewport, 200.0, 120.0);
+    viewport
+}
+
+fn press_at(viewport: &mut Viewport, x: f32, y: f32) -> Vec<WindowCommand> {
+    viewport.set_pointer_position_viewport(x, y);
+    viewport.dispatch_pointer_down_event(PointerButton::Left);
+    viewport.dispatch_pointer_up_event(PointerButton::Left);
+    viewport.drain_platform_requests().window_commands
+}
+
+#[test]
+fn press_on_drag_region_descendant_starts_window_drag() {
+    let mut viewport = title_bar_viewport();
+
+    assert_eq!(
+        press_at(&mut viewport, 60.0, 16.0),
+        vec![WindowCommand::DragWindow],
+        "an auto child inherits the title bar's drag region",
+    );
+    assert_eq!(
+        press_at(&mut viewport, 20.0, 16.0),
+        Vec::new(),
+        "no-drag button is carved out of the title bar",
+    );
+    assert_eq!(press_at(&mut viewport, 100.0, 80.0), Vec::new());
+}
+
+#[test]
+fn double_press_on_drag_region_runs_title_bar_action() {
+    let mut viewport = title_bar_viewport();
+
+    assert_eq!(
+        press_at(&mut viewport, 120.0, 16.0),
+        vec![WindowCommand::DragWindow]
+    );
+    assert_eq!(
+        press_at(&mut viewport, 120.0, 16.0),
+        vec![WindowCommand::ToggleMaximize]
+    );
+    assert_eq!(
+        press_at(&mut viewport, 120.0, 16.0),
+        vec![WindowCommand::DragWindow],
+        "a third press starts a new gesture",
+    );
+
+    viewport.set_title_bar_double_click(TitleBarDoubleClick::None);
+    assert_eq!(press_at(&mut viewport, 120.0, 16.0), Vec::new());
+}
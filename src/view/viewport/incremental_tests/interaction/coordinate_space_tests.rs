@@ -0,0 +1,110 @@
+use super::*;
+use crate::style::{Transform, Translate};
+use crate::ui::{CoordinateSpace, Rect};
+
+#[test]
+fn points_convert_through_scroll_offsets_and_ancestor_transforms() {
+    let tree = rsx! {
+        <HostElement style={{
+            width: Length::px(100.0),
+            height: Length::px(100.0),
+            scroll_direction: ScrollDirection::Vertical,
+        }}>
+            <HostElement style={{
+                width: Length::px(100.0),
+                height: Length::px(300.0),
+                transform: Transform::new([Translate::xy(Length::px(5.0), Length::px(0.0))]),
+            }}>
+                <HostElement style={{
+                    position: Position::absolute().left(Length::px(10.0)).top(Length::px(50.0)),
+                    width: Length::px(20.0),
+                    height: Length::px(20.0),
+                }} />
+            </HostElement>
+        </HostElement>
+    };
+    let mut viewport = Viewport::new();
+    viewport.set_size(100, 100);
+    viewport.render_rsx(&tree).expect("render coordinate tree");
+    run_layout_for_test(&mut viewport, 100.0, 100.0);
+    let root_key = viewport.scene.ui_root_keys[0];
+    let root_id = viewport
+        .scene
+        .node_arena
+        .get(root_key)
+        .expect("root")
+        .element
+        .stable_id();
+    assert!(crate::view::viewport::dispatch::set_scroll_offset_by_id(
+        &viewport.scene.node_arena,
+        root_key,
+        root_id,
+        (0.0, 30.0),
+    ));
+    run_layout_for_test(&mut viewport, 100.0, 100.0);
+    let child = viewport
+        .scene
+        .node_arena
+        .get(root_key)
+        .expect("root")
+        .children()[0];
+    let grandchild = viewport
+        .scene
+        .node_arena
+        .get(child)
+        .expect("child")
+        .children()[0];
+
+    let convert = |node, point, from, to| {
+        viewport
+            .convert_point(node, point, from, to)
+            .expect("node is in the tree")
+    };
+    assert_eq!(
+        convert(
+            grandchild,
+            (0.0, 0.0),
+            CoordinateSpace::Local,
+            CoordinateSpace::Viewport
+        ),
+        (15.0, 20.0),
+        "scrolled up by 30 and translated by the parent",
+    );
+    assert_eq!(
+        convert(
+            grandchild,
+            (15.0, 20.0),
+            CoordinateSpace::Viewport,
+            CoordinateSpace::Local
+        ),
+        (0.0, 0.0),
+    );
+    assert_eq!(
+        convert(
+            grandchild,
+            (0.0, 0.0),
+            CoordinateSpace::Local,
+            CoordinateSpace::Parent
+        ),
+        (10.0, 50.0),
+    );
+    assert_eq!(
+        convert(
+            child,
+            (0.0, 0.0),
+            CoordinateSpace::Local,
+            CoordinateSpace::Parent
+        ),
+        (5.0, 0.0),
+        "parent space is the root's scrolled content space",
+    );
+    assert_eq!(
+        viewport.convert_rect(
+            grandchild,
+            Rect::new(0.0, 0.0, 20.0, 20.0),
+            CoordinateSpace::Local,
+            CoordinateSpace::Viewport,
+        ),
+        Some(Rect::new(15.0, 20.0, 20.0, 20.0)),
+    );
+}
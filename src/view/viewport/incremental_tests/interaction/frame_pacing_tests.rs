@@ -0,0 +1,64 @@
+use super::*;
+use crate::time::{Duration, Instant};
+use crate::ui::take_state_dirty;
+use crate::view::viewport::{FrameSchedule, RedrawMode};
+
+fn idle_viewport() -> Viewport {
+    let viewport = Viewport::new();
+    let _ = take_state_dirty();
+    viewport
+}
+
+#[test]
+fn on_demand_viewport_sleeps_until_invalidated() {
+    let mut viewport = idle_viewport();
+    let now = Instant::now();
+
+    assert!(!viewport.needs_frame());
+    assert_eq!(viewport.frame_schedule(now, false), FrameSchedule::Idle);
+    assert_eq!(viewport.frame_schedule(now, true), FrameSchedule::Now);
+
+    viewport.request_redraw();
+    assert!(viewport.needs_frame());
+    assert_eq!(viewport.frame_schedule(now, false), FrameSchedule::Now);
+}
+
+#[test]
+fn continuous_mode_always_schedules_a_frame() {
+    let mut viewport = idle_viewport();
+    viewport.set_redraw_mode(RedrawMode::Continuous);
+
+    assert_eq!(viewport.redraw_mode(), RedrawMode::Continuous);
+    assert_eq!(
+        viewport.frame_schedule(Instant::now(), false),
+        FrameSchedule::Now
+    );
+}
+
+#[test]
+fn fps_cap_holds_frames_until_the_interval_elapses() {
+    let mut viewport = idle_viewport();
+    viewport.set_max_fps(Some(50.0));
+    let frame_start = Instant::now();
+    viewport.frame_pacer.record_frame(frame_start);
+
+    let due = frame_start + Duration::from_millis(20);
+    assert_eq!(
+        viewport.frame_schedule(frame_start + Duration::from_millis(5), true),
+        FrameSchedule::At(due)
+    );
+    assert_eq!(viewport.frame_schedule(due, true), FrameSchedule::Now);
+    assert_eq!(
+        viewport.frame_schedule(frame_start + Duration::from_millis(5), false),
+        FrameSchedule::Idle
+    );
+}
+
+#[test]
+fn invalid_fps_caps_are_treated_as_uncapped() {
+    let mut viewport = idle_viewport();
+    for cap in [0.0, -30.0, f32::NAN, f32::INFINITY] {
+        viewport.set_max_fps(Some(cap));
+        assert_eq!(viewport.max_fps(), None);
+    }
+}
@@ -0,0 +1,103 @@
+use super::*;
+use crate::platform::{Key, Modifiers, PlatformKeyEvent};
+use crate::ui::on_pointer_down;
+use crate::view::viewport::PointerButton;
+use std::cell::RefCell;
+use std::rc::Rc;
+
+fn key(key: Key, pressed: bool) -> PlatformKeyEvent {
+    PlatformKeyEvent {
+        key,
+        characters: None,
+        modifiers: Modifiers::empty(),
+        repeat: false,
+        is_composing: false,
+        pressed,
+        timestamp: crate::time::Instant::now(),
+    }
+}
+
+/// A 300x300 viewport with a 200x200 root whose top-left 100x100 child
+/// handles pointer downs.
+fn global_listener_viewport() -> Viewport {
+    let tree = rsx! {
+        <HostElement style={{ width: Length::px(200.0), height: Length::px(200.0) }}>
+            <HostElement
+                style={{ width: Length::px(100.0), height: Length::px(100.0) }}
+                on_pointer_down={on_pointer_down(|_| {})}
+            />
+        </HostElement>
+    };
+    let mut viewport = Viewport::new();
+    viewport.set_size(300, 300);
+    viewport
+        .render_rsx(&tree)
+        .expect("render global listener tree");
+    run_layout_for_test(&mut viewport, 300.0, 300.0);
+    viewport
+}
+
+#[test]
+fn global_key_listeners_run_without_a_focused_element() {
+    let mut viewport = global_listener_viewport();
+    let log = Rc::new(RefCell::new(Vec::new()));
+    let down_log = log.clone();
+    viewport.on_global_key_down(move |event, _| {
+        down_log
+            .borrow_mut()
+            .push(format!("down {:?} {}", event.key.key, event.handled));
+    });
+    let up_log = log.clone();
+    viewport.on_global_key_up(move |event, _| {
+        up_log.borrow_mut().push(format!("up {:?}", event.key.key));
+    });
+
+    assert!(!viewport.dispatch_platform_key_event(&key(Key::Escape, true)));
+    viewport.dispatch_platform_key_event(&key(Key::Escape, false));
+    assert_eq!(*log.borrow(), vec!["down Escape false", "up Escape"]);
+}
+
+#[test]
+fn global_pointer_listeners_see_every_target_after_element_dispatch() {
+    let mut viewport = global_listener_viewport();
+    let log = Rc::new(RefCell::new(Vec::new()));
+    let down_log = log.clone();
+    viewport.on_global_pointer_down(move |event, _| {
+        down_log.borrow_mut().push((
+            "down",
+            event.pointer.viewport_x,
+            event.pointer.button,
+            event.handled,
+        ));
+    });
+    let move_log = log.clone();
+    let move_id = viewport.on_global_pointer_move(move |event, _| {
+        move_log
+            .borrow_mut()
+            .push(("move", event.pointer.viewport_x, None, event.handled));
+    });
+
+    viewport.set_pointer_position_viewport(50.0, 50.0);
+    viewport.dispatch_pointer_down_event(PointerButton::Left);
+    viewport.dispatch_pointer_up_event(PointerButton::Left);
+    viewport.set_pointer_position_viewport(250.0, 250.0);
+    viewport.dispatch_pointer_move_event();
+    viewport.set_pointer_position_viewport(260.0, 260.0);
+    viewport.dispatch_pointer_move_event();
+    viewport.dispatch_pointer_down_event(PointerButton::Right);
+    assert_eq!(
+        *log.borrow(),
+        vec![
+            ("down", 50.0, Some(PointerButton::Left), true),
+            ("move", 250.0, None, false),
+            ("move", 260.0, None, false),
+            ("down", 260.0, Some(PointerButton::Right), false),
+        ]
+    );
+
+    assert!(viewport.remove_global_listener(move_id));
+    assert!(!viewport.remove_global_listener(move_id));
+    viewport.set_pointer_position_viewport(270.0, 270.0);
+    viewport.dispatch_pointer_move_event();
+    assert_eq!(log.borrow().len(), 4, "removed listeners stop running");
+}
@@ -36,6 +36,64 @@ fn cursor_resolves_pointer_from_hovered_text_child_ancestor() {
     );
 }
 
+#[test]
+fn user_agent_style_supplies_text_cursor_and_scrollbar_defaults() {
+    use crate::style::{FontSize, ScrollbarStyle, UserAgentStyle};
+    use crate::view::base_component::{Element, Text};
+
+    let mut viewport = Viewport::new();
+    viewport.set_size(160, 80);
+    viewport.set_user_agent_style(UserAgentStyle {
+        root: Style::new().with_font_size(FontSize::px(20.0)),
+        interactive_cursor: Cursor::Pointer,
+        scrollbar: ScrollbarStyle::new().width(10.0),
+    });
+    let tree = rsx! {
+        <HostElement style={{ width: Length::px(160.0), height: Length::px(80.0) }}>
+            <HostElement
+                style={{ width: Length::px(160.0), height: Length::px(40.0) }}
+                on_click={crate::ui::on_click(|_| {})}
+            >
+                {"Press"}
+            </HostElement>
+            <HostElement style={{
+                width: Length::px(160.0),
+                height: Length::px(40.0),
+                scroll_direction: ScrollDirection::Vertical,
+            }} />
+        </HostElement>
+    };
+    viewport.render_rsx(&tree).expect("render user agent tree");
+    run_layout_for_test(&mut viewport, 160.0, 80.0);
+
+    let arena = &viewport.scene.node_arena;
+    let root_key = viewport.scene.ui_root_keys[0];
+    let children = arena.children_of(root_key);
+    let text_key = find_text_node(arena, root_key, "Press").expect("text child");
+    let font_size = arena.get(text_key).and_then(|node| {
+        node.element
+            .as_any()
+            .downcast_ref::<Text>()
+            .map(Text::font_size)
+    });
+    assert_eq!(font_size, Some(20.0));
+
+    viewport.input_state.hovered_node_id = Some(text_key);
+    assert_eq!(viewport.resolve_cursor(), Cursor::Pointer);
+    viewport.input_state.hovered_node_id = Some(children[1]);
+    assert_eq!(viewport.resolve_cursor(), Cursor::Default);
+
+    let scrollbar_width = viewport.scene.node_arena.get(children[1]).and_then(|node| {
+        node.element
+            .as_any()
+            .downcast_ref::<Element>()
+            .map(|element| element.computed_style().scrollbar.resolved_width())
+    });
+    assert_eq!(scrollbar_width, Some(10.0));
+
+    UserAgentStyle::set_current(UserAgentStyle::default());
+}
+
 #[test]
 fn pointer_move_cursor_respects_root_stacking_over_anchor_parent_resize_handle() {
     let mut viewport = Viewport::new();
@@ -231,3 +289,108 @@ fn placement_only_position_update_moves_anchor_parent_resize_handle_hit_test() {
     );
     assert_eq!(viewport.resolve_cursor(), Cursor::EwResize);
 }
+
+#[test]
+fn pointer_events_none_subtree_lets_hits_through_to_content_below() {
+    let clicks = std::rc::Rc::new(std::cell::Cell::new(0));
+    let click_count = clicks.clone();
+    let tree = rsx! {
+        <HostElement style={{ width: Length::px(100.0), height: Length::px(100.0) }}>
+            <HostElement
+                style={{ width: Length::px(100.0), height: Length::px(100.0) }}
+                on_click={crate::ui::on_click(move |_| click_count.set(click_count.get() + 1))}
+            />
+            <HostElement
+                style={{
+                    position: Position::absolute().left(Length::px(0.0)).top(Length::px(0.0)),
+                    width: Length::px(100.0),
+                    height: Length::px(100.0),
+                    pointer_events: crate::style::PointerEvents::None,
+                }}
+            >
+                <HostElement style={{ width: Length::px(20.0), height: Length::px(20.0) }} />
+            </HostElement>
+        </HostElement>
+    };
+    let mut viewport = Viewport::new();
+    viewport.set_size(100, 100);
+    viewport.render_rsx(&tree).expect("render overlay tree");
+    run_layout_for_test(&mut viewport, 100.0, 100.0);
+
+    let root_key = viewport.scene.ui_root_keys[0];
+    let content_key = viewport.scene.node_arena.children_of(root_key)[0];
+    for (x, y) in [(10.0, 10.0), (60.0, 60.0)] {
+        assert_eq!(
+            crate::view::base_component::hit_test(&viewport.scene.node_arena, root_key, x, y),
+            Some(content_key),
+            "overlay and its children are skipped at ({x}, {y})",
+        );
+    }
+
+    viewport.set_pointer_position_viewport(10.0, 10.0);
+    viewport.dispatch_pointer_down_event(crate::view::viewport::PointerButton::Left);
+    viewport.dispatch_pointer_up_event(crate::view::viewport::PointerButton::Left);
+    viewport.dispatch_click_event(crate::view::viewport::PointerButton::Left);
+    assert_eq!(clicks.get(), 1);
+}
+
+#[test]
+fn hit_shapes_let_points_outside_the_shape_fall_through() {
+    use crate::style::HitShape;
+    use crate::view::{Image as HostImage, ImageFit, ImageSource};
+
+    let shape_box = |left: f32, shape: HitShape| {
+        rsx! {
+            <HostElement
+                style={{
+                    position: Position::absolute().left(Length::px(left)).top(Length::px(0.0)),
+                    width: Length::px(40.0),
+                    height: Length::px(40.0),
+                    hit_shape: shape,
+                }}
+            />
+        }
+    };
+    // Left half opaque, right half transparent.
+    let pixels: std::sync::Arc<[u8]> = [255, 0, 0, 255, 255, 0, 0, 0].into();
+    let tree = rsx! {
+        <HostElement style={{ width: Length::px(240.0), height: Length::px(40.0) }}>
+            {shape_box(0.0, HitShape::Ellipse)}
+            {shape_box(50.0, HitShape::polygon([(0.0, 0.0), (40.0, 0.0), (0.0, 40.0)]))}
+            {shape_box(100.0, HitShape::path("M0 40 L20 0 L40 40 Z").expect("path data"))}
+            <HostImage
+                source={ImageSource::Rgba { width: 2, height: 1, pixels }}
+                fit={ImageFit::Fill}
+                style={{
+                    position: Position::absolute().left(Length::px(150.0)).top(Length::px(0.0)),
+                    width: Length::px(40.0),
+                    height: Length::px(40.0),
+                    hit_shape: HitShape::Alpha(0.5),
+                }}
+            />
+        </HostElement>
+    };
+    let mut viewport = Viewport::new();
+    viewport.set_size(240, 40);
+    viewport.render_rsx(&tree).expect("render shape tree");
+    run_layout_for_test(&mut viewport, 240.0, 40.0);
+
+    let root_key = viewport.scene.ui_root_keys[0];
+    let children = viewport.scene.node_arena.children_of(root_key);
+    let hit = |x: f32, y: f32| {
+        crate::view::base_component::hit_test(&viewport.scene.node_arena, root_key, x, y)
+    };
+    let cases = [
+        (20.0, 20.0, children[0], "ellipse center"),
+        (3.0, 3.0, root_key, "ellipse corner"),
+        (55.0, 10.0, children[1], "polygon inside"),
+        (85.0, 35.0, root_key, "polygon outside hypotenuse"),
+        (120.0, 30.0, children[2], "path inside"),
+        (103.0, 5.0, root_key, "path outside"),
+        (160.0, 20.0, children[3], "opaque pixel"),
+        (180.0, 20.0, root_key, "transparent pixel"),
+    ];
+    for (x, y, expected, label) in cases {
+        assert_eq!(hit(x, y), Some(expected), "{label} at ({x}, {y})");
+    }
+}
@@ -0,0 +1,132 @@
+use super::*;
+use crate::style::Anchor;
+use crate::time::{Duration, Instant};
+use crate::ui::{on_pointer_enter, on_pointer_leave};
+use crate::view::viewport::HoverIntent;
+use std::cell::Cell;
+use std::rc::Rc;
+
+struct Counts {
+    enters: Rc<Cell<u32>>,
+    leaves: Rc<Cell<u32>>,
+}
+
+/// A 40x40 trigger at the top-left of a 200x200 root, with a 100x100 popup
+/// anchored to it at x = 100.
+fn hover_intent_viewport(intent: HoverIntent, start: Instant) -> (Viewport, Counts) {
+    let enters = Rc::new(Cell::new(0));
+    let leaves = Rc::new(Cell::new(0));
+    let enter_count = enters.clone();
+    let leave_count = leaves.clone();
+    let tree = rsx! {
+        <HostElement style={{ width: Length::px(200.0), height: Length::px(200.0) }}>
+            <HostElement
+                style={{
+                    position: Position::absolute().left(Length::px(0.0)).top(Length::px(0.0)),
+                    width: Length::px(40.0),
+                    height: Length::px(40.0),
+                }}
+                hover_intent={intent}
+                on_pointer_enter={on_pointer_enter(move |_| enter_count.set(enter_count.get() + 1))}
+                on_pointer_leave={on_pointer_leave(move |_| leave_count.set(leave_count.get() + 1))}
+            >
+                <HostElement
+                    style={{
+                        position: Position::absolute()
+                            .left(Length::px(100.0))
+                            .top(Length::px(0.0))
+                            .anchor(Anchor::Parent),
+                        width: Length::px(100.0),
+                        height: Length::px(100.0),
+                    }}
+                />
+            </HostElement>
+        </HostElement>
+    };
+    let mut viewport = Viewport::new();
+    viewport.set_size(200, 200);
+    viewport.set_frame_clock(Some(start));
+    viewport
+        .render_rsx(&tree)
+        .expect("render hover intent tree");
+    run_layout_for_test(&mut viewport, 200.0, 200.0);
+    (viewport, Counts { enters, leaves })
+}
+
+fn move_pointer(viewport: &mut Viewport, x: f32, y: f32) {
+    viewport.set_pointer_position_viewport(x, y);
+    viewport.dispatch_pointer_move_event();
+}
+
+#[test]
+fn enter_delay_holds_back_hover_until_the_pointer_rests() {
+    let start = Instant::now();
+    let (mut viewport, counts) = hover_intent_viewport(HoverIntent::new().enter_delay(200), start);
+
+    move_pointer(&mut viewport, 20.0, 20.0);
+    assert_eq!(counts.enters.get(), 0);
+    assert!(!viewport.dispatch_hover_intent_if_due(start + Duration::from_millis(150)));
+    assert!(viewport.dispatch_hover_intent_if_due(start + Duration::from_millis(200)));
+    assert_eq!(counts.enters.get(), 1);
+
+    move_pointer(&mut viewport, 60.0, 150.0);
+    assert_eq!(counts.leaves.get(), 1, "no exit delay: leave is immediate");
+
+    viewport.set_frame_clock(Some(start + Duration::from_millis(300)));
+    move_pointer(&mut viewport, 20.0, 20.0);
+    move_pointer(&mut viewport, 60.0, 150.0);
+    assert!(!viewport.dispatch_hover_intent_if_due(start + Duration::from_millis(600)));
+    assert_eq!(
+        counts.enters.get(),
+        1,
+        "passing over before the delay runs out never enters",
+    );
+}
+
+#[test]
+fn exit_delay_keeps_hover_after_the_pointer_leaves() {
+    let start = Instant::now();
+    let (mut viewport, counts) = hover_intent_viewport(HoverIntent::new().exit_delay(200), start);
+
+    move_pointer(&mut viewport, 20.0, 20.0);
+    assert_eq!(counts.enters.get(), 1, "no enter delay: enter is immediate");
+
+    move_pointer(&mut viewport, 60.0, 150.0);
+    assert_eq!(counts.leaves.get(), 0);
+    assert!(!viewport.dispatch_hover_intent_if_due(start + Duration::from_millis(150)));
+
+    move_pointer(&mut viewport, 20.0, 20.0);
+    assert!(!viewport.dispatch_hover_intent_if_due(start + Duration::from_millis(250)));
+    assert_eq!(
+        (counts.enters.get(), counts.leaves.get()),
+        (1, 0),
+        "returning within the delay keeps the hover",
+    );
+
+    move_pointer(&mut viewport, 60.0, 150.0);
+    assert!(viewport.dispatch_hover_intent_if_due(start + Duration::from_millis(200)));
+    assert_eq!(counts.leaves.get(), 1);
+}
+
+#[test]
+fn safe_triangle_keeps_hover_while_heading_for_the_anchored_popup() {
+    let start = Instant::now();
+    let (mut viewport, counts) =
+        hover_intent_viewport(HoverIntent::new().safe_triangle(true), start);
+
+    move_pointer(&mut viewport, 20.0, 20.0);
+    move_pointer(&mut viewport, 60.0, 30.0);
+    move_pointer(&mut viewport, 80.0, 20.0);
+    assert_eq!(
+        counts.leaves.get(),
+        0,
+        "inside the triangle toward the popup"
+    );
+
+    move_pointer(&mut viewport, 60.0, 150.0);
+    assert_eq!(
+        counts.leaves.get(),
+        1,
+        "leaving the triangle ends the hover"
+    );
+}
@@ -0,0 +1,204 @@
+use super::*;
+use crate::ui::{PreeditAttribute, PreeditStyle};
+use crate::view::TextArea as HostTextArea;
+use crate::view::base_component::{EventTarget as _, TextArea as TextAreaHost};
+
+fn focused_text_area(
+    binding: Binding<String>,
+    commit_preedit_on_blur: bool,
+) -> (Viewport, crate::view::node_arena::NodeKey) {
+    let mut viewport = Viewport::new();
+    viewport.set_size(240, 80);
+    viewport
+        .render_rsx(&rsx! {
+            <HostTextArea binding={binding} commit_preedit_on_blur={commit_preedit_on_blur} />
+        })
+        .expect("render TextArea");
+    run_layout_for_test(&mut viewport, 240.0, 80.0);
+    let root_key = viewport.scene.ui_root_keys[0];
+    viewport.set_focused_node_id(Some(root_key));
+    viewport.dispatch_focus_event(root_key);
+    (viewport, root_key)
+}
+
+fn with_text_area<R>(
+    viewport: &Viewport,
+    root_key: crate::view::node_arena::NodeKey,
+    f: impl FnOnce(&TextAreaHost) -> R,
+) -> R {
+    viewport
+        .scene
+        .node_arena
+        .with_element_taken_ref(root_key, |el, _| {
+            f(el.as_any()
+                .downcast_ref::<TextAreaHost>()
+                .expect("TextArea root"))
+        })
+        .expect("root")
+}
+
+#[test]
+fn blur_commits_preedit_unless_disabled() {
+    for (commit, expected) in [(true, "abzh"), (false, "ab")] {
+        let content = global_state(String::new);
+        content.binding().set(String::from("ab"));
+        let (mut viewport, root_key) = focused_text_area(content.binding(), commit);
+        viewport
+            .scene
+            .node_arena
+            .with_element_taken(root_key, |el, _| {
+                el.as_any_mut()
+                    .downcast_mut::<TextAreaHost>()
+                    .expect("TextArea root")
+                    .cursor_char = 2;
+            });
+
+        assert!(viewport.dispatch_ime_preedit_event("zh".to_string(), Some((2, 2))));
+        viewport.dispatch_blur_event(root_key);
+
+        assert_eq!(
+            with_text_area(&viewport, root_key, |text_area| text_area.content.clone()),
+            expected,
+            "commit_preedit_on_blur={commit}"
+        );
+        assert!(with_text_area(&viewport, root_key, |text_area| {
+            text_area.ime_preedit.is_empty() && text_area.ime_preedit_attributes.is_empty()
+        }));
+    }
+}
+
+#[test]
+fn preedit_attributes_are_kept_only_when_they_fit_the_preedit() {
+    let content = global_state(String::new);
+    content.binding().set(String::new());
+    let (mut viewport, root_key) = focused_text_area(content.binding(), true);
+    let attributes = vec![
+        PreeditAttribute {
+            start: 0,
+            end: 3,
+            style: PreeditStyle::Highlight,
+        },
+        PreeditAttribute {
+            start: 3,
+            end: 6,
+            style: PreeditStyle::DottedUnderline,
+        },
+        // Splits a char, dropped.
+        PreeditAttribute {
+            start: 1,
+            end: 3,
+            style: PreeditStyle::Underline,
+        },
+        // Past the end, dropped.
+        PreeditAttribute {
+            start: 6,
+            end: 9,
+            style: PreeditStyle::Underline,
+        },
+    ];
+
+    assert!(viewport.dispatch_ime_preedit_event_full(
+        "日本".to_string(),
+        Some((6, 6)),
+        None,
+        attributes.clone(),
+    ));
+
+    assert_eq!(
+        with_text_area(&viewport, root_key, |text_area| {
+            text_area.ime_preedit_attributes.clone()
+        }),
+        attributes[..2].to_vec()
+    );
+}
+
+#[test]
+fn ime_cursor_rect_follows_the_caret_after_layout() {
+    let content = global_state(String::new);
+    content.binding().set(String::from("hello"));
+    let (mut viewport, root_key) = focused_text_area(content.binding(), true);
+    viewport
+        .scene
+        .node_arena
+        .with_element_taken(root_key, |el, _| {
+            el.as_any_mut()
+                .downcast_mut::<TextAreaHost>()
+                .expect("TextArea root")
+                .cursor_char = 5;
+        });
+    viewport
+        .render_rsx(&rsx! {
+            <HostTextArea binding={content.binding()} />
+        })
+        .expect("re-render");
+    run_layout_for_test(&mut viewport, 240.0, 80.0);
+
+    let rect = viewport
+        .focused_ime_cursor_rect()
+        .expect("IME rect while focused");
+    let caret = viewport
+        .scene
+        .node_arena
+        .with_element_taken_ref(root_key, |el, arena| {
+            el.as_any()
+                .downcast_ref::<TextAreaHost>()
+                .expect("TextArea root")
+                .caret_screen_position(arena)
+        })
+        .expect("root")
+        .expect("caret");
+    assert_eq!(
+        (rect.0, rect.1, rect.3),
+        (caret.0, caret.1, caret.2.max(1.0))
+    );
+
+    viewport.dispatch_blur_event(root_key);
+    viewport.set_focused_node_id(None);
+    assert!(with_text_area(&viewport, root_key, |text_area| {
+        text_area.ime_cursor_rect().is_none()
+    }));
+}
+
+#[test]
+fn caret_selection_and_preedit_survive_a_cold_rebuild() {
+    let content = global_state(String::new);
+    content.binding().set(String::from("hello"));
+    let (mut viewport, root_key) = focused_text_area(content.binding(), true);
+    viewport
+        .scene
+        .node_arena
+        .with_element_taken(root_key, |el, _| {
+            let text_area = el
+                .as_any_mut()
+                .downcast_mut::<TextAreaHost>()
+                .expect("TextArea root");
+            text_area.selection_anchor_char = Some(1);
+            text_area.selection_focus_char = Some(3);
+            text_area.cursor_char = 3;
+        });
+    assert!(viewport.dispatch_ime_preedit_event("zh".to_string(), Some((2, 2))));
+
+    viewport.set_use_incremental_commit(false);
+    viewport
+        .render_rsx(&rsx! {
+            <HostTextArea binding={content.binding()} commit_preedit_on_blur={false} />
+        })
+        .expect("cold rebuild");
+
+    let new_key = viewport.scene.ui_root_keys[0];
+    assert_ne!(new_key, root_key, "cold rebuild mints a new instance");
+    assert_eq!(viewport.focused_node_id(), Some(new_key));
+    with_text_area(&viewport, new_key, |text_area| {
+        assert!(text_area.is_focused);
+        assert_eq!(text_area.cursor_char, 3);
+        assert_eq!(
+            (
+                text_area.selection_anchor_char,
+                text_area.selection_focus_char
+            ),
+            (Some(1), Some(3))
+        );
+        assert_eq!(text_area.ime_preedit, "zh");
+        assert_eq!(text_area.ime_preedit_cursor, Some((2, 2)));
+    });
+}
@@ -0,0 +1,86 @@
+use super::*;
+use crate::time::{Duration, Instant};
+use crate::ui::{on_click, on_long_press};
+use crate::view::viewport::PointerButton;
+use std::cell::Cell;
+use std::rc::Rc;
+
+struct Counts {
+    long_presses: Rc<Cell<u32>>,
+    clicks: Rc<Cell<u32>>,
+}
+
+/// A 100x100 target with long-press and click handlers, held for
+/// `hold_ms` before its long press fires.
+fn long_press_viewport(hold_ms: f32, start: Instant) -> (Viewport, Counts) {
+    let long_presses = Rc::new(Cell::new(0));
+    let clicks = Rc::new(Cell::new(0));
+    let long_press_count = long_presses.clone();
+    let click_count = clicks.clone();
+    let tree = rsx! {
+        <HostElement
+            style={{ width: Length::px(100.0), height: Length::px(100.0) }}
+            long_press_duration={hold_ms}
+            on_long_press={on_long_press(move |_| long_press_count.set(long_press_count.get() + 1))}
+            on_click={on_click(move |_| click_count.set(click_count.get() + 1))}
+        />
+    };
+    let mut viewport = Viewport::new();
+    viewport.set_size(100, 100);
+    viewport.set_frame_clock(Some(start));
+    viewport.render_rsx(&tree).expect("render long press tree");
+    run_layout_for_test(&mut viewport, 100.0, 100.0);
+    (
+        viewport,
+        Counts {
+            long_presses,
+            clicks,
+        },
+    )
+}
+
+fn release(viewport: &mut Viewport) {
+    viewport.dispatch_pointer_up_event(PointerButton::Left);
+    viewport.dispatch_click_event(PointerButton::Left);
+}
+
+#[test]
+fn held_press_fires_long_press_after_its_duration_and_suppresses_click() {
+    let start = Instant::now();
+    let (mut viewport, counts) = long_press_viewport(200.0, start);
+
+    viewport.set_pointer_position_viewport(50.0, 50.0);
+    viewport.dispatch_pointer_down_event(PointerButton::Left);
+    assert!(!viewport.dispatch_long_press_if_due(start + Duration::from_millis(150)));
+    assert_eq!(counts.long_presses.get(), 0);
+
+    assert!(viewport.dispatch_long_press_if_due(start + Duration::from_millis(200)));
+    assert_eq!(counts.long_presses.get(), 1);
+    assert!(
+        !viewport.dispatch_long_press_if_due(start + Duration::from_millis(400)),
+        "a press fires its long press once",
+    );
+
+    release(&mut viewport);
+    assert_eq!(counts.clicks.get(), 0, "the long press replaces the click");
+}
+
+#[test]
+fn early_release_or_travel_beyond_slop_cancels_long_press() {
+    let start = Instant::now();
+    let (mut viewport, counts) = long_press_viewport(200.0, start);
+
+    viewport.set_pointer_position_viewport(50.0, 50.0);
+    viewport.dispatch_pointer_down_event(PointerButton::Left);
+    release(&mut viewport);
+    assert!(!viewport.dispatch_long_press_if_due(start + Duration::from_millis(300)));
+    assert_eq!(counts.clicks.get(), 1, "an early release still clicks");
+
+    viewport.dispatch_pointer_down_event(PointerButton::Left);
+    viewport.set_pointer_position_viewport(52.0, 52.0);
+    viewport.dispatch_pointer_move_event();
+    viewport.set_pointer_position_viewport(70.0, 50.0);
+    viewport.dispatch_pointer_move_event();
+    assert!(!viewport.dispatch_long_press_if_due(start + Duration::from_millis(300)));
+    assert_eq!(counts.long_presses.get(), 0);
+}
@@ -0,0 +1,74 @@
+use super::*;
+use crate::platform::{
+    Accelerator, Key, Menu, MenuAction, MenuCommand, Modifiers, PlatformKeyEvent, TrayEvent,
+    TrayIcon,
+};
+use std::cell::RefCell;
+use std::rc::Rc;
+
+fn ctrl_key(key: Key) -> PlatformKeyEvent {
+    PlatformKeyEvent {
+        key,
+        characters: None,
+        modifiers: Modifiers::CTRL,
+        repeat: false,
+        is_composing: false,
+        pressed: true,
+        timestamp: crate::time::Instant::now(),
+    }
+}
+
+fn recording_action(id: &str, log: &Rc<RefCell<Vec<String>>>) -> MenuAction {
+    let log = log.clone();
+    let entry = id.to_string();
+    MenuAction::new(id, id).on_select(move |_| log.borrow_mut().push(entry.clone()))
+}
+
+#[test]
+fn menu_accelerators_and_host_activations_run_callbacks() {
+    let log = Rc::new(RefCell::new(Vec::new()));
+    let mut viewport = Viewport::new();
+    viewport.set_window_menu(Some(
+        Menu::new("").submenu(
+            Menu::new("File")
+                .action(
+                    recording_action("file.save", &log)
+                        .accelerator(Accelerator::new(Modifiers::CTRL, Key::KeyS)),
+                )
+                .action(recording_action("file.close", &log).enabled(false)),
+        ),
+    ));
+    let commands = viewport.drain_platform_requests().menu_commands;
+    assert!(matches!(
+        commands.as_slice(),
+        [MenuCommand::SetWindowMenu(Some(_))]
+    ));
+
+    assert!(viewport.dispatch_platform_key_event(&ctrl_key(Key::KeyS)));
+    assert!(viewport.dispatch_menu_event(&"file.save".into()));
+    assert!(!viewport.dispatch_menu_event(&"file.close".into()));
+    assert!(!viewport.dispatch_menu_event(&"file.missing".into()));
+    assert_eq!(*log.borrow(), vec!["file.save", "file.save"]);
+
+    viewport.set_native_menu_shortcuts(true);
+    viewport.dispatch_platform_key_event(&ctrl_key(Key::KeyS));
+    assert_eq!(log.borrow().len(), 2, "host menu owns the shortcut");
+}
+
+#[test]
+fn tray_menu_and_icon_events_dispatch() {
+    let log = Rc::new(RefCell::new(Vec::new()));
+    let mut viewport = Viewport::new();
+    assert!(!viewport.dispatch_tray_event(TrayEvent::Click));
+
+    let tray_log = log.clone();
+    viewport.set_tray_icon(Some(
+        TrayIcon::new("rfgui")
+            .menu(Menu::new("").action(recording_action("tray.quit", &log)))
+            .on_event(move |event, _| tray_log.borrow_mut().push(format!("{event:?}"))),
+    ));
+
+    assert!(viewport.dispatch_tray_event(TrayEvent::DoubleClick));
+    assert!(viewport.dispatch_menu_event(&"tray.quit".into()));
+    assert_eq!(*log.borrow(), vec!["DoubleClick", "tray.quit"]);
+}
@@ -0,0 +1,66 @@
+use super::*;
+use crate::platform::{
+    AppBadge, Notification, NotificationAction, NotificationCommand, NotificationEvent,
+};
+use std::cell::RefCell;
+use std::rc::Rc;
+
+#[test]
+fn notification_responses_run_callbacks_once() {
+    let log = Rc::new(RefCell::new(Vec::new()));
+    let mut viewport = Viewport::new();
+    let reply_log = log.clone();
+    let reply = viewport.notify(
+        "New message",
+        "Are you coming?",
+        vec![
+            NotificationAction::new("Reply")
+                .on_select(move |_| reply_log.borrow_mut().push("reply")),
+            NotificationAction::new("Mute"),
+        ],
+    );
+    let dismiss_log = log.clone();
+    let digest = viewport.post_notification(
+        Notification::new("Digest", "3 unread")
+            .on_dismiss(move |_| dismiss_log.borrow_mut().push("dismiss")),
+    );
+    let commands = viewport.drain_platform_requests().notification_commands;
+    assert!(matches!(
+        commands.as_slice(),
+        [NotificationCommand::Post(first), NotificationCommand::Post(second)]
+            if first.id == reply && first.actions.len() == 2 && second.id == digest
+    ));
+
+    assert!(viewport.dispatch_notification_event(reply, NotificationEvent::Action(0)));
+    assert!(
+        !viewport.dispatch_notification_event(reply, NotificationEvent::Action(0)),
+        "a response resolves the notification",
+    );
+    assert_eq!(*log.borrow(), vec!["reply"]);
+
+    viewport.withdraw_notification(digest);
+    assert!(!viewport.dispatch_notification_event(digest, NotificationEvent::Dismiss));
+    assert_eq!(log.borrow().len(), 1);
+    let commands = viewport.drain_platform_requests().notification_commands;
+    assert!(matches!(
+        commands.as_slice(),
+        [NotificationCommand::Withdraw(id)] if *id == digest
+    ));
+}
+
+#[test]
+fn badge_changes_reach_the_host_once() {
+    let mut viewport = Viewport::new();
+    viewport.set_badge(Some(AppBadge::Count(3)));
+    viewport.set_badge(Some(AppBadge::Count(3)));
+    assert_eq!(viewport.badge(), Some(AppBadge::Count(3)));
+    viewport.set_badge(None);
+    let commands = viewport.drain_platform_requests().notification_commands;
+    assert!(matches!(
+        commands.as_slice(),
+        [
+            NotificationCommand::SetBadge(Some(AppBadge::Count(3))),
+            NotificationCommand::SetBadge(None),
+        ]
+    ));
+}
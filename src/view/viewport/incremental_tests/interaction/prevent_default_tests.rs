@@ -0,0 +1,161 @@
+use super::*;
+use crate::platform::{Key, Modifiers, PlatformKeyEvent};
+use crate::style::ScrollbarStyle;
+use crate::ui::{on_click, on_key_down, on_paste, on_pointer_down};
+use crate::view::TextArea as HostTextArea;
+use crate::view::base_component::TextArea as TextAreaHost;
+use crate::view::viewport::PointerButton;
+use std::cell::Cell;
+use std::rc::Rc;
+
+fn key(key: Key, pressed: bool) -> PlatformKeyEvent {
+    PlatformKeyEvent {
+        key,
+        characters: None,
+        modifiers: Modifiers::empty(),
+        repeat: false,
+        is_composing: false,
+        pressed,
+        timestamp: crate::time::Instant::now(),
+    }
+}
+
+/// A 100x100 vertical scroller over 400px of content with an always
+/// visible 10px scrollbar. Returns the viewport and the click count.
+fn scrollbar_viewport(prevent: bool) -> (Viewport, Rc<Cell<u32>>) {
+    let clicks = Rc::new(Cell::new(0));
+    let click_count = clicks.clone();
+    let tree = rsx! {
+        <HostElement
+            style={{
+                width: Length::px(100.0),
+                height: Length::px(100.0),
+                scroll_direction: ScrollDirection::Vertical,
+                scrollbar: ScrollbarStyle::new().width(10.0).always_visible(),
+            }}
+            on_pointer_down={on_pointer_down(move |event| {
+                if prevent {
+                    event.meta.prevent_default();
+                }
+            })}
+            on_click={on_click(move |_| click_count.set(click_count.get() + 1))}
+        >
+            <HostElement style={{ width: Length::px(100.0), height: Length::px(400.0) }} />
+        </HostElement>
+    };
+    let mut viewport = Viewport::new();
+    viewport.set_size(100, 100);
+    viewport.render_rsx(&tree).expect("render scrollbar tree");
+    run_layout_for_test(&mut viewport, 100.0, 100.0);
+    (viewport, clicks)
+}
+
+/// Press the scrollbar thumb, drag it to `to_y`, release and click; returns
+/// the resulting vertical scroll offset.
+fn drag_scrollbar_thumb(viewport: &mut Viewport, to_y: f32) -> f32 {
+    viewport.set_pointer_position_viewport(95.0, 5.0);
+    viewport.dispatch_pointer_down_event(PointerButton::Left);
+    viewport.set_pointer_position_viewport(95.0, to_y);
+    viewport.dispatch_pointer_move_event();
+    viewport.dispatch_pointer_up_event(PointerButton::Left);
+    viewport.dispatch_click_event(PointerButton::Left);
+    let root_key = viewport.scene.ui_root_keys[0];
+    let stable_id = viewport
+        .scene
+        .node_arena
+        .get(root_key)
+        .expect("scroll root")
+        .element
+        .stable_id();
+    crate::view::viewport::dispatch::get_scroll_offset_by_id(
+        &viewport.scene.node_arena,
+        root_key,
+        stable_id,
+    )
+    .expect("scroll offset")
+    .1
+}
+
+#[test]
+fn prevent_default_on_pointer_down_keeps_the_scrollbar_from_dragging() {
+    let (mut viewport, clicks) = scrollbar_viewport(false);
+    assert!(drag_scrollbar_thumb(&mut viewport, 50.0) > 0.0);
+    let (mut viewport, clicks_in_place) = scrollbar_viewport(false);
+    drag_scrollbar_thumb(&mut viewport, 5.0);
+    assert_eq!(
+        clicks.get() + clicks_in_place.get(),
+        0,
+        "the scrollbar consumes its clicks"
+    );
+
+    let (mut viewport, clicks) = scrollbar_viewport(true);
+    assert_eq!(drag_scrollbar_thumb(&mut viewport, 50.0), 0.0);
+    assert_eq!(clicks.get(), 0, "moving past the click slop is no click");
+    drag_scrollbar_thumb(&mut viewport, 5.0);
+    assert_eq!(clicks.get(), 1, "the click reaches the element");
+}
+
+fn focused_text_area(tree: RsxNode) -> (Viewport, crate::view::node_arena::NodeKey) {
+    let mut viewport = Viewport::new();
+    viewport.set_size(240, 80);
+    viewport.render_rsx(&tree).expect("render TextArea");
+    run_layout_for_test(&mut viewport, 240.0, 80.0);
+    let root_key = viewport.scene.ui_root_keys[0];
+    viewport.set_focused_node_id(Some(root_key));
+    viewport.dispatch_focus_event(root_key);
+    (viewport, root_key)
+}
+
+fn text_area_content(viewport: &Viewport, root_key: crate::view::node_arena::NodeKey) -> String {
+    viewport
+        .scene
+        .node_arena
+        .with_element_taken_ref(root_key, |el, _| {
+            el.as_any()
+                .downcast_ref::<TextAreaHost>()
+                .expect("TextArea root")
+                .content
+                .clone()
+        })
+        .expect("root")
+}
+
+#[test]
+fn prevent_default_on_key_down_skips_text_area_editing_and_typed_text() {
+    let content = global_state(String::new);
+    let (mut viewport, root_key) = focused_text_area(rsx! {
+        <HostTextArea
+            binding={content.binding()}
+            on_key_down={on_key_down(|event| {
+                if matches!(event.key.key, Key::Tab | Key::KeyA) {
+                    event.meta.prevent_default();
+                }
+            })}
+        />
+    });
+
+    viewport.dispatch_platform_key_event(&key(Key::Tab, true));
+    viewport.dispatch_platform_key_event(&key(Key::Tab, false));
+    assert_eq!(text_area_content(&viewport, root_key), "");
+
+    viewport.dispatch_platform_key_event(&key(Key::KeyA, true));
+    assert!(!viewport.dispatch_text_input_event("a".to_string()));
+    viewport.dispatch_platform_key_event(&key(Key::KeyA, false));
+    viewport.dispatch_platform_key_event(&key(Key::KeyB, true));
+    assert!(viewport.dispatch_text_input_event("b".to_string()));
+    assert_eq!(text_area_content(&viewport, root_key), "b");
+}
+
+#[test]
+fn prevent_default_on_paste_skips_text_area_insertion() {
+    let content = global_state(String::new);
+    let (mut viewport, root_key) = focused_text_area(rsx! {
+        <HostTextArea
+            binding={content.binding()}
+            on_paste={on_paste(|event| event.meta.prevent_default())}
+        />
+    });
+
+    viewport.dispatch_paste_event("pasted".to_string());
+    assert_eq!(text_area_content(&viewport, root_key), "");
+}
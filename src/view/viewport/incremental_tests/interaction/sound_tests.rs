@@ -0,0 +1,40 @@
+use super::*;
+use crate::platform::{SoundCommand, SoundSource};
+
+fn plays(viewport: &mut Viewport) -> Vec<(String, f32)> {
+    viewport
+        .drain_platform_requests()
+        .sound_commands
+        .into_iter()
+        .filter_map(|command| match command {
+            SoundCommand::Play { name, volume } => Some((name.to_string(), volume)),
+            SoundCommand::Register { .. } => None,
+        })
+        .collect()
+}
+
+#[test]
+fn registered_sounds_play_at_master_volume_unless_muted() {
+    let mut viewport = Viewport::new();
+    viewport.register_sound("click", SoundSource::Path("click.wav".into()));
+    assert!(matches!(
+        viewport.drain_platform_requests().sound_commands.as_slice(),
+        [SoundCommand::Register { name, .. }] if name == "click"
+    ));
+
+    assert!(viewport.play_sound("click"));
+    assert!(!viewport.play_sound("missing"));
+    viewport.set_sound_volume(1.5);
+    crate::ui::use_viewport().play_sound("click");
+    assert_eq!(
+        plays(&mut viewport),
+        vec![("click".to_string(), 1.0), ("click".to_string(), 1.0)],
+        "queued handler plays reach the host on the next drain",
+    );
+
+    viewport.set_sound_volume(0.25);
+    viewport.play_sound("click");
+    viewport.set_sound_muted(true);
+    assert!(!viewport.play_sound("click"));
+    assert_eq!(plays(&mut viewport), vec![("click".to_string(), 0.25)]);
+}
@@ -0,0 +1,115 @@
+use super::*;
+use crate::platform::{Key, Modifiers, PlatformKeyEvent};
+use crate::ui::{FocusReason, RsxNode, on_focus, on_pointer_down};
+use crate::view::viewport::PointerButton;
+use std::cell::RefCell;
+use std::rc::Rc;
+
+type FocusLog = Rc<RefCell<Vec<(&'static str, FocusReason)>>>;
+
+fn key(key: Key, modifiers: Modifiers, pressed: bool) -> PlatformKeyEvent {
+    PlatformKeyEvent {
+        key,
+        characters: None,
+        modifiers,
+        repeat: false,
+        is_composing: false,
+        pressed,
+        timestamp: crate::time::Instant::now(),
+    }
+}
+
+fn press(viewport: &mut Viewport, code: Key, modifiers: Modifiers) {
+    viewport.dispatch_platform_key_event(&key(code, modifiers, true));
+    viewport.dispatch_platform_key_event(&key(code, modifiers, false));
+}
+
+fn tile(name: &'static str, left: f32, top: f32, log: &FocusLog) -> RsxNode {
+    let log = log.clone();
+    rsx! {
+        <HostElement
+            style={{
+                position: Position::absolute().left(Length::px(left)).top(Length::px(top)),
+                width: Length::px(80.0),
+                height: Length::px(80.0),
+            }}
+            on_pointer_down={on_pointer_down(|_| {})}
+            on_focus={on_focus(move |event| log.borrow_mut().push((name, event.reason)))}
+        />
+    }
+}
+
+/// Tiles `a`, `b`, `c` in a row with `d` below `b`, inside a 300px wide
+/// spatial-navigation container; `e` sits right of the container.
+fn grid_viewport(log: &FocusLog) -> Viewport {
+    let tree = rsx! {
+        <HostElement style={{ width: Length::px(400.0), height: Length::px(200.0) }}>
+            <HostElement
+                style={{
+                    position: Position::absolute().left(Length::px(0.0)).top(Length::px(0.0)),
+                    width: Length::px(300.0),
+                    height: Length::px(200.0),
+                }}
+                spatial_navigation={true}
+            >
+                {tile("a", 0.0, 0.0, log)}
+                {tile("b", 100.0, 0.0, log)}
+                {tile("c", 200.0, 0.0, log)}
+                {tile("d", 100.0, 100.0, log)}
+            </HostElement>
+            {tile("e", 320.0, 0.0, log)}
+        </HostElement>
+    };
+    let mut viewport = Viewport::new();
+    viewport.set_size(400, 200);
+    viewport.render_rsx(&tree).expect("render grid tree");
+    run_layout_for_test(&mut viewport, 400.0, 200.0);
+    viewport
+}
+
+fn last_focus(log: &FocusLog) -> Option<(&'static str, FocusReason)> {
+    log.borrow().last().copied()
+}
+
+#[test]
+fn arrow_keys_move_focus_to_the_nearest_tile_in_the_container() {
+    let log: FocusLog = Rc::default();
+    let mut viewport = grid_viewport(&log);
+    viewport.set_pointer_position_viewport(40.0, 40.0);
+    viewport.dispatch_pointer_down_event(PointerButton::Left);
+    viewport.dispatch_pointer_up_event(PointerButton::Left);
+    assert_eq!(last_focus(&log), Some(("a", FocusReason::Pointer)));
+
+    press(&mut viewport, Key::ArrowRight, Modifiers::empty());
+    assert_eq!(last_focus(&log), Some(("b", FocusReason::Keyboard)));
+    press(&mut viewport, Key::ArrowDown, Modifiers::empty());
+    assert_eq!(last_focus(&log), Some(("d", FocusReason::Keyboard)));
+    press(&mut viewport, Key::ArrowLeft, Modifiers::empty());
+    assert_eq!(last_focus(&log), Some(("a", FocusReason::Keyboard)));
+
+    press(&mut viewport, Key::ArrowRight, Modifiers::SHIFT);
+    assert_eq!(log.borrow().len(), 4, "modified arrows are left alone");
+
+    press(&mut viewport, Key::ArrowRight, Modifiers::empty());
+    press(&mut viewport, Key::ArrowRight, Modifiers::empty());
+    assert_eq!(last_focus(&log).map(|(name, _)| name), Some("c"));
+    press(&mut viewport, Key::ArrowRight, Modifiers::empty());
+    assert_eq!(
+        log.borrow().len(),
+        6,
+        "tiles outside the container are not reachable"
+    );
+}
+
+#[test]
+fn arrow_keys_do_not_navigate_outside_spatial_containers() {
+    let log: FocusLog = Rc::default();
+    let mut viewport = grid_viewport(&log);
+    viewport.set_pointer_position_viewport(360.0, 40.0);
+    viewport.dispatch_pointer_down_event(PointerButton::Left);
+    viewport.dispatch_pointer_up_event(PointerButton::Left);
+    assert_eq!(last_focus(&log), Some(("e", FocusReason::Pointer)));
+
+    press(&mut viewport, Key::ArrowLeft, Modifiers::empty());
+    assert_eq!(log.borrow().len(), 1);
+}
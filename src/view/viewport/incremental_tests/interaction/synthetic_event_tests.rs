@@ -0,0 +1,92 @@
+use super::*;
+use crate::platform::{Key, Modifiers};
+use crate::ui::{on_click, on_pointer_down};
+use crate::view::TextArea as HostTextArea;
+use crate::view::base_component::TextArea as TextAreaHost;
+use crate::view::viewport::{PointerButton, SyntheticEvent, SyntheticTarget};
+use std::cell::RefCell;
+use std::rc::Rc;
+
+#[test]
+fn synthetic_click_targets_a_node_or_a_point() {
+    let log = Rc::new(RefCell::new(Vec::new()));
+    let down_log = log.clone();
+    let click_log = log.clone();
+    let tree = rsx! {
+        <HostElement style={{ width: Length::px(200.0), height: Length::px(200.0) }}>
+            <HostElement
+                style={{
+                    position: Position::absolute().left(Length::px(100.0)).top(Length::px(100.0)),
+                    width: Length::px(50.0),
+                    height: Length::px(50.0),
+                }}
+                on_pointer_down={on_pointer_down(move |event| {
+                    down_log.borrow_mut().push(("down", event.pointer.viewport_x));
+                })}
+                on_click={on_click(move |event| {
+                    click_log.borrow_mut().push(("click", event.pointer.viewport_x));
+                })}
+            />
+        </HostElement>
+    };
+    let mut viewport = Viewport::new();
+    viewport.set_size(200, 200);
+    viewport.render_rsx(&tree).expect("render synthetic tree");
+    run_layout_for_test(&mut viewport, 200.0, 200.0);
+    let root_key = viewport.scene.ui_root_keys[0];
+    let child_key = viewport
+        .scene
+        .node_arena
+        .get(root_key)
+        .expect("root")
+        .children()[0];
+
+    assert!(viewport.dispatch(SyntheticEvent::Click {
+        at: SyntheticTarget::Node(child_key),
+        button: PointerButton::Left,
+    }));
+    assert_eq!(*log.borrow(), vec![("down", 125.0), ("click", 125.0)]);
+
+    log.borrow_mut().clear();
+    viewport.dispatch(SyntheticEvent::Click {
+        at: SyntheticTarget::Point { x: 20.0, y: 20.0 },
+        button: PointerButton::Left,
+    });
+    assert!(log.borrow().is_empty(), "the point misses the child");
+}
+
+#[test]
+fn synthetic_keys_and_text_focus_their_target_first() {
+    let content = global_state(String::new);
+    let mut viewport = Viewport::new();
+    viewport.set_size(240, 80);
+    viewport
+        .render_rsx(&rsx! { <HostTextArea binding={content.binding()} /> })
+        .expect("render TextArea");
+    run_layout_for_test(&mut viewport, 240.0, 80.0);
+    let root_key = viewport.scene.ui_root_keys[0];
+    assert_eq!(viewport.focused_node_id(), None);
+
+    assert!(viewport.dispatch(SyntheticEvent::Text {
+        target: Some(root_key),
+        text: "abc".to_string(),
+    }));
+    assert_eq!(viewport.focused_node_id(), Some(root_key));
+    assert!(viewport.dispatch(SyntheticEvent::KeyDown {
+        target: None,
+        key: Key::Backspace,
+        modifiers: Modifiers::empty(),
+    }));
+    let text = viewport
+        .scene
+        .node_arena
+        .with_element_taken_ref(root_key, |el, _| {
+            el.as_any()
+                .downcast_ref::<TextAreaHost>()
+                .expect("TextArea root")
+                .content
+                .clone()
+        })
+        .expect("root");
+    assert_eq!(text, "ab");
+}
@@ -0,0 +1,53 @@
+use super::*;
+use crate::ui::on_visibility_change;
+use std::cell::RefCell;
+use std::rc::Rc;
+
+#[test]
+fn visibility_change_fires_when_scrolling_crosses_the_threshold() {
+    let log = Rc::new(RefCell::new(Vec::new()));
+    let sink = log.clone();
+    let tree = rsx! {
+        <HostElement style={{
+            width: Length::px(100.0),
+            height: Length::px(100.0),
+            scroll_direction: ScrollDirection::Vertical,
+        }}>
+            <HostElement style={{ width: Length::px(100.0), height: Length::px(80.0) }} />
+            <HostElement
+                style={{ width: Length::px(100.0), height: Length::px(80.0) }}
+                visibility_threshold={0.5}
+                on_visibility_change={on_visibility_change(move |event| {
+                    sink.borrow_mut().push((event.visible, event.ratio));
+                })}
+            />
+        </HostElement>
+    };
+    let mut viewport = Viewport::new();
+    viewport.set_size(100, 100);
+    viewport.render_rsx(&tree).expect("render visibility tree");
+    run_layout_for_test(&mut viewport, 100.0, 100.0);
+    viewport.dispatch_visibility_changes();
+    assert_eq!(*log.borrow(), vec![(false, 0.25)], "first layout reports");
+
+    viewport.dispatch_visibility_changes();
+    assert_eq!(log.borrow().len(), 1, "no crossing, no event");
+
+    let root_key = viewport.scene.ui_root_keys[0];
+    let root_id = viewport
+        .scene
+        .node_arena
+        .get(root_key)
+        .expect("root")
+        .element
+        .stable_id();
+    assert!(crate::view::viewport::dispatch::set_scroll_offset_by_id(
+        &viewport.scene.node_arena,
+        root_key,
+        root_id,
+        (0.0, 30.0),
+    ));
+    run_layout_for_test(&mut viewport, 100.0, 100.0);
+    viewport.dispatch_visibility_changes();
+    assert_eq!(log.borrow().last().copied(), Some((true, 0.625)));
+}
@@ -0,0 +1,97 @@
+use super::*;
+use crate::platform::input::{WheelDeltaMode, WheelPhase};
+use crate::transition::ScrollAxis;
+
+fn scrollable_viewport() -> (Viewport, crate::view::node_arena::NodeKey, u64) {
+    let mut viewport = Viewport::new();
+    viewport.set_size(100, 50);
+    viewport
+        .render_rsx(&scrollable_box_model_tree())
+        .expect("render scrollable tree");
+    run_layout_for_test(&mut viewport, 100.0, 50.0);
+    viewport.set_pointer_position_viewport(50.0, 25.0);
+    let root_key = viewport.scene.ui_root_keys[0];
+    let stable_id = viewport
+        .scene
+        .node_arena
+        .get(root_key)
+        .expect("scroll root")
+        .element
+        .stable_id();
+    (viewport, root_key, stable_id)
+}
+
+fn scroll_offset(
+    viewport: &Viewport,
+    root_key: crate::view::node_arena::NodeKey,
+    stable_id: u64,
+) -> (f32, f32) {
+    crate::view::viewport::dispatch::get_scroll_offset_by_id(
+        &viewport.scene.node_arena,
+        root_key,
+        stable_id,
+    )
+    .expect("scroll offset")
+}
+
+#[test]
+fn wheel_tick_mid_animation_extends_pending_destination() {
+    let (mut viewport, root_key, stable_id) = scrollable_viewport();
+
+    assert!(viewport.dispatch_pointer_wheel_event(0.0, 12.0));
+    assert!(viewport.dispatch_pointer_wheel_event(0.0, 12.0));
+
+    assert_eq!(scroll_offset(&viewport, root_key, stable_id), (0.0, 0.0));
+    let pending = viewport
+        .transitions
+        .scroll_transition_plugin
+        .pending_scroll_target(stable_id, ScrollAxis::Y)
+        .expect("wheel ticks should animate through the scroll track");
+    assert!((pending - 24.0).abs() < 0.001);
+}
+
+#[test]
+fn phased_trackpad_gesture_applies_deltas_directly() {
+    let (mut viewport, root_key, stable_id) = scrollable_viewport();
+
+    assert!(viewport.dispatch_pointer_wheel_event(0.0, 12.0));
+    assert!(viewport.dispatch_pointer_wheel_event_full(
+        0.0,
+        10.0,
+        WheelDeltaMode::Pixel,
+        WheelPhase::Began,
+    ));
+    assert_eq!(scroll_offset(&viewport, root_key, stable_id), (0.0, 10.0));
+    assert_eq!(
+        viewport
+            .transitions
+            .scroll_transition_plugin
+            .pending_scroll_target(stable_id, ScrollAxis::Y),
+        None,
+        "a native gesture should cancel in-flight smoothing",
+    );
+
+    assert!(viewport.dispatch_pointer_wheel_event_full(
+        0.0,
+        5.0,
+        WheelDeltaMode::Pixel,
+        WheelPhase::Changed,
+    ));
+    assert_eq!(scroll_offset(&viewport, root_key, stable_id), (0.0, 15.0));
+
+    let _ = viewport.dispatch_pointer_wheel_event_full(
+        0.0,
+        0.0,
+        WheelDeltaMode::Pixel,
+        WheelPhase::Ended,
+    );
+    assert!(viewport.dispatch_pointer_wheel_event(0.0, 12.0));
+    assert_eq!(scroll_offset(&viewport, root_key, stable_id), (0.0, 15.0));
+    assert!(
+        viewport
+            .transitions
+            .scroll_transition_plugin
+            .pending_scroll_target(stable_id, ScrollAxis::Y)
+            .is_some()
+    );
+}
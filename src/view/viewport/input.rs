@@ -14,6 +14,14 @@ pub struct ViewportDebugOptions {
     pub retained_auto_authority: bool,
     pub retained_auto_reuse_actions: bool,
     pub retained_auto_fallback_reasons: bool,
+    /// Flash the boxes of elements whose paint changed this frame.
+    pub paint_flashing: bool,
+    /// Tint every element by its depth in the tree.
+    pub depth_tint: bool,
+    /// Outline flex line boxes and fill the gaps between items and lines.
+    pub flex_overlay: bool,
+    /// Outline the scissor rects rect draws were clipped to this frame.
+    pub scissor_overlay: bool,
 }
 
 impl Default for ViewportDebugOptions {
@@ -29,6 +37,10 @@ impl Default for ViewportDebugOptions {
             retained_auto_authority: true,
             retained_auto_reuse_actions: true,
             retained_auto_fallback_reasons: true,
+            paint_flashing: false,
+            depth_tint: false,
+            flex_overlay: false,
+            scissor_overlay: false,
         }
     }
 }
@@ -43,9 +55,32 @@ impl ViewportDebugOptions {
             trace_execute_detail: std::env::var("RFGUI_TRACE_EXECUTE_DETAIL").is_ok(),
             geometry_overlay: std::env::var("RFGUI_DEBUG_GEOMETRY_OVERLAY").is_ok(),
             retained_auto_overlay: std::env::var("RFGUI_DEBUG_RETAINED_AUTO").is_ok(),
+            paint_flashing: std::env::var("RFGUI_DEBUG_PAINT_FLASHING").is_ok(),
+            depth_tint: std::env::var("RFGUI_DEBUG_DEPTH_TINT").is_ok(),
+            flex_overlay: std::env::var("RFGUI_DEBUG_FLEX_OVERLAY").is_ok(),
+            scissor_overlay: std::env::var("RFGUI_DEBUG_SCISSOR_OVERLAY").is_ok(),
             ..Self::default()
         }
     }
+
+    /// Whether both sets of options draw the same overlay passes.
+    pub(crate) fn same_overlay_modes(&self, other: &Self) -> bool {
+        self.paint_flashing == other.paint_flashing
+            && self.depth_tint == other.depth_tint
+            && self.flex_overlay == other.flex_overlay
+            && self.scissor_overlay == other.scissor_overlay
+    }
+}
+
+/// What a double press on an [`crate::style::AppRegion::Drag`] area asks
+/// the host window to do. Mirrors the native title-bar setting; hosts that
+/// read the OS preference pass it to [`Viewport::set_title_bar_double_click`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum TitleBarDoubleClick {
+    #[default]
+    ToggleMaximize,
+    Minimize,
+    None,
 }
 
 #[derive(Debug, Clone, Default)]
@@ -58,14 +93,29 @@ pub(super) struct InputState {
     /// regardless of focus. `None` = no active capture.
     pub keyboard_capture_node_id: Option<crate::view::node_arena::NodeKey>,
     pub hovered_node_id: Option<crate::view::node_arena::NodeKey>,
+    /// Root-to-target chain that currently carries the hover flag, so the
+    /// next hover change only touches nodes entering or leaving it.
+    pub hovered_path: Vec<crate::view::node_arena::NodeKey>,
     pub pointer_position_viewport: Option<(f32, f32)>,
     pub pending_click: Option<PendingClick>,
+    /// Primary press held on an element with a long-press handler, waiting
+    /// for its hold time to elapse.
+    pub pending_long_press: Option<PendingLongPress>,
+    /// Hover changes held back by the elements' hover intent.
+    pub hover_intent: super::HoverIntentState,
     /// Last fired click, kept to compute `click_count` for consecutive
     /// clicks. Reset once the double-click window closes or the pointer
     /// drifts beyond the slop radius.
     pub last_click: Option<LastClick>,
+    /// Time and position of the last primary press that started a window
+    /// drag, so a second press inside the multi-click window runs the
+    /// title-bar double-click action instead of another drag.
+    pub last_drag_region_press: Option<(crate::time::Instant, f32, f32)>,
     pub pressed_pointer_buttons: FxHashSet<PointerButton>,
     pub pressed_keys: FxHashSet<String>,
+    /// The last key press had its default prevented, so the text it types
+    /// is dropped instead of being inserted.
+    pub key_down_default_prevented: bool,
     pub modifiers: crate::platform::Modifiers,
     /// Reason tagged on the next [`FocusEvent`] / [`BlurEvent`] flushed
     /// by `sync_focus_dispatch`. Callers that mutate focus (pointer
@@ -78,6 +128,10 @@ pub(super) struct InputState {
     /// pointer_up dispatch paths check this and route to drag events
     /// instead.
     pub drag_state: Option<DragState>,
+    /// A phased trackpad gesture is in progress (`Began` seen, `Ended` not
+    /// yet). Its `Changed` deltas belong to the gesture rather than to a
+    /// discrete wheel tick.
+    pub wheel_gesture_active: bool,
 }
 
 /// Per-drag engine state. Lives inside [`InputState`] for the lifetime
@@ -106,6 +160,19 @@ pub(super) struct PendingClick {
     pub viewport_y: f32,
 }
 
+#[derive(Debug, Clone, Copy)]
+pub(super) struct PendingLongPress {
+    pub target_id: crate::view::node_arena::NodeKey,
+    pub viewport_x: f32,
+    pub viewport_y: f32,
+    pub duration: crate::time::Duration,
+    pub fires_at: crate::time::Instant,
+}
+
+/// Max squared pointer travel (logical px²) a held press may drift before
+/// its long press is cancelled. Same slop radius as the click check.
+pub(super) const LONG_PRESS_MAX_TRAVEL_SQ: f32 = 25.0;
+
 #[derive(Debug, Clone, Copy)]
 pub struct LastClick {
     pub button: PointerButton,
@@ -173,7 +240,7 @@ pub(super) fn is_valid_click_candidate(
     ) <= CLICK_MAX_TRAVEL_SQ
 }
 
-fn distance_sq(x1: f32, y1: f32, x2: f32, y2: f32) -> f32 {
+pub(super) fn distance_sq(x1: f32, y1: f32, x2: f32, y2: f32) -> f32 {
     let dx = x1 - x2;
     let dy = y1 - y2;
     dx * dx + dy * dy
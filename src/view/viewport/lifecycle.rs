@@ -1,5 +1,10 @@
 use super::*;
 
+const EXTENDED_SURFACE_FORMAT: wgpu::TextureFormat = wgpu::TextureFormat::Rgba16Float;
+/// Offscreen targets store sRGB-encoded RGBA so read-back pixels can be
+/// written straight to PNG.
+const OFFSCREEN_FORMAT: wgpu::TextureFormat = wgpu::TextureFormat::Rgba8UnormSrgb;
+
 impl Viewport {
     /// Attach a surface target to the viewport.
     ///
@@ -16,10 +21,175 @@ impl Viewport {
         self.create_surface().await;
     }
 
+    /// Render into an offscreen RGBA texture instead of a window surface.
+    ///
+    /// Requests its own adapter and device without a surface, so it works
+    /// on headless machines that still expose a GPU (or a software adapter).
+    /// The target follows [`Self::set_size`]; read a finished frame back
+    /// with [`Self::read_pixels`].
+    pub async fn attach_offscreen(&mut self) -> Result<(), String> {
+        let instance = Instance::new(wgpu::InstanceDescriptor {
+            backends: wgpu::Backends::all(),
+            flags: wgpu::InstanceFlags::empty(),
+            memory_budget_thresholds: wgpu::MemoryBudgetThresholds::default(),
+            backend_options: wgpu::BackendOptions::default(),
+            display: None,
+        });
+        let adapter = instance
+            .request_adapter(&wgpu::RequestAdapterOptions {
+                power_preference: wgpu::PowerPreference::default(),
+                compatible_surface: None,
+                force_fallback_adapter: false,
+                apply_limit_buckets: false,
+            })
+            .await
+            .map_err(|error| format!("offscreen rendering needs a GPU adapter: {error:?}"))?;
+        let (device, queue) = adapter
+            .request_device(&wgpu::DeviceDescriptor {
+                label: Some("rfgui offscreen device"),
+                required_features: adapter.features() & wgpu::Features::DUAL_SOURCE_BLENDING,
+                required_limits: wgpu::Limits::default(),
+                experimental_features: wgpu::ExperimentalFeatures::default(),
+                memory_hints: wgpu::MemoryHints::default(),
+                trace: wgpu::Trace::Off,
+            })
+            .await
+            .map_err(|error| format!("failed to create offscreen device: {error:?}"))?;
+
+        if let Some((width, height)) = self.pending_size.take() {
+            self.gpu.surface_config.width = width;
+            self.gpu.surface_config.height = height;
+        }
+        self.gpu.surface_config.format = OFFSCREEN_FORMAT;
+        self.gpu.surface_config.view_formats = vec![OFFSCREEN_FORMAT];
+        self.gpu.surface_config.color_space = wgpu::SurfaceColorSpace::Auto;
+        self.gpu.surface_target_format = OFFSCREEN_FORMAT;
+        self.gpu.output_color_space = crate::style::OutputColorSpace::Srgb;
+        self.gpu.offscreen_target = Some(Self::create_offscreen_target(
+            &device,
+            &self.gpu.surface_config,
+        ));
+        self.gpu.instance = Some(instance);
+        self.gpu.surface = None;
+        self.gpu.device = Some(device);
+        self.gpu.queue = Some(queue);
+        self.release_render_resource_caches();
+        self.create_frame_attachments();
+        self.needs_reconfigure = false;
+        if let (Some(device), Some(queue)) = (self.gpu.device.as_ref(), self.gpu.queue.as_ref()) {
+            crate::view::render_pass::prewarm_text_pipeline(
+                device,
+                queue,
+                self.gpu.surface_target_format,
+                self.gpu.msaa_sample_count,
+            );
+        }
+        Ok(())
+    }
+
+    /// Copy the last rendered frame of an [`Self::attach_offscreen`]
+    /// viewport into CPU memory. Blocks until the GPU has finished it.
+    #[cfg(not(target_arch = "wasm32"))]
+    pub fn read_pixels(&self) -> Result<image::RgbaImage, String> {
+        let (Some(device), Some(queue), Some(texture)) = (
+            self.gpu.device.as_ref(),
+            self.gpu.queue.as_ref(),
+            self.gpu.offscreen_target.as_ref(),
+        ) else {
+            return Err("viewport has no offscreen target".to_string());
+        };
+        let width = texture.width();
+        let height = texture.height();
+        let row_bytes = width * 4;
+        let padded_row_bytes = row_bytes.div_ceil(wgpu::COPY_BYTES_PER_ROW_ALIGNMENT)
+            * wgpu::COPY_BYTES_PER_ROW_ALIGNMENT;
+        let readback = device.create_buffer(&wgpu::BufferDescriptor {
+            label: Some("rfgui offscreen readback"),
+            size: u64::from(padded_row_bytes) * u64::from(height),
+            usage: wgpu::BufferUsages::COPY_DST | wgpu::BufferUsages::MAP_READ,
+            mapped_at_creation: false,
+        });
+        let mut encoder =
+            device.create_command_encoder(&wgpu::CommandEncoderDescriptor { label: None });
+        encoder.copy_texture_to_buffer(
+            wgpu::TexelCopyTextureInfo {
+                texture,
+                mip_level: 0,
+                origin: wgpu::Origin3d::ZERO,
+                aspect: wgpu::TextureAspect::All,
+            },
+            wgpu::TexelCopyBufferInfo {
+                buffer: &readback,
+                layout: wgpu::TexelCopyBufferLayout {
+                    offset: 0,
+                    bytes_per_row: Some(padded_row_bytes),
+                    rows_per_image: Some(height),
+                },
+            },
+            texture.size(),
+        );
+        queue.submit(Some(encoder.finish()));
+
+        let (sender, receiver) = std::sync::mpsc::sync_channel(1);
+        readback.map_async(wgpu::MapMode::Read, .., move |result| {
+            let _ = sender.send(result);
+        });
+        device
+            .poll(wgpu::PollType::wait_indefinitely())
+            .map_err(|error| format!("GPU wait failed during readback: {error:?}"))?;
+        receiver
+            .recv()
+            .map_err(|error| format!("readback callback was lost: {error}"))?
+            .map_err(|error| format!("readback map failed: {error:?}"))?;
+        let mapped = readback
+            .slice(..)
+            .get_mapped_range()
+            .map_err(|error| format!("failed to access readback buffer: {error:?}"))?;
+        let mut pixels = Vec::with_capacity(row_bytes as usize * height as usize);
+        for row in mapped.chunks(padded_row_bytes as usize) {
+            pixels.extend_from_slice(&row[..row_bytes as usize]);
+        }
+        drop(mapped);
+        readback.unmap();
+        image::RgbaImage::from_raw(width, height, pixels)
+            .ok_or_else(|| "readback size does not match the target".to_string())
+    }
+
+    fn create_offscreen_target(
+        device: &wgpu::Device,
+        config: &wgpu::SurfaceConfiguration,
+    ) -> wgpu::Texture {
+        device.create_texture(&wgpu::TextureDescriptor {
+            label: Some("rfgui offscreen target"),
+            size: wgpu::Extent3d {
+                width: config.width,
+                height: config.height,
+                depth_or_array_layers: 1,
+            },
+            mip_level_count: 1,
+            sample_count: 1,
+            dimension: wgpu::TextureDimension::D2,
+            format: config.format,
+            usage: TextureUsages::RENDER_ATTACHMENT | TextureUsages::COPY_SRC,
+            view_formats: &[],
+        })
+    }
+
     pub fn set_surface_format_preference(&mut self, pref: SurfaceFormatPreference) {
         self.gpu.surface_format_preference = pref;
     }
 
+    /// Like the format preference, this is read when the surface is created,
+    /// so set it before [`Self::attach`].
+    pub fn set_color_management(&mut self, mode: ColorManagement) {
+        self.gpu.color_management = mode;
+    }
+
+    /// How the configured surface interprets rendered colors.
+    pub fn output_color_space(&self) -> crate::style::OutputColorSpace {
+        self.gpu.output_color_space
+    }
+
     pub fn set_size(&mut self, mut width: u32, mut height: u32) {
         if width == 0 {
             width = 1;
@@ -40,14 +210,31 @@ impl Viewport {
 
     pub fn set_style(&mut self, style: Style) {
         self.style = style;
-        self.scene.last_rsx_root = None;
-        self.request_redraw();
+        self.rebuild_with_cascade_root();
     }
 
     pub fn style(&self) -> &Style {
         &self.style
     }
 
+    /// Replace the default stylesheet host elements start from and rebuild
+    /// the tree against it.
+    pub fn set_user_agent_style(&mut self, user_agent_style: UserAgentStyle) {
+        UserAgentStyle::set_current(user_agent_style.clone());
+        self.user_agent_style = user_agent_style;
+        self.rebuild_with_cascade_root();
+    }
+
+    pub fn user_agent_style(&self) -> &UserAgentStyle {
+        &self.user_agent_style
+    }
+
+    fn rebuild_with_cascade_root(&mut self) {
+        self.cascade_root_style = self.user_agent_style.root.clone() + self.style.clone();
+        self.scene.last_rsx_root = None;
+        self.request_redraw();
+    }
+
     pub fn set_clear_color(&mut self, clear_color: Box<dyn ColorLike>) {
         self.clear_color = clear_color;
     }
@@ -56,6 +243,213 @@ impl Viewport {
         self.cursor_override = cursor;
     }
 
+    /// Composite the surface with the desktop behind it. Opaque surfaces
+    /// ignore the clear color's alpha; transparent ones keep it, so a
+    /// `Color::transparent()` clear shows the desktop.
+    pub fn set_transparent(&mut self, transparent: bool) {
+        if self.gpu.transparent == transparent {
+            return;
+        }
+        self.gpu.transparent = transparent;
+        if self.gpu.surface.is_some() {
+            self.gpu.surface_config.alpha_mode =
+                Self::alpha_mode_from_capabilities(&self.gpu.supported_alpha_modes, transparent);
+            self.needs_reconfigure = true;
+        }
+        self.request_redraw();
+    }
+
+    /// Replace the host's display list. `current` indexes the monitor that
+    /// holds the window; hosts call this at startup and again after a move
+    /// across screens or a scale factor change.
+    pub fn set_monitors(&mut self, monitors: Vec<MonitorInfo>, current: Option<usize>) {
+        self.current_monitor = current.filter(|&index| index < monitors.len());
+        self.monitors = monitors;
+    }
+
+    pub fn monitors(&self) -> &[MonitorInfo] {
+        &self.monitors
+    }
+
+    /// Monitor holding the window, falling back to the primary one when the
+    /// host could not tell.
+    pub fn current_monitor(&self) -> Option<&MonitorInfo> {
+        self.current_monitor
+            .and_then(|index| self.monitors.get(index))
+            .or_else(|| self.monitors.iter().find(|monitor| monitor.is_primary))
+    }
+
+    /// Monitor containing the physical desktop point, if any.
+    pub fn monitor_at(&self, x: i32, y: i32) -> Option<&MonitorInfo> {
+        self.monitors.iter().find(|monitor| monitor.contains(x, y))
+    }
+
+    /// Record the OS high-contrast setting. Hosts call this at startup and
+    /// whenever the setting may have changed; flipping the effective mode
+    /// rebuilds the app.
+    pub fn set_system_high_contrast(&mut self, high_contrast: bool) {
+        self.system_high_contrast = high_contrast;
+        self.sync_high_contrast();
+    }
+
+    pub fn contrast_preference(&self) -> ContrastPreference {
+        self.contrast_preference
+    }
+
+    pub fn set_contrast_preference(&mut self, preference: ContrastPreference) {
+        self.contrast_preference = preference;
+        self.sync_high_contrast();
+    }
+
+    /// Whether high-contrast mode is in effect: the system setting unless
+    /// the contrast preference pins one mode.
+    pub fn high_contrast(&self) -> bool {
+        self.contrast_preference.resolve(self.system_high_contrast)
+    }
+
+    fn sync_high_contrast(&mut self) {
+        crate::ui::set_high_contrast(self.high_contrast());
+        self.request_redraw();
+    }
+
+    /// Record the host window theme. Hosts call this at startup and when
+    /// the OS theme flips; `media(theme: ...)` style blocks match on it.
+    pub fn set_window_theme(&mut self, theme: WindowTheme) {
+        self.window_theme = theme;
+        self.sync_media_context();
+    }
+
+    pub fn window_theme(&self) -> WindowTheme {
+        self.window_theme
+    }
+
+    /// Queue a host window command, as handlers do through
+    /// `EventViewport::window_command`.
+    pub fn window_command(&mut self, command: WindowCommand) {
+        self.pending_platform_requests.window_commands.push(command);
+    }
+
+    /// Action taken when an `app_region: drag` area is double-pressed.
+    pub fn set_title_bar_double_click(&mut self, action: TitleBarDoubleClick) {
+        self.title_bar_double_click = action;
+    }
+
+    /// Install (or remove with `None`) the window / application menu and
+    /// ask the host to mirror it natively.
+    pub fn set_window_menu(&mut self, menu: Option<Menu>) {
+        self.window_menu = menu.clone();
+        self.pending_platform_requests
+            .menu_commands
+            .push(MenuCommand::SetWindowMenu(menu));
+    }
+
+    /// Install (or remove with `None`) the system tray icon.
+    pub fn set_tray_icon(&mut self, tray: Option<TrayIcon>) {
+        self.tray_icon = tray.clone();
+        self.pending_platform_requests
+            .menu_commands
+            .push(MenuCommand::SetTrayIcon(tray));
+    }
+
+    /// Post a system notification with `actions` as its buttons. See
+    /// [`Self::post_notification`] for click / dismiss callbacks or an icon.
+    pub fn notify(
+        &mut self,
+        title: impl Into<String>,
+        body: impl Into<String>,
+        actions: Vec<NotificationAction>,
+    ) -> NotificationId {
+        let notification = actions
+            .into_iter()
+            .fold(Notification::new(title, body), Notification::action);
+        self.post_notification(notification)
+    }
+
+    /// Ask the host to show `notification`. Its callbacks stay here until
+    /// the host reports a response or the app withdraws it.
+    pub fn post_notification(&mut self, notification: Notification) -> NotificationId {
+        let id = notification.id;
+        self.notifications.insert(id, notification.clone());
+        self.pending_platform_requests
+            .notification_commands
+            .push(NotificationCommand::Post(notification));
+        id
+    }
+
+    /// Remove a notification that has not been responded to yet.
+    pub fn withdraw_notification(&mut self, id: NotificationId) {
+        if self.notifications.remove(&id).is_some() {
+            self.pending_platform_requests
+                .notification_commands
+                .push(NotificationCommand::Withdraw(id));
+        }
+    }
+
+    /// Set (or clear with `None`) the taskbar / dock badge.
+    pub fn set_badge(&mut self, badge: Option<AppBadge>) {
+        if self.badge == badge {
+            return;
+        }
+        self.badge = badge;
+        self.pending_platform_requests
+            .notification_commands
+            .push(NotificationCommand::SetBadge(badge));
+    }
+
+    pub fn badge(&self) -> Option<AppBadge> {
+        self.badge
+    }
+
+    /// Make `source` playable as `name`, replacing any earlier sound of
+    /// that name. The host is asked to load it right away so the first
+    /// play does not wait on decoding.
+    pub fn register_sound(&mut self, name: impl Into<SmolStr>, source: SoundSource) {
+        let name = name.into();
+        self.sounds.insert(name.clone());
+        self.pending_platform_requests
+            .sound_commands
+            .push(SoundCommand::Register { name, source });
+    }
+
+    /// Ask the host to play the registered sound `name` at the master
+    /// volume. Returns false, playing nothing, for unknown names or while
+    /// muted or at zero volume.
+    pub fn play_sound(&mut self, name: &str) -> bool {
+        if self.sound_muted || self.sound_volume <= 0.0 || !self.sounds.contains(name) {
+            return false;
+        }
+        self.pending_platform_requests
+            .sound_commands
+            .push(SoundCommand::Play {
+                name: name.into(),
+                volume: self.sound_volume,
+            });
+        true
+    }
+
+    pub fn set_sound_muted(&mut self, muted: bool) {
+        self.sound_muted = muted;
+    }
+
+    pub fn sound_muted(&self) -> bool {
+        self.sound_muted
+    }
+
+    /// Master volume for UI sounds, clamped to `0.0..=1.0`.
+    pub fn set_sound_volume(&mut self, volume: f32) {
+        self.sound_volume = volume.clamp(0.0, 1.0);
+    }
+
+    pub fn sound_volume(&self) -> f32 {
+        self.sound_volume
+    }
+
+    /// Hosts whose native menu already delivers accelerators as menu
+    /// activations set this so key presses are not matched twice.
+    pub fn set_native_menu_shortcuts(&mut self, enabled: bool) {
+        self.native_menu_shortcuts = enabled;
+    }
+
     /// Push text the viewport wants written to the host clipboard into the
     /// pending platform request queue, and mirror it to the in-memory
     /// fallback so immediate reads from within this frame still see it.
@@ -75,6 +469,9 @@ impl Viewport {
     /// drain. Backends call this after each render/event batch and apply
     /// the results to the real window/clipboard.
     pub fn drain_platform_requests(&mut self) -> PlatformRequests {
+        // Handlers queue `use_viewport` actions (sound plays among them)
+        // that must reach the host even when no frame follows the event.
+        self.apply_pending_viewport_actions();
         // Fold the internal `redraw_requested` flag into the drain so the
         // backend only has to look in one place.
         if self.redraw_requested {
@@ -165,6 +562,148 @@ impl Viewport {
         std::mem::take(&mut self.redraw_requested)
     }
 
+    pub fn set_redraw_mode(&mut self, mode: RedrawMode) {
+        self.frame_pacer.mode = mode;
+    }
+
+    pub fn redraw_mode(&self) -> RedrawMode {
+        self.frame_pacer.mode
+    }
+
+    /// Cap the frame rate at `max_fps`; `None` renders as fast as frames
+    /// are invalidated (or presented, in continuous mode).
+    pub fn set_max_fps(&mut self, max_fps: Option<f32>) {
+        self.frame_pacer.set_max_fps(max_fps);
+    }
+
+    pub fn max_fps(&self) -> Option<f32> {
+        self.frame_pacer.max_fps()
+    }
+
+    /// True when something invalidated the viewport since the last frame:
+    /// a pending redraw request (input, animation-frame requests, resource
+    /// loads), dirty app state, a running transition, or a mounted
+    /// `use_animation_frame` hook.
+    pub fn needs_frame(&self) -> bool {
+        self.redraw_requested
+            || self.is_animating
+            || (self.needs_rebuild && self.app.is_some())
+            || (self.app.is_some() && crate::ui::has_animation_frame_hooks())
+            || peek_state_dirty().has_any()
+    }
+
+    /// When the host should render next. `redraw_pending` carries
+    /// invalidations the host already drained (for example
+    /// `PlatformRequests::request_redraw`). Hosts sleep on
+    /// [`FrameSchedule::Idle`], wait until the instant on
+    /// [`FrameSchedule::At`], and render on [`FrameSchedule::Now`].
+    /// Rebuild polls, pending long presses and hover changes held back
+    /// by hover intent wake an idle host.
+    pub fn frame_schedule(&self, now: Instant, redraw_pending: bool) -> FrameSchedule {
+        let wake_at = [
+            self.rebuild_poll.as_ref().map(|poll| poll.next_at),
+            self.input_state
+                .pending_long_press
+                .map(|press| press.fires_at),
+            self.input_state.hover_intent.next_deadline(),
+        ]
+        .into_iter()
+        .flatten()
+        .min();
+        match (
+            self.frame_pacer
+                .schedule(now, redraw_pending || self.needs_frame()),
+            wake_at,
+        ) {
+            (FrameSchedule::Idle, Some(at)) if at > now => FrameSchedule::At(at),
+            (FrameSchedule::Idle, Some(_)) => self.frame_pacer.schedule(now, true),
+            (schedule, _) => schedule,
+        }
+    }
+
+    /// Drive frames from `now` instead of the wall clock. Deterministic
+    /// hosts advance it between frames; `None` returns to the wall clock.
+    pub fn set_frame_clock(&mut self, now: Option<Instant>) {
+        self.frame_clock = now;
+    }
+
+    /// Current frame time: the clock set by [`Self::set_frame_clock`], or
+    /// the wall clock.
+    pub fn frame_now(&self) -> Instant {
+        self.frame_clock.unwrap_or_else(Instant::now)
+    }
+
+    /// Start capturing every input event passed to
+    /// [`Self::dispatch_app_event`], timed on [`Self::frame_now`].
+    /// Restarts a recording that is already running.
+    pub fn start_input_recording(&mut self) {
+        self.input_recorder = Some(InputRecorder::new(self.frame_now()));
+    }
+
+    /// Stop capturing and return the events recorded so far; `None` when
+    /// no recording was running.
+    pub fn stop_input_recording(&mut self) -> Option<InputRecording> {
+        self.input_recorder.take().map(InputRecorder::finish)
+    }
+
+    pub fn is_recording_input(&self) -> bool {
+        self.input_recorder.is_some()
+    }
+
+    /// Measure, place and run post-layout transitions inside `render_rsx`
+    /// when no surface is attached, so hosts without a GPU observe the same
+    /// geometry a rendered frame would produce. Off by default: a GPU host
+    /// whose surface is not ready yet skips the frame entirely.
+    pub fn set_headless_layout(&mut self, enabled: bool) {
+        self.headless_layout = enabled;
+    }
+
+    /// Every node matching `query`, in document order, as of the last
+    /// rendered frame.
+    pub fn query(&self, query: &crate::view::ElementQuery) -> Vec<crate::view::ElementHandle> {
+        crate::view::query::query_elements(
+            self.scene.last_rsx_root.as_ref(),
+            &self.scene.node_arena,
+            query,
+        )
+    }
+
+    /// First node matching `query`, in document order.
+    pub fn query_one(
+        &self,
+        query: &crate::view::ElementQuery,
+    ) -> Option<crate::view::ElementHandle> {
+        self.query(query).into_iter().next()
+    }
+
+    /// Run `changed` every `interval` and rebuild the app when it returns
+    /// `true`. Replacing the poll keeps its pending deadline, so apps can
+    /// reinstall it on every build without pushing the next check out.
+    pub fn set_rebuild_poll(&mut self, interval: Duration, changed: Box<dyn FnMut() -> bool>) {
+        let next_at = match &self.rebuild_poll {
+            Some(poll) => poll.next_at,
+            None => self.frame_now() + interval,
+        };
+        self.rebuild_poll = Some(RebuildPoll {
+            interval,
+            next_at,
+            changed,
+        });
+    }
+
+    /// Runs the rebuild poll when it is due at `now`; `true` when its
+    /// source changed.
+    pub(crate) fn run_rebuild_poll(&mut self, now: Instant) -> bool {
+        let Some(poll) = self.rebuild_poll.as_mut() else {
+            return false;
+        };
+        if poll.next_at > now {
+            return false;
+        }
+        poll.next_at = now + poll.interval;
+        (poll.changed)()
+    }
+
     pub async fn create_surface(&mut self) {
         let Some(surface_target) = self.gpu.window.clone() else {
             return;
@@ -195,10 +734,12 @@ impl Viewport {
                 return;
             };
 
+            // Dual-source blending backs subpixel text; without it the text
+            // pass stays on grayscale coverage.
             let (device, queue) = adapter
                 .request_device(&wgpu::DeviceDescriptor {
                     label: None,
-                    required_features: wgpu::Features::empty(),
+                    required_features: adapter.features() & wgpu::Features::DUAL_SOURCE_BLENDING,
                     required_limits: wgpu::Limits::default(),
                     experimental_features: wgpu::ExperimentalFeatures::default(),
                     memory_hints: wgpu::MemoryHints::default(),
@@ -227,16 +768,35 @@ impl Viewport {
                 self.gpu.surface_format_preference,
                 SurfaceFormatPreference::PreferSrgb
             );
-            let (storage_format, target_format) = if wants_srgb && !format.is_srgb() {
+            let wide_gamut = matches!(self.gpu.color_management, ColorManagement::WideGamut)
+                && Self::supports_extended_srgb_linear(&caps);
+            let (storage_format, target_format) = if wide_gamut {
+                // scRGB is linear, so no sRGB view is involved.
+                (EXTENDED_SURFACE_FORMAT, EXTENDED_SURFACE_FORMAT)
+            } else if wants_srgb && !format.is_srgb() {
                 let srgb = format.add_srgb_suffix();
                 (format, srgb)
             } else {
                 (format, format)
             };
+            let (color_space, output_color_space) = if wide_gamut {
+                (
+                    wgpu::SurfaceColorSpace::ExtendedSrgbLinear,
+                    crate::style::OutputColorSpace::ExtendedSrgbLinear,
+                )
+            } else {
+                (
+                    wgpu::SurfaceColorSpace::Auto,
+                    crate::style::OutputColorSpace::Srgb,
+                )
+            };
+            self.gpu.surface_config.color_space = color_space;
+            self.gpu.output_color_space = output_color_space;
             self.gpu.surface_config.format = storage_format;
             self.gpu.surface_target_format = target_format;
             self.gpu.surface_config.alpha_mode =
-                Self::alpha_mode_from_capabilities(&caps.alpha_modes);
+                Self::alpha_mode_from_capabilities(&caps.alpha_modes, self.gpu.transparent);
+            self.gpu.supported_alpha_modes = caps.alpha_modes.clone();
             self.gpu.surface_config.view_formats = if storage_format == target_format {
                 vec![storage_format]
             } else {
@@ -269,6 +829,13 @@ impl Viewport {
         }
     }
 
+    fn supports_extended_srgb_linear(caps: &wgpu::SurfaceCapabilities) -> bool {
+        caps.formats.contains(&EXTENDED_SURFACE_FORMAT)
+            && caps
+                .color_spaces(EXTENDED_SURFACE_FORMAT)
+                .contains(wgpu::SurfaceColorSpaces::EXTENDED_SRGB_LINEAR)
+    }
+
     /// Data-driven surface format selection. `preference` decides whether
     /// sRGB or non-sRGB formats win in the tiebreaker; `current` is kept if
     /// it's present in the capability list, otherwise the first matching
@@ -311,15 +878,20 @@ impl Viewport {
             self.gpu.surface_config.width = width;
             self.gpu.surface_config.height = height;
         }
-        let surface = match &self.gpu.surface {
-            Some(surface) => surface,
-            None => return false,
-        };
         let device = match &self.gpu.device {
             Some(device) => device,
             None => return false,
         };
-        surface.configure(device, &self.gpu.surface_config);
+        if let Some(surface) = &self.gpu.surface {
+            surface.configure(device, &self.gpu.surface_config);
+        } else if self.gpu.offscreen_target.is_some() {
+            self.gpu.offscreen_target = Some(Self::create_offscreen_target(
+                device,
+                &self.gpu.surface_config,
+            ));
+        } else {
+            return false;
+        }
         let device_for_prewarm = device.clone();
         self.release_render_resource_caches();
         self.create_frame_attachments();
@@ -1,35 +1,47 @@
 #![allow(missing_docs)]
 use rustc_hash::{FxHashMap, FxHashSet};
+use smol_str::SmolStr;
 
 #[cfg(test)]
 mod clipboard_tests;
 mod compositor_sync;
+mod coordinates;
 mod debug;
+mod debug_overlays;
 pub(crate) mod dispatch;
 mod frame;
+mod global_listeners;
 mod gpu_resources;
+mod hover_intent;
 #[cfg(test)]
 mod incremental_tests;
 mod input;
 mod lifecycle;
 mod render;
 pub(crate) mod scene_helpers;
+mod spatial_navigation;
+mod synthetic;
 #[cfg(any())]
 mod tests;
 pub(crate) mod transitions_tick;
+mod visibility;
 
-use crate::style::{ColorLike, Cursor, HexColor, PropertyId, Style};
-use crate::time::Instant;
+use crate::style::{
+    AppRegion, ColorLike, Cursor, HexColor, MediaContext, PropertyId, Style, UserAgentStyle,
+};
+use crate::time::{Duration, Instant};
 use crate::transition::{
     AnimationPlugin, CHANNEL_LAYOUT_HEIGHT, CHANNEL_LAYOUT_WIDTH, CHANNEL_LAYOUT_X,
     CHANNEL_LAYOUT_Y, CHANNEL_SCROLL_X, CHANNEL_SCROLL_Y, CHANNEL_STYLE_BACKGROUND_COLOR,
     CHANNEL_STYLE_BORDER_BOTTOM_COLOR, CHANNEL_STYLE_BORDER_LEFT_COLOR,
     CHANNEL_STYLE_BORDER_RADIUS, CHANNEL_STYLE_BORDER_RIGHT_COLOR, CHANNEL_STYLE_BORDER_TOP_COLOR,
-    CHANNEL_STYLE_BOX_SHADOW, CHANNEL_STYLE_COLOR, CHANNEL_STYLE_OPACITY, CHANNEL_STYLE_TRANSFORM,
-    CHANNEL_STYLE_TRANSFORM_ORIGIN, CHANNEL_VISUAL_X, CHANNEL_VISUAL_Y, ChannelId, ClaimMode,
-    LayoutTransitionPlugin, ScrollAxis, ScrollTransition, ScrollTransitionPlugin, StyleField,
-    StyleTransitionPlugin, StyleValue, TrackKey, TrackTarget, Transition, TransitionFrame,
-    TransitionHost, TransitionPluginId, VisualTransitionPlugin,
+    CHANNEL_STYLE_BOX_SHADOW, CHANNEL_STYLE_COLOR, CHANNEL_STYLE_GAP, CHANNEL_STYLE_OPACITY,
+    CHANNEL_STYLE_PADDING_BOTTOM, CHANNEL_STYLE_PADDING_LEFT, CHANNEL_STYLE_PADDING_RIGHT,
+    CHANNEL_STYLE_PADDING_TOP, CHANNEL_STYLE_TRANSFORM, CHANNEL_STYLE_TRANSFORM_ORIGIN,
+    CHANNEL_VISUAL_X, CHANNEL_VISUAL_Y, ChannelId, ClaimMode, LayoutTransitionPlugin, ScrollAxis,
+    ScrollTransition, ScrollTransitionPlugin, StyleField, StyleTransitionPlugin, StyleValue,
+    TrackKey, TrackTarget, Transition, TransitionFrame, TransitionGroupTracker, TransitionHost,
+    TransitionPlayback, TransitionPluginId, VisualTransitionPlugin, WheelScrollTransition,
 };
 use crate::ui::{
     BlurEvent, ClickEvent, EventCommand, EventMeta, FocusEvent, FromPropValue, ImePreeditEvent,
@@ -41,6 +53,7 @@ use crate::view::ElementStylePropSchema;
 use crate::view::frame_graph::texture_resource::TextureDesc;
 use crate::view::frame_graph::{AllocationId, BufferDesc, FrameGraph};
 use crate::view::render_pass::render_target::{OffscreenRenderTargetPool, RenderTargetBundle};
+use crate::view::render_pass::shadow_module::{ShadowRasterEntry, ShadowRasterFrame};
 
 use std::ops::Sub;
 use std::sync::Arc;
@@ -62,18 +75,34 @@ pub use self::dispatch::{
     dispatch_scroll_from_hit_test, get_scroll_offset_by_id, nearest_viewport_clip_ancestor_id,
     set_scroll_offset_by_id,
 };
-pub use self::frame::FrameParts;
 use self::frame::{
-    BeginFrameProfile, EndFrameProfile, FrameDisposition, FrameState, FrameStats, FrameTimings,
-    LayoutPassResult,
+    BeginFrameProfile, EndFrameProfile, FrameDisposition, FramePacer, FrameState, FrameStats,
+    FrameTimings, LayoutPassResult, RetainedUniformSlots,
+};
+pub use self::frame::{
+    FrameParts, FrameSchedule, OpacityGroupMetrics, RedrawMode, RenderReuseMetrics,
+};
+pub use self::global_listeners::{
+    GlobalKeyEvent, GlobalListenerId, GlobalPointerEvent, GlobalWheelEvent,
+};
+use self::global_listeners::{GlobalListenerList, GlobalListeners};
+pub use self::hover_intent::HoverIntent;
+use self::hover_intent::HoverIntentState;
+use self::input::{
+    DragState, InputState, LONG_PRESS_MAX_TRAVEL_SQ, PendingClick, PendingLongPress, distance_sq,
+    is_valid_click_candidate,
 };
-use self::input::{DragState, InputState, PendingClick, is_valid_click_candidate};
-pub use self::input::{PointerButton, ViewportDebugOptions};
+pub use self::input::{PointerButton, TitleBarDoubleClick, ViewportDebugOptions};
+pub use self::synthetic::{SyntheticEvent, SyntheticTarget};
 use self::transitions_tick::{TransitionHostAdapter, active_channels_by_node};
-use crate::app::App;
+use crate::app::recording::{InputRecorder, InputRecording};
+use crate::app::{App, WindowTheme};
 use crate::platform::{
-    Modifiers, PlatformImePreedit, PlatformKeyEvent, PlatformPointerEvent,
-    PlatformPointerEventKind, PlatformRequests, PlatformTextInput, PlatformWheelEvent, PointerType,
+    AppBadge, ContrastPreference, IconImage, Menu, MenuCommand, MenuItemId, Modifiers, MonitorInfo,
+    Notification, NotificationAction, NotificationCommand, NotificationEvent, NotificationId,
+    PlatformImePreedit, PlatformKeyEvent, PlatformPointerEvent, PlatformPointerEventKind,
+    PlatformRequests, PlatformTextInput, PlatformWheelEvent, PointerType, SoundCommand,
+    SoundSource, TrayEvent, TrayIcon, WindowCommand,
 };
 
 pub trait WindowHandle: HasWindowHandle + HasDisplayHandle {}
@@ -97,6 +126,17 @@ impl Default for SurfaceFormatPreference {
     }
 }
 
+/// Whether the viewport may configure a wide-gamut surface. `WideGamut`
+/// asks for an `Rgba16Float` surface in extended linear sRGB (scRGB) when the
+/// adapter offers one, so Display-P3 and saturated OKLCH colors are not
+/// clipped to sRGB; otherwise it falls back to the standard surface.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum ColorManagement {
+    #[default]
+    Srgb,
+    WideGamut,
+}
+
 /// Selects the viewport's production paint authority during the staged
 /// artifact-renderer rollout.
 ///
@@ -171,6 +211,10 @@ impl<'a> ViewportControl<'a> {
         self.viewport.transitions.scroll_transition = transition;
     }
 
+    pub fn set_wheel_scroll_transition(&mut self, transition: WheelScrollTransition) {
+        self.viewport.transitions.wheel_scroll_transition = transition;
+    }
+
     pub fn set_selects(&mut self, selects: Vec<u64>) {
         self.viewport.set_selects(selects);
     }
@@ -189,6 +233,22 @@ impl<'a> ViewportControl<'a> {
         self.viewport.cancel_scroll_track(target, axis);
     }
 
+    pub fn pause_transitions(&mut self) {
+        self.viewport.pause_transitions();
+    }
+
+    pub fn resume_transitions(&mut self) {
+        self.viewport.resume_transitions();
+    }
+
+    pub fn set_transition_playback_rate(&mut self, rate: f32) {
+        self.viewport.set_transition_playback_rate(rate);
+    }
+
+    pub fn finish_transitions(&mut self) {
+        self.viewport.finish_transitions();
+    }
+
     pub fn set_pointer_capture(&mut self, node_id: crate::view::node_arena::NodeKey) {
         self.viewport.set_pointer_capture_node_id(Some(node_id));
     }
@@ -211,6 +271,17 @@ impl<'a> ViewportControl<'a> {
         self.viewport.set_debug_options(options);
     }
 
+    pub fn set_text_render_options(
+        &mut self,
+        options: crate::view::render_pass::TextRenderOptions,
+    ) {
+        self.viewport.set_text_render_options(options);
+    }
+
+    pub fn set_pixel_snapping(&mut self, enabled: bool) {
+        self.viewport.set_pixel_snapping(enabled);
+    }
+
     pub fn set_paint_renderer_mode(&mut self, mode: ViewportPaintRendererMode) {
         self.viewport.set_paint_renderer_mode(mode);
     }
@@ -219,9 +290,144 @@ impl<'a> ViewportControl<'a> {
         self.viewport.set_msaa_sample_count(sample_count);
     }
 
+    pub fn set_window_icon(&mut self, icon: Option<IconImage>) {
+        self.viewport.window_command(WindowCommand::SetIcon(icon));
+    }
+
+    pub fn set_resizable(&mut self, resizable: bool) {
+        self.viewport
+            .window_command(WindowCommand::SetResizable(resizable));
+    }
+
+    pub fn set_min_size(&mut self, size: Option<(u32, u32)>) {
+        self.viewport
+            .window_command(WindowCommand::SetMinSize(size));
+    }
+
+    pub fn set_max_size(&mut self, size: Option<(u32, u32)>) {
+        self.viewport
+            .window_command(WindowCommand::SetMaxSize(size));
+    }
+
+    pub fn set_always_on_top(&mut self, always_on_top: bool) {
+        self.viewport
+            .window_command(WindowCommand::SetAlwaysOnTop(always_on_top));
+    }
+
+    pub fn set_fullscreen(&mut self, fullscreen: bool) {
+        self.viewport
+            .window_command(WindowCommand::SetFullscreen(fullscreen));
+    }
+
+    pub fn set_window_position(&mut self, x: i32, y: i32) {
+        self.viewport
+            .window_command(WindowCommand::SetPosition(x, y));
+    }
+
+    pub fn monitors(&self) -> &[MonitorInfo] {
+        self.viewport.monitors()
+    }
+
+    pub fn current_monitor(&self) -> Option<&MonitorInfo> {
+        self.viewport.current_monitor()
+    }
+
+    /// Whether high-contrast mode is in effect.
+    pub fn high_contrast(&self) -> bool {
+        self.viewport.high_contrast()
+    }
+
+    pub fn contrast_preference(&self) -> ContrastPreference {
+        self.viewport.contrast_preference()
+    }
+
+    /// Pin standard or high contrast, or follow the system again with
+    /// `ContrastPreference::System`.
+    pub fn set_contrast_preference(&mut self, preference: ContrastPreference) {
+        self.viewport.set_contrast_preference(preference);
+    }
+
+    pub fn window_theme(&self) -> WindowTheme {
+        self.viewport.window_theme()
+    }
+
+    /// Switch the surface alpha mode and ask the host to make its window
+    /// transparent (or opaque again).
+    pub fn set_transparent(&mut self, transparent: bool) {
+        self.viewport.set_transparent(transparent);
+        self.viewport
+            .window_command(WindowCommand::SetTransparent(transparent));
+    }
+
+    /// Switch between redrawing on invalidation and redrawing every frame.
+    pub fn set_redraw_mode(&mut self, mode: RedrawMode) {
+        self.viewport.set_redraw_mode(mode);
+        self.viewport.request_redraw();
+    }
+
+    pub fn set_max_fps(&mut self, max_fps: Option<f32>) {
+        self.viewport.set_max_fps(max_fps);
+    }
+
+    /// Call `changed` every `interval`, even while the viewport is idle,
+    /// and rebuild the app only when it returns `true`. Used for polling
+    /// external sources; replaces any earlier poll.
+    pub fn poll_for_rebuild(
+        &mut self,
+        interval: Duration,
+        changed: impl FnMut() -> bool + 'static,
+    ) {
+        self.viewport.set_rebuild_poll(interval, Box::new(changed));
+    }
+
     pub fn release_render_resource_caches(&mut self) {
         self.viewport.release_render_resource_caches();
     }
+
+    pub fn set_window_menu(&mut self, menu: Option<Menu>) {
+        self.viewport.set_window_menu(menu);
+    }
+
+    pub fn set_tray_icon(&mut self, tray: Option<TrayIcon>) {
+        self.viewport.set_tray_icon(tray);
+    }
+
+    pub fn notify(
+        &mut self,
+        title: impl Into<String>,
+        body: impl Into<String>,
+        actions: Vec<NotificationAction>,
+    ) -> NotificationId {
+        self.viewport.notify(title, body, actions)
+    }
+
+    pub fn post_notification(&mut self, notification: Notification) -> NotificationId {
+        self.viewport.post_notification(notification)
+    }
+
+    pub fn withdraw_notification(&mut self, id: NotificationId) {
+        self.viewport.withdraw_notification(id);
+    }
+
+    pub fn set_badge(&mut self, badge: Option<AppBadge>) {
+        self.viewport.set_badge(badge);
+    }
+
+    pub fn register_sound(&mut self, name: impl Into<SmolStr>, source: SoundSource) {
+        self.viewport.register_sound(name, source);
+    }
+
+    pub fn play_sound(&mut self, name: &str) -> bool {
+        self.viewport.play_sound(name)
+    }
+
+    pub fn set_sound_muted(&mut self, muted: bool) {
+        self.viewport.set_sound_muted(muted);
+    }
+
+    pub fn set_sound_volume(&mut self, volume: f32) {
+        self.viewport.set_sound_volume(volume);
+    }
 }
 
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
@@ -232,6 +438,10 @@ pub enum RenderFrameResult {
 
 pub struct Viewport {
     style: Style,
+    user_agent_style: UserAgentStyle,
+    /// `user_agent_style.root` merged under `style`; the cascade root of
+    /// every rendered tree.
+    cascade_root_style: Style,
     clear_color: Box<dyn ColorLike>,
     scale_factor: f32,
     logical_width: f32,
@@ -242,6 +452,8 @@ pub struct Viewport {
     needs_reconfigure: bool,
     redraw_requested: bool,
     debug_options: ViewportDebugOptions,
+    text_render_options: crate::view::render_pass::TextRenderOptions,
+    pixel_snapping: bool,
     paint_renderer_mode: ViewportPaintRendererMode,
     /// First terminal RetainedAuto failure. Selection observes this before any
     /// authority-specific graph mutation; it is never cleared by a successful
@@ -249,18 +461,63 @@ pub struct Viewport {
     retained_auto_terminal_failure: Option<RetainedAutoTerminalFailureStage>,
     compositor: CompositorState,
     input_state: InputState,
+    /// Listeners added through the `on_global_*` methods.
+    global_listeners: GlobalListeners,
     clipboard_fallback: Option<String>,
     dispatched_focus_node_id: Option<crate::view::node_arena::NodeKey>,
     scene: SceneState,
     transitions: TransitionRuntime,
     cursor_override: Option<Cursor>,
     last_recorded_cursor: Option<Cursor>,
+    title_bar_double_click: TitleBarDoubleClick,
+    /// Menus installed through `set_window_menu` / `set_tray_icon`. Kept
+    /// here so host activations and accelerators resolve to callbacks.
+    window_menu: Option<Menu>,
+    tray_icon: Option<TrayIcon>,
+    /// Host's native menu already turns accelerators into activations, so
+    /// key dispatch must not match them a second time.
+    native_menu_shortcuts: bool,
+    /// Posted notifications the host has not resolved yet, kept for their
+    /// callbacks, and the badge last requested.
+    notifications: FxHashMap<NotificationId, Notification>,
+    badge: Option<AppBadge>,
+    /// Names registered through `register_sound`, the global mute and the
+    /// master volume applied to every play.
+    sounds: FxHashSet<SmolStr>,
+    sound_muted: bool,
+    sound_volume: f32,
+    /// Host displays as last reported by `set_monitors`, and the index of
+    /// the one holding the window.
+    monitors: Vec<MonitorInfo>,
+    current_monitor: Option<usize>,
+    /// High-contrast setting as last reported by `set_system_high_contrast`,
+    /// and the app's override of it.
+    system_high_contrast: bool,
+    contrast_preference: ContrastPreference,
+    /// Host window theme as last reported by `set_window_theme`; matched
+    /// by `media(theme: ...)` style blocks.
+    window_theme: WindowTheme,
+    /// [`crate::style::ElevationScale`] generation elevated elements were
+    /// last restyled for.
+    elevation_generation: u64,
     pending_platform_requests: PlatformRequests,
     /// Set inside `render_rsx` whenever any transition or
     /// animation plugin reports `keep_running`. Cleared at the start of
     /// every render. Hosts query this via `is_animating()` to decide
     /// whether to pump another frame immediately or idle.
     is_animating: bool,
+    /// Redraw mode and FPS cap consulted by `frame_schedule`.
+    frame_pacer: FramePacer,
+    /// Change check installed by `set_rebuild_poll`; run by `render_frame`.
+    rebuild_poll: Option<RebuildPoll>,
+    /// Virtual time set by deterministic hosts; `None` reads the wall
+    /// clock. Drives transitions, animation frames and frame pacing.
+    frame_clock: Option<Instant>,
+    /// Lay out (and run post-layout transitions) in `render_rsx` even
+    /// though no surface is attached.
+    headless_layout: bool,
+    /// Active input capture started by `start_input_recording`.
+    input_recorder: Option<InputRecorder>,
     app: Option<Box<dyn App>>,
     cached_rsx: Option<RsxNode>,
     needs_rebuild: bool,
@@ -278,6 +535,13 @@ impl Drop for Viewport {
     }
 }
 
+/// Change check behind [`ViewportControl::poll_for_rebuild`].
+struct RebuildPoll {
+    interval: Duration,
+    next_at: Instant,
+    changed: Box<dyn FnMut() -> bool>,
+}
+
 /// Phase-7 extraction. The retained scene tree and the per-node state
 /// layered on top of it: the concrete `ElementTrait` roots produced by the
 /// last reconcile pass, ad-hoc scroll offsets, element-side snapshot
@@ -349,14 +613,39 @@ struct FrameRuntime {
     draw_rect_uniform_offset: u64,
     gradient_stops_buffer: Option<GradientStopsBufferEntry>,
     gradient_stops_byte_cursor: u64,
+    /// Bind groups shared across frames, keyed by the objects they bind
+    /// ([`crate::view::render_pass::BindGroupCacheKey`]).
+    bind_group_cache: FxHashMap<crate::view::render_pass::BindGroupCacheKey, CachedBindGroupEntry>,
+    /// Pipeline cache (hits, misses) when the current frame began, so the
+    /// submitted frame can report its own share of the global counters.
+    pipeline_cache_base: (u64, u64),
+    /// Blurred shadow rasters left in persistent textures by submitted
+    /// frames, keyed by shadow raster id.
+    shadow_rasters: FxHashMap<u64, ShadowRasterEntry>,
+    /// Shadow rasters of the frame being built; committed into
+    /// `shadow_rasters` when it is submitted.
+    pending_shadow_rasters: Option<Arc<std::sync::Mutex<ShadowRasterFrame>>>,
+    /// Reuse counters for the frame being built; copied to
+    /// `last_render_reuse` when it is submitted.
+    render_reuse: RenderReuseMetrics,
+    last_render_reuse: RenderReuseMetrics,
     frame_stats: FrameStats,
     frame_presented: bool,
     #[cfg(test)]
     completion_counts: FrameCompletionCounts,
+    /// Last successfully compiled graph, reset and rebuilt in place the
+    /// next frame so its pass, resource and usage lists keep their
+    /// capacity. Per-pass boxes and other transient frame data still
+    /// allocate as before.
     last_frame_graph: Option<FrameGraph>,
     compile_cache: Option<CachedCompiledGraph>,
     debug_overlay_vertices: Vec<super::render_pass::debug_overlay_pass::DebugOverlayVertex>,
     debug_overlay_indices: Vec<u32>,
+    /// Distinct logical scissor rects draws were clipped to this frame,
+    /// recorded while passes prepare for the scissor overlay.
+    debug_scissor_rects: Vec<[u32; 4]>,
+    /// Repainted regions still fading out under paint flashing.
+    paint_flashes: Vec<debug_overlays::PaintFlash>,
     last_retained_auto_debug: Option<crate::view::debug::DebugRetainedAutoCaptureInput>,
     /// Stash for `App::build()` elapsed time (ms) so the render trace tree
     /// can include RSX build cost.  Set in `render_frame`, consumed in
@@ -386,6 +675,12 @@ impl FrameRuntime {
             draw_rect_uniform_offset: 0,
             gradient_stops_buffer: None,
             gradient_stops_byte_cursor: 0,
+            bind_group_cache: FxHashMap::default(),
+            pipeline_cache_base: (0, 0),
+            shadow_rasters: FxHashMap::default(),
+            pending_shadow_rasters: None,
+            render_reuse: RenderReuseMetrics::default(),
+            last_render_reuse: RenderReuseMetrics::default(),
             frame_stats: FrameStats::new(trace_fps),
             frame_presented: false,
             #[cfg(test)]
@@ -394,6 +689,8 @@ impl FrameRuntime {
             compile_cache: None,
             debug_overlay_vertices: Vec::new(),
             debug_overlay_indices: Vec::new(),
+            debug_scissor_rects: Vec::new(),
+            paint_flashes: Vec::new(),
             last_retained_auto_debug: None,
             rsx_build_ms: 0.0,
             frame_number: 0,
@@ -417,7 +714,13 @@ struct TransitionRuntime {
     visual_transition_plugin: VisualTransitionPlugin,
     style_transition_plugin: StyleTransitionPlugin,
     animation_plugin: AnimationPlugin,
+    /// Staggered waves started by `TransitionGroup` hosts that have not
+    /// settled yet.
+    transition_groups: TransitionGroupTracker,
+    /// Pause state and rate multiplier of the clock non-scroll plugins run on.
+    playback: TransitionPlayback,
     scroll_transition: ScrollTransition,
+    wheel_scroll_transition: WheelScrollTransition,
     last_transition_tick: Option<Instant>,
     transition_epoch: Option<Instant>,
 }
@@ -445,6 +748,11 @@ impl TransitionRuntime {
                 CHANNEL_STYLE_BOX_SHADOW,
                 CHANNEL_STYLE_TRANSFORM,
                 CHANNEL_STYLE_TRANSFORM_ORIGIN,
+                CHANNEL_STYLE_GAP,
+                CHANNEL_STYLE_PADDING_TOP,
+                CHANNEL_STYLE_PADDING_RIGHT,
+                CHANNEL_STYLE_PADDING_BOTTOM,
+                CHANNEL_STYLE_PADDING_LEFT,
             ]
             .into_iter()
             .collect(),
@@ -455,7 +763,10 @@ impl TransitionRuntime {
             visual_transition_plugin: VisualTransitionPlugin::new(),
             style_transition_plugin: StyleTransitionPlugin::new(),
             animation_plugin: AnimationPlugin::new(),
+            transition_groups: TransitionGroupTracker::default(),
+            playback: TransitionPlayback::new(),
             scroll_transition: ScrollTransition::new(250).ease_out(),
+            wheel_scroll_transition: WheelScrollTransition::new(),
             last_transition_tick: None,
             transition_epoch: None,
         }
@@ -469,6 +780,9 @@ impl TransitionRuntime {
 struct GpuContext {
     render_resource_scope_id: u64,
     surface: Option<wgpu::Surface<'static>>,
+    /// Render target used instead of a surface after
+    /// [`Viewport::attach_offscreen`]; sized from `surface_config`.
+    offscreen_target: Option<wgpu::Texture>,
     surface_config: wgpu::SurfaceConfiguration,
     /// Format pipelines writing to the surface compile against and that the
     /// per-frame surface view is created with. Equals `surface_config.format`
@@ -481,6 +795,16 @@ struct GpuContext {
     instance: Option<Instance>,
     window: Option<Window>,
     surface_format_preference: SurfaceFormatPreference,
+    color_management: ColorManagement,
+    /// Resolved when the surface is configured; installed as the thread's
+    /// output space at the start of every frame.
+    output_color_space: crate::style::OutputColorSpace,
+    /// Surface should let the desktop show through; picks a non-opaque
+    /// composite alpha mode when the surface supports one.
+    transparent: bool,
+    /// Alpha modes the configured surface reported, kept so toggling
+    /// `transparent` can reconfigure without re-querying the adapter.
+    supported_alpha_modes: Vec<wgpu::CompositeAlphaMode>,
     queue: Option<Queue>,
     msaa_sample_count: u32,
     depth_texture: Option<wgpu::Texture>,
@@ -2736,6 +3060,13 @@ pub(super) struct DrawRectUniformBufferEntry {
     /// at offset 0 / size=slot_size; the per-draw dynamic offset is supplied separately,
     /// so one bind group is valid for *all* slots in this buffer.
     pub(super) bind_groups: FxHashMap<u64, wgpu::BindGroup>,
+    /// What each slot held when the last frame was submitted.
+    pub(super) slots: RetainedUniformSlots,
+}
+
+pub(super) struct CachedBindGroupEntry {
+    pub(super) bind_group: wgpu::BindGroup,
+    pub(super) last_used_frame: u64,
 }
 
 pub(super) struct GradientStopsBufferEntry {
@@ -2775,8 +3106,11 @@ impl Viewport {
 
     pub fn new() -> Self {
         let debug_options = ViewportDebugOptions::from_env();
+        let user_agent_style = UserAgentStyle::default();
         Viewport {
             style: Style::new(),
+            cascade_root_style: user_agent_style.root.clone(),
+            user_agent_style,
             clear_color: Box::new(HexColor::new("#000000")),
             scale_factor: 1.0,
             logical_width: 1.0,
@@ -2784,6 +3118,7 @@ impl Viewport {
             gpu: GpuContext {
                 render_resource_scope_id: next_render_resource_scope_id(),
                 surface: None,
+                offscreen_target: None,
                 surface_config: wgpu::SurfaceConfiguration {
                     usage: TextureUsages::RENDER_ATTACHMENT
                         | TextureUsages::COPY_SRC
@@ -2802,6 +3137,10 @@ impl Viewport {
                 instance: None,
                 window: None,
                 surface_format_preference: SurfaceFormatPreference::default(),
+                color_management: ColorManagement::default(),
+                output_color_space: crate::style::OutputColorSpace::default(),
+                transparent: false,
+                supported_alpha_modes: Vec::new(),
                 queue: None,
                 msaa_sample_count: Self::DEFAULT_MSAA_SAMPLE_COUNT,
                 depth_texture: None,
@@ -2815,18 +3154,41 @@ impl Viewport {
             needs_reconfigure: false,
             redraw_requested: false,
             debug_options,
+            text_render_options: crate::view::render_pass::TextRenderOptions::default(),
+            pixel_snapping: false,
             paint_renderer_mode: ViewportPaintRendererMode::default(),
             retained_auto_terminal_failure: None,
             compositor: CompositorState::new(),
             input_state: InputState::default(),
+            global_listeners: GlobalListeners::default(),
             clipboard_fallback: None,
             dispatched_focus_node_id: None,
             scene: SceneState::new(),
             transitions: TransitionRuntime::new(),
             cursor_override: None,
             last_recorded_cursor: None,
+            title_bar_double_click: TitleBarDoubleClick::default(),
+            window_menu: None,
+            tray_icon: None,
+            native_menu_shortcuts: false,
+            notifications: FxHashMap::default(),
+            badge: None,
+            sounds: FxHashSet::default(),
+            sound_muted: false,
+            sound_volume: 1.0,
+            monitors: Vec::new(),
+            current_monitor: None,
+            system_high_contrast: false,
+            contrast_preference: ContrastPreference::System,
+            window_theme: WindowTheme::Light,
+            elevation_generation: crate::style::ElevationScale::generation(),
             pending_platform_requests: PlatformRequests::default(),
             is_animating: false,
+            frame_pacer: FramePacer::default(),
+            rebuild_poll: None,
+            frame_clock: None,
+            headless_layout: false,
+            input_recorder: None,
             app: None,
             cached_rsx: None,
             needs_rebuild: true,
@@ -2859,6 +3221,28 @@ impl Viewport {
         self.debug_options
     }
 
+    /// Reuse counters from the last submitted frame.
+    pub fn render_reuse_metrics(&self) -> RenderReuseMetrics {
+        self.frame.last_render_reuse
+    }
+
+    /// Opacity layer counts from the last property-tree sync.
+    pub fn opacity_group_metrics(&self) -> OpacityGroupMetrics {
+        let property_trees = &self.compositor.property_trees;
+        OpacityGroupMetrics {
+            isolated_groups: property_trees.effects.len(),
+            baked_nodes: property_trees.baked_opacity.len(),
+        }
+    }
+
+    pub fn text_render_options(&self) -> crate::view::render_pass::TextRenderOptions {
+        self.text_render_options
+    }
+
+    pub fn pixel_snapping(&self) -> bool {
+        self.pixel_snapping
+    }
+
     /// Returns the current production paint rollout mode.
     pub fn paint_renderer_mode(&self) -> ViewportPaintRendererMode {
         self.paint_renderer_mode
@@ -2942,8 +3326,40 @@ impl Viewport {
     }
 
     pub fn set_debug_options(&mut self, options: ViewportDebugOptions) {
+        let overlays_changed = !self.debug_options.same_overlay_modes(&options);
         self.debug_options = options;
         self.frame.frame_stats.set_enabled(options.trace_fps);
+        if !options.paint_flashing {
+            self.frame.paint_flashes.clear();
+        }
+        if overlays_changed {
+            self.request_redraw();
+        }
+    }
+
+    /// Applied by the text pass from the next frame on.
+    pub fn set_text_render_options(
+        &mut self,
+        options: crate::view::render_pass::TextRenderOptions,
+    ) {
+        if self.text_render_options == options {
+            return;
+        }
+        self.text_render_options = options;
+        self.request_redraw();
+    }
+
+    /// When enabled, rect draws from elements that are not moving round
+    /// their edges and border widths to whole device pixels, so borders and
+    /// dividers stay crisp at fractional positions and scale factors.
+    /// Elements mid-transition keep their fractional positions to avoid
+    /// visible stepping.
+    pub fn set_pixel_snapping(&mut self, enabled: bool) {
+        if self.pixel_snapping == enabled {
+            return;
+        }
+        self.pixel_snapping = enabled;
+        self.request_redraw();
     }
 
     pub(crate) fn debug_overlay_enabled(&self) -> bool {
@@ -2953,6 +3369,7 @@ impl Viewport {
     pub(crate) fn clear_debug_overlay_geometry(&mut self) {
         self.frame.debug_overlay_vertices.clear();
         self.frame.debug_overlay_indices.clear();
+        self.frame.debug_scissor_rects.clear();
     }
 
     pub(crate) fn push_debug_overlay_geometry(
@@ -3032,6 +3449,38 @@ impl Viewport {
         let scale = self.scale_factor.max(0.0001);
         self.logical_width = (physical_width as f32 / scale).max(1.0);
         self.logical_height = (physical_height as f32 / scale).max(1.0);
+        crate::ui::set_viewport_size(self.logical_width, self.logical_height);
+        self.sync_media_context();
+    }
+
+    /// Publish size, scale factor and window theme for `media` style
+    /// blocks, restyling the elements whose matching blocks flipped.
+    fn sync_media_context(&mut self) {
+        let media = MediaContext {
+            viewport_width: self.logical_width,
+            viewport_height: self.logical_height,
+            scale_factor: self.scale_factor,
+            theme: self.window_theme,
+        };
+        if MediaContext::set_current(media)
+            && scene_helpers::refresh_media_styles(&self.scene.node_arena, &self.scene.ui_root_keys)
+        {
+            self.request_redraw();
+        }
+    }
+
+    /// Restyle elevated elements once a new
+    /// [`crate::style::ElevationScale`] was published, e.g. by a theme
+    /// switch.
+    pub(super) fn sync_elevation_scale(&mut self) {
+        let generation = crate::style::ElevationScale::generation();
+        if generation == self.elevation_generation {
+            return;
+        }
+        self.elevation_generation = generation;
+        if scene_helpers::refresh_media_styles(&self.scene.node_arena, &self.scene.ui_root_keys) {
+            self.request_redraw();
+        }
     }
 
     pub fn frame_box_models(&self) -> &[super::base_component::BoxModelSnapshot] {
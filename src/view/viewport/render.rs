@@ -679,6 +679,8 @@ fn debug_legacy_fallback(
         }
         LegacyPaintReason::Transform
         | LegacyPaintReason::BoxShadow
+        | LegacyPaintReason::BorderImage
+        | LegacyPaintReason::LayerEffect
         | LegacyPaintReason::SelfClip
         | LegacyPaintReason::ChildClip
         | LegacyPaintReason::ScrollContainer => Category::PropertyTopology,
@@ -710,6 +712,8 @@ fn legacy_fallback_reason_label(reason: crate::view::paint::LegacyPaintReason) -
         LegacyPaintReason::HasChildren => "has-children",
         LegacyPaintReason::Transform => "transform",
         LegacyPaintReason::BoxShadow => "box-shadow",
+        LegacyPaintReason::BorderImage => "border-image",
+        LegacyPaintReason::LayerEffect => "layer-effect",
         LegacyPaintReason::SelfClip => "self-clip",
         LegacyPaintReason::ChildClip => "child-clip",
         LegacyPaintReason::ScrollContainer => "scroll-container",
@@ -2068,6 +2072,16 @@ impl Viewport {
         self.run_layout_pass_with_registered_sync(true)
     }
 
+    /// Measure and place the committed tree without acquiring a frame.
+    /// `render_rsx` only lays out when it can render, so hosts without a
+    /// surface, such as benchmarks, call this after committing a tree.
+    pub fn layout_headless(&mut self) {
+        if self.scene.ui_root_keys.is_empty() {
+            return;
+        }
+        let _ = self.run_layout_pass();
+    }
+
     /// A transition-triggered second layout belongs to the same rendered
     /// frame. Resource-backed hosts were already frozen by the first pass, so
     /// repeating the arena sync here could mix two async resource generations
@@ -2080,6 +2094,9 @@ impl Viewport {
         &mut self,
         sync_registered_elements: bool,
     ) -> LayoutPassResult {
+        if sync_registered_elements {
+            self.sync_elevation_scale();
+        }
         self.compositor.frame_box_models.clear();
         crate::view::base_component::reset_text_measure_profile();
         crate::view::base_component::reset_layout_gate_candidate_profile();
@@ -2093,6 +2110,7 @@ impl Viewport {
             ..Default::default()
         };
 
+        let measure_span = crate::trace::span!("measure", roots = root_keys.len());
         let measure_started_at = Instant::now();
         let constraints = crate::view::base_component::LayoutConstraints {
             max_width: self.logical_width,
@@ -2144,8 +2162,10 @@ impl Viewport {
         traversal_profile.measure_roots_ms =
             measure_roots_started_at.elapsed().as_secs_f64() * 1000.0;
         let measure_ms = measure_started_at.elapsed().as_secs_f64() * 1000.0;
+        measure_span.exit();
         let text_measure_profile = crate::view::base_component::take_text_measure_profile();
 
+        let place_span = crate::trace::span!("place", roots = root_keys.len());
         let place_started_at = Instant::now();
         crate::view::base_component::reset_layout_place_profile();
         let placement = crate::view::base_component::LayoutPlacement {
@@ -2191,6 +2211,7 @@ impl Viewport {
         }
         traversal_profile.place_roots_ms = place_roots_started_at.elapsed().as_secs_f64() * 1000.0;
         let place_ms = place_started_at.elapsed().as_secs_f64() * 1000.0;
+        place_span.exit();
         let place_profile = crate::view::base_component::take_layout_place_profile();
         let gate_profile = crate::view::base_component::take_layout_gate_candidate_profile();
         traversal_profile.measure_candidate_clean_children =
@@ -2770,8 +2791,10 @@ impl Viewport {
         // elapsed-time diagnostics; retained frame semantics use the sample
         // captured once by `render_rsx`.
         let profile_start = Instant::now();
+        crate::style::set_output_color_space(self.gpu.output_color_space);
         self.frame.frame_number = self.frame.frame_number.saturating_add(1);
         let frame_number = self.frame.frame_number;
+        self.release_removed_sampled_textures();
         // A failed surface acquisition still represents a render attempt.
         // Clear test-only capture before `begin_frame` so callers can never
         // observe telemetry retained from the preceding successful frame.
@@ -2800,6 +2823,7 @@ impl Viewport {
         crate::view::base_component::set_layout_place_profile_enabled(
             self.debug_options.trace_render_time,
         );
+        let layout_span = crate::trace::span!("layout");
         let layout_started_at = Instant::now();
         let layout_result = self.run_layout_pass();
         timings.layout_measure_ms = layout_result.measure_ms;
@@ -2809,6 +2833,7 @@ impl Viewport {
         timings.layout_text_measure_profile = layout_result.text_measure_profile;
         timings.layout_place_profile = layout_result.place_profile;
         timings.layout_ms = layout_started_at.elapsed().as_secs_f64() * 1000.0;
+        layout_span.exit();
 
         // After layout is resolved for this frame, immediately run visual/style/scroll transitions
         // so their updated endpoints are visible in the same frame.
@@ -2820,6 +2845,7 @@ impl Viewport {
         // --- Relayout after transition (if needed) ---
         let relayout_started_at = Instant::now();
         if post_layout_transition.relayout_required {
+            let _span = crate::trace::span!("relayout");
             let relayout_result = self.run_relayout_pass();
             timings.relayout_measure_ms = relayout_result.measure_ms;
             timings.relayout_place_ms = relayout_result.place_ms;
@@ -2850,6 +2876,7 @@ impl Viewport {
             self.scene.node_arena = arena;
             changed
         };
+        self.dispatch_visibility_changes();
 
         // Final layout is now stable. Freeze resource-backed paint payloads
         // exactly once for this frame before property-tree observation and
@@ -2870,15 +2897,23 @@ impl Viewport {
         self.sync_compositor_property_trees();
 
         // --- Build frame graph ---
+        let build_graph_span = crate::trace::span!("frame_graph_build");
         let build_graph_started_at = Instant::now();
         self.clear_debug_overlay_geometry();
-        let mut graph = FrameGraph::new();
+        let mut graph = match self.frame.last_frame_graph.take() {
+            Some(mut graph) => {
+                graph.reset();
+                graph
+            }
+            None => FrameGraph::new(),
+        };
         let mut ctx = crate::view::base_component::UiBuildContext::new(
             self.gpu.surface_config.width,
             self.gpu.surface_config.height,
             self.offscreen_format(),
             self.scale_factor,
         );
+        ctx.set_shadow_raster_frame(self.begin_shadow_raster_frame());
         let retained_surface_frame_owner = self.begin_retained_surface_frame_stage();
         let root_keys_for_build = self.scene.ui_root_keys.clone();
         let capture_paint_authority_telemetry = self.debug_options.trace_render_time
@@ -2987,18 +3022,11 @@ impl Viewport {
                 }
             }
         }
-        let clear_uses_premultiplied_alpha = matches!(
+        let clear_rgba = crate::view::render_pass::clear_pass::ClearParams::for_alpha_mode(
+            self.clear_color.to_output_rgba_f32(),
             self.gpu.surface_config.alpha_mode,
-            wgpu::CompositeAlphaMode::PostMultiplied | wgpu::CompositeAlphaMode::PreMultiplied
-        );
-        let mut clear_rgba = self.clear_color.to_rgba_f32();
-        if clear_uses_premultiplied_alpha {
-            let a = clear_rgba[3].clamp(0.0, 1.0);
-            clear_rgba[0] *= a;
-            clear_rgba[1] *= a;
-            clear_rgba[2] *= a;
-            clear_rgba[3] = a;
-        }
+        )
+        .color;
         let native_scroll_forest_owner = if matches!(
             retained_transform_selection,
             RetainedTransformCanarySelection::NativeScrollForestPlanned(_)
@@ -4547,6 +4575,14 @@ impl Viewport {
             paint_authority_telemetry.as_ref(),
             &root_keys_for_build,
         );
+        if let Some(target) = ctx.current_target() {
+            self.append_debug_overlay_passes(
+                &mut graph,
+                target,
+                &root_keys_for_build,
+                semantic_now,
+            );
+        }
         let dependency_handle = ctx.current_target().and_then(|target| target.handle());
         if let Some(dep_handle) = dependency_handle {
             let present_pass =
@@ -4570,6 +4606,7 @@ impl Viewport {
                 .expect("surface present sink should register");
         }
         timings.build_graph_ms = build_graph_started_at.elapsed().as_secs_f64() * 1000.0;
+        build_graph_span.exit();
 
         // --- Compile ---
         // Take the cache out (moves ownership) so we can pass self mutably to compile.
@@ -4581,11 +4618,21 @@ impl Viewport {
             .take()
             .map(|c| (c.topology_key, c.graph));
         let mut compiled_topology_key = None;
+        let compile_span = crate::trace::span!("frame_graph_compile");
         let compiled = match graph.compile_with_upload_cached(self, prior_cache) {
             Ok((profile, topology_key)) => {
                 timings.compile_ms = profile.total_ms;
+                self.frame.render_reuse.compiled_graph_reused = profile.topology_cache_hit;
                 timings.compile_children =
                     build_compile_trace_nodes(&profile, self.debug_options.trace_compile_detail);
+                timings.compile_children.push(TraceRenderNode::new(
+                    format!(
+                        "rect_uniforms (written={}, reused={})",
+                        self.frame.render_reuse.rect_uniforms_written,
+                        self.frame.render_reuse.rect_uniforms_reused
+                    ),
+                    0.0,
+                ));
                 compiled_topology_key = Some(topology_key);
                 true
             }
@@ -4595,10 +4642,12 @@ impl Viewport {
                 false
             }
         };
+        compile_span.exit();
 
         // --- Execute ---
         let mut executed = false;
         if compiled {
+            let _span = crate::trace::span!("frame_graph_execute");
             match graph.execute_profiled(self, self.debug_options.trace_render_time) {
                 Ok(profile) => {
                     timings.execute_ms = profile.total_ms;
@@ -4675,11 +4724,19 @@ impl Viewport {
         // must settle before the acquired frame is either submitted or
         // discarded. A terminal compile/execute failure never submits a
         // partially recorded encoder and never presents its surface image.
+        let end_frame_span = crate::trace::span!("end_frame");
         let end_frame_profile = self.complete_frame(frame_disposition(compiled, executed));
+        end_frame_span.exit();
         timings.end_frame_ms = end_frame_profile.total_ms;
         timings.end_frame_submit_ms = end_frame_profile.submit_ms;
         timings.end_frame_present_ms = end_frame_profile.present_ms;
         timings.total_ms = profile_start.elapsed().as_secs_f64() * 1000.0;
+        crate::trace::counters!(
+            frame = frame_number,
+            passes = timings.execute_pass_count,
+            compile_cache_hit = self.frame.render_reuse.compiled_graph_reused,
+            total_ms = timings.total_ms,
+        );
 
         // --- Trace output ---
         if self.debug_options.trace_render_time {
@@ -4698,11 +4755,41 @@ impl Viewport {
         post_layout_transition.redraw_changed || post_layout_animation_changed
     }
 
+    /// The layout half of `render_render_tree` for hosts without a
+    /// surface: layout, post-layout transitions, relayout and post-layout
+    /// animation frames, with no frame graph or paint.
+    fn layout_without_surface(
+        &mut self,
+        dt: f32,
+        now_seconds: f64,
+        semantic_now: crate::time::Instant,
+    ) -> bool {
+        let _ = self.run_layout_pass();
+        let post_layout_transition = self.run_post_layout_transitions(dt, now_seconds);
+        if post_layout_transition.relayout_required {
+            let _ = self.run_relayout_pass();
+            self.resync_pointer_hover();
+        }
+        let post_layout_animation_changed = {
+            let mut arena = std::mem::take(&mut self.scene.node_arena);
+            let root_keys = self.scene.ui_root_keys.clone();
+            let changed = crate::view::base_component::tick_post_layout_animation_frames(
+                &mut arena,
+                &root_keys,
+                semantic_now,
+            );
+            self.scene.node_arena = arena;
+            changed
+        };
+        self.dispatch_visibility_changes();
+        post_layout_transition.redraw_changed || post_layout_animation_changed
+    }
+
     pub fn render_rsx(&mut self, root: &RsxNode) -> Result<(), String> {
         // The sole semantic engine-time sample for this viewport frame. Every
         // retained animation tick and paint-resource freeze observes this
         // exact value; profiling clocks below remain observational only.
-        let semantic_now = crate::time::Instant::now();
+        let semantic_now = self.frame_now();
         let state_dirty = take_state_dirty();
         // Apply any viewport mutations that component event handlers
         // enqueued via `use_viewport()` during the previous tick. Must
@@ -4742,14 +4829,17 @@ impl Viewport {
             // the arena stores (Fragment root → N arena roots).
             let old_roots = unpack_root_set(previous_root);
             let new_roots = unpack_root_set(root);
+            let reconcile_span = crate::trace::span!("reconcile");
             let rooted_patches = crate::ui::reconcile_multi(Some(&old_roots), &new_roots);
+            reconcile_span.exit();
+            crate::trace::counters!(patches = rooted_patches.len());
             let descriptor_ctx = crate::view::fiber_work::DescriptorContext {
                 new_rsx_root: root,
                 // 軌 1 #6: pass the previous tree so the translator
                 // can identity-validate parent_path walks for
                 // InsertChild patches.
                 old_rsx_root: Some(previous_root),
-                inherited_style: &self.style,
+                inherited_style: &self.cascade_root_style,
                 viewport_width: self.logical_width,
                 viewport_height: self.logical_height,
             };
@@ -4775,8 +4865,16 @@ impl Viewport {
                         &self.scene.ui_root_keys,
                         &mut incremental_scroll_offsets,
                     );
+                    let focused_stable_id = self.focused_stable_id();
+                    let mut incremental_host_states =
+                        super::scene_helpers::HostStateSnapshots::default();
+                    Self::save_host_states(
+                        &self.scene.node_arena,
+                        &self.scene.ui_root_keys,
+                        &mut incremental_host_states,
+                    );
                     let apply_ctx = crate::view::fiber_work::ApplyContext {
-                        viewport_style: &self.style,
+                        viewport_style: &self.cascade_root_style,
                         viewport_width: self.logical_width,
                         viewport_height: self.logical_height,
                     };
@@ -4797,6 +4895,12 @@ impl Viewport {
                                 &self.scene.ui_root_keys,
                                 &incremental_scroll_offsets,
                             );
+                            Self::restore_host_states(
+                                &self.scene.node_arena,
+                                &self.scene.ui_root_keys,
+                                &mut incremental_host_states,
+                            );
+                            self.refocus_by_stable_id(focused_stable_id);
                             self.scene.last_rsx_root = Some(root.clone());
                             needs_rebuild = false;
                         }
@@ -4825,6 +4929,13 @@ impl Viewport {
                 &self.scene.ui_root_keys,
                 &mut self.scene.scroll_offsets,
             );
+            let focused_stable_id = self.focused_stable_id();
+            let mut host_states = super::scene_helpers::HostStateSnapshots::default();
+            Self::save_host_states(
+                &self.scene.node_arena,
+                &self.scene.ui_root_keys,
+                &mut host_states,
+            );
             let layout_snapshots =
                 crate::view::viewport::transitions_tick::collect_layout_transition_snapshots(
                     &self.scene.node_arena,
@@ -4833,7 +4944,7 @@ impl Viewport {
             let (converted_descriptors, conversion_errors) =
                 crate::view::renderer_adapter::rsx_to_descriptors_with_context(
                     root,
-                    &self.style,
+                    &self.cascade_root_style,
                     self.logical_width,
                     self.logical_height,
                 );
@@ -4876,6 +4987,12 @@ impl Viewport {
                 &self.scene.ui_root_keys,
                 &self.scene.scroll_offsets,
             );
+            Self::restore_host_states(
+                &self.scene.node_arena,
+                &self.scene.ui_root_keys,
+                &mut host_states,
+            );
+            self.refocus_by_stable_id(focused_stable_id);
             {
                 let mut arena = std::mem::take(&mut self.scene.node_arena);
                 let root_keys = self.scene.ui_root_keys.clone();
@@ -4935,8 +5052,11 @@ impl Viewport {
             || self.run_pre_layout_transitions(dt, now_seconds);
         let mut transition_changed_after_layout = false;
         if !self.scene.ui_root_keys.is_empty() {
-            transition_changed_after_layout =
-                self.render_render_tree(dt, now_seconds, semantic_now);
+            transition_changed_after_layout = if self.headless_layout {
+                self.layout_without_surface(dt, now_seconds, semantic_now)
+            } else {
+                self.render_render_tree(dt, now_seconds, semantic_now)
+            };
         }
         let next_hover_target = self.pointer_position_viewport().and_then(|(x, y)| {
             Self::hit_test_pointer_target(
@@ -4948,7 +5068,7 @@ impl Viewport {
             )
             .map(|(_, t)| t)
         });
-        // Re-applying hover flags is a whole-tree walk; skip it when the
+        // Re-applying hover flags walks the hovered chain; skip it when the
         // hover target is unchanged and the arena was not rebuilt this
         // frame (a rebuild drops the per-node hover flags).
         let hover_changed =
@@ -4957,11 +5077,16 @@ impl Viewport {
             } else {
                 let mut arena = std::mem::take(&mut self.scene.node_arena);
                 let root_keys = self.scene.ui_root_keys.clone();
+                let pointer = self.pointer_position_viewport();
+                let now = self.frame_now();
                 let result = Self::sync_hover_visual_only(
                     &mut arena,
                     &root_keys,
                     &mut self.input_state.hovered_node_id,
+                    &mut self.input_state.hovered_path,
                     next_hover_target,
+                    pointer,
+                    Some((&mut self.input_state.hover_intent, now)),
                 );
                 self.scene.node_arena = arena;
                 result
@@ -5000,12 +5125,22 @@ impl Viewport {
         if self.app.is_none() {
             return super::RenderFrameResult::Ok;
         }
+        let _span = crate::trace::span!("frame");
+        let now = self.frame_now();
+        self.frame_pacer.record_frame(now);
+        self.dispatch_long_press_if_due(now);
+        self.dispatch_hover_intent_if_due(now);
+        crate::ui::run_animation_frame_hooks(now);
 
         if peek_state_dirty().needs_rebuild() {
             self.needs_rebuild = true;
         }
+        if self.run_rebuild_poll(now) {
+            self.needs_rebuild = true;
+        }
 
         if self.needs_rebuild || self.cached_rsx.is_none() {
+            let _span = crate::trace::span!("app_build");
             let build_start = Instant::now();
             let rsx = self.with_app(services, |app, ctx| app.build(ctx));
             self.frame.rsx_build_ms = build_start.elapsed().as_secs_f64() * 1000.0;
@@ -5026,12 +5161,17 @@ impl Viewport {
         }
     }
 
-    /// Forward an `AppEvent` to the held `App::on_event`.
+    /// Forward an `AppEvent` to the held `App::on_event`, logging it first
+    /// while [`Self::start_input_recording`] is capturing.
     pub fn dispatch_app_event(
         &mut self,
         event: &crate::app::AppEvent,
         services: crate::platform::PlatformServices<'_>,
     ) {
+        let now = self.frame_now();
+        if let Some(recorder) = self.input_recorder.as_mut() {
+            recorder.record(now, event);
+        }
         self.with_app(services, |app, ctx| app.on_event(event, ctx));
     }
 
@@ -5082,10 +5222,22 @@ impl Viewport {
     }
 
     /// Drain the thread-local queue populated by `ui::use_viewport()` and
-    /// apply each action to this viewport. Called at the top of
-    /// `render_rsx` so event handlers from the prior frame land
-    /// before dirty flags are read.
-    fn apply_pending_viewport_actions(&mut self) {
+    /// apply each action to this viewport, after the style updates queued
+    /// through element handles. Called at the top of `render_rsx` so
+    /// event handlers from the prior frame land before dirty flags are
+    /// read.
+    pub(super) fn apply_pending_viewport_actions(&mut self) {
+        let apply_ctx = crate::view::fiber_work::ApplyContext {
+            viewport_style: &self.cascade_root_style,
+            viewport_width: self.logical_width,
+            viewport_height: self.logical_height,
+        };
+        if crate::view::style_patch::apply_pending_style_patches(
+            &mut self.scene.node_arena,
+            apply_ctx,
+        ) {
+            self.request_redraw();
+        }
         let actions = crate::ui::drain_viewport_actions();
         if actions.is_empty() {
             return;
@@ -5130,6 +5282,9 @@ impl Viewport {
                     self.set_cursor(cursor);
                 }
                 crate::ui::ViewportAction::RequestRedraw => self.request_redraw(),
+                crate::ui::ViewportAction::PlaySound(name) => {
+                    self.play_sound(&name);
+                }
             }
         }
     }
@@ -5155,48 +5310,52 @@ impl Viewport {
         self.frame.draw_rect_uniform_cursor = 0;
         self.frame.draw_rect_uniform_offset = 0;
         self.frame.gradient_stops_byte_cursor = 0;
+        self.frame.render_reuse = RenderReuseMetrics::default();
+        self.frame.pipeline_cache_base = crate::view::frame_graph::pipeline_cache_totals();
         crate::view::render_pass::draw_rect_pass::begin_draw_rect_resources_frame();
         crate::view::render_pass::shadow_module::begin_shadow_resources_frame();
         crate::view::render_pass::text_pass::begin_text_resources_frame();
 
-        let surface = match &self.gpu.surface {
-            Some(s) => s,
-            None => return None,
-        };
         let device = match &self.gpu.device {
             Some(d) => d,
             None => return None,
         };
 
         let acquire_started_at = Instant::now();
-        let render_texture = match surface.get_current_texture() {
-            wgpu::CurrentSurfaceTexture::Success(texture) => texture,
-            wgpu::CurrentSurfaceTexture::Suboptimal(texture) => {
-                surface.configure(device, &self.gpu.surface_config);
-                texture
-            }
-            wgpu::CurrentSurfaceTexture::Lost | wgpu::CurrentSurfaceTexture::Outdated => {
-                println!("[warn] surface lost, recreate render texture");
-                surface.configure(device, &self.gpu.surface_config);
-                match surface.get_current_texture() {
-                    wgpu::CurrentSurfaceTexture::Success(texture)
-                    | wgpu::CurrentSurfaceTexture::Suboptimal(texture) => texture,
-                    _ => return None,
+        let render_texture = match (&self.gpu.surface, &self.gpu.offscreen_target) {
+            (Some(surface), _) => Some(match surface.get_current_texture() {
+                wgpu::CurrentSurfaceTexture::Success(texture) => texture,
+                wgpu::CurrentSurfaceTexture::Suboptimal(texture) => {
+                    surface.configure(device, &self.gpu.surface_config);
+                    texture
                 }
-            }
-            wgpu::CurrentSurfaceTexture::Timeout
-            | wgpu::CurrentSurfaceTexture::Occluded
-            | wgpu::CurrentSurfaceTexture::Validation => return None,
+                wgpu::CurrentSurfaceTexture::Lost | wgpu::CurrentSurfaceTexture::Outdated => {
+                    println!("[warn] surface lost, recreate render texture");
+                    surface.configure(device, &self.gpu.surface_config);
+                    match surface.get_current_texture() {
+                        wgpu::CurrentSurfaceTexture::Success(texture)
+                        | wgpu::CurrentSurfaceTexture::Suboptimal(texture) => texture,
+                        _ => return None,
+                    }
+                }
+                wgpu::CurrentSurfaceTexture::Timeout
+                | wgpu::CurrentSurfaceTexture::Occluded
+                | wgpu::CurrentSurfaceTexture::Validation => return None,
+            }),
+            (None, Some(_)) => None,
+            (None, None) => return None,
         };
         let acquire_ms = acquire_started_at.elapsed().as_secs_f64() * 1000.0;
 
         let create_view_started_at = Instant::now();
-        let surface_view = render_texture
-            .texture
-            .create_view(&wgpu::TextureViewDescriptor {
-                format: Some(self.gpu.surface_target_format),
-                ..Default::default()
-            });
+        let target_texture = match &render_texture {
+            Some(render_texture) => &render_texture.texture,
+            None => self.gpu.offscreen_target.as_ref()?,
+        };
+        let surface_view = target_texture.create_view(&wgpu::TextureViewDescriptor {
+            format: Some(self.gpu.surface_target_format),
+            ..Default::default()
+        });
         let (view, resolve_view) = (surface_view, None);
         let create_view_ms = create_view_started_at.elapsed().as_secs_f64() * 1000.0;
 
@@ -5206,11 +5365,8 @@ impl Viewport {
         let create_encoder_ms = create_encoder_started_at.elapsed().as_secs_f64() * 1000.0;
 
         self.frame.frame_state = Some(FrameState {
-            #[cfg(not(test))]
             render_texture,
             #[cfg(test)]
-            render_texture: Some(render_texture),
-            #[cfg(test)]
             offscreen_texture: None,
             view,
             resolve_view,
@@ -5260,6 +5416,8 @@ impl Viewport {
         self.frame.draw_rect_uniform_cursor = 0;
         self.frame.draw_rect_uniform_offset = 0;
         self.frame.gradient_stops_byte_cursor = 0;
+        self.frame.render_reuse = RenderReuseMetrics::default();
+        self.frame.pipeline_cache_base = crate::view::frame_graph::pipeline_cache_totals();
         crate::view::render_pass::draw_rect_pass::begin_draw_rect_resources_frame();
         crate::view::render_pass::shadow_module::begin_shadow_resources_frame();
         crate::view::render_pass::text_pass::begin_text_resources_frame();
@@ -5357,6 +5515,10 @@ impl Viewport {
         };
 
         frame.discard_unsubmitted();
+        for entry in &mut self.frame.draw_rect_uniform_pool {
+            entry.slots.discard_pending();
+        }
+        self.frame.pending_shadow_rasters = None;
 
         #[cfg(not(target_arch = "wasm32"))]
         {
@@ -5399,6 +5561,21 @@ impl Viewport {
             self.frame.completion_counts.submits =
                 self.frame.completion_counts.submits.saturating_add(1);
         }
+        for entry in &mut self.frame.draw_rect_uniform_pool {
+            entry.slots.commit();
+        }
+        if let Some(rasters) = self.frame.pending_shadow_rasters.take() {
+            let current = rasters
+                .lock()
+                .expect("shadow raster cache lock poisoned")
+                .take_current();
+            self.frame.shadow_rasters.extend(current);
+        }
+        let (pipeline_hits, pipeline_misses) = crate::view::frame_graph::pipeline_cache_totals();
+        let (base_hits, base_misses) = self.frame.pipeline_cache_base;
+        self.frame.render_reuse.pipelines_reused = pipeline_hits.saturating_sub(base_hits);
+        self.frame.render_reuse.pipelines_created = pipeline_misses.saturating_sub(base_misses);
+        self.frame.last_render_reuse = self.frame.render_reuse;
         #[cfg(not(target_arch = "wasm32"))]
         if let Some(staging_belt) = self.gpu.upload_staging_belt.as_mut() {
             staging_belt.recall();
@@ -5408,13 +5585,13 @@ impl Viewport {
         let submit_ms = submit_started_at.elapsed().as_secs_f64() * 1000.0;
 
         let present_started_at = Instant::now();
-        #[cfg(not(test))]
-        queue.present(frame.render_texture);
-        #[cfg(test)]
         if let Some(render_texture) = frame.render_texture {
             queue.present(render_texture);
-            self.frame.completion_counts.presents =
-                self.frame.completion_counts.presents.saturating_add(1);
+            #[cfg(test)]
+            {
+                self.frame.completion_counts.presents =
+                    self.frame.completion_counts.presents.saturating_add(1);
+            }
         }
         let present_ms = present_started_at.elapsed().as_secs_f64() * 1000.0;
         #[cfg(not(target_arch = "wasm32"))]
@@ -160,6 +160,26 @@ fn production_clip_policy_admits_exact_deferred_root_and_rejects_other_boundarie
     ));
 }
 
+#[test]
+fn production_clip_policy_routes_a_masked_root_through_a_layer_effect() {
+    let mut masked = colored_element(0x8c35, 10.0, Color::rgb(230, 20, 30));
+    let mut style = Style::new();
+    style.set_mask(crate::style::Gradient::linear(
+        crate::style::SideOrCorner::Right,
+    ));
+    masked.apply_style(style);
+    let mut arena = new_test_arena();
+    let root = commit_element(&mut arena, Box::new(masked));
+    let (measure, place) = constraints();
+    measure_and_place(&mut arena, root, measure, place);
+    let reasons = preflight_fallback_reasons(&arena, &[root]);
+    assert!(reasons.contains(
+        &crate::view::paint::FrameArtifactFallbackReason::LegacyBoundary(
+            crate::view::paint::LegacyPaintReason::LayerEffect,
+        ),
+    ));
+}
+
 #[test]
 fn production_root_opacity_with_clip_records_and_compiles_once() {
     let mut clipped = colored_element(0x8c40, 10.0, Color::rgb(230, 20, 30));
@@ -432,7 +452,10 @@ fn production_artifact_canary_dispatches_outer_shadow_atomically_for_m6a_and_c1(
     let fills =
         c1.test_graphics_passes::<crate::view::render_pass::shadow_module::ShadowFillPass>();
     assert_eq!(fills.len(), 1);
-    assert_eq!(fills[0].test_snapshot().color_bits[3], 1.0_f32.to_bits());
+    assert_eq!(
+        fills[0].test_snapshot().vertex_color_bits[0][3],
+        1.0_f32.to_bits()
+    );
 
     let (arena, roots) = prepared_outer_shadow_leaf(1.0, 0.000_5);
     crate::view::paint::take_full_artifact_record_count();
@@ -7,6 +7,10 @@ use crate::view::base_component::{
     BoxModelSnapshot, DirtyFlags, ElementTrait, RetainedSurfaceBounds, round_layout_value,
 };
 
+/// Host snapshots by stable id, with the node key they were taken from.
+pub(super) type HostStateSnapshots =
+    FxHashMap<u64, (crate::view::node_arena::NodeKey, Box<dyn std::any::Any>)>;
+
 impl Viewport {
     pub(super) fn cancel_pointer_interactions(
         arena: &crate::view::node_arena::NodeArena,
@@ -20,36 +24,72 @@ impl Viewport {
         changed
     }
 
-    pub(super) fn apply_hover_target(
+    pub(super) fn apply_hover_path(
         arena: &crate::view::node_arena::NodeArena,
-        root_keys: &[crate::view::node_arena::NodeKey],
-        target: Option<crate::view::node_arena::NodeKey>,
+        hovered_path: &mut Vec<crate::view::node_arena::NodeKey>,
+        next_path: Vec<crate::view::node_arena::NodeKey>,
     ) -> bool {
-        let mut changed = false;
-        for &root_key in root_keys {
-            if crate::view::viewport::scene_helpers::update_hover_state(arena, root_key, target) {
-                changed = true;
+        let changed = crate::view::viewport::scene_helpers::update_hover_path(
+            arena,
+            hovered_path,
+            &next_path,
+        );
+        *hovered_path = next_path;
+        changed
+    }
+
+    /// Chain that should carry hover once `next_target` is under the
+    /// pointer: its root-to-target path, adjusted by the hover intent of
+    /// the nodes entering and leaving it when `intent` is given.
+    fn resolve_hover_path(
+        arena: &crate::view::node_arena::NodeArena,
+        root_keys: &[crate::view::node_arena::NodeKey],
+        hovered_path: &[crate::view::node_arena::NodeKey],
+        next_target: Option<crate::view::node_arena::NodeKey>,
+        pointer: Option<(f32, f32)>,
+        intent: Option<(&mut HoverIntentState, Instant)>,
+    ) -> Vec<crate::view::node_arena::NodeKey> {
+        let raw_path = crate::view::viewport::scene_helpers::hover_path_for_target(
+            arena,
+            root_keys,
+            next_target,
+        );
+        match intent {
+            Some((intent, now)) => {
+                intent.resolve(arena, root_keys, hovered_path, &raw_path, pointer, now)
             }
+            None => raw_path,
         }
-        changed
     }
 
     pub(super) fn sync_hover_target(
         arena: &crate::view::node_arena::NodeArena,
         root_keys: &[crate::view::node_arena::NodeKey],
         hovered_node_id: &mut Option<crate::view::node_arena::NodeKey>,
+        hovered_path: &mut Vec<crate::view::node_arena::NodeKey>,
         next_target: Option<crate::view::node_arena::NodeKey>,
         pointer: crate::ui::PointerEventData,
+        intent: Option<(&mut HoverIntentState, Instant)>,
     ) -> (bool, bool) {
-        let transition_dispatched = crate::view::viewport::scene_helpers::dispatch_hover_transition(
+        let next_path = Self::resolve_hover_path(
             arena,
             root_keys,
-            *hovered_node_id,
+            hovered_path,
             next_target,
-            pointer,
+            Some((pointer.viewport_x, pointer.viewport_y)),
+            intent,
         );
+        let transition_dispatched =
+            crate::view::viewport::scene_helpers::dispatch_hover_path_transition(
+                arena,
+                hovered_path,
+                &next_path,
+                *hovered_node_id,
+                next_target,
+                pointer,
+            );
         *hovered_node_id = next_target;
-        let hover_changed = Self::apply_hover_target(arena, root_keys, next_target);
+        let hover_changed = Self::apply_hover_path(arena, hovered_path, next_path);
         (hover_changed, transition_dispatched)
     }
 
@@ -57,10 +97,15 @@ impl Viewport {
         arena: &crate::view::node_arena::NodeArena,
         root_keys: &[crate::view::node_arena::NodeKey],
         hovered_node_id: &mut Option<crate::view::node_arena::NodeKey>,
+        hovered_path: &mut Vec<crate::view::node_arena::NodeKey>,
         next_target: Option<crate::view::node_arena::NodeKey>,
+        pointer: Option<(f32, f32)>,
+        intent: Option<(&mut HoverIntentState, Instant)>,
     ) -> bool {
+        let next_path =
+            Self::resolve_hover_path(arena, root_keys, hovered_path, next_target, pointer, intent);
         *hovered_node_id = next_target;
-        Self::apply_hover_target(arena, root_keys, next_target)
+        Self::apply_hover_path(arena, hovered_path, next_path)
     }
 
     pub(super) fn save_scroll_states(
@@ -125,13 +170,106 @@ impl Viewport {
         }
     }
 
-    // Phase B (軌 1 #1-#6 unlocked): scene-side `save_element_snapshots`
-    // / `restore_element_snapshots` removed. Incremental commit no
-    // longer rebuilds Element instances on every render, so the
-    // host-state save/restore hack has nothing to compensate for on
-    // the happy path. The remaining full-rebuild fallbacks
-    // (Fragment-at-root, multi-descriptor Replace, Text cascade
-    // boundary, em/rem font_size) accept the documented state loss.
+    /// Collect `ElementTrait::snapshot_state` for every host under
+    /// `root_keys`, keyed by stable id. The node key is kept so the
+    /// restore can skip instances that survived the commit.
+    pub(super) fn save_host_states(
+        arena: &crate::view::node_arena::NodeArena,
+        root_keys: &[crate::view::node_arena::NodeKey],
+        map: &mut HostStateSnapshots,
+    ) {
+        fn walk(
+            arena: &crate::view::node_arena::NodeArena,
+            key: crate::view::node_arena::NodeKey,
+            map: &mut HostStateSnapshots,
+        ) {
+            let Some(node) = arena.get(key) else {
+                return;
+            };
+            if let Some(snapshot) = node.element.snapshot_state() {
+                map.insert(node.element.stable_id(), (key, snapshot));
+            }
+            for &child_key in &node.children {
+                walk(arena, child_key, map);
+            }
+        }
+        for &root_key in root_keys {
+            walk(arena, root_key, map);
+        }
+    }
+
+    /// Hand each snapshot from [`Self::save_host_states`] to the new
+    /// instance with the same stable id. The full-rebuild fallbacks
+    /// (Fragment-at-root, multi-descriptor Replace, Text cascade
+    /// boundary, em/rem font_size) and cross-parent keyed moves mint new
+    /// elements; this keeps a TextArea's caret, selection and IME
+    /// composition across them.
+    pub(super) fn restore_host_states(
+        arena: &crate::view::node_arena::NodeArena,
+        root_keys: &[crate::view::node_arena::NodeKey],
+        map: &mut HostStateSnapshots,
+    ) {
+        fn walk(
+            arena: &crate::view::node_arena::NodeArena,
+            key: crate::view::node_arena::NodeKey,
+            map: &mut HostStateSnapshots,
+        ) {
+            if map.is_empty() {
+                return;
+            }
+            let Some(node) = arena.get(key) else {
+                return;
+            };
+            let stable_id = node.element.stable_id();
+            let child_keys = node.children.clone();
+            drop(node);
+
+            if map
+                .get(&stable_id)
+                .is_some_and(|(old_key, _)| *old_key != key)
+                && let Some((_, snapshot)) = map.remove(&stable_id)
+            {
+                let _ = arena.mutate_element_ref_with_invalidation(key, |element, cx| {
+                    let dirty = element.restore_state(snapshot);
+                    if !dirty.is_empty() {
+                        cx.invalidate(dirty);
+                    }
+                });
+            }
+            for child_key in child_keys {
+                walk(arena, child_key, map);
+            }
+        }
+        for &root_key in root_keys {
+            walk(arena, root_key, map);
+        }
+    }
+
+    pub(super) fn focused_stable_id(&self) -> Option<u64> {
+        let key = self.input_state.focused_node_id?;
+        let node = self.scene.node_arena.get(key)?;
+        Some(node.element.stable_id())
+    }
+
+    /// Point focus at the successor of a focused node that a commit
+    /// replaced. Focus was already dispatched to the old instance, so the
+    /// move is silent: no blur/focus pair fires for the same logical node.
+    pub(super) fn refocus_by_stable_id(&mut self, stable_id: Option<u64>) {
+        let Some(old_key) = self.input_state.focused_node_id else {
+            return;
+        };
+        if self.scene.node_arena.contains_key(old_key) {
+            return;
+        }
+        let Some(new_key) = stable_id.and_then(|id| self.scene.node_arena.find_by_stable_id(id))
+        else {
+            return;
+        };
+        if self.dispatched_focus_node_id == Some(old_key) {
+            self.dispatched_focus_node_id = Some(new_key);
+        }
+        self.input_state.focused_node_id = Some(new_key);
+    }
 
     pub(super) fn extract_style_prop(
         props: &[(&'static str, PropValue)],
@@ -496,37 +634,79 @@ pub(crate) fn paint_snapped_retained_surface_bounds(
     }
 }
 
-pub(crate) fn update_hover_state(
+/// Move the hover flag from `previous_path` to `next_path`, both
+/// root-to-target chains from [`hover_path_for_target`]. Only nodes on the
+/// two chains are touched, so a pointer move costs O(depth) instead of a
+/// walk over every node. Keys that no longer resolve are skipped.
+pub(crate) fn update_hover_path(
     arena: &crate::view::node_arena::NodeArena,
-    root_key: crate::view::node_arena::NodeKey,
-    target_key: Option<crate::view::node_arena::NodeKey>,
+    previous_path: &[crate::view::node_arena::NodeKey],
+    next_path: &[crate::view::node_arena::NodeKey],
 ) -> bool {
-    fn walk(
+    fn set_hovered(
         arena: &crate::view::node_arena::NodeArena,
         key: crate::view::node_arena::NodeKey,
-        target_key: Option<crate::view::node_arena::NodeKey>,
-    ) -> (bool, bool) {
+        hovered: bool,
+    ) -> bool {
         arena
             .mutate_element_ref_with_invalidation(key, |element, cx| {
-                let mut contains_target = target_key == Some(key);
-                let mut changed = false;
-                let children: Vec<_> = element.children().to_vec();
-                for child_key in children.into_iter().rev() {
-                    let (child_contains_target, child_changed) =
-                        walk(cx.arena(), child_key, target_key);
-                    contains_target |= child_contains_target;
-                    changed |= child_changed;
-                }
-                changed |= element.set_hovered(contains_target);
+                let changed = element.set_hovered(hovered);
                 if changed {
                     cx.invalidate(element.local_dirty_flags());
                 }
-                (contains_target, changed)
+                changed
             })
-            .unwrap_or((false, false))
+            .unwrap_or(false)
+    }
+
+    let mut changed = false;
+    for &key in previous_path.iter().rev() {
+        if !next_path.contains(&key) {
+            changed |= set_hovered(arena, key, false);
+        }
+    }
+    for &key in next_path.iter().rev() {
+        changed |= set_hovered(arena, key, true);
+    }
+    changed
+}
+
+/// Restyle every node whose `media` style blocks flipped under the media
+/// context just published, or whose `elevation` presets were replaced.
+/// Returns whether any node restyled.
+pub(crate) fn refresh_media_styles(
+    arena: &crate::view::node_arena::NodeArena,
+    root_keys: &[crate::view::node_arena::NodeKey],
+) -> bool {
+    fn walk(
+        arena: &crate::view::node_arena::NodeArena,
+        key: crate::view::node_arena::NodeKey,
+    ) -> bool {
+        let Some(node) = arena.get(key) else {
+            return false;
+        };
+        let stale = node.element.media_style_stale();
+        let child_keys = node.children.clone();
+        drop(node);
+
+        let mut changed = stale
+            && arena
+                .mutate_element_ref_with_invalidation(key, |element, cx| {
+                    element.refresh_media_style();
+                    cx.invalidate(element.local_dirty_flags());
+                })
+                .is_some();
+        for child_key in child_keys {
+            changed |= walk(arena, child_key);
+        }
+        changed
     }
 
-    walk(arena, root_key, target_key).1
+    let mut changed = false;
+    for &root_key in root_keys {
+        changed |= walk(arena, root_key);
+    }
+    changed
 }
 
 /// Build a root-to-target path using `arena.parent_of`. Returns empty when
@@ -631,38 +811,31 @@ fn dispatch_pointer_leave_to_key(
         .unwrap_or(false)
 }
 
-pub(crate) fn dispatch_hover_transition(
+/// Dispatch `PointerLeave` to the nodes of `previous_path` missing from
+/// `next_path`, deepest first, then `PointerEnter` to the nodes new in
+/// `next_path`, outermost first. The paths need not be single chains: hover
+/// intent can keep a node from another branch in them.
+pub(crate) fn dispatch_hover_path_transition(
     arena: &crate::view::node_arena::NodeArena,
-    root_keys: &[crate::view::node_arena::NodeKey],
+    previous_path: &[crate::view::node_arena::NodeKey],
+    next_path: &[crate::view::node_arena::NodeKey],
     previous_target: Option<crate::view::node_arena::NodeKey>,
     next_target: Option<crate::view::node_arena::NodeKey>,
     pointer: crate::ui::PointerEventData,
 ) -> bool {
-    if previous_target == next_target {
-        return false;
-    }
-
-    let previous_path = hover_path_for_target(arena, root_keys, previous_target);
-    let next_path = hover_path_for_target(arena, root_keys, next_target);
-
-    let mut common_prefix_len = 0;
-    while common_prefix_len < previous_path.len()
-        && common_prefix_len < next_path.len()
-        && previous_path[common_prefix_len] == next_path[common_prefix_len]
-    {
-        common_prefix_len += 1;
-    }
-
     let mut dispatched = false;
 
-    for &k in previous_path[common_prefix_len..].iter().rev() {
-        if dispatch_pointer_leave_to_key(arena, k, next_target, pointer) {
+    for &k in previous_path.iter().rev() {
+        if !next_path.contains(&k) && dispatch_pointer_leave_to_key(arena, k, next_target, pointer)
+        {
             dispatched = true;
         }
     }
 
-    for &k in &next_path[common_prefix_len..] {
-        if dispatch_pointer_enter_to_key(arena, k, previous_target, pointer) {
+    for &k in next_path {
+        if !previous_path.contains(&k)
+            && dispatch_pointer_enter_to_key(arena, k, previous_target, pointer)
+        {
             dispatched = true;
         }
     }
@@ -723,6 +896,36 @@ mod hover_tests {
         }
     }
 
+    fn dispatch_hover_transition(
+        arena: &crate::view::node_arena::NodeArena,
+        root_keys: &[crate::view::node_arena::NodeKey],
+        previous_target: Option<crate::view::node_arena::NodeKey>,
+        next_target: Option<crate::view::node_arena::NodeKey>,
+        pointer: PointerEventData,
+    ) -> bool {
+        dispatch_hover_path_transition(
+            arena,
+            &hover_path_for_target(arena, root_keys, previous_target),
+            &hover_path_for_target(arena, root_keys, next_target),
+            previous_target,
+            next_target,
+            pointer,
+        )
+    }
+
+    fn apply_hover_target(
+        arena: &crate::view::node_arena::NodeArena,
+        root_keys: &[crate::view::node_arena::NodeKey],
+        hovered_path: &mut Vec<crate::view::node_arena::NodeKey>,
+        target: Option<crate::view::node_arena::NodeKey>,
+    ) -> bool {
+        Viewport::apply_hover_path(
+            arena,
+            hovered_path,
+            hover_path_for_target(arena, root_keys, target),
+        )
+    }
+
     #[test]
     fn hover_transition_dispatches_enter_leave_on_changed_ancestors_only() {
         let order = Rc::new(RefCell::new(Vec::new()));
@@ -794,4 +997,55 @@ mod hover_tests {
         ));
         assert!(order.borrow().is_empty());
     }
+
+    #[test]
+    fn hover_path_update_moves_flags_between_sibling_chains() {
+        let mut arena = new_test_arena();
+        let root_key = commit_element(&mut arena, Box::new(Element::new(0.0, 0.0, 120.0, 120.0)));
+        let first_key = commit_child(
+            &mut arena,
+            root_key,
+            Box::new(Element::new(0.0, 0.0, 60.0, 60.0)),
+        );
+        let second_key = commit_child(
+            &mut arena,
+            root_key,
+            Box::new(Element::new(60.0, 0.0, 60.0, 60.0)),
+        );
+        let roots = [root_key];
+        let hovered = |arena: &crate::view::node_arena::NodeArena, key| {
+            crate::view::test_support::get_element::<Element>(arena, key).is_hovered_for_test()
+        };
+
+        let mut path = Vec::new();
+        assert!(apply_hover_target(
+            &arena,
+            &roots,
+            &mut path,
+            Some(first_key)
+        ));
+        assert_eq!(path, vec![root_key, first_key]);
+        assert!(hovered(&arena, root_key) && hovered(&arena, first_key));
+        assert!(!hovered(&arena, second_key));
+
+        assert!(apply_hover_target(
+            &arena,
+            &roots,
+            &mut path,
+            Some(second_key)
+        ));
+        assert!(hovered(&arena, root_key) && hovered(&arena, second_key));
+        assert!(!hovered(&arena, first_key));
+
+        assert!(!apply_hover_target(
+            &arena,
+            &roots,
+            &mut path,
+            Some(second_key)
+        ));
+
+        assert!(apply_hover_target(&arena, &roots, &mut path, None));
+        assert!(path.is_empty());
+        assert!(!hovered(&arena, root_key) && !hovered(&arena, second_key));
+    }
 }
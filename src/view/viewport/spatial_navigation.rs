@@ -0,0 +1,163 @@
+//! Arrow-key spatial navigation for containers with `spatial_navigation`.
+//!
+//! An unprevented arrow key without modifiers moves focus from the focused
+//! node to the geometrically nearest focusable node of the closest
+//! enclosing spatial-navigation container. Candidates must lie past the
+//! focused box in the pressed direction; the winner minimises the distance
+//! along the key's axis plus twice the offset across it, so a roughly
+//! aligned neighbour beats a closer diagonal one.
+
+use super::*;
+use crate::platform::{Key, Modifiers};
+use crate::ui::{KeyEventData, Rect};
+use crate::view::node_arena::{NodeArena, NodeKey};
+
+#[derive(Clone, Copy)]
+enum Direction {
+    Left,
+    Right,
+    Up,
+    Down,
+}
+
+impl Direction {
+    fn from_key(key: &KeyEventData) -> Option<Self> {
+        let modifiers = Modifiers::SHIFT | Modifiers::CTRL | Modifiers::ALT | Modifiers::META;
+        if key.modifiers.intersects(modifiers) || key.is_composing {
+            return None;
+        }
+        match key.key {
+            Key::ArrowLeft => Some(Self::Left),
+            Key::ArrowRight => Some(Self::Right),
+            Key::ArrowUp => Some(Self::Up),
+            Key::ArrowDown => Some(Self::Down),
+            _ => None,
+        }
+    }
+
+    /// Distance along the key's axis and offset across it from `from` to
+    /// `to`, or `None` when `to` does not lie in this direction.
+    fn measure(self, from: Rect, to: Rect) -> Option<(f32, f32)> {
+        let (from_start, from_end, to_start, to_end) = match self {
+            Self::Left | Self::Right => (from.x, from.x + from.width, to.x, to.x + to.width),
+            Self::Up | Self::Down => (from.y, from.y + from.height, to.y, to.y + to.height),
+        };
+        let primary = match self {
+            Self::Right | Self::Down if to_start >= from_end - 0.5 => to_start - from_end,
+            Self::Left | Self::Up if to_end <= from_start + 0.5 => from_start - to_end,
+            _ => return None,
+        };
+        let (from_cross, to_cross) = match self {
+            Self::Left | Self::Right => (center(from).1, center(to).1),
+            Self::Up | Self::Down => (center(from).0, center(to).0),
+        };
+        Some((primary.max(0.0), (to_cross - from_cross).abs()))
+    }
+}
+
+impl Viewport {
+    /// Move focus for an arrow key left unhandled by the focused subtree.
+    /// Returns whether focus moved.
+    pub(super) fn navigate_spatially(&mut self, key: &KeyEventData) -> bool {
+        if self.input_state.key_down_default_prevented
+            || self.input_state.keyboard_capture_node_id.is_some()
+        {
+            return false;
+        }
+        let Some(direction) = Direction::from_key(key) else {
+            return false;
+        };
+        let Some(focused) = self.focused_node_id() else {
+            return false;
+        };
+        let arena = &self.scene.node_arena;
+        // Targets that consume key presses themselves (text areas) keep
+        // their arrow keys.
+        let Some(origin) = arena
+            .get(focused)
+            .filter(|node| !node.element.block_key_down_child_event())
+            .and_then(|node| border_box(&node))
+        else {
+            return false;
+        };
+        let Some(container) = spatial_container(arena, focused) else {
+            return false;
+        };
+        let mut best: Option<(f32, NodeKey)> = None;
+        collect_candidates(arena, container, &mut |key, rect| {
+            if key == focused {
+                return;
+            }
+            let Some((primary, cross)) = direction.measure(origin, rect) else {
+                return;
+            };
+            let score = primary + cross * 2.0;
+            if best.is_none_or(|(best_score, _)| score < best_score) {
+                best = Some((score, key));
+            }
+        });
+        let Some((_, next)) = best else {
+            return false;
+        };
+        self.input_state.pending_focus_reason = crate::ui::FocusReason::Keyboard;
+        self.set_focused_node_id(Some(next));
+        self.sync_focus_dispatch();
+        self.input_state.pending_focus_reason = crate::ui::FocusReason::Programmatic;
+        let root_keys = self.scene.ui_root_keys.clone();
+        dispatch::scroll_into_view_impl(
+            &self.scene.node_arena,
+            &root_keys,
+            next,
+            crate::ui::ScrollIntoViewOptions::default(),
+        );
+        self.request_redraw();
+        true
+    }
+}
+
+/// Closest ancestor of `key` with spatial navigation enabled.
+fn spatial_container(arena: &NodeArena, key: NodeKey) -> Option<NodeKey> {
+    let mut current = arena.parent_of(key);
+    while let Some(candidate) = current {
+        if arena.get(candidate)?.element.spatial_navigation() {
+            return Some(candidate);
+        }
+        current = arena.parent_of(candidate);
+    }
+    None
+}
+
+/// Visit every rendered, focusable descendant of `key` with its border box.
+fn collect_candidates(arena: &NodeArena, key: NodeKey, visit: &mut dyn FnMut(NodeKey, Rect)) {
+    let Some(node) = arena.get(key) else {
+        return;
+    };
+    let children = node.children().to_vec();
+    drop(node);
+    for child in children {
+        let Some(node) = arena.get(child) else {
+            continue;
+        };
+        let rect = border_box(&node);
+        let focusable = node.element.spatial_focusable();
+        drop(node);
+        if let Some(rect) = rect {
+            if focusable {
+                visit(child, rect);
+            }
+            collect_candidates(arena, child, visit);
+        }
+    }
+}
+
+/// Viewport-space border box, or `None` while the node is not rendered.
+fn border_box(node: &crate::view::node_arena::NodeGuard<'_>) -> Option<Rect> {
+    let snapshot = node.element.box_model_snapshot();
+    snapshot
+        .should_render
+        .then(|| Rect::new(snapshot.x, snapshot.y, snapshot.width, snapshot.height))
+}
+
+fn center(rect: Rect) -> (f32, f32) {
+    (rect.x + rect.width * 0.5, rect.y + rect.height * 0.5)
+}
@@ -0,0 +1,172 @@
+//! Synthetic input for automation: test harnesses, macros and remote
+//! control build events in code and feed them through the same dispatch
+//! path as platform input — hit testing, bubbling, focus changes, default
+//! actions and global listeners included.
+
+use super::*;
+use crate::platform::input::{Key, Modifiers};
+
+/// Where a synthetic pointer event lands.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum SyntheticTarget {
+    /// A point in viewport coordinates.
+    Point { x: f32, y: f32 },
+    /// The centre of a node's border box. Whatever is hit there receives
+    /// the event, so a child covering the centre is the target and the
+    /// node sees it while it bubbles.
+    Node(NodeId),
+}
+
+/// An input event built in code and fed to [`Viewport::dispatch`].
+#[derive(Clone, Debug, PartialEq)]
+pub enum SyntheticEvent {
+    PointerMove {
+        at: SyntheticTarget,
+    },
+    PointerDown {
+        at: SyntheticTarget,
+        button: PointerButton,
+    },
+    PointerUp {
+        at: SyntheticTarget,
+        button: PointerButton,
+    },
+    /// A full press: move, down, up and the click (or context menu) they
+    /// produce.
+    Click {
+        at: SyntheticTarget,
+        button: PointerButton,
+    },
+    Wheel {
+        at: SyntheticTarget,
+        delta_x: f32,
+        delta_y: f32,
+    },
+    /// A key press, sent to `target` (focused first) or to the current
+    /// focus when `None`.
+    KeyDown {
+        target: Option<NodeId>,
+        key: Key,
+        modifiers: Modifiers,
+    },
+    KeyUp {
+        target: Option<NodeId>,
+        key: Key,
+        modifiers: Modifiers,
+    },
+    /// Typed text, sent to `target` (focused first) or to the current
+    /// focus when `None`.
+    Text {
+        target: Option<NodeId>,
+        text: String,
+    },
+}
+
+impl Viewport {
+    /// Dispatch a synthetic event through the full input path. Returns
+    /// whether an element handled it, like the `dispatch_platform_*`
+    /// entry points; false as well when a node target no longer exists.
+    pub fn dispatch(&mut self, event: SyntheticEvent) -> bool {
+        let _span = crate::trace::span!("dispatch", event = "synthetic");
+        match event {
+            SyntheticEvent::PointerMove { at } => {
+                self.move_synthetic_pointer(at) && self.dispatch_pointer_move_event()
+            }
+            SyntheticEvent::PointerDown { at, button } => {
+                self.move_synthetic_pointer(at) && self.dispatch_pointer_down_event(button)
+            }
+            SyntheticEvent::PointerUp { at, button } => {
+                self.move_synthetic_pointer(at) && self.dispatch_pointer_up_event(button)
+            }
+            SyntheticEvent::Click { at, button } => {
+                if !self.move_synthetic_pointer(at) {
+                    return false;
+                }
+                self.dispatch_pointer_move_event();
+                let pressed = self.dispatch_pointer_down_event(button);
+                self.dispatch_pointer_up_event(button);
+                let clicked = self.dispatch_click_event(button);
+                pressed || clicked
+            }
+            SyntheticEvent::Wheel {
+                at,
+                delta_x,
+                delta_y,
+            } => {
+                self.move_synthetic_pointer(at)
+                    && self.dispatch_pointer_wheel_event(delta_x, delta_y)
+            }
+            SyntheticEvent::KeyDown {
+                target,
+                key,
+                modifiers,
+            } => {
+                self.focus_synthetic_target(target)
+                    && self.dispatch_synthetic_key(key, modifiers, true)
+            }
+            SyntheticEvent::KeyUp {
+                target,
+                key,
+                modifiers,
+            } => {
+                self.focus_synthetic_target(target)
+                    && self.dispatch_synthetic_key(key, modifiers, false)
+            }
+            SyntheticEvent::Text { target, text } => {
+                self.focus_synthetic_target(target)
+                    && self.dispatch_text_input_event_full(
+                        text,
+                        crate::ui::InputType::Typing,
+                        false,
+                    )
+            }
+        }
+    }
+
+    /// Move the pointer to `at`; false when a node target is gone.
+    fn move_synthetic_pointer(&mut self, at: SyntheticTarget) -> bool {
+        let (x, y) = match at {
+            SyntheticTarget::Point { x, y } => (x, y),
+            SyntheticTarget::Node(key) => {
+                let Some(node) = self.scene.node_arena.get(key) else {
+                    return false;
+                };
+                let snapshot = node.element.box_model_snapshot();
+                (
+                    snapshot.x + snapshot.width * 0.5,
+                    snapshot.y + snapshot.height * 0.5,
+                )
+            }
+        };
+        self.set_pointer_position_viewport(x, y);
+        true
+    }
+
+    /// Focus `target` unless it already has focus; false when it is gone.
+    fn focus_synthetic_target(&mut self, target: Option<NodeId>) -> bool {
+        let Some(key) = target else {
+            return true;
+        };
+        if self.scene.node_arena.get(key).is_none() {
+            return false;
+        }
+        if self.focused_node_id() != Some(key) {
+            self.input_state.pending_focus_reason = crate::ui::FocusReason::Programmatic;
+            self.set_focused_node_id(Some(key));
+            self.sync_focus_dispatch();
+        }
+        true
+    }
+
+    fn dispatch_synthetic_key(&mut self, key: Key, modifiers: Modifiers, pressed: bool) -> bool {
+        self.dispatch_platform_key_event(&PlatformKeyEvent {
+            key,
+            characters: None,
+            modifiers,
+            repeat: false,
+            is_composing: false,
+            pressed,
+            timestamp: Instant::now(),
+        })
+    }
+}
@@ -1,7 +1,8 @@
 use super::*;
+use crate::style::TransitionProperty;
 use crate::transition::{
-    AnimationRequest, LayoutField, LayoutTrackRequest, StyleTrackRequest, VisualField,
-    VisualTrackRequest,
+    AnimationRequest, LayoutField, LayoutTrackRequest, StyleTrackRequest, TransitionGroup,
+    VisualField, VisualTrackRequest,
 };
 use crate::view::base_component::{DirtyFlags, DirtyPassMask, Element, ElementTrait};
 
@@ -67,9 +68,103 @@ impl Viewport {
                 | CHANNEL_STYLE_TRANSFORM
                 | CHANNEL_STYLE_TRANSFORM_ORIGIN
                 | CHANNEL_STYLE_BOX_SHADOW
+                | CHANNEL_STYLE_GAP
+                | CHANNEL_STYLE_PADDING_TOP
+                | CHANNEL_STYLE_PADDING_RIGHT
+                | CHANNEL_STYLE_PADDING_BOTTOM
+                | CHANNEL_STYLE_PADDING_LEFT
         )
     }
 
+    /// Freeze every style, layout, and position transition and keyframe
+    /// animation in place. Scroll animations keep running.
+    pub fn pause_transitions(&mut self) {
+        self.transitions.playback.pause();
+    }
+
+    pub fn resume_transitions(&mut self) {
+        self.transitions.playback.resume();
+        self.request_redraw();
+    }
+
+    pub fn transitions_paused(&self) -> bool {
+        self.transitions.playback.is_paused()
+    }
+
+    /// Scale the speed of every non-scroll transition, e.g. `0.1` to debug
+    /// an effect in slow motion.
+    pub fn set_transition_playback_rate(&mut self, rate: f32) {
+        self.transitions.playback.set_rate(rate);
+        self.request_redraw();
+    }
+
+    pub fn transition_playback_rate(&self) -> f32 {
+        self.transitions.playback.rate()
+    }
+
+    /// Jump every running transition and finite keyframe animation to its
+    /// end on the next frame, even while paused.
+    pub fn finish_transitions(&mut self) {
+        self.transitions.style_transition_plugin.finish_all_tracks();
+        self.transitions
+            .layout_transition_plugin
+            .finish_all_tracks();
+        self.transitions
+            .visual_transition_plugin
+            .finish_all_tracks();
+        self.transitions.animation_plugin.finish_all_animations();
+        self.request_redraw();
+    }
+
+    /// Freeze or unfreeze one style, layout, or position track. Returns
+    /// `false` when no such track is running.
+    pub fn set_transition_track_paused(
+        &mut self,
+        key: TrackKey<TrackTarget>,
+        paused: bool,
+    ) -> bool {
+        let paused_track = match self.transitions.transition_claims.get(&key).copied() {
+            Some(StyleTransitionPlugin::BUILTIN_PLUGIN_ID) => self
+                .transitions
+                .style_transition_plugin
+                .set_track_paused(key, paused),
+            Some(LayoutTransitionPlugin::BUILTIN_PLUGIN_ID) => self
+                .transitions
+                .layout_transition_plugin
+                .set_track_paused(key, paused),
+            Some(VisualTransitionPlugin::BUILTIN_PLUGIN_ID) => self
+                .transitions
+                .visual_transition_plugin
+                .set_track_paused(key, paused),
+            _ => false,
+        };
+        if paused_track && !paused {
+            self.request_redraw();
+        }
+        paused_track
+    }
+
+    /// Jump one style, layout, or position track to its target on the next
+    /// frame. Returns `false` when no such track is running.
+    pub fn finish_transition_track(&mut self, key: TrackKey<TrackTarget>) -> bool {
+        let finished = match self.transitions.transition_claims.get(&key).copied() {
+            Some(StyleTransitionPlugin::BUILTIN_PLUGIN_ID) => {
+                self.transitions.style_transition_plugin.finish_track(key)
+            }
+            Some(LayoutTransitionPlugin::BUILTIN_PLUGIN_ID) => {
+                self.transitions.layout_transition_plugin.finish_track(key)
+            }
+            Some(VisualTransitionPlugin::BUILTIN_PLUGIN_ID) => {
+                self.transitions.visual_transition_plugin.finish_track(key)
+            }
+            _ => false,
+        };
+        if finished {
+            self.request_redraw();
+        }
+        finished
+    }
+
     pub(super) fn cancel_track_by_owner(&mut self, key: TrackKey<TrackTarget>) -> bool {
         let Some(owner) = self.transitions.transition_claims.get(&key).copied() else {
             return false;
@@ -149,21 +244,35 @@ impl Viewport {
         }
     }
 
+    /// Every pass writes premultiplied color, so transparent surfaces prefer
+    /// `PreMultiplied`; opaque ones prefer `Opaque` so stray alpha in the
+    /// frame never reaches the compositor.
     pub(super) fn alpha_mode_from_capabilities(
         alpha_modes: &[wgpu::CompositeAlphaMode],
+        transparent: bool,
     ) -> wgpu::CompositeAlphaMode {
-        for preferred in [
-            wgpu::CompositeAlphaMode::PostMultiplied,
-            wgpu::CompositeAlphaMode::PreMultiplied,
-            wgpu::CompositeAlphaMode::Inherit,
-            wgpu::CompositeAlphaMode::Auto,
-            wgpu::CompositeAlphaMode::Opaque,
-        ] {
-            if alpha_modes.contains(&preferred) {
-                return preferred;
-            }
-        }
-        wgpu::CompositeAlphaMode::Auto
+        let preferences: &[wgpu::CompositeAlphaMode] = if transparent {
+            &[
+                wgpu::CompositeAlphaMode::PreMultiplied,
+                wgpu::CompositeAlphaMode::PostMultiplied,
+                wgpu::CompositeAlphaMode::Inherit,
+                wgpu::CompositeAlphaMode::Auto,
+                wgpu::CompositeAlphaMode::Opaque,
+            ]
+        } else {
+            &[
+                wgpu::CompositeAlphaMode::Opaque,
+                wgpu::CompositeAlphaMode::Auto,
+                wgpu::CompositeAlphaMode::Inherit,
+                wgpu::CompositeAlphaMode::PreMultiplied,
+                wgpu::CompositeAlphaMode::PostMultiplied,
+            ]
+        };
+        preferences
+            .iter()
+            .copied()
+            .find(|preferred| alpha_modes.contains(preferred))
+            .unwrap_or(wgpu::CompositeAlphaMode::Auto)
     }
     fn sync_layout_transition_claims(&mut self) {
         let active_keys = self
@@ -255,7 +364,7 @@ impl Viewport {
     }
 
     pub(super) fn transition_timing(&mut self) -> (f32, f64) {
-        let now = Instant::now();
+        let now = self.frame_now();
         let dt = self
             .transitions
             .last_transition_tick
@@ -268,6 +377,7 @@ impl Viewport {
     }
 
     pub(super) fn run_pre_layout_transitions(&mut self, dt: f32, now_seconds: f64) -> bool {
+        let frame = self.transitions.playback.frame(dt, now_seconds);
         let mut arena = std::mem::take(&mut self.scene.node_arena);
         let root_keys = self.scene.ui_root_keys.clone();
         let mut layout_requests = Vec::new();
@@ -284,11 +394,20 @@ impl Viewport {
             }
         }
         if !layout_requests.is_empty() {
+            let delays = self.begin_transition_group_wave(
+                &arena,
+                &root_keys,
+                layout_requests.iter().map(|request| request.target),
+            );
             let mut host = TransitionHostAdapter {
                 registered_channels: &self.transitions.transition_channels,
                 claims: &mut self.transitions.transition_claims,
             };
-            for request in layout_requests {
+            for mut request in layout_requests {
+                if let Some(delay_ms) = delays.get(&request.target) {
+                    request.transition.delay_ms =
+                        request.transition.delay_ms.saturating_add(*delay_ms);
+                }
                 let _ = self
                     .transitions
                     .layout_transition_plugin
@@ -307,13 +426,9 @@ impl Viewport {
                 registered_channels: &self.transitions.transition_channels,
                 claims: &mut self.transitions.transition_claims,
             };
-            self.transitions.layout_transition_plugin.run_tracks(
-                TransitionFrame {
-                    dt_seconds: dt,
-                    now_seconds,
-                },
-                &mut host,
-            )
+            self.transitions
+                .layout_transition_plugin
+                .run_tracks(frame, &mut host)
         };
         self.sync_layout_transition_claims();
         let mut changed = false;
@@ -344,6 +459,7 @@ impl Viewport {
         dt: f32,
         now_seconds: f64,
     ) -> PostLayoutTransitionResult {
+        let frame = self.transitions.playback.frame(dt, now_seconds);
         let mut arena = std::mem::take(&mut self.scene.node_arena);
         let root_keys = self.scene.ui_root_keys.clone();
         // Building the live-id set is a whole-tree walk; only needed when
@@ -393,7 +509,28 @@ impl Viewport {
                 );
             }
         }
-        for request in animation_requests {
+        let group_delays = if animation_requests.is_empty()
+            && style_requests.is_empty()
+            && layout_requests.is_empty()
+            && visual_requests.is_empty()
+        {
+            FxHashMap::default()
+        } else {
+            self.begin_transition_group_wave(
+                &arena,
+                &root_keys,
+                animation_requests
+                    .iter()
+                    .map(|request| request.target)
+                    .chain(style_requests.iter().map(|request| request.target))
+                    .chain(layout_requests.iter().map(|request| request.target))
+                    .chain(visual_requests.iter().map(|request| request.target)),
+            )
+        };
+        for mut request in animation_requests {
+            if let Some(delay_ms) = group_delays.get(&request.target) {
+                request.delay_offset_ms = request.delay_offset_ms.saturating_add(*delay_ms);
+            }
             self.transitions.animation_plugin.start_animator(request);
         }
         if !style_requests.is_empty() {
@@ -401,7 +538,11 @@ impl Viewport {
                 registered_channels: &self.transitions.transition_channels,
                 claims: &mut self.transitions.transition_claims,
             };
-            for request in style_requests {
+            for mut request in style_requests {
+                if let Some(delay_ms) = group_delays.get(&request.target) {
+                    request.transition.delay_ms =
+                        request.transition.delay_ms.saturating_add(*delay_ms);
+                }
                 let _ = self.transitions.style_transition_plugin.start_style_track(
                     &mut host,
                     request.target,
@@ -417,7 +558,11 @@ impl Viewport {
                 registered_channels: &self.transitions.transition_channels,
                 claims: &mut self.transitions.transition_claims,
             };
-            for request in layout_requests {
+            for mut request in layout_requests {
+                if let Some(delay_ms) = group_delays.get(&request.target) {
+                    request.transition.delay_ms =
+                        request.transition.delay_ms.saturating_add(*delay_ms);
+                }
                 let _ = self
                     .transitions
                     .layout_transition_plugin
@@ -436,7 +581,11 @@ impl Viewport {
                 registered_channels: &self.transitions.transition_channels,
                 claims: &mut self.transitions.transition_claims,
             };
-            for request in visual_requests {
+            for mut request in visual_requests {
+                if let Some(delay_ms) = group_delays.get(&request.target) {
+                    request.transition.delay_ms =
+                        request.transition.delay_ms.saturating_add(*delay_ms);
+                }
                 let _ = self
                     .transitions
                     .visual_transition_plugin
@@ -469,43 +618,31 @@ impl Viewport {
                 registered_channels: &self.transitions.transition_channels,
                 claims: &mut self.transitions.transition_claims,
             };
-            self.transitions.style_transition_plugin.run_tracks(
-                TransitionFrame {
-                    dt_seconds: dt,
-                    now_seconds,
-                },
-                &mut host,
-            )
+            self.transitions
+                .style_transition_plugin
+                .run_tracks(frame, &mut host)
         };
         let animation_result = self
             .transitions
             .animation_plugin
-            .run_animations(dt, now_seconds);
+            .run_animations(frame.dt_seconds, frame.now_seconds);
         let visual_result = {
             let mut host = TransitionHostAdapter {
                 registered_channels: &self.transitions.transition_channels,
                 claims: &mut self.transitions.transition_claims,
             };
-            self.transitions.visual_transition_plugin.run_tracks(
-                TransitionFrame {
-                    dt_seconds: dt,
-                    now_seconds,
-                },
-                &mut host,
-            )
+            self.transitions
+                .visual_transition_plugin
+                .run_tracks(frame, &mut host)
         };
         let layout_result = {
             let mut host = TransitionHostAdapter {
                 registered_channels: &self.transitions.transition_channels,
                 claims: &mut self.transitions.transition_claims,
             };
-            self.transitions.layout_transition_plugin.run_tracks(
-                TransitionFrame {
-                    dt_seconds: dt,
-                    now_seconds,
-                },
-                &mut host,
-            )
+            self.transitions
+                .layout_transition_plugin
+                .run_tracks(frame, &mut host)
         };
         self.sync_layout_transition_claims();
         let samples = self.transitions.scroll_transition_plugin.take_samples();
@@ -606,6 +743,8 @@ impl Viewport {
                 }
             }
         }
+        self.dispatch_transition_lifecycle_events(&mut arena);
+        self.dispatch_settled_transition_groups(&mut arena);
         self.scene.node_arena = arena;
         if scroll_result.keep_running
             || style_result.keep_running
@@ -627,6 +766,115 @@ impl Viewport {
         }
     }
 
+    /// Rank the group members among `targets` by child index, record them
+    /// as a wave of their group, and return each member's stagger delay.
+    fn begin_transition_group_wave(
+        &mut self,
+        arena: &crate::view::node_arena::NodeArena,
+        root_keys: &[crate::view::node_arena::NodeKey],
+        targets: impl Iterator<Item = TrackTarget>,
+    ) -> FxHashMap<TrackTarget, u32> {
+        let mut delays = FxHashMap::default();
+        let members = collect_transition_group_members(arena, root_keys);
+        if members.is_empty() {
+            return delays;
+        }
+        let mut waves =
+            FxHashMap::<TrackTarget, (TransitionGroup, Vec<(usize, TrackTarget)>)>::default();
+        for target in targets {
+            let Some(&(group_id, child_index, group)) = members.get(&target) else {
+                continue;
+            };
+            let (_, wave) = waves.entry(group_id).or_insert_with(|| (group, Vec::new()));
+            if !wave.contains(&(child_index, target)) {
+                wave.push((child_index, target));
+            }
+        }
+        for (group_id, (group, mut wave)) in waves {
+            wave.sort_unstable();
+            let mut rank = 0;
+            let mut previous_child = None;
+            for &(child_index, target) in &wave {
+                if previous_child.is_some_and(|previous| previous != child_index) {
+                    rank += 1;
+                }
+                previous_child = Some(child_index);
+                delays.insert(target, group.member_delay_ms(rank));
+            }
+            self.transitions
+                .transition_groups
+                .begin_wave(group_id, wave.into_iter().map(|(_, target)| target));
+        }
+        delays
+    }
+
+    /// Deliver the start/end/cancel events the style, layout, and visual
+    /// plugins queued since the last tick to the elements they target.
+    fn dispatch_transition_lifecycle_events(
+        &mut self,
+        arena: &mut crate::view::node_arena::NodeArena,
+    ) {
+        let mut events = self
+            .transitions
+            .style_transition_plugin
+            .take_lifecycle_events();
+        events.extend(
+            self.transitions
+                .layout_transition_plugin
+                .take_lifecycle_events(),
+        );
+        events.extend(
+            self.transitions
+                .visual_transition_plugin
+                .take_lifecycle_events(),
+        );
+        for event in events {
+            let Some(property) = transition_property_for_channel(event.key.channel) else {
+                continue;
+            };
+            let Some(&key) = arena.stable_id_index().get(&event.key.target) else {
+                continue;
+            };
+            let _ = arena.with_element_taken(key, |element, _| {
+                if let Some(element) = element.as_any_mut().downcast_mut::<Element>() {
+                    element.dispatch_transition_lifecycle(event.phase, property);
+                }
+            });
+        }
+    }
+
+    /// Fire `on_transition_group_end` for every group whose staggered
+    /// members no longer hold a track or animation.
+    fn dispatch_settled_transition_groups(
+        &mut self,
+        arena: &mut crate::view::node_arena::NodeArena,
+    ) {
+        if self.transitions.transition_groups.is_empty() {
+            return;
+        }
+        let mut active = self.transitions.animation_plugin.active_targets();
+        active.extend(
+            self.transitions
+                .transition_claims
+                .keys()
+                .map(|key| key.target),
+        );
+        let settled = self
+            .transitions
+            .transition_groups
+            .take_settled(|target| active.contains(&target));
+        for (group_id, member_count) in settled {
+            let Some(&key) = arena.stable_id_index().get(&group_id) else {
+                continue;
+            };
+            let _ = arena.with_element_taken(key, |element, _| {
+                if let Some(element) = element.as_any_mut().downcast_mut::<Element>() {
+                    element.dispatch_transition_group_end(member_count);
+                }
+            });
+        }
+    }
+
     pub(super) fn sync_inflight_transition_state(&mut self) -> bool {
         let mut arena = std::mem::take(&mut self.scene.node_arena);
         let root_keys = self.scene.ui_root_keys.clone();
@@ -640,12 +888,16 @@ impl Viewport {
                 .animation_plugin
                 .prune_targets(&live_node_ids);
         }
-        let now = Instant::now();
+        let now = self.frame_now();
         let epoch = self.transitions.transition_epoch.get_or_insert(now);
-        let frame = TransitionFrame {
+        let scroll_frame = TransitionFrame {
             dt_seconds: 0.0,
             now_seconds: (now - *epoch).as_secs_f64(),
         };
+        let frame = self
+            .transitions
+            .playback
+            .frame(0.0, scroll_frame.now_seconds);
         let scroll_result = {
             let mut host = TransitionHostAdapter {
                 registered_channels: &self.transitions.transition_channels,
@@ -653,7 +905,7 @@ impl Viewport {
             };
             self.transitions
                 .scroll_transition_plugin
-                .run_tracks(frame, &mut host)
+                .run_tracks(scroll_frame, &mut host)
         };
         let style_result = {
             let mut host = TransitionHostAdapter {
@@ -1015,6 +1267,76 @@ pub(crate) fn collect_node_id_allowlist(
     out
 }
 
+/// The `transition` property that drives tracks on `channel`.
+fn transition_property_for_channel(channel: ChannelId) -> Option<TransitionProperty> {
+    Some(match channel {
+        CHANNEL_LAYOUT_X | CHANNEL_VISUAL_X => TransitionProperty::X,
+        CHANNEL_LAYOUT_Y | CHANNEL_VISUAL_Y => TransitionProperty::Y,
+        CHANNEL_LAYOUT_WIDTH => TransitionProperty::Width,
+        CHANNEL_LAYOUT_HEIGHT => TransitionProperty::Height,
+        CHANNEL_STYLE_OPACITY => TransitionProperty::Opacity,
+        CHANNEL_STYLE_BORDER_RADIUS => TransitionProperty::BorderRadius,
+        CHANNEL_STYLE_BACKGROUND_COLOR => TransitionProperty::BackgroundColor,
+        CHANNEL_STYLE_COLOR => TransitionProperty::Color,
+        CHANNEL_STYLE_BORDER_TOP_COLOR
+        | CHANNEL_STYLE_BORDER_RIGHT_COLOR
+        | CHANNEL_STYLE_BORDER_BOTTOM_COLOR
+        | CHANNEL_STYLE_BORDER_LEFT_COLOR => TransitionProperty::BorderColor,
+        CHANNEL_STYLE_TRANSFORM => TransitionProperty::Transform,
+        CHANNEL_STYLE_TRANSFORM_ORIGIN => TransitionProperty::TransformOrigin,
+        CHANNEL_STYLE_BOX_SHADOW => TransitionProperty::BoxShadow,
+        CHANNEL_STYLE_GAP => TransitionProperty::Gap,
+        CHANNEL_STYLE_PADDING_TOP
+        | CHANNEL_STYLE_PADDING_RIGHT
+        | CHANNEL_STYLE_PADDING_BOTTOM
+        | CHANNEL_STYLE_PADDING_LEFT => TransitionProperty::Padding,
+        _ => return None,
+    })
+}
+
+/// Map every node inside a `transition_group` host's children to its
+/// innermost group: `(group id, index of the host child it sits under,
+/// group)`.
+pub(crate) fn collect_transition_group_members(
+    arena: &crate::view::node_arena::NodeArena,
+    root_keys: &[crate::view::node_arena::NodeKey],
+) -> FxHashMap<u64, (u64, usize, TransitionGroup)> {
+    let mut out = FxHashMap::default();
+
+    fn walk(
+        arena: &crate::view::node_arena::NodeArena,
+        key: crate::view::node_arena::NodeKey,
+        membership: Option<(u64, usize, TransitionGroup)>,
+        out: &mut FxHashMap<u64, (u64, usize, TransitionGroup)>,
+    ) {
+        let Some(node) = arena.get(key) else { return };
+        let node_id = node.element.stable_id();
+        if let Some(membership) = membership {
+            out.insert(node_id, membership);
+        }
+        let group = node
+            .element
+            .as_any()
+            .downcast_ref::<Element>()
+            .and_then(Element::transition_group);
+        let child_count = node.children.len();
+        drop(node);
+        for index in 0..child_count {
+            let Some(child_key) = arena.child_key_at(key, index) else {
+                break;
+            };
+            let child_membership = group.map(|group| (node_id, index, group)).or(membership);
+            walk(arena, child_key, child_membership, out);
+        }
+    }
+
+    for &root_key in root_keys {
+        walk(arena, root_key, None, &mut out);
+    }
+
+    out
+}
+
 pub(crate) fn reconcile_transition_runtime_state(
     arena: &mut crate::view::node_arena::NodeArena,
     root_keys: &[crate::view::node_arena::NodeKey],
@@ -1072,36 +1394,6 @@ pub(crate) fn set_style_field_by_id(
     set_arena_dirty_style_field_by_id(arena, root_key, stable_id, field, &value)
 }
 
-fn find_active_transition_target(
-    arena: &crate::view::node_arena::NodeArena,
-    root_key: crate::view::node_arena::NodeKey,
-    stable_id: u64,
-) -> Option<crate::view::node_arena::NodeKey> {
-    if let Some(indexed_key) = arena.find_by_stable_id(stable_id)
-        && arena.root_for(indexed_key) == root_key
-    {
-        return Some(indexed_key);
-    }
-
-    fn walk(
-        arena: &crate::view::node_arena::NodeArena,
-        key: crate::view::node_arena::NodeKey,
-        stable_id: u64,
-    ) -> Option<crate::view::node_arena::NodeKey> {
-        let node = arena.get(key)?;
-        if node.element.stable_id() == stable_id {
-            return Some(key);
-        }
-        let children = node.children.clone();
-        drop(node);
-        children
-            .into_iter()
-            .find_map(|child| walk(arena, child, stable_id))
-    }
-
-    walk(arena, root_key, stable_id)
-}
-
 fn set_arena_dirty_style_field_by_id(
     arena: &mut crate::view::node_arena::NodeArena,
     root_key: crate::view::node_arena::NodeKey,
@@ -1109,7 +1401,7 @@ fn set_arena_dirty_style_field_by_id(
     field: StyleField,
     value: &StyleValue,
 ) -> bool {
-    let Some(target_key) = find_active_transition_target(arena, root_key, stable_id) else {
+    let Some(target_key) = arena.find_in_subtree_by_stable_id(root_key, stable_id) else {
         return false;
     };
     if !matches!(
@@ -1130,6 +1422,14 @@ fn set_arena_dirty_style_field_by_id(
                 StyleField::TransformOrigin,
                 StyleValue::TransformOriginProgress { .. }
             )
+            | (
+                StyleField::Gap
+                    | StyleField::PaddingTop
+                    | StyleField::PaddingRight
+                    | StyleField::PaddingBottom
+                    | StyleField::PaddingLeft,
+                StyleValue::Scalar(_)
+            )
     ) {
         return false;
     }
@@ -1189,6 +1489,17 @@ fn set_arena_dirty_style_field_by_id(
                         *from, *to, *progress, cx,
                     );
                 }
+                (
+                    StyleField::Gap
+                    | StyleField::PaddingTop
+                    | StyleField::PaddingRight
+                    | StyleField::PaddingBottom
+                    | StyleField::PaddingLeft,
+                    StyleValue::Scalar(value),
+                ) => {
+                    return element
+                        .set_spacing_transition_sample_with_invalidation(field, *value, cx);
+                }
                 _ => {
                     return false;
                 }
@@ -1292,6 +1603,16 @@ fn set_style_field_by_id_inner(
                     }
                     _ => return false,
                 },
+                StyleField::Gap
+                | StyleField::PaddingTop
+                | StyleField::PaddingRight
+                | StyleField::PaddingBottom
+                | StyleField::PaddingLeft => {
+                    if let StyleValue::Scalar(value) = value {
+                        return element.set_spacing_transition_sample(field, value);
+                    }
+                    return false;
+                }
             }
             return true;
         }
@@ -1313,7 +1634,7 @@ pub(crate) fn set_layout_field_by_id(
     field: LayoutField,
     value: f32,
 ) -> bool {
-    let Some(target_key) = find_active_transition_target(arena, root_key, node_id) else {
+    let Some(target_key) = arena.find_in_subtree_by_stable_id(root_key, node_id) else {
         return false;
     };
 
@@ -1371,7 +1692,7 @@ pub(crate) fn set_visual_field_by_id(
     field: VisualField,
     value: f32,
 ) -> bool {
-    let Some(target_key) = find_active_transition_target(arena, root_key, node_id) else {
+    let Some(target_key) = arena.find_in_subtree_by_stable_id(root_key, node_id) else {
         return false;
     };
 
@@ -0,0 +1,74 @@
+//! Post-layout visibility reporting for elements with an
+//! `on_visibility_change` handler.
+//!
+//! Each observed element's border box is intersected with every
+//! scroll-container ancestor's scrollport and the viewport, so content
+//! scrolled out of a nested list counts as hidden even while the list
+//! itself is on screen.
+
+use super::*;
+use crate::ui::Rect;
+use crate::view::node_arena::{NodeArena, NodeKey};
+
+impl Viewport {
+    /// Report the visible fraction of every observed element. Runs after
+    /// the post-layout tick, once scroll offsets and layout have settled.
+    pub(super) fn dispatch_visibility_changes(&mut self) {
+        let (width, height) = self.logical_size();
+        let bounds = Rect::new(0.0, 0.0, width, height);
+        let arena = &self.scene.node_arena;
+        for &root in &self.scene.ui_root_keys {
+            visit(arena, root, bounds);
+        }
+    }
+}
+
+fn visit(arena: &NodeArena, key: NodeKey, clip: Rect) {
+    let Some(mut node) = arena.get_mut(key) else {
+        return;
+    };
+    if node.element.observes_visibility() {
+        let snapshot = node.element.box_model_snapshot();
+        let ratio = if snapshot.should_render {
+            visible_ratio(
+                Rect::new(snapshot.x, snapshot.y, snapshot.width, snapshot.height),
+                clip,
+            )
+        } else {
+            0.0
+        };
+        node.element.dispatch_visibility(ratio);
+    }
+    let child_clip = match node.element.scrollport_rect() {
+        Some(scrollport) => intersect(clip, scrollport),
+        None => clip,
+    };
+    let children = node.children().to_vec();
+    drop(node);
+    for child in children {
+        visit(arena, child, child_clip);
+    }
+}
+
+/// Share of `rect` inside `clip`. An empty box is fully visible while
+/// its origin is inside the clip and hidden otherwise.
+fn visible_ratio(rect: Rect, clip: Rect) -> f32 {
+    let area = rect.width * rect.height;
+    if area <= 0.0 {
+        let inside = rect.x >= clip.x
+            && rect.x <= clip.x + clip.width
+            && rect.y >= clip.y
+            && rect.y <= clip.y + clip.height;
+        return if inside { 1.0 } else { 0.0 };
+    }
+    let overlap = intersect(rect, clip);
+    (overlap.width * overlap.height / area).clamp(0.0, 1.0)
+}
+
+fn intersect(a: Rect, b: Rect) -> Rect {
+    let x = a.x.max(b.x);
+    let y = a.y.max(b.y);
+    let right = (a.x + a.width).min(b.x + b.width);
+    let bottom = (a.y + a.height).min(b.y + b.height);
+    Rect::new(x, y, (right - x).max(0.0), (bottom - y).max(0.0))
+}